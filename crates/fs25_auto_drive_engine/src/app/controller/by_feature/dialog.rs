@@ -48,6 +48,14 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::dialog::close_zip_browser(state);
             Ok(())
         }
+        AppCommand::CloseRoutesDialog => {
+            handlers::dialog::close_routes_dialog(state);
+            Ok(())
+        }
+        AppCommand::CloseSavegamePicker => {
+            handlers::dialog::close_savegame_picker(state);
+            Ok(())
+        }
         AppCommand::OpenOverviewSourceDialog => {
             handlers::dialog::open_overview_source_dialog(state);
             Ok(())
@@ -80,6 +88,34 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::dialog::close_trace_all_fields_dialog(state);
             Ok(())
         }
+        AppCommand::OpenFieldCourseDialog => {
+            handlers::dialog::open_field_course_dialog(state);
+            Ok(())
+        }
+        AppCommand::CloseFieldCourseDialog => {
+            handlers::dialog::close_field_course_dialog(state);
+            Ok(())
+        }
+        AppCommand::OpenMoveSelectionDialog => {
+            handlers::dialog::open_move_selection_dialog(state);
+            Ok(())
+        }
+        AppCommand::CloseMoveSelectionDialog => {
+            handlers::dialog::close_move_selection_dialog(state);
+            Ok(())
+        }
+        AppCommand::OpenDeleteByFilterDialog => {
+            handlers::dialog::open_delete_by_filter_dialog(state);
+            Ok(())
+        }
+        AppCommand::CloseDeleteByFilterDialog => {
+            handlers::dialog::close_delete_by_filter_dialog(state);
+            Ok(())
+        }
+        AppCommand::ClosePdfExportDialog => {
+            handlers::dialog::close_pdf_export_dialog(state);
+            Ok(())
+        }
         AppCommand::RequestCurseplayImportDialog => {
             handlers::dialog::request_curseplay_import_dialog(state);
             Ok(())
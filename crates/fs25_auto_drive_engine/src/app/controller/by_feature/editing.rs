@@ -18,6 +18,10 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::delete_selected(state);
             Ok(())
         }
+        AppCommand::DeleteNodesByFilter { filter } => {
+            handlers::editing::delete_by_filter(state, filter);
+            Ok(())
+        }
         AppCommand::ConnectToolPickNode {
             world_pos,
             max_distance,
@@ -46,6 +50,14 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::set_connection_direction(state, start_id, end_id, direction);
             Ok(())
         }
+        AppCommand::SwipeConnectionDirection {
+            from,
+            to,
+            toggle_dual,
+        } => {
+            handlers::editing::swipe_connection_direction(state, from, to, toggle_dual);
+            Ok(())
+        }
         AppCommand::SetConnectionPriority {
             start_id,
             end_id,
@@ -54,10 +66,22 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::set_connection_priority(state, start_id, end_id, priority);
             Ok(())
         }
+        AppCommand::InvertConnection { start_id, end_id } => {
+            handlers::editing::invert_connection(state, start_id, end_id);
+            Ok(())
+        }
         AppCommand::SetNodeFlag { node_id, flag } => {
             handlers::editing::set_node_flag(state, node_id, flag);
             Ok(())
         }
+        AppCommand::SetSelectedNodeHeights { edit } => {
+            handlers::editing::set_selected_node_heights(state, edit);
+            Ok(())
+        }
+        AppCommand::ProjectSelectionOntoTerrain { offset } => {
+            handlers::editing::project_selection_onto_terrain(state, offset);
+            Ok(())
+        }
         AppCommand::SetDefaultDirection { direction } => {
             handlers::editing::set_default_direction(state, direction);
             Ok(())
@@ -78,6 +102,14 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::invert_all_between_selected(state);
             Ok(())
         }
+        AppCommand::ReversePathBetweenSelected => {
+            handlers::editing::reverse_path_between_selected(state);
+            Ok(())
+        }
+        AppCommand::InsertMovedNodeIntoConnection => {
+            handlers::editing::insert_moved_node_into_connection(state);
+            Ok(())
+        }
         AppCommand::SetAllConnectionsPriorityBetweenSelected { priority } => {
             handlers::editing::set_all_priorities_between_selected(state, priority);
             Ok(())
@@ -118,6 +150,69 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::streckenteilung_aktivieren(state);
             Ok(())
         }
+        AppCommand::EqualizeChainSpacing => {
+            handlers::editing::equalize_chain_spacing(state);
+            Ok(())
+        }
+        AppCommand::ResampleSelectedChain { spacing } => {
+            handlers::editing::resample_chain(state, spacing);
+            Ok(())
+        }
+        AppCommand::SmoothSelectedChain {
+            strength,
+            iterations,
+        } => {
+            handlers::editing::smooth_chain(state, strength, iterations);
+            Ok(())
+        }
+        AppCommand::SimplifySelectedChain { tolerance } => {
+            handlers::editing::simplify_chain(state, tolerance);
+            Ok(())
+        }
+        AppCommand::AlignSelectedNodes { mode } => {
+            handlers::editing::align_selected_nodes(state, mode);
+            Ok(())
+        }
+        AppCommand::DistributeSelectedNodes => {
+            handlers::editing::distribute_selected_nodes(state);
+            Ok(())
+        }
+        AppCommand::JoinSelectedChains => {
+            handlers::editing::join_selected_chains(state);
+            Ok(())
+        }
+        AppCommand::MergeSelectedNodes => {
+            handlers::editing::merge_selected_nodes(state);
+            Ok(())
+        }
+        AppCommand::ReverseSelectedChain => {
+            handlers::editing::reverse_selected_chain(state);
+            Ok(())
+        }
+        AppCommand::CutConnectionsAlongLine { from, to, split } => {
+            handlers::editing::cut_connections_along_line(state, from, to, split);
+            Ok(())
+        }
+        AppCommand::ConvertJunctionToRoundabout { radius } => {
+            handlers::editing::convert_junction_to_roundabout(state, radius);
+            Ok(())
+        }
+        AppCommand::StartPathOffsetPreview => {
+            handlers::editing::start_path_offset(state);
+            Ok(())
+        }
+        AppCommand::SetPathOffsetDistance { distance } => {
+            handlers::editing::set_path_offset_distance(state, distance);
+            Ok(())
+        }
+        AppCommand::ApplyPathOffset => {
+            handlers::editing::apply_path_offset(state);
+            Ok(())
+        }
+        AppCommand::CancelPathOffsetPreview => {
+            handlers::editing::cancel_path_offset(state);
+            Ok(())
+        }
         AppCommand::CopySelection => {
             handlers::editing::copy_selection(state);
             Ok(())
@@ -126,6 +221,10 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::start_paste_preview(state);
             Ok(())
         }
+        AppCommand::PasteFromText { text } => {
+            handlers::editing::paste_from_text(state, &text);
+            Ok(())
+        }
         AppCommand::UpdatePastePreview { world_pos } => {
             handlers::editing::update_paste_preview(state, world_pos);
             Ok(())
@@ -138,6 +237,14 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::editing::cancel_paste_preview(state);
             Ok(())
         }
+        AppCommand::RotatePastePreview { delta_rad } => {
+            handlers::editing::rotate_paste_preview(state, delta_rad);
+            Ok(())
+        }
+        AppCommand::DuplicateSelection => {
+            handlers::editing::duplicate_selection(state);
+            Ok(())
+        }
         AppCommand::TraceAllFields {
             spacing,
             offset,
@@ -158,6 +265,23 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             );
             Ok(())
         }
+        AppCommand::GenerateFieldCourse {
+            field_id,
+            spacing,
+            headland_offset,
+        } => {
+            handlers::dialog::close_field_course_dialog(state);
+            handlers::editing::generate_field_course(state, field_id, spacing, headland_offset);
+            Ok(())
+        }
+        AppCommand::UpdateLivePlayerPose { position, yaw } => {
+            handlers::editing::update_live_player_pose(state, position, yaw);
+            Ok(())
+        }
+        AppCommand::ToggleLiveCourseRecording => {
+            handlers::editing::toggle_live_course_recording(state);
+            Ok(())
+        }
         AppCommand::ImportCurseplay { path } => {
             handlers::editing::import_curseplay_file(state, &path);
             Ok(())
@@ -25,10 +25,51 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::file_io::set_heightmap(state, path);
             Ok(())
         }
+        AppCommand::ExtractHeightmapFromZip { path } => {
+            handlers::file_io::extract_heightmap_from_zip(state, &path)
+        }
         AppCommand::DeduplicateNodes => {
             handlers::file_io::deduplicate(state);
             Ok(())
         }
+        AppCommand::PushNetworkToGame => handlers::file_io::push_network_to_game(state),
+        AppCommand::OpenRoutesDialog => {
+            handlers::file_io::open_routes_dialog(state);
+            Ok(())
+        }
+        AppCommand::ImportStoredRoute { file_name } => {
+            handlers::file_io::import_stored_route(state, &file_name)
+        }
+        AppCommand::ExportStoredRoute { name } => {
+            handlers::file_io::export_stored_route(state, &name)
+        }
+        AppCommand::OpenSavegamePicker => {
+            handlers::file_io::open_savegame_picker(state);
+            Ok(())
+        }
+        AppCommand::RequestMapModZipDialog => {
+            handlers::file_io::request_map_mod_zip(state);
+            Ok(())
+        }
+        AppCommand::ExportIntoMapModZip { path } => {
+            handlers::file_io::export_into_map_mod_zip(state, &path)
+        }
+        AppCommand::RequestCoursesModExportDialog => {
+            handlers::file_io::request_courses_mod_export(state);
+            Ok(())
+        }
+        AppCommand::ExportCoursesMod { path } => {
+            handlers::file_io::export_courses_mod(state, &path)
+        }
+        AppCommand::OpenPdfExportDialog => {
+            handlers::file_io::open_pdf_export_dialog(state);
+            Ok(())
+        }
+        AppCommand::RequestPdfExportPath { options } => {
+            handlers::file_io::request_pdf_export_path(state, options);
+            Ok(())
+        }
+        AppCommand::ExportMapPdf { path } => handlers::file_io::export_map_pdf(state, &path),
         other => unreachable!("unerwarteter FileIo-Command: {other:?}"),
     }
 }
@@ -39,6 +39,22 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             );
             Ok(())
         }
+        AppCommand::SelectConnectedNodes {
+            world_pos,
+            max_distance,
+            additive,
+            follow_direction,
+        } => {
+            handlers::group::close_settings_popup(state);
+            handlers::selection::select_connected(
+                state,
+                world_pos,
+                max_distance,
+                additive,
+                follow_direction,
+            );
+            Ok(())
+        }
         AppCommand::SelectGroupByNearestNode {
             world_pos,
             max_distance,
@@ -59,6 +75,13 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::selection::move_selected(state, delta_world);
             Ok(())
         }
+        AppCommand::MoveSelectedNodesBy {
+            delta_world,
+            delta_height,
+        } => {
+            handlers::selection::move_selected_by(state, delta_world, delta_height);
+            Ok(())
+        }
         AppCommand::BeginMoveSelectedNodes => {
             handlers::selection::begin_move(state);
             Ok(())
@@ -75,6 +98,14 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::selection::rotate_selected(state, delta_angle);
             Ok(())
         }
+        AppCommand::TransformSelectedNodes {
+            pivot,
+            angle,
+            scale,
+        } => {
+            handlers::selection::transform_selected(state, pivot, angle, scale);
+            Ok(())
+        }
         AppCommand::EndRotateSelectedNodes => {
             handlers::selection::end_rotate(state);
             Ok(())
@@ -88,8 +119,28 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::selection::select_all(state);
             Ok(())
         }
-        AppCommand::InvertSelection => {
-            handlers::selection::invert(state);
+        AppCommand::InvertSelection { visible_only } => {
+            handlers::selection::invert(state, visible_only);
+            Ok(())
+        }
+        AppCommand::GrowSelection => {
+            handlers::selection::grow(state);
+            Ok(())
+        }
+        AppCommand::ShrinkSelection => {
+            handlers::selection::shrink(state);
+            Ok(())
+        }
+        AppCommand::SelectPathBetweenSelected => {
+            handlers::selection::select_path_between(state);
+            Ok(())
+        }
+        AppCommand::SelectNodesByConnectionPriority { priority } => {
+            handlers::selection::select_by_connection_priority(state, priority);
+            Ok(())
+        }
+        AppCommand::SetSelectionLocked { locked } => {
+            handlers::selection::set_locked(state, locked);
             Ok(())
         }
         other => unreachable!("unerwarteter Selection-Command: {other:?}"),
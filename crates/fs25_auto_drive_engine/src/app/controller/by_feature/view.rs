@@ -41,6 +41,10 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::view::set_render_quality(state, quality);
             Ok(())
         }
+        AppCommand::ToggleIsolateSelection => {
+            handlers::view::toggle_isolate_selection(state);
+            Ok(())
+        }
         AppCommand::LoadBackgroundMap { path, crop_size } => {
             handlers::view::load_background_map(state, path, crop_size)
         }
@@ -55,6 +59,9 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::view::scale_background(state, factor);
             Ok(())
         }
+        AppCommand::SetBackgroundSource { source } => {
+            handlers::view::set_background_source(state, source)
+        }
         AppCommand::BrowseZipBackground { path } => {
             handlers::view::browse_zip_background(state, path)
         }
@@ -77,6 +84,14 @@ pub(super) fn handle(state: &mut AppState, command: AppCommand) -> anyhow::Resul
             handlers::view::zoom_to_selection_bounds(state);
             Ok(())
         }
+        AppCommand::AddMeasurePoint { world_pos } => {
+            handlers::view::add_measure_point(state, world_pos);
+            Ok(())
+        }
+        AppCommand::ClearMeasurePoints => {
+            handlers::view::clear_measure_points(state);
+            Ok(())
+        }
         other => unreachable!("unerwarteter View-Command: {other:?}"),
     }
 }
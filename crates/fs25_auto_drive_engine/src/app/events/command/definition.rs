@@ -2,8 +2,9 @@ use crate::app::events::AppEventFeature;
 use crate::app::state::EditorTool;
 use crate::app::tool_contract::{RouteToolId, TangentSource};
 use crate::app::ui_contract::RouteToolPanelAction;
+use crate::app::use_cases::editing::{NodeAlignMode, NodeHeightEdit};
 use crate::core::{ConnectionDirection, ConnectionPriority, NodeFlag};
-use crate::shared::{BackgroundLayerKind, EditorOptions, RenderQuality};
+use crate::shared::{BackgroundLayerKind, BackgroundSource, EditorOptions, RenderQuality};
 
 /// Commands sind mutierende Schritte, die zentral ausgefuehrt werden.
 #[derive(Debug, Clone)]
@@ -14,6 +15,10 @@ pub enum AppCommand {
     AddNodeAtPosition { world_pos: glam::Vec2 },
     /// Selektierte Nodes loeschen
     DeleteSelectedNodes,
+    /// Nodes der Selektion loeschen, die den Filter erfuellen (ein Undo-Schritt)
+    DeleteNodesByFilter {
+        filter: crate::shared::NodeDeleteFilter,
+    },
     /// Connect-Tool: Node anwaehlen (Source oder Target)
     ConnectToolPickNode {
         world_pos: glam::Vec2,
@@ -34,14 +39,32 @@ pub enum AppCommand {
         end_id: u64,
         direction: ConnectionDirection,
     },
+    /// Richtung der Verbindung unter `from` per Wischgeste setzen
+    SwipeConnectionDirection {
+        from: glam::Vec2,
+        to: glam::Vec2,
+        toggle_dual: bool,
+    },
+    /// Verbindungen entlang der Linie schneiden (`split` = teilen statt entfernen)
+    CutConnectionsAlongLine {
+        from: glam::Vec2,
+        to: glam::Vec2,
+        split: bool,
+    },
     /// Prioritaet einer Verbindung aendern
     SetConnectionPriority {
         start_id: u64,
         end_id: u64,
         priority: ConnectionPriority,
     },
+    /// Einzelne Verbindung invertieren
+    InvertConnection { start_id: u64, end_id: u64 },
     /// Setzt das Flag eines Nodes
     SetNodeFlag { node_id: u64, flag: NodeFlag },
+    /// Setzt die Y-Werte aller selektierten Nodes
+    SetSelectedNodeHeights { edit: NodeHeightEdit },
+    /// Projiziert die selektierten Nodes auf das Gelaende der Heightmap
+    ProjectSelectionOntoTerrain { offset: f32 },
     /// Standard-Richtung fuer neue Verbindungen setzen
     SetDefaultDirection { direction: ConnectionDirection },
     /// Standard-Prioritaet fuer neue Verbindungen setzen
@@ -52,6 +75,10 @@ pub enum AppCommand {
     RemoveAllConnectionsBetweenSelected,
     /// Bulk: Richtung aller Verbindungen zwischen Selektion invertieren
     InvertAllConnectionsBetweenSelected,
+    /// Bulk: Selektierten Pfad umkehren (Einbahn-Verbindungen spiegeln)
+    ReversePathBetweenSelected,
+    /// Einzeln gezogenen Node beim Ablegen in eine nahe Verbindung einfuegen
+    InsertMovedNodeIntoConnection,
     /// Bulk: Prioritaet aller Verbindungen zwischen Selektion aendern
     SetAllConnectionsPriorityBetweenSelected { priority: ConnectionPriority },
     /// Zwei selektierte Nodes mit Standard-Einstellungen verbinden
@@ -102,6 +129,13 @@ pub enum AppCommand {
         stop_at_junction: bool,
         max_angle_deg: f32,
     },
+    /// Alle vom naechsten Node erreichbaren Nodes selektieren (Flood-Selektion)
+    SelectConnectedNodes {
+        world_pos: glam::Vec2,
+        max_distance: f32,
+        additive: bool,
+        follow_direction: bool,
+    },
     /// Alle Nodes einer Gruppe selektieren (identifiziert ueber Naehe zu world_pos)
     SelectGroupByNearestNode {
         world_pos: glam::Vec2,
@@ -121,20 +155,35 @@ pub enum AppCommand {
     },
     /// Selektierte Nodes um Delta verschieben
     MoveSelectedNodes { delta_world: glam::Vec2 },
+    /// Selektierte Nodes um exakten Versatz verschieben (ein Undo-Schritt, inkl. Hoehe)
+    MoveSelectedNodesBy {
+        delta_world: glam::Vec2,
+        delta_height: f32,
+    },
     /// Rotation-Lifecycle: Starten (Undo-Snapshot aufnehmen)
     BeginRotateSelectedNodes,
     /// Rotation-Lifecycle: Selektierte Nodes um Delta-Winkel (Radiant) rotieren
     RotateSelectedNodes { delta_angle: f32 },
+    /// Rotation-Lifecycle: Selektierte Nodes um `pivot` rotieren und skalieren (Deltas)
+    TransformSelectedNodes {
+        pivot: glam::Vec2,
+        angle: f32,
+        scale: f32,
+    },
     /// Rotation-Lifecycle: Beenden (Spatial-Index rebuild anstoßen)
     EndRotateSelectedNodes,
     /// Render-Qualitaet setzen
     SetRenderQuality { quality: RenderQuality },
+    /// Isolier-Modus umschalten
+    ToggleIsolateSelection,
     /// XML-Datei laden
     LoadFile { path: String },
     /// Datei speichern (None = aktueller Pfad, Some(p) = neuer Pfad)
     SaveFile { path: Option<String> },
     /// Heightmap setzen
     SetHeightmap { path: String },
+    /// Heightmap aus einem Map-Mod-ZIP extrahieren und setzen
+    ExtractHeightmapFromZip { path: String },
     /// Background-Map laden
     LoadBackgroundMap {
         path: String,
@@ -149,6 +198,8 @@ pub enum AppCommand {
     },
     /// Background-Ausdehnung skalieren (Faktor relativ)
     ScaleBackground { factor: f32 },
+    /// Hintergrundquelle setzen (Overview oder Heightmap-Relief)
+    SetBackgroundSource { source: BackgroundSource },
     /// Heightmap-Warnung schliessen
     DismissHeightmapWarning,
     /// Move-Lifecycle: Verschieben starten (Undo-Snapshot)
@@ -276,22 +327,70 @@ pub enum AppCommand {
     ResamplePath,
     /// Streckenteilung-Panel aktivieren
     StreckenteilungAktivieren,
+    /// Nodes der selektierten Kette auf gleiche Abstaende verteilen
+    EqualizeChainSpacing,
+    /// Selektierte Kette mit neuem Node-Abstand neu aufbauen
+    ResampleSelectedChain { spacing: f32 },
+    /// Innere Nodes der selektierten Kette glaetten
+    SmoothSelectedChain { strength: f32, iterations: u32 },
+    /// Selektierte Kette per Douglas-Peucker ausduennen
+    SimplifySelectedChain { tolerance: f32 },
+    /// Selektierte Nodes auf einer Geraden ausrichten
+    AlignSelectedNodes { mode: NodeAlignMode },
+    /// Selektierte Nodes entlang der Endpunkt-Achse gleichmaessig verteilen
+    DistributeSelectedNodes,
+    /// Zwei selektierte Ketten per Spline-Uebergang verbinden
+    JoinSelectedChains,
+    /// Selektierte Nodes im Schwerpunkt zusammenfuehren
+    MergeSelectedNodes,
+    /// Alle Verbindungen der selektierten Kette umkehren
+    ReverseSelectedChain,
+    /// Versatz-Vorschau fuer die selektierte Kette starten
+    StartPathOffsetPreview,
+    /// Seitlichen Versatz setzen und Vorschau aktualisieren
+    SetPathOffsetDistance { distance: f32 },
+    /// Erfasste Kette seitlich verschieben
+    ApplyPathOffset,
+    /// Versatz-Vorschau verwerfen
+    CancelPathOffsetPreview,
+    /// Selektierten Kreuzungs-Node in einen Kreisverkehr mit Radius (Meter) umwandeln
+    ConvertJunctionToRoundabout { radius: f32 },
     /// Alles in den Viewport einpassen (Zoom-to-fit)
     ZoomToFit,
     /// Kamera auf die Bounding Box der Selektion zoomen
     ZoomToSelectionBounds,
-    /// Auswahl invertieren
-    InvertSelection,
+    /// Messpunkt an die Messlinie des Mess-Werkzeugs anhaengen
+    AddMeasurePoint { world_pos: glam::Vec2 },
+    /// Messlinie des Mess-Werkzeugs leeren
+    ClearMeasurePoints,
+    /// Auswahl invertieren (optional nur innerhalb der sichtbaren Nodes)
+    InvertSelection { visible_only: bool },
+    /// Selektion um alle direkten Nachbarn erweitern
+    GrowSelection,
+    /// Selektion um ihre Randnodes verkleinern
+    ShrinkSelection,
+    /// Kuerzesten Pfad zwischen den zwei selektierten Nodes hinzufuegen
+    SelectPathBetweenSelected,
+    /// Alle Nodes an Verbindungen der gegebenen Prioritaet selektieren
+    SelectNodesByConnectionPriority { priority: ConnectionPriority },
+    /// Selektierte Nodes sperren bzw. entsperren
+    SetSelectionLocked { locked: bool },
     /// Selektion in die Zwischenablage kopieren
     CopySelection,
     /// Einfuegen-Vorschau starten
     StartPastePreview,
+    /// Zwischenablage aus Text (System-Zwischenablage) lesen und Einfuegen-Vorschau starten
+    PasteFromText { text: String },
     /// Einfuegen-Vorschau: Position aktualisieren
     UpdatePastePreview { world_pos: glam::Vec2 },
     /// Einfuegen an aktueller Vorschauposition bestaetigen
     ConfirmPaste,
     /// Einfuegen-Vorschau abbrechen
     CancelPastePreview,
+    /// Einfuegen-Vorschau um `delta_rad` (Radiant) drehen
+    RotatePastePreview { delta_rad: f32 },
+    /// Selektion duplizieren und Platzierungs-Vorschau starten
+    DuplicateSelection,
     /// Segment-Lock umschalten (gesperrt ↔ entsperrt)
     ToggleGroupLock { segment_id: u64 },
     /// Segment aufloesen (Segment-Record entfernen, Nodes beibehalten)
@@ -321,6 +420,60 @@ pub enum AppCommand {
         corner_rounding_radius: Option<f32>,
         corner_rounding_max_angle_deg: Option<f32>,
     },
+    /// Einstellungsdialog "Feld-Rundkurs erzeugen" oeffnen
+    OpenFieldCourseDialog,
+    /// Einstellungsdialog "Feld-Rundkurs erzeugen" schliessen (Abbruch)
+    CloseFieldCourseDialog,
+    /// Dialog "Selektion verschieben um..." oeffnen
+    OpenMoveSelectionDialog,
+    /// Dialog "Selektion verschieben um..." schliessen (Abbruch)
+    CloseMoveSelectionDialog,
+    /// Dialog "Loeschen nach Filter..." fuer die aktuelle Selektion oeffnen
+    OpenDeleteByFilterDialog,
+    /// Dialog "Loeschen nach Filter..." schliessen (Abbruch)
+    CloseDeleteByFilterDialog,
+    /// Vorgewende-Ring mit Zufahrt fuer ein einzelnes Feld erzeugen
+    GenerateFieldCourse {
+        field_id: u32,
+        spacing: f32,
+        headland_offset: f32,
+    },
+    /// Gemeldete Spielerposition uebernehmen (zeichnet bei aktiver Aufzeichnung auf)
+    UpdateLivePlayerPose { position: glam::Vec2, yaw: f32 },
+    /// Live-Aufzeichnung waehrend der Fahrt starten bzw. beenden
+    ToggleLiveCourseRecording,
+    /// Netz speichern und dem Spiel zum Neuladen melden
+    PushNetworkToGame,
+    /// Routen-Dialog mit den Routen des AutoDrive-Routen-Managers oeffnen
+    OpenRoutesDialog,
+    /// Routen-Dialog schliessen (Abbruch)
+    CloseRoutesDialog,
+    /// Route aus dem AutoDrive-Routen-Manager als aktuelles Netz importieren
+    ImportStoredRoute { file_name: String },
+    /// Aktuelles Netz als Route in den AutoDrive-Routen-Manager exportieren
+    ExportStoredRoute { name: String },
+    /// Auswahldialog fuer das Map-Mod-ZIP des Standard-Config-Exports anfordern
+    RequestMapModZipDialog,
+    /// Netz als Standard-Config in das Map-Mod-ZIP schreiben
+    ExportIntoMapModZip { path: String },
+    /// Speicherdialog fuer den Kurs-Mod anfordern
+    RequestCoursesModExportDialog,
+    /// Netz als eigenstaendigen Kurs-Mod-ZIP schreiben
+    ExportCoursesMod { path: String },
+    /// Dialog "Als PDF exportieren..." oeffnen
+    OpenPdfExportDialog,
+    /// Dialog "Als PDF exportieren..." schliessen (Abbruch)
+    ClosePdfExportDialog,
+    /// PDF-Einstellungen uebernehmen und Speicherdialog anfordern
+    RequestPdfExportPath {
+        options: crate::shared::PdfExportOptions,
+    },
+    /// Karte als mehrseitiges PDF schreiben
+    ExportMapPdf { path: String },
+    /// Savegame-Auswahl mit den Savegames des FS25-Profils oeffnen
+    OpenSavegamePicker,
+    /// Savegame-Auswahl schliessen
+    CloseSavegamePicker,
     /// Curseplay-Import-Dateidialog anfordern
     RequestCurseplayImportDialog,
     /// Curseplay-Datei importieren (Nodes + Ring-Verbindungen anlegen)
@@ -344,7 +497,20 @@ impl AppCommand {
             | Self::SaveFile { .. }
             | Self::ClearHeightmap
             | Self::SetHeightmap { .. }
-            | Self::DeduplicateNodes => AppEventFeature::FileIo,
+            | Self::ExtractHeightmapFromZip { .. }
+            | Self::DeduplicateNodes
+            | Self::PushNetworkToGame
+            | Self::OpenRoutesDialog
+            | Self::ImportStoredRoute { .. }
+            | Self::ExportStoredRoute { .. }
+            | Self::RequestMapModZipDialog
+            | Self::ExportIntoMapModZip { .. }
+            | Self::RequestCoursesModExportDialog
+            | Self::ExportCoursesMod { .. }
+            | Self::OpenPdfExportDialog
+            | Self::RequestPdfExportPath { .. }
+            | Self::ExportMapPdf { .. }
+            | Self::OpenSavegamePicker => AppEventFeature::FileIo,
             Self::ResetCamera
             | Self::ZoomIn
             | Self::ZoomOut
@@ -353,44 +519,63 @@ impl AppCommand {
             | Self::ZoomCamera { .. }
             | Self::CenterOnNode { .. }
             | Self::SetRenderQuality { .. }
+            | Self::ToggleIsolateSelection
             | Self::LoadBackgroundMap { .. }
             | Self::ToggleBackgroundVisibility
             | Self::SetBackgroundLayerVisibility { .. }
             | Self::ScaleBackground { .. }
+            | Self::SetBackgroundSource { .. }
             | Self::BrowseZipBackground { .. }
             | Self::LoadBackgroundFromZip { .. }
             | Self::GenerateOverviewWithOptions
             | Self::SaveBackgroundAsOverview { .. }
             | Self::ZoomToFit
-            | Self::ZoomToSelectionBounds => AppEventFeature::View,
+            | Self::ZoomToSelectionBounds
+            | Self::AddMeasurePoint { .. }
+            | Self::ClearMeasurePoints => AppEventFeature::View,
             Self::SelectNearestNode { .. }
             | Self::SelectSegmentBetweenNearestIntersections { .. }
+            | Self::SelectConnectedNodes { .. }
             | Self::SelectGroupByNearestNode { .. }
             | Self::SelectNodesInRect { .. }
             | Self::SelectNodesInLasso { .. }
             | Self::MoveSelectedNodes { .. }
+            | Self::MoveSelectedNodesBy { .. }
             | Self::BeginMoveSelectedNodes
             | Self::EndMoveSelectedNodes
             | Self::BeginRotateSelectedNodes
             | Self::RotateSelectedNodes { .. }
+            | Self::TransformSelectedNodes { .. }
             | Self::EndRotateSelectedNodes
             | Self::ClearSelection
             | Self::SelectAllNodes
-            | Self::InvertSelection => AppEventFeature::Selection,
+            | Self::InvertSelection { .. }
+            | Self::GrowSelection
+            | Self::ShrinkSelection
+            | Self::SelectPathBetweenSelected
+            | Self::SelectNodesByConnectionPriority { .. }
+            | Self::SetSelectionLocked { .. } => AppEventFeature::Selection,
             Self::SetEditorTool { .. }
             | Self::AddNodeAtPosition { .. }
             | Self::DeleteSelectedNodes
+            | Self::DeleteNodesByFilter { .. }
             | Self::ConnectToolPickNode { .. }
             | Self::AddConnection { .. }
             | Self::RemoveConnectionBetween { .. }
             | Self::SetConnectionDirection { .. }
+            | Self::SwipeConnectionDirection { .. }
             | Self::SetConnectionPriority { .. }
+            | Self::InvertConnection { .. }
             | Self::SetNodeFlag { .. }
+            | Self::SetSelectedNodeHeights { .. }
+            | Self::ProjectSelectionOntoTerrain { .. }
             | Self::SetDefaultDirection { .. }
             | Self::SetDefaultPriority { .. }
             | Self::SetAllConnectionsDirectionBetweenSelected { .. }
             | Self::RemoveAllConnectionsBetweenSelected
             | Self::InvertAllConnectionsBetweenSelected
+            | Self::ReversePathBetweenSelected
+            | Self::InsertMovedNodeIntoConnection
             | Self::SetAllConnectionsPriorityBetweenSelected { .. }
             | Self::ConnectSelectedNodes
             | Self::CreateMarker { .. }
@@ -399,12 +584,33 @@ impl AppCommand {
             | Self::UpdateMarker { .. }
             | Self::ResamplePath
             | Self::StreckenteilungAktivieren
+            | Self::EqualizeChainSpacing
+            | Self::ResampleSelectedChain { .. }
+            | Self::SmoothSelectedChain { .. }
+            | Self::SimplifySelectedChain { .. }
+            | Self::AlignSelectedNodes { .. }
+            | Self::DistributeSelectedNodes
+            | Self::JoinSelectedChains
+            | Self::MergeSelectedNodes
+            | Self::ReverseSelectedChain
+            | Self::StartPathOffsetPreview
+            | Self::SetPathOffsetDistance { .. }
+            | Self::ApplyPathOffset
+            | Self::CancelPathOffsetPreview
+            | Self::ConvertJunctionToRoundabout { .. }
+            | Self::CutConnectionsAlongLine { .. }
             | Self::CopySelection
             | Self::StartPastePreview
+            | Self::PasteFromText { .. }
             | Self::UpdatePastePreview { .. }
             | Self::ConfirmPaste
             | Self::CancelPastePreview
+            | Self::RotatePastePreview { .. }
+            | Self::DuplicateSelection
             | Self::TraceAllFields { .. }
+            | Self::GenerateFieldCourse { .. }
+            | Self::UpdateLivePlayerPose { .. }
+            | Self::ToggleLiveCourseRecording
             | Self::ImportCurseplay { .. }
             | Self::ExportCurseplay { .. } => AppEventFeature::Editing,
             Self::RouteToolClick { .. }
@@ -456,8 +662,17 @@ impl AppCommand {
             | Self::DismissSaveOverviewDialog
             | Self::OpenTraceAllFieldsDialog
             | Self::CloseTraceAllFieldsDialog
+            | Self::OpenFieldCourseDialog
+            | Self::CloseFieldCourseDialog
+            | Self::OpenMoveSelectionDialog
+            | Self::CloseMoveSelectionDialog
+            | Self::OpenDeleteByFilterDialog
+            | Self::CloseDeleteByFilterDialog
+            | Self::ClosePdfExportDialog
             | Self::RequestCurseplayImportDialog
-            | Self::RequestCurseplayExportDialog => AppEventFeature::Dialog,
+            | Self::RequestCurseplayExportDialog
+            | Self::CloseRoutesDialog
+            | Self::CloseSavegamePicker => AppEventFeature::Dialog,
             Self::Undo | Self::Redo => AppEventFeature::History,
         }
     }
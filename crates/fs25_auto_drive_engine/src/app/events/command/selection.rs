@@ -42,6 +42,12 @@ macro_rules! selection_command_variants {
         BeginRotateSelectedNodes,
         /// Rotation-Lifecycle: Selektierte Nodes um Delta-Winkel (Radiant) rotieren
         RotateSelectedNodes { delta_angle: f32 },
+        /// Rotation-Lifecycle: Selektierte Nodes um `pivot` rotieren und skalieren (Deltas)
+        TransformSelectedNodes {
+            pivot: glam::Vec2,
+            angle: f32,
+            scale: f32,
+        },
         /// Rotation-Lifecycle: Beenden (Spatial-Index rebuild anstoßen)
         EndRotateSelectedNodes,
         /// Selektion aufheben
@@ -67,6 +73,7 @@ macro_rules! selection_command_feature_arms {
         | Self::EndMoveSelectedNodes
         | Self::BeginRotateSelectedNodes
         | Self::RotateSelectedNodes { .. }
+        | Self::TransformSelectedNodes { .. }
         | Self::EndRotateSelectedNodes
         | Self::ClearSelection
         | Self::SelectAllNodes
@@ -2,8 +2,9 @@ use crate::app::events::AppEventFeature;
 use crate::app::state::EditorTool;
 use crate::app::tool_contract::{RouteToolId, TangentSource};
 use crate::app::ui_contract::RouteToolPanelAction;
+use crate::app::use_cases::editing::{NodeAlignMode, NodeHeightEdit};
 use crate::core::{ConnectionDirection, ConnectionPriority, NodeFlag};
-use crate::shared::{BackgroundLayerKind, EditorOptions, RenderQuality};
+use crate::shared::{BackgroundLayerKind, BackgroundSource, EditorOptions, RenderQuality};
 
 /// App-Intent und App-Command Events.
 /// Intents sind Eingaben aus UI/System ohne direkte Mutationslogik.
@@ -55,6 +56,11 @@ pub enum AppIntent {
         world_pos: glam::Vec2,
         additive: bool,
     },
+    /// Alle vom getroffenen Node erreichbaren Nodes selektieren (Shift + Doppelklick)
+    SelectConnectedRequested {
+        world_pos: glam::Vec2,
+        additive: bool,
+    },
     /// Nodes innerhalb eines Rechtecks selektieren (Shift + Drag)
     SelectNodesInRectRequested {
         min: glam::Vec2,
@@ -76,16 +82,27 @@ pub enum AppIntent {
     BeginRotateSelectedNodesRequested,
     /// Rotation-Lifecycle Update: Selektierte Nodes um Delta-Winkel (Radiant) rotieren
     RotateSelectedNodesRequested { delta_angle: f32 },
+    /// Rotation-Lifecycle Update (Transform-Gizmo): Selektion um `pivot` rotieren
+    /// (`angle`, Radiant) und skalieren (`scale`); beides Deltas zum letzten Schritt
+    TransformSelectionRequested {
+        pivot: glam::Vec2,
+        angle: f32,
+        scale: f32,
+    },
     /// Rotation-Lifecycle Ende: Spatial-Index rebuild ausloesen
     EndRotateSelectedNodesRequested,
     /// Render-Qualitaetsstufe aendern
     RenderQualityChanged { quality: RenderQuality },
+    /// Isolier-Modus umschalten (alle Nodes ausser der Selektion ausblenden)
+    ToggleIsolateSelectionRequested,
     /// Datei wurde im Dialog ausgewaehlt (Laden)
     FileSelected { path: String },
     /// Speicherpfad wurde im Dialog ausgewaehlt
     SaveFilePathSelected { path: String },
     /// Heightmap-Datei wurde im Dialog ausgewaehlt
     HeightmapSelected { path: String },
+    /// Heightmap aus einem Map-Mod-ZIP extrahieren, neben der Config ablegen und verwenden
+    ExtractHeightmapFromZipRequested { path: String },
     /// Background-Map auswaehlen
     BackgroundMapSelected {
         path: String,
@@ -100,6 +117,8 @@ pub enum AppIntent {
     },
     /// Background-Ausdehnung skalieren (Faktor relativ, z.B. 2.0 = verdoppeln)
     ScaleBackground { factor: f32 },
+    /// Hintergrundquelle waehlen (Overview oder Heightmap-Relief)
+    BackgroundSourceSelected { source: BackgroundSource },
     /// Undo: Letzte Aktion rueckgaengig machen
     UndoRequested,
     /// Redo: Rueckgaengig gemachte Aktion wiederherstellen
@@ -127,14 +146,28 @@ pub enum AppIntent {
         end_id: u64,
         direction: ConnectionDirection,
     },
+    /// Richtung der Verbindung unter `from` per Wischgeste setzen (Drag `from`→`to`)
+    ConnectionSwipeRequested {
+        from: glam::Vec2,
+        to: glam::Vec2,
+        toggle_dual: bool,
+    },
+    /// Messer-Werkzeug: Verbindungen entlang der Linie `from`→`to` schneiden
+    KnifeCutRequested { from: glam::Vec2, to: glam::Vec2 },
     /// Prioritaet einer Verbindung aendern
     SetConnectionPriorityRequested {
         start_id: u64,
         end_id: u64,
         priority: ConnectionPriority,
     },
+    /// Einzelne Verbindung invertieren (start ⇔ end)
+    InvertConnectionRequested { start_id: u64, end_id: u64 },
     /// Node-Flag aendern (Regular, SubPrio, etc.)
     NodeFlagChangeRequested { node_id: u64, flag: NodeFlag },
+    /// Y-Werte aller selektierten Nodes setzen, verschieben oder interpolieren
+    SetSelectedNodeHeightsRequested { edit: NodeHeightEdit },
+    /// Y-Werte aller selektierten Nodes aus der Heightmap neu berechnen (plus Versatz in Metern)
+    ProjectSelectionOntoTerrainRequested { offset: f32 },
     /// Standard-Richtung fuer neue Verbindungen aendern
     SetDefaultDirectionRequested { direction: ConnectionDirection },
     /// Standard-Strassenart fuer neue Verbindungen aendern
@@ -145,6 +178,8 @@ pub enum AppIntent {
     RemoveAllConnectionsBetweenSelectedRequested,
     /// Richtung aller Verbindungen zwischen selektierten Nodes invertieren (start↔end tauschen)
     InvertAllConnectionsBetweenSelectedRequested,
+    /// Selektierten Pfad umkehren (Einbahn-Verbindungen spiegeln, Reverse/Dual erhalten)
+    ReversePathBetweenSelectedRequested,
     /// Prioritaet aller Verbindungen zwischen selektierten Nodes aendern
     SetAllConnectionsPriorityBetweenSelectedRequested { priority: ConnectionPriority },
     /// Zwei selektierte Nodes verbinden (mit Standard-Richtung/Prioritaet)
@@ -258,12 +293,54 @@ pub enum AppIntent {
     ResamplePathRequested,
     /// Streckenteilung-Panel aktivieren (z.B. per Kontextmenue)
     StreckenteilungAktivieren,
+    /// Nodes der selektierten Kette auf gleiche Abstaende verteilen (Form bleibt erhalten)
+    EqualizeChainSpacingRequested,
+    /// Selektierte Kette mit neuem Node-Abstand neu aufbauen (Endpunkte bleiben angeschlossen)
+    ResampleChainRequested { spacing: f32 },
+    /// Innere Nodes der selektierten Kette glaetten (Staerke 0..1, Anzahl Durchgaenge)
+    SmoothChainRequested { strength: f32, iterations: u32 },
+    /// Selektierte Kette per Douglas-Peucker ausduennen (Toleranz in Metern)
+    SimplifyChainRequested { tolerance: f32 },
+    /// Selektierte Nodes auf einer Geraden ausrichten (X-/Z-Linie, Ausgleichsgerade, Endpunkte)
+    AlignSelectedRequested { mode: NodeAlignMode },
+    /// Selektierte Nodes entlang der Endpunkt-Achse auf gleiche Abstaende verteilen
+    DistributeSelectedRequested,
+    /// Zwei selektierte Ketten per Spline-Uebergang zwischen den naechsten Enden verbinden
+    JoinSelectedChainsRequested,
+    /// Selektierte Nodes zu einem Node im Schwerpunkt zusammenfuehren
+    MergeSelectedNodesRequested,
+    /// Laufrichtung der selektierten Kette umkehren (Start wird Ende)
+    ReverseChainRequested,
+    /// Vorschau fuer seitlichen Versatz der selektierten Kette starten
+    PathOffsetPreviewRequested,
+    /// Seitlichen Versatz (Meter, positiv = links) aendern
+    PathOffsetDistanceChanged { distance: f32 },
+    /// Seitlichen Versatz auf die Kette anwenden
+    PathOffsetConfirmed,
+    /// Versatz-Vorschau verwerfen
+    PathOffsetCancelled,
+    /// Selektierten Kreuzungs-Node in einen Kreisverkehr umwandeln
+    ConvertJunctionToRoundaboutRequested,
     /// Alles in den Viewport einpassen (Zoom-to-fit)
     ZoomToFitRequested,
     /// Viewport auf die Grenzen der aktuellen Selektion einpassen
     ZoomToSelectionBoundsRequested,
+    /// Mess-Werkzeug: Messpunkt an der Weltposition anhaengen
+    MeasurePointAdded { world_pos: glam::Vec2 },
+    /// Mess-Werkzeug: Messlinie verwerfen (Esc)
+    MeasureClearRequested,
     /// Auswahl invertieren (selektierte abwaehlen, nicht-selektierte waehlen)
     InvertSelectionRequested,
+    /// Selektion um alle direkten Nachbarn erweitern (Ctrl+Plus)
+    GrowSelectionRequested,
+    /// Selektion um ihre Randnodes verkleinern (Ctrl+Minus)
+    ShrinkSelectionRequested,
+    /// Kuerzesten Pfad zwischen den zwei selektierten Nodes zur Selektion hinzufuegen
+    SelectPathBetweenSelectedRequested,
+    /// Alle Nodes selektieren, die an Verbindungen der gegebenen Prioritaet haengen
+    SelectByConnectionPriorityRequested { priority: ConnectionPriority },
+    /// Selektierte Nodes sperren (`true`) oder entsperren (`false`)
+    SetSelectionLockedRequested { locked: bool },
     /// Route-Tool: Strecke neu berechnen mit aktuellem Config (nach Parameter-Aenderung)
     RouteToolRecreateRequested,
     /// Route-Tool: Node-Anzahl erhoehen (Pfeiltaste oben)
@@ -278,12 +355,18 @@ pub enum AppIntent {
     CopySelectionRequested,
     /// Einfuegen-Vorschau starten (Clipboard → Vorschau auf Karte)
     PasteStartRequested,
+    /// Einfuegen aus der System-Zwischenablage (Editor-JSON, sonst interne Zwischenablage)
+    PasteFromTextRequested { text: String },
     /// Einfuegen-Vorschau: Mauszeiger hat sich bewegt → Vorschau aktualisieren
     PastePreviewMoved { world_pos: glam::Vec2 },
     /// Einfuegen an aktueller Vorschauposition bestaetigen
     PasteConfirmRequested,
     /// Einfuegen-Vorschau abbrechen (Escape)
     PasteCancelled,
+    /// Einfuegen-Vorschau um einen Schritt drehen (R, mit Shift gegen den Uhrzeigersinn)
+    PastePreviewRotateRequested { counter_clockwise: bool },
+    /// Selektion duplizieren und die Kopie interaktiv platzieren
+    DuplicateSelectionRequested,
     /// Segment-Lock umschalten (gesperrt ↔ entsperrt)
     ToggleGroupLockRequested { segment_id: u64 },
     /// Segment aufloesen (Segment-Record entfernen, Nodes beibehalten)
@@ -313,6 +396,71 @@ pub enum AppIntent {
     },
     /// Alle-Felder-nachzeichnen-Dialog abgebrochen
     TraceAllFieldsCancelled,
+    /// Feld-Rundkurs-Einstellungsdialog oeffnen
+    OpenFieldCourseDialogRequested,
+    /// Feld-Rundkurs bestaetigt (nach Dialog-Eingabe)
+    FieldCourseConfirmed {
+        field_id: u32,
+        spacing: f32,
+        headland_offset: f32,
+    },
+    /// Feld-Rundkurs-Dialog abgebrochen
+    FieldCourseCancelled,
+    /// Dialog "Selektion verschieben um..." oeffnen
+    OpenMoveSelectionDialogRequested,
+    /// Selektion um exakten Versatz verschieben (X/Z = Kartenebene, Y = Hoehe)
+    MoveSelectionConfirmed { dx: f32, dy: f32, dz: f32 },
+    /// Selektion ohne Dialog um einen exakten Versatz in der Kartenebene verschieben
+    /// (Properties-Panel, Shift+Pfeiltasten)
+    NudgeSelectionRequested { delta: glam::Vec2 },
+    /// Dialog "Selektion verschieben um..." abgebrochen
+    MoveSelectionCancelled,
+    /// Dialog "Loeschen nach Filter..." fuer die aktuelle Selektion oeffnen
+    OpenDeleteByFilterDialogRequested,
+    /// Nodes der Selektion loeschen, die den Filter erfuellen
+    DeleteByFilterConfirmed {
+        filter: crate::shared::NodeDeleteFilter,
+    },
+    /// Dialog "Loeschen nach Filter..." abgebrochen
+    DeleteByFilterCancelled,
+    /// Live-Verbindung: Companion-Mod hat die aktuelle Spielerposition gemeldet
+    LivePlayerPoseReceived { position: glam::Vec2, yaw: f32 },
+    /// Live-Verbindung: "Kurs aufzeichnen waehrend der Fahrt" umschalten
+    LiveCourseRecordingToggled,
+    /// Live-Verbindung: bearbeitetes Netz speichern und im Spiel neu laden lassen
+    LiveNetworkPushRequested,
+    /// Dialog fuer den AutoDrive-Routen-Manager oeffnen
+    RoutesDialogRequested,
+    /// Route aus dem AutoDrive-Routen-Manager als aktuelles Netz importieren
+    StoredRouteImportRequested { file_name: String },
+    /// Aktuelles Netz unter `name` in den AutoDrive-Routen-Manager exportieren
+    StoredRouteExportRequested { name: String },
+    /// Routen-Dialog abgebrochen
+    RoutesDialogCancelled,
+    /// Netz als Standard-Config in ein Map-Mod-ZIP exportieren (ZIP-Auswahl anfordern)
+    MapModZipExportRequested,
+    /// Map-Mod-ZIP fuer den Standard-Config-Export wurde im Dialog ausgewaehlt
+    MapModZipSelected { path: String },
+    /// Netz als eigenstaendigen Kurs-Mod exportieren (Speicherdialog anfordern)
+    CoursesModExportRequested,
+    /// Zielpfad des Kurs-Mods wurde im Dialog ausgewaehlt
+    CoursesModExportPathSelected { path: String },
+    /// Dialog "Als PDF exportieren..." oeffnen
+    OpenPdfExportDialogRequested,
+    /// PDF-Einstellungen bestaetigt (Speicherdialog anfordern)
+    PdfExportConfirmed {
+        options: crate::shared::PdfExportOptions,
+    },
+    /// Dialog "Als PDF exportieren..." abgebrochen
+    PdfExportCancelled,
+    /// Zielpfad des PDF-Exports wurde im Dialog ausgewaehlt
+    PdfExportPathSelected { path: String },
+    /// Savegame-Auswahl im FS25-Profil oeffnen
+    SavegamePickerRequested,
+    /// AutoDrive-Config des gewaehlten Savegames oeffnen
+    SavegameOpenRequested { path: String },
+    /// Savegame-Auswahl abgebrochen
+    SavegamePickerCancelled,
     /// Curseplay-Import-Dialog anfordern
     CurseplayImportRequested,
     /// Curseplay-Export-Dialog anfordern
@@ -337,8 +485,25 @@ impl AppIntent {
             | Self::FileSelected { .. }
             | Self::SaveFilePathSelected { .. }
             | Self::HeightmapSelected { .. }
+            | Self::ExtractHeightmapFromZipRequested { .. }
             | Self::DeduplicateConfirmed
-            | Self::DeduplicateCancelled => AppEventFeature::FileIo,
+            | Self::DeduplicateCancelled
+            | Self::LiveNetworkPushRequested
+            | Self::RoutesDialogRequested
+            | Self::StoredRouteImportRequested { .. }
+            | Self::StoredRouteExportRequested { .. }
+            | Self::RoutesDialogCancelled
+            | Self::MapModZipExportRequested
+            | Self::MapModZipSelected { .. }
+            | Self::CoursesModExportRequested
+            | Self::CoursesModExportPathSelected { .. }
+            | Self::OpenPdfExportDialogRequested
+            | Self::PdfExportConfirmed { .. }
+            | Self::PdfExportCancelled
+            | Self::PdfExportPathSelected { .. }
+            | Self::SavegamePickerRequested
+            | Self::SavegameOpenRequested { .. }
+            | Self::SavegamePickerCancelled => AppEventFeature::FileIo,
             Self::ResetCameraRequested
             | Self::ZoomInRequested
             | Self::ZoomOutRequested
@@ -347,11 +512,13 @@ impl AppIntent {
             | Self::CameraZoom { .. }
             | Self::CenterOnNodeRequested { .. }
             | Self::RenderQualityChanged { .. }
+            | Self::ToggleIsolateSelectionRequested
             | Self::BackgroundMapSelectionRequested
             | Self::BackgroundMapSelected { .. }
             | Self::ToggleBackgroundVisibility
             | Self::SetBackgroundLayerVisibility { .. }
             | Self::ScaleBackground { .. }
+            | Self::BackgroundSourceSelected { .. }
             | Self::ZipBackgroundBrowseRequested { .. }
             | Self::ZipBackgroundFileSelected { .. }
             | Self::ZipBrowserCancelled
@@ -364,9 +531,12 @@ impl AppIntent {
             | Self::SaveBackgroundAsOverviewConfirmed
             | Self::SaveBackgroundAsOverviewDismissed
             | Self::ZoomToFitRequested
-            | Self::ZoomToSelectionBoundsRequested => AppEventFeature::View,
+            | Self::ZoomToSelectionBoundsRequested
+            | Self::MeasurePointAdded { .. }
+            | Self::MeasureClearRequested => AppEventFeature::View,
             Self::NodePickRequested { .. }
             | Self::NodeSegmentBetweenIntersectionsRequested { .. }
+            | Self::SelectConnectedRequested { .. }
             | Self::SelectNodesInRectRequested { .. }
             | Self::SelectNodesInLassoRequested { .. }
             | Self::BeginMoveSelectedNodesRequested
@@ -374,10 +544,16 @@ impl AppIntent {
             | Self::EndMoveSelectedNodesRequested
             | Self::BeginRotateSelectedNodesRequested
             | Self::RotateSelectedNodesRequested { .. }
+            | Self::TransformSelectionRequested { .. }
             | Self::EndRotateSelectedNodesRequested
             | Self::ClearSelectionRequested
             | Self::SelectAllRequested
-            | Self::InvertSelectionRequested => AppEventFeature::Selection,
+            | Self::InvertSelectionRequested
+            | Self::GrowSelectionRequested
+            | Self::ShrinkSelectionRequested
+            | Self::SelectPathBetweenSelectedRequested
+            | Self::SelectByConnectionPriorityRequested { .. }
+            | Self::SetSelectionLockedRequested { .. } => AppEventFeature::Selection,
             Self::SetEditorToolRequested { .. }
             | Self::AddNodeRequested { .. }
             | Self::DeleteSelectedRequested
@@ -385,13 +561,18 @@ impl AppIntent {
             | Self::AddConnectionRequested { .. }
             | Self::RemoveConnectionBetweenRequested { .. }
             | Self::SetConnectionDirectionRequested { .. }
+            | Self::ConnectionSwipeRequested { .. }
             | Self::SetConnectionPriorityRequested { .. }
+            | Self::InvertConnectionRequested { .. }
             | Self::NodeFlagChangeRequested { .. }
+            | Self::SetSelectedNodeHeightsRequested { .. }
+            | Self::ProjectSelectionOntoTerrainRequested { .. }
             | Self::SetDefaultDirectionRequested { .. }
             | Self::SetDefaultPriorityRequested { .. }
             | Self::SetAllConnectionsDirectionBetweenSelectedRequested { .. }
             | Self::RemoveAllConnectionsBetweenSelectedRequested
             | Self::InvertAllConnectionsBetweenSelectedRequested
+            | Self::ReversePathBetweenSelectedRequested
             | Self::SetAllConnectionsPriorityBetweenSelectedRequested { .. }
             | Self::ConnectSelectedNodesRequested
             | Self::CreateMarkerRequested { .. }
@@ -401,14 +582,44 @@ impl AppIntent {
             | Self::MarkerDialogCancelled
             | Self::ResamplePathRequested
             | Self::StreckenteilungAktivieren
+            | Self::EqualizeChainSpacingRequested
+            | Self::ResampleChainRequested { .. }
+            | Self::SmoothChainRequested { .. }
+            | Self::SimplifyChainRequested { .. }
+            | Self::AlignSelectedRequested { .. }
+            | Self::DistributeSelectedRequested
+            | Self::JoinSelectedChainsRequested
+            | Self::MergeSelectedNodesRequested
+            | Self::ReverseChainRequested
+            | Self::PathOffsetPreviewRequested
+            | Self::PathOffsetDistanceChanged { .. }
+            | Self::PathOffsetConfirmed
+            | Self::PathOffsetCancelled
+            | Self::ConvertJunctionToRoundaboutRequested
+            | Self::KnifeCutRequested { .. }
             | Self::CopySelectionRequested
             | Self::PasteStartRequested
+            | Self::PasteFromTextRequested { .. }
             | Self::PastePreviewMoved { .. }
             | Self::PasteConfirmRequested
             | Self::PasteCancelled
+            | Self::PastePreviewRotateRequested { .. }
+            | Self::DuplicateSelectionRequested
             | Self::OpenTraceAllFieldsDialogRequested
             | Self::TraceAllFieldsConfirmed { .. }
             | Self::TraceAllFieldsCancelled
+            | Self::OpenFieldCourseDialogRequested
+            | Self::FieldCourseConfirmed { .. }
+            | Self::FieldCourseCancelled
+            | Self::OpenMoveSelectionDialogRequested
+            | Self::MoveSelectionConfirmed { .. }
+            | Self::NudgeSelectionRequested { .. }
+            | Self::MoveSelectionCancelled
+            | Self::OpenDeleteByFilterDialogRequested
+            | Self::DeleteByFilterConfirmed { .. }
+            | Self::DeleteByFilterCancelled
+            | Self::LivePlayerPoseReceived { .. }
+            | Self::LiveCourseRecordingToggled
             | Self::CurseplayImportRequested
             | Self::CurseplayExportRequested
             | Self::CurseplayFileSelected { .. }
@@ -466,6 +677,7 @@ impl AppIntent {
                 | Self::HeightmapWarningCancelled
                 | Self::OverviewZipBrowseRequested
                 | Self::GenerateOverviewFromZip { .. }
+                | Self::ExtractHeightmapFromZipRequested { .. }
                 | Self::ZipBackgroundFileSelected { .. }
                 | Self::ZipBrowserCancelled
                 | Self::OverviewOptionsConfirmed
@@ -479,8 +691,10 @@ impl AppIntent {
                 | Self::ZoomOutRequested
                 | Self::CenterOnNodeRequested { .. }
                 | Self::RenderQualityChanged { .. }
+                | Self::ToggleIsolateSelectionRequested
                 | Self::ToggleBackgroundVisibility
                 | Self::ScaleBackground { .. }
+                | Self::BackgroundSourceSelected { .. }
                 | Self::CommandPaletteToggled
                 | Self::SetEditorToolRequested { .. }
                 | Self::SetDefaultDirectionRequested { .. }
@@ -489,9 +703,13 @@ impl AppIntent {
                 | Self::RemoveConnectionBetweenRequested { .. }
                 | Self::SetConnectionDirectionRequested { .. }
                 | Self::SetConnectionPriorityRequested { .. }
+                | Self::InvertConnectionRequested { .. }
+                | Self::SetSelectedNodeHeightsRequested { .. }
+                | Self::ProjectSelectionOntoTerrainRequested { .. }
                 | Self::ConnectSelectedNodesRequested
                 | Self::SetAllConnectionsDirectionBetweenSelectedRequested { .. }
                 | Self::InvertAllConnectionsBetweenSelectedRequested
+                | Self::ReversePathBetweenSelectedRequested
                 | Self::SetAllConnectionsPriorityBetweenSelectedRequested { .. }
                 | Self::RemoveAllConnectionsBetweenSelectedRequested
                 | Self::OptionsChanged { .. }
@@ -522,8 +740,29 @@ impl AppIntent {
                 | Self::IncreaseRouteToolSegmentLength
                 | Self::DecreaseRouteToolSegmentLength
                 | Self::InvertSelectionRequested
+                | Self::GrowSelectionRequested
+                | Self::ShrinkSelectionRequested
+                | Self::SelectPathBetweenSelectedRequested
+                | Self::SelectByConnectionPriorityRequested { .. }
+                | Self::SetSelectionLockedRequested { .. }
                 | Self::StreckenteilungAktivieren
                 | Self::ResamplePathRequested
+                | Self::EqualizeChainSpacingRequested
+                | Self::ResampleChainRequested { .. }
+                | Self::SmoothChainRequested { .. }
+                | Self::SimplifyChainRequested { .. }
+                | Self::AlignSelectedRequested { .. }
+                | Self::DistributeSelectedRequested
+                | Self::JoinSelectedChainsRequested
+                | Self::MergeSelectedNodesRequested
+                | Self::ReverseChainRequested
+                | Self::PathOffsetPreviewRequested
+                | Self::PathOffsetDistanceChanged { .. }
+                | Self::PathOffsetConfirmed
+                | Self::PathOffsetCancelled
+                | Self::ConvertJunctionToRoundaboutRequested
+                | Self::PasteFromTextRequested { .. }
+                | Self::DuplicateSelectionRequested
                 | Self::GroupEditStartRequested { .. }
                 | Self::GroupEditApplyRequested
                 | Self::GroupEditCancelRequested
@@ -532,12 +771,37 @@ impl AppIntent {
                 | Self::RemoveSelectedNodesFromGroupRequested
                 | Self::SetGroupBoundaryNodes { .. }
                 | Self::NodeSegmentBetweenIntersectionsRequested { .. }
+                | Self::SelectConnectedRequested { .. }
                 | Self::ToggleGroupLockRequested { .. }
                 | Self::DissolveGroupRequested { .. }
                 | Self::DissolveGroupConfirmed { .. }
                 | Self::OpenTraceAllFieldsDialogRequested
                 | Self::TraceAllFieldsConfirmed { .. }
                 | Self::TraceAllFieldsCancelled
+                | Self::OpenFieldCourseDialogRequested
+                | Self::FieldCourseConfirmed { .. }
+                | Self::FieldCourseCancelled
+                | Self::OpenMoveSelectionDialogRequested
+                | Self::MoveSelectionConfirmed { .. }
+                | Self::NudgeSelectionRequested { .. }
+                | Self::MoveSelectionCancelled
+                | Self::OpenDeleteByFilterDialogRequested
+                | Self::DeleteByFilterConfirmed { .. }
+                | Self::DeleteByFilterCancelled
+                | Self::LiveCourseRecordingToggled
+                | Self::LiveNetworkPushRequested
+                | Self::RoutesDialogRequested
+                | Self::StoredRouteImportRequested { .. }
+                | Self::StoredRouteExportRequested { .. }
+                | Self::RoutesDialogCancelled
+                | Self::MapModZipExportRequested
+                | Self::CoursesModExportRequested
+                | Self::OpenPdfExportDialogRequested
+                | Self::PdfExportConfirmed { .. }
+                | Self::PdfExportCancelled
+                | Self::SavegamePickerRequested
+                | Self::SavegameOpenRequested { .. }
+                | Self::SavegamePickerCancelled
         )
     }
 }
@@ -32,6 +32,13 @@ macro_rules! selection_intent_variants {
         BeginRotateSelectedNodesRequested,
         /// Rotation-Lifecycle Update: Selektierte Nodes um Delta-Winkel (Radiant) rotieren
         RotateSelectedNodesRequested { delta_angle: f32 },
+        /// Rotation-Lifecycle Update (Transform-Gizmo): Selektion um `pivot` rotieren
+        /// (`angle`, Radiant) und skalieren (`scale`); beides Deltas zum letzten Schritt
+        TransformSelectionRequested {
+            pivot: glam::Vec2,
+            angle: f32,
+            scale: f32,
+        },
         /// Rotation-Lifecycle Ende: Spatial-Index rebuild ausloesen
         EndRotateSelectedNodesRequested,
         /// Selektion aufheben
@@ -56,6 +63,7 @@ macro_rules! selection_intent_feature_arms {
         | Self::EndMoveSelectedNodesRequested
         | Self::BeginRotateSelectedNodesRequested
         | Self::RotateSelectedNodesRequested { .. }
+        | Self::TransformSelectionRequested { .. }
         | Self::EndRotateSelectedNodesRequested
         | Self::ClearSelectionRequested
         | Self::SelectAllRequested
@@ -33,6 +33,41 @@ pub fn close_trace_all_fields_dialog(state: &mut AppState) {
     state.ui.trace_all_fields_dialog.visible = false;
 }
 
+/// Oeffnet den Dialog fuer den Feld-Rundkurs.
+pub fn open_field_course_dialog(state: &mut AppState) {
+    state.ui.field_course_dialog.visible = true;
+}
+
+/// Schliesst den Dialog fuer den Feld-Rundkurs.
+pub fn close_field_course_dialog(state: &mut AppState) {
+    state.ui.field_course_dialog.visible = false;
+}
+
+/// Oeffnet den Dialog "Selektion verschieben um...".
+pub fn open_move_selection_dialog(state: &mut AppState) {
+    state.ui.move_selection_dialog.visible = true;
+}
+
+/// Schliesst den Dialog "Selektion verschieben um...".
+pub fn close_move_selection_dialog(state: &mut AppState) {
+    state.ui.move_selection_dialog.visible = false;
+}
+
+/// Oeffnet den Dialog "Loeschen nach Filter..." fuer die aktuelle Selektion.
+pub fn open_delete_by_filter_dialog(state: &mut AppState) {
+    use_cases::editing::open_delete_by_filter_dialog(state);
+}
+
+/// Schliesst den Dialog "Loeschen nach Filter...".
+pub fn close_delete_by_filter_dialog(state: &mut AppState) {
+    state.ui.delete_by_filter_dialog.visible = false;
+}
+
+/// Schliesst den Dialog "Als PDF exportieren...".
+pub fn close_pdf_export_dialog(state: &mut AppState) {
+    state.ui.pdf_export_dialog.visible = false;
+}
+
 /// Oeffnet den Curseplay-Import-Dateidialog.
 pub fn request_curseplay_import_dialog(state: &mut AppState) {
     state
@@ -98,6 +133,16 @@ pub fn close_zip_browser(state: &mut AppState) {
     state.ui.zip_browser = None;
 }
 
+/// Schliesst den Routen-Dialog des AutoDrive-Routen-Managers.
+pub fn close_routes_dialog(state: &mut AppState) {
+    use_cases::routes_manager::close_routes_dialog(state);
+}
+
+/// Schliesst die Savegame-Auswahl.
+pub fn close_savegame_picker(state: &mut AppState) {
+    use_cases::savegame_picker::close_savegame_picker(state);
+}
+
 fn build_overview_source_dialog_state(
     context: OverviewSourceContext,
     heightmap_set: bool,
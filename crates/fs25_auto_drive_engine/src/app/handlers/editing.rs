@@ -12,18 +12,26 @@ mod marker_ops;
 mod node_ops;
 
 pub use clipboard_ops::{
-    cancel_paste_preview, confirm_paste, copy_selection, export_curseplay_file,
-    import_curseplay_file, start_paste_preview, update_paste_preview,
+    cancel_paste_preview, confirm_paste, copy_selection, duplicate_selection,
+    export_curseplay_file, import_curseplay_file, paste_from_text, rotate_paste_preview,
+    start_paste_preview, update_paste_preview,
 };
 pub use connection_ops::{
-    add_connection, connect_selected, invert_all_between_selected, remove_all_between_selected,
-    remove_connection_between, set_all_directions_between_selected,
-    set_all_priorities_between_selected, set_connection_direction, set_connection_priority,
-    set_default_direction, set_default_priority,
+    add_connection, connect_selected, cut_connections_along_line,
+    insert_moved_node_into_connection, invert_all_between_selected, invert_connection,
+    remove_all_between_selected, remove_connection_between, reverse_path_between_selected,
+    set_all_directions_between_selected, set_all_priorities_between_selected,
+    set_connection_direction, set_connection_priority, set_default_direction, set_default_priority,
+    swipe_connection_direction,
 };
 pub use group_ops::edit_group;
 pub use marker_ops::{create_marker, open_marker_dialog, remove_marker, update_marker};
 pub use node_ops::{
-    add_node, connect_tool_pick, delete_selected, resample_path, set_editor_tool, set_node_flag,
-    streckenteilung_aktivieren, trace_all_fields,
+    add_node, align_selected_nodes, apply_path_offset, cancel_path_offset, connect_tool_pick,
+    convert_junction_to_roundabout, delete_by_filter, delete_selected, distribute_selected_nodes,
+    equalize_chain_spacing, generate_field_course, join_selected_chains, merge_selected_nodes,
+    project_selection_onto_terrain, resample_chain, resample_path, reverse_selected_chain,
+    set_editor_tool, set_node_flag, set_path_offset_distance, set_selected_node_heights,
+    simplify_chain, smooth_chain, start_path_offset, streckenteilung_aktivieren,
+    toggle_live_course_recording, trace_all_fields, update_live_player_pose,
 };
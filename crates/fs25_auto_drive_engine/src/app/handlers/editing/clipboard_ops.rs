@@ -11,6 +11,11 @@ pub fn start_paste_preview(state: &mut AppState) {
     use_cases::editing::start_paste_preview(state);
 }
 
+/// Liest die System-Zwischenablage (Editor-JSON) und startet die Einfuegen-Vorschau.
+pub fn paste_from_text(state: &mut AppState, text: &str) {
+    use_cases::editing::paste_from_text(state, text);
+}
+
 /// Aktualisiert die Einfuegen-Vorschauposition.
 pub fn update_paste_preview(state: &mut AppState, world_pos: glam::Vec2) {
     use_cases::editing::update_paste_preview(state, world_pos);
@@ -26,6 +31,16 @@ pub fn cancel_paste_preview(state: &mut AppState) {
     use_cases::editing::cancel_paste_preview(state);
 }
 
+/// Dreht die Einfuegen-Vorschau um `delta_rad` (Radiant).
+pub fn rotate_paste_preview(state: &mut AppState, delta_rad: f32) {
+    use_cases::editing::rotate_paste_preview(state, delta_rad);
+}
+
+/// Dupliziert die Selektion in den interaktiven Platzierungsmodus.
+pub fn duplicate_selection(state: &mut AppState) {
+    use_cases::editing::duplicate_selection(state);
+}
+
 /// Importiert eine Curseplay-XML-Datei und legt Nodes + Ring-Verbindungen an.
 pub fn import_curseplay_file(state: &mut AppState, path: &str) {
     use_cases::editing::import_curseplay(state, path);
@@ -1,6 +1,7 @@
 use crate::app::use_cases;
 use crate::app::AppState;
 use crate::core::{ConnectionDirection, ConnectionPriority};
+use glam::Vec2;
 
 /// Erstellt eine Verbindung zwischen zwei Nodes.
 pub fn add_connection(
@@ -28,6 +29,11 @@ pub fn set_connection_direction(
     use_cases::editing::set_connection_direction(state, start_id, end_id, direction);
 }
 
+/// Setzt die Richtung der Verbindung unter `from` per Wischgeste.
+pub fn swipe_connection_direction(state: &mut AppState, from: Vec2, to: Vec2, toggle_dual: bool) {
+    use_cases::editing::apply_connection_swipe(state, from, to, toggle_dual);
+}
+
 /// Setzt die Prioritaet einer bestehenden Verbindung.
 pub fn set_connection_priority(
     state: &mut AppState,
@@ -38,6 +44,11 @@ pub fn set_connection_priority(
     use_cases::editing::set_connection_priority(state, start_id, end_id, priority);
 }
 
+/// Invertiert eine einzelne Verbindung.
+pub fn invert_connection(state: &mut AppState, start_id: u64, end_id: u64) {
+    use_cases::editing::invert_connection(state, start_id, end_id);
+}
+
 /// Aktualisiert die Standard-Richtung fuer neue Verbindungen.
 pub fn set_default_direction(state: &mut AppState, direction: ConnectionDirection) {
     state.editor.default_direction = direction;
@@ -69,6 +80,16 @@ pub fn invert_all_between_selected(state: &mut AppState) {
     use_cases::editing::invert_all_connections_between_selected(state);
 }
 
+/// Kehrt den selektierten Pfad um (Einbahn-Verbindungen spiegeln).
+pub fn reverse_path_between_selected(state: &mut AppState) {
+    use_cases::editing::reverse_path_between_selected(state);
+}
+
+/// Fuegt den gerade abgelegten Node in eine nahe Verbindung ein.
+pub fn insert_moved_node_into_connection(state: &mut AppState) {
+    use_cases::editing::insert_moved_node_into_connection(state);
+}
+
 /// Setzt die Prioritaet aller Verbindungen zwischen selektierten Nodes.
 pub fn set_all_priorities_between_selected(state: &mut AppState, priority: ConnectionPriority) {
     use_cases::editing::set_all_connections_priority_between_selected(state, priority);
@@ -84,3 +105,8 @@ pub fn connect_selected(state: &mut AppState) {
         use_cases::editing::add_connection(state, ids[0], ids[1], direction, priority);
     }
 }
+
+/// Schneidet alle Verbindungen entlang der Linie `from`→`to` (Messer-Werkzeug, undo-faehig).
+pub fn cut_connections_along_line(state: &mut AppState, from: Vec2, to: Vec2, split: bool) {
+    use_cases::editing::cut_connections_along_line(state, from, to, split);
+}
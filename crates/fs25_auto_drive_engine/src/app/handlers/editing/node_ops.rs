@@ -1,4 +1,5 @@
 use crate::app::use_cases;
+use crate::app::use_cases::editing::{NodeAlignMode, NodeHeightEdit};
 use crate::app::AppState;
 use crate::core::NodeFlag;
 
@@ -6,6 +7,9 @@ use crate::core::NodeFlag;
 pub fn set_editor_tool(state: &mut AppState, tool: crate::app::state::EditorTool) {
     state.editor.active_tool = tool;
     state.editor.connect_source_node = None;
+    if tool != crate::app::state::EditorTool::Measure {
+        state.editor.measure_points.clear();
+    }
     log::info!("Editor-Werkzeug: {:?}", tool);
 }
 
@@ -32,6 +36,11 @@ pub fn delete_selected(state: &mut AppState) {
     use_cases::editing::delete_selected_nodes(state);
 }
 
+/// Loescht die Nodes der Selektion, die den Filter erfuellen (ein Undo-Schritt).
+pub fn delete_by_filter(state: &mut AppState, filter: crate::shared::NodeDeleteFilter) {
+    use_cases::editing::delete_nodes_by_filter(state, filter);
+}
+
 /// Verarbeitet einen Pick fuer das Connect-Tool.
 pub fn connect_tool_pick(state: &mut AppState, world_pos: glam::Vec2, max_distance: f32) {
     use_cases::editing::connect_tool_pick_node(state, world_pos, max_distance);
@@ -42,11 +51,91 @@ pub fn set_node_flag(state: &mut AppState, node_id: u64, flag: NodeFlag) {
     use_cases::editing::set_node_flag(state, node_id, flag);
 }
 
+/// Setzt die Y-Werte aller selektierten Nodes (undo-faehig).
+pub fn set_selected_node_heights(state: &mut AppState, edit: NodeHeightEdit) {
+    use_cases::editing::set_selected_node_heights(state, edit);
+}
+
+/// Projiziert die selektierten Nodes auf das Gelaende der Heightmap (undo-faehig).
+pub fn project_selection_onto_terrain(state: &mut AppState, offset: f32) {
+    use_cases::editing::project_selection_onto_terrain(state, offset);
+}
+
+/// Startet die Vorschau fuer den seitlichen Versatz der selektierten Kette.
+pub fn start_path_offset(state: &mut AppState) {
+    use_cases::editing::start_path_offset_preview(state);
+}
+
+/// Setzt den seitlichen Versatz und aktualisiert die Vorschau.
+pub fn set_path_offset_distance(state: &mut AppState, distance: f32) {
+    use_cases::editing::set_path_offset_distance(state, distance);
+}
+
+/// Verschiebt die erfasste Kette seitlich (undo-faehig).
+pub fn apply_path_offset(state: &mut AppState) {
+    use_cases::editing::apply_path_offset(state);
+}
+
+/// Verwirft die Versatz-Vorschau.
+pub fn cancel_path_offset(state: &mut AppState) {
+    use_cases::editing::cancel_path_offset_preview(state);
+}
+
+/// Wandelt den selektierten Kreuzungs-Node in einen Kreisverkehr um (undo-faehig).
+pub fn convert_junction_to_roundabout(state: &mut AppState, radius: f32) {
+    use_cases::editing::convert_junction_to_roundabout(state, radius);
+}
+
 /// Verteilt die selektierten Nodes gleichmaessig entlang eines Catmull-Rom-Splines.
 pub fn resample_path(state: &mut AppState) {
     use_cases::editing::resample_selected_path(state);
 }
 
+/// Verteilt die Nodes der selektierten Kette auf gleiche Abstaende (undo-faehig).
+pub fn equalize_chain_spacing(state: &mut AppState) {
+    use_cases::editing::equalize_selected_chain_spacing(state);
+}
+
+/// Baut die selektierte Kette mit neuem Node-Abstand neu auf (undo-faehig).
+pub fn resample_chain(state: &mut AppState, spacing: f32) {
+    use_cases::editing::resample_selected_chain(state, spacing);
+}
+
+/// Glaettet die inneren Nodes der selektierten Kette (undo-faehig).
+pub fn smooth_chain(state: &mut AppState, strength: f32, iterations: u32) {
+    use_cases::editing::smooth_selected_chain(state, strength, iterations);
+}
+
+/// Duennt die selektierte Kette per Douglas-Peucker aus (undo-faehig).
+pub fn simplify_chain(state: &mut AppState, tolerance: f32) {
+    use_cases::editing::simplify_selected_chain(state, tolerance);
+}
+
+/// Richtet die selektierten Nodes auf einer Geraden aus (undo-faehig).
+pub fn align_selected_nodes(state: &mut AppState, mode: NodeAlignMode) {
+    use_cases::editing::align_selected_nodes(state, mode);
+}
+
+/// Verteilt die selektierten Nodes gleichmaessig entlang der Endpunkt-Achse (undo-faehig).
+pub fn distribute_selected_nodes(state: &mut AppState) {
+    use_cases::editing::distribute_selected_nodes(state);
+}
+
+/// Verbindet zwei selektierte Ketten per Spline-Uebergang (undo-faehig).
+pub fn join_selected_chains(state: &mut AppState) {
+    use_cases::editing::join_selected_chains(state);
+}
+
+/// Fuehrt die selektierten Nodes im Schwerpunkt zusammen (undo-faehig).
+pub fn merge_selected_nodes(state: &mut AppState) {
+    use_cases::editing::merge_selected_nodes(state);
+}
+
+/// Kehrt die Laufrichtung der selektierten Kette um (undo-faehig).
+pub fn reverse_selected_chain(state: &mut AppState) {
+    use_cases::editing::reverse_selected_chain(state);
+}
+
 /// Zeichnet alle erkannten Farmland-Polygone als Wegpunkt-Ring nach (Batch-Operation).
 pub fn trace_all_fields(
     state: &mut AppState,
@@ -68,6 +157,26 @@ pub fn trace_all_fields(
     );
 }
 
+/// Erzeugt einen Vorgewende-Ring mit Zufahrt fuer ein einzelnes Feld.
+pub fn generate_field_course(
+    state: &mut AppState,
+    field_id: u32,
+    spacing: f32,
+    headland_offset: f32,
+) {
+    use_cases::editing::generate_field_course(state, field_id, spacing, headland_offset);
+}
+
+/// Uebernimmt die Live-Spielerposition (zeichnet bei aktiver Aufzeichnung auf).
+pub fn update_live_player_pose(state: &mut AppState, position: glam::Vec2, yaw: f32) {
+    use_cases::live_link::update_player_pose(state, position, yaw);
+}
+
+/// Startet bzw. beendet die Live-Aufzeichnung waehrend der Fahrt.
+pub fn toggle_live_course_recording(state: &mut AppState) {
+    use_cases::live_link::toggle_course_recording(state);
+}
+
 /// Aktiviert die Streckenteilung wenn mindestens 2 Nodes selektiert sind.
 pub fn streckenteilung_aktivieren(state: &mut AppState) {
     if state.selection.selected_node_ids.len() >= 2 {
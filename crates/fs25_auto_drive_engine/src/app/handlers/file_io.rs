@@ -4,6 +4,7 @@ use super::dialog;
 use crate::app::use_cases;
 use crate::app::AppState;
 use crate::app::BackgroundLayerFiles;
+use crate::shared::PdfExportOptions;
 use std::path::Path;
 
 /// Oeffnet den Datei-Oeffnen-Dialog.
@@ -33,7 +34,7 @@ pub fn load(state: &mut AppState, path: String) -> anyhow::Result<()> {
 }
 
 /// Fuehrt die automatische Erkennung von Heightmap, gespeicherten Overview-Layern,
-/// Legacy-Overview-Bildern und Map-Mod-ZIPs durch.
+/// Legacy-Overview-Bildern, Map-Mod-ZIPs, Savegame-Fahrzeugen und Farmland-Besitz durch.
 fn run_post_load_detection(state: &mut AppState, xml_path: &str) {
     let xml_path = Path::new(xml_path);
     let map_name = state.road_map.as_ref().and_then(|rm| rm.map_name.clone());
@@ -42,6 +43,8 @@ fn run_post_load_detection(state: &mut AppState, xml_path: &str) {
     let background_layer_files = result.background_layer_files;
     let overview_path = result.overview_path;
     let matching_zips = result.matching_zips;
+    use_cases::savegame_vehicles::load_savegame_vehicles(state, result.vehicles_path.as_deref());
+    use_cases::savegame_farmlands::load_savegame_farmlands(state, result.farmland_path.as_deref());
 
     let heightmap_set = result.heightmap_path.is_some();
     let heightmap_display = result
@@ -56,6 +59,7 @@ fn run_post_load_detection(state: &mut AppState, xml_path: &str) {
     {
         state.ui.heightmap_path = Some(hm_str.to_string());
         log::info!("Heightmap auto-detected: {}", hm_str);
+        use_cases::heightmap::reload_heightmap(state);
     }
 
     // Hintergrundbild automatisch laden: gespeichertes Layer-Bundle hat Vorrang,
@@ -148,11 +152,76 @@ pub fn set_heightmap(state: &mut AppState, path: String) {
     use_cases::heightmap::set_heightmap(state, path);
 }
 
+/// Extrahiert die Heightmap aus einem Map-Mod-ZIP und setzt sie.
+pub fn extract_heightmap_from_zip(state: &mut AppState, zip_path: &str) -> anyhow::Result<()> {
+    use_cases::heightmap::extract_heightmap_from_zip(state, zip_path)
+}
+
 /// Fuehrt die Duplikat-Bereinigung auf der geladenen RoadMap aus.
 pub fn deduplicate(state: &mut AppState) {
     use_cases::file_io::deduplicate_loaded_roadmap(state);
 }
 
+/// Speichert das Netz und merkt es zum Neuladen im laufenden Spiel vor.
+pub fn push_network_to_game(state: &mut AppState) -> anyhow::Result<()> {
+    use_cases::live_link::push_network_to_game(state)
+}
+
+/// Oeffnet den Routen-Dialog des AutoDrive-Routen-Managers.
+pub fn open_routes_dialog(state: &mut AppState) {
+    use_cases::routes_manager::open_routes_dialog(state);
+}
+
+/// Importiert eine Route aus dem AutoDrive-Routen-Manager als aktuelles Netz.
+pub fn import_stored_route(state: &mut AppState, file_name: &str) -> anyhow::Result<()> {
+    use_cases::routes_manager::import_stored_route(state, file_name)
+}
+
+/// Exportiert das aktuelle Netz als Route in den AutoDrive-Routen-Manager.
+pub fn export_stored_route(state: &mut AppState, name: &str) -> anyhow::Result<()> {
+    use_cases::routes_manager::export_stored_route(state, name)
+}
+
+/// Oeffnet die Savegame-Auswahl mit den Savegames des FS25-Profils.
+pub fn open_savegame_picker(state: &mut AppState) {
+    use_cases::savegame_picker::open_savegame_picker(state);
+}
+
+/// Oeffnet die Auswahl des Map-Mod-ZIPs fuer den Standard-Config-Export.
+pub fn request_map_mod_zip(state: &mut AppState) {
+    use_cases::map_mod_export::request_map_mod_zip(state);
+}
+
+/// Schreibt das Netz als Standard-Config in das gewaehlte Map-Mod-ZIP.
+pub fn export_into_map_mod_zip(state: &mut AppState, zip_path: &str) -> anyhow::Result<()> {
+    use_cases::map_mod_export::export_into_map_zip(state, zip_path)
+}
+
+/// Oeffnet den Speicherdialog fuer den Kurs-Mod.
+pub fn request_courses_mod_export(state: &mut AppState) {
+    use_cases::map_mod_export::request_courses_mod_export(state);
+}
+
+/// Schreibt das Netz als eigenstaendigen Kurs-Mod.
+pub fn export_courses_mod(state: &mut AppState, zip_path: &str) -> anyhow::Result<()> {
+    use_cases::map_mod_export::export_courses_mod(state, zip_path)
+}
+
+/// Oeffnet den Dialog "Als PDF exportieren...".
+pub fn open_pdf_export_dialog(state: &mut AppState) {
+    use_cases::pdf_export::open_pdf_export_dialog(state);
+}
+
+/// Uebernimmt die PDF-Einstellungen und fordert den Speicherdialog an.
+pub fn request_pdf_export_path(state: &mut AppState, options: PdfExportOptions) {
+    use_cases::pdf_export::request_pdf_export_path(state, options);
+}
+
+/// Schreibt die Karte als mehrseitiges PDF.
+pub fn export_map_pdf(state: &mut AppState, path: &str) -> anyhow::Result<()> {
+    use_cases::pdf_export::export_map_pdf(state, path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::run_post_load_detection;
@@ -41,6 +41,7 @@ pub fn record_selection_if_changed(
         let old_selection = SelectionState {
             selected_node_ids: old_selected,
             selection_anchor_node_id: old_anchor,
+            selected_connection: None,
             generation: 0,
         };
         let snap = Snapshot {
@@ -27,6 +27,9 @@ pub fn click(state: &mut AppState, world_pos: glam::Vec2, ctrl: bool) {
         let Some(road_map) = state.road_map.as_deref() else {
             return;
         };
+        let world_pos = state
+            .editor
+            .constrain_route_tool_pos(world_pos, road_map, &state.options);
         let Some(tool) = state.editor.tool_manager.active_tool_mut() else {
             return;
         };
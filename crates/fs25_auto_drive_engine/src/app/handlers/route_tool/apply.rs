@@ -2,6 +2,9 @@ use crate::app::use_cases;
 use crate::app::AppState;
 
 /// Fuehrt das aktive Route-Tool aus, wendet das Ergebnis an und registriert ggf. die Gruppe neu.
+///
+/// Wuerde das Ergebnis gesperrte Nodes entfernen (`nodes_to_remove`, z.B.
+/// Versatz mit "Original entfernen"), wird es verworfen und eine Statusmeldung gesetzt.
 pub(super) fn execute_and_apply(state: &mut AppState) {
     let result = match (
         state.editor.tool_manager.active_tool(),
@@ -12,6 +15,15 @@ pub(super) fn execute_and_apply(state: &mut AppState) {
     };
 
     if let Some(result) = result {
+        if result
+            .nodes_to_remove
+            .iter()
+            .any(|id| state.locked_node_ids.contains(id))
+        {
+            state.ui.status_message =
+                Some("Route-Tool wuerde gesperrte Nodes entfernen — nicht angewendet".to_string());
+            return;
+        }
         let pre_apply_road_map = state.road_map.clone();
         let marker_indices: Vec<usize> = result.markers.iter().map(|(idx, _, _)| *idx).collect();
         let ids = if state.active_tool_edit_session.is_some() {
@@ -47,6 +59,11 @@ pub(super) fn recreate(state: &mut AppState) {
         }
         None => return,
     };
+    if old_ids.iter().any(|id| state.locked_node_ids.contains(id)) {
+        state.ui.status_message =
+            Some("Strecke enthaelt gesperrte Nodes — nicht neu erstellt".to_string());
+        return;
+    }
 
     if state.active_tool_edit_session.is_none() {
         state.record_undo_snapshot();
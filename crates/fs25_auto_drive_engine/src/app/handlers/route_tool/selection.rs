@@ -141,7 +141,15 @@ fn build_connected_neighbor_seeds(
         .into_iter()
         .filter_map(|neighbor| {
             let position = road_map.node_position(neighbor.neighbor_id)?;
-            Some(RouteToolConnectedNeighborSeed::new(neighbor, position))
+            let (start_id, end_id) = if neighbor.is_outgoing {
+                (node_id, neighbor.neighbor_id)
+            } else {
+                (neighbor.neighbor_id, node_id)
+            };
+            let direction = road_map.find_connection(start_id, end_id)?.direction;
+            Some(RouteToolConnectedNeighborSeed::new(
+                neighbor, position, direction,
+            ))
         })
         .collect()
 }
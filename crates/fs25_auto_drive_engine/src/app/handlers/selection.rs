@@ -3,6 +3,7 @@
 use crate::app::handlers::helpers;
 use crate::app::use_cases;
 use crate::app::AppState;
+use crate::core::ConnectionPriority;
 use std::sync::Arc;
 
 /// Selektiert den naechstgelegenen Node zum Klickpunkt.
@@ -45,6 +46,25 @@ pub fn select_segment(
     helpers::record_selection_if_changed(state, old_selected, old_anchor);
 }
 
+/// Selektiert alle vom naechsten Node erreichbaren Nodes (Flood-Selektion).
+pub fn select_connected(
+    state: &mut AppState,
+    world_pos: glam::Vec2,
+    max_distance: f32,
+    additive: bool,
+    follow_direction: bool,
+) {
+    let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
+    use_cases::selection::select_connected_nodes(
+        state,
+        world_pos,
+        max_distance,
+        additive,
+        follow_direction,
+    );
+    helpers::record_selection_if_changed(state, old_selected, old_anchor);
+}
+
 /// Selektiert alle Nodes der Gruppe, zu der der naechste Node gehoert.
 pub fn select_group_nodes(
     state: &mut AppState,
@@ -81,6 +101,11 @@ pub fn begin_move(state: &mut AppState) {
     state.record_undo_snapshot();
 }
 
+/// Verschiebt die Selektion um einen exakten Versatz (ein Undo-Schritt).
+pub fn move_selected_by(state: &mut AppState, delta_world: glam::Vec2, delta_height: f32) {
+    use_cases::selection::move_selected_nodes_by(state, delta_world, delta_height);
+}
+
 /// Beendet den Move-Lifecycle und stoesst den Spatial-Index-Rebuild an.
 pub fn end_move(state: &mut AppState) {
     if let Some(road_map) = state.road_map.as_mut() {
@@ -99,6 +124,11 @@ pub fn rotate_selected(state: &mut AppState, delta_angle: f32) {
     use_cases::selection::rotate_selected_nodes(state, delta_angle);
 }
 
+/// Rotiert und skaliert alle selektierten Nodes um `pivot` (Transform-Gizmo, Deltas).
+pub fn transform_selected(state: &mut AppState, pivot: glam::Vec2, angle: f32, scale: f32) {
+    use_cases::selection::transform_selected_nodes(state, pivot, angle, scale);
+}
+
 /// Beendet den Rotation-Lifecycle und stoesst den Spatial-Index-Rebuild an.
 pub fn end_rotate(state: &mut AppState) {
     if let Some(road_map) = state.road_map.as_mut() {
@@ -128,8 +158,50 @@ pub fn select_all(state: &mut AppState) {
     }
 }
 
+/// Erweitert die Selektion um alle direkten Nachbarn.
+pub fn grow(state: &mut AppState) {
+    let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
+    use_cases::selection::grow_selection(state);
+    helpers::record_selection_if_changed(state, old_selected, old_anchor);
+}
+
+/// Verkleinert die Selektion um ihre Randnodes.
+pub fn shrink(state: &mut AppState) {
+    let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
+    use_cases::selection::shrink_selection(state);
+    helpers::record_selection_if_changed(state, old_selected, old_anchor);
+}
+
+/// Fuegt den kuerzesten Pfad zwischen den zwei selektierten Nodes hinzu.
+pub fn select_path_between(state: &mut AppState) {
+    let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
+    use_cases::selection::select_path_between_selected(state);
+    helpers::record_selection_if_changed(state, old_selected, old_anchor);
+}
+
+/// Selektiert alle Nodes an Verbindungen der gegebenen Prioritaet.
+pub fn select_by_connection_priority(state: &mut AppState, priority: ConnectionPriority) {
+    let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
+    use_cases::selection::select_nodes_by_connection_priority(state, priority);
+    helpers::record_selection_if_changed(state, old_selected, old_anchor);
+}
+
+/// Sperrt bzw. entsperrt die selektierten Nodes.
+pub fn set_locked(state: &mut AppState, locked: bool) {
+    use_cases::selection::set_selected_nodes_locked(state, locked);
+}
+
 /// Invertiert die aktuelle Selektion (alle unselektierten werden selektiert und umgekehrt).
-pub fn invert(state: &mut AppState) {
+///
+/// Mit `visible_only` betrifft die Inversion nur Nodes im Viewport, die nicht
+/// vom Isolier-Modus ausgeblendet sind.
+pub fn invert(state: &mut AppState, visible_only: bool) {
+    if visible_only {
+        let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
+        use_cases::selection::invert_visible_selection(state);
+        helpers::record_selection_if_changed(state, old_selected, old_anchor);
+        return;
+    }
     if let Some(rm) = &state.road_map {
         let (old_selected, old_anchor) = helpers::capture_selection_snapshot(state);
         let current = &state.selection.selected_node_ids;
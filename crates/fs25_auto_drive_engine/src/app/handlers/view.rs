@@ -2,7 +2,7 @@
 
 use crate::app::use_cases;
 use crate::app::AppState;
-use crate::shared::{BackgroundLayerKind, RenderQuality};
+use crate::shared::{BackgroundLayerKind, BackgroundSource, RenderQuality};
 
 /// Setzt die Kamera auf den Standardzustand zurueck.
 pub fn reset_camera(state: &mut AppState) {
@@ -48,6 +48,11 @@ pub fn set_render_quality(state: &mut AppState, quality: RenderQuality) {
     use_cases::viewport::set_render_quality(state, quality);
 }
 
+/// Schaltet den Isolier-Modus fuer die aktuelle Selektion um.
+pub fn toggle_isolate_selection(state: &mut AppState) {
+    use_cases::viewport::toggle_isolate_selection(state);
+}
+
 /// Laedt eine Background-Map und propagiert Fehler an den Aufrufer.
 pub fn load_background_map(
     state: &mut AppState,
@@ -76,6 +81,11 @@ pub fn scale_background(state: &mut AppState, factor: f32) {
     use_cases::background_map::scale_background(state, factor);
 }
 
+/// Schaltet den Hintergrund zwischen Overview und Heightmap-Relief um.
+pub fn set_background_source(state: &mut AppState, source: BackgroundSource) -> anyhow::Result<()> {
+    use_cases::background_map::set_background_source(state, source)
+}
+
 /// Oeffnet den ZIP-Browser-Dialog fuer die gewaehlte ZIP-Datei.
 pub fn browse_zip_background(state: &mut AppState, path: String) -> anyhow::Result<()> {
     use_cases::background_map::browse_zip_background(state, path)
@@ -108,6 +118,16 @@ pub fn zoom_to_selection_bounds(state: &mut AppState) {
     }
 }
 
+/// Haengt einen Messpunkt an die Messlinie an (Mess-Werkzeug).
+pub fn add_measure_point(state: &mut AppState, world_pos: glam::Vec2) {
+    use_cases::measure::add_measure_point(state, world_pos);
+}
+
+/// Verwirft die Messlinie des Mess-Werkzeugs.
+pub fn clear_measure_points(state: &mut AppState) {
+    use_cases::measure::clear_measure_points(state);
+}
+
 /// Zoomt passend: auf Selektion wenn vorhanden, sonst auf die gesamte RoadMap.
 pub fn zoom_to_fit(state: &mut AppState) {
     if let Some(rm) = state.road_map.clone() {
@@ -1,5 +1,6 @@
 //! Intent-Mapping fuer Editing, Marker, Copy/Paste und Editing-Extras.
 
+use crate::app::use_cases::editing::PASTE_PREVIEW_ROTATION_STEP_DEG;
 use crate::app::{AppCommand, AppIntent, AppState};
 
 /// Mappt Editing-Intents auf Commands.
@@ -39,6 +40,15 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
             end_id,
             direction,
         }],
+        AppIntent::ConnectionSwipeRequested {
+            from,
+            to,
+            toggle_dual,
+        } => vec![AppCommand::SwipeConnectionDirection {
+            from,
+            to,
+            toggle_dual,
+        }],
         AppIntent::SetConnectionPriorityRequested {
             start_id,
             end_id,
@@ -48,9 +58,18 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
             end_id,
             priority,
         }],
+        AppIntent::InvertConnectionRequested { start_id, end_id } => {
+            vec![AppCommand::InvertConnection { start_id, end_id }]
+        }
         AppIntent::NodeFlagChangeRequested { node_id, flag } => {
             vec![AppCommand::SetNodeFlag { node_id, flag }]
         }
+        AppIntent::SetSelectedNodeHeightsRequested { edit } => {
+            vec![AppCommand::SetSelectedNodeHeights { edit }]
+        }
+        AppIntent::ProjectSelectionOntoTerrainRequested { offset } => {
+            vec![AppCommand::ProjectSelectionOntoTerrain { offset }]
+        }
         AppIntent::SetDefaultDirectionRequested { direction } => {
             vec![AppCommand::SetDefaultDirection { direction }]
         }
@@ -66,6 +85,9 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
         AppIntent::InvertAllConnectionsBetweenSelectedRequested => {
             vec![AppCommand::InvertAllConnectionsBetweenSelected]
         }
+        AppIntent::ReversePathBetweenSelectedRequested => {
+            vec![AppCommand::ReversePathBetweenSelected]
+        }
         AppIntent::SetAllConnectionsPriorityBetweenSelectedRequested { priority } => {
             vec![AppCommand::SetAllConnectionsPriorityBetweenSelected { priority }]
         }
@@ -108,13 +130,57 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
         AppIntent::MarkerDialogCancelled => vec![AppCommand::CloseMarkerDialog],
         AppIntent::ResamplePathRequested => vec![AppCommand::ResamplePath],
         AppIntent::StreckenteilungAktivieren => vec![AppCommand::StreckenteilungAktivieren],
+        AppIntent::EqualizeChainSpacingRequested => vec![AppCommand::EqualizeChainSpacing],
+        AppIntent::ResampleChainRequested { spacing } => {
+            vec![AppCommand::ResampleSelectedChain { spacing }]
+        }
+        AppIntent::SimplifyChainRequested { tolerance } => {
+            vec![AppCommand::SimplifySelectedChain { tolerance }]
+        }
+        AppIntent::SmoothChainRequested {
+            strength,
+            iterations,
+        } => vec![AppCommand::SmoothSelectedChain {
+            strength,
+            iterations,
+        }],
+        AppIntent::AlignSelectedRequested { mode } => vec![AppCommand::AlignSelectedNodes { mode }],
+        AppIntent::DistributeSelectedRequested => vec![AppCommand::DistributeSelectedNodes],
+        AppIntent::JoinSelectedChainsRequested => vec![AppCommand::JoinSelectedChains],
+        AppIntent::MergeSelectedNodesRequested => vec![AppCommand::MergeSelectedNodes],
+        AppIntent::ReverseChainRequested => vec![AppCommand::ReverseSelectedChain],
+        AppIntent::PathOffsetPreviewRequested => vec![AppCommand::StartPathOffsetPreview],
+        AppIntent::PathOffsetDistanceChanged { distance } => {
+            vec![AppCommand::SetPathOffsetDistance { distance }]
+        }
+        AppIntent::PathOffsetConfirmed => vec![AppCommand::ApplyPathOffset],
+        AppIntent::PathOffsetCancelled => vec![AppCommand::CancelPathOffsetPreview],
+        AppIntent::KnifeCutRequested { from, to } => vec![AppCommand::CutConnectionsAlongLine {
+            from,
+            to,
+            split: state.options.knife_split_connections,
+        }],
+        AppIntent::ConvertJunctionToRoundaboutRequested => {
+            vec![AppCommand::ConvertJunctionToRoundabout {
+                radius: state.options.roundabout_radius_m,
+            }]
+        }
         AppIntent::CopySelectionRequested => vec![AppCommand::CopySelection],
         AppIntent::PasteStartRequested => vec![AppCommand::StartPastePreview],
+        AppIntent::PasteFromTextRequested { text } => vec![AppCommand::PasteFromText { text }],
         AppIntent::PastePreviewMoved { world_pos } => {
             vec![AppCommand::UpdatePastePreview { world_pos }]
         }
         AppIntent::PasteConfirmRequested => vec![AppCommand::ConfirmPaste],
         AppIntent::PasteCancelled => vec![AppCommand::CancelPastePreview],
+        AppIntent::PastePreviewRotateRequested { counter_clockwise } => {
+            // Welt-Y zeigt im Viewport nach unten: positive Winkel drehen am Bildschirm im Uhrzeigersinn
+            let step = PASTE_PREVIEW_ROTATION_STEP_DEG.to_radians();
+            vec![AppCommand::RotatePastePreview {
+                delta_rad: if counter_clockwise { -step } else { step },
+            }]
+        }
+        AppIntent::DuplicateSelectionRequested => vec![AppCommand::DuplicateSelection],
         AppIntent::OpenTraceAllFieldsDialogRequested => vec![AppCommand::OpenTraceAllFieldsDialog],
         AppIntent::TraceAllFieldsConfirmed {
             spacing,
@@ -132,6 +198,41 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
             corner_rounding_max_angle_deg,
         }],
         AppIntent::TraceAllFieldsCancelled => vec![AppCommand::CloseTraceAllFieldsDialog],
+        AppIntent::OpenFieldCourseDialogRequested => vec![AppCommand::OpenFieldCourseDialog],
+        AppIntent::FieldCourseConfirmed {
+            field_id,
+            spacing,
+            headland_offset,
+        } => vec![AppCommand::GenerateFieldCourse {
+            field_id,
+            spacing,
+            headland_offset,
+        }],
+        AppIntent::FieldCourseCancelled => vec![AppCommand::CloseFieldCourseDialog],
+        AppIntent::OpenMoveSelectionDialogRequested => vec![AppCommand::OpenMoveSelectionDialog],
+        AppIntent::MoveSelectionConfirmed { dx, dy, dz } => vec![
+            AppCommand::CloseMoveSelectionDialog,
+            AppCommand::MoveSelectedNodesBy {
+                delta_world: glam::Vec2::new(dx, dz),
+                delta_height: dy,
+            },
+        ],
+        AppIntent::NudgeSelectionRequested { delta } => vec![AppCommand::MoveSelectedNodesBy {
+            delta_world: delta,
+            delta_height: 0.0,
+        }],
+        AppIntent::MoveSelectionCancelled => vec![AppCommand::CloseMoveSelectionDialog],
+        AppIntent::OpenDeleteByFilterDialogRequested => {
+            vec![AppCommand::OpenDeleteByFilterDialog]
+        }
+        AppIntent::DeleteByFilterConfirmed { filter } => {
+            vec![AppCommand::DeleteNodesByFilter { filter }]
+        }
+        AppIntent::DeleteByFilterCancelled => vec![AppCommand::CloseDeleteByFilterDialog],
+        AppIntent::LivePlayerPoseReceived { position, yaw } => {
+            vec![AppCommand::UpdateLivePlayerPose { position, yaw }]
+        }
+        AppIntent::LiveCourseRecordingToggled => vec![AppCommand::ToggleLiveCourseRecording],
         AppIntent::CurseplayImportRequested => vec![AppCommand::RequestCurseplayImportDialog],
         AppIntent::CurseplayExportRequested => vec![AppCommand::RequestCurseplayExportDialog],
         AppIntent::CurseplayFileSelected { path } => vec![AppCommand::ImportCurseplay { path }],
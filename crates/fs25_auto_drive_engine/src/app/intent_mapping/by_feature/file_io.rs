@@ -21,8 +21,40 @@ pub(super) fn map(_state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
         AppIntent::FileSelected { path } => vec![AppCommand::LoadFile { path }],
         AppIntent::SaveFilePathSelected { path } => vec![AppCommand::SaveFile { path: Some(path) }],
         AppIntent::HeightmapSelected { path } => vec![AppCommand::SetHeightmap { path }],
+        AppIntent::ExtractHeightmapFromZipRequested { path } => {
+            vec![AppCommand::ExtractHeightmapFromZip { path }]
+        }
         AppIntent::DeduplicateConfirmed => vec![AppCommand::DeduplicateNodes],
         AppIntent::DeduplicateCancelled => vec![AppCommand::DismissDeduplicateDialog],
+        AppIntent::LiveNetworkPushRequested => vec![AppCommand::PushNetworkToGame],
+        AppIntent::RoutesDialogRequested => vec![AppCommand::OpenRoutesDialog],
+        AppIntent::StoredRouteImportRequested { file_name } => {
+            vec![AppCommand::ImportStoredRoute { file_name }]
+        }
+        AppIntent::StoredRouteExportRequested { name } => {
+            vec![AppCommand::ExportStoredRoute { name }]
+        }
+        AppIntent::RoutesDialogCancelled => vec![AppCommand::CloseRoutesDialog],
+        AppIntent::SavegamePickerRequested => vec![AppCommand::OpenSavegamePicker],
+        AppIntent::SavegameOpenRequested { path } => {
+            vec![
+                AppCommand::CloseSavegamePicker,
+                AppCommand::LoadFile { path },
+            ]
+        }
+        AppIntent::SavegamePickerCancelled => vec![AppCommand::CloseSavegamePicker],
+        AppIntent::MapModZipExportRequested => vec![AppCommand::RequestMapModZipDialog],
+        AppIntent::MapModZipSelected { path } => vec![AppCommand::ExportIntoMapModZip { path }],
+        AppIntent::CoursesModExportRequested => vec![AppCommand::RequestCoursesModExportDialog],
+        AppIntent::CoursesModExportPathSelected { path } => {
+            vec![AppCommand::ExportCoursesMod { path }]
+        }
+        AppIntent::OpenPdfExportDialogRequested => vec![AppCommand::OpenPdfExportDialog],
+        AppIntent::PdfExportConfirmed { options } => {
+            vec![AppCommand::RequestPdfExportPath { options }]
+        }
+        AppIntent::PdfExportCancelled => vec![AppCommand::ClosePdfExportDialog],
+        AppIntent::PdfExportPathSelected { path } => vec![AppCommand::ExportMapPdf { path }],
         other => unreachable!("unerwarteter FileIo-Intent: {other:?}"),
     }
 }
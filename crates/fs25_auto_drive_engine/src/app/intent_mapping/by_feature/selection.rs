@@ -82,6 +82,15 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
             world_pos,
             additive,
         } => map_segment_pick(state, world_pos, additive),
+        AppIntent::SelectConnectedRequested {
+            world_pos,
+            additive,
+        } => vec![AppCommand::SelectConnectedNodes {
+            world_pos,
+            max_distance: state.options.hitbox_radius(),
+            additive,
+            follow_direction: state.options.flood_select_follow_direction,
+        }],
         AppIntent::SelectNodesInRectRequested { min, max, additive } => {
             vec![AppCommand::SelectNodesInRect { min, max, additive }]
         }
@@ -92,15 +101,44 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
         AppIntent::MoveSelectedNodesRequested { delta_world } => {
             vec![AppCommand::MoveSelectedNodes { delta_world }]
         }
-        AppIntent::EndMoveSelectedNodesRequested => vec![AppCommand::EndMoveSelectedNodes],
+        AppIntent::EndMoveSelectedNodesRequested => {
+            // Erst Spatial-Index neu aufbauen, dann ggf. in Verbindung einfuegen
+            let mut commands = vec![AppCommand::EndMoveSelectedNodes];
+            if state.options.snap_moved_node_to_connection {
+                commands.push(AppCommand::InsertMovedNodeIntoConnection);
+            }
+            commands
+        }
         AppIntent::BeginRotateSelectedNodesRequested => vec![AppCommand::BeginRotateSelectedNodes],
         AppIntent::RotateSelectedNodesRequested { delta_angle } => {
             vec![AppCommand::RotateSelectedNodes { delta_angle }]
         }
+        AppIntent::TransformSelectionRequested {
+            pivot,
+            angle,
+            scale,
+        } => vec![AppCommand::TransformSelectedNodes {
+            pivot,
+            angle,
+            scale,
+        }],
         AppIntent::EndRotateSelectedNodesRequested => vec![AppCommand::EndRotateSelectedNodes],
         AppIntent::ClearSelectionRequested => vec![AppCommand::ClearSelection],
         AppIntent::SelectAllRequested => vec![AppCommand::SelectAllNodes],
-        AppIntent::InvertSelectionRequested => vec![AppCommand::InvertSelection],
+        AppIntent::InvertSelectionRequested => vec![AppCommand::InvertSelection {
+            visible_only: state.options.invert_selection_visible_only,
+        }],
+        AppIntent::GrowSelectionRequested => vec![AppCommand::GrowSelection],
+        AppIntent::ShrinkSelectionRequested => vec![AppCommand::ShrinkSelection],
+        AppIntent::SelectPathBetweenSelectedRequested => {
+            vec![AppCommand::SelectPathBetweenSelected]
+        }
+        AppIntent::SelectByConnectionPriorityRequested { priority } => {
+            vec![AppCommand::SelectNodesByConnectionPriority { priority }]
+        }
+        AppIntent::SetSelectionLockedRequested { locked } => {
+            vec![AppCommand::SetSelectionLocked { locked }]
+        }
         other => unreachable!("unerwarteter Selection-Intent: {other:?}"),
     }
 }
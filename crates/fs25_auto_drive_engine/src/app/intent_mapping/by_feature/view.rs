@@ -1,6 +1,6 @@
 //! Intent-Mapping fuer Kamera, Viewport und Background-/Overview-Features.
 
-use crate::app::{AppCommand, AppIntent, AppState};
+use crate::app::{AppCommand, AppIntent, AppState, EditorTool};
 
 /// Mappt View-Intents auf Commands.
 pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
@@ -21,6 +21,7 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
         AppIntent::RenderQualityChanged { quality } => {
             vec![AppCommand::SetRenderQuality { quality }]
         }
+        AppIntent::ToggleIsolateSelectionRequested => vec![AppCommand::ToggleIsolateSelection],
         AppIntent::BackgroundMapSelectionRequested => vec![AppCommand::RequestBackgroundMapDialog],
         AppIntent::BackgroundMapSelected { path, crop_size } => {
             vec![AppCommand::LoadBackgroundMap { path, crop_size }]
@@ -30,6 +31,9 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
             vec![AppCommand::SetBackgroundLayerVisibility { layer, visible }]
         }
         AppIntent::ScaleBackground { factor } => vec![AppCommand::ScaleBackground { factor }],
+        AppIntent::BackgroundSourceSelected { source } => {
+            vec![AppCommand::SetBackgroundSource { source }]
+        }
         AppIntent::ZipBackgroundBrowseRequested { path } => {
             vec![AppCommand::BrowseZipBackground { path }]
         }
@@ -61,6 +65,16 @@ pub(super) fn map(state: &AppState, intent: AppIntent) -> Vec<AppCommand> {
         AppIntent::SaveBackgroundAsOverviewDismissed => vec![AppCommand::DismissSaveOverviewDialog],
         AppIntent::ZoomToFitRequested => vec![AppCommand::ZoomToFit],
         AppIntent::ZoomToSelectionBoundsRequested => vec![AppCommand::ZoomToSelectionBounds],
+        AppIntent::MeasurePointAdded { world_pos } => {
+            vec![AppCommand::AddMeasurePoint { world_pos }]
+        }
+        // Erstes Esc verwirft die Messlinie, ein weiteres verlaesst das Mess-Werkzeug
+        AppIntent::MeasureClearRequested if state.editor.measure_points.is_empty() => {
+            vec![AppCommand::SetEditorTool {
+                tool: EditorTool::Select,
+            }]
+        }
+        AppIntent::MeasureClearRequested => vec![AppCommand::ClearMeasurePoints],
         other => unreachable!("unerwarteter View-Intent: {other:?}"),
     }
 }
@@ -1,6 +1,7 @@
 use crate::app::ui_contract::{ParkingPanelAction, RouteToolPanelAction};
-use crate::app::{AppCommand, AppIntent, AppState};
-use crate::core::ConnectionDirection;
+use crate::app::use_cases::editing::{NodeAlignMode, NodeHeightEdit};
+use crate::app::{AppCommand, AppIntent, AppState, EditorTool};
+use crate::core::{ConnectionDirection, ConnectionPriority};
 
 use super::map_intent_to_commands;
 
@@ -116,6 +117,46 @@ fn editing_dialog_request_intents_map_to_dialog_commands() {
     assert_eq!(commands.len(), 1);
     assert!(matches!(commands[0], AppCommand::OpenTraceAllFieldsDialog));
 
+    let commands = map_intent_to_commands(&state, AppIntent::OpenFieldCourseDialogRequested);
+    assert_eq!(commands.len(), 1);
+    assert!(matches!(commands[0], AppCommand::OpenFieldCourseDialog));
+
+    let commands = map_intent_to_commands(&state, AppIntent::OpenMoveSelectionDialogRequested);
+    assert_eq!(commands.len(), 1);
+    assert!(matches!(commands[0], AppCommand::OpenMoveSelectionDialog));
+
+    let commands = map_intent_to_commands(&state, AppIntent::OpenDeleteByFilterDialogRequested);
+    assert_eq!(commands.len(), 1);
+    assert!(matches!(commands[0], AppCommand::OpenDeleteByFilterDialog));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::MoveSelectionConfirmed {
+            dx: 2.5,
+            dy: 0.0,
+            dz: -1.0,
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [
+            AppCommand::CloseMoveSelectionDialog,
+            AppCommand::MoveSelectedNodesBy { .. }
+        ]
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::NudgeSelectionRequested {
+            delta: glam::Vec2::new(2.5, -1.0),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::MoveSelectedNodesBy { delta_world, delta_height }]
+            if *delta_world == glam::Vec2::new(2.5, -1.0) && *delta_height == 0.0
+    ));
+
     let commands = map_intent_to_commands(&state, AppIntent::CurseplayImportRequested);
     assert_eq!(commands.len(), 1);
     assert!(matches!(
@@ -123,3 +164,605 @@ fn editing_dialog_request_intents_map_to_dialog_commands() {
         AppCommand::RequestCurseplayImportDialog
     ));
 }
+
+#[test]
+fn live_link_intents_map_to_live_link_commands() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::LivePlayerPoseReceived {
+            position: glam::Vec2::new(3.0, 4.0),
+            yaw: 0.5,
+        },
+    );
+    assert_eq!(commands.len(), 1);
+    assert!(matches!(
+        commands[0],
+        AppCommand::UpdateLivePlayerPose { position, yaw }
+            if position == glam::Vec2::new(3.0, 4.0) && yaw == 0.5
+    ));
+
+    let commands = map_intent_to_commands(&state, AppIntent::LiveCourseRecordingToggled);
+    assert_eq!(commands.len(), 1);
+    assert!(matches!(commands[0], AppCommand::ToggleLiveCourseRecording));
+
+    let commands = map_intent_to_commands(&state, AppIntent::LiveNetworkPushRequested);
+    assert_eq!(commands.len(), 1);
+    assert!(matches!(commands[0], AppCommand::PushNetworkToGame));
+}
+
+#[test]
+fn routes_manager_intents_map_to_route_commands() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::RoutesDialogRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::OpenRoutesDialog]
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::StoredRouteImportRequested {
+            file_name: "Hof.xml".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ImportStoredRoute { file_name }] if file_name == "Hof.xml"
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::StoredRouteExportRequested {
+            name: "Hof".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ExportStoredRoute { name }] if name == "Hof"
+    ));
+
+    let commands = map_intent_to_commands(&state, AppIntent::RoutesDialogCancelled);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::CloseRoutesDialog]
+    ));
+}
+
+#[test]
+fn map_mod_export_path_selections_map_to_export_commands() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::MapModZipSelected {
+            path: "/mods/FS25_Riverbend.zip".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ExportIntoMapModZip { path }] if path == "/mods/FS25_Riverbend.zip"
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::CoursesModExportPathSelected {
+            path: "/tmp/FS25_AutoDrive_Riverbend.zip".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ExportCoursesMod { path }] if path == "/tmp/FS25_AutoDrive_Riverbend.zip"
+    ));
+}
+
+#[test]
+fn pdf_export_intents_map_to_dialog_and_export_commands() {
+    let state = AppState::new();
+    let options = crate::shared::PdfExportOptions {
+        scale_denominator: 2000,
+        ..Default::default()
+    };
+
+    let commands = map_intent_to_commands(&state, AppIntent::PdfExportConfirmed { options });
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::RequestPdfExportPath { options }] if options.scale_denominator == 2000
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::PdfExportPathSelected {
+            path: "/tmp/Riverbend.pdf".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ExportMapPdf { path }] if path == "/tmp/Riverbend.pdf"
+    ));
+
+    let commands = map_intent_to_commands(&state, AppIntent::PdfExportCancelled);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ClosePdfExportDialog]
+    ));
+}
+
+#[test]
+fn savegame_picker_intents_close_picker_and_load_config() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::SavegamePickerRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::OpenSavegamePicker]
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::SavegameOpenRequested {
+            path: "/fs25/savegame1/AutoDrive_config.xml".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::CloseSavegamePicker, AppCommand::LoadFile { path }]
+            if path == "/fs25/savegame1/AutoDrive_config.xml"
+    ));
+
+    let commands = map_intent_to_commands(&state, AppIntent::SavegamePickerCancelled);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::CloseSavegamePicker]
+    ));
+}
+
+#[test]
+fn grow_and_shrink_selection_intents_map_to_selection_commands() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::GrowSelectionRequested);
+    assert!(matches!(commands.as_slice(), [AppCommand::GrowSelection]));
+
+    let commands = map_intent_to_commands(&state, AppIntent::ShrinkSelectionRequested);
+    assert!(matches!(commands.as_slice(), [AppCommand::ShrinkSelection]));
+}
+
+#[test]
+fn select_path_between_intent_maps_to_selection_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::SelectPathBetweenSelectedRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SelectPathBetweenSelected]
+    ));
+}
+
+#[test]
+fn set_selection_locked_intent_maps_to_selection_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::SetSelectionLockedRequested { locked: true },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SetSelectionLocked { locked: true }]
+    ));
+}
+
+#[test]
+fn select_by_connection_priority_intent_maps_to_selection_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::SelectByConnectionPriorityRequested {
+            priority: ConnectionPriority::SubPriority,
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SelectNodesByConnectionPriority {
+            priority: ConnectionPriority::SubPriority
+        }]
+    ));
+}
+
+#[test]
+fn set_selected_node_heights_intent_maps_to_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::SetSelectedNodeHeightsRequested {
+            edit: NodeHeightEdit::Offset(1.5),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SetSelectedNodeHeights {
+            edit: NodeHeightEdit::Offset(delta)
+        }] if *delta == 1.5
+    ));
+}
+
+#[test]
+fn project_selection_onto_terrain_intent_maps_to_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::ProjectSelectionOntoTerrainRequested { offset: 0.25 },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ProjectSelectionOntoTerrain { offset }] if *offset == 0.25
+    ));
+}
+
+#[test]
+fn path_offset_intents_map_to_preview_lifecycle_commands() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::PathOffsetPreviewRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::StartPathOffsetPreview]
+    ));
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::PathOffsetDistanceChanged { distance: -2.0 },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SetPathOffsetDistance { distance }] if *distance == -2.0
+    ));
+
+    let commands = map_intent_to_commands(&state, AppIntent::PathOffsetConfirmed);
+    assert!(matches!(commands.as_slice(), [AppCommand::ApplyPathOffset]));
+
+    let commands = map_intent_to_commands(&state, AppIntent::PathOffsetCancelled);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::CancelPathOffsetPreview]
+    ));
+}
+
+#[test]
+fn measure_clear_intent_leaves_tool_once_line_is_empty() {
+    let mut state = AppState::new();
+    state.editor.active_tool = EditorTool::Measure;
+    state.editor.measure_points.push(glam::Vec2::ZERO);
+
+    let commands = map_intent_to_commands(&state, AppIntent::MeasureClearRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ClearMeasurePoints]
+    ));
+
+    state.editor.measure_points.clear();
+    let commands = map_intent_to_commands(&state, AppIntent::MeasureClearRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SetEditorTool {
+            tool: EditorTool::Select
+        }]
+    ));
+}
+
+#[test]
+fn knife_cut_intent_uses_split_option() {
+    let mut state = AppState::new();
+    state.options.knife_split_connections = true;
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::KnifeCutRequested {
+            from: glam::Vec2::new(0.0, -5.0),
+            to: glam::Vec2::new(0.0, 5.0),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::CutConnectionsAlongLine { split: true, .. }]
+    ));
+}
+
+#[test]
+fn end_move_inserts_into_connection_only_with_option() {
+    let mut state = AppState::new();
+    state.options.snap_moved_node_to_connection = true;
+    let commands = map_intent_to_commands(&state, AppIntent::EndMoveSelectedNodesRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [
+            AppCommand::EndMoveSelectedNodes,
+            AppCommand::InsertMovedNodeIntoConnection
+        ]
+    ));
+
+    state.options.snap_moved_node_to_connection = false;
+    let commands = map_intent_to_commands(&state, AppIntent::EndMoveSelectedNodesRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::EndMoveSelectedNodes]
+    ));
+}
+
+#[test]
+fn roundabout_intent_uses_radius_from_options() {
+    let mut state = AppState::new();
+    state.options.roundabout_radius_m = 18.0;
+
+    let commands = map_intent_to_commands(&state, AppIntent::ConvertJunctionToRoundaboutRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ConvertJunctionToRoundabout { radius }] if *radius == 18.0
+    ));
+}
+
+#[test]
+fn connection_swipe_intent_maps_to_swipe_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::ConnectionSwipeRequested {
+            from: glam::Vec2::new(1.0, 0.0),
+            to: glam::Vec2::new(4.0, 0.0),
+            toggle_dual: true,
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SwipeConnectionDirection {
+            from,
+            to,
+            toggle_dual: true,
+        }] if *from == glam::Vec2::new(1.0, 0.0) && *to == glam::Vec2::new(4.0, 0.0)
+    ));
+}
+
+#[test]
+fn invert_selection_intent_uses_visible_only_option() {
+    let mut state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::InvertSelectionRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::InvertSelection {
+            visible_only: false
+        }]
+    ));
+
+    state.options.invert_selection_visible_only = true;
+    let commands = map_intent_to_commands(&state, AppIntent::InvertSelectionRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::InvertSelection { visible_only: true }]
+    ));
+}
+
+#[test]
+fn toggle_isolate_selection_intent_maps_to_view_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::ToggleIsolateSelectionRequested);
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ToggleIsolateSelection]
+    ));
+}
+
+#[test]
+fn select_connected_intent_uses_hitbox_and_follow_direction_option() {
+    let mut state = AppState::new();
+    state.options.flood_select_follow_direction = true;
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::SelectConnectedRequested {
+            world_pos: glam::Vec2::new(3.0, 4.0),
+            additive: true,
+        },
+    );
+
+    match commands.as_slice() {
+        [AppCommand::SelectConnectedNodes {
+            world_pos,
+            max_distance,
+            additive,
+            follow_direction,
+        }] => {
+            assert_eq!(*world_pos, glam::Vec2::new(3.0, 4.0));
+            assert_eq!(*max_distance, state.options.hitbox_radius());
+            assert!(*additive);
+            assert!(*follow_direction);
+        }
+        other => panic!("unerwartete Commands: {other:?}"),
+    }
+}
+
+#[test]
+fn paste_rotate_intent_maps_to_signed_rotation_step() {
+    let state = AppState::new();
+    let step = crate::app::use_cases::editing::PASTE_PREVIEW_ROTATION_STEP_DEG.to_radians();
+
+    let clockwise = map_intent_to_commands(
+        &state,
+        AppIntent::PastePreviewRotateRequested {
+            counter_clockwise: false,
+        },
+    );
+    assert!(matches!(
+        clockwise.as_slice(),
+        [AppCommand::RotatePastePreview { delta_rad }] if *delta_rad == step
+    ));
+
+    let counter_clockwise = map_intent_to_commands(
+        &state,
+        AppIntent::PastePreviewRotateRequested {
+            counter_clockwise: true,
+        },
+    );
+    assert!(matches!(
+        counter_clockwise.as_slice(),
+        [AppCommand::RotatePastePreview { delta_rad }] if *delta_rad == -step
+    ));
+}
+
+#[test]
+fn paste_from_text_intent_forwards_clipboard_text() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::PasteFromTextRequested {
+            text: "{}".to_string(),
+        },
+    );
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::PasteFromText { text }] if text == "{}"
+    ));
+}
+
+#[test]
+fn transform_selection_intent_maps_to_transform_command() {
+    let state = AppState::new();
+    let pivot = glam::Vec2::new(3.0, -2.0);
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::TransformSelectionRequested {
+            pivot,
+            angle: 0.25,
+            scale: 1.5,
+        },
+    );
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::TransformSelectedNodes { pivot: p, angle, scale }]
+            if *p == pivot && *angle == 0.25 && *scale == 1.5
+    ));
+}
+
+#[test]
+fn align_and_distribute_intents_map_to_editing_commands() {
+    let state = AppState::new();
+
+    let align = map_intent_to_commands(
+        &state,
+        AppIntent::AlignSelectedRequested {
+            mode: NodeAlignMode::BestFitLine,
+        },
+    );
+    assert!(matches!(
+        align.as_slice(),
+        [AppCommand::AlignSelectedNodes {
+            mode: NodeAlignMode::BestFitLine
+        }]
+    ));
+
+    let distribute = map_intent_to_commands(&state, AppIntent::DistributeSelectedRequested);
+    assert!(matches!(
+        distribute.as_slice(),
+        [AppCommand::DistributeSelectedNodes]
+    ));
+}
+
+#[test]
+fn reverse_chain_intent_maps_to_reverse_selected_chain_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::ReverseChainRequested);
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ReverseSelectedChain]
+    ));
+}
+
+#[test]
+fn merge_selected_nodes_intent_maps_to_merge_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(&state, AppIntent::MergeSelectedNodesRequested);
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::MergeSelectedNodes]
+    ));
+}
+
+#[test]
+fn resample_chain_intent_carries_spacing() {
+    let state = AppState::new();
+
+    let commands =
+        map_intent_to_commands(&state, AppIntent::ResampleChainRequested { spacing: 4.5 });
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::ResampleSelectedChain { spacing }] if *spacing == 4.5
+    ));
+}
+
+#[test]
+fn smooth_chain_intent_carries_strength_and_iterations() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::SmoothChainRequested {
+            strength: 0.4,
+            iterations: 8,
+        },
+    );
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SmoothSelectedChain { strength, iterations: 8 }] if *strength == 0.4
+    ));
+}
+
+#[test]
+fn invert_connection_intent_maps_to_single_connection_command() {
+    let state = AppState::new();
+
+    let commands = map_intent_to_commands(
+        &state,
+        AppIntent::InvertConnectionRequested {
+            start_id: 4,
+            end_id: 9,
+        },
+    );
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::InvertConnection {
+            start_id: 4,
+            end_id: 9
+        }]
+    ));
+}
+
+#[test]
+fn simplify_chain_intent_carries_tolerance() {
+    let state = AppState::new();
+
+    let commands =
+        map_intent_to_commands(&state, AppIntent::SimplifyChainRequested { tolerance: 0.3 });
+
+    assert!(matches!(
+        commands.as_slice(),
+        [AppCommand::SimplifySelectedChain { tolerance }] if *tolerance == 0.3
+    ));
+}
@@ -53,10 +53,13 @@ pub use render_assets::build as build_render_assets;
 pub use render_scene::build as build_render_scene;
 pub use state::{
     AppState, BackgroundLayerCatalog, BackgroundLayerFiles, Clipboard, DedupDialogState,
-    EditorTool, EditorToolState, EngineUiState, FloatingMenuKind, FloatingMenuState,
-    GroupEditState, GroupSettingsPopupState, MarkerDialogState, OverviewOptionsDialogState,
-    OverviewSourceContext, PendingOverviewBundle, PostLoadDialogState, SaveOverviewDialogState,
-    SelectionState, StoredBackgroundLayer, TraceAllFieldsDialogState, ViewState, ZipBrowserState,
+    DeleteByFilterDialogState, EditorTool, EditorToolState, EngineUiState, FieldCourseDialogState,
+    FloatingMenuKind, FloatingMenuState, GroupEditState, GroupSettingsPopupState, LiveLinkState,
+    LivePlayerPose, MarkerDialogState, MoveSelectionDialogState, NodeDeleteCandidate,
+    NodeDeleteFilter, OverviewOptionsDialogState, OverviewSourceContext, PdfExportDialogState,
+    PendingOverviewBundle, PostLoadDialogState, RoutesDialogState, SaveOverviewDialogState,
+    SavegamePickerState, SelectionState, StoredBackgroundLayer, TraceAllFieldsDialogState,
+    ViewState, ZipBrowserState,
 };
 pub use tool_editing::ToolEditStore;
 pub use tools::field_boundary::compute_ring;
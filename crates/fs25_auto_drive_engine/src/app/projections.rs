@@ -7,15 +7,18 @@ use glam::Vec2;
 
 use super::tool_editing::RouteToolEditPayload;
 use super::ui_contract::{
-    CommandPalettePanelState, HostUiSnapshot, OptionsPanelState, PanelState, RouteToolConfigState,
-    ViewportOverlaySnapshot,
+    CommandPalettePanelState, HostUiSnapshot, OptionsPanelState, PanelState, RouteElevationProfile,
+    RouteToolConfigState, ViewportOverlaySnapshot,
 };
+use super::use_cases::heightmap::effective_height_scale;
 use super::{render_assets, render_scene, viewport_overlay, AppState};
 use crate::shared::{RenderAssetsSnapshot, RenderScene};
 
 const ROUNDING_RESTORE_EPSILON: f32 = 1e-3;
 const ROUNDING_MIN_CORNER_ANGLE_RAD: f32 = 5.0_f32.to_radians();
 const ROUNDING_MAX_CORNER_ANGLE_RAD: f32 = std::f32::consts::PI - 5.0_f32.to_radians();
+/// Kuerzere Vorschau-Verbindungen gehen nicht in die Steigung ein (Rundungsrauschen).
+const GRADE_MIN_RUN_M: f32 = 0.1;
 
 fn restored_rounding_preview_node_count(state: &AppState, max_angle_deg: f32) -> Option<usize> {
     let road_map = state.road_map.as_deref()?;
@@ -56,6 +59,55 @@ fn restored_rounding_preview_node_count(state: &AppState, max_angle_deg: f32) ->
     Some(segment_count + 1)
 }
 
+/// Berechnet das Hoehenprofil der Route-Tool-Vorschau ueber der geladenen Heightmap.
+///
+/// Die Steigung wird pro Vorschau-Verbindung bestimmt, damit auch Tools ohne
+/// durchgehende Node-Kette (z.B. Parkplatz) korrekt gewarnt werden.
+fn route_elevation_profile(state: &AppState) -> Option<RouteElevationProfile> {
+    let heightmap = state.heightmap.as_deref()?;
+    let road_map = state.road_map.as_deref()?;
+    let preview = state.editor.route_tool_preview(Vec2::ZERO, road_map)?;
+    if preview.nodes.len() < 2 {
+        return None;
+    }
+
+    let height_scale = effective_height_scale(state, Some(heightmap));
+    let heights: Vec<f32> = preview
+        .nodes
+        .iter()
+        .map(|pos| heightmap.sample_height(pos.x, pos.y, height_scale))
+        .collect();
+
+    let mut distance = 0.0;
+    let points = preview
+        .nodes
+        .iter()
+        .zip(&heights)
+        .enumerate()
+        .map(|(index, (pos, &height))| {
+            if index > 0 {
+                distance += pos.distance(preview.nodes[index - 1]);
+            }
+            [distance, height]
+        })
+        .collect();
+
+    let max_grade_percent = preview
+        .connections
+        .iter()
+        .filter_map(|&(from, to)| {
+            let run = preview.nodes.get(from)?.distance(*preview.nodes.get(to)?);
+            (run >= GRADE_MIN_RUN_M).then(|| (heights[to] - heights[from]).abs() / run * 100.0)
+        })
+        .fold(0.0, f32::max);
+
+    Some(RouteElevationProfile {
+        points,
+        max_grade_percent,
+        grade_limit_percent: state.options.max_route_grade_percent,
+    })
+}
+
 /// Baut die Render-Szene aus dem aktuellen AppState.
 pub fn build_render_scene(state: &AppState, viewport_size: [f32; 2]) -> RenderScene {
     render_scene::build(state, viewport_size)
@@ -106,6 +158,10 @@ pub fn build_host_ui_snapshot(state: &AppState) -> HostUiSnapshot {
             });
         }
 
+        if route_tool_panel.can_execute {
+            route_tool_panel.elevation_profile = route_elevation_profile(state);
+        }
+
         panels.push(PanelState::RouteTool(Box::new(route_tool_panel)));
     }
 
@@ -129,9 +185,11 @@ mod tests {
     use crate::app::group_registry::GroupRecord;
     use crate::app::tool_contract::RouteToolId;
     use crate::app::tool_editing::{ActiveToolEditSession, RouteToolEditPayload, ToolEditRecord};
-    use crate::app::AppState;
-    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use crate::app::tools::ToolAction;
+    use crate::app::{AppState, EditorTool};
+    use crate::core::{Heightmap, MapNode, NodeFlag, RoadMap, WorldBounds};
     use glam::Vec2;
+    use image::{DynamicImage, GrayImage, Luma};
     use std::sync::Arc;
 
     /// Prüft, dass build_host_ui_snapshot immer das CommandPalette-Panel enthält.
@@ -223,4 +281,66 @@ mod tests {
 
         assert_eq!(restored_rounding_preview_node_count(&state, 30.0), Some(4));
     }
+
+    #[test]
+    fn route_tool_panel_reports_elevation_profile_and_grade_warning() {
+        // 9x9 Pixel ueber -4..4 m, Anstieg 20 Grauwerte pro Meter in X
+        let image = GrayImage::from_fn(9, 9, |x, _| Luma([(x * 20) as u8]));
+        let heightmap = Heightmap::from_image(
+            DynamicImage::ImageLuma8(image),
+            WorldBounds::from_map_size(8.0),
+        )
+        .expect("Heightmap erwartet");
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(RoadMap::new(2)));
+        // 20 Grauwerte * 2.55 / 255 = 0.2 m Anstieg pro Meter → 20 %
+        state.options.terrain_height_scale = 2.55;
+        state.editor.active_tool = EditorTool::Route;
+        state
+            .editor
+            .tool_manager
+            .set_active_by_id(RouteToolId::Straight);
+
+        let road_map = RoadMap::new(2);
+        let tool = state
+            .editor
+            .tool_manager
+            .active_tool_mut()
+            .expect("Gerade Strecke muss aktiv sein");
+        tool.on_click(Vec2::new(-3.0, 0.0), &road_map, false);
+        assert_eq!(
+            tool.on_click(Vec2::new(3.0, 0.0), &road_map, false),
+            ToolAction::ReadyToExecute
+        );
+
+        // Ohne Heightmap kein Profil
+        let snapshot = build_host_ui_snapshot(&state);
+        let panel = snapshot
+            .route_tool_panel_state()
+            .expect("Route-Panel erwartet");
+        assert!(panel.elevation_profile.is_none());
+
+        state.heightmap = Some(Arc::new(heightmap));
+        let snapshot = build_host_ui_snapshot(&state);
+        let profile = snapshot
+            .route_tool_panel_state()
+            .and_then(|panel| panel.elevation_profile.clone())
+            .expect("Hoehenprofil erwartet");
+
+        let first = profile.points.first().expect("Startpunkt");
+        let last = profile.points.last().expect("Endpunkt");
+        assert!(first[0].abs() < 1e-4 && (first[1] - 0.2).abs() < 1e-3);
+        assert!((last[0] - 6.0).abs() < 1e-3 && (last[1] - 1.4).abs() < 1e-3);
+        assert!((profile.max_grade_percent - 20.0).abs() < 0.1);
+        assert!(profile.exceeds_limit());
+
+        state.options.max_route_grade_percent = 25.0;
+        let snapshot = build_host_ui_snapshot(&state);
+        let profile = snapshot
+            .route_tool_panel_state()
+            .and_then(|panel| panel.elevation_profile.clone())
+            .expect("Hoehenprofil erwartet");
+        assert!(!profile.exceeds_limit());
+    }
 }
@@ -8,13 +8,59 @@ use crate::app::{AppState, GroupRegistry};
 use crate::core::{ConnectionDirection, ConnectionPriority, NodeFlag, RoadMap};
 use crate::shared::{
     RenderCamera, RenderConnection, RenderConnectionDirection, RenderConnectionPriority, RenderMap,
-    RenderMarker, RenderNode, RenderNodeKind, RenderScene, RenderSceneFrameData,
+    RenderMarker, RenderNode, RenderNodeKind, RenderQuality, RenderScene, RenderSceneFrameData,
 };
 use indexmap::IndexSet;
 use std::collections::HashMap;
 use std::mem::size_of;
 use std::sync::{Arc, OnceLock};
 
+/// Vergleichsschluessel aller Eingaben, aus denen eine `RenderScene` entsteht.
+///
+/// Gleitkommawerte werden bitweise verglichen, damit der Schluessel `Eq` bleibt;
+/// die Optionen werden ueber die Adresse des geteilten Arcs identifiziert, da
+/// `set_options()` bei jeder Aenderung einen neuen Arc erzeugt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RenderSceneCacheKey {
+    map: Option<(u64, u64)>,
+    selection_generation: u64,
+    hide_original: bool,
+    isolation_revision: u64,
+    dimmed_generation: u64,
+    camera: [u32; 3],
+    viewport_size: [u32; 2],
+    render_quality: RenderQuality,
+    has_background: bool,
+    background_visible: bool,
+    options_ptr: usize,
+}
+
+impl RenderSceneCacheKey {
+    fn from_state(state: &AppState, viewport_size: [f32; 2]) -> Self {
+        let camera = &state.view.camera;
+        Self {
+            map: state
+                .road_map
+                .as_deref()
+                .map(|road_map| road_map.render_cache_key()),
+            selection_generation: state.selection.generation,
+            hide_original: state.ui.distanzen.should_hide_original(),
+            isolation_revision: state.view.isolation_revision,
+            dimmed_generation: state.group_registry.dimmed_generation,
+            camera: [
+                camera.position.x.to_bits(),
+                camera.position.y.to_bits(),
+                camera.zoom.to_bits(),
+            ],
+            viewport_size: [viewport_size[0].to_bits(), viewport_size[1].to_bits()],
+            render_quality: state.view.render_quality,
+            has_background: state.view.background_map.is_some(),
+            background_visible: state.view.background_visible,
+            options_ptr: Arc::as_ptr(&state.options_arc()) as usize,
+        }
+    }
+}
+
 /// Gibt einen Arc auf eine leere, statisch initialisierte `IndexSet<u64>` zurueck.
 ///
 /// Verhindert eine Heap-Allokation pro Frame, wenn kein Node ausgeblendet werden soll.
@@ -57,9 +103,10 @@ fn compute_dimmed_ids(
     }
 }
 
-fn build_render_map_snapshot(road_map: &RoadMap) -> RenderMap {
+fn build_render_map_snapshot(road_map: &RoadMap, previous: Option<&RenderMap>) -> RenderMap {
     let mut nodes = HashMap::with_capacity(road_map.node_count());
-    for (&id, node) in road_map.nodes() {
+    for node in road_map.nodes().iter() {
+        let id = node.id;
         let kind = match node.flag {
             NodeFlag::SubPrio => RenderNodeKind::SubPrio,
             NodeFlag::Warning => RenderNodeKind::Warning,
@@ -113,7 +160,10 @@ fn build_render_map_snapshot(road_map: &RoadMap) -> RenderMap {
         }
     }
 
-    RenderMap::new(nodes, connections, markers)
+    match previous {
+        Some(previous) => RenderMap::new_incremental(previous, nodes, connections, markers),
+        None => RenderMap::new(nodes, connections, markers),
+    }
 }
 
 fn estimate_render_snapshot_bytes(snapshot: &RenderMap) -> usize {
@@ -134,14 +184,21 @@ fn render_map_snapshot(state: &AppState) -> Option<Arc<RenderMap>> {
         {
             Some(Arc::clone(snapshot))
         }
-        _ => {
-            let snapshot = Arc::new(build_render_map_snapshot(road_map));
+        cached => {
+            // Nur Snapshots derselben Karteninstanz dienen als Vorgaenger, damit
+            // Layer-Revisionen nach einem Kartenwechsel garantiert neu vergeben werden.
+            let previous = cached
+                .filter(|(cached_instance_id, _, _)| *cached_instance_id == instance_id)
+                .map(|(_, _, snapshot)| snapshot.as_ref());
+            let snapshot = Arc::new(build_render_map_snapshot(road_map, previous));
+            let dirty = snapshot.dirty();
             log::debug!(
-                "RenderMap-Snapshot neu aufgebaut: nodes={}, connections={}, markers={}, approx_bytes={}",
+                "RenderMap-Snapshot neu aufgebaut: nodes={}, connections={}, markers={}, approx_bytes={}, dirty={:?}",
                 snapshot.node_count(),
                 snapshot.connection_count(),
                 snapshot.marker_count(),
-                estimate_render_snapshot_bytes(snapshot.as_ref())
+                estimate_render_snapshot_bytes(snapshot.as_ref()),
+                dirty
             );
             *cache = Some((instance_id, revision, Arc::clone(&snapshot)));
             Some(snapshot)
@@ -164,10 +221,15 @@ fn render_map_snapshot(state: &AppState) -> Option<Arc<RenderMap>> {
 ///
 /// - `hidden_node_ids` wird automatisch mit selektierten Nodes gefuellt,
 ///   wenn die Distanzen-Vorschau aktiv ist und "Original ausblenden" aktiviert wurde.
+///   Im Isolier-Modus enthaelt sie zusaetzlich alle beim Einschalten nicht selektierten Nodes.
 /// - `options_arc` ist ein Arc-Clone von `state.options_arc()` — das ermoeglicht
 ///   CoW-Updates ohne per-Frame Allokationen.
 /// - Der Karten-Snapshot wird ueber `RoadMap::render_cache_key()` lazy gecacht,
 ///   damit App und Render keinen Core-Typenvertrag mehr teilen muessen.
+/// - Die komplette Szene wird ueber einen [`RenderSceneCacheKey`] gecacht: Idle-Frames
+///   ohne Aenderung liefern nur einen Clone (Arc-Handles). Aendert sich nur ein Teil
+///   (z. B. die Kamera), bleiben Karten-Snapshot und Dimm-Menge in ihren eigenen
+///   Caches und werden ohne Neuaufbau wiederverwendet.
 ///
 /// # Parameter
 /// - `state` – Referenz zum aktuellen AppState
@@ -176,19 +238,44 @@ fn render_map_snapshot(state: &AppState) -> Option<Arc<RenderMap>> {
 /// # Rueckgabe
 /// Eine vollstaendige `RenderScene`, bereit zum Rendering.
 pub fn build(state: &AppState, viewport_size: [f32; 2]) -> RenderScene {
+    let key = RenderSceneCacheKey::from_state(state, viewport_size);
+    if let Some((cached_key, scene)) = state.render_scene_cache.borrow().as_ref()
+        && *cached_key == key
+    {
+        return scene.clone();
+    }
+
+    let scene = build_uncached(state, viewport_size);
+    *state.render_scene_cache.borrow_mut() = Some((key, scene.clone()));
+    scene
+}
+
+fn build_uncached(state: &AppState, viewport_size: [f32; 2]) -> RenderScene {
     // Arc einmal klonen — wiederverwendet fuer selected_node_ids UND hidden_node_ids
     let selected_arc = state.selection.selected_node_ids.clone();
     let selected_revision = state.selection.generation;
 
     // Wenn Distanzen-Vorschau aktiv + hide_original → selektierte Nodes ausblenden.
     // Statt nochmals zu klonen verwenden wir den gleichen Arc (billiger O(1)-Clone).
+    // Im Isolier-Modus kommt die eingefrorene Isolations-Menge hinzu.
     let hide_original = state.ui.distanzen.should_hide_original();
-    let hidden_node_ids = if hide_original {
-        Arc::clone(&selected_arc)
-    } else {
-        empty_hidden_ids()
+    let isolation = state.view.isolation_hidden_node_ids.as_ref();
+    let isolation_revision = state.view.isolation_revision;
+    let (hidden_node_ids, hidden_revision) = match (hide_original, isolation) {
+        (false, None) => (empty_hidden_ids(), 0),
+        (true, None) => (Arc::clone(&selected_arc), selected_revision),
+        (false, Some(isolated)) => (Arc::clone(isolated), isolation_revision),
+        (true, Some(isolated)) => {
+            let mut union = IndexSet::clone(isolated);
+            union.extend(selected_arc.iter().copied());
+            (
+                Arc::new(union),
+                selected_revision
+                    .rotate_left(1)
+                    .wrapping_add(isolation_revision),
+            )
+        }
     };
-    let hidden_revision = if hide_original { selected_revision } else { 0 };
 
     // Gedimmte Nodes: alle anderen Nodes des Segments wenn 1 Segment-Node selektiert.
     // Cache-Hit wenn weder Selektion noch Registry seit dem letzten Build geaendert haben.
@@ -235,6 +322,7 @@ mod tests {
     use crate::app::AppState;
     use crate::core::{MapNode, NodeFlag, RoadMap};
     use glam::Vec2;
+    use indexmap::IndexSet;
     use std::sync::Arc;
 
     fn make_map() -> RoadMap {
@@ -329,6 +417,91 @@ mod tests {
         assert_eq!(second.marker_count(), 1);
     }
 
+    #[test]
+    fn render_map_snapshot_keeps_unchanged_layer_revisions_after_move() {
+        let mut state = make_state();
+        editing::create_marker(&mut state, 2, "Hof", "All");
+        state.selection.ids_mut().insert(1);
+
+        let first = render_map_snapshot(&state).expect("Snapshot vorhanden");
+
+        selection::move_selected_nodes(&mut state, Vec2::new(5.0, 3.0));
+
+        let second = render_map_snapshot(&state).expect("Snapshot vorhanden");
+        let dirty = second.dirty();
+        assert!(dirty.nodes);
+        assert!(
+            !dirty.markers,
+            "Marker haengt an Node 2 und bleibt unveraendert"
+        );
+        assert_ne!(first.revisions().nodes, second.revisions().nodes);
+        assert_eq!(first.revisions().markers, second.revisions().markers);
+        assert_eq!(
+            first.revisions().connections,
+            second.revisions().connections
+        );
+    }
+
+    #[test]
+    fn render_map_snapshot_marks_only_markers_dirty_after_marker_creation() {
+        let mut state = make_state();
+        let first = render_map_snapshot(&state).expect("Snapshot vorhanden");
+
+        editing::create_marker(&mut state, 1, "Hof", "All");
+
+        let second = render_map_snapshot(&state).expect("Snapshot vorhanden");
+        assert_eq!(
+            second.revisions().dirty_since(&first.revisions()),
+            crate::shared::RenderMapDirty {
+                nodes: false,
+                connections: false,
+                markers: true,
+            }
+        );
+    }
+
+    #[test]
+    fn render_map_snapshot_assigns_fresh_revisions_for_new_road_map_instance() {
+        let mut state = make_state();
+        let first = render_map_snapshot(&state).expect("Snapshot vorhanden");
+
+        state.road_map = Some(Arc::new(make_map()));
+
+        let second = render_map_snapshot(&state).expect("Snapshot vorhanden");
+        assert_eq!(second.dirty(), crate::shared::RenderMapDirty::ALL);
+        assert!(second.revisions().dirty_since(&first.revisions()).any());
+    }
+
+    #[test]
+    fn build_render_scene_reuses_cached_scene_on_idle_frames() {
+        let state = make_state();
+
+        let first = super::build(&state, [1280.0, 720.0]);
+        let second = super::build(&state, [1280.0, 720.0]);
+
+        let (first_map, second_map) = (first.map().unwrap(), second.map().unwrap());
+        assert!(std::ptr::eq(first_map, second_map));
+        assert_eq!(
+            first.selected_node_ids_revision(),
+            second.selected_node_ids_revision()
+        );
+    }
+
+    #[test]
+    fn build_render_scene_invalidates_on_camera_change_but_keeps_map_snapshot() {
+        let mut state = make_state();
+
+        let first = super::build(&state, [1280.0, 720.0]);
+        state.view.camera.position = Vec2::new(42.0, -7.0);
+        let second = super::build(&state, [1280.0, 720.0]);
+
+        assert_eq!(second.camera().position, Vec2::new(42.0, -7.0));
+        assert!(std::ptr::eq(first.map().unwrap(), second.map().unwrap()));
+
+        let third = super::build(&state, [800.0, 600.0]);
+        assert_eq!(third.viewport_size(), [800.0, 600.0]);
+    }
+
     #[test]
     fn build_render_scene_increments_selection_revision_after_selection_mutation() {
         let mut state = make_state();
@@ -364,4 +537,23 @@ mod tests {
         let third = super::build(&state, [1280.0, 720.0]);
         assert_eq!(third.hidden_node_ids_revision(), 0);
     }
+
+    #[test]
+    fn build_render_scene_hides_isolated_nodes_until_isolation_ends() {
+        let mut state = make_state();
+        state.selection.ids_mut().insert(1);
+        state
+            .view
+            .set_isolation(Some(Arc::new(IndexSet::from_iter([2_u64]))));
+
+        let isolated = super::build(&state, [1280.0, 720.0]);
+        assert!(isolated.hidden_node_ids().contains(&2));
+        assert!(!isolated.hidden_node_ids().contains(&1));
+        assert!(isolated.hidden_node_ids_revision() > 0);
+
+        state.view.clear_isolation();
+        let released = super::build(&state, [1280.0, 720.0]);
+        assert!(released.hidden_node_ids().is_empty());
+        assert_eq!(released.hidden_node_ids_revision(), 0);
+    }
 }
@@ -4,19 +4,23 @@ mod app_state;
 mod background_layers;
 mod dialogs;
 mod editor;
+mod live_link;
 mod selection;
 mod view;
 
 pub use crate::shared::{
-    DedupDialogState, DistanzenState, FloatingMenuKind, FloatingMenuState, GroupSettingsPopupState,
-    MarkerDialogState, OverviewOptionsDialogState, OverviewSourceContext, PostLoadDialogState,
+    DedupDialogState, DeleteByFilterDialogState, DistanzenState, FieldCourseDialogState,
+    FloatingMenuKind, FloatingMenuState, GroupSettingsPopupState, MarkerDialogState,
+    MoveSelectionDialogState, NodeDeleteCandidate, NodeDeleteFilter, OverviewOptionsDialogState,
+    OverviewSourceContext, PathOffsetState, PdfExportDialogState, PostLoadDialogState,
     SaveOverviewDialogState, TraceAllFieldsDialogState,
 };
 pub use app_state::{AppState, Clipboard, GroupEditState};
 pub use background_layers::{
     BackgroundLayerCatalog, BackgroundLayerFiles, PendingOverviewBundle, StoredBackgroundLayer,
 };
-pub use dialogs::{EngineUiState, ZipBrowserState};
+pub use dialogs::{EngineUiState, RoutesDialogState, SavegamePickerState, ZipBrowserState};
 pub use editor::{EditorTool, EditorToolState};
+pub use live_link::{LiveLinkState, LivePlayerPose};
 pub use selection::SelectionState;
 pub use view::ViewState;
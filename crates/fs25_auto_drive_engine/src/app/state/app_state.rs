@@ -2,16 +2,21 @@ use crate::app::group_registry::GroupRegistry;
 use crate::app::history::Snapshot;
 use crate::app::tool_contract::RouteToolId;
 use crate::app::tool_editing::{ActiveToolEditSession, ToolEditStore};
+use crate::app::ui_contract::{OwnedFarmlandOverlaySnapshot, TerrainClippingOverlaySnapshot};
 use crate::app::CommandLog;
-use crate::core::{Connection, FarmlandGrid, FieldPolygon, MapMarker, MapNode, RoadMap};
-use crate::shared::{EditorOptions, RenderMap};
+use crate::core::{
+    Connection, FarmlandGrid, FieldPolygon, Heightmap, MapMarker, MapNode, RoadMap, SavegameVehicle,
+};
+use crate::shared::{EditorOptions, RenderMap, RenderScene};
+use fs25_map_overview::FarmlandOwnership;
 use glam::Vec2;
 use indexmap::IndexSet;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use super::background_layers::{BackgroundLayerCatalog, PendingOverviewBundle};
-use super::{EditorTool, EditorToolState, EngineUiState, SelectionState, ViewState};
+use super::{EditorTool, EditorToolState, EngineUiState, LiveLinkState, SelectionState, ViewState};
 
 /// Zwischenablage fuer Nodes, Verbindungen und Marker
 #[derive(Debug, Clone, Default)]
@@ -26,6 +31,14 @@ pub struct Clipboard {
     pub center: Vec2,
 }
 
+impl Clipboard {
+    /// Zielposition eines kopierten Nodes, wenn das Zentrum auf `target` liegt
+    /// und die Kopie um `rotation` (Radiant) um das Zentrum gedreht wird.
+    pub fn placed_position(&self, node_position: Vec2, target: Vec2, rotation: f32) -> Vec2 {
+        target + Vec2::from_angle(rotation).rotate(node_position - self.center)
+    }
+}
+
 /// Zustand einer aktiven Gruppen-Bearbeitung.
 ///
 /// Wird in `AppState::group_editing` gespeichert. `None` = Normal-Modus.
@@ -47,6 +60,27 @@ type DimmedIdsCache = Option<(u64, u64, Arc<IndexSet<u64>>)>;
 /// Tuple: `(render_instance_id, render_revision, gecachter_RenderMap_Snapshot)`.
 type RenderMapCache = Option<(u64, u64, Arc<RenderMap>)>;
 
+/// Cache-Eintrag fuer die zuletzt gebaute Render-Szene.
+///
+/// Tuple: `(Cache-Schluessel aller Szenen-Eingaben, gecachte RenderScene)`.
+type RenderSceneCache = Option<(crate::app::render_scene::RenderSceneCacheKey, RenderScene)>;
+
+/// Cache-Eintrag fuer die Terrain-Warnungen im Viewport-Overlay.
+///
+/// Tuple: `(Schluessel aus Karte, Heightmap, Hoehenskala und Toleranz, Warnungen)`.
+type TerrainClippingCache = Option<(
+    crate::app::viewport_overlay::TerrainClippingCacheKey,
+    Vec<TerrainClippingOverlaySnapshot>,
+)>;
+
+/// Cache-Eintrag fuer die getoenten Farmlands im Viewport-Overlay.
+///
+/// Tuple: `(Schluessel aus Farmland-Polygonen und Besitzverhaeltnissen, Flaechen)`.
+type OwnedFarmlandCache = Option<(
+    crate::app::viewport_overlay::OwnedFarmlandCacheKey,
+    Vec<OwnedFarmlandOverlaySnapshot>,
+)>;
+
 /// Hauptzustand der Anwendung
 pub struct AppState {
     /// Aktuell geladene RoadMap (None = keine Datei geladen)
@@ -63,6 +97,10 @@ pub struct AppState {
     pub clipboard: Clipboard,
     /// Aktuelle Vorschau-Position beim Einfuegen
     pub paste_preview_pos: Option<Vec2>,
+    /// Drehung der Einfuegen-Vorschau um das Clipboard-Zentrum (Radiant)
+    pub paste_preview_rotation: f32,
+    /// Beim Duplizieren gesicherte Zwischenablage; wird nach der Platzierung wiederhergestellt
+    pub clipboard_before_duplicate: Option<Clipboard>,
     /// Verlauf ausgefuehrter Commands
     pub command_log: CommandLog,
     /// Undo/Redo-History (Snapshot-basiert)
@@ -73,6 +111,10 @@ pub struct AppState {
     options_arc: Arc<EditorOptions>,
     /// In-Session-Registry aller erstellten Segmente (fuer nachtraegliche Bearbeitung)
     pub group_registry: GroupRegistry,
+    /// Gesperrte Nodes: koennen bis zum Entsperren weder verschoben noch geloescht werden.
+    ///
+    /// Reiner Editor-Zustand der Sitzung; wird beim Laden einer Datei geleert.
+    pub locked_node_ids: HashSet<u64>,
     /// Signalisiert dem Host (eframe), die Anwendung kontrolliert zu beenden
     pub should_exit: bool,
     /// Beim letzten Overview-Laden extrahierte Farmland-Feldgrenz-Polygone
@@ -86,6 +128,17 @@ pub struct AppState {
     /// Gecachtes Hintergrundbild fuer farbbasierte Tool-Analysen.
     /// `None` solange kein Overview geladen wurde.
     pub background_image: Option<Arc<image::DynamicImage>>,
+    /// Geladene Heightmap zu `ui.heightmap_path` fuer Hoehenprofile und Relief.
+    /// `None` ohne Heightmap oder wenn das Laden fehlgeschlagen ist.
+    pub heightmap: Option<Arc<Heightmap>>,
+    /// Fahrzeuge aus der `vehicles.xml` neben der geladenen Config.
+    /// Leer ohne Savegame-Fahrzeuge; wird bei jedem Laden neu gelesen.
+    pub savegame_vehicles: Vec<SavegameVehicle>,
+    /// Besitzverhaeltnisse aus der `farmland.xml` neben der geladenen Config.
+    /// `None` ohne Savegame-Farmlands; wird bei jedem Laden neu gelesen.
+    pub farmland_ownership: Option<Arc<FarmlandOwnership>>,
+    /// Zustand der Live-Verbindung zum laufenden Spiel (Position, Aufzeichnung).
+    pub live_link: LiveLinkState,
     /// Geladener Dateikatalog eines bereits gespeicherten Overview-Layer-Bundles.
     ///
     /// Haelt nur Metadaten, Dateipfade und Runtime-Sichtbarkeit.
@@ -118,6 +171,20 @@ pub struct AppState {
     /// Wird ueber `(render_instance_id, render_revision)` invalidiert, damit der
     /// Snapshot nur bei render-relevanten RoadMap-Aenderungen neu aufgebaut wird.
     pub(crate) render_map_cache: RefCell<RenderMapCache>,
+    /// Lazy Cache fuer die komplette Render-Szene.
+    ///
+    /// Solange sich weder Karte, Selektion, Kamera, Viewport noch Optionen aendern,
+    /// liefert `render_scene::build()` einen O(1)-Clone der gecachten Szene.
+    pub(crate) render_scene_cache: RefCell<RenderSceneCache>,
+    /// Cache fuer die Terrain-Warnungen des Viewport-Overlays.
+    ///
+    /// Wird beim Overlay-Aufbau (mit `&mut AppState`) gewaermt und ueber
+    /// Karten-Revision, Heightmap, Hoehenskala und Toleranz invalidiert.
+    pub(crate) terrain_clipping_cache: TerrainClippingCache,
+    /// Cache fuer die triangulierten eigenen Farmlands des Viewport-Overlays.
+    ///
+    /// Wird ueber die Arcs von Farmland-Polygonen und Besitzverhaeltnissen invalidiert.
+    pub(crate) owned_farmland_cache: OwnedFarmlandCache,
 }
 
 impl AppState {
@@ -134,15 +201,22 @@ impl AppState {
             editor: EditorToolState::new(),
             clipboard: Clipboard::default(),
             paste_preview_pos: None,
+            paste_preview_rotation: 0.0,
+            clipboard_before_duplicate: None,
             command_log: CommandLog::new(),
             history: crate::app::history::EditHistory::new_with_capacity(200),
             options,
             options_arc,
             group_registry: GroupRegistry::new(),
+            locked_node_ids: HashSet::new(),
             should_exit: false,
             farmland_polygons: None,
             farmland_grid: None,
             background_image: None,
+            heightmap: None,
+            savegame_vehicles: Vec::new(),
+            farmland_ownership: None,
+            live_link: LiveLinkState::default(),
             background_layers: None,
             pending_overview_bundle: None,
             saved_document_cache_key: None,
@@ -151,6 +225,9 @@ impl AppState {
             active_tool_edit_session: None,
             dimmed_ids_cache: RefCell::new(None),
             render_map_cache: RefCell::new(None),
+            render_scene_cache: RefCell::new(None),
+            terrain_clipping_cache: None,
+            owned_farmland_cache: None,
         }
     }
 
@@ -1,8 +1,9 @@
 use crate::app::ui_contract::DialogRequest;
 use crate::shared::{
-    DedupDialogState, DistanzenState, GroupSettingsPopupState, MarkerDialogState,
-    OverviewOptionsDialogState, PostLoadDialogState, SaveOverviewDialogState,
-    TraceAllFieldsDialogState,
+    DedupDialogState, DeleteByFilterDialogState, DistanzenState, FieldCourseDialogState,
+    GroupSettingsPopupState, MarkerDialogState, MoveSelectionDialogState,
+    OverviewOptionsDialogState, PathOffsetState, PdfExportDialogState, PostLoadDialogState,
+    SaveOverviewDialogState, TraceAllFieldsDialogState,
 };
 
 /// Zustand des ZIP-Browser-Dialogs.
@@ -18,6 +19,30 @@ pub struct ZipBrowserState {
     pub filter_overview: bool,
 }
 
+/// Zustand des Dialogs fuer den AutoDrive-Routen-Manager.
+#[derive(Debug, Clone)]
+pub struct RoutesDialogState {
+    /// Routen-Ordner des AutoDrive-Routen-Managers im Spielprofil
+    pub routes_dir: String,
+    /// In der `routes.xml` eingetragene Routen
+    pub routes: Vec<crate::core::StoredRoute>,
+    /// Index der aktuell selektierten Route
+    pub selected: Option<usize>,
+    /// Name fuer den Export des aktuellen Netzes
+    pub export_name: String,
+}
+
+/// Zustand der Savegame-Auswahl im FS25-Profil.
+#[derive(Debug, Clone)]
+pub struct SavegamePickerState {
+    /// Durchsuchtes FS25-Profilverzeichnis
+    pub profile_dir: String,
+    /// Gefundene Savegames, neuestes zuerst
+    pub savegames: Vec<crate::core::SavegameSummary>,
+    /// Index des aktuell selektierten Savegames
+    pub selected: Option<usize>,
+}
+
 /// UI-bezogener Anwendungszustand der Engine.
 ///
 /// Enthaelt fachliche Felder (Dialog-Queues, Dateipfade, Status, Workflow-Flags)
@@ -35,6 +60,9 @@ pub struct EngineUiState {
     pub current_file_path: Option<String>,
     /// Pfad der aktuell ausgewaehlten Heightmap (optional)
     pub heightmap_path: Option<String>,
+    /// Hoehenskala aus der Map-i3d des zuletzt genutzten Map-Mod-ZIPs
+    /// (gilt beim Export auch fuer PNG-Heightmaps)
+    pub map_height_scale: Option<f32>,
     /// Marker-Bearbeiten-Dialog
     pub marker_dialog: MarkerDialogState,
     /// Temporaere Statusnachricht (z.B. Duplikat-Bereinigung)
@@ -43,6 +71,10 @@ pub struct EngineUiState {
     pub dedup_dialog: DedupDialogState,
     /// ZIP-Browser-Dialog fuer Background-Map-Auswahl
     pub zip_browser: Option<ZipBrowserState>,
+    /// Dialog fuer Import/Export ueber den AutoDrive-Routen-Manager
+    pub routes_dialog: Option<RoutesDialogState>,
+    /// Savegame-Auswahl im FS25-Profil
+    pub savegame_picker: Option<SavegamePickerState>,
     /// Uebersichtskarten-Optionen-Dialog
     pub overview_options_dialog: OverviewOptionsDialogState,
     /// Post-Load-Dialog (Heightmap/ZIP-Erkennung)
@@ -51,8 +83,18 @@ pub struct EngineUiState {
     pub save_overview_dialog: SaveOverviewDialogState,
     /// Distanzen-Neuverteilen-Konfiguration (Eigenschaften-Panel)
     pub distanzen: DistanzenState,
+    /// Seitlicher Strecken-Versatz mit Vorschau (Eigenschaften-Panel)
+    pub path_offset: PathOffsetState,
     /// Dialog fuer "Alle Felder nachzeichnen"-Einstellungen
     pub trace_all_fields_dialog: TraceAllFieldsDialogState,
+    /// Dialog fuer "Feld-Rundkurs erzeugen"-Einstellungen
+    pub field_course_dialog: FieldCourseDialogState,
+    /// Dialog fuer "Selektion verschieben um..."
+    pub move_selection_dialog: MoveSelectionDialogState,
+    /// Dialog fuer "Loeschen nach Filter..."
+    pub delete_by_filter_dialog: DeleteByFilterDialogState,
+    /// Dialog fuer "Als PDF exportieren..."
+    pub pdf_export_dialog: PdfExportDialogState,
     /// Segment-Einstellungs-Popup (erscheint nach Doppelklick auf Segment-Node)
     pub group_settings_popup: GroupSettingsPopupState,
 }
@@ -66,15 +108,23 @@ impl EngineUiState {
             pending_save_path: None,
             current_file_path: None,
             heightmap_path: None,
+            map_height_scale: None,
             marker_dialog: MarkerDialogState::new(),
             status_message: None,
             dedup_dialog: DedupDialogState::new(),
             zip_browser: None,
+            routes_dialog: None,
+            savegame_picker: None,
             overview_options_dialog: OverviewOptionsDialogState::new(),
             post_load_dialog: PostLoadDialogState::new(),
             save_overview_dialog: SaveOverviewDialogState::default(),
             distanzen: DistanzenState::default(),
+            path_offset: PathOffsetState::default(),
             trace_all_fields_dialog: TraceAllFieldsDialogState::default(),
+            field_course_dialog: FieldCourseDialogState::default(),
+            move_selection_dialog: MoveSelectionDialogState::default(),
+            delete_by_filter_dialog: DeleteByFilterDialogState::default(),
+            pdf_export_dialog: PdfExportDialogState::default(),
             group_settings_popup: GroupSettingsPopupState::default(),
         }
     }
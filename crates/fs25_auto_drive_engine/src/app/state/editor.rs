@@ -1,8 +1,10 @@
 use crate::app::tool_contract::RouteToolId;
-use crate::app::tools::{ToolManager, ToolPreview};
+use crate::app::tools::{
+    apply_snap_constraints, snap_to_node, ToolAnchor, ToolManager, ToolPreview,
+};
 use crate::app::ui_contract::{RouteToolPanelState, RouteToolViewportData};
 use crate::core::{ConnectionDirection, ConnectionPriority, RoadMap};
-use crate::shared::RouteToolGroup;
+use crate::shared::{EditorOptions, RouteToolGroup};
 use glam::Vec2;
 
 /// Aktives Editor-Werkzeug
@@ -15,6 +17,10 @@ pub enum EditorTool {
     Connect,
     /// Neue Nodes auf der Karte platzieren
     AddNode,
+    /// Verbindungen entlang einer gezogenen Linie schneiden (Messer)
+    Knife,
+    /// Strecken und Richtungen per Klick messen (Lineal, erzeugt keine Nodes)
+    Measure,
     /// Route-Tools (Linie, Parkplatz, Kurve, …)
     Route,
 }
@@ -74,6 +80,8 @@ pub struct EditorToolState {
     pub route_tool_memory: RouteToolSelectionMemory,
     /// Route-Tool-Manager (Linie, Parkplatz, Kurve, …)
     pub tool_manager: ToolManager,
+    /// Messpunkte des Mess-Werkzeugs in Weltkoordinaten (temporaer, nicht im Undo).
+    pub measure_points: Vec<Vec2>,
 }
 
 impl Default for EditorToolState {
@@ -92,6 +100,7 @@ impl EditorToolState {
             default_priority: ConnectionPriority::Regular,
             route_tool_memory: RouteToolSelectionMemory::default(),
             tool_manager: ToolManager::new(),
+            measure_points: Vec::new(),
         }
     }
 
@@ -119,6 +128,7 @@ impl EditorToolState {
             has_pending_input: tool.is_some_and(|tool| tool.has_pending_input()),
             can_execute: tool.is_some_and(|tool| tool.is_ready()),
             config_state: tool.map(|tool| tool.panel_state()),
+            elevation_profile: None,
         })
     }
 
@@ -156,6 +166,30 @@ impl EditorToolState {
         }
     }
 
+    /// Wendet Grid- und Winkel-Snap auf eine Klick- oder Cursorposition des Route-Tools an.
+    ///
+    /// Ursprung fuer den Winkel-Snap ist `RouteToolCore::segment_origin()` des
+    /// aktiven Tools; ohne aktives Route-Tool bleibt die Position unveraendert.
+    pub fn constrain_route_tool_pos(
+        &self,
+        pos: Vec2,
+        road_map: &RoadMap,
+        options: &EditorOptions,
+    ) -> Vec2 {
+        if self.active_tool != EditorTool::Route {
+            return pos;
+        }
+        let origin = self
+            .tool_manager
+            .active_tool()
+            .and_then(|tool| tool.segment_origin());
+        let snapped_to_node = matches!(
+            snap_to_node(pos, road_map, options.snap_radius()),
+            ToolAnchor::ExistingNode(..)
+        );
+        apply_snap_constraints(pos, origin, snapped_to_node, options)
+    }
+
     /// Berechnet die aktuelle Preview-Geometrie des aktiven Route-Tools.
     pub fn route_tool_preview(
         &self,
@@ -223,6 +257,39 @@ mod tests {
         assert!(state.route_tool_preview(cursor_world, &road_map).is_none());
     }
 
+    #[test]
+    fn constrain_route_tool_pos_snaps_to_grid_then_angle_from_segment_origin() {
+        let mut state = EditorToolState::new();
+        let road_map = RoadMap::default();
+        let options = EditorOptions {
+            grid_snap_enabled: true,
+            grid_snap_size: 2.0,
+            angle_snap_degrees: 45.0,
+            ..EditorOptions::default()
+        };
+
+        state.active_tool = EditorTool::Route;
+        state.tool_manager.set_active_by_id(RouteToolId::Straight);
+        let start = state.constrain_route_tool_pos(Vec2::new(0.8, -1.2), &road_map, &options);
+        assert_eq!(start, Vec2::new(0.0, -2.0));
+
+        state
+            .tool_manager
+            .active_tool_mut()
+            .expect("Gerade Strecke muss aktiv sein")
+            .on_click(start, &road_map, false);
+        let end = state.constrain_route_tool_pos(Vec2::new(7.5, 5.0), &road_map, &options);
+        let expected = start + Vec2::from_angle(45f32.to_radians()) * 10.0;
+        assert!((end - expected).length() < 1e-4);
+
+        state.active_tool = EditorTool::Select;
+        let raw = Vec2::new(7.5, -1.0);
+        assert_eq!(
+            state.constrain_route_tool_pos(raw, &road_map, &options),
+            raw
+        );
+    }
+
     #[test]
     fn route_viewport_data_only_activates_segment_shortcuts_for_matching_capability() {
         let road_map = RoadMap::default();
@@ -0,0 +1,43 @@
+//! Zustand der Live-Verbindung zu einem laufenden Spiel (Companion-Mod).
+
+use glam::Vec2;
+
+/// Zuletzt vom Companion-Mod gemeldete Spielerposition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LivePlayerPose {
+    /// Position in Weltkoordinaten (X/Z)
+    pub position: Vec2,
+    /// Gierwinkel in Radiant (0 = Blick nach +Z, wie im Savegame)
+    pub yaw: f32,
+}
+
+impl LivePlayerPose {
+    /// Blickrichtung als Einheitsvektor in Weltkoordinaten (X/Z).
+    pub fn direction(&self) -> Vec2 {
+        Vec2::new(self.yaw.sin(), self.yaw.cos())
+    }
+}
+
+/// Engine-seitiger Zustand der Live-Verbindung.
+///
+/// Der Transport (Socket) gehoert dem Host; die Engine haelt nur die
+/// gemeldete Position, den Aufzeichnungsmodus und ausstehende Nachrichten an das Spiel.
+#[derive(Debug, Clone, Default)]
+pub struct LiveLinkState {
+    /// Letzte gemeldete Spielerposition (`None` bis zur ersten Meldung)
+    pub player: Option<LivePlayerPose>,
+    /// "Kurs aufzeichnen" aktiv: jede Positionsmeldung kann einen Node anlegen
+    pub recording: bool,
+    /// Zuletzt aufgezeichneter Node, an den der naechste angehaengt wird
+    pub last_recorded_node: Option<u64>,
+    /// Gespeicherter Config-Pfad, den der Host dem Spiel zum Neuladen schicken soll
+    pub pending_reload: Option<String>,
+}
+
+impl LiveLinkState {
+    /// Beendet eine laufende Aufzeichnung; die naechste beginnt mit einem neuen Kurs.
+    pub fn stop_recording(&mut self) {
+        self.recording = false;
+        self.last_recorded_node = None;
+    }
+}
@@ -10,6 +10,9 @@ pub struct SelectionState {
     pub selected_node_ids: Arc<IndexSet<u64>>,
     /// Letzter selektierter Node als Anker fuer additive Bereichsselektion
     pub selection_anchor_node_id: Option<u64>,
+    /// Per Klick auf eine Kante selektierte Verbindung als `(start_id, end_id)`.
+    /// Schliesst die Node-Selektion aus: jede Aenderung der Node-IDs hebt sie auf.
+    pub selected_connection: Option<(u64, u64)>,
     /// Monoton steigender Zaehler: wird bei jeder Mutation erhoehen.
     /// Dient als Invalidierungs-Token fuer den `dimmed_ids`-Cache in `AppState`.
     pub generation: u64,
@@ -21,6 +24,7 @@ impl SelectionState {
         Self {
             selected_node_ids: Arc::new(IndexSet::new()),
             selection_anchor_node_id: None,
+            selected_connection: None,
             generation: 0,
         }
     }
@@ -29,10 +33,12 @@ impl SelectionState {
     ///
     /// Alle Mutationen der Selektion gehen ueber diese Methode, damit der
     /// Arc-Klon in `RenderScene::build()` O(1) bleibt.
-    /// Erhoehen den Generations-Zaehler fuer Cache-Invalidierung.
+    /// Erhoehen den Generations-Zaehler fuer Cache-Invalidierung und heben eine
+    /// selektierte Verbindung auf.
     #[inline]
     pub fn ids_mut(&mut self) -> &mut IndexSet<u64> {
         self.generation += 1;
+        self.selected_connection = None;
         Arc::make_mut(&mut self.selected_node_ids)
     }
 }
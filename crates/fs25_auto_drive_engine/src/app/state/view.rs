@@ -1,5 +1,6 @@
 use crate::core::{BackgroundMap, Camera2D};
-use crate::shared::RenderQuality;
+use crate::shared::{BackgroundSource, RenderQuality};
+use indexmap::IndexSet;
 use std::sync::Arc;
 
 /// View-bezogener Anwendungszustand
@@ -21,6 +22,15 @@ pub struct ViewState {
     pub background_asset_revision: u64,
     /// Monotone Revision fuer Platzierung/Skalierung des Background-Assets.
     pub background_transform_revision: u64,
+    /// Quelle des angezeigten Hintergrunds (Overview oder Heightmap-Relief)
+    pub background_source: BackgroundSource,
+    /// Waehrend eines Heightmap-Reliefs zurueckgelegte Overview samt Skalierung
+    pub stashed_overview: Option<(Arc<BackgroundMap>, f32)>,
+    /// Beim Aktivieren des Isolier-Modus eingefrorene Menge ausgeblendeter Nodes
+    /// (alle Nodes ausser der damaligen Selektion); `None` = Isolation aus.
+    pub isolation_hidden_node_ids: Option<Arc<IndexSet<u64>>>,
+    /// Monotone Revision der Isolations-Menge (steigt bei jedem Ein-/Ausschalten).
+    pub isolation_revision: u64,
 }
 
 impl ViewState {
@@ -35,6 +45,10 @@ impl ViewState {
             background_scale: 1.0,
             background_asset_revision: 0,
             background_transform_revision: 0,
+            background_source: BackgroundSource::Overview,
+            stashed_overview: None,
+            isolation_hidden_node_ids: None,
+            isolation_revision: 0,
         }
     }
 
@@ -48,4 +62,29 @@ impl ViewState {
     pub fn mark_background_transform_changed(&mut self) {
         self.background_transform_revision = self.background_transform_revision.saturating_add(1);
     }
+
+    /// Setzt die Hintergrundquelle auf `Overview` zurueck und verwirft eine
+    /// zurueckgelegte Overview (ein neu geladener Hintergrund ersetzt sie).
+    pub fn reset_background_source(&mut self) {
+        self.background_source = BackgroundSource::Overview;
+        self.stashed_overview = None;
+    }
+
+    /// Gibt `true` zurueck, solange der Isolier-Modus aktiv ist.
+    pub fn is_isolation_active(&self) -> bool {
+        self.isolation_hidden_node_ids.is_some()
+    }
+
+    /// Setzt die Isolations-Menge (oder `None`) und erhoeht die Revision.
+    pub fn set_isolation(&mut self, hidden: Option<Arc<IndexSet<u64>>>) {
+        self.isolation_hidden_node_ids = hidden;
+        self.isolation_revision = self.isolation_revision.saturating_add(1);
+    }
+
+    /// Beendet einen aktiven Isolier-Modus (z.B. nach dem Laden einer neuen Karte).
+    pub fn clear_isolation(&mut self) {
+        if self.is_isolation_active() {
+            self.set_isolation(None);
+        }
+    }
 }
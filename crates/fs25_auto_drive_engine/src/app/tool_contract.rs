@@ -23,6 +23,8 @@ pub enum RouteToolId {
     Parking,
     /// Feldgrenzen-Analyse.
     FieldBoundary,
+    /// Feldkurs-Generator (Vorgewende + Serpentine).
+    FieldCourse,
     /// Feldweg-Analyse.
     FieldPath,
     /// Strecken-Versatz.
@@ -31,11 +33,17 @@ pub enum RouteToolId {
     Rounding,
     /// Farb-Pfad-Analyse.
     ColorPath,
+    /// Klothoiden-Uebergangsbogen.
+    Clothoid,
+    /// Kreisbogen durch drei Punkte.
+    Arc,
+    /// Kreuzung aus 3–4 Zufahrts-Endpunkten.
+    Junction,
 }
 
 impl RouteToolId {
     /// Alle registrierten Route-Tools in kanonischer Slot-Reihenfolge.
-    pub const ALL: [Self; 12] = [
+    pub const ALL: [Self; 16] = [
         Self::Straight,
         Self::CurveQuad,
         Self::CurveCubic,
@@ -48,6 +56,10 @@ impl RouteToolId {
         Self::RouteOffset,
         Self::Rounding,
         Self::ColorPath,
+        Self::FieldCourse,
+        Self::Clothoid,
+        Self::Arc,
+        Self::Junction,
     ];
 }
 
@@ -105,6 +105,26 @@ pub enum RouteToolEditPayload {
         /// Gemeinsame Routing-Basiswerte.
         base: ToolRouteBase,
     },
+    /// Persistenzdaten fuer das Kreisbogen-Tool.
+    Arc {
+        /// Start- und Endanker des Bogens.
+        anchors: ToolEditAnchors,
+        /// Freier Durchgangspunkt des Bogens.
+        through: Vec2,
+        /// Gemeinsame Routing-Basiswerte.
+        base: ToolRouteBase,
+    },
+    /// Persistenzdaten fuer das Klothoiden-Tool.
+    Clothoid {
+        /// Start- und Endanker des Bogens.
+        anchors: ToolEditAnchors,
+        /// Gewaehlte Tangente am Start.
+        tangent_start: TangentSource,
+        /// Gewaehlte Tangente am Ende.
+        tangent_end: TangentSource,
+        /// Gemeinsame Routing-Basiswerte.
+        base: ToolRouteBase,
+    },
     /// Persistenzdaten fuer geglaettete Kurven.
     SmoothCurve {
         /// Start- und Endanker der Kurve.
@@ -179,6 +199,8 @@ pub enum RouteToolEditPayload {
         keep_original: bool,
         /// Abtastabstand der neuen Strecke.
         base_spacing: f32,
+        /// Abstand der Querverbindungen in Metern (0 = keine).
+        cross_link_spacing: f32,
         /// Gemeinsame Routing-Basiswerte.
         base: ToolRouteBase,
     },
@@ -206,6 +228,8 @@ impl RouteToolEditPayload {
             Self::Straight { anchors, .. }
             | Self::CurveQuad { anchors, .. }
             | Self::CurveCubic { anchors, .. }
+            | Self::Arc { anchors, .. }
+            | Self::Clothoid { anchors, .. }
             | Self::SmoothCurve { anchors, .. } => anchors.protected_node_ids(),
             Self::Spline { anchors, .. } => match (anchors.first(), anchors.last()) {
                 (Some(start), Some(end)) => ToolEditAnchors {
@@ -107,6 +107,26 @@ pub(crate) fn begin_edit(state: &mut AppState, record_id: u64) {
         return;
     };
 
+    let protected_anchor_ids: HashSet<u64> = tool_edit_backup
+        .payload
+        .protected_anchor_ids()
+        .into_iter()
+        .collect();
+    let inner_ids: Vec<u64> = group_record
+        .node_ids
+        .iter()
+        .copied()
+        .filter(|id| !protected_anchor_ids.contains(id))
+        .collect();
+    if inner_ids
+        .iter()
+        .any(|id| state.locked_node_ids.contains(id))
+    {
+        state.ui.status_message =
+            Some("Segment enthaelt gesperrte Nodes — Bearbeitung nicht moeglich".to_string());
+        return;
+    }
+
     activate_tool_for_edit(state, tool_edit_backup.tool_id);
     if state.editor.tool_manager.active_group_edit_mut().is_none() {
         log::warn!(
@@ -128,18 +148,6 @@ pub(crate) fn begin_edit(state: &mut AppState, record_id: u64) {
         }
     }
 
-    let protected_anchor_ids: HashSet<u64> = tool_edit_backup
-        .payload
-        .protected_anchor_ids()
-        .into_iter()
-        .collect();
-    let inner_ids: Vec<u64> = group_record
-        .node_ids
-        .iter()
-        .copied()
-        .filter(|id| !protected_anchor_ids.contains(id))
-        .collect();
-
     state.tool_edit_store.remove(record_id);
     use_cases::editing::delete_nodes_by_ids(state, &inner_ids);
     state.group_registry.remove(record_id);
@@ -0,0 +1,51 @@
+//! Egui-freie Panel-Bruecke fuer das Kreisbogen-Tool.
+
+use super::super::RouteToolCore;
+use super::state::ArcTool;
+use crate::app::ui_contract::{
+    ArcPanelAction, ArcPanelState, RouteToolPanelEffect, SegmentConfigPanelAction,
+    SegmentLengthKind,
+};
+
+impl ArcTool {
+    /// Liefert den egui-freien Panelzustand des Kreisbogen-Tools.
+    pub(super) fn panel_state(&self) -> ArcPanelState {
+        let arc = self.current_arc();
+        ArcPanelState {
+            radius_m: arc.map(|arc| arc.radius),
+            sweep_deg: arc.map(|arc| arc.sweep.abs().to_degrees()),
+            segment: self.seg.panel_state(
+                self.is_adjusting(),
+                self.is_ready(),
+                self.total_length(),
+                SegmentLengthKind::Curve,
+                true,
+            ),
+        }
+    }
+
+    /// Wendet eine semantische Panel-Aktion auf das Kreisbogen-Tool an.
+    pub(super) fn apply_panel_action(&mut self, action: ArcPanelAction) -> RouteToolPanelEffect {
+        match action {
+            ArcPanelAction::Segment(segment_action) => self.apply_segment_action(segment_action),
+        }
+    }
+
+    fn apply_segment_action(&mut self, action: SegmentConfigPanelAction) -> RouteToolPanelEffect {
+        let result = self.seg.apply_panel_action(
+            action,
+            self.is_adjusting(),
+            self.is_ready(),
+            self.total_length(),
+            true,
+        );
+        if result.recreate {
+            self.lifecycle.recreate_needed = true;
+        }
+        RouteToolPanelEffect {
+            changed: result.changed,
+            needs_recreate: result.recreate,
+            next_action: None,
+        }
+    }
+}
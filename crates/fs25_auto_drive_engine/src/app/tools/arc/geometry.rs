@@ -0,0 +1,122 @@
+//! Geometrie-Berechnungen fuer das Kreisbogen-Tool.
+
+use super::super::{common, ToolAnchor, ToolResult};
+use crate::core::{ConnectionDirection, ConnectionPriority, RoadMap};
+use glam::Vec2;
+use std::f32::consts::TAU;
+
+/// Relative Toleranz, unterhalb der drei Punkte als kollinear gelten.
+const COLLINEAR_TOLERANCE: f32 = 1e-4;
+
+/// Kreisbogen zwischen Start und Ende.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircularArc {
+    /// Kreismittelpunkt.
+    pub center: Vec2,
+    /// Kreisradius in Metern.
+    pub radius: f32,
+    /// Winkel des Startpunkts relativ zum Mittelpunkt (Radiant).
+    pub start_angle: f32,
+    /// Ueberstrichener Winkel (Radiant, positiv = gegen den Uhrzeigersinn).
+    pub sweep: f32,
+}
+
+impl CircularArc {
+    /// Bogenlaenge in Metern.
+    pub fn length(&self) -> f32 {
+        self.radius * self.sweep.abs()
+    }
+
+    /// Punkt auf dem Bogen fuer den Anteil `t` in [0, 1].
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        let angle = self.start_angle + self.sweep * t;
+        self.center + Vec2::from_angle(angle) * self.radius
+    }
+}
+
+/// Bestimmt den Kreisbogen von `start` ueber `through` nach `end`.
+///
+/// # Rueckgabe
+/// `None` wenn die drei Punkte (nahezu) kollinear sind oder zusammenfallen
+pub fn arc_through_points(start: Vec2, through: Vec2, end: Vec2) -> Option<CircularArc> {
+    let ab = through - start;
+    let ac = end - start;
+    let cross = ab.perp_dot(ac);
+    if cross.abs() <= COLLINEAR_TOLERANCE * ab.length() * ac.length() {
+        return None;
+    }
+
+    // Schnittpunkt der Mittelsenkrechten (Umkreismittelpunkt)
+    let center =
+        start + (ab.perp() * ac.length_squared() - ac.perp() * ab.length_squared()) / (2.0 * cross);
+    let radius = center.distance(start);
+
+    let angle_of = |point: Vec2| {
+        let offset = point - center;
+        offset.y.atan2(offset.x)
+    };
+    let start_angle = angle_of(start);
+    let ccw_to_end = (angle_of(end) - start_angle).rem_euclid(TAU);
+    let ccw_to_through = (angle_of(through) - start_angle).rem_euclid(TAU);
+    // Durchgangspunkt liegt auf dem gegen den Uhrzeigersinn laufenden Bogen
+    // genau dann, wenn er vor dem Endpunkt erreicht wird.
+    let sweep = if ccw_to_through < ccw_to_end {
+        ccw_to_end
+    } else {
+        ccw_to_end - TAU
+    };
+
+    Some(CircularArc {
+        center,
+        radius,
+        start_angle,
+        sweep,
+    })
+}
+
+/// Berechnet die gleichmaessig verteilten Positionen des Bogens.
+///
+/// Gibt `segment_count + 1` Punkte zurueck (inkl. Start und Ende); bei
+/// kollinearen Punkten entsteht eine gerade Strecke von `start` nach `end`.
+pub fn compute_arc_positions(
+    start: Vec2,
+    through: Vec2,
+    end: Vec2,
+    max_segment_length: f32,
+) -> Vec<Vec2> {
+    let Some(arc) = arc_through_points(start, through, end) else {
+        let segment_count = (start.distance(end) / max_segment_length).ceil().max(1.0) as usize;
+        return (0..=segment_count)
+            .map(|i| start.lerp(end, i as f32 / segment_count as f32))
+            .collect();
+    };
+
+    let segment_count = (arc.length() / max_segment_length).ceil().max(1.0) as usize;
+    let mut positions: Vec<Vec2> = (0..segment_count)
+        .map(|i| arc.point_at(i as f32 / segment_count as f32))
+        .collect();
+    positions[0] = start;
+    positions.push(end);
+    positions
+}
+
+/// Gemeinsame Logik fuer `execute()` und `execute_from_anchors()`.
+pub(crate) fn build_result(
+    start: ToolAnchor,
+    through: Vec2,
+    end: ToolAnchor,
+    max_segment_length: f32,
+    direction: ConnectionDirection,
+    priority: ConnectionPriority,
+    road_map: &RoadMap,
+) -> Option<ToolResult> {
+    let positions = compute_arc_positions(
+        start.position(),
+        through,
+        end.position(),
+        max_segment_length,
+    );
+    Some(common::assemble_tool_result(
+        &positions, &start, &end, direction, priority, road_map,
+    ))
+}
@@ -0,0 +1,242 @@
+//! Lifecycle-Methoden des ArcTool.
+
+use super::super::{
+    common::{record_applied_tool_state, sync_tool_host},
+    RouteTool, RouteToolCore, RouteToolGroupEdit, RouteToolHostSync, RouteToolPanelBridge,
+    RouteToolRecreate, RouteToolSegmentAdjustments, ToolAction, ToolHostContext, ToolPreview,
+    ToolResult,
+};
+use super::geometry::{build_result, compute_arc_positions};
+use super::state::ArcTool;
+use crate::app::tool_editing::{RouteToolEditPayload, ToolEditAnchors, ToolRouteBase};
+use crate::app::ui_contract::{RouteToolConfigState, RouteToolPanelAction, RouteToolPanelEffect};
+use crate::core::RoadMap;
+use glam::Vec2;
+
+impl RouteToolPanelBridge for ArcTool {
+    fn status_text(&self) -> &str {
+        match (&self.start, &self.through, &self.end) {
+            (None, _, _) => "Startpunkt klicken",
+            (Some(_), None, _) => "Durchgangspunkt klicken",
+            (Some(_), Some(_), None) => "Endpunkt klicken",
+            (Some(_), Some(_), Some(_)) => "Bereit — Enter zum Ausfuehren, Escape zum Abbrechen",
+        }
+    }
+
+    fn panel_state(&self) -> RouteToolConfigState {
+        RouteToolConfigState::Arc(self.panel_state())
+    }
+
+    fn apply_panel_action(&mut self, action: RouteToolPanelAction) -> RouteToolPanelEffect {
+        let RouteToolPanelAction::Arc(action) = action else {
+            return RouteToolPanelEffect::default();
+        };
+
+        self.apply_panel_action(action)
+    }
+}
+
+impl RouteToolCore for ArcTool {
+    fn on_click(&mut self, pos: Vec2, road_map: &RoadMap, _ctrl: bool) -> ToolAction {
+        if self.start.is_none() {
+            if let Some(last_end) = self.lifecycle.chaining_start_anchor() {
+                // Verkettung: letzter Endpunkt wird Start, Klick setzt den Durchgangspunkt
+                self.lifecycle.prepare_for_chaining();
+                self.last_start_anchor = None;
+                self.last_through = None;
+                self.start = Some(last_end);
+                self.through = Some(pos);
+            } else {
+                self.start = Some(self.lifecycle.snap_at(pos, road_map));
+            }
+            ToolAction::Continue
+        } else if self.through.is_none() {
+            // Durchgangspunkt bleibt frei, er wird nicht mit bestehenden Nodes verbunden
+            self.through = Some(pos);
+            ToolAction::Continue
+        } else {
+            self.end = Some(self.lifecycle.snap_at(pos, road_map));
+            self.sync_derived();
+            ToolAction::ReadyToExecute
+        }
+    }
+
+    fn preview(&self, cursor_pos: Vec2, road_map: &RoadMap) -> ToolPreview {
+        let Some(start) = self.start else {
+            return ToolPreview::default();
+        };
+        let start_pos = start.position();
+        let end_pos = match &self.end {
+            Some(anchor) => anchor.position(),
+            None => self.lifecycle.snap_at(cursor_pos, road_map).position(),
+        };
+
+        let positions = match self.through {
+            Some(through) => {
+                compute_arc_positions(start_pos, through, end_pos, self.seg.max_segment_length)
+            }
+            None => vec![start_pos, cursor_pos],
+        };
+        ToolPreview::from_polyline(positions, self.direction, self.priority)
+    }
+
+    fn execute(&self, road_map: &RoadMap) -> Option<ToolResult> {
+        build_result(
+            self.start?,
+            self.through?,
+            self.end?,
+            self.seg.max_segment_length,
+            self.direction,
+            self.priority,
+            road_map,
+        )
+    }
+
+    fn reset(&mut self) {
+        self.start = None;
+        self.through = None;
+        self.end = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.start.is_some() && self.through.is_some() && self.end.is_some()
+    }
+
+    fn has_pending_input(&self) -> bool {
+        self.start.is_some()
+    }
+}
+
+impl RouteToolHostSync for ArcTool {
+    fn sync_host(&mut self, context: &ToolHostContext) {
+        sync_tool_host(
+            &mut self.direction,
+            &mut self.priority,
+            &mut self.lifecycle,
+            context,
+        );
+    }
+}
+
+impl RouteToolRecreate for ArcTool {
+    fn on_applied(&mut self, ids: &[u64], _road_map: &RoadMap) {
+        if self.start.is_some() {
+            self.last_start_anchor = self.start;
+        }
+        if self.through.is_some() {
+            self.last_through = self.through;
+        }
+        let end_anchor = self.end.or(self.lifecycle.last_end_anchor);
+        record_applied_tool_state(&mut self.lifecycle, ids, end_anchor);
+    }
+
+    fn last_created_ids(&self) -> &[u64] {
+        &self.lifecycle.last_created_ids
+    }
+
+    fn last_end_anchor(&self) -> Option<super::super::ToolAnchor> {
+        self.lifecycle.last_end_anchor
+    }
+
+    fn needs_recreate(&self) -> bool {
+        self.lifecycle.recreate_needed
+    }
+
+    fn clear_recreate_flag(&mut self) {
+        self.lifecycle.recreate_needed = false;
+    }
+
+    fn execute_from_anchors(&self, road_map: &RoadMap) -> Option<ToolResult> {
+        build_result(
+            self.last_start_anchor?,
+            self.last_through?,
+            self.lifecycle.last_end_anchor?,
+            self.seg.max_segment_length,
+            self.direction,
+            self.priority,
+            road_map,
+        )
+    }
+}
+
+impl RouteToolSegmentAdjustments for ArcTool {
+    fn increase_node_count(&mut self) {
+        self.seg.increase_node_count();
+        self.lifecycle.recreate_needed = true;
+    }
+
+    fn decrease_node_count(&mut self) {
+        self.seg.decrease_node_count();
+        self.lifecycle.recreate_needed = true;
+    }
+
+    fn increase_segment_length(&mut self) {
+        self.seg.increase_segment_length();
+        self.lifecycle.recreate_needed = true;
+    }
+
+    fn decrease_segment_length(&mut self) {
+        self.seg.decrease_segment_length();
+        self.lifecycle.recreate_needed = true;
+    }
+}
+
+impl RouteTool for ArcTool {
+    fn as_recreate(&self) -> Option<&dyn RouteToolRecreate> {
+        Some(self)
+    }
+
+    fn as_recreate_mut(&mut self) -> Option<&mut dyn RouteToolRecreate> {
+        Some(self)
+    }
+
+    fn as_segment_adjustments(&self) -> Option<&dyn RouteToolSegmentAdjustments> {
+        Some(self)
+    }
+
+    fn as_segment_adjustments_mut(&mut self) -> Option<&mut dyn RouteToolSegmentAdjustments> {
+        Some(self)
+    }
+
+    fn as_group_edit(&self) -> Option<&dyn RouteToolGroupEdit> {
+        Some(self)
+    }
+
+    fn as_group_edit_mut(&mut self) -> Option<&mut dyn RouteToolGroupEdit> {
+        Some(self)
+    }
+}
+
+impl RouteToolGroupEdit for ArcTool {
+    fn build_edit_payload(&self) -> Option<RouteToolEditPayload> {
+        let start = self.last_start_anchor?;
+        let end = self.lifecycle.last_end_anchor?;
+        Some(RouteToolEditPayload::Arc {
+            anchors: ToolEditAnchors { start, end },
+            through: self.last_through?,
+            base: ToolRouteBase {
+                direction: self.direction,
+                priority: self.priority,
+                max_segment_length: self.seg.max_segment_length,
+            },
+        })
+    }
+
+    fn restore_edit_payload(&mut self, payload: &RouteToolEditPayload) {
+        let RouteToolEditPayload::Arc {
+            anchors,
+            through,
+            base,
+        } = payload
+        else {
+            return;
+        };
+        self.start = Some(anchors.start);
+        self.through = Some(*through);
+        self.end = Some(anchors.end);
+        self.direction = base.direction;
+        self.priority = base.priority;
+        self.seg.max_segment_length = base.max_segment_length;
+        self.sync_derived();
+    }
+}
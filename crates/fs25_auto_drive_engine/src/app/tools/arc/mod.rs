@@ -0,0 +1,17 @@
+//! Kreisbogen-Tool: legt einen Kreisbogen durch Start-, Durchgangs- und Endpunkt.
+//!
+//! Aufgeteilt in:
+//! - `state`     — Struct, Konstruktor, Hilfsmethoden
+//! - `lifecycle` — RouteTool-Implementierung
+//! - `geometry`  — Kreis durch drei Punkte und Abtastung
+//! - `config_ui` — UI-Konfigurationspanel
+
+mod config_ui;
+pub(crate) mod geometry;
+mod lifecycle;
+mod state;
+
+pub use state::ArcTool;
+
+#[cfg(test)]
+mod tests;
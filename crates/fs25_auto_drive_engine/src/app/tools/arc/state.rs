@@ -0,0 +1,92 @@
+//! State-Definitionen und Konstruktor fuer das Kreisbogen-Tool.
+
+use super::super::common::{SegmentConfig, ToolLifecycleState};
+use super::super::ToolAnchor;
+use super::geometry::{arc_through_points, CircularArc};
+use crate::core::{ConnectionDirection, ConnectionPriority};
+use glam::Vec2;
+
+/// Kreisbogen-Tool (Start → Durchgangspunkt → Ende)
+pub struct ArcTool {
+    pub(crate) start: Option<ToolAnchor>,
+    /// Freier Durchgangspunkt zwischen Start und Ende
+    pub(crate) through: Option<Vec2>,
+    pub(crate) end: Option<ToolAnchor>,
+    /// Segment-Konfiguration (Abstand / Node-Anzahl)
+    pub(crate) seg: SegmentConfig,
+    /// Richtung fuer die erzeugten Verbindungen (aus Editor-Defaults)
+    pub direction: ConnectionDirection,
+    /// Prioritaet fuer die erzeugten Verbindungen (aus Editor-Defaults)
+    pub priority: ConnectionPriority,
+    /// Gemeinsamer Lifecycle-Zustand (IDs, Endpunkt-Anker, Recreate-Flag, Snap-Radius)
+    pub(crate) lifecycle: ToolLifecycleState,
+    /// Start-Anker der letzten Erstellung (fuer Neuberechnung)
+    pub(crate) last_start_anchor: Option<ToolAnchor>,
+    /// Durchgangspunkt der letzten Erstellung (fuer Neuberechnung)
+    pub(crate) last_through: Option<Vec2>,
+}
+
+impl ArcTool {
+    /// Erstellt ein neues Kreisbogen-Tool mit Standardwerten.
+    pub fn new() -> Self {
+        Self {
+            start: None,
+            through: None,
+            end: None,
+            seg: SegmentConfig::new(2.0),
+            direction: ConnectionDirection::Dual,
+            priority: ConnectionPriority::Regular,
+            lifecycle: ToolLifecycleState::new(3.0), // Default, wird vom Handler ueberschrieben
+            last_start_anchor: None,
+            last_through: None,
+        }
+    }
+
+    /// True wenn der zuletzt erzeugte Bogen nachbearbeitet wird.
+    pub(crate) fn is_adjusting(&self) -> bool {
+        self.lifecycle.has_last_created()
+            && self.last_start_anchor.is_some()
+            && self.last_through.is_some()
+            && self.lifecycle.last_end_anchor.is_some()
+    }
+
+    /// Start-, Durchgangs- und Endpunkt des aktuellen (oder zuletzt erzeugten) Bogens.
+    pub(crate) fn current_points(&self) -> Option<(Vec2, Vec2, Vec2)> {
+        if self.is_adjusting() {
+            Some((
+                self.last_start_anchor?.position(),
+                self.last_through?,
+                self.lifecycle.last_end_anchor?.position(),
+            ))
+        } else {
+            Some((self.start?.position(), self.through?, self.end?.position()))
+        }
+    }
+
+    /// Aktueller Kreisbogen; `None` bei kollinearen Punkten oder fehlender Eingabe.
+    pub(crate) fn current_arc(&self) -> Option<CircularArc> {
+        let (start, through, end) = self.current_points()?;
+        arc_through_points(start, through, end)
+    }
+
+    /// Laenge des aktuellen Bogens bzw. der Ersatzgeraden (0.0 wenn nicht bereit).
+    pub(crate) fn total_length(&self) -> f32 {
+        let Some((start, through, end)) = self.current_points() else {
+            return 0.0;
+        };
+        arc_through_points(start, through, end)
+            .map(|arc| arc.length())
+            .unwrap_or_else(|| start.distance(end))
+    }
+
+    /// Synchronisiert den jeweils abhaengigen Wert.
+    pub(crate) fn sync_derived(&mut self) {
+        self.seg.sync_from_length(self.total_length());
+    }
+}
+
+impl Default for ArcTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,120 @@
+use super::super::{RouteToolCore, RouteToolPanelBridge, RouteToolRecreate, ToolAction};
+use super::geometry::{arc_through_points, compute_arc_positions};
+use super::state::ArcTool;
+use crate::app::ui_contract::RouteToolConfigState;
+use crate::core::RoadMap;
+use glam::Vec2;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn test_arc_through_points_quarter_circle() {
+    // Viertelkreis um den Ursprung mit Radius 10, gegen den Uhrzeigersinn
+    let through = Vec2::from_angle(PI / 4.0) * 10.0;
+    let arc = arc_through_points(Vec2::new(10.0, 0.0), through, Vec2::new(0.0, 10.0))
+        .expect("Bogen erwartet");
+
+    assert!(
+        arc.center.length() < 1e-3,
+        "Mittelpunkt war {:?}",
+        arc.center
+    );
+    assert!((arc.radius - 10.0).abs() < 1e-3);
+    assert!((arc.sweep - FRAC_PI_2).abs() < 1e-4);
+    assert!((arc.length() - 5.0 * PI).abs() < 1e-2);
+}
+
+#[test]
+fn test_arc_through_points_takes_long_way_when_through_requires_it() {
+    // Durchgangspunkt auf der Gegenseite → Dreiviertelkreis im Uhrzeigersinn
+    let arc = arc_through_points(
+        Vec2::new(10.0, 0.0),
+        Vec2::new(-10.0, 0.0),
+        Vec2::new(0.0, 10.0),
+    )
+    .expect("Bogen erwartet");
+
+    assert!(
+        (arc.sweep + 1.5 * PI).abs() < 1e-4,
+        "sweep war {}",
+        arc.sweep
+    );
+}
+
+#[test]
+fn test_arc_through_points_collinear_returns_none() {
+    assert!(arc_through_points(Vec2::ZERO, Vec2::new(5.0, 0.0), Vec2::new(10.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_compute_arc_positions_constant_radius_and_spacing() {
+    let through = Vec2::from_angle(PI / 4.0) * 10.0;
+    let positions = compute_arc_positions(Vec2::new(10.0, 0.0), through, Vec2::new(0.0, 10.0), 2.0);
+
+    assert_eq!(positions.first(), Some(&Vec2::new(10.0, 0.0)));
+    assert_eq!(positions.last(), Some(&Vec2::new(0.0, 10.0)));
+    for position in &positions {
+        assert!((position.length() - 10.0).abs() < 1e-3);
+    }
+    for pair in positions.windows(2) {
+        assert!(pair[0].distance(pair[1]) <= 2.0 + 1e-3);
+    }
+}
+
+#[test]
+fn test_compute_arc_positions_collinear_falls_back_to_line() {
+    let positions =
+        compute_arc_positions(Vec2::ZERO, Vec2::new(6.0, 0.0), Vec2::new(12.0, 0.0), 6.0);
+    assert_eq!(positions.len(), 3);
+    assert!((positions[1] - Vec2::new(6.0, 0.0)).length() < 1e-3);
+}
+
+#[test]
+fn test_tool_click_flow_and_execute() {
+    let mut tool = ArcTool::new();
+    let road_map = RoadMap::new(3);
+
+    assert_eq!(
+        tool.on_click(Vec2::new(10.0, 0.0), &road_map, false),
+        ToolAction::Continue
+    );
+    assert_eq!(
+        tool.on_click(Vec2::from_angle(PI / 4.0) * 10.0, &road_map, false),
+        ToolAction::Continue
+    );
+    assert!(!tool.is_ready());
+    assert_eq!(
+        tool.on_click(Vec2::new(0.0, 10.0), &road_map, false),
+        ToolAction::ReadyToExecute
+    );
+
+    let RouteToolConfigState::Arc(state) = RouteToolPanelBridge::panel_state(&tool) else {
+        panic!("Kreisbogen-Panelzustand erwartet");
+    };
+    assert!((state.radius_m.unwrap() - 10.0).abs() < 1e-3);
+    assert!((state.sweep_deg.unwrap() - 90.0).abs() < 1e-2);
+
+    // Bogenlaenge 15.7 m bei 2 m Abstand → 8 Segmente
+    let result = tool.execute(&road_map).expect("Ergebnis erwartet");
+    assert_eq!(result.new_nodes.len(), 9);
+    assert_eq!(result.internal_connections.len(), 8);
+}
+
+#[test]
+fn test_chaining_uses_last_end_as_start() {
+    let mut tool = ArcTool::new();
+    let road_map = RoadMap::new(3);
+
+    tool.on_click(Vec2::ZERO, &road_map, false);
+    tool.on_click(Vec2::new(5.0, 2.0), &road_map, false);
+    tool.on_click(Vec2::new(10.0, 0.0), &road_map, false);
+    tool.on_applied(&[1, 2, 3], &road_map);
+    tool.reset();
+
+    assert_eq!(
+        tool.on_click(Vec2::new(15.0, -2.0), &road_map, false),
+        ToolAction::Continue
+    );
+    let start = tool.start.expect("Start aus Verkettung erwartet");
+    assert!((start.position() - Vec2::new(10.0, 0.0)).length() < 1e-3);
+    assert_eq!(tool.through, Some(Vec2::new(15.0, -2.0)));
+}
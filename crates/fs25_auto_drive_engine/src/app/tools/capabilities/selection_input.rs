@@ -1,6 +1,6 @@
 //! Capability fuer Tools, die aktuelle Node-Selektion als Eingabe benoetigen.
 
-use crate::core::ConnectedNeighbor;
+use crate::core::{ConnectedNeighbor, ConnectionDirection};
 use glam::Vec2;
 
 /// Nachbarschafts-Snapshot eines selektierten Nodes fuer selection-getriebene Tools.
@@ -14,16 +14,23 @@ pub struct RouteToolConnectedNeighborSeed {
     pub angle: f32,
     /// `true`, wenn die zugrunde liegende Connection vom selektierten Node zum Nachbar laeuft.
     pub is_outgoing: bool,
+    /// Richtung der zugrunde liegenden Connection (`Dual` = in beide Richtungen befahrbar).
+    pub direction: ConnectionDirection,
 }
 
 impl RouteToolConnectedNeighborSeed {
     /// Baut einen serialisierbaren Tool-Snapshot aus Core-Nachbar- und Positionsdaten.
-    pub fn new(neighbor: ConnectedNeighbor, position: Vec2) -> Self {
+    pub fn new(
+        neighbor: ConnectedNeighbor,
+        position: Vec2,
+        direction: ConnectionDirection,
+    ) -> Self {
         Self {
             neighbor_id: neighbor.neighbor_id,
             position,
             angle: neighbor.angle,
             is_outgoing: neighbor.is_outgoing,
+            direction,
         }
     }
 }
@@ -4,8 +4,8 @@ use crate::app::tool_contract::RouteToolId;
 use crate::shared::{I18nKey, RouteToolGroup};
 
 use super::{
-    bypass, color_path, curve, field_boundary, field_path, parking, rounding, route_offset,
-    smooth_curve, spline, straight_line, RouteTool,
+    arc, bypass, clothoid, color_path, curve, field_boundary, field_course, field_path, junction,
+    parking, rounding, route_offset, smooth_curve, spline, straight_line, RouteTool,
 };
 
 /// UI-Surface fuer Route-Tool-Eintraege.
@@ -48,6 +48,14 @@ pub enum RouteToolIconKey {
     Rounding,
     /// Icon fuer Farbpfad.
     ColorPath,
+    /// Icon fuer Feldkurs.
+    FieldCourse,
+    /// Icon fuer Klothoide.
+    Clothoid,
+    /// Icon fuer Kreisbogen.
+    Arc,
+    /// Icon fuer Kreuzung.
+    Junction,
 }
 
 /// Verfuegbarkeits-Anforderung eines Route-Tools.
@@ -233,8 +241,24 @@ fn make_color_path() -> Box<dyn RouteTool> {
     Box::new(color_path::ColorPathTool::new())
 }
 
+fn make_field_course() -> Box<dyn RouteTool> {
+    Box::new(field_course::FieldCourseTool::new())
+}
+
+fn make_clothoid() -> Box<dyn RouteTool> {
+    Box::new(clothoid::ClothoidTool::new())
+}
+
+fn make_arc() -> Box<dyn RouteTool> {
+    Box::new(arc::ArcTool::new())
+}
+
+fn make_junction() -> Box<dyn RouteTool> {
+    Box::new(junction::JunctionTool::new())
+}
+
 /// Kanonischer Katalog aller Route-Tools.
-pub const ROUTE_TOOL_CATALOG: [RouteToolDescriptor; 12] = [
+pub const ROUTE_TOOL_CATALOG: [RouteToolDescriptor; 16] = [
     RouteToolDescriptor {
         id: RouteToolId::Straight,
         name: "Gerade Strecke",
@@ -380,6 +404,54 @@ pub const ROUTE_TOOL_CATALOG: [RouteToolDescriptor; 12] = [
         backing_mode: RouteToolBackingMode::Ephemeral,
         factory: make_color_path,
     },
+    RouteToolDescriptor {
+        id: RouteToolId::FieldCourse,
+        name: "Feldkurs",
+        legacy_icon: "\u{1f69c}",
+        description: "Erzeugt Vorgewende-Runden und Serpentinen-Bahnen in einem Feld",
+        icon_key: RouteToolIconKey::FieldCourse,
+        group: RouteToolGroup::Analysis,
+        visible_on: &ALL_ROUTE_TOOL_SURFACES,
+        requirements: &REQUIREMENT_FARMLAND,
+        backing_mode: RouteToolBackingMode::Ephemeral,
+        factory: make_field_course,
+    },
+    RouteToolDescriptor {
+        id: RouteToolId::Clothoid,
+        name: "Klothoide",
+        legacy_icon: "\u{27BF}",
+        description: "Verbindet zwei Richtungen mit einem kruemmungsstetigen Uebergangsbogen",
+        icon_key: RouteToolIconKey::Clothoid,
+        group: RouteToolGroup::Basics,
+        visible_on: &ALL_ROUTE_TOOL_SURFACES,
+        requirements: &REQUIREMENTS_NONE,
+        backing_mode: RouteToolBackingMode::GroupBackedEditable,
+        factory: make_clothoid,
+    },
+    RouteToolDescriptor {
+        id: RouteToolId::Arc,
+        name: "Kreisbogen",
+        legacy_icon: "◠",
+        description: "Legt einen Kreisbogen durch Start-, Durchgangs- und Endpunkt",
+        icon_key: RouteToolIconKey::Arc,
+        group: RouteToolGroup::Basics,
+        visible_on: &ALL_ROUTE_TOOL_SURFACES,
+        requirements: &REQUIREMENTS_NONE,
+        backing_mode: RouteToolBackingMode::GroupBackedEditable,
+        factory: make_arc,
+    },
+    RouteToolDescriptor {
+        id: RouteToolId::Junction,
+        name: "Kreuzung",
+        legacy_icon: "✚",
+        description: "Verbindet 3–4 selektierte Zufahrts-Endpunkte mit gerichteten Abbiegespuren",
+        icon_key: RouteToolIconKey::Junction,
+        group: RouteToolGroup::Section,
+        visible_on: &ALL_ROUTE_TOOL_SURFACES,
+        requirements: &REQUIREMENTS_NONE,
+        backing_mode: RouteToolBackingMode::Ephemeral,
+        factory: make_junction,
+    },
 ];
 
 /// Liefert den gesamten Route-Tool-Katalog.
@@ -431,6 +503,10 @@ pub fn route_tool_label_key(tool_id: RouteToolId) -> I18nKey {
         RouteToolId::RouteOffset => I18nKey::FloatingEditRouteOffset,
         RouteToolId::Rounding => I18nKey::FloatingEditRounding,
         RouteToolId::ColorPath => I18nKey::FloatingAnalysisColorPath,
+        RouteToolId::FieldCourse => I18nKey::FloatingAnalysisFieldCourse,
+        RouteToolId::Clothoid => I18nKey::FloatingBasicClothoid,
+        RouteToolId::Arc => I18nKey::FloatingBasicArc,
+        RouteToolId::Junction => I18nKey::FloatingEditJunction,
     }
 }
 
@@ -449,6 +525,10 @@ pub fn route_tool_defaults_tooltip_key(tool_id: RouteToolId) -> I18nKey {
         RouteToolId::RouteOffset => I18nKey::LpRouteOffset,
         RouteToolId::Rounding => I18nKey::LpRounding,
         RouteToolId::ColorPath => I18nKey::LpColorPath,
+        RouteToolId::FieldCourse => I18nKey::LpFieldCourse,
+        RouteToolId::Clothoid => I18nKey::LpClothoid,
+        RouteToolId::Arc => I18nKey::LpArc,
+        RouteToolId::Junction => I18nKey::LpJunction,
     }
 }
 
@@ -537,17 +617,21 @@ mod tests {
             RouteToolId::CurveCubic,
             RouteToolId::Spline,
             RouteToolId::SmoothCurve,
+            RouteToolId::Clothoid,
+            RouteToolId::Arc,
         ];
         let expected_section = vec![
             RouteToolId::Bypass,
             RouteToolId::Parking,
             RouteToolId::RouteOffset,
             RouteToolId::Rounding,
+            RouteToolId::Junction,
         ];
         let expected_analysis = vec![
             RouteToolId::FieldBoundary,
             RouteToolId::FieldPath,
             RouteToolId::ColorPath,
+            RouteToolId::FieldCourse,
         ];
 
         for surface in [
@@ -579,7 +663,12 @@ mod tests {
         assert!(rounding.backing_mode.is_group_backed());
         assert!(rounding.backing_mode.is_editable());
 
-        for tool_id in [RouteToolId::FieldPath, RouteToolId::ColorPath] {
+        for tool_id in [
+            RouteToolId::FieldPath,
+            RouteToolId::ColorPath,
+            RouteToolId::FieldCourse,
+            RouteToolId::Junction,
+        ] {
             let descriptor = route_tool_descriptor(tool_id);
             assert_eq!(descriptor.backing_mode, RouteToolBackingMode::Ephemeral);
             assert!(!descriptor.backing_mode.is_group_backed());
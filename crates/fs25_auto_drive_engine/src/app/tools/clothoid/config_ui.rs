@@ -0,0 +1,121 @@
+//! Egui-freie Panel-Bruecke fuer das Klothoiden-Tool.
+
+use super::super::common::tangent_options;
+use super::super::RouteToolCore;
+use super::ClothoidTool;
+use crate::app::tool_contract::TangentSource;
+use crate::app::ui_contract::{
+    ClothoidPanelAction, ClothoidPanelState, RouteToolPanelEffect, SegmentConfigPanelAction,
+    SegmentLengthKind, TangentNoneReason, TangentOptionData, TangentSelectionState,
+};
+
+impl ClothoidTool {
+    /// Liefert den egui-freien Panelzustand des Klothoiden-Tools.
+    pub(super) fn panel_state(&self) -> ClothoidPanelState {
+        let adjusting = self.is_adjusting();
+        let show_tangents = adjusting || self.is_ready();
+        let segment = self.current_segment();
+
+        ClothoidPanelState {
+            start_tangent: show_tangents
+                .then(|| {
+                    tangent_selection_state(
+                        self.tangents.tangent_start,
+                        &self.tangents.start_neighbors,
+                    )
+                })
+                .filter(|state| !state.options.is_empty()),
+            end_tangent: show_tangents
+                .then(|| {
+                    tangent_selection_state(self.tangents.tangent_end, &self.tangents.end_neighbors)
+                })
+                .filter(|state| !state.options.is_empty()),
+            min_radius_m: segment.and_then(|segment| segment.min_radius()),
+            segment: self.seg.panel_state(
+                adjusting,
+                self.is_ready(),
+                segment.map(|segment| segment.length).unwrap_or(0.0),
+                SegmentLengthKind::Curve,
+                true,
+            ),
+        }
+    }
+
+    /// Wendet eine semantische Panel-Aktion auf das Klothoiden-Tool an.
+    pub(super) fn apply_panel_action(
+        &mut self,
+        action: ClothoidPanelAction,
+    ) -> RouteToolPanelEffect {
+        match action {
+            ClothoidPanelAction::SetTangentStart(source) => self.apply_tangent_action(true, source),
+            ClothoidPanelAction::SetTangentEnd(source) => self.apply_tangent_action(false, source),
+            ClothoidPanelAction::Segment(segment_action) => {
+                self.apply_segment_action(segment_action)
+            }
+        }
+    }
+
+    fn apply_tangent_action(
+        &mut self,
+        is_start: bool,
+        source: TangentSource,
+    ) -> RouteToolPanelEffect {
+        let current = if is_start {
+            self.tangents.tangent_start
+        } else {
+            self.tangents.tangent_end
+        };
+        if current == source {
+            return RouteToolPanelEffect::default();
+        }
+
+        if is_start {
+            self.tangents.tangent_start = source;
+        } else {
+            self.tangents.tangent_end = source;
+        }
+        self.sync_derived();
+        let needs_recreate = self.lifecycle.has_last_created();
+        if needs_recreate {
+            self.lifecycle.recreate_needed = true;
+        }
+        RouteToolPanelEffect {
+            changed: true,
+            needs_recreate,
+            next_action: None,
+        }
+    }
+
+    fn apply_segment_action(&mut self, action: SegmentConfigPanelAction) -> RouteToolPanelEffect {
+        let result = self.seg.apply_panel_action(
+            action,
+            self.is_adjusting(),
+            self.is_ready(),
+            self.clothoid_length(),
+            true,
+        );
+        if result.recreate {
+            self.lifecycle.recreate_needed = true;
+        }
+        RouteToolPanelEffect {
+            changed: result.changed,
+            needs_recreate: result.recreate,
+            next_action: None,
+        }
+    }
+}
+
+fn tangent_selection_state(
+    current: TangentSource,
+    neighbors: &[crate::core::ConnectedNeighbor],
+) -> TangentSelectionState {
+    TangentSelectionState {
+        none_reason: TangentNoneReason::UseDefault,
+        current,
+        options: tangent_options(neighbors)
+            .into_iter()
+            .filter(|option| option.source != TangentSource::None)
+            .collect::<Vec<TangentOptionData>>(),
+        enabled: true,
+    }
+}
@@ -0,0 +1,233 @@
+//! Geometrie des Klothoiden-Tools: G1-Hermite-Interpolation mit einer Euler-Spirale.
+//!
+//! Eine Klothoide hat eine linear mit der Bogenlaenge wachsende Kruemmung
+//! `κ(s) = κ0 + σ·s`. Dadurch gibt es innerhalb des Bogens keine
+//! Kruemmungsspruenge, wie sie an Bézier-Stoessen entstehen.
+//!
+//! Die Anpassung an zwei Punkte mit vorgegebenen Richtungen folgt dem
+//! Verfahren von Bertolazzi/Frego ("G1 fitting with clothoids", 2015): Nach
+//! Normierung auf die Sehne bleibt eine einzige Unbekannte `A`, die per
+//! Newton-Iteration bestimmt wird. Alle Integrale werden numerisch (Simpson)
+//! in `f64` ausgewertet.
+
+use glam::{DVec2, Vec2};
+use std::f64::consts::PI;
+
+/// Simpson-Stuetzstellen fuer die Anpassungs-Integrale (gerade Anzahl).
+const FIT_QUADRATURE_STEPS: usize = 128;
+/// Simpson-Stuetzstellen je Ausgabe-Segment beim Abtasten (gerade Anzahl).
+const SAMPLE_QUADRATURE_STEPS: usize = 16;
+/// Maximale Newton-Iterationen fuer die Anpassung.
+const MAX_NEWTON_ITERATIONS: usize = 40;
+/// Abbruchtoleranz fuer das Residuum der Anpassung.
+const NEWTON_TOLERANCE: f64 = 1e-10;
+/// Kruemmungen unterhalb dieses Werts gelten als gerade (1/m).
+const STRAIGHT_CURVATURE: f32 = 1e-5;
+
+/// Ein Klothoiden-Bogen zwischen zwei Punkten.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClothoidSegment {
+    /// Startpunkt.
+    pub start: Vec2,
+    /// Endpunkt (exakt, fuer das Abtasten).
+    pub end: Vec2,
+    /// Fahrtrichtung am Start (Radiant).
+    pub heading: f32,
+    /// Kruemmung am Start (1/m, positiv = Linkskurve).
+    pub curvature: f32,
+    /// Kruemmungsaenderung je Meter Bogenlaenge (1/m²).
+    pub sharpness: f32,
+    /// Bogenlaenge in Metern.
+    pub length: f32,
+}
+
+impl ClothoidSegment {
+    /// Kruemmung am Ende des Bogens.
+    pub fn end_curvature(&self) -> f32 {
+        self.curvature + self.sharpness * self.length
+    }
+
+    /// Fahrtrichtung am Ende des Bogens (Radiant).
+    #[cfg(test)]
+    pub fn end_heading(&self) -> f32 {
+        self.heading + self.curvature * self.length + 0.5 * self.sharpness * self.length.powi(2)
+    }
+
+    /// Kleinster Kurvenradius entlang des Bogens; `None` bei (nahezu) gerader Strecke.
+    ///
+    /// Da die Kruemmung linear verlaeuft, liegt das Maximum immer an einem Ende.
+    pub fn min_radius(&self) -> Option<f32> {
+        let max_curvature = self.curvature.abs().max(self.end_curvature().abs());
+        (max_curvature > STRAIGHT_CURVATURE).then(|| 1.0 / max_curvature)
+    }
+
+    /// Tastet den Bogen in gleichen Bogenlaengen-Abstaenden ab.
+    ///
+    /// Gibt `segment_count + 1` Punkte zurueck (inkl. Start und Ende); kein
+    /// Abstand ist groesser als `max_segment_length`.
+    pub fn sample(&self, max_segment_length: f32) -> Vec<Vec2> {
+        let length = f64::from(self.length);
+        let segment_count = (self.length / max_segment_length.max(0.1)).ceil().max(1.0) as usize;
+        let step = length / segment_count as f64;
+
+        let mut points = Vec::with_capacity(segment_count + 1);
+        points.push(self.start);
+        let mut position = self.start.as_dvec2();
+        for index in 1..segment_count {
+            let from = step * (index - 1) as f64;
+            position += self.integrate_direction(from, from + step);
+            points.push(position.as_vec2());
+        }
+        points.push(self.end);
+        points
+    }
+
+    /// ∫ (cos θ(s), sin θ(s)) ds zwischen `from` und `to` (Simpson).
+    fn integrate_direction(&self, from: f64, to: f64) -> DVec2 {
+        let heading = f64::from(self.heading);
+        let curvature = f64::from(self.curvature);
+        let sharpness = f64::from(self.sharpness);
+        simpson(from, to, SAMPLE_QUADRATURE_STEPS, |s| {
+            let theta = heading + curvature * s + 0.5 * sharpness * s * s;
+            DVec2::new(theta.cos(), theta.sin())
+        })
+    }
+}
+
+/// Bestimmt die Klothoide von `start` (Richtung `start_heading`) nach `end`
+/// (Richtung `end_heading`).
+///
+/// # Rueckgabe
+/// `None` wenn Start und Ende zusammenfallen oder die Iteration nicht konvergiert
+pub fn fit_clothoid(
+    start: Vec2,
+    start_heading: f32,
+    end: Vec2,
+    end_heading: f32,
+) -> Option<ClothoidSegment> {
+    let chord = (end - start).as_dvec2();
+    let chord_length = chord.length();
+    if chord_length < 1e-6 {
+        return None;
+    }
+    let chord_angle = chord.y.atan2(chord.x);
+    let phi0 = normalize_angle(f64::from(start_heading) - chord_angle);
+    let phi1 = normalize_angle(f64::from(end_heading) - chord_angle);
+    let delta = phi1 - phi0;
+
+    let mut a = 3.0 * (phi0 + phi1);
+    let mut converged = false;
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let (_, residual, derivative) = fit_moments(a, delta - a, phi0);
+        if residual.abs() < NEWTON_TOLERANCE {
+            converged = true;
+            break;
+        }
+        if derivative.abs() < 1e-14 {
+            return None;
+        }
+        a -= residual / derivative;
+    }
+    if !converged {
+        return None;
+    }
+
+    let (x, _, _) = fit_moments(a, delta - a, phi0);
+    if x <= 1e-9 {
+        return None;
+    }
+    let length = chord_length / x;
+
+    Some(ClothoidSegment {
+        start,
+        end,
+        heading: (chord_angle + phi0) as f32,
+        curvature: ((delta - a) / length) as f32,
+        sharpness: (2.0 * a / (length * length)) as f32,
+        length: length as f32,
+    })
+}
+
+/// Loest fehlende Richtungen an Start und Ende auf.
+///
+/// - beide vorgegeben: unveraendert
+/// - nur eine vorgegeben: die andere wird an der Sehne gespiegelt (Kreisbogen)
+/// - keine vorgegeben: beide entlang der Sehne (Gerade)
+pub fn resolve_headings(
+    start: Vec2,
+    end: Vec2,
+    start_heading: Option<f32>,
+    end_heading: Option<f32>,
+) -> (f32, f32) {
+    let chord = end - start;
+    let chord_angle = chord.y.atan2(chord.x);
+    match (start_heading, end_heading) {
+        (Some(from), Some(to)) => (from, to),
+        (Some(from), None) => (from, 2.0 * chord_angle - from),
+        (None, Some(to)) => (2.0 * chord_angle - to, to),
+        (None, None) => (chord_angle, chord_angle),
+    }
+}
+
+/// Normiert einen Winkel auf (-π, π].
+fn normalize_angle(angle: f64) -> f64 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped <= -PI {
+        wrapped + 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
+/// Liefert `(X, Y, dY/dA)` fuer die normierte Phase `a·t² + b·t + c` auf [0, 1].
+///
+/// `X`/`Y` sind die Fresnel-artigen Integrale ueber cos/sin der Phase;
+/// `dY/dA` ist die Ableitung von `Y(A, δ − A, φ0)` nach `A`.
+fn fit_moments(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+    let moments = simpson(0.0, 1.0, FIT_QUADRATURE_STEPS, |t| {
+        let phase = a * t * t + b * t + c;
+        let (sin, cos) = phase.sin_cos();
+        [cos, sin, cos * (t * t - t)]
+    });
+    (moments[0], moments[1], moments[2])
+}
+
+/// Zusammengesetzte Simpson-Regel fuer vektorwertige Integranden.
+fn simpson<T>(from: f64, to: f64, steps: usize, f: impl Fn(f64) -> T) -> T
+where
+    T: SimpsonValue,
+{
+    let h = (to - from) / steps as f64;
+    let mut sum = f(from).add(f(to));
+    for index in 1..steps {
+        let weight = if index % 2 == 1 { 4.0 } else { 2.0 };
+        sum = sum.add(f(from + h * index as f64).scale(weight));
+    }
+    sum.scale(h / 3.0)
+}
+
+/// Minimale Vektor-Operationen fuer [`simpson`].
+trait SimpsonValue: Copy {
+    fn add(self, other: Self) -> Self;
+    fn scale(self, factor: f64) -> Self;
+}
+
+impl SimpsonValue for DVec2 {
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        self * factor
+    }
+}
+
+impl SimpsonValue for [f64; 3] {
+    fn add(self, other: Self) -> Self {
+        [self[0] + other[0], self[1] + other[1], self[2] + other[2]]
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        self.map(|value| value * factor)
+    }
+}
@@ -0,0 +1,299 @@
+//! Lifecycle-Methoden des ClothoidTool.
+
+use super::super::{
+    common::{
+        populate_neighbors, record_applied_tool_state, suggest_tangent, sync_tool_host,
+        tangent_options,
+    },
+    RouteTool, RouteToolCore, RouteToolGroupEdit, RouteToolHostSync, RouteToolPanelBridge,
+    RouteToolRecreate, RouteToolSegmentAdjustments, RouteToolTangent, ToolAction, ToolHostContext,
+    ToolPreview, ToolResult,
+};
+use super::state::ClothoidTool;
+use crate::app::tool_contract::TangentSource;
+use crate::app::tool_editing::{RouteToolEditPayload, ToolEditAnchors, ToolRouteBase};
+use crate::app::ui_contract::{
+    RouteToolConfigState, RouteToolPanelAction, RouteToolPanelEffect, TangentMenuData,
+};
+use crate::core::RoadMap;
+use glam::Vec2;
+
+impl RouteToolPanelBridge for ClothoidTool {
+    fn status_text(&self) -> &str {
+        match (&self.start, &self.end) {
+            (None, _) => "Startpunkt klicken",
+            (Some(_), None) => "Endpunkt klicken",
+            (Some(_), Some(_)) => "Bereit — Enter zum Ausfuehren, Escape zum Abbrechen",
+        }
+    }
+
+    fn panel_state(&self) -> RouteToolConfigState {
+        RouteToolConfigState::Clothoid(self.panel_state())
+    }
+
+    fn apply_panel_action(&mut self, action: RouteToolPanelAction) -> RouteToolPanelEffect {
+        let RouteToolPanelAction::Clothoid(action) = action else {
+            return RouteToolPanelEffect::default();
+        };
+
+        self.apply_panel_action(action)
+    }
+}
+
+impl RouteToolCore for ClothoidTool {
+    fn on_click(&mut self, pos: Vec2, road_map: &RoadMap, _ctrl: bool) -> ToolAction {
+        let (anchor, neighbors) = self.lifecycle.snap_with_neighbors(pos, road_map);
+
+        if self.start.is_none() {
+            if let Some(last_end) = self.lifecycle.chaining_start_anchor() {
+                self.lifecycle.prepare_for_chaining();
+                self.last_start_anchor = None;
+                self.tangents.reset_tangents();
+                self.tangents.start_neighbors = populate_neighbors(&last_end, road_map);
+                self.tangents.end_neighbors = neighbors;
+                self.start = Some(last_end);
+                self.end = Some(anchor);
+                self.auto_suggest_tangents();
+                self.sync_derived();
+                ToolAction::ReadyToExecute
+            } else {
+                self.tangents.reset_tangents();
+                self.tangents.start_neighbors = neighbors;
+                self.start = Some(anchor);
+                ToolAction::Continue
+            }
+        } else {
+            self.tangents.end_neighbors = neighbors;
+            self.tangents.tangent_end = TangentSource::None;
+            self.end = Some(anchor);
+            self.auto_suggest_tangents();
+            self.sync_derived();
+            ToolAction::ReadyToExecute
+        }
+    }
+
+    fn preview(&self, cursor_pos: Vec2, road_map: &RoadMap) -> ToolPreview {
+        let Some(start) = self.start else {
+            return ToolPreview::default();
+        };
+        let end_pos = match &self.end {
+            Some(anchor) => anchor.position(),
+            None => self.lifecycle.snap_at(cursor_pos, road_map).position(),
+        };
+
+        // Waehrend der Endpunkt-Suche folgt die Start-Tangente der Sehne zum Cursor
+        let tangent_start = match (self.end, (end_pos - start.position()).try_normalize()) {
+            (None, Some(chord_dir)) => {
+                suggest_tangent(&self.tangents.start_neighbors, chord_dir, true, false)
+                    .unwrap_or(self.tangents.tangent_start)
+            }
+            _ => self.tangents.tangent_start,
+        };
+
+        let positions = match Self::segment_between(
+            start.position(),
+            end_pos,
+            tangent_start,
+            self.tangents.tangent_end,
+        ) {
+            Some(segment) => segment.sample(self.seg.max_segment_length),
+            None => vec![start.position(), end_pos],
+        };
+        ToolPreview::from_polyline(positions, self.direction, self.priority)
+    }
+
+    fn execute(&self, road_map: &RoadMap) -> Option<ToolResult> {
+        self.build_result(self.start?, self.end?, road_map)
+    }
+
+    fn reset(&mut self) {
+        self.start = None;
+        self.end = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.start.is_some() && self.end.is_some()
+    }
+
+    fn has_pending_input(&self) -> bool {
+        self.start.is_some()
+    }
+
+    fn segment_origin(&self) -> Option<Vec2> {
+        if self.end.is_some() {
+            return None;
+        }
+        self.start
+            .or_else(|| self.lifecycle.chaining_start_anchor())
+            .map(|anchor| anchor.position())
+    }
+}
+
+impl RouteToolHostSync for ClothoidTool {
+    fn sync_host(&mut self, context: &ToolHostContext) {
+        sync_tool_host(
+            &mut self.direction,
+            &mut self.priority,
+            &mut self.lifecycle,
+            context,
+        );
+    }
+}
+
+impl RouteToolRecreate for ClothoidTool {
+    fn on_applied(&mut self, ids: &[u64], _road_map: &RoadMap) {
+        if self.start.is_some() {
+            self.last_start_anchor = self.start;
+        }
+        self.tangents.save_for_recreate();
+        let end_anchor = self.end.or(self.lifecycle.last_end_anchor);
+        record_applied_tool_state(&mut self.lifecycle, ids, end_anchor);
+    }
+
+    fn last_created_ids(&self) -> &[u64] {
+        &self.lifecycle.last_created_ids
+    }
+
+    fn last_end_anchor(&self) -> Option<super::super::ToolAnchor> {
+        self.lifecycle.last_end_anchor
+    }
+
+    fn needs_recreate(&self) -> bool {
+        self.lifecycle.recreate_needed
+    }
+
+    fn clear_recreate_flag(&mut self) {
+        self.lifecycle.recreate_needed = false;
+    }
+
+    fn execute_from_anchors(&self, road_map: &RoadMap) -> Option<ToolResult> {
+        // Aktuelle Tangenten verwenden, damit Aenderungen im Nachbearbeitungs-Modus wirken
+        self.build_result(
+            self.last_start_anchor?,
+            self.lifecycle.last_end_anchor?,
+            road_map,
+        )
+    }
+}
+
+impl RouteToolTangent for ClothoidTool {
+    fn tangent_menu_data(&self) -> Option<TangentMenuData> {
+        if !self.is_ready() && !self.is_adjusting() {
+            return None;
+        }
+        if self.tangents.start_neighbors.is_empty() && self.tangents.end_neighbors.is_empty() {
+            return None;
+        }
+
+        Some(TangentMenuData {
+            start_options: tangent_options(&self.tangents.start_neighbors),
+            end_options: tangent_options(&self.tangents.end_neighbors),
+            current_start: self.tangents.tangent_start,
+            current_end: self.tangents.tangent_end,
+        })
+    }
+
+    fn apply_tangent_selection(&mut self, start: TangentSource, end: TangentSource) {
+        self.tangents.tangent_start = start;
+        self.tangents.tangent_end = end;
+        self.sync_derived();
+        if self.lifecycle.has_last_created() {
+            self.lifecycle.recreate_needed = true;
+        }
+    }
+}
+
+impl RouteToolSegmentAdjustments for ClothoidTool {
+    fn increase_node_count(&mut self) {
+        self.seg.increase_node_count();
+        self.lifecycle.recreate_needed = true;
+    }
+
+    fn decrease_node_count(&mut self) {
+        self.seg.decrease_node_count();
+        self.lifecycle.recreate_needed = true;
+    }
+
+    fn increase_segment_length(&mut self) {
+        self.seg.increase_segment_length();
+        self.lifecycle.recreate_needed = true;
+    }
+
+    fn decrease_segment_length(&mut self) {
+        self.seg.decrease_segment_length();
+        self.lifecycle.recreate_needed = true;
+    }
+}
+
+impl RouteTool for ClothoidTool {
+    fn as_recreate(&self) -> Option<&dyn RouteToolRecreate> {
+        Some(self)
+    }
+
+    fn as_recreate_mut(&mut self) -> Option<&mut dyn RouteToolRecreate> {
+        Some(self)
+    }
+
+    fn as_tangent(&self) -> Option<&dyn RouteToolTangent> {
+        Some(self)
+    }
+
+    fn as_tangent_mut(&mut self) -> Option<&mut dyn RouteToolTangent> {
+        Some(self)
+    }
+
+    fn as_segment_adjustments(&self) -> Option<&dyn RouteToolSegmentAdjustments> {
+        Some(self)
+    }
+
+    fn as_segment_adjustments_mut(&mut self) -> Option<&mut dyn RouteToolSegmentAdjustments> {
+        Some(self)
+    }
+
+    fn as_group_edit(&self) -> Option<&dyn RouteToolGroupEdit> {
+        Some(self)
+    }
+
+    fn as_group_edit_mut(&mut self) -> Option<&mut dyn RouteToolGroupEdit> {
+        Some(self)
+    }
+}
+
+impl RouteToolGroupEdit for ClothoidTool {
+    fn build_edit_payload(&self) -> Option<RouteToolEditPayload> {
+        let start = self.last_start_anchor?;
+        let end = self.lifecycle.last_end_anchor?;
+        Some(RouteToolEditPayload::Clothoid {
+            anchors: ToolEditAnchors { start, end },
+            tangent_start: self.tangents.last_tangent_start,
+            tangent_end: self.tangents.last_tangent_end,
+            base: ToolRouteBase {
+                direction: self.direction,
+                priority: self.priority,
+                max_segment_length: self.seg.max_segment_length,
+            },
+        })
+    }
+
+    fn restore_edit_payload(&mut self, payload: &RouteToolEditPayload) {
+        let RouteToolEditPayload::Clothoid {
+            anchors,
+            tangent_start,
+            tangent_end,
+            base,
+        } = payload
+        else {
+            return;
+        };
+        self.start = Some(anchors.start);
+        self.end = Some(anchors.end);
+        self.tangents.tangent_start = *tangent_start;
+        self.tangents.tangent_end = *tangent_end;
+        self.tangents.last_tangent_start = *tangent_start;
+        self.tangents.last_tangent_end = *tangent_end;
+        self.direction = base.direction;
+        self.priority = base.priority;
+        self.seg.max_segment_length = base.max_segment_length;
+        self.sync_derived();
+    }
+}
@@ -0,0 +1,17 @@
+//! Klothoiden-Tool — kruemmungsstetiger Uebergangsbogen zwischen zwei Richtungen.
+//!
+//! Aufgeteilt in:
+//! - `state`     — Struct, Konstruktor, Tangenten- und Ergebnis-Hilfsmethoden
+//! - `lifecycle` — RouteTool-Implementierung (on_click, preview, execute, reset, …)
+//! - `geometry`  — Euler-Spiralen-Anpassung und Abtastung
+//! - `config_ui` — UI-Konfigurationspanel
+
+mod config_ui;
+pub(crate) mod geometry;
+mod lifecycle;
+mod state;
+
+pub use state::ClothoidTool;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,153 @@
+//! State-Definitionen und Hilfsmethoden fuer das Klothoiden-Tool.
+
+use super::super::common::{
+    self, suggest_tangent, SegmentConfig, TangentState, ToolLifecycleState,
+};
+use super::super::{ToolAnchor, ToolResult};
+use super::geometry::{fit_clothoid, resolve_headings, ClothoidSegment};
+use crate::app::tool_contract::TangentSource;
+use crate::core::{ConnectionDirection, ConnectionPriority, RoadMap};
+use glam::Vec2;
+
+/// Klothoiden-Tool: kruemmungsstetiger Uebergangsbogen zwischen zwei Richtungen.
+pub struct ClothoidTool {
+    pub(crate) start: Option<ToolAnchor>,
+    pub(crate) end: Option<ToolAnchor>,
+    /// Segment-Konfiguration (Abstand / Node-Anzahl)
+    pub(crate) seg: SegmentConfig,
+    pub direction: ConnectionDirection,
+    pub priority: ConnectionPriority,
+    /// Gemeinsamer Lifecycle-Zustand (IDs, Endpunkt-Anker, Recreate-Flag, Snap-Radius)
+    pub(crate) lifecycle: ToolLifecycleState,
+    /// Start-Anker der letzten Erstellung (fuer Neuberechnung)
+    pub(crate) last_start_anchor: Option<ToolAnchor>,
+    /// Tangenten-Zustand (Start/Ende, Nachbarn-Cache, Recreation-Kopien)
+    pub(crate) tangents: TangentState,
+}
+
+impl ClothoidTool {
+    /// Erstellt ein neues Klothoiden-Tool mit Standardwerten.
+    pub fn new() -> Self {
+        Self {
+            start: None,
+            end: None,
+            seg: SegmentConfig::new(2.0),
+            direction: ConnectionDirection::Dual,
+            priority: ConnectionPriority::Regular,
+            lifecycle: ToolLifecycleState::new(3.0), // Default, wird vom Handler ueberschrieben
+            last_start_anchor: None,
+            tangents: TangentState::new(),
+        }
+    }
+
+    /// Bestimmt den Klothoiden-Bogen zwischen zwei Punkten.
+    ///
+    /// Eine Start-Tangente fuehrt von der gewaehlten Verbindung weg
+    /// (Fortsetzung ueber den Start-Node hinaus), eine End-Tangente in Richtung
+    /// des gewaehlten Nachbarn (Weiterfahrt ueber den End-Node hinaus).
+    pub(crate) fn segment_between(
+        start: Vec2,
+        end: Vec2,
+        tangent_start: TangentSource,
+        tangent_end: TangentSource,
+    ) -> Option<ClothoidSegment> {
+        let start_heading = match tangent_start {
+            TangentSource::Connection { angle, .. } => Some(angle + std::f32::consts::PI),
+            TangentSource::None => None,
+        };
+        let end_heading = match tangent_end {
+            TangentSource::Connection { angle, .. } => Some(angle),
+            TangentSource::None => None,
+        };
+        let (from, to) = resolve_headings(start, end, start_heading, end_heading);
+        fit_clothoid(start, from, end, to)
+    }
+
+    /// Aktueller Bogen (Live-Modus) oder der zuletzt erzeugte (Adjusting-Modus).
+    pub(crate) fn current_segment(&self) -> Option<ClothoidSegment> {
+        let (start, end) = if self.is_adjusting() {
+            (self.last_start_anchor?, self.lifecycle.last_end_anchor?)
+        } else {
+            (self.start?, self.end?)
+        };
+        Self::segment_between(
+            start.position(),
+            end.position(),
+            self.tangents.tangent_start,
+            self.tangents.tangent_end,
+        )
+    }
+
+    /// True wenn die zuletzt erzeugte Klothoide nachbearbeitet wird.
+    pub(crate) fn is_adjusting(&self) -> bool {
+        self.lifecycle.has_last_created()
+            && self.last_start_anchor.is_some()
+            && self.lifecycle.last_end_anchor.is_some()
+    }
+
+    /// Bogenlaenge des aktuellen Bogens (0.0 wenn nicht bestimmbar).
+    pub(crate) fn clothoid_length(&self) -> f32 {
+        self.current_segment()
+            .map(|segment| segment.length)
+            .unwrap_or(0.0)
+    }
+
+    /// Synchronisiert den jeweils abhaengigen Wert.
+    pub(crate) fn sync_derived(&mut self) {
+        self.seg.sync_from_length(self.clothoid_length());
+    }
+
+    /// Schlaegt Start- und End-Tangente anhand der Nachbarn und der Sehne vor.
+    ///
+    /// Gleiche Auswahlregeln wie beim kubischen Kurven-Tool: am Start wird
+    /// immer der beste Nachbar gewaehlt, am Ende nur einer in Sehnenrichtung.
+    pub(crate) fn auto_suggest_tangents(&mut self) {
+        let (Some(start), Some(end)) = (self.start, self.end) else {
+            return;
+        };
+        let Some(chord_dir) = (end.position() - start.position()).try_normalize() else {
+            return;
+        };
+        if let Some(source) =
+            suggest_tangent(&self.tangents.start_neighbors, chord_dir, true, false)
+        {
+            self.tangents.tangent_start = source;
+        }
+        if let Some(source) = suggest_tangent(&self.tangents.end_neighbors, chord_dir, false, true)
+        {
+            self.tangents.tangent_end = source;
+        }
+    }
+
+    /// Baut ein `ToolResult` zwischen zwei Ankern mit den aktuellen Tangenten.
+    ///
+    /// Zentrale Logik fuer `execute()` und `execute_from_anchors()`.
+    pub(crate) fn build_result(
+        &self,
+        start: ToolAnchor,
+        end: ToolAnchor,
+        road_map: &RoadMap,
+    ) -> Option<ToolResult> {
+        let segment = Self::segment_between(
+            start.position(),
+            end.position(),
+            self.tangents.tangent_start,
+            self.tangents.tangent_end,
+        )?;
+        let positions = segment.sample(self.seg.max_segment_length);
+        Some(common::assemble_tool_result(
+            &positions,
+            &start,
+            &end,
+            self.direction,
+            self.priority,
+            road_map,
+        ))
+    }
+}
+
+impl Default for ClothoidTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
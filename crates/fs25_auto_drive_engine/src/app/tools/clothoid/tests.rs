@@ -0,0 +1,205 @@
+use super::super::{RouteToolCore, RouteToolPanelBridge, RouteToolRecreate, ToolAction};
+use super::geometry::{fit_clothoid, resolve_headings};
+use super::state::ClothoidTool;
+use crate::app::tool_contract::TangentSource;
+use crate::app::ui_contract::RouteToolConfigState;
+use crate::core::{ConnectedNeighbor, RoadMap};
+use glam::Vec2;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+// ── fit_clothoid ──
+
+#[test]
+fn test_fit_clothoid_straight_line() {
+    let segment = fit_clothoid(Vec2::ZERO, 0.0, Vec2::new(20.0, 0.0), 0.0)
+        .expect("Gerade sollte loesbar sein");
+
+    assert!((segment.length - 20.0).abs() < 1e-3);
+    assert!(segment.curvature.abs() < 1e-5);
+    assert!(segment.sharpness.abs() < 1e-5);
+    assert!(segment.min_radius().is_none());
+}
+
+#[test]
+fn test_fit_clothoid_symmetric_headings_give_circular_arc() {
+    // Viertelkreis mit Radius 10: Start nach Osten, Ende nach Norden (+Y)
+    let segment = fit_clothoid(Vec2::ZERO, 0.0, Vec2::new(10.0, 10.0), FRAC_PI_2)
+        .expect("Viertelkreis sollte loesbar sein");
+
+    assert!(
+        segment.sharpness.abs() < 1e-4,
+        "Symmetrische Richtungen → konstante Kruemmung, war {}",
+        segment.sharpness
+    );
+    assert!((segment.curvature - 0.1).abs() < 1e-3);
+    assert!((segment.length - 5.0 * PI).abs() < 1e-2);
+    let radius = segment.min_radius().expect("Bogen hat einen Radius");
+    assert!((radius - 10.0).abs() < 0.05);
+}
+
+#[test]
+fn test_fit_clothoid_reaches_end_point_and_heading() {
+    let start = Vec2::new(5.0, -3.0);
+    let end = Vec2::new(40.0, 12.0);
+    let segment = fit_clothoid(start, 0.2, end, 1.1).expect("Klothoide sollte loesbar sein");
+
+    assert!((segment.end_heading() - 1.1).abs() < 1e-3);
+    assert!(
+        segment.sharpness.abs() > 1e-5,
+        "Asymmetrische Richtungen erfordern Kruemmungsaenderung"
+    );
+
+    // Numerisch integrierter vorletzter Punkt liegt nahe am exakten Endpunkt
+    let points = segment.sample(0.5);
+    let before_end = points[points.len() - 2];
+    assert!(before_end.distance(end) <= 0.5 + 1e-2);
+}
+
+#[test]
+fn test_fit_clothoid_s_curve_changes_curvature_sign() {
+    // Parallelversatz: gleiche Richtung an Start und Ende, Ziel seitlich verschoben
+    let segment = fit_clothoid(Vec2::ZERO, 0.0, Vec2::new(30.0, 6.0), 0.0)
+        .expect("S-Kurve sollte loesbar sein");
+
+    assert!(
+        segment.curvature * segment.end_curvature() < 0.0,
+        "S-Kurve braucht Vorzeichenwechsel der Kruemmung: {} → {}",
+        segment.curvature,
+        segment.end_curvature()
+    );
+}
+
+#[test]
+fn test_fit_clothoid_coincident_points_returns_none() {
+    assert!(fit_clothoid(Vec2::ONE, 0.0, Vec2::ONE, 1.0).is_none());
+}
+
+// ── sample / resolve_headings ──
+
+#[test]
+fn test_sample_respects_max_segment_length() {
+    let segment = fit_clothoid(Vec2::ZERO, 0.3, Vec2::new(50.0, 20.0), 1.2).unwrap();
+    let points = segment.sample(2.0);
+
+    assert_eq!(points.first(), Some(&Vec2::ZERO));
+    assert_eq!(points.last(), Some(&Vec2::new(50.0, 20.0)));
+    for pair in points.windows(2) {
+        assert!(pair[0].distance(pair[1]) <= 2.0 + 1e-3);
+    }
+}
+
+#[test]
+fn test_resolve_headings_mirrors_missing_side() {
+    let (from, to) = resolve_headings(Vec2::ZERO, Vec2::new(10.0, 0.0), Some(0.4), None);
+    assert!((from - 0.4).abs() < 1e-6);
+    assert!((to + 0.4).abs() < 1e-6);
+
+    let (from, to) = resolve_headings(Vec2::ZERO, Vec2::new(0.0, 10.0), None, None);
+    assert!((from - FRAC_PI_2).abs() < 1e-6);
+    assert!((to - FRAC_PI_2).abs() < 1e-6);
+}
+
+// ── Tool-Lifecycle ──
+
+#[test]
+fn test_two_clicks_ready_and_execute() {
+    let mut tool = ClothoidTool::new();
+    let road_map = RoadMap::new(3);
+
+    assert_eq!(
+        tool.on_click(Vec2::ZERO, &road_map, false),
+        ToolAction::Continue
+    );
+    assert_eq!(
+        tool.on_click(Vec2::new(20.0, 0.0), &road_map, false),
+        ToolAction::ReadyToExecute
+    );
+    assert!(tool.is_ready());
+
+    let result = tool.execute(&road_map).expect("Ergebnis erwartet");
+    // 20 m bei 2 m Abstand → 11 Nodes, 10 Verbindungen
+    assert_eq!(result.new_nodes.len(), 11);
+    assert_eq!(result.internal_connections.len(), 10);
+}
+
+#[test]
+fn test_segment_between_uses_connection_tangents() {
+    // Start: eingehend von Westen → Fahrtrichtung Ost; Ende: Weiterfahrt nach Sueden (+Y)
+    let segment = ClothoidTool::segment_between(
+        Vec2::ZERO,
+        Vec2::new(20.0, 20.0),
+        TangentSource::Connection {
+            neighbor_id: 1,
+            angle: PI,
+        },
+        TangentSource::Connection {
+            neighbor_id: 2,
+            angle: FRAC_PI_2,
+        },
+    )
+    .unwrap();
+
+    assert!(segment.heading.abs() < 1e-4);
+    assert!((segment.end_heading() - FRAC_PI_2).abs() < 1e-3);
+}
+
+#[test]
+fn test_panel_shows_tangents_and_min_radius_when_ready() {
+    let mut tool = ClothoidTool::new();
+    let road_map = RoadMap::new(3);
+
+    tool.on_click(Vec2::ZERO, &road_map, false);
+    tool.tangents.start_neighbors = vec![ConnectedNeighbor {
+        neighbor_id: 10,
+        angle: PI,
+        is_outgoing: false,
+    }];
+    tool.on_click(Vec2::new(10.0, 10.0), &road_map, false);
+
+    assert!(matches!(
+        tool.tangents.tangent_start,
+        TangentSource::Connection {
+            neighbor_id: 10,
+            ..
+        }
+    ));
+
+    let RouteToolConfigState::Clothoid(state) = RouteToolPanelBridge::panel_state(&tool) else {
+        panic!("Klothoiden-Panelzustand erwartet");
+    };
+    assert!(state.start_tangent.is_some());
+    assert!(state.end_tangent.is_none());
+    // Ost-Start + gespiegelte End-Richtung → Viertelkreis mit Radius 10
+    let radius = state.min_radius_m.expect("Radius erwartet");
+    assert!((radius - 10.0).abs() < 0.05);
+}
+
+#[test]
+fn test_tangent_change_after_apply_requests_recreate() {
+    let mut tool = ClothoidTool::new();
+    let road_map = RoadMap::new(3);
+
+    tool.on_click(Vec2::ZERO, &road_map, false);
+    tool.on_click(Vec2::new(20.0, 5.0), &road_map, false);
+    tool.on_applied(&[1, 2, 3], &road_map);
+    tool.reset();
+    tool.clear_recreate_flag();
+
+    tool.tangents.start_neighbors = vec![ConnectedNeighbor {
+        neighbor_id: 5,
+        angle: PI,
+        is_outgoing: false,
+    }];
+    let tangent_start = TangentSource::Connection {
+        neighbor_id: 5,
+        angle: PI,
+    };
+    super::super::RouteToolTangent::apply_tangent_selection(
+        &mut tool,
+        tangent_start,
+        TangentSource::None,
+    );
+
+    assert!(tool.needs_recreate());
+    assert!(tool.execute_from_anchors(&road_map).is_some());
+}
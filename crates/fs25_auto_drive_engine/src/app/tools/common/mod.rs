@@ -26,4 +26,4 @@ pub(crate) use lifecycle::{
     record_applied_tool_state, sync_tool_host, SegmentConfig, ToolLifecycleState,
 };
 pub(crate) use result::ToolResultBuilder;
-pub(crate) use tangent::TangentState;
+pub(crate) use tangent::{suggest_tangent, TangentState};
@@ -2,6 +2,7 @@
 
 use crate::app::tool_contract::TangentSource;
 use crate::core::ConnectedNeighbor;
+use glam::Vec2;
 
 /// Gemeinsamer Tangenten-Zustand fuer Curve- und Spline-Tool.
 ///
@@ -70,3 +71,56 @@ impl Default for TangentState {
         Self::new()
     }
 }
+
+/// Parametrisierte Auto-Tangenten-Auswahl (gemeinsam fuer Start und Ende).
+///
+/// Wird von CurveTool und ClothoidTool genutzt.
+///
+/// - `neighbors`: Verfuegbare Nachbarn am betreffenden Endpunkt
+/// - `chord_dir`: Normalisierte Sehnenrichtung Start→Ende (immer gleich fuer beide)
+/// - `is_start`: true = Start-Tangente (bevorzugt incoming, vergleicht angle+PI),
+///   false = End-Tangente (bevorzugt outgoing, vergleicht angle direkt)
+/// - `require_forward_dot`: true => nur Kandidaten mit `dot > 0`,
+///   false => bester Kandidat auch bei `dot <= 0` erlaubt
+pub(crate) fn suggest_tangent(
+    neighbors: &[ConnectedNeighbor],
+    chord_dir: Vec2,
+    is_start: bool,
+    require_forward_dot: bool,
+) -> Option<TangentSource> {
+    use std::f32::consts::PI;
+    if neighbors.is_empty() {
+        return None;
+    }
+
+    // Start: eingehende bevorzugen; Ende: ausgehende bevorzugen
+    let prefer_outgoing = !is_start;
+    let preferred: Vec<_> = neighbors
+        .iter()
+        .filter(|n| n.is_outgoing == prefer_outgoing)
+        .collect();
+    let candidates = if preferred.is_empty() {
+        neighbors.iter().collect::<Vec<_>>()
+    } else {
+        preferred
+    };
+
+    // Start: Fortsetzungsrichtung (angle + PI) mit Sehnenrichtung vergleichen
+    // Ende: Richtung direkt (angle) mit Sehnenrichtung vergleichen
+    let angle_offset = if is_start { PI } else { 0.0 };
+
+    let best = candidates.iter().max_by(|a, b| {
+        let da = Vec2::from_angle(a.angle + angle_offset).dot(chord_dir);
+        let db = Vec2::from_angle(b.angle + angle_offset).dot(chord_dir);
+        da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+    })?;
+    let dot = Vec2::from_angle(best.angle + angle_offset).dot(chord_dir);
+    if !require_forward_dot || dot > 0.0 {
+        Some(TangentSource::Connection {
+            neighbor_id: best.neighbor_id,
+            angle: best.angle,
+        })
+    } else {
+        None
+    }
+}
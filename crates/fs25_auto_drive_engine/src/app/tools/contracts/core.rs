@@ -28,4 +28,12 @@ pub trait RouteToolCore {
 
     /// Gibt an, ob das Tool bereits angefangene Eingaben haelt.
     fn has_pending_input(&self) -> bool;
+
+    /// Startpunkt des Segments, das der naechste Klick abschliesst.
+    ///
+    /// Dient als Ursprung fuer den Winkel-Snap; Tools ohne gerichtete
+    /// Segment-Platzierung liefern `None` und rasten nur auf das Raster ein.
+    fn segment_origin(&self) -> Option<Vec2> {
+        None
+    }
 }
@@ -221,6 +221,15 @@ impl RouteToolCore for CurveTool {
     fn has_pending_input(&self) -> bool {
         self.phase != Phase::Start
     }
+
+    fn segment_origin(&self) -> Option<Vec2> {
+        match self.phase {
+            Phase::Start => self.lifecycle.chaining_start_anchor(),
+            Phase::End => self.start,
+            _ => None,
+        }
+        .map(|anchor| anchor.position())
+    }
 }
 
 impl RouteToolHostSync for CurveTool {
@@ -1,6 +1,6 @@
 //! State-Definitionen und Konstruktor fuer das Bézier-Kurven-Tool.
 
-use super::super::common::{SegmentConfig, TangentState, ToolLifecycleState};
+use super::super::common::{suggest_tangent, SegmentConfig, TangentState, ToolLifecycleState};
 use super::super::ToolAnchor;
 use super::geometry::{
     approx_length, compute_curve_positions, compute_tangent_cp, cubic_bezier, quadratic_bezier,
@@ -176,7 +176,7 @@ impl CurveTool {
         if chord_len < f32::EPSILON {
             return;
         }
-        if let Some(t) = Self::auto_suggest_tangent(
+        if let Some(t) = suggest_tangent(
             &self.tangents.start_neighbors,
             chord / chord_len,
             true,
@@ -204,63 +204,12 @@ impl CurveTool {
             return;
         }
         if let Some(t) =
-            Self::auto_suggest_tangent(&self.tangents.end_neighbors, chord / chord_len, false, true)
+            suggest_tangent(&self.tangents.end_neighbors, chord / chord_len, false, true)
         {
             self.tangents.tangent_end = t;
         }
     }
 
-    /// Parametrisierte Auto-Tangenten-Auswahl (gemeinsam fuer Start und Ende).
-    ///
-    /// - `neighbors`: Verfuegbare Nachbarn am betreffenden Endpunkt
-    /// - `chord_dir`: Normalisierte Sehnenrichtung Start→Ende (immer gleich fuer beide)
-    /// - `is_start`: true = Start-Tangente (bevorzugt incoming, vergleicht angle+PI),
-    ///   false = End-Tangente (bevorzugt outgoing, vergleicht angle direkt)
-    /// - `require_forward_dot`: true => nur Kandidaten mit `dot > 0`,
-    ///   false => bester Kandidat auch bei `dot <= 0` erlaubt
-    fn auto_suggest_tangent(
-        neighbors: &[crate::core::ConnectedNeighbor],
-        chord_dir: Vec2,
-        is_start: bool,
-        require_forward_dot: bool,
-    ) -> Option<TangentSource> {
-        use std::f32::consts::PI;
-        if neighbors.is_empty() {
-            return None;
-        }
-
-        // Start: eingehende bevorzugen; Ende: ausgehende bevorzugen
-        let prefer_outgoing = !is_start;
-        let preferred: Vec<_> = neighbors
-            .iter()
-            .filter(|n| n.is_outgoing == prefer_outgoing)
-            .collect();
-        let candidates = if preferred.is_empty() {
-            neighbors.iter().collect::<Vec<_>>()
-        } else {
-            preferred
-        };
-
-        // Start: Fortsetzungsrichtung (angle + PI) mit Sehnenrichtung vergleichen
-        // Ende: Richtung direkt (angle) mit Sehnenrichtung vergleichen
-        let angle_offset = if is_start { PI } else { 0.0 };
-
-        let best = candidates.iter().max_by(|a, b| {
-            let da = Vec2::from_angle(a.angle + angle_offset).dot(chord_dir);
-            let db = Vec2::from_angle(b.angle + angle_offset).dot(chord_dir);
-            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
-        })?;
-        let dot = Vec2::from_angle(best.angle + angle_offset).dot(chord_dir);
-        if !require_forward_dot || dot > 0.0 {
-            Some(TangentSource::Connection {
-                neighbor_id: best.neighbor_id,
-                angle: best.angle,
-            })
-        } else {
-            None
-        }
-    }
-
     /// Kurvenlaenge je nach Grad.
     pub(crate) fn curve_length(&self) -> f32 {
         let s = self.start.as_ref().map(|a| a.position());
@@ -0,0 +1,86 @@
+//! Egui-freie Panel-Bruecke fuer das FieldCourseTool.
+
+use super::geometry::TurnStyle;
+use super::state::{FieldCoursePhase, FieldCourseTool};
+use crate::app::ui_contract::{
+    FieldCoursePanelAction, FieldCoursePanelState, FieldCourseTurnChoice, RouteToolPanelEffect,
+    FIELD_COURSE_HEADLAND_PASSES_LIMITS, FIELD_COURSE_NODE_SPACING_LIMITS,
+    FIELD_COURSE_WORKING_WIDTH_LIMITS,
+};
+
+impl FieldCourseTool {
+    /// Liefert den egui-freien Panelzustand des FieldCourseTools.
+    pub(super) fn panel_state(&self) -> FieldCoursePanelState {
+        let layout = self.layout();
+        FieldCoursePanelState {
+            selected_field_id: self.selected_polygon.as_ref().map(|polygon| polygon.id),
+            working_width: self.config.working_width,
+            headland_passes: self.config.headland_passes,
+            turn_style: match self.config.turn_style {
+                TurnStyle::Direct => FieldCourseTurnChoice::Direct,
+                TurnStyle::Rounded => FieldCourseTurnChoice::Rounded,
+            },
+            node_spacing: self.config.node_spacing,
+            generated_headlands: layout.as_ref().map_or(0, |layout| layout.headlands.len()),
+            lane_count: layout.as_ref().map_or(0, |layout| layout.lane_count),
+            show_select_hint: self.phase == FieldCoursePhase::Configuring,
+        }
+    }
+
+    /// Wendet eine semantische Panel-Aktion auf das FieldCourseTool an.
+    pub(super) fn apply_panel_action(
+        &mut self,
+        action: FieldCoursePanelAction,
+    ) -> RouteToolPanelEffect {
+        let changed = match action {
+            FieldCoursePanelAction::SetWorkingWidth(value) => set_f32(
+                &mut self.config.working_width,
+                FIELD_COURSE_WORKING_WIDTH_LIMITS.clamp(value),
+            ),
+            FieldCoursePanelAction::SetHeadlandPasses(value) => set_usize(
+                &mut self.config.headland_passes,
+                FIELD_COURSE_HEADLAND_PASSES_LIMITS.clamp(value),
+            ),
+            FieldCoursePanelAction::SetTurnStyle(value) => {
+                let style = match value {
+                    FieldCourseTurnChoice::Direct => TurnStyle::Direct,
+                    FieldCourseTurnChoice::Rounded => TurnStyle::Rounded,
+                };
+                if self.config.turn_style == style {
+                    false
+                } else {
+                    self.config.turn_style = style;
+                    true
+                }
+            }
+            FieldCoursePanelAction::SetNodeSpacing(value) => set_f32(
+                &mut self.config.node_spacing,
+                FIELD_COURSE_NODE_SPACING_LIMITS.clamp(value),
+            ),
+        };
+
+        RouteToolPanelEffect {
+            changed,
+            needs_recreate: false,
+            next_action: None,
+        }
+    }
+}
+
+fn set_f32(target: &mut f32, value: f32) -> bool {
+    if (*target - value).abs() < f32::EPSILON {
+        false
+    } else {
+        *target = value;
+        true
+    }
+}
+
+fn set_usize(target: &mut usize, value: usize) -> bool {
+    if *target == value {
+        false
+    } else {
+        *target = value;
+        true
+    }
+}
@@ -0,0 +1,235 @@
+//! Geometrie des Feldkurses: Vorgewende-Runden und Serpentinen-Bahnen.
+//!
+//! Alle Funktionen sind pur und arbeiten nur mit `Vec2`-Koordinaten.
+//!
+//! # Aufbau
+//!
+//! ```text
+//!  ┌──────────────────────────┐   Feldrand
+//!  │ ┌──────────────────────┐ │   Vorgewende-Runde(n), je eine Arbeitsbreite
+//!  │ │ ───────────────────╮ │ │
+//!  │ │ ╭──────────────────╯ │ │   Bahnen parallel zur laengsten Feldkante,
+//!  │ │ ╰──────────────────╮ │ │   abwechselnd hin und zurueck
+//!  │ └──────────────────────┘ │
+//!  └──────────────────────────┘
+//! ```
+
+use crate::core::{offset_polygon, simplify_polygon};
+use crate::shared::spline_geometry::resample_by_distance;
+use glam::Vec2;
+
+/// Begradigungs-Toleranz fuer die Rasterkontur des Feldes in Metern.
+const OUTLINE_TOLERANCE: f32 = 1.5;
+
+/// Abstand, um den Randbahnen beim Schnitt mit dem Innenbereich nach innen geprueft werden.
+const EDGE_PROBE_EPSILON: f32 = 1e-3;
+
+/// Form der Wende zwischen zwei benachbarten Bahnen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnStyle {
+    /// Direkte Verbindung vom Bahnende zum naechsten Bahnanfang.
+    Direct,
+    /// Halbkreis-Wende (Durchmesser = Bahnabstand), ragt ins Vorgewende.
+    Rounded,
+}
+
+/// Eingaben fuer die Feldkurs-Berechnung.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldCourseParams {
+    /// Arbeitsbreite in Metern.
+    pub working_width: f32,
+    /// Anzahl der Vorgewende-Runden.
+    pub headland_passes: usize,
+    /// Form der Wenden.
+    pub turn_style: TurnStyle,
+    /// Maximaler Node-Abstand in Metern.
+    pub node_spacing: f32,
+}
+
+/// Ergebnis der Feldkurs-Berechnung.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldCourseLayout {
+    /// Geschlossene Vorgewende-Runden von aussen nach innen (ohne Schluss-Duplikat).
+    pub headlands: Vec<Vec<Vec2>>,
+    /// Serpentine als offene Polyline (Bahnen samt Wenden).
+    pub serpentine: Vec<Vec2>,
+    /// Anzahl der Bahnen in der Serpentine.
+    pub lane_count: usize,
+}
+
+impl FieldCourseLayout {
+    /// Gibt `true` zurueck wenn weder Runden noch Bahnen erzeugt wurden.
+    pub fn is_empty(&self) -> bool {
+        self.headlands.is_empty() && self.serpentine.len() < 2
+    }
+}
+
+/// Berechnet Vorgewende-Runden und Serpentinen-Bahnen fuer ein Feld-Polygon.
+///
+/// Die Runden liegen jeweils eine halbe Arbeitsbreite innerhalb des
+/// vorherigen Streifens. Die Bahnen fuellen die verbleibende Innenflaeche
+/// parallel zur laengsten Feldkante; bei nicht-konvexen Feldern wird je
+/// Bahn der laengste zusammenhaengende Abschnitt verwendet, damit die
+/// Serpentine ohne Unterbrechung bleibt.
+///
+/// # Rueckgabe
+/// `None` wenn das Polygon degeneriert ist oder nichts erzeugt werden kann
+pub fn compute_field_course(
+    vertices: &[Vec2],
+    params: &FieldCourseParams,
+) -> Option<FieldCourseLayout> {
+    let width = params.working_width.max(0.5);
+    let spacing = params.node_spacing.max(0.5);
+    let outline = simplify_polygon(vertices, OUTLINE_TOLERANCE);
+    if outline.len() < 3 {
+        return None;
+    }
+
+    let headlands: Vec<Vec<Vec2>> = (0..params.headland_passes)
+        .map_while(|pass| inset(&outline, (pass as f32 + 0.5) * width))
+        .map(|ring| resample_ring(&ring, spacing))
+        .collect();
+
+    let lane_area = inset(&outline, (params.headland_passes as f32 + 0.5) * width);
+    let lanes = lane_area
+        .map(|area| lane_segments(&area, lane_direction(&outline), width))
+        .unwrap_or_default();
+    let lane_count = lanes.len();
+    let serpentine = serpentine_polyline(&lanes, params.turn_style, spacing);
+
+    let layout = FieldCourseLayout {
+        headlands,
+        serpentine,
+        lane_count,
+    };
+    (!layout.is_empty()).then_some(layout)
+}
+
+/// Verschiebt das Polygon um `distance` nach innen.
+///
+/// `offset_polygon` liefert bei Degeneration das Original zurueck; dieser
+/// Fall (und ein Flaechenwachstum) wird hier als "Feld zu schmal" erkannt.
+fn inset(outline: &[Vec2], distance: f32) -> Option<Vec<Vec2>> {
+    let ring = offset_polygon(outline, -distance);
+    if ring.len() < 3 || ring == outline || area(&ring) >= area(outline) {
+        return None;
+    }
+    Some(ring)
+}
+
+/// Betrag der Polygonflaeche (Shoelace).
+fn area(polygon: &[Vec2]) -> f32 {
+    let n = polygon.len();
+    (0..n)
+        .map(|i| polygon[i].perp_dot(polygon[(i + 1) % n]))
+        .sum::<f32>()
+        .abs()
+        * 0.5
+}
+
+/// Tastet einen geschlossenen Ring gleichmaessig ab (ohne Schluss-Duplikat).
+fn resample_ring(ring: &[Vec2], spacing: f32) -> Vec<Vec2> {
+    let mut closed = ring.to_vec();
+    closed.push(ring[0]);
+    let mut resampled = resample_by_distance(&closed, spacing);
+    if resampled.len() > 1 {
+        resampled.pop();
+    }
+    resampled
+}
+
+/// Richtung der laengsten Kante des Feldumrisses (Einheitsvektor).
+fn lane_direction(outline: &[Vec2]) -> Vec2 {
+    let n = outline.len();
+    (0..n)
+        .map(|i| outline[(i + 1) % n] - outline[i])
+        .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+        .and_then(|edge| edge.try_normalize())
+        .unwrap_or(Vec2::X)
+}
+
+/// Schneidet Bahnen im Abstand `width` mit dem Innenbereich.
+///
+/// Die Bahnen werden quer zur Fahrtrichtung zentriert; je Bahn bleibt der
+/// laengste Abschnitt innerhalb des Polygons. Bahnen kuerzer als eine
+/// Arbeitsbreite entfallen. Rueckgabe: `(start, ende)` in Bahn-Reihenfolge,
+/// jeweils in Fahrtrichtung `direction`.
+fn lane_segments(area: &[Vec2], direction: Vec2, width: f32) -> Vec<(Vec2, Vec2)> {
+    let across = direction.perp();
+    let local: Vec<Vec2> = area
+        .iter()
+        .map(|p| Vec2::new(p.dot(direction), p.dot(across)))
+        .collect();
+    let (min_y, max_y) = local.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+        (lo.min(p.y), hi.max(p.y))
+    });
+    let span = max_y - min_y;
+    let count = (span / width).floor() as usize + 1;
+    let first = min_y + (span - (count - 1) as f32 * width) * 0.5;
+
+    let to_world = |x: f32, y: f32| direction * x + across * y;
+    (0..count)
+        .filter_map(|lane| {
+            let y = first + lane as f32 * width;
+            // Randbahnen liegen exakt auf der Innenkante → minimal nach innen abtasten
+            let probe = y.clamp(min_y + EDGE_PROBE_EPSILON, max_y - EDGE_PROBE_EPSILON);
+            let (x0, x1) = longest_interval(&local, probe)?;
+            (x1 - x0 >= width).then(|| (to_world(x0, y), to_world(x1, y)))
+        })
+        .collect()
+}
+
+/// Laengster Innenabschnitt der waagrechten Linie `y` im (lokalen) Polygon.
+fn longest_interval(polygon: &[Vec2], y: f32) -> Option<(f32, f32)> {
+    let n = polygon.len();
+    let mut hits: Vec<f32> = (0..n)
+        .filter_map(|i| {
+            let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+            let crosses = (a.y <= y && y < b.y) || (b.y <= y && y < a.y);
+            crosses.then(|| a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y))
+        })
+        .collect();
+    hits.sort_by(f32::total_cmp);
+    hits.chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .max_by(|a, b| (a.1 - a.0).total_cmp(&(b.1 - b.0)))
+}
+
+/// Verbindet die Bahnen abwechselnd hin und zurueck zu einer Polyline.
+fn serpentine_polyline(lanes: &[(Vec2, Vec2)], turn_style: TurnStyle, spacing: f32) -> Vec<Vec2> {
+    let mut points: Vec<Vec2> = Vec::new();
+    for (index, &(start, end)) in lanes.iter().enumerate() {
+        let (from, to) = if index % 2 == 0 {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        if let (Some(&last), TurnStyle::Rounded) = (points.last(), turn_style) {
+            let heading = (last - points[points.len() - 2]).normalize_or_zero();
+            points.extend(semicircle(last, from, heading, spacing));
+        }
+        let lane = resample_by_distance(&[from, to], spacing);
+        points.extend(lane);
+    }
+    points
+}
+
+/// Innere Punkte eines Halbkreises von `from` nach `to`, ausgebeult in `heading`.
+fn semicircle(from: Vec2, to: Vec2, heading: Vec2, spacing: f32) -> Vec<Vec2> {
+    let center = (from + to) * 0.5;
+    let radius = from.distance(to) * 0.5;
+    if radius <= f32::EPSILON {
+        return Vec::new();
+    }
+    let radial = (from - center) / radius;
+    let bulge = (heading - radial * heading.dot(radial))
+        .try_normalize()
+        .unwrap_or(radial.perp());
+    let steps = ((std::f32::consts::PI * radius / spacing).ceil() as usize).max(4);
+    (1..steps)
+        .map(|step| {
+            let angle = std::f32::consts::PI * step as f32 / steps as f32;
+            center + (radial * angle.cos() + bulge * angle.sin()) * radius
+        })
+        .collect()
+}
@@ -0,0 +1,221 @@
+//! RouteTool-Implementierung fuer das FieldCourseTool.
+
+use super::geometry::{compute_field_course, FieldCourseLayout, FieldCourseParams};
+use super::state::{FieldCoursePhase, FieldCourseTool};
+use crate::app::tools::common::{sync_tool_host, ToolResultBuilder};
+use crate::app::tools::{
+    RouteTool, RouteToolCore, RouteToolHostSync, RouteToolPanelBridge, ToolAction, ToolHostContext,
+    ToolPreview, ToolResult,
+};
+use crate::app::ui_contract::{RouteToolConfigState, RouteToolPanelAction, RouteToolPanelEffect};
+use crate::core::{find_polygon_at, ConnectionDirection, ConnectionPriority, NodeFlag, RoadMap};
+use glam::Vec2;
+
+/// Nodes und gerichtete Kanten eines Feldkurses (Indizes in `nodes`).
+#[derive(Debug, Default)]
+pub(super) struct CourseGraph {
+    pub nodes: Vec<Vec2>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Baut aus dem Layout einen zusammenhaengenden Kurs.
+///
+/// Runden sind geschlossene Ringe; jede Runde ist am naechstgelegenen
+/// Punktpaar mit der naechstinneren verbunden. Die innerste Runde fuehrt
+/// in den Anfang der Serpentine, deren Ende zurueck in die innerste Runde.
+pub(super) fn build_course_graph(layout: &FieldCourseLayout) -> CourseGraph {
+    let mut graph = CourseGraph::default();
+    let mut ring_ranges: Vec<std::ops::Range<usize>> = Vec::new();
+
+    for ring in layout.headlands.iter().filter(|ring| ring.len() >= 3) {
+        let base = graph.nodes.len();
+        graph.nodes.extend_from_slice(ring);
+        let n = ring.len();
+        graph
+            .edges
+            .extend((0..n).map(|i| (base + i, base + (i + 1) % n)));
+        ring_ranges.push(base..base + n);
+    }
+
+    for pair in ring_ranges.windows(2) {
+        if let Some((outer, inner)) = closest_pair(&graph.nodes, pair[0].clone(), pair[1].clone()) {
+            graph.edges.push((outer, inner));
+        }
+    }
+
+    if layout.serpentine.len() >= 2 {
+        let base = graph.nodes.len();
+        let n = layout.serpentine.len();
+        graph.nodes.extend_from_slice(&layout.serpentine);
+        graph
+            .edges
+            .extend((0..n - 1).map(|i| (base + i, base + i + 1)));
+
+        if let Some(innermost) = ring_ranges.last().cloned() {
+            let entry = nearest_in(&graph.nodes, innermost.clone(), graph.nodes[base]);
+            let exit = nearest_in(&graph.nodes, innermost, graph.nodes[base + n - 1]);
+            graph.edges.push((entry, base));
+            graph.edges.push((base + n - 1, exit));
+        }
+    }
+
+    graph
+}
+
+/// Naechstgelegener Node aus `range` zu `target`.
+fn nearest_in(nodes: &[Vec2], range: std::ops::Range<usize>, target: Vec2) -> usize {
+    range
+        .min_by(|&a, &b| {
+            nodes[a]
+                .distance_squared(target)
+                .total_cmp(&nodes[b].distance_squared(target))
+        })
+        .expect("invariant: Ring-Bereich ist nicht leer")
+}
+
+/// Naechstgelegenes Punktpaar zwischen zwei Ringen.
+fn closest_pair(
+    nodes: &[Vec2],
+    outer: std::ops::Range<usize>,
+    inner: std::ops::Range<usize>,
+) -> Option<(usize, usize)> {
+    outer
+        .map(|a| (a, nearest_in(nodes, inner.clone(), nodes[a])))
+        .min_by(|&(a1, b1), &(a2, b2)| {
+            nodes[a1]
+                .distance_squared(nodes[b1])
+                .total_cmp(&nodes[a2].distance_squared(nodes[b2]))
+        })
+}
+
+impl FieldCourseTool {
+    fn params(&self) -> FieldCourseParams {
+        FieldCourseParams {
+            working_width: self.config.working_width,
+            headland_passes: self.config.headland_passes,
+            turn_style: self.config.turn_style,
+            node_spacing: self.config.node_spacing,
+        }
+    }
+
+    /// Berechnet das Layout fuer das gewaehlte Feld.
+    pub(super) fn layout(&self) -> Option<FieldCourseLayout> {
+        let polygon = self.selected_polygon.as_ref()?;
+        compute_field_course(&polygon.vertices, &self.params())
+    }
+}
+
+impl RouteToolPanelBridge for FieldCourseTool {
+    fn status_text(&self) -> &str {
+        match self.phase {
+            FieldCoursePhase::Idle => "In ein Feld klicken zum Erzeugen des Feldkurses",
+            FieldCoursePhase::Configuring => {
+                "Einstellungen anpassen \u{2014} Best\u{e4}tigen oder Abbrechen"
+            }
+        }
+    }
+
+    fn panel_state(&self) -> RouteToolConfigState {
+        RouteToolConfigState::FieldCourse(self.panel_state())
+    }
+
+    fn apply_panel_action(&mut self, action: RouteToolPanelAction) -> RouteToolPanelEffect {
+        let RouteToolPanelAction::FieldCourse(action) = action else {
+            return RouteToolPanelEffect::default();
+        };
+
+        self.apply_panel_action(action)
+    }
+}
+
+impl RouteToolCore for FieldCourseTool {
+    fn on_click(&mut self, pos: Vec2, _road_map: &RoadMap, _ctrl: bool) -> ToolAction {
+        match self.phase {
+            FieldCoursePhase::Idle => {
+                if let Some(data) = &self.farmland_data {
+                    if let Some(polygon) = find_polygon_at(pos, data) {
+                        self.selected_polygon = Some(polygon.clone());
+                        self.phase = FieldCoursePhase::Configuring;
+                    } else {
+                        log::info!(
+                            "Kein Feld an Position ({:.1}, {:.1}) gefunden",
+                            pos.x,
+                            pos.y
+                        );
+                    }
+                } else {
+                    log::warn!("Keine Farmland-Daten geladen – Feldkurs nicht moeglich");
+                }
+                ToolAction::Continue
+            }
+            FieldCoursePhase::Configuring => {
+                self.selected_polygon = None;
+                self.phase = FieldCoursePhase::Idle;
+                ToolAction::Continue
+            }
+        }
+    }
+
+    fn preview(&self, _cursor_pos: Vec2, _road_map: &RoadMap) -> ToolPreview {
+        let Some(layout) = self.layout() else {
+            return ToolPreview::default();
+        };
+        let graph = build_course_graph(&layout);
+        let connection_styles = vec![(self.direction, self.priority); graph.edges.len()];
+        ToolPreview {
+            nodes: graph.nodes,
+            connections: graph.edges,
+            connection_styles,
+            labels: vec![],
+        }
+    }
+
+    fn execute(&self, _road_map: &RoadMap) -> Option<ToolResult> {
+        if self.phase != FieldCoursePhase::Configuring {
+            return None;
+        }
+        let graph = build_course_graph(&self.layout()?);
+        if graph.nodes.len() < 2 {
+            return None;
+        }
+        let new_nodes: Vec<(Vec2, NodeFlag)> = graph
+            .nodes
+            .into_iter()
+            .map(|pos| (pos, NodeFlag::Regular))
+            .collect();
+        let internal_connections: Vec<(usize, usize, ConnectionDirection, ConnectionPriority)> =
+            graph
+                .edges
+                .into_iter()
+                .map(|(from, to)| (from, to, self.direction, self.priority))
+                .collect();
+        Some(ToolResultBuilder::new(new_nodes, internal_connections).build())
+    }
+
+    fn reset(&mut self) {
+        self.phase = FieldCoursePhase::Idle;
+        self.selected_polygon = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.phase == FieldCoursePhase::Configuring && self.selected_polygon.is_some()
+    }
+
+    fn has_pending_input(&self) -> bool {
+        self.phase == FieldCoursePhase::Configuring
+    }
+}
+
+impl RouteToolHostSync for FieldCourseTool {
+    fn sync_host(&mut self, context: &ToolHostContext) {
+        sync_tool_host(
+            &mut self.direction,
+            &mut self.priority,
+            &mut self.lifecycle,
+            context,
+        );
+        self.farmland_data = context.farmland_data.clone();
+    }
+}
+
+impl RouteTool for FieldCourseTool {}
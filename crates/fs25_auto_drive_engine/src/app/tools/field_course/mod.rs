@@ -0,0 +1,11 @@
+//! Feldkurs-Tool: erzeugt Vorgewende-Runden und ein Serpentinen-Bahnmuster in einem Feld.
+
+mod config_ui;
+pub mod geometry;
+mod lifecycle;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use geometry::{compute_field_course, FieldCourseLayout, FieldCourseParams, TurnStyle};
+pub use state::FieldCourseTool;
@@ -0,0 +1,78 @@
+//! State-Strukturen fuer das FieldCourseTool.
+
+use super::geometry::TurnStyle;
+use crate::app::tools::common::ToolLifecycleState;
+use crate::core::{ConnectionDirection, ConnectionPriority, FieldPolygon};
+use std::sync::Arc;
+
+/// Interaktionsphasen des FieldCourseTool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldCoursePhase {
+    /// Warten auf Klick in ein Feld.
+    Idle,
+    /// Feld gewaehlt, Vorschau aktiv, Konfiguration moeglich.
+    Configuring,
+}
+
+/// Konfiguration des FieldCourseTool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldCourseConfig {
+    /// Arbeitsbreite in Metern (Abstand zwischen Bahnen und Vorgewende-Runden).
+    pub working_width: f32,
+    /// Anzahl der Vorgewende-Runden entlang des Feldrands (0 = keine).
+    pub headland_passes: usize,
+    /// Form der Wende zwischen zwei Bahnen.
+    pub turn_style: TurnStyle,
+    /// Maximaler Abstand zwischen generierten Nodes in Metern.
+    pub node_spacing: f32,
+}
+
+impl Default for FieldCourseConfig {
+    fn default() -> Self {
+        Self {
+            working_width: 6.0,
+            headland_passes: 1,
+            turn_style: TurnStyle::Rounded,
+            node_spacing: 10.0,
+        }
+    }
+}
+
+/// Feldkurs-Tool.
+///
+/// Klick in ein Feld → Vorgewende-Runden und Serpentinen-Bahnen im Feldinneren erzeugen.
+pub struct FieldCourseTool {
+    pub(crate) phase: FieldCoursePhase,
+    /// Das per Klick ausgewaehlte Feld-Polygon.
+    pub(crate) selected_polygon: Option<FieldPolygon>,
+    /// Alle verfuegbaren Farmland-Polygone (gesetzt beim Tool-Aktivieren).
+    pub(crate) farmland_data: Option<Arc<Vec<FieldPolygon>>>,
+    /// Bahn-, Vorgewende- und Wende-Einstellungen.
+    pub config: FieldCourseConfig,
+    /// Verbindungsrichtung.
+    pub direction: ConnectionDirection,
+    /// Strassenart.
+    pub priority: ConnectionPriority,
+    pub(crate) lifecycle: ToolLifecycleState,
+}
+
+impl Default for FieldCourseTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FieldCourseTool {
+    /// Erstellt ein neues FieldCourseTool mit Standardwerten.
+    pub fn new() -> Self {
+        Self {
+            phase: FieldCoursePhase::Idle,
+            selected_polygon: None,
+            farmland_data: None,
+            config: FieldCourseConfig::default(),
+            direction: ConnectionDirection::Regular,
+            priority: ConnectionPriority::SubPriority,
+            lifecycle: ToolLifecycleState::new(3.0),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Unit-Tests fuer das Feldkurs-Tool.
+
+use super::geometry::{compute_field_course, FieldCourseParams, TurnStyle};
+use super::lifecycle::build_course_graph;
+use super::state::{FieldCoursePhase, FieldCourseTool};
+use crate::app::tools::RouteToolCore;
+use crate::core::{FieldPolygon, RoadMap};
+use glam::Vec2;
+use std::sync::Arc;
+
+// ─── Hilfsfunktionen ─────────────────────────────────────────────────────────
+
+fn rectangle(width: f32, height: f32) -> Vec<Vec2> {
+    vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(width, 0.0),
+        Vec2::new(width, height),
+        Vec2::new(0.0, height),
+    ]
+}
+
+fn params(headland_passes: usize, turn_style: TurnStyle) -> FieldCourseParams {
+    FieldCourseParams {
+        working_width: 6.0,
+        headland_passes,
+        turn_style,
+        node_spacing: 10.0,
+    }
+}
+
+// ─── Geometrie-Tests ─────────────────────────────────────────────────────────
+
+/// Bahnen laufen parallel zur laengsten Kante und fuellen den Innenbereich.
+#[test]
+fn test_rechteck_bahnen_parallel_zur_laengsten_kante() {
+    let layout = compute_field_course(&rectangle(200.0, 60.0), &params(1, TurnStyle::Direct))
+        .expect("Rechteck sollte Feldkurs liefern");
+
+    assert_eq!(layout.headlands.len(), 1);
+    // Innenbereich: 60 - 2 * 1.5 * 6 = 42 m → 8 Bahnen im 6-m-Abstand
+    assert_eq!(layout.lane_count, 8);
+
+    let first = layout.serpentine[0];
+    let second = layout.serpentine[1];
+    assert!(
+        (first.y - second.y).abs() < 1e-3,
+        "erste Bahn sollte entlang der X-Achse verlaufen"
+    );
+}
+
+/// Jede Vorgewende-Runde liegt eine Arbeitsbreite weiter innen.
+#[test]
+fn test_vorgewende_runden_nach_innen_versetzt() {
+    let layout =
+        compute_field_course(&rectangle(200.0, 100.0), &params(3, TurnStyle::Direct)).unwrap();
+
+    assert_eq!(layout.headlands.len(), 3);
+    let min_y: Vec<f32> = layout
+        .headlands
+        .iter()
+        .map(|ring| ring.iter().map(|p| p.y).fold(f32::MAX, f32::min))
+        .collect();
+    assert!(
+        (min_y[0] - 3.0).abs() < 0.1,
+        "erste Runde bei halber Breite"
+    );
+    assert!((min_y[1] - 9.0).abs() < 0.1);
+    assert!((min_y[2] - 15.0).abs() < 0.1);
+}
+
+/// Bogen-Wenden erzeugen zusaetzliche Punkte gegenueber direkten Wenden.
+#[test]
+fn test_bogen_wende_erzeugt_mehr_punkte() {
+    let field = rectangle(200.0, 60.0);
+    let direct = compute_field_course(&field, &params(1, TurnStyle::Direct)).unwrap();
+    let rounded = compute_field_course(&field, &params(1, TurnStyle::Rounded)).unwrap();
+
+    assert_eq!(direct.lane_count, rounded.lane_count);
+    assert!(rounded.serpentine.len() > direct.serpentine.len());
+}
+
+/// Zu schmales Feld liefert keinen Kurs.
+#[test]
+fn test_zu_schmales_feld_liefert_none() {
+    assert!(compute_field_course(&rectangle(200.0, 4.0), &params(2, TurnStyle::Direct)).is_none());
+}
+
+/// Ohne Vorgewende-Runden entsteht nur die Serpentine.
+#[test]
+fn test_ohne_vorgewende_nur_bahnen() {
+    let layout =
+        compute_field_course(&rectangle(120.0, 60.0), &params(0, TurnStyle::Rounded)).unwrap();
+    assert!(layout.headlands.is_empty());
+    assert!(layout.lane_count > 0);
+}
+
+// ─── Graph-Tests ─────────────────────────────────────────────────────────────
+
+/// Runden, Uebergaenge und Serpentine bilden einen zusammenhaengenden Kurs.
+#[test]
+fn test_kursgraph_ist_zusammenhaengend() {
+    let layout =
+        compute_field_course(&rectangle(200.0, 100.0), &params(2, TurnStyle::Rounded)).unwrap();
+    let graph = build_course_graph(&layout);
+
+    let mut reached = vec![false; graph.nodes.len()];
+    let mut stack = vec![0];
+    while let Some(node) = stack.pop() {
+        if std::mem::replace(&mut reached[node], true) {
+            continue;
+        }
+        stack.extend(
+            graph
+                .edges
+                .iter()
+                .filter_map(|&(a, b)| (a == node).then_some(b).or((b == node).then_some(a))),
+        );
+    }
+    assert!(
+        reached.iter().all(|&r| r),
+        "alle Nodes sollten erreichbar sein"
+    );
+}
+
+// ─── Lifecycle-Tests ─────────────────────────────────────────────────────────
+
+/// Klick ins Feld aktiviert die Konfiguration, execute liefert Nodes.
+#[test]
+fn test_klick_ins_feld_und_execute() {
+    let mut tool = FieldCourseTool::new();
+    tool.farmland_data = Some(Arc::new(vec![FieldPolygon {
+        id: 7,
+        vertices: rectangle(200.0, 60.0),
+    }]));
+    let road_map = RoadMap::new(3);
+
+    assert!(tool.execute(&road_map).is_none());
+    tool.on_click(Vec2::new(50.0, 30.0), &road_map, false);
+    assert_eq!(tool.phase, FieldCoursePhase::Configuring);
+    assert!(tool.is_ready());
+
+    let result = tool
+        .execute(&road_map)
+        .expect("Feldkurs sollte Ergebnis liefern");
+    assert!(result.new_nodes.len() > 20);
+    assert!(!result.internal_connections.is_empty());
+
+    tool.reset();
+    assert!(!tool.is_ready());
+}
+
+/// Klick ausserhalb jedes Feldes bleibt im Idle-Zustand.
+#[test]
+fn test_klick_ausserhalb_bleibt_idle() {
+    let mut tool = FieldCourseTool::new();
+    tool.farmland_data = Some(Arc::new(vec![FieldPolygon {
+        id: 1,
+        vertices: rectangle(100.0, 50.0),
+    }]));
+    tool.on_click(Vec2::new(500.0, 500.0), &RoadMap::new(3), false);
+    assert_eq!(tool.phase, FieldCoursePhase::Idle);
+}
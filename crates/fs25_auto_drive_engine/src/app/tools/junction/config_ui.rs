@@ -0,0 +1,53 @@
+//! Egui-freie Panel-Bruecke fuer das Kreuzungs-Tool.
+
+use super::state::{JunctionTool, JunctionValidation};
+use crate::app::ui_contract::{
+    JunctionPanelAction, JunctionPanelState, RouteToolPanelEffect, JUNCTION_NODE_SPACING_LIMITS,
+};
+
+impl JunctionTool {
+    /// Liefert den egui-freien Panelzustand des Kreuzungs-Tools.
+    pub(super) fn panel_state(&self) -> JunctionPanelState {
+        JunctionPanelState {
+            selected_node_count: self.selected_count,
+            lane_count: (self.validation == JunctionValidation::Ready).then_some(self.lanes.len()),
+            node_spacing: self.node_spacing,
+            sub_priority_turns: self.sub_priority_turns,
+        }
+    }
+
+    /// Wendet eine semantische Panel-Aktion auf das Kreuzungs-Tool an.
+    pub(super) fn apply_panel_action(
+        &mut self,
+        action: JunctionPanelAction,
+    ) -> RouteToolPanelEffect {
+        let changed = match action {
+            JunctionPanelAction::SetNodeSpacing(value) => {
+                let value = JUNCTION_NODE_SPACING_LIMITS.clamp(value);
+                if (self.node_spacing - value).abs() < f32::EPSILON {
+                    false
+                } else {
+                    self.node_spacing = value;
+                    true
+                }
+            }
+            JunctionPanelAction::SetSubPriorityTurns(value) => {
+                if self.sub_priority_turns == value {
+                    false
+                } else {
+                    self.sub_priority_turns = value;
+                    true
+                }
+            }
+        };
+        if changed {
+            self.refresh();
+        }
+
+        RouteToolPanelEffect {
+            changed,
+            needs_recreate: false,
+            next_action: None,
+        }
+    }
+}
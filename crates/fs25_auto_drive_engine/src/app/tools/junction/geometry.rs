@@ -0,0 +1,151 @@
+//! Geometrie des Kreuzungs-Tools: Zufahrten, Abbiege-Klassifikation und Spur-Kurven.
+//!
+//! Jede Zufahrt endet in einem selektierten Sackgassen-Node. Die Spuren
+//! verbinden je zwei Zufahrten mit einem kubischen Bézier, dessen
+//! Kontrollpunkte in Verlaengerung der Zufahrtsrichtungen liegen — dadurch
+//! schliessen die Spuren tangential an die bestehenden Strassen an.
+
+use crate::app::tools::curve::geometry::{compute_curve_positions, cubic_bezier};
+use crate::core::{ConnectionDirection, ConnectionPriority};
+use glam::Vec2;
+
+/// Abbiegewinkel (Grad), bis zu dem eine Fahrbeziehung als geradeaus gilt.
+const THROUGH_MAX_TURN_DEG: f32 = 45.0;
+/// Kontrollpunkt-Abstand relativ zur Sehne (≈ Viertelkreis-Naeherung).
+const CONTROL_POINT_FACTOR: f32 = 0.4;
+
+/// Eine Zufahrt der Kreuzung.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JunctionApproach {
+    /// ID des selektierten Endpunkt-Nodes.
+    pub node_id: u64,
+    /// Position des Endpunkts.
+    pub position: Vec2,
+    /// Einheitsvektor in Fahrtrichtung zur Kreuzung hin (Nachbar → Endpunkt).
+    pub heading: Vec2,
+    /// `true`, wenn Verkehr ueber diese Zufahrt in die Kreuzung einfahren kann.
+    pub allows_entry: bool,
+    /// `true`, wenn Verkehr die Kreuzung ueber diese Zufahrt verlassen kann.
+    pub allows_exit: bool,
+}
+
+/// Art einer Fahrbeziehung aus Sicht des einfahrenden Fahrzeugs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnKind {
+    /// Geradeaus ueber die Kreuzung.
+    Through,
+    /// Linksabbieger.
+    Left,
+    /// Rechtsabbieger.
+    Right,
+}
+
+/// Eine erzeugte Spur zwischen zwei Zufahrten.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JunctionLane {
+    /// Index der einfahrenden Zufahrt.
+    pub from: usize,
+    /// Index der ausfahrenden Zufahrt.
+    pub to: usize,
+    /// Fahrbeziehung in Richtung `from` → `to`.
+    pub kind: TurnKind,
+    /// `Dual`, wenn die Spur in beide Richtungen befahren wird, sonst `Regular`.
+    pub direction: ConnectionDirection,
+    /// Strassenart der Spur.
+    pub priority: ConnectionPriority,
+    /// Abgetastete Punkte inkl. beider Endpunkte (mindestens drei).
+    pub positions: Vec<Vec2>,
+}
+
+/// Klassifiziert die Fahrbeziehung zwischen Einfahrt- und Ausfahrtrichtung.
+///
+/// Beide Richtungen sind Fahrtrichtungen (Einheitsvektoren). Bei Y nach Sueden
+/// ist ein positives Kreuzprodukt eine Rechtskurve.
+pub fn classify_turn(entry_heading: Vec2, exit_heading: Vec2) -> TurnKind {
+    let turn = entry_heading.angle_to(exit_heading);
+    if turn.abs() < THROUGH_MAX_TURN_DEG.to_radians() {
+        TurnKind::Through
+    } else if entry_heading.perp_dot(exit_heading) > 0.0 {
+        TurnKind::Right
+    } else {
+        TurnKind::Left
+    }
+}
+
+/// Bestimmt die Hauptstrasse als das am staerksten gegenueberliegende Zufahrtspaar.
+pub fn main_road_pair(approaches: &[JunctionApproach]) -> Option<(usize, usize)> {
+    let mut best: Option<((usize, usize), f32)> = None;
+    for i in 0..approaches.len() {
+        for j in (i + 1)..approaches.len() {
+            let alignment = approaches[i].heading.dot(approaches[j].heading);
+            if best.is_none_or(|(_, value)| alignment < value) {
+                best = Some(((i, j), alignment));
+            }
+        }
+    }
+    best.map(|(pair, _)| pair)
+}
+
+/// Tastet die Spur von Zufahrt `from` nach Zufahrt `to` ab.
+///
+/// Das Ergebnis enthaelt immer mindestens einen Zwischenpunkt, damit auch
+/// sehr kurze Spuren als eigene Nodes entstehen.
+pub fn lane_positions(from: &JunctionApproach, to: &JunctionApproach, spacing: f32) -> Vec<Vec2> {
+    let p0 = from.position;
+    let p3 = to.position;
+    let handle = p0.distance(p3) * CONTROL_POINT_FACTOR;
+    let cp1 = p0 + from.heading * handle;
+    let cp2 = p3 + to.heading * handle;
+    let eval = |t: f32| cubic_bezier(p0, cp1, cp2, p3, t);
+
+    let mut positions = compute_curve_positions(eval, spacing.max(0.1));
+    if positions.len() < 3 {
+        positions = vec![p0, eval(0.5), p3];
+    }
+    positions
+}
+
+/// Erzeugt alle Spuren zwischen den Zufahrten.
+///
+/// Pro Zufahrtspaar entsteht hoechstens eine Spur: `Dual`, wenn beide
+/// Fahrbeziehungen erlaubt sind, sonst eine gerichtete Spur. Spuren ausserhalb
+/// der Hauptstrasse werden bei `sub_priority_turns` als Nebenstrecke angelegt.
+pub fn build_lanes(
+    approaches: &[JunctionApproach],
+    spacing: f32,
+    sub_priority_turns: bool,
+) -> Vec<JunctionLane> {
+    let main_pair = main_road_pair(approaches);
+    let mut lanes = Vec::new();
+
+    for i in 0..approaches.len() {
+        for j in (i + 1)..approaches.len() {
+            let (a, b) = (&approaches[i], &approaches[j]);
+            let forward = a.allows_entry && b.allows_exit;
+            let backward = b.allows_entry && a.allows_exit;
+            let (from, to, direction) = match (forward, backward) {
+                (true, true) => (i, j, ConnectionDirection::Dual),
+                (true, false) => (i, j, ConnectionDirection::Regular),
+                (false, true) => (j, i, ConnectionDirection::Regular),
+                (false, false) => continue,
+            };
+
+            let priority = if sub_priority_turns && main_pair != Some((i, j)) {
+                ConnectionPriority::SubPriority
+            } else {
+                ConnectionPriority::Regular
+            };
+            let (entry, exit) = (&approaches[from], &approaches[to]);
+            lanes.push(JunctionLane {
+                from,
+                to,
+                kind: classify_turn(entry.heading, -exit.heading),
+                direction,
+                priority,
+                positions: lane_positions(entry, exit, spacing),
+            });
+        }
+    }
+
+    lanes
+}
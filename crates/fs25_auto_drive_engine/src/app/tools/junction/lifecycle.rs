@@ -0,0 +1,149 @@
+//! RouteTool-Implementierung fuer das Kreuzungs-Tool.
+
+use super::state::{JunctionTool, JunctionValidation};
+use crate::app::tools::common::ToolResultBuilder;
+use crate::app::tools::{
+    RouteTool, RouteToolCore, RouteToolHostSync, RouteToolPanelBridge, RouteToolSelectionInput,
+    RouteToolSelectionSeed, ToolAction, ToolHostContext, ToolPreview, ToolResult,
+};
+use crate::app::ui_contract::{RouteToolConfigState, RouteToolPanelAction, RouteToolPanelEffect};
+use crate::core::{NodeFlag, RoadMap};
+use glam::Vec2;
+
+impl RouteToolPanelBridge for JunctionTool {
+    fn status_text(&self) -> &str {
+        match self.validation {
+            JunctionValidation::NeedApproaches => "3 oder 4 Zufahrts-Endpunkte selektieren",
+            JunctionValidation::NotEndpoint => {
+                "Jeder selektierte Node muss ein Endpunkt mit genau einem Nachbarn sein"
+            }
+            JunctionValidation::NoLanes => "Fahrtrichtungen der Zufahrten erlauben keine Spur",
+            JunctionValidation::Ready => "Bereit — Enter zum Ausfuehren, Escape zum Abbrechen",
+        }
+    }
+
+    fn panel_state(&self) -> RouteToolConfigState {
+        RouteToolConfigState::Junction(self.panel_state())
+    }
+
+    fn apply_panel_action(&mut self, action: RouteToolPanelAction) -> RouteToolPanelEffect {
+        let RouteToolPanelAction::Junction(action) = action else {
+            return RouteToolPanelEffect::default();
+        };
+
+        self.apply_panel_action(action)
+    }
+}
+
+impl RouteToolCore for JunctionTool {
+    fn on_click(&mut self, _pos: Vec2, _road_map: &RoadMap, _ctrl: bool) -> ToolAction {
+        ToolAction::Continue
+    }
+
+    fn preview(&self, _cursor_pos: Vec2, _road_map: &RoadMap) -> ToolPreview {
+        if self.validation != JunctionValidation::Ready {
+            return ToolPreview::default();
+        }
+
+        let mut preview = ToolPreview::default();
+        for lane in &self.lanes {
+            let base = preview.nodes.len();
+            preview.nodes.extend_from_slice(&lane.positions);
+            for index in base..preview.nodes.len() - 1 {
+                preview.connections.push((index, index + 1));
+                preview
+                    .connection_styles
+                    .push((lane.direction, lane.priority));
+            }
+        }
+        preview
+    }
+
+    fn execute(&self, road_map: &RoadMap) -> Option<ToolResult> {
+        if self.validation != JunctionValidation::Ready
+            || self
+                .approaches
+                .iter()
+                .any(|approach| !road_map.contains_node(approach.node_id))
+        {
+            return None;
+        }
+        Some(self.build_result())
+    }
+
+    fn reset(&mut self) {
+        self.clear_selection();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.validation == JunctionValidation::Ready
+    }
+
+    fn has_pending_input(&self) -> bool {
+        self.selected_count > 0
+    }
+}
+
+impl JunctionTool {
+    /// Baut das `ToolResult` aus den berechneten Spuren.
+    ///
+    /// Die inneren Punkte jeder Spur werden zu neuen Nodes; die Spur-Enden
+    /// schliessen an die bestehenden Endpunkt-Nodes der Zufahrten an.
+    fn build_result(&self) -> ToolResult {
+        let mut new_nodes: Vec<(Vec2, NodeFlag)> = Vec::new();
+        let mut internal_connections = Vec::new();
+        let mut external_connections = Vec::new();
+
+        for lane in &self.lanes {
+            let inner = &lane.positions[1..lane.positions.len() - 1];
+            let first = new_nodes.len();
+            let last = first + inner.len() - 1;
+            new_nodes.extend(inner.iter().map(|&position| (position, NodeFlag::Regular)));
+
+            let (direction, priority) = (lane.direction, lane.priority);
+            external_connections.push((
+                first,
+                self.approaches[lane.from].node_id,
+                true,
+                direction,
+                priority,
+            ));
+            for index in first..last {
+                internal_connections.push((index, index + 1, direction, priority));
+            }
+            external_connections.push((
+                last,
+                self.approaches[lane.to].node_id,
+                false,
+                direction,
+                priority,
+            ));
+        }
+
+        ToolResultBuilder::new(new_nodes, internal_connections)
+            .with_external_connections(external_connections)
+            .build()
+    }
+}
+
+impl RouteToolHostSync for JunctionTool {
+    fn sync_host(&mut self, _context: &ToolHostContext) {
+        // Richtung und Strassenart ergeben sich aus den Zufahrten, nicht aus den Host-Defaults.
+    }
+}
+
+impl RouteToolSelectionInput for JunctionTool {
+    fn load_selection(&mut self, selection: RouteToolSelectionSeed) {
+        self.load_selection_seed(selection);
+    }
+}
+
+impl RouteTool for JunctionTool {
+    fn as_selection_input(&self) -> Option<&dyn RouteToolSelectionInput> {
+        Some(self)
+    }
+
+    fn as_selection_input_mut(&mut self) -> Option<&mut dyn RouteToolSelectionInput> {
+        Some(self)
+    }
+}
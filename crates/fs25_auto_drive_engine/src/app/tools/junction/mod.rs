@@ -0,0 +1,17 @@
+//! Kreuzungs-Tool: erzeugt aus 3–4 selektierten Zufahrts-Endpunkten eine Kreuzung
+//! mit korrekt gerichteten Abbiege- und Geradeaus-Spuren.
+//!
+//! Aufgeteilt in:
+//! - `state` — Struct-Definition, Validierung und Selektions-Laden
+//! - `geometry` — Zufahrten, Abbiege-Klassifikation und Spur-Kurven
+//! - `lifecycle` — RouteTool-Implementierung
+//! - `config_ui` — egui-freie Panel-Bruecke
+
+mod config_ui;
+pub(crate) mod geometry;
+mod lifecycle;
+mod state;
+#[cfg(test)]
+mod tests;
+
+pub use state::JunctionTool;
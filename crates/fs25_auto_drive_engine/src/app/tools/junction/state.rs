@@ -0,0 +1,132 @@
+//! State-Definitionen, Validierung und Selektions-Laden des Kreuzungs-Tools.
+
+use super::geometry::{build_lanes, JunctionApproach, JunctionLane};
+use crate::app::tools::{RouteToolConnectedNeighborSeed, RouteToolSelectionSeed};
+use crate::core::ConnectionDirection;
+use glam::Vec2;
+
+pub(crate) const DEFAULT_NODE_SPACING_M: f32 = 3.0;
+/// Mindest- und Hoechstzahl der Zufahrten einer Kreuzung.
+pub(crate) const MIN_APPROACHES: usize = 3;
+pub(crate) const MAX_APPROACHES: usize = 4;
+
+/// Validierungsergebnis der geladenen Selektion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JunctionValidation {
+    /// Weniger als drei oder mehr als vier Nodes selektiert.
+    NeedApproaches,
+    /// Mindestens ein selektierter Node ist kein Sackgassen-Endpunkt.
+    NotEndpoint,
+    /// Die Fahrtrichtungen der Zufahrten lassen keine Spur zu.
+    NoLanes,
+    /// Kreuzung kann erzeugt werden.
+    Ready,
+}
+
+/// Kreuzungs-Tool: verbindet 3–4 Zufahrts-Endpunkte mit gerichteten Spuren.
+pub struct JunctionTool {
+    /// Anzahl der zuletzt geladenen selektierten Nodes.
+    pub(crate) selected_count: usize,
+    /// Aus der Selektion abgeleitete Zufahrten (nur bei gueltigen Endpunkten).
+    pub(crate) approaches: Vec<JunctionApproach>,
+    /// Maximaler Abstand zwischen erzeugten Spur-Nodes in Metern.
+    pub(crate) node_spacing: f32,
+    /// Abbiegespuren ausserhalb der Hauptstrasse als Nebenstrecke anlegen.
+    pub(crate) sub_priority_turns: bool,
+    /// Letztes Validierungsergebnis.
+    pub(crate) validation: JunctionValidation,
+    /// Zuletzt berechnete Spuren fuer Preview/Execute.
+    pub(crate) lanes: Vec<JunctionLane>,
+}
+
+impl JunctionTool {
+    /// Erstellt ein neues Kreuzungs-Tool mit Standardwerten.
+    pub fn new() -> Self {
+        Self {
+            selected_count: 0,
+            approaches: Vec::new(),
+            node_spacing: DEFAULT_NODE_SPACING_M,
+            sub_priority_turns: true,
+            validation: JunctionValidation::NeedApproaches,
+            lanes: Vec::new(),
+        }
+    }
+
+    /// Laedt die aktuelle Node-Selektion als Zufahrts-Endpunkte.
+    pub(crate) fn load_selection_seed(&mut self, selection: RouteToolSelectionSeed) {
+        self.selected_count = selection.node_ids.len();
+        self.approaches = selection
+            .node_ids
+            .iter()
+            .zip(&selection.positions)
+            .zip(&selection.connected_neighbors)
+            .filter_map(|((&node_id, &position), neighbors)| {
+                approach_from_seed(node_id, position, neighbors)
+            })
+            .collect();
+        self.refresh();
+    }
+
+    /// Validiert die Zufahrten und berechnet die Spuren neu.
+    pub(crate) fn refresh(&mut self) {
+        self.lanes.clear();
+        self.validation = if !(MIN_APPROACHES..=MAX_APPROACHES).contains(&self.selected_count) {
+            JunctionValidation::NeedApproaches
+        } else if self.approaches.len() != self.selected_count {
+            JunctionValidation::NotEndpoint
+        } else {
+            self.lanes = build_lanes(&self.approaches, self.node_spacing, self.sub_priority_turns);
+            if self.lanes.is_empty() {
+                JunctionValidation::NoLanes
+            } else {
+                JunctionValidation::Ready
+            }
+        };
+    }
+
+    /// Setzt die geladene Selektion zurueck, Einstellungen bleiben erhalten.
+    pub(crate) fn clear_selection(&mut self) {
+        self.selected_count = 0;
+        self.approaches.clear();
+        self.refresh();
+    }
+}
+
+impl Default for JunctionTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Baut eine Zufahrt aus einem selektierten Node.
+///
+/// Nur Sackgassen-Endpunkte (genau ein Nachbar) sind gueltig. Ein- und
+/// Ausfahrt ergeben sich aus der gespeicherten Richtung der Verbindungen;
+/// `Dual` erlaubt beides.
+fn approach_from_seed(
+    node_id: u64,
+    position: Vec2,
+    neighbors: &[RouteToolConnectedNeighborSeed],
+) -> Option<JunctionApproach> {
+    let first = neighbors.first()?;
+    if neighbors
+        .iter()
+        .any(|neighbor| neighbor.neighbor_id != first.neighbor_id)
+    {
+        return None;
+    }
+    let heading = (position - first.position).try_normalize()?;
+    let is_dual =
+        |neighbor: &RouteToolConnectedNeighborSeed| neighbor.direction == ConnectionDirection::Dual;
+    Some(JunctionApproach {
+        node_id,
+        position,
+        heading,
+        allows_entry: neighbors
+            .iter()
+            .any(|neighbor| !neighbor.is_outgoing || is_dual(neighbor)),
+        allows_exit: neighbors
+            .iter()
+            .any(|neighbor| neighbor.is_outgoing || is_dual(neighbor)),
+    })
+}
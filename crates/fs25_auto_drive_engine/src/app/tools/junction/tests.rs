@@ -0,0 +1,262 @@
+//! Unit-Tests fuer das Kreuzungs-Tool.
+
+use super::geometry::{classify_turn, TurnKind};
+use super::state::{JunctionTool, JunctionValidation};
+use crate::app::tools::{
+    RouteToolConnectedNeighborSeed, RouteToolCore, RouteToolPanelBridge, RouteToolSelectionSeed,
+};
+use crate::app::ui_contract::{JunctionPanelAction, RouteToolConfigState, RouteToolPanelAction};
+use crate::core::{
+    Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+};
+use glam::Vec2;
+
+// ─── Hilfsfunktionen ─────────────────────────────────────────────────────────
+
+/// Zufahrt: Endpunkt `id` bei `end`, Nachbar `id + 100` weiter aussen bei `outer`.
+struct Arm {
+    id: u64,
+    end: Vec2,
+    outer: Vec2,
+    /// `Some(true)`: Einfahrt (Nachbar → Endpunkt), `Some(false)`: Ausfahrt, `None`: Dual.
+    inbound: Option<bool>,
+}
+
+fn arm(id: u64, end: Vec2, inbound: Option<bool>) -> Arm {
+    Arm {
+        id,
+        end,
+        outer: end * 3.0,
+        inbound,
+    }
+}
+
+fn junction_map(arms: &[Arm]) -> RoadMap {
+    let mut road_map = RoadMap::new(3);
+    for arm in arms {
+        let outer_id = arm.id + 100;
+        road_map.add_node(MapNode::new(arm.id, arm.end, NodeFlag::Regular));
+        road_map.add_node(MapNode::new(outer_id, arm.outer, NodeFlag::Regular));
+        let (start, end, direction) = match arm.inbound {
+            Some(true) => (outer_id, arm.id, ConnectionDirection::Regular),
+            Some(false) => (arm.id, outer_id, ConnectionDirection::Regular),
+            None => (outer_id, arm.id, ConnectionDirection::Dual),
+        };
+        road_map.add_connection(Connection::new(
+            start,
+            end,
+            direction,
+            ConnectionPriority::Regular,
+            road_map.node_position(start).unwrap(),
+            road_map.node_position(end).unwrap(),
+        ));
+    }
+    road_map
+}
+
+/// Baut den Selektions-Seed wie der Route-Tool-Handler.
+fn selection_seed(road_map: &RoadMap, node_ids: &[u64]) -> RouteToolSelectionSeed {
+    let connected_neighbors = node_ids
+        .iter()
+        .map(|&node_id| {
+            road_map
+                .connected_neighbors(node_id)
+                .into_iter()
+                .map(|neighbor| {
+                    let (start, end) = if neighbor.is_outgoing {
+                        (node_id, neighbor.neighbor_id)
+                    } else {
+                        (neighbor.neighbor_id, node_id)
+                    };
+                    let direction = road_map.find_connection(start, end).unwrap().direction;
+                    let position = road_map.node_position(neighbor.neighbor_id).unwrap();
+                    RouteToolConnectedNeighborSeed::new(neighbor, position, direction)
+                })
+                .collect()
+        })
+        .collect();
+
+    RouteToolSelectionSeed {
+        node_ids: node_ids.to_vec(),
+        positions: node_ids
+            .iter()
+            .map(|&id| road_map.node_position(id).unwrap())
+            .collect(),
+        connected_neighbors,
+        linear_stretches: vec![Vec::new(); node_ids.len()],
+        anchor_paths: Vec::new(),
+    }
+}
+
+fn four_way_dual() -> (RoadMap, JunctionTool) {
+    let road_map = junction_map(&[
+        arm(1, Vec2::new(-10.0, 0.0), None),
+        arm(2, Vec2::new(10.0, 0.0), None),
+        arm(3, Vec2::new(0.0, -10.0), None),
+        arm(4, Vec2::new(0.0, 10.0), None),
+    ]);
+    let mut tool = JunctionTool::new();
+    tool.load_selection_seed(selection_seed(&road_map, &[1, 2, 3, 4]));
+    (road_map, tool)
+}
+
+// ─── Geometrie-Tests ─────────────────────────────────────────────────────────
+
+/// Bei Y nach Sueden ist Ost → Sued eine Rechts-, Ost → Nord eine Linkskurve.
+#[test]
+fn test_classify_turn_rechts_links_geradeaus() {
+    assert_eq!(classify_turn(Vec2::X, Vec2::Y), TurnKind::Right);
+    assert_eq!(classify_turn(Vec2::X, -Vec2::Y), TurnKind::Left);
+    assert_eq!(
+        classify_turn(Vec2::X, Vec2::new(1.0, 0.3).normalize()),
+        TurnKind::Through
+    );
+}
+
+/// Vier Dual-Zufahrten → sechs Dual-Spuren, nur die Hauptstrasse bleibt Regular.
+#[test]
+fn test_vierer_kreuzung_mit_dual_zufahrten() {
+    let (_, tool) = four_way_dual();
+
+    assert_eq!(tool.validation, JunctionValidation::Ready);
+    assert_eq!(tool.lanes.len(), 6);
+    assert!(tool
+        .lanes
+        .iter()
+        .all(|lane| lane.direction == ConnectionDirection::Dual));
+
+    let regular: Vec<_> = tool
+        .lanes
+        .iter()
+        .filter(|lane| lane.priority == ConnectionPriority::Regular)
+        .collect();
+    assert_eq!(regular.len(), 1, "nur die Hauptstrasse ist Regular");
+    assert_eq!(regular[0].kind, TurnKind::Through);
+    assert_eq!((regular[0].from, regular[0].to), (0, 1));
+}
+
+/// Spuren beginnen und enden tangential an den Zufahrten.
+#[test]
+fn test_spuren_schliessen_tangential_an() {
+    let (_, tool) = four_way_dual();
+    let turn = tool
+        .lanes
+        .iter()
+        .find(|lane| lane.from == 0 && lane.to == 3)
+        .expect("Spur West → Sued erwartet");
+
+    let first_step = (turn.positions[1] - turn.positions[0]).normalize();
+    assert!(first_step.dot(Vec2::X) > 0.9, "Start in Fahrtrichtung Ost");
+    let n = turn.positions.len();
+    let last_step = (turn.positions[n - 1] - turn.positions[n - 2]).normalize();
+    assert!(last_step.dot(Vec2::Y) > 0.9, "Ende in Fahrtrichtung Sued");
+}
+
+/// Einbahn-Zufahrten erzeugen nur die erlaubten, korrekt gerichteten Spuren.
+#[test]
+fn test_einbahn_zufahrten_bestimmen_spurrichtung() {
+    // West: nur Einfahrt, Ost: nur Ausfahrt, Sued: beide Richtungen
+    let road_map = junction_map(&[
+        arm(1, Vec2::new(-10.0, 0.0), Some(true)),
+        arm(2, Vec2::new(10.0, 0.0), Some(false)),
+        arm(3, Vec2::new(0.0, 10.0), None),
+    ]);
+    let mut tool = JunctionTool::new();
+    tool.load_selection_seed(selection_seed(&road_map, &[1, 2, 3]));
+
+    assert_eq!(tool.validation, JunctionValidation::Ready);
+    let mut moves: Vec<_> = tool
+        .lanes
+        .iter()
+        .map(|lane| (lane.from, lane.to, lane.kind, lane.direction))
+        .collect();
+    moves.sort_by_key(|&(from, to, _, _)| (from, to));
+    assert_eq!(
+        moves,
+        vec![
+            (0, 1, TurnKind::Through, ConnectionDirection::Regular),
+            (0, 2, TurnKind::Right, ConnectionDirection::Regular),
+            (2, 1, TurnKind::Right, ConnectionDirection::Regular),
+        ]
+    );
+}
+
+/// Nodes mit mehreren Nachbarn sind keine gueltigen Zufahrten.
+#[test]
+fn test_kein_endpunkt_wird_abgelehnt() {
+    let mut road_map = junction_map(&[
+        arm(1, Vec2::new(-10.0, 0.0), None),
+        arm(2, Vec2::new(10.0, 0.0), None),
+        arm(3, Vec2::new(0.0, 10.0), None),
+    ]);
+    road_map.add_connection(Connection::new(
+        1,
+        2,
+        ConnectionDirection::Dual,
+        ConnectionPriority::Regular,
+        Vec2::new(-10.0, 0.0),
+        Vec2::new(10.0, 0.0),
+    ));
+    let mut tool = JunctionTool::new();
+    tool.load_selection_seed(selection_seed(&road_map, &[1, 2, 3]));
+
+    assert_eq!(tool.validation, JunctionValidation::NotEndpoint);
+    assert!(!tool.is_ready());
+}
+
+/// Weniger als drei Zufahrten reichen nicht fuer eine Kreuzung.
+#[test]
+fn test_zwei_zufahrten_reichen_nicht() {
+    let road_map = junction_map(&[
+        arm(1, Vec2::new(-10.0, 0.0), None),
+        arm(2, Vec2::new(10.0, 0.0), None),
+    ]);
+    let mut tool = JunctionTool::new();
+    tool.load_selection_seed(selection_seed(&road_map, &[1, 2]));
+
+    assert_eq!(tool.validation, JunctionValidation::NeedApproaches);
+}
+
+// ─── Lifecycle-Tests ─────────────────────────────────────────────────────────
+
+/// Execute verbindet jede Spur an beiden Enden mit den Endpunkt-Nodes.
+#[test]
+fn test_execute_verbindet_spuren_mit_endpunkten() {
+    let (road_map, tool) = four_way_dual();
+    let result = tool.execute(&road_map).expect("Kreuzung erwartet");
+
+    assert_eq!(result.external_connections.len(), 2 * tool.lanes.len());
+    let inner_nodes: usize = tool.lanes.iter().map(|lane| lane.positions.len() - 2).sum();
+    assert_eq!(result.new_nodes.len(), inner_nodes);
+    assert_eq!(
+        result.internal_connections.len(),
+        inner_nodes - tool.lanes.len()
+    );
+    assert!(result
+        .external_connections
+        .iter()
+        .all(|&(_, id, _, _, _)| (1..=4).contains(&id)));
+}
+
+/// Panel-Aktionen berechnen die Spuren neu.
+#[test]
+fn test_panel_aktion_schaltet_nebenstrecke_um() {
+    let (_, mut tool) = four_way_dual();
+
+    let effect = RouteToolPanelBridge::apply_panel_action(
+        &mut tool,
+        RouteToolPanelAction::Junction(JunctionPanelAction::SetSubPriorityTurns(false)),
+    );
+    assert!(effect.changed);
+    assert!(tool
+        .lanes
+        .iter()
+        .all(|lane| lane.priority == ConnectionPriority::Regular));
+
+    let RouteToolConfigState::Junction(state) = RouteToolPanelBridge::panel_state(&tool) else {
+        panic!("Kreuzungs-Panelzustand erwartet");
+    };
+    assert_eq!(state.selected_node_count, 4);
+    assert_eq!(state.lane_count, Some(6));
+    assert!(!state.sub_priority_turns);
+}
@@ -4,12 +4,16 @@
 //! Kernvertrag, Panel-Bruecke und Host-Sync. Optionale Interaktionen laufen
 //! ueber additive Capabilities, die der `ToolManager` gezielt entdeckt.
 
+/// Kreisbogen-Tool — Kreisbogen durch Start-, Durchgangs- und Endpunkt.
+pub mod arc;
 /// Ausweichstrecken-Tool — generiert eine parallele Strecke zur selektierten Kette.
 pub mod bypass;
 /// Additive Capabilities fuer optionale Tool-Faehigkeiten.
 mod capabilities;
 /// Kanonischer Tool-Katalog mit stabilen Tool-IDs und Surface-Metadaten.
 mod catalog;
+/// Klothoiden-Tool — kruemmungsstetiger Uebergangsbogen zwischen zwei Tangentenrichtungen.
+pub mod clothoid;
 /// Farb-Pfad-Tool: erkennt Wege anhand der Farbe im Hintergrundbild.
 pub mod color_path;
 /// Gemeinsame Hilfsfunktionen fuer Route-Tools.
@@ -20,8 +24,12 @@ mod contracts;
 pub mod curve;
 /// Feldgrenz-Erkennungs-Tool: erzeugt eine Route entlang eines erkannten Feldumrisses.
 pub mod field_boundary;
+/// Feldkurs-Tool: erzeugt Vorgewende-Runden und Serpentinen-Bahnen in einem Feld.
+pub mod field_course;
 /// Feldweg-Erkennungs-Tool: berechnet eine Mittellinie zwischen zwei Farmland-Seiten.
 pub mod field_path;
+/// Kreuzungs-Tool: verbindet 3–4 Zufahrts-Endpunkte mit gerichteten Abbiegespuren.
+pub mod junction;
 /// ToolManager und Capability-Discovery.
 mod manager;
 /// Parkplatz-Layout-Tool mit Wendekreis und konfigurierbaren Parkreihen.
@@ -40,6 +48,7 @@ pub mod spline;
 pub mod straight_line;
 
 pub use crate::app::tool_contract::ToolAnchor;
+pub use crate::shared::geometry::{apply_snap_constraints, constrain_to_angle, snap_to_grid};
 pub use crate::shared::RouteToolGroup;
 pub use capabilities::{
     OrderedNodeChain, RouteToolAnchorPathSeed, RouteToolChainInput, RouteToolConnectedNeighborSeed,
@@ -3,7 +3,8 @@
 use super::state::RouteOffsetTool;
 use crate::app::ui_contract::{
     RouteOffsetPanelAction, RouteOffsetPanelState, RouteToolPanelEffect,
-    ROUTE_OFFSET_BASE_SPACING_LIMITS, ROUTE_OFFSET_DISTANCE_LIMITS,
+    ROUTE_OFFSET_BASE_SPACING_LIMITS, ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS,
+    ROUTE_OFFSET_DISTANCE_LIMITS,
 };
 
 impl RouteOffsetTool {
@@ -17,6 +18,8 @@ impl RouteOffsetTool {
             right_distance: self.config.right_distance,
             base_spacing: self.config.base_spacing,
             keep_original: self.config.keep_original,
+            cross_links_enabled: self.config.cross_links_enabled,
+            cross_link_spacing: self.config.cross_link_spacing,
             chain_node_count: self.chain_positions.len(),
         }
     }
@@ -48,6 +51,13 @@ impl RouteOffsetTool {
             RouteOffsetPanelAction::SetKeepOriginal(value) => {
                 set_bool(&mut self.config.keep_original, value)
             }
+            RouteOffsetPanelAction::SetCrossLinksEnabled(value) => {
+                set_bool(&mut self.config.cross_links_enabled, value)
+            }
+            RouteOffsetPanelAction::SetCrossLinkSpacing(value) => set_f32(
+                &mut self.config.cross_link_spacing,
+                ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS.clamp(value),
+            ),
             RouteOffsetPanelAction::ToggleReversedSide => {
                 if self.direction != crate::core::ConnectionDirection::Dual
                     && self.config.left_enabled
@@ -45,3 +45,43 @@ pub fn compute_offset_positions(
     }
     Some(resampled)
 }
+
+/// Waehlt die Endpunkte der Querverbindungen zwischen zwei parallelen Ketten.
+///
+/// Auf `from` wird alle `spacing` Meter (Bogenlaenge) ein innerer Punkt
+/// gewaehlt und mit dem naechstgelegenen inneren Punkt von `to` gepaart.
+/// Endpunkte beider Ketten bleiben aussen vor, weil sie bereits ueber die
+/// Anker angebunden sind; doppelte Ziele werden uebersprungen.
+///
+/// # Rueckgabe
+/// Paare `(from_idx, to_idx)` in Reihenfolge entlang `from`
+pub fn cross_link_pairs(from: &[Vec2], to: &[Vec2], spacing: f32) -> Vec<(usize, usize)> {
+    if from.len() < 3 || to.len() < 3 || spacing <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut travelled = 0.0;
+    let mut next_mark = spacing;
+    for i in 1..from.len() - 1 {
+        travelled += from[i].distance(from[i - 1]);
+        if travelled + f32::EPSILON < next_mark {
+            continue;
+        }
+        while next_mark <= travelled + f32::EPSILON {
+            next_mark += spacing;
+        }
+
+        let target = (1..to.len() - 1)
+            .min_by(|&a, &b| {
+                from[i]
+                    .distance_squared(to[a])
+                    .total_cmp(&from[i].distance_squared(to[b]))
+            })
+            .expect("invariant: to hat mindestens einen inneren Punkt");
+        if pairs.iter().all(|&(_, used)| used != target) {
+            pairs.push((i, target));
+        }
+    }
+    pairs
+}
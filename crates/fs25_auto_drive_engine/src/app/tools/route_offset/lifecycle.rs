@@ -1,7 +1,7 @@
 //! Route-Tool-Implementierung fuer das Strecken-Versatz-Tool.
 
-use super::geometry::compute_offset_positions;
-use super::state::{RouteOffsetPreviewCache, RouteOffsetPreviewKey, RouteOffsetTool};
+use super::geometry::{compute_offset_positions, cross_link_pairs};
+use super::state::{CrossLink, RouteOffsetPreviewCache, RouteOffsetPreviewKey, RouteOffsetTool};
 use crate::app::tool_editing::{RouteToolEditPayload, ToolRouteBase};
 use crate::app::tools::common::{
     record_applied_tool_state, sync_tool_host, ToolLifecycleState, ToolResultBuilder,
@@ -40,6 +40,8 @@ impl RouteOffsetTool {
             left_distance: self.config.left_distance,
             right_distance: self.config.right_distance,
             base_spacing: self.config.base_spacing,
+            cross_links_enabled: self.config.cross_links_enabled,
+            cross_link_spacing: self.config.cross_link_spacing,
         }
     }
 
@@ -75,6 +77,62 @@ impl RouteOffsetTool {
         });
     }
 
+    /// IDs der Quellkette in Kettenreihenfolge, falls alle inneren IDs bekannt sind.
+    fn chain_ids(&self) -> Option<Vec<u64>> {
+        if self.chain_inner_ids.len() + 2 != self.chain_positions.len() {
+            return None;
+        }
+        let mut ids = Vec::with_capacity(self.chain_positions.len());
+        ids.push(self.chain_start_id);
+        ids.extend_from_slice(&self.chain_inner_ids);
+        ids.push(self.chain_end_id);
+        Some(ids)
+    }
+
+    /// Bestimmt die Querverbindungen fuer die (bereits ausgerichteten) Seiten.
+    ///
+    /// Bleibt das Original erhalten, wird jede Seite mit der Quellkette
+    /// verbunden; sonst werden linke und rechte Seite miteinander verbunden.
+    fn cross_links(&self, left: Option<&[Vec2]>, right: Option<&[Vec2]>) -> Vec<CrossLink> {
+        if !self.config.cross_links_enabled {
+            return Vec::new();
+        }
+        let spacing = self.config.cross_link_spacing;
+
+        if self.config.keep_original {
+            if self.chain_ids().is_none() {
+                return Vec::new();
+            }
+            let mut links = Vec::new();
+            for (left_side, points) in [(true, left), (false, right)] {
+                let Some(points) = points else {
+                    continue;
+                };
+                links.extend(
+                    cross_link_pairs(points, &self.chain_positions, spacing)
+                        .into_iter()
+                        .map(|(side_idx, chain_idx)| CrossLink::ToOriginal {
+                            left_side,
+                            side_idx,
+                            chain_idx,
+                        }),
+                );
+            }
+            return links;
+        }
+
+        let (Some(left), Some(right)) = (left, right) else {
+            return Vec::new();
+        };
+        cross_link_pairs(left, right, spacing)
+            .into_iter()
+            .map(|(left_idx, right_idx)| CrossLink::BetweenSides {
+                left_idx,
+                right_idx,
+            })
+            .collect()
+    }
+
     fn is_one_way(&self) -> bool {
         self.direction != ConnectionDirection::Dual
     }
@@ -92,6 +150,15 @@ impl RouteOffsetTool {
     }
 }
 
+/// Liefert die Seitenpunkte in Fahrtrichtung (bei Bedarf umgekehrt).
+fn oriented(points: Option<&[Vec2]>, reverse: bool) -> Option<Vec<Vec2>> {
+    let mut points = points?.to_vec();
+    if reverse {
+        points.reverse();
+    }
+    Some(points)
+}
+
 impl RouteToolPanelBridge for RouteOffsetTool {
     fn status_text(&self) -> &str {
         if self.has_chain() {
@@ -142,35 +209,40 @@ impl RouteToolCore for RouteOffsetTool {
             .as_ref()
             .expect("invariant: preview cache must exist after ensure_preview_cache");
         let (left_reverse, right_reverse) = self.side_reverse_flags();
+        let left = oriented(cached.left_points.as_deref(), left_reverse);
+        let right = oriented(cached.right_points.as_deref(), right_reverse);
 
-        if let Some(pts) = cached.left_points.as_ref() {
+        let mut side_starts = [0usize; 2];
+        for (slot, pts) in [&left, &right].into_iter().enumerate() {
+            let Some(pts) = pts else {
+                continue;
+            };
             let start = nodes.len();
-            if left_reverse {
-                for point in pts.iter().rev() {
-                    nodes.push(*point);
-                }
-            } else {
-                nodes.extend_from_slice(pts);
-            }
+            side_starts[slot] = start;
+            nodes.extend_from_slice(pts);
             for i in 0..pts.len().saturating_sub(1) {
                 connections.push((start + i, start + i + 1));
                 styles.push((self.direction, self.priority));
             }
         }
 
-        if let Some(pts) = cached.right_points.as_ref() {
-            let start = nodes.len();
-            if right_reverse {
-                for point in pts.iter().rev() {
-                    nodes.push(*point);
+        for link in self.cross_links(left.as_deref(), right.as_deref()) {
+            let connection = match link {
+                CrossLink::ToOriginal {
+                    left_side,
+                    side_idx,
+                    chain_idx,
+                } => {
+                    let side_start = side_starts[if left_side { 0 } else { 1 }];
+                    (side_start + side_idx, orig_start + chain_idx)
                 }
-            } else {
-                nodes.extend_from_slice(pts);
-            }
-            for i in 0..pts.len().saturating_sub(1) {
-                connections.push((start + i, start + i + 1));
-                styles.push((self.direction, self.priority));
-            }
+                CrossLink::BetweenSides {
+                    left_idx,
+                    right_idx,
+                } => (side_starts[0] + left_idx, side_starts[1] + right_idx),
+            };
+            connections.push(connection);
+            styles.push((ConnectionDirection::Dual, self.priority));
         }
 
         if nodes.is_empty() {
@@ -204,19 +276,33 @@ impl RouteToolCore for RouteOffsetTool {
             ConnectionPriority,
         )> = Vec::new();
         let (left_reverse, right_reverse) = self.side_reverse_flags();
-
-        let mut add_side = |offset: f32, reverse_points: bool| {
-            let Some(mut pts) =
-                compute_offset_positions(&self.chain_positions, offset, self.config.base_spacing)
-            else {
-                return;
-            };
+        let side_points = |enabled: bool, offset: f32, reverse_points: bool| {
+            if !enabled {
+                return None;
+            }
+            let mut pts =
+                compute_offset_positions(&self.chain_positions, offset, self.config.base_spacing)?;
             if reverse_points {
                 pts.reverse();
             }
+            Some(pts)
+        };
+        let left = side_points(
+            self.config.left_enabled,
+            self.config.left_distance,
+            left_reverse,
+        );
+        let right = side_points(
+            self.config.right_enabled,
+            -self.config.right_distance,
+            right_reverse,
+        );
 
+        let mut side_bases = [0usize; 2];
+        let mut add_side = |slot: usize, pts: &[Vec2], reverse_points: bool| {
             let base = new_nodes.len();
-            for &p in &pts {
+            side_bases[slot] = base;
+            for &p in pts {
                 new_nodes.push((p, NodeFlag::Regular));
             }
             for i in 0..pts.len().saturating_sub(1) {
@@ -234,17 +320,49 @@ impl RouteToolCore for RouteOffsetTool {
             external_connections.push((last, last_anchor, false, self.direction, self.priority));
         };
 
-        if self.config.left_enabled {
-            add_side(self.config.left_distance, left_reverse);
+        if let Some(pts) = left.as_deref() {
+            add_side(0, pts, left_reverse);
         }
-        if self.config.right_enabled {
-            add_side(-self.config.right_distance, right_reverse);
+        if let Some(pts) = right.as_deref() {
+            add_side(1, pts, right_reverse);
         }
 
         if new_nodes.is_empty() {
             return None;
         }
 
+        let chain_ids = self.chain_ids();
+        for link in self.cross_links(left.as_deref(), right.as_deref()) {
+            match link {
+                CrossLink::ToOriginal {
+                    left_side,
+                    side_idx,
+                    chain_idx,
+                } => {
+                    let Some(chain_ids) = chain_ids.as_ref() else {
+                        continue;
+                    };
+                    let side_base = side_bases[if left_side { 0 } else { 1 }];
+                    external_connections.push((
+                        side_base + side_idx,
+                        chain_ids[chain_idx],
+                        false,
+                        ConnectionDirection::Dual,
+                        self.priority,
+                    ));
+                }
+                CrossLink::BetweenSides {
+                    left_idx,
+                    right_idx,
+                } => internal_connections.push((
+                    side_bases[0] + left_idx,
+                    side_bases[1] + right_idx,
+                    ConnectionDirection::Dual,
+                    self.priority,
+                )),
+            }
+        }
+
         let nodes_to_remove = if !self.config.keep_original {
             self.chain_inner_ids.clone()
         } else {
@@ -373,6 +491,11 @@ impl RouteToolGroupEdit for RouteOffsetTool {
             },
             keep_original: self.config.keep_original,
             base_spacing: self.config.base_spacing,
+            cross_link_spacing: if self.config.cross_links_enabled {
+                self.config.cross_link_spacing
+            } else {
+                0.0
+            },
             base: ToolRouteBase {
                 direction: self.direction,
                 priority: self.priority,
@@ -390,6 +513,7 @@ impl RouteToolGroupEdit for RouteOffsetTool {
             offset_right,
             keep_original,
             base_spacing,
+            cross_link_spacing,
             base,
         } = payload
         else {
@@ -406,6 +530,10 @@ impl RouteToolGroupEdit for RouteOffsetTool {
         }
         self.config.keep_original = *keep_original;
         self.config.base_spacing = *base_spacing;
+        self.config.cross_links_enabled = *cross_link_spacing > 0.0;
+        if *cross_link_spacing > 0.0 {
+            self.config.cross_link_spacing = *cross_link_spacing;
+        }
         self.direction = base.direction;
         self.priority = base.priority;
     }
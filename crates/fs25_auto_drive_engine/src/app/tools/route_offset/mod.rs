@@ -10,5 +10,5 @@ mod state;
 #[cfg(test)]
 mod tests;
 
-pub use geometry::compute_offset_positions;
+pub use geometry::{compute_offset_positions, cross_link_pairs};
 pub use state::RouteOffsetTool;
@@ -13,6 +13,8 @@ pub(crate) struct RouteOffsetPreviewKey {
     pub left_distance: f32,
     pub right_distance: f32,
     pub base_spacing: f32,
+    pub cross_links_enabled: bool,
+    pub cross_link_spacing: f32,
 }
 
 /// Gecachte Preview-Geometrie fuer die berechneten Versatzseiten.
@@ -24,6 +26,19 @@ pub(crate) struct RouteOffsetPreviewCache {
     pub right_points: Option<Vec<Vec2>>,
 }
 
+/// Eine Querverbindung zwischen zwei parallelen Ketten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CrossLink {
+    /// Versatz-Node (Index in der Seite) → Original-Node (Index in der Quellkette).
+    ToOriginal {
+        left_side: bool,
+        side_idx: usize,
+        chain_idx: usize,
+    },
+    /// Linker Versatz-Node → rechter Versatz-Node (Indizes in der jeweiligen Seite).
+    BetweenSides { left_idx: usize, right_idx: usize },
+}
+
 /// Konfiguration fuer das RouteOffsetTool.
 #[derive(Debug, Clone)]
 pub struct OffsetConfig {
@@ -47,6 +62,13 @@ pub struct OffsetConfig {
     /// `false`: rechts folgt der gewaehlten Einbahn-Richtung, links verlaeuft
     /// entgegengesetzt.
     pub left_side_follows_one_way: bool,
+    /// Querverbindungen (Zweirichtung) zwischen den parallelen Ketten erzeugen?
+    ///
+    /// Mit Original: jede Versatz-Seite wird mit der Original-Kette verbunden.
+    /// Ohne Original: linke und rechte Seite werden miteinander verbunden.
+    pub cross_links_enabled: bool,
+    /// Abstand der Querverbindungen entlang der Kette in Metern
+    pub cross_link_spacing: f32,
 }
 
 impl Default for OffsetConfig {
@@ -59,6 +81,8 @@ impl Default for OffsetConfig {
             keep_original: true,
             base_spacing: 6.0,
             left_side_follows_one_way: true,
+            cross_links_enabled: false,
+            cross_link_spacing: 50.0,
         }
     }
 }
@@ -333,6 +333,86 @@ fn test_offset_original_beibehalten_leeres_nodes_to_remove() {
     );
 }
 
+/// Querverbindungen mit Original: jede Seite wird alle N Meter zweiseitig
+/// mit einem inneren Original-Node verbunden.
+#[test]
+fn test_querverbindungen_zum_original() {
+    let mut tool = RouteOffsetTool::new();
+    tool.load_chain(make_chain(21, 10.0), 1, 21);
+    tool.config.cross_links_enabled = true;
+    tool.config.cross_link_spacing = 50.0;
+    let road_map = RoadMap::new(3);
+    let result = tool.execute(&road_map).unwrap();
+
+    let cross: Vec<_> = result
+        .external_connections
+        .iter()
+        .filter(|(_, _, _, direction, _)| *direction == ConnectionDirection::Dual)
+        .filter(|(_, id, _, _, _)| *id != 1 && *id != 21)
+        .collect();
+    // 200 m Kette → Querverbindungen bei 50, 100 und 150 m.
+    assert_eq!(cross.len(), 3);
+    for &&(idx, id, _, _, _) in &cross {
+        let expected_x = (id - 1) as f32 * 10.0;
+        assert!((result.new_nodes[idx].0.x - expected_x).abs() <= 5.0);
+    }
+}
+
+/// Querverbindungen ohne Original verbinden linke und rechte Seite.
+#[test]
+fn test_querverbindungen_zwischen_den_seiten_ohne_original() {
+    let mut tool = RouteOffsetTool::new();
+    tool.load_chain(make_chain(21, 10.0), 1, 21);
+    tool.config.right_enabled = true;
+    tool.config.keep_original = false;
+    tool.config.cross_links_enabled = true;
+    tool.config.cross_link_spacing = 50.0;
+    let road_map = RoadMap::new(3);
+    let result = tool.execute(&road_map).unwrap();
+
+    let cross: Vec<_> = result
+        .internal_connections
+        .iter()
+        .filter(|(from, to, _, _)| {
+            let (a, b) = (result.new_nodes[*from].0, result.new_nodes[*to].0);
+            a.y > 0.0 && b.y < 0.0
+        })
+        .collect();
+    assert_eq!(cross.len(), 3);
+    assert!(cross
+        .iter()
+        .all(|(_, _, direction, _)| *direction == ConnectionDirection::Dual));
+    assert_eq!(result.external_connections.len(), 4);
+
+    tool.config.cross_links_enabled = false;
+    let plain = tool.execute(&road_map).unwrap();
+    assert_eq!(
+        plain.internal_connections.len() + 3,
+        result.internal_connections.len()
+    );
+}
+
+/// Querverbindungen aendern Panelzustand und Edit-Payload konsistent.
+#[test]
+fn test_querverbindungen_panel_und_payload() {
+    use crate::app::tools::RouteToolGroupEdit;
+
+    let mut tool = RouteOffsetTool::new();
+    tool.load_chain(make_chain(5, 10.0), 1, 5);
+    assert!(
+        tool.apply_panel_action(RouteOffsetPanelAction::SetCrossLinksEnabled(true))
+            .changed
+    );
+    tool.apply_panel_action(RouteOffsetPanelAction::SetCrossLinkSpacing(1.0));
+    assert_eq!(tool.config.cross_link_spacing, 10.0, "Untergrenze greift");
+
+    let payload = tool.build_edit_payload().unwrap();
+    let mut restored = RouteOffsetTool::new();
+    restored.restore_edit_payload(&payload);
+    assert!(restored.config.cross_links_enabled);
+    assert_eq!(restored.config.cross_link_spacing, 10.0);
+}
+
 /// Keine Kette → execute() gibt None zurueck.
 #[test]
 fn test_execute_ohne_kette_gibt_none() {
@@ -193,6 +193,15 @@ impl RouteToolCore for SmoothCurveTool {
     fn has_pending_input(&self) -> bool {
         self.phase != Phase::Start
     }
+
+    fn segment_origin(&self) -> Option<Vec2> {
+        match self.phase {
+            Phase::Start => self.lifecycle.chaining_start_anchor(),
+            Phase::End => self.start,
+            _ => None,
+        }
+        .map(|anchor| anchor.position())
+    }
 }
 
 impl RouteToolHostSync for SmoothCurveTool {
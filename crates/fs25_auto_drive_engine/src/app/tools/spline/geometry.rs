@@ -4,7 +4,8 @@
 //! Dieses Modul re-exportiert sie fuer die bequeme Nutzung im Tool-Layer.
 
 pub use crate::shared::spline_geometry::{
-    catmull_rom_chain_with_tangents, polyline_length, resample_by_distance,
+    catmull_rom_chain_with_tangents, catmull_rom_segments_into, phantom_from_tangent,
+    polyline_length, resample_by_distance,
 };
 
 /// Kompatibilitaet: Standard-Catmull-Rom-Chain ohne Tangent-Override (nur fuer Tests).
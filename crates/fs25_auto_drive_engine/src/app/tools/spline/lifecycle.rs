@@ -77,7 +77,7 @@ impl RouteToolCore for SplineTool {
             let start = self.anchors[0].position();
             vec![start, snapped_cursor]
         } else {
-            self.compute_resampled(Some(snapped_cursor))
+            self.compute_preview_resampled(snapped_cursor)
         };
 
         let connections = linear_connections(positions.len());
@@ -121,6 +121,14 @@ impl RouteToolCore for SplineTool {
     fn has_pending_input(&self) -> bool {
         !self.anchors.is_empty()
     }
+
+    fn segment_origin(&self) -> Option<Vec2> {
+        self.anchors
+            .last()
+            .copied()
+            .or_else(|| self.lifecycle.chaining_start_anchor())
+            .map(|anchor| anchor.position())
+    }
 }
 
 impl RouteToolHostSync for SplineTool {
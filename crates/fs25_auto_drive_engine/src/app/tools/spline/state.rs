@@ -2,10 +2,14 @@
 
 use super::super::common::{self, SegmentConfig, TangentState, ToolLifecycleState};
 use super::super::{ToolAnchor, ToolResult};
-use super::geometry::{catmull_rom_chain_with_tangents, polyline_length, resample_by_distance};
+use super::geometry::{
+    catmull_rom_chain_with_tangents, catmull_rom_segments_into, phantom_from_tangent,
+    polyline_length, resample_by_distance,
+};
 use crate::app::tool_contract::TangentSource;
 use crate::core::{ConnectionDirection, ConnectionPriority, RoadMap};
 use glam::Vec2;
+use std::cell::RefCell;
 
 /// Zwischenpunkte pro Catmull-Rom-Segment beim Dicht-Sampling.
 ///
@@ -13,6 +17,41 @@ use glam::Vec2;
 /// doppelt so viele (32) liefern keinen sichtbaren Qualitaetsunterschied.
 const SPLINE_SAMPLES_PER_SEGMENT: usize = 16;
 
+/// Cursor-Bewegung (Welteinheiten), unterhalb der die letzte Preview wiederverwendet wird.
+///
+/// Drosselt die Neuberechnung bei Mikrobewegungen der Maus; der Cursor-Node
+/// selbst folgt weiterhin exakt, nur die Resampling-Kette wird nicht neu gebaut.
+pub(crate) const PREVIEW_CURSOR_TOLERANCE: f32 = 0.05;
+
+/// Cursor-unabhaengiger Anfang der dichten Preview-Polyline.
+///
+/// Bei `n` Ankern plus Cursor haengen nur die letzten beiden Catmull-Rom-Segmente
+/// vom Cursor ab; die Segmente `0..n-2` bleiben bis zum naechsten Klick gleich.
+#[derive(Debug, Clone)]
+pub(crate) struct SplineDensePrefix {
+    pub anchors: Vec<Vec2>,
+    pub tangent_start: TangentSource,
+    pub dense: Vec<Vec2>,
+}
+
+/// Zuletzt berechnete, resampelte Preview-Positionen (fuer das Drosseln).
+#[derive(Debug, Clone)]
+pub(crate) struct SplineResampledPreview {
+    pub anchors: Vec<Vec2>,
+    pub tangent_start: TangentSource,
+    pub tangent_end: TangentSource,
+    pub max_segment_length: f32,
+    pub cursor: Vec2,
+    pub positions: Vec<Vec2>,
+}
+
+/// Zwischenspeicher fuer den Preview-Hotpath des Spline-Tools.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SplinePreviewCache {
+    pub prefix: Option<SplineDensePrefix>,
+    pub resampled: Option<SplineResampledPreview>,
+}
+
 /// Spline-Tool: Interpolierender Catmull-Rom-Spline durch geklickte Punkte.
 pub struct SplineTool {
     /// Alle bestaetigten Kontrollpunkte (geklickt)
@@ -27,6 +66,8 @@ pub struct SplineTool {
     pub(crate) last_anchors: Vec<ToolAnchor>,
     /// Tangenten-Zustand (Start/Ende, Nachbarn-Cache, Recreation-Kopien)
     pub(crate) tangents: TangentState,
+    /// Cache fuer dichte Polyline und Resampling im Preview-Hotpath.
+    pub(crate) preview_cache: RefCell<SplinePreviewCache>,
 }
 
 impl SplineTool {
@@ -40,6 +81,7 @@ impl SplineTool {
             lifecycle: ToolLifecycleState::new(3.0), // Default, wird vom Handler ueberschrieben
             last_anchors: Vec::new(),
             tangents: TangentState::new(),
+            preview_cache: RefCell::new(SplinePreviewCache::default()),
         }
     }
 
@@ -63,9 +105,7 @@ impl SplineTool {
         tangent_angle: f32,
         neighbor_pos: Vec2,
     ) -> Vec2 {
-        let dist = anchor_pos.distance(neighbor_pos).max(1.0);
-        let dir = Vec2::from_angle(tangent_angle + std::f32::consts::PI);
-        anchor_pos + dir * dist
+        phantom_from_tangent(anchor_pos, tangent_angle, neighbor_pos)
     }
 
     /// Berechnet optionale Phantom-Punkte fuer Start und Ende des Splines.
@@ -123,10 +163,91 @@ impl SplineTool {
         )
     }
 
-    /// Berechnet die verteilt gesampelten Positionen (fuer Nodes).
-    pub(crate) fn compute_resampled(&self, extra_cursor: Option<Vec2>) -> Vec<Vec2> {
-        let dense = self.compute_dense_polyline(extra_cursor);
-        resample_by_distance(&dense, self.seg.max_segment_length)
+    /// Preview-Variante von [`compute_dense_polyline`](Self::compute_dense_polyline).
+    ///
+    /// Liefert dasselbe Ergebnis, berechnet aber nur die beiden cursorabhaengigen
+    /// End-Segmente neu und uebernimmt den Anfang aus dem Cache.
+    pub(crate) fn compute_dense_polyline_cached(&self, cursor: Vec2) -> Vec<Vec2> {
+        if self.anchors.len() < 3 {
+            return self.compute_dense_polyline(Some(cursor));
+        }
+
+        let mut pts: Vec<Vec2> = self.anchor_positions().collect();
+        pts.push(cursor);
+        let (start_phantom, end_phantom) =
+            Self::compute_phantoms(&pts, self.tangents.tangent_start, self.tangents.tangent_end);
+        let anchor_count = self.anchors.len();
+        let stable_segments = anchor_count - 2;
+
+        let mut cache = self.preview_cache.borrow_mut();
+        let prefix_valid = cache.prefix.as_ref().is_some_and(|prefix| {
+            prefix.tangent_start == self.tangents.tangent_start
+                && prefix.anchors == pts[..anchor_count]
+        });
+        if !prefix_valid {
+            let mut dense = Vec::with_capacity(stable_segments * SPLINE_SAMPLES_PER_SEGMENT);
+            catmull_rom_segments_into(
+                &pts,
+                SPLINE_SAMPLES_PER_SEGMENT,
+                start_phantom,
+                end_phantom,
+                0..stable_segments,
+                &mut dense,
+            );
+            cache.prefix = Some(SplineDensePrefix {
+                anchors: pts[..anchor_count].to_vec(),
+                tangent_start: self.tangents.tangent_start,
+                dense,
+            });
+        }
+
+        let prefix = &cache
+            .prefix
+            .as_ref()
+            .expect("invariant: Prefix-Cache wurde oben befuellt")
+            .dense;
+        let mut dense = Vec::with_capacity(prefix.len() + 2 * SPLINE_SAMPLES_PER_SEGMENT + 1);
+        dense.extend_from_slice(prefix);
+        catmull_rom_segments_into(
+            &pts,
+            SPLINE_SAMPLES_PER_SEGMENT,
+            start_phantom,
+            end_phantom,
+            stable_segments..anchor_count,
+            &mut dense,
+        );
+        dense
+    }
+
+    /// Resampelte Preview-Positionen mit Cursor als naechstem Punkt.
+    ///
+    /// Bewegt sich der Cursor weniger als [`PREVIEW_CURSOR_TOLERANCE`] seit der
+    /// letzten Berechnung und sind Anker, Tangenten und Segment-Laenge
+    /// unveraendert, wird das vorherige Ergebnis ohne Neuberechnung geliefert.
+    pub(crate) fn compute_preview_resampled(&self, cursor: Vec2) -> Vec<Vec2> {
+        let max_segment_length = self.seg.max_segment_length;
+        if let Some(last) = self.preview_cache.borrow().resampled.as_ref()
+            && last.cursor.distance(cursor) < PREVIEW_CURSOR_TOLERANCE
+            && last.max_segment_length == max_segment_length
+            && last.tangent_start == self.tangents.tangent_start
+            && last.tangent_end == self.tangents.tangent_end
+            && last.anchors.iter().copied().eq(self.anchor_positions())
+        {
+            return last.positions.clone();
+        }
+
+        let dense = self.compute_dense_polyline_cached(cursor);
+        let positions = resample_by_distance(&dense, max_segment_length);
+
+        self.preview_cache.borrow_mut().resampled = Some(SplineResampledPreview {
+            anchors: self.anchor_positions().collect(),
+            tangent_start: self.tangents.tangent_start,
+            tangent_end: self.tangents.tangent_end,
+            max_segment_length,
+            cursor,
+            positions: positions.clone(),
+        });
+        positions
     }
 
     /// Spline-Laenge ueber aktuelle Anker.
@@ -270,3 +270,59 @@ fn test_spline_execute_from_anchors() {
         .expect("Ergebnis erwartet");
     assert!(result.new_nodes.len() < original.new_nodes.len());
 }
+
+// ── Preview-Cache ──
+
+fn spline_with_anchors(count: usize) -> SplineTool {
+    let mut tool = SplineTool::new();
+    let road_map = RoadMap::new(3);
+    for i in 0..count {
+        let x = i as f32 * 10.0;
+        let y = if i % 2 == 0 { 0.0 } else { 6.0 };
+        tool.on_click(Vec2::new(x, y), &road_map, false);
+    }
+    tool
+}
+
+#[test]
+fn test_cached_dense_polyline_matches_full_recompute() {
+    let tool = spline_with_anchors(12);
+
+    for cursor in [Vec2::new(130.0, 4.0), Vec2::new(125.0, -7.0)] {
+        let cached = tool.compute_dense_polyline_cached(cursor);
+        let full = tool.compute_dense_polyline(Some(cursor));
+        assert_eq!(cached, full);
+    }
+}
+
+#[test]
+fn test_cached_dense_polyline_invalidates_on_new_anchor() {
+    let mut tool = spline_with_anchors(5);
+    let road_map = RoadMap::new(3);
+    let cursor = Vec2::new(70.0, 3.0);
+
+    let _ = tool.compute_dense_polyline_cached(cursor);
+    tool.on_click(Vec2::new(60.0, -4.0), &road_map, false);
+
+    assert_eq!(
+        tool.compute_dense_polyline_cached(cursor),
+        tool.compute_dense_polyline(Some(cursor))
+    );
+}
+
+#[test]
+fn test_preview_resampling_is_throttled_for_tiny_cursor_moves() {
+    let tool = spline_with_anchors(6);
+    let cursor = Vec2::new(70.0, 3.0);
+
+    let first = tool.compute_preview_resampled(cursor);
+    let nudged = cursor + Vec2::new(super::state::PREVIEW_CURSOR_TOLERANCE * 0.5, 0.0);
+    assert_eq!(tool.compute_preview_resampled(nudged), first);
+
+    let moved = cursor + Vec2::new(5.0, 0.0);
+    let expected = resample_by_distance(
+        &tool.compute_dense_polyline(Some(moved)),
+        tool.seg.max_segment_length,
+    );
+    assert_eq!(tool.compute_preview_resampled(moved), expected);
+}
@@ -98,6 +98,15 @@ impl RouteToolCore for StraightLineTool {
     fn has_pending_input(&self) -> bool {
         self.start.is_some()
     }
+
+    fn segment_origin(&self) -> Option<Vec2> {
+        if self.end.is_some() {
+            return None;
+        }
+        self.start
+            .or_else(|| self.lifecycle.chaining_start_anchor())
+            .map(|anchor| anchor.position())
+    }
 }
 
 impl RouteToolHostSync for StraightLineTool {
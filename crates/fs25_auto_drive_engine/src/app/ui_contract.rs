@@ -13,29 +13,37 @@ pub use host_ui::{
     PanelAction, PanelState,
 };
 pub use route_tool_panel::{
-    BypassPanelAction, BypassPanelState, ColorPathPanelAction, ColorPathPanelPhase,
-    ColorPathPanelState, ColorPathPreviewStats, CurveDegreeChoice, CurvePanelAction,
-    CurvePanelState, CurveTangentsPanelState, ExistingConnectionModeChoice,
-    FieldBoundaryPanelAction, FieldBoundaryPanelState, FieldPathModeChoice, FieldPathPanelAction,
-    FieldPathPanelPhase, FieldPathPanelState, FieldPathPreviewStatus, FieldPathSelectionSummary,
+    ArcPanelAction, ArcPanelState, BypassPanelAction, BypassPanelState, ClothoidPanelAction,
+    ClothoidPanelState, ColorPathPanelAction, ColorPathPanelPhase, ColorPathPanelState,
+    ColorPathPreviewStats, CurveDegreeChoice, CurvePanelAction, CurvePanelState,
+    CurveTangentsPanelState, ExistingConnectionModeChoice, FieldBoundaryPanelAction,
+    FieldBoundaryPanelState, FieldCoursePanelAction, FieldCoursePanelState, FieldCourseTurnChoice,
+    FieldPathModeChoice, FieldPathPanelAction, FieldPathPanelPhase, FieldPathPanelState,
+    FieldPathPreviewStatus, FieldPathSelectionSummary, JunctionPanelAction, JunctionPanelState,
     ParkingPanelAction, ParkingPanelState, ParkingRampSideChoice, RoundingPanelAction,
-    RoundingPanelState, RouteOffsetPanelAction, RouteOffsetPanelState, RouteToolConfigState,
-    RouteToolPanelAction, RouteToolPanelEffect, RouteToolPanelFollowUp, RouteToolPanelState,
-    SegmentConfigPanelAction, SegmentConfigPanelState, SegmentLengthKind, SegmentPanelMode,
-    SmoothCurvePanelAction, SmoothCurvePanelState, SmoothCurveSteererState, SplinePanelAction,
-    SplinePanelState, StraightPanelAction, StraightPanelState, TangentHelpHint, TangentNoneReason,
-    TangentSelectionState,
+    RoundingPanelState, RouteElevationProfile, RouteOffsetPanelAction, RouteOffsetPanelState,
+    RouteToolConfigState, RouteToolPanelAction, RouteToolPanelEffect, RouteToolPanelFollowUp,
+    RouteToolPanelState, SegmentConfigPanelAction, SegmentConfigPanelState, SegmentLengthKind,
+    SegmentPanelMode, SmoothCurvePanelAction, SmoothCurvePanelState, SmoothCurveSteererState,
+    SplinePanelAction, SplinePanelState, StraightPanelAction, StraightPanelState, TangentHelpHint,
+    TangentNoneReason, TangentSelectionState,
 };
 pub use route_tool_panel::{
-    BYPASS_BASE_SPACING_LIMITS, BYPASS_OFFSET_LIMITS, PARKING_BAY_LENGTH_LIMITS,
-    PARKING_ENTRY_EXIT_T_LIMITS, PARKING_MAX_NODE_DISTANCE_LIMITS, PARKING_NUM_ROWS_LIMITS,
-    PARKING_RAMP_LENGTH_LIMITS, PARKING_ROTATION_STEP_LIMITS, PARKING_ROW_SPACING_LIMITS,
-    ROUNDING_ARC_RADIUS_LIMITS, ROUNDING_MAX_ANGLE_LIMITS, ROUTE_OFFSET_BASE_SPACING_LIMITS,
-    ROUTE_OFFSET_DISTANCE_LIMITS, SMOOTH_CURVE_MAX_ANGLE_LIMITS, SMOOTH_CURVE_MIN_DISTANCE_LIMITS,
+    BYPASS_BASE_SPACING_LIMITS, BYPASS_OFFSET_LIMITS, FIELD_COURSE_HEADLAND_PASSES_LIMITS,
+    FIELD_COURSE_NODE_SPACING_LIMITS, FIELD_COURSE_WORKING_WIDTH_LIMITS,
+    JUNCTION_NODE_SPACING_LIMITS, PARKING_BAY_LENGTH_LIMITS, PARKING_ENTRY_EXIT_T_LIMITS,
+    PARKING_MAX_NODE_DISTANCE_LIMITS, PARKING_NUM_ROWS_LIMITS, PARKING_RAMP_LENGTH_LIMITS,
+    PARKING_ROTATION_STEP_LIMITS, PARKING_ROW_SPACING_LIMITS, ROUNDING_ARC_RADIUS_LIMITS,
+    ROUNDING_MAX_ANGLE_LIMITS, ROUTE_OFFSET_BASE_SPACING_LIMITS,
+    ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS, ROUTE_OFFSET_DISTANCE_LIMITS,
+    SMOOTH_CURVE_MAX_ANGLE_LIMITS, SMOOTH_CURVE_MIN_DISTANCE_LIMITS,
 };
 pub use viewport_overlay::{
     ClipboardOverlaySnapshot, ClipboardPreviewNode, GroupBoundaryOverlaySnapshot,
-    GroupLockOverlaySnapshot, PolylineOverlaySnapshot, ViewportOverlaySnapshot,
+    GroupLockOverlaySnapshot, LivePlayerOverlaySnapshot, LockedNodeOverlaySnapshot,
+    MeasureOverlaySnapshot, MeasureSegmentSnapshot, OwnedFarmlandOverlaySnapshot,
+    PolylineOverlaySnapshot, SelectedConnectionOverlaySnapshot, TerrainClippingOverlaySnapshot,
+    VehicleOverlaySnapshot, ViewportOverlaySnapshot,
 };
 
 /// Eine waehlbare Tangenten-Option mit bereits aufbereitetem UI-Label.
@@ -24,6 +24,12 @@ pub enum DialogRequestKind {
     CurseplayImport,
     /// Curseplay-Datei exportieren.
     CurseplayExport,
+    /// Map-Mod-ZIP fuer den Standard-Config-Export auswaehlen.
+    MapModZip,
+    /// Zielpfad des Kurs-Mod-ZIPs waehlen.
+    CoursesModExport,
+    /// Zielpfad des PDF-Kartenexports waehlen.
+    PdfExport,
 }
 
 /// Semantische Host-Anforderung: Datei-/Pfad-Dialoge oder Chrome-Sichtbarkeitsaenderungen.
@@ -137,6 +143,11 @@ pub fn dialog_result_to_intent(result: DialogResult) -> Option<AppIntent> {
             DialogRequestKind::CurseplayExport => {
                 Some(AppIntent::CurseplayExportPathSelected { path })
             }
+            DialogRequestKind::MapModZip => Some(AppIntent::MapModZipSelected { path }),
+            DialogRequestKind::CoursesModExport => {
+                Some(AppIntent::CoursesModExportPathSelected { path })
+            }
+            DialogRequestKind::PdfExport => Some(AppIntent::PdfExportPathSelected { path }),
         },
     }
 }
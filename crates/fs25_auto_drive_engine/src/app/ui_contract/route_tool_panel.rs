@@ -8,30 +8,35 @@ mod limits;
 pub use analysis_family::{
     ColorPathPanelAction, ColorPathPanelPhase, ColorPathPanelState, ColorPathPreviewStats,
     ExistingConnectionModeChoice, FieldBoundaryPanelAction, FieldBoundaryPanelState,
-    FieldPathModeChoice, FieldPathPanelAction, FieldPathPanelPhase, FieldPathPanelState,
-    FieldPathPreviewStatus, FieldPathSelectionSummary, RouteOffsetPanelAction,
-    RouteOffsetPanelState,
+    FieldCoursePanelAction, FieldCoursePanelState, FieldCourseTurnChoice, FieldPathModeChoice,
+    FieldPathPanelAction, FieldPathPanelPhase, FieldPathPanelState, FieldPathPreviewStatus,
+    FieldPathSelectionSummary, RouteOffsetPanelAction, RouteOffsetPanelState,
 };
 pub use common::{
-    RouteToolPanelEffect, RouteToolPanelFollowUp, RouteToolPanelState, SegmentConfigPanelAction,
-    SegmentConfigPanelState, SegmentLengthKind, SegmentPanelMode, TangentNoneReason,
-    TangentSelectionState,
+    RouteElevationProfile, RouteToolPanelEffect, RouteToolPanelFollowUp, RouteToolPanelState,
+    SegmentConfigPanelAction, SegmentConfigPanelState, SegmentLengthKind, SegmentPanelMode,
+    TangentNoneReason, TangentSelectionState,
 };
 pub use curve_family::{
-    CurveDegreeChoice, CurvePanelAction, CurvePanelState, CurveTangentsPanelState,
-    SplinePanelAction, SplinePanelState, TangentHelpHint,
+    ArcPanelAction, ArcPanelState, ClothoidPanelAction, ClothoidPanelState, CurveDegreeChoice,
+    CurvePanelAction, CurvePanelState, CurveTangentsPanelState, SplinePanelAction,
+    SplinePanelState, TangentHelpHint,
 };
 pub use generator_family::{
-    BypassPanelAction, BypassPanelState, ParkingPanelAction, ParkingPanelState,
-    ParkingRampSideChoice, RoundingPanelAction, RoundingPanelState, SmoothCurvePanelAction,
-    SmoothCurvePanelState, SmoothCurveSteererState, StraightPanelAction, StraightPanelState,
+    BypassPanelAction, BypassPanelState, JunctionPanelAction, JunctionPanelState,
+    ParkingPanelAction, ParkingPanelState, ParkingRampSideChoice, RoundingPanelAction,
+    RoundingPanelState, SmoothCurvePanelAction, SmoothCurvePanelState, SmoothCurveSteererState,
+    StraightPanelAction, StraightPanelState,
 };
 pub use limits::{
-    BYPASS_BASE_SPACING_LIMITS, BYPASS_OFFSET_LIMITS, PARKING_BAY_LENGTH_LIMITS,
-    PARKING_ENTRY_EXIT_T_LIMITS, PARKING_MAX_NODE_DISTANCE_LIMITS, PARKING_NUM_ROWS_LIMITS,
-    PARKING_RAMP_LENGTH_LIMITS, PARKING_ROTATION_STEP_LIMITS, PARKING_ROW_SPACING_LIMITS,
-    ROUNDING_ARC_RADIUS_LIMITS, ROUNDING_MAX_ANGLE_LIMITS, ROUTE_OFFSET_BASE_SPACING_LIMITS,
-    ROUTE_OFFSET_DISTANCE_LIMITS, SMOOTH_CURVE_MAX_ANGLE_LIMITS, SMOOTH_CURVE_MIN_DISTANCE_LIMITS,
+    BYPASS_BASE_SPACING_LIMITS, BYPASS_OFFSET_LIMITS, FIELD_COURSE_HEADLAND_PASSES_LIMITS,
+    FIELD_COURSE_NODE_SPACING_LIMITS, FIELD_COURSE_WORKING_WIDTH_LIMITS,
+    JUNCTION_NODE_SPACING_LIMITS, PARKING_BAY_LENGTH_LIMITS, PARKING_ENTRY_EXIT_T_LIMITS,
+    PARKING_MAX_NODE_DISTANCE_LIMITS, PARKING_NUM_ROWS_LIMITS, PARKING_RAMP_LENGTH_LIMITS,
+    PARKING_ROTATION_STEP_LIMITS, PARKING_ROW_SPACING_LIMITS, ROUNDING_ARC_RADIUS_LIMITS,
+    ROUNDING_MAX_ANGLE_LIMITS, ROUTE_OFFSET_BASE_SPACING_LIMITS,
+    ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS, ROUTE_OFFSET_DISTANCE_LIMITS,
+    SMOOTH_CURVE_MAX_ANGLE_LIMITS, SMOOTH_CURVE_MIN_DISTANCE_LIMITS,
 };
 use serde::{Deserialize, Serialize};
 
@@ -52,6 +57,8 @@ pub enum RouteToolConfigState {
     Parking(ParkingPanelState),
     /// Panelzustand fuer das Feldgrenz-Tool.
     FieldBoundary(FieldBoundaryPanelState),
+    /// Panelzustand fuer das Feldkurs-Tool.
+    FieldCourse(FieldCoursePanelState),
     /// Panelzustand fuer das Feldweg-Tool.
     FieldPath(FieldPathPanelState),
     /// Panelzustand fuer das Strecken-Versatz-Tool.
@@ -60,6 +67,12 @@ pub enum RouteToolConfigState {
     Rounding(RoundingPanelState),
     /// Panelzustand fuer das Farb-Pfad-Tool.
     ColorPath(ColorPathPanelState),
+    /// Panelzustand fuer das Klothoiden-Tool.
+    Clothoid(ClothoidPanelState),
+    /// Panelzustand fuer das Kreisbogen-Tool.
+    Arc(ArcPanelState),
+    /// Panelzustand fuer das Kreuzungs-Tool.
+    Junction(JunctionPanelState),
 }
 
 /// Semantische Panel-Aktion fuer das aktive Route-Tool.
@@ -80,6 +93,8 @@ pub enum RouteToolPanelAction {
     Parking(ParkingPanelAction),
     /// Panel-Aktion fuer das Feldgrenz-Tool.
     FieldBoundary(FieldBoundaryPanelAction),
+    /// Panel-Aktion fuer das Feldkurs-Tool.
+    FieldCourse(FieldCoursePanelAction),
     /// Panel-Aktion fuer das Feldweg-Tool.
     FieldPath(FieldPathPanelAction),
     /// Panel-Aktion fuer das Strecken-Versatz-Tool.
@@ -88,4 +103,10 @@ pub enum RouteToolPanelAction {
     Rounding(RoundingPanelAction),
     /// Panel-Aktion fuer das Farb-Pfad-Tool.
     ColorPath(ColorPathPanelAction),
+    /// Panel-Aktion fuer das Klothoiden-Tool.
+    Clothoid(ClothoidPanelAction),
+    /// Panel-Aktion fuer das Kreisbogen-Tool.
+    Arc(ArcPanelAction),
+    /// Panel-Aktion fuer das Kreuzungs-Tool.
+    Junction(JunctionPanelAction),
 }
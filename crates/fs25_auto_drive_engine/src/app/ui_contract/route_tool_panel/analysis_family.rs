@@ -19,6 +19,10 @@ pub struct RouteOffsetPanelState {
     pub base_spacing: f32,
     /// Original-Kette beibehalten?
     pub keep_original: bool,
+    /// Querverbindungen zwischen den parallelen Ketten erzeugen?
+    pub cross_links_enabled: bool,
+    /// Abstand der Querverbindungen in Metern.
+    pub cross_link_spacing: f32,
     /// Anzahl Nodes der geladenen Kette.
     pub chain_node_count: usize,
 }
@@ -39,6 +43,10 @@ pub enum RouteOffsetPanelAction {
     SetBaseSpacing(f32),
     /// Original-Kette beibehalten setzen.
     SetKeepOriginal(bool),
+    /// Querverbindungen aktivieren/deaktivieren.
+    SetCrossLinksEnabled(bool),
+    /// Abstand der Querverbindungen setzen.
+    SetCrossLinkSpacing(f32),
     /// Bei Einbahn und beidseitigem Versatz den Links/Rechts-Verkehr umschalten.
     ToggleReversedSide,
 }
@@ -98,6 +106,51 @@ pub enum FieldBoundaryPanelAction {
     SetPriority(ConnectionPriority),
 }
 
+/// Wendeform des Feldkurs-Tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldCourseTurnChoice {
+    /// Direkte Verbindung zur naechsten Bahn.
+    Direct,
+    /// Halbkreis-Wende ins Vorgewende.
+    Rounded,
+}
+
+/// Panelzustand des Feldkurs-Tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldCoursePanelState {
+    /// ID des aktuell gewaehlten Feldes.
+    pub selected_field_id: Option<u32>,
+    /// Arbeitsbreite in Metern.
+    pub working_width: f32,
+    /// Gewuenschte Anzahl der Vorgewende-Runden.
+    pub headland_passes: usize,
+    /// Wendeform zwischen den Bahnen.
+    pub turn_style: FieldCourseTurnChoice,
+    /// Abstand zwischen erzeugten Nodes.
+    pub node_spacing: f32,
+    /// Tatsaechlich erzeugte Vorgewende-Runden (schmale Felder: weniger).
+    pub generated_headlands: usize,
+    /// Anzahl der Bahnen in der Serpentine.
+    pub lane_count: usize,
+    /// Zeigt Hinweis: weiterer Klick waehlt anderes Feld.
+    pub show_select_hint: bool,
+}
+
+/// Panel-Aktion des Feldkurs-Tools.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum FieldCoursePanelAction {
+    /// Arbeitsbreite setzen.
+    SetWorkingWidth(f32),
+    /// Anzahl der Vorgewende-Runden setzen.
+    SetHeadlandPasses(usize),
+    /// Wendeform setzen.
+    SetTurnStyle(FieldCourseTurnChoice),
+    /// Node-Abstand setzen.
+    SetNodeSpacing(f32),
+}
+
 /// Auswahlmodus des Feldweg-Panels.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
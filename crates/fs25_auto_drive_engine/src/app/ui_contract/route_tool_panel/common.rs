@@ -17,6 +17,26 @@ pub struct RouteToolPanelState {
     pub can_execute: bool,
     /// Tool-spezifischer Panelzustand.
     pub config_state: Option<RouteToolConfigState>,
+    /// Hoehenprofil der Vorschau; nur mit geladener Heightmap und ausfuehrbarem Tool.
+    pub elevation_profile: Option<RouteElevationProfile>,
+}
+
+/// Hoehenprofil der Route-Tool-Vorschau ueber der geladenen Heightmap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteElevationProfile {
+    /// Stuetzpunkte `[Streckenmeter, Hoehe in m]` in Node-Reihenfolge der Vorschau.
+    pub points: Vec<[f32; 2]>,
+    /// Betrag der steilsten Vorschau-Verbindung in Prozent.
+    pub max_grade_percent: f32,
+    /// Konfigurierte Steigungsgrenze in Prozent.
+    pub grade_limit_percent: f32,
+}
+
+impl RouteElevationProfile {
+    /// Gibt an, ob mindestens eine Verbindung die Steigungsgrenze ueberschreitet.
+    pub fn exceeds_limit(&self) -> bool {
+        self.max_grade_percent > self.grade_limit_percent
+    }
 }
 
 /// Rueckgabe der App nach einer semantischen Panel-Aktion.
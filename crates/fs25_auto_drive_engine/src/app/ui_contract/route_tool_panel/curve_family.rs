@@ -80,3 +80,47 @@ pub enum SplinePanelAction {
     /// Gemeinsame Segment-Konfiguration aendern.
     Segment(SegmentConfigPanelAction),
 }
+
+/// Panelzustand des Klothoiden-Tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClothoidPanelState {
+    /// Optionale Start-Tangenten-Auswahl (sobald Start und Ende gesetzt sind).
+    pub start_tangent: Option<TangentSelectionState>,
+    /// Optionale End-Tangenten-Auswahl (sobald Start und Ende gesetzt sind).
+    pub end_tangent: Option<TangentSelectionState>,
+    /// Kleinster Kurvenradius in Metern; `None` bei gerader oder unbestimmter Strecke.
+    pub min_radius_m: Option<f32>,
+    /// Gemeinsame Segment-Konfiguration.
+    pub segment: SegmentConfigPanelState,
+}
+
+/// Panel-Aktion des Klothoiden-Tools.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum ClothoidPanelAction {
+    /// Start-Tangente setzen.
+    SetTangentStart(TangentSource),
+    /// End-Tangente setzen.
+    SetTangentEnd(TangentSource),
+    /// Gemeinsame Segment-Konfiguration aendern.
+    Segment(SegmentConfigPanelAction),
+}
+
+/// Panelzustand des Kreisbogen-Tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcPanelState {
+    /// Radius des aktuellen Bogens in Metern; `None` bei kollinearen Punkten.
+    pub radius_m: Option<f32>,
+    /// Ueberstrichener Winkel des aktuellen Bogens in Grad.
+    pub sweep_deg: Option<f32>,
+    /// Gemeinsame Segment-Konfiguration.
+    pub segment: SegmentConfigPanelState,
+}
+
+/// Panel-Aktion des Kreisbogen-Tools.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum ArcPanelAction {
+    /// Gemeinsame Segment-Konfiguration aendern.
+    Segment(SegmentConfigPanelAction),
+}
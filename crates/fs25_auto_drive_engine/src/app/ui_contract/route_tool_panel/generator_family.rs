@@ -185,3 +185,26 @@ pub enum BypassPanelAction {
     /// Grundabstand setzen.
     SetBaseSpacing(f32),
 }
+
+/// Panelzustand des Kreuzungs-Tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JunctionPanelState {
+    /// Anzahl aktuell selektierter Nodes (Zufahrts-Endpunkte).
+    pub selected_node_count: usize,
+    /// Anzahl berechneter Spuren, falls die Kreuzung erzeugt werden kann.
+    pub lane_count: Option<usize>,
+    /// Maximaler Abstand zwischen erzeugten Spur-Nodes.
+    pub node_spacing: f32,
+    /// Abbiegespuren ausserhalb der Hauptstrasse als Nebenstrecke anlegen.
+    pub sub_priority_turns: bool,
+}
+
+/// Panel-Aktion des Kreuzungs-Tools.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum JunctionPanelAction {
+    /// Node-Abstand der Spuren setzen.
+    SetNodeSpacing(f32),
+    /// Nebenstrecken-Prioritaet fuer Abbiegespuren umschalten.
+    SetSubPriorityTurns(bool),
+}
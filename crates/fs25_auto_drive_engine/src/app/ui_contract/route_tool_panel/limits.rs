@@ -58,6 +58,15 @@ pub const BYPASS_BASE_SPACING_LIMITS: FloatInputLimits = FloatInputLimits::new(1
 pub const ROUTE_OFFSET_DISTANCE_LIMITS: FloatInputLimits = FloatInputLimits::new(0.5, 200.0);
 /// Eingabegrenzen fuer die Basisschrittweite des Route-Offset-Tools.
 pub const ROUTE_OFFSET_BASE_SPACING_LIMITS: FloatInputLimits = FloatInputLimits::new(1.0, 50.0);
+/// Eingabegrenzen fuer den Abstand der Querverbindungen des Route-Offset-Tools.
+pub const ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS: FloatInputLimits =
+    FloatInputLimits::new(10.0, 500.0);
+/// Eingabegrenzen fuer die Arbeitsbreite des Feldkurs-Tools.
+pub const FIELD_COURSE_WORKING_WIDTH_LIMITS: FloatInputLimits = FloatInputLimits::new(2.0, 50.0);
+/// Eingabegrenzen fuer die Anzahl der Vorgewende-Runden des Feldkurs-Tools.
+pub const FIELD_COURSE_HEADLAND_PASSES_LIMITS: UsizeInputLimits = UsizeInputLimits::new(0, 5);
+/// Eingabegrenzen fuer den Node-Abstand des Feldkurs-Tools.
+pub const FIELD_COURSE_NODE_SPACING_LIMITS: FloatInputLimits = FloatInputLimits::new(1.0, 50.0);
 /// Eingabegrenzen fuer den Arc-Radius des Verrundungs-Tools.
 pub const ROUNDING_ARC_RADIUS_LIMITS: FloatInputLimits = FloatInputLimits::new(0.5, 200.0);
 /// Eingabegrenzen fuer den maximalen Segmentwinkel des Verrundungs-Tools.
@@ -80,3 +89,5 @@ pub const PARKING_ENTRY_EXIT_T_LIMITS: FloatInputLimits = FloatInputLimits::new(
 pub const PARKING_RAMP_LENGTH_LIMITS: FloatInputLimits = FloatInputLimits::new(2.0, 20.0);
 /// Eingabegrenzen fuer den Rotationsschritt des Parkplatz-Tools.
 pub const PARKING_ROTATION_STEP_LIMITS: FloatInputLimits = FloatInputLimits::new(0.5, 45.0);
+/// Eingabegrenzen fuer den Node-Abstand der Kreuzungsspuren.
+pub const JUNCTION_NODE_SPACING_LIMITS: FloatInputLimits = FloatInputLimits::new(1.0, 20.0);
@@ -13,10 +13,26 @@ pub struct ViewportOverlaySnapshot {
     pub clipboard_preview: Option<ClipboardOverlaySnapshot>,
     /// Vorschau-Linie fuer Distanzen-Resampling.
     pub distance_preview: Option<PolylineOverlaySnapshot>,
+    /// Vorschau-Linie fuer den seitlichen Strecken-Versatz.
+    pub path_offset_preview: Option<PolylineOverlaySnapshot>,
+    /// Messlinie des Mess-Werkzeugs mit Segmentlaengen und Kursen.
+    pub measure: Option<MeasureOverlaySnapshot>,
     /// Klickbare Segment-Lock-Overlay-Elemente.
     pub group_locks: Vec<GroupLockOverlaySnapshot>,
+    /// Schloss-Badges fuer gesperrte Nodes.
+    pub locked_nodes: Vec<LockedNodeOverlaySnapshot>,
+    /// Hervorhebung der per Kanten-Klick selektierten Verbindung.
+    pub selected_connection: Option<SelectedConnectionOverlaySnapshot>,
     /// Boundary-Icon-Daten fuer Gruppen.
     pub group_boundaries: Vec<GroupBoundaryOverlaySnapshot>,
+    /// Warnungen fuer Verbindungen, die das Terrain schneiden oder ueberspannen.
+    pub terrain_clipping: Vec<TerrainClippingOverlaySnapshot>,
+    /// Im Savegame gekaufte Farmlands (getoente Flaechen).
+    pub owned_farmlands: Vec<OwnedFarmlandOverlaySnapshot>,
+    /// Im Savegame abgestellte Fahrzeuge (Icon mit Name).
+    pub vehicles: Vec<VehicleOverlaySnapshot>,
+    /// Live gemeldete Spielerposition aus dem laufenden Spiel.
+    pub live_player: Option<LivePlayerOverlaySnapshot>,
     /// Hinweistext anzeigen, wenn keine Karte geladen ist.
     pub show_no_file_hint: bool,
 }
@@ -48,6 +64,26 @@ pub struct PolylineOverlaySnapshot {
     pub points: Vec<Vec2>,
 }
 
+/// Read-only Snapshot der Messlinie des Mess-Werkzeugs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeasureOverlaySnapshot {
+    /// Messpunkte in Weltkoordinaten.
+    pub points: Vec<Vec2>,
+    /// Kennzahlen je Segment (`segments[i]` verbindet `points[i]` und `points[i + 1]`).
+    pub segments: Vec<MeasureSegmentSnapshot>,
+}
+
+/// Kennzahlen eines Segments der Messlinie.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasureSegmentSnapshot {
+    /// Laenge des Segments in Metern.
+    pub length_m: f32,
+    /// Aufsummierte Laenge bis zum Segmentende in Metern.
+    pub cumulative_m: f32,
+    /// Kompasskurs des Segments in Grad (0 = Norden, 90 = Osten).
+    pub heading_deg: f32,
+}
+
 /// Klickbares Segment-Lock-Overlay.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GroupLockOverlaySnapshot {
@@ -59,6 +95,28 @@ pub struct GroupLockOverlaySnapshot {
     pub locked: bool,
 }
 
+/// Schloss-Badge ueber einem gesperrten Node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LockedNodeOverlaySnapshot {
+    /// Gesperrter Node.
+    pub node_id: u64,
+    /// Weltposition des Nodes.
+    pub world_pos: Vec2,
+}
+
+/// Per Kanten-Klick selektierte Verbindung.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectedConnectionOverlaySnapshot {
+    /// Start-Node der Verbindung.
+    pub start_id: u64,
+    /// End-Node der Verbindung.
+    pub end_id: u64,
+    /// Weltposition des Start-Nodes.
+    pub start_pos: Vec2,
+    /// Weltposition des End-Nodes.
+    pub end_pos: Vec2,
+}
+
 /// Boundary-Icon-Overlay fuer Ein-/Ausfahrt oder bidirektionale Knoten.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GroupBoundaryOverlaySnapshot {
@@ -71,3 +129,54 @@ pub struct GroupBoundaryOverlaySnapshot {
     /// Richtung des Boundary-Icons.
     pub direction: BoundaryDirection,
 }
+
+/// Warn-Overlay fuer eine Verbindung mit zu grosser Terrain-Abweichung.
+///
+/// Die Node-Hoehen entstehen beim Export aus der Heightmap; dazwischen verlaeuft
+/// die Verbindung geradlinig und kann Kuppen schneiden oder Senken ueberspannen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrainClippingOverlaySnapshot {
+    /// Start-Node der Verbindung.
+    pub start_id: u64,
+    /// End-Node der Verbindung.
+    pub end_id: u64,
+    /// Weltposition der groessten Abweichung (Anker des Warnsymbols).
+    pub world_pos: Vec2,
+    /// Betrag der groessten Abweichung in Metern.
+    pub deviation_m: f32,
+}
+
+/// Gekauftes Farmland aus der `farmland.xml` des Savegames als getoente Flaeche.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedFarmlandOverlaySnapshot {
+    /// Farmland-ID.
+    pub farmland_id: u32,
+    /// Farm-ID des Besitzers.
+    pub farm_id: u32,
+    /// Umriss in Weltkoordinaten.
+    pub outline: Vec<Vec2>,
+    /// Fuell-Dreiecke als Index-Tripel in `outline`.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+/// Fahrzeug-Icon aus der `vehicles.xml` des Savegames.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VehicleOverlaySnapshot {
+    /// Anzeigename des Fahrzeugs.
+    pub name: String,
+    /// Position in Weltkoordinaten.
+    pub world_pos: Vec2,
+    /// Blickrichtung als Einheitsvektor in Weltkoordinaten.
+    pub direction: Vec2,
+}
+
+/// Spieler-Icon der Live-Verbindung zum laufenden Spiel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LivePlayerOverlaySnapshot {
+    /// Position in Weltkoordinaten.
+    pub world_pos: Vec2,
+    /// Blickrichtung als Einheitsvektor in Weltkoordinaten.
+    pub direction: Vec2,
+    /// Gibt an, ob gerade ein Kurs aufgezeichnet wird.
+    pub recording: bool,
+}
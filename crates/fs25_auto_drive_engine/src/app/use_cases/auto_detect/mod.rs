@@ -5,6 +5,8 @@
 //! 2. Ein gespeichertes Overview-Layer-Bundle im XML-Verzeichnis liegt → spaeter bevorzugt laden
 //! 3. Im XML-Verzeichnis oder im Mods-Verzeichnis ein passendes ZIP zum `map_name` existiert
 //!    → Dialog anzeigen
+//! 4. Eine `vehicles.xml` des Savegames im selben Verzeichnis liegt → Fahrzeuge anzeigen
+//! 5. Eine `farmland.xml` des Savegames im selben Verzeichnis liegt → eigene Farmlands toenen
 
 use crate::app::use_cases::background_layers::discover_background_layer_files;
 use crate::app::BackgroundLayerFiles;
@@ -22,6 +24,10 @@ pub struct PostLoadDetectionResult {
     pub overview_path: Option<PathBuf>,
     /// Passende ZIP-Dateien aus XML-Verzeichnis und Mods-Verzeichnis
     pub matching_zips: Vec<PathBuf>,
+    /// Pfad zur `vehicles.xml` des Savegames im XML-Verzeichnis (falls vorhanden)
+    pub vehicles_path: Option<PathBuf>,
+    /// Pfad zur `farmland.xml` (Besitzverhaeltnisse) des Savegames im XML-Verzeichnis
+    pub farmland_path: Option<PathBuf>,
 }
 
 /// Fuehrt die komplette Auto-Detection durch.
@@ -56,6 +62,8 @@ pub fn detect_post_load(xml_path: &Path, map_name: Option<&str>) -> PostLoadDete
         background_layer_files,
         overview_path,
         matching_zips,
+        vehicles_path: find_vehicles_next_to(xml_path),
+        farmland_path: find_farmland_next_to(xml_path),
     }
 }
 
@@ -96,6 +104,18 @@ fn find_heightmap_next_to(xml_path: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Prueft ob die `vehicles.xml` des Savegames im selben Verzeichnis wie die XML liegt.
+fn find_vehicles_next_to(xml_path: &Path) -> Option<PathBuf> {
+    let vehicles = xml_path.parent()?.join("vehicles.xml");
+    vehicles.is_file().then_some(vehicles)
+}
+
+/// Prueft ob die `farmland.xml` des Savegames im selben Verzeichnis wie die XML liegt.
+fn find_farmland_next_to(xml_path: &Path) -> Option<PathBuf> {
+    let farmland = xml_path.parent()?.join("farmland.xml");
+    farmland.is_file().then_some(farmland)
+}
+
 /// Ermittelt das Mods-Verzeichnis relativ zum XML-Pfad.
 ///
 /// Erwartet die Savegame-Struktur:
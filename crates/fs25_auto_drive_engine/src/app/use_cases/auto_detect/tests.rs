@@ -184,12 +184,16 @@ fn test_detect_post_load_integration() {
     fs::write(&xml_path, b"<xml/>").unwrap();
     fs::write(savegame.join("terrain.heightmap.png"), b"PNG").unwrap();
     fs::write(savegame.join("overview_terrain.png"), b"PNG").unwrap();
+    fs::write(savegame.join("vehicles.xml"), b"<vehicles/>").unwrap();
+    fs::write(savegame.join("farmland.xml"), b"<farmlands/>").unwrap();
     fs::write(mods.join("FS25_TestMap.zip"), b"").unwrap();
 
     let result = detect_post_load(&xml_path, Some("TestMap"));
     assert!(result.heightmap_path.is_some());
     assert!(result.background_layer_files.is_some());
     assert_eq!(result.matching_zips.len(), 1);
+    assert_eq!(result.vehicles_path, Some(savegame.join("vehicles.xml")));
+    assert_eq!(result.farmland_path, Some(savegame.join("farmland.xml")));
 
     let _ = fs::remove_dir_all(&tmp);
 }
@@ -230,6 +230,7 @@ fn apply_background_map_with_scale(
     scale: f32,
 ) {
     let image_arc = background_map.image_arc();
+    state.view.reset_background_source();
     state.view.background_map = Some(Arc::new(background_map));
     state.view.background_scale = scale;
     state.view.mark_background_asset_changed();
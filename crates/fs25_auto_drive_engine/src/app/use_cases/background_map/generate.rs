@@ -39,6 +39,8 @@ pub fn generate_overview_with_options(state: &mut AppState) -> Result<()> {
         farmland_ids: layers.farmland_ids,
         pois: layers.pois,
         legend: layers.legend,
+        farmland_ownership: state.farmland_ownership.as_deref().cloned(),
+        ..Default::default()
     };
 
     let bundle = fs25_map_overview::generate_overview_layer_bundle_from_zip(&zip_path, &options)?;
@@ -46,6 +48,9 @@ pub fn generate_overview_with_options(state: &mut AppState) -> Result<()> {
     let (width, height) = bundle.combined.dimensions();
     log::info!("Uebersichtskarte generiert: {}x{} Pixel", width, height);
 
+    // Hoehenskala der Karte fuer den Y-Export merken (auch bei PNG-Heightmaps)
+    state.ui.map_height_scale = Some(bundle.height_scale);
+
     // Savegame-Verzeichnis (Elternordner der aktuell geladenen Config)
     let savegame_dir = state.ui.current_file_path.as_ref().and_then(|xml_path| {
         Path::new(xml_path.as_str())
@@ -202,10 +207,13 @@ pub fn save_background_as_overview(state: &mut AppState, path: String) -> Result
         })?;
     }
 
+    // Bei aktivem Heightmap-Relief die zurueckgelegte Overview speichern
     let bg_map = state
         .view
-        .background_map
+        .stashed_overview
         .as_ref()
+        .map(|(overview, _)| overview)
+        .or(state.view.background_map.as_ref())
         .ok_or_else(|| anyhow::anyhow!("Keine Background-Map geladen"))?;
 
     let rgb_image = bg_map.image_data().to_rgb8();
@@ -5,10 +5,14 @@
 //! - `field_polygons`: Farmland-Polygon-Verwaltung und JSON-I/O
 //! - `load`: Laden aus Datei und ZIP-Archiven
 //! - `generate`: Uebersichtskarten-Generierung und -Speicherung
+//! - `network`: Umwandlung der `RoadMap` in den Overview-Netzwerk-Layer
+//! - `relief`: Heightmap-Relief als alternativer Hintergrund
 
 mod field_polygons;
 mod generate;
 mod load;
+mod network;
+mod relief;
 mod state_helpers;
 
 pub use field_polygons::load_farmland_json;
@@ -17,6 +21,8 @@ pub use load::{
     browse_zip_background, clear_background_map, load_background_from_zip, load_background_map,
     request_background_map_dialog, scale_background, toggle_background_visibility,
 };
+pub use network::overview_network_from_road_map;
+pub use relief::{refresh_heightmap_background, set_background_source};
 
 #[cfg(test)]
 mod tests {
@@ -26,6 +32,7 @@ mod tests {
     use super::load::{
         browse_zip_background, clear_background_map, load_background_from_zip, load_background_map,
     };
+    use super::relief::set_background_source;
     use super::state_helpers::persist_overview_defaults;
     use crate::app::state::{
         BackgroundLayerCatalog, BackgroundLayerFiles, PendingOverviewBundle, StoredBackgroundLayer,
@@ -33,7 +40,9 @@ mod tests {
     };
     use crate::app::AppState;
     use crate::core::{BackgroundMap, FieldPolygon};
-    use crate::shared::{BackgroundLayerKind, OverviewFieldDetectionSource, OverviewLayerOptions};
+    use crate::shared::{
+        BackgroundLayerKind, BackgroundSource, OverviewFieldDetectionSource, OverviewLayerOptions,
+    };
     use glam::Vec2;
     use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
     use std::io::{Cursor, Write};
@@ -87,10 +96,18 @@ mod tests {
 
     fn sample_overview_bundle() -> fs25_map_overview::OverviewLayerBundle {
         let terrain = RgbaImage::from_pixel(2, 2, Rgba([20, 40, 60, 255]));
+        let forest = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+        let water = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
         let hillshade = RgbaImage::from_pixel(2, 2, Rgba([200, 0, 0, 128]));
+        let contours = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+        let placement_collision = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
         let farmland_borders = RgbaImage::from_pixel(2, 2, Rgba([0, 200, 0, 96]));
         let farmland_ids = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+        let farmland_prices = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+        let field_borders = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+        let roads = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
         let poi_markers = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 200, 128]));
+        let network = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
         let legend = RgbaImage::from_pixel(2, 2, Rgba([255, 255, 255, 64]));
         let combined = fs25_map_overview::compose_layers(
             &terrain,
@@ -105,16 +122,25 @@ mod tests {
 
         fs25_map_overview::OverviewLayerBundle {
             terrain,
+            forest,
+            water,
             hillshade,
+            contours,
+            placement_collision,
             farmland_borders,
             farmland_ids,
+            farmland_prices,
+            field_borders,
+            roads,
             poi_markers,
+            network,
             legend,
             combined,
             farmland_polygons: Vec::new(),
             grle_width: 2,
             grle_height: 2,
             map_size: 2.0,
+            height_scale: 255.0,
             farmland_ids_raw: Some(vec![1, 2, 3, 4]),
         }
     }
@@ -571,4 +597,56 @@ mod tests {
         assert!(state.background_layers.is_none());
         assert!(state.pending_overview_bundle.is_none());
     }
+
+    #[test]
+    fn heightmap_background_source_stashes_and_restores_overview() {
+        let temp_dir = TempDirGuard::new("heightmap_relief");
+        // 9 Pixel Heightmap → 8 m Weltbreite
+        let heightmap_path = temp_dir.path().join("terrain.heightmap.png");
+        image::GrayImage::from_fn(9, 9, |x, _| image::Luma([(x * 20) as u8]))
+            .save(&heightmap_path)
+            .expect("Test-Heightmap muss geschrieben werden");
+
+        let mut state = AppState::new();
+        let overview = Arc::new(
+            BackgroundMap::from_image(
+                DynamicImage::ImageRgba8(image::RgbaImage::new(4, 4)),
+                "overview",
+                None,
+            )
+            .expect("Overview-Hintergrund erwartet"),
+        );
+        state.view.background_map = Some(Arc::clone(&overview));
+        state.view.background_scale = 2.0;
+
+        // Ohne Heightmap bleibt die Overview unveraendert aktiv
+        assert!(set_background_source(&mut state, BackgroundSource::HeightmapGradient).is_err());
+        assert_eq!(state.view.background_source, BackgroundSource::Overview);
+
+        crate::app::use_cases::heightmap::set_heightmap(
+            &mut state,
+            heightmap_path.to_string_lossy().into_owned(),
+        );
+        set_background_source(&mut state, BackgroundSource::HeightmapGradient)
+            .expect("Heightmap-Relief muss erzeugt werden");
+        let relief = state.view.background_map.clone().expect("Relief aktiv");
+        assert_eq!(relief.dimensions(), (9, 9));
+        assert!((state.view.background_scale - 8.0 / 9.0).abs() < 1e-6);
+        assert_eq!(
+            state.view.background_source,
+            BackgroundSource::HeightmapGradient
+        );
+
+        // Stilwechsel behaelt die urspruengliche Overview
+        set_background_source(&mut state, BackgroundSource::HeightmapHillshade)
+            .expect("Stilwechsel muss gelingen");
+        set_background_source(&mut state, BackgroundSource::Overview)
+            .expect("Rueckwechsel muss gelingen");
+
+        let restored = state.view.background_map.as_ref().expect("Overview aktiv");
+        assert!(Arc::ptr_eq(restored, &overview));
+        assert_eq!(state.view.background_scale, 2.0);
+        assert_eq!(state.view.background_source, BackgroundSource::Overview);
+        assert!(state.view.stashed_overview.is_none());
+    }
 }
@@ -0,0 +1,91 @@
+//! Umwandlung der `RoadMap` in den Netzwerk-Layer von `fs25_map_overview`.
+
+use crate::core::{ConnectionDirection, ConnectionPriority, NodeFlag, RoadMap};
+use fs25_map_overview::{
+    AutoDriveNetwork, NetworkConnection, NetworkDirection, NetworkMarker, NetworkNode,
+};
+
+/// Baut aus der aktuellen `RoadMap` ein [`AutoDriveNetwork`] fuer die Overview-Ausgabe.
+///
+/// Das Ergebnis kann in `OverviewOptions::network` gesetzt werden, um das
+/// Kursnetz direkt in die generierte Uebersichtskarte einzuzeichnen.
+/// Debug-Marker werden nicht uebernommen.
+pub fn overview_network_from_road_map(road_map: &RoadMap) -> AutoDriveNetwork {
+    let nodes = road_map
+        .nodes()
+        .iter()
+        .map(|node| NetworkNode {
+            id: node.id,
+            x: node.position.x,
+            z: node.position.y,
+            sub_prio: node.flag == NodeFlag::SubPrio,
+        })
+        .collect();
+
+    let connections = road_map
+        .connections_iter()
+        .map(|connection| NetworkConnection {
+            start: connection.start_id,
+            end: connection.end_id,
+            direction: match connection.direction {
+                ConnectionDirection::Regular => NetworkDirection::Regular,
+                ConnectionDirection::Dual => NetworkDirection::Dual,
+                ConnectionDirection::Reverse => NetworkDirection::Reverse,
+            },
+            sub_prio: connection.priority == ConnectionPriority::SubPriority,
+        })
+        .collect();
+
+    let markers = road_map
+        .map_markers()
+        .iter()
+        .filter(|marker| !marker.is_debug)
+        .map(|marker| NetworkMarker {
+            node_id: marker.id,
+            name: marker.name.clone(),
+        })
+        .collect();
+
+    AutoDriveNetwork {
+        nodes,
+        connections,
+        markers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, MapMarker, MapNode};
+    use glam::Vec2;
+
+    #[test]
+    fn overview_network_mirrors_nodes_connections_and_markers() {
+        let mut road_map = RoadMap::new(3);
+        road_map.add_node(MapNode::new(1, Vec2::new(-5.0, 2.0), NodeFlag::Regular));
+        road_map.add_node(MapNode::new(2, Vec2::new(5.0, 2.0), NodeFlag::SubPrio));
+        road_map.add_connection(Connection::new(
+            1,
+            2,
+            ConnectionDirection::Dual,
+            ConnectionPriority::SubPriority,
+            Vec2::new(-5.0, 2.0),
+            Vec2::new(5.0, 2.0),
+        ));
+        road_map.add_map_marker(MapMarker::new(2, "Hof".into(), "All".into(), 1, false));
+        road_map.add_map_marker(MapMarker::new(1, "Debug".into(), "All".into(), 2, true));
+
+        let network = overview_network_from_road_map(&road_map);
+
+        assert_eq!(network.nodes.len(), 2);
+        assert!(network
+            .nodes
+            .iter()
+            .any(|node| node.id == 2 && node.sub_prio));
+        assert_eq!(network.connections.len(), 1);
+        assert_eq!(network.connections[0].direction, NetworkDirection::Dual);
+        assert!(network.connections[0].sub_prio);
+        assert_eq!(network.markers.len(), 1);
+        assert_eq!(network.markers[0].name, "Hof");
+    }
+}
@@ -0,0 +1,102 @@
+//! Heightmap-Relief als alternativer Viewport-Hintergrund.
+//!
+//! Beim Wechsel auf eine Heightmap-Quelle wird die aktuelle Overview samt
+//! Skalierung in `ViewState::stashed_overview` zurueckgelegt und beim Wechsel
+//! zurueck auf `Overview` wiederhergestellt.
+
+use super::state_helpers::{apply_background_map_with_scale, clear_background_assets};
+use crate::app::AppState;
+use crate::core::BackgroundMap;
+use crate::shared::BackgroundSource;
+use anyhow::{Context, Result};
+use image::DynamicImage;
+
+/// Schaltet die Quelle des Viewport-Hintergrunds um.
+///
+/// Heightmap-Quellen rendern die ausgewaehlte Heightmap ueber
+/// `fs25_map_overview::render_relief`; ohne Heightmap schlaegt der Wechsel fehl
+/// und der bisherige Hintergrund bleibt erhalten.
+pub fn set_background_source(state: &mut AppState, source: BackgroundSource) -> Result<()> {
+    let style = match source {
+        BackgroundSource::Overview => {
+            restore_overview(state);
+            return Ok(());
+        }
+        BackgroundSource::HeightmapHillshade => fs25_map_overview::ReliefStyle::Hillshade,
+        BackgroundSource::HeightmapGradient => fs25_map_overview::ReliefStyle::Gradient,
+    };
+
+    let path = state
+        .ui
+        .heightmap_path
+        .clone()
+        .context("Keine Heightmap ausgewaehlt")?;
+    let heightmap = state
+        .heightmap
+        .clone()
+        .context("Heightmap konnte nicht geladen werden")?;
+    let relief = fs25_map_overview::render_relief(
+        &heightmap.to_image(),
+        style,
+        &fs25_map_overview::HillshadeParams::default(),
+    )?;
+
+    // BackgroundMap rechnet 1 Pixel = 1 m; DEMs mit unitsPerPixel = 2 hochskalieren
+    let bounds = heightmap.world_bounds();
+    let scale = (bounds.max_x - bounds.min_x) / relief.width().min(relief.height()) as f32;
+    let relief_map = BackgroundMap::from_image(DynamicImage::ImageRgb8(relief), &path, None)?;
+
+    let stashed = if state.view.background_source.is_heightmap() {
+        state.view.stashed_overview.take()
+    } else {
+        state
+            .view
+            .background_map
+            .clone()
+            .map(|overview| (overview, state.view.background_scale))
+    };
+    apply_background_map_with_scale(state, relief_map, scale);
+    state.view.background_source = source;
+    state.view.stashed_overview = stashed;
+    log::info!("Hintergrund: Heightmap-Relief ({:?}) aus {}", style, path);
+    Ok(())
+}
+
+/// Stellt die zurueckgelegte Overview wieder her (ohne Overview: kein Hintergrund).
+fn restore_overview(state: &mut AppState) {
+    if !state.view.background_source.is_heightmap() {
+        return;
+    }
+
+    match state.view.stashed_overview.take() {
+        Some((overview, scale)) => {
+            let image_arc = overview.image_arc();
+            state.view.reset_background_source();
+            state.view.background_map = Some(overview);
+            state.view.background_scale = scale;
+            state.view.mark_background_asset_changed();
+            state.background_image = Some(image_arc);
+        }
+        None => clear_background_assets(state),
+    }
+    log::info!("Hintergrund: Overview");
+}
+
+/// Rendert ein aktives Heightmap-Relief nach einem Heightmap-Wechsel neu bzw.
+/// kehrt zur Overview zurueck, wenn keine Heightmap mehr ausgewaehlt ist.
+pub fn refresh_heightmap_background(state: &mut AppState) {
+    let source = state.view.background_source;
+    if !source.is_heightmap() {
+        return;
+    }
+
+    if state.ui.heightmap_path.is_none() {
+        restore_overview(state);
+    } else if let Err(error) = set_background_source(state, source) {
+        log::warn!(
+            "Heightmap-Relief konnte nicht aktualisiert werden: {}",
+            error
+        );
+        restore_overview(state);
+    }
+}
@@ -14,6 +14,7 @@ pub(super) fn apply_background_map_with_scale(
     scale: f32,
 ) {
     let image_arc = bg_map.image_arc();
+    state.view.reset_background_source();
     state.view.background_map = Some(Arc::new(bg_map));
     state.view.background_scale = scale;
     state.view.mark_background_asset_changed();
@@ -22,6 +23,7 @@ pub(super) fn apply_background_map_with_scale(
 
 pub(super) fn clear_background_assets(state: &mut AppState) {
     let had_background = state.view.background_map.is_some() || state.background_image.is_some();
+    state.view.reset_background_source();
     state.view.background_map = None;
     if had_background {
         state.view.mark_background_asset_changed();
@@ -110,11 +110,11 @@ pub fn center_on_road_map(state: &mut AppState, road_map: &RoadMap) {
     let mut min_y = f32::MAX;
     let mut max_y = f32::MIN;
 
-    for node in road_map.nodes().values() {
-        min_x = min_x.min(node.position.x);
-        max_x = max_x.max(node.position.x);
-        min_y = min_y.min(node.position.y);
-        max_y = max_y.max(node.position.y);
+    for (_, position) in road_map.nodes().positions() {
+        min_x = min_x.min(position.x);
+        max_x = max_x.max(position.x);
+        min_y = min_y.min(position.y);
+        max_y = max_y.max(position.y);
     }
 
     let center_x = (min_x + max_x) / 2.0;
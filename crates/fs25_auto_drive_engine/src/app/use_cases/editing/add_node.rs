@@ -2,6 +2,7 @@
 
 use crate::app::AppState;
 use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+use crate::shared::geometry::apply_snap_constraints;
 use glam::Vec2;
 use std::sync::Arc;
 
@@ -16,43 +17,6 @@ pub enum AddNodeResult {
     Created(u64),
 }
 
-/// Berechnet den minimalen Abstand von Punkt `pt` zum Liniensegment `a→b`.
-fn point_to_segment_dist(pt: Vec2, a: Vec2, b: Vec2) -> f32 {
-    let ab = b - a;
-    let len_sq = ab.dot(ab);
-    if len_sq < f32::EPSILON {
-        return pt.distance(a);
-    }
-    let t = ((pt - a).dot(ab) / len_sq).clamp(0.0, 1.0);
-    pt.distance(a + t * ab)
-}
-
-/// Sucht die Verbindung, die der Klickposition am naechsten liegt (innerhalb `threshold`).
-/// Gibt `(start_id, end_id, direction, priority)` zurueck.
-fn find_nearest_connection(
-    road_map: &crate::core::RoadMap,
-    pt: Vec2,
-    threshold: f32,
-) -> Option<(u64, u64, ConnectionDirection, ConnectionPriority)> {
-    let mut best_dist = threshold;
-    let mut best = None;
-
-    for conn in road_map.connections_iter() {
-        let Some(s_node) = road_map.node(conn.start_id) else {
-            continue;
-        };
-        let Some(e_node) = road_map.node(conn.end_id) else {
-            continue;
-        };
-        let dist = point_to_segment_dist(pt, s_node.position, e_node.position);
-        if dist < best_dist {
-            best_dist = dist;
-            best = Some((conn.start_id, conn.end_id, conn.direction, conn.priority));
-        }
-    }
-    best
-}
-
 /// Fuegt einen neuen Node an der gegebenen Weltposition hinzu.
 ///
 /// Wenn genau ein Node selektiert ist, wird der neue Node automatisch
@@ -63,6 +27,10 @@ fn find_nearest_connection(
 /// nahe einer bestehenden Verbindung liegt, wird diese Verbindung gesplittet
 /// und der neue Node dazwischen eingefuegt (anstelle des normalen Auto-Connects).
 ///
+/// Ist `options.grid_snap_enabled` aktiv, rastet die Position auf das Raster ein;
+/// beim Auto-Connect wird stattdessen die Richtung vom selektierten Node aus
+/// auf das Winkelraster beschraenkt.
+///
 /// Trifft der Klick einen existierenden Node (innerhalb snap_radius),
 /// wird dieser stattdessen nur selektiert (keine Neuerstellung).
 pub fn add_node_at_position(state: &mut AppState, world_pos: Vec2) -> AddNodeResult {
@@ -94,18 +62,23 @@ pub fn add_node_at_position(state: &mut AppState, world_pos: Vec2) -> AddNodeRes
         None
     };
 
+    // Grid-/Winkel-Snap: Ursprung ist der Auto-Connect-Node (falls vorhanden)
+    let origin = connect_from.and_then(|id| road_map_ref.node_position(id));
+    let snapped_to_node = road_map_ref
+        .nearest_node(world_pos)
+        .is_some_and(|hit| hit.distance <= state.options.snap_radius());
+    let world_pos = apply_snap_constraints(world_pos, origin, snapped_to_node, &state.options);
+
     // Verbindung zum Splitten suchen (nur wenn Option aktiv)
     let snap_threshold = state.options.snap_radius() * 1.5;
     let split_target: Option<(u64, u64, ConnectionDirection, ConnectionPriority)> =
         if state.options.split_connection_on_place {
-            find_nearest_connection(
-                state
-                    .road_map
-                    .as_deref()
-                    .expect("road_map ist Some nach as_ref()-Guard in add_node_at_position"),
-                world_pos,
-                snap_threshold,
-            )
+            state
+                .road_map
+                .as_deref()
+                .expect("road_map ist Some nach as_ref()-Guard in add_node_at_position")
+                .nearest_connection(world_pos, snap_threshold)
+                .map(|conn| (conn.start_id, conn.end_id, conn.direction, conn.priority))
         } else {
             None
         };
@@ -0,0 +1,390 @@
+//! Use-Case: Selektierte Nodes ausrichten und gleichmaessig verteilen.
+//!
+//! Ausrichten projiziert die Nodes auf eine Gerade (X- bzw. Z-Linie,
+//! Ausgleichsgerade oder Verbindung der Endpunkte). Verteilen setzt die Nodes
+//! entlang der Endpunkt-Achse auf gleiche Abstaende und erhaelt dabei den
+//! seitlichen Versatz — anders als `equalize_spacing`, das der Kettenform folgt.
+//! Node-IDs, Marker und Verbindungen bleiben in beiden Faellen erhalten.
+
+use crate::app::AppState;
+use crate::core::RoadMap;
+use glam::Vec2;
+use indexmap::IndexSet;
+use std::sync::Arc;
+
+/// Ziel-Gerade fuer `align_selected_nodes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeAlignMode {
+    /// Gemeinsame Z-Koordinate (Mittelwert) — Linie parallel zur X-Achse.
+    AxisX,
+    /// Gemeinsame X-Koordinate (Mittelwert) — Linie parallel zur Z-Achse.
+    AxisZ,
+    /// Lotrechte Projektion auf die Ausgleichsgerade durch alle Nodes.
+    BestFitLine,
+    /// Lotrechte Projektion auf die Gerade zwischen den beiden Endpunkten.
+    EndpointLine,
+}
+
+impl NodeAlignMode {
+    /// Mindestanzahl selektierter Nodes fuer diesen Modus.
+    pub fn min_node_count(self) -> usize {
+        match self {
+            Self::AxisX | Self::AxisZ | Self::BestFitLine => 2,
+            Self::EndpointLine => 3,
+        }
+    }
+}
+
+/// Mindestanzahl selektierter Nodes fuer `distribute_selected_nodes`.
+pub const DISTRIBUTE_MIN_NODE_COUNT: usize = 3;
+
+/// Ordnet die selektierten Nodes entlang ihres Verlaufs.
+///
+/// Bildet die Selektion eine lineare Kette, gilt deren Reihenfolge; sonst wird
+/// nach der Projektion auf die Achse zwischen den beiden am weitesten
+/// voneinander entfernten Nodes sortiert (Naeherung in zwei Durchlaeufen).
+fn ordered_selection(road_map: &RoadMap, selected: &IndexSet<u64>) -> Option<Vec<(u64, Vec2)>> {
+    let with_positions = |ids: Vec<u64>| {
+        ids.into_iter()
+            .map(|id| road_map.node_position(id).map(|pos| (id, pos)))
+            .collect::<Option<Vec<_>>>()
+    };
+    if let Some(chain) = road_map.ordered_chain_nodes(selected) {
+        return with_positions(chain);
+    }
+
+    let mut nodes = with_positions(selected.iter().copied().collect())?;
+    let first = nodes.first()?.1;
+    let farthest_from = |origin: Vec2| {
+        nodes.iter().map(|&(_, pos)| pos).max_by(|a, b| {
+            a.distance_squared(origin)
+                .total_cmp(&b.distance_squared(origin))
+        })
+    };
+    let start = farthest_from(first)?;
+    let end = farthest_from(start)?;
+    let axis = end - start;
+    nodes.sort_by(|a, b| (a.1 - start).dot(axis).total_cmp(&(b.1 - start).dot(axis)));
+    Some(nodes)
+}
+
+/// Projiziert `point` lotrecht auf die Gerade durch `origin` mit Richtung `direction`.
+fn project_onto_line(point: Vec2, origin: Vec2, direction: Vec2) -> Vec2 {
+    let direction = direction.normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return origin;
+    }
+    origin + direction * (point - origin).dot(direction)
+}
+
+/// Hauptrichtung der Punktwolke (Eigenvektor der Kovarianz zum groessten Eigenwert).
+fn principal_direction(positions: &[Vec2], centroid: Vec2) -> Vec2 {
+    let (mut sxx, mut sxy, mut syy) = (0.0_f32, 0.0_f32, 0.0_f32);
+    for &pos in positions {
+        let d = pos - centroid;
+        sxx += d.x * d.x;
+        sxy += d.x * d.y;
+        syy += d.y * d.y;
+    }
+    let angle = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+    Vec2::from_angle(angle)
+}
+
+/// Berechnet die ausgerichteten Positionen der geordneten Punkte.
+fn aligned_positions(positions: &[Vec2], mode: NodeAlignMode) -> Vec<Vec2> {
+    let Some((&first, &last)) = positions.first().zip(positions.last()) else {
+        return Vec::new();
+    };
+    let centroid = positions.iter().copied().sum::<Vec2>() / positions.len() as f32;
+    match mode {
+        NodeAlignMode::AxisX => positions
+            .iter()
+            .map(|pos| Vec2::new(pos.x, centroid.y))
+            .collect(),
+        NodeAlignMode::AxisZ => positions
+            .iter()
+            .map(|pos| Vec2::new(centroid.x, pos.y))
+            .collect(),
+        NodeAlignMode::BestFitLine => {
+            let direction = principal_direction(positions, centroid);
+            positions
+                .iter()
+                .map(|&pos| project_onto_line(pos, centroid, direction))
+                .collect()
+        }
+        NodeAlignMode::EndpointLine => positions
+            .iter()
+            .map(|&pos| project_onto_line(pos, first, last - first))
+            .collect(),
+    }
+}
+
+/// Verteilt die geordneten Punkte auf gleiche Abstaende entlang der Endpunkt-Achse.
+///
+/// Der seitliche Versatz jedes Punkts zur Achse bleibt erhalten; Endpunkte
+/// bleiben exakt stehen.
+fn distributed_positions(positions: &[Vec2]) -> Vec<Vec2> {
+    let count = positions.len();
+    if count < DISTRIBUTE_MIN_NODE_COUNT {
+        return positions.to_vec();
+    }
+    let (first, last) = (positions[0], positions[count - 1]);
+    let axis = last - first;
+    let direction = axis.normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return positions.to_vec();
+    }
+
+    positions
+        .iter()
+        .enumerate()
+        .map(|(index, &pos)| {
+            if index == 0 || index == count - 1 {
+                return pos;
+            }
+            let along = first + axis * (index as f32 / (count - 1) as f32);
+            let lateral = (pos - first) - direction * (pos - first).dot(direction);
+            along + lateral
+        })
+        .collect()
+}
+
+/// Ermittelt die geordnete Selektion oder setzt eine Statusmeldung.
+fn prepare_selection(
+    state: &mut AppState,
+    min_count: usize,
+    action: &str,
+) -> Option<Vec<(u64, Vec2)>> {
+    let road_map = state.road_map.as_deref()?;
+    let selected = &state.selection.selected_node_ids;
+    if selected.len() < min_count {
+        state.ui.status_message = Some(format!(
+            "{action} benoetigt mindestens {min_count} selektierte Nodes"
+        ));
+        return None;
+    }
+    if selected.iter().any(|id| state.locked_node_ids.contains(id)) {
+        state.ui.status_message = Some(format!(
+            "Selektion enthaelt gesperrte Nodes — {action} abgebrochen"
+        ));
+        return None;
+    }
+    ordered_selection(road_map, selected)
+}
+
+/// Schreibt neue Positionen mit Undo-Snapshot und meldet das Ergebnis.
+fn apply_positions(state: &mut AppState, updates: Vec<(u64, Vec2)>, message: String) {
+    state.record_undo_snapshot();
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    Arc::make_mut(road_map_arc).set_node_positions(&updates);
+
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+/// Richtet alle selektierten Nodes auf der Ziel-Geraden `mode` aus (mit Undo-Snapshot).
+///
+/// Enthaelt die Selektion gesperrte Nodes oder zu wenige Nodes fuer den Modus,
+/// wird nichts veraendert und eine Statusmeldung gesetzt.
+pub fn align_selected_nodes(state: &mut AppState, mode: NodeAlignMode) {
+    let Some(nodes) = prepare_selection(state, mode.min_node_count(), "Ausrichten") else {
+        return;
+    };
+    let positions: Vec<Vec2> = nodes.iter().map(|&(_, pos)| pos).collect();
+    let updates: Vec<(u64, Vec2)> = nodes
+        .iter()
+        .map(|&(id, _)| id)
+        .zip(aligned_positions(&positions, mode))
+        .collect();
+
+    let message = format!("{} Node(s) ausgerichtet", updates.len());
+    apply_positions(state, updates, message);
+}
+
+/// Verteilt die selektierten Nodes auf gleiche Abstaende entlang der Endpunkt-Achse
+/// (mit Undo-Snapshot).
+///
+/// Reihenfolge: Kettenreihenfolge, sonst Projektion auf die laengste Achse der
+/// Selektion. Endpunkte und seitlicher Versatz bleiben erhalten.
+pub fn distribute_selected_nodes(state: &mut AppState) {
+    let Some(nodes) = prepare_selection(state, DISTRIBUTE_MIN_NODE_COUNT, "Verteilen") else {
+        return;
+    };
+    let positions: Vec<Vec2> = nodes.iter().map(|&(_, pos)| pos).collect();
+    let spacing =
+        positions[0].distance(positions[positions.len() - 1]) / (positions.len() - 1) as f32;
+    let updates: Vec<(u64, Vec2)> = nodes
+        .iter()
+        .map(|&(id, _)| id)
+        .zip(distributed_positions(&positions))
+        .collect();
+
+    let message = format!(
+        "{} Node(s) gleichmaessig verteilt (Ø {:.2} m)",
+        updates.len(),
+        spacing
+    );
+    apply_positions(state, updates, message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+
+    fn state_with_nodes(nodes: &[(u64, Vec2)], chain: bool) -> AppState {
+        let mut map = RoadMap::new(3);
+        for &(id, pos) in nodes {
+            map.add_node(MapNode::new(id, pos, NodeFlag::Regular));
+        }
+        if chain {
+            for pair in nodes.windows(2) {
+                map.add_connection(Connection::new(
+                    pair[0].0,
+                    pair[1].0,
+                    ConnectionDirection::Regular,
+                    ConnectionPriority::Regular,
+                    pair[0].1,
+                    pair[1].1,
+                ));
+            }
+        }
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state
+            .selection
+            .ids_mut()
+            .extend(nodes.iter().map(|&(id, _)| id));
+        state
+    }
+
+    fn position(state: &AppState, id: u64) -> Vec2 {
+        state
+            .road_map
+            .as_deref()
+            .and_then(|map| map.node_position(id))
+            .expect("Node erwartet")
+    }
+
+    fn assert_close(actual: Vec2, expected: Vec2) {
+        assert!(
+            actual.distance(expected) < 1e-4,
+            "erwartet {expected:?}, erhalten {actual:?}"
+        );
+    }
+
+    #[test]
+    fn align_axis_modes_use_mean_coordinate() {
+        let nodes = [(1, Vec2::new(0.0, 0.0)), (2, Vec2::new(5.0, 4.0))];
+
+        let mut state = state_with_nodes(&nodes, false);
+        align_selected_nodes(&mut state, NodeAlignMode::AxisX);
+        assert_close(position(&state, 1), Vec2::new(0.0, 2.0));
+        assert_close(position(&state, 2), Vec2::new(5.0, 2.0));
+
+        let mut state = state_with_nodes(&nodes, false);
+        align_selected_nodes(&mut state, NodeAlignMode::AxisZ);
+        assert_close(position(&state, 1), Vec2::new(2.5, 0.0));
+        assert_close(position(&state, 2), Vec2::new(2.5, 4.0));
+    }
+
+    #[test]
+    fn align_best_fit_line_projects_onto_principal_axis() {
+        let positions = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(5.0, 6.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(15.0, 14.0),
+        ];
+
+        let aligned = aligned_positions(&positions, NodeAlignMode::BestFitLine);
+
+        let direction = (aligned[3] - aligned[0]).normalize();
+        for pos in &aligned {
+            let offset = *pos - aligned[0];
+            assert!(
+                offset.perp_dot(direction).abs() < 1e-3,
+                "{pos:?} nicht kollinear"
+            );
+        }
+        // Nahezu diagonaler Verlauf → Richtung nahe 45°
+        assert!((direction.x - direction.y).abs() < 0.1);
+    }
+
+    #[test]
+    fn align_endpoint_line_keeps_endpoints_of_chain() {
+        let nodes = [
+            (1, Vec2::new(0.0, 0.0)),
+            (2, Vec2::new(3.0, 2.0)),
+            (3, Vec2::new(6.0, -1.0)),
+            (4, Vec2::new(10.0, 0.0)),
+        ];
+        let mut state = state_with_nodes(&nodes, true);
+
+        align_selected_nodes(&mut state, NodeAlignMode::EndpointLine);
+
+        assert_close(position(&state, 1), Vec2::new(0.0, 0.0));
+        assert_close(position(&state, 2), Vec2::new(3.0, 0.0));
+        assert_close(position(&state, 3), Vec2::new(6.0, 0.0));
+        assert_close(position(&state, 4), Vec2::new(10.0, 0.0));
+    }
+
+    #[test]
+    fn distribute_spaces_nodes_evenly_and_keeps_lateral_offset() {
+        let nodes = [
+            (1, Vec2::new(0.0, 0.0)),
+            (2, Vec2::new(1.0, 2.0)),
+            (3, Vec2::new(9.0, -1.0)),
+            (4, Vec2::new(12.0, 0.0)),
+        ];
+        let mut state = state_with_nodes(&nodes, true);
+
+        distribute_selected_nodes(&mut state);
+
+        assert_close(position(&state, 1), Vec2::new(0.0, 0.0));
+        assert_close(position(&state, 2), Vec2::new(4.0, 2.0));
+        assert_close(position(&state, 3), Vec2::new(8.0, -1.0));
+        assert_close(position(&state, 4), Vec2::new(12.0, 0.0));
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn distribute_orders_unconnected_selection_by_projection() {
+        // Reihenfolge der Selektion weicht von der raeumlichen Reihenfolge ab
+        let nodes = [
+            (1, Vec2::new(10.0, 0.0)),
+            (2, Vec2::new(0.0, 0.0)),
+            (3, Vec2::new(1.0, 0.0)),
+        ];
+        let mut state = state_with_nodes(&nodes, false);
+
+        distribute_selected_nodes(&mut state);
+
+        assert_close(position(&state, 1), Vec2::new(10.0, 0.0));
+        assert_close(position(&state, 2), Vec2::new(0.0, 0.0));
+        assert_close(position(&state, 3), Vec2::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn locked_or_too_small_selection_is_left_untouched() {
+        let nodes = [
+            (1, Vec2::new(0.0, 0.0)),
+            (2, Vec2::new(1.0, 2.0)),
+            (3, Vec2::new(10.0, 0.0)),
+        ];
+        let mut state = state_with_nodes(&nodes, true);
+        state.locked_node_ids.insert(2);
+
+        distribute_selected_nodes(&mut state);
+        assert_eq!(position(&state, 2), Vec2::new(1.0, 2.0));
+        assert!(state.ui.status_message.is_some());
+
+        state.locked_node_ids.clear();
+        state.selection.ids_mut().retain(|&id| id != 3);
+        align_selected_nodes(&mut state, NodeAlignMode::EndpointLine);
+        assert_eq!(position(&state, 2), Vec2::new(1.0, 2.0));
+        assert!(!state.can_undo());
+    }
+}
@@ -1,7 +1,7 @@
 //! Use-Case: Bulk-Aenderungen an Verbindungen zwischen selektierten Nodes.
 
 use crate::app::AppState;
-use crate::core::{ConnectionDirection, ConnectionPriority, RoadMap};
+use crate::core::{Connection, ConnectionDirection, ConnectionPriority, RoadMap};
 use indexmap::IndexSet;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -173,6 +173,57 @@ pub fn invert_all_connections_between_selected(state: &mut AppState) {
     }
 }
 
+/// Kehrt die Fahrtrichtung des selektierten Pfads um.
+///
+/// Anders als das reine Invertieren werden alle Einbahn-Verbindungen zuerst
+/// entfernt und dann gespiegelt neu angelegt: Regular bleibt Regular,
+/// Reverse (Rueckwaerts-Verbindungen) bleibt Reverse, und gegenlaeufige
+/// Einbahn-Paare tauschen ihre Attribute, statt sich gegenseitig zu
+/// ueberschreiben. Dual-Verbindungen sind richtungslos und bleiben unveraendert.
+/// So laesst sich z.B. eine Einbahn-Schleife in einem Schritt umdrehen.
+pub fn reverse_path_between_selected(state: &mut AppState) {
+    let count = mutate_connections_between_selected(state, "reverse_path", |road_map, selected| {
+        let one_way: Vec<Connection> = road_map
+            .connections_between_ids(selected)
+            .filter(|conn| conn.direction != ConnectionDirection::Dual)
+            .cloned()
+            .collect();
+        reverse_connections(road_map, &one_way);
+        one_way.len() as u32
+    });
+
+    if count > 0 {
+        log::info!("Pfad umgekehrt: {} Verbindung(en) gespiegelt", count);
+        state.ui.status_message = Some(format!("Pfad umgekehrt ({} Verbindungen)", count));
+    }
+}
+
+/// Tauscht Start und Ende der uebergebenen Verbindungen (Richtung und Prioritaet bleiben).
+///
+/// Erst werden alle Verbindungen entfernt, dann gedreht neu angelegt, damit
+/// gegenlaeufige Paare sich nicht gegenseitig ueberschreiben.
+pub(super) fn reverse_connections(road_map: &mut RoadMap, connections: &[Connection]) {
+    for conn in connections {
+        road_map.remove_connection(conn.start_id, conn.end_id);
+    }
+    for conn in connections {
+        let (Some(start_pos), Some(end_pos)) = (
+            road_map.node_position(conn.end_id),
+            road_map.node_position(conn.start_id),
+        ) else {
+            continue;
+        };
+        road_map.add_connection(Connection::new(
+            conn.end_id,
+            conn.start_id,
+            conn.direction,
+            conn.priority,
+            start_pos,
+            end_pos,
+        ));
+    }
+}
+
 /// Aendert die Prioritaet aller Verbindungen zwischen den selektierten Nodes.
 pub fn set_all_connections_priority_between_selected(
     state: &mut AppState,
@@ -198,12 +249,14 @@ pub fn set_all_connections_priority_between_selected(
 
 #[cfg(test)]
 mod tests {
-    use super::collect_selected_connection_keys;
+    use super::{collect_selected_connection_keys, reverse_path_between_selected};
+    use crate::app::AppState;
     use crate::core::{
         Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
     };
     use glam::Vec2;
     use indexmap::IndexSet;
+    use std::sync::Arc;
 
     #[test]
     fn collects_only_connections_with_both_ends_selected() {
@@ -234,4 +287,85 @@ mod tests {
 
         assert_eq!(keys, vec![(1, 2)]);
     }
+
+    #[test]
+    fn reverse_path_flips_one_way_loop_and_keeps_reverse_and_dual() {
+        let mut road_map = RoadMap::new(3);
+        let positions = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(0.0, 10.0),
+        ];
+        for (index, pos) in positions.iter().enumerate() {
+            road_map.add_node(MapNode::new(index as u64 + 1, *pos, NodeFlag::Regular));
+        }
+        for (start, end, direction, priority) in [
+            (
+                1,
+                2,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+            ),
+            (
+                2,
+                3,
+                ConnectionDirection::Reverse,
+                ConnectionPriority::Regular,
+            ),
+            (
+                3,
+                4,
+                ConnectionDirection::Regular,
+                ConnectionPriority::SubPriority,
+            ),
+            (
+                4,
+                3,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+            ),
+            (4, 1, ConnectionDirection::Dual, ConnectionPriority::Regular),
+        ] {
+            road_map.add_connection(Connection::new(
+                start,
+                end,
+                direction,
+                priority,
+                positions[start as usize - 1],
+                positions[end as usize - 1],
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(road_map));
+        state.selection.ids_mut().extend([1, 2, 3, 4]);
+
+        reverse_path_between_selected(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        assert!(!road_map.has_connection(1, 2));
+        assert_eq!(
+            road_map.find_connection(2, 1).map(|c| c.direction),
+            Some(ConnectionDirection::Regular)
+        );
+        assert_eq!(
+            road_map.find_connection(3, 2).map(|c| c.direction),
+            Some(ConnectionDirection::Reverse)
+        );
+        assert_eq!(
+            road_map.find_connection(4, 3).map(|c| c.priority),
+            Some(ConnectionPriority::SubPriority)
+        );
+        assert_eq!(
+            road_map.find_connection(3, 4).map(|c| c.priority),
+            Some(ConnectionPriority::Regular)
+        );
+        assert_eq!(
+            road_map.find_connection(4, 1).map(|c| c.direction),
+            Some(ConnectionDirection::Dual)
+        );
+        assert_eq!(road_map.connection_count(), 5);
+        assert!(state.can_undo());
+    }
 }
@@ -0,0 +1,279 @@
+//! Use-Cases fuer den Austausch der Zwischenablage ueber die System-Zwischenablage (JSON).
+//!
+//! Beim Kopieren wird die interne Zwischenablage zusaetzlich als JSON-Text
+//! serialisiert, damit sich Teilnetze (z.B. Kreuzungs-Vorlagen) zwischen
+//! Dateien und Editor-Instanzen wiederverwenden lassen.
+
+use crate::app::state::Clipboard;
+use crate::app::AppState;
+use crate::core::{
+    Connection, ConnectionDirection, ConnectionPriority, MapMarker, MapNode, NodeFlag,
+};
+use anyhow::{bail, Context};
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::copy_paste::start_paste_preview;
+
+/// Formatkennung im JSON-Text der System-Zwischenablage.
+pub const CLIPBOARD_JSON_FORMAT: &str = "fs25_ad_editor/clipboard";
+/// Aktuelle Version des Zwischenablage-JSON-Formats.
+pub const CLIPBOARD_JSON_VERSION: u32 = 1;
+
+/// Serialisierte Zwischenablage (stabiles Austauschformat, unabhaengig von `MapNode`/`Connection`).
+#[derive(Debug, Serialize, Deserialize)]
+struct ClipboardDocument {
+    format: String,
+    version: u32,
+    nodes: Vec<ClipboardNodeDto>,
+    #[serde(default)]
+    connections: Vec<ClipboardConnectionDto>,
+    #[serde(default)]
+    markers: Vec<MapMarker>,
+}
+
+/// Ein kopierter Node im Austauschformat.
+#[derive(Debug, Serialize, Deserialize)]
+struct ClipboardNodeDto {
+    id: u64,
+    x: f32,
+    z: f32,
+    flag: u32,
+}
+
+/// Eine kopierte Verbindung im Austauschformat (ohne abgeleitete Geometrie).
+#[derive(Debug, Serialize, Deserialize)]
+struct ClipboardConnectionDto {
+    start_id: u64,
+    end_id: u64,
+    direction: ConnectionDirection,
+    priority: ConnectionPriority,
+}
+
+/// Serialisiert die Zwischenablage als JSON-Text fuer die System-Zwischenablage.
+///
+/// Gibt `None` zurueck, wenn die Zwischenablage leer ist.
+pub fn clipboard_to_json(clipboard: &Clipboard) -> Option<String> {
+    if clipboard.nodes.is_empty() {
+        return None;
+    }
+
+    let document = ClipboardDocument {
+        format: CLIPBOARD_JSON_FORMAT.to_string(),
+        version: CLIPBOARD_JSON_VERSION,
+        nodes: clipboard
+            .nodes
+            .iter()
+            .map(|node| ClipboardNodeDto {
+                id: node.id,
+                x: node.position.x,
+                z: node.position.y,
+                flag: node.flag.to_u32(),
+            })
+            .collect(),
+        connections: clipboard
+            .connections
+            .iter()
+            .map(|conn| ClipboardConnectionDto {
+                start_id: conn.start_id,
+                end_id: conn.end_id,
+                direction: conn.direction,
+                priority: conn.priority,
+            })
+            .collect(),
+        markers: clipboard.markers.clone(),
+    };
+
+    match serde_json::to_string(&document) {
+        Ok(json) => Some(json),
+        Err(e) => {
+            log::warn!("Zwischenablage konnte nicht serialisiert werden: {e}");
+            None
+        }
+    }
+}
+
+/// Liest eine Zwischenablage aus JSON-Text (Gegenstueck zu [`clipboard_to_json`]).
+///
+/// Verbindungen und Marker, deren Node-IDs nicht in der Kopie enthalten sind,
+/// werden verworfen; das Zentrum wird aus den Node-Positionen neu berechnet.
+pub fn clipboard_from_json(text: &str) -> anyhow::Result<Clipboard> {
+    let document: ClipboardDocument =
+        serde_json::from_str(text.trim()).context("Kein gueltiges Zwischenablage-JSON")?;
+    if document.format != CLIPBOARD_JSON_FORMAT {
+        bail!("Unbekanntes Zwischenablage-Format: {}", document.format);
+    }
+    if document.version > CLIPBOARD_JSON_VERSION {
+        bail!(
+            "Zwischenablage-Version {} wird nicht unterstuetzt (max. {})",
+            document.version,
+            CLIPBOARD_JSON_VERSION
+        );
+    }
+    if document.nodes.is_empty() {
+        bail!("Zwischenablage enthaelt keine Nodes");
+    }
+
+    let nodes: Vec<MapNode> = document
+        .nodes
+        .iter()
+        .map(|node| {
+            MapNode::new(
+                node.id,
+                Vec2::new(node.x, node.z),
+                NodeFlag::from_u32(node.flag),
+            )
+        })
+        .collect();
+    let positions: HashMap<u64, Vec2> = nodes.iter().map(|n| (n.id, n.position)).collect();
+    let center = nodes.iter().fold(Vec2::ZERO, |acc, n| acc + n.position) / nodes.len() as f32;
+
+    let connections = document
+        .connections
+        .iter()
+        .filter_map(|conn| {
+            let start_pos = *positions.get(&conn.start_id)?;
+            let end_pos = *positions.get(&conn.end_id)?;
+            Some(Connection::new(
+                conn.start_id,
+                conn.end_id,
+                conn.direction,
+                conn.priority,
+                start_pos,
+                end_pos,
+            ))
+        })
+        .collect();
+    let markers = document
+        .markers
+        .into_iter()
+        .filter(|marker| positions.contains_key(&marker.id))
+        .collect();
+
+    Ok(Clipboard {
+        nodes,
+        connections,
+        markers,
+        center,
+    })
+}
+
+/// Startet die Einfuegen-Vorschau mit dem Inhalt der System-Zwischenablage.
+///
+/// Enthaelt `text` Editor-JSON, ersetzt es die interne Zwischenablage; die
+/// neuen IDs werden wie gewohnt erst beim Bestaetigen vergeben. Fremder Text
+/// faellt auf die interne Zwischenablage zurueck, sofern diese gefuellt ist.
+pub fn paste_from_text(state: &mut AppState, text: &str) {
+    if state.paste_preview_pos.is_some() {
+        return;
+    }
+
+    match clipboard_from_json(text) {
+        Ok(clipboard) => {
+            log::info!(
+                "System-Zwischenablage gelesen: {} Nodes, {} Verbindungen, {} Marker",
+                clipboard.nodes.len(),
+                clipboard.connections.len(),
+                clipboard.markers.len()
+            );
+            state.clipboard = clipboard;
+        }
+        Err(e) if state.clipboard.nodes.is_empty() => {
+            log::debug!("paste_from_text: {e:#}");
+            state.ui.status_message =
+                Some("Zwischenablage enthaelt keine Editor-Daten".to_string());
+            return;
+        }
+        Err(e) => log::debug!("paste_from_text: {e:#} — nutze interne Zwischenablage"),
+    }
+
+    start_paste_preview(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::use_cases::editing::{confirm_paste, update_paste_preview};
+    use crate::core::RoadMap;
+    use std::sync::Arc;
+
+    fn sample_clipboard() -> Clipboard {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(10.0, 4.0);
+        Clipboard {
+            nodes: vec![
+                MapNode::new(5, a, NodeFlag::Regular),
+                MapNode::new(9, b, NodeFlag::SubPrio),
+            ],
+            connections: vec![Connection::new(
+                5,
+                9,
+                ConnectionDirection::Dual,
+                ConnectionPriority::SubPriority,
+                a,
+                b,
+            )],
+            markers: vec![MapMarker::new(9, "Hof".into(), "All".into(), 1, false)],
+            center: Vec2::new(5.0, 2.0),
+        }
+    }
+
+    #[test]
+    fn json_roundtrip_keeps_nodes_connections_and_markers() {
+        let json = clipboard_to_json(&sample_clipboard()).expect("JSON erwartet");
+        assert!(json.contains(CLIPBOARD_JSON_FORMAT));
+
+        let clipboard = clipboard_from_json(&json).expect("Roundtrip muss gelingen");
+        assert_eq!(clipboard.nodes.len(), 2);
+        assert_eq!(clipboard.nodes[1].flag, NodeFlag::SubPrio);
+        assert_eq!(clipboard.center, Vec2::new(5.0, 2.0));
+        assert_eq!(clipboard.connections.len(), 1);
+        assert_eq!(
+            clipboard.connections[0].direction,
+            ConnectionDirection::Dual
+        );
+        assert_eq!(clipboard.markers[0].name, "Hof");
+    }
+
+    #[test]
+    fn foreign_text_is_rejected() {
+        assert!(clipboard_from_json("hallo").is_err());
+        assert!(clipboard_from_json("{\"format\":\"other\",\"version\":1,\"nodes\":[]}").is_err());
+        assert!(clipboard_to_json(&Clipboard::default()).is_none());
+    }
+
+    #[test]
+    fn paste_from_text_inserts_copy_with_fresh_ids() {
+        let json = clipboard_to_json(&sample_clipboard()).expect("JSON erwartet");
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(5, Vec2::new(-50.0, 0.0), NodeFlag::Regular));
+        map.ensure_spatial_index();
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+
+        paste_from_text(&mut state, &json);
+        assert_eq!(state.paste_preview_pos, Some(Vec2::new(5.0, 2.0)));
+        update_paste_preview(&mut state, Vec2::new(105.0, 102.0));
+        confirm_paste(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        assert_eq!(road_map.node_count(), 3);
+        let new_ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert!(!new_ids.contains(&5));
+        assert!(road_map.has_connection(new_ids[0], new_ids[1]));
+        assert_eq!(
+            road_map.node_position(new_ids[1]),
+            Some(Vec2::new(110.0, 104.0))
+        );
+        assert!(road_map.map_markers().iter().any(|m| m.id == new_ids[1]));
+    }
+
+    #[test]
+    fn paste_from_foreign_text_without_clipboard_sets_status() {
+        let mut state = AppState::new();
+        paste_from_text(&mut state, "kein json");
+        assert!(state.paste_preview_pos.is_none());
+        assert!(state.ui.status_message.is_some());
+    }
+}
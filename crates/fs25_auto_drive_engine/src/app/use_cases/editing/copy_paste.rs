@@ -1,4 +1,4 @@
-//! Use-Cases fuer Kopieren/Einfuegen (Copy/Paste) von Nodes, Verbindungen und Markern.
+//! Use-Cases fuer Kopieren/Einfuegen (Copy/Paste) und Duplizieren von Nodes, Verbindungen und Markern.
 
 use crate::app::state::Clipboard;
 use crate::app::AppState;
@@ -7,6 +7,9 @@ use glam::Vec2;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Drehschritt der Einfuegen-Vorschau pro Tastendruck in Grad.
+pub const PASTE_PREVIEW_ROTATION_STEP_DEG: f32 = 15.0;
+
 /// Kopiert alle selektierten Nodes, interne Verbindungen und Marker in die Zwischenablage.
 ///
 /// "Intern" bedeutet: beide Endpunkte der Verbindung sind selektiert.
@@ -26,7 +29,7 @@ pub fn copy_selected_to_clipboard(state: &mut AppState) {
     // Nodes kopieren
     let nodes: Vec<MapNode> = selected_ids
         .iter()
-        .filter_map(|&id| road_map.node(id).cloned())
+        .filter_map(|&id| road_map.node(id))
         .collect();
 
     if nodes.is_empty() {
@@ -88,9 +91,50 @@ pub fn start_paste_preview(state: &mut AppState) {
         return;
     }
     state.paste_preview_pos = Some(state.clipboard.center);
+    state.paste_preview_rotation = 0.0;
     log::debug!("Paste-Vorschau gestartet");
 }
 
+/// Dupliziert die Selektion in einen interaktiven Platzierungsmodus.
+///
+/// Die Kopie wird nicht sofort eingefuegt, sondern haengt als Vorschau am
+/// Cursor (gleicher Ablauf wie beim Einfuegen: drehen, Klick platziert, Esc
+/// bricht ab). Die bisherige Zwischenablage bleibt erhalten und wird nach
+/// Abschluss der Platzierung wiederhergestellt.
+pub fn duplicate_selection(state: &mut AppState) {
+    if state.selection.selected_node_ids.is_empty() || state.paste_preview_pos.is_some() {
+        return;
+    }
+
+    let previous = std::mem::take(&mut state.clipboard);
+    copy_selected_to_clipboard(state);
+    if state.clipboard.nodes.is_empty() {
+        state.clipboard = previous;
+        return;
+    }
+    state.clipboard_before_duplicate = Some(previous);
+    start_paste_preview(state);
+    log::debug!("Duplikat-Platzierung gestartet");
+}
+
+/// Dreht die Einfuegen-Vorschau um `delta` (Radiant) um ihr Zentrum.
+pub fn rotate_paste_preview(state: &mut AppState, delta: f32) {
+    if state.paste_preview_pos.is_none() {
+        return;
+    }
+    state.paste_preview_rotation =
+        (state.paste_preview_rotation + delta).rem_euclid(std::f32::consts::TAU);
+}
+
+/// Beendet den Vorschau-Modus und stellt eine beim Duplizieren gesicherte Zwischenablage wieder her.
+fn finish_paste_preview(state: &mut AppState) {
+    state.paste_preview_pos = None;
+    state.paste_preview_rotation = 0.0;
+    if let Some(previous) = state.clipboard_before_duplicate.take() {
+        state.clipboard = previous;
+    }
+}
+
 /// Aktualisiert die Vorschauposition beim Einfuegen.
 pub fn update_paste_preview(state: &mut AppState, world_pos: Vec2) {
     if state.clipboard.nodes.is_empty() {
@@ -101,7 +145,7 @@ pub fn update_paste_preview(state: &mut AppState, world_pos: Vec2) {
 
 /// Bricht den Einfuegen-Vorschau-Modus ab.
 pub fn cancel_paste_preview(state: &mut AppState) {
-    state.paste_preview_pos = None;
+    finish_paste_preview(state);
     log::debug!("Paste-Vorschau abgebrochen");
 }
 
@@ -109,7 +153,7 @@ pub fn cancel_paste_preview(state: &mut AppState) {
 ///
 /// - Erstellt einen Undo-Snapshot vor der Mutation.
 /// - Vergibt neue IDs fortlaufend ab `next_node_id`.
-/// - Berechnet den Positions-Offset: `preview_pos - clipboard.center`.
+/// - Verschiebt das Clipboard-Zentrum auf `preview_pos` und dreht um `paste_preview_rotation`.
 /// - Remappt alle Verbindungs-IDs und Marker-IDs auf die neuen IDs.
 /// - Baut Geometrie und Spatial-Index neu auf.
 /// - Selektiert die neu eingefuegten Nodes.
@@ -125,13 +169,13 @@ pub fn confirm_paste(state: &mut AppState) {
 
     if state.clipboard.nodes.is_empty() {
         log::warn!("confirm_paste: Zwischenablage leer");
-        state.paste_preview_pos = None;
+        finish_paste_preview(state);
         return;
     }
 
     let Some(_road_map_arc) = state.road_map.as_ref() else {
         log::warn!("confirm_paste: keine RoadMap geladen");
-        state.paste_preview_pos = None;
+        finish_paste_preview(state);
         return;
     };
 
@@ -143,8 +187,7 @@ pub fn confirm_paste(state: &mut AppState) {
     };
     let road_map = Arc::make_mut(road_map_arc);
 
-    // Positions-Offset: Vorschauposition relativ zum gespeicherten Zentrum
-    let offset = preview_pos - state.clipboard.center;
+    let rotation = state.paste_preview_rotation;
 
     // Neue IDs fortlaufend ab next_node_id vergeben
     let base_id = road_map.next_node_id();
@@ -160,7 +203,10 @@ pub fn confirm_paste(state: &mut AppState) {
     let mut new_ids = Vec::with_capacity(state.clipboard.nodes.len());
     for node in &state.clipboard.nodes {
         let new_id = id_map[&node.id];
-        let new_node = MapNode::new(new_id, node.position + offset, node.flag);
+        let position = state
+            .clipboard
+            .placed_position(node.position, preview_pos, rotation);
+        let new_node = MapNode::new(new_id, position, node.flag);
         road_map.add_node(new_node);
         new_ids.push(new_id);
     }
@@ -215,7 +261,7 @@ pub fn confirm_paste(state: &mut AppState) {
     }
 
     // Vorschau-Modus beenden
-    state.paste_preview_pos = None;
+    finish_paste_preview(state);
 
     log::info!(
         "Paste bestaetigt: {} Nodes eingefuegt (IDs {}..{})",
@@ -224,3 +270,79 @@ pub fn confirm_paste(state: &mut AppState) {
         base_id + new_ids.len() as u64 - 1
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ConnectionDirection, ConnectionPriority, NodeFlag, RoadMap};
+
+    /// Zwei verbundene Nodes (1→2) und ein bereits gefuelltes Clipboard mit Node 99.
+    fn state_with_selected_pair() -> AppState {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+        map.add_connection(Connection::new(
+            1,
+            2,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            Vec2::new(0.0, 0.0),
+            Vec2::new(10.0, 0.0),
+        ));
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.clipboard = Clipboard {
+            nodes: vec![MapNode::new(99, Vec2::new(50.0, 50.0), NodeFlag::Regular)],
+            center: Vec2::new(50.0, 50.0),
+            ..Clipboard::default()
+        };
+        state.selection.ids_mut().extend([1, 2]);
+        state
+    }
+
+    #[test]
+    fn duplicate_places_rotated_copy_and_restores_clipboard() {
+        let mut state = state_with_selected_pair();
+
+        duplicate_selection(&mut state);
+        assert_eq!(state.paste_preview_pos, Some(Vec2::new(5.0, 0.0)));
+        assert_eq!(
+            state.road_map.as_deref().map(RoadMap::node_count),
+            Some(2),
+            "Duplizieren darf erst beim Platzieren einfuegen"
+        );
+
+        update_paste_preview(&mut state, Vec2::new(100.0, 100.0));
+        rotate_paste_preview(&mut state, std::f32::consts::FRAC_PI_2);
+        confirm_paste(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        assert_eq!(road_map.node_count(), 4);
+        let new_ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert!(road_map.has_connection(new_ids[0], new_ids[1]));
+        let start = road_map.node_position(new_ids[0]).expect("Kopie 1");
+        let end = road_map.node_position(new_ids[1]).expect("Kopie 2");
+        assert!(start.distance(Vec2::new(100.0, 95.0)) < 1e-3);
+        assert!(end.distance(Vec2::new(100.0, 105.0)) < 1e-3);
+
+        assert!(state.paste_preview_pos.is_none());
+        assert_eq!(state.paste_preview_rotation, 0.0);
+        assert_eq!(state.clipboard.nodes.len(), 1);
+        assert_eq!(state.clipboard.nodes[0].id, 99);
+    }
+
+    #[test]
+    fn cancelled_duplicate_leaves_map_and_clipboard_untouched() {
+        let mut state = state_with_selected_pair();
+
+        duplicate_selection(&mut state);
+        cancel_paste_preview(&mut state);
+
+        assert_eq!(state.road_map.as_deref().map(RoadMap::node_count), Some(2));
+        assert!(state.clipboard_before_duplicate.is_none());
+        assert_eq!(state.clipboard.nodes[0].id, 99);
+        assert!(!state.can_undo());
+    }
+}
@@ -0,0 +1,189 @@
+//! Use-Case: Nodes einer Region nach Filtern loeschen ("Loeschen nach Filter...").
+//!
+//! Die Region ist die aktuelle Selektion (per Rechteck- oder Lasso-Selektion
+//! gezogen). Der Dialog zeigt vor dem Loeschen, wie viele Nodes die gewaehlten
+//! Filter treffen; geloescht wird in genau einem Undo-Schritt.
+
+use super::delete_nodes_by_ids::delete_nodes_internal;
+use crate::app::AppState;
+use crate::core::NodeFlag;
+use crate::shared::{NodeDeleteCandidate, NodeDeleteFilter};
+use std::sync::Arc;
+
+/// Erfasst die ungesperrten Nodes der Selektion mit ihren Filter-Eigenschaften
+/// sowie die Anzahl gesperrter Nodes.
+fn collect_candidates(state: &AppState) -> (Vec<NodeDeleteCandidate>, usize) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return (Vec::new(), 0);
+    };
+    let mut locked_count = 0;
+    let candidates = state
+        .selection
+        .selected_node_ids
+        .iter()
+        .filter(|id| {
+            let locked = state.locked_node_ids.contains(id);
+            locked_count += usize::from(locked);
+            !locked
+        })
+        .filter_map(|&node_id| {
+            let node = road_map.node(node_id)?;
+            Some(NodeDeleteCandidate {
+                node_id,
+                is_subprio: node.flag == NodeFlag::SubPrio,
+                is_unconnected: road_map.degree(node_id) == 0,
+                has_marker: road_map.has_marker(node_id),
+            })
+        })
+        .collect();
+    (candidates, locked_count)
+}
+
+/// Oeffnet den Dialog "Loeschen nach Filter..." fuer die aktuelle Selektion.
+///
+/// Die zuletzt gewaehlten Filter bleiben erhalten; die Kandidaten werden neu
+/// erfasst. Ohne Selektion wird nur ein Hinweis in der Statusleiste gesetzt.
+pub fn open_delete_by_filter_dialog(state: &mut AppState) {
+    if state.selection.selected_node_ids.is_empty() {
+        state.ui.status_message = Some(
+            "Loeschen nach Filter: zuerst eine Region per Rechteck oder Lasso selektieren"
+                .to_string(),
+        );
+        return;
+    }
+    let (candidates, locked_count) = collect_candidates(state);
+    let dialog = &mut state.ui.delete_by_filter_dialog;
+    dialog.candidates = candidates;
+    dialog.locked_count = locked_count;
+    dialog.visible = true;
+}
+
+/// Loescht alle ungesperrten Nodes der Selektion, die `filter` erfuellen
+/// (mit Undo-Snapshot).
+///
+/// Verbindungen und Marker der geloeschten Nodes werden mit entfernt; anders
+/// als beim normalen Loeschen wird nicht neu verbunden. Treffer verlassen die
+/// Selektion, uebrige Nodes der Region bleiben selektiert.
+pub fn delete_nodes_by_filter(state: &mut AppState, filter: NodeDeleteFilter) {
+    state.ui.delete_by_filter_dialog.visible = false;
+
+    let (candidates, locked_count) = collect_candidates(state);
+    let ids: Vec<u64> = candidates
+        .iter()
+        .filter(|candidate| filter.matches(candidate))
+        .map(|candidate| candidate.node_id)
+        .collect();
+    if ids.is_empty() {
+        state.ui.status_message =
+            Some("Loeschen nach Filter: keine passenden Nodes in der Region".to_string());
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    delete_nodes_internal(Arc::make_mut(road_map_arc), &ids, true);
+
+    for id in &ids {
+        state.selection.ids_mut().shift_remove(id);
+    }
+    state.selection.selection_anchor_node_id = state.selection.selected_node_ids.last().copied();
+    let invalidated = state.group_registry.invalidate_by_node_ids(&ids);
+    state.tool_edit_store.remove_many(invalidated);
+
+    let message = format!(
+        "{} von {} Node(s) nach Filter geloescht ({} gesperrt)",
+        ids.len(),
+        candidates.len() + locked_count,
+        locked_count
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        Connection, ConnectionDirection, ConnectionPriority, MapMarker, MapNode, RoadMap,
+    };
+    use glam::Vec2;
+
+    /// Region mit: 1 (SubPrio, verbunden mit 2), 2 (SubPrio), 3 (Regular, lose),
+    /// 4 (SubPrio, lose, mit Marker), 5 (SubPrio, lose). Alle selektiert.
+    fn region_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        for (id, flag) in [
+            (1, NodeFlag::SubPrio),
+            (2, NodeFlag::SubPrio),
+            (3, NodeFlag::Regular),
+            (4, NodeFlag::SubPrio),
+            (5, NodeFlag::SubPrio),
+        ] {
+            map.add_node(MapNode::new(id, Vec2::new(id as f32 * 10.0, 0.0), flag));
+        }
+        map.add_connection(Connection::new(
+            1,
+            2,
+            ConnectionDirection::Regular,
+            ConnectionPriority::SubPriority,
+            Vec2::new(10.0, 0.0),
+            Vec2::new(20.0, 0.0),
+        ));
+        map.add_map_marker(MapMarker::new(
+            4,
+            "Hof".to_string(),
+            "All".to_string(),
+            1,
+            false,
+        ));
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3, 4, 5]);
+        state
+    }
+
+    #[test]
+    fn open_dialog_counts_matches_per_filter() {
+        let mut state = region_state();
+        state.locked_node_ids.insert(5);
+
+        open_delete_by_filter_dialog(&mut state);
+
+        let dialog = &mut state.ui.delete_by_filter_dialog;
+        assert!(dialog.visible);
+        assert_eq!(dialog.locked_count, 1);
+        assert_eq!(dialog.matching_count(), 4);
+        dialog.filter.only_subprio = true;
+        assert_eq!(dialog.matching_count(), 3);
+        dialog.filter.only_unconnected = true;
+        assert_eq!(dialog.matching_count(), 1);
+        dialog.filter.only_without_markers = true;
+        assert_eq!(dialog.matching_count(), 0);
+    }
+
+    #[test]
+    fn delete_by_filter_removes_only_matching_nodes_in_one_undo_step() {
+        let mut state = region_state();
+        let filter = NodeDeleteFilter {
+            only_subprio: true,
+            only_unconnected: true,
+            only_without_markers: true,
+        };
+
+        delete_nodes_by_filter(&mut state, filter);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        assert!(!road_map.contains_node(5));
+        for id in [1, 2, 3, 4] {
+            assert!(road_map.contains_node(id));
+        }
+        assert!(!state.selection.selected_node_ids.contains(&5));
+        assert!(state.can_undo());
+    }
+}
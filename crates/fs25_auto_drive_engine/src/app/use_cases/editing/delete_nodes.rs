@@ -59,7 +59,10 @@ fn collect_reconnect(
 }
 
 /// Hoeherwertige Richtung: Dual > Regular/Reverse; bei Widerspruch → Dual.
-fn merge_directions(a: ConnectionDirection, b: ConnectionDirection) -> ConnectionDirection {
+pub(super) fn merge_directions(
+    a: ConnectionDirection,
+    b: ConnectionDirection,
+) -> ConnectionDirection {
     match (a, b) {
         (ConnectionDirection::Dual, _) | (_, ConnectionDirection::Dual) => {
             ConnectionDirection::Dual
@@ -70,7 +73,7 @@ fn merge_directions(a: ConnectionDirection, b: ConnectionDirection) -> Connectio
 }
 
 /// Hoeherwertige Prioritaet: Hauptstrasse (Regular) schlaegt Nebenstrasse (SubPriority).
-fn merge_priorities(a: ConnectionPriority, b: ConnectionPriority) -> ConnectionPriority {
+pub(super) fn merge_priorities(a: ConnectionPriority, b: ConnectionPriority) -> ConnectionPriority {
     match (a, b) {
         (ConnectionPriority::SubPriority, ConnectionPriority::SubPriority) => {
             ConnectionPriority::SubPriority
@@ -84,6 +87,8 @@ fn merge_priorities(a: ConnectionPriority, b: ConnectionPriority) -> ConnectionP
 /// Wenn `options.reconnect_on_delete` aktiviert ist, werden Nodes mit genau einem
 /// Vorgaenger und einem Nachfolger so geloescht, dass Vorgaenger und Nachfolger
 /// direkt miteinander verbunden werden (Richtung/Prioritaet der Ausgangsverbindung).
+///
+/// Gesperrte Nodes (`AppState::locked_node_ids`) werden uebersprungen und bleiben selektiert.
 pub fn delete_selected_nodes(state: &mut AppState) {
     if state.selection.selected_node_ids.is_empty() {
         log::debug!("Nichts zum Loeschen selektiert");
@@ -94,10 +99,25 @@ pub fn delete_selected_nodes(state: &mut AppState) {
         return;
     }
 
+    let ids_to_delete: Vec<u64> = state
+        .selection
+        .selected_node_ids
+        .iter()
+        .copied()
+        .filter(|id| !state.locked_node_ids.contains(id))
+        .collect();
+    let locked_count = state.selection.selected_node_ids.len() - ids_to_delete.len();
+    if ids_to_delete.is_empty() {
+        state.ui.status_message = Some(format!(
+            "{} gesperrte Node(s) koennen nicht geloescht werden",
+            locked_count
+        ));
+        return;
+    }
+
     // Snapshot VOR Mutation
     state.record_undo_snapshot();
 
-    let ids_to_delete: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
     let id_set: std::collections::HashSet<u64> = ids_to_delete.iter().copied().collect();
 
     // Reconnect-Operationen vorbereiten (falls Option aktiv)
@@ -148,8 +168,18 @@ pub fn delete_selected_nodes(state: &mut AppState) {
     let invalidated = state.group_registry.invalidate_by_node_ids(&ids_to_delete);
     state.tool_edit_store.remove_many(invalidated);
 
-    state.selection.ids_mut().clear();
-    state.selection.selection_anchor_node_id = None;
+    if locked_count > 0 {
+        state.selection.ids_mut().retain(|id| !id_set.contains(id));
+        state.selection.selection_anchor_node_id =
+            state.selection.selected_node_ids.last().copied();
+        state.ui.status_message = Some(format!(
+            "{} Node(s) geloescht, {} gesperrte Node(s) uebersprungen",
+            count, locked_count
+        ));
+    } else {
+        state.selection.ids_mut().clear();
+        state.selection.selection_anchor_node_id = None;
+    }
 
     log::info!("{} Node(s) geloescht", count);
 }
@@ -54,7 +54,15 @@ pub(crate) fn delete_nodes_internal(road_map: &mut RoadMap, ids: &[u64], remove_
 
 /// Loescht die angegebenen Nodes und deren Connections ueber den batch-faehigen Core-Loeschpfad.
 /// Erstellt KEINEN Undo-Snapshot (Caller verantwortlich).
+///
+/// Gesperrte Nodes (`AppState::locked_node_ids`) werden uebersprungen; Caller,
+/// die eine vollstaendige Loeschung benoetigen, pruefen die Sperre vorab.
 pub fn delete_nodes_by_ids(state: &mut AppState, ids: &[u64]) {
+    let ids: Vec<u64> = ids
+        .iter()
+        .copied()
+        .filter(|id| !state.locked_node_ids.contains(id))
+        .collect();
     if ids.is_empty() {
         return;
     }
@@ -64,16 +72,41 @@ pub fn delete_nodes_by_ids(state: &mut AppState, ids: &[u64]) {
     };
     let road_map = Arc::make_mut(road_map_arc);
 
-    delete_nodes_internal(road_map, ids, false);
+    delete_nodes_internal(road_map, &ids, false);
 
     // Geloeschte Nodes aus Selektion entfernen
-    for &id in ids {
+    for &id in &ids {
         state.selection.ids_mut().shift_remove(&id);
     }
 
     // Segment-Registry: Records mit diesen Nodes invalidieren
-    let invalidated = state.group_registry.invalidate_by_node_ids(ids);
+    let invalidated = state.group_registry.invalidate_by_node_ids(&ids);
     state.tool_edit_store.remove_many(invalidated);
 
     log::debug!("{} Nodes geloescht (Route-Tool-Neuberechnung)", ids.len());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::delete_nodes_by_ids;
+    use crate::app::AppState;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+    use std::sync::Arc;
+
+    #[test]
+    fn delete_nodes_by_ids_skips_locked_nodes() {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.locked_node_ids.insert(2);
+
+        delete_nodes_by_ids(&mut state, &[1, 2]);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap erwartet");
+        assert!(!road_map.contains_node(1));
+        assert!(road_map.contains_node(2));
+    }
+}
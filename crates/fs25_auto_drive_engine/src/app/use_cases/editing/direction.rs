@@ -2,6 +2,7 @@
 
 use crate::app::AppState;
 use crate::core::ConnectionDirection;
+use glam::Vec2;
 use std::sync::Arc;
 
 /// Aendert die Richtung einer bestehenden Verbindung.
@@ -64,6 +65,123 @@ pub fn set_connection_direction(
     );
 }
 
+/// Invertiert eine einzelne Verbindung (start ⇔ end) als eigenen Undo-Schritt.
+///
+/// Ist die Verbindung selektiert, zeigt die Verbindungs-Selektion danach auf
+/// die umgedrehte Verbindung. Existiert bereits eine Gegen-Connection, bleibt
+/// alles unveraendert.
+pub fn invert_connection(state: &mut AppState, start_id: u64, end_id: u64) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    if road_map.find_connection(start_id, end_id).is_none() {
+        log::warn!("Verbindung {}→{} nicht gefunden", start_id, end_id);
+        return;
+    }
+    if road_map.has_connection(end_id, start_id) {
+        state.ui.status_message = Some(format!(
+            "Gegenverbindung {}→{} existiert bereits — nicht invertiert",
+            end_id, start_id
+        ));
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    road_map.invert_connection(start_id, end_id);
+    road_map.recalculate_node_flags(&[start_id, end_id]);
+    if state.selection.selected_connection == Some((start_id, end_id)) {
+        state.selection.selected_connection = Some((end_id, start_id));
+    }
+
+    log::info!("Verbindung {}→{} invertiert", start_id, end_id);
+}
+
+/// Setzt die Richtung der Verbindung unter `from` per Wischgeste (Drag von `from` nach `to`).
+///
+/// Ohne `toggle_dual` wird die Verbindung zur Einbahnstrasse in Zugrichtung:
+/// sie wird bei Bedarf invertiert, eine Gegen-Connection entfernt. Mit
+/// `toggle_dual` wird eine Einbahnverbindung zu Dual bzw. eine Dual-Verbindung
+/// zur Einbahnstrasse in Zugrichtung. Alles erfolgt in einem Undo-Schritt.
+pub fn apply_connection_swipe(state: &mut AppState, from: Vec2, to: Vec2, toggle_dual: bool) {
+    let swipe = to - from;
+    if swipe.length_squared() < f32::EPSILON {
+        return;
+    }
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let Some(conn) = road_map.nearest_connection(from, state.options.hitbox_radius()) else {
+        return;
+    };
+    let (start_id, end_id, old_direction) = (conn.start_id, conn.end_id, conn.direction);
+    let (Some(start_pos), Some(end_pos)) = (
+        road_map.node_position(start_id),
+        road_map.node_position(end_id),
+    ) else {
+        return;
+    };
+
+    if toggle_dual && old_direction != ConnectionDirection::Dual {
+        state.record_undo_snapshot();
+        let Some(road_map_arc) = state.road_map.as_mut() else {
+            return;
+        };
+        let road_map = Arc::make_mut(road_map_arc);
+        road_map.set_connection_direction(start_id, end_id, ConnectionDirection::Dual);
+        road_map.recalculate_node_flags(&[start_id, end_id]);
+        state.ui.status_message = Some(format!(
+            "Verbindung {}↔{} auf Dual gesetzt",
+            start_id, end_id
+        ));
+        return;
+    }
+
+    // Zielverbindung in Zugrichtung bestimmen
+    let (target_start, target_end) = if swipe.dot(end_pos - start_pos) >= 0.0 {
+        (start_id, end_id)
+    } else {
+        (end_id, start_id)
+    };
+    let already_oriented = road_map
+        .find_connection(target_start, target_end)
+        .is_some_and(|c| c.direction == ConnectionDirection::Regular)
+        && !road_map.has_connection(target_end, target_start);
+    if already_oriented {
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    if road_map.has_connection(target_start, target_end) {
+        road_map.remove_connection(target_end, target_start);
+    } else {
+        road_map.invert_connection(target_end, target_start);
+    }
+    road_map.set_connection_direction(target_start, target_end, ConnectionDirection::Regular);
+    road_map.recalculate_node_flags(&[start_id, end_id]);
+
+    log::info!(
+        "Verbindung {}→{} per Wischgeste auf Zugrichtung gesetzt",
+        target_start,
+        target_end
+    );
+    state.ui.status_message = Some(format!(
+        "Verbindung {}→{} auf Zugrichtung gesetzt",
+        target_start, target_end
+    ));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +245,61 @@ mod tests {
         assert!(rm.has_connection(1, 2));
         assert!(!rm.has_connection(2, 1));
     }
+
+    #[test]
+    fn invert_connection_flips_endpoints_and_follows_selection() {
+        let mut state = make_state_ab(ConnectionDirection::Regular);
+        state.selection.selected_connection = Some((1, 2));
+
+        invert_connection(&mut state, 1, 2);
+
+        let rm = state.road_map.as_deref().unwrap();
+        assert!(!rm.has_connection(1, 2));
+        assert!(rm.has_connection(2, 1));
+        assert_eq!(state.selection.selected_connection, Some((2, 1)));
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn swipe_against_connection_inverts_it() {
+        let mut state = make_state_ab(ConnectionDirection::Regular);
+        apply_connection_swipe(&mut state, Vec2::new(6.0, 0.2), Vec2::new(3.0, 0.0), false);
+        let rm = state.road_map.as_deref().unwrap();
+        assert!(!rm.has_connection(1, 2));
+        assert_eq!(
+            rm.find_connection(2, 1).map(|c| c.direction),
+            Some(ConnectionDirection::Regular)
+        );
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn swipe_along_existing_orientation_is_noop() {
+        let mut state = make_state_ab(ConnectionDirection::Regular);
+        apply_connection_swipe(&mut state, Vec2::new(3.0, 0.0), Vec2::new(6.0, 0.0), false);
+        assert!(!state.can_undo());
+    }
+
+    #[test]
+    fn swipe_with_toggle_dual_switches_between_dual_and_swipe_direction() {
+        let mut state = make_state_ab(ConnectionDirection::Regular);
+        apply_connection_swipe(&mut state, Vec2::new(3.0, 0.0), Vec2::new(6.0, 0.0), true);
+        assert_eq!(
+            state
+                .road_map
+                .as_deref()
+                .unwrap()
+                .find_connection(1, 2)
+                .map(|c| c.direction),
+            Some(ConnectionDirection::Dual)
+        );
+
+        apply_connection_swipe(&mut state, Vec2::new(6.0, 0.0), Vec2::new(3.0, 0.0), true);
+        let rm = state.road_map.as_deref().unwrap();
+        assert!(!rm.has_connection(1, 2));
+        assert_eq!(
+            rm.find_connection(2, 1).map(|c| c.direction),
+            Some(ConnectionDirection::Regular)
+        );
+    }
 }
@@ -33,6 +33,15 @@ pub fn remove_connection_between(state: &mut AppState, node_a: u64, node_b: u64)
     let removed = road_map.remove_connections_between(node_a, node_b);
     // Flags der betroffenen Nodes neu berechnen
     road_map.recalculate_node_flags(&[node_a, node_b]);
+    if state
+        .selection
+        .selected_connection
+        .is_some_and(|(start, end)| {
+            (start, end) == (node_a, node_b) || (end, start) == (node_a, node_b)
+        })
+    {
+        state.selection.selected_connection = None;
+    }
 
     log::info!(
         "{} Verbindung(en) zwischen {} und {} entfernt",
@@ -0,0 +1,215 @@
+//! Use-Case: Nodes einer selektierten Kette auf gleiche Abstaende verteilen.
+//!
+//! Anders als die Streckenteilung (`resample_path`) bleiben Node-IDs, Anzahl,
+//! Marker und externe Verbindungen erhalten — nur die inneren Nodes rutschen
+//! entlang der bestehenden Kettenform an gleichmaessige Bogenlaengen-Positionen.
+
+use crate::app::AppState;
+use crate::shared::spline_geometry::polyline_length;
+use glam::Vec2;
+use std::sync::Arc;
+
+/// Berechnet fuer eine Polyline `positions.len()` Punkte mit gleichem
+/// Bogenlaengen-Abstand. Erster und letzter Punkt bleiben exakt erhalten.
+fn equalized_positions(positions: &[Vec2]) -> Vec<Vec2> {
+    let count = positions.len();
+    let total = polyline_length(positions);
+    if count < 3 || total < f32::EPSILON {
+        return positions.to_vec();
+    }
+
+    let step = total / (count - 1) as f32;
+    let mut result = Vec::with_capacity(count);
+    result.push(positions[0]);
+
+    let mut segment = 0;
+    let mut segment_start = 0.0_f32; // Bogenlaenge am Anfang von `segment`
+    for index in 1..count - 1 {
+        let target = step * index as f32;
+        // Bis zum Segment vorlaufen, das die Ziel-Bogenlaenge enthaelt
+        while segment + 2 < count {
+            let length = positions[segment].distance(positions[segment + 1]);
+            if segment_start + length >= target {
+                break;
+            }
+            segment_start += length;
+            segment += 1;
+        }
+        let (a, b) = (positions[segment], positions[segment + 1]);
+        let length = a.distance(b);
+        let t = if length > f32::EPSILON {
+            ((target - segment_start) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        result.push(a.lerp(b, t));
+    }
+
+    result.push(positions[count - 1]);
+    result
+}
+
+/// Verteilt die inneren Nodes der selektierten Kette auf gleiche Abstaende
+/// entlang der aktuellen Kettenform (mit Undo-Snapshot).
+///
+/// Die Selektion muss eine zusammenhaengende lineare Kette mit mindestens drei
+/// Nodes bilden. Endpunkte bleiben fest; Node-IDs, Marker und Verbindungen
+/// (auch nach ausserhalb der Selektion) bleiben unveraendert. Enthaelt die
+/// Kette gesperrte Nodes, wird nichts veraendert.
+pub fn equalize_selected_chain_spacing(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let chain_ids = match road_map.ordered_chain_nodes(&state.selection.selected_node_ids) {
+        Some(chain_ids) if chain_ids.len() >= 3 => chain_ids,
+        _ => {
+            state.ui.status_message = Some(
+                "Gleiche Abstaende benoetigen eine zusammenhaengende Kette aus mind. 3 Nodes"
+                    .to_string(),
+            );
+            return;
+        }
+    };
+    if chain_ids
+        .iter()
+        .any(|id| state.locked_node_ids.contains(id))
+    {
+        state.ui.status_message =
+            Some("Kette enthaelt gesperrte Nodes — Abstaende nicht angeglichen".to_string());
+        return;
+    }
+    let Some(positions) = chain_ids
+        .iter()
+        .map(|&id| road_map.node_position(id))
+        .collect::<Option<Vec<Vec2>>>()
+    else {
+        return;
+    };
+    let spacing = polyline_length(&positions) / (positions.len() - 1) as f32;
+    let updates: Vec<(u64, Vec2)> = chain_ids
+        .iter()
+        .copied()
+        .zip(equalized_positions(&positions))
+        .skip(1)
+        .take(chain_ids.len() - 2)
+        .collect();
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    Arc::make_mut(road_map_arc).set_node_positions(&updates);
+
+    let message = format!(
+        "{} Node(s) auf gleiche Abstaende verteilt (Ø {:.2} m)",
+        chain_ids.len(),
+        spacing
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+    };
+
+    /// Winkel-Kette 1→2→3→4→5 (0,0)→(2,0)→(10,0)→(10,6)→(10,10) mit externem
+    /// Nachbarn 9, alle Kettennodes selektiert.
+    fn corner_chain_state() -> AppState {
+        let nodes = [
+            (1_u64, Vec2::new(0.0, 0.0)),
+            (2, Vec2::new(2.0, 0.0)),
+            (3, Vec2::new(10.0, 0.0)),
+            (4, Vec2::new(10.0, 6.0)),
+            (5, Vec2::new(10.0, 10.0)),
+            (9, Vec2::new(20.0, 0.0)),
+        ];
+        let mut map = RoadMap::new(3);
+        for (id, pos) in nodes {
+            map.add_node(MapNode::new(id, pos, NodeFlag::Regular));
+        }
+        for (start, end) in [(1_usize, 2_usize), (2, 3), (3, 4), (4, 5), (3, 6)] {
+            let ((start_id, start_pos), (end_id, end_pos)) = (nodes[start - 1], nodes[end - 1]);
+            map.add_connection(Connection::new(
+                start_id,
+                end_id,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                start_pos,
+                end_pos,
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3, 4, 5]);
+        state
+    }
+
+    fn position(state: &AppState, id: u64) -> Vec2 {
+        state
+            .road_map
+            .as_deref()
+            .and_then(|map| map.node_position(id))
+            .expect("Node erwartet")
+    }
+
+    #[test]
+    fn equalized_positions_follow_existing_polyline() {
+        let positions = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(9.0, 0.0),
+            Vec2::new(12.0, 0.0),
+        ];
+
+        assert_eq!(
+            equalized_positions(&positions),
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(4.0, 0.0),
+                Vec2::new(8.0, 0.0),
+                Vec2::new(12.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn equalize_moves_inner_nodes_and_keeps_ids_and_connections() {
+        let mut state = corner_chain_state();
+        let connections_before = state
+            .road_map
+            .as_deref()
+            .map(RoadMap::connection_count)
+            .expect("Karte erwartet");
+
+        equalize_selected_chain_spacing(&mut state);
+
+        assert_eq!(position(&state, 1), Vec2::new(0.0, 0.0));
+        assert_eq!(position(&state, 2), Vec2::new(5.0, 0.0));
+        assert_eq!(position(&state, 3), Vec2::new(10.0, 0.0));
+        assert_eq!(position(&state, 4), Vec2::new(10.0, 5.0));
+        assert_eq!(position(&state, 5), Vec2::new(10.0, 10.0));
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        assert_eq!(road_map.connection_count(), connections_before);
+        assert!(road_map.has_connection(3, 9));
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn equalize_refuses_chain_with_locked_nodes() {
+        let mut state = corner_chain_state();
+        state.locked_node_ids.insert(2);
+
+        equalize_selected_chain_spacing(&mut state);
+
+        assert_eq!(position(&state, 2), Vec2::new(2.0, 0.0));
+        assert!(!state.can_undo());
+        assert!(state.ui.status_message.is_some());
+    }
+}
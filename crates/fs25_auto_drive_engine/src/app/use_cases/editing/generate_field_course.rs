@@ -0,0 +1,303 @@
+//! Use-Case: Vorgewende-Rundkurs mit Zufahrt fuer ein einzelnes Feld erzeugen.
+//!
+//! Nimmt das Farmland-Polygon mit der gewaehlten Feldnummer, legt darauf mit
+//! dem gewuenschten Vorgewende-Versatz einen geschlossenen Wegpunkt-Ring
+//! (wie das FieldBoundaryTool) und verbindet den Ring ueber eine gerade
+//! Zufahrt mit dem naechstgelegenen bestehenden Node des Strassennetzes.
+//! Ring und Zufahrt bilden einen einzigen Undo-Schritt.
+
+use crate::app::tool_contract::RouteToolId;
+use crate::app::tool_editing::{register_persisted_group, RouteToolEditPayload, ToolRouteBase};
+use crate::app::{compute_ring, field_boundary_ring_node_flag, AppState};
+use crate::core::{
+    Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+};
+use glam::Vec2;
+use std::sync::Arc;
+
+/// Kuerzeste Verbindung zwischen Ring und bestehendem Strassennetz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Approach {
+    /// Index des Ring-Punkts, an dem die Zufahrt einmuendet
+    ring_index: usize,
+    /// Bestehender Node, an dem die Zufahrt beginnt
+    road_node_id: u64,
+    /// Laenge der Zufahrt in Metern
+    distance: f32,
+}
+
+/// Sucht den Ring-Punkt mit dem geringsten Abstand zu einem bestehenden Node.
+///
+/// Gibt `None` zurueck wenn die RoadMap leer ist.
+fn find_approach(road_map: &RoadMap, ring: &[Vec2]) -> Option<Approach> {
+    ring.iter()
+        .enumerate()
+        .filter_map(|(ring_index, &pos)| {
+            road_map.nearest_node(pos).map(|hit| Approach {
+                ring_index,
+                road_node_id: hit.node_id,
+                distance: hit.distance,
+            })
+        })
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+/// Zwischenpunkte einer geraden Zufahrt (ohne Start- und Endpunkt).
+///
+/// Die Punkte liegen gleichmaessig verteilt, hoechstens `spacing` auseinander.
+fn approach_waypoints(from: Vec2, to: Vec2, spacing: f32) -> Vec<Vec2> {
+    let segments = (from.distance(to) / spacing.max(0.1)).ceil().max(1.0) as usize;
+    (1..segments)
+        .map(|i| from.lerp(to, i as f32 / segments as f32))
+        .collect()
+}
+
+/// Erzeugt fuer das Feld `field_id` einen Vorgewende-Ring samt Zufahrt.
+///
+/// Der Ring wird als `GroupRecord` des FieldBoundaryTool registriert und bleibt
+/// damit nachtraeglich bearbeitbar; die Zufahrt-Nodes gehoeren nicht zur Gruppe.
+/// Ist die RoadMap vor dem Aufruf leer, entsteht nur der Ring.
+/// Die Selektion wird auf alle neu erstellten Nodes gesetzt.
+///
+/// Gibt fruehzeitig zurueck wenn keine RoadMap geladen, das Feld unbekannt
+/// oder der Ring nach dem Versatz zu klein ist.
+///
+/// # Parameter
+/// * `field_id` – Farmland-ID des Feldes
+/// * `spacing` – Abstand zwischen Wegpunkten in Metern (Ring und Zufahrt)
+/// * `headland_offset` – Abstand des Rings vom Feldrand nach innen (Vorgewendebreite)
+pub fn generate_field_course(
+    state: &mut AppState,
+    field_id: u32,
+    spacing: f32,
+    headland_offset: f32,
+) {
+    let Some(vertices) = state.farmland_polygons.as_ref().and_then(|polygons| {
+        polygons
+            .iter()
+            .find(|polygon| polygon.id == field_id)
+            .map(|polygon| polygon.vertices.clone())
+    }) else {
+        log::warn!(
+            "Feld {} nicht gefunden — Feld-Rundkurs abgebrochen",
+            field_id
+        );
+        return;
+    };
+
+    let Some(road_map) = state.road_map.as_deref() else {
+        log::warn!("Keine RoadMap geladen — Feld-Rundkurs abgebrochen");
+        return;
+    };
+
+    // compute_ring versetzt bei positivem Wert nach aussen
+    let ring_offset = -headland_offset;
+    let ring = compute_ring(&vertices, ring_offset, 0.0, spacing, None, None, None);
+    if ring.len() < 3 {
+        log::warn!(
+            "Feld {}: zu wenige Punkte nach Ring-Berechnung — Feld-Rundkurs abgebrochen",
+            field_id
+        );
+        return;
+    }
+
+    let ring_positions: Vec<Vec2> = ring.iter().map(|(pos, _)| *pos).collect();
+    let approach = find_approach(road_map, &ring_positions);
+
+    // Standard-Parameter (entsprechen FieldBoundaryTool::new())
+    let direction = ConnectionDirection::Dual;
+    let priority = ConnectionPriority::Regular;
+
+    // Undo-Snapshot VOR der Mutation (Arc-Clone, O(1))
+    state.record_undo_snapshot();
+
+    let (ring_ids, all_new_ids) = {
+        let road_map = Arc::make_mut(state.road_map.as_mut().expect("road_map vorhanden"));
+        let connect = |road_map: &mut RoadMap, from_id: u64, to_id: u64| {
+            let from_pos = road_map
+                .node_position(from_id)
+                .expect("Start-Node vorhanden");
+            let to_pos = road_map.node_position(to_id).expect("End-Node vorhanden");
+            road_map.add_connection(Connection::new(
+                from_id, to_id, direction, priority, from_pos, to_pos,
+            ));
+        };
+
+        // Vorgewende-Ring
+        let mut ring_ids: Vec<u64> = Vec::with_capacity(ring.len());
+        for (pos, kind) in &ring {
+            let id = road_map.next_node_id();
+            road_map.add_node(MapNode::new(id, *pos, field_boundary_ring_node_flag(*kind)));
+            ring_ids.push(id);
+        }
+        for i in 0..ring_ids.len() {
+            connect(road_map, ring_ids[i], ring_ids[(i + 1) % ring_ids.len()]);
+        }
+
+        let mut all_new_ids = ring_ids.clone();
+        let mut touched_ids = ring_ids.clone();
+
+        // Zufahrt vom naechsten bestehenden Node zum Ring
+        if let Some(approach) = approach {
+            let start_pos = road_map
+                .node_position(approach.road_node_id)
+                .expect("Strassen-Node vorhanden");
+            let mut previous_id = approach.road_node_id;
+            for pos in approach_waypoints(start_pos, ring_positions[approach.ring_index], spacing) {
+                let id = road_map.next_node_id();
+                road_map.add_node(MapNode::new(id, pos, NodeFlag::Regular));
+                connect(road_map, previous_id, id);
+                all_new_ids.push(id);
+                previous_id = id;
+            }
+            connect(road_map, previous_id, ring_ids[approach.ring_index]);
+            touched_ids.extend(all_new_ids[ring_ids.len()..].iter().copied());
+            touched_ids.push(approach.road_node_id);
+            log::info!(
+                "Feld {}: Zufahrt von Node {} ({:.1} m)",
+                field_id,
+                approach.road_node_id,
+                approach.distance
+            );
+        } else {
+            log::info!(
+                "Feld {}: kein bestehender Node — Ring ohne Zufahrt",
+                field_id
+            );
+        }
+
+        road_map.recalculate_node_flags(&touched_ids);
+        road_map.ensure_spatial_index();
+        (ring_ids, all_new_ids)
+    };
+
+    log::info!(
+        "Feld-Rundkurs fuer Feld {} erzeugt: {} Ring-Nodes, {} Zufahrt-Nodes",
+        field_id,
+        ring_ids.len(),
+        all_new_ids.len() - ring_ids.len()
+    );
+
+    // Ring als FieldBoundary-Gruppe registrieren, damit er bearbeitbar bleibt
+    let payload = RouteToolEditPayload::FieldBoundary {
+        field_id,
+        node_spacing: spacing,
+        offset: ring_offset,
+        straighten_tolerance: 0.0,
+        corner_angle_threshold: None,
+        corner_rounding_radius: None,
+        corner_rounding_max_angle_deg: None,
+        base: ToolRouteBase {
+            direction,
+            priority,
+            max_segment_length: 0.0,
+        },
+    };
+    let _ = register_persisted_group(
+        state,
+        None,
+        RouteToolId::FieldBoundary,
+        payload,
+        &ring_ids,
+        Vec::new(),
+    );
+
+    // Selektion auf neu erstellte Nodes setzen
+    state.selection.ids_mut().clear();
+    for &id in &all_new_ids {
+        state.selection.ids_mut().insert(id);
+    }
+    state.selection.selection_anchor_node_id = all_new_ids.last().copied();
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FieldPolygon;
+
+    /// AppState mit einem 100×100-Feld (ID 7) und einem Strassen-Node westlich davon.
+    fn make_state_with_field_and_road() -> AppState {
+        let mut state = AppState::new();
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(-60.0, 50.0), NodeFlag::Regular));
+        map.ensure_spatial_index();
+        state.road_map = Some(Arc::new(map));
+        state.farmland_polygons = Some(Arc::new(vec![FieldPolygon {
+            id: 7,
+            vertices: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(100.0, 0.0),
+                Vec2::new(100.0, 100.0),
+                Vec2::new(0.0, 100.0),
+            ],
+        }]));
+        state
+    }
+
+    #[test]
+    fn approach_waypoints_respect_spacing() {
+        let points = approach_waypoints(Vec2::ZERO, Vec2::new(25.0, 0.0), 10.0);
+        assert_eq!(points.len(), 2);
+        assert!(points[0].distance(Vec2::new(25.0 / 3.0, 0.0)) < 1e-4);
+        assert!(points[1].distance(Vec2::new(50.0 / 3.0, 0.0)) < 1e-4);
+        assert!(approach_waypoints(Vec2::ZERO, Vec2::new(5.0, 0.0), 10.0).is_empty());
+    }
+
+    #[test]
+    fn generates_headland_ring_connected_to_nearest_road_node() {
+        let mut state = make_state_with_field_and_road();
+
+        generate_field_course(&mut state, 7, 10.0, 5.0);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        let new_ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert!(new_ids.len() > 4, "Ring und Zufahrt erwartet");
+        assert!(!new_ids.contains(&1));
+
+        // Ring liegt 5 m innerhalb des Feldrands
+        let group = state.group_registry.records().next().expect("Ring-Gruppe");
+        for id in &group.node_ids {
+            let pos = road_map.node_position(*id).expect("Ring-Node");
+            assert!((5.0 - 1e-3..=95.0 + 1e-3).contains(&pos.x), "{pos:?}");
+            assert!((5.0 - 1e-3..=95.0 + 1e-3).contains(&pos.y), "{pos:?}");
+        }
+
+        // Zufahrt beginnt am bestehenden Strassen-Node und endet am Ring
+        let neighbors = road_map.connected_neighbors(1);
+        assert_eq!(neighbors.len(), 1);
+        let mut current = neighbors[0].neighbor_id;
+        let mut previous = 1;
+        while !group.node_ids.contains(&current) {
+            let next = road_map
+                .connected_neighbors(current)
+                .into_iter()
+                .map(|n| n.neighbor_id)
+                .find(|&id| id != previous)
+                .expect("Zufahrt ohne Unterbrechung");
+            previous = current;
+            current = next;
+        }
+        let entry = road_map.node_position(current).expect("Einmuendung");
+        assert!(
+            (entry.x - 5.0).abs() < 1e-3,
+            "Einmuendung am Westrand erwartet"
+        );
+    }
+
+    #[test]
+    fn generates_single_undo_step_and_ignores_unknown_field() {
+        let mut state = make_state_with_field_and_road();
+
+        generate_field_course(&mut state, 3, 10.0, 5.0);
+        assert_eq!(state.road_map.as_deref().unwrap().node_count(), 1);
+        assert!(!state.can_undo());
+
+        generate_field_course(&mut state, 7, 10.0, 5.0);
+        assert!(state.road_map.as_deref().unwrap().node_count() > 1);
+        assert!(state.can_undo());
+    }
+}
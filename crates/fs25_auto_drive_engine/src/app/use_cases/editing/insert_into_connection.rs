@@ -0,0 +1,179 @@
+//! Use-Case: Gezogenen Node beim Ablegen in eine bestehende Verbindung einfuegen.
+
+use crate::app::AppState;
+use crate::core::Connection;
+use std::sync::Arc;
+
+/// Fuegt den einzeln verschobenen Node in die naechstgelegene Verbindung ein.
+///
+/// Wird nach dem Ablegen eines Drags aufgerufen: Liegt der Node innerhalb des
+/// Fangradius neben einer fremden Verbindung (und nicht auf einem anderen
+/// Node), rastet er auf das Segment ein und die Verbindung wird geteilt:
+/// Start→Node→Ende mit gleicher Richtung und Prioritaet. Eine vorhandene
+/// Gegenverbindung wird ebenso geteilt. Kein eigener Undo-Snapshot — der
+/// Snapshot vom Beginn des Verschiebens deckt Drag und Einfuegen gemeinsam ab.
+pub fn insert_moved_node_into_connection(state: &mut AppState) {
+    if state.selection.selected_node_ids.len() != 1 {
+        return;
+    }
+    let Some(node_id) = state.selection.selected_node_ids.iter().next().copied() else {
+        return;
+    };
+    if state.locked_node_ids.contains(&node_id) {
+        return;
+    }
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let Some(drop_pos) = road_map.node_position(node_id) else {
+        return;
+    };
+
+    // Ablage auf einem anderen Node ist kein Einfuegen in eine Verbindung
+    let hitbox = state.options.hitbox_radius();
+    if road_map
+        .nodes_within_radius(drop_pos, hitbox)
+        .iter()
+        .any(|hit| hit.node_id != node_id)
+    {
+        return;
+    }
+
+    let Some((target, snap_pos)) =
+        road_map.nearest_connection_not_touching(drop_pos, state.options.snap_radius(), node_id)
+    else {
+        return;
+    };
+    let (start_id, end_id) = (target.start_id, target.end_id);
+    if state.locked_node_ids.contains(&start_id) || state.locked_node_ids.contains(&end_id) {
+        return;
+    }
+    // Bereits mit einem Endpunkt verbunden → Einfuegen wuerde Verbindungen doppeln
+    if road_map
+        .neighbors(node_id)
+        .iter()
+        .any(|&(nb, _)| nb == start_id || nb == end_id)
+    {
+        return;
+    }
+    let targets: Vec<Connection> = road_map
+        .find_connections_between(start_id, end_id)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    road_map.update_node_position(node_id, snap_pos);
+    for conn in &targets {
+        road_map.remove_connection(conn.start_id, conn.end_id);
+        let (Some(from_pos), Some(to_pos)) = (
+            road_map.node_position(conn.start_id),
+            road_map.node_position(conn.end_id),
+        ) else {
+            continue;
+        };
+        road_map.add_connection(Connection::new(
+            conn.start_id,
+            node_id,
+            conn.direction,
+            conn.priority,
+            from_pos,
+            snap_pos,
+        ));
+        road_map.add_connection(Connection::new(
+            node_id,
+            conn.end_id,
+            conn.direction,
+            conn.priority,
+            snap_pos,
+            to_pos,
+        ));
+    }
+    road_map.recalculate_node_flags(&[start_id, node_id, end_id]);
+    road_map.ensure_spatial_index();
+
+    // Segment-Registry: Records mit betroffenen Nodes invalidieren
+    let invalidated = state
+        .group_registry
+        .invalidate_by_node_ids(&[start_id, node_id, end_id]);
+    state.tool_edit_store.remove_many(invalidated);
+
+    let message = format!(
+        "Node {} in Verbindung {}→{} eingefuegt",
+        node_id, start_id, end_id
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+
+    /// Hauptstrasse 1↔2 (Hin- und Rueckweg, y=0) und ein loser Node 3 knapp daneben.
+    fn main_road_state(loose_pos: Vec2) -> AppState {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(20.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(3, loose_pos, NodeFlag::Regular));
+        for (start, end) in [(1, 2), (2, 1)] {
+            let start_pos = map.node_position(start).expect("Start vorhanden");
+            let end_pos = map.node_position(end).expect("Ende vorhanden");
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                ConnectionPriority::SubPriority,
+                start_pos,
+                end_pos,
+            ));
+        }
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().insert(3);
+        state
+    }
+
+    fn map(state: &AppState) -> &RoadMap {
+        state.road_map.as_deref().expect("Karte erwartet")
+    }
+
+    #[test]
+    fn dropped_node_near_connection_splits_both_directions() {
+        let mut state = main_road_state(Vec2::new(8.0, 0.5));
+
+        insert_moved_node_into_connection(&mut state);
+
+        let road_map = map(&state);
+        let snapped = road_map.node_position(3).expect("Node 3 vorhanden");
+        assert!(snapped.distance(Vec2::new(8.0, 0.0)) < 1.0e-4);
+        assert!(!road_map.has_connection(1, 2));
+        assert!(!road_map.has_connection(2, 1));
+        for (start, end) in [(1, 3), (3, 2), (2, 3), (3, 1)] {
+            assert_eq!(
+                road_map.find_connection(start, end).map(|c| c.priority),
+                Some(ConnectionPriority::SubPriority),
+                "Verbindung {start}→{end} erwartet"
+            );
+        }
+    }
+
+    #[test]
+    fn drop_far_away_or_on_node_keeps_connection() {
+        let mut state = main_road_state(Vec2::new(8.0, 15.0));
+        insert_moved_node_into_connection(&mut state);
+        assert!(map(&state).has_connection(1, 2));
+
+        let mut state = main_road_state(Vec2::new(0.3, 0.3));
+        insert_moved_node_into_connection(&mut state);
+        assert!(map(&state).has_connection(1, 2));
+        assert_eq!(map(&state).connection_count(), 2);
+    }
+}
@@ -0,0 +1,255 @@
+//! Use-Case: Zwei selektierte Ketten per Catmull-Rom-Uebergang verbinden.
+//!
+//! Die Geometrie stammt aus `shared::spline_geometry` (wie beim Spline-Tool):
+//! Die Endtangenten beider Ketten werden als Phantom-Punkte uebergeben, sodass der Uebergang tangential aus
+//! der einen Kette heraus- und in die andere hineinlaeuft.
+
+use super::apply_tool_result::apply_tool_result;
+use crate::app::tools::ToolResult;
+use crate::app::AppState;
+use crate::core::{NodeFlag, RoadMap};
+use crate::shared::spline_geometry::{
+    catmull_rom_segments_into, phantom_from_tangent, polyline_length, resample_by_distance,
+};
+use glam::Vec2;
+
+/// Catmull-Rom-Samples pro Segment fuer den Uebergang.
+const SAMPLES_PER_SEGMENT: usize = 16;
+
+/// Untergrenze fuer den Node-Abstand im Uebergang (Meter).
+const MIN_BLEND_SPACING: f32 = 0.5;
+
+/// Kettenende mit seinem Nachbarn innerhalb der Kette.
+#[derive(Debug, Clone, Copy)]
+struct ChainEnd {
+    node_id: u64,
+    position: Vec2,
+    inner_id: u64,
+    inner_position: Vec2,
+    /// `true`, wenn die Kette an diesem Ende (in Verbindungsrichtung) herauslaeuft.
+    is_outflow: bool,
+}
+
+impl ChainEnd {
+    /// Winkel der Kette in Richtung dieses Endes (vom inneren Nachbarn nach aussen).
+    fn outward_angle(&self) -> f32 {
+        (self.position - self.inner_position).to_angle()
+    }
+}
+
+/// Liefert beide Enden einer geordneten Kette (erstes Ende = Kettenanfang).
+fn chain_ends(road_map: &RoadMap, chain: &[u64]) -> Option<[ChainEnd; 2]> {
+    let end = |index: usize, inner: usize, is_outflow: bool| {
+        Some(ChainEnd {
+            node_id: chain[index],
+            position: road_map.node_position(chain[index])?,
+            inner_id: chain[inner],
+            inner_position: road_map.node_position(chain[inner])?,
+            is_outflow,
+        })
+    };
+    let last = chain.len().checked_sub(1)?;
+    Some([end(0, 1, false)?, end(last, last - 1, true)?])
+}
+
+/// Mittlerer Node-Abstand einer geordneten Kette.
+fn average_spacing(road_map: &RoadMap, chain: &[u64]) -> Option<f32> {
+    let positions: Vec<Vec2> = chain
+        .iter()
+        .map(|&id| road_map.node_position(id))
+        .collect::<Option<_>>()?;
+    Some(polyline_length(&positions) / (positions.len() - 1) as f32)
+}
+
+/// Verbindet genau zwei selektierte Ketten mit einem Catmull-Rom-Uebergang
+/// zwischen dem naechstgelegenen Endpunkt-Paar (mit Undo-Snapshot).
+///
+/// Der Uebergang folgt den Endtangenten beider Ketten, uebernimmt Richtung und
+/// Prioritaet der Startkette und verwendet deren mittleren Node-Abstand.
+/// Laeuft eine Kette an ihrem gewaehlten Ende heraus, beginnt der Uebergang
+/// dort. Danach sind die neuen Uebergangs-Nodes selektiert.
+pub fn join_selected_chains(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+
+    let Some([chain_a, chain_b]) = road_map.two_disjoint_chains(&state.selection.selected_node_ids)
+    else {
+        state.ui.status_message =
+            Some("Verbinden benoetigt genau zwei Ketten aus je mind. 2 Nodes".to_string());
+        return;
+    };
+    let (Some(ends_a), Some(ends_b)) = (
+        chain_ends(road_map, &chain_a),
+        chain_ends(road_map, &chain_b),
+    ) else {
+        return;
+    };
+
+    // Naechstgelegenes Endpunkt-Paar
+    let Some((end_a, end_b)) = ends_a
+        .iter()
+        .flat_map(|a| ends_b.iter().map(move |b| (*a, *b)))
+        .min_by(|(a1, b1), (a2, b2)| {
+            a1.position
+                .distance_squared(b1.position)
+                .total_cmp(&a2.position.distance_squared(b2.position))
+        })
+    else {
+        return;
+    };
+    let (start, end, start_chain) = if end_a.is_outflow || !end_b.is_outflow {
+        (end_a, end_b, &chain_a)
+    } else {
+        (end_b, end_a, &chain_b)
+    };
+
+    let gap = start.position.distance(end.position);
+    if gap < f32::EPSILON {
+        state.ui.status_message = Some("Kettenenden liegen aufeinander".to_string());
+        return;
+    }
+
+    let (direction, priority) = road_map
+        .find_connection(start.inner_id, start.node_id)
+        .or_else(|| road_map.find_connection(start.node_id, start.inner_id))
+        .map(|conn| (conn.direction, conn.priority))
+        .unwrap_or((
+            state.editor.default_direction,
+            state.editor.default_priority,
+        ));
+
+    // Endtangenten als Phantom-Punkte: hinter dem Start bzw. hinter dem Ende,
+    // jeweils auf der Seite der eigenen Kette.
+    let start_phantom = phantom_from_tangent(start.position, start.outward_angle(), end.position);
+    let end_phantom = phantom_from_tangent(end.position, end.outward_angle(), start.position);
+    let control = [start_phantom, start.position, end.position, end_phantom];
+    let mut dense = Vec::with_capacity(SAMPLES_PER_SEGMENT + 1);
+    catmull_rom_segments_into(&control, SAMPLES_PER_SEGMENT, None, None, 1..2, &mut dense);
+    dense.push(end.position);
+
+    let spacing = average_spacing(road_map, start_chain)
+        .unwrap_or(MIN_BLEND_SPACING)
+        .min(polyline_length(&dense) / 2.0)
+        .max(MIN_BLEND_SPACING);
+    let positions = resample_by_distance(&dense, spacing);
+    if positions.len() < 3 {
+        state.ui.status_message =
+            Some("Kettenenden liegen zu nah fuer einen Uebergang".to_string());
+        return;
+    }
+
+    // Innere Punkte werden neue Nodes; die Kettenenden bleiben bestehen.
+    let inner = &positions[1..positions.len() - 1];
+    let last = inner.len() - 1;
+    let result = ToolResult {
+        new_nodes: inner.iter().map(|&pos| (pos, NodeFlag::Regular)).collect(),
+        internal_connections: (0..last)
+            .map(|index| (index, index + 1, direction, priority))
+            .collect(),
+        external_connections: vec![
+            (0, start.node_id, true, direction, priority),
+            (last, end.node_id, false, direction, priority),
+        ],
+        markers: Vec::new(),
+        nodes_to_remove: Vec::new(),
+        source_group_node_ids: Vec::new(),
+    };
+    let new_ids = apply_tool_result(state, result);
+
+    let message = format!(
+        "Ketten verbunden: {} Node(s) zwischen {} und {} eingefuegt",
+        new_ids.len(),
+        start.node_id,
+        end.node_id
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+    use std::sync::Arc;
+
+    fn add_chain(map: &mut RoadMap, nodes: &[(u64, Vec2)]) {
+        for &(id, pos) in nodes {
+            map.add_node(MapNode::new(id, pos, NodeFlag::Regular));
+        }
+        for pair in nodes.windows(2) {
+            let ((start, start_pos), (end, end_pos)) = (pair[0], pair[1]);
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                ConnectionPriority::SubPriority,
+                start_pos,
+                end_pos,
+            ));
+        }
+    }
+
+    /// Kette 1→2→3 entlang X bis (20,0), Kette 10→11→12 entlang Y ab (40,20).
+    fn two_chains_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        add_chain(
+            &mut map,
+            &[
+                (1, Vec2::new(0.0, 0.0)),
+                (2, Vec2::new(10.0, 0.0)),
+                (3, Vec2::new(20.0, 0.0)),
+            ],
+        );
+        add_chain(
+            &mut map,
+            &[
+                (10, Vec2::new(40.0, 20.0)),
+                (11, Vec2::new(40.0, 30.0)),
+                (12, Vec2::new(40.0, 40.0)),
+            ],
+        );
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3, 10, 11, 12]);
+        state
+    }
+
+    #[test]
+    fn join_blends_nearest_endpoints_along_end_tangents() {
+        let mut state = two_chains_state();
+
+        join_selected_chains(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        let new_ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert!(!new_ids.is_empty());
+        assert!(state.can_undo());
+
+        let first = new_ids[0];
+        let last = *new_ids.last().expect("Uebergangs-Node erwartet");
+        let first_conn = road_map.find_connection(3, first).expect("3 → Uebergang");
+        assert_eq!(first_conn.direction, ConnectionDirection::Regular);
+        assert_eq!(first_conn.priority, ConnectionPriority::SubPriority);
+        assert!(road_map.has_connection(last, 10));
+
+        // Tangential: erster Node laeuft in +X weiter, letzter Node kommt von -Y
+        let first_pos = road_map.node_position(first).expect("Position erwartet");
+        let last_pos = road_map.node_position(last).expect("Position erwartet");
+        assert!(first_pos.x > 20.0 && first_pos.y.abs() < first_pos.x - 20.0);
+        assert!(last_pos.y < 20.0 && (40.0 - last_pos.x) < 20.0 - last_pos.y);
+    }
+
+    #[test]
+    fn join_requires_exactly_two_chains() {
+        let mut state = two_chains_state();
+        state.selection.ids_mut().retain(|&id| id < 10);
+
+        join_selected_chains(&mut state);
+
+        assert!(!state.can_undo());
+        assert!(state.ui.status_message.is_some());
+    }
+}
@@ -0,0 +1,236 @@
+//! Use-Case: Verbindungen entlang einer gezogenen Linie schneiden (Messer-Werkzeug).
+
+use crate::app::AppState;
+use crate::core::{Connection, MapNode, NodeFlag};
+use glam::Vec2;
+use std::sync::Arc;
+
+/// Schneidet alle Verbindungen, die die Linie `from`→`to` kreuzen.
+///
+/// Mit `split` wird an jedem Schnittpunkt ein neuer Node eingefuegt und die
+/// Verbindung in zwei Teile mit gleicher Richtung und Prioritaet zerlegt;
+/// Hin- und Rueckverbindung zwischen denselben Nodes teilen sich den neuen
+/// Node. Ohne `split` werden die gekreuzten Verbindungen entfernt.
+/// Verbindungen an gesperrten Nodes bleiben unveraendert. Alles erfolgt in
+/// einem Undo-Schritt; neu erzeugte Nodes werden selektiert.
+pub fn cut_connections_along_line(state: &mut AppState, from: Vec2, to: Vec2, split: bool) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    if from.distance_squared(to) < f32::EPSILON {
+        return;
+    }
+
+    let cuts: Vec<(Connection, Vec2)> = road_map
+        .connections_crossing_segment(from, to)
+        .into_iter()
+        .filter(|(conn, _)| {
+            !state.locked_node_ids.contains(&conn.start_id)
+                && !state.locked_node_ids.contains(&conn.end_id)
+        })
+        .map(|(conn, point)| (conn.clone(), point))
+        .collect();
+    if cuts.is_empty() {
+        state.ui.status_message = Some("Messer: keine Verbindung geschnitten".to_string());
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+
+    let mut touched_ids: Vec<u64> = Vec::with_capacity(cuts.len() * 3);
+    // Pro ungeordnetem Node-Paar genau ein Schnitt-Node
+    let mut split_nodes: Vec<((u64, u64), u64)> = Vec::new();
+    for (conn, point) in &cuts {
+        road_map.remove_connection(conn.start_id, conn.end_id);
+        touched_ids.extend([conn.start_id, conn.end_id]);
+        if !split {
+            continue;
+        }
+
+        let pair = (
+            conn.start_id.min(conn.end_id),
+            conn.start_id.max(conn.end_id),
+        );
+        let (mid_id, mid_pos) = match split_nodes.iter().find(|(key, _)| *key == pair) {
+            Some(&(_, id)) => (id, road_map.node_position(id).unwrap_or(*point)),
+            None => {
+                let id = road_map.next_node_id();
+                road_map.add_node(MapNode::new(id, *point, NodeFlag::Regular));
+                if let (Some(start_height), Some(end_height), Some(start_pos), Some(end_pos)) = (
+                    road_map.node_height_override(conn.start_id),
+                    road_map.node_height_override(conn.end_id),
+                    road_map.node_position(conn.start_id),
+                    road_map.node_position(conn.end_id),
+                ) {
+                    let length = start_pos.distance(end_pos).max(f32::EPSILON);
+                    let t = start_pos.distance(*point) / length;
+                    road_map.set_node_height_override(
+                        id,
+                        start_height + (end_height - start_height) * t,
+                    );
+                }
+                split_nodes.push((pair, id));
+                touched_ids.push(id);
+                (id, *point)
+            }
+        };
+
+        if let Some(start_pos) = road_map.node_position(conn.start_id) {
+            road_map.add_connection(Connection::new(
+                conn.start_id,
+                mid_id,
+                conn.direction,
+                conn.priority,
+                start_pos,
+                mid_pos,
+            ));
+        }
+        if let Some(end_pos) = road_map.node_position(conn.end_id) {
+            road_map.add_connection(Connection::new(
+                mid_id,
+                conn.end_id,
+                conn.direction,
+                conn.priority,
+                mid_pos,
+                end_pos,
+            ));
+        }
+    }
+    road_map.recalculate_node_flags(&touched_ids);
+    road_map.ensure_spatial_index();
+
+    // Segment-Registry: Records mit betroffenen Endpunkten invalidieren
+    let endpoint_ids: Vec<u64> = cuts
+        .iter()
+        .flat_map(|(conn, _)| [conn.start_id, conn.end_id])
+        .collect();
+    let invalidated = state.group_registry.invalidate_by_node_ids(&endpoint_ids);
+    state.tool_edit_store.remove_many(invalidated);
+
+    let message = if split {
+        state.selection.ids_mut().clear();
+        state
+            .selection
+            .ids_mut()
+            .extend(split_nodes.iter().map(|&(_, id)| id));
+        state.selection.selection_anchor_node_id = split_nodes.first().map(|&(_, id)| id);
+        format!(
+            "Messer: {} Verbindungen an {} Schnittpunkten geteilt",
+            cuts.len(),
+            split_nodes.len()
+        )
+    } else {
+        format!("Messer: {} Verbindungen entfernt", cuts.len())
+    };
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ConnectionDirection, ConnectionPriority, RoadMap};
+
+    /// Zwei parallele horizontale Strassen: 1→2 (y=0) und 3↔4 als Hin-/Rueckweg (y=10).
+    fn parallel_roads_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(20.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(3, Vec2::new(0.0, 10.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(4, Vec2::new(20.0, 10.0), NodeFlag::Regular));
+        for (start, end, priority) in [
+            (1, 2, ConnectionPriority::SubPriority),
+            (3, 4, ConnectionPriority::Regular),
+            (4, 3, ConnectionPriority::Regular),
+        ] {
+            let start_pos = map.node_position(start).expect("Start vorhanden");
+            let end_pos = map.node_position(end).expect("Ende vorhanden");
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                priority,
+                start_pos,
+                end_pos,
+            ));
+        }
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state
+    }
+
+    fn map(state: &AppState) -> &RoadMap {
+        state.road_map.as_deref().expect("Karte erwartet")
+    }
+
+    #[test]
+    fn knife_removes_crossed_connections() {
+        let mut state = parallel_roads_state();
+
+        cut_connections_along_line(
+            &mut state,
+            Vec2::new(5.0, -5.0),
+            Vec2::new(5.0, 15.0),
+            false,
+        );
+
+        let road_map = map(&state);
+        assert_eq!(road_map.connection_count(), 0);
+        assert_eq!(road_map.node_count(), 4);
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn knife_splits_crossed_connections_sharing_one_node_per_pair() {
+        let mut state = parallel_roads_state();
+
+        cut_connections_along_line(&mut state, Vec2::new(5.0, -5.0), Vec2::new(5.0, 15.0), true);
+
+        let road_map = map(&state);
+        let new_ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert_eq!(new_ids.len(), 2);
+        assert_eq!(road_map.node_count(), 6);
+        assert_eq!(road_map.connection_count(), 6);
+
+        let lower = *new_ids
+            .iter()
+            .find(|&&id| {
+                road_map
+                    .node_position(id)
+                    .is_some_and(|p| p.y.abs() < 1.0e-3)
+            })
+            .expect("Schnitt-Node auf y=0");
+        assert_eq!(
+            road_map.find_connection(1, lower).map(|c| c.priority),
+            Some(ConnectionPriority::SubPriority)
+        );
+        assert!(road_map.has_connection(lower, 2));
+        assert!(!road_map.has_connection(1, 2));
+    }
+
+    #[test]
+    fn knife_skips_locked_nodes_and_misses() {
+        let mut state = parallel_roads_state();
+        state.locked_node_ids.insert(1);
+
+        cut_connections_along_line(&mut state, Vec2::new(5.0, -5.0), Vec2::new(5.0, 5.0), false);
+        assert!(map(&state).has_connection(1, 2));
+        assert!(!state.can_undo());
+
+        cut_connections_along_line(
+            &mut state,
+            Vec2::new(30.0, -5.0),
+            Vec2::new(30.0, 15.0),
+            true,
+        );
+        assert_eq!(map(&state).node_count(), 4);
+    }
+}
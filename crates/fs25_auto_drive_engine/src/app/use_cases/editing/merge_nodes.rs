@@ -0,0 +1,264 @@
+//! Use-Case: Selektierte Nodes zu einem Node im Schwerpunkt zusammenfuehren.
+//!
+//! Anders als die Duplikat-Bereinigung beim Laden arbeitet der Merge auf einer
+//! beliebigen Selektion: Ein Node bleibt erhalten, wandert in den Schwerpunkt
+//! und uebernimmt alle externen Verbindungen der uebrigen Nodes.
+
+use super::delete_nodes::{merge_directions, merge_priorities};
+use super::delete_nodes_by_ids::delete_nodes_internal;
+use crate::app::AppState;
+use crate::core::{Connection, ConnectionDirection, ConnectionPriority};
+use glam::Vec2;
+use indexmap::IndexMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Mindestanzahl selektierter Nodes fuer einen Merge.
+pub const MERGE_NODES_MIN_COUNT: usize = 2;
+
+/// Fuehrt alle selektierten Nodes im Schwerpunkt zusammen (mit Undo-Snapshot).
+///
+/// Es bleibt der selektierte Node mit Marker erhalten (der Marker bleibt so
+/// unveraendert), sonst der erste selektierte Node. Verbindungen der uebrigen Nodes werden auf ihn umgehaengt;
+/// Verbindungen innerhalb der Selektion entfallen, doppelte Verbindungen
+/// werden mit der hoeherwertigen Richtung und Prioritaet vereinigt. Tragen
+/// mehrere Nodes einen Marker oder ist ein Node gesperrt, wird nichts veraendert.
+pub fn merge_selected_nodes(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let selected: Vec<u64> = state
+        .selection
+        .selected_node_ids
+        .iter()
+        .copied()
+        .filter(|&id| road_map.contains_node(id))
+        .collect();
+    if selected.len() < MERGE_NODES_MIN_COUNT {
+        state.ui.status_message =
+            Some("Zusammenfuehren benoetigt mind. 2 selektierte Nodes".to_string());
+        return;
+    }
+    if selected.iter().any(|id| state.locked_node_ids.contains(id)) {
+        state.ui.status_message =
+            Some("Selektion enthaelt gesperrte Nodes — nicht zusammengefuehrt".to_string());
+        return;
+    }
+    let marker_ids: Vec<u64> = selected
+        .iter()
+        .copied()
+        .filter(|&id| road_map.has_marker(id))
+        .collect();
+    if marker_ids.len() > 1 {
+        state.ui.status_message = Some(format!(
+            "{} selektierte Nodes tragen Marker — nur einer kann erhalten bleiben",
+            marker_ids.len()
+        ));
+        return;
+    }
+
+    let survivor = marker_ids.first().copied().unwrap_or(selected[0]);
+    let merged: HashSet<u64> = selected.iter().copied().collect();
+    let centroid = selected
+        .iter()
+        .filter_map(|&id| road_map.node_position(id))
+        .fold(Vec2::ZERO, |sum, pos| sum + pos)
+        / selected.len() as f32;
+
+    // Verbindungen der wegfallenden Nodes auf den Survivor umhaengen (VOR dem Loeschen)
+    let remap = |id: u64| if merged.contains(&id) { survivor } else { id };
+    let mut rewired: IndexMap<(u64, u64), (ConnectionDirection, ConnectionPriority)> =
+        IndexMap::new();
+    for &id in selected.iter().filter(|&&id| id != survivor) {
+        for &(neighbor, outgoing) in road_map.neighbors(id) {
+            let key = if outgoing {
+                (id, neighbor)
+            } else {
+                (neighbor, id)
+            };
+            let Some(conn) = road_map.find_connection(key.0, key.1) else {
+                continue;
+            };
+            let target = (remap(key.0), remap(key.1));
+            if target.0 == target.1 {
+                continue;
+            }
+            rewired
+                .entry(target)
+                .and_modify(|(direction, priority)| {
+                    *direction = merge_directions(*direction, conn.direction);
+                    *priority = merge_priorities(*priority, conn.priority);
+                })
+                .or_insert((conn.direction, conn.priority));
+        }
+    }
+    let removed: Vec<u64> = selected
+        .iter()
+        .copied()
+        .filter(|&id| id != survivor)
+        .collect();
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    // Verbindungen zwischen Survivor und den uebrigen Nodes der Selektion entfallen
+    for &id in &removed {
+        road_map.remove_connections_between(survivor, id);
+    }
+    delete_nodes_internal(road_map, &removed, true);
+    road_map.set_node_positions(&[(survivor, centroid)]);
+
+    let mut touched = vec![survivor];
+    for ((start_id, end_id), (direction, priority)) in rewired {
+        touched.push(if start_id == survivor {
+            end_id
+        } else {
+            start_id
+        });
+        if let Some(existing) = road_map.find_connection(start_id, end_id) {
+            let direction = merge_directions(existing.direction, direction);
+            let priority = merge_priorities(existing.priority, priority);
+            road_map.set_connection_direction(start_id, end_id, direction);
+            road_map.set_connection_priority(start_id, end_id, priority);
+            continue;
+        }
+        let (Some(start_pos), Some(end_pos)) = (
+            road_map.node_position(start_id),
+            road_map.node_position(end_id),
+        ) else {
+            continue;
+        };
+        road_map.add_connection(Connection::new(
+            start_id, end_id, direction, priority, start_pos, end_pos,
+        ));
+    }
+    road_map.recalculate_node_flags(&touched);
+    road_map.ensure_spatial_index();
+
+    let invalidated = state.group_registry.invalidate_by_node_ids(&selected);
+    state.tool_edit_store.remove_many(invalidated);
+    let selection = state.selection.ids_mut();
+    selection.clear();
+    selection.insert(survivor);
+    state.selection.selection_anchor_node_id = Some(survivor);
+
+    let message = format!(
+        "{} Nodes zu Node {} zusammengefuehrt",
+        selected.len(),
+        survivor
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MapMarker, MapNode, NodeFlag, RoadMap};
+
+    /// Kreuz aus drei eng beieinander liegenden Nodes 1..=3 mit externen Nachbarn 10 und 11.
+    ///
+    /// 10 → 1 (Regular), 2 → 11 (SubPriority), 3 → 11 (Regular), 1 → 2 (intern).
+    fn cluster_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        for (id, x, y) in [
+            (1, 0.0, 0.0),
+            (2, 1.0, 0.0),
+            (3, 0.5, 1.5),
+            (10, -20.0, 0.0),
+            (11, 20.0, 0.0),
+        ] {
+            map.add_node(MapNode::new(id, Vec2::new(x, y), NodeFlag::Regular));
+        }
+        for (start, end, priority) in [
+            (10, 1, ConnectionPriority::Regular),
+            (2, 11, ConnectionPriority::SubPriority),
+            (3, 11, ConnectionPriority::Regular),
+            (1, 2, ConnectionPriority::Regular),
+        ] {
+            let start_pos = map.node_position(start).unwrap();
+            let end_pos = map.node_position(end).unwrap();
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                priority,
+                start_pos,
+                end_pos,
+            ));
+        }
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3]);
+        state
+    }
+
+    #[test]
+    fn merge_moves_survivor_to_centroid_and_rewires_connections() {
+        let mut state = cluster_state();
+
+        merge_selected_nodes(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        assert_eq!(road_map.node_count(), 3);
+        assert_eq!(road_map.node_position(1), Some(Vec2::new(0.5, 0.5)));
+        assert!(road_map.has_connection(10, 1));
+        let merged = road_map
+            .find_connection(1, 11)
+            .expect("zusammengefuehrte Verbindung");
+        assert_eq!(merged.priority, ConnectionPriority::Regular);
+        assert_eq!(road_map.connection_count(), 2);
+        assert_eq!(state.selection.selected_node_ids.len(), 1);
+        assert!(state.selection.selected_node_ids.contains(&1));
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn merge_keeps_marker_node_as_survivor() {
+        let mut state = cluster_state();
+        Arc::make_mut(state.road_map.as_mut().unwrap()).add_map_marker(MapMarker::new(
+            3,
+            "Hof".to_string(),
+            "All".to_string(),
+            1,
+            false,
+        ));
+
+        merge_selected_nodes(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        assert!(road_map.contains_node(3));
+        assert!(!road_map.contains_node(1));
+        assert!(road_map.has_marker(3));
+        assert!(road_map.has_connection(10, 3));
+    }
+
+    #[test]
+    fn merge_rejects_locked_nodes_and_multiple_markers() {
+        let mut state = cluster_state();
+        state.locked_node_ids.insert(2);
+        merge_selected_nodes(&mut state);
+        assert!(!state.can_undo());
+
+        let mut state = cluster_state();
+        let road_map = Arc::make_mut(state.road_map.as_mut().unwrap());
+        for id in [1, 2] {
+            road_map.add_map_marker(MapMarker::new(
+                id,
+                format!("M{id}"),
+                "All".to_string(),
+                id as u32,
+                false,
+            ));
+        }
+        merge_selected_nodes(&mut state);
+        assert!(!state.can_undo());
+        assert_eq!(state.road_map.as_deref().map(RoadMap::node_count), Some(5));
+    }
+}
@@ -3,15 +3,32 @@
 //! Aufgeteilt nach Operation:
 //! - `add_node` — Neuen Node hinzufuegen (inkl. optionalem Connection-Split)
 //! - `delete_nodes` — Selektierte Nodes loeschen (inkl. optionalem Reconnect)
+//! - `delete_by_filter` — Nodes einer Region nach Filtern loeschen (Dialog mit Zaehlung)
 //! - `connect` — Verbindungen erstellen
 //! - `disconnect` — Verbindungen entfernen
-//! - `direction` — Verbindungsrichtung aendern
+//! - `direction` — Verbindungsrichtung aendern (auch per Wischgeste) oder einzeln invertieren
 //! - `priority` — Verbindungsprioritaet aendern
 //! - `node_flag` — Node-Flag gezielt setzen
+//! - `node_heights` — Y-Werte selektierter Nodes setzen, verschieben oder interpolieren
+//! - `project_terrain` — Y-Werte selektierter Nodes aus der Heightmap neu berechnen
+//! - `offset_path` — Selektierte Kette seitlich verschieben (mit Vorschau)
 //! - `bulk_connections` — Bulk-Aenderungen an Verbindungen
+//! - `insert_into_connection` — Gezogenen Node beim Ablegen in eine Verbindung einfuegen
+//! - `knife` — Verbindungen entlang einer Linie entfernen oder teilen (Messer)
 //! - `markers` — Map-Marker-Operationen
+//! - `roundabout` — Kreuzungs-Node in einen Kreisverkehr umwandeln
 //! - `resample_path` — Nodes-Kette per Catmull-Rom-Spline neu verteilen (Distanzen)
+//! - `resample_chain` — Kette mit neuem Node-Abstand neu aufbauen (Endpunkte bleiben angeschlossen)
+//! - `equalize_spacing` — Kettennodes entlang der bestehenden Form auf gleiche Abstaende schieben
+//! - `smooth_chain` — Innere Kettennodes per Laplace-Glaettung beruhigen (Zick-Zack entfernen)
+//! - `simplify_chain` — Kette per Douglas-Peucker ausduennen (Form bleibt erhalten)
+//! - `align_nodes` — Selektion auf einer Geraden ausrichten bzw. entlang der Endpunkt-Achse verteilen
+//! - `join_chains` — Zwei selektierte Ketten per Catmull-Rom-Uebergang verbinden
+//! - `merge_nodes` — Selektierte Nodes im Schwerpunkt zu einem Node zusammenfuehren
+//! - `reverse_chain` — Laufrichtung einer selektierten Kette komplett umkehren
 //! - `copy_paste` — Kopieren/Einfuegen von Nodes, Verbindungen und Markern
+//! - `clipboard_json` — Zwischenablage als JSON fuer die System-Zwischenablage
+//! - `generate_field_course` — Vorgewende-Ring mit Zufahrt fuer ein einzelnes Feld
 
 ///
 /// Aufgeteilt nach Operation:
@@ -21,43 +38,91 @@
 /// - `disconnect` — Verbindungen entfernen
 /// - `direction` — Verbindungsrichtung aendern
 mod add_node;
+mod align_nodes;
 mod apply_tool_result;
 mod bulk_connections;
+mod clipboard_json;
 mod connect;
 mod copy_paste;
+mod delete_by_filter;
 mod delete_nodes;
 mod delete_nodes_by_ids;
 mod direction;
 mod disconnect;
+mod equalize_spacing;
 mod export_curseplay;
+mod generate_field_course;
 mod import_curseplay;
+mod insert_into_connection;
+mod join_chains;
+mod knife;
 mod markers;
+mod merge_nodes;
 mod node_flag;
+mod node_heights;
+mod offset_path;
 mod priority;
+mod project_terrain;
+mod resample_chain;
 mod resample_path;
+mod reverse_chain;
+mod roundabout;
+mod simplify_chain;
+mod smooth_chain;
 mod trace_all_fields;
 
 pub use add_node::add_node_at_position;
 pub use add_node::AddNodeResult;
+pub use align_nodes::{
+    align_selected_nodes, distribute_selected_nodes, NodeAlignMode, DISTRIBUTE_MIN_NODE_COUNT,
+};
 pub use apply_tool_result::apply_tool_result;
 pub use apply_tool_result::apply_tool_result_no_snapshot;
 pub use bulk_connections::{
     invert_all_connections_between_selected, remove_all_connections_between_selected,
-    set_all_connections_direction_between_selected, set_all_connections_priority_between_selected,
+    reverse_path_between_selected, set_all_connections_direction_between_selected,
+    set_all_connections_priority_between_selected,
+};
+pub use clipboard_json::{
+    clipboard_from_json, clipboard_to_json, paste_from_text, CLIPBOARD_JSON_FORMAT,
+    CLIPBOARD_JSON_VERSION,
 };
 pub use connect::{add_connection, connect_tool_pick_node};
 pub use copy_paste::{
-    cancel_paste_preview, confirm_paste, copy_selected_to_clipboard, start_paste_preview,
-    update_paste_preview,
+    cancel_paste_preview, confirm_paste, copy_selected_to_clipboard, duplicate_selection,
+    rotate_paste_preview, start_paste_preview, update_paste_preview,
+    PASTE_PREVIEW_ROTATION_STEP_DEG,
 };
+pub use delete_by_filter::{delete_nodes_by_filter, open_delete_by_filter_dialog};
 pub use delete_nodes::delete_selected_nodes;
 pub use delete_nodes_by_ids::delete_nodes_by_ids;
-pub use direction::set_connection_direction;
+pub use direction::{apply_connection_swipe, invert_connection, set_connection_direction};
 pub use disconnect::remove_connection_between;
+pub use equalize_spacing::equalize_selected_chain_spacing;
 pub use export_curseplay::export_curseplay;
+pub use generate_field_course::generate_field_course;
 pub use import_curseplay::import_curseplay;
+pub use insert_into_connection::insert_moved_node_into_connection;
+pub use join_chains::join_selected_chains;
+pub use knife::cut_connections_along_line;
 pub use markers::{create_marker, open_marker_dialog, remove_marker, update_marker};
+pub use merge_nodes::{merge_selected_nodes, MERGE_NODES_MIN_COUNT};
 pub use node_flag::set_node_flag;
+pub(crate) use node_heights::current_node_height;
+pub use node_heights::{set_selected_node_heights, NodeHeightEdit};
+pub use offset_path::{
+    apply_path_offset, cancel_path_offset_preview, set_path_offset_distance,
+    start_path_offset_preview, PATH_OFFSET_MAX_DISTANCE,
+};
 pub use priority::set_connection_priority;
+pub use project_terrain::project_selection_onto_terrain;
+pub use resample_chain::{resample_selected_chain, RESAMPLE_CHAIN_MIN_SPACING};
 pub use resample_path::resample_selected_path;
+pub use reverse_chain::reverse_selected_chain;
+pub use roundabout::{convert_junction_to_roundabout, ROUNDABOUT_MIN_JUNCTION_DEGREE};
+pub use simplify_chain::{
+    simplify_chain_preview, simplify_selected_chain, SimplifyChainPreview,
+    SIMPLIFY_CHAIN_MAX_TOLERANCE,
+};
+pub use smooth_chain::{smooth_selected_chain, SMOOTH_CHAIN_MAX_ITERATIONS};
 pub use trace_all_fields::trace_all_fields;
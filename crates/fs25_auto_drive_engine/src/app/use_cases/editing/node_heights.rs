@@ -0,0 +1,246 @@
+//! Use-Case: Y-Werte (Hoehe) aller selektierten Nodes numerisch setzen.
+
+use crate::app::use_cases::heightmap::effective_height_scale;
+use crate::app::AppState;
+use crate::core::RoadMap;
+use std::sync::Arc;
+
+/// Art der Hoehen-Aenderung fuer selektierte Nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeHeightEdit {
+    /// Alle Nodes erhalten denselben absoluten Y-Wert.
+    Absolute(f32),
+    /// Der aktuelle Y-Wert jedes Nodes wird um den Betrag verschoben.
+    Offset(f32),
+    /// Innere Kettennodes werden linear zwischen den Y-Werten der Endpunkte
+    /// interpoliert (gewichtet nach Streckenlaenge).
+    InterpolateChain,
+    /// Wie `InterpolateChain`, aber als S-Kurve (kubischer Hermite-Spline mit
+    /// flachen Enden) — fuer sanfte Rampen- und Brueckenauffahrten.
+    InterpolateChainSmooth,
+}
+
+/// Aktueller Y-Wert eines Nodes: manueller Wert, sonst Heightmap, sonst 0.
+pub(crate) fn current_node_height(state: &AppState, road_map: &RoadMap, node_id: u64) -> f32 {
+    if let Some(height) = road_map.node_height_override(node_id) {
+        return height;
+    }
+    let Some(position) = road_map.node_position(node_id) else {
+        return 0.0;
+    };
+    let heightmap = state.heightmap.as_deref();
+    heightmap.map_or(0.0, |hm| {
+        hm.sample_height(
+            position.x,
+            position.y,
+            effective_height_scale(state, heightmap),
+        )
+    })
+}
+
+/// Setzt die Y-Werte aller selektierten Nodes gemaess `edit`.
+///
+/// Gesperrte Nodes (`AppState::locked_node_ids`) werden uebersprungen. Fuer
+/// `InterpolateChain`/`InterpolateChainSmooth` muss die Selektion eine lineare Kette bilden; die
+/// Endpunkte behalten ihren Y-Wert. Vor der Mutation wird ein Undo-Snapshot
+/// erstellt.
+pub fn set_selected_node_heights(state: &mut AppState, edit: NodeHeightEdit) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    if state.selection.selected_node_ids.is_empty() {
+        return;
+    }
+
+    let targets: Vec<(u64, f32)> = match edit {
+        NodeHeightEdit::Absolute(height) => state
+            .selection
+            .selected_node_ids
+            .iter()
+            .map(|&id| (id, height))
+            .collect(),
+        NodeHeightEdit::Offset(delta) => state
+            .selection
+            .selected_node_ids
+            .iter()
+            .map(|&id| (id, current_node_height(state, road_map, id) + delta))
+            .collect(),
+        NodeHeightEdit::InterpolateChain | NodeHeightEdit::InterpolateChainSmooth => {
+            let Some(chain) = road_map.ordered_chain_nodes(&state.selection.selected_node_ids)
+            else {
+                state.ui.status_message =
+                    Some("Interpolation benoetigt eine zusammenhaengende Kette".to_string());
+                return;
+            };
+            let smooth = edit == NodeHeightEdit::InterpolateChainSmooth;
+            interpolate_chain_heights(state, road_map, &chain, smooth)
+        }
+    };
+
+    let targets: Vec<(u64, f32)> = targets
+        .into_iter()
+        .filter(|(id, _)| road_map.contains_node(*id) && !state.locked_node_ids.contains(id))
+        .collect();
+    if targets.is_empty() {
+        state.ui.status_message =
+            Some("Keine aenderbaren Nodes fuer Y-Werte selektiert".to_string());
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    for &(id, height) in &targets {
+        road_map.set_node_height_override(id, height);
+    }
+
+    let message = format!("Y-Wert fuer {} Node(s) gesetzt", targets.len());
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+/// Berechnet interpolierte Y-Werte fuer die inneren Nodes einer Kette.
+///
+/// Der Anteil `t` ergibt sich aus der Streckenlaenge; mit `smooth` wird er
+/// ueber `3t² - 2t³` geglaettet, sodass die Steigung an beiden Endpunkten 0 ist.
+fn interpolate_chain_heights(
+    state: &AppState,
+    road_map: &RoadMap,
+    chain: &[u64],
+    smooth: bool,
+) -> Vec<(u64, f32)> {
+    let (Some(&first), Some(&last)) = (chain.first(), chain.last()) else {
+        return Vec::new();
+    };
+    let start_height = current_node_height(state, road_map, first);
+    let end_height = current_node_height(state, road_map, last);
+
+    let positions: Vec<glam::Vec2> = chain
+        .iter()
+        .filter_map(|&id| road_map.node_position(id))
+        .collect();
+    if positions.len() != chain.len() {
+        return Vec::new();
+    }
+
+    let mut cumulative = Vec::with_capacity(positions.len());
+    let mut length = 0.0;
+    for (index, position) in positions.iter().enumerate() {
+        if index > 0 {
+            length += position.distance(positions[index - 1]);
+        }
+        cumulative.push(length);
+    }
+
+    chain
+        .iter()
+        .zip(&cumulative)
+        .skip(1)
+        .take(chain.len().saturating_sub(2))
+        .map(|(&id, &distance)| {
+            let mut t = if length > 0.0 { distance / length } else { 0.0 };
+            if smooth {
+                t = t * t * (3.0 - 2.0 * t);
+            }
+            (id, start_height + (end_height - start_height) * t)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+    use glam::Vec2;
+
+    /// Kette 1→2→3→4 mit ungleichmaessigen Abstaenden (0, 10, 30, 40).
+    fn chain_state() -> AppState {
+        let xs = [(1_u64, 0.0), (2, 10.0), (3, 30.0), (4, 40.0)];
+        let mut map = RoadMap::new(3);
+        for (id, x) in xs {
+            map.add_node(MapNode::new(id, Vec2::new(x, 0.0), NodeFlag::Regular));
+        }
+        for pair in xs.windows(2) {
+            let ((start, sx), (end, ex)) = (pair[0], pair[1]);
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                Vec2::new(sx, 0.0),
+                Vec2::new(ex, 0.0),
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3, 4]);
+        state
+    }
+
+    fn height(state: &AppState, id: u64) -> Option<f32> {
+        state
+            .road_map
+            .as_deref()
+            .and_then(|map| map.node_height_override(id))
+    }
+
+    #[test]
+    fn absolute_and_offset_update_all_selected_nodes_undoably() {
+        let mut state = chain_state();
+
+        set_selected_node_heights(&mut state, NodeHeightEdit::Absolute(10.0));
+        set_selected_node_heights(&mut state, NodeHeightEdit::Offset(-2.5));
+
+        assert_eq!(height(&state, 1), Some(7.5));
+        assert_eq!(height(&state, 4), Some(7.5));
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn interpolate_chain_weights_by_distance_between_endpoints() {
+        let mut state = chain_state();
+        {
+            let map = Arc::make_mut(state.road_map.as_mut().expect("Karte erwartet"));
+            map.set_node_height_override(1, 0.0);
+            map.set_node_height_override(4, 40.0);
+        }
+
+        set_selected_node_heights(&mut state, NodeHeightEdit::InterpolateChain);
+
+        assert_eq!(height(&state, 2), Some(10.0));
+        assert_eq!(height(&state, 3), Some(30.0));
+        assert_eq!(height(&state, 4), Some(40.0));
+    }
+
+    #[test]
+    fn smooth_interpolation_flattens_towards_endpoints() {
+        let mut state = chain_state();
+        {
+            let map = Arc::make_mut(state.road_map.as_mut().expect("Karte erwartet"));
+            map.set_node_height_override(1, 0.0);
+            map.set_node_height_override(4, 40.0);
+        }
+
+        set_selected_node_heights(&mut state, NodeHeightEdit::InterpolateChainSmooth);
+
+        assert_eq!(height(&state, 2), Some(6.25));
+        assert_eq!(height(&state, 3), Some(33.75));
+        assert_eq!(height(&state, 4), Some(40.0));
+    }
+
+    #[test]
+    fn locked_nodes_keep_their_height() {
+        let mut state = chain_state();
+        state.locked_node_ids.insert(2);
+
+        set_selected_node_heights(&mut state, NodeHeightEdit::Absolute(5.0));
+
+        assert_eq!(height(&state, 1), Some(5.0));
+        assert_eq!(height(&state, 2), None);
+    }
+}
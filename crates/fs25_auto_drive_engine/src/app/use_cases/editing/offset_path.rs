@@ -0,0 +1,220 @@
+//! Use-Case: Selektierte Kette seitlich (senkrecht zur Fahrtrichtung) verschieben.
+//!
+//! Ablauf: Vorschau starten → Versatz anpassen (Overlay zeigt die verschobene
+//! Kette) → uebernehmen oder abbrechen.
+
+use crate::app::AppState;
+use crate::core::RoadMap;
+use crate::shared::geometry::parallel_offset;
+use glam::Vec2;
+use std::sync::Arc;
+
+/// Maximaler Betrag des seitlichen Versatzes in Metern.
+pub const PATH_OFFSET_MAX_DISTANCE: f32 = 100.0;
+
+/// Berechnet die verschobenen Positionen einer geordneten Kette.
+///
+/// `distance > 0` verschiebt nach links in Kettenrichtung, `distance < 0` nach rechts.
+fn offset_chain_positions(
+    road_map: &RoadMap,
+    chain_ids: &[u64],
+    distance: f32,
+) -> Option<Vec<Vec2>> {
+    let positions: Vec<Vec2> = chain_ids
+        .iter()
+        .map(|&id| road_map.node_position(id))
+        .collect::<Option<_>>()?;
+    if positions.len() < 2 {
+        return None;
+    }
+    Some(parallel_offset(&positions, distance))
+}
+
+fn refresh_preview(state: &mut AppState) {
+    let preview = state.road_map.as_deref().and_then(|road_map| {
+        offset_chain_positions(
+            road_map,
+            &state.ui.path_offset.chain_ids,
+            state.ui.path_offset.distance,
+        )
+    });
+    state.ui.path_offset.preview_positions = preview.unwrap_or_default();
+}
+
+/// Startet die Versatz-Vorschau fuer die aktuell selektierte Kette.
+///
+/// Die Selektion muss eine zusammenhaengende lineare Kette bilden; die
+/// Reihenfolge wird beim Start erfasst und bis zum Uebernehmen beibehalten.
+pub fn start_path_offset_preview(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let Some(chain_ids) = road_map.ordered_chain_nodes(&state.selection.selected_node_ids) else {
+        state.ui.status_message =
+            Some("Seitlicher Versatz benoetigt eine zusammenhaengende Kette".to_string());
+        return;
+    };
+
+    state.ui.path_offset.chain_ids = chain_ids;
+    state.ui.path_offset.active = true;
+    refresh_preview(state);
+}
+
+/// Setzt den vorzeichenbehafteten Versatz und aktualisiert eine laufende Vorschau.
+pub fn set_path_offset_distance(state: &mut AppState, distance: f32) {
+    state.ui.path_offset.distance =
+        distance.clamp(-PATH_OFFSET_MAX_DISTANCE, PATH_OFFSET_MAX_DISTANCE);
+    if state.ui.path_offset.active {
+        refresh_preview(state);
+    }
+}
+
+/// Verwirft die Versatz-Vorschau ohne Aenderung an der Karte.
+pub fn cancel_path_offset_preview(state: &mut AppState) {
+    state.ui.path_offset.deactivate();
+}
+
+/// Verschiebt die erfasste Kette um den eingestellten Versatz (mit Undo-Snapshot).
+///
+/// Enthaelt die Kette gesperrte Nodes, bleibt die Vorschau aktiv und es wird
+/// nichts veraendert.
+pub fn apply_path_offset(state: &mut AppState) {
+    if !state.ui.path_offset.active {
+        return;
+    }
+    let chain_ids = state.ui.path_offset.chain_ids.clone();
+    if chain_ids
+        .iter()
+        .any(|id| state.locked_node_ids.contains(id))
+    {
+        state.ui.status_message =
+            Some("Kette enthaelt gesperrte Nodes — Versatz nicht moeglich".to_string());
+        return;
+    }
+
+    let distance = state.ui.path_offset.distance;
+    let Some(new_positions) = state
+        .road_map
+        .as_deref()
+        .and_then(|road_map| offset_chain_positions(road_map, &chain_ids, distance))
+    else {
+        state.ui.path_offset.deactivate();
+        return;
+    };
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let updates: Vec<(u64, Vec2)> = chain_ids.iter().copied().zip(new_positions).collect();
+    Arc::make_mut(road_map_arc).set_node_positions(&updates);
+    state.ui.path_offset.deactivate();
+
+    let message = format!(
+        "{} Node(s) um {:.2} m seitlich versetzt",
+        updates.len(),
+        distance
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+
+    /// Gerade Kette 1→2→3 entlang der X-Achse, alle Nodes selektiert.
+    fn straight_chain_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        for id in 1..=3_u64 {
+            map.add_node(MapNode::new(
+                id,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        for (start, end) in [(1_u64, 2_u64), (2, 3)] {
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                Vec2::new(start as f32 * 10.0, 0.0),
+                Vec2::new(end as f32 * 10.0, 0.0),
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3]);
+        state
+    }
+
+    fn position(state: &AppState, id: u64) -> Vec2 {
+        state
+            .road_map
+            .as_deref()
+            .and_then(|map| map.node_position(id))
+            .expect("Node erwartet")
+    }
+
+    #[test]
+    fn preview_follows_distance_without_touching_the_map() {
+        let mut state = straight_chain_state();
+
+        start_path_offset_preview(&mut state);
+        set_path_offset_distance(&mut state, -4.0);
+
+        assert!(state.ui.path_offset.active);
+        assert_eq!(
+            state.ui.path_offset.preview_positions,
+            vec![
+                Vec2::new(10.0, -4.0),
+                Vec2::new(20.0, -4.0),
+                Vec2::new(30.0, -4.0)
+            ]
+        );
+        assert_eq!(position(&state, 2), Vec2::new(20.0, 0.0));
+        assert!(!state.can_undo());
+    }
+
+    #[test]
+    fn apply_moves_chain_sideways_and_ends_preview() {
+        let mut state = straight_chain_state();
+
+        start_path_offset_preview(&mut state);
+        set_path_offset_distance(&mut state, 5.0);
+        apply_path_offset(&mut state);
+
+        assert_eq!(position(&state, 1), Vec2::new(10.0, 5.0));
+        assert_eq!(position(&state, 3), Vec2::new(30.0, 5.0));
+        assert!(!state.ui.path_offset.active);
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn apply_refuses_chain_with_locked_nodes() {
+        let mut state = straight_chain_state();
+        state.locked_node_ids.insert(2);
+
+        start_path_offset_preview(&mut state);
+        apply_path_offset(&mut state);
+
+        assert_eq!(position(&state, 1), Vec2::new(10.0, 0.0));
+        assert!(state.ui.path_offset.active);
+    }
+
+    #[test]
+    fn start_requires_connected_chain() {
+        let mut state = straight_chain_state();
+        state.selection.ids_mut().shift_remove(&2);
+
+        start_path_offset_preview(&mut state);
+
+        assert!(!state.ui.path_offset.active);
+        assert!(state.ui.status_message.is_some());
+    }
+}
@@ -0,0 +1,114 @@
+//! Use-Case: Y-Werte selektierter Nodes aus der geladenen Heightmap neu berechnen.
+
+use crate::app::use_cases::heightmap::effective_height_scale;
+use crate::app::AppState;
+use std::sync::Arc;
+
+/// Projiziert alle selektierten Nodes auf das Gelaende der geladenen Heightmap.
+///
+/// Jeder nicht gesperrte Node erhaelt als manuellen Y-Wert die Gelaendehoehe
+/// an seiner Position plus `offset` (Meter). Korrigiert importierte Kurse, die
+/// ueber dem Boden schweben oder darin versinken. Ohne Heightmap wird nur eine
+/// Statusmeldung gesetzt. Vor der Mutation wird ein Undo-Snapshot erstellt.
+pub fn project_selection_onto_terrain(state: &mut AppState, offset: f32) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    if state.selection.selected_node_ids.is_empty() {
+        return;
+    }
+    let Some(heightmap) = state.heightmap.as_deref() else {
+        state.ui.status_message =
+            Some("Keine Heightmap geladen — Projektion auf Gelaende nicht moeglich".to_string());
+        return;
+    };
+
+    let height_scale = effective_height_scale(state, Some(heightmap));
+    let targets: Vec<(u64, f32)> = state
+        .selection
+        .selected_node_ids
+        .iter()
+        .filter(|id| !state.locked_node_ids.contains(id))
+        .filter_map(|&id| {
+            let position = road_map.node_position(id)?;
+            let height = heightmap.sample_height(position.x, position.y, height_scale);
+            Some((id, height + offset))
+        })
+        .collect();
+    if targets.is_empty() {
+        state.ui.status_message =
+            Some("Keine aenderbaren Nodes fuer die Projektion selektiert".to_string());
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    for &(id, height) in &targets {
+        road_map.set_node_height_override(id, height);
+    }
+
+    let message = format!("{} Node(s) auf das Gelaende projiziert", targets.len());
+    log::info!("{} (Versatz {:.2} m)", message, offset);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Heightmap, MapNode, NodeFlag, RoadMap, WorldBounds};
+    use glam::Vec2;
+    use image::{DynamicImage, GrayImage, Luma};
+
+    fn state_with_nodes() -> AppState {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(2.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(3, Vec2::new(-2.0, 0.0), NodeFlag::Regular));
+        map.set_node_height_override(1, 40.0);
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3]);
+        state
+    }
+
+    #[test]
+    fn projects_unlocked_selection_onto_terrain_with_offset() {
+        // 9x9 Pixel ueber -4..4 m, 20 Grauwerte pro Meter in X → 0,2 m pro Meter
+        let image = GrayImage::from_fn(9, 9, |x, _| Luma([(x * 20) as u8]));
+        let heightmap = Heightmap::from_image(
+            DynamicImage::ImageLuma8(image),
+            WorldBounds::from_map_size(8.0),
+        )
+        .expect("Heightmap erwartet");
+        let mut state = state_with_nodes();
+        state.heightmap = Some(Arc::new(heightmap));
+        state.options.terrain_height_scale = 2.55;
+        state.locked_node_ids.insert(3);
+
+        project_selection_onto_terrain(&mut state, 0.5);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        let height = |id| road_map.node_height_override(id).expect("Y-Wert erwartet");
+        assert!((height(1) - 1.3).abs() < 1e-4);
+        assert!((height(2) - 1.7).abs() < 1e-4);
+        assert_eq!(road_map.node_height_override(3), None);
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn without_heightmap_only_sets_status_message() {
+        let mut state = state_with_nodes();
+
+        project_selection_onto_terrain(&mut state, 0.0);
+
+        let road_map = state.road_map.as_deref().expect("Karte erwartet");
+        assert_eq!(road_map.node_height_override(1), Some(40.0));
+        assert!(state.ui.status_message.is_some());
+        assert!(!state.can_undo());
+    }
+}
@@ -0,0 +1,279 @@
+//! Use-Case: Selektierte Kette mit neuem Node-Abstand neu aufbauen.
+//!
+//! Im Gegensatz zur Streckenteilung (`resample_path`) bleiben die beiden
+//! Endpunkte mit ihren IDs, Markern und externen Verbindungen erhalten — nur
+//! die inneren Nodes werden entlang der geglaetteten Kettenform neu erzeugt.
+//! Richtung und Prioritaet der Kette werden auf die neuen Verbindungen uebertragen.
+
+use super::delete_nodes_by_ids::delete_nodes_internal;
+use crate::app::AppState;
+use crate::core::{
+    Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+};
+use crate::shared::spline_geometry::{catmull_rom_chain_with_tangents, resample_by_distance};
+use glam::Vec2;
+use std::sync::Arc;
+
+/// Dichte der Catmull-Rom-Interpolation (Punkte je Segment).
+const SAMPLES_PER_SEGMENT: usize = 16;
+
+/// Kleinster zulaessiger Node-Abstand in Metern.
+pub const RESAMPLE_CHAIN_MIN_SPACING: f32 = 0.5;
+
+/// Verbindungsparameter der bestehenden Kette.
+pub(super) struct ChainLink {
+    pub(super) direction: ConnectionDirection,
+    pub(super) priority: ConnectionPriority,
+    /// `true`, wenn die Verbindungen gegen die Ketten-Reihenfolge gespeichert sind.
+    pub(super) reversed: bool,
+}
+
+/// Liest Richtung, Prioritaet und Orientierung der Verbindung `first`–`second`.
+pub(super) fn chain_link(road_map: &RoadMap, first: u64, second: u64) -> Option<ChainLink> {
+    if let Some(conn) = road_map.find_connection(first, second) {
+        return Some(ChainLink {
+            direction: conn.direction,
+            priority: conn.priority,
+            reversed: false,
+        });
+    }
+    road_map
+        .find_connection(second, first)
+        .map(|conn| ChainLink {
+            direction: conn.direction,
+            priority: conn.priority,
+            reversed: true,
+        })
+}
+
+/// Baut die selektierte Kette mit dem Node-Abstand `spacing` neu auf (mit Undo-Snapshot).
+///
+/// Die Selektion muss eine zusammenhaengende Kette sein
+/// (`RoadMap::is_resampleable_chain`). Die Endpunkte bleiben unveraendert und
+/// werden mit den neuen inneren Nodes verbunden; Richtung und Prioritaet der
+/// ersten Kettenverbindung gelten fuer alle neuen Verbindungen. Marker auf
+/// inneren Nodes entfallen. Enthaelt die Kette gesperrte Nodes, wird nichts veraendert.
+pub fn resample_selected_chain(state: &mut AppState, spacing: f32) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let selected = &state.selection.selected_node_ids;
+    let chain_ids = if road_map.is_resampleable_chain(selected) {
+        road_map.ordered_chain_nodes(selected)
+    } else {
+        None
+    };
+    let Some(chain_ids) = chain_ids else {
+        state.ui.status_message = Some(
+            "Kette neu aufbauen benoetigt eine zusammenhaengende Kette aus mind. 2 Nodes"
+                .to_string(),
+        );
+        return;
+    };
+    if chain_ids
+        .iter()
+        .any(|id| state.locked_node_ids.contains(id))
+    {
+        state.ui.status_message =
+            Some("Kette enthaelt gesperrte Nodes — nicht neu aufgebaut".to_string());
+        return;
+    }
+    let Some(positions) = chain_ids
+        .iter()
+        .map(|&id| road_map.node_position(id))
+        .collect::<Option<Vec<Vec2>>>()
+    else {
+        return;
+    };
+    let Some(link) = chain_link(road_map, chain_ids[0], chain_ids[1]) else {
+        return;
+    };
+
+    let spacing = spacing.max(RESAMPLE_CHAIN_MIN_SPACING);
+    let dense = catmull_rom_chain_with_tangents(&positions, SAMPLES_PER_SEGMENT, None, None);
+    let resampled = resample_by_distance(&dense, spacing);
+    if resampled.len() < 2 {
+        return;
+    }
+    let inner_positions = &resampled[1..resampled.len() - 1];
+
+    let first_id = chain_ids[0];
+    let last_id = chain_ids[chain_ids.len() - 1];
+    let old_inner: Vec<u64> = chain_ids[1..chain_ids.len() - 1].to_vec();
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+
+    if old_inner.is_empty() {
+        road_map.remove_connections_between(first_id, last_id);
+    } else {
+        delete_nodes_internal(road_map, &old_inner, true);
+    }
+
+    let mut new_inner = Vec::with_capacity(inner_positions.len());
+    for &position in inner_positions {
+        let id = road_map.next_node_id();
+        road_map.add_node(MapNode::new(id, position, NodeFlag::Regular));
+        new_inner.push(id);
+    }
+
+    let mut chain: Vec<u64> = Vec::with_capacity(new_inner.len() + 2);
+    chain.push(first_id);
+    chain.extend(&new_inner);
+    chain.push(last_id);
+    for pair in chain.windows(2) {
+        let (start_id, end_id) = if link.reversed {
+            (pair[1], pair[0])
+        } else {
+            (pair[0], pair[1])
+        };
+        let (Some(start_pos), Some(end_pos)) = (
+            road_map.node_position(start_id),
+            road_map.node_position(end_id),
+        ) else {
+            continue;
+        };
+        road_map.add_connection(Connection::new(
+            start_id,
+            end_id,
+            link.direction,
+            link.priority,
+            start_pos,
+            end_pos,
+        ));
+    }
+
+    road_map.recalculate_node_flags(&chain);
+    road_map.ensure_spatial_index();
+
+    let invalidated = state.group_registry.invalidate_by_node_ids(&chain_ids);
+    state.tool_edit_store.remove_many(invalidated);
+
+    state.selection.ids_mut().clear();
+    state.selection.ids_mut().extend(chain.iter().copied());
+    state.selection.selection_anchor_node_id = Some(first_id);
+
+    let message = format!(
+        "Kette neu aufgebaut: {} → {} Nodes (Abstand {:.1} m)",
+        chain_ids.len(),
+        chain.len(),
+        spacing
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RoadMap;
+
+    fn chain_map(direction: ConnectionDirection, reversed: bool) -> RoadMap {
+        let mut map = RoadMap::new(3);
+        for (id, x) in [(10, -10.0), (1, 0.0), (2, 10.0), (3, 20.0), (20, 30.0)] {
+            map.add_node(MapNode::new(id, Vec2::new(x, 0.0), NodeFlag::Regular));
+        }
+        for (a, b) in [(10u64, 1u64), (1, 2), (2, 3), (3, 20)] {
+            let (start, end) = if reversed { (b, a) } else { (a, b) };
+            let start_pos = map.node_position(start).expect("Start vorhanden");
+            let end_pos = map.node_position(end).expect("Ende vorhanden");
+            map.add_connection(Connection::new(
+                start,
+                end,
+                direction,
+                ConnectionPriority::SubPriority,
+                start_pos,
+                end_pos,
+            ));
+        }
+        map.ensure_spatial_index();
+        map
+    }
+
+    fn state_with_selection(map: RoadMap, ids: &[u64]) -> AppState {
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend(ids.iter().copied());
+        state
+    }
+
+    #[test]
+    fn resample_chain_keeps_endpoints_and_external_connections() {
+        let mut state =
+            state_with_selection(chain_map(ConnectionDirection::Regular, false), &[1, 2, 3]);
+
+        resample_selected_chain(&mut state, 5.0);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        assert!(road_map.contains_node(1));
+        assert!(road_map.contains_node(3));
+        assert!(!road_map.contains_node(2));
+        assert!(road_map.has_connection(10, 1));
+        assert!(road_map.has_connection(3, 20));
+
+        let chain = road_map
+            .ordered_chain_nodes(&state.selection.selected_node_ids)
+            .expect("Neue Kette muss zusammenhaengen");
+        assert_eq!(chain.len(), 5);
+        assert_eq!(chain.first(), Some(&1));
+        assert_eq!(chain.last(), Some(&3));
+        for pair in chain.windows(2) {
+            let conn = road_map
+                .find_connection(pair[0], pair[1])
+                .expect("Kettenverbindung in Originalrichtung");
+            assert_eq!(conn.priority, ConnectionPriority::SubPriority);
+        }
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn resample_chain_preserves_reversed_dual_links() {
+        let mut state =
+            state_with_selection(chain_map(ConnectionDirection::Dual, true), &[1, 2, 3]);
+
+        resample_selected_chain(&mut state, 4.0);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        let new_ids: Vec<u64> = state
+            .selection
+            .selected_node_ids
+            .iter()
+            .copied()
+            .filter(|id| *id != 1 && *id != 3)
+            .collect();
+        assert!(!new_ids.is_empty());
+        for id in new_ids {
+            for conn in road_map.connections_iter().filter(|c| c.start_id == id) {
+                assert_eq!(conn.direction, ConnectionDirection::Dual);
+                assert_eq!(conn.priority, ConnectionPriority::SubPriority);
+            }
+        }
+        assert!(road_map
+            .connections_iter()
+            .any(|c| c.end_id == 1 && c.start_id != 10));
+        assert!(road_map.has_connection(20, 3));
+    }
+
+    #[test]
+    fn resample_chain_rejects_locked_and_non_chain_selections() {
+        let mut state =
+            state_with_selection(chain_map(ConnectionDirection::Regular, false), &[1, 3]);
+        resample_selected_chain(&mut state, 5.0);
+        assert!(!state.can_undo());
+
+        let mut state =
+            state_with_selection(chain_map(ConnectionDirection::Regular, false), &[1, 2, 3]);
+        state.locked_node_ids.insert(2);
+        resample_selected_chain(&mut state, 5.0);
+        assert!(!state.can_undo());
+        assert!(state
+            .road_map
+            .as_deref()
+            .is_some_and(|road_map| road_map.contains_node(2)));
+    }
+}
@@ -71,7 +71,8 @@ fn order_chain(node_ids: &IndexSet<u64>, road_map: &crate::core::RoadMap) -> Opt
 /// - `by_count = false`: Waypoints mit maximalem Abstand `distance` Welteinheiten
 ///
 /// Gibt eine Warnung aus (und bricht ab) wenn die selektierten Nodes keine
-/// lineare Kette bilden.
+/// lineare Kette bilden. Enthaelt die Kette gesperrte Nodes, bleibt sie
+/// unveraendert und es wird eine Statusmeldung gesetzt.
 pub fn resample_selected_path(state: &mut AppState) {
     let Some(road_map_ref) = state.road_map.as_ref() else {
         log::warn!("Distanzen: keine RoadMap geladen");
@@ -90,6 +91,11 @@ pub fn resample_selected_path(state: &mut AppState) {
         log::warn!("Distanzen: selektierte Nodes bilden keine vollstaendige lineare Kette");
         return;
     };
+    if ordered.iter().any(|id| state.locked_node_ids.contains(id)) {
+        state.ui.status_message =
+            Some("Kette enthaelt gesperrte Nodes — nicht neu verteilt".to_string());
+        return;
+    }
 
     // Positionen und Verbindungsparameter
     let positions: Vec<Vec2> = ordered
@@ -264,4 +270,23 @@ mod tests {
             assert!(!road_map.has_connection(*new_id, 20));
         }
     }
+
+    #[test]
+    fn resample_refuses_locked_chain() {
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(test_map_with_external_endpoint_connections()));
+        state.selection.ids_mut().extend([1, 2, 3]);
+        state.locked_node_ids.insert(2);
+        state.ui.distanzen.by_count = true;
+        state.ui.distanzen.count = 5;
+
+        resample_selected_path(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap erwartet");
+        assert_eq!(road_map.node_count(), 5);
+        assert!(road_map.has_connection(1, 2));
+        assert!(road_map.has_connection(2, 3));
+        assert!(state.ui.status_message.is_some());
+        assert!(!state.can_undo());
+    }
 }
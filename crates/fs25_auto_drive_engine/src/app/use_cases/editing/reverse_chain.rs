@@ -0,0 +1,133 @@
+//! Use-Case: Laufrichtung der selektierten Kette komplett umkehren.
+//!
+//! Anders als `reverse_path_between_selected` arbeitet der Befehl entlang der
+//! geordneten Kette: Jede Verbindung zwischen zwei Kettennachbarn tauscht
+//! Start- und End-ID (auch Dual), und die Selektion wird in umgekehrter
+//! Kettenreihenfolge neu aufgebaut, sodass der bisherige Endpunkt zum Start wird.
+
+use super::bulk_connections::reverse_connections;
+use crate::app::AppState;
+use crate::core::Connection;
+use std::sync::Arc;
+
+/// Kehrt alle Verbindungen der selektierten Kette um (mit Undo-Snapshot).
+///
+/// Die Selektion muss eine zusammenhaengende lineare Kette aus mindestens zwei
+/// Nodes bilden. Richtung und Prioritaet jeder Verbindung bleiben erhalten,
+/// nur Start und Ende werden getauscht; gegenlaeufige Paare tauschen dabei
+/// ihre Attribute. Anschluesse an Nodes ausserhalb der Kette bleiben unberuehrt.
+pub fn reverse_selected_chain(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let Some(chain_ids) = road_map.ordered_chain_nodes(&state.selection.selected_node_ids) else {
+        state.ui.status_message =
+            Some("Umkehren benoetigt eine zusammenhaengende Kette aus mind. 2 Nodes".to_string());
+        return;
+    };
+    let chain_connections: Vec<Connection> = chain_ids
+        .windows(2)
+        .flat_map(|pair| road_map.find_connections_between(pair[0], pair[1]))
+        .cloned()
+        .collect();
+    if chain_connections.is_empty() {
+        return;
+    }
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    reverse_connections(road_map, &chain_connections);
+    road_map.recalculate_node_flags(&chain_ids);
+
+    let selection = state.selection.ids_mut();
+    selection.clear();
+    selection.extend(chain_ids.iter().rev().copied());
+
+    let message = format!(
+        "Kette umgekehrt: {} Nodes, {} Verbindung(en) gedreht",
+        chain_ids.len(),
+        chain_connections.len()
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+
+    /// Einbahn-Kette 1 → 2 → 3 → 4 mit externem Zubringer 10 → 1.
+    fn one_way_chain_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        for (id, x) in [(1, 0.0), (2, 10.0), (3, 20.0), (4, 30.0), (10, -10.0)] {
+            map.add_node(MapNode::new(id, Vec2::new(x, 0.0), NodeFlag::Regular));
+        }
+        for (start, end, priority) in [
+            (10, 1, ConnectionPriority::Regular),
+            (1, 2, ConnectionPriority::Regular),
+            (2, 3, ConnectionPriority::SubPriority),
+            (3, 4, ConnectionPriority::Regular),
+        ] {
+            let start_pos = map.node_position(start).unwrap();
+            let end_pos = map.node_position(end).unwrap();
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                priority,
+                start_pos,
+                end_pos,
+            ));
+        }
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2, 3, 4]);
+        state
+    }
+
+    #[test]
+    fn reverse_chain_swaps_endpoints_and_keeps_attributes() {
+        let mut state = one_way_chain_state();
+
+        reverse_selected_chain(&mut state);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        assert!(road_map.has_connection(4, 3));
+        assert!(road_map.has_connection(2, 1));
+        assert!(!road_map.has_connection(1, 2));
+        let middle = road_map.find_connection(3, 2).expect("gedrehte Verbindung");
+        assert_eq!(middle.direction, ConnectionDirection::Regular);
+        assert_eq!(middle.priority, ConnectionPriority::SubPriority);
+        assert!(
+            road_map.has_connection(10, 1),
+            "Zubringer bleibt unberuehrt"
+        );
+        let order: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert_eq!(order, vec![4, 3, 2, 1]);
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn reverse_chain_rejects_non_chain_selection() {
+        let mut state = one_way_chain_state();
+        state.selection.ids_mut().clear();
+        state.selection.ids_mut().extend([1, 3]);
+
+        reverse_selected_chain(&mut state);
+
+        assert!(!state.can_undo());
+        assert!(state
+            .road_map
+            .as_deref()
+            .is_some_and(|road_map| road_map.has_connection(1, 2)));
+    }
+}
@@ -0,0 +1,375 @@
+//! Use-Case: Kreuzungs-Node in einen Einbahn-Kreisverkehr umwandeln.
+//!
+//! Der selektierte Kreuzungs-Node (mindestens drei Nachbarn) wird entfernt.
+//! An seiner Stelle entsteht ein Ring mit dem gewuenschten Radius, an dem
+//! jede Zufahrt mit ihrer urspruenglichen Richtung und Strassenart wieder
+//! anschliesst. Ring und Umverdrahtung bilden einen einzigen Undo-Schritt.
+
+use crate::app::AppState;
+use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+use glam::Vec2;
+use std::f32::consts::{PI, TAU};
+use std::sync::Arc;
+
+/// Mindestanzahl unterschiedlicher Nachbarn, ab der ein Node als Kreuzung gilt.
+pub const ROUNDABOUT_MIN_JUNCTION_DEGREE: usize = 3;
+
+/// Maximaler Winkel zwischen zwei Ring-Nodes (30°).
+const MAX_RING_SEGMENT_ANGLE: f32 = PI / 6.0;
+
+/// Zufahrten, deren Winkel naeher beieinander liegen, teilen sich einen Ring-Node.
+const MERGE_ANGLE_EPSILON: f32 = 1.0e-3;
+
+/// Eine an der Kreuzung haengende Verbindung aus Sicht des Nachbarn.
+#[derive(Debug, Clone, Copy)]
+struct ApproachLink {
+    /// `true` = Kreuzung → Nachbar, `false` = Nachbar → Kreuzung
+    outgoing: bool,
+    direction: ConnectionDirection,
+    priority: ConnectionPriority,
+}
+
+/// Alle Verbindungen zu einem Nachbarn samt Richtungswinkel vom Zentrum aus.
+#[derive(Debug, Clone)]
+struct Approach {
+    neighbor_id: u64,
+    angle: f32,
+    links: Vec<ApproachLink>,
+}
+
+/// Ring-Punkt mit den dort anschliessenden Zufahrten (leer = Zwischenpunkt).
+struct RingPoint {
+    position: Vec2,
+    approaches: Vec<usize>,
+}
+
+/// Berechnet die Ring-Punkte in Fahrtrichtung.
+///
+/// Der Ring wird in der Kartenansicht gegen den Uhrzeigersinn befahren
+/// (Rechtsverkehr). Da die Welt-Z-Achse auf dem Bildschirm nach unten zeigt,
+/// entspricht das fallenden `atan2`-Winkeln.
+fn ring_points(center: Vec2, radius: f32, approaches: &[Approach]) -> Vec<RingPoint> {
+    let mut order: Vec<usize> = (0..approaches.len()).collect();
+    order.sort_by(|&a, &b| approaches[b].angle.total_cmp(&approaches[a].angle));
+
+    // Zufahrten mit (nahezu) gleichem Winkel zusammenfassen
+    let mut anchors: Vec<(f32, Vec<usize>)> = Vec::with_capacity(order.len());
+    for index in order {
+        let angle = approaches[index].angle;
+        match anchors.last_mut() {
+            Some((last_angle, members)) if (*last_angle - angle).abs() < MERGE_ANGLE_EPSILON => {
+                members.push(index);
+            }
+            _ => anchors.push((angle, vec![index])),
+        }
+    }
+
+    let point_at = |angle: f32| center + Vec2::new(angle.cos(), angle.sin()) * radius;
+    let mut points = Vec::new();
+    for (i, (angle, members)) in anchors.iter().enumerate() {
+        points.push(RingPoint {
+            position: point_at(*angle),
+            approaches: members.clone(),
+        });
+
+        let next_angle = anchors[(i + 1) % anchors.len()].0;
+        let mut span = (angle - next_angle).rem_euclid(TAU);
+        if span < MERGE_ANGLE_EPSILON {
+            span = TAU;
+        }
+        let steps = (span / MAX_RING_SEGMENT_ANGLE).ceil().max(1.0) as usize;
+        for step in 1..steps {
+            points.push(RingPoint {
+                position: point_at(angle - span * step as f32 / steps as f32),
+                approaches: Vec::new(),
+            });
+        }
+    }
+    points
+}
+
+/// Wandelt den einzigen selektierten Kreuzungs-Node in einen Kreisverkehr um.
+///
+/// Voraussetzungen: genau ein selektierter, nicht gesperrter Node ohne Marker
+/// mit mindestens `ROUNDABOUT_MIN_JUNCTION_DEGREE` Nachbarn, die alle
+/// ausserhalb von `radius` liegen. Andernfalls bleibt die Karte unveraendert
+/// und eine Statusmeldung erklaert den Grund. Danach sind die Ring-Nodes selektiert.
+pub fn convert_junction_to_roundabout(state: &mut AppState, radius: f32) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let junction_id = match state.selection.selected_node_ids.iter().collect::<Vec<_>>()[..] {
+        [&id] => id,
+        _ => {
+            state.ui.status_message =
+                Some("Kreisverkehr benoetigt genau einen selektierten Kreuzungs-Node".to_string());
+            return;
+        }
+    };
+    let Some(center) = road_map.node_position(junction_id) else {
+        return;
+    };
+    if road_map.node_degree(junction_id) < ROUNDABOUT_MIN_JUNCTION_DEGREE {
+        state.ui.status_message = Some(format!(
+            "Node {} ist keine Kreuzung (mindestens {} Nachbarn noetig)",
+            junction_id, ROUNDABOUT_MIN_JUNCTION_DEGREE
+        ));
+        return;
+    }
+    if state.locked_node_ids.contains(&junction_id) {
+        state.ui.status_message = Some(format!("Node {} ist gesperrt", junction_id));
+        return;
+    }
+    if road_map.has_marker(junction_id) {
+        state.ui.status_message = Some(format!(
+            "Node {} traegt einen Marker — Kreisverkehr nicht moeglich",
+            junction_id
+        ));
+        return;
+    }
+
+    let mut approaches: Vec<Approach> = Vec::new();
+    for &(neighbor_id, outgoing) in road_map.neighbors(junction_id) {
+        let key = if outgoing {
+            (junction_id, neighbor_id)
+        } else {
+            (neighbor_id, junction_id)
+        };
+        let (Some(conn), Some(neighbor_pos)) = (
+            road_map.find_connection(key.0, key.1),
+            road_map.node_position(neighbor_id),
+        ) else {
+            continue;
+        };
+        if neighbor_pos.distance(center) <= radius {
+            state.ui.status_message = Some(format!(
+                "Nachbar-Node {} liegt innerhalb des Kreisverkehr-Radius ({:.1} m)",
+                neighbor_id, radius
+            ));
+            return;
+        }
+        let link = ApproachLink {
+            outgoing,
+            direction: conn.direction,
+            priority: conn.priority,
+        };
+        match approaches.iter_mut().find(|a| a.neighbor_id == neighbor_id) {
+            Some(approach) => approach.links.push(link),
+            None => {
+                let offset = neighbor_pos - center;
+                approaches.push(Approach {
+                    neighbor_id,
+                    angle: offset.y.atan2(offset.x),
+                    links: vec![link],
+                });
+            }
+        }
+    }
+
+    let ring = ring_points(center, radius, &approaches);
+    let height = road_map.node_height_override(junction_id);
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    road_map.remove_node(junction_id);
+
+    let mut ring_ids: Vec<u64> = Vec::with_capacity(ring.len());
+    for point in &ring {
+        let id = road_map.next_node_id();
+        road_map.add_node(MapNode::new(id, point.position, NodeFlag::Regular));
+        if let Some(height) = height {
+            road_map.set_node_height_override(id, height);
+        }
+        ring_ids.push(id);
+    }
+    for (i, &from_id) in ring_ids.iter().enumerate() {
+        let to_id = ring_ids[(i + 1) % ring_ids.len()];
+        road_map.add_connection(Connection::new(
+            from_id,
+            to_id,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            ring[i].position,
+            ring[(i + 1) % ring.len()].position,
+        ));
+    }
+
+    let mut touched_ids = ring_ids.clone();
+    for (point, &ring_id) in ring.iter().zip(&ring_ids) {
+        for &approach_index in &point.approaches {
+            let approach = &approaches[approach_index];
+            let Some(neighbor_pos) = road_map.node_position(approach.neighbor_id) else {
+                continue;
+            };
+            for link in &approach.links {
+                let (start_id, end_id, start_pos, end_pos) = if link.outgoing {
+                    (ring_id, approach.neighbor_id, point.position, neighbor_pos)
+                } else {
+                    (approach.neighbor_id, ring_id, neighbor_pos, point.position)
+                };
+                road_map.add_connection(Connection::new(
+                    start_id,
+                    end_id,
+                    link.direction,
+                    link.priority,
+                    start_pos,
+                    end_pos,
+                ));
+            }
+            touched_ids.push(approach.neighbor_id);
+        }
+    }
+    road_map.recalculate_node_flags(&touched_ids);
+    road_map.ensure_spatial_index();
+
+    // Segment-Registry: Records mit der entfernten Kreuzung invalidieren
+    let invalidated = state.group_registry.invalidate_by_node_ids(&[junction_id]);
+    state.tool_edit_store.remove_many(invalidated);
+
+    state.selection.ids_mut().clear();
+    state.selection.ids_mut().extend(ring_ids.iter().copied());
+    state.selection.selection_anchor_node_id = ring_ids.first().copied();
+
+    let message = format!(
+        "Kreuzung {} in Kreisverkehr mit {} Nodes umgewandelt (Radius {:.1} m)",
+        junction_id,
+        ring_ids.len(),
+        radius
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RoadMap;
+
+    /// T-Kreuzung: Node 1 im Ursprung, Zufahrten von Osten (2→1),
+    /// nach Westen (1→3) und Dual nach Sueden (1↔4).
+    fn junction_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::ZERO, NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(50.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(3, Vec2::new(-50.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(4, Vec2::new(0.0, 50.0), NodeFlag::Regular));
+        let mut connect = |start: u64, end: u64, direction, priority| {
+            let start_pos = map.node_position(start).expect("Start vorhanden");
+            let end_pos = map.node_position(end).expect("Ende vorhanden");
+            map.add_connection(Connection::new(
+                start, end, direction, priority, start_pos, end_pos,
+            ));
+        };
+        connect(
+            2,
+            1,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+        );
+        connect(
+            1,
+            3,
+            ConnectionDirection::Regular,
+            ConnectionPriority::SubPriority,
+        );
+        connect(1, 4, ConnectionDirection::Dual, ConnectionPriority::Regular);
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().insert(1);
+        state
+    }
+
+    fn map(state: &AppState) -> &RoadMap {
+        state.road_map.as_deref().expect("Karte erwartet")
+    }
+
+    /// Ring-Node, an dem der gegebene Nachbar anschliesst.
+    fn ring_node_for(state: &AppState, neighbor_id: u64) -> u64 {
+        map(state)
+            .neighbors(neighbor_id)
+            .iter()
+            .map(|&(id, _)| id)
+            .find(|id| state.selection.selected_node_ids.contains(id))
+            .expect("Zufahrt muss am Ring anschliessen")
+    }
+
+    #[test]
+    fn converts_junction_into_one_way_ring_with_reconnected_approaches() {
+        let mut state = junction_state();
+
+        convert_junction_to_roundabout(&mut state, 10.0);
+
+        let road_map = map(&state);
+        assert!(!road_map.contains_node(1));
+        let ring: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        assert!(
+            ring.len() >= 12,
+            "Ring braucht Zwischenpunkte: {}",
+            ring.len()
+        );
+        for &id in &ring {
+            let position = road_map.node_position(id).expect("Ring-Node vorhanden");
+            assert!((position.length() - 10.0).abs() < 1.0e-3);
+            assert_eq!(
+                road_map
+                    .outgoing_neighbors(id)
+                    .filter(|n| ring.contains(n))
+                    .count(),
+                1
+            );
+        }
+
+        let east = ring_node_for(&state, 2);
+        let west = ring_node_for(&state, 3);
+        let south = ring_node_for(&state, 4);
+        assert!(road_map.has_connection(2, east));
+        assert_eq!(
+            road_map.find_connection(west, 3).map(|c| c.priority),
+            Some(ConnectionPriority::SubPriority)
+        );
+        assert_eq!(
+            road_map.find_connection(south, 4).map(|c| c.direction),
+            Some(ConnectionDirection::Dual)
+        );
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn ring_runs_counterclockwise_in_map_view() {
+        let mut state = junction_state();
+
+        convert_junction_to_roundabout(&mut state, 10.0);
+
+        // Von Osten (Winkel 0) geht es auf dem Bildschirm nach oben (negatives Z)
+        let east = ring_node_for(&state, 2);
+        let road_map = map(&state);
+        let next = road_map
+            .outgoing_neighbors(east)
+            .find(|id| *id != 2)
+            .and_then(|id| road_map.node_position(id))
+            .expect("Folge-Node im Ring");
+        assert!(next.y < 0.0);
+    }
+
+    #[test]
+    fn refuses_non_junction_and_too_large_radius() {
+        let mut state = junction_state();
+        state.selection.ids_mut().clear();
+        state.selection.ids_mut().insert(2);
+        convert_junction_to_roundabout(&mut state, 10.0);
+        assert!(map(&state).contains_node(1));
+
+        state.selection.ids_mut().clear();
+        state.selection.ids_mut().insert(1);
+        convert_junction_to_roundabout(&mut state, 60.0);
+        assert!(map(&state).contains_node(1));
+        assert!(!state.can_undo());
+    }
+}
@@ -0,0 +1,286 @@
+//! Use-Case: Selektierte Kette per Douglas-Peucker ausduennen.
+//!
+//! Entfernt innere Nodes, deren Abweichung von der vereinfachten Linie unter
+//! der Toleranz liegt, und verbindet die verbleibenden Nachbarn direkt. Nodes
+//! mit Markern oder Verbindungen nach ausserhalb der Kette bleiben immer stehen.
+
+use super::delete_nodes_by_ids::delete_nodes_internal;
+use super::resample_chain::{chain_link, ChainLink};
+use crate::app::AppState;
+use crate::core::{simplify_polyline_indices, Connection, RoadMap};
+use glam::Vec2;
+use indexmap::IndexSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Groesste zulaessige Vereinfachungs-Toleranz in Metern.
+pub const SIMPLIFY_CHAIN_MAX_TOLERANCE: f32 = 10.0;
+
+/// Ergebnis einer Ausduennungs-Vorschau fuer die aktuelle Selektion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimplifyChainPreview {
+    /// Kette in Verbindungsreihenfolge.
+    pub chain_ids: Vec<u64>,
+    /// Nach der Vereinfachung verbleibende Nodes (Teilmenge von `chain_ids`, gleiche Reihenfolge).
+    pub kept_ids: Vec<u64>,
+}
+
+impl SimplifyChainPreview {
+    /// Anzahl der Nodes, die entfernt wuerden.
+    pub fn removed_count(&self) -> usize {
+        self.chain_ids.len() - self.kept_ids.len()
+    }
+}
+
+/// Berechnet, welche Nodes der selektierten Kette bei `tolerance` erhalten bleiben.
+///
+/// Liefert `None`, wenn die Selektion keine zusammenhaengende Kette aus
+/// mindestens drei Nodes ist. Endpunkte, Marker-Nodes und Nodes mit
+/// Verbindungen nach ausserhalb der Kette werden immer behalten; die
+/// Douglas-Peucker-Vereinfachung laeuft jeweils zwischen diesen Ankern.
+pub fn simplify_chain_preview(
+    road_map: &RoadMap,
+    node_ids: &IndexSet<u64>,
+    tolerance: f32,
+) -> Option<SimplifyChainPreview> {
+    if !road_map.is_resampleable_chain(node_ids) {
+        return None;
+    }
+    let chain_ids = road_map.ordered_chain_nodes(node_ids)?;
+    if chain_ids.len() < 3 {
+        return None;
+    }
+    let positions = chain_ids
+        .iter()
+        .map(|&id| road_map.node_position(id))
+        .collect::<Option<Vec<Vec2>>>()?;
+    let chain_set: HashSet<u64> = chain_ids.iter().copied().collect();
+    let last = chain_ids.len() - 1;
+    let anchors: Vec<usize> = (0..=last)
+        .filter(|&index| {
+            let id = chain_ids[index];
+            index == 0
+                || index == last
+                || road_map.has_marker(id)
+                || road_map
+                    .neighbors(id)
+                    .iter()
+                    .any(|(neighbor, _)| !chain_set.contains(neighbor))
+        })
+        .collect();
+
+    let tolerance = tolerance.clamp(0.0, SIMPLIFY_CHAIN_MAX_TOLERANCE);
+    let mut kept_ids = vec![chain_ids[0]];
+    for span in anchors.windows(2) {
+        let (start, end) = (span[0], span[1]);
+        let kept = simplify_polyline_indices(&positions[start..=end], tolerance);
+        kept_ids.extend(
+            kept.into_iter()
+                .skip(1)
+                .map(|index| chain_ids[start + index]),
+        );
+    }
+
+    Some(SimplifyChainPreview {
+        chain_ids,
+        kept_ids,
+    })
+}
+
+/// Duennt die selektierte Kette mit Douglas-Peucker-Toleranz `tolerance` aus (mit Undo-Snapshot).
+///
+/// Entfernte Nodes werden geloescht, die verbleibenden Nachbarn mit Richtung
+/// und Prioritaet des jeweils ersetzten Abschnitts direkt verbunden. Enthaelt
+/// die Kette gesperrte Nodes, wird nichts veraendert.
+pub fn simplify_selected_chain(state: &mut AppState, tolerance: f32) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let Some(preview) =
+        simplify_chain_preview(road_map, &state.selection.selected_node_ids, tolerance)
+    else {
+        state.ui.status_message = Some(
+            "Vereinfachen benoetigt eine zusammenhaengende Kette aus mind. 3 Nodes".to_string(),
+        );
+        return;
+    };
+    if preview
+        .chain_ids
+        .iter()
+        .any(|id| state.locked_node_ids.contains(id))
+    {
+        state.ui.status_message =
+            Some("Kette enthaelt gesperrte Nodes — nicht vereinfacht".to_string());
+        return;
+    }
+    if preview.removed_count() == 0 {
+        state.ui.status_message =
+            Some("Keine Nodes innerhalb der Toleranz — Kette unveraendert".to_string());
+        return;
+    }
+
+    // Verbindungsparameter je ueberbruecktem Abschnitt VOR dem Loeschen erfassen
+    let kept_set: HashSet<u64> = preview.kept_ids.iter().copied().collect();
+    let mut bridges: Vec<(u64, u64, ChainLink)> = Vec::new();
+    for (index, pair) in preview.chain_ids.windows(2).enumerate() {
+        if !kept_set.contains(&pair[0]) || kept_set.contains(&pair[1]) {
+            continue;
+        }
+        let Some(end_id) = preview.chain_ids[index + 1..]
+            .iter()
+            .copied()
+            .find(|id| kept_set.contains(id))
+        else {
+            continue;
+        };
+        if let Some(link) = chain_link(road_map, pair[0], pair[1]) {
+            bridges.push((pair[0], end_id, link));
+        }
+    }
+    let removed: Vec<u64> = preview
+        .chain_ids
+        .iter()
+        .copied()
+        .filter(|id| !kept_set.contains(id))
+        .collect();
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    delete_nodes_internal(road_map, &removed, false);
+
+    for (start_id, end_id, link) in bridges {
+        let (start_id, end_id) = if link.reversed {
+            (end_id, start_id)
+        } else {
+            (start_id, end_id)
+        };
+        let (Some(start_pos), Some(end_pos)) = (
+            road_map.node_position(start_id),
+            road_map.node_position(end_id),
+        ) else {
+            continue;
+        };
+        road_map.add_connection(Connection::new(
+            start_id,
+            end_id,
+            link.direction,
+            link.priority,
+            start_pos,
+            end_pos,
+        ));
+    }
+    road_map.recalculate_node_flags(&preview.kept_ids);
+    road_map.ensure_spatial_index();
+
+    let invalidated = state.group_registry.invalidate_by_node_ids(&removed);
+    state.tool_edit_store.remove_many(invalidated);
+    state.selection.ids_mut().retain(|id| kept_set.contains(id));
+
+    let message = format!(
+        "Kette vereinfacht: {} → {} Nodes (Toleranz {:.2} m)",
+        preview.chain_ids.len(),
+        preview.kept_ids.len(),
+        tolerance
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ConnectionDirection, ConnectionPriority, MapMarker, MapNode, NodeFlag};
+
+    /// Fast gerade Kette 1..=6 mit leichtem Rauschen und einem Knick bei Node 4.
+    fn noisy_map(reversed: bool) -> RoadMap {
+        let points = [
+            (1, 0.0, 0.0),
+            (2, 5.0, 0.1),
+            (3, 10.0, -0.1),
+            (4, 15.0, 0.0),
+            (5, 15.1, 5.0),
+            (6, 15.0, 10.0),
+        ];
+        let mut map = RoadMap::new(3);
+        for (id, x, y) in points {
+            map.add_node(MapNode::new(id, Vec2::new(x, y), NodeFlag::Regular));
+        }
+        for pair in points.windows(2) {
+            let (a, b) = if reversed {
+                (pair[1], pair[0])
+            } else {
+                (pair[0], pair[1])
+            };
+            map.add_connection(Connection::new(
+                a.0,
+                b.0,
+                ConnectionDirection::Regular,
+                ConnectionPriority::SubPriority,
+                Vec2::new(a.1, a.2),
+                Vec2::new(b.1, b.2),
+            ));
+        }
+        map.ensure_spatial_index();
+        map
+    }
+
+    fn state_for(map: RoadMap) -> AppState {
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend(1..=6);
+        state
+    }
+
+    #[test]
+    fn preview_keeps_endpoints_corner_and_marker_nodes() {
+        let mut map = noisy_map(false);
+        let selected: IndexSet<u64> = (1..=6).collect();
+
+        let preview = simplify_chain_preview(&map, &selected, 0.5).expect("Kette erwartet");
+        assert_eq!(preview.kept_ids, vec![1, 4, 6]);
+        assert_eq!(preview.removed_count(), 3);
+
+        map.add_map_marker(MapMarker::new(
+            2,
+            "Hof".to_string(),
+            "All".to_string(),
+            1,
+            false,
+        ));
+        let preview = simplify_chain_preview(&map, &selected, 0.5).expect("Kette erwartet");
+        assert_eq!(preview.kept_ids, vec![1, 2, 4, 6]);
+    }
+
+    #[test]
+    fn simplify_removes_nodes_and_bridges_with_original_link() {
+        let mut state = state_for(noisy_map(true));
+
+        simplify_selected_chain(&mut state, 0.5);
+
+        let road_map = state.road_map.as_deref().expect("RoadMap vorhanden");
+        assert_eq!(road_map.node_count(), 3);
+        let bridge = road_map
+            .find_connection(4, 1)
+            .expect("Ueberbrueckung in Originalrichtung");
+        assert_eq!(bridge.priority, ConnectionPriority::SubPriority);
+        assert!(road_map.has_connection(6, 4));
+        assert_eq!(state.selection.selected_node_ids.len(), 3);
+        assert!(state.can_undo());
+    }
+
+    #[test]
+    fn simplify_rejects_locked_chain() {
+        let mut state = state_for(noisy_map(false));
+        state.locked_node_ids.insert(2);
+
+        simplify_selected_chain(&mut state, 0.5);
+
+        assert!(!state.can_undo());
+        assert_eq!(state.road_map.as_deref().map(RoadMap::node_count), Some(6));
+    }
+}
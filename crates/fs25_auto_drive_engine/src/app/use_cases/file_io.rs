@@ -3,6 +3,7 @@
 
 use crate::app::ui_contract::{DialogRequest, DialogRequestKind};
 use crate::app::AppState;
+use crate::core::Heightmap;
 use std::sync::Arc;
 
 /// Oeffnet den Open-Datei-Dialog ueber UI-State.
@@ -16,12 +17,15 @@ pub fn request_open_file(state: &mut AppState) {
 ///
 /// Erkennt duplizierte Nodes und zeigt ggf. einen Bestaetigungsdialog.
 pub fn load_selected_file(state: &mut AppState, path: String) -> anyhow::Result<()> {
-    let xml_content = std::fs::read_to_string(&path)?;
-    let road_map = crate::xml::parse_autodrive_config(&xml_content)?;
+    let road_map = crate::xml::load_autodrive_config(&path)?;
 
     // Merke Pfad fuer spaeteres Save
     state.ui.current_file_path = Some(path.to_string());
     state.selection.ids_mut().clear();
+    state.locked_node_ids.clear();
+    state.view.clear_isolation();
+    state.ui.path_offset.deactivate();
+    state.live_link.stop_recording();
 
     log::info!(
         "Loaded RoadMap: {} nodes, {} connections",
@@ -122,14 +126,31 @@ pub fn save_file_as(state: &mut AppState, path: String) -> anyhow::Result<()> {
 
 /// Schreibt die RoadMap als XML in eine Datei.
 fn write_roadmap_to_file(state: &mut AppState, path: &str) -> anyhow::Result<()> {
+    let xml_content = export_config_xml(state)?;
+    std::fs::write(path, xml_content)?;
+    Ok(())
+}
+
+/// Serialisiert das aktuelle Netz als AutoDrive-Config (Y-Werte aus der Heightmap).
+pub(super) fn export_config_xml(state: &AppState) -> anyhow::Result<String> {
     let road_map = state
         .road_map
         .as_ref()
         .ok_or_else(|| anyhow::anyhow!("Keine Datei geladen"))?;
 
-    // Lade Heightmap falls ausgewaehlt (Bit-Tiefe & Map-Groesse werden automatisch erkannt)
-    let heightmap = if let Some(ref hm_path) = state.ui.heightmap_path {
-        match crate::core::Heightmap::load(hm_path) {
+    let heightmap = load_export_heightmap(state);
+    let height_scale = super::heightmap::effective_height_scale(state, heightmap.as_ref());
+    crate::xml::write_autodrive_config(road_map, heightmap.as_ref(), height_scale)
+}
+
+/// Laedt die ausgewaehlte Heightmap fuer die Y-Koordinaten eines Exports.
+///
+/// Bit-Tiefe und Map-Groesse werden automatisch erkannt; bei einem Map-Mod-ZIP
+/// kommen Map-Groesse und Hoehenskala aus dem Mod. Ohne (ladbare) Heightmap
+/// werden die Y-Werte auf 0 gesetzt.
+pub(super) fn load_export_heightmap(state: &AppState) -> Option<Heightmap> {
+    if let Some(ref hm_path) = state.ui.heightmap_path {
+        match super::heightmap::load_heightmap(hm_path) {
             Ok(hm) => {
                 log::info!(
                     "Heightmap geladen: {} ({}-Bit, {}x{})",
@@ -151,15 +172,7 @@ fn write_roadmap_to_file(state: &mut AppState, path: &str) -> anyhow::Result<()>
     } else {
         log::info!("Keine Heightmap ausgewaehlt, Y-Werte werden auf 0 gesetzt");
         None
-    };
-
-    let xml_content = crate::xml::write_autodrive_config(
-        road_map,
-        heightmap.as_ref(),
-        state.options.terrain_height_scale,
-    )?;
-    std::fs::write(path, xml_content)?;
-    Ok(())
+    }
 }
 
 /// Speichert mit Heightmap-Pruefung (zeigt Warnung wenn keine Heightmap ausgewaehlt).
@@ -274,4 +287,40 @@ mod tests {
         let _ = fs::remove_file(&input_path);
         let _ = fs::remove_file(&output_path);
     }
+
+    #[test]
+    fn save_uses_map_height_scale_for_png_heightmap() {
+        let sample_path = PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../ad_sample_data/AutoDrive_config-test.xml"
+        ));
+        let heightmap_path = unique_temp_xml_path("height_scale").with_extension("png");
+        let output_path = unique_temp_xml_path("height_scale_output");
+
+        // Weisse Heightmap: jeder Node liegt auf voller Hoehenskala
+        image::GrayImage::from_pixel(3, 3, image::Luma([255u8]))
+            .save(&heightmap_path)
+            .expect("Test-Heightmap muss schreibbar sein");
+
+        let mut state = AppState::new();
+        load_selected_file(&mut state, sample_path.to_string_lossy().into_owned())
+            .expect("Beispiel-XML muss ladbar sein");
+        state.ui.heightmap_path = Some(heightmap_path.to_string_lossy().into_owned());
+        state.ui.map_height_scale = Some(400.0);
+
+        save_file_as(&mut state, output_path.to_string_lossy().into_owned())
+            .expect("Speichern muss gelingen");
+        let written = fs::read_to_string(&output_path).expect("Export muss lesbar sein");
+        let _ = fs::remove_file(&heightmap_path);
+        let _ = fs::remove_file(&output_path);
+
+        let ys = written
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("<y>"))
+            .and_then(|line| line.strip_suffix("</y>"))
+            .expect("Export enthaelt Y-Werte");
+        assert!(ys
+            .split(',')
+            .all(|y| (y.parse::<f32>().expect("Y ist eine Zahl") - 400.0).abs() < 1e-3));
+    }
 }
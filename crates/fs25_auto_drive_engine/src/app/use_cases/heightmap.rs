@@ -2,6 +2,16 @@
 
 use crate::app::ui_contract::{DialogRequest, DialogRequestKind};
 use crate::app::AppState;
+use crate::core::Heightmap;
+use anyhow::Context;
+use image::{imageops::FilterType, DynamicImage};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Dateiname der extrahierten Heightmap neben der Config (wie bei der Auto-Erkennung).
+const EXTRACTED_HEIGHTMAP_FILE: &str = "terrain.heightmap.png";
+/// Ausweich-Dateiname, wenn bereits eine `terrain.heightmap.png` neben der Config liegt.
+const EXTRACTED_HEIGHTMAP_FALLBACK_FILE: &str = "terrain.heightmap.extracted.png";
 
 /// Oeffnet den Heightmap-Auswahl-Dialog.
 pub fn request_heightmap_dialog(state: &mut AppState) {
@@ -13,13 +23,73 @@ pub fn request_heightmap_dialog(state: &mut AppState) {
 /// Entfernt die ausgewaehlte Heightmap.
 pub fn clear_heightmap(state: &mut AppState) {
     state.ui.heightmap_path = None;
+    state.heightmap = None;
     log::info!("Heightmap geloescht");
+    super::background_map::refresh_heightmap_background(state);
 }
 
 /// Setzt die Heightmap auf den angegebenen Pfad.
 pub fn set_heightmap(state: &mut AppState, path: String) {
     state.ui.heightmap_path = Some(path.clone());
     log::info!("Heightmap ausgewaehlt: {}", path);
+    reload_heightmap(state);
+    super::background_map::refresh_heightmap_background(state);
+}
+
+/// Extrahiert das DEM aus einem Map-Mod-ZIP, legt es als
+/// `terrain.heightmap.png` neben der geladenen Config ab und verwendet es.
+///
+/// Eine vorhandene `terrain.heightmap.png` wird nie ueberschrieben; das DEM
+/// landet dann in `terrain.heightmap.extracted.png`. Das DEM wird auf `map_size + 1` Pixel Kantenlaenge skaliert, damit der
+/// PNG-Loader dieselbe Weltgroesse ableitet; die Hoehenskala der Karte wird als
+/// `ui.map_height_scale` uebernommen. Ohne geladene Config schlaegt die
+/// Extraktion fehl.
+pub fn extract_heightmap_from_zip(state: &mut AppState, zip_path: &str) -> anyhow::Result<()> {
+    let config_path = state
+        .ui
+        .current_file_path
+        .as_deref()
+        .context("Keine Config geladen – Zielordner fuer die Heightmap unbekannt")?;
+    let target = extracted_heightmap_path(Path::new(config_path))?;
+
+    let extracted = fs25_map_overview::extract_heightmap_from_zip(zip_path)
+        .with_context(|| format!("Fehler beim Laden des DEM aus: {}", zip_path))?;
+    let offset_warning = (extracted.center_offset != (0.0, 0.0)).then(|| {
+        format!(
+            "Terrain-Mitte um ({:.1}, {:.1}) verschoben – die extrahierte Heightmap geht von (0, 0) aus",
+            extracted.center_offset.0, extracted.center_offset.1
+        )
+    });
+    if let Some(warning) = &offset_warning {
+        log::warn!("{}", warning);
+    }
+
+    resample_dem(&extracted.dem, extracted.map_size + 1)
+        .save(&target)
+        .with_context(|| {
+            format!(
+                "Heightmap konnte nicht gespeichert werden: {}",
+                target.display()
+            )
+        })?;
+    log::info!(
+        "Heightmap aus {} extrahiert: {} (Hoehenskala {})",
+        zip_path,
+        target.display(),
+        extracted.height_scale
+    );
+
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    state.ui.map_height_scale = Some(extracted.height_scale);
+    set_heightmap(state, target.to_string_lossy().into_owned());
+    state.ui.status_message = Some(match offset_warning {
+        Some(warning) => format!("Heightmap als {file_name} extrahiert – {warning}"),
+        None => format!("Heightmap als {file_name} extrahiert"),
+    });
+    Ok(())
 }
 
 /// Blendet die Heightmap-Warnung aus und setzt den ausstehenden Speicherpfad zurueck.
@@ -29,3 +99,209 @@ pub fn dismiss_heightmap_warning(state: &mut AppState) {
         .request_dialog(DialogRequest::DismissHeightmapWarning);
     state.ui.pending_save_path = None;
 }
+
+/// Laedt die Heightmap zu `ui.heightmap_path` nach `AppState::heightmap`.
+///
+/// Ein Ladefehler wird nur geloggt; der Pfad bleibt fuer den Export erhalten.
+pub(crate) fn reload_heightmap(state: &mut AppState) {
+    state.heightmap =
+        state
+            .ui
+            .heightmap_path
+            .as_deref()
+            .and_then(|path| match load_heightmap(path) {
+                Ok(heightmap) => Some(Arc::new(heightmap)),
+                Err(error) => {
+                    log::warn!("Heightmap konnte nicht geladen werden: {}", error);
+                    None
+                }
+            });
+}
+
+/// Laedt die Heightmap von `path`: Bilddatei oder Map-Mod-ZIP (dann mit
+/// Weltgroesse und Hoehenskala aus dem Mod).
+pub(crate) fn load_heightmap(path: &str) -> anyhow::Result<Heightmap> {
+    if is_map_zip_path(path) {
+        Heightmap::load_from_map_zip(path)
+    } else {
+        Heightmap::load(path)
+    }
+}
+
+/// Hoehenskala fuer das Sampling von `heightmap`.
+///
+/// Vorrang hat die Skala aus dem Map-Mod-ZIP der Heightmap, danach die aus der
+/// Map-i3d der generierten Uebersichtskarte, zuletzt die Editor-Einstellung.
+pub(crate) fn effective_height_scale(state: &AppState, heightmap: Option<&Heightmap>) -> f32 {
+    heightmap
+        .and_then(Heightmap::height_scale)
+        .or(state.ui.map_height_scale)
+        .unwrap_or(state.options.terrain_height_scale)
+}
+
+/// Zielpfad der extrahierten Heightmap im Ordner der Config.
+///
+/// Liegt dort bereits eine `terrain.heightmap.png`, wird der Ausweichname
+/// verwendet, damit eine vorhandene Heightmap nicht verloren geht.
+fn extracted_heightmap_path(config_path: &Path) -> anyhow::Result<PathBuf> {
+    let dir = config_path
+        .parent()
+        .context("Config-Pfad ohne Elternordner")?;
+    let target = dir.join(EXTRACTED_HEIGHTMAP_FILE);
+    if target.exists() {
+        return Ok(dir.join(EXTRACTED_HEIGHTMAP_FALLBACK_FILE));
+    }
+    Ok(target)
+}
+
+/// Skaliert das DEM als 16-Bit-Graustufenbild auf `size` × `size` Pixel.
+fn resample_dem(dem: &DynamicImage, size: u32) -> DynamicImage {
+    let luma = dem.to_luma16();
+    if luma.dimensions() == (size, size) {
+        return DynamicImage::ImageLuma16(luma);
+    }
+    DynamicImage::ImageLuma16(image::imageops::resize(
+        &luma,
+        size,
+        size,
+        FilterType::Triangle,
+    ))
+}
+
+/// Prueft, ob der Heightmap-Pfad auf ein Map-Mod-ZIP statt auf ein Bild zeigt.
+fn is_map_zip_path(path: &str) -> bool {
+    std::path::Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    /// Schreibt ein minimales Map-Mod-ZIP (32 m, `heightScale` 400, DEM 17 px) nach `path`.
+    fn write_map_zip(path: &Path) {
+        let mut dem_png = Vec::new();
+        DynamicImage::ImageLuma16(image::ImageBuffer::from_pixel(
+            17,
+            17,
+            image::Luma([32768u16]),
+        ))
+        .write_to(&mut Cursor::new(&mut dem_png), image::ImageFormat::Png)
+        .expect("DEM-PNG muss kodiert werden");
+
+        let mut writer = zip::ZipWriter::new(
+            std::fs::File::create(path).expect("ZIP-Datei muss erstellt werden"),
+        );
+        for (name, bytes) in [
+            (
+                "modDesc.xml",
+                br#"<modDesc><title><en>Hills</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                    .to_vec(),
+            ),
+            (
+                "maps/config/map.xml",
+                br#"<map width="32" height="32"><filename>maps/map.i3d</filename></map>"#.to_vec(),
+            ),
+            (
+                "maps/map.i3d",
+                br#"<i3D><TerrainTransformGroup heightScale="400" unitsPerPixel="2" /></i3D>"#
+                    .to_vec(),
+            ),
+            ("maps/data/dem.png", dem_png),
+        ] {
+            writer
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .expect("ZIP-Eintrag muss angelegt werden");
+            writer
+                .write_all(&bytes)
+                .expect("ZIP-Eintrag muss geschrieben werden");
+        }
+        writer.finish().expect("ZIP muss finalisiert werden");
+    }
+
+    /// Legt einen eindeutigen Testordner im Temp-Verzeichnis an.
+    fn unique_test_dir(prefix: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "{prefix}_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("Systemzeit nach Unix-Epoche")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).expect("Testordner muss angelegt werden");
+        dir
+    }
+
+    #[test]
+    fn extract_heightmap_from_zip_stores_resampled_png_next_to_config() {
+        let dir = unique_test_dir("fs25_extract_heightmap");
+        let zip_path = dir.join("FS25_Hills.zip");
+        write_map_zip(&zip_path);
+
+        let mut state = AppState::new();
+        state.ui.current_file_path = Some(
+            dir.join("AutoDrive_config.xml")
+                .to_string_lossy()
+                .into_owned(),
+        );
+        let result = extract_heightmap_from_zip(&mut state, zip_path.to_str().expect("UTF-8-Pfad"));
+        let target = dir.join(EXTRACTED_HEIGHTMAP_FILE);
+        let stored = image::open(&target);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        result.expect("Extraktion erwartet");
+        let stored = stored.expect("Heightmap-PNG erwartet");
+        // 32 m Karte → 33 px (pixels = map_size + 1), 16 Bit
+        assert_eq!(stored.width(), 33);
+        assert_eq!(stored.height(), 33);
+        assert_eq!(stored.color(), image::ColorType::L16);
+
+        assert_eq!(state.ui.heightmap_path.as_deref(), target.to_str());
+        assert_eq!(state.ui.map_height_scale, Some(400.0));
+        let heightmap = state.heightmap.as_ref().expect("Heightmap geladen");
+        assert_eq!(heightmap.world_bounds().max_x, 16.0);
+        let y = heightmap.sample_height(0.0, 0.0, effective_height_scale(&state, Some(heightmap)));
+        assert!((y - 200.0).abs() < 0.1, "Hoehe {y}");
+        assert!(state.ui.status_message.is_some());
+    }
+
+    #[test]
+    fn extract_heightmap_from_zip_keeps_existing_heightmap() {
+        let dir = unique_test_dir("fs25_extract_heightmap_existing");
+        let zip_path = dir.join("FS25_Hills.zip");
+        write_map_zip(&zip_path);
+        let existing = dir.join(EXTRACTED_HEIGHTMAP_FILE);
+        std::fs::write(&existing, b"vorhandene Heightmap").expect("Datei muss geschrieben werden");
+
+        let mut state = AppState::new();
+        state.ui.current_file_path = Some(
+            dir.join("AutoDrive_config.xml")
+                .to_string_lossy()
+                .into_owned(),
+        );
+        let result = extract_heightmap_from_zip(&mut state, zip_path.to_str().expect("UTF-8-Pfad"));
+        let existing_bytes = std::fs::read(&existing);
+        let fallback = dir.join(EXTRACTED_HEIGHTMAP_FALLBACK_FILE);
+        let stored = image::open(&fallback);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        result.expect("Extraktion erwartet");
+        assert_eq!(
+            existing_bytes.expect("vorhandene Datei erwartet"),
+            b"vorhandene Heightmap"
+        );
+        assert_eq!(stored.expect("Ausweich-PNG erwartet").width(), 33);
+        assert_eq!(state.ui.heightmap_path.as_deref(), fallback.to_str());
+    }
+
+    #[test]
+    fn extract_heightmap_from_zip_requires_loaded_config() {
+        let mut state = AppState::new();
+
+        assert!(extract_heightmap_from_zip(&mut state, "/tmp/missing.zip").is_err());
+        assert!(state.ui.heightmap_path.is_none());
+    }
+}
@@ -0,0 +1,177 @@
+//! Use-Case-Funktionen fuer die Live-Verbindung zum laufenden Spiel.
+//!
+//! Der Host empfaengt Positionsmeldungen des Companion-Mods und reicht sie als
+//! Intent herein. Bei aktiver Aufzeichnung entsteht daraus ein Kurs aus
+//! einseitigen Verbindungen in Fahrtrichtung ("Kurs aufzeichnen waehrend der Fahrt").
+
+use crate::app::state::LivePlayerPose;
+use crate::app::AppState;
+use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
+use glam::Vec2;
+use std::sync::Arc;
+
+/// Uebernimmt eine gemeldete Spielerposition und zeichnet ggf. einen Wegpunkt auf.
+///
+/// Bei aktiver Aufzeichnung wird ein neuer Node angelegt, sobald der Spieler
+/// mindestens `live_record_spacing_m` vom zuletzt aufgezeichneten Node entfernt ist.
+/// Der erste Wegpunkt einer Aufzeichnung setzt an einem bestehenden Node an,
+/// falls einer naeher als dieser Abstand liegt.
+pub fn update_player_pose(state: &mut AppState, position: Vec2, yaw: f32) {
+    state.live_link.player = Some(LivePlayerPose { position, yaw });
+
+    if !state.live_link.recording {
+        return;
+    }
+    let spacing = state.options.live_record_spacing_m;
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+
+    // Ausgangs-Node pruefen (kann durch Undo oder Loeschen verschwunden sein)
+    let last = state
+        .live_link
+        .last_recorded_node
+        .and_then(|id| road_map.node_position(id).map(|pos| (id, pos)));
+    if let Some((_, last_pos)) = last
+        && last_pos.distance(position) < spacing
+    {
+        return;
+    }
+    if last.is_none()
+        && let Some(hit) = road_map.nearest_node(position)
+        && hit.distance < spacing
+    {
+        state.live_link.last_recorded_node = Some(hit.node_id);
+        return;
+    }
+
+    let road_map = Arc::make_mut(state.road_map.as_mut().expect("road_map vorhanden"));
+    let id = road_map.next_node_id();
+    road_map.add_node(MapNode::new(id, position, NodeFlag::Regular));
+    let mut touched_ids = vec![id];
+    if let Some((last_id, last_pos)) = last {
+        road_map.add_connection(Connection::new(
+            last_id,
+            id,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            last_pos,
+            position,
+        ));
+        touched_ids.push(last_id);
+    }
+    road_map.recalculate_node_flags(&touched_ids);
+    road_map.ensure_spatial_index();
+    state.live_link.last_recorded_node = Some(id);
+}
+
+/// Startet bzw. beendet "Kurs aufzeichnen waehrend der Fahrt".
+///
+/// Beim Start wird ein Undo-Snapshot angelegt, damit die ganze Aufzeichnung
+/// in einem Schritt rueckgaengig gemacht werden kann. Ohne geladene Karte
+/// wird die Aufzeichnung nicht gestartet.
+pub fn toggle_course_recording(state: &mut AppState) {
+    if state.live_link.recording {
+        state.live_link.stop_recording();
+        log::info!("Live-Aufzeichnung beendet");
+        return;
+    }
+    if state.road_map.is_none() {
+        log::warn!("Keine RoadMap geladen — Live-Aufzeichnung nicht gestartet");
+        return;
+    }
+
+    state.record_undo_snapshot();
+    state.live_link.recording = true;
+    state.live_link.last_recorded_node = None;
+    log::info!("Live-Aufzeichnung gestartet");
+}
+
+/// Speichert das bearbeitete Netz und merkt den Pfad zum Neuladen im Spiel vor.
+///
+/// Der Host schickt den Pfad beim naechsten Abfragen an den Companion-Mod,
+/// der die Config ohne Neustart des Spiels neu einliest.
+pub fn push_network_to_game(state: &mut AppState) -> anyhow::Result<()> {
+    let path = state
+        .ui
+        .current_file_path
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("Keine gespeicherte Datei — zuerst speichern"))?;
+    super::file_io::save_current_file(state)?;
+    state.live_link.pending_reload = Some(path);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RoadMap;
+
+    fn make_state_with_node() -> AppState {
+        let mut state = AppState::new();
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::ZERO, NodeFlag::Regular));
+        map.ensure_spatial_index();
+        state.road_map = Some(Arc::new(map));
+        state.options.live_record_spacing_m = 5.0;
+        state
+    }
+
+    #[test]
+    fn pose_without_recording_only_updates_player() {
+        let mut state = make_state_with_node();
+
+        update_player_pose(&mut state, Vec2::new(50.0, 0.0), 1.0);
+
+        let player = state.live_link.player.expect("Spielerposition");
+        assert_eq!(player.position, Vec2::new(50.0, 0.0));
+        assert_eq!(state.road_map.as_deref().unwrap().node_count(), 1);
+    }
+
+    #[test]
+    fn recording_starts_at_nearby_node_and_respects_spacing() {
+        let mut state = make_state_with_node();
+        toggle_course_recording(&mut state);
+        assert!(state.live_link.recording);
+        assert!(state.can_undo());
+
+        // Start neben dem bestehenden Node → setzt dort an
+        update_player_pose(&mut state, Vec2::new(1.0, 0.0), 0.0);
+        assert_eq!(state.live_link.last_recorded_node, Some(1));
+        // Zu nah → kein neuer Node
+        update_player_pose(&mut state, Vec2::new(4.0, 0.0), 0.0);
+        assert_eq!(state.road_map.as_deref().unwrap().node_count(), 1);
+        // Weit genug → neuer Node mit Einbahn-Verbindung in Fahrtrichtung
+        update_player_pose(&mut state, Vec2::new(6.0, 0.0), 0.0);
+
+        let road_map = state.road_map.as_deref().unwrap();
+        assert_eq!(road_map.node_count(), 2);
+        let new_id = state.live_link.last_recorded_node.expect("neuer Node");
+        let connection = road_map
+            .find_connection(1, new_id)
+            .expect("Verbindung vom Start-Node");
+        assert_eq!(connection.direction, ConnectionDirection::Regular);
+
+        toggle_course_recording(&mut state);
+        assert!(!state.live_link.recording);
+        assert_eq!(state.live_link.last_recorded_node, None);
+    }
+
+    #[test]
+    fn recording_is_not_started_without_road_map() {
+        let mut state = AppState::new();
+        toggle_course_recording(&mut state);
+        assert!(!state.live_link.recording);
+    }
+
+    #[test]
+    fn push_requires_saved_file() {
+        let mut state = make_state_with_node();
+        assert!(push_network_to_game(&mut state).is_err());
+        assert_eq!(state.live_link.pending_reload, None);
+    }
+}
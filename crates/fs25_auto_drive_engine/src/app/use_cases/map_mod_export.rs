@@ -0,0 +1,335 @@
+//! Use-Case-Funktionen fuer den Export des Netzes als Standard-Config eines Mods.
+//!
+//! Kartenersteller liefern ein vorgefertigtes AutoDrive-Netz als
+//! `AutoDrive_config.xml` im Wurzelverzeichnis des Map-Mods aus; AutoDrive
+//! uebernimmt es beim ersten Start eines Savegames ohne eigene Config.
+//! Alternativ entsteht ein eigenstaendiger Kurs-Mod aus `modDesc.xml` und Config.
+
+use crate::app::ui_contract::{DialogRequest, DialogRequestKind};
+use crate::app::AppState;
+use crate::xml::write_courses_mod_desc;
+use anyhow::Context;
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+
+/// Pfad der Standard-Config im Mod-Archiv.
+const DEFAULT_CONFIG_ENTRY: &str = "AutoDrive_config.xml";
+/// Pfad der Mod-Beschreibung im Archiv des Kurs-Mods.
+const MOD_DESC_ENTRY: &str = "modDesc.xml";
+
+/// Fordert die Auswahl des Map-Mod-ZIPs an, in das die Standard-Config kommt.
+pub fn request_map_mod_zip(state: &mut AppState) {
+    state
+        .ui
+        .request_dialog(DialogRequest::pick_path(DialogRequestKind::MapModZip));
+}
+
+/// Fordert den Speicherdialog fuer den Kurs-Mod mit Dateinamenvorschlag an.
+pub fn request_courses_mod_export(state: &mut AppState) {
+    let file_name = suggested_courses_mod_file_name(state);
+    state.ui.request_dialog(DialogRequest::PickPath {
+        kind: DialogRequestKind::CoursesModExport,
+        suggested_file_name: Some(file_name),
+    });
+}
+
+/// Schreibt das aktuelle Netz als Standard-Config in ein bestehendes Map-Mod-ZIP.
+///
+/// Alle uebrigen Eintraege werden unveraendert (ohne Neukomprimierung) kopiert,
+/// eine vorhandene Standard-Config wird ersetzt. Das urspruengliche Archiv
+/// bleibt als `<name>.zip.bak` daneben erhalten; eine bereits vorhandene
+/// Sicherung wird nicht ueberschrieben, damit wiederholte Exporte das Original
+/// nicht verdraengen. Bei Fehlern wird `<name>.zip.tmp` wieder entfernt.
+pub fn export_into_map_zip(state: &mut AppState, zip_path: &str) -> anyhow::Result<()> {
+    let config_xml = super::file_io::export_config_xml(state)?;
+    let zip_path = Path::new(zip_path);
+    let tmp_path = sibling_path(zip_path, "tmp");
+    let backup_path = sibling_path(zip_path, "bak");
+
+    let mut archive =
+        zip::ZipArchive::new(BufReader::new(File::open(zip_path).with_context(|| {
+            format!("Map-Mod-ZIP nicht lesbar: {}", zip_path.display())
+        })?))
+        .with_context(|| format!("Kein gueltiges ZIP: {}", zip_path.display()))?;
+    if !(0..archive.len()).any(|i| {
+        archive
+            .name_for_index(i)
+            .is_some_and(|name| name.eq_ignore_ascii_case(MOD_DESC_ENTRY))
+    }) {
+        anyhow::bail!(
+            "Kein Map-Mod (modDesc.xml fehlt im Wurzelverzeichnis): {}",
+            zip_path.display()
+        );
+    }
+
+    let replaced = match write_map_zip_copy(&mut archive, &tmp_path, &config_xml) {
+        Ok(replaced) => replaced,
+        Err(err) => {
+            let _ = std::fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+    };
+    drop(archive);
+
+    let backup_created = !backup_path.exists();
+    if backup_created && let Err(err) = std::fs::rename(zip_path, &backup_path) {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(err)
+            .with_context(|| format!("Sicherung nicht moeglich: {}", backup_path.display()));
+    }
+    if let Err(err) = std::fs::rename(&tmp_path, zip_path) {
+        let _ = std::fs::remove_file(&tmp_path);
+        if backup_created {
+            // Original zurueckholen, damit das Map-Mod-ZIP nicht fehlt
+            let _ = std::fs::rename(&backup_path, zip_path);
+        }
+        return Err(err).with_context(|| format!("ZIP nicht ersetzbar: {}", zip_path.display()));
+    }
+
+    let message = format!(
+        "Standard-Config {} in {} (Sicherung: {}{})",
+        if replaced { "ersetzt" } else { "eingefuegt" },
+        zip_path.display(),
+        backup_path.display(),
+        if backup_created { "" } else { ", bestehend" }
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+    Ok(())
+}
+
+/// Kopiert alle Eintraege ausser der Standard-Config nach `tmp_path` und haengt
+/// `config_xml` an. Liefert, ob eine vorhandene Standard-Config ersetzt wurde.
+fn write_map_zip_copy(
+    archive: &mut zip::ZipArchive<BufReader<File>>,
+    tmp_path: &Path,
+    config_xml: &str,
+) -> anyhow::Result<bool> {
+    let mut writer = zip::ZipWriter::new(
+        File::create(tmp_path)
+            .with_context(|| format!("ZIP nicht anlegbar: {}", tmp_path.display()))?,
+    );
+    let mut replaced = false;
+    for i in 0..archive.len() {
+        let entry = archive.by_index_raw(i)?;
+        if entry.name().eq_ignore_ascii_case(DEFAULT_CONFIG_ENTRY) {
+            replaced = true;
+            continue;
+        }
+        writer.raw_copy_file(entry)?;
+    }
+    writer.start_file(DEFAULT_CONFIG_ENTRY, SimpleFileOptions::default())?;
+    writer.write_all(config_xml.as_bytes())?;
+    writer.finish()?;
+    Ok(replaced)
+}
+
+/// Schreibt das aktuelle Netz als eigenstaendigen Kurs-Mod nach `zip_path`.
+///
+/// Das Archiv enthaelt `modDesc.xml` (Abhaengigkeit auf AutoDrive) und die
+/// Standard-Config im Wurzelverzeichnis.
+pub fn export_courses_mod(state: &mut AppState, zip_path: &str) -> anyhow::Result<()> {
+    let config_xml = super::file_io::export_config_xml(state)?;
+    let map_name = export_map_name(state);
+
+    let mut writer = zip::ZipWriter::new(
+        File::create(zip_path).with_context(|| format!("ZIP nicht anlegbar: {}", zip_path))?,
+    );
+    writer.start_file(MOD_DESC_ENTRY, SimpleFileOptions::default())?;
+    writer.write_all(write_courses_mod_desc(&map_name).as_bytes())?;
+    writer.start_file(DEFAULT_CONFIG_ENTRY, SimpleFileOptions::default())?;
+    writer.write_all(config_xml.as_bytes())?;
+    writer.finish()?;
+
+    let message = format!("Kurs-Mod exportiert: {}", zip_path);
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+    Ok(())
+}
+
+/// Schlaegt einen Dateinamen fuer den Kurs-Mod vor (`FS25_AutoDrive_<Karte>.zip`).
+///
+/// FS25 erwartet Mod-Namen aus ASCII-Buchstaben, Ziffern und `_`.
+pub fn suggested_courses_mod_file_name(state: &AppState) -> String {
+    let map: String = export_map_name(state)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("FS25_AutoDrive_{map}.zip")
+}
+
+/// Kartenname des geladenen Netzes; ohne Angabe ein neutraler Platzhalter.
+fn export_map_name(state: &AppState) -> String {
+    state
+        .road_map
+        .as_deref()
+        .and_then(|road_map| road_map.map_name.clone())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "Map".to_string())
+}
+
+/// Haengt `suffix` an den Dateinamen an (`map.zip` → `map.zip.<suffix>`).
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+    use std::io::Read;
+    use std::sync::Arc;
+
+    fn make_state() -> AppState {
+        let mut state = AppState::new();
+        let mut map = RoadMap::new(3);
+        map.map_name = Some("Riverbend Springs".to_string());
+        map.add_node(MapNode::new(1, Vec2::ZERO, NodeFlag::Regular));
+        state.road_map = Some(Arc::new(map));
+        state
+    }
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "fs25_ad_editor_map_mod_{label}_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read_entry(zip_path: &Path, name: &str) -> Option<String> {
+        let mut archive = zip::ZipArchive::new(File::open(zip_path).unwrap()).unwrap();
+        let mut entry = archive.by_name(name).ok()?;
+        let mut content = String::new();
+        entry.read_to_string(&mut content).unwrap();
+        Some(content)
+    }
+
+    #[test]
+    fn suggests_mod_file_name_from_map_name() {
+        assert_eq!(
+            suggested_courses_mod_file_name(&make_state()),
+            "FS25_AutoDrive_Riverbend_Springs.zip"
+        );
+        assert_eq!(
+            suggested_courses_mod_file_name(&AppState::new()),
+            "FS25_AutoDrive_Map.zip"
+        );
+    }
+
+    #[test]
+    fn courses_mod_contains_mod_desc_and_config() {
+        let dir = temp_dir("courses");
+        let zip_path = dir.join("FS25_AutoDrive_Test.zip");
+        let mut state = make_state();
+
+        export_courses_mod(&mut state, zip_path.to_str().unwrap()).expect("Export");
+
+        let mod_desc = read_entry(&zip_path, MOD_DESC_ENTRY).expect("modDesc.xml");
+        assert!(mod_desc.contains("Riverbend Springs"));
+        let config = read_entry(&zip_path, DEFAULT_CONFIG_ENTRY).expect("Config");
+        assert!(config.contains("<AutoDrive>"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn map_zip_export_replaces_config_and_keeps_other_entries() {
+        let dir = temp_dir("map_zip");
+        let zip_path = dir.join("FS25_Riverbend.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        for (name, content) in [
+            (MOD_DESC_ENTRY, "<modDesc/>"),
+            ("maps/map.i3d", "<i3D/>"),
+            (DEFAULT_CONFIG_ENTRY, "alt"),
+        ] {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+        let mut state = make_state();
+
+        export_into_map_zip(&mut state, zip_path.to_str().unwrap()).expect("Export");
+
+        assert_eq!(
+            read_entry(&zip_path, "maps/map.i3d").as_deref(),
+            Some("<i3D/>")
+        );
+        let config = read_entry(&zip_path, DEFAULT_CONFIG_ENTRY).expect("Config");
+        assert!(config.contains("<AutoDrive>"));
+        let backup = sibling_path(&zip_path, "bak");
+        assert_eq!(
+            read_entry(&backup, DEFAULT_CONFIG_ENTRY).as_deref(),
+            Some("alt")
+        );
+
+        // Zweiter Export darf die Sicherung des Originals nicht ueberschreiben
+        export_into_map_zip(&mut state, zip_path.to_str().unwrap()).expect("Erneuter Export");
+        assert_eq!(
+            read_entry(&backup, DEFAULT_CONFIG_ENTRY).as_deref(),
+            Some("alt")
+        );
+        assert!(read_entry(&zip_path, DEFAULT_CONFIG_ENTRY)
+            .expect("Config")
+            .contains("<AutoDrive>"));
+        assert!(!sibling_path(&zip_path, "tmp").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn map_zip_export_rejects_archives_without_mod_desc() {
+        let dir = temp_dir("no_mod");
+        let zip_path = dir.join("random.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        writer
+            .start_file("readme.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.finish().unwrap();
+        let mut state = make_state();
+
+        assert!(export_into_map_zip(&mut state, zip_path.to_str().unwrap()).is_err());
+        assert!(!sibling_path(&zip_path, "bak").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn map_zip_export_removes_tmp_file_when_copy_fails() {
+        let dir = temp_dir("broken_entry");
+        let zip_path = dir.join("FS25_Broken.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        for (name, content) in [(MOD_DESC_ENTRY, "<modDesc/>"), ("maps/map.i3d", "<i3D/>")] {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+        // Lokalen Header des zweiten Eintrags zerstoeren; das Zentralverzeichnis bleibt lesbar
+        let mut bytes = std::fs::read(&zip_path).unwrap();
+        let header = bytes
+            .windows(4)
+            .rposition(|window| window == b"PK\x03\x04")
+            .unwrap();
+        bytes[header..header + 4].copy_from_slice(b"XXXX");
+        std::fs::write(&zip_path, bytes).unwrap();
+        let mut state = make_state();
+
+        assert!(export_into_map_zip(&mut state, zip_path.to_str().unwrap()).is_err());
+        assert!(!sibling_path(&zip_path, "tmp").exists());
+        assert!(!sibling_path(&zip_path, "bak").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,66 @@
+//! Use-Case-Funktionen fuer das Mess-Werkzeug (Lineal).
+//!
+//! Die Messlinie ist rein temporaer: Sie erzeugt keine Nodes, landet nicht im
+//! Undo-Verlauf und wird per Esc oder beim Werkzeugwechsel verworfen.
+
+use crate::app::AppState;
+use glam::Vec2;
+
+/// Haengt einen Messpunkt an und meldet die Gesamtlaenge in der Statusleiste.
+pub fn add_measure_point(state: &mut AppState, world_pos: Vec2) {
+    let points = &mut state.editor.measure_points;
+    points.push(world_pos);
+    if points.len() < 2 {
+        state.ui.status_message = Some("Messen: weiteren Punkt setzen (Esc verwirft)".to_string());
+        return;
+    }
+    state.ui.status_message = Some(format!(
+        "Messen: {} Segment(e), gesamt {:.1} m",
+        points.len() - 1,
+        measured_length(points)
+    ));
+}
+
+/// Verwirft alle Messpunkte.
+pub fn clear_measure_points(state: &mut AppState) {
+    if state.editor.measure_points.is_empty() {
+        return;
+    }
+    state.editor.measure_points.clear();
+    state.ui.status_message = None;
+}
+
+/// Gesamtlaenge der Messlinie in Metern.
+pub fn measured_length(points: &[Vec2]) -> f32 {
+    points
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_points_accumulate_and_clear() {
+        let mut state = AppState::new();
+
+        add_measure_point(&mut state, Vec2::ZERO);
+        add_measure_point(&mut state, Vec2::new(3.0, 4.0));
+        add_measure_point(&mut state, Vec2::new(3.0, 10.0));
+
+        assert_eq!(state.editor.measure_points.len(), 3);
+        assert!((measured_length(&state.editor.measure_points) - 11.0).abs() < 1e-5);
+        assert!(state
+            .ui
+            .status_message
+            .as_deref()
+            .is_some_and(|message| message.contains("11.0 m")));
+        assert!(state.road_map.is_none());
+
+        clear_measure_points(&mut state);
+        assert!(state.editor.measure_points.is_empty());
+        assert!(state.ui.status_message.is_none());
+    }
+}
@@ -14,9 +14,25 @@ pub mod editing;
 pub mod file_io;
 /// Use-Cases fuer Heightmap-Verwaltung (Setzen, Dialog, Warnung).
 pub mod heightmap;
+/// Use-Case-Funktionen fuer die Live-Verbindung zum laufenden Spiel.
+pub mod live_link;
+/// Use-Case-Funktionen fuer den Export als Standard-Config eines Map- oder Kurs-Mods.
+pub mod map_mod_export;
+/// Use-Case-Funktionen fuer das Mess-Werkzeug (Lineal).
+pub mod measure;
 /// Use-Case-Funktionen fuer Editor-Optionen und deren Persistenz.
 pub mod options;
+/// Use-Case-Funktionen fuer den mehrseitigen PDF-Kartenexport.
+pub mod pdf_export;
+/// Use-Case-Funktionen fuer den AutoDrive-Routen-Manager (Routen auflisten, importieren, exportieren).
+pub mod routes_manager;
+/// Use-Case-Funktionen fuer Farmland-Besitzverhaeltnisse aus der `farmland.xml` des Savegames.
+pub mod savegame_farmlands;
+/// Use-Case-Funktionen fuer die Savegame-Auswahl im FS25-Profil.
+pub mod savegame_picker;
+/// Use-Case-Funktionen fuer Fahrzeugpositionen aus der `vehicles.xml` des Savegames.
+pub mod savegame_vehicles;
 /// Use-Case-Funktionen fuer Node-Selektion (Pick, Rect, Lasso, Move).
 pub mod selection;
-/// Use-Cases fuer Viewport-Groesse und Render-Qualitaet.
+/// Use-Cases fuer Viewport-Groesse, Render-Qualitaet und Isolier-Modus.
 pub mod viewport;
@@ -0,0 +1,135 @@
+//! Use-Case-Funktionen fuer den mehrseitigen PDF-Kartenexport.
+//!
+//! Ablauf: Dialog oeffnen (Kartenausdehnung erfassen) → Einstellungen
+//! bestaetigen (Speicherdialog anfordern) → Pfad gewaehlt (PDF schreiben).
+
+use crate::app::ui_contract::{DialogRequest, DialogRequestKind};
+use crate::app::AppState;
+use crate::pdf::{map_print_bounds, write_map_pdf};
+use crate::shared::PdfExportOptions;
+use anyhow::Context;
+
+/// Oeffnet den Dialog "Als PDF exportieren..." fuer die geladene Karte.
+///
+/// Die zuletzt gewaehlten Einstellungen bleiben erhalten; ohne Nodes wird
+/// der Dialog nicht geoeffnet.
+pub fn open_pdf_export_dialog(state: &mut AppState) {
+    let Some((min, max)) = state.road_map.as_deref().and_then(map_print_bounds) else {
+        state.ui.status_message = Some("PDF-Export: keine Karte mit Nodes geladen".to_string());
+        return;
+    };
+    let dialog = &mut state.ui.pdf_export_dialog;
+    dialog.map_extent = max - min;
+    dialog.visible = true;
+}
+
+/// Uebernimmt die Einstellungen, schliesst den Dialog und fordert den
+/// Speicherdialog mit Dateinamenvorschlag an.
+pub fn request_pdf_export_path(state: &mut AppState, options: PdfExportOptions) {
+    state.ui.pdf_export_dialog.options = options;
+    state.ui.pdf_export_dialog.visible = false;
+    let file_name = suggested_pdf_file_name(state);
+    state.ui.request_dialog(DialogRequest::PickPath {
+        kind: DialogRequestKind::PdfExport,
+        suggested_file_name: Some(file_name),
+    });
+}
+
+/// Schreibt die Karte mit den zuletzt bestaetigten Einstellungen nach `path`.
+pub fn export_map_pdf(state: &mut AppState, path: &str) -> anyhow::Result<()> {
+    let road_map = state
+        .road_map
+        .as_deref()
+        .context("PDF-Export: keine Karte geladen")?;
+    let pdf = write_map_pdf(road_map, &state.ui.pdf_export_dialog.options)?;
+    std::fs::write(path, &pdf.bytes).with_context(|| format!("PDF nicht schreibbar: {}", path))?;
+
+    let message = format!("PDF exportiert: {} Seite(n) nach {}", pdf.page_count, path);
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+    Ok(())
+}
+
+/// Schlaegt `<Karte>.pdf` als Dateinamen vor.
+fn suggested_pdf_file_name(state: &AppState) -> String {
+    let map = state
+        .road_map
+        .as_deref()
+        .and_then(|road_map| road_map.map_name.clone())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "AutoDrive".to_string());
+    let map: String = map
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{map}.pdf")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+    use std::sync::Arc;
+
+    #[test]
+    fn export_flow_requests_named_path_and_writes_pdf() {
+        let mut state = AppState::new();
+        let mut map = RoadMap::new(3);
+        map.map_name = Some("Riverbend Springs".to_string());
+        map.add_node(MapNode::new(1, Vec2::ZERO, NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(300.0, 100.0), NodeFlag::Regular));
+        state.road_map = Some(Arc::new(map));
+
+        open_pdf_export_dialog(&mut state);
+        assert!(state.ui.pdf_export_dialog.visible);
+        assert_eq!(
+            state.ui.pdf_export_dialog.map_extent,
+            Vec2::new(340.0, 140.0)
+        );
+
+        let options = PdfExportOptions {
+            scale_denominator: 1000,
+            ..PdfExportOptions::default()
+        };
+        request_pdf_export_path(&mut state, options);
+        assert!(!state.ui.pdf_export_dialog.visible);
+        let requests = state.ui.take_dialog_requests();
+        assert_eq!(
+            requests,
+            vec![DialogRequest::PickPath {
+                kind: DialogRequestKind::PdfExport,
+                suggested_file_name: Some("Riverbend_Springs.pdf".to_string()),
+            }]
+        );
+
+        let path = std::env::temp_dir().join(format!(
+            "fs25_ad_editor_pdf_export_{}.pdf",
+            std::process::id()
+        ));
+        export_map_pdf(&mut state, path.to_str().unwrap()).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(bytes.starts_with(b"%PDF-"));
+        // 340 m Breite bei rund 277 m pro A4-Querseite (1:1000) → 2 Seiten.
+        assert!(state
+            .ui
+            .status_message
+            .as_deref()
+            .is_some_and(|message| message.contains("2 Seite(n)")));
+    }
+
+    #[test]
+    fn dialog_stays_closed_without_nodes() {
+        let mut state = AppState::new();
+        open_pdf_export_dialog(&mut state);
+        assert!(!state.ui.pdf_export_dialog.visible);
+        assert!(state.ui.status_message.is_some());
+    }
+}
@@ -0,0 +1,437 @@
+//! Use-Case-Funktionen fuer den AutoDrive-Routen-Manager im Spielprofil.
+//!
+//! AutoDrive tauscht Strassennetze zwischen Savegames ueber den Ordner
+//! `autoDrive/routesManager/` im FS25-Profil aus (Liste in `routes.xml`,
+//! Routen-Dateien im Unterordner `routes/`). Der Editor listet diese Routen,
+//! importiert eine davon als aktuelles Netz oder exportiert das aktuelle Netz
+//! als neue bzw. ueberarbeitete Route.
+
+use crate::app::state::RoutesDialogState;
+use crate::app::{AppState, GroupRegistry, ToolEditStore};
+use crate::core::StoredRoute;
+use crate::xml::{parse_autodrive_route, parse_routes_index, write_routes_index};
+use anyhow::{bail, Context};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Routen-Manager-Ordner relativ zum FS25-Profil.
+const ROUTES_MANAGER_DIR: &str = "autoDrive/routesManager";
+/// Name der Routen-Liste im Routen-Manager-Ordner.
+const ROUTES_INDEX_FILE: &str = "routes.xml";
+/// Unterordner mit den einzelnen Routen-Dateien.
+const ROUTES_SUBDIR: &str = "routes";
+
+/// Ermittelt den Routen-Manager-Ordner relativ zur geladenen Config.
+///
+/// Erwartet die Savegame-Struktur:
+/// `.../FarmingSimulator2025/savegameN/AutoDrive_config.xml`
+/// → Routen-Ordner: `.../FarmingSimulator2025/autoDrive/routesManager/`
+///
+/// Gibt `None` zurueck, wenn der Ordner nicht existiert (AutoDrive legt ihn
+/// beim ersten Spielstart mit dem Mod an).
+pub fn resolve_routes_dir(xml_path: &Path) -> Option<PathBuf> {
+    let profile_dir = xml_path.parent()?.parent()?;
+    let routes_dir = profile_dir.join(ROUTES_MANAGER_DIR);
+    routes_dir.is_dir().then_some(routes_dir)
+}
+
+/// Liest die Routen-Liste aus `routes_dir`; eine fehlende `routes.xml` ergibt eine leere Liste.
+pub fn list_stored_routes(routes_dir: &Path) -> anyhow::Result<Vec<StoredRoute>> {
+    let index_path = routes_dir.join(ROUTES_INDEX_FILE);
+    if !index_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&index_path)
+        .with_context(|| format!("Fehler beim Lesen von {}", index_path.display()))?;
+    parse_routes_index(&content)
+}
+
+/// Oeffnet den Routen-Dialog mit den Routen des zur Config gehoerenden Profils.
+///
+/// Ohne geladene Config oder ohne Routen-Ordner bleibt der Dialog geschlossen
+/// und eine Statusmeldung erklaert den Grund.
+pub fn open_routes_dialog(state: &mut AppState) {
+    let Some(routes_dir) = state
+        .ui
+        .current_file_path
+        .as_deref()
+        .and_then(|path| resolve_routes_dir(Path::new(path)))
+    else {
+        log::warn!("AutoDrive-Routen-Ordner nicht gefunden");
+        state.ui.status_message = Some(format!(
+            "AutoDrive-Routen-Ordner nicht gefunden (erwartet: <Profil>/{})",
+            ROUTES_MANAGER_DIR
+        ));
+        return;
+    };
+
+    let routes = match list_stored_routes(&routes_dir) {
+        Ok(routes) => routes,
+        Err(error) => {
+            log::warn!("AutoDrive-Routen konnten nicht gelesen werden: {:#}", error);
+            Vec::new()
+        }
+    };
+    log::info!(
+        "{} AutoDrive-Routen in {} gefunden",
+        routes.len(),
+        routes_dir.display()
+    );
+
+    let export_name = state
+        .road_map
+        .as_deref()
+        .and_then(|road_map| road_map.map_name.clone())
+        .unwrap_or_default();
+    state.ui.routes_dialog = Some(RoutesDialogState {
+        routes_dir: routes_dir.to_string_lossy().into_owned(),
+        routes,
+        selected: None,
+        export_name,
+    });
+}
+
+/// Schliesst den Routen-Dialog.
+pub fn close_routes_dialog(state: &mut AppState) {
+    state.ui.routes_dialog = None;
+}
+
+/// Ersetzt das aktuelle Netz durch die Route `file_name` aus dem Routen-Dialog.
+///
+/// Version, Map-Name und Optionen der geladenen Config bleiben erhalten, damit
+/// das Ergebnis wieder als Config des Savegames gespeichert werden kann.
+/// Der Import ist ein einzelner Undo-Schritt; Gruppen und Tool-Edit-Daten
+/// beziehen sich auf das alte Netz und werden verworfen.
+pub fn import_stored_route(state: &mut AppState, file_name: &str) -> anyhow::Result<()> {
+    let routes_dir = open_dialog_routes_dir(state)?;
+    let Some(current) = state.road_map.as_deref() else {
+        bail!("Keine Config geladen — Route kann nicht importiert werden");
+    };
+
+    let path = routes_dir
+        .join(ROUTES_SUBDIR)
+        .join(plain_file_name(file_name)?);
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Fehler beim Lesen von {}", path.display()))?;
+    let mut road_map = parse_autodrive_route(&content)
+        .with_context(|| format!("Route {} ist ungueltig", path.display()))?;
+    road_map.version = current.version;
+    road_map.map_name = current.map_name.clone();
+    road_map.meta = current.meta.clone();
+
+    let message = format!(
+        "Route '{}' importiert: {} Nodes, {} Verbindungen, {} Marker",
+        file_name,
+        road_map.node_count(),
+        road_map.connection_count(),
+        road_map.map_markers().len()
+    );
+
+    state.record_undo_snapshot();
+    state.road_map = Some(Arc::new(road_map));
+    state.selection.ids_mut().clear();
+    state.group_registry = GroupRegistry::new();
+    state.locked_node_ids.clear();
+    state.view.clear_isolation();
+    state.ui.path_offset.deactivate();
+    state.tool_edit_store = ToolEditStore::new();
+    state.live_link.stop_recording();
+    state.ui.routes_dialog = None;
+
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+    Ok(())
+}
+
+/// Exportiert das aktuelle Netz als Route `name` in den Routen-Manager.
+///
+/// Existiert bereits eine gleichnamige Route, wird deren Datei ueberschrieben
+/// und die Revision erhoeht; sonst wird ein neuer Eintrag mit einem im Index
+/// eindeutigen Dateinamen angelegt.
+pub fn export_stored_route(state: &mut AppState, name: &str) -> anyhow::Result<()> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Routen-Name darf nicht leer sein");
+    }
+    let routes_dir = open_dialog_routes_dir(state)?;
+    let Some(road_map) = state.road_map.as_deref() else {
+        bail!("Keine Config geladen — nichts zu exportieren");
+    };
+
+    let mut routes = list_stored_routes(&routes_dir)?;
+    let date = format_route_date(SystemTime::now());
+    let map = road_map.map_name.clone().unwrap_or_default();
+    let entry = match routes.iter_mut().find(|route| route.name == name) {
+        Some(existing) => {
+            existing.revision += 1;
+            existing.map = map;
+            existing.date = date;
+            existing.clone()
+        }
+        None => {
+            let entry = StoredRoute {
+                name: name.to_string(),
+                file_name: unique_route_file_name(name, &routes),
+                map,
+                revision: 1,
+                date,
+            };
+            routes.push(entry.clone());
+            entry
+        }
+    };
+
+    let heightmap = super::file_io::load_export_heightmap(state);
+    let height_scale = super::heightmap::effective_height_scale(state, heightmap.as_ref());
+    let xml_content =
+        crate::xml::write_autodrive_route(road_map, heightmap.as_ref(), height_scale)?;
+
+    // Dateinamen aus dem Index stammen aus fremden Quellen — wie beim Import pruefen
+    let route_path = routes_dir
+        .join(ROUTES_SUBDIR)
+        .join(plain_file_name(&entry.file_name)?);
+    std::fs::create_dir_all(routes_dir.join(ROUTES_SUBDIR))?;
+    std::fs::write(&route_path, xml_content)
+        .with_context(|| format!("Fehler beim Schreiben von {}", route_path.display()))?;
+    std::fs::write(
+        routes_dir.join(ROUTES_INDEX_FILE),
+        write_routes_index(&routes),
+    )?;
+
+    let message = format!(
+        "Route '{}' exportiert (Revision {})",
+        entry.name, entry.revision
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+    state.ui.routes_dialog = None;
+    Ok(())
+}
+
+/// Routen-Ordner des offenen Dialogs.
+fn open_dialog_routes_dir(state: &AppState) -> anyhow::Result<PathBuf> {
+    state
+        .ui
+        .routes_dialog
+        .as_ref()
+        .map(|dialog| PathBuf::from(&dialog.routes_dir))
+        .context("Routen-Dialog ist nicht geoeffnet")
+}
+
+/// Lehnt Dateinamen mit Pfadanteilen ab, damit nur der `routes`-Ordner gelesen wird.
+fn plain_file_name(file_name: &str) -> anyhow::Result<&str> {
+    match Path::new(file_name).file_name() {
+        Some(plain) if plain == file_name => Ok(file_name),
+        _ => bail!("Ungueltiger Routen-Dateiname: {}", file_name),
+    }
+}
+
+/// Leitet einen Dateinamen aus dem Routen-Namen ab (nur ASCII-Buchstaben, Ziffern, `-` und `_`).
+fn route_file_name(name: &str) -> String {
+    let stem: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{stem}.xml")
+}
+
+/// Wie [`route_file_name`], haengt aber bei Kollision mit einem Index-Eintrag
+/// `_2`, `_3`, … an (z.B. "Hof A" und "Hof_A").
+fn unique_route_file_name(name: &str, routes: &[StoredRoute]) -> String {
+    let base = route_file_name(name);
+    let taken = |candidate: &str| {
+        routes
+            .iter()
+            .any(|route| route.file_name.eq_ignore_ascii_case(candidate))
+    };
+    if !taken(&base) {
+        return base;
+    }
+    let stem = base.trim_end_matches(".xml");
+    (2..)
+        .map(|suffix| format!("{stem}_{suffix}.xml"))
+        .find(|candidate| !taken(candidate))
+        .expect("unendlicher Suffix-Bereich")
+}
+
+/// Formatiert einen Zeitpunkt wie AutoDrive (`JJJJ/MM/TT hh:mm:ss`, UTC).
+fn format_route_date(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let (days, day_secs) = (secs / 86_400, secs % 86_400);
+
+    // Tage seit 1970-01-01 → Kalenderdatum (Algorithmus "civil_from_days")
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}/{:02}/{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        day_secs / 3_600,
+        day_secs % 3_600 / 60,
+        day_secs % 60
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+    use std::fs;
+    use std::time::Duration;
+
+    /// Legt Profil, Savegame-Config und Routen-Ordner in einem Temp-Verzeichnis an.
+    fn make_profile(label: &str) -> (PathBuf, AppState) {
+        let profile = std::env::temp_dir().join(format!(
+            "fs25_ad_editor_routes_{label}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&profile);
+        fs::create_dir_all(profile.join("savegame1")).unwrap();
+        fs::create_dir_all(profile.join(ROUTES_MANAGER_DIR)).unwrap();
+
+        let mut state = AppState::new();
+        let mut map = RoadMap::new(3);
+        map.map_name = Some("Riverbend Springs".to_string());
+        map.add_node(MapNode::new(1, Vec2::ZERO, NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+        map.ensure_spatial_index();
+        state.road_map = Some(Arc::new(map));
+        state.ui.current_file_path = Some(
+            profile
+                .join("savegame1/AutoDrive_config.xml")
+                .to_string_lossy()
+                .into_owned(),
+        );
+        (profile, state)
+    }
+
+    #[test]
+    fn formats_route_date_like_autodrive() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_738_346_652);
+        assert_eq!(format_route_date(time), "2025/01/31 18:04:12");
+        assert_eq!(format_route_date(UNIX_EPOCH), "1970/01/01 00:00:00");
+    }
+
+    #[test]
+    fn route_file_names_are_sanitized_and_paths_rejected() {
+        assert_eq!(route_file_name("Hof & Feld 2"), "Hof___Feld_2.xml");
+        assert!(plain_file_name("Hof.xml").is_ok());
+        assert!(plain_file_name("../AutoDrive_config.xml").is_err());
+    }
+
+    #[test]
+    fn dialog_stays_closed_without_routes_dir() {
+        let mut state = AppState::new();
+        open_routes_dialog(&mut state);
+        assert!(state.ui.routes_dialog.is_none());
+        assert!(state.ui.status_message.is_some());
+    }
+
+    #[test]
+    fn export_then_import_roundtrips_through_routes_manager() {
+        let (profile, mut state) = make_profile("roundtrip");
+
+        open_routes_dialog(&mut state);
+        let dialog = state.ui.routes_dialog.as_ref().expect("Dialog offen");
+        assert!(dialog.routes.is_empty());
+        assert_eq!(dialog.export_name, "Riverbend Springs");
+
+        export_stored_route(&mut state, "Hof").expect("Export");
+        open_routes_dialog(&mut state);
+        export_stored_route(&mut state, "Hof").expect("Erneuter Export");
+
+        let routes = list_stored_routes(&profile.join(ROUTES_MANAGER_DIR)).unwrap();
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].file_name, "Hof.xml");
+        assert_eq!(routes[0].revision, 2);
+        assert_eq!(routes[0].map, "Riverbend Springs");
+
+        // Netz veraendern, dann die Route zurueckholen
+        Arc::make_mut(state.road_map.as_mut().unwrap()).add_node(MapNode::new(
+            3,
+            Vec2::new(20.0, 0.0),
+            NodeFlag::Regular,
+        ));
+        open_routes_dialog(&mut state);
+        import_stored_route(&mut state, "Hof.xml").expect("Import");
+
+        let road_map = state.road_map.as_deref().unwrap();
+        assert_eq!(road_map.node_count(), 2);
+        assert_eq!(road_map.map_name.as_deref(), Some("Riverbend Springs"));
+        assert!(state.ui.routes_dialog.is_none());
+        assert!(state.can_undo());
+
+        let _ = fs::remove_dir_all(&profile);
+    }
+
+    #[test]
+    fn export_gives_colliding_route_names_distinct_files() {
+        let (profile, mut state) = make_profile("collision");
+
+        open_routes_dialog(&mut state);
+        export_stored_route(&mut state, "Hof A").expect("Export Hof A");
+        open_routes_dialog(&mut state);
+        export_stored_route(&mut state, "Hof_A").expect("Export Hof_A");
+
+        let routes = list_stored_routes(&profile.join(ROUTES_MANAGER_DIR)).unwrap();
+        let file_of = |name: &str| {
+            routes
+                .iter()
+                .find(|route| route.name == name)
+                .map(|route| route.file_name.clone())
+                .unwrap()
+        };
+        assert_eq!(file_of("Hof A"), "Hof_A.xml");
+        assert_eq!(file_of("Hof_A"), "Hof_A_2.xml");
+        assert_eq!(routes.iter().map(|route| route.revision).max(), Some(1));
+
+        let _ = fs::remove_dir_all(&profile);
+    }
+
+    #[test]
+    fn export_rejects_index_file_name_with_path() {
+        let (profile, mut state) = make_profile("traversal");
+        let routes_dir = profile.join(ROUTES_MANAGER_DIR);
+        let poisoned = StoredRoute {
+            name: "Hof".to_string(),
+            file_name: "../../savegame1/AutoDrive_config.xml".to_string(),
+            map: String::new(),
+            revision: 1,
+            date: String::new(),
+        };
+        fs::write(
+            routes_dir.join(ROUTES_INDEX_FILE),
+            write_routes_index(&[poisoned]),
+        )
+        .unwrap();
+
+        open_routes_dialog(&mut state);
+        assert!(export_stored_route(&mut state, "Hof").is_err());
+        assert!(!profile.join("savegame1/AutoDrive_config.xml").exists());
+
+        let _ = fs::remove_dir_all(&profile);
+    }
+}
@@ -0,0 +1,54 @@
+//! Use-Case-Funktionen fuer die Farmland-Besitzverhaeltnisse aus dem Savegame.
+
+use crate::app::AppState;
+use fs25_map_overview::FarmlandOwnership;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Liest die Besitzverhaeltnisse aus `farmland_path` nach `AppState::farmland_ownership`.
+///
+/// Ohne Pfad (keine `farmland.xml` gefunden) wird der Besitz verworfen. Lese- und
+/// Parse-Fehler werden nur geloggt; die Karte bleibt davon unberuehrt.
+pub fn load_savegame_farmlands(state: &mut AppState, farmland_path: Option<&Path>) {
+    state.farmland_ownership = match farmland_path.map(FarmlandOwnership::from_savegame_xml_path) {
+        Some(Ok(ownership)) => {
+            log::info!("{} gekaufte Farmlands geladen", ownership.owners.len());
+            Some(Arc::new(ownership))
+        }
+        Some(Err(error)) => {
+            log::warn!("Farmland-Besitz konnte nicht geladen werden: {:#}", error);
+            None
+        }
+        None => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_owned_farmlands_and_clears_without_path() {
+        let dir = std::env::temp_dir().join(format!(
+            "fs25_ad_editor_savegame_farmlands_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("farmland.xml");
+        std::fs::write(
+            &path,
+            r#"<farmlands><farmland id="3" farmId="1"/><farmland id="4" farmId="0"/></farmlands>"#,
+        )
+        .unwrap();
+        let mut state = AppState::new();
+
+        load_savegame_farmlands(&mut state, Some(&path));
+        let ownership = state.farmland_ownership.clone().expect("Besitz erwartet");
+        assert_eq!(ownership.owners.get(&3), Some(&1));
+        assert!(!ownership.owners.contains_key(&4));
+
+        load_savegame_farmlands(&mut state, None);
+        assert!(state.farmland_ownership.is_none());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
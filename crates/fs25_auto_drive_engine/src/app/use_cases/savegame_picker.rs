@@ -0,0 +1,267 @@
+//! Use-Case-Funktionen fuer die Savegame-Auswahl im FS25-Profil.
+//!
+//! Statt die `AutoDrive_config.xml` im Dateidialog zu suchen, listet der Editor
+//! alle `savegameN`-Ordner des Profils mit Karte, Hof und Speicherdatum; die
+//! Config des gewaehlten Savegames wird dann ueber den normalen Ladeweg geoeffnet.
+
+use crate::app::state::SavegamePickerState;
+use crate::app::AppState;
+use crate::core::SavegameSummary;
+use crate::xml::{parse_career_savegame, parse_farm_name};
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+
+/// Name des Profilordners unter `Documents/My Games`.
+const PROFILE_DIR_NAME: &str = "FarmingSimulator2025";
+/// Steam-App-ID von FS25 (Proton-Praefix unter Linux).
+const STEAM_APP_ID: &str = "2300320";
+/// Kopfdaten-Datei eines Savegames.
+const CAREER_SAVEGAME_FILE: &str = "careerSavegame.xml";
+/// Hof-Datei eines Savegames.
+const FARMS_FILE: &str = "farms.xml";
+/// AutoDrive-Config eines Savegames.
+const AUTODRIVE_CONFIG_FILE: &str = "AutoDrive_config.xml";
+
+/// Ermittelt das FS25-Profilverzeichnis.
+///
+/// Vorrang hat das Profil der aktuell geladenen Config
+/// (`.../FarmingSimulator2025/savegameN/AutoDrive_config.xml`), danach die
+/// Standardorte unter Windows, macOS und Linux (Steam/Proton).
+pub fn resolve_profile_dir(current_file: Option<&Path>) -> Option<PathBuf> {
+    current_file
+        .and_then(|path| path.parent()?.parent())
+        .map(Path::to_path_buf)
+        .into_iter()
+        .chain(default_profile_dirs())
+        .find(|dir| dir.is_dir())
+}
+
+/// Standardorte des FS25-Profils, abgeleitet aus `USERPROFILE` bzw. `HOME`.
+fn default_profile_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(user_profile) = std::env::var_os("USERPROFILE") {
+        dirs.push(
+            Path::new(&user_profile)
+                .join("Documents/My Games")
+                .join(PROFILE_DIR_NAME),
+        );
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        let home = Path::new(&home);
+        dirs.push(
+            home.join("Library/Application Support")
+                .join(PROFILE_DIR_NAME),
+        );
+        dirs.push(
+            home.join(".local/share/Steam/steamapps/compatdata")
+                .join(STEAM_APP_ID)
+                .join("pfx/drive_c/users/steamuser/Documents/My Games")
+                .join(PROFILE_DIR_NAME),
+        );
+    }
+    dirs
+}
+
+/// Listet alle Savegames in `profile_dir`, neuestes Speicherdatum zuerst.
+///
+/// Beruecksichtigt werden `savegameN`-Ordner mit `careerSavegame.xml`;
+/// unlesbare Savegames werden mit Warnung uebersprungen.
+pub fn scan_savegames(profile_dir: &Path) -> anyhow::Result<Vec<SavegameSummary>> {
+    let entries = std::fs::read_dir(profile_dir)
+        .with_context(|| format!("Profil nicht lesbar: {}", profile_dir.display()))?;
+
+    let mut savegames: Vec<SavegameSummary> = entries
+        .flatten()
+        .filter(|entry| savegame_slot(&entry.file_name().to_string_lossy()).is_some())
+        .filter_map(|entry| match read_savegame(&entry.path()) {
+            Ok(summary) => summary,
+            Err(error) => {
+                log::warn!(
+                    "Savegame {} uebersprungen: {:#}",
+                    entry.path().display(),
+                    error
+                );
+                None
+            }
+        })
+        .collect();
+
+    savegames.sort_by(|a, b| {
+        b.save_date
+            .cmp(&a.save_date)
+            .then_with(|| savegame_slot(&a.dir_name).cmp(&savegame_slot(&b.dir_name)))
+    });
+    Ok(savegames)
+}
+
+/// Liest die Kurzuebersicht eines Savegame-Ordners; `None` ohne `careerSavegame.xml`.
+fn read_savegame(dir: &Path) -> anyhow::Result<Option<SavegameSummary>> {
+    let career_path = dir.join(CAREER_SAVEGAME_FILE);
+    if !career_path.is_file() {
+        return Ok(None);
+    }
+    let settings = parse_career_savegame(&std::fs::read_to_string(&career_path)?)
+        .with_context(|| format!("{} ist ungueltig", career_path.display()))?;
+
+    let farms_path = dir.join(FARMS_FILE);
+    let farm_name = if farms_path.is_file() {
+        parse_farm_name(&std::fs::read_to_string(&farms_path)?)?.unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let config_path = dir.join(AUTODRIVE_CONFIG_FILE);
+    Ok(Some(SavegameSummary {
+        dir_name: dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        savegame_name: settings.savegame_name,
+        map_title: settings.map_title,
+        farm_name,
+        save_date: settings.save_date,
+        config_path: config_path
+            .is_file()
+            .then(|| config_path.to_string_lossy().into_owned()),
+    }))
+}
+
+/// Slot-Nummer eines Savegame-Ordners (`savegame12` → 12).
+fn savegame_slot(dir_name: &str) -> Option<u32> {
+    dir_name.strip_prefix("savegame")?.parse().ok()
+}
+
+/// Oeffnet die Savegame-Auswahl mit allen Savegames des Profils.
+///
+/// Das Savegame der aktuell geladenen Config ist vorausgewaehlt. Ohne
+/// auffindbares Profil bleibt der Dialog geschlossen und eine Statusmeldung
+/// erklaert den Grund.
+pub fn open_savegame_picker(state: &mut AppState) {
+    let current_file = state.ui.current_file_path.as_deref().map(Path::new);
+    let Some(profile_dir) = resolve_profile_dir(current_file) else {
+        log::warn!("FS25-Profilverzeichnis nicht gefunden");
+        state.ui.status_message = Some(format!(
+            "FS25-Profil nicht gefunden (erwartet: Documents/My Games/{})",
+            PROFILE_DIR_NAME
+        ));
+        return;
+    };
+
+    let savegames = match scan_savegames(&profile_dir) {
+        Ok(savegames) => savegames,
+        Err(error) => {
+            log::warn!("Savegames konnten nicht gelesen werden: {:#}", error);
+            Vec::new()
+        }
+    };
+    log::info!(
+        "{} Savegames in {} gefunden",
+        savegames.len(),
+        profile_dir.display()
+    );
+
+    let selected = state.ui.current_file_path.as_deref().and_then(|current| {
+        savegames
+            .iter()
+            .position(|savegame| savegame.config_path.as_deref() == Some(current))
+    });
+    state.ui.savegame_picker = Some(SavegamePickerState {
+        profile_dir: profile_dir.to_string_lossy().into_owned(),
+        savegames,
+        selected,
+    });
+}
+
+/// Schliesst die Savegame-Auswahl.
+pub fn close_savegame_picker(state: &mut AppState) {
+    state.ui.savegame_picker = None;
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_savegame(profile: &Path, dir: &str, map: &str, date: &str, with_config: bool) {
+        let dir = profile.join(dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(CAREER_SAVEGAME_FILE),
+            format!(
+                "<careerSavegame><settings><savegameName>{dir_name}</savegameName>\
+                 <mapTitle>{map}</mapTitle><saveDate>{date}</saveDate></settings></careerSavegame>",
+                dir_name = dir.file_name().unwrap().to_string_lossy()
+            ),
+        )
+        .unwrap();
+        fs::write(
+            dir.join(FARMS_FILE),
+            r#"<farms><farm farmId="1" name="Gut Sonnenhof"/></farms>"#,
+        )
+        .unwrap();
+        if with_config {
+            fs::write(dir.join(AUTODRIVE_CONFIG_FILE), "<AutoDrive/>").unwrap();
+        }
+    }
+
+    fn make_profile(label: &str) -> PathBuf {
+        let profile = std::env::temp_dir().join(format!(
+            "fs25_ad_editor_savegames_{label}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&profile);
+        fs::create_dir_all(profile.join("savegameBackup")).unwrap();
+        fs::create_dir_all(profile.join("savegame4")).unwrap();
+        write_savegame(&profile, "savegame1", "Elmcreek", "2025-01-05", true);
+        write_savegame(
+            &profile,
+            "savegame2",
+            "Riverbend Springs",
+            "2025-03-01",
+            false,
+        );
+        write_savegame(&profile, "savegame10", "Zielonka", "2025-01-05", true);
+        profile
+    }
+
+    #[test]
+    fn scan_lists_savegames_newest_first() {
+        let profile = make_profile("scan");
+
+        let savegames = scan_savegames(&profile).expect("Scan");
+
+        let dirs: Vec<&str> = savegames.iter().map(|s| s.dir_name.as_str()).collect();
+        assert_eq!(dirs, ["savegame2", "savegame1", "savegame10"]);
+        assert_eq!(savegames[0].map_title, "Riverbend Springs");
+        assert_eq!(savegames[0].farm_name, "Gut Sonnenhof");
+        assert!(savegames[0].config_path.is_none());
+        assert!(savegames[1]
+            .config_path
+            .as_deref()
+            .is_some_and(|path| path.ends_with(AUTODRIVE_CONFIG_FILE)));
+
+        let _ = fs::remove_dir_all(&profile);
+    }
+
+    #[test]
+    fn picker_uses_profile_of_current_config_and_preselects_it() {
+        let profile = make_profile("picker");
+        let mut state = AppState::new();
+        let current = profile.join("savegame10").join(AUTODRIVE_CONFIG_FILE);
+        state.ui.current_file_path = Some(current.to_string_lossy().into_owned());
+
+        open_savegame_picker(&mut state);
+
+        let picker = state.ui.savegame_picker.as_ref().expect("Dialog offen");
+        assert_eq!(picker.savegames.len(), 3);
+        assert_eq!(picker.selected, Some(2));
+
+        close_savegame_picker(&mut state);
+        assert!(state.ui.savegame_picker.is_none());
+        let _ = fs::remove_dir_all(&profile);
+    }
+}
@@ -0,0 +1,33 @@
+//! Use-Case-Funktionen fuer Fahrzeugpositionen aus dem Savegame.
+
+use crate::app::AppState;
+use crate::xml::parse_savegame_vehicles;
+use anyhow::Context;
+use std::path::Path;
+
+/// Liest die Fahrzeuge aus `vehicles_path` nach `AppState::savegame_vehicles`.
+///
+/// Ohne Pfad (keine `vehicles.xml` gefunden) wird die Liste geleert. Lese- und
+/// Parse-Fehler werden nur geloggt; die Karte bleibt davon unberuehrt.
+pub fn load_savegame_vehicles(state: &mut AppState, vehicles_path: Option<&Path>) {
+    state.savegame_vehicles = match vehicles_path.map(read_vehicles) {
+        Some(Ok(vehicles)) => {
+            log::info!("{} Savegame-Fahrzeuge geladen", vehicles.len());
+            vehicles
+        }
+        Some(Err(error)) => {
+            log::warn!(
+                "Savegame-Fahrzeuge konnten nicht geladen werden: {:#}",
+                error
+            );
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+}
+
+fn read_vehicles(path: &Path) -> anyhow::Result<Vec<crate::core::SavegameVehicle>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Fehler beim Lesen von {}", path.display()))?;
+    parse_savegame_vehicles(&content)
+}
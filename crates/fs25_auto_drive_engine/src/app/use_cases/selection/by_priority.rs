@@ -0,0 +1,113 @@
+//! Use-Case: Alle Nodes an Verbindungen einer Prioritaet selektieren.
+
+use crate::core::ConnectionPriority;
+use crate::AppState;
+use indexmap::IndexSet;
+
+/// Ersetzt die Selektion durch alle Nodes, die Start- oder Endpunkt einer
+/// Verbindung mit der gegebenen Prioritaet sind.
+///
+/// Kreuzungen zwischen Haupt- und Nebenstrasse erscheinen in beiden Mengen.
+/// Im Isolier-Modus ausgeblendete Nodes werden nicht erfasst.
+pub fn select_nodes_by_connection_priority(state: &mut AppState, priority: ConnectionPriority) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+
+    let isolated = state.view.isolation_hidden_node_ids.as_deref();
+    let mut hits = IndexSet::new();
+    for connection in road_map
+        .connections_iter()
+        .filter(|connection| connection.priority == priority)
+    {
+        hits.insert(connection.start_id);
+        hits.insert(connection.end_id);
+    }
+    hits.retain(|id| !isolated.is_some_and(|hidden| hidden.contains(id)));
+
+    let message = match priority {
+        ConnectionPriority::Regular => format!("{} Hauptstrassen-Node(s) selektiert", hits.len()),
+        ConnectionPriority::SubPriority => {
+            format!("{} Nebenstrassen-Node(s) selektiert", hits.len())
+        }
+    };
+    *state.selection.ids_mut() = hits;
+    state.selection.selection_anchor_node_id = None;
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, ConnectionDirection, MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+    use std::sync::Arc;
+
+    /// Kette 1 -Haupt- 2 -Neben- 3 -Neben- 4, plus isolierter Node 5.
+    fn priority_state() -> AppState {
+        let mut map = RoadMap::new(5);
+        for id in 1..=5 {
+            map.add_node(MapNode::new(
+                id,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        for (start, end, priority) in [
+            (1, 2, ConnectionPriority::Regular),
+            (2, 3, ConnectionPriority::SubPriority),
+            (3, 4, ConnectionPriority::SubPriority),
+        ] {
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                priority,
+                Vec2::new(start as f32 * 10.0, 0.0),
+                Vec2::new(end as f32 * 10.0, 0.0),
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().insert(5);
+        state
+    }
+
+    fn selected_sorted(state: &AppState) -> Vec<u64> {
+        let mut ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn selects_nodes_of_subprio_connections_and_replaces_selection() {
+        let mut state = priority_state();
+
+        select_nodes_by_connection_priority(&mut state, ConnectionPriority::SubPriority);
+
+        assert_eq!(selected_sorted(&state), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn selects_nodes_of_main_road_connections() {
+        let mut state = priority_state();
+
+        select_nodes_by_connection_priority(&mut state, ConnectionPriority::Regular);
+
+        assert_eq!(selected_sorted(&state), vec![1, 2]);
+    }
+
+    #[test]
+    fn skips_nodes_hidden_by_isolation() {
+        let mut state = priority_state();
+        state
+            .view
+            .set_isolation(Some(Arc::new(IndexSet::from_iter([4_u64]))));
+
+        select_nodes_by_connection_priority(&mut state, ConnectionPriority::SubPriority);
+
+        assert_eq!(selected_sorted(&state), vec![2, 3]);
+    }
+}
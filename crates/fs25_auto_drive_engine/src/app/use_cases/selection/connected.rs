@@ -0,0 +1,151 @@
+//! Use-Case: Flood-Selektion aller vom getroffenen Node erreichbaren Nodes.
+
+use crate::core::{ConnectionDirection, RoadMap};
+use crate::AppState;
+use glam::Vec2;
+use indexmap::IndexSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use super::helpers::clear_selection;
+
+/// Selektiert alle Nodes, die vom naechsten Node zum Klickpunkt erreichbar sind.
+///
+/// Ohne `follow_direction` wird die gesamte Zusammenhangskomponente selektiert
+/// (Verbindungsrichtung egal). Mit `follow_direction` werden nur Verbindungen in
+/// Fahrtrichtung verfolgt; `Dual`-Verbindungen sind in beide Richtungen befahrbar.
+/// Der getroffene Node wird Anker; bei `additive = true` wird die bestehende
+/// Selektion erweitert.
+pub fn select_connected_nodes(
+    state: &mut AppState,
+    world_pos: Vec2,
+    max_distance: f32,
+    additive: bool,
+    follow_direction: bool,
+) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        if !additive {
+            clear_selection(state);
+        }
+        return;
+    };
+
+    let Some(hit_id) = road_map
+        .nearest_node(world_pos)
+        .filter(|hit| hit.distance <= max_distance)
+        .map(|hit| hit.node_id)
+    else {
+        if !additive {
+            clear_selection(state);
+        }
+        return;
+    };
+
+    let reachable = reachable_nodes(road_map, hit_id, follow_direction);
+    if additive {
+        state.selection.ids_mut().extend(reachable);
+    } else {
+        state.selection.selected_node_ids = Arc::new(reachable);
+    }
+    state.selection.selection_anchor_node_id = Some(hit_id);
+}
+
+/// Breitensuche ab `start`; Reihenfolge der Treffer = Entdeckungsreihenfolge.
+fn reachable_nodes(road_map: &RoadMap, start: u64, follow_direction: bool) -> IndexSet<u64> {
+    let mut visited = IndexSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        for &(neighbor, is_outgoing) in road_map.neighbors(current) {
+            let passable = !follow_direction
+                || is_outgoing
+                || road_map
+                    .find_connection(neighbor, current)
+                    .is_some_and(|conn| conn.direction == ConnectionDirection::Dual);
+            if passable && visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Connection, ConnectionPriority, MapNode, NodeFlag};
+
+    fn connect(map: &mut RoadMap, start: u64, end: u64, direction: ConnectionDirection) {
+        let start_pos = map.node_position(start).unwrap();
+        let end_pos = map.node_position(end).unwrap();
+        map.add_connection(Connection::new(
+            start,
+            end,
+            direction,
+            ConnectionPriority::Regular,
+            start_pos,
+            end_pos,
+        ));
+    }
+
+    /// Einbahn 1 → 2 → 3, Dual 3 ↔ 4, separate Komponente 5 → 6.
+    fn flood_state() -> AppState {
+        let mut map = RoadMap::new(3);
+        for id in 1..=6 {
+            map.add_node(MapNode::new(
+                id,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        connect(&mut map, 1, 2, ConnectionDirection::Regular);
+        connect(&mut map, 2, 3, ConnectionDirection::Regular);
+        connect(&mut map, 3, 4, ConnectionDirection::Dual);
+        connect(&mut map, 5, 6, ConnectionDirection::Regular);
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state
+    }
+
+    fn selected_ids(state: &AppState) -> Vec<u64> {
+        let mut ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn selects_whole_component_ignoring_direction() {
+        let mut state = flood_state();
+
+        select_connected_nodes(&mut state, Vec2::new(20.0, 0.0), 1.0, false, false);
+
+        assert_eq!(selected_ids(&state), [1, 2, 3, 4]);
+        assert_eq!(state.selection.selection_anchor_node_id, Some(2));
+    }
+
+    #[test]
+    fn follow_direction_only_walks_driveable_connections() {
+        let mut state = flood_state();
+
+        select_connected_nodes(&mut state, Vec2::new(20.0, 0.0), 1.0, false, true);
+        assert_eq!(selected_ids(&state), [2, 3, 4]);
+
+        select_connected_nodes(&mut state, Vec2::new(40.0, 0.0), 1.0, false, true);
+        assert_eq!(selected_ids(&state), [3, 4]);
+    }
+
+    #[test]
+    fn additive_extends_and_miss_clears_selection() {
+        let mut state = flood_state();
+
+        select_connected_nodes(&mut state, Vec2::new(50.0, 0.0), 1.0, false, false);
+        select_connected_nodes(&mut state, Vec2::new(10.0, 0.0), 1.0, true, false);
+        assert_eq!(selected_ids(&state), [1, 2, 3, 4, 5, 6]);
+
+        select_connected_nodes(&mut state, Vec2::new(500.0, 0.0), 1.0, false, false);
+        assert!(state.selection.selected_node_ids.is_empty());
+        assert_eq!(state.selection.selection_anchor_node_id, None);
+    }
+}
@@ -0,0 +1,141 @@
+//! Use-Case: Selektion um eine Nachbarschaftsstufe erweitern oder verkleinern.
+
+use crate::AppState;
+use indexmap::IndexSet;
+
+/// Erweitert die Selektion um alle direkten Nachbarn der selektierten Nodes.
+///
+/// Die Verbindungsrichtung spielt keine Rolle; neue Nodes werden hinter den
+/// bisher selektierten angehaengt, der Anker bleibt erhalten.
+pub fn grow_selection(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+
+    let neighbors: Vec<u64> = state
+        .selection
+        .selected_node_ids
+        .iter()
+        .flat_map(|&id| road_map.neighbors(id).iter().map(|&(neighbor, _)| neighbor))
+        .filter(|id| !state.selection.selected_node_ids.contains(id))
+        .collect();
+    if neighbors.is_empty() {
+        return;
+    }
+    state.selection.ids_mut().extend(neighbors);
+}
+
+/// Verkleinert die Selektion um ihre Randnodes.
+///
+/// Randnode ist jeder selektierte Node mit mindestens einem nicht selektierten
+/// Nachbarn; Nodes ohne Verbindungen zaehlen ebenfalls zum Rand. Liegt der
+/// Anker auf dem Rand, wird er auf den ersten verbleibenden Node gesetzt.
+pub fn shrink_selection(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+
+    let selected = &state.selection.selected_node_ids;
+    let interior: IndexSet<u64> = selected
+        .iter()
+        .copied()
+        .filter(|&id| {
+            let neighbors = road_map.neighbors(id);
+            !neighbors.is_empty()
+                && neighbors
+                    .iter()
+                    .all(|(neighbor, _)| selected.contains(neighbor))
+        })
+        .collect();
+    if interior.len() == selected.len() {
+        return;
+    }
+
+    *state.selection.ids_mut() = interior;
+    let anchor_kept = state
+        .selection
+        .selection_anchor_node_id
+        .is_some_and(|anchor| state.selection.selected_node_ids.contains(&anchor));
+    if !anchor_kept {
+        state.selection.selection_anchor_node_id =
+            state.selection.selected_node_ids.first().copied();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+    };
+    use glam::Vec2;
+    use std::sync::Arc;
+
+    /// Kette 1 → 2 → 3 → 4 → 5 plus isolierter Node 9.
+    fn chain_state(selected: &[u64]) -> AppState {
+        let mut map = RoadMap::new(3);
+        for id in 1..=5 {
+            map.add_node(MapNode::new(
+                id,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        map.add_node(MapNode::new(9, Vec2::new(0.0, 50.0), NodeFlag::Regular));
+        for id in 1..5 {
+            map.add_connection(Connection::new(
+                id,
+                id + 1,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                Vec2::new((id + 1) as f32 * 10.0, 0.0),
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend(selected.iter().copied());
+        state.selection.selection_anchor_node_id = selected.first().copied();
+        state
+    }
+
+    fn selected_ids(state: &AppState) -> Vec<u64> {
+        let mut ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn grow_adds_incoming_and_outgoing_neighbors() {
+        let mut state = chain_state(&[3]);
+
+        grow_selection(&mut state);
+        assert_eq!(selected_ids(&state), [2, 3, 4]);
+
+        grow_selection(&mut state);
+        assert_eq!(selected_ids(&state), [1, 2, 3, 4, 5]);
+        assert_eq!(state.selection.selection_anchor_node_id, Some(3));
+    }
+
+    #[test]
+    fn shrink_removes_boundary_and_isolated_nodes() {
+        let mut state = chain_state(&[2, 3, 4, 9]);
+
+        shrink_selection(&mut state);
+        assert_eq!(selected_ids(&state), [3]);
+        assert_eq!(state.selection.selection_anchor_node_id, Some(3));
+
+        shrink_selection(&mut state);
+        assert!(state.selection.selected_node_ids.is_empty());
+        assert_eq!(state.selection.selection_anchor_node_id, None);
+    }
+
+    #[test]
+    fn shrink_keeps_fully_enclosed_selection() {
+        let mut state = chain_state(&[1, 2, 3, 4, 5]);
+
+        shrink_selection(&mut state);
+        assert_eq!(selected_ids(&state), [1, 2, 3, 4, 5]);
+    }
+}
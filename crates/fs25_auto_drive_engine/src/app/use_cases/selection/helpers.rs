@@ -2,7 +2,7 @@
 
 use crate::core::{ConnectionDirection, ConnectionPriority, NodeFlag, RoadMap};
 use crate::AppState;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Loescht die aktuelle Selektion explizit.
 pub fn clear_selection(state: &mut AppState) {
@@ -10,6 +10,53 @@ pub fn clear_selection(state: &mut AppState) {
     state.selection.selection_anchor_node_id = None;
 }
 
+/// Berechnet den kuerzesten Pfad (BFS, richtungsunabhaengig) zwischen zwei Nodes.
+pub(super) fn shortest_path_nodes(road_map: &RoadMap, start: u64, goal: u64) -> Option<Vec<u64>> {
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    if !road_map.contains_node(start) || !road_map.contains_node(goal) {
+        return None;
+    }
+
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut predecessors: HashMap<u64, u64> = HashMap::new();
+
+    queue.push_back(start);
+    visited.insert(start);
+
+    while let Some(current) = queue.pop_front() {
+        if current == goal {
+            break;
+        }
+
+        for &(neighbor, _) in road_map.neighbors(current) {
+            if visited.insert(neighbor) {
+                predecessors.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    if !visited.contains(&goal) {
+        return None;
+    }
+
+    let mut path = vec![goal];
+    let mut current = goal;
+
+    while current != start {
+        let &previous = predecessors.get(&current)?;
+        path.push(previous);
+        current = previous;
+    }
+
+    path.reverse();
+    Some(path)
+}
+
 /// Berechnet das achsen-alignierte Bounding-Rect aus zwei Eckpunkten.
 pub(super) fn rect_min_max(a: glam::Vec2, b: glam::Vec2) -> (glam::Vec2, glam::Vec2) {
     (
@@ -0,0 +1,99 @@
+//! Use-Case: Selektion nur innerhalb der sichtbaren Nodes invertieren.
+
+use crate::AppState;
+use indexmap::IndexSet;
+use std::collections::HashSet;
+
+use super::helpers::rect_min_max;
+
+/// Invertiert die Selektion nur fuer sichtbare Nodes.
+///
+/// Sichtbar sind Nodes innerhalb des aktuellen Viewports, die nicht vom
+/// Isolier-Modus ausgeblendet werden. Nodes ausserhalb behalten ihren
+/// Selektionszustand. Ohne Karte oder ohne Viewport-Groesse passiert nichts.
+pub fn invert_visible_selection(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let size = glam::Vec2::from(state.view.viewport_size);
+    if size.x <= 0.0 || size.y <= 0.0 {
+        return;
+    }
+
+    let camera = &state.view.camera;
+    let (min, max) = rect_min_max(
+        camera.screen_to_world(glam::Vec2::ZERO, size),
+        camera.screen_to_world(size, size),
+    );
+    let isolated = state.view.isolation_hidden_node_ids.as_deref();
+    let visible: Vec<u64> = road_map
+        .nodes_within_rect(min, max)
+        .into_iter()
+        .filter(|id| !isolated.is_some_and(|hidden| hidden.contains(id)))
+        .collect();
+    let visible_lookup: HashSet<u64> = visible.iter().copied().collect();
+
+    let current = &state.selection.selected_node_ids;
+    let mut inverted: IndexSet<u64> = current
+        .iter()
+        .copied()
+        .filter(|id| !visible_lookup.contains(id))
+        .collect();
+    inverted.extend(visible.into_iter().filter(|id| !current.contains(id)));
+
+    *state.selection.ids_mut() = inverted;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Camera2D, MapNode, NodeFlag, RoadMap};
+    use std::sync::Arc;
+
+    fn state_with_viewport() -> AppState {
+        let mut map = RoadMap::new(3);
+        for (id, x) in [(1, 0.0), (2, 10.0), (3, 200.0)] {
+            map.add_node(MapNode::new(id, glam::Vec2::new(x, 0.0), NodeFlag::Regular));
+        }
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.view.viewport_size = [100.0, 100.0];
+        state.view.camera.look_at(glam::Vec2::ZERO);
+        // Halbe sichtbare Ausdehnung = 50 Welteinheiten
+        state.view.camera.zoom = Camera2D::BASE_WORLD_EXTENT / 50.0;
+        state
+    }
+
+    #[test]
+    fn invert_visible_selection_keeps_offscreen_nodes_untouched() {
+        let mut state = state_with_viewport();
+        state.selection.ids_mut().extend([2, 3]);
+
+        invert_visible_selection(&mut state);
+
+        let selected = &state.selection.selected_node_ids;
+        assert!(selected.contains(&1));
+        assert!(!selected.contains(&2));
+        assert!(
+            selected.contains(&3),
+            "Node ausserhalb des Viewports bleibt"
+        );
+    }
+
+    #[test]
+    fn invert_visible_selection_skips_isolated_nodes() {
+        let mut state = state_with_viewport();
+        state
+            .view
+            .set_isolation(Some(Arc::new(IndexSet::from_iter([1_u64]))));
+
+        invert_visible_selection(&mut state);
+
+        let selected = &state.selection.selected_node_ids;
+        assert!(!selected.contains(&1));
+        assert!(selected.contains(&2));
+        assert!(!selected.contains(&3));
+    }
+}
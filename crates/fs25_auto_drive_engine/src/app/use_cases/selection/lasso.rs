@@ -56,6 +56,8 @@ fn point_in_polygon(point: glam::Vec2, polygon: &[glam::Vec2]) -> bool {
 }
 
 /// Selektiert alle Nodes innerhalb eines Lasso-Polygons (inkl. Rand).
+///
+/// Im Isolier-Modus ausgeblendete Nodes werden nicht erfasst.
 pub fn select_nodes_in_lasso(state: &mut AppState, polygon: &[glam::Vec2], additive: bool) {
     if polygon.len() < 3 {
         return;
@@ -76,6 +78,7 @@ pub fn select_nodes_in_lasso(state: &mut AppState, polygon: &[glam::Vec2], addit
         max.y = max.y.max(point.y);
     }
 
+    let isolated = state.view.isolation_hidden_node_ids.as_deref();
     let candidates = road_map.nodes_within_rect(min, max);
 
     if !additive {
@@ -83,6 +86,9 @@ pub fn select_nodes_in_lasso(state: &mut AppState, polygon: &[glam::Vec2], addit
     }
 
     for node_id in candidates {
+        if isolated.is_some_and(|hidden| hidden.contains(&node_id)) {
+            continue;
+        }
         if let Some(node) = road_map.node(node_id)
             && point_in_polygon(node.position, polygon)
         {
@@ -0,0 +1,127 @@
+//! Use-Case: Selektierte Nodes sperren bzw. entsperren.
+
+use crate::AppState;
+
+/// Sperrt (`locked = true`) oder entsperrt alle selektierten Nodes.
+///
+/// Gesperrte Nodes werden von Verschieben, Rotieren und Loeschen ausgenommen,
+/// bis sie wieder entsperrt werden. Die Selektion selbst bleibt unveraendert.
+pub fn set_selected_nodes_locked(state: &mut AppState, locked: bool) {
+    if state.selection.selected_node_ids.is_empty() {
+        return;
+    }
+
+    let selected = state.selection.selected_node_ids.iter().copied();
+    let changed = if locked {
+        selected
+            .filter(|&id| state.locked_node_ids.insert(id))
+            .count()
+    } else {
+        selected
+            .filter(|id| state.locked_node_ids.remove(id))
+            .count()
+    };
+
+    let message = if locked {
+        format!("{} Node(s) gesperrt", changed)
+    } else {
+        format!("{} Node(s) entsperrt", changed)
+    };
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::use_cases::editing::delete_selected_nodes;
+    use crate::app::use_cases::selection::move_selected_nodes;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use glam::Vec2;
+    use std::sync::Arc;
+
+    fn lock_state(selected: &[u64]) -> AppState {
+        let mut map = RoadMap::new(3);
+        for id in 1..=3 {
+            map.add_node(MapNode::new(
+                id,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend(selected.iter().copied());
+        state
+    }
+
+    fn node_position(state: &AppState, id: u64) -> Option<Vec2> {
+        state
+            .road_map
+            .as_deref()
+            .and_then(|map| map.node(id))
+            .map(|node| node.position)
+    }
+
+    #[test]
+    fn lock_and_unlock_toggle_selected_nodes_only() {
+        let mut state = lock_state(&[1, 2]);
+
+        set_selected_nodes_locked(&mut state, true);
+        assert!(state.locked_node_ids.contains(&1));
+        assert!(state.locked_node_ids.contains(&2));
+        assert!(!state.locked_node_ids.contains(&3));
+
+        state.selection.ids_mut().shift_remove(&2);
+        set_selected_nodes_locked(&mut state, false);
+        assert!(!state.locked_node_ids.contains(&1));
+        assert!(state.locked_node_ids.contains(&2));
+    }
+
+    #[test]
+    fn locked_nodes_are_not_moved() {
+        let mut state = lock_state(&[1]);
+        set_selected_nodes_locked(&mut state, true);
+        state.selection.ids_mut().insert(2);
+
+        move_selected_nodes(&mut state, Vec2::new(0.0, 5.0));
+
+        assert_eq!(node_position(&state, 1), Some(Vec2::new(10.0, 0.0)));
+        assert_eq!(node_position(&state, 2), Some(Vec2::new(20.0, 5.0)));
+    }
+
+    #[test]
+    fn delete_skips_locked_nodes_and_keeps_them_selected() {
+        let mut state = lock_state(&[1]);
+        set_selected_nodes_locked(&mut state, true);
+        state.selection.ids_mut().insert(2);
+
+        delete_selected_nodes(&mut state);
+
+        assert!(node_position(&state, 1).is_some());
+        assert!(node_position(&state, 2).is_none());
+        assert_eq!(
+            state
+                .selection
+                .selected_node_ids
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            [1]
+        );
+    }
+
+    #[test]
+    fn delete_with_only_locked_nodes_records_no_undo_step() {
+        let mut state = lock_state(&[1, 2]);
+        set_selected_nodes_locked(&mut state, true);
+
+        delete_selected_nodes(&mut state);
+
+        assert!(node_position(&state, 1).is_some());
+        assert!(node_position(&state, 2).is_some());
+        assert!(!state.can_undo());
+    }
+}
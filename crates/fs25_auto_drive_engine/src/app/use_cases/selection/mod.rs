@@ -3,12 +3,22 @@
 //! Aufgeteilt nach Selektionsmodus:
 //! - `pick` — Einzelklick-Selektion (Nearest-Node)
 //! - `segment` — Doppelklick-Selektion (Korridor zwischen Kreuzungen)
+//! - `connected` — Shift+Doppelklick-Selektion (alle erreichbaren Nodes)
 //! - `rect` — Rechteck-Selektion (Shift + Drag)
 //! - `lasso` — Lasso-Selektion (Alt + Drag)
+//! - `invert` — Selektion nur innerhalb der sichtbaren Nodes invertieren
 //! - `move_nodes` — Verschieben selektierter Nodes
+//! - `transform_nodes` — Rotieren/Skalieren selektierter Nodes um einen Pivot (Gizmo)
+//! - `lock` — Selektierte Nodes gegen Verschieben/Loeschen sperren
+//! - `grow_shrink` — Selektion um Nachbarn erweitern bzw. um Randnodes verkleinern
+//! - `path_between` — Kuerzesten Pfad zwischen zwei selektierten Nodes hinzufuegen
+//! - `by_priority` — Alle Nodes an Haupt- bzw. Nebenstrassen-Verbindungen selektieren
 //! - `helpers` — Gemeinsame Hilfsfunktionen
 
+mod by_priority;
+mod connected;
 mod group;
+mod grow_shrink;
 ///
 /// Aufgeteilt nach Selektionsmodus:
 /// - `pick` — Einzelklick-Selektion (Nearest-Node)
@@ -19,18 +29,29 @@ mod group;
 /// - `rotate_nodes` — Rotation selektierter Nodes um Zentrum
 /// - `helpers` — Gemeinsame Hilfsfunktionen
 mod helpers;
+mod invert;
 mod lasso;
+mod lock;
 mod move_nodes;
+mod path_between;
 mod pick;
 mod rect;
 mod rotate_nodes;
 mod segment;
+mod transform_nodes;
 
+pub use by_priority::select_nodes_by_connection_priority;
+pub use connected::select_connected_nodes;
 pub use group::select_group_by_nearest_node;
+pub use grow_shrink::{grow_selection, shrink_selection};
 pub use helpers::clear_selection;
+pub use invert::invert_visible_selection;
 pub use lasso::select_nodes_in_lasso;
-pub use move_nodes::move_selected_nodes;
+pub use lock::set_selected_nodes_locked;
+pub use move_nodes::{move_selected_nodes, move_selected_nodes_by};
+pub use path_between::select_path_between_selected;
 pub use pick::select_nearest_node;
 pub use rect::select_nodes_in_rect;
 pub use rotate_nodes::rotate_selected_nodes;
 pub use segment::select_segment_between_nearest_intersections;
+pub use transform_nodes::transform_selected_nodes;
@@ -1,5 +1,6 @@
 //! Use-Case: Verschieben selektierter Nodes.
 
+use crate::app::use_cases::editing::current_node_height;
 use crate::AppState;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -9,7 +10,8 @@ use std::sync::Arc;
 /// Wenn ein selektierter Node zu einem gesperrten (locked) Segment gehoert,
 /// werden alle Nodes dieses Segments gemeinsam verschoben. Anschliessend
 /// werden die `original_positions` der betroffenen locked Segments
-/// aktualisiert, damit das Segment-Overlay gueltig bleibt.
+/// aktualisiert, damit das Segment-Overlay gueltig bleibt. Gesperrte Nodes
+/// (`AppState::locked_node_ids`) bleiben an ihrer Position.
 pub fn move_selected_nodes(state: &mut AppState, delta_world: glam::Vec2) {
     if delta_world == glam::Vec2::ZERO {
         return;
@@ -27,7 +29,12 @@ pub fn move_selected_nodes(state: &mut AppState, delta_world: glam::Vec2) {
     let selected: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
     let extra = state.group_registry.expand_locked_selection(&selected);
 
-    let move_ids: HashSet<u64> = selected.iter().copied().chain(extra).collect();
+    let move_ids: HashSet<u64> = selected
+        .iter()
+        .copied()
+        .chain(extra)
+        .filter(|id| !state.locked_node_ids.contains(id))
+        .collect();
     let move_ids_vec: Vec<u64> = move_ids.iter().copied().collect();
 
     let road_map_mut = Arc::make_mut(road_map);
@@ -51,6 +58,61 @@ pub fn move_selected_nodes(state: &mut AppState, delta_world: glam::Vec2) {
     }
 }
 
+/// Verschiebt alle selektierten Nodes um einen exakt eingegebenen Versatz.
+///
+/// Gedacht fuer numerische Eingaben, z.B. um eine importierte Kurs-Vorlage um
+/// einen bekannten Karten-Offset zu versetzen. Die Kartenebene folgt den Regeln
+/// von [`move_selected_nodes`]; `delta_height` verschiebt zusaetzlich den
+/// Y-Wert der selektierten, nicht gesperrten Nodes. Alles ist ein Undo-Schritt,
+/// der Spatial-Index wird direkt neu aufgebaut.
+pub fn move_selected_nodes_by(state: &mut AppState, delta_world: glam::Vec2, delta_height: f32) {
+    if delta_world == glam::Vec2::ZERO && delta_height == 0.0 {
+        return;
+    }
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    if state.selection.selected_node_ids.is_empty() {
+        return;
+    }
+
+    // Y-Werte vor dem Verschieben bestimmen (Heightmap an der alten Position)
+    let heights: Vec<(u64, f32)> = if delta_height != 0.0 {
+        state
+            .selection
+            .selected_node_ids
+            .iter()
+            .filter(|id| !state.locked_node_ids.contains(id))
+            .map(|&id| (id, current_node_height(state, road_map, id) + delta_height))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Snapshot VOR Mutation
+    state.record_undo_snapshot();
+    move_selected_nodes(state, delta_world);
+
+    let Some(road_map_arc) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map = Arc::make_mut(road_map_arc);
+    for &(id, height) in &heights {
+        road_map.set_node_height_override(id, height);
+    }
+    road_map.rebuild_spatial_index();
+
+    let message = format!(
+        "{} Node(s) verschoben um X {:+.2} / Y {:+.2} / Z {:+.2} m",
+        state.selection.selected_node_ids.len(),
+        delta_world.x,
+        delta_height,
+        delta_world.y
+    );
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,6 +166,34 @@ mod tests {
         assert_eq!(node2.position, glam::Vec2::new(12.0, 3.0));
     }
 
+    #[test]
+    fn move_selected_nodes_by_applies_offset_and_height_in_one_undo_step() {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+        map.set_node_height_override(1, 40.0);
+        map.ensure_spatial_index();
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend([1, 2]);
+        state.locked_node_ids.insert(2);
+
+        move_selected_nodes_by(&mut state, Vec2::new(250.0, -120.0), 2.5);
+
+        let rm = state.road_map.as_deref().expect("map vorhanden");
+        assert_eq!(rm.node_position(1), Some(Vec2::new(250.0, -120.0)));
+        assert_eq!(rm.node_height_override(1), Some(42.5));
+        assert_eq!(rm.node_position(2), Some(Vec2::new(10.0, 0.0)));
+        assert_eq!(rm.node_height_override(2), None);
+        assert_eq!(
+            rm.nearest_node(Vec2::new(250.0, -120.0))
+                .map(|hit| hit.node_id),
+            Some(1)
+        );
+        assert!(state.can_undo());
+    }
+
     #[test]
     fn move_selected_nodes_defers_spatial_index_update() {
         let mut map = RoadMap::new(3);
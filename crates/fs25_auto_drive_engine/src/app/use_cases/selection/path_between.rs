@@ -0,0 +1,111 @@
+//! Use-Case: Pfad zwischen zwei selektierten Nodes zur Selektion hinzufuegen.
+
+use super::helpers::shortest_path_nodes;
+use crate::AppState;
+
+/// Fuegt alle Nodes des kuerzesten Pfads zwischen den zwei selektierten Nodes hinzu.
+///
+/// Der Pfad wird richtungsunabhaengig gesucht (wie bei Shift+Klick). Ohne genau
+/// zwei selektierte Nodes passiert nichts; gibt es keine Verbindung, bleibt die
+/// Selektion unveraendert und eine Statusmeldung erklaert den Grund.
+pub fn select_path_between_selected(state: &mut AppState) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let selected = &state.selection.selected_node_ids;
+    if selected.len() != 2 {
+        return;
+    }
+    let (start, goal) = (selected[0], selected[1]);
+
+    match shortest_path_nodes(road_map, start, goal) {
+        Some(path) => {
+            log::info!(
+                "Pfad zwischen Node {} und {} selektiert ({} Nodes)",
+                start,
+                goal,
+                path.len()
+            );
+            state.selection.ids_mut().extend(path);
+        }
+        None => {
+            state.ui.status_message = Some(format!(
+                "Keine Verbindung zwischen Node {} und {} gefunden",
+                start, goal
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+    };
+    use glam::Vec2;
+    use std::sync::Arc;
+
+    /// Kette 1 → 2 → 3 → 4 mit Abzweig 2 → 5 und isoliertem Node 9.
+    fn path_state(selected: &[u64]) -> AppState {
+        let mut map = RoadMap::new(3);
+        for id in [1, 2, 3, 4, 5, 9] {
+            map.add_node(MapNode::new(
+                id,
+                Vec2::new(id as f32 * 10.0, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        for (start, end) in [(1, 2), (2, 3), (3, 4), (2, 5)] {
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                Vec2::new(start as f32 * 10.0, 0.0),
+                Vec2::new(end as f32 * 10.0, 0.0),
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.ids_mut().extend(selected.iter().copied());
+        state.selection.selection_anchor_node_id = selected.last().copied();
+        state
+    }
+
+    fn selected_ids(state: &AppState) -> Vec<u64> {
+        let mut ids: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn adds_nodes_along_path_ignoring_direction() {
+        let mut state = path_state(&[4, 1]);
+
+        select_path_between_selected(&mut state);
+
+        assert_eq!(selected_ids(&state), [1, 2, 3, 4]);
+        assert_eq!(state.selection.selection_anchor_node_id, Some(1));
+    }
+
+    #[test]
+    fn unreachable_pair_keeps_selection_and_reports_status() {
+        let mut state = path_state(&[1, 9]);
+
+        select_path_between_selected(&mut state);
+
+        assert_eq!(selected_ids(&state), [1, 9]);
+        assert!(state.ui.status_message.is_some());
+    }
+
+    #[test]
+    fn requires_exactly_two_selected_nodes() {
+        let mut state = path_state(&[1, 3, 5]);
+
+        select_path_between_selected(&mut state);
+
+        assert_eq!(selected_ids(&state), [1, 3, 5]);
+    }
+}
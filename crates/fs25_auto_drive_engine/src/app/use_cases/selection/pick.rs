@@ -1,59 +1,13 @@
 //! Use-Case: Node-Selektion per Klick (Nearest-Node-Pick).
 
-use crate::core::RoadMap;
+use super::helpers::shortest_path_nodes;
 use crate::AppState;
-use std::collections::{HashMap, HashSet, VecDeque};
-
-/// Berechnet den kuerzesten Pfad (BFS) zwischen zwei Nodes.
-fn shortest_path_nodes(road_map: &RoadMap, start: u64, goal: u64) -> Option<Vec<u64>> {
-    if start == goal {
-        return Some(vec![start]);
-    }
-
-    if !road_map.contains_node(start) || !road_map.contains_node(goal) {
-        return None;
-    }
-
-    let mut queue = VecDeque::new();
-    let mut visited = HashSet::new();
-    let mut predecessors: HashMap<u64, u64> = HashMap::new();
-
-    queue.push_back(start);
-    visited.insert(start);
-
-    while let Some(current) = queue.pop_front() {
-        if current == goal {
-            break;
-        }
-
-        for &(neighbor, _) in road_map.neighbors(current) {
-            if visited.insert(neighbor) {
-                predecessors.insert(neighbor, current);
-                queue.push_back(neighbor);
-            }
-        }
-    }
-
-    if !visited.contains(&goal) {
-        return None;
-    }
-
-    let mut path = vec![goal];
-    let mut current = goal;
-
-    while current != start {
-        let &previous = predecessors.get(&current)?;
-        path.push(previous);
-        current = previous;
-    }
-
-    path.reverse();
-    Some(path)
-}
 
 /// Selektiert den naechsten Node zur gegebenen Weltposition.
 ///
 /// Falls kein Node innerhalb von `max_distance` gefunden wird, wird die Selektion geloescht.
+/// Liegt bei einem nicht-additiven Klick stattdessen eine Verbindung im Radius,
+/// wird diese als Verbindungs-Selektion uebernommen.
 pub fn select_nearest_node(
     state: &mut AppState,
     world_pos: glam::Vec2,
@@ -115,6 +69,10 @@ pub fn select_nearest_node(
         if let Some(node_id) = hit {
             state.selection.ids_mut().insert(node_id);
             state.selection.selection_anchor_node_id = Some(node_id);
+        } else {
+            state.selection.selected_connection = road_map
+                .nearest_connection(world_pos, max_distance)
+                .map(|conn| (conn.start_id, conn.end_id));
         }
     }
 }
@@ -227,6 +185,20 @@ mod tests {
         assert_eq!(state.selection.selection_anchor_node_id, Some(3));
     }
 
+    #[test]
+    fn click_on_connection_selects_connection_instead_of_nodes() {
+        let mut state = with_path_test_map();
+        select_nearest_node(&mut state, glam::Vec2::new(0.1, 0.0), 2.0, false, false);
+
+        select_nearest_node(&mut state, glam::Vec2::new(15.0, 0.5), 2.0, false, false);
+        assert!(state.selection.selected_node_ids.is_empty());
+        assert_eq!(state.selection.selected_connection, Some((2, 3)));
+
+        select_nearest_node(&mut state, glam::Vec2::new(10.1, 0.0), 2.0, false, false);
+        assert_eq!(state.selection.selected_connection, None);
+        assert!(state.selection.selected_node_ids.contains(&2));
+    }
+
     #[test]
     fn additive_without_extend_path_does_not_select_intermediate_nodes() {
         let mut state = with_path_test_map();
@@ -5,6 +5,8 @@ use crate::AppState;
 use super::helpers::{clear_selection, rect_min_max};
 
 /// Selektiert alle Nodes im Rechteck (inkl. Rand).
+///
+/// Im Isolier-Modus ausgeblendete Nodes werden nicht erfasst.
 pub fn select_nodes_in_rect(
     state: &mut AppState,
     corner_a: glam::Vec2,
@@ -19,7 +21,12 @@ pub fn select_nodes_in_rect(
     };
 
     let (min, max) = rect_min_max(corner_a, corner_b);
-    let hit_ids = road_map.nodes_within_rect(min, max);
+    let isolated = state.view.isolation_hidden_node_ids.as_deref();
+    let hit_ids: Vec<u64> = road_map
+        .nodes_within_rect(min, max)
+        .into_iter()
+        .filter(|id| !isolated.is_some_and(|hidden| hidden.contains(id)))
+        .collect();
 
     if !additive {
         state.selection.ids_mut().clear();
@@ -91,4 +98,23 @@ mod tests {
         assert!(state.selection.selected_node_ids.contains(&2));
         assert!(!state.selection.selected_node_ids.contains(&3));
     }
+
+    #[test]
+    fn select_nodes_in_rect_skips_nodes_hidden_by_isolation() {
+        let mut state = with_path_test_map();
+        state
+            .view
+            .set_isolation(Some(Arc::new(indexmap::IndexSet::from_iter([2_u64]))));
+
+        select_nodes_in_rect(
+            &mut state,
+            glam::Vec2::new(-1.0, -1.0),
+            glam::Vec2::new(25.0, 1.0),
+            false,
+        );
+
+        assert!(state.selection.selected_node_ids.contains(&1));
+        assert!(!state.selection.selected_node_ids.contains(&2));
+        assert!(state.selection.selected_node_ids.contains(&3));
+    }
 }
@@ -1,14 +1,15 @@
 //! Use-Case: Rotation selektierter Nodes um ihr gemeinsames Zentrum.
 
+use super::transform_nodes::{collect_transform_node_ids, refresh_locked_segment_positions};
 use crate::AppState;
 use glam::Vec2;
-use std::collections::HashSet;
 use std::sync::Arc;
 
 /// Rotiert alle selektierten Nodes um den gegebenen Winkel (Radiant).
 ///
 /// Der Drehpunkt ist das arithmetische Mittel aller betroffenen Node-Positionen.
-/// Nodes aus gesperrten Segmenten werden analog zum Move-Use-Case mit-rotiert.
+/// Nodes aus gesperrten Segmenten werden analog zum Move-Use-Case mit-rotiert;
+/// gesperrte Nodes (`AppState::locked_node_ids`) bleiben unveraendert.
 ///
 /// Der Spatial-Index wird **nicht** rebuilt — das muss der Aufrufer am Ende
 /// des Rotation-Lifecycles separat anstoßen.
@@ -17,21 +18,18 @@ pub fn rotate_selected_nodes(state: &mut AppState, angle_rad: f32) {
         return;
     }
 
-    let Some(road_map) = state.road_map.as_mut() else {
-        return;
-    };
-
-    if state.selection.selected_node_ids.is_empty() {
+    if state.road_map.is_none() || state.selection.selected_node_ids.is_empty() {
         return;
     }
 
     // Selektion um Nodes von locked Segments erweitern (analog move_nodes.rs)
-    let selected: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
-    let extra = state.group_registry.expand_locked_selection(&selected);
-
-    let rotate_ids: HashSet<u64> = selected.iter().copied().chain(extra).collect();
+    let rotate_ids = collect_transform_node_ids(state);
     let rotate_ids_vec: Vec<u64> = rotate_ids.iter().copied().collect();
 
+    let Some(road_map) = state.road_map.as_mut() else {
+        return;
+    };
+
     let road_map_ref = road_map.as_ref();
 
     // Geometrisches Zentrum aller betroffenen Nodes berechnen
@@ -52,18 +50,7 @@ pub fn rotate_selected_nodes(state: &mut AppState, angle_rad: f32) {
     road_map_mut.rotate_nodes(&rotate_ids_vec, center, angle_rad);
 
     // Locked-Segment-original_positions aktualisieren
-    let locked_segment_ids: Vec<u64> = state
-        .group_registry
-        .records()
-        .filter(|r| r.locked && r.node_ids.iter().any(|id| rotate_ids.contains(id)))
-        .map(|r| r.id)
-        .collect();
-
-    for seg_id in locked_segment_ids {
-        state
-            .group_registry
-            .update_original_positions(seg_id, road_map_mut);
-    }
+    refresh_locked_segment_positions(state, &rotate_ids);
 }
 
 #[cfg(test)]
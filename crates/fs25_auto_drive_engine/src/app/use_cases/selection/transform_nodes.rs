@@ -0,0 +1,150 @@
+//! Use-Case: Rotation und Skalierung selektierter Nodes um einen frei gewaehlten Pivot.
+
+use crate::AppState;
+use glam::Vec2;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Rotiert (`angle_rad`) und skaliert (`scale`) alle selektierten Nodes um `pivot`.
+///
+/// Die Werte sind Deltas relativ zur aktuellen Lage — Hosts senden waehrend eines
+/// Gizmo-Drags pro Frame nur die Aenderung seit dem letzten Schritt. Nodes aus
+/// gesperrten Segmenten werden mit-transformiert, gesperrte Nodes
+/// (`AppState::locked_node_ids`) bleiben unveraendert. Nicht-positive oder
+/// nicht-endliche Skalierungen werden ignoriert.
+///
+/// Der Spatial-Index wird **nicht** rebuilt — das muss der Aufrufer am Ende
+/// des Transform-Lifecycles separat anstoßen.
+pub fn transform_selected_nodes(state: &mut AppState, pivot: Vec2, angle_rad: f32, scale: f32) {
+    if !scale.is_finite() || scale <= 0.0 || (angle_rad == 0.0 && scale == 1.0) {
+        return;
+    }
+    if state.road_map.is_none() || state.selection.selected_node_ids.is_empty() {
+        return;
+    }
+
+    let transform_ids = collect_transform_node_ids(state);
+    let transform_ids_vec: Vec<u64> = transform_ids.iter().copied().collect();
+
+    let Some(road_map) = state.road_map.as_mut() else {
+        return;
+    };
+    let road_map_mut = Arc::make_mut(road_map);
+    if !road_map_mut.transform_nodes(&transform_ids_vec, pivot, angle_rad, scale) {
+        return;
+    }
+
+    refresh_locked_segment_positions(state, &transform_ids);
+}
+
+/// Liefert die von einer Transformation betroffenen Node-IDs.
+///
+/// Selektion plus Nodes gesperrter Segmente (analog `move_nodes.rs`), ohne
+/// einzeln gesperrte Nodes.
+pub(super) fn collect_transform_node_ids(state: &AppState) -> HashSet<u64> {
+    let selected: Vec<u64> = state.selection.selected_node_ids.iter().copied().collect();
+    let extra = state.group_registry.expand_locked_selection(&selected);
+
+    selected
+        .iter()
+        .copied()
+        .chain(extra)
+        .filter(|id| !state.locked_node_ids.contains(id))
+        .collect()
+}
+
+/// Aktualisiert die `original_positions` aller gesperrten Segmente, die
+/// transformierte Nodes enthalten.
+pub(super) fn refresh_locked_segment_positions(state: &mut AppState, node_ids: &HashSet<u64>) {
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+
+    let locked_segment_ids: Vec<u64> = state
+        .group_registry
+        .records()
+        .filter(|r| r.locked && r.node_ids.iter().any(|id| node_ids.contains(id)))
+        .map(|r| r.id)
+        .collect();
+
+    for seg_id in locked_segment_ids {
+        state
+            .group_registry
+            .update_original_positions(seg_id, road_map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
+    use std::f32::consts::FRAC_PI_2;
+
+    fn make_state_with_nodes(positions: &[(u64, f32, f32)]) -> AppState {
+        let mut map = RoadMap::new(positions.len() as u32 + 1);
+        for &(id, x, y) in positions {
+            map.add_node(MapNode::new(id, Vec2::new(x, y), NodeFlag::Regular));
+        }
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state
+    }
+
+    fn position(state: &AppState, id: u64) -> Vec2 {
+        state
+            .road_map
+            .as_ref()
+            .and_then(|rm| rm.node(id))
+            .expect("Node vorhanden")
+            .position
+    }
+
+    #[test]
+    fn scale_doubles_distance_to_pivot() {
+        let mut state = make_state_with_nodes(&[(1, 2.0, 0.0), (2, -2.0, 0.0)]);
+        state.selection.ids_mut().insert(1);
+        state.selection.ids_mut().insert(2);
+
+        transform_selected_nodes(&mut state, Vec2::ZERO, 0.0, 2.0);
+
+        assert!((position(&state, 1) - Vec2::new(4.0, 0.0)).length() < 1e-5);
+        assert!((position(&state, 2) - Vec2::new(-4.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn rotate_and_scale_around_custom_pivot() {
+        let mut state = make_state_with_nodes(&[(1, 11.0, 5.0), (2, 20.0, 20.0)]);
+        state.selection.ids_mut().insert(1);
+
+        transform_selected_nodes(&mut state, Vec2::new(10.0, 5.0), FRAC_PI_2, 3.0);
+
+        // (1,0) relativ zum Pivot → skaliert (3,0) → rotiert (0,3)
+        assert!((position(&state, 1) - Vec2::new(10.0, 8.0)).length() < 1e-4);
+        // Nicht selektierter Node bleibt stehen
+        assert_eq!(position(&state, 2), Vec2::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn locked_nodes_stay_in_place() {
+        let mut state = make_state_with_nodes(&[(1, 1.0, 0.0), (2, -1.0, 0.0)]);
+        state.selection.ids_mut().insert(1);
+        state.selection.ids_mut().insert(2);
+        state.locked_node_ids.insert(2);
+
+        transform_selected_nodes(&mut state, Vec2::ZERO, 0.0, 2.0);
+
+        assert!((position(&state, 1) - Vec2::new(2.0, 0.0)).length() < 1e-5);
+        assert_eq!(position(&state, 2), Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn invalid_scale_is_ignored() {
+        let mut state = make_state_with_nodes(&[(1, 1.0, 0.0)]);
+        state.selection.ids_mut().insert(1);
+
+        transform_selected_nodes(&mut state, Vec2::ZERO, 0.5, 0.0);
+        transform_selected_nodes(&mut state, Vec2::ZERO, 0.5, f32::NAN);
+
+        assert_eq!(position(&state, 1), Vec2::new(1.0, 0.0));
+    }
+}
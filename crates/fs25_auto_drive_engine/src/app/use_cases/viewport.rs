@@ -2,6 +2,8 @@
 
 use crate::app::AppState;
 use crate::shared::RenderQuality;
+use indexmap::IndexSet;
+use std::sync::Arc;
 
 /// Aktualisiert die gespeicherte Viewport-Groesse.
 pub fn resize(state: &mut AppState, size: [f32; 2]) {
@@ -13,9 +15,41 @@ pub fn set_render_quality(state: &mut AppState, quality: RenderQuality) {
     state.view.render_quality = quality;
 }
 
+/// Schaltet den Isolier-Modus um: blendet alle Nodes ausser der aktuellen Selektion aus.
+///
+/// Die ausgeblendete Menge wird beim Einschalten eingefroren; spaeter erstellte
+/// Nodes bleiben daher sichtbar. Ohne Selektion (oder ohne Karte) laesst sich
+/// die Isolation nur ausschalten.
+pub fn toggle_isolate_selection(state: &mut AppState) {
+    if state.view.is_isolation_active() {
+        state.view.set_isolation(None);
+        state.ui.status_message = Some("Isolation aufgehoben".to_string());
+        return;
+    }
+
+    let Some(road_map) = state.road_map.as_deref() else {
+        return;
+    };
+    let selected = &state.selection.selected_node_ids;
+    if selected.is_empty() {
+        return;
+    }
+
+    let hidden: IndexSet<u64> = road_map
+        .nodes()
+        .ids()
+        .filter(|id| !selected.contains(id))
+        .collect();
+    let message = format!("{} Node(s) isoliert", selected.len());
+    state.view.set_isolation(Some(Arc::new(hidden)));
+    log::info!("{}", message);
+    state.ui.status_message = Some(message);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{MapNode, NodeFlag, RoadMap};
 
     #[test]
     fn resize_updates_viewport_size() {
@@ -35,4 +69,60 @@ mod tests {
 
         assert_eq!(state.view.render_quality, RenderQuality::Low);
     }
+
+    fn state_with_nodes() -> AppState {
+        let mut map = RoadMap::new(3);
+        for id in 1..=3 {
+            map.add_node(MapNode::new(
+                id,
+                glam::Vec2::new(id as f32, 0.0),
+                NodeFlag::Regular,
+            ));
+        }
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state
+    }
+
+    #[test]
+    fn toggle_isolate_selection_hides_all_unselected_nodes_and_toggles_back() {
+        let mut state = state_with_nodes();
+        state.selection.ids_mut().insert(2);
+
+        toggle_isolate_selection(&mut state);
+
+        let hidden = state
+            .view
+            .isolation_hidden_node_ids
+            .clone()
+            .expect("Isolation aktiv");
+        let mut hidden_ids: Vec<u64> = hidden.iter().copied().collect();
+        hidden_ids.sort_unstable();
+        assert_eq!(hidden_ids, vec![1, 3]);
+        let revision = state.view.isolation_revision;
+
+        // Die eingefrorene Menge folgt spaeteren Selektionsaenderungen nicht.
+        state.selection.ids_mut().insert(1);
+        assert!(!state
+            .view
+            .isolation_hidden_node_ids
+            .as_ref()
+            .unwrap()
+            .contains(&2));
+
+        toggle_isolate_selection(&mut state);
+
+        assert!(!state.view.is_isolation_active());
+        assert!(state.view.isolation_revision > revision);
+    }
+
+    #[test]
+    fn toggle_isolate_selection_without_selection_is_noop() {
+        let mut state = state_with_nodes();
+
+        toggle_isolate_selection(&mut state);
+
+        assert!(!state.view.is_isolation_active());
+        assert_eq!(state.view.isolation_revision, 0);
+    }
 }
@@ -2,12 +2,40 @@
 
 use crate::app::ui_contract::{
     ClipboardOverlaySnapshot, ClipboardPreviewNode, GroupBoundaryOverlaySnapshot,
-    GroupLockOverlaySnapshot, PolylineOverlaySnapshot, ViewportOverlaySnapshot,
+    GroupLockOverlaySnapshot, LivePlayerOverlaySnapshot, LockedNodeOverlaySnapshot,
+    MeasureOverlaySnapshot, MeasureSegmentSnapshot, OwnedFarmlandOverlaySnapshot,
+    PolylineOverlaySnapshot, SelectedConnectionOverlaySnapshot, TerrainClippingOverlaySnapshot,
+    VehicleOverlaySnapshot, ViewportOverlaySnapshot,
 };
+use crate::app::use_cases::heightmap::effective_height_scale;
 use crate::app::AppState;
-use crate::core::RoadMap;
+use crate::core::{triangulate_polygon, Heightmap, RoadMap};
+use crate::shared::geometry::compass_heading_deg;
 use glam::Vec2;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Schluessel fuer den Cache der Terrain-Warnungen.
+///
+/// Die Heightmap wird ueber die Adresse ihres Arcs identifiziert (neu geladen =
+/// neuer Arc), Gleitkommawerte bitweise verglichen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TerrainClippingCacheKey {
+    map: (u64, u64),
+    heightmap: usize,
+    height_scale: u32,
+    tolerance: u32,
+}
+
+/// Schluessel fuer den Cache der getoenten eigenen Farmlands.
+///
+/// Polygone und Besitzverhaeltnisse werden ueber die Adressen ihrer Arcs
+/// identifiziert (neu geladen = neuer Arc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OwnedFarmlandCacheKey {
+    polygons: usize,
+    ownership: usize,
+}
 
 /// Baut den aktuellen host-neutralen Overlay-Snapshot aus dem AppState.
 pub fn build(state: &mut AppState, cursor_world: Option<Vec2>) -> ViewportOverlaySnapshot {
@@ -15,19 +43,40 @@ pub fn build(state: &mut AppState, cursor_world: Option<Vec2>) -> ViewportOverla
     let road_map = road_map_arc.as_deref();
 
     let route_tool_preview = match (cursor_world, road_map) {
-        (Some(cursor), Some(map)) => state.editor.route_tool_preview(cursor, map),
+        (Some(cursor), Some(map)) => {
+            let cursor = state
+                .editor
+                .constrain_route_tool_pos(cursor, map, &state.options);
+            state.editor.route_tool_preview(cursor, map)
+        }
         _ => None,
     };
 
     let clipboard_preview = build_clipboard_preview(state);
     let distance_preview = build_distance_preview(state);
+    let path_offset_preview = build_path_offset_preview(state);
 
     let mut snapshot = ViewportOverlaySnapshot {
         route_tool_preview,
         clipboard_preview,
         distance_preview,
+        path_offset_preview,
+        measure: build_measure_overlay(state),
         group_locks: Vec::new(),
+        locked_nodes: Vec::new(),
+        selected_connection: None,
         group_boundaries: Vec::new(),
+        terrain_clipping: Vec::new(),
+        owned_farmlands: build_owned_farmland_overlays(state),
+        vehicles: Vec::new(),
+        live_player: state
+            .live_link
+            .player
+            .map(|player| LivePlayerOverlaySnapshot {
+                world_pos: player.position,
+                direction: player.direction(),
+                recording: state.live_link.recording,
+            }),
         show_no_file_hint: road_map.is_none(),
     };
 
@@ -36,19 +85,148 @@ pub fn build(state: &mut AppState, cursor_world: Option<Vec2>) -> ViewportOverla
     };
 
     snapshot.group_locks = build_group_lock_overlays(state, road_map);
+    snapshot.locked_nodes = build_locked_node_overlays(state, road_map);
+    snapshot.selected_connection = build_selected_connection_overlay(state, road_map);
     snapshot.group_boundaries = build_group_boundary_overlays(state, road_map);
+    snapshot.terrain_clipping = build_terrain_clipping_overlays(state, road_map);
+    snapshot.vehicles = build_vehicle_overlays(state);
 
     snapshot
 }
 
+/// Fahrzeug-Icons aus dem Savegame (leer, wenn per Option ausgeblendet).
+fn build_vehicle_overlays(state: &AppState) -> Vec<VehicleOverlaySnapshot> {
+    if !state.options.show_savegame_vehicles {
+        return Vec::new();
+    }
+
+    state
+        .savegame_vehicles
+        .iter()
+        .map(|vehicle| VehicleOverlaySnapshot {
+            name: vehicle.name.clone(),
+            world_pos: vehicle.position,
+            direction: vehicle.direction(),
+        })
+        .collect()
+}
+
+/// Liefert die triangulierten eigenen Farmlands aus dem Cache bzw. berechnet
+/// sie neu, sobald Farmland-Polygone oder Besitzverhaeltnisse neu geladen wurden.
+fn build_owned_farmland_overlays(state: &mut AppState) -> Vec<OwnedFarmlandOverlaySnapshot> {
+    let (Some(polygons), Some(ownership)) = (
+        state.farmland_polygons.clone(),
+        state.farmland_ownership.clone(),
+    ) else {
+        state.owned_farmland_cache = None;
+        return Vec::new();
+    };
+    if !state.options.show_owned_farmlands {
+        return Vec::new();
+    }
+
+    let key = OwnedFarmlandCacheKey {
+        polygons: Arc::as_ptr(&polygons) as usize,
+        ownership: Arc::as_ptr(&ownership) as usize,
+    };
+    if let Some((cached_key, overlays)) = &state.owned_farmland_cache
+        && *cached_key == key
+    {
+        return overlays.clone();
+    }
+
+    let overlays: Vec<_> = polygons
+        .iter()
+        .filter_map(|polygon| {
+            let farm_id = *ownership.owners.get(&polygon.id)?;
+            Some(OwnedFarmlandOverlaySnapshot {
+                farmland_id: polygon.id,
+                farm_id,
+                triangles: triangulate_polygon(&polygon.vertices),
+                outline: polygon.vertices.clone(),
+            })
+        })
+        .collect();
+    state.owned_farmland_cache = Some((key, overlays.clone()));
+    overlays
+}
+
+/// Liefert die Terrain-Warnungen aus dem Cache bzw. berechnet sie neu, sobald
+/// sich Karte, Heightmap, Hoehenskala oder Toleranz geaendert haben.
+fn build_terrain_clipping_overlays(
+    state: &mut AppState,
+    road_map: &RoadMap,
+) -> Vec<TerrainClippingOverlaySnapshot> {
+    let tolerance = state.options.terrain_clipping_tolerance_m;
+    let Some(heightmap) = state.heightmap.clone().filter(|_| tolerance > 0.0) else {
+        state.terrain_clipping_cache = None;
+        return Vec::new();
+    };
+
+    let height_scale = effective_height_scale(state, Some(&heightmap));
+    let key = TerrainClippingCacheKey {
+        map: road_map.render_cache_key(),
+        heightmap: Arc::as_ptr(&heightmap) as usize,
+        height_scale: height_scale.to_bits(),
+        tolerance: tolerance.to_bits(),
+    };
+    if let Some((cached_key, overlays)) = &state.terrain_clipping_cache
+        && *cached_key == key
+    {
+        return overlays.clone();
+    }
+
+    let overlays = compute_terrain_clipping(road_map, &heightmap, height_scale, tolerance);
+    state.terrain_clipping_cache = Some((key, overlays.clone()));
+    overlays
+}
+
+/// Prueft jede Verbindung (Gegenrichtungen nur einmal) gegen das Terrain.
+fn compute_terrain_clipping(
+    road_map: &RoadMap,
+    heightmap: &Heightmap,
+    height_scale: f32,
+    tolerance: f32,
+) -> Vec<TerrainClippingOverlaySnapshot> {
+    let mut seen = HashSet::new();
+    let mut overlays = Vec::new();
+
+    for connection in road_map.connections_iter() {
+        let pair = (
+            connection.start_id.min(connection.end_id),
+            connection.start_id.max(connection.end_id),
+        );
+        if !seen.insert(pair) {
+            continue;
+        }
+        let (Some(start), Some(end)) = (
+            road_map.node_position(connection.start_id),
+            road_map.node_position(connection.end_id),
+        ) else {
+            continue;
+        };
+
+        let (deviation_m, world_pos) = heightmap.chord_deviation(start, end, height_scale);
+        if deviation_m > tolerance {
+            overlays.push(TerrainClippingOverlaySnapshot {
+                start_id: connection.start_id,
+                end_id: connection.end_id,
+                world_pos,
+                deviation_m,
+            });
+        }
+    }
+
+    overlays
+}
+
 fn build_clipboard_preview(state: &AppState) -> Option<ClipboardOverlaySnapshot> {
     let paste_pos = state.paste_preview_pos?;
+    let rotation = state.paste_preview_rotation;
     if state.clipboard.nodes.is_empty() {
         return None;
     }
 
-    let offset = paste_pos - state.clipboard.center;
-
     let marker_ids: HashSet<u64> = state
         .clipboard
         .markers
@@ -61,7 +239,9 @@ fn build_clipboard_preview(state: &AppState) -> Option<ClipboardOverlaySnapshot>
     for (index, node) in state.clipboard.nodes.iter().enumerate() {
         id_to_index.insert(node.id, index);
         nodes.push(ClipboardPreviewNode {
-            world_pos: node.position + offset,
+            world_pos: state
+                .clipboard
+                .placed_position(node.position, paste_pos, rotation),
             has_marker: marker_ids.contains(&node.id),
         });
     }
@@ -94,6 +274,44 @@ fn build_distance_preview(state: &AppState) -> Option<PolylineOverlaySnapshot> {
     })
 }
 
+fn build_path_offset_preview(state: &AppState) -> Option<PolylineOverlaySnapshot> {
+    let path_offset = &state.ui.path_offset;
+    if !path_offset.active || path_offset.preview_positions.is_empty() {
+        return None;
+    }
+
+    Some(PolylineOverlaySnapshot {
+        points: path_offset.preview_positions.clone(),
+    })
+}
+
+/// Messlinie mit Laenge, Summe und Kurs je Segment (ab dem ersten Messpunkt).
+fn build_measure_overlay(state: &AppState) -> Option<MeasureOverlaySnapshot> {
+    let points = &state.editor.measure_points;
+    if points.is_empty() {
+        return None;
+    }
+
+    let mut cumulative_m = 0.0;
+    let segments = points
+        .windows(2)
+        .map(|pair| {
+            let length_m = pair[0].distance(pair[1]);
+            cumulative_m += length_m;
+            MeasureSegmentSnapshot {
+                length_m,
+                cumulative_m,
+                heading_deg: compass_heading_deg(pair[0], pair[1]),
+            }
+        })
+        .collect();
+
+    Some(MeasureOverlaySnapshot {
+        points: points.clone(),
+        segments,
+    })
+}
+
 fn build_group_lock_overlays(
     state: &AppState,
     road_map: &RoadMap,
@@ -129,6 +347,42 @@ fn build_group_lock_overlays(
     overlays
 }
 
+/// Schloss-Badges fuer alle gesperrten Nodes, nach Node-ID sortiert.
+fn build_locked_node_overlays(
+    state: &AppState,
+    road_map: &RoadMap,
+) -> Vec<LockedNodeOverlaySnapshot> {
+    let mut overlays: Vec<LockedNodeOverlaySnapshot> = state
+        .locked_node_ids
+        .iter()
+        .filter_map(|&node_id| {
+            road_map
+                .node(node_id)
+                .map(|node| LockedNodeOverlaySnapshot {
+                    node_id,
+                    world_pos: node.position,
+                })
+        })
+        .collect();
+    overlays.sort_unstable_by_key(|overlay| overlay.node_id);
+    overlays
+}
+
+/// Hervorhebung der selektierten Verbindung (entfaellt, wenn sie nicht mehr existiert).
+fn build_selected_connection_overlay(
+    state: &AppState,
+    road_map: &RoadMap,
+) -> Option<SelectedConnectionOverlaySnapshot> {
+    let (start_id, end_id) = state.selection.selected_connection?;
+    road_map.find_connection(start_id, end_id)?;
+    Some(SelectedConnectionOverlaySnapshot {
+        start_id,
+        end_id,
+        start_pos: road_map.node_position(start_id)?,
+        end_pos: road_map.node_position(end_id)?,
+    })
+}
+
 fn build_group_boundary_overlays(
     state: &mut AppState,
     road_map: &RoadMap,
@@ -224,7 +478,7 @@ fn push_unique_boundary_overlay(
 mod tests {
     use super::*;
     use crate::app::MapNode;
-    use crate::core::NodeFlag;
+    use crate::core::{Connection, ConnectionDirection, ConnectionPriority, NodeFlag};
 
     #[test]
     fn build_marks_no_file_hint_without_map() {
@@ -270,5 +524,218 @@ mod tests {
         assert_eq!(clipboard.connections, vec![(0, 1)]);
         assert_eq!(clipboard.nodes[0].world_pos, Vec2::new(18.0, 20.0));
         assert_eq!(clipboard.nodes[1].world_pos, Vec2::new(22.0, 20.0));
+
+        state.paste_preview_rotation = std::f32::consts::FRAC_PI_2;
+        let rotated = build(&mut state, None)
+            .clipboard_preview
+            .expect("Gedrehte Clipboard-Preview muss vorhanden sein");
+        assert!(rotated.nodes[0].world_pos.distance(Vec2::new(20.0, 18.0)) < 1e-4);
+        assert!(rotated.nodes[1].world_pos.distance(Vec2::new(20.0, 22.0)) < 1e-4);
+    }
+
+    #[test]
+    fn build_exposes_path_offset_preview_only_while_active() {
+        let mut state = AppState::new();
+        state.ui.path_offset.preview_positions = vec![Vec2::ZERO, Vec2::new(5.0, 0.0)];
+
+        assert!(build(&mut state, None).path_offset_preview.is_none());
+
+        state.ui.path_offset.active = true;
+        let preview = build(&mut state, None)
+            .path_offset_preview
+            .expect("Versatz-Vorschau muss vorhanden sein");
+
+        assert_eq!(preview.points, vec![Vec2::ZERO, Vec2::new(5.0, 0.0)]);
+    }
+
+    #[test]
+    fn build_exposes_measure_segments_with_cumulative_length_and_heading() {
+        let mut state = AppState::new();
+        assert!(build(&mut state, None).measure.is_none());
+
+        state.editor.measure_points = vec![Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(3.0, -4.0)];
+        let measure = build(&mut state, None).measure.expect("Messlinie erwartet");
+
+        assert_eq!(measure.points.len(), 3);
+        assert_eq!(measure.segments.len(), 2);
+        assert!((measure.segments[0].length_m - 3.0).abs() < 1e-5);
+        assert!((measure.segments[0].heading_deg - 90.0).abs() < 1e-4);
+        assert!((measure.segments[1].cumulative_m - 7.0).abs() < 1e-5);
+        assert!(measure.segments[1].heading_deg.abs() < 1e-4);
+    }
+
+    #[test]
+    fn build_lists_locked_nodes_with_positions() {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(1.0, 2.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(3.0, 4.0), NodeFlag::Regular));
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        // Node 7 existiert nicht mehr und darf kein Badge erzeugen
+        state.locked_node_ids.extend([2, 7]);
+
+        let snapshot = build(&mut state, None);
+
+        assert_eq!(
+            snapshot.locked_nodes,
+            vec![LockedNodeOverlaySnapshot {
+                node_id: 2,
+                world_pos: Vec2::new(3.0, 4.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn build_highlights_selected_connection_only_while_it_exists() {
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(5.0, 0.0), NodeFlag::Regular));
+        map.add_connection(Connection::new(
+            1,
+            2,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            Vec2::new(0.0, 0.0),
+            Vec2::new(5.0, 0.0),
+        ));
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(map));
+        state.selection.selected_connection = Some((1, 2));
+
+        let snapshot = build(&mut state, None);
+        assert_eq!(
+            snapshot.selected_connection,
+            Some(SelectedConnectionOverlaySnapshot {
+                start_id: 1,
+                end_id: 2,
+                start_pos: Vec2::new(0.0, 0.0),
+                end_pos: Vec2::new(5.0, 0.0),
+            })
+        );
+
+        state.selection.selected_connection = Some((2, 1));
+        assert_eq!(build(&mut state, None).selected_connection, None);
+    }
+
+    #[test]
+    fn build_warns_on_connections_crossing_terrain_ridges() {
+        // 9x9 Pixel ueber -4..4 m, 10 m hoher Kamm bei x = 0
+        let image =
+            image::GrayImage::from_fn(9, 9, |x, _| image::Luma([if x == 4 { 10 } else { 0 }]));
+        let heightmap = Heightmap::from_image(
+            image::DynamicImage::ImageLuma8(image),
+            crate::core::WorldBounds::from_map_size(8.0),
+        )
+        .expect("Heightmap erwartet");
+
+        let mut road_map = RoadMap::new(3);
+        for (id, pos) in [
+            (1, Vec2::new(-3.0, 0.0)),
+            (2, Vec2::new(3.0, 0.0)),
+            (3, Vec2::new(-3.0, 3.0)),
+        ] {
+            road_map.add_node(MapNode::new(id, pos, NodeFlag::Regular));
+        }
+        for (start_id, end_id) in [(1, 2), (1, 3)] {
+            let start = road_map.node_position(start_id).expect("Start-Node");
+            let end = road_map.node_position(end_id).expect("End-Node");
+            road_map.add_connection(crate::app::Connection::new(
+                start_id,
+                end_id,
+                crate::app::ConnectionDirection::Regular,
+                crate::app::ConnectionPriority::Regular,
+                start,
+                end,
+            ));
+        }
+
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(road_map));
+        assert!(build(&mut state, None).terrain_clipping.is_empty());
+
+        state.heightmap = Some(Arc::new(heightmap));
+        let warnings = build(&mut state, None).terrain_clipping;
+        assert_eq!(warnings.len(), 1);
+        assert_eq!((warnings[0].start_id, warnings[0].end_id), (1, 2));
+        assert!((warnings[0].deviation_m - 10.0).abs() < 1e-3);
+        assert!(warnings[0].world_pos.distance(Vec2::ZERO) < 1e-4);
+
+        // Toleranz oberhalb der Abweichung → keine Warnung mehr
+        state.options.terrain_clipping_tolerance_m = 12.0;
+        assert!(build(&mut state, None).terrain_clipping.is_empty());
+    }
+
+    #[test]
+    fn build_lists_savegame_vehicles_unless_hidden() {
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(RoadMap::new(3)));
+        state.savegame_vehicles = vec![crate::core::SavegameVehicle {
+            name: "fendt942Vario".to_string(),
+            position: Vec2::new(5.0, -7.0),
+            yaw: 0.0,
+        }];
+
+        let snapshot = build(&mut state, None);
+        assert_eq!(snapshot.vehicles.len(), 1);
+        assert_eq!(snapshot.vehicles[0].name, "fendt942Vario");
+        assert_eq!(snapshot.vehicles[0].world_pos, Vec2::new(5.0, -7.0));
+        assert_eq!(snapshot.vehicles[0].direction, Vec2::Y);
+
+        state.options.show_savegame_vehicles = false;
+        assert!(build(&mut state, None).vehicles.is_empty());
+    }
+
+    #[test]
+    fn build_tints_only_owned_farmlands() {
+        let square = |offset: f32| {
+            vec![
+                Vec2::new(offset, 0.0),
+                Vec2::new(offset + 4.0, 0.0),
+                Vec2::new(offset + 4.0, 4.0),
+                Vec2::new(offset, 4.0),
+            ]
+        };
+        let mut state = AppState::new();
+        state.farmland_polygons = Some(Arc::new(vec![
+            crate::core::FieldPolygon {
+                id: 1,
+                vertices: square(0.0),
+            },
+            crate::core::FieldPolygon {
+                id: 2,
+                vertices: square(10.0),
+            },
+        ]));
+        assert!(build(&mut state, None).owned_farmlands.is_empty());
+
+        state.farmland_ownership = Some(Arc::new(fs25_map_overview::FarmlandOwnership {
+            owners: HashMap::from([(2, 1)]),
+        }));
+        let owned = build(&mut state, None).owned_farmlands;
+        assert_eq!(owned.len(), 1);
+        assert_eq!((owned[0].farmland_id, owned[0].farm_id), (2, 1));
+        assert_eq!(owned[0].triangles.len(), 2);
+
+        state.options.show_owned_farmlands = false;
+        assert!(build(&mut state, None).owned_farmlands.is_empty());
+    }
+
+    #[test]
+    fn build_shows_live_player_even_without_map() {
+        let mut state = AppState::new();
+        assert!(build(&mut state, None).live_player.is_none());
+
+        state.live_link.player = Some(crate::app::LivePlayerPose {
+            position: Vec2::new(1.0, 2.0),
+            yaw: 0.0,
+        });
+        state.live_link.recording = true;
+
+        let player = build(&mut state, None)
+            .live_player
+            .expect("Spieler-Overlay");
+        assert_eq!(player.world_pos, Vec2::new(1.0, 2.0));
+        assert_eq!(player.direction, Vec2::Y);
+        assert!(player.recording);
     }
 }
@@ -3,6 +3,7 @@
 use anyhow::{Context, Result};
 use image::{DynamicImage, GenericImageView, ImageReader};
 use std::io::{BufReader, Cursor, Read};
+use std::path::Path;
 use std::sync::Arc;
 
 use super::WorldBounds;
@@ -66,7 +67,38 @@ impl BackgroundMap {
             }
         };
 
-        Self::from_image(image, path, crop_size)
+        let mut background = Self::from_image(image, path, crop_size)?;
+
+        // Begleitendes World-File (z.B. `overview.pgw`) legt die Weltausdehnung fest;
+        // nach einem Center-Crop passt es nicht mehr zum Bild
+        if crop_size.is_none() {
+            match fs25_map_overview::WorldFile::find_for_image(Path::new(path)) {
+                Ok(Some(world_file)) => background.apply_world_file(&world_file),
+                Ok(None) => {}
+                Err(e) => log::warn!("World-File ignoriert: {:#}", e),
+            }
+        }
+
+        Ok(background)
+    }
+
+    /// Uebernimmt die Weltausdehnung aus einem World-File statt `1 Pixel = 1 m`.
+    fn apply_world_file(&mut self, world_file: &fs25_map_overview::WorldFile) {
+        let (width, height) = self.image_data.dimensions();
+        let (min_x, min_z, max_x, max_z) = world_file.world_bounds(width, height);
+        self.world_bounds = WorldBounds {
+            min_x: min_x as f32,
+            min_z: min_z as f32,
+            max_x: max_x as f32,
+            max_z: max_z as f32,
+        };
+        log::info!(
+            "Background-Map per World-File ausgerichtet: ({:.1}, {:.1}) bis ({:.1}, {:.1})",
+            min_x,
+            min_z,
+            max_x,
+            max_z
+        );
     }
 
     /// Fuehrt Center-Crop auf ein Bild durch
@@ -328,4 +360,35 @@ mod tests {
         map.set_opacity(0.5);
         assert_eq!(map.opacity(), 0.5);
     }
+
+    #[test]
+    fn load_from_file_uses_companion_world_file_for_bounds() {
+        let dir = std::env::temp_dir().join(format!(
+            "fs25_bg_world_file_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("Systemzeit nach Unix-Epoche")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).expect("Temp-Verzeichnis");
+        let image_path = dir.join("overview.png");
+        // 512 Pixel fuer eine 2048-m-Karte → 4 m pro Pixel
+        fs25_map_overview::save_with_world_file(
+            &DynamicImage::new_rgb8(512, 512),
+            &image_path,
+            2048,
+        )
+        .expect("Overview + World-File speichern");
+
+        let map = BackgroundMap::load_from_file(image_path.to_str().expect("UTF-8-Pfad"), None)
+            .expect("Background laden");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let bounds = map.world_bounds();
+        assert_eq!(
+            (bounds.min_x, bounds.min_z, bounds.max_x, bounds.max_z),
+            (-1024.0, -1024.0, 1024.0, 1024.0)
+        );
+    }
 }
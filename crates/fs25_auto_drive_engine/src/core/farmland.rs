@@ -77,8 +77,18 @@ fn perpendicular_distance(pt: Vec2, line_start: Vec2, line_end: Vec2) -> f32 {
 /// Gibt eine vereinfachte Teilmenge der Eingabepunkte zurueck, beginnend
 /// mit dem ersten und endend mit dem letzten Punkt.
 fn dp_open(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
+    dp_open_indices(points, tolerance)
+        .into_iter()
+        .map(|index| points[index])
+        .collect()
+}
+
+/// Douglas-Peucker fuer offene Polylinien, liefert die Indizes der behaltenen Punkte.
+///
+/// Die Indizes sind aufsteigend und enthalten immer den ersten und letzten Punkt.
+fn dp_open_indices(points: &[Vec2], tolerance: f32) -> Vec<usize> {
     if points.len() <= 2 {
-        return points.to_vec();
+        return (0..points.len()).collect();
     }
     let first = points[0];
     let last = *points
@@ -103,14 +113,14 @@ fn dp_open(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
 
     if max_dist > tolerance {
         // Teile am Maximum und vereinfache rekursiv
-        let mut left = dp_open(&points[..=max_idx], tolerance);
-        let right = dp_open(&points[max_idx..], tolerance);
+        let mut left = dp_open_indices(&points[..=max_idx], tolerance);
+        let right = dp_open_indices(&points[max_idx..], tolerance);
         left.pop(); // Duplikat des Teilungspunktes entfernen
-        left.extend(right);
+        left.extend(right.into_iter().map(|index| index + max_idx));
         left
     } else {
         // Alle Zwischenpunkte liegen innerhalb der Toleranz
-        vec![first, last]
+        vec![0, points.len() - 1]
     }
 }
 
@@ -200,6 +210,17 @@ pub fn simplify_polyline(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
     dp_open(points, tolerance)
 }
 
+/// Wie [`simplify_polyline`], liefert aber die aufsteigenden Indizes der
+/// behaltenen Punkte (erster und letzter Punkt sind immer enthalten).
+///
+/// - `tolerance = 0.0` → alle Indizes.
+pub fn simplify_polyline_indices(points: &[Vec2], tolerance: f32) -> Vec<usize> {
+    if points.len() < 2 || tolerance <= 0.0 {
+        return (0..points.len()).collect();
+    }
+    dp_open_indices(points, tolerance)
+}
+
 /// Verschiebt ein Polygon um `offset` Meter nach innen (negativ) oder aussen (positiv).
 ///
 /// Nutzt Normalen-basiertes Vertex-Offset mit Miter-Korrektur: jeder Vertex
@@ -302,6 +323,76 @@ pub fn offset_polygon(vertices: &[Vec2], offset: f32) -> Vec<Vec2> {
     result
 }
 
+/// Zerlegt ein einfaches (nicht selbstschneidendes) Polygon per Ear-Clipping in Dreiecke.
+///
+/// Liefert Index-Tripel in `vertices`; die Orientierung (CW/CCW) ist beliebig.
+/// Kollineare Punkte werden ohne Dreieck entfernt. Bei degenerierten Umrissen
+/// bricht die Zerlegung ab und liefert die bis dahin gefundenen Dreiecke.
+pub fn triangulate_polygon(vertices: &[Vec2]) -> Vec<[usize; 3]> {
+    let area = polygon_area_signed(vertices);
+    if vertices.len() < 3 || area.abs() <= f32::EPSILON {
+        return Vec::new();
+    }
+    let orientation = area.signum();
+
+    let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+    let mut triangles = Vec::with_capacity(vertices.len() - 2);
+    let mut i = 0;
+    let mut misses = 0;
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let (a, b, c) = (
+            remaining[(i + len - 1) % len],
+            remaining[i],
+            remaining[(i + 1) % len],
+        );
+        let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+        let cross = (pb - pa).perp_dot(pc - pb) * orientation;
+
+        if cross.abs() <= f32::EPSILON {
+            // Kollinearer Punkt: traegt keine Flaeche bei
+            remaining.remove(i);
+            misses = 0;
+        } else if cross > 0.0
+            && !remaining
+                .iter()
+                .filter(|&&k| k != a && k != b && k != c)
+                .any(|&k| point_in_triangle(vertices[k], pa, pb, pc))
+        {
+            triangles.push([a, b, c]);
+            remaining.remove(i);
+            misses = 0;
+        } else {
+            i += 1;
+            misses += 1;
+            if misses > len {
+                log::debug!("Ear-Clipping abgebrochen: Umriss ist nicht einfach");
+                return triangles;
+            }
+        }
+        i %= remaining.len();
+    }
+    let [a, b, c] = [remaining[0], remaining[1], remaining[2]];
+    if (vertices[b] - vertices[a])
+        .perp_dot(vertices[c] - vertices[b])
+        .abs()
+        > f32::EPSILON
+    {
+        triangles.push([a, b, c]);
+    }
+    triangles
+}
+
+/// Prueft, ob `point` im Dreieck (`a`, `b`, `c`) liegt; Randpunkte zaehlen als innen.
+fn point_in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    let d1 = (b - a).perp_dot(point - a);
+    let d2 = (c - b).perp_dot(point - b);
+    let d3 = (a - c).perp_dot(point - c);
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
 // ---------------------------------------------------------------------------
 // Rasterbasierte Farmland-ID-Karte
 // ---------------------------------------------------------------------------
@@ -387,6 +478,55 @@ mod tests {
         ]
     }
 
+    // --- simplify_polyline_indices ---
+
+    #[test]
+    fn test_simplify_polyline_indices_behaelt_ecke_und_enden() {
+        let points = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.05),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.05, 1.0),
+            Vec2::new(2.0, 2.0),
+        ];
+
+        let indices = simplify_polyline_indices(&points, 0.2);
+
+        assert_eq!(indices, vec![0, 2, 4]);
+        let via_points: Vec<Vec2> = indices.iter().map(|&i| points[i]).collect();
+        assert_eq!(via_points, simplify_polyline(&points, 0.2));
+    }
+
+    // --- triangulate_polygon ---
+
+    #[test]
+    fn test_triangulate_l_form_deckt_flaeche_ab() {
+        // L-Form (CW) mit einer konkaven Ecke und einem kollinearen Punkt
+        let poly = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 4.0),
+            Vec2::new(2.0, 4.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(4.0, 1.0),
+            Vec2::new(4.0, 0.0),
+        ];
+        let triangles = triangulate_polygon(&poly);
+
+        let area: f32 = triangles
+            .iter()
+            .map(|&[a, b, c]| (poly[b] - poly[a]).perp_dot(poly[c] - poly[a]).abs() / 2.0)
+            .sum();
+        assert!(triangles.len() <= poly.len() - 2);
+        assert!((area - 12.0).abs() < 1e-4, "Flaeche {area}");
+    }
+
+    #[test]
+    fn test_triangulate_degeneriert_leer() {
+        assert!(triangulate_polygon(&[Vec2::ZERO, Vec2::X]).is_empty());
+        assert!(triangulate_polygon(&[Vec2::ZERO, Vec2::X, Vec2::new(2.0, 0.0)]).is_empty());
+    }
+
     // --- point_in_polygon ---
 
     #[test]
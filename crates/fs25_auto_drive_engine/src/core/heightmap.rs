@@ -2,11 +2,16 @@
 //!
 //! Erkennt automatisch die Bit-Tiefe (8-Bit oder 16-Bit) und normalisiert
 //! die Pixelwerte entsprechend. Die Map-Groesse wird aus den Pixel-Dimensionen
-//! abgeleitet (FS25-Konvention: pixels = map_size + 1).
+//! abgeleitet (FS25-Konvention: pixels = map_size + 1). Aus einem Map-Mod-ZIP
+//! geladene DEMs bringen Weltgroesse und Hoehenskala der Karte selbst mit.
 
 use anyhow::{Context, Result};
+use glam::Vec2;
 use image::{DynamicImage, GenericImageView};
 
+/// Obergrenze der Stuetzstellen pro Strecke in [`Heightmap::chord_deviation`].
+const MAX_CHORD_SAMPLES: usize = 256;
+
 /// Heightmap fuer Y-Koordinaten-Berechnung
 pub struct Heightmap {
     /// Normalisierte Grauwerte [0.0, 1.0], zeilenweise gespeichert
@@ -17,6 +22,8 @@ pub struct Heightmap {
     world_bounds: WorldBounds,
     /// Erkannte Bit-Tiefe (8 oder 16)
     bit_depth: u8,
+    /// Hoehenskala aus der Map-i3d (nur bei Laden aus einem Map-Mod-ZIP)
+    height_scale: Option<f32>,
 }
 
 /// Weltkoordinaten-Begrenzungen der Heightmap
@@ -70,18 +77,43 @@ impl Heightmap {
         Self::from_image(image, world_bounds)
     }
 
+    /// Laedt das DEM aus einem Map-Mod-ZIP (oder entpackten Mod-Ordner).
+    ///
+    /// World-Bounds kommen aus dem Terrain der Map-i3d (Groesse und Versatz der
+    /// Terrain-Mitte), ohne i3d aus der Kartengroesse der Map-Config – nicht aus
+    /// den Pixel-Dimensionen (DEMs mit `unitsPerPixel = 2` sind halb so gross).
+    /// Die Hoehenskala der Karte ist ueber [`Self::height_scale`] abrufbar.
+    pub fn load_from_map_zip(zip_path: &str) -> Result<Self> {
+        let extracted = fs25_map_overview::extract_heightmap_from_zip(zip_path)
+            .with_context(|| format!("Fehler beim Laden des DEM aus: {}", zip_path))?;
+        let (min_x, min_z, max_x, max_z) = extracted.world_bounds();
+        let world_bounds = WorldBounds {
+            min_x,
+            min_z,
+            max_x,
+            max_z,
+        };
+
+        let mut heightmap = Self::from_image(extracted.dem, world_bounds)?;
+        heightmap.height_scale = Some(extracted.height_scale);
+        Ok(heightmap)
+    }
+
     /// Erstellt eine Heightmap aus einem geladenen Bild.
     /// Erkennt die Bit-Tiefe automatisch und konvertiert alle Pixel
     /// in normalisierte f32-Werte [0.0, 1.0].
-    fn from_image(image: DynamicImage, world_bounds: WorldBounds) -> Result<Self> {
+    pub(crate) fn from_image(image: DynamicImage, world_bounds: WorldBounds) -> Result<Self> {
         let (width, height) = image.dimensions();
 
-        // Bit-Tiefe aus dem Farbtyp erkennen
+        // Bit-Tiefe aus dem Farbtyp erkennen; Float-Bilder verlieren als
+        // 16-Bit-Werte keine fuer Hoehen relevante Genauigkeit
         let bit_depth = match image.color() {
             image::ColorType::L16
             | image::ColorType::La16
             | image::ColorType::Rgb16
-            | image::ColorType::Rgba16 => 16u8,
+            | image::ColorType::Rgba16
+            | image::ColorType::Rgb32F
+            | image::ColorType::Rgba32F => 16u8,
             _ => 8u8,
         };
 
@@ -111,12 +143,15 @@ impl Heightmap {
             height,
             world_bounds,
             bit_depth,
+            height_scale: None,
         })
     }
 
     /// Berechnet Y-Koordinate (Hoehe) fuer eine gegebene X/Z-Position.
     ///
-    /// Verwendet bikubische Interpolation (4×4 Nachbarpixel) fuer praezise, glatte Hoehenwerte.
+    /// Verwendet bilineare Interpolation der 4 umgebenden Pixel. Anders als
+    /// kubische Verfahren ueberschwingt sie an Boeschungen und Dammkanten nicht,
+    /// Nodes landen also nie ueber oder unter dem tatsaechlichen Terrain.
     /// Die Formel ist: `Y_meter = normalized_pixel × height_scale`
     ///
     /// Fuer Standard-FS25-Maps gilt `height_scale = 255.0` (maximale Terrainhoehe).
@@ -147,8 +182,7 @@ impl Heightmap {
             pz
         );
 
-        // Bikubische Interpolation (nutzt 16 Nachbarpixel fuer beste Qualitaet)
-        let height = self.sample_bicubic(px, pz);
+        let height = self.sample_bilinear(px, pz);
 
         // Debug: Zeige Interpolationsergebnis
         log::trace!(
@@ -161,71 +195,68 @@ impl Heightmap {
         height * height_scale
     }
 
-    /// Bikubische Interpolation fuer glatte Hoehenwerte
-    /// Nutzt 4x4 Grid von Pixeln um den Sample-Punkt
-    fn sample_bicubic(&self, px: f32, pz: f32) -> f32 {
-        let x = px.floor() as i32;
-        let z = pz.floor() as i32;
-
-        let fx = px - px.floor();
-        let fz = pz - pz.floor();
-
-        // Debug: Zeige Interpolationsfaktoren
-        log::trace!("  Bicubic: x={}, z={}, fx={:.6}, fz={:.6}", x, z, fx, fz);
-
-        // Sample 4x4 Grid von Pixeln
-        let mut values = [[0.0f32; 4]; 4];
-        for (j, row) in values.iter_mut().enumerate() {
-            for (i, cell) in row.iter_mut().enumerate() {
-                let sample_x = (x + i as i32 - 1).clamp(0, self.width as i32 - 1) as u32;
-                let sample_z = (z + j as i32 - 1).clamp(0, self.height as i32 - 1) as u32;
-                *cell = self.get_grayscale(sample_x, sample_z);
-            }
-        }
+    /// Groesste Abweichung des Terrains von der Geraden zwischen `start` und `end`.
+    ///
+    /// Beide Endpunkte liegen wie beim Export auf dem Terrain; dazwischen wird
+    /// etwa einmal pro Pixelabstand gesampelt. Liefert den Betrag der
+    /// Abweichung in Metern (Kuppe wie Senke) und die Weltposition, an der sie
+    /// auftritt.
+    pub fn chord_deviation(&self, start: Vec2, end: Vec2, height_scale: f32) -> (f32, Vec2) {
+        let pixel_size =
+            (self.world_bounds.max_x - self.world_bounds.min_x) / (self.width.max(2) - 1) as f32;
+        let steps =
+            ((start.distance(end) / pixel_size).ceil() as usize).clamp(1, MAX_CHORD_SAMPLES);
+        let start_height = self.sample_height(start.x, start.y, height_scale);
+        let end_height = self.sample_height(end.x, end.y, height_scale);
+
+        (1..steps)
+            .map(|step| {
+                let t = step as f32 / steps as f32;
+                let pos = start.lerp(end, t);
+                let chord = Self::lerp(start_height, end_height, t);
+                (
+                    (self.sample_height(pos.x, pos.y, height_scale) - chord).abs(),
+                    pos,
+                )
+            })
+            .fold((0.0, start.lerp(end, 0.5)), |max, sample| {
+                if sample.0 > max.0 {
+                    sample
+                } else {
+                    max
+                }
+            })
+    }
 
-        // Debug: Zeige das zentrale 2x2 Grid
-        log::trace!(
-            "  Center 2x2 grid: [{:.3}, {:.3} | {:.3}, {:.3}]",
-            values[1][1],
-            values[1][2],
-            values[2][1],
-            values[2][2]
-        );
+    /// Bilineare Interpolation zwischen den 2x2 Pixeln um den Sample-Punkt
+    fn sample_bilinear(&self, px: f32, pz: f32) -> f32 {
+        let x0 = (px.floor() as u32).min(self.width - 1);
+        let z0 = (pz.floor() as u32).min(self.height - 1);
+        let x1 = (x0 + 1).min(self.width - 1);
+        let z1 = (z0 + 1).min(self.height - 1);
 
-        // Bikubische Interpolation in X-Richtung
-        let mut col_values = [0.0f32; 4];
-        for j in 0..4 {
-            col_values[j] =
-                Self::cubic_interpolate(values[j][0], values[j][1], values[j][2], values[j][3], fx);
-        }
+        let fx = px - x0 as f32;
+        let fz = pz - z0 as f32;
+
+        let top = Self::lerp(self.get_grayscale(x0, z0), self.get_grayscale(x1, z0), fx);
+        let bottom = Self::lerp(self.get_grayscale(x0, z1), self.get_grayscale(x1, z1), fx);
+        let result = Self::lerp(top, bottom, fz);
 
-        // Bikubische Interpolation in Z-Richtung
-        let result = Self::cubic_interpolate(
-            col_values[0],
-            col_values[1],
-            col_values[2],
-            col_values[3],
+        log::trace!(
+            "  Bilinear: x={}, z={}, fx={:.6}, fz={:.6} -> {:.6}",
+            x0,
+            z0,
+            fx,
             fz,
+            result
         );
 
-        log::trace!("  Bicubic result: {:.6}", result);
-
         result
     }
 
-    /// Kubische Interpolation zwischen 4 Werten
-    /// Nutzt Catmull-Rom Spline fuer glatte Kurven
-    fn cubic_interpolate(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
-        let t2 = t * t;
-        let t3 = t2 * t;
-
-        // Catmull-Rom Spline Koeffizienten
-        let a = -0.5 * p0 + 1.5 * p1 - 1.5 * p2 + 0.5 * p3;
-        let b = p0 - 2.5 * p1 + 2.0 * p2 - 0.5 * p3;
-        let c = -0.5 * p0 + 0.5 * p2;
-        let d = p1;
-
-        a * t3 + b * t2 + c * t + d
+    /// Lineare Interpolation zwischen `a` (t=0) und `b` (t=1)
+    fn lerp(a: f32, b: f32, t: f32) -> f32 {
+        a + (b - a) * t
     }
 
     /// Holt normalisierten Grauwert eines Pixels (0.0 = schwarz, 1.0 = weiss).
@@ -234,6 +265,18 @@ impl Heightmap {
         self.pixels[(y * self.width + x) as usize]
     }
 
+    /// Gibt die Heightmap als 16-Bit-Graustufenbild zurueck (z.B. fuer Reliefdarstellungen).
+    pub fn to_image(&self) -> DynamicImage {
+        let pixels = self
+            .pixels
+            .iter()
+            .map(|&value| (value * 65535.0).round() as u16)
+            .collect();
+        let buffer = image::ImageBuffer::from_raw(self.width, self.height, pixels)
+            .expect("Pixelanzahl entspricht den Heightmap-Dimensionen");
+        DynamicImage::ImageLuma16(buffer)
+    }
+
     /// Gibt die Dimensionen der Heightmap zurueck
     pub fn dimensions(&self) -> (u32, u32) {
         (self.width, self.height)
@@ -248,6 +291,13 @@ impl Heightmap {
     pub fn world_bounds(&self) -> &WorldBounds {
         &self.world_bounds
     }
+
+    /// Hoehenskala der Karte, falls aus einem Map-Mod-ZIP geladen.
+    ///
+    /// `None` bei Bilddateien; dann gilt die Editor-Einstellung.
+    pub fn height_scale(&self) -> Option<f32> {
+        self.height_scale
+    }
 }
 
 #[cfg(test)]
@@ -267,40 +317,145 @@ mod tests {
         assert_eq!(small.max_x, 512.0);
     }
 
+    /// 8-Bit-Heightmap ueber `pixels` (zeilenweise), Map-Groesse = Pixel - 1.
+    fn heightmap(width: u32, height: u32, pixels: &[u8]) -> Heightmap {
+        let image = image::GrayImage::from_raw(width, height, pixels.to_vec())
+            .expect("Pixelanzahl passt zur Groesse");
+        let size = (width.max(height) - 1) as f32;
+        Heightmap::from_image(
+            DynamicImage::ImageLuma8(image),
+            WorldBounds::from_map_size(size),
+        )
+        .expect("Heightmap erwartet")
+    }
+
+    #[test]
+    fn test_bilinear_sampling_between_pixels() {
+        // 3x3 Pixel → Welt von -1 bis 1, Pixelmitten auf ganzen Metern
+        let hm = heightmap(3, 3, &[0, 100, 200, 0, 100, 200, 50, 150, 250]);
+
+        // Exakt auf einem Pixel
+        assert!((hm.sample_height(0.0, 0.0, 255.0) - 100.0).abs() < 1e-3);
+        // Halb zwischen zwei Pixeln in X
+        assert!((hm.sample_height(-0.5, -1.0, 255.0) - 50.0).abs() < 1e-3);
+        // Mitte einer Zelle: Mittel der vier Ecken (100, 200, 150, 250)
+        assert!((hm.sample_height(0.5, 0.5, 255.0) - 175.0).abs() < 1e-3);
+        // Rand: kein Zugriff ausserhalb des Bildes
+        assert!((hm.sample_height(1.0, 1.0, 255.0) - 250.0).abs() < 1e-3);
+    }
+
     #[test]
-    fn test_cubic_interpolation() {
-        // Test Catmull-Rom Interpolation
-        // Bei t=0 sollte p1 zurueckgegeben werden
-        let result = Heightmap::cubic_interpolate(0.0, 0.5, 1.0, 1.5, 0.0);
-        assert!((result - 0.5).abs() < 0.001);
-
-        // Bei t=1 sollte p2 zurueckgegeben werden
-        let result = Heightmap::cubic_interpolate(0.0, 0.5, 1.0, 1.5, 1.0);
-        assert!((result - 1.0).abs() < 0.001);
-
-        // Bei t=0.5 sollte ein Wert zwischen p1 und p2 zurueckgegeben werden
-        let result = Heightmap::cubic_interpolate(0.0, 0.5, 1.0, 1.5, 0.5);
-        assert!(result > 0.5 && result < 1.0);
+    fn test_16bit_heightmap_keeps_sub_8bit_precision() {
+        // Beide Werte fielen als 8-Bit auf dieselbe Stufe (117)
+        let image = image::ImageBuffer::from_raw(2, 1, vec![30_000u16, 30_100])
+            .expect("Pixelanzahl passt zur Groesse");
+        let hm = Heightmap::from_image(
+            DynamicImage::ImageLuma16(image),
+            WorldBounds::from_map_size(1.0),
+        )
+        .expect("Heightmap erwartet");
+
+        assert_eq!(hm.bit_depth(), 16);
+        let left = hm.sample_height(-0.5, 0.0, 255.0);
+        let mid = hm.sample_height(0.0, 0.0, 255.0);
+        let right = hm.sample_height(0.5, 0.0, 255.0);
+        assert!((left - 30_000.0 / 257.0).abs() < 1e-3, "{left}");
+        assert!((right - 30_100.0 / 257.0).abs() < 1e-3, "{right}");
+        assert!(left < mid && mid < right);
     }
 
     #[test]
-    fn test_cubic_interpolation_non_integer() {
-        // Test mit Werten wie sie von Heightmap-Pixeln kommen wuerden
-        // Simuliere Hoehen 35m, 36m, 37m, 38m als normalisierte Werte
-        let p0 = 35.0 / 255.0; // 0.137254...
-        let p1 = 36.0 / 255.0; // 0.141176...
-        let p2 = 37.0 / 255.0; // 0.145098...
-        let p3 = 38.0 / 255.0; // 0.149019...
-
-        // Bei t=0.5 zwischen p1 und p2 sollte ein Wert ~36.5/255 rauskommen
-        let result = Heightmap::cubic_interpolate(p0, p1, p2, p3, 0.5);
-        let height_meters = result * 255.0;
-
-        // Sollte zwischen 36.0 und 37.0 liegen, aber nicht exakt 36.5
-        // (wegen der Catmull-Rom Spline-Kurve)
-        assert!(height_meters > 36.0 && height_meters < 37.0);
-
-        // Sollte definitiv Nachkommastellen haben
-        assert!((height_meters - height_meters.round()).abs() > 0.001);
+    fn test_bilinear_sampling_does_not_overshoot_steps() {
+        // Boeschung: flach, dann Sprung – Hoehe bleibt zwischen den Nachbarn
+        let hm = heightmap(5, 1, &[10, 10, 10, 200, 200]);
+        let size = 4.0;
+        for step in 0..=40 {
+            let x = -size / 2.0 + step as f32 * size / 40.0;
+            let y = hm.sample_height(x, 0.0, 255.0);
+            assert!((10.0 - 1e-3..=200.0 + 1e-3).contains(&y), "x={x}: {y}");
+        }
+    }
+
+    #[test]
+    fn test_load_from_map_zip_uses_map_size_and_height_scale() {
+        use std::io::{Cursor, Write};
+
+        let mut dem_png = Vec::new();
+        DynamicImage::ImageLuma16(image::ImageBuffer::from_pixel(
+            17,
+            17,
+            image::Luma([32768u16]),
+        ))
+        .write_to(&mut Cursor::new(&mut dem_png), image::ImageFormat::Png)
+        .expect("DEM-PNG muss kodiert werden");
+
+        let zip_path = std::env::temp_dir().join(format!(
+            "fs25_heightmap_zip_{}_{}.zip",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("Systemzeit nach Unix-Epoche")
+                .as_nanos()
+        ));
+        let mut writer = zip::ZipWriter::new(
+            std::fs::File::create(&zip_path).expect("ZIP-Datei muss erstellt werden"),
+        );
+        for (name, bytes) in [
+            (
+                "modDesc.xml",
+                br#"<modDesc><title><en>Hills</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                    .to_vec(),
+            ),
+            (
+                "maps/config/map.xml",
+                br#"<map width="32" height="32"><filename>maps/map.i3d</filename></map>"#.to_vec(),
+            ),
+            (
+                "maps/map.i3d",
+                br#"<i3D><TerrainTransformGroup heightScale="400" unitsPerPixel="2" /></i3D>"#
+                    .to_vec(),
+            ),
+            ("maps/data/dem.png", dem_png),
+        ] {
+            writer
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .expect("ZIP-Eintrag muss angelegt werden");
+            writer
+                .write_all(&bytes)
+                .expect("ZIP-Eintrag muss geschrieben werden");
+        }
+        writer.finish().expect("ZIP muss finalisiert werden");
+
+        let loaded = Heightmap::load_from_map_zip(zip_path.to_str().expect("UTF-8-Pfad"));
+        let _ = std::fs::remove_file(&zip_path);
+        let heightmap = loaded.expect("DEM muss aus dem ZIP geladen werden");
+
+        // 17 Pixel decken 32 m ab (unitsPerPixel = 2), nicht 16 m
+        assert_eq!(heightmap.world_bounds().max_x, 16.0);
+        assert_eq!(heightmap.world_bounds().min_z, -16.0);
+        assert_eq!(heightmap.bit_depth(), 16);
+        assert_eq!(heightmap.height_scale(), Some(400.0));
+        let y = heightmap.sample_height(0.0, 0.0, 400.0);
+        assert!((y - 200.0).abs() < 0.01, "Hoehe {y}");
+    }
+
+    #[test]
+    fn test_chord_deviation_finds_ridge_between_endpoints() {
+        // 5x5 Pixel → Welt von -2 bis 2, Kamm bei x = 0
+        let hm = heightmap(
+            5,
+            5,
+            &[
+                0, 0, 100, 0, 0, 0, 0, 100, 0, 0, 0, 0, 100, 0, 0, 0, 0, 100, 0, 0, 0, 0, 100, 0, 0,
+            ],
+        );
+
+        let (deviation, pos) = hm.chord_deviation(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0), 255.0);
+        assert!((deviation - 100.0).abs() < 1e-3);
+        assert!(pos.distance(Vec2::ZERO) < 1e-4);
+
+        // Entlang des Kamms keine Abweichung
+        let (flat, _) = hm.chord_deviation(Vec2::new(0.0, -2.0), Vec2::new(0.0, 2.0), 255.0);
+        assert!(flat.abs() < 1e-3);
     }
 }
@@ -20,8 +20,14 @@ pub mod meta;
 pub mod node;
 /// Zentrales Straßennetz-Datenmodell mit Nodes, Connections und Spatial-Index.
 pub mod road_map;
+/// Kurzuebersicht eines Savegames im FS25-Profil (Karte, Hof, Speicherdatum).
+pub mod savegame_summary;
+/// Fahrzeugpositionen aus der `vehicles.xml` eines Savegames.
+pub mod savegame_vehicle;
 /// Spatial-Index (KD-Tree) fuer schnelle Node-Abfragen.
 pub mod spatial;
+/// Eintraege des AutoDrive-Routen-Managers (`routes.xml` im Spielprofil).
+pub mod stored_route;
 /// Zhang-Suen-Thinning: Skelettierung von Binaermasken.
 pub mod thinning;
 
@@ -35,12 +41,15 @@ pub use centerline::{
 pub use connection::{Connection, ConnectionDirection, ConnectionPriority};
 pub use farmland::{
     find_polygon_at, offset_polygon, point_in_polygon, simplify_polygon, simplify_polyline,
-    FarmlandGrid, FieldPolygon,
+    simplify_polyline_indices, triangulate_polygon, FarmlandGrid, FieldPolygon,
 };
 pub use heightmap::{Heightmap, WorldBounds};
 pub use map_marker::MapMarker;
 pub use meta::AutoDriveMeta;
 pub use node::{MapNode, NodeFlag};
-pub use road_map::{BoundaryNode, ConnectedNeighbor, DeduplicationResult, RoadMap};
-pub use spatial::{SpatialIndex, SpatialMatch};
+pub use road_map::{BoundaryNode, ConnectedNeighbor, DeduplicationResult, NodeStore, RoadMap};
+pub use savegame_summary::SavegameSummary;
+pub use savegame_vehicle::SavegameVehicle;
+pub use spatial::{spatial_query_count, SpatialIndex, SpatialMatch};
+pub use stored_route::StoredRoute;
 pub use thinning::zhang_suen_thinning;
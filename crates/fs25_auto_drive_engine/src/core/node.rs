@@ -60,7 +60,7 @@ impl NodeFlag {
 }
 
 /// Ein Wegpunkt im AutoDrive-Netzwerk.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct MapNode {
     /// Eindeutige ID (vom User/AutoDrive vergeben)
     pub id: u64,
@@ -36,14 +36,16 @@ mod boundary;
 mod chain;
 mod dedup;
 mod neighbors;
+mod node_store;
 mod query;
 pub use dedup::DeduplicationResult;
+pub use node_store::NodeStore;
 
 /// Vollstaendige AutoDrive-Konfiguration
 #[derive(Debug, Clone)]
 pub struct RoadMap {
-    /// Alle Wegpunkte, indexiert nach ihrer ID
-    nodes: HashMap<u64, MapNode>,
+    /// Alle Wegpunkte im SoA-Layout (stabile Slots + ID-Map)
+    nodes: NodeStore,
     /// Alle Verbindungen, indexiert nach (start_id, end_id) fuer O(1)-Zugriff
     connections: HashMap<(u64, u64), Connection>,
     /// Alle Map-Marker
@@ -61,6 +63,8 @@ pub struct RoadMap {
     /// Adjacency-Index: Node-ID → Liste von (Nachbar-ID, ist_ausgehend).
     /// Wird bei jeder Connection-Mutation synchron gepflegt.
     adjacency: HashMap<u64, Vec<(u64, bool)>>,
+    /// Manuell gesetzte Y-Werte (Hoehe) pro Node; ueberschreiben beim Export die Heightmap.
+    height_overrides: HashMap<u64, f32>,
     /// Stabile Instanz-ID fuer render-seitige Snapshot-Caches.
     render_instance_id: u64,
     /// Revision fuer render-relevante Mutationen.
@@ -71,7 +75,7 @@ impl RoadMap {
     /// Erstellt eine neue leere RoadMap
     pub fn new(version: u32) -> Self {
         Self {
-            nodes: HashMap::new(),
+            nodes: NodeStore::new(),
             connections: HashMap::new(),
             map_markers: Vec::new(),
             meta: AutoDriveMeta::default(),
@@ -80,6 +84,7 @@ impl RoadMap {
             spatial_index: SpatialIndex::empty(),
             spatial_dirty: false,
             adjacency: HashMap::new(),
+            height_overrides: HashMap::new(),
             render_instance_id: NEXT_RENDER_INSTANCE_ID.fetch_add(1, Ordering::Relaxed),
             render_revision: 0,
         }
@@ -87,7 +92,7 @@ impl RoadMap {
 
     pub(crate) fn from_parts(
         version: u32,
-        nodes: HashMap<u64, MapNode>,
+        nodes: NodeStore,
         connections: Vec<Connection>,
         map_markers: Vec<MapMarker>,
         meta: AutoDriveMeta,
@@ -106,11 +111,11 @@ impl RoadMap {
             spatial_index: SpatialIndex::empty(),
             spatial_dirty: false,
             adjacency: HashMap::new(),
+            height_overrides: HashMap::new(),
             render_instance_id: NEXT_RENDER_INSTANCE_ID.fetch_add(1, Ordering::Relaxed),
             render_revision: 0,
         };
-        road_map.rebuild_adjacency();
-        road_map.rebuild_spatial_index();
+        road_map.rebuild_indices_parallel();
         road_map
     }
 
@@ -124,8 +129,8 @@ impl RoadMap {
             .connections
             .keys()
             .filter_map(|&(s, e)| {
-                let start_pos = self.nodes.get(&s)?.position;
-                let end_pos = self.nodes.get(&e)?.position;
+                let start_pos = self.nodes.position(s)?;
+                let end_pos = self.nodes.position(e)?;
                 Some(((s, e), start_pos, end_pos))
             })
             .collect();
@@ -151,29 +156,38 @@ impl RoadMap {
         (self.render_instance_id, self.render_revision)
     }
 
-    /// Gibt read-only Zugriff auf alle Nodes.
-    pub fn nodes(&self) -> &HashMap<u64, MapNode> {
+    /// Gibt read-only Zugriff auf die Node-Ablage (SoA-Layout).
+    ///
+    /// Bewusste API-Aenderung gegenueber der frueheren `&HashMap<u64, MapNode>`:
+    /// Die Nodes liegen spaltenweise, eine Map existiert nicht mehr. `NodeStore`
+    /// bietet die gleichen Lesezugriffe (`len`, `contains`, `get`, `iter`, `ids`).
+    pub fn nodes(&self) -> &NodeStore {
         &self.nodes
     }
 
-    /// Gibt einen Node anhand seiner ID zurueck.
-    pub fn node(&self, node_id: u64) -> Option<&MapNode> {
-        self.nodes.get(&node_id)
+    /// Gibt eine Kopie des Nodes anhand seiner ID zurueck.
+    ///
+    /// Liefert bewusst `Option<MapNode>` statt `Option<&MapNode>`: Im SoA-Layout
+    /// gibt es keinen zusammenhaengenden `MapNode` im Speicher, auf den eine
+    /// Referenz zeigen koennte. `MapNode` ist `Copy` (24 Byte), fuer Lesezugriffe
+    /// ist die Kopie gleichwertig; Aenderungen laufen ueber die Mutations-API.
+    pub fn node(&self, node_id: u64) -> Option<MapNode> {
+        self.nodes.get(node_id)
     }
 
     /// Prueft, ob ein Node mit der gegebenen ID existiert.
     pub fn contains_node(&self, node_id: u64) -> bool {
-        self.nodes.contains_key(&node_id)
+        self.nodes.contains(node_id)
     }
 
     /// Gibt die Position eines Nodes zurueck.
     pub fn node_position(&self, node_id: u64) -> Option<Vec2> {
-        self.nodes.get(&node_id).map(|node| node.position)
+        self.nodes.position(node_id)
     }
 
     /// Iterator ueber alle Node-IDs.
     pub fn node_ids(&self) -> impl Iterator<Item = u64> + '_ {
-        self.nodes.keys().copied()
+        self.nodes.ids()
     }
 
     /// Gibt read-only Zugriff auf alle Map-Marker.
@@ -189,14 +203,14 @@ impl RoadMap {
     /// Fuegt einen Node hinzu
     pub fn add_node(&mut self, node: MapNode) {
         self.adjacency.entry(node.id).or_default();
-        self.nodes.insert(node.id, node);
+        self.nodes.insert(node);
         self.spatial_dirty = true;
         self.mark_render_dirty();
     }
 
     /// Entfernt einen Node inklusive aller betroffenen Verbindungen
     pub fn remove_node(&mut self, node_id: u64) -> Option<MapNode> {
-        let removed = self.nodes.remove(&node_id);
+        let removed = self.nodes.remove(node_id);
         if removed.is_some() {
             // Adjacency-Eintraege der Nachbarn bereinigen — muss vor connections.retain() geschehen
             let neighbors: Vec<u64> = self
@@ -210,6 +224,7 @@ impl RoadMap {
                 }
             }
             self.adjacency.remove(&node_id);
+            self.height_overrides.remove(&node_id);
 
             self.connections
                 .retain(|(s, e), _| *s != node_id && *e != node_id);
@@ -237,7 +252,7 @@ impl RoadMap {
 
         let removed: Vec<MapNode> = node_ids
             .iter()
-            .filter_map(|&id| self.nodes.remove(&id))
+            .filter_map(|&id| self.nodes.remove(id))
             .collect();
 
         if removed.is_empty() {
@@ -257,6 +272,8 @@ impl RoadMap {
             }
         }
 
+        self.height_overrides.retain(|id, _| !node_ids.contains(id));
+
         // Verbindungen einmalig bereinigen — O(|connections|) statt O(k × |connections|)
         self.connections
             .retain(|(s, e), _| !node_ids.contains(s) && !node_ids.contains(e));
@@ -269,15 +286,15 @@ impl RoadMap {
 
     /// Aktualisiert die Position eines Nodes und baut bei Bedarf Geometrie/Index neu auf
     pub fn update_node_position(&mut self, node_id: u64, new_position: Vec2) -> bool {
-        let Some(node) = self.nodes.get_mut(&node_id) else {
+        let Some(position) = self.nodes.position_mut(node_id) else {
             return false;
         };
 
-        if node.position == new_position {
+        if *position == new_position {
             return true;
         }
 
-        node.position = new_position;
+        *position = new_position;
         self.refresh_after_node_position_change();
         true
     }
@@ -292,8 +309,29 @@ impl RoadMap {
 
         let mut changed = false;
         for &node_id in node_ids {
-            if let Some(node) = self.nodes.get_mut(&node_id) {
-                node.position += delta_world;
+            if let Some(position) = self.nodes.position_mut(node_id) {
+                *position += delta_world;
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.refresh_after_node_position_change();
+        }
+
+        changed
+    }
+
+    /// Setzt die Positionen mehrerer Nodes in einem Schritt.
+    ///
+    /// Aktualisiert Connection-Geometrie und Render-Revision konsistent nur einmal.
+    pub fn set_node_positions(&mut self, updates: &[(u64, Vec2)]) -> bool {
+        let mut changed = false;
+        for &(node_id, new_position) in updates {
+            if let Some(position) = self.nodes.position_mut(node_id)
+                && *position != new_position
+            {
+                *position = new_position;
                 changed = true;
             }
         }
@@ -309,15 +347,32 @@ impl RoadMap {
     ///
     /// Aktualisiert Connection-Geometrie und Render-Revision konsistent nur einmal.
     pub fn rotate_nodes(&mut self, node_ids: &[u64], center: Vec2, angle_rad: f32) -> bool {
-        if node_ids.is_empty() || angle_rad == 0.0 {
+        self.transform_nodes(node_ids, center, angle_rad, 1.0)
+    }
+
+    /// Rotiert und skaliert mehrere Nodes in einem Schritt um einen gemeinsamen Pivot.
+    ///
+    /// Erst wird um `scale` (gleichmaessig, > 0) skaliert, dann um `angle_rad` rotiert.
+    /// Aktualisiert Connection-Geometrie und Render-Revision konsistent nur einmal.
+    pub fn transform_nodes(
+        &mut self,
+        node_ids: &[u64],
+        pivot: Vec2,
+        angle_rad: f32,
+        scale: f32,
+    ) -> bool {
+        if node_ids.is_empty() || !scale.is_finite() || scale <= 0.0 {
+            return false;
+        }
+        if angle_rad == 0.0 && scale == 1.0 {
             return false;
         }
 
-        let rotation = Mat2::from_angle(angle_rad);
+        let transform = Mat2::from_angle(angle_rad) * scale;
         let mut changed = false;
         for &node_id in node_ids {
-            if let Some(node) = self.nodes.get_mut(&node_id) {
-                node.position = center + rotation * (node.position - center);
+            if let Some(position) = self.nodes.position_mut(node_id) {
+                *position = pivot + transform * (*position - pivot);
                 changed = true;
             }
         }
@@ -331,11 +386,11 @@ impl RoadMap {
 
     /// Setzt das Flag eines Nodes — O(1)
     pub fn set_node_flag(&mut self, node_id: u64, flag: super::NodeFlag) -> bool {
-        if let Some(node) = self.nodes.get_mut(&node_id) {
-            if node.flag == flag {
+        if let Some(node_flag) = self.nodes.flag_mut(node_id) {
+            if *node_flag == flag {
                 return true;
             }
-            node.flag = flag;
+            *node_flag = flag;
             self.mark_render_dirty();
             true
         } else {
@@ -343,6 +398,27 @@ impl RoadMap {
         }
     }
 
+    /// Gibt den manuell gesetzten Y-Wert eines Nodes zurueck (falls vorhanden).
+    pub fn node_height_override(&self, node_id: u64) -> Option<f32> {
+        self.height_overrides.get(&node_id).copied()
+    }
+
+    /// Setzt den Y-Wert eines Nodes manuell — O(1).
+    ///
+    /// Gibt `false` zurueck, wenn der Node nicht existiert.
+    pub fn set_node_height_override(&mut self, node_id: u64, height: f32) -> bool {
+        if !self.nodes.contains(node_id) {
+            return false;
+        }
+        self.height_overrides.insert(node_id, height);
+        true
+    }
+
+    /// Entfernt den manuell gesetzten Y-Wert eines Nodes.
+    pub fn clear_node_height_override(&mut self, node_id: u64) -> bool {
+        self.height_overrides.remove(&node_id).is_some()
+    }
+
     /// Fuegt eine Verbindung hinzu
     pub fn add_connection(&mut self, connection: Connection) {
         let s = connection.start_id;
@@ -463,8 +539,8 @@ impl RoadMap {
         if let Some(mut conn) = self.connections.remove(&(start_id, end_id)) {
             conn.start_id = end_id;
             conn.end_id = start_id;
-            let new_start = self.nodes.get(&end_id).map(|n| n.position);
-            let new_end = self.nodes.get(&start_id).map(|n| n.position);
+            let new_start = self.nodes.position(end_id);
+            let new_end = self.nodes.position(start_id);
             if let (Some(s), Some(e)) = (new_start, new_end) {
                 conn.update_geometry(s, e);
             }
@@ -502,7 +578,7 @@ impl RoadMap {
 
     /// Berechnet die naechste freie Node-ID
     pub fn next_node_id(&self) -> u64 {
-        self.nodes.keys().max().copied().unwrap_or(0) + 1
+        self.nodes.max_id().unwrap_or(0) + 1
     }
 
     /// Fuegt einen Map-Marker hinzu
@@ -615,12 +691,12 @@ impl RoadMap {
 
         let mut changed = false;
         for &nid in node_ids {
-            let Some(node) = self.nodes.get(&nid) else {
+            let Some(flag) = self.nodes.flag(nid) else {
                 continue;
             };
 
             // Warning/Reserved/RoundedCorner nicht anfassen
-            match node.flag {
+            match flag {
                 NodeFlag::Warning | NodeFlag::Reserved | NodeFlag::RoundedCorner => continue,
                 _ => {}
             }
@@ -634,10 +710,10 @@ impl RoadMap {
                 NodeFlag::SubPrio
             };
 
-            if let Some(node) = self.nodes.get_mut(&nid)
-                && node.flag != new_flag
+            if let Some(node_flag) = self.nodes.flag_mut(nid)
+                && *node_flag != new_flag
             {
-                node.flag = new_flag;
+                *node_flag = new_flag;
                 changed = true;
             }
         }
@@ -651,20 +727,22 @@ impl RoadMap {
     ///
     /// Internale Methode — aufrufen nach Bulk-Ladungen oder Dedup-Operationen.
     fn rebuild_adjacency(&mut self) {
-        self.adjacency.clear();
-        for &node_id in self.nodes.keys() {
-            self.adjacency.entry(node_id).or_default();
-        }
-        for conn in self.connections.values() {
-            self.adjacency
-                .entry(conn.start_id)
-                .or_default()
-                .push((conn.end_id, true));
-            self.adjacency
-                .entry(conn.end_id)
-                .or_default()
-                .push((conn.start_id, false));
-        }
+        self.adjacency = build_adjacency(&self.nodes, &self.connections);
+    }
+
+    /// Baut Adjacency- und Spatial-Index nach Bulk-Operationen gleichzeitig neu auf.
+    ///
+    /// Beide Indizes lesen nur Nodes/Connections und werden via `rayon::join`
+    /// parallel erzeugt; der Spatial-Index parallelisiert grosse Maps zusaetzlich
+    /// intern (siehe [`SpatialIndex::from_nodes`]).
+    fn rebuild_indices_parallel(&mut self) {
+        let (adjacency, spatial_index) = rayon::join(
+            || build_adjacency(&self.nodes, &self.connections),
+            || SpatialIndex::from_nodes(&self.nodes),
+        );
+        self.adjacency = adjacency;
+        self.spatial_index = spatial_index;
+        self.spatial_dirty = false;
     }
 
     /// Baut den Adjacency-Index neu auf — oeffentlich fuer den XML-Parser und Bulk-Operationen.
@@ -735,6 +813,28 @@ impl RoadMap {
     }
 }
 
+/// Erzeugt den Adjacency-Index aus Nodes und Connections.
+///
+/// Jeder Node erhaelt einen Eintrag, auch wenn er isoliert ist.
+fn build_adjacency(
+    nodes: &NodeStore,
+    connections: &HashMap<(u64, u64), Connection>,
+) -> HashMap<u64, Vec<(u64, bool)>> {
+    let mut adjacency: HashMap<u64, Vec<(u64, bool)>> =
+        nodes.ids().map(|node_id| (node_id, Vec::new())).collect();
+    for conn in connections.values() {
+        adjacency
+            .entry(conn.start_id)
+            .or_default()
+            .push((conn.end_id, true));
+        adjacency
+            .entry(conn.end_id)
+            .or_default()
+            .push((conn.start_id, false));
+    }
+    adjacency
+}
+
 impl Default for RoadMap {
     fn default() -> Self {
         Self::new(3) // FS25 als Default
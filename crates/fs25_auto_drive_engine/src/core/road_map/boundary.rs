@@ -59,11 +59,11 @@ mod tests {
                 position: Vec2::new(id as f32, 0.0),
                 flag: NodeFlag::Regular,
             };
-            map.nodes.insert(id, node);
+            map.nodes.insert(node);
         }
         for &(start, end) in connections {
-            let start_pos = map.nodes[&start].position;
-            let end_pos = map.nodes[&end].position;
+            let start_pos = map.nodes.position(start).expect("Start-Node existiert");
+            let end_pos = map.nodes.position(end).expect("End-Node existiert");
             let conn = Connection {
                 start_id: start,
                 end_id: end,
@@ -126,6 +126,35 @@ impl RoadMap {
 
         visited.len() == node_ids.len()
     }
+
+    /// Zerlegt die Nodes in genau zwei voneinander getrennte lineare Ketten.
+    ///
+    /// Jede Kette muss aus mindestens 2 Nodes bestehen und wird in
+    /// Verbindungsrichtung geordnet (siehe `ordered_chain_nodes`). Gibt `None`
+    /// zurueck, wenn die Nodes nicht genau zwei solche Ketten bilden.
+    pub fn two_disjoint_chains(&self, node_ids: &IndexSet<u64>) -> Option<[Vec<u64>; 2]> {
+        let mut remaining = node_ids.clone();
+        let mut chains = Vec::with_capacity(2);
+        while let Some(start) = remaining.pop() {
+            if chains.len() == 2 {
+                return None;
+            }
+            let mut component = IndexSet::new();
+            let mut stack = vec![start];
+            while let Some(current) = stack.pop() {
+                if !component.insert(current) {
+                    continue;
+                }
+                for &(nb, _) in self.neighbors(current) {
+                    if remaining.shift_remove(&nb) {
+                        stack.push(nb);
+                    }
+                }
+            }
+            chains.push(self.ordered_chain_nodes(&component)?);
+        }
+        chains.try_into().ok()
+    }
 }
 
 #[cfg(test)]
@@ -199,6 +228,35 @@ mod tests {
         assert!(!map.is_connected_subgraph(&ids));
     }
 
+    #[test]
+    fn two_disjoint_chains_splits_selection() {
+        let map = make_map_with_star();
+        let ids: IndexSet<u64> = [2, 1, 3].into_iter().collect();
+        assert_eq!(map.two_disjoint_chains(&ids), None);
+
+        // Zusaetzliche Kette 4 → 5 → 6 (4 haengt am Stern, 5/6 nicht)
+        let mut map = make_map_with_star();
+        for (start, end) in [(4_u64, 5_u64), (5, 6)] {
+            let end_pos = glam::Vec2::new(end as f32 * 10.0, 0.0);
+            map.add_node(MapNode::new(end, end_pos, NodeFlag::Regular));
+            map.add_connection(Connection::new(
+                start,
+                end,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                glam::Vec2::new(start as f32 * 10.0, 0.0),
+                end_pos,
+            ));
+        }
+        let ids: IndexSet<u64> = [6, 1, 2, 5].into_iter().collect();
+        let chains = map.two_disjoint_chains(&ids).expect("zwei Ketten erwartet");
+        assert!(chains.contains(&vec![1, 2]));
+        assert!(chains.contains(&vec![5, 6]));
+
+        let ids: IndexSet<u64> = [1, 2, 6].into_iter().collect();
+        assert_eq!(map.two_disjoint_chains(&ids), None);
+    }
+
     #[test]
     fn connected_subgraph_kette() {
         // Lineare Kette: 1 → 2 → 3 → 4
@@ -11,9 +11,9 @@ impl RoadMap {
     pub fn count_duplicates(&self, epsilon: f32) -> (u32, u32) {
         let inv_epsilon = if epsilon > 0.0 { 1.0 / epsilon } else { 1000.0 };
         let mut grid: HashMap<(i64, i64), u32> = HashMap::new();
-        for node in self.nodes.values() {
-            let gx = (node.position.x * inv_epsilon).round() as i64;
-            let gz = (node.position.y * inv_epsilon).round() as i64;
+        for (_, position) in self.nodes.positions() {
+            let gx = (position.x * inv_epsilon).round() as i64;
+            let gz = (position.y * inv_epsilon).round() as i64;
             *grid.entry((gx, gz)).or_default() += 1;
         }
         let mut dup_nodes = 0u32;
@@ -32,9 +32,9 @@ impl RoadMap {
         let inv_epsilon = if epsilon > 0.0 { 1.0 / epsilon } else { 1000.0 };
 
         let mut grid: HashMap<(i64, i64), Vec<u64>> = HashMap::new();
-        for (&id, node) in &self.nodes {
-            let gx = (node.position.x * inv_epsilon).round() as i64;
-            let gz = (node.position.y * inv_epsilon).round() as i64;
+        for (id, position) in self.nodes.positions() {
+            let gx = (position.x * inv_epsilon).round() as i64;
+            let gz = (position.y * inv_epsilon).round() as i64;
             grid.entry((gx, gz)).or_default().push(id);
         }
 
@@ -85,8 +85,8 @@ impl RoadMap {
             if was_remapped {
                 remapped_connections += 1;
                 if let (Some(s), Some(e)) = (
-                    self.nodes.get(&conn.start_id).map(|n| n.position),
-                    self.nodes.get(&conn.end_id).map(|n| n.position),
+                    self.nodes.position(conn.start_id),
+                    self.nodes.position(conn.end_id),
                 ) {
                     conn.update_geometry(s, e);
                 }
@@ -103,7 +103,8 @@ impl RoadMap {
         }
 
         for &dup_id in &duplicate_ids {
-            self.nodes.remove(&dup_id);
+            self.nodes.remove(dup_id);
+            self.height_overrides.remove(&dup_id);
         }
 
         let mut remapped_markers = 0u32;
@@ -116,8 +117,7 @@ impl RoadMap {
         let mut seen_marker_ids = std::collections::HashSet::new();
         self.map_markers.retain(|m| seen_marker_ids.insert(m.id));
 
-        self.rebuild_spatial_index();
-        self.rebuild_adjacency_index();
+        self.rebuild_indices_parallel();
         self.mark_render_dirty();
 
         DeduplicationResult {
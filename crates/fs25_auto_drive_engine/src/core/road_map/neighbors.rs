@@ -29,6 +29,15 @@ impl RoadMap {
             })
             .collect()
     }
+
+    /// Anzahl unterschiedlicher Nachbar-Nodes (Dual- und Gegenverbindungen zaehlen einfach).
+    pub fn node_degree(&self, node_id: u64) -> usize {
+        let mut neighbor_ids: Vec<u64> =
+            self.neighbors(node_id).iter().map(|&(nb, _)| nb).collect();
+        neighbor_ids.sort_unstable();
+        neighbor_ids.dedup();
+        neighbor_ids.len()
+    }
 }
 
 #[cfg(test)]
@@ -88,4 +97,19 @@ mod tests {
         // 1→2 (outgoing) + 2→1 (end_id==1, incoming) → 2 Einträge
         assert_eq!(count_2, 2);
     }
+
+    #[test]
+    fn test_node_degree_counts_distinct_neighbors() {
+        // 1↔2 (zwei Verbindungen) + 1→3 → Grad 2
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+        map.add_node(MapNode::new(3, Vec2::new(0.0, 10.0), NodeFlag::Regular));
+        map.add_connection(make_conn(1, 2, 0.0, 0.0, 10.0, 0.0));
+        map.add_connection(make_conn(2, 1, 10.0, 0.0, 0.0, 0.0));
+        map.add_connection(make_conn(1, 3, 0.0, 0.0, 0.0, 10.0));
+
+        assert_eq!(map.node_degree(1), 2);
+        assert_eq!(map.node_degree(3), 1);
+    }
 }
@@ -0,0 +1,370 @@
+//! Slab-basierte Node-Ablage im Structure-of-Arrays-Layout.
+//!
+//! Positionen, Flags und IDs liegen in getrennten, dicht gepackten Vektoren.
+//! Jeder Node belegt einen Slot mit stabilem Index; freigewordene Slots werden
+//! ueber eine Free-List wiederverwendet und in einem Bitset als frei markiert.
+//!
+//! Die `id → slot`-Zuordnung nutzt fuer die in AutoDrive-Dateien ueblichen
+//! fortlaufenden IDs eine dichte `u32`-Tabelle (4 Byte je ID); nur weit
+//! verstreute IDs landen in einer HashMap. Pro Node fallen damit rund 21 Byte
+//! an — gegenueber ~33 Byte je Eintrag der frueheren `HashMap<u64, MapNode>`.
+
+use crate::core::{MapNode, NodeFlag};
+use glam::Vec2;
+use std::collections::HashMap;
+
+/// Markiert einen unbelegten Eintrag der dichten ID-Tabelle.
+const NO_SLOT: u32 = u32::MAX;
+/// Feste Reserve, bis zu der die dichte ID-Tabelle auch bei wenigen Nodes waechst.
+const DENSE_ID_HEADROOM: usize = 1024;
+
+/// SoA-Ablage aller Nodes einer [`RoadMap`](super::RoadMap).
+#[derive(Debug, Clone, Default)]
+pub struct NodeStore {
+    /// Slot → Node-ID (nur gueltig, wenn der Slot in `occupied` gesetzt ist)
+    ids: Vec<u64>,
+    /// Slot → Weltposition
+    positions: Vec<Vec2>,
+    /// Slot → Flag
+    flags: Vec<NodeFlag>,
+    /// Belegte Slots als Bitset (ein Bit pro Slot)
+    occupied: Vec<u64>,
+    /// Node-ID → Slot fuer IDs `< slot_by_dense_id.len()` (`NO_SLOT` = unbelegt)
+    slot_by_dense_id: Vec<u32>,
+    /// Node-ID → Slot fuer IDs ausserhalb der dichten Tabelle
+    slot_by_sparse_id: HashMap<u64, u32>,
+    /// Wiederverwendbare Slots aus entfernten Nodes
+    free_slots: Vec<u32>,
+    /// Anzahl belegter Slots
+    len: usize,
+}
+
+impl NodeStore {
+    /// Erstellt eine leere Ablage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Erstellt eine leere Ablage mit reservierter Kapazitaet.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: Vec::with_capacity(capacity),
+            positions: Vec::with_capacity(capacity),
+            flags: Vec::with_capacity(capacity),
+            occupied: Vec::with_capacity(capacity.div_ceil(64)),
+            ..Self::default()
+        }
+    }
+
+    /// Anzahl gespeicherter Nodes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Gibt `true` zurueck, wenn keine Nodes gespeichert sind.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Prueft, ob ein Node mit der ID existiert.
+    pub fn contains(&self, node_id: u64) -> bool {
+        self.slot_of(node_id).is_some()
+    }
+
+    /// Liefert den stabilen Slot-Index eines Nodes.
+    ///
+    /// Der Index bleibt gueltig, bis der Node entfernt wird.
+    pub fn slot_of(&self, node_id: u64) -> Option<usize> {
+        match self.dense_index(node_id) {
+            Some(index) => {
+                let slot = self.slot_by_dense_id[index];
+                (slot != NO_SLOT).then_some(slot as usize)
+            }
+            None => self
+                .slot_by_sparse_id
+                .get(&node_id)
+                .map(|&slot| slot as usize),
+        }
+    }
+
+    /// Liefert eine Kopie des Nodes mit der ID.
+    pub fn get(&self, node_id: u64) -> Option<MapNode> {
+        let slot = self.slot_of(node_id)?;
+        Some(MapNode::new(
+            node_id,
+            self.positions[slot],
+            self.flags[slot],
+        ))
+    }
+
+    /// Liefert die Position eines Nodes.
+    pub fn position(&self, node_id: u64) -> Option<Vec2> {
+        self.slot_of(node_id).map(|slot| self.positions[slot])
+    }
+
+    /// Liefert das Flag eines Nodes.
+    pub fn flag(&self, node_id: u64) -> Option<NodeFlag> {
+        self.slot_of(node_id).map(|slot| self.flags[slot])
+    }
+
+    /// Mutabler Zugriff auf die Position eines Nodes.
+    pub(crate) fn position_mut(&mut self, node_id: u64) -> Option<&mut Vec2> {
+        let slot = self.slot_of(node_id)?;
+        Some(&mut self.positions[slot])
+    }
+
+    /// Mutabler Zugriff auf das Flag eines Nodes.
+    pub(crate) fn flag_mut(&mut self, node_id: u64) -> Option<&mut NodeFlag> {
+        let slot = self.slot_of(node_id)?;
+        Some(&mut self.flags[slot])
+    }
+
+    /// Fuegt einen Node ein oder ersetzt einen bestehenden mit gleicher ID.
+    ///
+    /// Gibt den vorherigen Node zurueck, falls die ID schon belegt war.
+    pub fn insert(&mut self, node: MapNode) -> Option<MapNode> {
+        if let Some(slot) = self.slot_of(node.id) {
+            let previous = MapNode::new(node.id, self.positions[slot], self.flags[slot]);
+            self.positions[slot] = node.position;
+            self.flags[slot] = node.flag;
+            return Some(previous);
+        }
+
+        let slot = match self.free_slots.pop() {
+            Some(slot) => {
+                let index = slot as usize;
+                self.ids[index] = node.id;
+                self.positions[index] = node.position;
+                self.flags[index] = node.flag;
+                slot
+            }
+            None => {
+                self.ids.push(node.id);
+                self.positions.push(node.position);
+                self.flags.push(node.flag);
+                if self.occupied.len() * 64 < self.ids.len() {
+                    self.occupied.push(0);
+                }
+                (self.ids.len() - 1) as u32
+            }
+        };
+        self.set_occupied(slot as usize, true);
+        self.map_id_to_slot(node.id, slot);
+        self.len += 1;
+        None
+    }
+
+    /// Entfernt einen Node und gibt seinen Slot zur Wiederverwendung frei.
+    pub fn remove(&mut self, node_id: u64) -> Option<MapNode> {
+        let index = match self.dense_index(node_id) {
+            Some(dense) => {
+                let slot = std::mem::replace(&mut self.slot_by_dense_id[dense], NO_SLOT);
+                (slot != NO_SLOT).then_some(slot)?
+            }
+            None => self.slot_by_sparse_id.remove(&node_id)?,
+        } as usize;
+        self.set_occupied(index, false);
+        self.free_slots.push(index as u32);
+        self.len -= 1;
+        Some(MapNode::new(
+            node_id,
+            self.positions[index],
+            self.flags[index],
+        ))
+    }
+
+    /// Iterator ueber alle Node-IDs.
+    pub fn ids(&self) -> impl Iterator<Item = u64> + '_ {
+        self.occupied_slots().map(|(_, id)| id)
+    }
+
+    /// Iterator ueber alle Nodes (als Kopie).
+    pub fn iter(&self) -> impl Iterator<Item = MapNode> + '_ {
+        self.occupied_slots()
+            .map(|(slot, id)| MapNode::new(id, self.positions[slot], self.flags[slot]))
+    }
+
+    /// Iterator ueber `(ID, Position)`-Paare aller Nodes.
+    ///
+    /// Liest nur die ID- und Positions-Arrays — der cache-freundliche Pfad fuer
+    /// Spatial-Index, Renderer und Bounding-Box-Berechnungen.
+    pub fn positions(&self) -> impl Iterator<Item = (u64, Vec2)> + '_ {
+        self.occupied_slots()
+            .map(|(slot, id)| (id, self.positions[slot]))
+    }
+
+    /// Hoechste vergebene Node-ID.
+    pub fn max_id(&self) -> Option<u64> {
+        self.ids().max()
+    }
+
+    fn occupied_slots(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.ids
+            .iter()
+            .enumerate()
+            .filter(|(slot, _)| self.occupied[slot / 64] & (1 << (slot % 64)) != 0)
+            .map(|(slot, &id)| (slot, id))
+    }
+
+    fn set_occupied(&mut self, slot: usize, occupied: bool) {
+        let bit = 1u64 << (slot % 64);
+        if occupied {
+            self.occupied[slot / 64] |= bit;
+        } else {
+            self.occupied[slot / 64] &= !bit;
+        }
+    }
+
+    /// Index in die dichte ID-Tabelle, falls die ID dort abgelegt wird.
+    fn dense_index(&self, node_id: u64) -> Option<usize> {
+        usize::try_from(node_id)
+            .ok()
+            .filter(|&index| index < self.slot_by_dense_id.len())
+    }
+
+    /// Traegt `id → slot` ein und vergroessert die dichte Tabelle bei Bedarf.
+    ///
+    /// Die Tabelle waechst nur bis etwa zur doppelten Node-Anzahl (plus Reserve),
+    /// damit einzelne sehr grosse IDs keinen Speicher fuer die Luecke belegen.
+    fn map_id_to_slot(&mut self, node_id: u64, slot: u32) {
+        if let Some(index) = self.dense_index(node_id) {
+            self.slot_by_dense_id[index] = slot;
+            return;
+        }
+
+        let dense_limit = 2 * (self.len + 1) + DENSE_ID_HEADROOM;
+        match usize::try_from(node_id).ok().filter(|&id| id < dense_limit) {
+            Some(index) => {
+                self.slot_by_dense_id.resize(index + 1, NO_SLOT);
+                self.slot_by_dense_id[index] = slot;
+                // Verstreute IDs, die jetzt in die Tabelle fallen, umziehen.
+                let dense_len = self.slot_by_dense_id.len() as u64;
+                let moved: Vec<(u64, u32)> = self
+                    .slot_by_sparse_id
+                    .iter()
+                    .filter(|&(&id, _)| id < dense_len)
+                    .map(|(&id, &slot)| (id, slot))
+                    .collect();
+                for (id, slot) in moved {
+                    self.slot_by_sparse_id.remove(&id);
+                    self.slot_by_dense_id[id as usize] = slot;
+                }
+            }
+            None => {
+                self.slot_by_sparse_id.insert(node_id, slot);
+            }
+        }
+    }
+}
+
+impl FromIterator<MapNode> for NodeStore {
+    fn from_iter<I: IntoIterator<Item = MapNode>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut store = Self::with_capacity(iter.size_hint().0);
+        for node in iter {
+            store.insert(node);
+        }
+        store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: u64, x: f32) -> MapNode {
+        MapNode::new(id, Vec2::new(x, 0.0), NodeFlag::Regular)
+    }
+
+    #[test]
+    fn insert_get_and_replace() {
+        let mut store = NodeStore::new();
+        assert!(store.insert(node(7, 1.0)).is_none());
+        assert_eq!(store.position(7), Some(Vec2::new(1.0, 0.0)));
+
+        let previous = store.insert(node(7, 2.0)).expect("Node 7 existiert");
+        assert_eq!(previous.position, Vec2::new(1.0, 0.0));
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.position(7), Some(Vec2::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn remove_frees_slot_for_reuse_and_keeps_other_slots_stable() {
+        let mut store: NodeStore = [node(1, 1.0), node(2, 2.0), node(3, 3.0)]
+            .into_iter()
+            .collect();
+        let slot_of_3 = store.slot_of(3);
+
+        let removed = store.remove(2).expect("Node 2 existiert");
+        assert_eq!(removed.position, Vec2::new(2.0, 0.0));
+        assert!(!store.contains(2));
+        assert_eq!(store.slot_of(3), slot_of_3);
+
+        store.insert(node(9, 9.0));
+        assert_eq!(store.slot_of(9), Some(1));
+        assert_eq!(store.len(), 3);
+
+        let mut ids: Vec<u64> = store.ids().collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 3, 9]);
+    }
+
+    #[test]
+    fn mutable_accessors_update_arrays() {
+        let mut store: NodeStore = std::iter::once(node(4, 0.0)).collect();
+
+        *store.position_mut(4).expect("Node 4 existiert") = Vec2::new(5.0, 6.0);
+        *store.flag_mut(4).expect("Node 4 existiert") = NodeFlag::Warning;
+
+        let node = store.get(4).expect("Node 4 existiert");
+        assert_eq!(node.position, Vec2::new(5.0, 6.0));
+        assert_eq!(node.flag, NodeFlag::Warning);
+        assert!(store.position_mut(99).is_none());
+    }
+
+    #[test]
+    fn sparse_and_dense_ids_resolve_and_migrate() {
+        let mut store = NodeStore::new();
+        store.insert(node(5_000, 1.0));
+        store.insert(node(u64::MAX, 2.0));
+        store.insert(node(3, 3.0));
+        assert_eq!(store.position(5_000), Some(Vec2::new(1.0, 0.0)));
+        assert_eq!(store.position(u64::MAX), Some(Vec2::new(2.0, 0.0)));
+
+        assert_eq!(store.slot_by_sparse_id.len(), 2);
+
+        // Genug Nodes, damit die dichte Tabelle bis hinter ID 5000 waechst
+        for id in 10..3_000 {
+            store.insert(node(id, 0.0));
+        }
+        store.insert(node(5_001, 4.0));
+        assert_eq!(store.slot_by_sparse_id.len(), 1);
+        assert_eq!(store.position(5_000), Some(Vec2::new(1.0, 0.0)));
+        assert_eq!(store.len(), 2_994);
+
+        assert!(store.remove(u64::MAX).is_some());
+        assert!(store.remove(5_000).is_some());
+        assert!(!store.contains(5_000));
+        assert_eq!(store.ids().count(), 2_992);
+    }
+
+    #[test]
+    fn memory_per_node_is_below_hashmap_layout() {
+        const COUNT: usize = 100_000;
+        let store: NodeStore = (1..=COUNT as u64).map(|id| node(id, id as f32)).collect();
+
+        let soa_bytes = store.ids.capacity() * std::mem::size_of::<u64>()
+            + store.positions.capacity() * std::mem::size_of::<Vec2>()
+            + store.flags.capacity() * std::mem::size_of::<NodeFlag>()
+            + store.occupied.capacity() * std::mem::size_of::<u64>()
+            + store.slot_by_dense_id.capacity() * std::mem::size_of::<u32>()
+            + store.slot_by_sparse_id.capacity() * std::mem::size_of::<(u64, u32)>();
+        // Untergrenze der frueheren HashMap<u64, MapNode>: Eintrag + 1 Kontrollbyte
+        let hashmap_bytes = COUNT * (std::mem::size_of::<(u64, MapNode)>() + 1);
+
+        assert!(
+            soa_bytes < hashmap_bytes,
+            "SoA {soa_bytes} B >= HashMap {hashmap_bytes} B"
+        );
+    }
+}
@@ -22,6 +22,110 @@ impl RoadMap {
         )
     }
 
+    /// Summierte Laenge aller Connections, deren Start- und End-Id in `ids` liegen.
+    ///
+    /// Laeuft nur ueber die Adjazenz der Menge (O(|ids|)) und ist damit auch fuer
+    /// die Live-Anzeige in der Statusleiste geeignet.
+    pub fn connection_length_between_ids(&self, ids: &IndexSet<u64>) -> f32 {
+        ids.iter()
+            .filter_map(|&id| Some((id, self.node_position(id)?)))
+            .flat_map(|(id, pos)| {
+                self.outgoing_neighbors(id)
+                    .filter(|nb| ids.contains(nb))
+                    .filter_map(move |nb| Some(pos.distance(self.node_position(nb)?)))
+            })
+            .sum()
+    }
+
+    /// Achsenparallele Bounding Box `(min, max)` der Nodes aus `ids`.
+    ///
+    /// Unbekannte IDs werden ignoriert; `None`, wenn keiner der Nodes existiert.
+    pub fn bounds_of_ids(&self, ids: &IndexSet<u64>) -> Option<(Vec2, Vec2)> {
+        ids.iter()
+            .filter_map(|&id| self.node_position(id))
+            .fold(None, |bounds, pos| match bounds {
+                None => Some((pos, pos)),
+                Some((min, max)) => Some((min.min(pos), max.max(pos))),
+            })
+    }
+
+    /// Findet die Verbindung mit dem kleinsten Abstand zur Weltposition (innerhalb `max_distance`).
+    ///
+    /// Abstand wird zum geraden Segment Start→End gemessen. O(n) ueber alle
+    /// Connections — nur fuer einzelne Hit-Tests (Klick/Drag-Start) gedacht.
+    pub fn nearest_connection(&self, query: Vec2, max_distance: f32) -> Option<&Connection> {
+        let mut best_distance = max_distance;
+        let mut best = None;
+        for connection in self.connections.values() {
+            let (Some(start), Some(end)) = (
+                self.nodes.position(connection.start_id),
+                self.nodes.position(connection.end_id),
+            ) else {
+                continue;
+            };
+            let distance = point_to_segment_distance(query, start, end);
+            if distance < best_distance {
+                best_distance = distance;
+                best = Some(connection);
+            }
+        }
+        best
+    }
+
+    /// Findet die naechste Verbindung, die `node_id` nicht beruehrt, samt Lotfusspunkt.
+    ///
+    /// Liefert die Verbindung mit dem kleinsten Segmentabstand innerhalb
+    /// `max_distance` und den naechstgelegenen Punkt auf ihr. Verbindungen mit
+    /// `node_id` als Start oder Ende werden uebersprungen. O(n) — fuer einzelne
+    /// Drop-Tests gedacht.
+    pub fn nearest_connection_not_touching(
+        &self,
+        query: Vec2,
+        max_distance: f32,
+        node_id: u64,
+    ) -> Option<(&Connection, Vec2)> {
+        let mut best_distance = max_distance;
+        let mut best = None;
+        for connection in self.connections.values() {
+            if connection.start_id == node_id || connection.end_id == node_id {
+                continue;
+            }
+            let (Some(start), Some(end)) = (
+                self.nodes.position(connection.start_id),
+                self.nodes.position(connection.end_id),
+            ) else {
+                continue;
+            };
+            let point = closest_point_on_segment(query, start, end);
+            let distance = query.distance(point);
+            if distance < best_distance {
+                best_distance = distance;
+                best = Some((connection, point));
+            }
+        }
+        best
+    }
+
+    /// Findet alle Verbindungen, die das Segment `a`→`b` kreuzen, samt Schnittpunkt.
+    ///
+    /// Beruehrungen genau an einem Verbindungs-Endpunkt (Node) zaehlen nicht.
+    /// O(n) ueber alle Connections — fuer einzelne Schnitt-Gesten gedacht.
+    pub fn connections_crossing_segment(&self, a: Vec2, b: Vec2) -> Vec<(&Connection, Vec2)> {
+        let mut hits = Vec::new();
+        for connection in self.connections.values() {
+            let (Some(start), Some(end)) = (
+                self.nodes.position(connection.start_id),
+                self.nodes.position(connection.end_id),
+            ) else {
+                continue;
+            };
+            if let Some(point) = segment_intersection(a, b, start, end) {
+                hits.push((connection, point));
+            }
+        }
+        hits
+    }
+
     /// Findet den naechstgelegenen Node zur Weltposition.
     pub fn nearest_node(&self, query: Vec2) -> Option<SpatialMatch> {
         debug_assert!(
@@ -58,3 +162,33 @@ impl RoadMap {
         self.spatial_index.within_rect_into(min, max, out)
     }
 }
+
+/// Schnittpunkt der Segmente `a`→`b` und `c`→`d`, sofern er echt im Inneren von `c`→`d` liegt.
+fn segment_intersection(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> Option<Vec2> {
+    const ENDPOINT_EPSILON: f32 = 1.0e-4;
+    let r = b - a;
+    let s = d - c;
+    let denom = r.perp_dot(s);
+    if denom.abs() < f32::EPSILON {
+        return None;
+    }
+    let t = (c - a).perp_dot(s) / denom;
+    let u = (c - a).perp_dot(r) / denom;
+    let on_cut = (0.0..=1.0).contains(&t);
+    let inside_connection = u > ENDPOINT_EPSILON && u < 1.0 - ENDPOINT_EPSILON;
+    (on_cut && inside_connection).then(|| c + u * s)
+}
+
+fn closest_point_on_segment(point: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let len_sq = ab.dot(ab);
+    if len_sq < f32::EPSILON {
+        return a;
+    }
+    let t = ((point - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    a + t * ab
+}
+
+fn point_to_segment_distance(point: Vec2, a: Vec2, b: Vec2) -> f32 {
+    point.distance(closest_point_on_segment(point, a, b))
+}
@@ -60,7 +60,7 @@ fn test_rebuild_connection_geometry_updates_after_node_move() {
     );
     map.add_connection(connection);
 
-    map.nodes.get_mut(&2).unwrap().position = Vec2::new(3.0, 4.0);
+    *map.nodes.position_mut(2).unwrap() = Vec2::new(3.0, 4.0);
     map.rebuild_connection_geometry();
 
     let connection = map.connections_iter().next().expect("Verbindung erwartet");
@@ -150,6 +150,24 @@ fn test_rotate_nodes_invalidates_render_cache_once() {
     assert_eq!(after.1, before.1 + 1);
 }
 
+#[test]
+fn test_transform_nodes_scales_and_rotates_around_pivot() {
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(1, Vec2::new(12.0, 10.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(2, Vec2::new(10.0, 10.0), NodeFlag::Regular));
+
+    let pivot = Vec2::new(10.0, 10.0);
+    assert!(map.transform_nodes(&[1, 2], pivot, std::f32::consts::FRAC_PI_2, 2.0));
+
+    let p1 = map.node(1).expect("Node 1 vorhanden").position;
+    assert!((p1 - Vec2::new(10.0, 14.0)).length() < 1e-4, "p1={p1:?}");
+    assert_eq!(map.node(2).expect("Node 2 vorhanden").position, pivot);
+
+    // Nicht-positive Skalierung und Identitaet sind No-ops
+    assert!(!map.transform_nodes(&[1], pivot, 0.3, 0.0));
+    assert!(!map.transform_nodes(&[1], pivot, 0.0, 1.0));
+}
+
 #[test]
 fn test_update_marker_invalidates_render_cache() {
     let mut map = RoadMap::new(3);
@@ -190,8 +208,8 @@ fn test_recalculate_node_flags_subprio_only() {
     map.add_connection(conn);
     map.recalculate_node_flags(&[1, 2]);
 
-    assert_eq!(map.nodes[&1].flag, NodeFlag::SubPrio);
-    assert_eq!(map.nodes[&2].flag, NodeFlag::SubPrio);
+    assert_eq!(map.nodes.flag(1).unwrap(), NodeFlag::SubPrio);
+    assert_eq!(map.nodes.flag(2).unwrap(), NodeFlag::SubPrio);
 }
 
 #[test]
@@ -221,9 +239,9 @@ fn test_recalculate_node_flags_mixed_priority() {
     map.add_connection(c2);
     map.recalculate_node_flags(&[1, 2, 3]);
 
-    assert_eq!(map.nodes[&1].flag, NodeFlag::Regular);
-    assert_eq!(map.nodes[&2].flag, NodeFlag::Regular);
-    assert_eq!(map.nodes[&3].flag, NodeFlag::SubPrio);
+    assert_eq!(map.nodes.flag(1).unwrap(), NodeFlag::Regular);
+    assert_eq!(map.nodes.flag(2).unwrap(), NodeFlag::Regular);
+    assert_eq!(map.nodes.flag(3).unwrap(), NodeFlag::SubPrio);
 }
 
 #[test]
@@ -243,8 +261,8 @@ fn test_recalculate_node_flags_preserves_warning() {
     map.add_connection(conn);
     map.recalculate_node_flags(&[1, 2]);
 
-    assert_eq!(map.nodes[&1].flag, NodeFlag::Warning);
-    assert_eq!(map.nodes[&2].flag, NodeFlag::SubPrio);
+    assert_eq!(map.nodes.flag(1).unwrap(), NodeFlag::Warning);
+    assert_eq!(map.nodes.flag(2).unwrap(), NodeFlag::SubPrio);
 }
 
 /// Stellt sicher, dass Reserved-Nodes beim Flag-Update unberührt bleiben.
@@ -265,8 +283,8 @@ fn test_recalculate_node_flags_preserves_reserved() {
     map.add_connection(conn);
     map.recalculate_node_flags(&[1, 2]);
 
-    assert_eq!(map.nodes[&1].flag, NodeFlag::Reserved);
-    assert_eq!(map.nodes[&2].flag, NodeFlag::SubPrio);
+    assert_eq!(map.nodes.flag(1).unwrap(), NodeFlag::Reserved);
+    assert_eq!(map.nodes.flag(2).unwrap(), NodeFlag::SubPrio);
 }
 
 #[test]
@@ -275,7 +293,7 @@ fn test_recalculate_node_flags_no_connections() {
     map.add_node(MapNode::new(1, Vec2::ZERO, NodeFlag::SubPrio));
 
     map.recalculate_node_flags(&[1]);
-    assert_eq!(map.nodes[&1].flag, NodeFlag::Regular);
+    assert_eq!(map.nodes.flag(1).unwrap(), NodeFlag::Regular);
 }
 
 #[test]
@@ -320,8 +338,8 @@ fn test_deduplicate_removes_exact_duplicates() {
     assert_eq!(result.removed_nodes, 1);
     assert_eq!(result.duplicate_groups, 1);
     assert_eq!(map.node_count(), 2);
-    assert!(map.nodes.contains_key(&1));
-    assert!(!map.nodes.contains_key(&2));
+    assert!(map.nodes.contains(1));
+    assert!(!map.nodes.contains(2));
     assert!(map.has_connection(1, 3));
 }
 
@@ -335,7 +353,7 @@ fn test_deduplicate_keeps_lowest_id() {
     let result = map.deduplicate_nodes(0.01);
     assert_eq!(result.removed_nodes, 2);
     assert_eq!(map.node_count(), 1);
-    assert!(map.nodes.contains_key(&2));
+    assert!(map.nodes.contains(2));
 }
 
 #[test]
@@ -472,8 +490,8 @@ fn make_chain_map(nodes: &[(u64, f32, f32)], edges: &[(u64, u64)]) -> RoadMap {
         map.add_node(MapNode::new(id, Vec2::new(x, y), NodeFlag::Regular));
     }
     for &(a, b) in edges {
-        let pa = map.nodes[&a].position;
-        let pb = map.nodes[&b].position;
+        let pa = map.nodes.position(a).unwrap();
+        let pb = map.nodes.position(b).unwrap();
         map.add_connection(Connection::new(
             a,
             b,
@@ -858,3 +876,135 @@ fn remove_nodes_batch_preserves_unrelated_connections() {
     assert!(!map.has_connection(2, 3), "2→3 muss weg sein");
     assert!(!map.has_connection(3, 4), "3→4 muss weg sein");
 }
+
+/// Manuell gesetzte Y-Werte gelten nur fuer existierende Nodes und verschwinden mit ihnen.
+#[test]
+fn height_override_follows_node_lifecycle() {
+    let mut map = RoadMap::new(3);
+    for id in 1u64..=3 {
+        map.add_node(MapNode::new(
+            id,
+            Vec2::new(id as f32 * 10.0, 0.0),
+            NodeFlag::Regular,
+        ));
+    }
+
+    assert!(!map.set_node_height_override(99, 5.0));
+    assert!(map.set_node_height_override(1, 12.5));
+    assert!(map.set_node_height_override(2, 7.0));
+    assert!(map.set_node_height_override(3, 3.0));
+    assert_eq!(map.node_height_override(1), Some(12.5));
+
+    map.remove_node(1);
+    let to_delete: std::collections::HashSet<u64> = [2u64].into_iter().collect();
+    map.remove_nodes_batch(&to_delete);
+    assert!(map.clear_node_height_override(3));
+
+    assert_eq!(map.node_height_override(1), None);
+    assert_eq!(map.node_height_override(2), None);
+    assert_eq!(map.node_height_override(3), None);
+}
+
+/// set_node_positions verschiebt mehrere Nodes und zieht die Verbindungsgeometrie nach.
+#[test]
+fn set_node_positions_updates_geometry_and_render_revision() {
+    let mut map = make_chain_map(&[(1, 0.0, 0.0), (2, 10.0, 0.0)], &[(1, 2)]);
+    let (_, revision_before) = map.render_cache_key();
+
+    assert!(map.set_node_positions(&[(1, Vec2::new(0.0, 5.0)), (2, Vec2::new(10.0, 5.0))]));
+    assert!(!map.set_node_positions(&[(1, Vec2::new(0.0, 5.0)), (99, Vec2::ZERO)]));
+
+    let connection = map.connections_iter().next().expect("Verbindung erwartet");
+    assert_eq!(connection.midpoint, Vec2::new(5.0, 5.0));
+    assert_ne!(map.render_cache_key().1, revision_before);
+}
+
+#[test]
+fn test_connections_crossing_segment_reports_interior_hits_only() {
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(3, Vec2::new(10.0, 10.0), NodeFlag::Regular));
+    for (start, end, start_pos, end_pos) in [
+        (1, 2, Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)),
+        (2, 3, Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0)),
+    ] {
+        map.add_connection(Connection::new(
+            start,
+            end,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            start_pos,
+            end_pos,
+        ));
+    }
+
+    let hits = map.connections_crossing_segment(Vec2::new(4.0, -5.0), Vec2::new(4.0, 5.0));
+    assert_eq!(hits.len(), 1);
+    assert_eq!((hits[0].0.start_id, hits[0].0.end_id), (1, 2));
+    assert!(hits[0].1.distance(Vec2::new(4.0, 0.0)) < 1.0e-4);
+
+    // Schnitt genau durch Node 2 trifft keine Verbindung
+    let through_node = map.connections_crossing_segment(Vec2::new(5.0, -5.0), Vec2::new(15.0, 5.0));
+    assert!(through_node.is_empty());
+}
+
+#[test]
+fn test_nearest_connection_not_touching_skips_own_connections() {
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(3, Vec2::new(4.0, 1.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(4, Vec2::new(4.0, 20.0), NodeFlag::Regular));
+    for (start, end) in [(1, 2), (3, 4)] {
+        let start_pos = map.node_position(start).expect("Start vorhanden");
+        let end_pos = map.node_position(end).expect("Ende vorhanden");
+        map.add_connection(Connection::new(
+            start,
+            end,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            start_pos,
+            end_pos,
+        ));
+    }
+
+    let (connection, point) = map
+        .nearest_connection_not_touching(Vec2::new(4.0, 1.0), 2.0, 3)
+        .expect("Verbindung 1→2 erwartet");
+    assert_eq!((connection.start_id, connection.end_id), (1, 2));
+    assert!(point.distance(Vec2::new(4.0, 0.0)) < 1.0e-4);
+
+    assert!(map
+        .nearest_connection_not_touching(Vec2::new(4.0, 1.0), 0.5, 3)
+        .is_none());
+}
+
+#[test]
+fn test_selection_length_and_bounds_only_count_inner_connections() {
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(2, Vec2::new(3.0, 4.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(3, Vec2::new(3.0, 10.0), NodeFlag::Regular));
+    map.add_node(MapNode::new(4, Vec2::new(50.0, 10.0), NodeFlag::Regular));
+    for (start, end) in [(1, 2), (2, 3), (3, 4)] {
+        let start_pos = map.node_position(start).expect("Start vorhanden");
+        let end_pos = map.node_position(end).expect("Ende vorhanden");
+        map.add_connection(Connection::new(
+            start,
+            end,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            start_pos,
+            end_pos,
+        ));
+    }
+    let ids: IndexSet<u64> = [1, 2, 3].into_iter().collect();
+
+    assert!((map.connection_length_between_ids(&ids) - 11.0).abs() < 1.0e-5);
+    assert_eq!(
+        map.bounds_of_ids(&ids),
+        Some((Vec2::new(0.0, 0.0), Vec2::new(3.0, 10.0)))
+    );
+    assert_eq!(map.bounds_of_ids(&[99].into_iter().collect()), None);
+}
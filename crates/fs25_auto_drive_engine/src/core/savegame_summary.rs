@@ -0,0 +1,18 @@
+//! Kurzuebersicht eines Savegames im FS25-Profil (fuer die Savegame-Auswahl).
+
+/// Savegame-Ordner (`savegameN`) mit den Angaben aus `careerSavegame.xml` und `farms.xml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavegameSummary {
+    /// Ordnername im Profil (z.B. `savegame3`)
+    pub dir_name: String,
+    /// Vom Spieler vergebener Name des Savegames
+    pub savegame_name: String,
+    /// Anzeigename der Karte
+    pub map_title: String,
+    /// Name des eigenen Hofs (leer, wenn `farms.xml` fehlt)
+    pub farm_name: String,
+    /// Datum des letzten Speicherns (`JJJJ-MM-TT`)
+    pub save_date: String,
+    /// Pfad der `AutoDrive_config.xml`, falls das Savegame eine besitzt
+    pub config_path: Option<String>,
+}
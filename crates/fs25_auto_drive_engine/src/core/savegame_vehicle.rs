@@ -0,0 +1,21 @@
+//! Fahrzeugpositionen aus der `vehicles.xml` eines Savegames.
+
+use glam::Vec2;
+
+/// Im Savegame abgestelltes Fahrzeug (nur fuer die Anzeige im Viewport).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavegameVehicle {
+    /// Anzeigename (Dateiname der Fahrzeug-XML ohne Endung)
+    pub name: String,
+    /// Position der Hauptkomponente in Weltkoordinaten (X/Z)
+    pub position: Vec2,
+    /// Gierwinkel der Hauptkomponente in Radiant (0 = Blick nach +Z)
+    pub yaw: f32,
+}
+
+impl SavegameVehicle {
+    /// Blickrichtung als Einheitsvektor in Weltkoordinaten (X/Z).
+    pub fn direction(&self) -> Vec2 {
+        Vec2::new(self.yaw.sin(), self.yaw.cos())
+    }
+}
@@ -1,11 +1,31 @@
 //! Spatial-Index (KD-Tree) fuer schnelle Node-Abfragen.
 
-use std::collections::HashMap;
-
 use glam::Vec2;
 use kiddo::{ImmutableKdTree, SquaredEuclidean};
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::core::NodeStore;
+
+/// Ab dieser Node-Anzahl wird der Index-Aufbau mit rayon parallelisiert.
+///
+/// Darunter ueberwiegt der Thread-Overhead den Gewinn.
+pub const PARALLEL_BUILD_THRESHOLD: usize = 20_000;
+
+/// Prozessweiter Zaehler aller Abfragen gegen [`SpatialIndex`]-Instanzen.
+static QUERY_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Liefert die Gesamtzahl bisheriger Spatial-Index-Abfragen.
+///
+/// Der Zaehler ist monoton; Profiling-Anzeigen bilden die Differenz zwischen
+/// zwei Frames, um Abfragen pro Frame zu erhalten.
+pub fn spatial_query_count() -> u64 {
+    QUERY_COUNT.load(Ordering::Relaxed)
+}
 
-use crate::core::MapNode;
+fn record_query() {
+    QUERY_COUNT.fetch_add(1, Ordering::Relaxed);
+}
 
 /// Ergebnis einer Distanzabfrage gegen den Spatial-Index.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -35,17 +55,45 @@ impl SpatialIndex {
     }
 
     /// Baut einen neuen Index aus den uebergebenen Nodes.
-    pub fn from_nodes(nodes: &HashMap<u64, MapNode>) -> Self {
-        let mut node_ids: Vec<u64> = nodes.keys().copied().collect();
-        node_ids.sort_unstable();
+    ///
+    /// Ab [`PARALLEL_BUILD_THRESHOLD`] Nodes wird automatisch
+    /// [`from_nodes_parallel`](Self::from_nodes_parallel) verwendet.
+    pub fn from_nodes(nodes: &NodeStore) -> Self {
+        if nodes.len() >= PARALLEL_BUILD_THRESHOLD {
+            return Self::from_nodes_parallel(nodes);
+        }
+
+        let mut pairs: Vec<(u64, Vec2)> = nodes.positions().collect();
+        pairs.sort_unstable_by_key(|&(id, _)| id);
+        let (node_ids, positions): (Vec<u64>, Vec<Vec2>) = pairs.into_iter().unzip();
 
-        let positions: Vec<Vec2> = node_ids
+        let entries: Vec<[f64; 2]> = positions
             .iter()
-            .filter_map(|id| nodes.get(id).map(|node| node.position))
+            .map(|position| [position.x as f64, position.y as f64])
             .collect();
 
+        let tree: ImmutableKdTree<f64, 2> = entries.as_slice().into();
+
+        Self {
+            tree,
+            node_ids,
+            positions,
+        }
+    }
+
+    /// Baut einen neuen Index mit rayon auf.
+    ///
+    /// ID-Sortierung und Koordinaten-Konvertierung laufen parallel; nur der
+    /// eigentliche KD-Tree-Aufbau bleibt sequentiell. Das Ergebnis ist identisch
+    /// mit dem sequentiellen Aufbau (gleiche ID-Reihenfolge).
+    pub fn from_nodes_parallel(nodes: &NodeStore) -> Self {
+        let mut pairs: Vec<(u64, Vec2)> = nodes.positions().collect();
+        pairs.par_sort_unstable_by_key(|&(id, _)| id);
+
+        let (node_ids, positions): (Vec<u64>, Vec<Vec2>) = pairs.into_par_iter().unzip();
+
         let entries: Vec<[f64; 2]> = positions
-            .iter()
+            .par_iter()
             .map(|position| [position.x as f64, position.y as f64])
             .collect();
 
@@ -70,6 +118,7 @@ impl SpatialIndex {
 
     /// Findet den naechsten Node zur gegebenen Weltposition.
     pub fn nearest(&self, query: Vec2) -> Option<SpatialMatch> {
+        record_query();
         if self.is_empty() {
             return None;
         }
@@ -87,6 +136,7 @@ impl SpatialIndex {
 
     /// Findet alle Nodes innerhalb eines Radius um die Query-Position.
     pub fn within_radius(&self, query: Vec2, radius: f32) -> Vec<SpatialMatch> {
+        record_query();
         if self.is_empty() || radius.is_sign_negative() {
             return Vec::new();
         }
@@ -120,6 +170,7 @@ impl SpatialIndex {
 
     /// Findet alle Nodes innerhalb eines axis-aligned Rechtecks und schreibt in einen Scratch-Buffer.
     pub fn within_rect_into(&self, min: Vec2, max: Vec2, out: &mut Vec<u64>) {
+        record_query();
         out.clear();
         if self.is_empty() {
             return;
@@ -153,14 +204,16 @@ impl SpatialIndex {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::NodeFlag;
-
-    fn sample_nodes() -> HashMap<u64, MapNode> {
-        let mut nodes = HashMap::new();
-        nodes.insert(1, MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular));
-        nodes.insert(2, MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular));
-        nodes.insert(3, MapNode::new(3, Vec2::new(4.0, 3.0), NodeFlag::Regular));
-        nodes
+    use crate::core::{MapNode, NodeFlag};
+
+    fn sample_nodes() -> NodeStore {
+        [
+            MapNode::new(1, Vec2::new(0.0, 0.0), NodeFlag::Regular),
+            MapNode::new(2, Vec2::new(10.0, 0.0), NodeFlag::Regular),
+            MapNode::new(3, Vec2::new(4.0, 3.0), NodeFlag::Regular),
+        ]
+        .into_iter()
+        .collect()
     }
 
     #[test]
@@ -192,6 +245,19 @@ mod tests {
         assert_eq!(ids, vec![1, 3]);
     }
 
+    #[test]
+    fn query_counter_counts_every_query() {
+        let index = SpatialIndex::from_nodes(&sample_nodes());
+        let before = spatial_query_count();
+
+        index.nearest(Vec2::ZERO);
+        index.within_radius(Vec2::ZERO, 1.0);
+        index.within_rect(Vec2::ZERO, Vec2::ONE);
+
+        // Andere Tests laufen parallel und zaehlen mit — daher nur Untergrenze.
+        assert!(spatial_query_count() >= before + 3);
+    }
+
     #[test]
     fn rect_query_into_clears_and_reuses_scratch_buffer() {
         let index = SpatialIndex::from_nodes(&sample_nodes());
@@ -222,6 +288,29 @@ mod tests {
         assert_eq!(scratch, from_allocating);
     }
 
+    #[test]
+    fn parallel_build_matches_sequential_build() {
+        let nodes: NodeStore = (1..=500u64)
+            .rev()
+            .map(|id| {
+                let position = Vec2::new((id % 37) as f32 * 3.0, (id / 37) as f32 * 5.0);
+                MapNode::new(id, position, NodeFlag::Regular)
+            })
+            .collect();
+
+        let sequential = SpatialIndex::from_nodes(&nodes);
+        let parallel = SpatialIndex::from_nodes_parallel(&nodes);
+
+        assert_eq!(sequential.node_ids, parallel.node_ids);
+        assert_eq!(sequential.positions, parallel.positions);
+        let query = Vec2::new(17.5, 22.0);
+        assert_eq!(sequential.nearest(query), parallel.nearest(query));
+        assert_eq!(
+            sequential.within_radius(query, 12.0),
+            parallel.within_radius(query, 12.0)
+        );
+    }
+
     #[test]
     fn empty_index_has_no_entries() {
         let index = SpatialIndex::empty();
@@ -0,0 +1,16 @@
+//! Eintraege des AutoDrive-Routen-Managers (`routes.xml` im Spielprofil).
+
+/// Im AutoDrive-Routen-Manager abgelegte Route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredRoute {
+    /// Anzeigename der Route
+    pub name: String,
+    /// Dateiname der Routen-XML im `routes`-Unterordner
+    pub file_name: String,
+    /// Map, fuer die die Route exportiert wurde
+    pub map: String,
+    /// Revision (wird beim Ueberschreiben einer gleichnamigen Route erhoeht)
+    pub revision: u32,
+    /// Zeitpunkt des Exports (`JJJJ/MM/TT hh:mm:ss`)
+    pub date: String,
+}
@@ -4,10 +4,12 @@
 pub mod app;
 /// Core-Domaenentypen: Nodes, Connections, RoadMap, Kamera, Spatial-Index.
 pub mod core;
+/// PDF-Export der Karte fuer den Ausdruck (Seitenraster, Legende, Massstab).
+pub mod pdf;
 /// Geteilte, host-neutrale Vertraege und Optionen.
 pub mod shared;
 /// XML Import/Export fuer AutoDrive-Konfigurationen.
 pub mod xml;
 
 pub use app::{AppCommand, AppController, AppIntent, AppState};
-pub use xml::{parse_autodrive_config, write_autodrive_config};
+pub use xml::{load_autodrive_config, parse_autodrive_config, write_autodrive_config};
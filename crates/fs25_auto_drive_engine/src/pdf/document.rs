@@ -0,0 +1,362 @@
+//! Minimaler PDF-1.4-Writer fuer Vektorgrafik und Text.
+//!
+//! Unterstuetzt genau das, was der Kartenexport braucht: Seiten mit
+//! unkomprimierten Content-Streams, Linien/Flaechen/Kreise, Clipping und Text
+//! in der PDF-Standardschrift Helvetica (WinAnsi, muss nicht eingebettet werden).
+
+use glam::Vec2;
+use std::fmt::Write as _;
+
+/// Objektnummern der festen Dokumentobjekte.
+const CATALOG_OBJ: usize = 1;
+const PAGES_OBJ: usize = 2;
+const FONT_OBJ: usize = 3;
+const INFO_OBJ: usize = 4;
+/// Erste Objektnummer der Seiten; je Seite folgen Seitenobjekt und Content-Stream.
+const FIRST_PAGE_OBJ: usize = 5;
+
+/// Kappa fuer die Annaeherung eines Viertelkreises durch eine kubische Bezierkurve.
+const CIRCLE_KAPPA: f32 = 0.552_284_8;
+/// Mittlere Zeichenbreite von Helvetica in Em (Naeherung fuer Textausrichtung).
+const HELVETICA_AVG_CHAR_EM: f32 = 0.52;
+
+/// Eine Seite mit Groesse in Punkten und ihrem Content-Stream.
+///
+/// Koordinaten folgen der PDF-Konvention: Ursprung unten links, y nach oben.
+#[derive(Debug, Clone)]
+pub struct PdfPage {
+    size: Vec2,
+    content: String,
+}
+
+impl PdfPage {
+    /// Erstellt eine leere Seite der Groesse `size` (Punkte).
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            content: String::new(),
+        }
+    }
+
+    /// Seitengroesse in Punkten.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Sichert den Grafikzustand (`q`).
+    pub fn save_state(&mut self) {
+        self.content.push_str("q\n");
+    }
+
+    /// Stellt den zuletzt gesicherten Grafikzustand wieder her (`Q`).
+    pub fn restore_state(&mut self) {
+        self.content.push_str("Q\n");
+    }
+
+    /// Setzt die Linienfarbe (RGB, 0..1).
+    pub fn set_stroke_rgb(&mut self, rgb: [f32; 3]) {
+        self.op(&format!(
+            "{} {} {} RG",
+            num(rgb[0]),
+            num(rgb[1]),
+            num(rgb[2])
+        ));
+    }
+
+    /// Setzt die Fuellfarbe (RGB, 0..1).
+    pub fn set_fill_rgb(&mut self, rgb: [f32; 3]) {
+        self.op(&format!(
+            "{} {} {} rg",
+            num(rgb[0]),
+            num(rgb[1]),
+            num(rgb[2])
+        ));
+    }
+
+    /// Setzt die Linienbreite in Punkten.
+    pub fn set_line_width(&mut self, width: f32) {
+        self.op(&format!("{} w", num(width)));
+    }
+
+    /// Setzt ein Strichmuster; ein leeres Muster zeichnet durchgezogen.
+    pub fn set_dash(&mut self, pattern: &[f32]) {
+        let pattern: Vec<String> = pattern.iter().map(|&v| num(v)).collect();
+        self.op(&format!("[{}] 0 d", pattern.join(" ")));
+    }
+
+    /// Beginnt einen neuen Teilpfad.
+    pub fn move_to(&mut self, p: Vec2) {
+        self.op(&format!("{} {} m", num(p.x), num(p.y)));
+    }
+
+    /// Verlaengert den Pfad geradlinig.
+    pub fn line_to(&mut self, p: Vec2) {
+        self.op(&format!("{} {} l", num(p.x), num(p.y)));
+    }
+
+    /// Schliesst den aktuellen Teilpfad.
+    pub fn close_path(&mut self) {
+        self.content.push_str("h\n");
+    }
+
+    /// Fuegt ein achsenparalleles Rechteck zum Pfad hinzu.
+    pub fn rect(&mut self, min: Vec2, size: Vec2) {
+        self.op(&format!(
+            "{} {} {} {} re",
+            num(min.x),
+            num(min.y),
+            num(size.x),
+            num(size.y)
+        ));
+    }
+
+    /// Fuegt einen Kreis (vier Bezierboegen) zum Pfad hinzu.
+    pub fn circle(&mut self, center: Vec2, radius: f32) {
+        let k = radius * CIRCLE_KAPPA;
+        let (cx, cy, r) = (center.x, center.y, radius);
+        self.move_to(Vec2::new(cx + r, cy));
+        let arcs = [
+            [cx + r, cy + k, cx + k, cy + r, cx, cy + r],
+            [cx - k, cy + r, cx - r, cy + k, cx - r, cy],
+            [cx - r, cy - k, cx - k, cy - r, cx, cy - r],
+            [cx + k, cy - r, cx + r, cy - k, cx + r, cy],
+        ];
+        for arc in arcs {
+            let coords: Vec<String> = arc.iter().map(|&v| num(v)).collect();
+            self.op(&format!("{} c", coords.join(" ")));
+        }
+        self.close_path();
+    }
+
+    /// Zeichnet den aktuellen Pfad als Linie.
+    pub fn stroke(&mut self) {
+        self.content.push_str("S\n");
+    }
+
+    /// Fuellt den aktuellen Pfad.
+    pub fn fill(&mut self) {
+        self.content.push_str("f\n");
+    }
+
+    /// Fuellt und umrandet den aktuellen Pfad.
+    pub fn fill_stroke(&mut self) {
+        self.content.push_str("B\n");
+    }
+
+    /// Begrenzt alle folgenden Zeichenoperationen auf ein Rechteck.
+    ///
+    /// Gilt bis zum naechsten `restore_state()`.
+    pub fn clip_rect(&mut self, min: Vec2, size: Vec2) {
+        self.rect(min, size);
+        self.content.push_str("W n\n");
+    }
+
+    /// Schreibt `text` in Helvetica mit Grundlinie links bei `pos`.
+    pub fn text(&mut self, pos: Vec2, size: f32, text: &str) {
+        self.op(&format!(
+            "BT /F1 {} Tf {} {} Td ({}) Tj ET",
+            num(size),
+            num(pos.x),
+            num(pos.y),
+            escape_text(text)
+        ));
+    }
+
+    /// Geschaetzte Breite von `text` in Helvetica der Groesse `size`.
+    pub fn text_width(size: f32, text: &str) -> f32 {
+        text.chars().count() as f32 * size * HELVETICA_AVG_CHAR_EM
+    }
+
+    fn op(&mut self, op: &str) {
+        self.content.push_str(op);
+        self.content.push('\n');
+    }
+}
+
+/// Mehrseitiges PDF-Dokument.
+#[derive(Debug, Clone, Default)]
+pub struct PdfDocument {
+    title: String,
+    pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+    /// Erstellt ein leeres Dokument mit Titel fuer die Dokumentinfo.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            pages: Vec::new(),
+        }
+    }
+
+    /// Haengt eine Seite an.
+    pub fn add_page(&mut self, page: PdfPage) {
+        self.pages.push(page);
+    }
+
+    /// Anzahl der Seiten.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Serialisiert das Dokument inklusive Querverweistabelle.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let object_count = FIRST_PAGE_OBJ + 2 * self.pages.len();
+        let mut out: Vec<u8> = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = vec![0usize; object_count];
+
+        let kids: Vec<String> = (0..self.pages.len())
+            .map(|i| format!("{} 0 R", FIRST_PAGE_OBJ + 2 * i))
+            .collect();
+        let fixed = [
+            (
+                CATALOG_OBJ,
+                format!("<< /Type /Catalog /Pages {PAGES_OBJ} 0 R >>"),
+            ),
+            (
+                PAGES_OBJ,
+                format!(
+                    "<< /Type /Pages /Kids [{}] /Count {} >>",
+                    kids.join(" "),
+                    self.pages.len()
+                ),
+            ),
+            (
+                FONT_OBJ,
+                "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                    .to_string(),
+            ),
+            (
+                INFO_OBJ,
+                format!(
+                    "<< /Title ({}) /Producer (FS25 AutoDrive Editor) >>",
+                    escape_text(&self.title)
+                ),
+            ),
+        ];
+        for (id, body) in fixed {
+            offsets[id] = out.len();
+            out.extend_from_slice(format!("{id} 0 obj\n{body}\nendobj\n").as_bytes());
+        }
+
+        for (i, page) in self.pages.iter().enumerate() {
+            let page_id = FIRST_PAGE_OBJ + 2 * i;
+            let content_id = page_id + 1;
+            offsets[page_id] = out.len();
+            out.extend_from_slice(
+                format!(
+                    "{page_id} 0 obj\n<< /Type /Page /Parent {PAGES_OBJ} 0 R /MediaBox [0 0 {} {}] \
+                     /Resources << /Font << /F1 {FONT_OBJ} 0 R >> >> /Contents {content_id} 0 R >>\nendobj\n",
+                    num(page.size.x),
+                    num(page.size.y)
+                )
+                .as_bytes(),
+            );
+            offsets[content_id] = out.len();
+            let stream = page.content.as_bytes();
+            out.extend_from_slice(
+                format!(
+                    "{content_id} 0 obj\n<< /Length {} >>\nstream\n",
+                    stream.len()
+                )
+                .as_bytes(),
+            );
+            out.extend_from_slice(stream);
+            out.extend_from_slice(b"\nendstream\nendobj\n");
+        }
+
+        let xref_offset = out.len();
+        let mut xref = format!("xref\n0 {object_count}\n0000000000 65535 f \n");
+        for offset in offsets.iter().skip(1) {
+            let _ = writeln!(xref, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            xref,
+            "trailer\n<< /Size {object_count} /Root {CATALOG_OBJ} 0 R /Info {INFO_OBJ} 0 R >>\n\
+             startxref\n{xref_offset}\n%%EOF\n"
+        );
+        out.extend_from_slice(xref.as_bytes());
+        out
+    }
+}
+
+/// Formatiert eine Zahl kompakt mit hoechstens zwei Nachkommastellen.
+fn num(value: f32) -> String {
+    let value = if value.is_finite() { value } else { 0.0 };
+    let formatted = format!("{value:.2}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "" | "-" | "-0" => "0".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Maskiert Text fuer einen PDF-Literalstring in WinAnsi-Kodierung.
+///
+/// Latin-1-Zeichen (z. B. Umlaute) werden oktal kodiert, alle uebrigen
+/// Nicht-ASCII-Zeichen durch `?` ersetzt.
+fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            '\u{A0}'..='\u{FF}' => {
+                let _ = write!(out, "\\{:03o}", c as u32);
+            }
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xref_offsets_point_at_their_objects() {
+        let mut document = PdfDocument::new("Test");
+        for _ in 0..2 {
+            let mut page = PdfPage::new(Vec2::new(100.0, 50.0));
+            page.circle(Vec2::new(10.0, 10.0), 2.0);
+            page.fill();
+            page.text(Vec2::new(5.0, 5.0), 8.0, "Hof (S\u{fc}d)");
+            document.add_page(page);
+        }
+        let bytes = document.to_bytes();
+        let text = String::from_utf8_lossy(&bytes);
+
+        assert!(bytes.starts_with(b"%PDF-1.4"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/Count 2"));
+        assert!(text.contains("(Hof \\(S\\374d\\)) Tj"));
+
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|value| value.parse().ok())
+            .unwrap();
+        assert!(bytes[startxref..].starts_with(b"xref\n0 9\n"));
+
+        let xref = std::str::from_utf8(&bytes[startxref..]).unwrap();
+        let entries = xref.lines().skip(3).take(8);
+        for (index, entry) in entries.enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            let header = format!("{} 0 obj", index + 1);
+            assert!(bytes[offset..].starts_with(header.as_bytes()), "{header}");
+        }
+    }
+
+    #[test]
+    fn numbers_are_compact() {
+        assert_eq!(num(1.0), "1");
+        assert_eq!(num(0.126), "0.13");
+        assert_eq!(num(-0.001), "0");
+        assert_eq!(num(f32::NAN), "0");
+    }
+}
@@ -0,0 +1,555 @@
+//! Mehrseitiger Kartendruck: teilt die Karte im gewaehlten Massstab auf
+//! Seiten auf und zeichnet Netz, Marker, Gitter, Legende und Massstabsleiste.
+
+use super::document::{PdfDocument, PdfPage};
+use crate::core::{Connection, ConnectionDirection, ConnectionPriority, RoadMap};
+use crate::shared::pdf_export::{
+    PDF_FOOTER_HEIGHT_PT, PDF_HEADER_HEIGHT_PT, PDF_MAX_PAGES, PDF_PAGE_MARGIN_PT,
+};
+use crate::shared::PdfExportOptions;
+use glam::Vec2;
+
+/// Rand um die Node-Bounding-Box, damit Randmarker samt Beschriftung lesbar bleiben.
+const PRINT_PADDING_M: f32 = 20.0;
+
+const BLACK: [f32; 3] = [0.0, 0.0, 0.0];
+const WHITE: [f32; 3] = [1.0, 1.0, 1.0];
+const GRID_COLOR: [f32; 3] = [0.78, 0.78, 0.78];
+const MAIN_ROAD_COLOR: [f32; 3] = [0.1, 0.25, 0.75];
+const SUB_ROAD_COLOR: [f32; 3] = [0.95, 0.55, 0.1];
+const MARKER_COLOR: [f32; 3] = [0.85, 0.1, 0.1];
+
+const MAIN_ROAD_WIDTH_PT: f32 = 0.9;
+const SUB_ROAD_WIDTH_PT: f32 = 0.6;
+const REVERSE_DASH_PT: [f32; 2] = [2.0, 1.5];
+/// Einbahn-Pfeile nur auf Segmenten, die auf dem Papier mindestens so lang sind.
+const ARROW_MIN_SEGMENT_PT: f32 = 8.0;
+const ARROW_SIZE_PT: f32 = 2.4;
+const MARKER_RADIUS_PT: f32 = 1.8;
+
+const HEADER_FONT_PT: f32 = 9.0;
+const LABEL_FONT_PT: f32 = 6.0;
+const FOOTER_FONT_PT: f32 = 7.0;
+/// Maximale Laenge der Massstabsleiste auf dem Papier.
+const SCALE_BAR_MAX_PT: f32 = 140.0;
+/// "Runde" Leistenlaengen in Metern, aus denen die laengste passende gewaehlt wird.
+const SCALE_BAR_STEPS_M: [f32; 12] = [
+    10.0, 20.0, 25.0, 50.0, 100.0, 200.0, 250.0, 500.0, 1000.0, 2000.0, 2500.0, 5000.0,
+];
+/// Kantenlaenge einer Zelle der Seitenuebersicht in der Fusszeile.
+const PAGE_INDEX_CELL_MAX_PT: f32 = 6.0;
+const PAGE_INDEX_MAX_PT: f32 = 36.0;
+
+/// Ergebnis eines PDF-Kartenexports.
+#[derive(Debug, Clone)]
+pub struct MapPdf {
+    /// Serialisiertes PDF-Dokument.
+    pub bytes: Vec<u8>,
+    /// Anzahl der erzeugten Seiten.
+    pub page_count: usize,
+}
+
+/// Zu druckender Weltausschnitt `(min, max)`: Bounding Box aller Nodes plus Rand.
+///
+/// `None`, wenn die Karte keine Nodes enthaelt.
+pub fn map_print_bounds(road_map: &RoadMap) -> Option<(Vec2, Vec2)> {
+    road_map
+        .nodes()
+        .positions()
+        .map(|(_, pos)| pos)
+        .fold(None, |bounds, pos| match bounds {
+            None => Some((pos, pos)),
+            Some((min, max)) => Some((min.min(pos), max.max(pos))),
+        })
+        .map(|(min, max)| {
+            (
+                min - Vec2::splat(PRINT_PADDING_M),
+                max + Vec2::splat(PRINT_PADDING_M),
+            )
+        })
+}
+
+/// Rendert die Karte als mehrseitiges PDF.
+///
+/// Das Seitenraster wird auf den Kartenausschnitt zentriert; Seiten werden
+/// zeilenweise von Nord nach Sued und spaltenweise von West nach Ost nummeriert.
+pub fn write_map_pdf(road_map: &RoadMap, options: &PdfExportOptions) -> anyhow::Result<MapPdf> {
+    let Some((min, max)) = map_print_bounds(road_map) else {
+        anyhow::bail!("Die Karte enthaelt keine Nodes");
+    };
+    let (columns, rows) = options.page_grid(max - min);
+    let page_count = columns * rows;
+    if page_count > PDF_MAX_PAGES {
+        anyhow::bail!(
+            "{page_count} Seiten ueberschreiten das Maximum von {PDF_MAX_PAGES}; \
+             kleineren Massstab oder groesseres Papier waehlen"
+        );
+    }
+
+    let page_extent = options.page_extent_m();
+    let grid_size = page_extent * Vec2::new(columns as f32, rows as f32);
+    let origin = (min + max) * 0.5 - grid_size * 0.5;
+    let title = road_map
+        .map_name
+        .clone()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "AutoDrive-Karte".to_string());
+
+    let mut document = PdfDocument::new(title.clone());
+    for row in 0..rows {
+        for column in 0..columns {
+            let tile_min = origin + page_extent * Vec2::new(column as f32, row as f32);
+            let sheet = SheetLayout {
+                options,
+                tile_min,
+                tile_max: tile_min + page_extent,
+                columns,
+                rows,
+                column,
+                row,
+            };
+            document.add_page(sheet.render(road_map, &title));
+        }
+    }
+
+    Ok(MapPdf {
+        page_count: document.page_count(),
+        bytes: document.to_bytes(),
+    })
+}
+
+/// Geometrie einer einzelnen Seite im Seitenraster.
+struct SheetLayout<'a> {
+    options: &'a PdfExportOptions,
+    tile_min: Vec2,
+    tile_max: Vec2,
+    columns: usize,
+    rows: usize,
+    column: usize,
+    row: usize,
+}
+
+impl SheetLayout<'_> {
+    fn page_size(&self) -> Vec2 {
+        self.options.page_size_pt()
+    }
+
+    /// Untere linke Ecke des Kartenrahmens in Seitenkoordinaten.
+    fn frame_min(&self) -> Vec2 {
+        Vec2::new(
+            PDF_PAGE_MARGIN_PT,
+            PDF_PAGE_MARGIN_PT + PDF_FOOTER_HEIGHT_PT,
+        )
+    }
+
+    fn frame_size(&self) -> Vec2 {
+        self.options.map_frame_size_pt()
+    }
+
+    /// Weltposition (x, z) → Seitenkoordinate; Norden (−z) liegt oben.
+    fn to_page(&self, world: Vec2) -> Vec2 {
+        let scale = self.options.pt_per_meter();
+        let frame_top = self.frame_min().y + self.frame_size().y;
+        Vec2::new(
+            self.frame_min().x + (world.x - self.tile_min.x) * scale,
+            frame_top - (world.y - self.tile_min.y) * scale,
+        )
+    }
+
+    /// Prueft grob, ob ein Segment den Seitenausschnitt beruehrt.
+    fn touches(&self, a: Vec2, b: Vec2) -> bool {
+        a.min(b).cmple(self.tile_max).all() && a.max(b).cmpge(self.tile_min).all()
+    }
+
+    fn render(&self, road_map: &RoadMap, title: &str) -> PdfPage {
+        let mut page = PdfPage::new(self.page_size());
+
+        page.save_state();
+        page.clip_rect(self.frame_min(), self.frame_size());
+        if self.options.show_grid {
+            self.draw_grid(&mut page);
+        }
+        self.draw_connections(&mut page, road_map);
+        self.draw_markers(&mut page, road_map);
+        page.restore_state();
+
+        page.set_stroke_rgb(BLACK);
+        page.set_line_width(0.8);
+        page.rect(self.frame_min(), self.frame_size());
+        page.stroke();
+
+        self.draw_header(&mut page, title);
+        self.draw_legend(&mut page);
+        self.draw_page_index(&mut page);
+        self.draw_scale_bar(&mut page);
+        page
+    }
+
+    fn draw_grid(&self, page: &mut PdfPage) {
+        let spacing = self.options.grid_spacing_m;
+        if spacing <= 0.0 {
+            return;
+        }
+        page.set_stroke_rgb(GRID_COLOR);
+        page.set_line_width(0.3);
+        page.set_fill_rgb(GRID_COLOR);
+
+        let mut x = (self.tile_min.x / spacing).ceil() * spacing;
+        while x <= self.tile_max.x {
+            let top = self.to_page(Vec2::new(x, self.tile_min.y));
+            page.move_to(top);
+            page.line_to(self.to_page(Vec2::new(x, self.tile_max.y)));
+            page.stroke();
+            page.text(
+                top + Vec2::new(1.5, -LABEL_FONT_PT),
+                LABEL_FONT_PT,
+                &format!("{x:.0}"),
+            );
+            x += spacing;
+        }
+        let mut z = (self.tile_min.y / spacing).ceil() * spacing;
+        while z <= self.tile_max.y {
+            let left = self.to_page(Vec2::new(self.tile_min.x, z));
+            page.move_to(left);
+            page.line_to(self.to_page(Vec2::new(self.tile_max.x, z)));
+            page.stroke();
+            page.text(
+                left + Vec2::new(1.5, 1.5),
+                LABEL_FONT_PT,
+                &format!("{z:.0}"),
+            );
+            z += spacing;
+        }
+    }
+
+    /// Zeichnet Nebenstrassen zuerst, damit Hauptstrassen obenauf liegen.
+    fn draw_connections(&self, page: &mut PdfPage, road_map: &RoadMap) {
+        for priority in [ConnectionPriority::SubPriority, ConnectionPriority::Regular] {
+            let (color, width) = road_style(priority);
+            page.set_stroke_rgb(color);
+            page.set_fill_rgb(color);
+            page.set_line_width(width);
+            for reverse in [false, true] {
+                page.set_dash(if reverse { &REVERSE_DASH_PT } else { &[] });
+                let segments: Vec<(Vec2, Vec2, bool)> = road_map
+                    .connections_iter()
+                    .filter(|c| c.priority == priority)
+                    .filter(|c| (c.direction == ConnectionDirection::Reverse) == reverse)
+                    .filter(|c| !is_mirrored_dual(road_map, c))
+                    .filter_map(|c| {
+                        let start = road_map.node_position(c.start_id)?;
+                        let end = road_map.node_position(c.end_id)?;
+                        self.touches(start, end).then(|| {
+                            (
+                                self.to_page(start),
+                                self.to_page(end),
+                                c.direction != ConnectionDirection::Dual,
+                            )
+                        })
+                    })
+                    .collect();
+                if segments.is_empty() {
+                    continue;
+                }
+                for &(start, end, _) in &segments {
+                    page.move_to(start);
+                    page.line_to(end);
+                }
+                page.stroke();
+                for &(start, end, one_way) in &segments {
+                    if one_way && start.distance(end) >= ARROW_MIN_SEGMENT_PT {
+                        draw_arrow_head(page, start, end);
+                    }
+                }
+            }
+        }
+        page.set_dash(&[]);
+    }
+
+    fn draw_markers(&self, page: &mut PdfPage, road_map: &RoadMap) {
+        page.set_line_width(0.4);
+        page.set_stroke_rgb(WHITE);
+        for marker in road_map.map_markers().iter().filter(|m| !m.is_debug) {
+            let Some(pos) = road_map.node_position(marker.id) else {
+                continue;
+            };
+            if !self.touches(pos, pos) {
+                continue;
+            }
+            let point = self.to_page(pos);
+            page.set_fill_rgb(MARKER_COLOR);
+            page.circle(point, MARKER_RADIUS_PT);
+            page.fill_stroke();
+            page.set_fill_rgb(BLACK);
+            page.text(
+                point + Vec2::new(MARKER_RADIUS_PT + 1.0, 1.0),
+                LABEL_FONT_PT,
+                &marker.name,
+            );
+        }
+    }
+
+    fn draw_header(&self, page: &mut PdfPage, title: &str) {
+        let baseline = self.page_size().y - PDF_PAGE_MARGIN_PT - PDF_HEADER_HEIGHT_PT + 5.0;
+        let orientation = if self.options.landscape {
+            "quer"
+        } else {
+            "hoch"
+        };
+        page.set_fill_rgb(BLACK);
+        page.text(
+            Vec2::new(PDF_PAGE_MARGIN_PT, baseline),
+            HEADER_FONT_PT,
+            &format!(
+                "{title} - Massstab 1:{} - {} {orientation}",
+                self.options.scale_denominator,
+                self.options.paper.label()
+            ),
+        );
+        let page_label = format!(
+            "Seite {} von {} (Zeile {}, Spalte {})",
+            self.row * self.columns + self.column + 1,
+            self.columns * self.rows,
+            self.row + 1,
+            self.column + 1
+        );
+        let width = PdfPage::text_width(HEADER_FONT_PT, &page_label);
+        page.text(
+            Vec2::new(self.page_size().x - PDF_PAGE_MARGIN_PT - width, baseline),
+            HEADER_FONT_PT,
+            &page_label,
+        );
+    }
+
+    fn draw_legend(&self, page: &mut PdfPage) {
+        let top = PDF_PAGE_MARGIN_PT + PDF_FOOTER_HEIGHT_PT - 12.0;
+        let left = PDF_PAGE_MARGIN_PT;
+        let entries = [
+            (
+                "Hauptstrasse",
+                MAIN_ROAD_COLOR,
+                MAIN_ROAD_WIDTH_PT,
+                false,
+                false,
+            ),
+            (
+                "Nebenstrasse",
+                SUB_ROAD_COLOR,
+                SUB_ROAD_WIDTH_PT,
+                false,
+                false,
+            ),
+            ("Einbahn", MAIN_ROAD_COLOR, MAIN_ROAD_WIDTH_PT, false, true),
+            (
+                "Rueckwaerts",
+                MAIN_ROAD_COLOR,
+                MAIN_ROAD_WIDTH_PT,
+                true,
+                false,
+            ),
+        ];
+        for (index, (label, color, width, dashed, arrow)) in entries.into_iter().enumerate() {
+            let origin = Vec2::new(
+                left + (index % 2) as f32 * 90.0,
+                top - (index / 2) as f32 * 11.0,
+            );
+            let (start, end) = (origin, origin + Vec2::new(18.0, 0.0));
+            page.set_stroke_rgb(color);
+            page.set_fill_rgb(color);
+            page.set_line_width(width);
+            page.set_dash(if dashed { &REVERSE_DASH_PT } else { &[] });
+            page.move_to(start);
+            page.line_to(end);
+            page.stroke();
+            if arrow {
+                draw_arrow_head(page, start, end);
+            }
+            page.set_fill_rgb(BLACK);
+            page.text(end + Vec2::new(4.0, -2.5), FOOTER_FONT_PT, label);
+        }
+        page.set_dash(&[]);
+
+        let marker = Vec2::new(left + 9.0, top - 22.0);
+        page.set_line_width(0.4);
+        page.set_stroke_rgb(WHITE);
+        page.set_fill_rgb(MARKER_COLOR);
+        page.circle(marker, MARKER_RADIUS_PT);
+        page.fill_stroke();
+        page.set_fill_rgb(BLACK);
+        page.text(marker + Vec2::new(13.0, -2.5), FOOTER_FONT_PT, "Marker");
+        if self.options.show_grid {
+            page.text(
+                Vec2::new(left + 90.0 + 22.0, top - 24.5),
+                FOOTER_FONT_PT,
+                &format!("Gitter {:.0} m", self.options.grid_spacing_m),
+            );
+        }
+    }
+
+    /// Miniatur des Seitenrasters mit hervorgehobener aktueller Seite.
+    fn draw_page_index(&self, page: &mut PdfPage) {
+        let cell =
+            (PAGE_INDEX_MAX_PT / self.columns.max(self.rows) as f32).min(PAGE_INDEX_CELL_MAX_PT);
+        let size = cell * Vec2::new(self.columns as f32, self.rows as f32);
+        let top_left = Vec2::new(
+            self.page_size().x * 0.5 - size.x * 0.5,
+            PDF_PAGE_MARGIN_PT + PDF_FOOTER_HEIGHT_PT - 6.0,
+        );
+        page.set_line_width(0.3);
+        page.set_stroke_rgb(BLACK);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let min = top_left + Vec2::new(column as f32 * cell, -((row + 1) as f32) * cell);
+                page.set_fill_rgb(if (row, column) == (self.row, self.column) {
+                    MARKER_COLOR
+                } else {
+                    WHITE
+                });
+                page.rect(min, Vec2::splat(cell));
+                page.fill_stroke();
+            }
+        }
+    }
+
+    fn draw_scale_bar(&self, page: &mut PdfPage) {
+        let pt_per_m = self.options.pt_per_meter();
+        let length_m = SCALE_BAR_STEPS_M
+            .iter()
+            .copied()
+            .rfind(|&m| m * pt_per_m <= SCALE_BAR_MAX_PT)
+            .unwrap_or(SCALE_BAR_STEPS_M[0]);
+        let length_pt = length_m * pt_per_m;
+        let right = self.page_size().x - PDF_PAGE_MARGIN_PT;
+        let origin = Vec2::new(right - length_pt, PDF_PAGE_MARGIN_PT + 14.0);
+
+        page.set_line_width(0.5);
+        page.set_stroke_rgb(BLACK);
+        for part in 0..4 {
+            page.set_fill_rgb(if part % 2 == 0 { BLACK } else { WHITE });
+            page.rect(
+                origin + Vec2::new(part as f32 * length_pt / 4.0, 0.0),
+                Vec2::new(length_pt / 4.0, 3.0),
+            );
+            page.fill_stroke();
+        }
+
+        page.set_fill_rgb(BLACK);
+        let labels = [
+            (0.0, "0".to_string()),
+            (0.5, format!("{}", length_m * 0.5)),
+            (1.0, format!("{length_m} m")),
+        ];
+        for (fraction, label) in labels {
+            let width = PdfPage::text_width(FOOTER_FONT_PT, &label);
+            let x = (origin.x + fraction * length_pt - width * 0.5).min(right - width);
+            page.text(Vec2::new(x, origin.y - 9.0), FOOTER_FONT_PT, &label);
+        }
+        let caption = format!("Massstab 1:{}", self.options.scale_denominator);
+        page.text(
+            Vec2::new(
+                right - PdfPage::text_width(FOOTER_FONT_PT, &caption),
+                origin.y + 7.0,
+            ),
+            FOOTER_FONT_PT,
+            &caption,
+        );
+    }
+}
+
+fn road_style(priority: ConnectionPriority) -> ([f32; 3], f32) {
+    match priority {
+        ConnectionPriority::Regular => (MAIN_ROAD_COLOR, MAIN_ROAD_WIDTH_PT),
+        ConnectionPriority::SubPriority => (SUB_ROAD_COLOR, SUB_ROAD_WIDTH_PT),
+    }
+}
+
+/// Zweirichtungsverbindungen liegen oft doppelt vor; gezeichnet wird nur eine.
+fn is_mirrored_dual(road_map: &RoadMap, connection: &Connection) -> bool {
+    connection.direction == ConnectionDirection::Dual
+        && connection.start_id > connection.end_id
+        && road_map.has_connection(connection.end_id, connection.start_id)
+}
+
+/// Gefuellte Pfeilspitze in Segmentmitte, zeigt von `start` nach `end`.
+fn draw_arrow_head(page: &mut PdfPage, start: Vec2, end: Vec2) {
+    let direction = (end - start).normalize_or_zero();
+    let normal = direction.perp();
+    let tip = (start + end) * 0.5 + direction * ARROW_SIZE_PT * 0.5;
+    let base = tip - direction * ARROW_SIZE_PT;
+    page.move_to(tip);
+    page.line_to(base + normal * ARROW_SIZE_PT * 0.5);
+    page.line_to(base - normal * ARROW_SIZE_PT * 0.5);
+    page.close_path();
+    page.fill();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MapMarker, MapNode, NodeFlag};
+    use crate::shared::PdfPaperSize;
+
+    fn make_map(width_m: f32) -> RoadMap {
+        let mut map = RoadMap::new(3);
+        map.map_name = Some("Riverbend".to_string());
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(width_m, 0.0);
+        map.add_node(MapNode::new(1, a, NodeFlag::Regular));
+        map.add_node(MapNode::new(2, b, NodeFlag::Regular));
+        map.add_connection(Connection::new(
+            1,
+            2,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            a,
+            b,
+        ));
+        map.add_map_marker(MapMarker::new(
+            2,
+            "Hof".to_string(),
+            "All".to_string(),
+            1,
+            false,
+        ));
+        map
+    }
+
+    #[test]
+    fn paginates_wide_map_and_draws_legend_and_markers() {
+        let options = PdfExportOptions {
+            paper: PdfPaperSize::A4,
+            landscape: true,
+            scale_denominator: 2000,
+            show_grid: true,
+            grid_spacing_m: 100.0,
+        };
+        let pdf = write_map_pdf(&make_map(1200.0), &options).unwrap();
+        let text = String::from_utf8_lossy(&pdf.bytes);
+
+        // 1240 m Breite bei rund 554 m pro Seite → 3 Spalten, 1 Zeile.
+        assert_eq!(pdf.page_count, 3);
+        assert!(text.contains("/Count 3"));
+        assert!(text.contains("(Hof) Tj"));
+        assert!(text.contains("(Hauptstrasse) Tj"));
+        assert!(text.contains("(Massstab 1:2000) Tj"));
+        assert!(text.contains("(Seite 3 von 3 \\(Zeile 1, Spalte 3\\)) Tj"));
+    }
+
+    #[test]
+    fn rejects_empty_maps_and_too_many_pages() {
+        let options = PdfExportOptions {
+            scale_denominator: 1000,
+            ..PdfExportOptions::default()
+        };
+        assert!(write_map_pdf(&RoadMap::new(3), &options).is_err());
+
+        let mut huge = make_map(0.0);
+        huge.add_node(MapNode::new(
+            3,
+            Vec2::new(8000.0, 8000.0),
+            NodeFlag::Regular,
+        ));
+        let error = write_map_pdf(&huge, &options).unwrap_err();
+        assert!(error.to_string().contains("Maximum"));
+    }
+}
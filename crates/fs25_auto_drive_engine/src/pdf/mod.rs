@@ -0,0 +1,12 @@
+//! PDF-Export der Karte fuer den Ausdruck.
+//!
+//! Ein kleiner eigener PDF-Writer (Vektorgrafik, Standardschrift Helvetica)
+//! genuegt fuer Kursplaene; eine externe PDF-Bibliothek ist nicht noetig.
+
+/// Minimaler PDF-Writer (Seiten, Pfade, Text, Querverweistabelle).
+pub mod document;
+/// Mehrseitiger Kartendruck mit Legende, Massstabsleiste und Gitter.
+pub mod map_sheet;
+
+pub use document::{PdfDocument, PdfPage};
+pub use map_sheet::{map_print_bounds, write_map_pdf, MapPdf};
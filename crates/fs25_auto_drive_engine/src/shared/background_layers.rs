@@ -58,6 +58,29 @@ impl fmt::Display for BackgroundLayerKind {
     }
 }
 
+/// Quelle des angezeigten Viewport-Hintergrunds.
+///
+/// `Overview` zeigt die geladene bzw. generierte Uebersichtskarte; die beiden
+/// Heightmap-Varianten rendern stattdessen die ausgewaehlte Heightmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundSource {
+    /// Uebersichtskarte bzw. frei gewaehltes Hintergrundbild.
+    #[default]
+    Overview,
+    /// Heightmap als Graustufen-Hillshade.
+    HeightmapHillshade,
+    /// Heightmap mit Hoehenfarben und Hillshade.
+    HeightmapGradient,
+}
+
+impl BackgroundSource {
+    /// Ob die Quelle aus der Heightmap gerendert wird.
+    pub const fn is_heightmap(self) -> bool {
+        matches!(self, Self::HeightmapHillshade | Self::HeightmapGradient)
+    }
+}
+
 /// Host-neutrale Quelle fuer die Feldpolygon-Erkennung der Uebersichtskarte.
 ///
 /// Gueltige serialisierte Werte: `from_zip`, `zip_ground_gdm`,
@@ -5,6 +5,7 @@
 
 use crate::shared::background_layers::OverviewFieldDetectionSource;
 use crate::shared::OverviewLayerOptions;
+use crate::shared::PdfExportOptions;
 use glam::Vec2;
 use std::path::PathBuf;
 
@@ -213,6 +214,130 @@ impl Default for TraceAllFieldsDialogState {
     }
 }
 
+/// Einstellungen fuer den "Feld-Rundkurs erzeugen"-Dialog.
+#[derive(Debug, Clone)]
+pub struct FieldCourseDialogState {
+    /// Ob der Dialog sichtbar ist.
+    pub visible: bool,
+    /// Farmland-ID des Feldes, fuer das der Rundkurs erzeugt wird.
+    pub field_id: u32,
+    /// Abstand zwischen generierten Wegpunkten in Welteinheiten (Meter).
+    pub spacing: f32,
+    /// Abstand des Vorgewende-Rings vom Feldrand nach innen (Meter).
+    pub headland_offset: f32,
+}
+
+impl Default for FieldCourseDialogState {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            field_id: 1,
+            spacing: 10.0,
+            headland_offset: 6.0,
+        }
+    }
+}
+
+/// Eingaben fuer den "Selektion verschieben um..."-Dialog.
+///
+/// Die Werte folgen den FS25-Koordinaten: X und Z liegen in der Kartenebene,
+/// Y ist die Hoehe.
+#[derive(Debug, Clone, Default)]
+pub struct MoveSelectionDialogState {
+    /// Ob der Dialog sichtbar ist.
+    pub visible: bool,
+    /// Versatz entlang X in Metern.
+    pub dx: f32,
+    /// Versatz der Hoehe (Y-Wert) in Metern.
+    pub dy: f32,
+    /// Versatz entlang Z in Metern.
+    pub dz: f32,
+}
+
+/// Filter fuer "Loeschen nach Filter...".
+///
+/// Aktive Filter werden UND-verknuepft; ohne aktiven Filter gilt jeder
+/// ungesperrte Node der Region als Treffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NodeDeleteFilter {
+    /// Nur Nodes mit Flag `SubPrio` (Nebenstrasse).
+    pub only_subprio: bool,
+    /// Nur Nodes ohne jede Verbindung.
+    pub only_unconnected: bool,
+    /// Nur Nodes ohne Map-Marker.
+    pub only_without_markers: bool,
+}
+
+impl NodeDeleteFilter {
+    /// Prueft, ob ein Kandidat alle aktiven Filter erfuellt.
+    pub fn matches(&self, candidate: &NodeDeleteCandidate) -> bool {
+        (!self.only_subprio || candidate.is_subprio)
+            && (!self.only_unconnected || candidate.is_unconnected)
+            && (!self.only_without_markers || !candidate.has_marker)
+    }
+}
+
+/// Filterrelevante Eigenschaften eines Nodes in der Loesch-Region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeDeleteCandidate {
+    /// ID des Nodes.
+    pub node_id: u64,
+    /// Node traegt das Flag `SubPrio`.
+    pub is_subprio: bool,
+    /// Node hat keine Verbindungen.
+    pub is_unconnected: bool,
+    /// Node traegt einen Map-Marker.
+    pub has_marker: bool,
+}
+
+/// Zustand des Dialogs "Loeschen nach Filter...".
+///
+/// Die Region ist die beim Oeffnen aktive Selektion (Rechteck- oder
+/// Lasso-Selektion). Die Kandidaten werden beim Oeffnen einmal erfasst, damit
+/// der Dialog die Treffer bei jeder Filteraenderung ohne Karte zaehlen kann.
+#[derive(Debug, Clone, Default)]
+pub struct DeleteByFilterDialogState {
+    /// Ob der Dialog sichtbar ist.
+    pub visible: bool,
+    /// Aktuell gewaehlte Filter.
+    pub filter: NodeDeleteFilter,
+    /// Ungesperrte Nodes der Region.
+    pub candidates: Vec<NodeDeleteCandidate>,
+    /// Gesperrte Nodes der Region (werden nie geloescht).
+    pub locked_count: usize,
+}
+
+impl DeleteByFilterDialogState {
+    /// Anzahl der Kandidaten, die der aktuelle Filter loeschen wuerde.
+    pub fn matching_count(&self) -> usize {
+        self.candidates
+            .iter()
+            .filter(|candidate| self.filter.matches(candidate))
+            .count()
+    }
+}
+
+/// Zustand des Dialogs "Als PDF exportieren...".
+///
+/// Die Kartenausdehnung wird beim Oeffnen einmal erfasst, damit der Dialog die
+/// Seitenanzahl bei jeder Format- oder Massstabsaenderung ohne Karte berechnen kann.
+#[derive(Debug, Clone, Default)]
+pub struct PdfExportDialogState {
+    /// Ob der Dialog sichtbar ist.
+    pub visible: bool,
+    /// Aktuell gewaehlte Export-Einstellungen.
+    pub options: PdfExportOptions,
+    /// Ausdehnung des zu druckenden Kartenausschnitts in Metern.
+    pub map_extent: Vec2,
+}
+
+impl PdfExportDialogState {
+    /// Seitenraster `(Spalten, Zeilen)` fuer die aktuellen Einstellungen.
+    pub fn page_grid(&self) -> (usize, usize) {
+        self.options.page_grid(self.map_extent)
+    }
+}
+
 /// Konfiguration fuer das Distanzen-Neuverteilen-Feature im Eigenschaften-Bereich.
 #[derive(Debug, Clone)]
 pub struct DistanzenState {
@@ -276,3 +401,36 @@ impl DistanzenState {
         self.active && self.hide_original
     }
 }
+
+/// Zustand des seitlichen Strecken-Versatzes (Kette senkrecht verschieben).
+#[derive(Debug, Clone)]
+pub struct PathOffsetState {
+    /// Vorschau-Modus aktiv (verschobene Kette wird im Viewport gezeichnet)
+    pub active: bool,
+    /// Vorzeichenbehafteter Versatz in Metern (positiv = links in Kettenrichtung)
+    pub distance: f32,
+    /// Geordnete Node-IDs der Kette, die beim Start der Vorschau erfasst wurde
+    pub chain_ids: Vec<u64>,
+    /// Vorschau-Positionen der verschobenen Kette (fuer Overlay)
+    pub preview_positions: Vec<Vec2>,
+}
+
+impl Default for PathOffsetState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            distance: 3.0,
+            chain_ids: Vec::new(),
+            preview_positions: Vec::new(),
+        }
+    }
+}
+
+impl PathOffsetState {
+    /// Deaktiviert den Vorschau-Modus; der zuletzt genutzte Versatz bleibt erhalten.
+    pub fn deactivate(&mut self) {
+        self.active = false;
+        self.chain_ids.clear();
+        self.preview_positions.clear();
+    }
+}
@@ -1,5 +1,8 @@
 //! Gemeinsame Geometrie-Hilfsfunktionen fuer layer-uebergreifende Nutzung.
 
+use crate::shared::EditorOptions;
+use glam::Vec2;
+
 /// Berechnet die Abweichung zwischen Einlauf- und Auslaufwinkel (0 = geradeaus, PI = Umkehr).
 ///
 /// Misst, wie stark die Richtung abknickt. Der Rueckgabewert liegt im Bereich [0, PI].
@@ -9,9 +12,114 @@ pub fn angle_deviation(incoming: f32, outgoing: f32) -> f32 {
     diff.abs()
 }
 
+/// Kompasskurs von `from` nach `to` in Grad im Bereich [0, 360).
+///
+/// 0° = Norden (-Z), 90° = Osten (+X), im Uhrzeigersinn wie in der Kartenansicht.
+pub fn compass_heading_deg(from: Vec2, to: Vec2) -> f32 {
+    let delta = to - from;
+    delta.x.atan2(-delta.y).to_degrees().rem_euclid(360.0)
+}
+
+/// Rundet eine Position auf das naechste Rasterfeld (`grid_size` in Metern).
+pub fn snap_to_grid(pos: Vec2, grid_size: f32) -> Vec2 {
+    if grid_size <= 0.0 {
+        return pos;
+    }
+    (pos / grid_size).round() * grid_size
+}
+
+/// Beschraenkt das Segment `origin → pos` auf Vielfache von `angle_degrees`.
+///
+/// Die Segmentlaenge wird dabei auf Vielfache von `grid_size` gerundet
+/// (mindestens ein Rasterschritt), damit auch schraege Segmente gleichmaessig
+/// bleiben. Liegt `pos` auf `origin`, bleibt die Position unveraendert.
+pub fn constrain_to_angle(origin: Vec2, pos: Vec2, angle_degrees: f32, grid_size: f32) -> Vec2 {
+    let delta = pos - origin;
+    let length = delta.length();
+    if length <= f32::EPSILON || angle_degrees <= 0.0 {
+        return pos;
+    }
+    let step = angle_degrees.to_radians();
+    let angle = (delta.y.atan2(delta.x) / step).round() * step;
+    let length = if grid_size > 0.0 {
+        (length / grid_size).round().max(1.0) * grid_size
+    } else {
+        length
+    };
+    origin + Vec2::from_angle(angle) * length
+}
+
+/// Wendet Grid- und Winkel-Snap aus den `EditorOptions` auf eine Platzierung an.
+///
+/// Mit `origin` (Startpunkt des aktuellen Segments) wird die Richtung auf das
+/// Winkelraster beschraenkt, sonst die Position auf das Raster gesetzt.
+/// `snapped_to_node` meldet der Aufrufer, wenn ein existierender Node im
+/// Snap-Radius liegt — der Node-Snap hat dann Vorrang und die Position bleibt
+/// unveraendert.
+pub fn apply_snap_constraints(
+    pos: Vec2,
+    origin: Option<Vec2>,
+    snapped_to_node: bool,
+    options: &EditorOptions,
+) -> Vec2 {
+    if !options.grid_snap_enabled || snapped_to_node {
+        return pos;
+    }
+    match origin {
+        Some(origin) => constrain_to_angle(
+            origin,
+            pos,
+            options.angle_snap_degrees,
+            options.grid_snap_size,
+        ),
+        None => snap_to_grid(pos, options.grid_snap_size),
+    }
+}
+
+/// Berechnet einen Parallel-Offset einer Polyline.
+///
+/// `offset > 0` → links (positive Senkrechte in Fahrtrichtung).
+/// `offset < 0` → rechts.
+/// Bei weniger als 2 Punkten wird die Eingabe unveraendert zurueckgegeben.
+pub fn parallel_offset(polyline: &[Vec2], offset: f32) -> Vec<Vec2> {
+    if polyline.len() < 2 {
+        return polyline.to_vec();
+    }
+    polyline
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            let perp = local_perp(i, polyline);
+            p + perp * offset
+        })
+        .collect()
+}
+
+/// Lokale Senkrechte am Index `i` einer Polyline (Durchschnitt benachbarter Segmente).
+///
+/// Randpunkte (i==0 oder i==n-1) nutzen nur das angrenzende Segment.
+pub fn local_perp(i: usize, poly: &[Vec2]) -> Vec2 {
+    let n = poly.len();
+    let tangent = if i == 0 {
+        (poly[1] - poly[0]).normalize_or_zero()
+    } else if i == n - 1 {
+        (poly[n - 1] - poly[n - 2]).normalize_or_zero()
+    } else {
+        let t1 = (poly[i] - poly[i - 1]).normalize_or_zero();
+        let t2 = (poly[i + 1] - poly[i]).normalize_or_zero();
+        (t1 + t2).normalize_or_zero()
+    };
+    Vec2::new(-tangent.y, tangent.x)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::angle_deviation;
+    use super::{
+        angle_deviation, apply_snap_constraints, compass_heading_deg, constrain_to_angle,
+        snap_to_grid,
+    };
+    use crate::shared::EditorOptions;
+    use glam::Vec2;
 
     #[test]
     fn angle_deviation_straight() {
@@ -41,4 +149,56 @@ mod tests {
             dev.to_degrees()
         );
     }
+
+    #[test]
+    fn compass_heading_follows_map_orientation() {
+        let cases = [
+            (Vec2::new(0.0, -1.0), 0.0),
+            (Vec2::new(1.0, 0.0), 90.0),
+            (Vec2::new(0.0, 1.0), 180.0),
+            (Vec2::new(-1.0, 0.0), 270.0),
+        ];
+        for (to, expected) in cases {
+            let heading = compass_heading_deg(Vec2::ZERO, to);
+            assert!(
+                (heading - expected).abs() < 1e-4,
+                "{expected}° erwartet, ist {heading}°"
+            );
+        }
+    }
+
+    #[test]
+    fn snap_to_grid_rounds_to_nearest_cell() {
+        assert_eq!(
+            snap_to_grid(Vec2::new(2.4, -7.6), 5.0),
+            Vec2::new(0.0, -10.0)
+        );
+        assert_eq!(snap_to_grid(Vec2::new(2.4, 1.6), 0.0), Vec2::new(2.4, 1.6));
+    }
+
+    #[test]
+    fn constrain_to_angle_quantizes_direction_and_length() {
+        let origin = Vec2::new(10.0, 10.0);
+        let constrained = constrain_to_angle(origin, Vec2::new(19.6, 11.0), 15.0, 1.0);
+        assert!((constrained - Vec2::new(20.0, 10.0)).length() < 1e-4);
+
+        let diagonal = constrain_to_angle(Vec2::ZERO, Vec2::new(6.0, 8.0), 45.0, 1.0);
+        let expected = Vec2::from_angle(45f32.to_radians()) * 10.0;
+        assert!((diagonal - expected).length() < 1e-4);
+    }
+
+    #[test]
+    fn apply_snap_constraints_prefers_node_snap_and_respects_toggle() {
+        let mut options = EditorOptions::default();
+        let pos = Vec2::new(7.7, 0.4);
+
+        assert_eq!(apply_snap_constraints(pos, None, false, &options), pos);
+
+        options.grid_snap_enabled = true;
+        assert_eq!(
+            apply_snap_constraints(pos, None, false, &options),
+            Vec2::new(8.0, 0.0)
+        );
+        assert_eq!(apply_snap_constraints(pos, None, true, &options), pos);
+    }
 }
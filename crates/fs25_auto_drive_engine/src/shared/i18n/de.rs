@@ -42,6 +42,7 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptSubSectionLodDesc => "Pixel-Untergrenzen und Node-Ausdünnung beim Herauszoomen.",
         I18nKey::OptSubSectionBackground => "Hintergrund",
         I18nKey::OptSubSectionCopyPaste => "Copy/Paste-Vorschau",
+        I18nKey::OptSubSectionLiveLink => "Live-Verbindung zum Spiel",
         I18nKey::OptSubSectionOverview => "Uebersichtskarte (Standard-Layer)",
         I18nKey::OptNodeSizeWorld => "Groesse (Welt):",
         I18nKey::OptNodeSizeWorldHelp => "Durchmesser eines Wegpunkts in Welteinheiten (Meter).",
@@ -59,6 +60,20 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptSnapRadiusHelp => "Fangradius fuer Werkzeuge in Prozent der Node-Groesse. Bestimmt ab welcher Entfernung ein Node gefangen wird.",
         I18nKey::OptMouseWheelDistStep => "Mausrad-Schritt Distanz:",
         I18nKey::OptMouseWheelDistStepHelp => "Schrittweite in Metern pro Mausrad-Tick bei Distanz-Eingaben.",
+        I18nKey::OptMaxRouteGrade => "Max. Steigung Route:",
+        I18nKey::OptMaxRouteGradeHelp => "Steigungsgrenze für Route-Tool-Vorschauen; steilere Verbindungen werden im Höhenprofil gewarnt.",
+        I18nKey::OptRoundaboutRadius => "Kreisverkehr-Radius:",
+        I18nKey::OptRoundaboutRadiusHelp => "Radius des Rings, der beim Umwandeln einer Kreuzung in einen Kreisverkehr entsteht.",
+        I18nKey::OptKnifeSplitConnections => "Messer teilt Verbindungen",
+        I18nKey::OptKnifeSplitConnectionsHelp => "Aktiviert setzt das Messer-Werkzeug an jedem Schnittpunkt einen neuen Node und teilt die Verbindung; sonst werden gekreuzte Verbindungen entfernt.",
+        I18nKey::OptSnapMovedNodeToConnection => "Gezogenen Node in Verbindung einfügen",
+        I18nKey::OptGridSnapSize => "Rasterweite:",
+        I18nKey::OptGridSnapSizeHelp => "Abstand der Rasterpunkte bei aktivem Raster-Snap; bei Winkel-Snap zugleich Längenraster der Segmente.",
+        I18nKey::OptAngleSnap => "Winkelraster:",
+        I18nKey::OptAngleSnapHelp => "Segmente rasten bei aktivem Raster-Snap auf Vielfache dieses Winkels ein (z.B. 15° oder 45°).",
+        I18nKey::OptSnapMovedNodeToConnectionHelp => "Wird ein einzelner Node nahe einer bestehenden Verbindung (nicht auf einem Node) abgelegt, wird die Verbindung geteilt und der Node dazwischen eingefügt.",
+        I18nKey::OptTerrainClippingTolerance => "Toleranz Terrain-Warnung:",
+        I18nKey::OptTerrainClippingToleranceHelp => "Verbindungen, deren Gerade stärker vom Heightmap-Terrain abweicht, werden mit einem Warnsymbol markiert (0 = aus).",
         I18nKey::OptSelectionSizeFactor => "Groessenfaktor (%):",
         I18nKey::OptSelectionSizeFactorHelp => "Selektierte Nodes werden um diesen Faktor vergroessert dargestellt (100% = keine Vergroesserung).",
         I18nKey::OptSelectionStyle => "Markierungsstil:",
@@ -71,6 +86,10 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptSegmentMaxAngle => "Max. Winkel (°):",
         I18nKey::OptSegmentMaxAngleHelp => "Maximale Winkelabweichung in Grad fuer Doppelklick-Gruppen-Erkennung. 0 = deaktiviert.",
         I18nKey::OptSegmentDisabled => "(deaktiviert)",
+        I18nKey::OptFloodSelectFollowDirection => "Shift+Doppelklick nur in Fahrtrichtung",
+        I18nKey::OptFloodSelectFollowDirectionHelp => "Shift+Doppelklick selektiert alle erreichbaren Nodes. Aktiviert folgt die Auswahl nur Verbindungen in Fahrtrichtung, sonst dem ganzen zusammenhängenden Netz.",
+        I18nKey::OptInvertSelectionVisibleOnly => "Auswahl invertieren nur im sichtbaren Bereich",
+        I18nKey::OptInvertSelectionVisibleOnlyHelp => "Aktiviert schaltet \"Auswahl invertieren\" nur Nodes im aktuellen Ausschnitt um (ohne isolierte/ausgeblendete Nodes); Nodes außerhalb behalten ihren Zustand.",
         I18nKey::OptConnectionWidthMain => "Breite Hauptstrasse:",
         I18nKey::OptConnectionWidthMainHelp => "Linienstaerke fuer Verbindungen mit normaler Prioritaet in Welteinheiten.",
         I18nKey::OptConnectionWidthSubprio => "Breite Nebenstrasse:",
@@ -86,6 +105,16 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptMarkerSizeHelp => "Groesse des Marker-Pin-Icons in Welteinheiten.",
         I18nKey::OptMarkerColor => "Pin-Farbe:",
         I18nKey::OptMarkerOutlineWidth => "Umrissstaerke:",
+        I18nKey::OptShowSavegameVehicles => "Savegame-Fahrzeuge anzeigen",
+        I18nKey::OptShowOwnedFarmlands => "Eigene Farmlands toenen",
+        I18nKey::OptShowOwnedFarmlandsHelp => "Hebt die laut farmland.xml des Savegames gekauften Farmlands im Viewport farbig hervor (benoetigt Farmland-Polygone).",
+        I18nKey::OptShowSavegameVehiclesHelp => "Zeigt die in der vehicles.xml des Savegames abgestellten Fahrzeuge mit Namen im Viewport.",
+        I18nKey::OptLiveLinkEnabled => "Live-Verbindung aktivieren",
+        I18nKey::OptLiveLinkEnabledHelp => "Lauscht auf 127.0.0.1 auf Nachrichten des Companion-Mods (Spielerposition) und kann das Netz ohne Neustart ins Spiel laden lassen.",
+        I18nKey::OptLiveLinkPort => "UDP-Port:",
+        I18nKey::OptLiveLinkPortHelp => "Lokaler Port, auf dem der Editor lauscht. Muss mit der Einstellung des Companion-Mods uebereinstimmen.",
+        I18nKey::OptLiveRecordSpacing => "Aufzeichnungsabstand (m):",
+        I18nKey::OptLiveRecordSpacingHelp => "Abstand zwischen Wegpunkten beim Aufzeichnen eines Kurses waehrend der Fahrt.",
         I18nKey::OptMarkerOutlineWidthHelp => "Strichdicke des Marker-Umrisses als Anteil am Radius. Aendert das SVG-Icon zur Laufzeit.",
         I18nKey::OptCameraZoomMin => "Min Zoom:",
         I18nKey::OptCameraZoomMinHelp => "Minimaler Zoom-Faktor. Kleinere Werte erlauben staerkeres Herauszoomen.",
@@ -138,8 +167,17 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         // === Menü: Datei ===
         I18nKey::MenuFile => "Datei",
         I18nKey::MenuOpen => "Öffnen...",
+        I18nKey::MenuOpenSavegame => "Savegame öffnen...",
+        I18nKey::MenuOpenSavegameHelp => "Savegame aus dem FS25-Profil waehlen und dessen AutoDrive-Config oeffnen",
         I18nKey::MenuSave => "Speichern",
         I18nKey::MenuSaveAs => "Speichern unter...",
+        I18nKey::MenuExportModConfig => "Als Mod-Standardconfig exportieren",
+        I18nKey::MenuExportIntoMapZip => "In Map-Mod-ZIP schreiben...",
+        I18nKey::MenuExportIntoMapZipHelp => "Legt das Netz als AutoDrive_config.xml in das Wurzelverzeichnis eines Map-Mod-ZIPs (Sicherung als .zip.bak)",
+        I18nKey::MenuExportCoursesMod => "Als Kurs-Mod exportieren...",
+        I18nKey::MenuExportCoursesModHelp => "Erzeugt einen eigenstaendigen Mod mit modDesc.xml und AutoDrive_config.xml",
+        I18nKey::MenuExportPdf => "Als PDF exportieren...",
+        I18nKey::MenuExportPdfHelp => "Druckt die Karte mehrseitig mit Legende, Maßstabsleiste und optionalem Gitter",
         I18nKey::MenuSelectHeightmap => "Höhenkarte auswählen...",
         I18nKey::MenuChangeHeightmap => "Höhenkarte ändern...",
         I18nKey::MenuClearHeightmap => "Höhenkarte entfernen",
@@ -151,12 +189,17 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::MenuRedo => "Wiederherstellen (Ctrl+Y / Shift+Cmd+Z)",
         I18nKey::MenuCopy => "Kopieren (Ctrl+C)",
         I18nKey::MenuPaste => "Einfügen (Ctrl+V)",
+        I18nKey::MenuDuplicate => "Duplizieren (Ctrl+D)",
+        I18nKey::MenuMoveSelection => "Verschieben um...",
+        I18nKey::MenuDeleteByFilter => "Löschen nach Filter...",
+        I18nKey::MenuProjectOntoTerrain => "Auf Gelände projizieren",
         I18nKey::MenuOptions => "Optionen...",
         // === Menü: Ansicht ===
         I18nKey::MenuView => "Ansicht",
         I18nKey::MenuResetCamera => "Kamera zurücksetzen",
         I18nKey::MenuZoomIn => "Vergrößern",
         I18nKey::MenuZoomOut => "Verkleinern",
+        I18nKey::MenuIsolateSelection => "Auswahl isolieren",
         I18nKey::MenuLoadBackground => "Hintergrund laden...",
         I18nKey::MenuChangeBackground => "Hintergrund ändern...",
         I18nKey::MenuBackgroundLayers => "Hintergrund-Layer",
@@ -166,16 +209,39 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::MenuBgLayerFarmlandIds => "Farmland-IDs",
         I18nKey::MenuBgLayerPoiMarkers => "POI-Marker",
         I18nKey::MenuBgLayerLegend => "Legende",
+        I18nKey::MenuBackgroundSource => "Hintergrundquelle",
+        I18nKey::MenuBgSourceOverview => "Übersichtskarte",
+        I18nKey::MenuBgSourceHeightmapHillshade => "Heightmap (Schattierung)",
+        I18nKey::MenuBgSourceHeightmapGradient => "Heightmap (Höhenfarben)",
         I18nKey::MenuRenderQuality => "Renderqualität",
         I18nKey::MenuQualityLow => "Niedrig",
         I18nKey::MenuQualityMedium => "Mittel",
         I18nKey::MenuQualityHigh => "Hoch",
+        I18nKey::MenuDebug => "Debug",
+        I18nKey::MenuProfilingOverlay => "Performance-HUD",
+        // === Performance-HUD ===
+        I18nKey::ProfilingFrameTime => "Frame-Zeit",
+        I18nKey::ProfilingSceneBuild => "Szenen-Aufbau",
+        I18nKey::ProfilingUploaded => "Hochgeladen",
+        I18nKey::ProfilingDrawn => "Gezeichnet",
+        I18nKey::ProfilingBackgroundTiles => "Hintergrund-Kacheln",
+        I18nKey::ProfilingSpatialQueries => "Spatial-Abfragen (Engine / Renderer)",
         // === Menü: Extras ===
         I18nKey::MenuExtras => "Extras",
         I18nKey::MenuDetectField => "\u{1F33E} Feld erkennen",
         I18nKey::MenuTraceAllFields => "\u{1F4CD} Alle Felder nachzeichnen",
         I18nKey::MenuExtrasNeedBackground => "Hintergrund mit Feldgrenzen zuerst laden",
         I18nKey::MenuTraceAllFieldsHelp => "Alle erkannten Felder automatisch mit Wegpunkten nachzeichnen (ein Undo-Schritt)",
+        I18nKey::MenuFieldCourse => "\u{1F69C} Feld-Rundkurs erzeugen...",
+        I18nKey::MenuFieldCourseHelp => "Vorgewende-Ring fuer ein Feld erzeugen und mit dem naechsten bestehenden Node verbinden (ein Undo-Schritt)",
+        I18nKey::MenuLiveRecordStart => "\u{23FA} Kurs aufzeichnen",
+        I18nKey::MenuLiveRecordStop => "\u{23F9} Aufzeichnung beenden",
+        I18nKey::MenuLiveRecordHelp => "Legt waehrend der Fahrt im Spiel Wegpunkte mit Einbahn-Verbindungen an (ein Undo-Schritt)",
+        I18nKey::MenuLivePush => "\u{1F4E4} Netz ans Spiel senden",
+        I18nKey::MenuLivePushHelp => "Speichert die Config und laesst sie vom Companion-Mod ohne Neustart neu laden",
+        I18nKey::MenuLiveNotConnected => "Kein Spiel verbunden (Live-Verbindung in den Optionen aktivieren)",
+        I18nKey::MenuAutoDriveRoutes => "\u{1F5C2} AutoDrive-Routen...",
+        I18nKey::MenuAutoDriveRoutesHelp => "Routen ueber den Routes-Manager-Ordner von AutoDrive importieren oder exportieren",
         I18nKey::MenuCurseplayImport => "Courseplay importieren",
         I18nKey::MenuCurseplayExport => "Courseplay exportieren",
         I18nKey::MenuFieldPath => "\u{1F6E4} Feldweg erkennen",
@@ -200,12 +266,16 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::StatusHeightmapNone => "Keine",
         I18nKey::StatusSelectedNodes => "Ausgewählte Knoten",
         I18nKey::StatusExample => "z.B.",
+        I18nKey::StatusSelectionLength => "Länge",
+        I18nKey::StatusSelectionBounds => "Ausdehnung",
         I18nKey::StatusTool => "Werkzeug",
         I18nKey::StatusFps => "FPS",
         // === Werkzeug-Namen ===
         I18nKey::ToolNameSelect => "Auswahl",
         I18nKey::ToolNameConnect => "Verbinden",
         I18nKey::ToolNameAddNode => "Knoten hinzufügen",
+        I18nKey::ToolNameKnife => "Messer",
+        I18nKey::ToolNameMeasure => "Messen",
         I18nKey::ToolNameRoute => "Routen-Werkzeug",
         // === Sidebar: Sections ===
         I18nKey::SidebarTools => "Werkzeuge",
@@ -224,6 +294,8 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::ZoomInHelp => "Hineinzoomen (+)",
         I18nKey::ZoomOutHelp => "Herauszoomen (-)",
         // === Hintergrund ===
+        I18nKey::GridSnapEnable => "Raster- und Winkel-Snap einschalten",
+        I18nKey::GridSnapDisable => "Raster- und Winkel-Snap ausschalten",
         I18nKey::BackgroundHide => "Hintergrund ausblenden",
         I18nKey::BackgroundShow => "Hintergrund einblenden",
         I18nKey::BackgroundScaleDown => "Ausdehnung halbieren",
@@ -238,20 +310,26 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::FloatingToolSelect => "Auswahl",
         I18nKey::FloatingToolConnect => "Verbinden",
         I18nKey::FloatingToolAddNode => "Node hinzufuegen",
+        I18nKey::FloatingToolKnife => "Messer",
+        I18nKey::FloatingToolMeasure => "Messen (Lineal)",
         // === Floating-Menu: Grundbefehle ===
         I18nKey::FloatingBasicStraight => "Gerade Strecke",
         I18nKey::FloatingBasicQuadratic => "Bezier Grad 2",
         I18nKey::FloatingBasicCubic => "Bezier Grad 3",
         I18nKey::FloatingBasicSpline => "Spline",
         I18nKey::FloatingBasicSmoothCurve => "Geglaettete Kurve",
+        I18nKey::FloatingBasicClothoid => "Klothoide",
+        I18nKey::FloatingBasicArc => "Kreisbogen",
         // === Floating-Menu: Bearbeiten ===
         I18nKey::FloatingEditBypass => "Ausweichstrecke",
         I18nKey::FloatingEditParking => "Parkplatz",
         I18nKey::FloatingEditRouteOffset => "Strecke versetzen",
         I18nKey::FloatingEditRounding => "Verrunden",
+        I18nKey::FloatingEditJunction => "Kreuzung",
         I18nKey::FloatingAnalysisFieldBoundary => "Feld erkennen",
         I18nKey::FloatingAnalysisFieldPath => "Feldweg erkennen",
         I18nKey::FloatingAnalysisColorPath => "Farb-Pfad erkennen",
+        I18nKey::FloatingAnalysisFieldCourse => "Feldkurs erzeugen",
         // === Floating-Menu: Richtung + Strassenart ===
         I18nKey::FloatingDirectionRegular => "Einbahn vorwaerts",
         I18nKey::FloatingDirectionDual => "Zweirichtungsverkehr",
@@ -279,12 +357,16 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::CtxRemoveFromGroup => "Aus Gruppe entfernen",
         I18nKey::CtxDissolveGroup => "Gruppe aufloesen",
         I18nKey::CtxConnectNodes => "Nodes verbinden",
+        I18nKey::CtxJoinChains => "Ketten verbinden",
+        I18nKey::CtxMergeNodes => "Nodes zusammenführen",
         I18nKey::CtxCreateRoute => "Strecke erzeugen",
         I18nKey::CtxDirectionSubmenu => "Richtung",
         I18nKey::CtxDirectionRegular => "Einbahn vorwaerts",
         I18nKey::CtxDirectionDual => "Zweirichtungsverkehr",
         I18nKey::CtxDirectionReverse => "Einbahn rueckwaerts",
         I18nKey::CtxDirectionInvert => "Invertieren",
+        I18nKey::CtxDirectionReversePath => "Pfad umkehren",
+        I18nKey::CtxReverseChain => "Kette umkehren",
         I18nKey::CtxPrioritySubmenu => "Strassenart",
         I18nKey::CtxPriorityMain => "Hauptstrasse",
         I18nKey::CtxPrioritySub => "Nebenstrasse",
@@ -293,10 +375,25 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::CtxSelectionInvert => "Invertieren",
         I18nKey::CtxSelectAll => "Alles auswaehlen",
         I18nKey::CtxClearSelection => "Auswahl aufheben",
+        I18nKey::CtxSelectConnected => "Alles Erreichbare auswaehlen",
+        I18nKey::CtxConvertToRoundabout => "In Kreisverkehr umwandeln",
+        I18nKey::CtxSelectPathBetween => "Pfad dazwischen auswaehlen",
+        I18nKey::CtxLockSelection => "Sperren",
+        I18nKey::CtxUnlockSelection => "Entsperren",
+        I18nKey::CtxSelectMainRoadNodes => "Alle Hauptstrassen-Nodes",
+        I18nKey::CtxSelectSubPrioNodes => "Alle Nebenstrassen-Nodes",
         I18nKey::CtxStreckenteilung => "Streckenteilung",
+        I18nKey::CtxEqualizeSpacing => "Gleiche Abstaende",
+        I18nKey::CtxAlignSubmenu => "Ausrichten",
+        I18nKey::CtxAlignAxisX => "Auf X-Linie (gleiches Z)",
+        I18nKey::CtxAlignAxisZ => "Auf Z-Linie (gleiches X)",
+        I18nKey::CtxAlignBestFit => "Auf Ausgleichsgerade",
+        I18nKey::CtxAlignEndpointLine => "Auf Gerade zwischen Endpunkten",
+        I18nKey::CtxDistributeEvenly => "Gleichmäßig verteilen",
         I18nKey::CtxDeleteSelected => "Loeschen",
         I18nKey::CtxCopy => "Kopieren",
         I18nKey::CtxPaste => "Einfuegen",
+        I18nKey::CtxDuplicate => "Duplizieren",
         // === Command Palette ===
         I18nKey::PaletteSearchHint => "Befehl eingeben...",
         I18nKey::PaletteNoResults => "Keine Treffer",
@@ -305,31 +402,43 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::PaletteUndo => "Rueckgaengig",
         I18nKey::PaletteRedo => "Wiederholen",
         I18nKey::PaletteSelectAll => "Alles auswaehlen",
+        I18nKey::PaletteGrowSelection => "Auswahl erweitern (Nachbarn)",
+        I18nKey::PaletteShrinkSelection => "Auswahl verkleinern (Rand)",
         I18nKey::PaletteDeleteSelected => "Auswahl loeschen",
         I18nKey::PaletteCopy => "Kopieren",
         I18nKey::PalettePaste => "Einfuegen",
+        I18nKey::PaletteDuplicate => "Duplizieren",
+        I18nKey::PaletteProjectOntoTerrain => "Auswahl auf Gelaende projizieren",
         I18nKey::PaletteResetCamera => "Kamera zuruecksetzen",
         I18nKey::PaletteToolSelect => "Select-Tool",
         I18nKey::PaletteToolConnect => "Connect-Tool",
         I18nKey::PaletteToolAddNode => "Add-Node-Tool",
+        I18nKey::PaletteToolKnife => "Messer-Tool",
+        I18nKey::PaletteToolMeasure => "Mess-Tool",
         I18nKey::PaletteRouteToolPrefix => "Route-Tool:",
         I18nKey::MenuRouteTools => "Route-Tools",
         // === Sidebar: LongPress-Tooltips ===
         I18nKey::LpToolSelect => "Auswahl\nNodes per Klick oder Lasso selektieren.\nSelektierte Nodes per Drag verschieben.\n(Taste T)",
         I18nKey::LpToolConnect => "Verbinden\nVerbindung zwischen 2 Nodes erstellen (Taste C)\noder loeschen (Taste X). Pfeilrichtung = Fahrtrichtung.\n(Taste T)",
         I18nKey::LpToolAddNode => "Node hinzufuegen\nNeuen Wegpunkt per Klick in die Karte setzen.\n(Taste T)",
+        I18nKey::LpToolKnife => "Messer\nLinie ueber die Karte ziehen: gekreuzte Verbindungen werden entfernt oder am Schnittpunkt geteilt (siehe Optionen).\n(Taste T)",
+        I18nKey::LpToolMeasure => "Messen (Lineal)\nPunkte auf die Karte klicken: Laenge je Segment, Gesamtlaenge und Kurs werden angezeigt, es entstehen keine Nodes. Esc verwirft die Messung.\n(Taste T)",
         I18nKey::LpStraight => "Gerade Strecke\nNodes mit gleichmaessigem Abstand entlang einer Linie platzieren.\nPfeiltasten: Abstand/Anzahl anpassen. Enter: Bestaetigen.\n(G)",
         I18nKey::LpCurveQuad => "Bezier-Kurve quadratisch\n1 Kontrollpunkt. Einfache, gleichmaessige Kurve.\n(G)",
         I18nKey::LpCurveCubic => "Bezier-Kurve kubisch\n2 Kontrollpunkte fuer maximale Formkontrolle.\n(G)",
         I18nKey::LpSpline => "Catmull-Rom Spline\nGlatte Kurve durch existierende Nodes.\nZusaetzliche Zwischenpunkte werden berechnet.\n(G)",
         I18nKey::LpSmoothCurve => "Geglaettete Kurve\nWinkelgeglaettete Strecke zwischen zwei Nodes.\nAutomatische Ausrichtung an Strassenrasterwinkeln.\n(G)",
+        I18nKey::LpClothoid => "Klothoide\nKrümmungsstetiger Übergangsbogen zwischen zwei Richtungen.\nTangenten werden aus angeschlossenen Verbindungen übernommen.\n(G)",
+        I18nKey::LpArc => "Kreisbogen\nKreisbogen mit konstantem Radius durch Start-, Durchgangs- und Endpunkt.\nGeeignet für gleichmäßige Straßenkurven.\n(G)",
         I18nKey::LpBypass => "Ausweichstrecke\nErzeugt eine parallele Umgehungsstrecke zur Selektion.\n(B)",
         I18nKey::LpParking => "Parkplatz\nGeneriert ein Parkplatz-Layout aus der Selektion.\n(B)",
         I18nKey::LpRouteOffset => "Strecke versetzen\nVerschiebt die selektierte Route parallel um einen konfigurierbaren Abstand.\n(B)",
         I18nKey::LpRounding => "Verrunden\nBereitet lokale Streckenuebergaenge fuer Arc- oder Quadratic-Modi vor.\n(B)",
+        I18nKey::LpJunction => "Kreuzung\nVerbindet 3–4 selektierte Zufahrts-Endpunkte mit gerichteten Links-, Rechts- und Geradeaus-Spuren.\nAbbiegespuren werden als Nebenstrecke angelegt.\n(B)",
         I18nKey::LpFieldBoundary => "Feld erkennen\nExtrahiert aus geladenen Farmland-Daten eine bearbeitbare Feldgrenze.\nBenötigt Farmland-Daten.\n(A)",
         I18nKey::LpFieldPath => "Feldweg erkennen\nErmittelt aus Farmland-Daten einen Fahrpfad innerhalb eines Feldes.\nBenötigt Farmland-Daten.\n(A)",
         I18nKey::LpColorPath => "Farb-Pfad erkennen\nLeitet aus der geladenen Hintergrundkarte einen Pfad per Farbsegmentierung ab.\nBenötigt eine Hintergrundkarte.\n(A)",
+        I18nKey::LpFieldCourse => "Feldkurs erzeugen\nLegt Vorgewende-Runden und Serpentinen-Bahnen mit einstellbarer Arbeitsbreite in ein Feld.\nBenötigt Farmland-Daten.\n(A)",
         I18nKey::LpDirectionRegular => "Einbahn vorwaerts\nFahrzeuge nutzen diese Verbindungen nur in Vorwaertsrichtung.\nStandard fuer normale gerichtete Strecken.\n(R)",
         I18nKey::LpDirectionDual => "Zweirichtung\nFahrzeuge koennen in beide Richtungen fahren.\nFuer Wege die in beiden Richtungen befahrbar sein sollen.\n(R)",
         I18nKey::LpDirectionReverse => "Einbahn rueckwaerts\nFahrzeuge fahren ausschliesslich rueckwaerts auf dieser Strecke.\n(R)",
@@ -338,6 +447,9 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::RouteToolNeedFarmland => "Farmland-Daten zuerst laden",
         I18nKey::RouteToolNeedBackground => "Hintergrundkarte zuerst laden",
         I18nKey::RouteToolNeedOrderedChain => "Geordnete Node-Kette selektieren",
+        I18nKey::RouteToolElevationProfile => "Höhenprofil",
+        I18nKey::RouteToolGradeExceeded => "Steigungsgrenze überschritten",
+        I18nKey::TerrainClippingWarning => "Terrain-Abweichung",
         I18nKey::ParkingHintRotate => "Alt+Mausrad zum Drehen",
         I18nKey::ParkingHintFixed => "Position fixiert \u{2014} Viewport-Klick zum Verschieben",
         I18nKey::ParkingHintAdjusting => "Klicken zum Fixieren \u{2014} Alt+Mausrad zum Drehen",
@@ -42,6 +42,7 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptSubSectionLodDesc => "Pixel minimums and node decimation when zooming out.",
         I18nKey::OptSubSectionBackground => "Background",
         I18nKey::OptSubSectionCopyPaste => "Copy/Paste Preview",
+        I18nKey::OptSubSectionLiveLink => "Live Link to Game",
         I18nKey::OptSubSectionOverview => "Overview Map (Standard Layers)",
         I18nKey::OptNodeSizeWorld => "Size (World):",
         I18nKey::OptNodeSizeWorldHelp => "Diameter of a waypoint in world units (meters).",
@@ -59,6 +60,20 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptSnapRadiusHelp => "Snap radius for tools as percent of node size. Determines the distance at which a node is snapped.",
         I18nKey::OptMouseWheelDistStep => "Mouse Wheel Distance Step:",
         I18nKey::OptMouseWheelDistStepHelp => "Step size in meters per mouse wheel tick for distance inputs.",
+        I18nKey::OptMaxRouteGrade => "Max. Route Grade:",
+        I18nKey::OptMaxRouteGradeHelp => "Grade limit for route tool previews; steeper connections are flagged in the elevation profile.",
+        I18nKey::OptRoundaboutRadius => "Roundabout Radius:",
+        I18nKey::OptRoundaboutRadiusHelp => "Radius of the ring created when converting a junction into a roundabout.",
+        I18nKey::OptKnifeSplitConnections => "Knife splits connections",
+        I18nKey::OptKnifeSplitConnectionsHelp => "When enabled, the knife tool inserts a new node at every intersection and splits the connection; otherwise crossed connections are removed.",
+        I18nKey::OptSnapMovedNodeToConnection => "Insert dragged node into connection",
+        I18nKey::OptGridSnapSize => "Grid Size:",
+        I18nKey::OptGridSnapSizeHelp => "Spacing of grid points while grid snap is active; with angle snap it also quantizes segment lengths.",
+        I18nKey::OptAngleSnap => "Angle Step:",
+        I18nKey::OptAngleSnapHelp => "While grid snap is active, segments snap to multiples of this angle (e.g. 15° or 45°).",
+        I18nKey::OptSnapMovedNodeToConnectionHelp => "When a single node is dropped near an existing connection (not on a node), the connection is split and the node is inserted in between.",
+        I18nKey::OptTerrainClippingTolerance => "Terrain Warning Tolerance:",
+        I18nKey::OptTerrainClippingToleranceHelp => "Connections whose straight line deviates from the heightmap terrain by more than this are marked with a warning glyph (0 = off).",
         I18nKey::OptSelectionSizeFactor => "Size Factor (%):",
         I18nKey::OptSelectionSizeFactorHelp => "Selected nodes are displayed scaled by this factor (100% = no scaling).",
         I18nKey::OptSelectionStyle => "Selection Style:",
@@ -71,6 +86,10 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptSegmentMaxAngle => "Max. Angle (°):",
         I18nKey::OptSegmentMaxAngleHelp => "Maximum angle deviation in degrees for double-click group detection. 0 = disabled.",
         I18nKey::OptSegmentDisabled => "(disabled)",
+        I18nKey::OptFloodSelectFollowDirection => "Shift+Double-Click follows direction only",
+        I18nKey::OptFloodSelectFollowDirectionHelp => "Shift+double-click selects every reachable node. When enabled, only connections in driving direction are followed; otherwise the whole connected network is selected.",
+        I18nKey::OptInvertSelectionVisibleOnly => "Invert selection within visible area only",
+        I18nKey::OptInvertSelectionVisibleOnlyHelp => "When enabled, \"Invert selection\" only toggles nodes inside the current view (excluding isolated/hidden nodes); nodes outside keep their state.",
         I18nKey::OptConnectionWidthMain => "Width Main Road:",
         I18nKey::OptConnectionWidthMainHelp => "Line thickness for connections with normal priority in world units.",
         I18nKey::OptConnectionWidthSubprio => "Width Side Road:",
@@ -86,6 +105,16 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::OptMarkerSizeHelp => "Size of the marker pin icon in world units.",
         I18nKey::OptMarkerColor => "Pin Color:",
         I18nKey::OptMarkerOutlineWidth => "Outline Width:",
+        I18nKey::OptShowSavegameVehicles => "Show Savegame Vehicles",
+        I18nKey::OptShowOwnedFarmlands => "Tint Owned Farmlands",
+        I18nKey::OptShowOwnedFarmlandsHelp => "Highlights the farmlands bought according to the savegame's farmland.xml in the viewport (requires farmland polygons).",
+        I18nKey::OptShowSavegameVehiclesHelp => "Shows the vehicles parked in the savegame's vehicles.xml with their names in the viewport.",
+        I18nKey::OptLiveLinkEnabled => "Enable live link",
+        I18nKey::OptLiveLinkEnabledHelp => "Listens on 127.0.0.1 for messages from the companion mod (player position) and can make the game reload the network without a restart.",
+        I18nKey::OptLiveLinkPort => "UDP port:",
+        I18nKey::OptLiveLinkPortHelp => "Local port the editor listens on. Must match the companion mod's setting.",
+        I18nKey::OptLiveRecordSpacing => "Recording spacing (m):",
+        I18nKey::OptLiveRecordSpacingHelp => "Distance between waypoints when recording a course while driving.",
         I18nKey::OptMarkerOutlineWidthHelp => "Stroke width of the marker outline as a fraction of the radius. Changes the SVG icon at runtime.",
         I18nKey::OptCameraZoomMin => "Min Zoom:",
         I18nKey::OptCameraZoomMinHelp => "Minimum zoom factor. Smaller values allow zooming out further.",
@@ -138,8 +167,17 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         // === Menü: Datei ===
         I18nKey::MenuFile => "File",
         I18nKey::MenuOpen => "Open...",
+        I18nKey::MenuOpenSavegame => "Open Savegame...",
+        I18nKey::MenuOpenSavegameHelp => "Pick a savegame from the FS25 profile and open its AutoDrive config",
         I18nKey::MenuSave => "Save",
         I18nKey::MenuSaveAs => "Save As...",
+        I18nKey::MenuExportModConfig => "Export as Mod Default Config",
+        I18nKey::MenuExportIntoMapZip => "Write into Map Mod ZIP...",
+        I18nKey::MenuExportIntoMapZipHelp => "Stores the network as AutoDrive_config.xml in the root of a map mod ZIP (backup as .zip.bak)",
+        I18nKey::MenuExportCoursesMod => "Export as Courses Mod...",
+        I18nKey::MenuExportCoursesModHelp => "Creates a standalone mod with modDesc.xml and AutoDrive_config.xml",
+        I18nKey::MenuExportPdf => "Export as PDF...",
+        I18nKey::MenuExportPdfHelp => "Prints the map across pages with legend, scale bar and optional grid",
         I18nKey::MenuSelectHeightmap => "Select Heightmap...",
         I18nKey::MenuChangeHeightmap => "Change Heightmap...",
         I18nKey::MenuClearHeightmap => "Clear Heightmap",
@@ -151,12 +189,17 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::MenuRedo => "Redo (Ctrl+Y / Shift+Cmd+Z)",
         I18nKey::MenuCopy => "Copy (Ctrl+C)",
         I18nKey::MenuPaste => "Paste (Ctrl+V)",
+        I18nKey::MenuDuplicate => "Duplicate (Ctrl+D)",
+        I18nKey::MenuMoveSelection => "Move selection by...",
+        I18nKey::MenuDeleteByFilter => "Delete by filter...",
+        I18nKey::MenuProjectOntoTerrain => "Project onto terrain",
         I18nKey::MenuOptions => "Options...",
         // === Menü: Ansicht ===
         I18nKey::MenuView => "View",
         I18nKey::MenuResetCamera => "Reset Camera",
         I18nKey::MenuZoomIn => "Zoom In",
         I18nKey::MenuZoomOut => "Zoom Out",
+        I18nKey::MenuIsolateSelection => "Isolate Selection",
         I18nKey::MenuLoadBackground => "Load Background...",
         I18nKey::MenuChangeBackground => "Change Background...",
         I18nKey::MenuBackgroundLayers => "Background Layers",
@@ -166,16 +209,39 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::MenuBgLayerFarmlandIds => "Farmland IDs",
         I18nKey::MenuBgLayerPoiMarkers => "POI Markers",
         I18nKey::MenuBgLayerLegend => "Legend",
+        I18nKey::MenuBackgroundSource => "Background Source",
+        I18nKey::MenuBgSourceOverview => "Overview Map",
+        I18nKey::MenuBgSourceHeightmapHillshade => "Heightmap (Hillshade)",
+        I18nKey::MenuBgSourceHeightmapGradient => "Heightmap (Elevation Colors)",
         I18nKey::MenuRenderQuality => "Render Quality",
         I18nKey::MenuQualityLow => "Low",
         I18nKey::MenuQualityMedium => "Medium",
         I18nKey::MenuQualityHigh => "High",
+        I18nKey::MenuDebug => "Debug",
+        I18nKey::MenuProfilingOverlay => "Performance HUD",
+        // === Performance HUD ===
+        I18nKey::ProfilingFrameTime => "Frame time",
+        I18nKey::ProfilingSceneBuild => "Scene build",
+        I18nKey::ProfilingUploaded => "Uploaded",
+        I18nKey::ProfilingDrawn => "Drawn",
+        I18nKey::ProfilingBackgroundTiles => "Background tiles",
+        I18nKey::ProfilingSpatialQueries => "Spatial queries (engine / renderer)",
         // === Menü: Extras ===
         I18nKey::MenuExtras => "Extras",
         I18nKey::MenuDetectField => "\u{1F33E} Detect Field",
         I18nKey::MenuTraceAllFields => "\u{1F4CD} Trace All Fields",
         I18nKey::MenuExtrasNeedBackground => "Load background with field boundaries first",
         I18nKey::MenuTraceAllFieldsHelp => "Automatically trace all detected fields with waypoints (one undo step)",
+        I18nKey::MenuFieldCourse => "\u{1F69C} Generate Field Course...",
+        I18nKey::MenuFieldCourseHelp => "Create a headland loop for one field and connect it to the nearest existing node (one undo step)",
+        I18nKey::MenuLiveRecordStart => "\u{23FA} Record Course",
+        I18nKey::MenuLiveRecordStop => "\u{23F9} Stop Recording",
+        I18nKey::MenuLiveRecordHelp => "Creates waypoints with one-way connections while driving in the game (one undo step)",
+        I18nKey::MenuLivePush => "\u{1F4E4} Send Network to Game",
+        I18nKey::MenuLivePushHelp => "Saves the config and lets the companion mod reload it without a restart",
+        I18nKey::MenuLiveNotConnected => "No game connected (enable the live link in the options)",
+        I18nKey::MenuAutoDriveRoutes => "\u{1F5C2} AutoDrive Routes...",
+        I18nKey::MenuAutoDriveRoutesHelp => "Import or export routes via the AutoDrive routes manager folder",
         I18nKey::MenuCurseplayImport => "Import Courseplay",
         I18nKey::MenuCurseplayExport => "Export Courseplay",
         I18nKey::MenuFieldPath => "\u{1F6E4} Detect Field Path",
@@ -200,12 +266,16 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::StatusHeightmapNone => "None",
         I18nKey::StatusSelectedNodes => "Selected Nodes",
         I18nKey::StatusExample => "e.g.",
+        I18nKey::StatusSelectionLength => "Length",
+        I18nKey::StatusSelectionBounds => "Extent",
         I18nKey::StatusTool => "Tool",
         I18nKey::StatusFps => "FPS",
         // === Werkzeug-Namen ===
         I18nKey::ToolNameSelect => "Select",
         I18nKey::ToolNameConnect => "Connect",
         I18nKey::ToolNameAddNode => "Add Node",
+        I18nKey::ToolNameKnife => "Knife",
+        I18nKey::ToolNameMeasure => "Measure",
         I18nKey::ToolNameRoute => "Route Tool",
         // === Sidebar: Sections ===
         I18nKey::SidebarTools => "Tools",
@@ -224,6 +294,8 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::ZoomInHelp => "Zoom In (+)",
         I18nKey::ZoomOutHelp => "Zoom Out (-)",
         // === Hintergrund ===
+        I18nKey::GridSnapEnable => "Enable grid and angle snap",
+        I18nKey::GridSnapDisable => "Disable grid and angle snap",
         I18nKey::BackgroundHide => "Hide background",
         I18nKey::BackgroundShow => "Show background",
         I18nKey::BackgroundScaleDown => "Halve extent",
@@ -238,20 +310,26 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::FloatingToolSelect => "Select",
         I18nKey::FloatingToolConnect => "Connect",
         I18nKey::FloatingToolAddNode => "Add Node",
+        I18nKey::FloatingToolKnife => "Knife",
+        I18nKey::FloatingToolMeasure => "Measure (ruler)",
         // === Floating-Menu: Grundbefehle ===
         I18nKey::FloatingBasicStraight => "Straight Route",
         I18nKey::FloatingBasicQuadratic => "Bezier Degree 2",
         I18nKey::FloatingBasicCubic => "Bezier Degree 3",
         I18nKey::FloatingBasicSpline => "Spline",
         I18nKey::FloatingBasicSmoothCurve => "Smooth Curve",
+        I18nKey::FloatingBasicClothoid => "Clothoid",
+        I18nKey::FloatingBasicArc => "Circular Arc",
         // === Floating-Menu: Bearbeiten ===
         I18nKey::FloatingEditBypass => "Bypass Route",
         I18nKey::FloatingEditParking => "Parking",
         I18nKey::FloatingEditRouteOffset => "Route Offset",
         I18nKey::FloatingEditRounding => "Rounding",
+        I18nKey::FloatingEditJunction => "Junction",
         I18nKey::FloatingAnalysisFieldBoundary => "Detect Field",
         I18nKey::FloatingAnalysisFieldPath => "Detect Field Path",
         I18nKey::FloatingAnalysisColorPath => "Detect Color Path",
+        I18nKey::FloatingAnalysisFieldCourse => "Generate Field Course",
         // === Floating-Menu: Richtung + Strassenart ===
         I18nKey::FloatingDirectionRegular => "One-way forward",
         I18nKey::FloatingDirectionDual => "Bidirectional",
@@ -279,12 +357,16 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::CtxRemoveFromGroup => "Remove from Group",
         I18nKey::CtxDissolveGroup => "Dissolve Group",
         I18nKey::CtxConnectNodes => "Connect Nodes",
+        I18nKey::CtxJoinChains => "Join chains",
+        I18nKey::CtxMergeNodes => "Merge nodes",
         I18nKey::CtxCreateRoute => "Create Route",
         I18nKey::CtxDirectionSubmenu => "Direction",
         I18nKey::CtxDirectionRegular => "One-way forward",
         I18nKey::CtxDirectionDual => "Bidirectional",
         I18nKey::CtxDirectionReverse => "One-way reverse",
         I18nKey::CtxDirectionInvert => "Invert",
+        I18nKey::CtxDirectionReversePath => "Reverse path",
+        I18nKey::CtxReverseChain => "Reverse chain",
         I18nKey::CtxPrioritySubmenu => "Road Type",
         I18nKey::CtxPriorityMain => "Main road",
         I18nKey::CtxPrioritySub => "Side road",
@@ -293,10 +375,25 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::CtxSelectionInvert => "Invert",
         I18nKey::CtxSelectAll => "Select all",
         I18nKey::CtxClearSelection => "Clear selection",
+        I18nKey::CtxSelectConnected => "Select everything reachable",
+        I18nKey::CtxConvertToRoundabout => "Convert to roundabout",
+        I18nKey::CtxSelectPathBetween => "Select path between",
+        I18nKey::CtxLockSelection => "Lock",
+        I18nKey::CtxUnlockSelection => "Unlock",
+        I18nKey::CtxSelectMainRoadNodes => "All main-road nodes",
+        I18nKey::CtxSelectSubPrioNodes => "All sub-priority nodes",
         I18nKey::CtxStreckenteilung => "Route splitting",
+        I18nKey::CtxEqualizeSpacing => "Equalize spacing",
+        I18nKey::CtxAlignSubmenu => "Align",
+        I18nKey::CtxAlignAxisX => "To X line (same Z)",
+        I18nKey::CtxAlignAxisZ => "To Z line (same X)",
+        I18nKey::CtxAlignBestFit => "To best-fit line",
+        I18nKey::CtxAlignEndpointLine => "To line between endpoints",
+        I18nKey::CtxDistributeEvenly => "Distribute evenly",
         I18nKey::CtxDeleteSelected => "Delete",
         I18nKey::CtxCopy => "Copy",
         I18nKey::CtxPaste => "Paste",
+        I18nKey::CtxDuplicate => "Duplicate",
         // === Command Palette ===
         I18nKey::PaletteSearchHint => "Enter command...",
         I18nKey::PaletteNoResults => "No results",
@@ -305,31 +402,43 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::PaletteUndo => "Undo",
         I18nKey::PaletteRedo => "Redo",
         I18nKey::PaletteSelectAll => "Select all",
+        I18nKey::PaletteGrowSelection => "Grow selection (neighbors)",
+        I18nKey::PaletteShrinkSelection => "Shrink selection (boundary)",
         I18nKey::PaletteDeleteSelected => "Delete selection",
         I18nKey::PaletteCopy => "Copy",
         I18nKey::PalettePaste => "Paste",
+        I18nKey::PaletteDuplicate => "Duplicate",
+        I18nKey::PaletteProjectOntoTerrain => "Project selection onto terrain",
         I18nKey::PaletteResetCamera => "Reset camera",
         I18nKey::PaletteToolSelect => "Select Tool",
         I18nKey::PaletteToolConnect => "Connect Tool",
         I18nKey::PaletteToolAddNode => "Add Node Tool",
+        I18nKey::PaletteToolKnife => "Knife Tool",
+        I18nKey::PaletteToolMeasure => "Measure Tool",
         I18nKey::PaletteRouteToolPrefix => "Route Tool:",
         I18nKey::MenuRouteTools => "Route Tools",
         // === Sidebar: LongPress-Tooltips ===
         I18nKey::LpToolSelect => "Select\nSelect nodes by click or lasso.\nDrag selected nodes to move them.\n(Key T)",
         I18nKey::LpToolConnect => "Connect\nCreate connection between 2 nodes (Key C)\nor delete (Key X). Arrow direction = travel direction.\n(Key T)",
         I18nKey::LpToolAddNode => "Add Node\nPlace a new waypoint by clicking the map.\n(Key T)",
+        I18nKey::LpToolKnife => "Knife\nDraw a line across the map: crossed connections are removed or split at the intersection (see options).\n(Key T)",
+        I18nKey::LpToolMeasure => "Measure (ruler)\nClick points on the map: per-segment length, total length and heading are shown, no nodes are created. Esc clears the measurement.\n(Key T)",
         I18nKey::LpStraight => "Straight Route\nPlace nodes at equal spacing along a line.\nArrow keys: adjust spacing/count. Enter: confirm.\n(G)",
         I18nKey::LpCurveQuad => "Bezier quadratic\n1 control point. Simple, smooth curve.\n(G)",
         I18nKey::LpCurveCubic => "Bezier cubic\n2 control points for maximum shape control.\n(G)",
         I18nKey::LpSpline => "Catmull-Rom Spline\nSmooth curve through existing nodes.\nAdditional intermediate points are calculated.\n(G)",
         I18nKey::LpSmoothCurve => "Smooth Curve\nAngle-smoothed route between two nodes.\nAutomatic alignment to road grid angles.\n(G)",
+        I18nKey::LpClothoid => "Clothoid\nCurvature-continuous transition between two directions.\nTangents are taken from connected connections.\n(G)",
+        I18nKey::LpArc => "Circular Arc\nConstant-radius arc through start, through and end point.\nSuited for evenly curved road bends.\n(G)",
         I18nKey::LpBypass => "Bypass Route\nCreates a parallel bypass route from the selection.\n(B)",
         I18nKey::LpParking => "Parking\nGenerates a parking layout from the selection.\n(B)",
         I18nKey::LpRouteOffset => "Route Offset\nShifts the selected route parallel by a configurable distance.\n(B)",
         I18nKey::LpRounding => "Rounding\nPrepares local route transitions for arc or quadratic modes.\n(B)",
+        I18nKey::LpJunction => "Junction\nConnects 3–4 selected approach endpoints with directed left, right and through lanes.\nTurn lanes are created as sub-priority routes.\n(B)",
         I18nKey::LpFieldBoundary => "Detect Field\nExtracts an editable field boundary from loaded farmland data.\nRequires farmland data.\n(A)",
         I18nKey::LpFieldPath => "Detect Field Path\nDerives an in-field driving path from farmland data.\nRequires farmland data.\n(A)",
         I18nKey::LpColorPath => "Detect Color Path\nDerives a path from the loaded background map via color segmentation.\nRequires a background image.\n(A)",
+        I18nKey::LpFieldCourse => "Generate Field Course\nFills a field with headland passes and serpentine lanes at a configurable working width.\nRequires farmland data.\n(A)",
         I18nKey::LpDirectionRegular => "One-way forward\nVehicles use these connections in forward direction only.\nDefault for normal directed routes.\n(R)",
         I18nKey::LpDirectionDual => "Bidirectional\nVehicles can travel in both directions.\nFor paths that should be traversable in both directions.\n(R)",
         I18nKey::LpDirectionReverse => "One-way reverse\nVehicles travel exclusively in reverse on this route.\n(R)",
@@ -338,6 +447,9 @@ pub(super) fn translate(key: I18nKey) -> &'static str {
         I18nKey::RouteToolNeedFarmland => "Load farmland data first",
         I18nKey::RouteToolNeedBackground => "Load a background map first",
         I18nKey::RouteToolNeedOrderedChain => "Select an ordered node chain",
+        I18nKey::RouteToolElevationProfile => "Elevation profile",
+        I18nKey::RouteToolGradeExceeded => "Grade limit exceeded",
+        I18nKey::TerrainClippingWarning => "Terrain deviation",
         I18nKey::ParkingHintRotate => "Alt+Scroll to rotate",
         I18nKey::ParkingHintFixed => "Position fixed \u{2014} click in viewport to move",
         I18nKey::ParkingHintAdjusting => "Click to fix \u{2014} Alt+Scroll to rotate",
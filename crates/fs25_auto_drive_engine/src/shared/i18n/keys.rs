@@ -89,6 +89,8 @@ pub enum I18nKey {
     OptSubSectionBackground,
     /// Unterabschnitt-Titel "Copy/Paste-Vorschau"
     OptSubSectionCopyPaste,
+    /// Unterabschnitt-Titel "Live-Verbindung zum Spiel"
+    OptSubSectionLiveLink,
     /// Unterabschnitt-Titel "Übersichtskarte (Standard-Layer)"
     OptSubSectionOverview,
 
@@ -127,6 +129,34 @@ pub enum I18nKey {
     OptMouseWheelDistStep,
     /// Tooltip: Mausrad-Schrittweite Distanz
     OptMouseWheelDistStepHelp,
+    /// Label: Steigungsgrenze fuer Route-Tool-Vorschauen
+    OptMaxRouteGrade,
+    /// Tooltip: Steigungsgrenze fuer Route-Tool-Vorschauen
+    OptMaxRouteGradeHelp,
+    /// Label: Radius fuer Kreisverkehr aus Kreuzung
+    OptRoundaboutRadius,
+    /// Tooltip: Radius fuer Kreisverkehr aus Kreuzung
+    OptRoundaboutRadiusHelp,
+    /// Label: Messer-Werkzeug teilt gekreuzte Verbindungen statt sie zu entfernen
+    OptKnifeSplitConnections,
+    /// Tooltip: Verhalten des Messer-Werkzeugs
+    OptKnifeSplitConnectionsHelp,
+    /// Label: Gezogenen Node beim Ablegen in nahe Verbindung einfuegen
+    OptSnapMovedNodeToConnection,
+    /// Tooltip: Einfuegen gezogener Nodes in Verbindungen
+    OptSnapMovedNodeToConnectionHelp,
+    /// Label: Rasterweite fuer Grid-Snap
+    OptGridSnapSize,
+    /// Tooltip: Rasterweite fuer Grid-Snap
+    OptGridSnapSizeHelp,
+    /// Label: Winkelraster fuer Segment-Richtungen
+    OptAngleSnap,
+    /// Tooltip: Winkelraster fuer Segment-Richtungen
+    OptAngleSnapHelp,
+    /// Label: Toleranz fuer Terrain-Warnungen an Verbindungen
+    OptTerrainClippingTolerance,
+    /// Tooltip: Toleranz fuer Terrain-Warnungen an Verbindungen
+    OptTerrainClippingToleranceHelp,
 
     // === Options-Dialog: Selektion (Unterabschnitt) ===
     /// Label: Selektions-Größenfaktor
@@ -153,6 +183,14 @@ pub enum I18nKey {
     OptSegmentMaxAngleHelp,
     /// Schwacher Hinweistext: Gruppenerkennung deaktiviert
     OptSegmentDisabled,
+    /// Checkbox: Flood-Selektion folgt nur der Fahrtrichtung
+    OptFloodSelectFollowDirection,
+    /// Tooltip: Flood-Selektion folgt nur der Fahrtrichtung
+    OptFloodSelectFollowDirectionHelp,
+    /// Checkbox: Auswahl invertieren nur im sichtbaren Bereich
+    OptInvertSelectionVisibleOnly,
+    /// Tooltip: Auswahl invertieren nur im sichtbaren Bereich
+    OptInvertSelectionVisibleOnlyHelp,
 
     // === Options-Dialog: Verbindungen ===
     /// Label: Breite Hauptstraße
@@ -189,6 +227,26 @@ pub enum I18nKey {
     OptMarkerOutlineWidth,
     /// Tooltip: Umrissstärke Marker
     OptMarkerOutlineWidthHelp,
+    /// Checkbox: Savegame-Fahrzeuge anzeigen
+    OptShowSavegameVehicles,
+    /// Tooltip: Savegame-Fahrzeuge anzeigen
+    OptShowSavegameVehiclesHelp,
+    /// Checkbox: Eigene Farmlands toenen
+    OptShowOwnedFarmlands,
+    /// Tooltip: Eigene Farmlands toenen
+    OptShowOwnedFarmlandsHelp,
+    /// Checkbox: Live-Verbindung aktivieren
+    OptLiveLinkEnabled,
+    /// Tooltip: Live-Verbindung aktivieren
+    OptLiveLinkEnabledHelp,
+    /// Label: UDP-Port der Live-Verbindung
+    OptLiveLinkPort,
+    /// Tooltip: UDP-Port der Live-Verbindung
+    OptLiveLinkPortHelp,
+    /// Label: Wegpunktabstand beim Aufzeichnen
+    OptLiveRecordSpacing,
+    /// Tooltip: Wegpunktabstand beim Aufzeichnen
+    OptLiveRecordSpacingHelp,
 
     // === Options-Dialog: Kamera ===
     /// Label: Minimaler Zoom
@@ -303,10 +361,28 @@ pub enum I18nKey {
     MenuFile,
     /// Menüeintrag "Öffnen…"
     MenuOpen,
+    /// Menüeintrag "Savegame öffnen…" (Auswahl aus dem FS25-Profil)
+    MenuOpenSavegame,
+    /// Hover-Tooltip: Savegame aus dem Profil waehlen statt die XML zu suchen
+    MenuOpenSavegameHelp,
     /// Menüeintrag "Speichern"
     MenuSave,
     /// Menüeintrag "Speichern unter…"
     MenuSaveAs,
+    /// Untermenue "Als Mod-Standardconfig exportieren"
+    MenuExportModConfig,
+    /// Menüeintrag "In Map-Mod-ZIP schreiben..."
+    MenuExportIntoMapZip,
+    /// Hover-Tooltip: Standard-Config in ein Map-Mod-ZIP schreiben
+    MenuExportIntoMapZipHelp,
+    /// Menüeintrag "Als Kurs-Mod exportieren..."
+    MenuExportCoursesMod,
+    /// Hover-Tooltip: Eigenstaendigen Kurs-Mod exportieren
+    MenuExportCoursesModHelp,
+    /// Menüeintrag "Als PDF exportieren..."
+    MenuExportPdf,
+    /// Hover-Tooltip: Karte mehrseitig als PDF drucken
+    MenuExportPdfHelp,
     /// Menüeintrag "Höhenkarte auswählen…"
     MenuSelectHeightmap,
     /// Menüeintrag "Höhenkarte ändern…"
@@ -329,6 +405,14 @@ pub enum I18nKey {
     MenuCopy,
     /// Menüeintrag "Einfügen (Ctrl+V)"
     MenuPaste,
+    /// Menüeintrag "Duplizieren (Ctrl+D)"
+    MenuDuplicate,
+    /// Menüeintrag "Verschieben um…"
+    MenuMoveSelection,
+    /// Menüeintrag "Löschen nach Filter…"
+    MenuDeleteByFilter,
+    /// Menüeintrag "Auf Gelände projizieren"
+    MenuProjectOntoTerrain,
     /// Menüeintrag "Optionen…"
     MenuOptions,
 
@@ -341,6 +425,8 @@ pub enum I18nKey {
     MenuZoomIn,
     /// Menüeintrag "Verkleinern"
     MenuZoomOut,
+    /// Menüeintrag "Auswahl isolieren" (Umschalter)
+    MenuIsolateSelection,
     /// Menüeintrag "Hintergrund laden…"
     MenuLoadBackground,
     /// Menüeintrag "Hintergrund ändern…"
@@ -359,6 +445,14 @@ pub enum I18nKey {
     MenuBgLayerPoiMarkers,
     /// Menüeintrag: Legende
     MenuBgLayerLegend,
+    /// Untermenü "Hintergrundquelle"
+    MenuBackgroundSource,
+    /// Menüeintrag: Übersichtskarte als Hintergrund
+    MenuBgSourceOverview,
+    /// Menüeintrag: Heightmap als Schattierung
+    MenuBgSourceHeightmapHillshade,
+    /// Menüeintrag: Heightmap mit Höhenfarben
+    MenuBgSourceHeightmapGradient,
     /// Untermenü "Renderqualität"
     MenuRenderQuality,
     /// Qualitätsstufe "Niedrig"
@@ -367,6 +461,24 @@ pub enum I18nKey {
     MenuQualityMedium,
     /// Qualitätsstufe "Hoch"
     MenuQualityHigh,
+    /// Untermenü "Debug"
+    MenuDebug,
+    /// Checkbox: Performance-HUD ein-/ausblenden
+    MenuProfilingOverlay,
+
+    // === Performance-HUD ===
+    /// HUD-Zeile: Frame-Zeit
+    ProfilingFrameTime,
+    /// HUD-Zeile: Aufbau der Render-Szene
+    ProfilingSceneBuild,
+    /// HUD-Spalte: hochgeladene Instanzen/Vertices
+    ProfilingUploaded,
+    /// HUD-Spalte: gezeichnete Instanzen/Vertices
+    ProfilingDrawn,
+    /// HUD-Zeile: gezeichnete Background-Kacheln
+    ProfilingBackgroundTiles,
+    /// HUD-Zeile: Spatial-Abfragen pro Frame
+    ProfilingSpatialQueries,
 
     // === Menü: Extras ===
     /// Menüeintrag "Extras"
@@ -379,6 +491,26 @@ pub enum I18nKey {
     MenuExtrasNeedBackground,
     /// Hover-Tooltip: Alle Felder nachzeichnen (Beschreibung)
     MenuTraceAllFieldsHelp,
+    /// Menüeintrag "Feld-Rundkurs erzeugen"
+    MenuFieldCourse,
+    /// Hover-Tooltip: Feld-Rundkurs erzeugen (Beschreibung)
+    MenuFieldCourseHelp,
+    /// Menüeintrag "Kurs aufzeichnen" (Live-Verbindung)
+    MenuLiveRecordStart,
+    /// Menüeintrag "Aufzeichnung beenden" (Live-Verbindung)
+    MenuLiveRecordStop,
+    /// Hover-Tooltip: Kurs waehrend der Fahrt aufzeichnen
+    MenuLiveRecordHelp,
+    /// Menüeintrag "Netz ans Spiel senden"
+    MenuLivePush,
+    /// Hover-Tooltip: Netz ans Spiel senden
+    MenuLivePushHelp,
+    /// Disabled-Tooltip: Kein Spiel ueber die Live-Verbindung verbunden
+    MenuLiveNotConnected,
+    /// Menüeintrag "AutoDrive-Routen" (Routes-Manager-Ordner)
+    MenuAutoDriveRoutes,
+    /// Hover-Tooltip: Routen mit dem Routes-Manager austauschen
+    MenuAutoDriveRoutesHelp,
     /// Menüeintrag "Courseplay importieren"
     MenuCurseplayImport,
     /// Menüeintrag "Courseplay exportieren"
@@ -417,6 +549,10 @@ pub enum I18nKey {
     StatusSelectedNodes,
     /// Abkürzung "z. B." für Status-Beispielwert
     StatusExample,
+    /// Status-Label "Länge" (Verbindungslänge der Selektion)
+    StatusSelectionLength,
+    /// Status-Label "Ausdehnung" (Bounding Box der Selektion)
+    StatusSelectionBounds,
     /// Status-Label "Werkzeug"
     StatusTool,
     /// Status-Label "FPS"
@@ -429,6 +565,10 @@ pub enum I18nKey {
     ToolNameConnect,
     /// Werkzeugname "Knoten hinzufügen"
     ToolNameAddNode,
+    /// Werkzeugname "Messer"
+    ToolNameKnife,
+    /// Werkzeugname "Messen"
+    ToolNameMeasure,
     /// Werkzeugname "Routen-Werkzeug"
     ToolNameRoute,
 
@@ -464,6 +604,12 @@ pub enum I18nKey {
     /// Tooltip "Herauszoomen (-)"
     ZoomOutHelp,
 
+    // === Raster-Snap ===
+    /// Button-Tooltip "Raster- und Winkel-Snap einschalten"
+    GridSnapEnable,
+    /// Button-Tooltip "Raster- und Winkel-Snap ausschalten"
+    GridSnapDisable,
+
     // === Hintergrund ===
     /// Button-Tooltip "Hintergrund ausblenden"
     BackgroundHide,
@@ -493,6 +639,10 @@ pub enum I18nKey {
     FloatingToolConnect,
     /// Tooltip "Node hinzufügen"
     FloatingToolAddNode,
+    /// Tooltip "Messer"
+    FloatingToolKnife,
+    /// Tooltip "Messen"
+    FloatingToolMeasure,
 
     // === Floating-Menu: Grundbefehle ===
     /// Tooltip "Gerade Strecke"
@@ -505,6 +655,10 @@ pub enum I18nKey {
     FloatingBasicSpline,
     /// Tooltip "Geglaettete Kurve"
     FloatingBasicSmoothCurve,
+    /// Tooltip "Klothoide"
+    FloatingBasicClothoid,
+    /// Tooltip "Kreisbogen"
+    FloatingBasicArc,
 
     // === Floating-Menu: Bearbeiten ===
     /// Tooltip "Ausweichstrecke"
@@ -515,12 +669,16 @@ pub enum I18nKey {
     FloatingEditRouteOffset,
     /// Tooltip "Verrunden"
     FloatingEditRounding,
+    /// Tooltip "Kreuzung"
+    FloatingEditJunction,
     /// Tooltip "Feld erkennen"
     FloatingAnalysisFieldBoundary,
     /// Tooltip "Feldweg erkennen"
     FloatingAnalysisFieldPath,
     /// Tooltip "Farb-Pfad erkennen"
     FloatingAnalysisColorPath,
+    /// Tooltip "Feldkurs erzeugen"
+    FloatingAnalysisFieldCourse,
 
     // === Floating-Menu: Richtung + Strassenart ===
     /// Tooltip "Einbahn vorwaerts"
@@ -575,6 +733,10 @@ pub enum I18nKey {
     CtxDissolveGroup,
     /// Eintrag "Nodes verbinden"
     CtxConnectNodes,
+    /// Eintrag "Ketten verbinden"
+    CtxJoinChains,
+    /// Eintrag "Nodes zusammenfuehren"
+    CtxMergeNodes,
     /// Eintrag "Strecke erzeugen"
     CtxCreateRoute,
     /// Submenu-Titel "Richtung"
@@ -587,6 +749,10 @@ pub enum I18nKey {
     CtxDirectionReverse,
     /// Eintrag "Invertieren"
     CtxDirectionInvert,
+    /// Eintrag "Pfad umkehren"
+    CtxDirectionReversePath,
+    /// Eintrag "Kette umkehren"
+    CtxReverseChain,
     /// Submenu-Titel "Strassenart"
     CtxPrioritySubmenu,
     /// Eintrag "Hauptstrasse"
@@ -603,14 +769,44 @@ pub enum I18nKey {
     CtxSelectAll,
     /// Eintrag "Auswahl aufheben"
     CtxClearSelection,
+    /// Eintrag "Alles Erreichbare auswaehlen"
+    CtxSelectConnected,
+    /// Eintrag "In Kreisverkehr umwandeln" (Kreuzungs-Node)
+    CtxConvertToRoundabout,
+    /// Eintrag "Pfad dazwischen auswaehlen"
+    CtxSelectPathBetween,
+    /// Eintrag "Sperren" (Nodes gegen Verschieben/Loeschen schuetzen)
+    CtxLockSelection,
+    /// Eintrag "Entsperren"
+    CtxUnlockSelection,
+    /// Eintrag "Alle Hauptstrassen-Nodes"
+    CtxSelectMainRoadNodes,
+    /// Eintrag "Alle Nebenstrassen-Nodes"
+    CtxSelectSubPrioNodes,
     /// Eintrag "Streckenteilung"
     CtxStreckenteilung,
+    /// Eintrag "Gleiche Abstaende"
+    CtxEqualizeSpacing,
+    /// Untermenue "Ausrichten"
+    CtxAlignSubmenu,
+    /// Eintrag "Auf X-Linie (gleiches Z)"
+    CtxAlignAxisX,
+    /// Eintrag "Auf Z-Linie (gleiches X)"
+    CtxAlignAxisZ,
+    /// Eintrag "Auf Ausgleichsgerade"
+    CtxAlignBestFit,
+    /// Eintrag "Auf Gerade zwischen Endpunkten"
+    CtxAlignEndpointLine,
+    /// Eintrag "Gleichmaessig verteilen"
+    CtxDistributeEvenly,
     /// Eintrag "Loeschen"
     CtxDeleteSelected,
     /// Eintrag "Kopieren"
     CtxCopy,
     /// Eintrag "Einfuegen"
     CtxPaste,
+    /// Eintrag "Duplizieren"
+    CtxDuplicate,
 
     // === Command Palette ===
     /// Placeholder "Befehl eingeben..."
@@ -627,12 +823,20 @@ pub enum I18nKey {
     PaletteRedo,
     /// Eintrag "Alles auswaehlen"
     PaletteSelectAll,
+    /// Eintrag "Auswahl erweitern"
+    PaletteGrowSelection,
+    /// Eintrag "Auswahl verkleinern"
+    PaletteShrinkSelection,
     /// Eintrag "Auswahl loeschen"
     PaletteDeleteSelected,
     /// Eintrag "Kopieren"
     PaletteCopy,
     /// Eintrag "Einfuegen"
     PalettePaste,
+    /// Eintrag "Duplizieren"
+    PaletteDuplicate,
+    /// Eintrag "Auswahl auf Gelaende projizieren"
+    PaletteProjectOntoTerrain,
     /// Eintrag "Kamera zuruecksetzen"
     PaletteResetCamera,
     /// Eintrag "Select-Tool"
@@ -641,6 +845,10 @@ pub enum I18nKey {
     PaletteToolConnect,
     /// Eintrag "Add-Node-Tool"
     PaletteToolAddNode,
+    /// Eintrag "Messer-Tool"
+    PaletteToolKnife,
+    /// Eintrag "Mess-Tool"
+    PaletteToolMeasure,
     /// Praefix "Route-Tool:"
     PaletteRouteToolPrefix,
     /// Menueeintrag "Route-Tools"
@@ -653,6 +861,10 @@ pub enum I18nKey {
     LpToolConnect,
     /// LongPress-Tooltip fuer AddNode-Tool
     LpToolAddNode,
+    /// LongPress-Tooltip fuer Messer-Tool
+    LpToolKnife,
+    /// LongPress-Tooltip fuer Mess-Tool
+    LpToolMeasure,
     /// LongPress-Tooltip fuer Gerade Strecke
     LpStraight,
     /// LongPress-Tooltip fuer Bezier quadratisch
@@ -663,6 +875,10 @@ pub enum I18nKey {
     LpSpline,
     /// LongPress-Tooltip fuer Geglaettete Kurve
     LpSmoothCurve,
+    /// LongPress-Tooltip fuer Klothoide
+    LpClothoid,
+    /// LongPress-Tooltip fuer Kreisbogen
+    LpArc,
     /// LongPress-Tooltip fuer Ausweichstrecke
     LpBypass,
     /// LongPress-Tooltip fuer Parkplatz
@@ -671,12 +887,16 @@ pub enum I18nKey {
     LpRouteOffset,
     /// LongPress-Tooltip fuer Verrunden
     LpRounding,
+    /// LongPress-Tooltip fuer Kreuzung
+    LpJunction,
     /// LongPress-Tooltip fuer Feld erkennen
     LpFieldBoundary,
     /// LongPress-Tooltip fuer Feldweg erkennen
     LpFieldPath,
     /// LongPress-Tooltip fuer Farb-Pfad erkennen
     LpColorPath,
+    /// LongPress-Tooltip fuer Feldkurs erzeugen
+    LpFieldCourse,
     /// LongPress-Tooltip fuer Einbahn vorwaerts
     LpDirectionRegular,
     /// LongPress-Tooltip fuer Zweirichtung
@@ -693,6 +913,12 @@ pub enum I18nKey {
     RouteToolNeedBackground,
     /// Disabled-Text fuer fehlende geordnete Kette
     RouteToolNeedOrderedChain,
+    /// Ueberschrift des Hoehenprofils im Route-Tool-Panel
+    RouteToolElevationProfile,
+    /// Warnung bei ueberschrittener Steigungsgrenze
+    RouteToolGradeExceeded,
+    /// Tooltip des Terrain-Warnsymbols an Verbindungen
+    TerrainClippingWarning,
 
     // === Bestaetigungsdialog: Gruppe aufloesen ===
     /// Bestätigungsdialog: Titel
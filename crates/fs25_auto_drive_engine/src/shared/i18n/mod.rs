@@ -116,6 +116,7 @@ mod tests {
             I18nKey::OptSubSectionLodDesc,
             I18nKey::OptSubSectionBackground,
             I18nKey::OptSubSectionCopyPaste,
+            I18nKey::OptSubSectionLiveLink,
             I18nKey::OptSubSectionOverview,
             I18nKey::OptNodeSizeWorld,
             I18nKey::OptNodeSizeWorldHelp,
@@ -133,6 +134,20 @@ mod tests {
             I18nKey::OptSnapRadiusHelp,
             I18nKey::OptMouseWheelDistStep,
             I18nKey::OptMouseWheelDistStepHelp,
+            I18nKey::OptMaxRouteGrade,
+            I18nKey::OptMaxRouteGradeHelp,
+            I18nKey::OptRoundaboutRadius,
+            I18nKey::OptRoundaboutRadiusHelp,
+            I18nKey::OptKnifeSplitConnections,
+            I18nKey::OptKnifeSplitConnectionsHelp,
+            I18nKey::OptSnapMovedNodeToConnection,
+            I18nKey::OptSnapMovedNodeToConnectionHelp,
+            I18nKey::OptGridSnapSize,
+            I18nKey::OptGridSnapSizeHelp,
+            I18nKey::OptAngleSnap,
+            I18nKey::OptAngleSnapHelp,
+            I18nKey::OptTerrainClippingTolerance,
+            I18nKey::OptTerrainClippingToleranceHelp,
             I18nKey::OptSelectionSizeFactor,
             I18nKey::OptSelectionSizeFactorHelp,
             I18nKey::OptSelectionStyle,
@@ -145,6 +160,10 @@ mod tests {
             I18nKey::OptSegmentMaxAngle,
             I18nKey::OptSegmentMaxAngleHelp,
             I18nKey::OptSegmentDisabled,
+            I18nKey::OptFloodSelectFollowDirection,
+            I18nKey::OptFloodSelectFollowDirectionHelp,
+            I18nKey::OptInvertSelectionVisibleOnly,
+            I18nKey::OptInvertSelectionVisibleOnlyHelp,
             I18nKey::OptConnectionWidthMain,
             I18nKey::OptConnectionWidthMainHelp,
             I18nKey::OptConnectionWidthSubprio,
@@ -161,6 +180,16 @@ mod tests {
             I18nKey::OptMarkerColor,
             I18nKey::OptMarkerOutlineWidth,
             I18nKey::OptMarkerOutlineWidthHelp,
+            I18nKey::OptShowSavegameVehicles,
+            I18nKey::OptShowSavegameVehiclesHelp,
+            I18nKey::OptShowOwnedFarmlands,
+            I18nKey::OptShowOwnedFarmlandsHelp,
+            I18nKey::OptLiveLinkEnabled,
+            I18nKey::OptLiveLinkEnabledHelp,
+            I18nKey::OptLiveLinkPort,
+            I18nKey::OptLiveLinkPortHelp,
+            I18nKey::OptLiveRecordSpacing,
+            I18nKey::OptLiveRecordSpacingHelp,
             I18nKey::OptCameraZoomMin,
             I18nKey::OptCameraZoomMinHelp,
             I18nKey::OptCameraZoomMax,
@@ -211,8 +240,17 @@ mod tests {
             I18nKey::OptCopyPastePreviewOpacityHelp,
             I18nKey::MenuFile,
             I18nKey::MenuOpen,
+            I18nKey::MenuOpenSavegame,
+            I18nKey::MenuOpenSavegameHelp,
             I18nKey::MenuSave,
             I18nKey::MenuSaveAs,
+            I18nKey::MenuExportModConfig,
+            I18nKey::MenuExportIntoMapZip,
+            I18nKey::MenuExportIntoMapZipHelp,
+            I18nKey::MenuExportCoursesMod,
+            I18nKey::MenuExportCoursesModHelp,
+            I18nKey::MenuExportPdf,
+            I18nKey::MenuExportPdfHelp,
             I18nKey::MenuSelectHeightmap,
             I18nKey::MenuChangeHeightmap,
             I18nKey::MenuClearHeightmap,
@@ -223,11 +261,16 @@ mod tests {
             I18nKey::MenuRedo,
             I18nKey::MenuCopy,
             I18nKey::MenuPaste,
+            I18nKey::MenuDuplicate,
+            I18nKey::MenuMoveSelection,
+            I18nKey::MenuDeleteByFilter,
+            I18nKey::MenuProjectOntoTerrain,
             I18nKey::MenuOptions,
             I18nKey::MenuView,
             I18nKey::MenuResetCamera,
             I18nKey::MenuZoomIn,
             I18nKey::MenuZoomOut,
+            I18nKey::MenuIsolateSelection,
             I18nKey::MenuLoadBackground,
             I18nKey::MenuChangeBackground,
             I18nKey::MenuBackgroundLayers,
@@ -237,15 +280,37 @@ mod tests {
             I18nKey::MenuBgLayerFarmlandIds,
             I18nKey::MenuBgLayerPoiMarkers,
             I18nKey::MenuBgLayerLegend,
+            I18nKey::MenuBackgroundSource,
+            I18nKey::MenuBgSourceOverview,
+            I18nKey::MenuBgSourceHeightmapHillshade,
+            I18nKey::MenuBgSourceHeightmapGradient,
             I18nKey::MenuRenderQuality,
             I18nKey::MenuQualityLow,
             I18nKey::MenuQualityMedium,
             I18nKey::MenuQualityHigh,
+            I18nKey::MenuDebug,
+            I18nKey::MenuProfilingOverlay,
+            I18nKey::ProfilingFrameTime,
+            I18nKey::ProfilingSceneBuild,
+            I18nKey::ProfilingUploaded,
+            I18nKey::ProfilingDrawn,
+            I18nKey::ProfilingBackgroundTiles,
+            I18nKey::ProfilingSpatialQueries,
             I18nKey::MenuExtras,
             I18nKey::MenuDetectField,
             I18nKey::MenuTraceAllFields,
             I18nKey::MenuExtrasNeedBackground,
             I18nKey::MenuTraceAllFieldsHelp,
+            I18nKey::MenuFieldCourse,
+            I18nKey::MenuFieldCourseHelp,
+            I18nKey::MenuLiveRecordStart,
+            I18nKey::MenuLiveRecordStop,
+            I18nKey::MenuLiveRecordHelp,
+            I18nKey::MenuLivePush,
+            I18nKey::MenuLivePushHelp,
+            I18nKey::MenuLiveNotConnected,
+            I18nKey::MenuAutoDriveRoutes,
+            I18nKey::MenuAutoDriveRoutesHelp,
             I18nKey::MenuHelp,
             I18nKey::MenuAbout,
             I18nKey::StatusNoFile,
@@ -259,11 +324,15 @@ mod tests {
             I18nKey::StatusHeightmapNone,
             I18nKey::StatusSelectedNodes,
             I18nKey::StatusExample,
+            I18nKey::StatusSelectionLength,
+            I18nKey::StatusSelectionBounds,
             I18nKey::StatusTool,
             I18nKey::StatusFps,
             I18nKey::ToolNameSelect,
             I18nKey::ToolNameConnect,
             I18nKey::ToolNameAddNode,
+            I18nKey::ToolNameKnife,
+            I18nKey::ToolNameMeasure,
             I18nKey::ToolNameRoute,
         ];
 
@@ -15,6 +15,8 @@ pub mod geometry;
 pub mod i18n;
 /// Konfigurationsoptionen (EditorOptions, RenderQuality, Farben, Kamera-Parameter).
 pub mod options;
+/// Optionen und Seitenlayout des PDF-Kartenexports.
+pub mod pdf_export;
 mod render_assets;
 mod render_quality;
 mod render_scene;
@@ -23,11 +25,12 @@ pub mod spline_geometry;
 /// Gemeinsame Route-Tool-Gruppenklassifikation.
 mod tool_group;
 
-pub use background_layers::{BackgroundLayerKind, OverviewFieldDetectionSource};
+pub use background_layers::{BackgroundLayerKind, BackgroundSource, OverviewFieldDetectionSource};
 pub use dialog_state::{
-    DedupDialogState, DistanzenState, GroupSettingsPopupState, MarkerDialogState,
-    OverviewOptionsDialogState, OverviewSourceContext, PostLoadDialogState,
-    SaveOverviewDialogState, TraceAllFieldsDialogState,
+    DedupDialogState, DeleteByFilterDialogState, DistanzenState, FieldCourseDialogState,
+    GroupSettingsPopupState, MarkerDialogState, MoveSelectionDialogState, NodeDeleteCandidate,
+    NodeDeleteFilter, OverviewOptionsDialogState, OverviewSourceContext, PathOffsetState,
+    PdfExportDialogState, PostLoadDialogState, SaveOverviewDialogState, TraceAllFieldsDialogState,
 };
 pub use floating_menu::{FloatingMenuKind, FloatingMenuState};
 pub use geometry::angle_deviation;
@@ -37,6 +40,7 @@ pub use options::OverviewLayerOptions;
 pub use options::SelectionStyle;
 pub use options::ValueAdjustInputMode;
 pub use options::{SNAP_SCALE_PERCENT, TERRAIN_HEIGHT_SCALE};
+pub use pdf_export::{PdfExportOptions, PdfPaperSize};
 pub use render_assets::{
     RenderAssetSnapshot, RenderAssetsSnapshot, RenderBackgroundAssetSnapshot,
     RenderBackgroundWorldBounds,
@@ -45,6 +49,7 @@ pub use render_quality::RenderQuality;
 pub use render_scene::RenderScene;
 pub use render_scene::{
     RenderCamera, RenderConnection, RenderConnectionDirection, RenderConnectionPriority, RenderMap,
-    RenderMarker, RenderNode, RenderNodeKind, RenderSceneFrameData,
+    RenderMapDirty, RenderMapRevisions, RenderMarker, RenderNode, RenderNodeKind,
+    RenderSceneFrameData,
 };
 pub use tool_group::RouteToolGroup;
@@ -8,10 +8,12 @@ use super::render::{
     MARKER_COLOR, MARKER_OUTLINE_COLOR, MARKER_OUTLINE_WIDTH, MARKER_SIZE_WORLD, MIN_ARROW_SIZE_PX,
     MIN_CONNECTION_WIDTH_PX, MIN_MARKER_SIZE_PX, MIN_NODE_SIZE_PX, NODE_COLOR_DEFAULT,
     NODE_COLOR_SELECTED, NODE_COLOR_SUBPRIO, NODE_COLOR_WARNING, NODE_DECIMATION_SPACING_PX,
-    NODE_SIZE_WORLD, SELECTION_SIZE_FACTOR, TERRAIN_HEIGHT_SCALE,
+    NODE_SIZE_WORLD, SELECTION_SIZE_FACTOR, TERRAIN_CLIPPING_TOLERANCE_M, TERRAIN_HEIGHT_SCALE,
 };
 use super::tools::{
-    ValueAdjustInputMode, HITBOX_SCALE_PERCENT, MOUSE_WHEEL_DISTANCE_STEP_M, SNAP_SCALE_PERCENT,
+    ValueAdjustInputMode, ANGLE_SNAP_DEGREES, GRID_SNAP_SIZE_M, HITBOX_SCALE_PERCENT,
+    LIVE_LINK_PORT, LIVE_RECORD_SPACING_M, MAX_ROUTE_GRADE_PERCENT, MOUSE_WHEEL_DISTANCE_STEP_M,
+    ROUNDABOUT_RADIUS_M, SNAP_SCALE_PERCENT,
 };
 use crate::shared::background_layers::OverviewFieldDetectionSource;
 use crate::shared::i18n::Language;
@@ -37,6 +39,12 @@ pub struct EditorOptions {
     /// Doppelklick-Segment: Max. Winkelabweichung in Grad (0 = nicht pruefen).
     #[serde(default = "default_segment_max_angle_deg")]
     pub segment_max_angle_deg: f32,
+    /// Flood-Selektion (Shift+Doppelklick): Nur Verbindungen in Fahrtrichtung folgen.
+    #[serde(default)]
+    pub flood_select_follow_direction: bool,
+    /// Auswahl invertieren: Nur sichtbare Nodes (Viewport, nicht isoliert) umschalten.
+    #[serde(default)]
+    pub invert_selection_visible_only: bool,
 
     // Connections
     pub connection_thickness_world: f32,
@@ -76,9 +84,33 @@ pub struct EditorOptions {
     pub reconnect_on_delete: bool,
     #[serde(default)]
     pub split_connection_on_place: bool,
+    /// Steigungsgrenze in Prozent; steilere Abschnitte der Route-Tool-Vorschau werden gewarnt.
+    #[serde(default = "default_max_route_grade_percent")]
+    pub max_route_grade_percent: f32,
+    /// Radius in Metern fuer "Kreuzung in Kreisverkehr umwandeln".
+    #[serde(default = "default_roundabout_radius_m")]
+    pub roundabout_radius_m: f32,
+    /// true = Messer-Werkzeug teilt gekreuzte Verbindungen am Schnittpunkt, false = entfernt sie.
+    #[serde(default)]
+    pub knife_split_connections: bool,
+    /// true = ein einzeln gezogener Node wird beim Ablegen nahe einer Verbindung in diese eingefuegt.
+    #[serde(default = "default_snap_moved_node_to_connection")]
+    pub snap_moved_node_to_connection: bool,
+    /// true = Platzieren, Ziehen und Route-Tools rasten auf Raster und Winkelraster ein.
+    #[serde(default)]
+    pub grid_snap_enabled: bool,
+    /// Rasterweite in Metern fuer Grid-Snap (auch Laengenraster bei Winkel-Snap).
+    #[serde(default = "default_grid_snap_size")]
+    pub grid_snap_size: f32,
+    /// Winkelraster in Grad fuer Segment-Richtungen (z.B. 15 oder 45).
+    #[serde(default = "default_angle_snap_degrees")]
+    pub angle_snap_degrees: f32,
 
     // Terrain
     pub terrain_height_scale: f32,
+    /// Warnschwelle in Metern fuer Terrain-Abweichungen entlang von Verbindungen (0 = aus).
+    #[serde(default = "default_terrain_clipping_tolerance_m")]
+    pub terrain_clipping_tolerance_m: f32,
 
     // Hintergrund
     #[serde(default = "default_bg_opacity")]
@@ -101,6 +133,25 @@ pub struct EditorOptions {
     #[serde(default)]
     pub show_all_group_boundaries: bool,
 
+    // Savegame-Overlay
+    /// Fahrzeuge aus der `vehicles.xml` des Savegames im Viewport anzeigen.
+    #[serde(default = "default_show_savegame_vehicles")]
+    pub show_savegame_vehicles: bool,
+    /// Gekaufte Farmlands aus der `farmland.xml` des Savegames im Viewport toenen.
+    #[serde(default = "default_show_owned_farmlands")]
+    pub show_owned_farmlands: bool,
+
+    // Live-Verbindung
+    /// UDP-Listener fuer den Companion-Mod im laufenden Spiel aktivieren.
+    #[serde(default)]
+    pub live_link_enabled: bool,
+    /// Lokaler UDP-Port, auf dem der Editor Nachrichten des Companion-Mods empfaengt.
+    #[serde(default = "default_live_link_port")]
+    pub live_link_port: u16,
+    /// Abstand zwischen Wegpunkten beim Aufzeichnen waehrend der Fahrt in Metern.
+    #[serde(default = "default_live_record_spacing_m")]
+    pub live_record_spacing_m: f32,
+
     // Uebersichtskarte
     #[serde(default)]
     pub overview_layers: OverviewLayerOptions,
@@ -149,6 +200,8 @@ impl Default for EditorOptions {
             selection_style: SelectionStyle::default(),
             segment_stop_at_junction: default_segment_stop_at_junction(),
             segment_max_angle_deg: default_segment_max_angle_deg(),
+            flood_select_follow_direction: false,
+            invert_selection_visible_only: false,
             connection_thickness_world: CONNECTION_THICKNESS_WORLD,
             connection_thickness_subprio_world: CONNECTION_THICKNESS_SUBPRIO_WORLD,
             arrow_length_world: ARROW_LENGTH_WORLD,
@@ -170,13 +223,26 @@ impl Default for EditorOptions {
             value_adjust_input_mode: ValueAdjustInputMode::default(),
             reconnect_on_delete: true,
             split_connection_on_place: true,
+            max_route_grade_percent: MAX_ROUTE_GRADE_PERCENT,
+            roundabout_radius_m: ROUNDABOUT_RADIUS_M,
+            knife_split_connections: false,
+            snap_moved_node_to_connection: true,
+            grid_snap_enabled: false,
+            grid_snap_size: GRID_SNAP_SIZE_M,
+            angle_snap_degrees: ANGLE_SNAP_DEGREES,
             terrain_height_scale: TERRAIN_HEIGHT_SCALE,
+            terrain_clipping_tolerance_m: TERRAIN_CLIPPING_TOLERANCE_M,
             bg_opacity: 1.0,
             bg_opacity_at_min_zoom: 0.0,
             bg_fade_start_zoom: 3.5,
             copy_preview_opacity: default_copy_preview_opacity(),
             segment_lock_icon_size_px: default_segment_lock_icon_size_px(),
             show_all_group_boundaries: false,
+            show_savegame_vehicles: true,
+            show_owned_farmlands: true,
+            live_link_enabled: false,
+            live_link_port: LIVE_LINK_PORT,
+            live_record_spacing_m: LIVE_RECORD_SPACING_M,
             overview_layers: OverviewLayerOptions::default(),
             overview_field_detection_source: OverviewFieldDetectionSource::default(),
             zoom_compensation_max: DEFAULT_ZOOM_COMPENSATION_MAX,
@@ -190,6 +256,26 @@ impl Default for EditorOptions {
     }
 }
 
+fn default_snap_moved_node_to_connection() -> bool {
+    true
+}
+
+fn default_show_savegame_vehicles() -> bool {
+    true
+}
+
+fn default_show_owned_farmlands() -> bool {
+    true
+}
+
+fn default_live_link_port() -> u16 {
+    LIVE_LINK_PORT
+}
+
+fn default_live_record_spacing_m() -> f32 {
+    LIVE_RECORD_SPACING_M
+}
+
 fn default_segment_stop_at_junction() -> bool {
     true
 }
@@ -218,6 +304,26 @@ fn default_mouse_wheel_distance_step_m() -> f32 {
     MOUSE_WHEEL_DISTANCE_STEP_M
 }
 
+fn default_terrain_clipping_tolerance_m() -> f32 {
+    TERRAIN_CLIPPING_TOLERANCE_M
+}
+
+fn default_max_route_grade_percent() -> f32 {
+    MAX_ROUTE_GRADE_PERCENT
+}
+
+fn default_roundabout_radius_m() -> f32 {
+    ROUNDABOUT_RADIUS_M
+}
+
+fn default_grid_snap_size() -> f32 {
+    GRID_SNAP_SIZE_M
+}
+
+fn default_angle_snap_degrees() -> f32 {
+    ANGLE_SNAP_DEGREES
+}
+
 fn default_marker_outline_width() -> f32 {
     MARKER_OUTLINE_WIDTH
 }
@@ -309,6 +415,51 @@ impl EditorOptions {
             ));
         }
 
+        if self.max_route_grade_percent <= 0.0 || self.max_route_grade_percent > 100.0 {
+            return Err(anyhow::anyhow!(
+                "max_route_grade_percent ({}) muss > 0 und <= 100 sein",
+                self.max_route_grade_percent
+            ));
+        }
+
+        if self.roundabout_radius_m <= 0.0 || self.roundabout_radius_m > 100.0 {
+            return Err(anyhow::anyhow!(
+                "roundabout_radius_m ({}) muss > 0 und <= 100 sein",
+                self.roundabout_radius_m
+            ));
+        }
+
+        if self.grid_snap_size <= 0.0 || self.grid_snap_size > 100.0 {
+            return Err(anyhow::anyhow!(
+                "grid_snap_size ({}) muss > 0 und <= 100 sein",
+                self.grid_snap_size
+            ));
+        }
+
+        if self.angle_snap_degrees <= 0.0 || self.angle_snap_degrees > 90.0 {
+            return Err(anyhow::anyhow!(
+                "angle_snap_degrees ({}) muss > 0 und <= 90 sein",
+                self.angle_snap_degrees
+            ));
+        }
+
+        if self.terrain_clipping_tolerance_m < 0.0 {
+            return Err(anyhow::anyhow!(
+                "terrain_clipping_tolerance_m darf nicht negativ sein"
+            ));
+        }
+
+        if self.live_link_port == 0 {
+            return Err(anyhow::anyhow!("live_link_port muss > 0 sein"));
+        }
+
+        if self.live_record_spacing_m <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "live_record_spacing_m ({}) muss > 0 sein",
+                self.live_record_spacing_m
+            ));
+        }
+
         if self.copy_preview_opacity < 0.0 || self.copy_preview_opacity > 1.0 {
             return Err(anyhow::anyhow!(
                 "copy_preview_opacity ({}) muss zwischen 0 und 1 liegen",
@@ -432,6 +583,25 @@ legend = false
             !opts.show_all_group_boundaries,
             "show_all_group_boundaries muss default false sein"
         );
+        assert!(
+            opts.show_savegame_vehicles,
+            "show_savegame_vehicles muss default true sein"
+        );
+        assert!(
+            opts.show_owned_farmlands,
+            "show_owned_farmlands muss default true sein"
+        );
+        assert!(
+            !opts.live_link_enabled,
+            "live_link_enabled muss default false sein"
+        );
+        assert_eq!(opts.live_link_port, LIVE_LINK_PORT);
+        assert!(
+            !opts.grid_snap_enabled,
+            "grid_snap_enabled muss default false sein"
+        );
+        assert_eq!(opts.grid_snap_size, GRID_SNAP_SIZE_M);
+        assert_eq!(opts.angle_snap_degrees, ANGLE_SNAP_DEGREES);
         assert!(
             (opts.marker_outline_width - MARKER_OUTLINE_WIDTH).abs() < f32::EPSILON,
             "marker_outline_width muss default {} sein, ist {}",
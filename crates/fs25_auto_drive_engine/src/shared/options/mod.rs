@@ -18,8 +18,10 @@ pub use render::{
     CONNECTION_THICKNESS_SUBPRIO_WORLD, CONNECTION_THICKNESS_WORLD, MARKER_COLOR,
     MARKER_OUTLINE_COLOR, MARKER_OUTLINE_WIDTH, MARKER_SIZE_WORLD, NODE_COLOR_DEFAULT,
     NODE_COLOR_SELECTED, NODE_COLOR_SUBPRIO, NODE_COLOR_WARNING, NODE_SIZE_WORLD,
-    SELECTION_SIZE_FACTOR, TERRAIN_HEIGHT_SCALE,
+    SELECTION_SIZE_FACTOR, TERRAIN_CLIPPING_TOLERANCE_M, TERRAIN_HEIGHT_SCALE,
 };
 pub use tools::{
-    ValueAdjustInputMode, HITBOX_SCALE_PERCENT, MOUSE_WHEEL_DISTANCE_STEP_M, SNAP_SCALE_PERCENT,
+    ValueAdjustInputMode, ANGLE_SNAP_DEGREES, GRID_SNAP_SIZE_M, HITBOX_SCALE_PERCENT,
+    LIVE_LINK_PORT, LIVE_RECORD_SPACING_M, MAX_ROUTE_GRADE_PERCENT, MOUSE_WHEEL_DISTANCE_STEP_M,
+    ROUNDABOUT_RADIUS_M, SNAP_SCALE_PERCENT,
 };
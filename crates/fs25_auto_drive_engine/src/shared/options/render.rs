@@ -5,6 +5,9 @@ use serde::{Deserialize, Serialize};
 /// Standard-Terrain-Hoehenskala (FS25: normalized_pixel x Faktor = Y-Meter).
 pub const TERRAIN_HEIGHT_SCALE: f32 = 255.0;
 
+/// Standard-Toleranz in Metern, ab der eine Verbindung das Terrain schneidet oder ueberspannt.
+pub const TERRAIN_CLIPPING_TOLERANCE_M: f32 = 1.0;
+
 /// Groessenfaktor fuer selektierte Nodes in Prozent.
 pub const SELECTION_SIZE_FACTOR: f32 = 175.0;
 
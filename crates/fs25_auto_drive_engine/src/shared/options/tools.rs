@@ -8,6 +8,18 @@ pub const SNAP_SCALE_PERCENT: f32 = 100.0;
 pub const HITBOX_SCALE_PERCENT: f32 = 100.0;
 /// Schrittweite fuer Distanz-Felder bei Mausrad-Anpassung in Metern.
 pub const MOUSE_WHEEL_DISTANCE_STEP_M: f32 = 0.1;
+/// Standard-Steigungsgrenze fuer das Hoehenprofil der Route-Tool-Vorschau in Prozent.
+pub const MAX_ROUTE_GRADE_PERCENT: f32 = 15.0;
+/// Standard-Radius fuer aus Kreuzungen erzeugte Kreisverkehre in Metern.
+pub const ROUNDABOUT_RADIUS_M: f32 = 12.0;
+/// Standard-Rasterweite fuer Grid-Snap in Metern.
+pub const GRID_SNAP_SIZE_M: f32 = 1.0;
+/// Standard-Winkelraster fuer Segment-Richtungen in Grad.
+pub const ANGLE_SNAP_DEGREES: f32 = 15.0;
+/// Standard-UDP-Port fuer die Live-Verbindung zum Companion-Mod.
+pub const LIVE_LINK_PORT: u16 = 17825;
+/// Standard-Abstand zwischen aufgezeichneten Wegpunkten beim Live-Aufzeichnen in Metern.
+pub const LIVE_RECORD_SPACING_M: f32 = 6.0;
 
 /// Praeferenz fuer die primaere Interaktion an numerischen DragValue-Feldern.
 ///
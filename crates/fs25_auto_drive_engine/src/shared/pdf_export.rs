@@ -0,0 +1,138 @@
+//! Optionen und Seitenlayout des PDF-Kartenexports.
+//!
+//! Das Layout lebt hier statt im Writer, damit Dialoge die Seitenanzahl fuer
+//! Papierformat und Massstab schon vor dem Export anzeigen koennen.
+
+use glam::Vec2;
+
+/// PostScript-Punkte pro Millimeter (1 pt = 1/72 Zoll).
+pub const PDF_PT_PER_MM: f32 = 72.0 / 25.4;
+/// Seitenrand rundum in Punkten (10 mm).
+pub const PDF_PAGE_MARGIN_PT: f32 = 10.0 * PDF_PT_PER_MM;
+/// Hoehe der Kopfzeile (Titel, Seitenangabe) in Punkten.
+pub const PDF_HEADER_HEIGHT_PT: f32 = 18.0;
+/// Hoehe der Fusszeile (Legende, Massstabsleiste) in Punkten.
+pub const PDF_FOOTER_HEIGHT_PT: f32 = 44.0;
+/// Obergrenze der Seitenanzahl eines Exports.
+pub const PDF_MAX_PAGES: usize = 200;
+/// Auswaehlbare Massstabszahlen (1:n).
+pub const PDF_SCALE_PRESETS: [u32; 6] = [1000, 2000, 2500, 5000, 10_000, 20_000];
+
+/// Papierformat des PDF-Exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PdfPaperSize {
+    /// DIN A4 (210 × 297 mm).
+    #[default]
+    A4,
+    /// DIN A3 (297 × 420 mm).
+    A3,
+}
+
+impl PdfPaperSize {
+    /// Blattgroesse im Hochformat in Millimetern (Breite, Hoehe).
+    pub fn portrait_mm(self) -> Vec2 {
+        match self {
+            Self::A4 => Vec2::new(210.0, 297.0),
+            Self::A3 => Vec2::new(297.0, 420.0),
+        }
+    }
+
+    /// Anzeigename des Formats.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::A4 => "A4",
+            Self::A3 => "A3",
+        }
+    }
+}
+
+/// Einstellungen des PDF-Kartenexports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfExportOptions {
+    /// Papierformat.
+    pub paper: PdfPaperSize,
+    /// Querformat statt Hochformat.
+    pub landscape: bool,
+    /// Massstabszahl `n` fuer 1:n (1 m Welt = 1000/n mm Papier).
+    pub scale_denominator: u32,
+    /// Koordinatengitter zeichnen.
+    pub show_grid: bool,
+    /// Gitterabstand in Metern.
+    pub grid_spacing_m: f32,
+}
+
+impl Default for PdfExportOptions {
+    fn default() -> Self {
+        Self {
+            paper: PdfPaperSize::A4,
+            landscape: true,
+            scale_denominator: 5000,
+            show_grid: true,
+            grid_spacing_m: 100.0,
+        }
+    }
+}
+
+impl PdfExportOptions {
+    /// Blattgroesse in Punkten unter Beruecksichtigung der Ausrichtung.
+    pub fn page_size_pt(&self) -> Vec2 {
+        let size = self.paper.portrait_mm() * PDF_PT_PER_MM;
+        if self.landscape {
+            Vec2::new(size.y, size.x)
+        } else {
+            size
+        }
+    }
+
+    /// Groesse des Kartenrahmens einer Seite in Punkten.
+    pub fn map_frame_size_pt(&self) -> Vec2 {
+        self.page_size_pt()
+            - Vec2::new(
+                2.0 * PDF_PAGE_MARGIN_PT,
+                2.0 * PDF_PAGE_MARGIN_PT + PDF_HEADER_HEIGHT_PT + PDF_FOOTER_HEIGHT_PT,
+            )
+    }
+
+    /// Punkte auf dem Papier pro Meter in der Welt.
+    pub fn pt_per_meter(&self) -> f32 {
+        1000.0 * PDF_PT_PER_MM / self.scale_denominator.max(1) as f32
+    }
+
+    /// Weltausschnitt einer Seite in Metern (Breite, Tiefe).
+    pub fn page_extent_m(&self) -> Vec2 {
+        self.map_frame_size_pt() / self.pt_per_meter()
+    }
+
+    /// Seitenraster `(Spalten, Zeilen)`, das einen Kartenausschnitt von
+    /// `extent_m` Metern abdeckt; mindestens eine Seite.
+    pub fn page_grid(&self, extent_m: Vec2) -> (usize, usize) {
+        let page = self.page_extent_m();
+        let count = |extent: f32, page: f32| ((extent / page).ceil() as usize).max(1);
+        (count(extent_m.x, page.x), count(extent_m.y, page.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_grid_grows_with_scale_and_shrinks_with_paper() {
+        let mut options = PdfExportOptions {
+            paper: PdfPaperSize::A4,
+            landscape: true,
+            scale_denominator: 5000,
+            ..PdfExportOptions::default()
+        };
+        // A4 quer: Rahmen 277 mm breit → bei 1:5000 rund 1385 m pro Seite.
+        assert!((options.page_extent_m().x - 1385.0).abs() < 1.0);
+        assert_eq!(options.page_grid(Vec2::new(2048.0, 2048.0)), (2, 3));
+
+        options.scale_denominator = 2000;
+        assert_eq!(options.page_grid(Vec2::new(2048.0, 2048.0)), (4, 7));
+
+        options.paper = PdfPaperSize::A3;
+        assert_eq!(options.page_grid(Vec2::new(2048.0, 2048.0)), (3, 5));
+        assert_eq!(options.page_grid(Vec2::ZERO), (1, 1));
+    }
+}
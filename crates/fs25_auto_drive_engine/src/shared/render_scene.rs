@@ -8,6 +8,7 @@ use glam::{Mat3, Vec2};
 use indexmap::IndexSet;
 use kiddo::{ImmutableKdTree, SquaredEuclidean};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 /// Render-seitige Klassifikation eines Nodes.
@@ -22,7 +23,7 @@ pub enum RenderNodeKind {
 }
 
 /// Render-seitige Node-Daten ohne Domain-Abhaengigkeit.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RenderNode {
     /// Stabile Node-ID fuer Auswahl- und Sichtbarkeitsmengen.
     pub id: u64,
@@ -55,7 +56,7 @@ pub enum RenderConnectionPriority {
 }
 
 /// Render-seitige Verbindung mit bereits aufgeloester Geometrie.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RenderConnection {
     /// Start-Node-ID fuer Hidden-Filtering.
     pub start_id: u64,
@@ -72,7 +73,7 @@ pub struct RenderConnection {
 }
 
 /// Render-seitige Marker-Daten mit bereits aufgeloester Position.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RenderMarker {
     /// Weltposition des Markers.
     pub position: Vec2,
@@ -150,6 +151,74 @@ impl RenderSpatialIndex {
     }
 }
 
+/// Prozessweit eindeutige Quelle fuer Layer-Revisionen.
+///
+/// Startet bei 1, damit `0` in Render-Fingerprints "keine Map" bedeuten kann.
+static NEXT_LAYER_REVISION: AtomicU64 = AtomicU64::new(1);
+
+fn next_layer_revision() -> u64 {
+    NEXT_LAYER_REVISION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Inhalts-Revisionen der einzelnen Layer eines `RenderMap`-Snapshots.
+///
+/// Eine Revision bleibt ueber Snapshot-Neuaufbauten stabil, solange sich der
+/// Inhalt des Layers nicht aendert. Renderer vergleichen nur die Revision des
+/// Layers, den sie zeichnen, und bauen ihre GPU-Daten sonst nicht neu auf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderMapRevisions {
+    /// Revision der Nodes (Positionen, Klassifikation).
+    pub nodes: u64,
+    /// Revision der Verbindungen (Geometrie, Richtung, Prioritaet).
+    pub connections: u64,
+    /// Revision der Marker-Positionen.
+    pub markers: u64,
+}
+
+impl RenderMapRevisions {
+    fn fresh() -> Self {
+        Self {
+            nodes: next_layer_revision(),
+            connections: next_layer_revision(),
+            markers: next_layer_revision(),
+        }
+    }
+
+    /// Liefert die Dirty-Flags gegenueber einem frueheren Revisionsstand.
+    pub fn dirty_since(&self, previous: &RenderMapRevisions) -> RenderMapDirty {
+        RenderMapDirty {
+            nodes: self.nodes != previous.nodes,
+            connections: self.connections != previous.connections,
+            markers: self.markers != previous.markers,
+        }
+    }
+}
+
+/// Dirty-Flags pro Layer eines `RenderMap`-Snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderMapDirty {
+    /// Nodes haben sich geaendert.
+    pub nodes: bool,
+    /// Verbindungen haben sich geaendert.
+    pub connections: bool,
+    /// Marker haben sich geaendert.
+    pub markers: bool,
+}
+
+impl RenderMapDirty {
+    /// Alle Layer als geaendert markiert (z. B. erster Snapshot einer Karte).
+    pub const ALL: Self = Self {
+        nodes: true,
+        connections: true,
+        markers: true,
+    };
+
+    /// Prueft, ob mindestens ein Layer geaendert wurde.
+    pub fn any(&self) -> bool {
+        self.nodes || self.connections || self.markers
+    }
+}
+
 /// Render-spezifischer Snapshot einer Karte.
 #[derive(Debug)]
 pub struct RenderMap {
@@ -157,6 +226,8 @@ pub struct RenderMap {
     connections: Vec<RenderConnection>,
     markers: Vec<RenderMarker>,
     spatial_index: RenderSpatialIndex,
+    revisions: RenderMapRevisions,
+    dirty: RenderMapDirty,
 }
 
 impl RenderMap {
@@ -171,9 +242,64 @@ impl RenderMap {
             connections,
             markers,
             spatial_index,
+            revisions: RenderMapRevisions::fresh(),
+            dirty: RenderMapDirty::ALL,
         }
     }
 
+    /// Baut einen Nachfolger-Snapshot und uebernimmt die Revisionen unveraenderter Layer.
+    ///
+    /// Jeder Layer wird mit dem Vorgaenger verglichen; nur geaenderte Layer erhalten
+    /// eine neue Revision und ein gesetztes Dirty-Flag. Bei unveraenderten Nodes wird
+    /// zusaetzlich der KD-Index des Vorgaengers wiederverwendet.
+    pub fn new_incremental(
+        previous: &RenderMap,
+        nodes: HashMap<u64, RenderNode>,
+        connections: Vec<RenderConnection>,
+        markers: Vec<RenderMarker>,
+    ) -> Self {
+        let dirty = RenderMapDirty {
+            nodes: nodes != previous.nodes,
+            connections: connections != previous.connections,
+            markers: markers != previous.markers,
+        };
+        let revision_for = |changed: bool, previous: u64| {
+            if changed {
+                next_layer_revision()
+            } else {
+                previous
+            }
+        };
+        let revisions = RenderMapRevisions {
+            nodes: revision_for(dirty.nodes, previous.revisions.nodes),
+            connections: revision_for(dirty.connections, previous.revisions.connections),
+            markers: revision_for(dirty.markers, previous.revisions.markers),
+        };
+        let spatial_index = if dirty.nodes {
+            RenderSpatialIndex::from_nodes(&nodes)
+        } else {
+            previous.spatial_index.clone()
+        };
+        Self {
+            nodes,
+            connections,
+            markers,
+            spatial_index,
+            revisions,
+            dirty,
+        }
+    }
+
+    /// Inhalts-Revisionen der Layer dieses Snapshots.
+    pub fn revisions(&self) -> RenderMapRevisions {
+        self.revisions
+    }
+
+    /// Dirty-Flags gegenueber dem Vorgaenger-Snapshot.
+    pub fn dirty(&self) -> RenderMapDirty {
+        self.dirty
+    }
+
     pub fn node(&self, node_id: &u64) -> Option<&RenderNode> {
         self.nodes.get(node_id)
     }
@@ -309,6 +435,16 @@ impl RenderScene {
         self.map.as_deref()
     }
 
+    /// Layer-Revisionen des Karten-Snapshots (`None` ohne Karte).
+    pub fn map_revisions(&self) -> Option<RenderMapRevisions> {
+        self.map.as_deref().map(RenderMap::revisions)
+    }
+
+    /// Dirty-Flags des Karten-Snapshots gegenueber seinem Vorgaenger (`None` ohne Karte).
+    pub fn map_dirty(&self) -> Option<RenderMapDirty> {
+        self.map.as_deref().map(RenderMap::dirty)
+    }
+
     pub fn camera(&self) -> &RenderCamera {
         &self.camera
     }
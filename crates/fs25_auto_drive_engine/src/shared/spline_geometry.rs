@@ -46,8 +46,41 @@ pub fn catmull_rom_chain_with_tangents(
 
     let n = points.len();
     let mut result = Vec::with_capacity((n - 1) * samples_per_segment + 1);
+    catmull_rom_segments_into(
+        points,
+        samples_per_segment,
+        start_phantom,
+        end_phantom,
+        0..(n - 1),
+        &mut result,
+    );
+    result
+}
 
-    for seg in 0..(n - 1) {
+/// Haengt die dichten Punkte der Catmull-Rom-Segmente `segments` an `out` an.
+///
+/// Segment `i` verlaeuft von `points[i]` nach `points[i + 1]` und haengt nur von
+/// `points[i - 1..=i + 2]` ab. Dadurch lassen sich unveraenderte Anfangssegmente
+/// cachen und nur die Segmente am Ende neu berechnen, deren Kontrollpunkte sich
+/// geaendert haben. Die Konkatenation aller Teilbereiche ergibt exakt
+/// [`catmull_rom_chain_with_tangents`] (fuer mindestens 3 Punkte).
+///
+/// Erwartet mindestens 3 Punkte; Bereiche ausserhalb von `0..points.len() - 1`
+/// werden abgeschnitten.
+pub fn catmull_rom_segments_into(
+    points: &[Vec2],
+    samples_per_segment: usize,
+    start_phantom: Option<Vec2>,
+    end_phantom: Option<Vec2>,
+    segments: std::ops::Range<usize>,
+    out: &mut Vec<Vec2>,
+) {
+    let n = points.len();
+    if n < 3 {
+        return;
+    }
+
+    for seg in segments.start..segments.end.min(n - 1) {
         // Phantom-Punkte an den Raendern (ggf. durch Tangente ueberschrieben)
         let p0 = if seg == 0 {
             start_phantom.unwrap_or_else(|| 2.0 * points[0] - points[1])
@@ -70,11 +103,20 @@ pub fn catmull_rom_chain_with_tangents(
 
         for i in 0..steps {
             let t = i as f32 / samples_per_segment as f32;
-            result.push(catmull_rom_point(p0, p1, p2, p3, t));
+            out.push(catmull_rom_point(p0, p1, p2, p3, t));
         }
     }
+}
 
-    result
+/// Berechnet einen Phantom-Punkt aus einer Tangenten-Richtung.
+///
+/// Der Punkt liegt entgegen `tangent_angle` hinter `anchor_pos`, im gleichen
+/// Abstand wie `anchor_pos` → `neighbor_pos` (mind. 1 m). Als virtueller
+/// p0/p3-Punkt richtet er die Kurve am Rand tangential aus.
+pub fn phantom_from_tangent(anchor_pos: Vec2, tangent_angle: f32, neighbor_pos: Vec2) -> Vec2 {
+    let dist = anchor_pos.distance(neighbor_pos).max(1.0);
+    let dir = Vec2::from_angle(tangent_angle + std::f32::consts::PI);
+    anchor_pos + dir * dist
 }
 
 /// Approximierte Laenge einer Polyline.
@@ -5,11 +5,26 @@
 
 /// Curseplay XML-Import/Export fuer Feldumrandungen (`<customField>`-Format).
 pub mod curseplay;
+/// `modDesc.xml` fuer eigenstaendige AutoDrive-Kurs-Mods.
+pub mod mod_desc;
 /// XML-Parser fuer AutoDrive-Konfigurationen (quick-xml, Structure of Arrays).
 pub mod parser;
+/// Index des AutoDrive-Routen-Managers (`routes.xml`).
+pub mod routes;
+/// Parser fuer Savegame-Kopfdaten (`careerSavegame.xml`, `farms.xml`).
+pub mod savegame;
+/// Datei-Loader fuer AutoDrive-Konfigurationen (Memory-Mapping mit Lese-Fallback).
+pub mod source;
+/// Parser fuer Fahrzeugpositionen aus der `vehicles.xml` eines Savegames.
+pub mod vehicles;
 /// XML-Writer fuer AutoDrive-Konfigurationen mit lueckenloser ID-Neunummerierung.
 pub mod writer;
 
 pub use curseplay::{parse_curseplay, write_curseplay};
-pub use parser::parse_autodrive_config;
-pub use writer::write_autodrive_config;
+pub use mod_desc::write_courses_mod_desc;
+pub use parser::{parse_autodrive_config, parse_autodrive_route};
+pub use routes::{parse_routes_index, write_routes_index};
+pub use savegame::{parse_career_savegame, parse_farm_name, CareerSavegameSettings};
+pub use source::load_autodrive_config;
+pub use vehicles::parse_savegame_vehicles;
+pub use writer::{write_autodrive_config, write_autodrive_route};
@@ -0,0 +1,49 @@
+//! `modDesc.xml` fuer einen eigenstaendigen AutoDrive-Kurs-Mod.
+
+use super::writer::escape_xml;
+
+/// `descVersion` der erzeugten `modDesc.xml` (FS25).
+const MOD_DESC_VERSION: u32 = 92;
+
+/// Schreibt die `modDesc.xml` eines Kurs-Mods, der nur die Standard-Config
+/// fuer die Karte `map_name` mitbringt.
+///
+/// Der Mod haengt von `FS25_AutoDrive` ab und ist multiplayer-faehig; Titel
+/// und Beschreibung werden deutsch und englisch ausgegeben.
+pub fn write_courses_mod_desc(map_name: &str) -> String {
+    let map_name = escape_xml(map_name);
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8" standalone="no"?>
+<modDesc descVersion="{MOD_DESC_VERSION}">
+    <author>FS25 AutoDrive Editor</author>
+    <version>1.0.0.0</version>
+    <title>
+        <en>AutoDrive Courses - {map_name}</en>
+        <de>AutoDrive-Kurse - {map_name}</de>
+    </title>
+    <description>
+        <en>Default AutoDrive network for the map "{map_name}".</en>
+        <de>Standard-AutoDrive-Netz fuer die Karte "{map_name}".</de>
+    </description>
+    <multiplayer supported="true"/>
+    <dependencies>
+        <dependency>FS25_AutoDrive</dependency>
+    </dependencies>
+</modDesc>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mod_desc_escapes_map_name_and_depends_on_autodrive() {
+        let xml = write_courses_mod_desc("Hof & Feld");
+
+        assert!(xml.contains("<en>AutoDrive Courses - Hof &amp; Feld</en>"));
+        assert!(xml.contains("<dependency>FS25_AutoDrive</dependency>"));
+        assert!(xml.contains(r#"<modDesc descVersion="92">"#));
+    }
+}
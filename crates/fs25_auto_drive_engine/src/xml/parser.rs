@@ -11,6 +11,9 @@ use quick_xml::Reader;
 use quick_xml::XmlVersion;
 use waypoints::build_nodes_and_connections;
 
+/// Config-Version fuer Routen-Exporte, die selbst keine Version tragen (FS25).
+const ROUTE_EXPORT_VERSION: u32 = 3;
+
 /// Haengt Text an eine `Option<String>` an (oder initialisiert sie).
 fn append_or_set(target: &mut Option<String>, text: &str) {
     match target {
@@ -21,6 +24,25 @@ fn append_or_set(target: &mut Option<String>, text: &str) {
 
 /// Parst eine AutoDrive-Konfiguration aus einem XML-String.
 pub fn parse_autodrive_config(xml_content: &str) -> Result<RoadMap> {
+    parse_document(xml_content, "AutoDrive", None)
+}
+
+/// Parst einen Routen-Export des AutoDrive-Routen-Managers (`<routeExport>`).
+///
+/// Wegpunkte und Marker liegen im selben Format wie in der Config vor; da der
+/// Export keine Version traegt, wird FS25 (Version 3) angenommen.
+pub fn parse_autodrive_route(xml_content: &str) -> Result<RoadMap> {
+    parse_document(xml_content, "routeExport", Some(ROUTE_EXPORT_VERSION))
+}
+
+/// Parst ein AutoDrive-Dokument mit dem Wurzel-Tag `root_tag`.
+///
+/// `fallback_version` wird verwendet, wenn das Dokument keine Version enthaelt.
+fn parse_document(
+    xml_content: &str,
+    root_tag: &str,
+    fallback_version: Option<u32>,
+) -> Result<RoadMap> {
     let mut reader = Reader::from_str(xml_content);
     reader.config_mut().trim_text(false);
 
@@ -60,7 +82,7 @@ pub fn parse_autodrive_config(xml_content: &str) -> Result<RoadMap> {
                 let name = e.name();
                 let tag = reader.decoder().decode(name.as_ref())?;
 
-                if tag == "AutoDrive" {
+                if tag == root_tag {
                     for attr in e.attributes().with_checks(false) {
                         let attr = attr?;
                         let key = reader.decoder().decode(attr.key.as_ref())?;
@@ -90,7 +112,7 @@ pub fn parse_autodrive_config(xml_content: &str) -> Result<RoadMap> {
                 } else if in_marker_element {
                     // Innerhalb eines Marker-Elements: <id>, <name>, <group>
                     current_tag = Some(tag.to_string());
-                } else if tag != "AutoDrive" {
+                } else if tag != root_tag {
                     current_tag = Some(tag.to_string());
                 }
             }
@@ -193,7 +215,10 @@ pub fn parse_autodrive_config(xml_content: &str) -> Result<RoadMap> {
         buffer.clear();
     }
 
-    let version = parse_version(version_attr.clone(), version_text)?;
+    let version = match (fallback_version, &version_attr, &version_text) {
+        (Some(fallback), None, None) => fallback,
+        _ => parse_version(version_attr.clone(), version_text)?,
+    };
 
     // config_version fuer den Writer sicherstellen (Roundtrip-Faehigkeit)
     if config_version.is_none() {
@@ -1,6 +1,8 @@
 use super::*;
 use crate::core::ConnectionDirection;
-use crate::xml::parser::waypoints::{parse_list, parse_nested_list};
+use crate::xml::parser::waypoints::{
+    parse_float_list, parse_list, parse_nested_list, split_into_chunks, PARALLEL_PARSE_MIN_BYTES,
+};
 
 #[test]
 fn test_parse_simple_list() {
@@ -14,6 +16,57 @@ fn test_parse_nested_list() {
     assert_eq!(result, vec![vec![2, 3], vec![4, 5], vec![], vec![1],]);
 }
 
+#[test]
+fn test_parse_float_list_accepts_autodrive_formats() {
+    let result = parse_float_list("1.5, -2.25,3,1e2,", ',').unwrap();
+    assert_eq!(result, vec![1.5, -2.25, 3.0, 100.0]);
+    assert!(parse_float_list("1.0,abc", ',').is_err());
+}
+
+#[test]
+fn test_split_into_chunks_preserves_split_parts() {
+    let text = (0..50_000)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let chunks = split_into_chunks(&text, ',');
+    assert!(chunks.len() > 1, "Grosse Listen muessen aufgeteilt werden");
+
+    let chunked: Vec<&str> = chunks.iter().flat_map(|chunk| chunk.split(',')).collect();
+    let whole: Vec<&str> = text.split(',').collect();
+    assert_eq!(chunked, whole);
+}
+
+#[test]
+fn test_parallel_parsing_matches_sequential_result() {
+    let count = 40_000u64;
+    let coords = (0..count)
+        .map(|i| format!("{:.3}", i as f32 * 0.5))
+        .collect::<Vec<_>>()
+        .join(",");
+    let nested = (0..count)
+        .map(|i| {
+            if i % 3 == 0 {
+                "-1".to_string()
+            } else {
+                format!("{},{}", i, i + 1)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(";");
+    assert!(coords.len() >= PARALLEL_PARSE_MIN_BYTES);
+    assert!(nested.len() >= PARALLEL_PARSE_MIN_BYTES);
+
+    let xs = parse_float_list(&coords, ',').unwrap();
+    assert_eq!(xs.len(), count as usize);
+    assert_eq!(xs[12_345], 12_345.0 * 0.5);
+
+    let lists = parse_nested_list(&nested).unwrap();
+    assert_eq!(lists.len(), count as usize);
+    assert!(lists[0].is_empty());
+    assert_eq!(lists[12_346], vec![12_346, 12_347]);
+}
+
 #[test]
 fn test_parse_fails_for_invalid_marker_id() {
     let xml = r#"
@@ -103,3 +156,44 @@ fn test_bidirectional_roundtrip_preserves_connections() {
     let reparsed = parse_autodrive_config(&written).expect("Re-Parsing fehlgeschlagen");
     assert_eq!(reparsed.connection_count(), road_map.connection_count());
 }
+
+#[test]
+fn test_route_export_roundtrip_without_version() {
+    use crate::xml::writer::write_autodrive_route;
+
+    let xml = r#"
+    <routeExport>
+        <waypoints>
+            <id>1,2</id>
+            <x>0,10</x>
+            <y>0,0</y>
+            <z>0,5</z>
+            <out>2;-1</out>
+            <incoming>-1;1</incoming>
+            <flags>0,0</flags>
+        </waypoints>
+        <mapmarker>
+            <mm1>
+                <id>2</id>
+                <name>Hof</name>
+                <group>All</group>
+            </mm1>
+        </mapmarker>
+    </routeExport>
+    "#;
+
+    let road_map = parse_autodrive_route(xml).expect("Routen-Export parsebar");
+    assert_eq!(road_map.version, 3);
+    assert_eq!(road_map.node_count(), 2);
+    assert_eq!(road_map.connection_count(), 1);
+    assert_eq!(road_map.map_markers().len(), 1);
+    assert!(road_map.meta.options.is_empty());
+
+    let written = write_autodrive_route(&road_map, None, 255.0).expect("Export fehlgeschlagen");
+    assert!(written.contains("<routeExport>"));
+    assert!(!written.contains("<version>"));
+    let reparsed = parse_autodrive_route(&written).expect("Re-Parsing fehlgeschlagen");
+    assert_eq!(reparsed.connection_count(), 1);
+    assert_eq!(reparsed.map_markers()[0].name, "Hof");
+    assert!(parse_autodrive_config(&written).is_err());
+}
@@ -1,10 +1,21 @@
 //! Wegpunkt-Parsing: Konvertiert rohe String-Puffer in Nodes und Connections.
 
-use crate::core::{Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag};
-use anyhow::{bail, Context, Result};
+use crate::core::{
+    Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, NodeStore,
+};
+use anyhow::{anyhow, bail, Context, Result};
 use glam::Vec2;
+use rayon::prelude::*;
 use std::collections::HashMap;
 
+/// Ab dieser Textlaenge (Bytes) werden Listen in Chunks parallel geparst.
+///
+/// Darunter ueberwiegt der Thread-Overhead den Gewinn.
+pub(super) const PARALLEL_PARSE_MIN_BYTES: usize = 64 * 1024;
+
+/// Minimale Chunk-Groesse (Bytes) beim parallelen Parsen.
+const MIN_CHUNK_BYTES: usize = 16 * 1024;
+
 /// Baut Nodes und Connections aus den geparsten Waypoint-Rohdaten auf.
 ///
 /// Die Parameter entsprechen den komma- bzw. semikolon-getrennten Strings aus
@@ -17,24 +28,58 @@ pub(super) fn build_nodes_and_connections(
     flags_raw: &str,
     out_raw: &str,
     incoming_raw: &str,
-) -> Result<(HashMap<u64, MapNode>, Vec<Connection>)> {
-    let ids = parse_list::<u64>(ids_raw, ',').context("Fehler beim Parsen der ID-Liste")?;
-    let xs = parse_list::<f32>(x_raw, ',').context("Fehler beim Parsen der X-Koordinaten")?;
-    let zs = parse_list::<f32>(z_raw, ',').context("Fehler beim Parsen der Z-Koordinaten")?;
-    let flags = parse_list::<u32>(flags_raw, ',').context("Fehler beim Parsen der Flags")?;
-    let outgoing = parse_nested_list(out_raw).context("Fehler beim Parsen der Outgoing-Liste")?;
-    let incoming =
-        parse_nested_list(incoming_raw).context("Fehler beim Parsen der Incoming-Liste")?;
-
-    let ys: Option<Vec<f32>> = if let Some(y) = y_raw {
-        if !y.is_empty() {
-            Some(parse_list::<f32>(y, ',')?)
-        } else {
-            None
-        }
-    } else {
-        None
-    };
+) -> Result<(NodeStore, Vec<Connection>)> {
+    // Die Listen sind voneinander unabhaengig und werden gleichzeitig geparst;
+    // grosse Listen teilen sich zusaetzlich intern in parallele Chunks auf.
+    let ((ids, flags), ((xs, ys), (zs, (outgoing, incoming)))) = rayon::join(
+        || {
+            rayon::join(
+                || parse_list::<u64>(ids_raw, ',').context("Fehler beim Parsen der ID-Liste"),
+                || parse_list::<u32>(flags_raw, ',').context("Fehler beim Parsen der Flags"),
+            )
+        },
+        || {
+            rayon::join(
+                || {
+                    rayon::join(
+                        || {
+                            parse_float_list(x_raw, ',')
+                                .context("Fehler beim Parsen der X-Koordinaten")
+                        },
+                        || {
+                            y_raw
+                                .filter(|y| !y.is_empty())
+                                .map(|y| parse_float_list(y, ','))
+                                .transpose()
+                                .context("Fehler beim Parsen der Y-Koordinaten")
+                        },
+                    )
+                },
+                || {
+                    rayon::join(
+                        || {
+                            parse_float_list(z_raw, ',')
+                                .context("Fehler beim Parsen der Z-Koordinaten")
+                        },
+                        || {
+                            rayon::join(
+                                || {
+                                    parse_nested_list(out_raw)
+                                        .context("Fehler beim Parsen der Outgoing-Liste")
+                                },
+                                || {
+                                    parse_nested_list(incoming_raw)
+                                        .context("Fehler beim Parsen der Incoming-Liste")
+                                },
+                            )
+                        },
+                    )
+                },
+            )
+        },
+    );
+    let (ids, flags, xs, ys, zs) = (ids?, flags?, xs?, ys?, zs?);
+    let (outgoing, incoming) = (outgoing?, incoming?);
 
     let expected_len = ids.len();
     if xs.len() != expected_len
@@ -53,13 +98,13 @@ pub(super) fn build_nodes_and_connections(
     }
 
     // Phase 1: Nodes aufbauen
-    let mut nodes = HashMap::new();
+    let mut nodes = NodeStore::with_capacity(expected_len);
     let mut id_to_index = HashMap::new();
 
     for (index, id) in ids.iter().enumerate() {
         let flag = NodeFlag::from_u32(flags[index]);
         let position = Vec2::new(xs[index], zs[index]);
-        nodes.insert(*id, MapNode::new(*id, position, flag));
+        nodes.insert(MapNode::new(*id, position, flag));
         id_to_index.insert(*id, index);
     }
 
@@ -103,13 +148,13 @@ pub(super) fn build_nodes_and_connections(
                 dual_pairs.insert(pair);
             }
 
-            let priority = match nodes.get(target_id).map(|node| node.flag) {
+            let priority = match nodes.flag(*target_id) {
                 Some(NodeFlag::SubPrio) => ConnectionPriority::SubPriority,
                 _ => ConnectionPriority::Regular,
             };
 
-            let start_pos = nodes.get(source_id).context("Start-Node fehlt")?.position;
-            let end_pos = nodes.get(target_id).context("End-Node fehlt")?.position;
+            let start_pos = nodes.position(*source_id).context("Start-Node fehlt")?;
+            let end_pos = nodes.position(*target_id).context("End-Node fehlt")?;
 
             connections.push(Connection::new(
                 *source_id, *target_id, direction, priority, start_pos, end_pos,
@@ -121,22 +166,59 @@ pub(super) fn build_nodes_and_connections(
 }
 
 /// Parst eine kommagetrennte Liste einfacher Werte.
-pub(super) fn parse_list<T: std::str::FromStr>(text: &str, delimiter: char) -> Result<Vec<T>>
+pub(super) fn parse_list<T>(text: &str, delimiter: char) -> Result<Vec<T>>
 where
+    T: std::str::FromStr + Send,
     <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
 {
-    text.split(delimiter)
-        .filter(|s| !s.is_empty())
-        .map(|s| {
-            let trimmed = s.trim();
-            trimmed.parse::<T>().with_context(|| {
-                format!(
-                    "Wert '{}' konnte nicht geparst werden",
-                    truncate_for_error(trimmed)
-                )
-            })
+    parse_list_with(text, delimiter, |value| {
+        value.parse::<T>().with_context(|| {
+            format!(
+                "Wert '{}' konnte nicht geparst werden",
+                truncate_for_error(value)
+            )
+        })
+    })
+}
+
+/// Parst eine Liste von Koordinaten mit dem schnellen Float-Parser (`fast-float2`).
+pub(super) fn parse_float_list(text: &str, delimiter: char) -> Result<Vec<f32>> {
+    parse_list_with(text, delimiter, |value| {
+        fast_float2::parse::<f32, _>(value).map_err(|_| {
+            anyhow!(
+                "Wert '{}' konnte nicht geparst werden",
+                truncate_for_error(value)
+            )
         })
-        .collect::<Result<Vec<T>, _>>()
+    })
+}
+
+/// Gemeinsamer Kern von [`parse_list`] und [`parse_float_list`].
+///
+/// Grosse Texte werden an Trennzeichen-Grenzen in Chunks zerlegt und parallel
+/// geparst; das Ergebnis entspricht exakt dem sequentiellen Parsen.
+fn parse_list_with<T, F>(text: &str, delimiter: char, parse: F) -> Result<Vec<T>>
+where
+    T: Send,
+    F: Fn(&str) -> Result<T> + Sync,
+{
+    let parse_chunk = |chunk: &str| -> Result<Vec<T>> {
+        chunk
+            .split(delimiter)
+            .filter(|s| !s.is_empty())
+            .map(|s| parse(s.trim()))
+            .collect()
+    };
+
+    if text.len() < PARALLEL_PARSE_MIN_BYTES {
+        return parse_chunk(text);
+    }
+
+    let parts = split_into_chunks(text, delimiter)
+        .into_par_iter()
+        .map(parse_chunk)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(parts.into_iter().flatten().collect())
 }
 
 /// Parst verschachtelte Listen (fuer out/incoming).
@@ -144,6 +226,18 @@ where
 /// Werte ≤ 0 (z.B. -1) werden ignoriert — sie markieren Endpunkte oder
 /// rueckwaerts befahrene Strecken in AutoDrive.
 pub(super) fn parse_nested_list(text: &str) -> Result<Vec<Vec<u64>>> {
+    if text.len() < PARALLEL_PARSE_MIN_BYTES {
+        return parse_nested_chunk(text);
+    }
+
+    let parts = split_into_chunks(text, ';')
+        .into_par_iter()
+        .map(parse_nested_chunk)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(parts.into_iter().flatten().collect())
+}
+
+fn parse_nested_chunk(text: &str) -> Result<Vec<Vec<u64>>> {
     text.split(';')
         .map(|part| {
             if part.trim().is_empty() {
@@ -170,6 +264,34 @@ pub(super) fn parse_nested_list(text: &str) -> Result<Vec<Vec<u64>>> {
         .collect()
 }
 
+/// Zerlegt `text` an Vorkommen von `delimiter` in etwa gleich grosse Chunks.
+///
+/// Das trennende Zeichen selbst faellt weg, sodass `split(delimiter)` ueber alle
+/// Chunks dieselben Teile liefert wie ueber den Gesamttext. Das Trennzeichen muss
+/// ASCII sein, damit Byte-Positionen immer auf Zeichengrenzen liegen.
+pub(super) fn split_into_chunks(text: &str, delimiter: char) -> Vec<&str> {
+    debug_assert!(delimiter.is_ascii());
+    let delimiter = delimiter as u8;
+    let target_chunks = rayon::current_num_threads() * 4;
+    let chunk_len = (text.len() / target_chunks.max(1)).max(MIN_CHUNK_BYTES);
+
+    let mut chunks = Vec::with_capacity(text.len() / chunk_len + 1);
+    let mut rest = text;
+    while rest.len() > chunk_len {
+        let Some(offset) = rest.as_bytes()[chunk_len..]
+            .iter()
+            .position(|&byte| byte == delimiter)
+        else {
+            break;
+        };
+        let split_at = chunk_len + offset;
+        chunks.push(&rest[..split_at]);
+        rest = &rest[split_at + 1..];
+    }
+    chunks.push(rest);
+    chunks
+}
+
 /// Kuerzt einen String fuer Fehlermeldungen auf max. 40 Zeichen.
 fn truncate_for_error(s: &str) -> &str {
     if s.len() <= 40 {
@@ -0,0 +1,133 @@
+//! Index des AutoDrive-Routen-Managers (`routes.xml`).
+//!
+//! AutoDrive legt exportierte Routen im Spielprofil unter
+//! `autoDrive/routesManager/` ab: die Routen selbst als `<routeExport>`-Dateien
+//! im Unterordner `routes/`, die Liste aller Routen in `routes.xml`:
+//!
+//! ```xml
+//! <autoDriveRoutesManager>
+//!     <routes>
+//!         <route name="Hof" fileName="Hof.xml" map="Riverbend Springs" revision="2" date="2025/01/31 18:04:12"/>
+//!     </routes>
+//! </autoDriveRoutesManager>
+//! ```
+
+use super::writer::escape_xml;
+use crate::core::StoredRoute;
+use anyhow::{anyhow, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+
+/// Parst die `routes.xml` des AutoDrive-Routen-Managers.
+///
+/// Eintraege ohne `name` oder `fileName` werden uebersprungen. Gibt einen
+/// Fehler nur bei ungueltigem XML zurueck.
+pub fn parse_routes_index(xml_content: &str) -> Result<Vec<StoredRoute>> {
+    let mut reader = Reader::from_str(xml_content);
+    reader.config_mut().trim_text(true);
+
+    let mut routes = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"route" => {
+                if let Some(route) = parse_route_entry(&reader, e)? {
+                    routes.push(route);
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(anyhow!("XML-Lesefehler: {}", e)),
+            _ => {}
+        }
+    }
+    Ok(routes)
+}
+
+/// Schreibt die `routes.xml` des AutoDrive-Routen-Managers.
+pub fn write_routes_index(routes: &[StoredRoute]) -> String {
+    let mut output = String::new();
+    output.push_str("<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"no\"?>\n");
+    output.push_str("<autoDriveRoutesManager>\n");
+    output.push_str("    <routes>\n");
+    for route in routes {
+        output.push_str(&format!(
+            "        <route name=\"{}\" fileName=\"{}\" map=\"{}\" revision=\"{}\" date=\"{}\"/>\n",
+            escape_xml(&route.name),
+            escape_xml(&route.file_name),
+            escape_xml(&route.map),
+            route.revision,
+            escape_xml(&route.date)
+        ));
+    }
+    output.push_str("    </routes>\n");
+    output.push_str("</autoDriveRoutesManager>\n");
+    output
+}
+
+fn parse_route_entry(reader: &Reader<&[u8]>, element: &BytesStart) -> Result<Option<StoredRoute>> {
+    let mut route = StoredRoute {
+        name: String::new(),
+        file_name: String::new(),
+        map: String::new(),
+        revision: 1,
+        date: String::new(),
+    };
+    for attr in element.attributes().with_checks(false) {
+        let attr = attr?;
+        let value = attr
+            .decoded_and_normalized_value(XmlVersion::Implicit1_0, reader.decoder())?
+            .into_owned();
+        match attr.key.as_ref() {
+            b"name" => route.name = value,
+            b"fileName" => route.file_name = value,
+            b"map" => route.map = value,
+            b"revision" => route.revision = value.trim().parse().unwrap_or(1),
+            b"date" => route.date = value,
+            _ => {}
+        }
+    }
+    Ok((!route.name.is_empty() && !route.file_name.is_empty()).then_some(route))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_roundtrip_keeps_all_fields_and_escapes_names() {
+        let routes = vec![
+            StoredRoute {
+                name: "Hof & Feld".to_string(),
+                file_name: "Hof_Feld.xml".to_string(),
+                map: "Riverbend Springs".to_string(),
+                revision: 2,
+                date: "2025/01/31 18:04:12".to_string(),
+            },
+            StoredRoute {
+                name: "Silo".to_string(),
+                file_name: "Silo.xml".to_string(),
+                map: "Hutan Pantai".to_string(),
+                revision: 1,
+                date: String::new(),
+            },
+        ];
+
+        let parsed = parse_routes_index(&write_routes_index(&routes)).expect("Index parsebar");
+
+        assert_eq!(parsed, routes);
+    }
+
+    #[test]
+    fn skips_incomplete_entries_and_rejects_malformed_xml() {
+        let xml = r#"<autoDriveRoutesManager><routes>
+            <route name="Ohne Datei"/>
+            <route name="Ok" fileName="ok.xml"/>
+        </routes></autoDriveRoutesManager>"#;
+
+        let parsed = parse_routes_index(xml).expect("Index parsebar");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].file_name, "ok.xml");
+        assert_eq!(parsed[0].revision, 1);
+
+        assert!(parse_routes_index("<routes><route name=\"x\"></routes>").is_err());
+    }
+}
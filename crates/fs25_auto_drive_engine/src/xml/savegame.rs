@@ -0,0 +1,173 @@
+//! Parser fuer die Savegame-Kopfdaten (`careerSavegame.xml`, `farms.xml`).
+//!
+//! Die Savegame-Auswahl braucht nur wenige Angaben: Name, Karte und
+//! Speicherdatum stehen als Text-Elemente unter `<careerSavegame><settings>`,
+//! der Hofname als Attribut `name` eines `<farm>` in der `farms.xml`.
+
+use anyhow::{anyhow, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+
+/// Kopfdaten eines Savegames aus `careerSavegame.xml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CareerSavegameSettings {
+    /// Inhalt von `<savegameName>`
+    pub savegame_name: String,
+    /// Inhalt von `<mapTitle>`
+    pub map_title: String,
+    /// Inhalt von `<saveDate>` (`JJJJ-MM-TT`)
+    pub save_date: String,
+}
+
+/// Parst die `<settings>` einer `careerSavegame.xml`.
+///
+/// Fehlende Elemente bleiben leer; Fehler gibt es nur bei ungueltigem XML.
+pub fn parse_career_savegame(xml_content: &str) -> Result<CareerSavegameSettings> {
+    // Ohne `trim_text`: Entities teilen den Text, getrimmte Teile verloeren Leerzeichen
+    let mut reader = Reader::from_str(xml_content);
+
+    let mut settings = CareerSavegameSettings::default();
+    let mut in_settings = false;
+    let mut current_tag: Option<Vec<u8>> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"settings" => in_settings = true,
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"settings" => in_settings = false,
+            Ok(Event::Start(ref e)) if in_settings => {
+                current_tag = Some(e.name().as_ref().to_vec());
+            }
+            Ok(Event::End(_)) => current_tag = None,
+            Ok(Event::Text(e)) => {
+                let text = e.xml_content(XmlVersion::Implicit1_0)?;
+                if let Some(field) = settings_field(&mut settings, current_tag.as_deref()) {
+                    field.push_str(&text);
+                }
+            }
+            Ok(Event::GeneralRef(e)) => {
+                let resolved = match &*e {
+                    b"amp" => "&",
+                    b"lt" => "<",
+                    b"gt" => ">",
+                    b"quot" => "\"",
+                    b"apos" => "'",
+                    _ => continue,
+                };
+                if let Some(field) = settings_field(&mut settings, current_tag.as_deref()) {
+                    field.push_str(resolved);
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(anyhow!("XML-Lesefehler: {}", e)),
+            _ => {}
+        }
+    }
+
+    for field in [
+        &mut settings.savegame_name,
+        &mut settings.map_title,
+        &mut settings.save_date,
+    ] {
+        *field = field.trim().to_string();
+    }
+    Ok(settings)
+}
+
+/// Parst den Hofnamen aus der `farms.xml` eines Savegames.
+///
+/// Bevorzugt wird der Hof mit `farmId="1"` (Einzelspieler-Hof), sonst der
+/// erste Hof mit Namen. Gibt `None` zurueck, wenn kein Hof einen Namen hat.
+pub fn parse_farm_name(xml_content: &str) -> Result<Option<String>> {
+    let mut reader = Reader::from_str(xml_content);
+    reader.config_mut().trim_text(true);
+
+    let mut first_name = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"farm" => {
+                let Some(name) = attr(&reader, e, b"name")?.filter(|name| !name.is_empty()) else {
+                    continue;
+                };
+                if attr(&reader, e, b"farmId")?.as_deref() == Some("1") {
+                    return Ok(Some(name));
+                }
+                first_name.get_or_insert(name);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(anyhow!("XML-Lesefehler: {}", e)),
+            _ => {}
+        }
+    }
+    Ok(first_name)
+}
+
+/// Zielfeld fuer den Text des aktuell offenen `<settings>`-Kindelements.
+fn settings_field<'a>(
+    settings: &'a mut CareerSavegameSettings,
+    tag: Option<&[u8]>,
+) -> Option<&'a mut String> {
+    match tag? {
+        b"savegameName" => Some(&mut settings.savegame_name),
+        b"mapTitle" => Some(&mut settings.map_title),
+        b"saveDate" => Some(&mut settings.save_date),
+        _ => None,
+    }
+}
+
+fn attr(reader: &Reader<&[u8]>, element: &BytesStart, key: &[u8]) -> Result<Option<String>> {
+    for attr in element.attributes().with_checks(false) {
+        let attr = attr?;
+        if attr.key.as_ref() == key {
+            let value =
+                attr.decoded_and_normalized_value(XmlVersion::Implicit1_0, reader.decoder())?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_name_map_and_save_date_from_settings() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8" standalone="no"?>
+<careerSavegame revision="2" valid="true">
+    <settings>
+        <savegameName>Hof &amp; Feld</savegameName>
+        <creationDate>2025-01-02</creationDate>
+        <mapId>MapUS</mapId>
+        <mapTitle>Riverbend Springs</mapTitle>
+        <saveDateFormatted>02.02.2025</saveDateFormatted>
+        <saveDate>2025-02-02</saveDate>
+    </settings>
+    <statistics><money>1000</money></statistics>
+</careerSavegame>"#;
+
+        let settings = parse_career_savegame(xml).expect("Parsen sollte klappen");
+
+        assert_eq!(settings.savegame_name, "Hof & Feld");
+        assert_eq!(settings.map_title, "Riverbend Springs");
+        assert_eq!(settings.save_date, "2025-02-02");
+    }
+
+    #[test]
+    fn farm_name_prefers_farm_one() {
+        let xml = r#"<farms>
+    <farm farmId="2" name="Nachbar" color="2"/>
+    <farm farmId="1" name="Gut Sonnenhof" color="1"><players/></farm>
+</farms>"#;
+        assert_eq!(
+            parse_farm_name(xml).unwrap().as_deref(),
+            Some("Gut Sonnenhof")
+        );
+        assert_eq!(
+            parse_farm_name(r#"<farms><farm farmId="3" name="Nur ich"/></farms>"#)
+                .unwrap()
+                .as_deref(),
+            Some("Nur ich")
+        );
+        assert_eq!(parse_farm_name("<farms/>").unwrap(), None);
+    }
+}
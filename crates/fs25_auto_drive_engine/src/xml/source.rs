@@ -0,0 +1,191 @@
+//! Laden von AutoDrive-Konfigurationen aus Dateien per Memory-Mapping.
+//!
+//! Mehrere hundert MB grosse Configs werden direkt aus dem gemappten Speicher
+//! geparst, statt sie vorher vollstaendig in einen `String` zu kopieren.
+//! Kleine Dateien (unter 16 MiB) sowie Plattformen ohne
+//! mmap-Unterstuetzung (oder ein fehlgeschlagenes Mapping) lesen die Datei
+//! klassisch in einen Puffer ein.
+
+use crate::core::RoadMap;
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::path::Path;
+
+use super::parser::parse_autodrive_config;
+
+/// Ab dieser Dateigroesse wird per Memory-Mapping gelesen (16 MiB).
+///
+/// Darunter ist die Kopie guenstig, und das Laden bleibt frei vom
+/// SIGBUS-Risiko eines extern gekuerzten Mappings.
+#[cfg(any(unix, windows))]
+const MMAP_MIN_FILE_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Rohbytes einer Config-Datei — gemappt oder eingelesen.
+enum ConfigBytes {
+    #[cfg(any(unix, windows))]
+    Mapped(memmap2::Mmap),
+    Owned(Vec<u8>),
+}
+
+impl ConfigBytes {
+    /// Oeffnet die Datei; grosse Dateien werden in den Speicher gemappt, kleine
+    /// (und solche, deren Mapping fehlschlaegt) eingelesen.
+    fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("Config-Datei '{}' nicht lesbar", path.display()))?;
+
+        #[cfg(any(unix, windows))]
+        if file
+            .metadata()
+            .is_ok_and(|meta| meta.len() >= MMAP_MIN_FILE_SIZE)
+        {
+            // SAFETY: Das Mapping wird nur gelesen und lebt nur fuer die Dauer des
+            // Parsens. Rust kann nicht garantieren, dass die Datei waehrenddessen
+            // unveraendert bleibt: Inhaltliche Aenderungen fuehren zu inkonsistenten
+            // Bytes, die der Parser ablehnt (UTF-8-Pruefung, XML-Syntax,
+            // Listenlaengen). Wird die Datei jedoch extern gekuerzt, loest der Zugriff
+            // auf Seiten hinter dem neuen Dateiende auf Unix SIGBUS aus (Windows
+            // verhindert das Kuerzen gemappter Dateien). Dieses Restrisiko wird nur
+            // fuer grosse Configs ab `MMAP_MIN_FILE_SIZE` in Kauf genommen, bei denen
+            // die eingesparte Vollkopie ins Gewicht faellt; das Spiel schreibt die
+            // Config nur beim Speichern des Savegames.
+            match unsafe { memmap2::Mmap::map(&file) } {
+                Ok(mmap) => return Ok(Self::Mapped(mmap)),
+                Err(error) => log::debug!(
+                    "Memory-Mapping von '{}' fehlgeschlagen, lese Datei ein: {error}",
+                    path.display()
+                ),
+            }
+        }
+
+        Self::read(file, path)
+    }
+
+    fn read(mut file: File, path: &Path) -> Result<Self> {
+        use std::io::Read;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)
+            .with_context(|| format!("Config-Datei '{}' nicht lesbar", path.display()))?;
+        Ok(Self::Owned(bytes))
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            #[cfg(any(unix, windows))]
+            Self::Mapped(mmap) => mmap,
+            Self::Owned(bytes) => bytes,
+        }
+    }
+
+    fn is_mapped(&self) -> bool {
+        match self {
+            #[cfg(any(unix, windows))]
+            Self::Mapped(_) => true,
+            Self::Owned(_) => false,
+        }
+    }
+}
+
+/// Laedt und parst eine AutoDrive-Konfiguration direkt aus einer Datei.
+///
+/// Die Datei wird nach Moeglichkeit per Memory-Mapping gelesen und ohne
+/// Zwischenkopie aus dem gemappten Slice geparst. Ist mmap nicht verfuegbar,
+/// wird die Datei vollstaendig eingelesen.
+///
+/// # Fehler
+/// - Datei nicht lesbar
+/// - Inhalt ist kein gueltiges UTF-8
+/// - Parsing-Fehler aus [`parse_autodrive_config`]
+pub fn load_autodrive_config(path: impl AsRef<Path>) -> Result<RoadMap> {
+    let path = path.as_ref();
+    let bytes = ConfigBytes::open(path)?;
+    log::debug!(
+        "Config '{}' geladen: {} Bytes, mapped={}",
+        path.display(),
+        bytes.as_bytes().len(),
+        bytes.is_mapped()
+    );
+
+    let xml_content = std::str::from_utf8(bytes.as_bytes())
+        .with_context(|| format!("Config-Datei '{}' ist kein gueltiges UTF-8", path.display()))?;
+    parse_autodrive_config(xml_content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<AutoDrive version="3">
+        <waypoints>
+            <id>1,2</id>
+            <x>0,10</x>
+            <y>0,0</y>
+            <z>0,5</z>
+            <out>2;</out>
+            <incoming>;1</incoming>
+            <flags>0,0</flags>
+        </waypoints>
+    </AutoDrive>"#;
+
+    fn temp_path(label: &str) -> std::path::PathBuf {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Systemzeit darf nicht vor Unix-Epoche liegen")
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "fs25_ad_editor_{label}_{}_{}.xml",
+            std::process::id(),
+            timestamp
+        ))
+    }
+
+    #[test]
+    fn load_matches_string_parsing() {
+        let path = temp_path("mmap_load");
+        std::fs::write(&path, SAMPLE).expect("Testdatei schreibbar");
+
+        let loaded = load_autodrive_config(&path).expect("Config ladbar");
+        let parsed = parse_autodrive_config(SAMPLE).expect("Config parsbar");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.node_count(), parsed.node_count());
+        assert_eq!(loaded.connection_count(), parsed.connection_count());
+    }
+
+    #[test]
+    fn small_files_are_read_instead_of_mapped() {
+        let path = temp_path("mmap_small");
+        std::fs::write(&path, SAMPLE).expect("Testdatei schreibbar");
+
+        let bytes = ConfigBytes::open(&path).expect("Datei lesbar");
+        std::fs::remove_file(&path).ok();
+
+        assert!(!bytes.is_mapped());
+        assert_eq!(bytes.as_bytes(), SAMPLE.as_bytes());
+    }
+
+    #[test]
+    fn fallback_read_yields_same_bytes() {
+        let path = temp_path("mmap_fallback");
+        std::fs::write(&path, SAMPLE).expect("Testdatei schreibbar");
+
+        let file = File::open(&path).expect("Testdatei oeffnbar");
+        let bytes = ConfigBytes::read(file, &path).expect("Datei lesbar");
+        std::fs::remove_file(&path).ok();
+
+        assert!(!bytes.is_mapped());
+        assert_eq!(bytes.as_bytes(), SAMPLE.as_bytes());
+    }
+
+    #[test]
+    fn load_rejects_invalid_utf8() {
+        let path = temp_path("mmap_utf8");
+        std::fs::write(&path, [0x3c, 0xff, 0xfe, 0x3e]).expect("Testdatei schreibbar");
+
+        let error = load_autodrive_config(&path).expect_err("Ungueltiges UTF-8 muss scheitern");
+        std::fs::remove_file(&path).ok();
+
+        assert!(format!("{error:#}").contains("UTF-8"));
+    }
+}
@@ -0,0 +1,129 @@
+//! Parser fuer Fahrzeugpositionen aus der `vehicles.xml` eines Savegames.
+//!
+//! Jedes `<vehicle>` verweist per `filename` auf seine Fahrzeug-XML und
+//! speichert die Lage seiner Komponenten als `<component position="x y z"
+//! rotation="rx ry rz" />`. Massgeblich fuer die Anzeige ist die erste
+//! Komponente (Hauptkoerper); Fahrzeuge ohne Komponente werden uebersprungen.
+
+use crate::core::SavegameVehicle;
+use anyhow::{anyhow, Result};
+use glam::Vec2;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::path::Path;
+
+/// Parst die `vehicles.xml` eines Savegames.
+///
+/// Der Anzeigename ist der Dateiname der Fahrzeug-XML ohne Endung
+/// (z.B. `fendt942Vario`). Gibt einen Fehler nur bei ungueltigem XML zurueck;
+/// eine Datei ohne Fahrzeuge ergibt eine leere Liste.
+pub fn parse_savegame_vehicles(xml_content: &str) -> Result<Vec<SavegameVehicle>> {
+    let mut reader = Reader::from_str(xml_content);
+    reader.config_mut().trim_text(true);
+
+    let mut vehicles = Vec::new();
+    // Name und (sobald gelesen) Lage des aktuell offenen <vehicle>
+    let mut current: Option<(String, Option<(Vec2, f32)>)> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"vehicle" => {
+                current = Some((vehicle_name(e), None));
+            }
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"component" => {
+                if let Some((_, pose @ None)) = current.as_mut() {
+                    *pose = component_pose(e);
+                }
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"vehicle" => {
+                if let Some((name, Some((position, yaw)))) = current.take() {
+                    vehicles.push(SavegameVehicle {
+                        name,
+                        position,
+                        yaw,
+                    });
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(anyhow!("XML-Lesefehler: {}", e)),
+            _ => {}
+        }
+    }
+
+    Ok(vehicles)
+}
+
+/// Anzeigename aus dem `filename`-Attribut (Dateiname ohne Endung).
+fn vehicle_name(element: &BytesStart) -> String {
+    attr(element, "filename")
+        .and_then(|filename| {
+            Path::new(&filename)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "?".to_string())
+}
+
+/// Position (X/Z) und Gierwinkel einer `<component>`; `None` ohne gueltige Position.
+fn component_pose(element: &BytesStart) -> Option<(Vec2, f32)> {
+    let position = parse_triple(&attr(element, "position")?)?;
+    let yaw = attr(element, "rotation")
+        .and_then(|rotation| parse_triple(&rotation))
+        .map_or(0.0, |[_, ry, _]| ry);
+    Some((Vec2::new(position[0], position[2]), yaw))
+}
+
+/// Parst drei leerzeichengetrennte Zahlen.
+fn parse_triple(text: &str) -> Option<[f32; 3]> {
+    let mut parts = text.split_whitespace().map(|part| part.parse::<f32>().ok());
+    let triple = [parts.next()??, parts.next()??, parts.next()??];
+    triple.iter().all(|v| v.is_finite()).then_some(triple)
+}
+
+fn attr(element: &BytesStart, key: &str) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key.as_bytes())
+        .map(|attr| String::from_utf8_lossy(&attr.value).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_XML: &str = r#"<?xml version="1.0" encoding="utf-8" standalone="no"?>
+<vehicles>
+    <vehicle uniqueId="vehicle1" filename="data/vehicles/fendt/fendt942Vario/fendt942Vario.xml" farmId="1">
+        <component index="1" position="-120.5 95.2 340.25" rotation="0 1.25 0"/>
+        <component index="2" position="-118.0 95.0 338.0" rotation="0 0 0"/>
+        <wheels/>
+    </vehicle>
+    <vehicle uniqueId="vehicle2" filename="$moddir$FS25_Trailer/trailer.xml" farmId="1">
+        <component index="1" position="10 80 -20"/>
+    </vehicle>
+    <vehicle uniqueId="vehicle3" filename="data/vehicles/pallet.xml"/>
+</vehicles>"#;
+
+    #[test]
+    fn parses_name_position_and_yaw_of_first_component() {
+        let vehicles = parse_savegame_vehicles(SAMPLE_XML).expect("Parsen sollte klappen");
+
+        assert_eq!(vehicles.len(), 2);
+        assert_eq!(vehicles[0].name, "fendt942Vario");
+        assert_eq!(vehicles[0].position, Vec2::new(-120.5, 340.25));
+        assert!((vehicles[0].yaw - 1.25).abs() < 1e-6);
+        assert!(
+            (vehicles[0].direction() - Vec2::new(1.25f32.sin(), 1.25f32.cos())).length() < 1e-6
+        );
+
+        assert_eq!(vehicles[1].name, "trailer");
+        assert_eq!(vehicles[1].position, Vec2::new(10.0, -20.0));
+        assert_eq!(vehicles[1].yaw, 0.0);
+    }
+
+    #[test]
+    fn rejects_malformed_xml() {
+        assert!(parse_savegame_vehicles("<vehicles><vehicle></vehicles>").is_err());
+    }
+}
@@ -15,41 +15,41 @@ pub fn write_autodrive_config(
     heightmap: Option<&Heightmap>,
     terrain_height_scale: f32,
 ) -> Result<String> {
-    let mut output = String::new();
-    output.push_str("<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"no\"?>\n");
-    output.push_str("<AutoDrive>\n");
-
-    if let Some(ref config_version) = road_map.meta.config_version {
-        output.push_str(&format!(
-            "    <version>{}</version>\n",
-            escape_xml(config_version)
-        ));
-    }
-
-    if let Some(ref map_name) = road_map.map_name {
-        output.push_str(&format!(
-            "    <MapName>{}</MapName>\n",
-            escape_xml(map_name)
-        ));
-    }
+    write_document(road_map, heightmap, terrain_height_scale, "AutoDrive", true)
+}
 
-    if let Some(ref route_version) = road_map.meta.route_version {
-        output.push_str(&format!(
-            "    <ADRouteVersion>{}</ADRouteVersion>\n",
-            escape_xml(route_version)
-        ));
-    }
+/// Schreibt eine RoadMap als Routen-Export fuer den AutoDrive-Routen-Manager.
+///
+/// Enthaelt nur Wegpunkte und Marker unter `<routeExport>`; Version, Map-Name
+/// und Optionen gehoeren zur Config des Savegames und werden weggelassen.
+pub fn write_autodrive_route(
+    road_map: &RoadMap,
+    heightmap: Option<&Heightmap>,
+    terrain_height_scale: f32,
+) -> Result<String> {
+    write_document(
+        road_map,
+        heightmap,
+        terrain_height_scale,
+        "routeExport",
+        false,
+    )
+}
 
-    if let Some(ref route_author) = road_map.meta.route_author {
-        output.push_str(&format!(
-            "    <ADRouteAuthor>{}</ADRouteAuthor>\n",
-            escape_xml(route_author)
-        ));
-    }
+/// Schreibt Wegpunkte und Marker unter `root_tag`, optional mit Config-Metadaten.
+fn write_document(
+    road_map: &RoadMap,
+    heightmap: Option<&Heightmap>,
+    terrain_height_scale: f32,
+    root_tag: &str,
+    with_meta: bool,
+) -> Result<String> {
+    let mut output = String::new();
+    output.push_str("<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"no\"?>\n");
+    output.push_str(&format!("<{}>\n", root_tag));
 
-    // Options in Original-Reihenfolge schreiben
-    for (key, value) in &road_map.meta.options {
-        output.push_str(&format!("    <{}>{}</{}>\n", key, escape_xml(value), key));
+    if with_meta {
+        write_meta(&mut output, road_map);
     }
 
     let mut node_ids: Vec<u64> = road_map.node_ids().collect();
@@ -111,8 +111,10 @@ pub fn write_autodrive_config(
         ids_text.push(new_id.to_string());
         xs_text.push(format_float(node.position.x));
 
-        // Y-Koordinate: Aus Heightmap berechnen oder 0.0
-        let y_value = if let Some(hm) = heightmap {
+        // Y-Koordinate: Manueller Wert hat Vorrang, sonst Heightmap oder 0.0
+        let y_value = if let Some(height) = road_map.node_height_override(*id) {
+            height
+        } else if let Some(hm) = heightmap {
             let height = hm.sample_height(node.position.x, node.position.y, terrain_height_scale);
 
             // Debug: Zeige erste 10 Y-Werte zur Kontrolle
@@ -214,11 +216,47 @@ pub fn write_autodrive_config(
     }
     output.push_str("    </mapmarker>\n");
 
-    output.push_str("</AutoDrive>\n");
+    output.push_str(&format!("</{}>\n", root_tag));
 
     Ok(output)
 }
 
+/// Schreibt Version, Map-Name, Routen-Metadaten und Optionen der Config.
+fn write_meta(output: &mut String, road_map: &RoadMap) {
+    if let Some(ref config_version) = road_map.meta.config_version {
+        output.push_str(&format!(
+            "    <version>{}</version>\n",
+            escape_xml(config_version)
+        ));
+    }
+
+    if let Some(ref map_name) = road_map.map_name {
+        output.push_str(&format!(
+            "    <MapName>{}</MapName>\n",
+            escape_xml(map_name)
+        ));
+    }
+
+    if let Some(ref route_version) = road_map.meta.route_version {
+        output.push_str(&format!(
+            "    <ADRouteVersion>{}</ADRouteVersion>\n",
+            escape_xml(route_version)
+        ));
+    }
+
+    if let Some(ref route_author) = road_map.meta.route_author {
+        output.push_str(&format!(
+            "    <ADRouteAuthor>{}</ADRouteAuthor>\n",
+            escape_xml(route_author)
+        ));
+    }
+
+    // Options in Original-Reihenfolge schreiben
+    for (key, value) in &road_map.meta.options {
+        output.push_str(&format!("    <{}>{}</{}>\n", key, escape_xml(value), key));
+    }
+}
+
 fn join_ids(ids: &[u64]) -> String {
     use std::fmt::Write;
     let mut result = String::new();
@@ -235,7 +273,7 @@ fn format_float(value: f32) -> String {
     format!("{:.3}", value)
 }
 
-fn escape_xml(value: &str) -> String {
+pub(super) fn escape_xml(value: &str) -> String {
     value
         .replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -257,4 +295,26 @@ mod tests {
         assert_eq!(format_float(-50.123_456), "-50.123");
         assert_eq!(format_float(1_234.999_9), "1235.000");
     }
+
+    #[test]
+    fn test_height_override_is_written_as_y() {
+        use crate::core::{MapNode, NodeFlag};
+
+        let mut map = RoadMap::new(3);
+        map.add_node(MapNode::new(
+            1,
+            glam::Vec2::new(1.0, 2.0),
+            NodeFlag::Regular,
+        ));
+        map.add_node(MapNode::new(
+            2,
+            glam::Vec2::new(3.0, 4.0),
+            NodeFlag::Regular,
+        ));
+        map.set_node_height_override(2, 42.5);
+
+        let xml = write_autodrive_config(&map, None, 255.0).expect("Export erwartet");
+
+        assert!(xml.contains("<y>0.000,42.500</y>"), "{xml}");
+    }
 }
@@ -44,6 +44,8 @@ impl EditorApp {
             dialog_state.options.language,
         ));
         events.extend(ui::show_zip_browser(ctx, dialog_state.ui));
+        events.extend(ui::show_routes_dialog(ctx, dialog_state.ui));
+        events.extend(ui::show_savegame_picker(ctx, dialog_state.ui));
         events.extend(ui::show_overview_options_dialog(
             ctx,
             &mut dialog_state.ui.overview_options_dialog,
@@ -51,6 +53,10 @@ impl EditorApp {
         events.extend(ui::show_post_load_dialog(ctx, dialog_state.ui));
         events.extend(ui::show_save_overview_dialog(ctx, dialog_state.ui));
         events.extend(ui::show_trace_all_fields_dialog(ctx, dialog_state.ui));
+        events.extend(ui::show_field_course_dialog(ctx, dialog_state.ui));
+        events.extend(ui::show_move_selection_dialog(ctx, dialog_state.ui));
+        events.extend(ui::show_delete_by_filter_dialog(ctx, dialog_state.ui));
+        events.extend(ui::show_pdf_export_dialog(ctx, dialog_state.ui));
         events.extend(ui::show_group_settings_popup(
             ctx,
             &mut dialog_state.ui.group_settings_popup,
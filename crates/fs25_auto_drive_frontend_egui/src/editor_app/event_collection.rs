@@ -69,6 +69,11 @@ impl EditorApp {
                         .into_iter()
                         .map(map_intent_to_collected_event),
                 );
+                self.render_profiling_overlay(
+                    ui.ctx(),
+                    rect,
+                    host_chrome_snapshot.options.language,
+                );
             });
 
         events
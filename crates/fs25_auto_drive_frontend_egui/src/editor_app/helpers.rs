@@ -10,6 +10,9 @@ use fs25_auto_drive_host_bridge::{
 
 use super::EditorApp;
 
+/// Abfrageintervall der Live-Verbindung, solange sie in den Optionen aktiviert ist.
+const LIVE_LINK_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
 fn split_render_frame_for_egui(
     frame: HostRenderFrameSnapshot,
 ) -> (render::WgpuRenderData, crate::shared::RenderAssetsSnapshot) {
@@ -40,7 +43,9 @@ impl EditorApp {
         rect: egui::Rect,
         viewport_size: [f32; 2],
     ) {
+        let build_started = std::time::Instant::now();
         let frame = self.session.build_render_frame(viewport_size);
+        self.profiling.record_scene_build(build_started.elapsed());
         let (render_data, assets) = split_render_frame_for_egui(frame);
         self.pending_render_assets = Some(assets);
 
@@ -127,6 +132,9 @@ impl EditorApp {
             &dialog_snapshot,
         ) {
             ctx.request_repaint();
+        } else if chrome_snapshot.options.live_link_enabled {
+            // Ohne Frames wuerde der Socket nicht abgefragt
+            ctx.request_repaint_after(LIVE_LINK_POLL_INTERVAL);
         }
     }
 }
@@ -8,6 +8,7 @@ mod panel_collector;
 /// Processor-Gegenstueck zu den Collector-Modulen: Event-Dispatch nach der
 /// Collector-Phase (siehe `processor.rs`).
 mod processor;
+mod profiling;
 mod viewport_collector;
 
 use crate::app::{use_cases, AppIntent};
@@ -41,6 +42,8 @@ pub(crate) struct EditorApp {
     pending_render_assets: Option<crate::shared::RenderAssetsSnapshot>,
     /// Gecachte egui-Textur-Handles fuer Gruppen-Boundary-Icons (lazy initialisiert).
     group_boundary_icons: Option<ui::GroupBoundaryIcons>,
+    /// Messwerte und Sichtbarkeit des Performance-HUD.
+    profiling: profiling::ProfilingState,
 }
 
 impl EditorApp {
@@ -69,6 +72,7 @@ impl EditorApp {
             last_background_transform_revision: 0,
             pending_render_assets: None,
             group_boundary_icons: None,
+            profiling: profiling::ProfilingState::default(),
         }
     }
 }
@@ -83,10 +87,18 @@ impl eframe::App for EditorApp {
         }
 
         self.pending_render_assets = None;
+        self.profiling
+            .begin_frame(crate::core::spatial_query_count());
+
+        // Live-Verbindung vor dem Event-Sammeln abfragen, damit die Position aktuell ist
+        let live_link_changed = self.session.poll_live_link().unwrap_or_else(|e| {
+            log::warn!("Live-Verbindung fehlgeschlagen: {:#}", e);
+            false
+        });
 
         let events = self.collect_ui_events(&ctx);
 
-        let has_meaningful_events = events.iter().any(is_meaningful_event);
+        let has_meaningful_events = live_link_changed || events.iter().any(is_meaningful_event);
 
         self.process_events(&ctx, events);
 
@@ -1,4 +1,4 @@
-//! Overlay-Rendering fuer Gruppen, Boundaries, Vorschau und Distanzen.
+//! Overlay-Rendering fuer Gruppen, Boundaries, Vorschau, Distanzen, Messlinie und Terrain-Warnungen.
 
 use crate::app::{AppIntent, Camera2D};
 use crate::ui;
@@ -43,6 +43,17 @@ impl EditorApp {
             .session
             .build_viewport_overlay_snapshot(self.last_cursor_world);
 
+        // ── Eigene Farmlands (unter allen anderen Overlays) ──
+        if !overlay_snapshot.owned_farmlands.is_empty() {
+            ui::render_owned_farmland_overlays(
+                &ui.painter_at(rect),
+                rect,
+                &camera,
+                vp,
+                &overlay_snapshot.owned_farmlands,
+            );
+        }
+
         // ── Tool-Preview-Overlay ─────────────
         if let Some(preview) = overlay_snapshot.route_tool_preview.as_ref() {
             let painter = ui.painter_at(rect);
@@ -74,6 +85,22 @@ impl EditorApp {
             );
         }
 
+        // ── Versatz-Vorschau-Overlay ────────────
+        if let Some(path_offset_preview) = overlay_snapshot.path_offset_preview.as_ref() {
+            ui::paint_preview_polyline(
+                &ui.painter_at(rect),
+                rect,
+                &camera,
+                vp,
+                &path_offset_preview.points,
+            );
+        }
+
+        // ── Mess-Overlay (Lineal) ─────────────
+        if let Some(measure) = overlay_snapshot.measure.as_ref() {
+            ui::render_measure_overlay(&ui.painter_at(rect), rect, &camera, vp, measure);
+        }
+
         // ── Segment-Overlay ──────────────────
         if !overlay_snapshot.group_locks.is_empty() {
             // Klick nur weiterreichen wenn der Response einen Klick registriert hat
@@ -106,6 +133,29 @@ impl EditorApp {
             }
         }
 
+        // ── Selektierte Verbindung ────────────────
+        if let Some(selected_connection) = overlay_snapshot.selected_connection.as_ref() {
+            ui::render_selected_connection_overlay(
+                &ui.painter_at(rect),
+                rect,
+                &camera,
+                vp,
+                selected_connection,
+                chrome_snapshot.options.node_color_selected,
+            );
+        }
+
+        // ── Gesperrte Nodes ───────────────────────
+        if !overlay_snapshot.locked_nodes.is_empty() {
+            ui::render_locked_node_overlays(
+                &ui.painter_at(rect),
+                rect,
+                &camera,
+                vp,
+                &overlay_snapshot.locked_nodes,
+            );
+        }
+
         // ── Gruppen-Boundary-Overlay ──────────────────
         if !overlay_snapshot.group_boundaries.is_empty() {
             // Icons lazy initialisieren (benoetigen egui::Context)
@@ -126,6 +176,38 @@ impl EditorApp {
             }
         }
 
+        // ── Savegame-Fahrzeuge ────────────────────
+        if !overlay_snapshot.vehicles.is_empty() {
+            ui::render_vehicle_overlays(
+                &ui.painter_at(rect),
+                rect,
+                &camera,
+                vp,
+                &overlay_snapshot.vehicles,
+            );
+        }
+
+        // ── Live-Spielerposition ──────────────────
+        if let Some(live_player) = &overlay_snapshot.live_player {
+            ui::render_live_player_overlay(&ui.painter_at(rect), rect, &camera, vp, live_player);
+        }
+
+        // ── Terrain-Warn-Overlay ──────────────────
+        if !overlay_snapshot.terrain_clipping.is_empty() {
+            let hovered = ui::render_terrain_clipping_overlays(
+                &ui.painter_at(rect),
+                rect,
+                &camera,
+                vp,
+                &overlay_snapshot.terrain_clipping,
+                response.hover_pos(),
+                chrome_snapshot.options.language,
+            );
+            if let Some(text) = hovered {
+                response.clone().on_hover_text_at_pointer(text);
+            }
+        }
+
         if overlay_snapshot.show_no_file_hint {
             ui.painter().text(
                 rect.center(),
@@ -22,9 +22,13 @@ impl EditorApp {
         let should_close_floating_menu = {
             ui::status::render_status_bar_inside(top_ui, host_chrome_snapshot);
             events.extend(
-                ui::menu::render_menu_inside(top_ui, host_chrome_snapshot)
-                    .into_iter()
-                    .map(map_intent_to_collected_event),
+                ui::menu::render_menu_inside(
+                    top_ui,
+                    host_chrome_snapshot,
+                    &mut self.profiling.visible,
+                )
+                .into_iter()
+                .map(map_intent_to_collected_event),
             );
             let (floating_events, should_close) = ui::render_floating_menu(
                 ctx,
@@ -62,8 +66,10 @@ impl EditorApp {
         let node_details = selected_node_ids
             .first()
             .and_then(|&node_id| self.session.node_details(node_id));
-        let connection_pair = match selected_node_ids.as_slice() {
-            [node_a, node_b] => Some(self.session.connection_pair(*node_a, *node_b)),
+        let selected_connection = self.session.app_state().selection.selected_connection;
+        let connection_pair = match (selected_node_ids.as_slice(), selected_connection) {
+            ([node_a, node_b], _) => Some(self.session.connection_pair(*node_a, *node_b)),
+            ([], Some((start_id, end_id))) => Some(self.session.connection_pair(start_id, end_id)),
             _ => None,
         };
         let panel_state = self.session.panel_properties_state_mut();
@@ -104,6 +110,7 @@ impl EditorApp {
                                         road_map: panel_state.road_map,
                                         selected_node_ids: panel_state.selected_node_ids,
                                         node_details: node_details.as_ref(),
+                                        selected_connection,
                                         connection_pair: connection_pair.as_ref(),
                                         default_direction: panel_state.default_direction,
                                         default_priority: panel_state.default_priority,
@@ -111,6 +118,7 @@ impl EditorApp {
                                         group_registry: Some(panel_state.group_registry),
                                         tool_edit_store: Some(panel_state.tool_edit_store),
                                         distance_state: panel_state.distanzen,
+                                        path_offset: panel_state.path_offset,
                                     },
                                 )
                                 .into_iter()
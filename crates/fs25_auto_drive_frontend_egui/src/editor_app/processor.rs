@@ -68,6 +68,11 @@ impl EditorApp {
     /// direkt ueber die Session, `AppIntent` ueber `dispatch_intent_via_session(...)`.
     pub(super) fn process_events(&mut self, ctx: &egui::Context, events: Vec<CollectedEvent>) {
         for event in events {
+            let copies_selection = matches!(
+                event,
+                CollectedEvent::HostAction(HostSessionAction::CopySelection)
+                    | CollectedEvent::Intent(AppIntent::CopySelectionRequested)
+            );
             match event {
                 CollectedEvent::HostAction(action) => {
                     if let Err(e) = self.session.apply_action(action) {
@@ -87,6 +92,17 @@ impl EditorApp {
                     }
                 }
             }
+            if copies_selection {
+                self.export_clipboard_to_system(ctx);
+            }
+        }
+    }
+
+    /// Spiegelt die interne Zwischenablage als Editor-JSON in die System-Zwischenablage,
+    /// damit die Kopie in anderen Dateien bzw. Editor-Instanzen eingefuegt werden kann.
+    fn export_clipboard_to_system(&self, ctx: &egui::Context) {
+        if let Some(json) = self.session.clipboard_json() {
+            ctx.copy_text(json);
         }
     }
 }
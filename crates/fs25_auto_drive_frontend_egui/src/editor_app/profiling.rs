@@ -0,0 +1,140 @@
+//! Messwerte fuer das Performance-HUD (Frame-Zeit, Szenen-Aufbau, Spatial-Abfragen).
+
+use crate::render::RenderStats;
+use crate::shared::{I18nKey, Language};
+use crate::ui;
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+use super::EditorApp;
+
+/// Gewicht eines neuen Messwerts im exponentiell gleitenden Mittel.
+const SMOOTHING: f32 = 0.1;
+
+/// Host-lokaler Profiling-Zustand der egui-App.
+///
+/// Wird ueber das Debug-Menue ein-/ausgeblendet; die Messung laeuft immer mit,
+/// damit das HUD beim Einblenden sofort stabile Werte zeigt.
+#[derive(Debug, Default)]
+pub(super) struct ProfilingState {
+    /// Sichtbarkeit des HUD (Debug-Menue).
+    pub(super) visible: bool,
+    frame_time_ms: f32,
+    scene_build_ms: f32,
+    last_frame_start: Option<Instant>,
+    last_spatial_query_total: u64,
+    engine_spatial_queries: u64,
+}
+
+impl ProfilingState {
+    /// Markiert den Beginn eines neuen egui-Frames.
+    ///
+    /// Misst den Abstand zum vorherigen Frame und die Engine-Spatial-Abfragen,
+    /// die seitdem angefallen sind.
+    pub(super) fn begin_frame(&mut self, spatial_query_total: u64) {
+        let now = Instant::now();
+        if let Some(previous) = self.last_frame_start.replace(now) {
+            self.frame_time_ms = smooth(self.frame_time_ms, millis(now - previous));
+        }
+        self.engine_spatial_queries =
+            spatial_query_total.saturating_sub(self.last_spatial_query_total);
+        self.last_spatial_query_total = spatial_query_total;
+    }
+
+    /// Verbucht die Dauer eines Render-Szenen-Aufbaus.
+    pub(super) fn record_scene_build(&mut self, elapsed: Duration) {
+        self.scene_build_ms = smooth(self.scene_build_ms, millis(elapsed));
+    }
+
+    /// Baut die HUD-Daten aus den eigenen Messwerten und den Renderer-Zaehlern.
+    pub(super) fn hud_data(&self, render_stats: RenderStats) -> ui::ProfilingHudData {
+        ui::ProfilingHudData {
+            frame_time_ms: self.frame_time_ms,
+            scene_build_ms: self.scene_build_ms,
+            renderers: vec![
+                ui::ProfilingRendererRow {
+                    label: I18nKey::StatusMarkers,
+                    uploaded: render_stats.markers.uploaded,
+                    drawn: render_stats.markers.drawn,
+                },
+                ui::ProfilingRendererRow {
+                    label: I18nKey::StatusConnections,
+                    uploaded: render_stats.connections.uploaded,
+                    drawn: render_stats.connections.drawn,
+                },
+                ui::ProfilingRendererRow {
+                    label: I18nKey::StatusNodes,
+                    uploaded: render_stats.nodes.uploaded,
+                    drawn: render_stats.nodes.drawn,
+                },
+            ],
+            background_tiles: render_stats.background_tiles,
+            engine_spatial_queries: self.engine_spatial_queries,
+            render_spatial_queries: render_stats.spatial_queries(),
+        }
+    }
+}
+
+impl EditorApp {
+    /// Zeichnet das Performance-HUD, falls es ueber das Debug-Menue aktiviert ist.
+    ///
+    /// Die Renderer-Zaehler stammen aus dem zuletzt ausgefuehrten Paint-Callback
+    /// und hinken dem aktuellen Frame daher um einen Frame hinterher.
+    pub(super) fn render_profiling_overlay(
+        &self,
+        ctx: &egui::Context,
+        rect: egui::Rect,
+        lang: Language,
+    ) {
+        if !self.profiling.visible {
+            return;
+        }
+
+        let render_stats = match self.renderer.lock() {
+            Ok(renderer) => renderer.last_frame_stats(),
+            Err(_) => {
+                log::error!("Renderer-Lock fehlgeschlagen (Mutex vergiftet)");
+                return;
+            }
+        };
+
+        ui::render_profiling_overlay(ctx, rect, &self.profiling.hud_data(render_stats), lang);
+    }
+}
+
+fn millis(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+fn smooth(current: f32, sample: f32) -> f32 {
+    if current <= 0.0 {
+        sample
+    } else {
+        current + (sample - current) * SMOOTHING
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn begin_frame_reports_spatial_query_delta() {
+        let mut state = ProfilingState::default();
+        state.begin_frame(10);
+        state.begin_frame(25);
+
+        let data = state.hud_data(RenderStats::default());
+        assert_eq!(data.engine_spatial_queries, 15);
+    }
+
+    #[test]
+    fn scene_build_time_is_smoothed() {
+        let mut state = ProfilingState::default();
+        state.record_scene_build(Duration::from_millis(10));
+        state.record_scene_build(Duration::from_millis(20));
+
+        let data = state.hud_data(RenderStats::default());
+        assert!((data.scene_build_ms - 11.0).abs() < 1e-3);
+    }
+}
@@ -126,6 +126,17 @@ impl EditorApp {
                 ));
             }
 
+            // R → Vorschau drehen (Shift+R gegen den Uhrzeigersinn)
+            let (rotate_pressed, shift) =
+                ui.input(|i| (i.key_pressed(egui::Key::R), i.modifiers.shift));
+            if rotate_pressed {
+                events.push(map_intent_to_collected_event(
+                    AppIntent::PastePreviewRotateRequested {
+                        counter_clockwise: shift,
+                    },
+                ));
+            }
+
             // Esc → Vorschau abbrechen
             if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
                 events.push(map_intent_to_collected_event(AppIntent::PasteCancelled));
@@ -178,12 +189,15 @@ impl EditorApp {
         // Mauszeiger im Viewport je nach aktivem Werkzeug anpassen
         if response.hovered() {
             match viewport_state.active_tool {
-                EditorTool::AddNode => {
+                EditorTool::AddNode | EditorTool::Measure => {
                     ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
                 }
                 EditorTool::Connect => {
                     ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeNwSe);
                 }
+                EditorTool::Knife => {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::Cell);
+                }
                 _ => {}
             }
         }
@@ -6,6 +6,7 @@ pub use callback::{WgpuRenderCallback, WgpuRenderData};
 pub use fs25_auto_drive_render_wgpu::BackgroundWorldBounds;
 pub use fs25_auto_drive_render_wgpu::RenderQuality;
 pub use fs25_auto_drive_render_wgpu::RenderScene;
+pub use fs25_auto_drive_render_wgpu::RenderStats;
 pub use fs25_auto_drive_render_wgpu::RendererTargetConfig;
 
 /// Egui-Host-Adapter fuer den host-neutralen Renderer-Kern.
@@ -36,6 +37,11 @@ impl Renderer {
         self.core.render_scene(device, queue, render_pass, scene);
     }
 
+    /// Zaehlwerte des zuletzt gerenderten Frames (fuer das Performance-HUD).
+    pub fn last_frame_stats(&self) -> RenderStats {
+        self.core.last_frame_stats()
+    }
+
     /// Setzt oder aktualisiert das Background-Asset.
     pub fn set_background(
         &mut self,
@@ -18,6 +18,8 @@ pub fn run_native() -> Result<(), eframe::Error> {
         Box::new(|cc| {
             // SVG/Bild-Loader fuer egui installieren (benoetigt fuer Toolbar-Icons)
             egui_extras::install_image_loaders(&cc.egui_ctx);
+            // Ctrl+Plus/Minus gehoeren der Selektion, nicht dem UI-Zoom
+            crate::ui::disable_builtin_zoom_shortcuts(&cc.egui_ctx);
 
             let render_state = cc.wgpu_render_state.as_ref().ok_or_else(|| {
                 anyhow::anyhow!("wgpu nicht verfuegbar: Renderer konnte nicht initialisiert werden")
@@ -77,6 +77,16 @@ fn build_catalog(lang: Language, host_chrome_snapshot: &HostChromeSnapshot) -> V
             "Ctrl+A",
             AppIntent::SelectAllRequested,
         ),
+        palette_entry(
+            t(lang, I18nKey::PaletteGrowSelection).to_owned(),
+            "Ctrl++",
+            AppIntent::GrowSelectionRequested,
+        ),
+        palette_entry(
+            t(lang, I18nKey::PaletteShrinkSelection).to_owned(),
+            "Ctrl+-",
+            AppIntent::ShrinkSelectionRequested,
+        ),
         palette_entry(
             t(lang, I18nKey::PaletteDeleteSelected).to_owned(),
             "Del",
@@ -92,6 +102,16 @@ fn build_catalog(lang: Language, host_chrome_snapshot: &HostChromeSnapshot) -> V
             "Ctrl+V",
             AppIntent::PasteStartRequested,
         ),
+        palette_entry(
+            t(lang, I18nKey::PaletteDuplicate).to_owned(),
+            "Ctrl+D",
+            AppIntent::DuplicateSelectionRequested,
+        ),
+        palette_entry(
+            t(lang, I18nKey::PaletteProjectOntoTerrain).to_owned(),
+            "",
+            AppIntent::ProjectSelectionOntoTerrainRequested { offset: 0.0 },
+        ),
         palette_entry(
             t(lang, I18nKey::PaletteResetCamera).to_owned(),
             "Home",
@@ -118,6 +138,20 @@ fn build_catalog(lang: Language, host_chrome_snapshot: &HostChromeSnapshot) -> V
                 tool: EditorTool::AddNode,
             },
         ),
+        palette_entry(
+            t(lang, I18nKey::PaletteToolKnife).to_owned(),
+            "T",
+            AppIntent::SetEditorToolRequested {
+                tool: EditorTool::Knife,
+            },
+        ),
+        palette_entry(
+            t(lang, I18nKey::PaletteToolMeasure).to_owned(),
+            "T",
+            AppIntent::SetEditorToolRequested {
+                tool: EditorTool::Measure,
+            },
+        ),
     ];
 
     let prefix = t(lang, I18nKey::PaletteRouteToolPrefix);
@@ -408,6 +442,7 @@ mod tests {
         for tool_id in [
             RouteToolId::Bypass,
             RouteToolId::FieldBoundary,
+            RouteToolId::FieldCourse,
             RouteToolId::FieldPath,
             RouteToolId::RouteOffset,
             RouteToolId::ColorPath,
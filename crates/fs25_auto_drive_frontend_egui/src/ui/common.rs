@@ -255,6 +255,10 @@ pub(crate) fn host_route_tool_to_engine(tool: HostRouteToolId) -> RouteToolId {
         HostRouteToolId::RouteOffset => RouteToolId::RouteOffset,
         HostRouteToolId::Rounding => RouteToolId::Rounding,
         HostRouteToolId::ColorPath => RouteToolId::ColorPath,
+        HostRouteToolId::FieldCourse => RouteToolId::FieldCourse,
+        HostRouteToolId::Clothoid => RouteToolId::Clothoid,
+        HostRouteToolId::Arc => RouteToolId::Arc,
+        HostRouteToolId::Junction => RouteToolId::Junction,
     }
 }
 
@@ -264,6 +268,8 @@ pub(crate) fn host_active_tool_to_editor(tool: HostActiveTool) -> EditorTool {
         HostActiveTool::Select => EditorTool::Select,
         HostActiveTool::Connect => EditorTool::Connect,
         HostActiveTool::AddNode => EditorTool::AddNode,
+        HostActiveTool::Knife => EditorTool::Knife,
+        HostActiveTool::Measure => EditorTool::Measure,
         HostActiveTool::Route => EditorTool::Route,
     }
 }
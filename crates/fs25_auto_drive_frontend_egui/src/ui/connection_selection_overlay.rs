@@ -0,0 +1,36 @@
+//! Verbindungs-Selektions-Overlay: Hervorhebung der per Kanten-Klick selektierten Verbindung.
+
+use eframe::egui;
+use glam::Vec2;
+
+use crate::app::ui_contract::SelectedConnectionOverlaySnapshot;
+use crate::app::Camera2D;
+
+/// Linienbreite der Hervorhebung in Pixeln.
+const HIGHLIGHT_WIDTH_PX: f32 = 5.0;
+/// Radius der Endpunkt-Markierungen in Pixeln.
+const ENDPOINT_RADIUS_PX: f32 = 4.0;
+
+/// Zeichnet die selektierte Verbindung in Selektionsfarbe; der End-Node wird
+/// gefuellt, der Start-Node nur umrandet markiert.
+pub fn render_selected_connection_overlay(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlay: &SelectedConnectionOverlaySnapshot,
+    color: [f32; 4],
+) {
+    let to_screen = |world: Vec2| {
+        let screen = camera.world_to_screen(world, viewport_size);
+        egui::pos2(rect.min.x + screen.x, rect.min.y + screen.y)
+    };
+    let color: egui::Color32 =
+        egui::Rgba::from_rgba_unmultiplied(color[0], color[1], color[2], color[3]).into();
+    let start = to_screen(overlay.start_pos);
+    let end = to_screen(overlay.end_pos);
+
+    painter.line_segment([start, end], egui::Stroke::new(HIGHLIGHT_WIDTH_PX, color));
+    painter.circle_stroke(start, ENDPOINT_RADIUS_PX, egui::Stroke::new(2.0, color));
+    painter.circle_filled(end, ENDPOINT_RADIUS_PX, color);
+}
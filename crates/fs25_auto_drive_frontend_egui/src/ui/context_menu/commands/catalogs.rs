@@ -51,7 +51,23 @@ impl MenuCatalog {
         }
     }
 
-    /// EmptyArea: Tool-Auswahl inkl. Route-Tools, optional Streckenteilung.
+    /// Strassenart-Selektion: alle Haupt- bzw. Nebenstrassen-Nodes auswaehlen.
+    fn priority_selection_entries(lang: Language) -> Vec<MenuEntry> {
+        vec![
+            MenuEntry::Command {
+                id: CommandId::SelectMainRoadNodes,
+                label: t(lang, I18nKey::CtxSelectMainRoadNodes).into(),
+                preconditions: vec![],
+            },
+            MenuEntry::Command {
+                id: CommandId::SelectSubPrioNodes,
+                label: t(lang, I18nKey::CtxSelectSubPrioNodes).into(),
+                preconditions: vec![],
+            },
+        ]
+    }
+
+    /// EmptyArea: Tool-Auswahl inkl. Route-Tools und Strassenart-Selektion.
     pub fn for_empty_area(lang: Language) -> Self {
         let entries = vec![
             Self::tool_submenu(lang),
@@ -81,6 +97,10 @@ impl MenuCatalog {
                     },
                 ],
             },
+            MenuEntry::Submenu {
+                label: t(lang, I18nKey::CtxSelectionSubmenu).into(),
+                entries: Self::priority_selection_entries(lang),
+            },
         ];
 
         MenuCatalog { entries }
@@ -122,6 +142,16 @@ impl MenuCatalog {
                 label: t(lang, I18nKey::CtxConnectNodes).into(),
                 preconditions: vec![Precondition::TwoSelectedUnconnected],
             },
+            MenuEntry::Command {
+                id: CommandId::JoinChains,
+                label: t(lang, I18nKey::CtxJoinChains).into(),
+                preconditions: vec![Precondition::IsTwoChains],
+            },
+            MenuEntry::Command {
+                id: CommandId::MergeNodes,
+                label: t(lang, I18nKey::CtxMergeNodes).into(),
+                preconditions: vec![Precondition::AtLeastTwoSelected],
+            },
             // ── Strecke erzeugen (nur bei 2 Nodes) ───────────────
             MenuEntry::Submenu {
                 label: t(lang, I18nKey::CtxCreateRoute).into(),
@@ -172,6 +202,16 @@ impl MenuCatalog {
                         label: t(lang, I18nKey::CtxDirectionInvert).into(),
                         preconditions: vec![Precondition::HasConnectionsBetweenSelected],
                     },
+                    MenuEntry::Command {
+                        id: CommandId::DirectionReversePath,
+                        label: t(lang, I18nKey::CtxDirectionReversePath).into(),
+                        preconditions: vec![Precondition::HasConnectionsBetweenSelected],
+                    },
+                    MenuEntry::Command {
+                        id: CommandId::ReverseChain,
+                        label: t(lang, I18nKey::CtxReverseChain).into(),
+                        preconditions: vec![Precondition::IsResampleableChain],
+                    },
                 ],
             },
             MenuEntry::Submenu {
@@ -198,23 +238,46 @@ impl MenuCatalog {
             MenuEntry::Separator,
             MenuEntry::Submenu {
                 label: t(lang, I18nKey::CtxSelectionSubmenu).into(),
-                entries: vec![
-                    MenuEntry::Command {
-                        id: CommandId::InvertSelection,
-                        label: t(lang, I18nKey::CtxSelectionInvert).into(),
-                        preconditions: vec![],
-                    },
-                    MenuEntry::Command {
-                        id: CommandId::SelectAll,
-                        label: t(lang, I18nKey::CtxSelectAll).into(),
-                        preconditions: vec![],
-                    },
-                    MenuEntry::Command {
-                        id: CommandId::ClearSelection,
-                        label: t(lang, I18nKey::CtxClearSelection).into(),
-                        preconditions: vec![],
-                    },
-                ],
+                entries: [
+                    vec![
+                        MenuEntry::Command {
+                            id: CommandId::InvertSelection,
+                            label: t(lang, I18nKey::CtxSelectionInvert).into(),
+                            preconditions: vec![],
+                        },
+                        MenuEntry::Command {
+                            id: CommandId::SelectAll,
+                            label: t(lang, I18nKey::CtxSelectAll).into(),
+                            preconditions: vec![],
+                        },
+                        MenuEntry::Command {
+                            id: CommandId::SelectPathBetween,
+                            label: t(lang, I18nKey::CtxSelectPathBetween).into(),
+                            preconditions: vec![Precondition::ExactlyTwoSelected],
+                        },
+                        MenuEntry::Command {
+                            id: CommandId::ClearSelection,
+                            label: t(lang, I18nKey::CtxClearSelection).into(),
+                            preconditions: vec![],
+                        },
+                        MenuEntry::Separator,
+                    ],
+                    Self::priority_selection_entries(lang),
+                    vec![
+                        MenuEntry::Separator,
+                        MenuEntry::Command {
+                            id: CommandId::LockSelection,
+                            label: t(lang, I18nKey::CtxLockSelection).into(),
+                            preconditions: vec![Precondition::HasSelection],
+                        },
+                        MenuEntry::Command {
+                            id: CommandId::UnlockSelection,
+                            label: t(lang, I18nKey::CtxUnlockSelection).into(),
+                            preconditions: vec![Precondition::HasSelection],
+                        },
+                    ],
+                ]
+                .concat(),
             },
             // ── Streckenteilung ────────────────────────────────────
             MenuEntry::Separator,
@@ -226,6 +289,43 @@ impl MenuCatalog {
                     Precondition::StreckenteilungActive(false),
                 ],
             },
+            MenuEntry::Command {
+                id: CommandId::EqualizeSpacing,
+                label: t(lang, I18nKey::CtxEqualizeSpacing).into(),
+                preconditions: vec![Precondition::IsResampleableChain],
+            },
+            // ── Ausrichten ─────────────────────────────────────────
+            MenuEntry::Submenu {
+                label: t(lang, I18nKey::CtxAlignSubmenu).into(),
+                entries: vec![
+                    MenuEntry::Command {
+                        id: CommandId::AlignAxisX,
+                        label: t(lang, I18nKey::CtxAlignAxisX).into(),
+                        preconditions: vec![Precondition::AtLeastTwoSelected],
+                    },
+                    MenuEntry::Command {
+                        id: CommandId::AlignAxisZ,
+                        label: t(lang, I18nKey::CtxAlignAxisZ).into(),
+                        preconditions: vec![Precondition::AtLeastTwoSelected],
+                    },
+                    MenuEntry::Command {
+                        id: CommandId::AlignBestFit,
+                        label: t(lang, I18nKey::CtxAlignBestFit).into(),
+                        preconditions: vec![Precondition::AtLeastTwoSelected],
+                    },
+                    MenuEntry::Command {
+                        id: CommandId::AlignEndpointLine,
+                        label: t(lang, I18nKey::CtxAlignEndpointLine).into(),
+                        preconditions: vec![Precondition::AtLeastThreeSelected],
+                    },
+                    MenuEntry::Separator,
+                    MenuEntry::Command {
+                        id: CommandId::DistributeEvenly,
+                        label: t(lang, I18nKey::CtxDistributeEvenly).into(),
+                        preconditions: vec![Precondition::AtLeastThreeSelected],
+                    },
+                ],
+            },
         ]
     }
 
@@ -256,6 +356,11 @@ impl MenuCatalog {
             label: t(lang, I18nKey::CtxPaste).into(),
             preconditions: vec![Precondition::ClipboardHasData],
         });
+        entries.push(MenuEntry::Command {
+            id: CommandId::DuplicateSelection,
+            label: t(lang, I18nKey::CtxDuplicate).into(),
+            preconditions: vec![Precondition::HasSelection],
+        });
         MenuCatalog { entries }
     }
 
@@ -295,6 +400,19 @@ impl MenuCatalog {
                     },
                 ],
             },
+            MenuEntry::Command {
+                id: CommandId::SelectConnected,
+                label: t(lang, I18nKey::CtxSelectConnected).into(),
+                preconditions: vec![Precondition::NodeExists(node_id)],
+            },
+            MenuEntry::Command {
+                id: CommandId::ConvertToRoundabout,
+                label: t(lang, I18nKey::CtxConvertToRoundabout).into(),
+                preconditions: vec![
+                    Precondition::NodeExists(node_id),
+                    Precondition::IsSingleSelectedJunction(node_id),
+                ],
+            },
             MenuEntry::Command {
                 id: CommandId::DeleteSelected,
                 label: t(lang, I18nKey::CtxDeleteSelected).into(),
@@ -320,6 +438,11 @@ impl MenuCatalog {
             label: t(lang, I18nKey::CtxPaste).into(),
             preconditions: vec![Precondition::ClipboardHasData],
         });
+        entries.push(MenuEntry::Command {
+            id: CommandId::DuplicateSelection,
+            label: t(lang, I18nKey::CtxDuplicate).into(),
+            preconditions: vec![Precondition::HasSelection],
+        });
 
         MenuCatalog { entries }
     }
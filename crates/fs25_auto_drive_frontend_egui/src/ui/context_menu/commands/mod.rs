@@ -17,6 +17,7 @@ pub use preconditions::{Precondition, PreconditionContext};
 pub use validation::{validate_entries, ValidatedEntry};
 
 use crate::app::tool_contract::RouteToolId;
+use crate::app::use_cases::editing::NodeAlignMode;
 use crate::app::{AppIntent, ConnectionDirection, ConnectionPriority, EditorTool};
 
 // =============================================================================
@@ -51,9 +52,17 @@ pub enum CommandId {
     EditMarker,
     /// Marker loeschen
     RemoveMarker,
+    /// Alle vom Node erreichbaren Nodes selektieren
+    SelectConnected,
+    /// Kreuzungs-Node in einen Kreisverkehr umwandeln
+    ConvertToRoundabout,
     // ── Selection-Befehle (SelectionOnly + NodeFocused) ─────────────
     /// Zwei Nodes verbinden (nur bei genau 2 unverbundenen)
     ConnectTwoNodes,
+    /// Zwei selektierte Ketten per Spline-Uebergang verbinden
+    JoinChains,
+    /// Selektierte Nodes im Schwerpunkt zusammenfuehren (mind. 2)
+    MergeNodes,
     /// Gerade Strecke erzeugen (2 Nodes)
     RouteStraight,
     /// Geglättete Kurve erzeugen (2 Nodes)
@@ -70,6 +79,10 @@ pub enum CommandId {
     DirectionReverse,
     /// Richtung: Invertieren
     DirectionInvert,
+    /// Richtung: Selektierten Pfad umkehren
+    DirectionReversePath,
+    /// Gesamte Kette umkehren (Start wird Ende)
+    ReverseChain,
     /// Prioritaet: Hauptstrasse
     PriorityRegular,
     /// Prioritaet: Nebenstrasse
@@ -78,12 +91,34 @@ pub enum CommandId {
     RemoveAllConnections,
     /// Streckenteilung (bei selektierten Nodes)
     StreckenteilungMulti,
+    /// Kettennodes auf gleiche Abstaende verteilen (Form bleibt erhalten)
+    EqualizeSpacing,
+    /// Ausrichten: gemeinsamer Z-Wert (X-Linie)
+    AlignAxisX,
+    /// Ausrichten: gemeinsamer X-Wert (Z-Linie)
+    AlignAxisZ,
+    /// Ausrichten: auf Ausgleichsgerade
+    AlignBestFit,
+    /// Ausrichten: auf Gerade zwischen den Endpunkten
+    AlignEndpointLine,
+    /// Nodes gleichmaessig zwischen den Endpunkten verteilen
+    DistributeEvenly,
     /// Selektion invertieren
     InvertSelection,
     /// Alle Nodes auswaehlen
     SelectAll,
+    /// Kuerzesten Pfad zwischen zwei selektierten Nodes auswaehlen
+    SelectPathBetween,
     /// Selektion aufheben
     ClearSelection,
+    /// Alle Nodes an Hauptstrassen-Verbindungen auswaehlen
+    SelectMainRoadNodes,
+    /// Alle Nodes an Nebenstrassen-Verbindungen auswaehlen
+    SelectSubPrioNodes,
+    /// Selektierte Nodes gegen Verschieben/Loeschen sperren
+    LockSelection,
+    /// Sperre der selektierten Nodes aufheben
+    UnlockSelection,
     /// Selektierte Nodes loeschen
     DeleteSelected,
     // ── RouteTool ────────────────────────────────────────────────────
@@ -99,6 +134,8 @@ pub enum CommandId {
     CopySelection,
     /// Einfuegen-Vorschau starten
     PasteHere,
+    /// Selektion duplizieren und Kopie interaktiv platzieren
+    DuplicateSelection,
 
     // ── Segment ──────────────────────────────────────────────────────
     /// Selektiertes Segment nachtraeglich bearbeiten
@@ -225,10 +262,17 @@ impl CommandId {
             Self::RemoveMarker => AppIntent::RemoveMarkerRequested {
                 node_id: ctx.node_id.unwrap_or(0),
             },
+            Self::SelectConnected => AppIntent::SelectConnectedRequested {
+                world_pos: ctx.node_position.unwrap_or_default(),
+                additive: false,
+            },
+            Self::ConvertToRoundabout => AppIntent::ConvertJunctionToRoundaboutRequested,
             Self::DeleteSelected => AppIntent::DeleteSelectedRequested,
 
             // ── Selection-Befehle ────────────────────────────────────
             Self::ConnectTwoNodes => AppIntent::ConnectSelectedNodesRequested,
+            Self::JoinChains => AppIntent::JoinSelectedChainsRequested,
+            Self::MergeNodes => AppIntent::MergeSelectedNodesRequested,
             Self::RouteStraight
             | Self::RouteSmoothCurve
             | Self::RouteQuadratic
@@ -256,6 +300,8 @@ impl CommandId {
                 }
             }
             Self::DirectionInvert => AppIntent::InvertAllConnectionsBetweenSelectedRequested,
+            Self::DirectionReversePath => AppIntent::ReversePathBetweenSelectedRequested,
+            Self::ReverseChain => AppIntent::ReverseChainRequested,
             Self::PriorityRegular => AppIntent::SetAllConnectionsPriorityBetweenSelectedRequested {
                 priority: ConnectionPriority::Regular,
             },
@@ -263,9 +309,32 @@ impl CommandId {
                 priority: ConnectionPriority::SubPriority,
             },
             Self::RemoveAllConnections => AppIntent::RemoveAllConnectionsBetweenSelectedRequested,
+            Self::EqualizeSpacing => AppIntent::EqualizeChainSpacingRequested,
+            Self::AlignAxisX => AppIntent::AlignSelectedRequested {
+                mode: NodeAlignMode::AxisX,
+            },
+            Self::AlignAxisZ => AppIntent::AlignSelectedRequested {
+                mode: NodeAlignMode::AxisZ,
+            },
+            Self::AlignBestFit => AppIntent::AlignSelectedRequested {
+                mode: NodeAlignMode::BestFitLine,
+            },
+            Self::AlignEndpointLine => AppIntent::AlignSelectedRequested {
+                mode: NodeAlignMode::EndpointLine,
+            },
+            Self::DistributeEvenly => AppIntent::DistributeSelectedRequested,
             Self::InvertSelection => AppIntent::InvertSelectionRequested,
             Self::SelectAll => AppIntent::SelectAllRequested,
+            Self::SelectPathBetween => AppIntent::SelectPathBetweenSelectedRequested,
             Self::ClearSelection => AppIntent::ClearSelectionRequested,
+            Self::SelectMainRoadNodes => AppIntent::SelectByConnectionPriorityRequested {
+                priority: ConnectionPriority::Regular,
+            },
+            Self::SelectSubPrioNodes => AppIntent::SelectByConnectionPriorityRequested {
+                priority: ConnectionPriority::SubPriority,
+            },
+            Self::LockSelection => AppIntent::SetSelectionLockedRequested { locked: true },
+            Self::UnlockSelection => AppIntent::SetSelectionLockedRequested { locked: false },
 
             // ── RouteTool ────────────────────────────────────────────
             Self::RouteExecute => AppIntent::RouteToolExecuteRequested,
@@ -274,6 +343,7 @@ impl CommandId {
             // ── Copy/Paste ────────────────────────────────────────────────
             Self::CopySelection => AppIntent::CopySelectionRequested,
             Self::PasteHere => AppIntent::PasteStartRequested,
+            Self::DuplicateSelection => AppIntent::DuplicateSelectionRequested,
 
             // ── Segment ──────────────────────────────────────────────────────
             Self::EditGroup => AppIntent::GroupEditStartRequested {
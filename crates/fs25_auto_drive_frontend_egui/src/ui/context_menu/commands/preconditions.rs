@@ -2,6 +2,7 @@
 //!
 //! Pruefbare Vorbedingungen als Enum (kein dyn Trait, performant).
 
+use crate::app::use_cases::editing::ROUNDABOUT_MIN_JUNCTION_DEGREE;
 use crate::app::RoadMap;
 use indexmap::IndexSet;
 
@@ -14,6 +15,8 @@ pub enum Precondition {
     HasMarker(u64),
     /// Node hat keinen Marker
     HasNoMarker(u64),
+    /// Node ist der einzige selektierte Node und eine Kreuzung (mind. 3 Nachbarn)
+    IsSingleSelectedJunction(u64),
     /// Genau 2 Nodes selektiert
     ExactlyTwoSelected,
     /// Genau 2 Nodes selektiert UND keine Verbindung dazwischen
@@ -24,6 +27,8 @@ pub enum Precondition {
     StreckenteilungActive(bool),
     /// Selektion bildet eine zusammenhaengende Kette (fuer Streckenteilung)
     IsResampleableChain,
+    /// Selektion besteht aus genau zwei getrennten Ketten (fuer Ketten verbinden)
+    IsTwoChains,
     /// Selektion bildet einen zusammenhaengenden Subgraphen (fuer Gruppierung)
     IsConnectedSubgraph,
     /// Mindestens 1 selektierter Node gehoert zu einer Gruppe
@@ -38,6 +43,8 @@ pub enum Precondition {
     FarmlandPolygonsLoaded,
     /// Mindestens 2 Nodes selektiert (fuer Zoom-auf-Auswahl)
     AtLeastTwoSelected,
+    /// Mindestens 3 Nodes selektiert (fuer Ausrichten zwischen Endpunkten / Verteilen)
+    AtLeastThreeSelected,
     /// Kein Group-Edit aktiv (fuer neue Gruppierung)
     NoGroupEditActive,
 }
@@ -70,6 +77,12 @@ impl Precondition {
 
             Self::HasNoMarker(id) => !ctx.road_map.has_marker(*id),
 
+            Self::IsSingleSelectedJunction(id) => {
+                ctx.selected_node_ids.len() == 1
+                    && ctx.selected_node_ids.contains(id)
+                    && ctx.road_map.node_degree(*id) >= ROUNDABOUT_MIN_JUNCTION_DEGREE
+            }
+
             Self::ExactlyTwoSelected => ctx.selected_node_ids.len() == 2,
 
             Self::TwoSelectedUnconnected => {
@@ -92,6 +105,11 @@ impl Precondition {
 
             Self::IsResampleableChain => ctx.road_map.is_resampleable_chain(ctx.selected_node_ids),
 
+            Self::IsTwoChains => ctx
+                .road_map
+                .two_disjoint_chains(ctx.selected_node_ids)
+                .is_some(),
+
             Self::IsConnectedSubgraph => ctx.road_map.is_connected_subgraph(ctx.selected_node_ids),
 
             Self::HasSelection => !ctx.selected_node_ids.is_empty(),
@@ -104,6 +122,8 @@ impl Precondition {
 
             Self::AtLeastTwoSelected => ctx.selected_node_ids.len() >= 2,
 
+            Self::AtLeastThreeSelected => ctx.selected_node_ids.len() >= 3,
+
             Self::NoGroupEditActive => !ctx.group_editing_active,
 
             Self::SelectionHasGroupMember => ctx.selection_has_group_member,
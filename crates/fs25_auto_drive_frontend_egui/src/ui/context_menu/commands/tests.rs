@@ -98,6 +98,47 @@ fn precondition_has_marker() {
     assert!(Precondition::HasNoMarker(2).is_valid(&ctx));
 }
 
+#[test]
+fn precondition_single_selected_junction() {
+    let mut map = make_road_map(&[
+        (1, 0.0, 0.0),
+        (2, 10.0, 0.0),
+        (3, -10.0, 0.0),
+        (4, 0.0, 10.0),
+    ]);
+    for neighbor in [2, 3, 4] {
+        let end_pos = map.node(neighbor).expect("Nachbar vorhanden").position;
+        map.add_connection(Connection::new(
+            1,
+            neighbor,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            Vec2::ZERO,
+            end_pos,
+        ));
+    }
+    let selected = IndexSet::from([1]);
+    let ctx = PreconditionContext {
+        road_map: &map,
+        selected_node_ids: &selected,
+        distanzen_active: false,
+        clipboard_has_data: false,
+        group_record_id: None,
+        group_editing_active: false,
+        selection_has_group_member: false,
+        farmland_polygons_loaded: false,
+    };
+    assert!(Precondition::IsSingleSelectedJunction(1).is_valid(&ctx));
+    assert!(!Precondition::IsSingleSelectedJunction(2).is_valid(&ctx));
+
+    let two_selected = IndexSet::from([1, 2]);
+    let ctx = PreconditionContext {
+        selected_node_ids: &two_selected,
+        ..ctx
+    };
+    assert!(!Precondition::IsSingleSelectedJunction(1).is_valid(&ctx));
+}
+
 #[test]
 fn precondition_exactly_two_selected() {
     let map = make_road_map(&[(1, 0.0, 0.0), (2, 10.0, 0.0), (3, 20.0, 0.0)]);
@@ -270,7 +311,9 @@ fn catalog_empty_area_shows_tools() {
     assert!(has_command(&entries, CommandId::SetToolRouteQuadratic));
     assert!(has_command(&entries, CommandId::SetToolRouteCubic));
     assert!(has_command(&entries, CommandId::ZoomToFit));
-    assert_eq!(count_commands(&entries), 8);
+    assert!(has_command(&entries, CommandId::SelectMainRoadNodes));
+    assert!(has_command(&entries, CommandId::SelectSubPrioNodes));
+    assert_eq!(count_commands(&entries), 10);
 }
 
 #[test]
@@ -387,6 +430,7 @@ fn catalog_node_focused_shows_delete_and_duplicate() {
     let entries = validate_entries(&catalog, &ctx, &intent_ctx);
 
     assert!(has_command(&entries, CommandId::DeleteSelected));
+    assert!(has_command(&entries, CommandId::DuplicateSelection));
 }
 
 #[test]
@@ -501,6 +545,12 @@ fn catalog_multi_nodes_route_tools_only_when_two_selected() {
     assert!(!has_command(&entries, CommandId::RouteSmoothCurve));
     assert!(!has_command(&entries, CommandId::RouteQuadratic));
     assert!(!has_command(&entries, CommandId::RouteCubic));
+    assert!(!has_command(&entries, CommandId::SelectPathBetween));
+    assert!(has_command(&entries, CommandId::LockSelection));
+    assert!(has_command(&entries, CommandId::UnlockSelection));
+    assert!(has_command(&entries, CommandId::SelectSubPrioNodes));
+    assert!(has_command(&entries, CommandId::AlignEndpointLine));
+    assert!(has_command(&entries, CommandId::DistributeEvenly));
 }
 
 #[test]
@@ -551,6 +601,11 @@ fn catalog_multi_nodes_selection_commands_always_visible() {
     let entries = validate_entries(&catalog, &ctx, &intent_ctx);
 
     assert!(has_command(&entries, CommandId::DeleteSelected));
+    assert!(has_command(&entries, CommandId::MergeNodes));
+    assert!(has_command(&entries, CommandId::AlignAxisX));
+    assert!(has_command(&entries, CommandId::AlignBestFit));
+    assert!(!has_command(&entries, CommandId::AlignEndpointLine));
+    assert!(!has_command(&entries, CommandId::DistributeEvenly));
 }
 
 #[test]
@@ -607,6 +662,30 @@ fn intent_mapping_connect_two_nodes() {
     assert!(matches!(intent, AppIntent::ConnectSelectedNodesRequested));
 }
 
+#[test]
+fn intent_mapping_align_and_distribute() {
+    let ctx = IntentContext {
+        node_id: None,
+        node_position: None,
+        two_node_ids: None,
+        group_record_id: None,
+    };
+    assert!(matches!(
+        CommandId::AlignEndpointLine.to_intent(&ctx),
+        AppIntent::AlignSelectedRequested {
+            mode: NodeAlignMode::EndpointLine
+        }
+    ));
+    assert!(matches!(
+        CommandId::DistributeEvenly.to_intent(&ctx),
+        AppIntent::DistributeSelectedRequested
+    ));
+    assert!(matches!(
+        CommandId::ReverseChain.to_intent(&ctx),
+        AppIntent::ReverseChainRequested
+    ));
+}
+
 #[test]
 fn cleanup_removes_orphaned_labels() {
     let entries = vec![
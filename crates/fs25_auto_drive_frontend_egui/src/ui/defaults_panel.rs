@@ -5,7 +5,7 @@ use crate::app::tools::{
     route_tool_defaults_tooltip_key, route_tool_group_label_key, RouteToolGroup,
 };
 use crate::app::{AppIntent, ConnectionDirection, ConnectionPriority, EditorTool};
-use crate::shared::{t, I18nKey};
+use crate::shared::{t, EditorOptions, I18nKey};
 use crate::ui::common::{
     host_active_tool_to_editor, host_default_direction_to_engine, host_default_priority_to_engine,
     host_memory_tool_for_group, host_route_tool_disabled_reason_key, host_route_tool_entries_for,
@@ -149,7 +149,11 @@ pub(crate) fn render_route_defaults_panel_inside(
     let active_route_id = host_chrome_snapshot.active_route_tool;
     let is_werkzeug_active = matches!(
         active_tool,
-        EditorTool::Select | EditorTool::Connect | EditorTool::AddNode
+        EditorTool::Select
+            | EditorTool::Connect
+            | EditorTool::AddNode
+            | EditorTool::Knife
+            | EditorTool::Measure
     );
     let tools_items = [
         tool_item(
@@ -167,6 +171,16 @@ pub(crate) fn render_route_defaults_panel_inside(
             t(lang, I18nKey::LpToolAddNode),
             EditorTool::AddNode,
         ),
+        tool_item(
+            egui::include_image!("../../../../assets/icons/icon_knife.svg"),
+            t(lang, I18nKey::LpToolKnife),
+            EditorTool::Knife,
+        ),
+        tool_item(
+            egui::include_image!("../../../../assets/icons/icon_measure.svg"),
+            t(lang, I18nKey::LpToolMeasure),
+            EditorTool::Measure,
+        ),
     ];
 
     let basic_items =
@@ -263,6 +277,34 @@ pub(crate) fn render_route_defaults_panel_inside(
                 events.push(AppIntent::SetEditorToolRequested { tool });
             }
 
+            // Raster-/Winkel-Snap: gilt fuer AddNode, Node-Drag und alle Route-Tools
+            let grid_snap = host_chrome_snapshot.options.grid_snap_enabled;
+            let grid_snap_img = svg_icon(
+                egui::include_image!("../../../../assets/icons/icon_grid_snap.svg"),
+                ICON_SIZE,
+            )
+            .tint(if grid_snap {
+                active_icon_color
+            } else {
+                icon_color
+            });
+            if ui
+                .add(egui::Button::image(grid_snap_img).selected(grid_snap))
+                .on_hover_text(if grid_snap {
+                    t(lang, I18nKey::GridSnapDisable)
+                } else {
+                    t(lang, I18nKey::GridSnapEnable)
+                })
+                .clicked()
+            {
+                events.push(AppIntent::OptionsChanged {
+                    options: Box::new(EditorOptions {
+                        grid_snap_enabled: !grid_snap,
+                        ..host_chrome_snapshot.options.clone()
+                    }),
+                });
+            }
+
             ui.add_space(6.0);
             ui.separator();
             ui.add_space(6.0);
@@ -0,0 +1,85 @@
+//! Bestaetigungsdialog fuer "Loeschen nach Filter...".
+//!
+//! Oeffnet sich ueber das Bearbeiten-Menue fuer die aktuelle Selektion (per
+//! Rechteck oder Lasso gezogene Region). Zeigt live, wie viele Nodes die
+//! gewaehlten Filter treffen, bevor in einem Undo-Schritt geloescht wird.
+
+use crate::app::AppIntent;
+use fs25_auto_drive_host_bridge::HostLocalDialogState;
+
+use super::{dialog_two_action_row_enabled, DialogTwoAction};
+
+/// Rendert den Dialog "Loeschen nach Filter...".
+///
+/// Solange `state.delete_by_filter_dialog.visible` gesetzt ist, wird das
+/// Fenster zentriert angezeigt. Die Filter werden direkt in der Arbeitskopie
+/// in `HostLocalDialogState` bearbeitet.
+pub fn show_delete_by_filter_dialog(
+    ctx: &egui::Context,
+    ui_state: &mut HostLocalDialogState,
+) -> Vec<AppIntent> {
+    let mut events = Vec::new();
+
+    if !ui_state.delete_by_filter_dialog.visible {
+        return events;
+    }
+
+    let mut action = None;
+
+    egui::Window::new("\u{1F5D1} Loeschen nach Filter")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.set_min_width(320.0);
+            let dlg = &mut ui_state.delete_by_filter_dialog;
+
+            ui.label("Region: aktuelle Selektion (Rechteck oder Lasso)");
+            ui.add_space(6.0);
+
+            ui.checkbox(
+                &mut dlg.filter.only_subprio,
+                "Nur Nebenstrassen-Nodes (SubPrio)",
+            );
+            ui.checkbox(&mut dlg.filter.only_unconnected, "Nur unverbundene Nodes");
+            ui.checkbox(
+                &mut dlg.filter.only_without_markers,
+                "Nur Nodes ohne Marker",
+            );
+
+            ui.add_space(8.0);
+            let matching = dlg.matching_count();
+            ui.strong(format!(
+                "{} von {} Node(s) werden geloescht",
+                matching,
+                dlg.candidates.len() + dlg.locked_count
+            ));
+            if dlg.locked_count > 0 {
+                ui.label(format!(
+                    "{} gesperrte Node(s) bleiben erhalten",
+                    dlg.locked_count
+                ));
+            }
+            ui.label("Verbindungen und Marker der Treffer werden mit entfernt; ein Undo-Schritt.");
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(6.0);
+
+            action = dialog_two_action_row_enabled(ui, "Loeschen", "Abbrechen", matching > 0, true);
+        });
+
+    match action {
+        Some(DialogTwoAction::Confirm) => {
+            events.push(AppIntent::DeleteByFilterConfirmed {
+                filter: ui_state.delete_by_filter_dialog.filter,
+            });
+        }
+        Some(DialogTwoAction::Cancel) => {
+            events.push(AppIntent::DeleteByFilterCancelled);
+        }
+        None => {}
+    }
+
+    events
+}
@@ -0,0 +1,101 @@
+//! Einstellungsdialog fuer "Feld-Rundkurs erzeugen".
+//!
+//! Oeffnet sich wenn der Nutzer im Extras-Menue "Feld-Rundkurs erzeugen" waehlt.
+//! Zeigt Feldnummer, Nodedistanz und Vorgewendebreite.
+//! Keine Vorschau — nach Bestaetigung wird direkt erzeugt.
+
+use crate::app::AppIntent;
+use crate::ui::common::apply_wheel_step;
+use fs25_auto_drive_host_bridge::HostLocalDialogState;
+
+use super::{dialog_two_action_row_enabled, DialogTwoAction};
+
+/// Rendert den Einstellungsdialog fuer den Feld-Rundkurs.
+///
+/// Solange `state.field_course_dialog.visible` gesetzt ist, wird das Fenster
+/// zentriert angezeigt. Der Dialog mutiert seinen State direkt (Arbeitskopie in
+/// `HostLocalDialogState`), damit die Werte beim naechsten Oeffnen erhalten bleiben.
+pub fn show_field_course_dialog(
+    ctx: &egui::Context,
+    ui_state: &mut HostLocalDialogState,
+) -> Vec<AppIntent> {
+    let mut events = Vec::new();
+
+    if !ui_state.field_course_dialog.visible {
+        return events;
+    }
+
+    let mut action = None;
+
+    egui::Window::new("\u{1F69C} Feld-Rundkurs erzeugen")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.set_min_width(360.0);
+            let dlg = &mut ui_state.field_course_dialog;
+
+            egui::Grid::new("field_course_grid")
+                .num_columns(2)
+                .spacing([16.0, 8.0])
+                .show(ui, |ui| {
+                    // Feldnummer
+                    ui.label("Feld-Nr.:").on_hover_text(
+                        "Farmland-ID des Feldes (wie in der Uebersichtskarte beschriftet)",
+                    );
+                    ui.add(egui::DragValue::new(&mut dlg.field_id).range(1..=255));
+                    ui.end_row();
+
+                    // Nodedistanz
+                    ui.label("Nodedistanz (m):").on_hover_text(
+                        "Abstand zwischen erzeugten Wegpunkten auf Ring und Zufahrt",
+                    );
+                    let r = ui.add(
+                        egui::DragValue::new(&mut dlg.spacing)
+                            .range(1.0..=100.0)
+                            .speed(0.1)
+                            .suffix(" m"),
+                    );
+                    apply_wheel_step(ui, &r, &mut dlg.spacing, 0.1, 1.0..=100.0);
+                    ui.end_row();
+
+                    // Vorgewendebreite
+                    ui.label("Vorgewende (m):")
+                        .on_hover_text("Abstand des Rundkurses vom Feldrand nach innen");
+                    let r = ui.add(
+                        egui::DragValue::new(&mut dlg.headland_offset)
+                            .range(0.0..=50.0)
+                            .speed(0.1)
+                            .suffix(" m"),
+                    );
+                    apply_wheel_step(ui, &r, &mut dlg.headland_offset, 0.1, 0.0..=50.0);
+                    ui.end_row();
+                });
+
+            ui.add_space(6.0);
+            ui.label("Die Zufahrt fuehrt gerade zum naechstgelegenen bestehenden Node.");
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(6.0);
+
+            action = dialog_two_action_row_enabled(ui, "Erstellen", "Abbrechen", true, true);
+        });
+
+    match action {
+        Some(DialogTwoAction::Confirm) => {
+            let dlg = &ui_state.field_course_dialog;
+            events.push(AppIntent::FieldCourseConfirmed {
+                field_id: dlg.field_id,
+                spacing: dlg.spacing,
+                headland_offset: dlg.headland_offset,
+            });
+        }
+        Some(DialogTwoAction::Cancel) => {
+            events.push(AppIntent::FieldCourseCancelled);
+        }
+        None => {}
+    }
+
+    events
+}
@@ -9,7 +9,9 @@ fn effective_file_name(
     suggested_file_name: Option<&str>,
 ) -> Option<&str> {
     match kind {
-        HostDialogRequestKind::SaveFile => suggested_file_name,
+        HostDialogRequestKind::SaveFile
+        | HostDialogRequestKind::CoursesModExport
+        | HostDialogRequestKind::PdfExport => suggested_file_name,
         HostDialogRequestKind::CurseplayExport => {
             Some(suggested_file_name.unwrap_or("customField"))
         }
@@ -53,6 +55,7 @@ fn handle_dialog_request(request: HostDialogRequest) -> HostDialogResult {
         }
         HostDialogRequestKind::Heightmap => rfd::FileDialog::new()
             .add_filter("Heightmap Image", &["png", "jpg", "jpeg"])
+            .add_filter("Map-Mod ZIP (DEM)", &["zip"])
             .pick_file()
             .map(|path| path_to_ui_string(&path)),
         HostDialogRequestKind::BackgroundMap => rfd::FileDialog::new()
@@ -73,6 +76,24 @@ fn handle_dialog_request(request: HostDialogRequest) -> HostDialogResult {
             }
             dialog.save_file().map(|path| path_to_ui_string(&path))
         }
+        HostDialogRequestKind::MapModZip => rfd::FileDialog::new()
+            .add_filter("FS25 Map-Mod ZIP", &["zip"])
+            .pick_file()
+            .map(|path| path_to_ui_string(&path)),
+        HostDialogRequestKind::CoursesModExport => {
+            let mut dialog = rfd::FileDialog::new().add_filter("FS25 Mod ZIP", &["zip"]);
+            if let Some(file_name) = effective_file_name {
+                dialog = dialog.set_file_name(file_name);
+            }
+            dialog.save_file().map(|path| path_to_ui_string(&path))
+        }
+        HostDialogRequestKind::PdfExport => {
+            let mut dialog = rfd::FileDialog::new().add_filter("PDF", &["pdf"]);
+            if let Some(file_name) = effective_file_name {
+                dialog = dialog.set_file_name(file_name);
+            }
+            dialog.save_file().map(|path| path_to_ui_string(&path))
+        }
     };
 
     result_from_selected_path(kind, selected_path)
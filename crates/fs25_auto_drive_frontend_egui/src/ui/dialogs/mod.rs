@@ -2,30 +2,42 @@
 
 mod confirm_dissolve_dialog;
 mod dedup_dialog;
+mod delete_by_filter_dialog;
 mod dialog_widgets;
+mod field_course_dialog;
 mod file_dialogs;
 mod group_settings_popup;
 mod heightmap_warning;
 mod marker_dialog;
+mod move_selection_dialog;
 mod overview_options_dialog;
+mod pdf_export_dialog;
 mod post_load_dialog;
+mod routes_dialog;
 mod save_overview_dialog;
+mod savegame_picker;
 mod trace_all_fields_dialog;
 mod zip_browser;
 
 pub use confirm_dissolve_dialog::show_confirm_dissolve_dialog;
 pub use dedup_dialog::show_dedup_dialog;
+pub use delete_by_filter_dialog::show_delete_by_filter_dialog;
 pub use dialog_widgets::{
     dialog_three_action_row, dialog_three_action_row_enabled, dialog_two_action_row_enabled,
     DialogThreeAction, DialogTwoAction, DIALOG_BUTTON_ROW_ITEM_SPACING,
     DIALOG_BUTTON_ROW_TOP_SPACING,
 };
+pub use field_course_dialog::show_field_course_dialog;
 pub use file_dialogs::handle_file_dialogs;
 pub use group_settings_popup::show_group_settings_popup;
 pub use heightmap_warning::show_heightmap_warning;
 pub use marker_dialog::show_marker_dialog;
+pub use move_selection_dialog::show_move_selection_dialog;
 pub use overview_options_dialog::show_overview_options_dialog;
+pub use pdf_export_dialog::show_pdf_export_dialog;
 pub use post_load_dialog::show_post_load_dialog;
+pub use routes_dialog::show_routes_dialog;
 pub use save_overview_dialog::show_save_overview_dialog;
+pub use savegame_picker::show_savegame_picker;
 pub use trace_all_fields_dialog::show_trace_all_fields_dialog;
 pub use zip_browser::show_zip_browser;
@@ -0,0 +1,101 @@
+//! Eingabedialog fuer "Selektion verschieben um...".
+//!
+//! Oeffnet sich ueber das Bearbeiten-Menue. Nimmt einen exakten Versatz in
+//! FS25-Koordinaten entgegen (X/Z in der Kartenebene, Y als Hoehe), z.B. um
+//! eine importierte Kurs-Vorlage um einen bekannten Karten-Offset zu versetzen.
+
+use crate::app::AppIntent;
+use fs25_auto_drive_host_bridge::HostLocalDialogState;
+
+use super::{dialog_two_action_row_enabled, DialogTwoAction};
+
+/// Rendert den Dialog "Selektion verschieben um...".
+///
+/// Solange `state.move_selection_dialog.visible` gesetzt ist, wird das Fenster
+/// zentriert angezeigt. Die Eingaben werden direkt in der Arbeitskopie in
+/// `HostLocalDialogState` bearbeitet.
+pub fn show_move_selection_dialog(
+    ctx: &egui::Context,
+    ui_state: &mut HostLocalDialogState,
+) -> Vec<AppIntent> {
+    let mut events = Vec::new();
+
+    if !ui_state.move_selection_dialog.visible {
+        return events;
+    }
+
+    let mut action = None;
+
+    egui::Window::new("\u{2725} Selektion verschieben um")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.set_min_width(320.0);
+            let dlg = &mut ui_state.move_selection_dialog;
+
+            egui::Grid::new("move_selection_grid")
+                .num_columns(2)
+                .spacing([16.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label("X (m):")
+                        .on_hover_text("Versatz entlang der X-Achse der Karte");
+                    ui.add(
+                        egui::DragValue::new(&mut dlg.dx)
+                            .speed(0.1)
+                            .max_decimals(3)
+                            .suffix(" m"),
+                    );
+                    ui.end_row();
+
+                    ui.label("Z (m):")
+                        .on_hover_text("Versatz entlang der Z-Achse der Karte");
+                    ui.add(
+                        egui::DragValue::new(&mut dlg.dz)
+                            .speed(0.1)
+                            .max_decimals(3)
+                            .suffix(" m"),
+                    );
+                    ui.end_row();
+
+                    ui.label("Y-Wert (m):").on_hover_text(
+                        "Hoehenversatz; setzt den Y-Wert der selektierten Nodes relativ",
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut dlg.dy)
+                            .speed(0.1)
+                            .max_decimals(3)
+                            .suffix(" m"),
+                    );
+                    ui.end_row();
+                });
+
+            ui.add_space(6.0);
+            ui.label("Gesperrte Nodes bleiben stehen; ein Undo-Schritt.");
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(6.0);
+
+            let has_offset = dlg.dx != 0.0 || dlg.dy != 0.0 || dlg.dz != 0.0;
+            action =
+                dialog_two_action_row_enabled(ui, "Verschieben", "Abbrechen", has_offset, true);
+        });
+
+    match action {
+        Some(DialogTwoAction::Confirm) => {
+            let dlg = &ui_state.move_selection_dialog;
+            events.push(AppIntent::MoveSelectionConfirmed {
+                dx: dlg.dx,
+                dy: dlg.dy,
+                dz: dlg.dz,
+            });
+        }
+        Some(DialogTwoAction::Cancel) => {
+            events.push(AppIntent::MoveSelectionCancelled);
+        }
+        None => {}
+    }
+
+    events
+}
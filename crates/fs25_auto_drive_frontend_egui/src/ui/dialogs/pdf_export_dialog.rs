@@ -0,0 +1,117 @@
+//! Einstellungsdialog fuer "Als PDF exportieren...".
+//!
+//! Oeffnet sich ueber das Datei-Menue. Papierformat, Ausrichtung, Massstab und
+//! Gitter werden hier gewaehlt; die resultierende Seitenanzahl wird live
+//! angezeigt, bevor der Speicherdialog folgt.
+
+use crate::app::AppIntent;
+use crate::shared::pdf_export::{PDF_MAX_PAGES, PDF_SCALE_PRESETS};
+use crate::shared::PdfPaperSize;
+use fs25_auto_drive_host_bridge::HostLocalDialogState;
+
+use super::{dialog_two_action_row_enabled, DialogTwoAction};
+
+/// Rendert den Dialog "Als PDF exportieren...".
+///
+/// Solange `state.pdf_export_dialog.visible` gesetzt ist, wird das Fenster
+/// zentriert angezeigt. Die Einstellungen werden direkt in der Arbeitskopie in
+/// `HostLocalDialogState` bearbeitet.
+pub fn show_pdf_export_dialog(
+    ctx: &egui::Context,
+    ui_state: &mut HostLocalDialogState,
+) -> Vec<AppIntent> {
+    let mut events = Vec::new();
+
+    if !ui_state.pdf_export_dialog.visible {
+        return events;
+    }
+
+    let mut action = None;
+
+    egui::Window::new("\u{1F4C4} Als PDF exportieren")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.set_min_width(320.0);
+            let dlg = &mut ui_state.pdf_export_dialog;
+
+            egui::Grid::new("pdf_export_grid")
+                .num_columns(2)
+                .spacing([16.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label("Papier:");
+                    ui.horizontal(|ui| {
+                        for paper in [PdfPaperSize::A4, PdfPaperSize::A3] {
+                            ui.selectable_value(&mut dlg.options.paper, paper, paper.label());
+                        }
+                        ui.separator();
+                        ui.selectable_value(&mut dlg.options.landscape, false, "Hoch");
+                        ui.selectable_value(&mut dlg.options.landscape, true, "Quer");
+                    });
+                    ui.end_row();
+
+                    ui.label("Massstab:");
+                    egui::ComboBox::from_id_salt("pdf_export_scale")
+                        .selected_text(format!("1:{}", dlg.options.scale_denominator))
+                        .show_ui(ui, |ui| {
+                            for scale in PDF_SCALE_PRESETS {
+                                ui.selectable_value(
+                                    &mut dlg.options.scale_denominator,
+                                    scale,
+                                    format!("1:{scale}"),
+                                );
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.checkbox(&mut dlg.options.show_grid, "Gitter:");
+                    ui.add_enabled(
+                        dlg.options.show_grid,
+                        egui::DragValue::new(&mut dlg.options.grid_spacing_m)
+                            .range(10.0..=5000.0)
+                            .speed(10.0)
+                            .suffix(" m"),
+                    );
+                    ui.end_row();
+                });
+
+            ui.add_space(8.0);
+            let (columns, rows) = dlg.page_grid();
+            let pages = columns * rows;
+            ui.strong(format!("{pages} Seite(n) ({columns} × {rows})"));
+            if pages > PDF_MAX_PAGES {
+                ui.colored_label(
+                    egui::Color32::from_rgb(230, 120, 60),
+                    format!("Maximal {PDF_MAX_PAGES} Seiten: kleineren Massstab waehlen"),
+                );
+            }
+            ui.label("Jede Seite enthaelt Legende, Massstabsleiste und Seitenuebersicht.");
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(6.0);
+
+            action = dialog_two_action_row_enabled(
+                ui,
+                "Exportieren...",
+                "Abbrechen",
+                pages <= PDF_MAX_PAGES,
+                true,
+            );
+        });
+
+    match action {
+        Some(DialogTwoAction::Confirm) => {
+            events.push(AppIntent::PdfExportConfirmed {
+                options: ui_state.pdf_export_dialog.options,
+            });
+        }
+        Some(DialogTwoAction::Cancel) => {
+            events.push(AppIntent::PdfExportCancelled);
+        }
+        None => {}
+    }
+
+    events
+}
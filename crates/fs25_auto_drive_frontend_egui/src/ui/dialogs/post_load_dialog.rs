@@ -119,6 +119,23 @@ pub fn show_post_load_dialog(
                     ui.label(egui::RichText::new("Waehlen Sie zuerst eine gueltige ZIP-Datei aus.").weak());
                 }
 
+                // Ohne erkannte Heightmap das DEM direkt aus dem Map-Mod uebernehmen
+                if matches!(context, OverviewSourceContext::PostLoadDetected)
+                    && !ui_state.post_load_dialog.heightmap_set
+                    && let Some(path) = selected_zip.clone()
+                {
+                    if ui
+                        .button("⛰ Heightmap extrahieren und verwenden")
+                        .on_hover_text(
+                            "Speichert das DEM des Map-Mods als terrain.heightmap.png neben der Config",
+                        )
+                        .clicked()
+                    {
+                        events.push(AppIntent::ExtractHeightmapFromZipRequested { path });
+                    }
+                    ui.add_space(8.0);
+                }
+
                 if let Some(action) = dialog_three_action_row_enabled(
                     ui,
                     "Uebersichtskarte generieren",
@@ -0,0 +1,102 @@
+//! Dialog fuer den AutoDrive-Routes-Manager-Ordner.
+//!
+//! Listet die im Routes-Manager gespeicherten Routen und erlaubt das Importieren
+//! einer Route als aktuelles Netz sowie das Exportieren des aktuellen Netzes
+//! unter einem Namen.
+
+use super::{dialog_two_action_row_enabled, DialogTwoAction};
+use crate::app::AppIntent;
+use fs25_auto_drive_host_bridge::HostLocalDialogState;
+
+/// Zeigt den Routes-Manager-Dialog mit Routenliste und Export-Feld.
+pub fn show_routes_dialog(
+    ctx: &egui::Context,
+    ui_state: &mut HostLocalDialogState,
+) -> Vec<AppIntent> {
+    let mut events = Vec::new();
+
+    let Some(dialog) = &mut ui_state.routes_dialog else {
+        return events;
+    };
+
+    let mut open = true;
+    egui::Window::new("AutoDrive-Routen")
+        .collapsible(false)
+        .resizable(true)
+        .open(&mut open)
+        .default_width(420.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(egui::RichText::new(&dialog.routes_dir).weak());
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(format!("{} gespeicherte Routen:", dialog.routes.len()))
+                    .strong(),
+            );
+            ui.add_space(4.0);
+
+            egui::ScrollArea::vertical()
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    for (i, route) in dialog.routes.iter().enumerate() {
+                        let selected = dialog.selected == Some(i);
+                        let label = format!(
+                            "{} — {} (Rev. {}, {})",
+                            route.name, route.map, route.revision, route.date
+                        );
+                        let response = ui.selectable_label(selected, &label);
+                        if response.clicked() {
+                            dialog.selected = Some(i);
+                        }
+                        if response.double_clicked() {
+                            events.push(AppIntent::StoredRouteImportRequested {
+                                file_name: route.file_name.clone(),
+                            });
+                        }
+                    }
+                });
+
+            ui.add_space(8.0);
+            let can_import = dialog.selected.is_some();
+            if let Some(action) =
+                dialog_two_action_row_enabled(ui, "Importieren", "Abbrechen", can_import, true)
+            {
+                match action {
+                    DialogTwoAction::Confirm => {
+                        if let Some(route) = dialog.selected.and_then(|i| dialog.routes.get(i)) {
+                            events.push(AppIntent::StoredRouteImportRequested {
+                                file_name: route.file_name.clone(),
+                            });
+                        }
+                    }
+                    DialogTwoAction::Cancel => {
+                        events.push(AppIntent::RoutesDialogCancelled);
+                    }
+                }
+            }
+
+            ui.add_space(8.0);
+            ui.separator();
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.label("Aktuelles Netz exportieren als:");
+                ui.text_edit_singleline(&mut dialog.export_name);
+                let can_export = !dialog.export_name.trim().is_empty();
+                if ui
+                    .add_enabled(can_export, egui::Button::new("Exportieren"))
+                    .on_hover_text("Gleicher Name erhoeht die Revision der bestehenden Route")
+                    .clicked()
+                {
+                    events.push(AppIntent::StoredRouteExportRequested {
+                        name: dialog.export_name.trim().to_string(),
+                    });
+                }
+            });
+        });
+
+    if !open {
+        events.push(AppIntent::RoutesDialogCancelled);
+    }
+
+    events
+}
@@ -0,0 +1,112 @@
+//! Savegame-Auswahl im FS25-Profil.
+//!
+//! Listet die Savegames mit Karte, Hof und Speicherdatum und oeffnet die
+//! AutoDrive-Config des gewaehlten Savegames.
+
+use super::{dialog_two_action_row_enabled, DialogTwoAction};
+use crate::app::AppIntent;
+use fs25_auto_drive_host_bridge::HostLocalDialogState;
+
+/// Zeigt die Savegame-Auswahl mit der Liste aller Savegames des Profils.
+pub fn show_savegame_picker(
+    ctx: &egui::Context,
+    ui_state: &mut HostLocalDialogState,
+) -> Vec<AppIntent> {
+    let mut events = Vec::new();
+
+    let Some(picker) = &mut ui_state.savegame_picker else {
+        return events;
+    };
+
+    let mut open = true;
+    egui::Window::new("Savegame oeffnen")
+        .collapsible(false)
+        .resizable(true)
+        .open(&mut open)
+        .default_width(520.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(egui::RichText::new(&picker.profile_dir).weak());
+            ui.add_space(4.0);
+
+            if picker.savegames.is_empty() {
+                ui.label("Keine Savegames im Profil gefunden.");
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(320.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("savegame_picker_grid")
+                        .num_columns(4)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong("Savegame");
+                            ui.strong("Karte");
+                            ui.strong("Hof");
+                            ui.strong("Zuletzt gespielt");
+                            ui.end_row();
+
+                            for (i, savegame) in picker.savegames.iter().enumerate() {
+                                let has_config = savegame.config_path.is_some();
+                                let label = if savegame.savegame_name.is_empty() {
+                                    savegame.dir_name.clone()
+                                } else {
+                                    format!("{} ({})", savegame.savegame_name, savegame.dir_name)
+                                };
+                                let response = ui
+                                    .add_enabled(
+                                        has_config,
+                                        egui::Button::selectable(picker.selected == Some(i), label),
+                                    )
+                                    .on_disabled_hover_text("Keine AutoDrive_config.xml vorhanden");
+                                if response.clicked() {
+                                    picker.selected = Some(i);
+                                }
+                                if let Some(path) = savegame
+                                    .config_path
+                                    .as_ref()
+                                    .filter(|_| response.double_clicked())
+                                {
+                                    events.push(AppIntent::SavegameOpenRequested {
+                                        path: path.clone(),
+                                    });
+                                }
+                                ui.label(&savegame.map_title);
+                                ui.label(&savegame.farm_name);
+                                ui.label(&savegame.save_date);
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            ui.add_space(8.0);
+            let selected_config = picker
+                .selected
+                .and_then(|i| picker.savegames.get(i))
+                .and_then(|savegame| savegame.config_path.clone());
+            if let Some(action) = dialog_two_action_row_enabled(
+                ui,
+                "Oeffnen",
+                "Abbrechen",
+                selected_config.is_some(),
+                true,
+            ) {
+                match action {
+                    DialogTwoAction::Confirm => {
+                        if let Some(path) = selected_config {
+                            events.push(AppIntent::SavegameOpenRequested { path });
+                        }
+                    }
+                    DialogTwoAction::Cancel => {
+                        events.push(AppIntent::SavegamePickerCancelled);
+                    }
+                }
+            }
+        });
+
+    if !open {
+        events.push(AppIntent::SavegamePickerCancelled);
+    }
+
+    events
+}
@@ -1,4 +1,4 @@
-//! Drag-Selektion (Rect/Lasso) und Overlay-Painting.
+//! Drag-Selektion (Rect/Lasso), Messer-Linie, Polygon-Lasso und Overlay-Painting.
 
 /// Modus der Drag-Selektion
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,6 +10,20 @@ pub(crate) enum DragSelectionMode {
     /// Freihand-Lasso fuer das aktive Route-Tool (z.B. ColorPathTool).
     /// Wird an das Tool weitergeleitet statt zur Node-Selektion verwendet.
     ToolLasso,
+    /// Schnittlinie des Messer-Werkzeugs (Start → aktuelle Position).
+    KnifeLine,
+}
+
+/// Zustand eines Polygon-Lassos (Eckpunkte per Klick, Abschluss per Doppelklick/Enter).
+///
+/// Die Eckpunkte liegen in Weltkoordinaten, damit Pan/Zoom zwischen den Klicks
+/// das Polygon nicht verschiebt.
+#[derive(Debug, Clone)]
+pub(crate) struct PolygonLasso {
+    /// Gesetzte Eckpunkte in Weltkoordinaten
+    pub points_world: Vec<glam::Vec2>,
+    /// Zur bestehenden Selektion hinzufuegen (Ctrl beim ersten Klick)
+    pub additive: bool,
 }
 
 /// Zustand einer aktiven Drag-Selektion
@@ -60,16 +74,8 @@ fn draw_lasso_polygon(
     painter.add(egui::Shape::line(points_screen.to_vec(), stroke));
 }
 
-/// Zeichnet das Drag-Selektion-Overlay (Rect oder Lasso).
-pub(super) fn draw_drag_selection_overlay(
-    selection: Option<&DragSelection>,
-    ui: &egui::Ui,
-    response: &egui::Response,
-) {
-    let Some(selection) = selection else {
-        return;
-    };
-
+/// Fuell- und Randfarbe der Selektions-Overlays (mit Fallback fuer transparente Themes).
+pub(super) fn selection_overlay_style(ui: &egui::Ui) -> (egui::Color32, egui::Stroke) {
     let mut stroke_color = ui.visuals().selection.stroke.color;
     if stroke_color.a() == 0 {
         // Fallback, falls Theme die Selection-Stroke transparent setzt.
@@ -84,7 +90,20 @@ pub(super) fn draw_drag_selection_overlay(
     } else {
         fill_color = fill_color.gamma_multiply(0.15);
     }
-    let fill = fill_color;
+    (fill_color, stroke)
+}
+
+/// Zeichnet das Drag-Selektion-Overlay (Rect oder Lasso).
+pub(super) fn draw_drag_selection_overlay(
+    selection: Option<&DragSelection>,
+    ui: &egui::Ui,
+    response: &egui::Response,
+) {
+    let Some(selection) = selection else {
+        return;
+    };
+
+    let (fill, stroke) = selection_overlay_style(ui);
     let painter = ui
         .ctx()
         .layer_painter(egui::LayerId::new(egui::Order::Foreground, response.id))
@@ -111,5 +130,42 @@ pub(super) fn draw_drag_selection_overlay(
             let tool_fill = egui::Color32::from_rgba_unmultiplied(255, 165, 0, 30);
             draw_lasso_polygon(&painter, &selection.points_screen, tool_fill, tool_stroke);
         }
+        DragSelectionMode::KnifeLine => {
+            let current = selection
+                .points_screen
+                .last()
+                .copied()
+                .unwrap_or(selection.start_screen);
+            let knife_stroke = egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 80, 80));
+            painter.line_segment([selection.start_screen, current], knife_stroke);
+        }
+    }
+}
+
+/// Zeichnet das Overlay des Polygon-Lassos.
+///
+/// `points_screen` enthaelt die gesetzten Eckpunkte, `cursor_screen` die aktuelle
+/// Mausposition als vorlaeufigen letzten Eckpunkt (Gummiband).
+pub(super) fn draw_polygon_lasso_overlay(
+    points_screen: &[egui::Pos2],
+    cursor_screen: Option<egui::Pos2>,
+    ui: &egui::Ui,
+    response: &egui::Response,
+) {
+    if points_screen.is_empty() {
+        return;
+    }
+
+    let (fill, stroke) = selection_overlay_style(ui);
+    let painter = ui
+        .ctx()
+        .layer_painter(egui::LayerId::new(egui::Order::Foreground, response.id))
+        .with_clip_rect(response.rect);
+
+    let mut outline = points_screen.to_vec();
+    outline.extend(cursor_screen);
+    draw_lasso_polygon(&painter, &outline, fill, stroke);
+    for point in points_screen {
+        painter.circle_filled(*point, 3.0, stroke.color);
     }
 }
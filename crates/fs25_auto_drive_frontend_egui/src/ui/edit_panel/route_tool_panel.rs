@@ -1,22 +1,26 @@
 use crate::app::tool_contract::{RouteToolId, TangentSource};
 use crate::app::tools::route_tool_label_key;
 use crate::app::ui_contract::{
-    panel_action_to_intent, BypassPanelAction, BypassPanelState, ColorPathPanelAction,
-    ColorPathPanelPhase, ColorPathPanelState, CurveDegreeChoice, CurvePanelAction, CurvePanelState,
+    panel_action_to_intent, ArcPanelAction, ArcPanelState, BypassPanelAction, BypassPanelState,
+    ClothoidPanelAction, ClothoidPanelState, ColorPathPanelAction, ColorPathPanelPhase,
+    ColorPathPanelState, CurveDegreeChoice, CurvePanelAction, CurvePanelState,
     CurveTangentsPanelState, ExistingConnectionModeChoice, FieldBoundaryPanelAction,
-    FieldBoundaryPanelState, FieldPathModeChoice, FieldPathPanelAction, FieldPathPanelPhase,
-    FieldPathPanelState, FieldPathPreviewStatus, FieldPathSelectionSummary, PanelAction,
-    ParkingPanelAction, ParkingPanelState, ParkingRampSideChoice, RoundingPanelAction,
-    RoundingPanelState, RouteOffsetPanelAction, RouteOffsetPanelState, RouteToolConfigState,
-    RouteToolPanelAction, RouteToolPanelState, SegmentConfigPanelAction, SegmentConfigPanelState,
-    SegmentLengthKind, SmoothCurvePanelAction, SmoothCurvePanelState, SplinePanelAction,
-    SplinePanelState, StraightPanelAction, StraightPanelState, TangentHelpHint, TangentNoneReason,
-    TangentSelectionState, BYPASS_BASE_SPACING_LIMITS, BYPASS_OFFSET_LIMITS,
-    PARKING_BAY_LENGTH_LIMITS, PARKING_ENTRY_EXIT_T_LIMITS, PARKING_MAX_NODE_DISTANCE_LIMITS,
-    PARKING_NUM_ROWS_LIMITS, PARKING_RAMP_LENGTH_LIMITS, PARKING_ROTATION_STEP_LIMITS,
-    PARKING_ROW_SPACING_LIMITS, ROUNDING_ARC_RADIUS_LIMITS, ROUNDING_MAX_ANGLE_LIMITS,
-    ROUTE_OFFSET_BASE_SPACING_LIMITS, ROUTE_OFFSET_DISTANCE_LIMITS, SMOOTH_CURVE_MAX_ANGLE_LIMITS,
-    SMOOTH_CURVE_MIN_DISTANCE_LIMITS,
+    FieldBoundaryPanelState, FieldCoursePanelAction, FieldCoursePanelState, FieldCourseTurnChoice,
+    FieldPathModeChoice, FieldPathPanelAction, FieldPathPanelPhase, FieldPathPanelState,
+    FieldPathPreviewStatus, FieldPathSelectionSummary, JunctionPanelAction, JunctionPanelState,
+    PanelAction, ParkingPanelAction, ParkingPanelState, ParkingRampSideChoice, RoundingPanelAction,
+    RoundingPanelState, RouteElevationProfile, RouteOffsetPanelAction, RouteOffsetPanelState,
+    RouteToolConfigState, RouteToolPanelAction, RouteToolPanelState, SegmentConfigPanelAction,
+    SegmentConfigPanelState, SegmentLengthKind, SmoothCurvePanelAction, SmoothCurvePanelState,
+    SplinePanelAction, SplinePanelState, StraightPanelAction, StraightPanelState, TangentHelpHint,
+    TangentNoneReason, TangentSelectionState, BYPASS_BASE_SPACING_LIMITS, BYPASS_OFFSET_LIMITS,
+    FIELD_COURSE_HEADLAND_PASSES_LIMITS, FIELD_COURSE_NODE_SPACING_LIMITS,
+    FIELD_COURSE_WORKING_WIDTH_LIMITS, JUNCTION_NODE_SPACING_LIMITS, PARKING_BAY_LENGTH_LIMITS,
+    PARKING_ENTRY_EXIT_T_LIMITS, PARKING_MAX_NODE_DISTANCE_LIMITS, PARKING_NUM_ROWS_LIMITS,
+    PARKING_RAMP_LENGTH_LIMITS, PARKING_ROTATION_STEP_LIMITS, PARKING_ROW_SPACING_LIMITS,
+    ROUNDING_ARC_RADIUS_LIMITS, ROUNDING_MAX_ANGLE_LIMITS, ROUTE_OFFSET_BASE_SPACING_LIMITS,
+    ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS, ROUTE_OFFSET_DISTANCE_LIMITS,
+    SMOOTH_CURVE_MAX_ANGLE_LIMITS, SMOOTH_CURVE_MIN_DISTANCE_LIMITS,
 };
 use crate::app::{AppIntent, ConnectionDirection, ConnectionPriority};
 use crate::shared::{t, I18nKey, Language};
@@ -120,6 +124,11 @@ pub(super) fn render_route_tool_panel(
             ui.small("Kein Route-Tool aktiv.");
         }
 
+        if let Some(profile) = route_tool.elevation_profile.as_ref() {
+            ui.add_space(6.0);
+            render_elevation_profile(ui, profile, lang);
+        }
+
         ui.add_space(8.0);
         ui.horizontal(|ui| {
             if ui
@@ -135,6 +144,62 @@ pub(super) fn render_route_tool_panel(
     });
 }
 
+/// Zeichnet das Hoehenprofil der Vorschau samt Warnung bei zu steilen Verbindungen.
+fn render_elevation_profile(ui: &mut egui::Ui, profile: &RouteElevationProfile, lang: Language) {
+    let (min_h, max_h) = profile
+        .points
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+            (lo.min(p[1]), hi.max(p[1]))
+        });
+    let length = profile.points.last().map_or(0.0, |p| p[0]);
+
+    ui.small(format!(
+        "{}: {:.1} – {:.1} m, max. {:.1} %",
+        t(lang, I18nKey::RouteToolElevationProfile),
+        min_h,
+        max_h,
+        profile.max_grade_percent
+    ));
+
+    let (response, painter) =
+        ui.allocate_painter(egui::vec2(ui.available_width(), 48.0), egui::Sense::hover());
+    let rect = response.rect.shrink(2.0);
+    painter.rect_filled(response.rect, 2.0, ui.visuals().extreme_bg_color);
+    if length > 0.0 {
+        // Mindestens 1 m Hoehenspanne, damit flache Strecken nicht verrauscht wirken
+        let span = (max_h - min_h).max(1.0);
+        let points: Vec<egui::Pos2> = profile
+            .points
+            .iter()
+            .map(|p| {
+                egui::pos2(
+                    rect.left() + p[0] / length * rect.width(),
+                    rect.bottom() - (p[1] - min_h) / span * rect.height(),
+                )
+            })
+            .collect();
+        let color = if profile.exceeds_limit() {
+            ui.visuals().warn_fg_color
+        } else {
+            ui.visuals().strong_text_color()
+        };
+        painter.add(egui::Shape::line(points, egui::Stroke::new(1.5, color)));
+    }
+
+    if profile.exceeds_limit() {
+        ui.colored_label(
+            ui.visuals().warn_fg_color,
+            format!(
+                "⚠ {}: {:.1} % > {:.1} %",
+                t(lang, I18nKey::RouteToolGradeExceeded),
+                profile.max_grade_percent,
+                profile.grade_limit_percent
+            ),
+        );
+    }
+}
+
 fn render_route_tool_config(
     ui: &mut egui::Ui,
     config_state: &RouteToolConfigState,
@@ -152,6 +217,7 @@ fn render_route_tool_config(
         RouteToolConfigState::FieldBoundary(state) => {
             render_field_boundary_panel(ui, state, panel_ctx)
         }
+        RouteToolConfigState::FieldCourse(state) => render_field_course_panel(ui, state, panel_ctx),
         RouteToolConfigState::FieldPath(state) => {
             render_field_path_panel(ui, state, lang, panel_ctx)
         }
@@ -160,6 +226,9 @@ fn render_route_tool_config(
         }
         RouteToolConfigState::Rounding(state) => render_rounding_panel(ui, state, panel_ctx),
         RouteToolConfigState::ColorPath(state) => render_color_path_panel(ui, state, panel_ctx),
+        RouteToolConfigState::Clothoid(state) => render_clothoid_panel(ui, state, panel_ctx),
+        RouteToolConfigState::Arc(state) => render_arc_panel(ui, state, panel_ctx),
+        RouteToolConfigState::Junction(state) => render_junction_panel(ui, state, panel_ctx),
     }
 }
 
@@ -194,6 +263,30 @@ fn render_spline_panel(
     curve_panel::render_spline_panel(ui, state, panel_ctx);
 }
 
+fn render_arc_panel(
+    ui: &mut egui::Ui,
+    state: &ArcPanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    curve_panel::render_arc_panel(ui, state, panel_ctx);
+}
+
+fn render_clothoid_panel(
+    ui: &mut egui::Ui,
+    state: &ClothoidPanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    curve_panel::render_clothoid_panel(ui, state, panel_ctx);
+}
+
+fn render_junction_panel(
+    ui: &mut egui::Ui,
+    state: &JunctionPanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    generator_panel::render_junction_panel(ui, state, panel_ctx);
+}
+
 fn render_smooth_curve_panel(
     ui: &mut egui::Ui,
     state: &SmoothCurvePanelState,
@@ -227,6 +320,14 @@ fn render_field_boundary_panel(
     analysis_panel::render_field_boundary_panel(ui, state, panel_ctx);
 }
 
+fn render_field_course_panel(
+    ui: &mut egui::Ui,
+    state: &FieldCoursePanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    analysis_panel::render_field_course_panel(ui, state, panel_ctx);
+}
+
 fn render_field_path_panel(
     ui: &mut egui::Ui,
     state: &FieldPathPanelState,
@@ -149,6 +149,98 @@ pub(super) fn render_field_boundary_panel(
     }
 }
 
+/// Rendert die Konfiguration fuer das FieldCourse-Tool.
+pub(super) fn render_field_course_panel(
+    ui: &mut egui::Ui,
+    state: &FieldCoursePanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    if let Some(field_id) = state.selected_field_id {
+        ui.label(format!("Feld #{field_id}"));
+    } else {
+        ui.colored_label(
+            egui::Color32::GRAY,
+            "Kein Feld ausgewaehlt — in ein Feld klicken",
+        );
+    }
+
+    ui.separator();
+    render_drag_f32(
+        panel_ctx,
+        DragF32Props {
+            ui,
+            label: "Arbeitsbreite:",
+            current: state.working_width,
+            range: FIELD_COURSE_WORKING_WIDTH_LIMITS.range(),
+            speed: 0.1,
+            suffix: " m",
+        },
+        |value| RouteToolPanelAction::FieldCourse(FieldCoursePanelAction::SetWorkingWidth(value)),
+    );
+    render_drag_usize(
+        panel_ctx,
+        DragUsizeProps {
+            ui,
+            label: "Vorgewende-Runden:",
+            current: state.headland_passes,
+            range: FIELD_COURSE_HEADLAND_PASSES_LIMITS.range(),
+            speed: 0.05,
+        },
+        |value| RouteToolPanelAction::FieldCourse(FieldCoursePanelAction::SetHeadlandPasses(value)),
+    );
+
+    ui.horizontal(|ui| {
+        ui.label("Wenden:");
+        for (choice, label) in [
+            (FieldCourseTurnChoice::Direct, "Direkt"),
+            (FieldCourseTurnChoice::Rounded, "Bogen"),
+        ] {
+            if ui
+                .selectable_label(state.turn_style == choice, label)
+                .clicked()
+                && state.turn_style != choice
+            {
+                push_action(
+                    panel_ctx.events,
+                    RouteToolPanelAction::FieldCourse(FieldCoursePanelAction::SetTurnStyle(choice)),
+                );
+            }
+        }
+    });
+
+    render_drag_f32(
+        panel_ctx,
+        DragF32Props {
+            ui,
+            label: "Node-Abstand:",
+            current: state.node_spacing,
+            range: FIELD_COURSE_NODE_SPACING_LIMITS.range(),
+            speed: 0.1,
+            suffix: " m",
+        },
+        |value| RouteToolPanelAction::FieldCourse(FieldCoursePanelAction::SetNodeSpacing(value)),
+    );
+
+    if state.selected_field_id.is_some() {
+        ui.separator();
+        if state.lane_count == 0 && state.generated_headlands == 0 {
+            ui.colored_label(
+                egui::Color32::from_rgb(230, 120, 60),
+                "Feld zu schmal fuer die gewaehlte Arbeitsbreite",
+            );
+        } else {
+            ui.label(format!(
+                "{} Vorgewende-Runde(n), {} Bahn(en)",
+                state.generated_headlands, state.lane_count
+            ));
+        }
+    }
+
+    if state.show_select_hint {
+        ui.small("Erneuter Klick im Viewport → anderes Feld auswählen");
+    }
+}
+
 /// Rendert die Konfiguration fuer das FieldPath-Tool.
 ///
 /// `wheel_enabled` steuert, ob numerische Widgets ihre Mausrad-Helfer aus
@@ -395,6 +487,41 @@ pub(super) fn render_route_offset_panel(
         );
     }
 
+    let mut cross_links_enabled = state.cross_links_enabled;
+    if ui
+        .checkbox(&mut cross_links_enabled, "Querverbindungen erzeugen")
+        .on_hover_text(
+            "Mit Original: jede Seite wird mit der Original-Kette verbunden.\n\
+             Ohne Original: linke und rechte Seite werden verbunden.",
+        )
+        .changed()
+    {
+        push_action(
+            panel_ctx.events,
+            RouteToolPanelAction::RouteOffset(RouteOffsetPanelAction::SetCrossLinksEnabled(
+                cross_links_enabled,
+            )),
+        );
+    }
+    if state.cross_links_enabled {
+        render_drag_f32(
+            panel_ctx,
+            DragF32Props {
+                ui,
+                label: "Querverbindung alle:",
+                current: state.cross_link_spacing,
+                range: ROUTE_OFFSET_CROSS_LINK_SPACING_LIMITS.range(),
+                speed: 1.0,
+                suffix: " m",
+            },
+            |value| {
+                RouteToolPanelAction::RouteOffset(RouteOffsetPanelAction::SetCrossLinkSpacing(
+                    value,
+                ))
+            },
+        );
+    }
+
     let can_toggle_traffic_side =
         default_direction != ConnectionDirection::Dual && state.left_enabled && state.right_enabled;
     ui.separator();
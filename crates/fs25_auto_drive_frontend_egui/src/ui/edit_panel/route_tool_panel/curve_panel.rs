@@ -102,3 +102,57 @@ pub(super) fn render_spline_panel(
         RouteToolPanelAction::Spline(SplinePanelAction::Segment(action))
     });
 }
+
+/// Rendert den Klothoiden-Konfigurationsbereich im Route-Tool-Panel.
+///
+/// Zeigt neben der Tangentenauswahl den kleinsten Kurvenradius des
+/// Uebergangsbogens an.
+pub(super) fn render_clothoid_panel(
+    ui: &mut egui::Ui,
+    state: &ClothoidPanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    if let Some(start_tangent) = state.start_tangent.as_ref() {
+        render_tangent_selection(ui, "Tangente Start:", start_tangent, panel_ctx, |value| {
+            RouteToolPanelAction::Clothoid(ClothoidPanelAction::SetTangentStart(value))
+        });
+    }
+
+    if let Some(end_tangent) = state.end_tangent.as_ref() {
+        render_tangent_selection(ui, "Tangente Ende:", end_tangent, panel_ctx, |value| {
+            RouteToolPanelAction::Clothoid(ClothoidPanelAction::SetTangentEnd(value))
+        });
+    }
+
+    if let Some(min_radius_m) = state.min_radius_m {
+        ui.label(format!("Min. Radius: {min_radius_m:.1} m"));
+    }
+
+    ui.separator();
+    render_segment_config(ui, &state.segment, panel_ctx, |action| {
+        RouteToolPanelAction::Clothoid(ClothoidPanelAction::Segment(action))
+    });
+}
+
+/// Rendert den Kreisbogen-Konfigurationsbereich im Route-Tool-Panel.
+pub(super) fn render_arc_panel(
+    ui: &mut egui::Ui,
+    state: &ArcPanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    match (state.radius_m, state.sweep_deg) {
+        (Some(radius_m), Some(sweep_deg)) => {
+            ui.label(format!("Radius: {radius_m:.1} m"));
+            ui.label(format!("Bogenwinkel: {sweep_deg:.1}°"));
+        }
+        (None, _) if state.segment.length_m.is_some() => {
+            ui.colored_label(egui::Color32::GRAY, "Punkte liegen auf einer Geraden.");
+        }
+        _ => {}
+    }
+
+    ui.separator();
+    render_segment_config(ui, &state.segment, panel_ctx, |action| {
+        RouteToolPanelAction::Arc(ArcPanelAction::Segment(action))
+    });
+}
@@ -290,3 +290,42 @@ pub(super) fn render_parking_panel(
         ui.small(t(lang, hint_key));
     }
 }
+
+pub(super) fn render_junction_panel(
+    ui: &mut egui::Ui,
+    state: &JunctionPanelState,
+    panel_ctx: &mut RouteToolPanelRenderContext<'_>,
+) {
+    ui.label(format!("Zufahrten: {} Node(s)", state.selected_node_count));
+    if let Some(lane_count) = state.lane_count {
+        ui.label(format!("Spuren: {lane_count}"));
+    }
+
+    ui.separator();
+    render_drag_f32(
+        panel_ctx,
+        DragF32Props {
+            ui,
+            label: "Node-Abstand:",
+            current: state.node_spacing,
+            range: JUNCTION_NODE_SPACING_LIMITS.range(),
+            speed: 0.1,
+            suffix: " m",
+        },
+        |value| RouteToolPanelAction::Junction(JunctionPanelAction::SetNodeSpacing(value)),
+    );
+
+    let mut sub_priority_turns = state.sub_priority_turns;
+    if ui
+        .checkbox(&mut sub_priority_turns, "Abbieger als Nebenstrecke")
+        .changed()
+    {
+        push_action(
+            panel_ctx.events,
+            RouteToolPanelAction::Junction(JunctionPanelAction::SetSubPriorityTurns(
+                sub_priority_turns,
+            )),
+        );
+    }
+    ui.small("Erwartet 3–4 selektierte Sackgassen-Endpunkte.");
+}
@@ -80,6 +80,19 @@ pub(super) fn render_streckenteilung_panel(
                 events.push(AppIntent::ResamplePathRequested);
                 distanzen_state.deactivate();
             }
+            if ui
+                .button("⟳ Kette neu aufbauen")
+                .on_hover_text(
+                    "Innere Nodes im eingestellten Abstand neu erzeugen; Endpunkte, \
+                     Richtung und Prioritaet bleiben erhalten",
+                )
+                .clicked()
+            {
+                events.push(AppIntent::ResampleChainRequested {
+                    spacing: distanzen_state.distance,
+                });
+                distanzen_state.deactivate();
+            }
             if ui.button("✕ Verwerfen").clicked() {
                 distanzen_state.deactivate();
             }
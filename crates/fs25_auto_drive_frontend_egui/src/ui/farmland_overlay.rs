@@ -0,0 +1,51 @@
+//! Farmland-Overlay: Toent die laut Savegame gekauften Farmlands im Viewport.
+
+use eframe::egui;
+use glam::Vec2;
+
+use crate::app::ui_contract::OwnedFarmlandOverlaySnapshot;
+use crate::app::Camera2D;
+
+/// Fuellfarbe gekaufter Farmlands (Hellblau, halbtransparent).
+const OWNED_FILL_COLOR: egui::Color32 =
+    egui::Color32::from_rgba_unmultiplied_const(90, 170, 255, 60);
+/// Umrissfarbe gekaufter Farmlands.
+const OWNED_OUTLINE_COLOR: egui::Color32 = egui::Color32::from_rgb(90, 170, 255);
+/// Umrissstaerke gekaufter Farmlands in Pixeln.
+const OWNED_OUTLINE_WIDTH_PX: f32 = 1.5;
+
+/// Zeichnet jedes gekaufte Farmland als getoente Flaeche mit Umriss.
+pub fn render_owned_farmland_overlays(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlays: &[OwnedFarmlandOverlaySnapshot],
+) {
+    for overlay in overlays {
+        let points: Vec<egui::Pos2> = overlay
+            .outline
+            .iter()
+            .map(|&world| {
+                let screen = camera.world_to_screen(world, viewport_size);
+                egui::pos2(rect.min.x + screen.x, rect.min.y + screen.y)
+            })
+            .collect();
+        if !egui::Rect::from_points(&points).intersects(rect) {
+            continue;
+        }
+
+        let mut mesh = egui::Mesh::default();
+        for &point in &points {
+            mesh.colored_vertex(point, OWNED_FILL_COLOR);
+        }
+        for &[a, b, c] in &overlay.triangles {
+            mesh.add_triangle(a as u32, b as u32, c as u32);
+        }
+        painter.add(egui::Shape::mesh(mesh));
+        painter.add(egui::Shape::closed_line(
+            points,
+            egui::Stroke::new(OWNED_OUTLINE_WIDTH_PX, OWNED_OUTLINE_COLOR),
+        ));
+    }
+}
@@ -134,6 +134,38 @@ pub fn render_floating_menu(
                                 tool: EditorTool::AddNode,
                             });
                         }
+
+                        if tool_icon_button(
+                            ui,
+                            egui::include_image!("../../../../assets/icons/icon_knife.svg"),
+                            IconButtonConfig {
+                                tooltip: t(lang, I18nKey::FloatingToolKnife),
+                                is_active: active_tool == EditorTool::Knife,
+                                enabled: true,
+                                disabled_tooltip: None,
+                                colors: button_colors,
+                            },
+                        ) {
+                            events.push(AppIntent::SetEditorToolRequested {
+                                tool: EditorTool::Knife,
+                            });
+                        }
+
+                        if tool_icon_button(
+                            ui,
+                            egui::include_image!("../../../../assets/icons/icon_measure.svg"),
+                            IconButtonConfig {
+                                tooltip: t(lang, I18nKey::FloatingToolMeasure),
+                                is_active: active_tool == EditorTool::Measure,
+                                enabled: true,
+                                disabled_tooltip: None,
+                                colors: button_colors,
+                            },
+                        ) {
+                            events.push(AppIntent::SetEditorToolRequested {
+                                tool: EditorTool::Measure,
+                            });
+                        }
                     }
                     FloatingMenuKind::RouteTools(group) => {
                         let host_group = match group {
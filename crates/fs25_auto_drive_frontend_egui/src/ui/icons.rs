@@ -47,6 +47,9 @@ fn route_tool_icon_from_key(icon_key: RouteToolIconKey) -> ImageSource<'static>
         RouteToolIconKey::FieldBoundary => {
             egui::include_image!("../../../../assets/icons/icon_field_boundary.svg")
         }
+        RouteToolIconKey::FieldCourse => {
+            egui::include_image!("../../../../assets/icons/icon_field_boundary.svg")
+        }
         RouteToolIconKey::FieldPath => {
             egui::include_image!("../../../../assets/icons/icon_field_path.svg")
         }
@@ -59,6 +62,15 @@ fn route_tool_icon_from_key(icon_key: RouteToolIconKey) -> ImageSource<'static>
         RouteToolIconKey::ColorPath => {
             egui::include_image!("../../../../assets/icons/icon_color_path.svg")
         }
+        RouteToolIconKey::Clothoid => {
+            egui::include_image!("../../../../assets/icons/icon_spline.svg")
+        }
+        RouteToolIconKey::Arc => {
+            egui::include_image!("../../../../assets/icons/icon_bezier_quadratic.svg")
+        }
+        RouteToolIconKey::Junction => {
+            egui::include_image!("../../../../assets/icons/icon_connect.svg")
+        }
     }
 }
 
@@ -77,12 +89,18 @@ pub fn host_route_tool_icon(icon_key: HostRouteToolIconKey) -> ImageSource<'stat
         HostRouteToolIconKey::FieldBoundary => {
             route_tool_icon_from_key(RouteToolIconKey::FieldBoundary)
         }
+        HostRouteToolIconKey::FieldCourse => {
+            route_tool_icon_from_key(RouteToolIconKey::FieldCourse)
+        }
         HostRouteToolIconKey::FieldPath => route_tool_icon_from_key(RouteToolIconKey::FieldPath),
         HostRouteToolIconKey::RouteOffset => {
             route_tool_icon_from_key(RouteToolIconKey::RouteOffset)
         }
         HostRouteToolIconKey::Rounding => route_tool_icon_from_key(RouteToolIconKey::Rounding),
         HostRouteToolIconKey::ColorPath => route_tool_icon_from_key(RouteToolIconKey::ColorPath),
+        HostRouteToolIconKey::Clothoid => route_tool_icon_from_key(RouteToolIconKey::Clothoid),
+        HostRouteToolIconKey::Arc => route_tool_icon_from_key(RouteToolIconKey::Arc),
+        HostRouteToolIconKey::Junction => route_tool_icon_from_key(RouteToolIconKey::Junction),
     }
 }
 
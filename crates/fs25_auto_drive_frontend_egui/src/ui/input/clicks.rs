@@ -47,7 +47,7 @@ impl InputState {
                             modifiers: host_modifiers(modifiers),
                         });
                     }
-                    EditorTool::Select => {
+                    EditorTool::Select | EditorTool::Measure => {
                         host_events.push(HostViewportInputEvent::Tap {
                             button: fs25_auto_drive_host_bridge::HostPointerButton::Primary,
                             tap_kind: host_tap_kind(false),
@@ -55,6 +55,8 @@ impl InputState {
                             modifiers: host_modifiers(modifiers),
                         });
                     }
+                    // Messer schneidet nur per Drag
+                    EditorTool::Knife => {}
                     EditorTool::Route => {
                         if ctx.route_tool_prefers_generic_node_pick {
                             host_events.push(HostViewportInputEvent::Tap {
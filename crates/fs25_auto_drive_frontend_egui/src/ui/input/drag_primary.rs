@@ -1,4 +1,5 @@
-//! Drag-Start/-Ende: Selektion-Move, Kamera-Pan, Route-Tool-Drag, Rect/Lasso-Selektion.
+//! Drag-Start/-Ende: Selektion-Move, Kamera-Pan, Route-Tool-Drag, Transform-Gizmo,
+//! Rect/Lasso-Selektion, Messer.
 
 use super::super::drag::DragSelectionMode;
 use super::{
@@ -76,6 +77,14 @@ impl InputState {
 
         // Route-Tool Drag-Target Hit-Test (hat Vorrang vor Node-Move)
         let press_pos = ctx.ui.input(|i| i.pointer.press_origin());
+
+        // Transform-Gizmo-Griffe der Selektion (nur Auswahl-Werkzeug)
+        if let Some(pointer_pos) = press_pos
+            && self.try_begin_transform_gizmo(ctx, pointer_pos, local_intents)
+        {
+            return;
+        }
+
         let route_drag_hit = if ctx.active_tool == EditorTool::Route && !ctx.drag_targets.is_empty()
         {
             press_pos.and_then(|pointer_pos| {
@@ -102,6 +111,14 @@ impl InputState {
             self.primary_drag_via_bridge = false;
         } else if let Some(pointer_pos) = press_pos.or_else(|| ctx.response.interact_pointer_pos())
         {
+            // Messer: Linie lokal fuer das Overlay mitfuehren, Schnitt loest die Bridge aus
+            if ctx.active_tool == EditorTool::Knife {
+                self.drag_selection = Some(DragSelection {
+                    mode: DragSelectionMode::KnifeLine,
+                    start_screen: pointer_pos,
+                    points_screen: vec![pointer_pos],
+                });
+            }
             host_events.push(HostViewportInputEvent::DragStart {
                 button: fs25_auto_drive_host_bridge::HostPointerButton::Primary,
                 screen_pos: to_viewport_screen_pos(pointer_pos, ctx.response),
@@ -125,7 +142,7 @@ impl InputState {
         }
         if let Some(pointer_pos) = ctx.response.interact_pointer_pos() {
             match selection.mode {
-                DragSelectionMode::Rect => {
+                DragSelectionMode::Rect | DragSelectionMode::KnifeLine => {
                     if selection.points_screen.len() == 1 {
                         selection.points_screen.push(pointer_pos);
                     } else {
@@ -171,6 +188,9 @@ impl InputState {
                 DragSelectionMode::Lasso => {
                     // Normales Node-Lasso laeuft ueber den stateful Bridge-Drag-Lifecycle.
                 }
+                DragSelectionMode::KnifeLine => {
+                    // Messer-Schnitt laeuft ueber den stateful Bridge-Drag-Lifecycle.
+                }
                 DragSelectionMode::ToolLasso => {
                     if selection.points_screen.len() >= 3 {
                         let polygon = selection
@@ -194,6 +214,9 @@ impl InputState {
 
         if self.primary_drag_mode == PrimaryDragMode::RouteToolPointDrag {
             local_intents.push(AppIntent::RouteToolDragEnded);
+        } else if self.primary_drag_mode == PrimaryDragMode::TransformGizmo {
+            self.transform_drag = None;
+            local_intents.push(AppIntent::EndRotateSelectedNodesRequested);
         } else if self.primary_drag_via_bridge {
             host_events.push(HostViewportInputEvent::DragEnd {
                 button: fs25_auto_drive_host_bridge::HostPointerButton::Primary,
@@ -3,7 +3,9 @@
 //! Aufgeteilt in phasenbasierte Submodule:
 //! - `clicks` — Klick-Events (Einfach-/Doppel-Klick, Tool-Routing)
 //! - `drag_primary` — Drag-Start/-Ende (Selektion-Move, Kamera-Pan, Route-Tool-Drag)
+//! - `polygon_lasso` — Polygon-Lasso (Eckpunkte per Alt+Klick, Abschluss per Doppelklick/Enter)
 //! - `pointer_delta` — Pan/Move-Deltas waehrend aktiver Drags
+//! - `transform_gizmo` — Rotier-/Skalier-Griffe um die Selektion (Auswahl-Werkzeug)
 //! - `zoom` — Scroll-Zoom auf Mausposition
 //!
 //! Interne Hilfsmodule:
@@ -14,6 +16,8 @@
 mod clicks;
 mod drag_primary;
 mod pointer_delta;
+mod polygon_lasso;
+mod transform_gizmo;
 mod zoom;
 
 mod helpers;
@@ -302,6 +306,60 @@ mod tests {
         assert!(!input_state.primary_drag_via_bridge);
     }
 
+    /// Prüft, dass ein Drag im Messer-Tool die Linie lokal zeichnet und den Schnitt der Bridge überlässt.
+    #[test]
+    fn test_knife_drag_draws_line_and_uses_bridge_lifecycle() {
+        let ctx = egui::Context::default();
+        let mut input_state = InputState::default();
+        let modifiers = egui::Modifiers::NONE;
+
+        collect_frame(
+            &ctx,
+            &mut input_state,
+            pointer_move_input(DRAG_START_POS, modifiers),
+            EditorTool::Knife,
+            false,
+            false,
+        );
+        collect_frame(
+            &ctx,
+            &mut input_state,
+            pointer_button_input(DRAG_START_POS, true, modifiers),
+            EditorTool::Knife,
+            false,
+            false,
+        );
+        let start_frame = collect_frame(
+            &ctx,
+            &mut input_state,
+            pointer_move_input(DRAG_MOVE_1, modifiers),
+            EditorTool::Knife,
+            false,
+            false,
+        );
+        assert_eq!(
+            input_state
+                .drag_selection
+                .as_ref()
+                .map(|selection| selection.mode),
+            Some(super::super::drag::DragSelectionMode::KnifeLine)
+        );
+        assert!(has_primary_drag_start(&start_frame.host_events));
+        assert!(input_state.primary_drag_via_bridge);
+
+        let end_frame = collect_frame(
+            &ctx,
+            &mut input_state,
+            pointer_button_input(DRAG_MOVE_1, false, modifiers),
+            EditorTool::Knife,
+            false,
+            false,
+        );
+        assert!(end_frame.intents.is_empty());
+        assert!(has_primary_drag_end(&end_frame.host_events));
+        assert!(input_state.drag_selection.is_none());
+    }
+
     /// Prüft, dass normales Alt-Drag weiterhin als Node-Lasso über den Bridge-Drag-Lifecycle läuft.
     #[test]
     fn test_lasso_drag_smoke_uses_bridge_lifecycle() {
@@ -466,6 +524,94 @@ mod tests {
         assert!(!input_state.primary_drag_via_bridge);
     }
 
+    #[test]
+    fn test_polygon_lasso_alt_clicks_and_enter_emit_lasso_selection() {
+        let ctx = egui::Context::default();
+        let mut input_state = InputState::default();
+        let alt = egui::Modifiers {
+            alt: true,
+            ..Default::default()
+        };
+        let corners = [DRAG_START_POS, DRAG_MOVE_1, DRAG_MOVE_3];
+
+        for (index, &pos) in corners.iter().enumerate() {
+            // Grosser Zeitabstand zwischen den Klicks verhindert eine Doppelklick-Erkennung.
+            let time = index as f64 * 2.0;
+            let mut hover = pointer_move_input(pos, alt);
+            hover.time = Some(time);
+            let mut press = pointer_button_input(pos, true, alt);
+            press.time = Some(time + 0.01);
+            let mut release = pointer_button_input(pos, false, alt);
+            release.time = Some(time + 0.05);
+
+            collect_frame(
+                &ctx,
+                &mut input_state,
+                hover,
+                EditorTool::Select,
+                false,
+                false,
+            );
+            collect_frame(
+                &ctx,
+                &mut input_state,
+                press,
+                EditorTool::Select,
+                false,
+                false,
+            );
+            let release_frame = collect_frame(
+                &ctx,
+                &mut input_state,
+                release,
+                EditorTool::Select,
+                false,
+                false,
+            );
+            assert!(release_frame.intents.is_empty());
+            assert!(release_frame
+                .host_events
+                .iter()
+                .all(|event| { !matches!(event, HostViewportInputEvent::Tap { .. }) }));
+        }
+        assert_eq!(
+            input_state
+                .polygon_lasso
+                .as_ref()
+                .map(|lasso| lasso.points_world.len()),
+            Some(3)
+        );
+
+        let mut enter = frame_input(
+            vec![egui::Event::Key {
+                key: egui::Key::Enter,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::NONE,
+            }],
+            egui::Modifiers::NONE,
+        );
+        enter.time = Some(10.0);
+        let enter_frame = collect_frame(
+            &ctx,
+            &mut input_state,
+            enter,
+            EditorTool::Select,
+            false,
+            false,
+        );
+
+        let lasso = enter_frame.intents.iter().find_map(|intent| match intent {
+            AppIntent::SelectNodesInLassoRequested { polygon, additive } => {
+                Some((polygon.len(), *additive))
+            }
+            _ => None,
+        });
+        assert_eq!(lasso, Some((3, false)));
+        assert!(input_state.polygon_lasso.is_none());
+    }
+
     #[test]
     fn test_discrete_mouse_wheel_zoom_emits_one_raw_step_without_followup_frames() {
         let ctx = egui::Context::default();
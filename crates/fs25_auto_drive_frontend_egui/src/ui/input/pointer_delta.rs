@@ -46,6 +46,9 @@ impl InputState {
                         local_intents.push(AppIntent::RouteToolDragUpdated { world_pos });
                     }
                 }
+                PrimaryDragMode::TransformGizmo => {
+                    self.update_transform_gizmo(ctx, local_intents);
+                }
                 PrimaryDragMode::None if self.primary_drag_via_bridge => {
                     if let Some(pointer_pos) = ctx
                         .response
@@ -0,0 +1,133 @@
+//! Polygon-Lasso: Eckpunkte per Klick setzen, Abschluss per Doppelklick oder Enter.
+//!
+//! Alt+Klick im Auswahl-Werkzeug startet das Polygon; solange es aktiv ist, setzt
+//! jeder Linksklick einen weiteren Eckpunkt. Backspace entfernt den letzten
+//! Eckpunkt, Escape bricht ab. Der Abschluss emittiert denselben
+//! `SelectNodesInLassoRequested`-Intent wie das Freihand-Lasso.
+
+use super::super::drag::{draw_polygon_lasso_overlay, PolygonLasso};
+use super::{screen_pos_to_world, InputState, ViewportContext};
+use crate::app::{AppIntent, EditorTool};
+
+impl InputState {
+    /// Verarbeitet Tastatur-Eingaben eines aktiven Polygon-Lassos.
+    ///
+    /// Muss vor den allgemeinen Shortcuts laufen: Enter, Backspace und Escape
+    /// werden konsumiert, damit sie nicht zusaetzlich Route-Tool, Loeschen oder
+    /// Selektion-Aufheben ausloesen.
+    pub(crate) fn handle_polygon_lasso_keys(
+        &mut self,
+        ctx: &ViewportContext,
+        local_intents: &mut Vec<AppIntent>,
+    ) {
+        if self.polygon_lasso.is_none() {
+            return;
+        }
+        if ctx.active_tool != EditorTool::Select {
+            self.polygon_lasso = None;
+            return;
+        }
+
+        let (enter, backspace, escape) = ctx.ui.ctx().input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Backspace),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            )
+        });
+
+        if escape {
+            self.polygon_lasso = None;
+        } else if enter {
+            self.finish_polygon_lasso(local_intents);
+        } else if backspace && let Some(lasso) = self.polygon_lasso.as_mut() {
+            lasso.points_world.pop();
+            if lasso.points_world.is_empty() {
+                self.polygon_lasso = None;
+            }
+        }
+    }
+
+    /// Verarbeitet Klicks fuer das Polygon-Lasso.
+    ///
+    /// Gibt `true` zurueck, wenn der Klick verbraucht wurde und nicht mehr als
+    /// normaler Node-Pick/Doppelklick weitergereicht werden darf.
+    pub(crate) fn handle_polygon_lasso_clicks(
+        &mut self,
+        ctx: &ViewportContext,
+        modifiers: egui::Modifiers,
+        local_intents: &mut Vec<AppIntent>,
+    ) -> bool {
+        if ctx.active_tool != EditorTool::Select {
+            return false;
+        }
+
+        if self.polygon_lasso.is_some()
+            && ctx.response.double_clicked_by(egui::PointerButton::Primary)
+        {
+            // Der erste Klick des Doppelklicks hat den letzten Eckpunkt bereits gesetzt.
+            self.finish_polygon_lasso(local_intents);
+            return true;
+        }
+
+        if !ctx.response.clicked_by(egui::PointerButton::Primary) {
+            return false;
+        }
+        let Some(pointer_pos) = ctx.response.interact_pointer_pos() else {
+            return false;
+        };
+        let world_pos =
+            screen_pos_to_world(pointer_pos, ctx.response, ctx.viewport_size, ctx.camera);
+
+        match self.polygon_lasso.as_mut() {
+            Some(lasso) => {
+                lasso.points_world.push(world_pos);
+                true
+            }
+            None if modifiers.alt => {
+                self.polygon_lasso = Some(PolygonLasso {
+                    points_world: vec![world_pos],
+                    additive: modifiers.command,
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Zeichnet das aktive Polygon-Lasso inklusive Gummiband zur Mausposition.
+    pub(crate) fn draw_polygon_lasso(&self, ctx: &ViewportContext) {
+        let Some(lasso) = self.polygon_lasso.as_ref() else {
+            return;
+        };
+
+        let viewport_size = glam::Vec2::new(ctx.viewport_size[0], ctx.viewport_size[1]);
+        let points_screen: Vec<egui::Pos2> = lasso
+            .points_world
+            .iter()
+            .map(|&world| {
+                let local = ctx.camera.world_to_screen(world, viewport_size);
+                ctx.response.rect.min + egui::vec2(local.x, local.y)
+            })
+            .collect();
+        draw_polygon_lasso_overlay(
+            &points_screen,
+            ctx.response.hover_pos(),
+            ctx.ui,
+            ctx.response,
+        );
+    }
+
+    /// Schliesst das Polygon und emittiert die Lasso-Selektion (ab drei Eckpunkten).
+    fn finish_polygon_lasso(&mut self, local_intents: &mut Vec<AppIntent>) {
+        let Some(lasso) = self.polygon_lasso.take() else {
+            return;
+        };
+        if lasso.points_world.len() >= 3 {
+            local_intents.push(AppIntent::SelectNodesInLassoRequested {
+                polygon: lasso.points_world,
+                additive: lasso.additive,
+            });
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! InputState und zugehoerige Typen fuer das Viewport-Input-Handling.
 
+use super::super::drag::PolygonLasso;
 use super::context_menu;
+use super::transform_gizmo::TransformGizmoDrag;
 use super::DragSelection;
 
 /// Modus des primaeren (Links-)Drags im Viewport.
@@ -10,6 +12,8 @@ pub(crate) enum PrimaryDragMode {
     None,
     /// Drag eines Route-Tool-Steuerpunkts (Anker/CP)
     RouteToolPointDrag,
+    /// Drag eines Transform-Gizmo-Griffs (Rotieren/Skalieren der Selektion)
+    TransformGizmo,
 }
 
 /// Immutable Snapshot des Kontextmenue-States beim Rechtsklick.
@@ -35,6 +39,10 @@ pub struct InputState {
     /// Gibt an, ob der aktuelle Primaer-Drag ueber die Bridge-Seam laeuft.
     pub(crate) primary_drag_via_bridge: bool,
     pub(crate) drag_selection: Option<DragSelection>,
+    /// Aktives Polygon-Lasso (Eckpunkte per Klick), unabhaengig von Drags.
+    pub(crate) polygon_lasso: Option<PolygonLasso>,
+    /// Laufender Transform-Gizmo-Drag (nur bei `PrimaryDragMode::TransformGizmo`).
+    pub(crate) transform_drag: Option<TransformGizmoDrag>,
     /// Snapshot des Menue-Zustands, gueltig solange das Popup offen ist.
     /// Wird beim Rechtsklick gesetzt und erst geleert, wenn egui das Popup schliesst.
     pub(crate) context_menu_snapshot: Option<ContextMenuSnapshot>,
@@ -55,6 +63,8 @@ impl InputState {
             primary_drag_mode: PrimaryDragMode::None,
             primary_drag_via_bridge: false,
             drag_selection: None,
+            polygon_lasso: None,
+            transform_drag: None,
             context_menu_snapshot: None,
             edit_panel_pos: None,
             rotation_active: false,
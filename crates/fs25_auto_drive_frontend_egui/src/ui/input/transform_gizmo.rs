@@ -0,0 +1,383 @@
+//! Transform-Gizmo: Rotier- und Skalier-Griffe um die Selektion im Auswahl-Werkzeug.
+//!
+//! Ab zwei selektierten Nodes zeichnet das Auswahl-Werkzeug einen Rahmen um die
+//! Selektion mit vier Eck-Griffen (Skalieren) und einem Griff ueber der Oberkante
+//! (Rotieren). Ein Drag auf einem Griff laeuft ueber den Rotation-Lifecycle
+//! (`BeginRotateSelectedNodesRequested` → `TransformSelectionRequested` →
+//! `EndRotateSelectedNodesRequested`) und sendet pro Frame nur Deltas. Pivot ist
+//! die Mitte des Rahmens; Ctrl rastet die Rotation in 15°-Schritten ein.
+
+use super::super::drag::selection_overlay_style;
+use super::{screen_pos_to_world, InputState, PrimaryDragMode, ViewportContext};
+use crate::app::{AppIntent, EditorTool};
+use std::f32::consts::{PI, TAU};
+
+/// Abstand des Rahmens zur Selektions-Bounding-Box in Pixeln.
+const FRAME_PADDING_PX: f32 = 10.0;
+/// Abstand des Rotier-Griffs ueber der Rahmen-Oberkante in Pixeln.
+const ROTATE_HANDLE_OFFSET_PX: f32 = 24.0;
+/// Trefferradius der Griffe in Pixeln.
+const HANDLE_HIT_RADIUS_PX: f32 = 8.0;
+/// Zeichengroesse der Griffe in Pixeln.
+const HANDLE_SIZE_PX: f32 = 7.0;
+/// Einrast-Schritt der Rotation bei gedrueckter Ctrl-Taste.
+const ROTATION_SNAP_STEP_RAD: f32 = 15.0 * PI / 180.0;
+/// Untergrenze des Gesamt-Skalierungsfaktors eines Drags.
+const MIN_TOTAL_SCALE: f32 = 0.01;
+
+/// Art des Gizmo-Griffs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransformGizmoHandle {
+    /// Rotier-Griff ueber der Oberkante
+    Rotate,
+    /// Eck-Griff zum gleichmaessigen Skalieren
+    Scale,
+}
+
+/// Laufender Gizmo-Drag inklusive bereits angewendeter Transformation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TransformGizmoDrag {
+    /// Gezogener Griff
+    pub handle: TransformGizmoHandle,
+    /// Pivot in Weltkoordinaten (Rahmenmitte beim Drag-Start)
+    pub pivot: glam::Vec2,
+    /// Zeigerwinkel um den Pivot im letzten Frame
+    last_pointer_angle: f32,
+    /// Aufsummierter Zeigerwinkel seit Drag-Start (ohne Einrasten)
+    raw_angle: f32,
+    /// Bereits per Intent angewendeter Winkel
+    applied_angle: f32,
+    /// Zeigerabstand zum Pivot beim Drag-Start
+    start_distance: f32,
+    /// Bereits per Intent angewendeter Skalierungsfaktor
+    applied_scale: f32,
+}
+
+impl TransformGizmoDrag {
+    /// Startet einen Gizmo-Drag am Weltpunkt `pointer_world`.
+    pub(crate) fn new(
+        handle: TransformGizmoHandle,
+        pivot: glam::Vec2,
+        pointer_world: glam::Vec2,
+    ) -> Self {
+        let offset = pointer_world - pivot;
+        Self {
+            handle,
+            pivot,
+            last_pointer_angle: offset.y.atan2(offset.x),
+            raw_angle: 0.0,
+            applied_angle: 0.0,
+            start_distance: offset.length(),
+            applied_scale: 1.0,
+        }
+    }
+
+    /// Berechnet das Delta `(angle, scale)` zur neuen Zeigerposition.
+    ///
+    /// Liefert `None`, wenn sich die Transformation nicht aendert.
+    pub(crate) fn advance(
+        &mut self,
+        pointer_world: glam::Vec2,
+        snap_rotation: bool,
+    ) -> Option<(f32, f32)> {
+        let offset = pointer_world - self.pivot;
+        match self.handle {
+            TransformGizmoHandle::Rotate => {
+                if offset.length_squared() <= f32::EPSILON {
+                    return None;
+                }
+                let pointer_angle = offset.y.atan2(offset.x);
+                self.raw_angle += wrap_angle(pointer_angle - self.last_pointer_angle);
+                self.last_pointer_angle = pointer_angle;
+
+                let target = if snap_rotation {
+                    (self.raw_angle / ROTATION_SNAP_STEP_RAD).round() * ROTATION_SNAP_STEP_RAD
+                } else {
+                    self.raw_angle
+                };
+                let delta = target - self.applied_angle;
+                if delta == 0.0 {
+                    return None;
+                }
+                self.applied_angle = target;
+                Some((delta, 1.0))
+            }
+            TransformGizmoHandle::Scale => {
+                if self.start_distance <= f32::EPSILON {
+                    return None;
+                }
+                let target = (offset.length() / self.start_distance).max(MIN_TOTAL_SCALE);
+                let delta = target / self.applied_scale;
+                if delta == 1.0 {
+                    return None;
+                }
+                self.applied_scale = target;
+                Some((0.0, delta))
+            }
+        }
+    }
+}
+
+/// Bildschirm-Geometrie des Gizmos fuer die aktuelle Selektion.
+#[derive(Debug, Clone, Copy)]
+struct GizmoLayout {
+    /// Rahmen um die Selektion (inkl. Padding)
+    frame: egui::Rect,
+    /// Pivot in Weltkoordinaten (Mitte der Bounding-Box)
+    pivot_world: glam::Vec2,
+}
+
+impl GizmoLayout {
+    fn corners(&self) -> [egui::Pos2; 4] {
+        [
+            self.frame.left_top(),
+            self.frame.right_top(),
+            self.frame.right_bottom(),
+            self.frame.left_bottom(),
+        ]
+    }
+
+    fn rotate_handle(&self) -> egui::Pos2 {
+        self.frame.center_top() - egui::vec2(0.0, ROTATE_HANDLE_OFFSET_PX)
+    }
+
+    fn hit_test(&self, screen_pos: egui::Pos2) -> Option<TransformGizmoHandle> {
+        let radius_sq = HANDLE_HIT_RADIUS_PX * HANDLE_HIT_RADIUS_PX;
+        if self.rotate_handle().distance_sq(screen_pos) <= radius_sq {
+            return Some(TransformGizmoHandle::Rotate);
+        }
+        self.corners()
+            .iter()
+            .any(|corner| corner.distance_sq(screen_pos) <= radius_sq)
+            .then_some(TransformGizmoHandle::Scale)
+    }
+}
+
+/// Normalisiert einen Winkel auf `(-PI, PI]`.
+fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    if wrapped <= -PI {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+/// Berechnet das Gizmo-Layout, falls das Auswahl-Werkzeug mindestens zwei Nodes selektiert hat.
+fn gizmo_layout(ctx: &ViewportContext) -> Option<GizmoLayout> {
+    if ctx.active_tool != EditorTool::Select || ctx.selected_node_ids.len() < 2 {
+        return None;
+    }
+    let road_map = ctx.road_map?;
+
+    let mut min = glam::Vec2::splat(f32::INFINITY);
+    let mut max = glam::Vec2::splat(f32::NEG_INFINITY);
+    for node in ctx
+        .selected_node_ids
+        .iter()
+        .filter_map(|&id| road_map.node(id))
+    {
+        min = min.min(node.position);
+        max = max.max(node.position);
+    }
+    if !min.is_finite() || !max.is_finite() {
+        return None;
+    }
+
+    let viewport_size = glam::Vec2::new(ctx.viewport_size[0], ctx.viewport_size[1]);
+    let to_screen = |world: glam::Vec2| {
+        let local = ctx.camera.world_to_screen(world, viewport_size);
+        ctx.response.rect.min + egui::vec2(local.x, local.y)
+    };
+    let frame = egui::Rect::from_two_pos(to_screen(min), to_screen(max)).expand(FRAME_PADDING_PX);
+
+    Some(GizmoLayout {
+        frame,
+        pivot_world: (min + max) * 0.5,
+    })
+}
+
+impl InputState {
+    /// Startet einen Gizmo-Drag, wenn der Druckpunkt einen Griff trifft.
+    ///
+    /// Gibt `true` zurueck, wenn der Drag vom Gizmo uebernommen wurde.
+    pub(crate) fn try_begin_transform_gizmo(
+        &mut self,
+        ctx: &ViewportContext,
+        press_pos: egui::Pos2,
+        local_intents: &mut Vec<AppIntent>,
+    ) -> bool {
+        let Some(layout) = gizmo_layout(ctx) else {
+            return false;
+        };
+        let Some(handle) = layout.hit_test(press_pos) else {
+            return false;
+        };
+
+        let pointer_world =
+            screen_pos_to_world(press_pos, ctx.response, ctx.viewport_size, ctx.camera);
+        self.transform_drag = Some(TransformGizmoDrag::new(
+            handle,
+            layout.pivot_world,
+            pointer_world,
+        ));
+        self.primary_drag_mode = PrimaryDragMode::TransformGizmo;
+        self.primary_drag_via_bridge = false;
+        local_intents.push(AppIntent::BeginRotateSelectedNodesRequested);
+        true
+    }
+
+    /// Emittiert das Transform-Delta eines laufenden Gizmo-Drags.
+    pub(crate) fn update_transform_gizmo(
+        &mut self,
+        ctx: &ViewportContext,
+        local_intents: &mut Vec<AppIntent>,
+    ) {
+        let Some(drag) = self.transform_drag.as_mut() else {
+            return;
+        };
+        let Some(pointer_pos) = ctx.response.interact_pointer_pos() else {
+            return;
+        };
+
+        let pointer_world =
+            screen_pos_to_world(pointer_pos, ctx.response, ctx.viewport_size, ctx.camera);
+        let snap_rotation = ctx.ui.input(|i| i.modifiers.command);
+        if let Some((angle, scale)) = drag.advance(pointer_world, snap_rotation) {
+            local_intents.push(AppIntent::TransformSelectionRequested {
+                pivot: drag.pivot,
+                angle,
+                scale,
+            });
+        }
+    }
+
+    /// Zeichnet Rahmen und Griffe des Gizmos und setzt den Hover-Cursor.
+    pub(crate) fn draw_transform_gizmo(&self, ctx: &ViewportContext) {
+        if self.drag_selection.is_some() || self.polygon_lasso.is_some() {
+            return;
+        }
+        let Some(layout) = gizmo_layout(ctx) else {
+            return;
+        };
+
+        let (_, stroke) = selection_overlay_style(ctx.ui);
+        let painter = ctx
+            .ui
+            .ctx()
+            .layer_painter(egui::LayerId::new(egui::Order::Foreground, ctx.response.id))
+            .with_clip_rect(ctx.response.rect);
+
+        let frame_stroke = egui::Stroke::new(1.0, stroke.color.gamma_multiply(0.7));
+        painter.rect_stroke(layout.frame, 0.0, frame_stroke, egui::StrokeKind::Middle);
+        painter.line_segment(
+            [layout.frame.center_top(), layout.rotate_handle()],
+            frame_stroke,
+        );
+
+        let handle_fill = ctx.ui.visuals().extreme_bg_color;
+        for corner in layout.corners() {
+            let rect = egui::Rect::from_center_size(corner, egui::Vec2::splat(HANDLE_SIZE_PX));
+            painter.rect_filled(rect, 0.0, handle_fill);
+            painter.rect_stroke(rect, 0.0, stroke, egui::StrokeKind::Middle);
+        }
+        painter.circle(
+            layout.rotate_handle(),
+            HANDLE_SIZE_PX * 0.6,
+            handle_fill,
+            stroke,
+        );
+
+        let hovered = self.transform_drag.map(|drag| drag.handle).or_else(|| {
+            ctx.response
+                .hover_pos()
+                .and_then(|pos| layout.hit_test(pos))
+        });
+        match hovered {
+            Some(TransformGizmoHandle::Rotate) => {
+                ctx.ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+            }
+            Some(TransformGizmoHandle::Scale) => {
+                ctx.ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeNwSe);
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn rotate_drag_emits_incremental_angle_deltas() {
+        let mut drag = TransformGizmoDrag::new(
+            TransformGizmoHandle::Rotate,
+            glam::Vec2::ZERO,
+            glam::Vec2::new(10.0, 0.0),
+        );
+
+        let (first, scale) = drag
+            .advance(glam::Vec2::new(10.0, 10.0), false)
+            .expect("Delta erwartet");
+        assert!((first - FRAC_PI_2 / 2.0).abs() < 1e-5);
+        assert_eq!(scale, 1.0);
+
+        let (second, _) = drag
+            .advance(glam::Vec2::new(0.0, 10.0), false)
+            .expect("Delta erwartet");
+        assert!((first + second - FRAC_PI_2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rotate_drag_crosses_angle_wrap_without_jump() {
+        let mut drag = TransformGizmoDrag::new(
+            TransformGizmoHandle::Rotate,
+            glam::Vec2::ZERO,
+            glam::Vec2::new(-10.0, 0.1),
+        );
+
+        let (delta, _) = drag
+            .advance(glam::Vec2::new(-10.0, -0.1), false)
+            .expect("Delta erwartet");
+        assert!(delta.abs() < 0.1, "delta={delta}");
+    }
+
+    #[test]
+    fn snapped_rotate_drag_applies_whole_steps_only() {
+        let mut drag = TransformGizmoDrag::new(
+            TransformGizmoHandle::Rotate,
+            glam::Vec2::ZERO,
+            glam::Vec2::new(10.0, 0.0),
+        );
+
+        // 5° liegen unter dem halben Einrast-Schritt → keine Aenderung
+        let small = glam::Vec2::from_angle(5f32.to_radians()) * 10.0;
+        assert!(drag.advance(small, true).is_none());
+
+        let larger = glam::Vec2::from_angle(20f32.to_radians()) * 10.0;
+        let (delta, _) = drag.advance(larger, true).expect("Delta erwartet");
+        assert!((delta - ROTATION_SNAP_STEP_RAD).abs() < 1e-5);
+    }
+
+    #[test]
+    fn scale_drag_emits_relative_factors() {
+        let mut drag = TransformGizmoDrag::new(
+            TransformGizmoHandle::Scale,
+            glam::Vec2::ZERO,
+            glam::Vec2::new(10.0, 0.0),
+        );
+
+        let (angle, first) = drag
+            .advance(glam::Vec2::new(20.0, 0.0), false)
+            .expect("Delta erwartet");
+        assert_eq!(angle, 0.0);
+        assert!((first - 2.0).abs() < 1e-5);
+
+        let (_, second) = drag
+            .advance(glam::Vec2::new(5.0, 0.0), false)
+            .expect("Delta erwartet");
+        assert!((first * second - 0.5).abs() < 1e-5);
+    }
+}
@@ -21,6 +21,8 @@ pub(crate) struct ViewportContext<'a> {
     pub response: &'a egui::Response,
     pub viewport_size: [f32; 2],
     pub camera: &'a Camera2D,
+    /// Geladene RoadMap (fuer Gizmo-Geometrie der Selektion)
+    pub road_map: Option<&'a RoadMap>,
     pub selected_node_ids: &'a IndexSet<u64>,
     pub active_tool: EditorTool,
     pub route_tool_prefers_generic_node_pick: bool,
@@ -81,6 +83,7 @@ impl InputState {
             response,
             viewport_size,
             camera,
+            road_map,
             selected_node_ids,
             active_tool,
             route_tool_prefers_generic_node_pick,
@@ -96,6 +99,9 @@ impl InputState {
             size_px: viewport_size,
         });
 
+        // Polygon-Lasso konsumiert Enter/Backspace/Escape vor den allgemeinen Shortcuts
+        self.handle_polygon_lasso_keys(&ctx, &mut local_intents);
+
         // Keyboard-Shortcuts (ausgelagert in keyboard.rs)
         local_intents.extend(keyboard::collect_keyboard_intents(
             ui,
@@ -115,11 +121,15 @@ impl InputState {
         self.handle_drag_start(&ctx, modifiers, &mut local_intents, &mut host_events);
         self.handle_drag_update(&ctx);
         self.handle_drag_end(&ctx, &mut local_intents, &mut host_events);
-        self.handle_clicks(&ctx, modifiers, &mut local_intents, &mut host_events);
+        if !self.handle_polygon_lasso_clicks(&ctx, modifiers, &mut local_intents) {
+            self.handle_clicks(&ctx, modifiers, &mut local_intents, &mut host_events);
+        }
         self.handle_pointer_delta(&ctx, &mut local_intents, &mut host_events);
 
         // Drag-Selektion Overlay (ausgelagert in drag.rs)
         draw_drag_selection_overlay(self.drag_selection.as_ref(), ui, response);
+        self.draw_polygon_lasso(&ctx);
+        self.draw_transform_gizmo(&ctx);
 
         // ── Einheitliches Context-Menu-System ───────────────────────────
         // Genau EIN `response.context_menu()`-Aufruf pro Frame.
@@ -134,7 +144,10 @@ impl InputState {
 
         // Beim Rechtsklick: Snapshot erstellen und einfrieren
         // Guard: Kein Kontextmenue waehrend Rect/Lasso-Drag
-        if response.secondary_clicked() && self.drag_selection.is_none() {
+        if response.secondary_clicked()
+            && self.drag_selection.is_none()
+            && self.polygon_lasso.is_none()
+        {
             // Node unter Mausposition finden (fuer NodeFocused-Menue)
             let clicked_node_id = pointer_pos_world.and_then(|pos| {
                 road_map.and_then(|rm| {
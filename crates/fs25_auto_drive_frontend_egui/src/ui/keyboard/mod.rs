@@ -8,6 +8,20 @@ use crate::app::{
 };
 use indexmap::IndexSet;
 
+/// Schrittweite fuer Shift+Pfeiltasten (Selektion verschieben) in Metern.
+const NUDGE_STEP_M: f32 = 1.0;
+/// Feine Schrittweite fuer Shift+Alt+Pfeiltasten in Metern.
+const NUDGE_FINE_STEP_M: f32 = 0.1;
+
+/// Schaltet egui's eingebauten UI-Zoom per Ctrl+Plus/Ctrl+Minus/Ctrl+0 ab.
+///
+/// Diese Kombinationen erweitern bzw. verkleinern im Editor die Selektion;
+/// ohne diesen Aufruf wuerde egui bei jedem Druck zusaetzlich die gesamte
+/// Oberflaeche skalieren. Einmalig beim Start aufrufen.
+pub fn disable_builtin_zoom_shortcuts(ctx: &egui::Context) {
+    ctx.options_mut(|options| options.zoom_with_keyboard = false);
+}
+
 #[derive(Clone, Copy)]
 pub(super) struct KeyboardContext {
     active_tool: EditorTool,
@@ -54,6 +68,11 @@ fn collect_escape_intents(
         return vec![AppIntent::RouteToolCancelled];
     }
 
+    if active_tool == EditorTool::Measure {
+        // Mess-Tool aktiv -> Messlinie verwerfen (erneutes Esc verlaesst das Tool)
+        return vec![AppIntent::MeasureClearRequested];
+    }
+
     if !selected_node_ids.is_empty() {
         // Selektion aufheben (gilt fuer alle Tools inkl. Route im Leerlauf)
         return vec![AppIntent::ClearSelectionRequested];
@@ -182,6 +201,7 @@ pub(super) fn collect_keyboard_intents(
         key_c_pressed,
         key_v_pressed,
         key_x_pressed,
+        key_d_pressed,
         key_enter_pressed,
         key_up_pressed,
         key_down_pressed,
@@ -193,6 +213,7 @@ pub(super) fn collect_keyboard_intents(
             i.key_pressed(egui::Key::C),
             i.key_pressed(egui::Key::V),
             i.key_pressed(egui::Key::X),
+            i.key_pressed(egui::Key::D),
             i.key_pressed(egui::Key::Enter),
             i.key_pressed(egui::Key::ArrowUp),
             i.key_pressed(egui::Key::ArrowDown),
@@ -323,16 +344,38 @@ pub(super) fn collect_keyboard_intents(
         });
     }
 
+    // Ctrl+C / Ctrl+V liefert egui-winit als `Event::Copy` bzw. `Event::Paste(text)`
+    // (mit dem Text der System-Zwischenablage) statt als Key-Events.
+    let (copy_event, paste_text) = ui.input(|i| {
+        let mut copy_event = false;
+        let mut paste_text = None;
+        for event in &i.events {
+            match event {
+                egui::Event::Copy => copy_event = true,
+                egui::Event::Paste(text) => paste_text = Some(text.clone()),
+                _ => {}
+            }
+        }
+        (copy_event, paste_text)
+    });
+
     // Ctrl+C: Selektion kopieren
-    if modifiers.command && key_c_pressed && !selected_node_ids.is_empty() {
+    if (copy_event || (modifiers.command && key_c_pressed)) && !selected_node_ids.is_empty() {
         events.push(AppIntent::CopySelectionRequested);
     }
 
-    // Ctrl+V: Paste-Vorschau starten
-    if modifiers.command && key_v_pressed && clipboard_has_data {
+    // Ctrl+V: Editor-JSON aus der System-Zwischenablage oder interne Zwischenablage einfuegen
+    if let Some(text) = paste_text {
+        events.push(AppIntent::PasteFromTextRequested { text });
+    } else if modifiers.command && key_v_pressed && clipboard_has_data {
         events.push(AppIntent::PasteStartRequested);
     }
 
+    // Ctrl+D: Selektion duplizieren und Kopie interaktiv platzieren
+    if modifiers.command && key_d_pressed && !selected_node_ids.is_empty() {
+        events.push(AppIntent::DuplicateSelectionRequested);
+    }
+
     // X = Trennen (bei genau 2 selektierten Nodes)
     if key_x_pressed && !modifiers.command && selected_node_ids.len() == 2 {
         let ids: Vec<u64> = selected_node_ids.iter().copied().collect();
@@ -360,6 +403,29 @@ pub(super) fn collect_keyboard_intents(
         if key_left_pressed {
             events.push(AppIntent::DecreaseRouteToolSegmentLength);
         }
+    } else if modifiers.shift && !modifiers.command && !selected_node_ids.is_empty() {
+        // Shift+Pfeiltasten: Selektion in Welt-Metern verschieben (mit Alt fein)
+        let step = if modifiers.alt {
+            NUDGE_FINE_STEP_M
+        } else {
+            NUDGE_STEP_M
+        };
+        let mut delta = glam::Vec2::ZERO;
+        if key_up_pressed {
+            delta.y -= step;
+        }
+        if key_down_pressed {
+            delta.y += step;
+        }
+        if key_left_pressed {
+            delta.x -= step;
+        }
+        if key_right_pressed {
+            delta.x += step;
+        }
+        if delta != glam::Vec2::ZERO {
+            events.push(AppIntent::NudgeSelectionRequested { delta });
+        }
     } else if !modifiers.command && !modifiers.shift && !modifiers.alt {
         // Pfeiltasten fuer Kamera-Pan (außer bei aktiven Segment-Shortcuts)
         const PAN_STEP: f32 = 100.0;
@@ -385,10 +451,13 @@ pub(super) fn collect_keyboard_intents(
         }
     }
 
-    // Plus/Minus fuer stufenweises Zoomen (keine Modifier)
-    let (key_plus_no_mod, key_minus_no_mod) = ui.input(|i| {
+    // Plus/Minus fuer stufenweises Zoomen (keine Modifier),
+    // Ctrl+Plus/Ctrl+Minus zum Erweitern/Verkleinern der Selektion
+    let (key_plus_no_mod, key_minus_no_mod, key_plus_command, key_minus_command) = ui.input(|i| {
         let mut plus = false;
         let mut minus = false;
+        let mut plus_command = false;
+        let mut minus_command = false;
         for event in &i.events {
             if let egui::Event::Key {
                 key,
@@ -399,14 +468,18 @@ pub(super) fn collect_keyboard_intents(
             {
                 let no_mod =
                     !modifiers.command && !modifiers.ctrl && !modifiers.shift && !modifiers.alt;
+                // Plus liegt auf US-Layouts auf Shift+=, daher Shift hier zulassen
+                let command = modifiers.command && !modifiers.alt;
                 match key {
                     egui::Key::Plus if no_mod => plus = true,
                     egui::Key::Minus if no_mod => minus = true,
+                    egui::Key::Plus | egui::Key::Equals if command => plus_command = true,
+                    egui::Key::Minus if command => minus_command = true,
                     _ => {}
                 }
             }
         }
-        (plus, minus)
+        (plus, minus, plus_command, minus_command)
     });
 
     if key_plus_no_mod {
@@ -415,6 +488,12 @@ pub(super) fn collect_keyboard_intents(
     if key_minus_no_mod {
         events.push(AppIntent::ZoomOutRequested);
     }
+    if key_plus_command && !selected_node_ids.is_empty() {
+        events.push(AppIntent::GrowSelectionRequested);
+    }
+    if key_minus_command && !selected_node_ids.is_empty() {
+        events.push(AppIntent::ShrinkSelectionRequested);
+    }
 
     events
 }
@@ -171,6 +171,29 @@ fn test_escape_without_selection_switches_to_select_tool() {
     )));
 }
 
+#[test]
+fn test_escape_in_measure_tool_clears_measurement() {
+    let mut selected = IndexSet::new();
+    selected.insert(5);
+
+    let events = collect_with_key_event_and_tool(
+        egui::Event::Key {
+            key: egui::Key::Escape,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::default(),
+        },
+        selected,
+        EditorTool::Measure,
+    );
+
+    assert!(matches!(
+        events.as_slice(),
+        [AppIntent::MeasureClearRequested]
+    ));
+}
+
 #[test]
 fn test_escape_in_select_tool_without_selection_does_nothing() {
     let events = collect_with_key_event(
@@ -308,6 +331,42 @@ fn test_route_tool_arrow_up_pans_camera_without_segment_adjustment_capability()
         .any(|event| matches!(event, AppIntent::IncreaseRouteToolNodeCount)));
 }
 
+#[test]
+fn test_shift_arrow_nudges_selection_instead_of_panning() {
+    let shift = egui::Modifiers {
+        shift: true,
+        ..Default::default()
+    };
+    let arrow_right = |modifiers| egui::Event::Key {
+        key: egui::Key::ArrowRight,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    };
+
+    let events = collect_with_key_event_and_modifiers(arrow_right(shift), shift, [7].into());
+    assert!(events.iter().any(|event| matches!(
+        event,
+        AppIntent::NudgeSelectionRequested { delta } if *delta == glam::Vec2::new(1.0, 0.0)
+    )));
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, AppIntent::CameraPan { .. })));
+
+    let fine = egui::Modifiers { alt: true, ..shift };
+    let events = collect_with_key_event_and_modifiers(arrow_right(fine), fine, [7].into());
+    assert!(events.iter().any(|event| matches!(
+        event,
+        AppIntent::NudgeSelectionRequested { delta } if *delta == glam::Vec2::new(0.1, 0.0)
+    )));
+
+    let events = collect_with_key_event_and_modifiers(arrow_right(shift), shift, IndexSet::new());
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, AppIntent::NudgeSelectionRequested { .. })));
+}
+
 #[test]
 fn test_text_input_focus_blocks_regular_shortcuts() {
     let events = collect_with_key_event_text_input_focus(
@@ -568,3 +627,124 @@ fn ctrl_z_bleibt_undo() {
         }
     )));
 }
+
+#[test]
+fn test_ctrl_plus_and_minus_grow_and_shrink_selection() {
+    let ctrl = egui::Modifiers {
+        ctrl: true,
+        command: true,
+        ..egui::Modifiers::default()
+    };
+    let key_event = |key| egui::Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: ctrl,
+    };
+    let selected = IndexSet::from([1u64, 2]);
+
+    let events =
+        collect_with_key_event_and_modifiers(key_event(egui::Key::Plus), ctrl, selected.clone());
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, AppIntent::GrowSelectionRequested)));
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, AppIntent::ZoomInRequested)));
+
+    let events =
+        collect_with_key_event_and_modifiers(key_event(egui::Key::Minus), ctrl, selected.clone());
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, AppIntent::ShrinkSelectionRequested)));
+
+    let events =
+        collect_with_key_event_and_modifiers(key_event(egui::Key::Plus), ctrl, IndexSet::new());
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, AppIntent::GrowSelectionRequested)));
+}
+
+#[test]
+fn test_ctrl_plus_does_not_rescale_ui_after_disabling_builtin_zoom() {
+    let ctrl = egui::Modifiers {
+        ctrl: true,
+        command: true,
+        ..egui::Modifiers::default()
+    };
+    let run_ctrl_plus = |ctx: &egui::Context| {
+        let mut raw_input = egui::RawInput {
+            modifiers: ctrl,
+            ..Default::default()
+        };
+        raw_input.events.push(egui::Event::Key {
+            key: egui::Key::Plus,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: ctrl,
+        });
+        let _ = ctx.run_ui(raw_input, |_| {});
+        // egui uebernimmt einen geaenderten Zoom erst im naechsten Frame
+        let _ = ctx.run_ui(egui::RawInput::default(), |_| {});
+        ctx.zoom_factor()
+    };
+
+    // Gegenprobe: egui zoomt standardmaessig mit Ctrl+Plus
+    assert!(run_ctrl_plus(&egui::Context::default()) > 1.0);
+
+    let ctx = egui::Context::default();
+    disable_builtin_zoom_shortcuts(&ctx);
+    assert_eq!(run_ctrl_plus(&ctx), 1.0);
+}
+
+#[test]
+fn test_ctrl_d_duplicates_only_with_selection() {
+    let ctrl = egui::Modifiers {
+        ctrl: true,
+        command: true,
+        ..egui::Modifiers::default()
+    };
+    let key_event = egui::Event::Key {
+        key: egui::Key::D,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: ctrl,
+    };
+
+    let events =
+        collect_with_key_event_and_modifiers(key_event.clone(), ctrl, IndexSet::from([7u64]));
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, AppIntent::DuplicateSelectionRequested)));
+
+    let events = collect_with_key_event_and_modifiers(key_event, ctrl, IndexSet::new());
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, AppIntent::DuplicateSelectionRequested)));
+}
+
+#[test]
+fn test_copy_event_copies_selection() {
+    let events = collect_with_key_event(egui::Event::Copy, IndexSet::from([3u64]));
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, AppIntent::CopySelectionRequested)));
+
+    let events = collect_with_key_event(egui::Event::Copy, IndexSet::new());
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_paste_event_forwards_system_clipboard_text() {
+    let events = collect_with_key_event(
+        egui::Event::Paste("{\"format\":\"fs25_ad_editor/clipboard\"}".to_string()),
+        IndexSet::new(),
+    );
+    assert!(matches!(
+        events.as_slice(),
+        [AppIntent::PasteFromTextRequested { text }] if text.contains("fs25_ad_editor/clipboard")
+    ));
+}
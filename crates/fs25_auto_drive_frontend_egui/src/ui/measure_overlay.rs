@@ -0,0 +1,74 @@
+//! Mess-Overlay: Messlinie des Lineal-Werkzeugs mit Segmentlaengen und Kursen.
+
+use eframe::egui;
+use glam::Vec2;
+
+use crate::app::ui_contract::MeasureOverlaySnapshot;
+use crate::app::Camera2D;
+
+/// Farbe der Messlinie und ihrer Punkte.
+const MEASURE_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 230, 0);
+/// Hintergrund der Beschriftungen.
+const LABEL_BACKGROUND: egui::Color32 = egui::Color32::from_rgba_premultiplied(0, 0, 0, 170);
+/// Schriftgroesse der Beschriftungen in Pixeln.
+const LABEL_FONT_SIZE_PX: f32 = 12.0;
+
+/// Zeichnet die Messlinie mit Laenge und Kurs je Segment (Segmentmitte) sowie
+/// der aufsummierten Laenge an jedem Segmentende.
+pub fn render_measure_overlay(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlay: &MeasureOverlaySnapshot,
+) {
+    let to_screen = |world: Vec2| {
+        let screen = camera.world_to_screen(world, viewport_size);
+        egui::pos2(rect.min.x + screen.x, rect.min.y + screen.y)
+    };
+    let points: Vec<egui::Pos2> = overlay.points.iter().map(|&p| to_screen(p)).collect();
+
+    for pair in points.windows(2) {
+        painter.line_segment([pair[0], pair[1]], egui::Stroke::new(2.0, MEASURE_COLOR));
+    }
+    for &point in &points {
+        painter.circle(
+            point,
+            4.0,
+            MEASURE_COLOR,
+            egui::Stroke::new(1.0, egui::Color32::BLACK),
+        );
+    }
+
+    for (index, segment) in overlay.segments.iter().enumerate() {
+        let (Some(&start), Some(&end)) = (points.get(index), points.get(index + 1)) else {
+            continue;
+        };
+        paint_label(
+            painter,
+            start + (end - start) * 0.5,
+            egui::Align2::CENTER_BOTTOM,
+            &format!("{:.1} m · {:03.0}°", segment.length_m, segment.heading_deg),
+        );
+        if overlay.segments.len() > 1 {
+            paint_label(
+                painter,
+                end + egui::vec2(8.0, 8.0),
+                egui::Align2::LEFT_TOP,
+                &format!("Σ {:.1} m", segment.cumulative_m),
+            );
+        }
+    }
+}
+
+/// Zeichnet einen Text mit halbtransparentem Hintergrund.
+fn paint_label(painter: &egui::Painter, pos: egui::Pos2, anchor: egui::Align2, text: &str) {
+    let galley = painter.layout_no_wrap(
+        text.to_owned(),
+        egui::FontId::proportional(LABEL_FONT_SIZE_PX),
+        MEASURE_COLOR,
+    );
+    let text_rect = anchor.anchor_size(pos, galley.size());
+    painter.rect_filled(text_rect.expand(2.0), 3.0, LABEL_BACKGROUND);
+    painter.galley(text_rect.min, galley, MEASURE_COLOR);
+}
@@ -8,7 +8,7 @@ use crate::ui::common::{
     host_background_layer_label_key, host_background_layer_to_engine,
     host_route_tool_disabled_reason_key, host_route_tool_entries_for, host_route_tool_to_engine,
 };
-use fs25_auto_drive_engine::shared::RenderQuality;
+use fs25_auto_drive_engine::shared::{BackgroundSource, RenderQuality};
 use fs25_auto_drive_host_bridge::{HostChromeSnapshot, HostRouteToolGroup, HostRouteToolSurface};
 
 fn push_route_tool_selection(events: &mut Vec<AppIntent>, tool_id: RouteToolId) {
@@ -64,19 +64,24 @@ fn render_route_tool_group_menu(
     });
 }
 
-/// Rendert die Menue-Leiste
+/// Rendert die Menue-Leiste.
+///
+/// `show_profiling_overlay` ist ein rein host-lokaler Debug-Schalter und wird
+/// direkt umgeschaltet statt ueber einen `AppIntent` zu laufen.
 pub fn render_menu(
     ctx: &egui::Context,
     host_chrome_snapshot: &HostChromeSnapshot,
+    show_profiling_overlay: &mut bool,
 ) -> Vec<AppIntent> {
     let mut top_ui = crate::ui::common::create_top_level_ui(ctx, "menu_bar_top_level");
-    render_menu_inside(&mut top_ui, host_chrome_snapshot)
+    render_menu_inside(&mut top_ui, host_chrome_snapshot, show_profiling_overlay)
 }
 
 /// Rendert die Menue-Leiste innerhalb eines bestehenden Top-Level-UIs.
 pub(crate) fn render_menu_inside(
     ui_root: &mut egui::Ui,
     host_chrome_snapshot: &HostChromeSnapshot,
+    show_profiling_overlay: &mut bool,
 ) -> Vec<AppIntent> {
     let mut events = Vec::new();
     let lang = host_chrome_snapshot.options.language;
@@ -89,6 +94,15 @@ pub(crate) fn render_menu_inside(
                     ui.close();
                 }
 
+                if ui
+                    .button(t(lang, I18nKey::MenuOpenSavegame))
+                    .on_hover_text(t(lang, I18nKey::MenuOpenSavegameHelp))
+                    .clicked()
+                {
+                    events.push(AppIntent::SavegamePickerRequested);
+                    ui.close();
+                }
+
                 ui.separator();
 
                 let has_file = host_chrome_snapshot.has_map;
@@ -109,6 +123,40 @@ pub(crate) fn render_menu_inside(
                     ui.close();
                 }
 
+                ui.menu_button(t(lang, I18nKey::MenuExportModConfig), |ui| {
+                    if ui
+                        .add_enabled(
+                            has_file,
+                            egui::Button::new(t(lang, I18nKey::MenuExportIntoMapZip)),
+                        )
+                        .on_hover_text(t(lang, I18nKey::MenuExportIntoMapZipHelp))
+                        .clicked()
+                    {
+                        events.push(AppIntent::MapModZipExportRequested);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(
+                            has_file,
+                            egui::Button::new(t(lang, I18nKey::MenuExportCoursesMod)),
+                        )
+                        .on_hover_text(t(lang, I18nKey::MenuExportCoursesModHelp))
+                        .clicked()
+                    {
+                        events.push(AppIntent::CoursesModExportRequested);
+                        ui.close();
+                    }
+                });
+
+                if ui
+                    .add_enabled(has_file, egui::Button::new(t(lang, I18nKey::MenuExportPdf)))
+                    .on_hover_text(t(lang, I18nKey::MenuExportPdfHelp))
+                    .clicked()
+                {
+                    events.push(AppIntent::OpenPdfExportDialogRequested);
+                    ui.close();
+                }
+
                 ui.separator();
 
                 // Heightmap-Option
@@ -191,6 +239,50 @@ pub(crate) fn render_menu_inside(
                     ui.close();
                 }
 
+                if ui
+                    .add_enabled(
+                        has_selection,
+                        egui::Button::new(t(lang, I18nKey::MenuDuplicate)),
+                    )
+                    .clicked()
+                {
+                    events.push(AppIntent::DuplicateSelectionRequested);
+                    ui.close();
+                }
+
+                if ui
+                    .add_enabled(
+                        has_selection,
+                        egui::Button::new(t(lang, I18nKey::MenuMoveSelection)),
+                    )
+                    .clicked()
+                {
+                    events.push(AppIntent::OpenMoveSelectionDialogRequested);
+                    ui.close();
+                }
+
+                if ui
+                    .add_enabled(
+                        has_selection,
+                        egui::Button::new(t(lang, I18nKey::MenuDeleteByFilter)),
+                    )
+                    .clicked()
+                {
+                    events.push(AppIntent::OpenDeleteByFilterDialogRequested);
+                    ui.close();
+                }
+
+                if ui
+                    .add_enabled(
+                        has_selection,
+                        egui::Button::new(t(lang, I18nKey::MenuProjectOntoTerrain)),
+                    )
+                    .clicked()
+                {
+                    events.push(AppIntent::ProjectSelectionOntoTerrainRequested { offset: 0.0 });
+                    ui.close();
+                }
+
                 ui.separator();
 
                 if ui.button(t(lang, I18nKey::MenuOptions)).clicked() {
@@ -236,6 +328,21 @@ pub(crate) fn render_menu_inside(
                     ui.close();
                 }
 
+                let mut isolation_active = host_chrome_snapshot.isolation_active;
+                if ui
+                    .add_enabled(
+                        isolation_active || host_chrome_snapshot.has_selection,
+                        egui::Checkbox::new(
+                            &mut isolation_active,
+                            t(lang, I18nKey::MenuIsolateSelection),
+                        ),
+                    )
+                    .changed()
+                {
+                    events.push(AppIntent::ToggleIsolateSelectionRequested);
+                    ui.close();
+                }
+
                 ui.separator();
 
                 // Background-Map-Option
@@ -272,6 +379,39 @@ pub(crate) fn render_menu_inside(
                     });
                 }
 
+                ui.menu_button(t(lang, I18nKey::MenuBackgroundSource), |ui| {
+                    let current = host_chrome_snapshot.background_source;
+                    let has_heightmap = host_chrome_snapshot.heightmap_path.is_some();
+                    for (source, key, enabled) in [
+                        (
+                            BackgroundSource::Overview,
+                            I18nKey::MenuBgSourceOverview,
+                            true,
+                        ),
+                        (
+                            BackgroundSource::HeightmapHillshade,
+                            I18nKey::MenuBgSourceHeightmapHillshade,
+                            has_heightmap,
+                        ),
+                        (
+                            BackgroundSource::HeightmapGradient,
+                            I18nKey::MenuBgSourceHeightmapGradient,
+                            has_heightmap,
+                        ),
+                    ] {
+                        if ui
+                            .add_enabled(
+                                enabled,
+                                egui::Button::selectable(current == source, t(lang, key)),
+                            )
+                            .clicked()
+                        {
+                            events.push(AppIntent::BackgroundSourceSelected { source });
+                            ui.close();
+                        }
+                    }
+                });
+
                 ui.separator();
 
                 ui.menu_button(t(lang, I18nKey::MenuRenderQuality), |ui| {
@@ -316,6 +456,15 @@ pub(crate) fn render_menu_inside(
                         ui.close();
                     }
                 });
+
+                ui.separator();
+
+                ui.menu_button(t(lang, I18nKey::MenuDebug), |ui| {
+                    ui.checkbox(
+                        show_profiling_overlay,
+                        t(lang, I18nKey::MenuProfilingOverlay),
+                    );
+                });
             });
 
             ui.menu_button(t(lang, I18nKey::MenuExtras), |ui| {
@@ -333,8 +482,67 @@ pub(crate) fn render_menu_inside(
                     ui.close();
                 }
 
+                if ui
+                    .add_enabled(
+                        has_farmland && host_chrome_snapshot.has_map,
+                        egui::Button::new(t(lang, I18nKey::MenuFieldCourse)),
+                    )
+                    .on_disabled_hover_text(t(lang, I18nKey::RouteToolNeedFarmland))
+                    .on_hover_text(t(lang, I18nKey::MenuFieldCourseHelp))
+                    .clicked()
+                {
+                    events.push(AppIntent::OpenFieldCourseDialogRequested);
+                    ui.close();
+                }
+
                 ui.separator();
 
+                let live_connected = host_chrome_snapshot.live_link_connected;
+                let record_label = if host_chrome_snapshot.live_recording {
+                    I18nKey::MenuLiveRecordStop
+                } else {
+                    I18nKey::MenuLiveRecordStart
+                };
+                if ui
+                    .add_enabled(
+                        live_connected && host_chrome_snapshot.has_map,
+                        egui::Button::new(t(lang, record_label)),
+                    )
+                    .on_disabled_hover_text(t(lang, I18nKey::MenuLiveNotConnected))
+                    .on_hover_text(t(lang, I18nKey::MenuLiveRecordHelp))
+                    .clicked()
+                {
+                    events.push(AppIntent::LiveCourseRecordingToggled);
+                    ui.close();
+                }
+
+                if ui
+                    .add_enabled(
+                        live_connected && host_chrome_snapshot.has_map,
+                        egui::Button::new(t(lang, I18nKey::MenuLivePush)),
+                    )
+                    .on_disabled_hover_text(t(lang, I18nKey::MenuLiveNotConnected))
+                    .on_hover_text(t(lang, I18nKey::MenuLivePushHelp))
+                    .clicked()
+                {
+                    events.push(AppIntent::LiveNetworkPushRequested);
+                    ui.close();
+                }
+
+                ui.separator();
+
+                if ui
+                    .add_enabled(
+                        host_chrome_snapshot.has_map,
+                        egui::Button::new(t(lang, I18nKey::MenuAutoDriveRoutes)),
+                    )
+                    .on_hover_text(t(lang, I18nKey::MenuAutoDriveRoutesHelp))
+                    .clicked()
+                {
+                    events.push(AppIntent::RoutesDialogRequested);
+                    ui.close();
+                }
+
                 let has_file = host_chrome_snapshot.has_map;
                 if ui
                     .add_enabled(
@@ -4,6 +4,8 @@
 pub mod command_palette;
 /// Gemeinsame UI-Hilfsfunktionen (Wheel-Step, etc.).
 pub mod common;
+/// Verbindungs-Selektions-Overlay: Hervorhebung der selektierten Verbindung.
+pub mod connection_selection_overlay;
 /// Kontext-Menue fuer Rechtsklick-Aktionen im Viewport.
 pub mod context_menu;
 /// Panel fuer Default-Werte neuer Verbindungen (Richtung, Prioritaet).
@@ -13,6 +15,8 @@ pub mod dialogs;
 mod drag;
 /// Editor-Panel fuer die Bearbeitung selektierter Knoten und Verbindungen.
 pub mod edit_panel;
+/// Farmland-Overlay fuer die im Savegame gekauften Farmlands.
+pub mod farmland_overlay;
 /// Schwebendes Kontextmenue fuer Werkzeuggruppen an der Mausposition.
 pub mod floating_menu;
 /// Gruppen-Boundary-Overlay: Ein-/Ausfahrt-Icons fuer Boundary-Nodes einer Gruppe.
@@ -28,34 +32,54 @@ mod keyboard;
 pub mod long_press;
 /// Rechte Sidebar fuer Map-Marker (Kamera-Zentrierung bei Klick).
 pub mod marker_panel;
+/// Mess-Overlay: Messlinie des Lineal-Werkzeugs mit Laengen und Kursen.
+pub mod measure_overlay;
 /// Menue-Leiste mit Datei-, Bearbeitungs- und Ansicht-Aktionen.
 pub mod menu;
+/// Node-Lock-Overlay: Schloss-Badges fuer gesperrte Nodes.
+pub mod node_lock_overlay;
 /// Optionen-Dialog fuer Editor-Einstellungen.
 pub mod options_dialog;
+/// Performance-HUD fuer Frame-, Szenen- und Renderer-Kennzahlen.
+pub mod profiling_overlay;
 /// Properties-Panel fuer selektierte Nodes und Verbindungen.
 pub mod properties;
 /// Statusleiste mit Anzeige des aktuellen Editor-Zustands.
 pub mod status;
+/// Terrain-Warn-Overlay fuer Verbindungen mit zu grosser Terrain-Abweichung.
+pub mod terrain_clipping_overlay;
 /// Live-Vorschau aktiver Werkzeuge im Viewport (Overlay-Rendering).
 pub mod tool_preview;
+/// Fahrzeug-Overlay fuer die im Savegame abgestellten Fahrzeuge.
+pub mod vehicle_overlay;
+pub use connection_selection_overlay::render_selected_connection_overlay;
 pub use defaults_panel::render_route_defaults_panel;
 pub use dialogs::{
     handle_file_dialogs, show_confirm_dissolve_dialog, show_dedup_dialog,
-    show_group_settings_popup, show_heightmap_warning, show_marker_dialog,
-    show_overview_options_dialog, show_post_load_dialog, show_save_overview_dialog,
+    show_delete_by_filter_dialog, show_field_course_dialog, show_group_settings_popup,
+    show_heightmap_warning, show_marker_dialog, show_move_selection_dialog,
+    show_overview_options_dialog, show_pdf_export_dialog, show_post_load_dialog,
+    show_routes_dialog, show_save_overview_dialog, show_savegame_picker,
     show_trace_all_fields_dialog, show_zip_browser,
 };
 pub use edit_panel::render_edit_panel;
+pub use farmland_overlay::render_owned_farmland_overlays;
 pub use floating_menu::render_floating_menu;
 pub use group_boundary_overlay::{render_group_boundary_overlays, GroupBoundaryIcons};
 pub use group_overlay::{render_group_overlays, GroupOverlayEvent};
 pub use input::InputState;
+pub use keyboard::disable_builtin_zoom_shortcuts;
 pub use marker_panel::render_marker_content;
+pub use measure_overlay::render_measure_overlay;
 pub use menu::render_menu;
+pub use node_lock_overlay::render_locked_node_overlays;
 pub use options_dialog::show_options_dialog;
+pub use profiling_overlay::{render_profiling_overlay, ProfilingHudData, ProfilingRendererRow};
 pub use properties::{render_properties_content, PropertiesContext};
 pub use status::render_status_bar;
+pub use terrain_clipping_overlay::render_terrain_clipping_overlays;
 pub use tool_preview::{
     paint_clipboard_preview, paint_clipboard_snapshot_preview, paint_preview,
     paint_preview_polyline, render_tool_preview,
 };
+pub use vehicle_overlay::{render_live_player_overlay, render_vehicle_overlays};
@@ -0,0 +1,45 @@
+//! Node-Lock-Overlay: Kleines Schloss-Badge neben jedem gesperrten Node.
+
+use eframe::egui;
+use glam::Vec2;
+
+use crate::app::ui_contract::LockedNodeOverlaySnapshot;
+use crate::app::Camera2D;
+
+/// Schriftgroesse des Schloss-Symbols in Pixeln.
+const BADGE_SIZE_PX: f32 = 10.0;
+/// Versatz des Badges zum Node-Mittelpunkt (rechts oben) in Pixeln.
+const BADGE_OFFSET_PX: egui::Vec2 = egui::vec2(8.0, -8.0);
+
+/// Zeichnet ein Schloss-Badge rechts oberhalb jedes gesperrten Nodes.
+pub fn render_locked_node_overlays(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlays: &[LockedNodeOverlaySnapshot],
+) {
+    let bg_size = egui::vec2(BADGE_SIZE_PX + 4.0, BADGE_SIZE_PX + 4.0);
+
+    for overlay in overlays {
+        let screen_local = camera.world_to_screen(overlay.world_pos, viewport_size);
+        let badge_pos =
+            egui::pos2(rect.min.x + screen_local.x, rect.min.y + screen_local.y) + BADGE_OFFSET_PX;
+        if !rect.expand(BADGE_SIZE_PX).contains(badge_pos) {
+            continue;
+        }
+
+        painter.rect_filled(
+            egui::Rect::from_center_size(badge_pos, bg_size),
+            3.0,
+            egui::Color32::from_rgba_unmultiplied(30, 30, 30, 200),
+        );
+        painter.text(
+            badge_pos,
+            egui::Align2::CENTER_CENTER,
+            "\u{1F512}",
+            egui::FontId::proportional(BADGE_SIZE_PX),
+            egui::Color32::WHITE,
+        );
+    }
+}
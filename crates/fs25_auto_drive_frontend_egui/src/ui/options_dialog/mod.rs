@@ -108,6 +108,9 @@ fn render_selected_section(
                 render_subsection(ui, t(lang, I18nKey::OptSubSectionCopyPaste), None, |ui| {
                     sections::render_copy_paste(ui, opts, lang)
                 });
+            changed |= render_subsection(ui, t(lang, I18nKey::OptSubSectionLiveLink), None, |ui| {
+                sections::render_live_link(ui, opts, lang)
+            });
         }
         OptionsSection::Nodes => {
             changed |= sections::render_nodes(ui, opts, lang);
@@ -0,0 +1,32 @@
+use crate::shared::{t, EditorOptions, I18nKey, Language};
+use crate::ui::common::apply_wheel_step_default;
+
+/// Rendert die Einstellungen der Live-Verbindung (Aktivierung, Port, Aufzeichnungsabstand).
+pub fn render_live_link(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: Language) -> bool {
+    let mut changed = false;
+    changed |= ui
+        .checkbox(
+            &mut opts.live_link_enabled,
+            t(lang, I18nKey::OptLiveLinkEnabled),
+        )
+        .on_hover_text(t(lang, I18nKey::OptLiveLinkEnabledHelp))
+        .changed();
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptLiveLinkPort));
+        let r = ui.add(egui::DragValue::new(&mut opts.live_link_port).range(1024..=65535));
+        changed |= r.changed();
+        r.on_hover_text(t(lang, I18nKey::OptLiveLinkPortHelp));
+    });
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptLiveRecordSpacing));
+        let r = ui.add(
+            egui::DragValue::new(&mut opts.live_record_spacing_m)
+                .range(1.0..=50.0)
+                .speed(0.1),
+        );
+        changed |= r.changed()
+            | apply_wheel_step_default(ui, &r, &mut opts.live_record_spacing_m, 1.0..=50.0);
+        r.on_hover_text(t(lang, I18nKey::OptLiveRecordSpacingHelp));
+    });
+    changed
+}
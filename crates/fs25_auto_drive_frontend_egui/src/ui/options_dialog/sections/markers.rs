@@ -34,5 +34,19 @@ pub fn render_markers(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: Languag
             | apply_wheel_step_default(ui, &r, &mut opts.marker_outline_width, 0.01..=0.3);
         r.on_hover_text(t(lang, I18nKey::OptMarkerOutlineWidthHelp));
     });
+    changed |= ui
+        .checkbox(
+            &mut opts.show_savegame_vehicles,
+            t(lang, I18nKey::OptShowSavegameVehicles),
+        )
+        .on_hover_text(t(lang, I18nKey::OptShowSavegameVehiclesHelp))
+        .changed();
+    changed |= ui
+        .checkbox(
+            &mut opts.show_owned_farmlands,
+            t(lang, I18nKey::OptShowOwnedFarmlands),
+        )
+        .on_hover_text(t(lang, I18nKey::OptShowOwnedFarmlandsHelp))
+        .changed();
     changed
 }
@@ -7,6 +7,7 @@ mod background;
 mod camera;
 mod connections;
 mod copy_paste;
+mod live_link;
 mod lod;
 mod markers;
 mod node_behavior;
@@ -20,6 +21,7 @@ pub(super) use background::render_background;
 pub(super) use camera::render_camera;
 pub(super) use connections::render_connections;
 pub(super) use copy_paste::render_copy_paste;
+pub(super) use live_link::render_live_link;
 pub(super) use lod::render_lod;
 pub(super) use markers::render_markers;
 pub(super) use node_behavior::render_node_behavior;
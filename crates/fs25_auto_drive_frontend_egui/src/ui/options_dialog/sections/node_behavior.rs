@@ -1,6 +1,6 @@
 use crate::shared::{t, EditorOptions, I18nKey, Language};
 
-/// Rendert die Node-Verhalten-Einstellungen (Reconnect beim Loeschen, Verbindung teilen).
+/// Rendert die Node-Verhalten-Einstellungen (Reconnect beim Loeschen, Verbindung teilen, Messer, Einfuegen beim Ziehen).
 pub fn render_node_behavior(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: Language) -> bool {
     let mut changed = false;
     if ui
@@ -23,5 +23,25 @@ pub fn render_node_behavior(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: L
     {
         changed = true;
     }
+    if ui
+        .checkbox(
+            &mut opts.knife_split_connections,
+            t(lang, I18nKey::OptKnifeSplitConnections),
+        )
+        .on_hover_text(t(lang, I18nKey::OptKnifeSplitConnectionsHelp))
+        .changed()
+    {
+        changed = true;
+    }
+    if ui
+        .checkbox(
+            &mut opts.snap_moved_node_to_connection,
+            t(lang, I18nKey::OptSnapMovedNodeToConnection),
+        )
+        .on_hover_text(t(lang, I18nKey::OptSnapMovedNodeToConnectionHelp))
+        .changed()
+    {
+        changed = true;
+    }
     changed
 }
@@ -70,5 +70,20 @@ pub fn render_selection(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: Langu
             ui.weak(t(lang, I18nKey::OptSegmentDisabled));
         }
     });
+    ui.separator();
+    changed |= ui
+        .checkbox(
+            &mut opts.flood_select_follow_direction,
+            t(lang, I18nKey::OptFloodSelectFollowDirection),
+        )
+        .on_hover_text(t(lang, I18nKey::OptFloodSelectFollowDirectionHelp))
+        .changed();
+    changed |= ui
+        .checkbox(
+            &mut opts.invert_selection_visible_only,
+            t(lang, I18nKey::OptInvertSelectionVisibleOnly),
+        )
+        .on_hover_text(t(lang, I18nKey::OptInvertSelectionVisibleOnlyHelp))
+        .changed();
     changed
 }
@@ -1,7 +1,8 @@
 use crate::shared::{t, EditorOptions, I18nKey, Language, ValueAdjustInputMode};
 use crate::ui::common::{apply_wheel_step, apply_wheel_step_default};
 
-/// Rendert die Werkzeug-Einstellungen (Eingabemodus, Snap-Radius, Mausrad-Schritt).
+/// Rendert die Werkzeug-Einstellungen (Eingabemodus, Snap-Radius, Mausrad-Schritt,
+/// Steigungsgrenze, Raster-/Winkel-Snap, Terrain-Warnung).
 pub fn render_tools(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: Language) -> bool {
     let mut changed = false;
     ui.horizontal(|ui| {
@@ -61,5 +62,71 @@ pub fn render_tools(ui: &mut egui::Ui, opts: &mut EditorOptions, lang: Language)
             | apply_wheel_step_default(ui, &r, &mut opts.mouse_wheel_distance_step_m, 0.01..=5.0);
         r.on_hover_text(t(lang, I18nKey::OptMouseWheelDistStepHelp));
     });
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptMaxRouteGrade));
+        let r = ui.add(
+            egui::DragValue::new(&mut opts.max_route_grade_percent)
+                .range(1.0..=100.0)
+                .speed(0.5)
+                .suffix(" %"),
+        );
+        changed |= r.changed()
+            | apply_wheel_step(ui, &r, &mut opts.max_route_grade_percent, 1.0, 1.0..=100.0);
+        r.on_hover_text(t(lang, I18nKey::OptMaxRouteGradeHelp));
+    });
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptRoundaboutRadius));
+        let r = ui.add(
+            egui::DragValue::new(&mut opts.roundabout_radius_m)
+                .range(3.0..=100.0)
+                .speed(0.5)
+                .suffix(" m"),
+        );
+        changed |=
+            r.changed() | apply_wheel_step(ui, &r, &mut opts.roundabout_radius_m, 0.5, 3.0..=100.0);
+        r.on_hover_text(t(lang, I18nKey::OptRoundaboutRadiusHelp));
+    });
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptGridSnapSize));
+        let r = ui.add(
+            egui::DragValue::new(&mut opts.grid_snap_size)
+                .range(0.1..=100.0)
+                .speed(0.1)
+                .suffix(" m"),
+        );
+        changed |=
+            r.changed() | apply_wheel_step(ui, &r, &mut opts.grid_snap_size, 0.5, 0.1..=100.0);
+        r.on_hover_text(t(lang, I18nKey::OptGridSnapSizeHelp));
+    });
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptAngleSnap));
+        let r = ui.add(
+            egui::DragValue::new(&mut opts.angle_snap_degrees)
+                .range(1.0..=90.0)
+                .speed(1.0)
+                .suffix("°"),
+        );
+        changed |=
+            r.changed() | apply_wheel_step(ui, &r, &mut opts.angle_snap_degrees, 15.0, 1.0..=90.0);
+        r.on_hover_text(t(lang, I18nKey::OptAngleSnapHelp));
+    });
+    ui.horizontal(|ui| {
+        ui.label(t(lang, I18nKey::OptTerrainClippingTolerance));
+        let r = ui.add(
+            egui::DragValue::new(&mut opts.terrain_clipping_tolerance_m)
+                .range(0.0..=20.0)
+                .speed(0.1)
+                .suffix(" m"),
+        );
+        changed |= r.changed()
+            | apply_wheel_step(
+                ui,
+                &r,
+                &mut opts.terrain_clipping_tolerance_m,
+                0.1,
+                0.0..=20.0,
+            );
+        r.on_hover_text(t(lang, I18nKey::OptTerrainClippingToleranceHelp));
+    });
     changed
 }
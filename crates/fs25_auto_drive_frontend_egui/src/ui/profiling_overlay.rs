@@ -0,0 +1,94 @@
+//! Performance-HUD mit Frame-, Szenen- und Renderer-Kennzahlen.
+
+use crate::shared::{t, I18nKey, Language};
+
+/// Upload-/Draw-Zaehlwerte eines Sub-Renderers fuer die HUD-Anzeige.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfilingRendererRow {
+    /// Anzeigename des Sub-Renderers.
+    pub label: I18nKey,
+    /// In diesem Frame hochgeladene Instanzen bzw. Vertices.
+    pub uploaded: u32,
+    /// Gezeichnete Instanzen bzw. Vertices.
+    pub drawn: u32,
+}
+
+/// Kennzahlen, die das Performance-HUD pro Frame anzeigt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfilingHudData {
+    /// Geglaettete Frame-Zeit in Millisekunden.
+    pub frame_time_ms: f32,
+    /// Geglaettete Dauer des Render-Szenen-Aufbaus in Millisekunden.
+    pub scene_build_ms: f32,
+    /// Zaehlwerte pro Sub-Renderer (Marker, Connections, Nodes).
+    pub renderers: Vec<ProfilingRendererRow>,
+    /// Anzahl gezeichneter Background-Kacheln.
+    pub background_tiles: u32,
+    /// Spatial-Index-Abfragen der Engine im letzten Frame.
+    pub engine_spatial_queries: u64,
+    /// Spatial-Abfragen der Sub-Renderer im letzten Frame.
+    pub render_spatial_queries: u32,
+}
+
+/// Zeichnet das Performance-HUD in die linke obere Ecke des Viewports.
+pub fn render_profiling_overlay(
+    ctx: &egui::Context,
+    viewport_rect: egui::Rect,
+    data: &ProfilingHudData,
+    lang: Language,
+) {
+    egui::Area::new(egui::Id::new("profiling_overlay"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(viewport_rect.min + egui::vec2(8.0, 8.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                egui::Grid::new("profiling_overlay_grid")
+                    .num_columns(3)
+                    .spacing([12.0, 2.0])
+                    .show(ui, |ui| {
+                        ui.label(t(lang, I18nKey::ProfilingFrameTime));
+                        ui.monospace(format!("{:.2} ms", data.frame_time_ms));
+                        ui.monospace(format!("{:.0} FPS", fps(data.frame_time_ms)));
+                        ui.end_row();
+
+                        ui.label(t(lang, I18nKey::ProfilingSceneBuild));
+                        ui.monospace(format!("{:.2} ms", data.scene_build_ms));
+                        ui.end_row();
+
+                        ui.separator();
+                        ui.strong(t(lang, I18nKey::ProfilingUploaded));
+                        ui.strong(t(lang, I18nKey::ProfilingDrawn));
+                        ui.end_row();
+
+                        for row in &data.renderers {
+                            ui.label(t(lang, row.label));
+                            ui.monospace(row.uploaded.to_string());
+                            ui.monospace(row.drawn.to_string());
+                            ui.end_row();
+                        }
+
+                        ui.label(t(lang, I18nKey::ProfilingBackgroundTiles));
+                        ui.label("");
+                        ui.monospace(data.background_tiles.to_string());
+                        ui.end_row();
+
+                        ui.label(t(lang, I18nKey::ProfilingSpatialQueries));
+                        ui.monospace(format!(
+                            "{} / {}",
+                            data.engine_spatial_queries, data.render_spatial_queries
+                        ));
+                        ui.end_row();
+                    });
+            });
+        });
+}
+
+/// Rechnet eine Frame-Zeit in Bilder pro Sekunde um.
+fn fps(frame_time_ms: f32) -> f32 {
+    if frame_time_ms > f32::EPSILON {
+        1000.0 / frame_time_ms
+    } else {
+        0.0
+    }
+}
@@ -1,7 +1,13 @@
 //! Properties-Panel (rechte Seitenleiste) für Node- und Connection-Eigenschaften.
 
+mod connection;
 mod distances;
+mod heights;
+mod nudge;
+mod path_offset;
 pub(crate) mod selectors;
+mod simplify;
+mod smoothing;
 
 use fs25_auto_drive_host_bridge::{
     HostConnectionPairEntry, HostConnectionPairSnapshot, HostDefaultConnectionDirection,
@@ -13,21 +19,29 @@ use crate::app::{
     group_registry::GroupRegistry, tools::route_tool_descriptor, AppIntent, ConnectionDirection,
     ConnectionPriority, NodeFlag, RoadMap, ToolEditStore,
 };
+use connection::render_selected_connection_panel;
 use distances::render_distance_panel;
+use heights::render_height_panel;
+use nudge::render_nudge_panel;
+use path_offset::render_path_offset_panel;
 use selectors::{render_direction_icon_selector, render_priority_icon_selector};
+use simplify::render_simplify_panel;
+use smoothing::render_smoothing_panel;
 
 /// Kontext fuer das Properties-Panel-Rendering.
 ///
 /// Fasst alle Parameter von `render_properties_content()` zusammen,
 /// um die Signatur kompakt zu halten.
 pub struct PropertiesContext<'a> {
-    /// Aktuelle RoadMap (nur fuer das Distanz-Panel benoetigt).
+    /// Aktuelle RoadMap (fuer Distanz-Panel und Vereinfachungs-Vorschau benoetigt).
     pub road_map: Option<&'a RoadMap>,
     /// Aktuell selektierte Node-IDs.
     pub selected_node_ids: &'a IndexSet<u64>,
     /// Details des ersten selektierten Nodes (Einzelselektion).
     pub node_details: Option<&'a HostNodeDetails>,
-    /// Verbindungs-Snapshot fuer Zwei-Node-Selektion.
+    /// Per Kanten-Klick selektierte Verbindung `(start_id, end_id)`.
+    pub selected_connection: Option<(u64, u64)>,
+    /// Verbindungs-Snapshot fuer Zwei-Node- oder Verbindungs-Selektion.
     pub connection_pair: Option<&'a HostConnectionPairSnapshot>,
     /// Standard-Verbindungsrichtung.
     pub default_direction: ConnectionDirection,
@@ -41,6 +55,8 @@ pub struct PropertiesContext<'a> {
     pub tool_edit_store: Option<&'a ToolEditStore>,
     /// Zustand des Distanzen-Panels (mutable).
     pub distance_state: &'a mut crate::app::state::DistanzenState,
+    /// Zustand des seitlichen Strecken-Versatzes (read-only).
+    pub path_offset: &'a crate::app::state::PathOffsetState,
 }
 
 struct SelectionInfoContext<'a> {
@@ -55,12 +71,13 @@ struct SelectionInfoContext<'a> {
 ///
 /// Die Detaildaten fuer Einzel- und Zwei-Node-Selektion werden ueber
 /// vorab geladene Bridge-DTOs gelesen; `road_map` bleibt hier nur fuer das
-/// Distanz-Panel erhalten.
+/// Distanz-Panel und die Vereinfachungs-Vorschau erhalten.
 pub fn render_properties_content(ui: &mut egui::Ui, ctx: PropertiesContext<'_>) -> Vec<AppIntent> {
     let PropertiesContext {
         road_map,
         selected_node_ids,
         node_details,
+        selected_connection,
         connection_pair,
         default_direction,
         default_priority,
@@ -68,11 +85,16 @@ pub fn render_properties_content(ui: &mut egui::Ui, ctx: PropertiesContext<'_>)
         group_registry,
         tool_edit_store,
         distance_state,
+        path_offset,
     } = ctx;
 
     let mut events = Vec::new();
 
-    if selected_node_ids.is_empty() {
+    if let (true, Some(connection), Some(rm)) =
+        (selected_node_ids.is_empty(), selected_connection, road_map)
+    {
+        render_selected_connection_panel(ui, rm, connection, connection_pair, &mut events);
+    } else if selected_node_ids.is_empty() {
         ui.label("Keine Selektion");
     } else {
         render_selection_info(
@@ -88,6 +110,8 @@ pub fn render_properties_content(ui: &mut egui::Ui, ctx: PropertiesContext<'_>)
                 events: &mut events,
             },
         );
+        render_height_panel(ui, selected_node_ids.len(), &mut events);
+        render_nudge_panel(ui, selected_node_ids.len(), &mut events);
     }
 
     // Distanzen-Panel: immer sichtbar wenn 2+ Nodes selektiert
@@ -107,6 +131,17 @@ pub fn render_properties_content(ui: &mut egui::Ui, ctx: PropertiesContext<'_>)
         distance_state.deactivate();
     }
 
+    if selected_node_ids.len() >= 3 && !path_offset.active {
+        render_smoothing_panel(ui, &mut events);
+        if let Some(rm) = road_map {
+            render_simplify_panel(ui, rm, selected_node_ids, &mut events);
+        }
+    }
+
+    if selected_node_ids.len() >= 2 || path_offset.active {
+        render_path_offset_panel(ui, path_offset, &mut events);
+    }
+
     events
 }
 
@@ -0,0 +1,79 @@
+use fs25_auto_drive_host_bridge::{
+    HostConnectionPairSnapshot, HostDefaultConnectionDirection, HostDefaultConnectionPriority,
+};
+
+use super::selectors::{render_direction_icon_selector, render_priority_icon_selector};
+use crate::app::{AppIntent, ConnectionDirection, ConnectionPriority, RoadMap};
+
+/// Rendert die Eigenschaften der per Kanten-Klick selektierten Verbindung.
+///
+/// Zeigt Laenge, Richtung und Prioritaet samt Buttons zum Umkehren und
+/// Loeschen. Existiert die Verbindung nicht mehr (z.B. nach Undo), erscheint
+/// stattdessen der Leer-Hinweis.
+pub fn render_selected_connection_panel(
+    ui: &mut egui::Ui,
+    road_map: &RoadMap,
+    (start_id, end_id): (u64, u64),
+    connection_pair: Option<&HostConnectionPairSnapshot>,
+    events: &mut Vec<AppIntent>,
+) {
+    let entry = connection_pair.and_then(|pair| {
+        pair.connections
+            .iter()
+            .find(|conn| conn.start_id == start_id && conn.end_id == end_id)
+    });
+    let (Some(entry), Some(start_pos), Some(end_pos)) = (
+        entry,
+        road_map.node_position(start_id),
+        road_map.node_position(end_id),
+    ) else {
+        ui.label("Keine Selektion");
+        return;
+    };
+
+    ui.label(format!("Verbindung: {}→{}", start_id, end_id));
+    ui.label(format!("Laenge: {:.2} m", start_pos.distance(end_pos)));
+    ui.separator();
+
+    let id_suffix = format!("selected_{}_{}", start_id, end_id);
+    ui.label("Richtung:");
+    let mut selected_dir = ConnectionDirection::from(&entry.direction);
+    render_direction_icon_selector(ui, &mut selected_dir, &id_suffix);
+    if HostDefaultConnectionDirection::from(&selected_dir) != entry.direction {
+        events.push(AppIntent::SetConnectionDirectionRequested {
+            start_id,
+            end_id,
+            direction: selected_dir,
+        });
+    }
+
+    ui.label("Strassenart:");
+    let mut selected_prio = ConnectionPriority::from(&entry.priority);
+    render_priority_icon_selector(ui, &mut selected_prio, &id_suffix);
+    if HostDefaultConnectionPriority::from(&selected_prio) != entry.priority {
+        events.push(AppIntent::SetConnectionPriorityRequested {
+            start_id,
+            end_id,
+            priority: selected_prio,
+        });
+    }
+
+    ui.separator();
+    let has_counterpart = road_map.has_connection(end_id, start_id);
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(!has_counterpart, egui::Button::new("⇄ Umkehren"))
+            .on_hover_text("Start und Ende der Verbindung tauschen")
+            .on_disabled_hover_text("Gegenverbindung existiert bereits")
+            .clicked()
+        {
+            events.push(AppIntent::InvertConnectionRequested { start_id, end_id });
+        }
+        if ui.button("✕ Loeschen").clicked() {
+            events.push(AppIntent::RemoveConnectionBetweenRequested {
+                node_a: start_id,
+                node_b: end_id,
+            });
+        }
+    });
+}
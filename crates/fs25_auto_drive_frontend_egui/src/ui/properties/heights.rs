@@ -0,0 +1,76 @@
+use crate::app::use_cases::editing::NodeHeightEdit;
+use crate::app::AppIntent;
+
+/// Rendert die Y-Wert-Bearbeitung fuer alle selektierten Nodes.
+///
+/// Der Eingabewert wird im egui-Temp-Speicher gehalten; jede Aktion erzeugt
+/// genau einen undo-faehigen Schritt.
+pub fn render_height_panel(ui: &mut egui::Ui, selected_count: usize, events: &mut Vec<AppIntent>) {
+    let value_id = ui.id().with("node_height_value");
+    let mut value = ui.data_mut(|d| d.get_temp::<f32>(value_id).unwrap_or_default());
+
+    ui.separator();
+    ui.label(format!("Y-Wert ({} Node(s))", selected_count));
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::DragValue::new(&mut value)
+                .speed(0.1)
+                .max_decimals(3)
+                .suffix(" m"),
+        );
+        if ui
+            .button("Setzen")
+            .on_hover_text("Alle selektierten Nodes auf diesen Y-Wert setzen")
+            .clicked()
+        {
+            events.push(AppIntent::SetSelectedNodeHeightsRequested {
+                edit: NodeHeightEdit::Absolute(value),
+            });
+        }
+        if ui
+            .button("± Versatz")
+            .on_hover_text("Y-Wert aller selektierten Nodes um diesen Betrag verschieben")
+            .clicked()
+        {
+            events.push(AppIntent::SetSelectedNodeHeightsRequested {
+                edit: NodeHeightEdit::Offset(value),
+            });
+        }
+        if ui
+            .button("Auf Gelaende")
+            .on_hover_text(
+                "Selektierte Nodes auf die Heightmap-Hoehe setzen (Eingabewert als Versatz)",
+            )
+            .clicked()
+        {
+            events.push(AppIntent::ProjectSelectionOntoTerrainRequested { offset: value });
+        }
+    });
+    if selected_count >= 3 {
+        ui.label("Zwischen Endpunkten interpolieren:");
+        ui.horizontal(|ui| {
+            if ui
+                .button("Linear")
+                .on_hover_text("Innere Kettennodes linear zwischen den Endpunkt-Hoehen verteilen")
+                .clicked()
+            {
+                events.push(AppIntent::SetSelectedNodeHeightsRequested {
+                    edit: NodeHeightEdit::InterpolateChain,
+                });
+            }
+            if ui
+                .button("Sanft (Spline)")
+                .on_hover_text(
+                    "Innere Kettennodes als S-Kurve mit flachen Enden verteilen (Rampen, Bruecken)",
+                )
+                .clicked()
+            {
+                events.push(AppIntent::SetSelectedNodeHeightsRequested {
+                    edit: NodeHeightEdit::InterpolateChainSmooth,
+                });
+            }
+        });
+    }
+
+    ui.data_mut(|d| d.insert_temp(value_id, value));
+}
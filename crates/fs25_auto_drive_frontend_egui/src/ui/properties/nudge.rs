@@ -0,0 +1,56 @@
+use crate::app::AppIntent;
+
+/// Rendert die numerische Verschiebung der Selektion in der Kartenebene.
+///
+/// X/Z-Versatz werden im egui-Temp-Speicher gehalten; jeder Klick erzeugt
+/// genau einen undo-faehigen Schritt. Die Pfeil-Buttons verschieben nur um
+/// die jeweilige Achse, "Verschieben" wendet beide Werte gemeinsam an.
+pub fn render_nudge_panel(ui: &mut egui::Ui, selected_count: usize, events: &mut Vec<AppIntent>) {
+    let offset_id = ui.id().with("selection_nudge_offset");
+    let mut offset = ui.data_mut(|d| d.get_temp::<[f32; 2]>(offset_id).unwrap_or([1.0, 1.0]));
+
+    ui.separator();
+    ui.label(format!("Verschieben ({} Node(s))", selected_count));
+    ui.horizontal(|ui| {
+        ui.label("X:");
+        ui.add(
+            egui::DragValue::new(&mut offset[0])
+                .speed(0.1)
+                .max_decimals(3)
+                .suffix(" m"),
+        );
+        ui.label("Z:");
+        ui.add(
+            egui::DragValue::new(&mut offset[1])
+                .speed(0.1)
+                .max_decimals(3)
+                .suffix(" m"),
+        );
+    });
+    ui.horizontal(|ui| {
+        let mut nudge = |delta: glam::Vec2| {
+            events.push(AppIntent::NudgeSelectionRequested { delta });
+        };
+        if ui.small_button("−X").clicked() {
+            nudge(glam::Vec2::new(-offset[0], 0.0));
+        }
+        if ui.small_button("+X").clicked() {
+            nudge(glam::Vec2::new(offset[0], 0.0));
+        }
+        if ui.small_button("−Z").clicked() {
+            nudge(glam::Vec2::new(0.0, -offset[1]));
+        }
+        if ui.small_button("+Z").clicked() {
+            nudge(glam::Vec2::new(0.0, offset[1]));
+        }
+        if ui
+            .button("Verschieben")
+            .on_hover_text("Selektion um X und Z gemeinsam verschieben (Shift+Pfeiltasten: 1 m)")
+            .clicked()
+        {
+            nudge(glam::Vec2::new(offset[0], offset[1]));
+        }
+    });
+
+    ui.data_mut(|d| d.insert_temp(offset_id, offset));
+}
@@ -0,0 +1,50 @@
+use crate::app::state::PathOffsetState;
+use crate::app::use_cases::editing::PATH_OFFSET_MAX_DISTANCE;
+use crate::app::AppIntent;
+
+/// Rendert den seitlichen Versatz fuer die selektierte Kette.
+///
+/// Ohne aktive Vorschau wird nur der Start-Button angezeigt; waehrend der
+/// Vorschau zeigt das Viewport-Overlay die verschobene Kette.
+pub fn render_path_offset_panel(
+    ui: &mut egui::Ui,
+    state: &PathOffsetState,
+    events: &mut Vec<AppIntent>,
+) {
+    ui.separator();
+    if !state.active {
+        if ui
+            .button("Seitlich versetzen…")
+            .on_hover_text("Selektierte Kette senkrecht zur Fahrtrichtung verschieben")
+            .clicked()
+        {
+            events.push(AppIntent::PathOffsetPreviewRequested);
+        }
+        return;
+    }
+
+    ui.label(format!(
+        "Seitlicher Versatz ({} Node(s))",
+        state.chain_ids.len()
+    ));
+    let mut distance = state.distance;
+    let response = ui.add(
+        egui::DragValue::new(&mut distance)
+            .speed(0.1)
+            .range(-PATH_OFFSET_MAX_DISTANCE..=PATH_OFFSET_MAX_DISTANCE)
+            .max_decimals(2)
+            .suffix(" m"),
+    );
+    if response.changed() {
+        events.push(AppIntent::PathOffsetDistanceChanged { distance });
+    }
+    ui.small("positiv = links, negativ = rechts (in Kettenrichtung)");
+    ui.horizontal(|ui| {
+        if ui.button("Übernehmen").clicked() {
+            events.push(AppIntent::PathOffsetConfirmed);
+        }
+        if ui.button("Abbrechen").clicked() {
+            events.push(AppIntent::PathOffsetCancelled);
+        }
+    });
+}
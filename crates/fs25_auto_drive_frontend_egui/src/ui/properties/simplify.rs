@@ -0,0 +1,57 @@
+use crate::app::use_cases::editing::{simplify_chain_preview, SIMPLIFY_CHAIN_MAX_TOLERANCE};
+use crate::app::{AppIntent, RoadMap};
+use indexmap::IndexSet;
+
+/// Standard-Toleranz der Kettenvereinfachung in Metern.
+const DEFAULT_TOLERANCE: f32 = 0.25;
+
+/// Rendert die Douglas-Peucker-Vereinfachung fuer die selektierte Kette.
+///
+/// Die Toleranz wird im egui-Temp-Speicher gehalten; die Node-Anzahl nach der
+/// Vereinfachung wird bei jeder Aenderung live berechnet. Ohne gueltige Kette
+/// wird nichts angezeigt.
+pub fn render_simplify_panel(
+    ui: &mut egui::Ui,
+    road_map: &RoadMap,
+    selected_node_ids: &IndexSet<u64>,
+    events: &mut Vec<AppIntent>,
+) {
+    let tolerance_id = ui.id().with("chain_simplify_tolerance");
+    let mut tolerance =
+        ui.data_mut(|d| d.get_temp::<f32>(tolerance_id).unwrap_or(DEFAULT_TOLERANCE));
+    let Some(preview) = simplify_chain_preview(road_map, selected_node_ids, tolerance) else {
+        return;
+    };
+
+    ui.separator();
+    ui.label("Kette vereinfachen");
+    ui.add(
+        egui::Slider::new(&mut tolerance, 0.01..=SIMPLIFY_CHAIN_MAX_TOLERANCE)
+            .logarithmic(true)
+            .max_decimals(2)
+            .suffix(" m")
+            .text("Toleranz"),
+    );
+    ui.horizontal(|ui| {
+        ui.label(format!(
+            "Vorschau: {} → {} Nodes",
+            preview.chain_ids.len(),
+            preview.kept_ids.len()
+        ));
+        if ui
+            .add_enabled(
+                preview.removed_count() > 0,
+                egui::Button::new("Vereinfachen"),
+            )
+            .on_hover_text(
+                "Nodes entfernen, die weniger als die Toleranz von der Linie abweichen; \
+                 Endpunkte, Marker und Anschluesse bleiben erhalten",
+            )
+            .clicked()
+        {
+            events.push(AppIntent::SimplifyChainRequested { tolerance });
+        }
+    });
+
+    ui.data_mut(|d| d.insert_temp(tolerance_id, tolerance));
+}
@@ -0,0 +1,47 @@
+use crate::app::use_cases::editing::SMOOTH_CHAIN_MAX_ITERATIONS;
+use crate::app::AppIntent;
+
+/// Standardwerte fuer Staerke und Durchgaenge der Kettenglaettung.
+const DEFAULT_STRENGTH: f32 = 0.5;
+const DEFAULT_ITERATIONS: u32 = 5;
+
+/// Rendert die Glaettung fuer die selektierte Kette.
+///
+/// Staerke und Durchgaenge werden im egui-Temp-Speicher gehalten; jeder Klick
+/// erzeugt genau einen undo-faehigen Schritt.
+pub fn render_smoothing_panel(ui: &mut egui::Ui, events: &mut Vec<AppIntent>) {
+    let strength_id = ui.id().with("chain_smooth_strength");
+    let iterations_id = ui.id().with("chain_smooth_iterations");
+    let mut strength = ui.data_mut(|d| d.get_temp::<f32>(strength_id).unwrap_or(DEFAULT_STRENGTH));
+    let mut iterations = ui.data_mut(|d| {
+        d.get_temp::<u32>(iterations_id)
+            .unwrap_or(DEFAULT_ITERATIONS)
+    });
+
+    ui.separator();
+    ui.label("Kette glaetten");
+    ui.add(egui::Slider::new(&mut strength, 0.05..=1.0).text("Staerke"));
+    ui.horizontal(|ui| {
+        ui.label("Durchgaenge:");
+        ui.add(
+            egui::DragValue::new(&mut iterations)
+                .speed(0.2)
+                .range(1..=SMOOTH_CHAIN_MAX_ITERATIONS),
+        );
+        if ui
+            .button("Glaetten")
+            .on_hover_text("Zick-Zack der inneren Kettennodes ausgleichen; Endpunkte bleiben fest")
+            .clicked()
+        {
+            events.push(AppIntent::SmoothChainRequested {
+                strength,
+                iterations,
+            });
+        }
+    });
+
+    ui.data_mut(|d| {
+        d.insert_temp(strength_id, strength);
+        d.insert_temp(iterations_id, iterations);
+    });
+}
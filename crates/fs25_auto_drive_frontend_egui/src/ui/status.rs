@@ -88,6 +88,23 @@ pub(crate) fn render_status_bar_inside(
                     t(lang, I18nKey::StatusExample),
                     example_id
                 ));
+
+                // Selektions-Kennzahlen (Verbindungslaenge, Bounding Box)
+                ui.separator();
+                ui.label(format!(
+                    "{}: {:.1} m",
+                    t(lang, I18nKey::StatusSelectionLength),
+                    host_chrome_snapshot.selection_connected_length
+                ));
+                if let Some([width, height]) = host_chrome_snapshot.selection_bounds_size {
+                    ui.separator();
+                    ui.label(format!(
+                        "{}: {:.1} × {:.1} m",
+                        t(lang, I18nKey::StatusSelectionBounds),
+                        width,
+                        height
+                    ));
+                }
             } else {
                 ui.label(format!("{}: 0", t(lang, I18nKey::StatusSelectedNodes)));
             }
@@ -99,6 +116,8 @@ pub(crate) fn render_status_bar_inside(
                 EditorTool::Select => t(lang, I18nKey::ToolNameSelect),
                 EditorTool::Connect => t(lang, I18nKey::ToolNameConnect),
                 EditorTool::AddNode => t(lang, I18nKey::ToolNameAddNode),
+                EditorTool::Knife => t(lang, I18nKey::ToolNameKnife),
+                EditorTool::Measure => t(lang, I18nKey::ToolNameMeasure),
                 EditorTool::Route => t(lang, I18nKey::ToolNameRoute),
             };
             ui.label(format!("{}: {}", t(lang, I18nKey::StatusTool), tool_name));
@@ -0,0 +1,69 @@
+//! Terrain-Warn-Overlay: Markiert Verbindungen, die das Terrain schneiden oder ueberspannen.
+
+use eframe::egui;
+use glam::Vec2;
+
+use crate::app::ui_contract::TerrainClippingOverlaySnapshot;
+use crate::app::Camera2D;
+use crate::shared::{t, I18nKey, Language};
+
+/// Warnfarbe (Bernstein) des Dreieck-Symbols.
+const WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 180, 0);
+/// Kantenlaenge des Warnsymbols in Pixeln.
+const GLYPH_SIZE_PX: f32 = 14.0;
+
+/// Zeichnet ein Warnsymbol an der Stelle der groessten Terrain-Abweichung
+/// jeder gemeldeten Verbindung.
+///
+/// Liegt `hover_pos` ueber einem Symbol, wird der Tooltip-Text mit Node-IDs und
+/// Abweichung in Metern zurueckgegeben.
+pub fn render_terrain_clipping_overlays(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlays: &[TerrainClippingOverlaySnapshot],
+    hover_pos: Option<egui::Pos2>,
+    lang: Language,
+) -> Option<String> {
+    let half = GLYPH_SIZE_PX / 2.0;
+    let mut hovered = None;
+
+    for overlay in overlays {
+        let screen = camera.world_to_screen(overlay.world_pos, viewport_size);
+        let center = egui::pos2(rect.min.x + screen.x, rect.min.y + screen.y);
+        if !rect.expand(GLYPH_SIZE_PX).contains(center) {
+            continue;
+        }
+
+        let points = vec![
+            egui::pos2(center.x, center.y - half),
+            egui::pos2(center.x + half, center.y + half),
+            egui::pos2(center.x - half, center.y + half),
+        ];
+        painter.add(egui::Shape::convex_polygon(
+            points,
+            WARNING_COLOR,
+            egui::Stroke::new(1.0, egui::Color32::BLACK),
+        ));
+        painter.text(
+            egui::pos2(center.x, center.y + half * 0.25),
+            egui::Align2::CENTER_CENTER,
+            "!",
+            egui::FontId::proportional(GLYPH_SIZE_PX * 0.7),
+            egui::Color32::BLACK,
+        );
+
+        if hover_pos.is_some_and(|pos| pos.distance(center) <= GLYPH_SIZE_PX) {
+            hovered = Some(format!(
+                "{} ({} → {}): {:.1} m",
+                t(lang, I18nKey::TerrainClippingWarning),
+                overlay.start_id,
+                overlay.end_id,
+                overlay.deviation_m
+            ));
+        }
+    }
+
+    hovered
+}
@@ -0,0 +1,116 @@
+//! Fahrzeug-Overlay: Zeigt die im Savegame abgestellten Fahrzeuge mit Namen
+//! sowie die live gemeldete Spielerposition.
+
+use eframe::egui;
+use glam::Vec2;
+
+use crate::app::ui_contract::{LivePlayerOverlaySnapshot, VehicleOverlaySnapshot};
+use crate::app::Camera2D;
+
+/// Fuellfarbe des Fahrzeug-Symbols.
+const VEHICLE_COLOR: egui::Color32 = egui::Color32::from_rgb(70, 160, 90);
+/// Fuellfarbe des Live-Spieler-Symbols.
+const LIVE_PLAYER_COLOR: egui::Color32 = egui::Color32::from_rgb(40, 120, 230);
+/// Fuellfarbe des Live-Spieler-Symbols waehrend der Aufzeichnung.
+const LIVE_PLAYER_RECORDING_COLOR: egui::Color32 = egui::Color32::from_rgb(220, 50, 50);
+/// Radius des Fahrzeug-Symbols in Pixeln.
+const ICON_RADIUS_PX: f32 = 7.0;
+/// Schriftgroesse des Fahrzeugnamens in Pixeln.
+const LABEL_SIZE_PX: f32 = 11.0;
+
+/// Zeichnet jedes Fahrzeug als Kreis mit Richtungsspitze und Namen darunter.
+pub fn render_vehicle_overlays(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlays: &[VehicleOverlaySnapshot],
+) {
+    for overlay in overlays {
+        if let Some(center) = draw_vehicle_icon(
+            painter,
+            rect,
+            camera,
+            viewport_size,
+            overlay.world_pos,
+            overlay.direction,
+            VEHICLE_COLOR,
+        ) {
+            painter.text(
+                egui::pos2(center.x, center.y + ICON_RADIUS_PX + 2.0),
+                egui::Align2::CENTER_TOP,
+                &overlay.name,
+                egui::FontId::proportional(LABEL_SIZE_PX),
+                egui::Color32::WHITE,
+            );
+        }
+    }
+}
+
+/// Zeichnet die live gemeldete Spielerposition; waehrend der Aufzeichnung rot.
+pub fn render_live_player_overlay(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    overlay: &LivePlayerOverlaySnapshot,
+) {
+    let color = if overlay.recording {
+        LIVE_PLAYER_RECORDING_COLOR
+    } else {
+        LIVE_PLAYER_COLOR
+    };
+    draw_vehicle_icon(
+        painter,
+        rect,
+        camera,
+        viewport_size,
+        overlay.world_pos,
+        overlay.direction,
+        color,
+    );
+}
+
+/// Zeichnet Kreis mit Richtungsspitze; gibt den Bildschirm-Mittelpunkt zurueck
+/// oder `None`, wenn das Symbol ausserhalb des Viewports liegt.
+fn draw_vehicle_icon(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    camera: &Camera2D,
+    viewport_size: Vec2,
+    world_pos: Vec2,
+    direction: Vec2,
+    color: egui::Color32,
+) -> Option<egui::Pos2> {
+    let screen = camera.world_to_screen(world_pos, viewport_size);
+    let center = egui::pos2(rect.min.x + screen.x, rect.min.y + screen.y);
+    if !rect.expand(ICON_RADIUS_PX * 4.0).contains(center) {
+        return None;
+    }
+
+    // Richtung im Bildschirm unabhaengig von der Achsenorientierung der Kamera
+    let ahead = camera.world_to_screen(world_pos + direction, viewport_size);
+    let heading = (ahead - screen).normalize_or_zero();
+    let normal = Vec2::new(-heading.y, heading.x);
+    let point = |along: f32, across: f32| {
+        let offset = heading * along + normal * across;
+        egui::pos2(center.x + offset.x, center.y + offset.y)
+    };
+
+    painter.circle(
+        center,
+        ICON_RADIUS_PX,
+        color,
+        egui::Stroke::new(1.0, egui::Color32::BLACK),
+    );
+    painter.add(egui::Shape::convex_polygon(
+        vec![
+            point(ICON_RADIUS_PX * 1.8, 0.0),
+            point(ICON_RADIUS_PX * 0.6, ICON_RADIUS_PX * 0.6),
+            point(ICON_RADIUS_PX * 0.6, -ICON_RADIUS_PX * 0.6),
+        ],
+        egui::Color32::WHITE,
+        egui::Stroke::new(1.0, egui::Color32::BLACK),
+    ));
+    Some(center)
+}
@@ -27,12 +27,14 @@ mod tests {
     use fs25_auto_drive_engine::app::use_cases::background_layers::{
         discover_background_layer_files, load_background_layer_catalog,
     };
+    use fs25_auto_drive_engine::app::use_cases::editing::{NodeAlignMode, NodeHeightEdit};
     use fs25_auto_drive_engine::app::{AppController, AppIntent, AppState};
     use fs25_auto_drive_engine::core::{
         Connection, ConnectionDirection, ConnectionPriority, MapMarker, MapNode, NodeFlag, RoadMap,
     };
     use fs25_auto_drive_engine::shared::{
-        BackgroundLayerKind, OverviewLayerOptions, RenderQuality,
+        BackgroundLayerKind, BackgroundSource, NodeDeleteFilter, OverviewLayerOptions,
+        PdfExportOptions, PdfPaperSize, RenderQuality,
     };
     use glam::Vec2;
     use std::fs;
@@ -42,10 +44,11 @@ mod tests {
 
     use crate::dto::{
         HostActiveTool, HostBackgroundLayerKind, HostDefaultConnectionDirection,
-        HostDefaultConnectionPriority, HostDialogRequestKind, HostDialogResult,
-        HostRouteToolAction, HostRouteToolDisabledReason, HostRouteToolGroup, HostRouteToolIconKey,
-        HostRouteToolId, HostRouteToolSurface, HostSessionAction, HostTangentSource,
-        HostViewportConnectionDirection, HostViewportConnectionPriority, HostViewportNodeKind,
+        HostDefaultConnectionPriority, HostDialogRequestKind, HostDialogResult, HostNodeAlignMode,
+        HostNodeHeightEdit, HostPdfPaperSize, HostRouteToolAction, HostRouteToolDisabledReason,
+        HostRouteToolGroup, HostRouteToolIconKey, HostRouteToolId, HostRouteToolSurface,
+        HostSessionAction, HostTangentSource, HostViewportConnectionDirection,
+        HostViewportConnectionPriority, HostViewportNodeKind,
     };
 
     use super::{
@@ -122,6 +125,39 @@ mod tests {
         assert_eq!(requests[2].kind, HostDialogRequestKind::CurseplayExport);
     }
 
+    #[test]
+    fn map_mod_export_requests_roundtrip_to_export_intents() {
+        let mut controller = AppController::new();
+        let mut state = AppState::new();
+
+        controller
+            .handle_intent(&mut state, AppIntent::MapModZipExportRequested)
+            .expect("MapModZipExportRequested muss Dialog-Anforderung erzeugen");
+        controller
+            .handle_intent(&mut state, AppIntent::CoursesModExportRequested)
+            .expect("CoursesModExportRequested muss Dialog-Anforderung erzeugen");
+
+        let requests = take_host_dialog_requests(&controller, &mut state);
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].kind, HostDialogRequestKind::MapModZip);
+        assert_eq!(requests[1].kind, HostDialogRequestKind::CoursesModExport);
+        assert_eq!(
+            requests[1].suggested_file_name.as_deref(),
+            Some("FS25_AutoDrive_Map.zip")
+        );
+
+        let intent = map_host_action_to_intent(HostSessionAction::SubmitDialogResult {
+            result: HostDialogResult::PathSelected {
+                kind: HostDialogRequestKind::MapModZip,
+                path: "/mods/FS25_Riverbend.zip".to_string(),
+            },
+        });
+        assert!(matches!(
+            intent,
+            Some(AppIntent::MapModZipSelected { path }) if path == "/mods/FS25_Riverbend.zip"
+        ));
+    }
+
     #[test]
     fn apply_host_action_dispatches_mapped_action() {
         let mut controller = AppController::new();
@@ -613,6 +649,10 @@ mod tests {
                 AppIntent::InvertAllConnectionsBetweenSelectedRequested,
                 HostSessionAction::InvertAllConnectionsBetweenSelected,
             ),
+            (
+                AppIntent::ReversePathBetweenSelectedRequested,
+                HostSessionAction::ReversePathBetweenSelected,
+            ),
             (
                 AppIntent::SetAllConnectionsPriorityBetweenSelectedRequested {
                     priority: ConnectionPriority::SubPriority,
@@ -708,11 +748,31 @@ mod tests {
                 AppIntent::PasteStartRequested,
                 HostSessionAction::PasteStart,
             ),
+            (
+                AppIntent::PasteFromTextRequested {
+                    text: "{}".to_string(),
+                },
+                HostSessionAction::PasteFromText {
+                    text: "{}".to_string(),
+                },
+            ),
             (
                 AppIntent::PasteConfirmRequested,
                 HostSessionAction::PasteConfirm,
             ),
             (AppIntent::PasteCancelled, HostSessionAction::PasteCancel),
+            (
+                AppIntent::PastePreviewRotateRequested {
+                    counter_clockwise: false,
+                },
+                HostSessionAction::PasteRotate {
+                    counter_clockwise: false,
+                },
+            ),
+            (
+                AppIntent::DuplicateSelectionRequested,
+                HostSessionAction::DuplicateSelection,
+            ),
         ];
 
         for (intent, expected_action) in cases {
@@ -747,6 +807,14 @@ mod tests {
                     path: "/tmp/overview.zip".to_string(),
                 },
             ),
+            (
+                AppIntent::ExtractHeightmapFromZipRequested {
+                    path: "/tmp/overview.zip".to_string(),
+                },
+                HostSessionAction::ExtractHeightmapFromZip {
+                    path: "/tmp/overview.zip".to_string(),
+                },
+            ),
             (
                 AppIntent::ZipBackgroundFileSelected {
                     zip_path: "/tmp/background.zip".to_string(),
@@ -803,6 +871,10 @@ mod tests {
                     quality: RenderQuality::Low,
                 },
             ),
+            (
+                AppIntent::ToggleIsolateSelectionRequested,
+                HostSessionAction::ToggleIsolateSelection,
+            ),
             (
                 AppIntent::ToggleBackgroundVisibility,
                 HostSessionAction::ToggleBackgroundVisibility,
@@ -821,6 +893,14 @@ mod tests {
                 AppIntent::ScaleBackground { factor: 0.5 },
                 HostSessionAction::ScaleBackground { factor: 0.5 },
             ),
+            (
+                AppIntent::BackgroundSourceSelected {
+                    source: BackgroundSource::HeightmapHillshade,
+                },
+                HostSessionAction::SetBackgroundSource {
+                    source: BackgroundSource::HeightmapHillshade,
+                },
+            ),
             (
                 AppIntent::CreateMarkerRequested { node_id: 21 },
                 HostSessionAction::OpenCreateMarkerDialog { node_id: 21 },
@@ -837,6 +917,42 @@ mod tests {
                 AppIntent::InvertSelectionRequested,
                 HostSessionAction::InvertSelection,
             ),
+            (
+                AppIntent::GrowSelectionRequested,
+                HostSessionAction::GrowSelection,
+            ),
+            (
+                AppIntent::ShrinkSelectionRequested,
+                HostSessionAction::ShrinkSelection,
+            ),
+            (
+                AppIntent::SelectPathBetweenSelectedRequested,
+                HostSessionAction::SelectPathBetweenSelected,
+            ),
+            (
+                AppIntent::SelectByConnectionPriorityRequested {
+                    priority: ConnectionPriority::Regular,
+                },
+                HostSessionAction::SelectByConnectionPriority {
+                    priority: HostDefaultConnectionPriority::Regular,
+                },
+            ),
+            (
+                AppIntent::SetSelectedNodeHeightsRequested {
+                    edit: NodeHeightEdit::Offset(-1.5),
+                },
+                HostSessionAction::SetSelectedNodeHeights {
+                    edit: HostNodeHeightEdit::Offset { delta: -1.5 },
+                },
+            ),
+            (
+                AppIntent::ProjectSelectionOntoTerrainRequested { offset: 0.25 },
+                HostSessionAction::ProjectSelectionOntoTerrain { offset: 0.25 },
+            ),
+            (
+                AppIntent::SetSelectionLockedRequested { locked: false },
+                HostSessionAction::SetSelectionLocked { locked: false },
+            ),
             (
                 AppIntent::StreckenteilungAktivieren,
                 HostSessionAction::StartResampleSelection,
@@ -845,6 +961,82 @@ mod tests {
                 AppIntent::ResamplePathRequested,
                 HostSessionAction::ApplyCurrentResample,
             ),
+            (
+                AppIntent::EqualizeChainSpacingRequested,
+                HostSessionAction::EqualizeChainSpacing,
+            ),
+            (
+                AppIntent::ResampleChainRequested { spacing: 3.5 },
+                HostSessionAction::ResampleSelectedChain { spacing: 3.5 },
+            ),
+            (
+                AppIntent::SmoothChainRequested {
+                    strength: 0.25,
+                    iterations: 4,
+                },
+                HostSessionAction::SmoothSelectedChain {
+                    strength: 0.25,
+                    iterations: 4,
+                },
+            ),
+            (
+                AppIntent::SimplifyChainRequested { tolerance: 0.75 },
+                HostSessionAction::SimplifySelectedChain { tolerance: 0.75 },
+            ),
+            (
+                AppIntent::InvertConnectionRequested {
+                    start_id: 5,
+                    end_id: 6,
+                },
+                HostSessionAction::InvertConnection {
+                    start_id: 5,
+                    end_id: 6,
+                },
+            ),
+            (
+                AppIntent::AlignSelectedRequested {
+                    mode: NodeAlignMode::AxisZ,
+                },
+                HostSessionAction::AlignSelectedNodes {
+                    mode: HostNodeAlignMode::AxisZ,
+                },
+            ),
+            (
+                AppIntent::DistributeSelectedRequested,
+                HostSessionAction::DistributeSelectedNodes,
+            ),
+            (
+                AppIntent::JoinSelectedChainsRequested,
+                HostSessionAction::JoinSelectedChains,
+            ),
+            (
+                AppIntent::MergeSelectedNodesRequested,
+                HostSessionAction::MergeSelectedNodes,
+            ),
+            (
+                AppIntent::ReverseChainRequested,
+                HostSessionAction::ReverseSelectedChain,
+            ),
+            (
+                AppIntent::PathOffsetPreviewRequested,
+                HostSessionAction::StartPathOffset,
+            ),
+            (
+                AppIntent::PathOffsetDistanceChanged { distance: 2.5 },
+                HostSessionAction::SetPathOffsetDistance { distance: 2.5 },
+            ),
+            (
+                AppIntent::PathOffsetConfirmed,
+                HostSessionAction::ApplyPathOffset,
+            ),
+            (
+                AppIntent::PathOffsetCancelled,
+                HostSessionAction::CancelPathOffset,
+            ),
+            (
+                AppIntent::ConvertJunctionToRoundaboutRequested,
+                HostSessionAction::ConvertJunctionToRoundabout,
+            ),
             (
                 AppIntent::GroupEditStartRequested { record_id: 3 },
                 HostSessionAction::StartGroupEdit { record_id: 3 },
@@ -891,6 +1083,16 @@ mod tests {
                     additive: true,
                 },
             ),
+            (
+                AppIntent::SelectConnectedRequested {
+                    world_pos: Vec2::new(5.0, 6.0),
+                    additive: false,
+                },
+                HostSessionAction::SelectConnected {
+                    world_pos: [5.0, 6.0],
+                    additive: false,
+                },
+            ),
             (
                 AppIntent::ToggleGroupLockRequested { segment_id: 6 },
                 HostSessionAction::ToggleGroupLock { segment_id: 6 },
@@ -929,6 +1131,156 @@ mod tests {
                 AppIntent::TraceAllFieldsCancelled,
                 HostSessionAction::CancelTraceAllFields,
             ),
+            (
+                AppIntent::OpenFieldCourseDialogRequested,
+                HostSessionAction::OpenFieldCourseDialog,
+            ),
+            (
+                AppIntent::FieldCourseConfirmed {
+                    field_id: 4,
+                    spacing: 10.0,
+                    headland_offset: 6.0,
+                },
+                HostSessionAction::ConfirmFieldCourse {
+                    field_id: 4,
+                    spacing: 10.0,
+                    headland_offset: 6.0,
+                },
+            ),
+            (
+                AppIntent::FieldCourseCancelled,
+                HostSessionAction::CancelFieldCourse,
+            ),
+            (
+                AppIntent::OpenMoveSelectionDialogRequested,
+                HostSessionAction::OpenMoveSelectionDialog,
+            ),
+            (
+                AppIntent::MoveSelectionConfirmed {
+                    dx: 10.0,
+                    dy: 0.5,
+                    dz: -4.0,
+                },
+                HostSessionAction::ConfirmMoveSelection {
+                    dx: 10.0,
+                    dy: 0.5,
+                    dz: -4.0,
+                },
+            ),
+            (
+                AppIntent::NudgeSelectionRequested {
+                    delta: Vec2::new(2.5, -1.0),
+                },
+                HostSessionAction::NudgeSelection { dx: 2.5, dz: -1.0 },
+            ),
+            (
+                AppIntent::MoveSelectionCancelled,
+                HostSessionAction::CancelMoveSelection,
+            ),
+            (
+                AppIntent::OpenDeleteByFilterDialogRequested,
+                HostSessionAction::OpenDeleteByFilterDialog,
+            ),
+            (
+                AppIntent::DeleteByFilterConfirmed {
+                    filter: NodeDeleteFilter {
+                        only_subprio: false,
+                        only_unconnected: true,
+                        only_without_markers: true,
+                    },
+                },
+                HostSessionAction::ConfirmDeleteByFilter {
+                    only_subprio: false,
+                    only_unconnected: true,
+                    only_without_markers: true,
+                },
+            ),
+            (
+                AppIntent::DeleteByFilterCancelled,
+                HostSessionAction::CancelDeleteByFilter,
+            ),
+            (
+                AppIntent::OpenPdfExportDialogRequested,
+                HostSessionAction::OpenPdfExportDialog,
+            ),
+            (
+                AppIntent::PdfExportConfirmed {
+                    options: PdfExportOptions {
+                        paper: PdfPaperSize::A3,
+                        landscape: false,
+                        scale_denominator: 2500,
+                        show_grid: true,
+                        grid_spacing_m: 50.0,
+                    },
+                },
+                HostSessionAction::ConfirmPdfExport {
+                    paper: HostPdfPaperSize::A3,
+                    landscape: false,
+                    scale_denominator: 2500,
+                    show_grid: true,
+                    grid_spacing_m: 50.0,
+                },
+            ),
+            (
+                AppIntent::PdfExportCancelled,
+                HostSessionAction::CancelPdfExport,
+            ),
+            (
+                AppIntent::LiveCourseRecordingToggled,
+                HostSessionAction::ToggleLiveCourseRecording,
+            ),
+            (
+                AppIntent::LiveNetworkPushRequested,
+                HostSessionAction::PushNetworkToGame,
+            ),
+            (
+                AppIntent::RoutesDialogRequested,
+                HostSessionAction::OpenRoutesDialog,
+            ),
+            (
+                AppIntent::StoredRouteImportRequested {
+                    file_name: "Hof.xml".to_string(),
+                },
+                HostSessionAction::ImportStoredRoute {
+                    file_name: "Hof.xml".to_string(),
+                },
+            ),
+            (
+                AppIntent::StoredRouteExportRequested {
+                    name: "Hof".to_string(),
+                },
+                HostSessionAction::ExportStoredRoute {
+                    name: "Hof".to_string(),
+                },
+            ),
+            (
+                AppIntent::RoutesDialogCancelled,
+                HostSessionAction::CancelRoutesDialog,
+            ),
+            (
+                AppIntent::SavegamePickerRequested,
+                HostSessionAction::OpenSavegamePicker,
+            ),
+            (
+                AppIntent::SavegameOpenRequested {
+                    path: "/fs25/savegame1/AutoDrive_config.xml".to_string(),
+                },
+                HostSessionAction::OpenSavegame {
+                    path: "/fs25/savegame1/AutoDrive_config.xml".to_string(),
+                },
+            ),
+            (
+                AppIntent::SavegamePickerCancelled,
+                HostSessionAction::CancelSavegamePicker,
+            ),
+            (
+                AppIntent::MapModZipExportRequested,
+                HostSessionAction::MapModZipExport,
+            ),
+            (
+                AppIntent::CoursesModExportRequested,
+                HostSessionAction::CoursesModExport,
+            ),
         ];
 
         for (intent, expected_action) in cases {
@@ -1094,6 +1446,23 @@ mod tests {
         assert!(chrome.background_layer_entries.is_empty());
     }
 
+    #[test]
+    fn build_host_chrome_snapshot_exposes_selection_metrics() {
+        let mut state = AppState::new();
+        state.road_map = Some(Arc::new(geometry_test_map()));
+
+        let chrome = build_host_chrome_snapshot(&state);
+        assert_eq!(chrome.selection_connected_length, 0.0);
+        assert_eq!(chrome.selection_bounds_size, None);
+
+        state.selection.ids_mut().extend([1, 2]);
+        let chrome = build_host_chrome_snapshot(&state);
+
+        assert_eq!(chrome.selection_count, 2);
+        assert!((chrome.selection_connected_length - 500.0_f32.sqrt()).abs() < 1.0e-4);
+        assert_eq!(chrome.selection_bounds_size, Some([20.0, 10.0]));
+    }
+
     #[test]
     fn build_host_chrome_snapshot_exposes_background_layer_entries() {
         let timestamp = SystemTime::now()
@@ -10,17 +10,19 @@ use fs25_auto_drive_engine::app::ui_contract::{
 };
 use fs25_auto_drive_engine::app::{AppIntent, AppState, ConnectionDirection, ConnectionPriority};
 use fs25_auto_drive_engine::shared::{
-    BackgroundLayerKind, RenderConnectionDirection, RenderConnectionPriority, RenderNodeKind,
+    BackgroundLayerKind, NodeDeleteFilter, PdfExportOptions, RenderConnectionDirection,
+    RenderConnectionPriority, RenderNodeKind,
 };
 use glam::Vec2;
 
 use crate::dto::{
     HostActiveTool, HostBackgroundLayerKind, HostDefaultConnectionDirection,
     HostDefaultConnectionPriority, HostDialogRequest, HostDialogRequestKind, HostDialogResult,
-    HostNodeFlag, HostRouteToolAction, HostRouteToolDisabledReason, HostRouteToolGroup,
-    HostRouteToolIconKey, HostRouteToolId, HostRouteToolSurface, HostSessionAction,
-    HostTangentMenuSnapshot, HostTangentOptionSnapshot, HostTangentSource,
-    HostViewportConnectionDirection, HostViewportConnectionPriority, HostViewportNodeKind,
+    HostNodeAlignMode, HostNodeFlag, HostNodeHeightEdit, HostRouteToolAction,
+    HostRouteToolDisabledReason, HostRouteToolGroup, HostRouteToolIconKey, HostRouteToolId,
+    HostRouteToolSurface, HostSessionAction, HostTangentMenuSnapshot, HostTangentOptionSnapshot,
+    HostTangentSource, HostViewportConnectionDirection, HostViewportConnectionPriority,
+    HostViewportNodeKind,
 };
 use fs25_auto_drive_engine::app::EditorTool;
 
@@ -31,6 +33,8 @@ pub(super) fn map_host_active_tool(tool: HostActiveTool) -> EditorTool {
         HostActiveTool::Select => EditorTool::Select,
         HostActiveTool::Connect => EditorTool::Connect,
         HostActiveTool::AddNode => EditorTool::AddNode,
+        HostActiveTool::Knife => EditorTool::Knife,
+        HostActiveTool::Measure => EditorTool::Measure,
         HostActiveTool::Route => EditorTool::Route,
     }
 }
@@ -40,6 +44,8 @@ pub(super) fn map_editor_tool(tool: EditorTool) -> HostActiveTool {
         EditorTool::Select => HostActiveTool::Select,
         EditorTool::Connect => HostActiveTool::Connect,
         EditorTool::AddNode => HostActiveTool::AddNode,
+        EditorTool::Knife => HostActiveTool::Knife,
+        EditorTool::Measure => HostActiveTool::Measure,
         EditorTool::Route => HostActiveTool::Route,
     }
 }
@@ -163,6 +169,10 @@ pub(super) fn map_route_tool_id(tool_id: RouteToolId) -> HostRouteToolId {
         RouteToolId::RouteOffset => HostRouteToolId::RouteOffset,
         RouteToolId::Rounding => HostRouteToolId::Rounding,
         RouteToolId::ColorPath => HostRouteToolId::ColorPath,
+        RouteToolId::FieldCourse => HostRouteToolId::FieldCourse,
+        RouteToolId::Clothoid => HostRouteToolId::Clothoid,
+        RouteToolId::Arc => HostRouteToolId::Arc,
+        RouteToolId::Junction => HostRouteToolId::Junction,
     }
 }
 
@@ -180,6 +190,10 @@ pub(super) fn map_host_route_tool_id(tool_id: HostRouteToolId) -> RouteToolId {
         HostRouteToolId::RouteOffset => RouteToolId::RouteOffset,
         HostRouteToolId::Rounding => RouteToolId::Rounding,
         HostRouteToolId::ColorPath => RouteToolId::ColorPath,
+        HostRouteToolId::FieldCourse => RouteToolId::FieldCourse,
+        HostRouteToolId::Clothoid => RouteToolId::Clothoid,
+        HostRouteToolId::Arc => RouteToolId::Arc,
+        HostRouteToolId::Junction => RouteToolId::Junction,
     }
 }
 
@@ -281,6 +295,10 @@ pub(super) fn map_route_tool_icon_key(icon_key: RouteToolIconKey) -> HostRouteTo
         RouteToolIconKey::RouteOffset => HostRouteToolIconKey::RouteOffset,
         RouteToolIconKey::Rounding => HostRouteToolIconKey::Rounding,
         RouteToolIconKey::ColorPath => HostRouteToolIconKey::ColorPath,
+        RouteToolIconKey::FieldCourse => HostRouteToolIconKey::FieldCourse,
+        RouteToolIconKey::Clothoid => HostRouteToolIconKey::Clothoid,
+        RouteToolIconKey::Arc => HostRouteToolIconKey::Arc,
+        RouteToolIconKey::Junction => HostRouteToolIconKey::Junction,
     }
 }
 
@@ -351,6 +369,9 @@ pub(super) fn map_engine_dialog_request_kind(kind: DialogRequestKind) -> HostDia
         DialogRequestKind::OverviewZip => HostDialogRequestKind::OverviewZip,
         DialogRequestKind::CurseplayImport => HostDialogRequestKind::CurseplayImport,
         DialogRequestKind::CurseplayExport => HostDialogRequestKind::CurseplayExport,
+        DialogRequestKind::MapModZip => HostDialogRequestKind::MapModZip,
+        DialogRequestKind::CoursesModExport => HostDialogRequestKind::CoursesModExport,
+        DialogRequestKind::PdfExport => HostDialogRequestKind::PdfExport,
     }
 }
 
@@ -377,6 +398,9 @@ pub(super) fn map_host_dialog_request_kind(kind: HostDialogRequestKind) -> Dialo
         HostDialogRequestKind::OverviewZip => DialogRequestKind::OverviewZip,
         HostDialogRequestKind::CurseplayImport => DialogRequestKind::CurseplayImport,
         HostDialogRequestKind::CurseplayExport => DialogRequestKind::CurseplayExport,
+        HostDialogRequestKind::MapModZip => DialogRequestKind::MapModZip,
+        HostDialogRequestKind::CoursesModExport => DialogRequestKind::CoursesModExport,
+        HostDialogRequestKind::PdfExport => DialogRequestKind::PdfExport,
     }
 }
 
@@ -417,6 +441,9 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
         AppIntent::GenerateOverviewFromZip { path } => {
             Some(HostSessionAction::GenerateOverviewFromZip { path: path.clone() })
         }
+        AppIntent::ExtractHeightmapFromZipRequested { path } => {
+            Some(HostSessionAction::ExtractHeightmapFromZip { path: path.clone() })
+        }
         AppIntent::ZipBackgroundFileSelected {
             zip_path,
             entry_name,
@@ -447,6 +474,9 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
         AppIntent::RenderQualityChanged { quality } => {
             Some(HostSessionAction::SetRenderQuality { quality: *quality })
         }
+        AppIntent::ToggleIsolateSelectionRequested => {
+            Some(HostSessionAction::ToggleIsolateSelection)
+        }
         AppIntent::ToggleBackgroundVisibility => {
             Some(HostSessionAction::ToggleBackgroundVisibility)
         }
@@ -459,6 +489,9 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
         AppIntent::ScaleBackground { factor } => {
             Some(HostSessionAction::ScaleBackground { factor: *factor })
         }
+        AppIntent::BackgroundSourceSelected { source } => {
+            Some(HostSessionAction::SetBackgroundSource { source: *source })
+        }
         AppIntent::ZoomToFitRequested => Some(HostSessionAction::ZoomToFit),
         AppIntent::ZoomToSelectionBoundsRequested => Some(HostSessionAction::ZoomToSelectionBounds),
         AppIntent::ExitRequested => Some(HostSessionAction::Exit),
@@ -511,6 +544,12 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
             end_id: *end_id,
             priority: map_connection_priority(*priority),
         }),
+        AppIntent::InvertConnectionRequested { start_id, end_id } => {
+            Some(HostSessionAction::InvertConnection {
+                start_id: *start_id,
+                end_id: *end_id,
+            })
+        }
         AppIntent::ConnectSelectedNodesRequested => Some(HostSessionAction::ConnectSelectedNodes),
         AppIntent::SetAllConnectionsDirectionBetweenSelectedRequested { direction } => Some(
             HostSessionAction::SetAllConnectionsDirectionBetweenSelected {
@@ -520,6 +559,9 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
         AppIntent::InvertAllConnectionsBetweenSelectedRequested => {
             Some(HostSessionAction::InvertAllConnectionsBetweenSelected)
         }
+        AppIntent::ReversePathBetweenSelectedRequested => {
+            Some(HostSessionAction::ReversePathBetweenSelected)
+        }
         AppIntent::SetAllConnectionsPriorityBetweenSelectedRequested { priority } => Some(
             HostSessionAction::SetAllConnectionsPriorityBetweenSelected {
                 priority: map_connection_priority(*priority),
@@ -619,6 +661,14 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
                 flag: HostNodeFlag::from(flag),
             })
         }
+        AppIntent::SetSelectedNodeHeightsRequested { edit } => {
+            Some(HostSessionAction::SetSelectedNodeHeights {
+                edit: HostNodeHeightEdit::from(edit),
+            })
+        }
+        AppIntent::ProjectSelectionOntoTerrainRequested { offset } => {
+            Some(HostSessionAction::ProjectSelectionOntoTerrain { offset: *offset })
+        }
         AppIntent::CreateMarkerRequested { node_id } => {
             Some(HostSessionAction::OpenCreateMarkerDialog { node_id: *node_id })
         }
@@ -650,9 +700,56 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
         AppIntent::DeleteSelectedRequested => Some(HostSessionAction::DeleteSelected),
         AppIntent::SelectAllRequested => Some(HostSessionAction::SelectAll),
         AppIntent::InvertSelectionRequested => Some(HostSessionAction::InvertSelection),
+        AppIntent::GrowSelectionRequested => Some(HostSessionAction::GrowSelection),
+        AppIntent::ShrinkSelectionRequested => Some(HostSessionAction::ShrinkSelection),
+        AppIntent::SelectPathBetweenSelectedRequested => {
+            Some(HostSessionAction::SelectPathBetweenSelected)
+        }
+        AppIntent::SelectByConnectionPriorityRequested { priority } => {
+            Some(HostSessionAction::SelectByConnectionPriority {
+                priority: map_connection_priority(*priority),
+            })
+        }
+        AppIntent::SetSelectionLockedRequested { locked } => {
+            Some(HostSessionAction::SetSelectionLocked { locked: *locked })
+        }
         AppIntent::ClearSelectionRequested => Some(HostSessionAction::ClearSelection),
         AppIntent::StreckenteilungAktivieren => Some(HostSessionAction::StartResampleSelection),
         AppIntent::ResamplePathRequested => Some(HostSessionAction::ApplyCurrentResample),
+        AppIntent::EqualizeChainSpacingRequested => Some(HostSessionAction::EqualizeChainSpacing),
+        AppIntent::ResampleChainRequested { spacing } => {
+            Some(HostSessionAction::ResampleSelectedChain { spacing: *spacing })
+        }
+        AppIntent::SmoothChainRequested {
+            strength,
+            iterations,
+        } => Some(HostSessionAction::SmoothSelectedChain {
+            strength: *strength,
+            iterations: *iterations,
+        }),
+        AppIntent::SimplifyChainRequested { tolerance } => {
+            Some(HostSessionAction::SimplifySelectedChain {
+                tolerance: *tolerance,
+            })
+        }
+        AppIntent::AlignSelectedRequested { mode } => Some(HostSessionAction::AlignSelectedNodes {
+            mode: HostNodeAlignMode::from(*mode),
+        }),
+        AppIntent::DistributeSelectedRequested => Some(HostSessionAction::DistributeSelectedNodes),
+        AppIntent::JoinSelectedChainsRequested => Some(HostSessionAction::JoinSelectedChains),
+        AppIntent::MergeSelectedNodesRequested => Some(HostSessionAction::MergeSelectedNodes),
+        AppIntent::ReverseChainRequested => Some(HostSessionAction::ReverseSelectedChain),
+        AppIntent::PathOffsetPreviewRequested => Some(HostSessionAction::StartPathOffset),
+        AppIntent::PathOffsetDistanceChanged { distance } => {
+            Some(HostSessionAction::SetPathOffsetDistance {
+                distance: *distance,
+            })
+        }
+        AppIntent::PathOffsetConfirmed => Some(HostSessionAction::ApplyPathOffset),
+        AppIntent::PathOffsetCancelled => Some(HostSessionAction::CancelPathOffset),
+        AppIntent::ConvertJunctionToRoundaboutRequested => {
+            Some(HostSessionAction::ConvertJunctionToRoundabout)
+        }
         AppIntent::GroupEditStartRequested { record_id } => {
             Some(HostSessionAction::StartGroupEdit {
                 record_id: *record_id,
@@ -685,6 +782,13 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
             world_pos: [world_pos.x, world_pos.y],
             additive: *additive,
         }),
+        AppIntent::SelectConnectedRequested {
+            world_pos,
+            additive,
+        } => Some(HostSessionAction::SelectConnected {
+            world_pos: [world_pos.x, world_pos.y],
+            additive: *additive,
+        }),
         AppIntent::ToggleGroupLockRequested { segment_id } => {
             Some(HostSessionAction::ToggleGroupLock {
                 segment_id: *segment_id,
@@ -702,8 +806,17 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
         }
         AppIntent::CopySelectionRequested => Some(HostSessionAction::CopySelection),
         AppIntent::PasteStartRequested => Some(HostSessionAction::PasteStart),
+        AppIntent::PasteFromTextRequested { text } => {
+            Some(HostSessionAction::PasteFromText { text: text.clone() })
+        }
         AppIntent::PasteConfirmRequested => Some(HostSessionAction::PasteConfirm),
         AppIntent::PasteCancelled => Some(HostSessionAction::PasteCancel),
+        AppIntent::PastePreviewRotateRequested { counter_clockwise } => {
+            Some(HostSessionAction::PasteRotate {
+                counter_clockwise: *counter_clockwise,
+            })
+        }
+        AppIntent::DuplicateSelectionRequested => Some(HostSessionAction::DuplicateSelection),
         AppIntent::OpenTraceAllFieldsDialogRequested => {
             Some(HostSessionAction::OpenTraceAllFieldsDialog)
         }
@@ -723,6 +836,71 @@ pub fn map_intent_to_host_action(intent: &AppIntent) -> Option<HostSessionAction
             corner_rounding_max_angle_deg: *corner_rounding_max_angle_deg,
         }),
         AppIntent::TraceAllFieldsCancelled => Some(HostSessionAction::CancelTraceAllFields),
+        AppIntent::OpenFieldCourseDialogRequested => Some(HostSessionAction::OpenFieldCourseDialog),
+        AppIntent::FieldCourseConfirmed {
+            field_id,
+            spacing,
+            headland_offset,
+        } => Some(HostSessionAction::ConfirmFieldCourse {
+            field_id: *field_id,
+            spacing: *spacing,
+            headland_offset: *headland_offset,
+        }),
+        AppIntent::FieldCourseCancelled => Some(HostSessionAction::CancelFieldCourse),
+        AppIntent::OpenMoveSelectionDialogRequested => {
+            Some(HostSessionAction::OpenMoveSelectionDialog)
+        }
+        AppIntent::MoveSelectionConfirmed { dx, dy, dz } => {
+            Some(HostSessionAction::ConfirmMoveSelection {
+                dx: *dx,
+                dy: *dy,
+                dz: *dz,
+            })
+        }
+        AppIntent::NudgeSelectionRequested { delta } => Some(HostSessionAction::NudgeSelection {
+            dx: delta.x,
+            dz: delta.y,
+        }),
+        AppIntent::MoveSelectionCancelled => Some(HostSessionAction::CancelMoveSelection),
+        AppIntent::OpenDeleteByFilterDialogRequested => {
+            Some(HostSessionAction::OpenDeleteByFilterDialog)
+        }
+        AppIntent::DeleteByFilterConfirmed { filter } => {
+            Some(HostSessionAction::ConfirmDeleteByFilter {
+                only_subprio: filter.only_subprio,
+                only_unconnected: filter.only_unconnected,
+                only_without_markers: filter.only_without_markers,
+            })
+        }
+        AppIntent::DeleteByFilterCancelled => Some(HostSessionAction::CancelDeleteByFilter),
+        AppIntent::LiveCourseRecordingToggled => Some(HostSessionAction::ToggleLiveCourseRecording),
+        AppIntent::LiveNetworkPushRequested => Some(HostSessionAction::PushNetworkToGame),
+        AppIntent::RoutesDialogRequested => Some(HostSessionAction::OpenRoutesDialog),
+        AppIntent::StoredRouteImportRequested { file_name } => {
+            Some(HostSessionAction::ImportStoredRoute {
+                file_name: file_name.clone(),
+            })
+        }
+        AppIntent::StoredRouteExportRequested { name } => {
+            Some(HostSessionAction::ExportStoredRoute { name: name.clone() })
+        }
+        AppIntent::RoutesDialogCancelled => Some(HostSessionAction::CancelRoutesDialog),
+        AppIntent::SavegamePickerRequested => Some(HostSessionAction::OpenSavegamePicker),
+        AppIntent::SavegameOpenRequested { path } => {
+            Some(HostSessionAction::OpenSavegame { path: path.clone() })
+        }
+        AppIntent::SavegamePickerCancelled => Some(HostSessionAction::CancelSavegamePicker),
+        AppIntent::MapModZipExportRequested => Some(HostSessionAction::MapModZipExport),
+        AppIntent::CoursesModExportRequested => Some(HostSessionAction::CoursesModExport),
+        AppIntent::OpenPdfExportDialogRequested => Some(HostSessionAction::OpenPdfExportDialog),
+        AppIntent::PdfExportConfirmed { options } => Some(HostSessionAction::ConfirmPdfExport {
+            paper: options.paper.into(),
+            landscape: options.landscape,
+            scale_denominator: options.scale_denominator,
+            show_grid: options.show_grid,
+            grid_spacing_m: options.grid_spacing_m,
+        }),
+        AppIntent::PdfExportCancelled => Some(HostSessionAction::CancelPdfExport),
         _ => None,
     }
 }
@@ -752,6 +930,9 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
         HostSessionAction::GenerateOverviewFromZip { path } => {
             Some(AppIntent::GenerateOverviewFromZip { path })
         }
+        HostSessionAction::ExtractHeightmapFromZip { path } => {
+            Some(AppIntent::ExtractHeightmapFromZipRequested { path })
+        }
         HostSessionAction::SelectZipBackgroundFile {
             zip_path,
             entry_name,
@@ -783,6 +964,9 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
         HostSessionAction::SetRenderQuality { quality } => {
             Some(AppIntent::RenderQualityChanged { quality })
         }
+        HostSessionAction::ToggleIsolateSelection => {
+            Some(AppIntent::ToggleIsolateSelectionRequested)
+        }
         HostSessionAction::ToggleBackgroundVisibility => {
             Some(AppIntent::ToggleBackgroundVisibility)
         }
@@ -795,6 +979,9 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
         HostSessionAction::ScaleBackground { factor } => {
             Some(AppIntent::ScaleBackground { factor })
         }
+        HostSessionAction::SetBackgroundSource { source } => {
+            Some(AppIntent::BackgroundSourceSelected { source })
+        }
         HostSessionAction::ZoomToSelectionBounds => Some(AppIntent::ZoomToSelectionBoundsRequested),
         HostSessionAction::Exit => Some(AppIntent::ExitRequested),
         HostSessionAction::ToggleCommandPalette => Some(AppIntent::CommandPaletteToggled),
@@ -844,6 +1031,9 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
             end_id,
             priority: map_host_connection_priority(priority),
         }),
+        HostSessionAction::InvertConnection { start_id, end_id } => {
+            Some(AppIntent::InvertConnectionRequested { start_id, end_id })
+        }
         HostSessionAction::ConnectSelectedNodes => Some(AppIntent::ConnectSelectedNodesRequested),
         HostSessionAction::SetAllConnectionsDirectionBetweenSelected { direction } => Some(
             AppIntent::SetAllConnectionsDirectionBetweenSelectedRequested {
@@ -853,6 +1043,9 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
         HostSessionAction::InvertAllConnectionsBetweenSelected => {
             Some(AppIntent::InvertAllConnectionsBetweenSelectedRequested)
         }
+        HostSessionAction::ReversePathBetweenSelected => {
+            Some(AppIntent::ReversePathBetweenSelectedRequested)
+        }
         HostSessionAction::SetAllConnectionsPriorityBetweenSelected { priority } => Some(
             AppIntent::SetAllConnectionsPriorityBetweenSelectedRequested {
                 priority: map_host_connection_priority(priority),
@@ -875,6 +1068,14 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
                 flag: map_host_node_flag(flag),
             })
         }
+        HostSessionAction::SetSelectedNodeHeights { edit } => {
+            Some(AppIntent::SetSelectedNodeHeightsRequested {
+                edit: (&edit).into(),
+            })
+        }
+        HostSessionAction::ProjectSelectionOntoTerrain { offset } => {
+            Some(AppIntent::ProjectSelectionOntoTerrainRequested { offset })
+        }
         HostSessionAction::OpenCreateMarkerDialog { node_id } => {
             Some(AppIntent::CreateMarkerRequested { node_id })
         }
@@ -908,9 +1109,52 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
         HostSessionAction::DeleteSelected => Some(AppIntent::DeleteSelectedRequested),
         HostSessionAction::SelectAll => Some(AppIntent::SelectAllRequested),
         HostSessionAction::InvertSelection => Some(AppIntent::InvertSelectionRequested),
+        HostSessionAction::GrowSelection => Some(AppIntent::GrowSelectionRequested),
+        HostSessionAction::ShrinkSelection => Some(AppIntent::ShrinkSelectionRequested),
+        HostSessionAction::SelectPathBetweenSelected => {
+            Some(AppIntent::SelectPathBetweenSelectedRequested)
+        }
+        HostSessionAction::SelectByConnectionPriority { priority } => {
+            Some(AppIntent::SelectByConnectionPriorityRequested {
+                priority: map_host_connection_priority(priority),
+            })
+        }
+        HostSessionAction::SetSelectionLocked { locked } => {
+            Some(AppIntent::SetSelectionLockedRequested { locked })
+        }
         HostSessionAction::ClearSelection => Some(AppIntent::ClearSelectionRequested),
         HostSessionAction::StartResampleSelection => Some(AppIntent::StreckenteilungAktivieren),
         HostSessionAction::ApplyCurrentResample => Some(AppIntent::ResamplePathRequested),
+        HostSessionAction::EqualizeChainSpacing => Some(AppIntent::EqualizeChainSpacingRequested),
+        HostSessionAction::ResampleSelectedChain { spacing } => {
+            Some(AppIntent::ResampleChainRequested { spacing })
+        }
+        HostSessionAction::SmoothSelectedChain {
+            strength,
+            iterations,
+        } => Some(AppIntent::SmoothChainRequested {
+            strength,
+            iterations,
+        }),
+        HostSessionAction::SimplifySelectedChain { tolerance } => {
+            Some(AppIntent::SimplifyChainRequested { tolerance })
+        }
+        HostSessionAction::AlignSelectedNodes { mode } => {
+            Some(AppIntent::AlignSelectedRequested { mode: mode.into() })
+        }
+        HostSessionAction::DistributeSelectedNodes => Some(AppIntent::DistributeSelectedRequested),
+        HostSessionAction::JoinSelectedChains => Some(AppIntent::JoinSelectedChainsRequested),
+        HostSessionAction::MergeSelectedNodes => Some(AppIntent::MergeSelectedNodesRequested),
+        HostSessionAction::ReverseSelectedChain => Some(AppIntent::ReverseChainRequested),
+        HostSessionAction::StartPathOffset => Some(AppIntent::PathOffsetPreviewRequested),
+        HostSessionAction::SetPathOffsetDistance { distance } => {
+            Some(AppIntent::PathOffsetDistanceChanged { distance })
+        }
+        HostSessionAction::ApplyPathOffset => Some(AppIntent::PathOffsetConfirmed),
+        HostSessionAction::CancelPathOffset => Some(AppIntent::PathOffsetCancelled),
+        HostSessionAction::ConvertJunctionToRoundabout => {
+            Some(AppIntent::ConvertJunctionToRoundaboutRequested)
+        }
         HostSessionAction::StartGroupEdit { record_id } => {
             Some(AppIntent::GroupEditStartRequested { record_id })
         }
@@ -939,6 +1183,13 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
             world_pos: Vec2::new(world_pos[0], world_pos[1]),
             additive,
         }),
+        HostSessionAction::SelectConnected {
+            world_pos,
+            additive,
+        } => Some(AppIntent::SelectConnectedRequested {
+            world_pos: Vec2::new(world_pos[0], world_pos[1]),
+            additive,
+        }),
         HostSessionAction::ToggleGroupLock { segment_id } => {
             Some(AppIntent::ToggleGroupLockRequested { segment_id })
         }
@@ -950,8 +1201,15 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
         }
         HostSessionAction::CopySelection => Some(AppIntent::CopySelectionRequested),
         HostSessionAction::PasteStart => Some(AppIntent::PasteStartRequested),
+        HostSessionAction::PasteFromText { text } => {
+            Some(AppIntent::PasteFromTextRequested { text })
+        }
         HostSessionAction::PasteConfirm => Some(AppIntent::PasteConfirmRequested),
         HostSessionAction::PasteCancel => Some(AppIntent::PasteCancelled),
+        HostSessionAction::PasteRotate { counter_clockwise } => {
+            Some(AppIntent::PastePreviewRotateRequested { counter_clockwise })
+        }
+        HostSessionAction::DuplicateSelection => Some(AppIntent::DuplicateSelectionRequested),
         HostSessionAction::OpenTraceAllFieldsDialog => {
             Some(AppIntent::OpenTraceAllFieldsDialogRequested)
         }
@@ -971,6 +1229,74 @@ pub fn map_host_action_to_intent(action: HostSessionAction) -> Option<AppIntent>
             corner_rounding_max_angle_deg,
         }),
         HostSessionAction::CancelTraceAllFields => Some(AppIntent::TraceAllFieldsCancelled),
+        HostSessionAction::OpenFieldCourseDialog => Some(AppIntent::OpenFieldCourseDialogRequested),
+        HostSessionAction::ConfirmFieldCourse {
+            field_id,
+            spacing,
+            headland_offset,
+        } => Some(AppIntent::FieldCourseConfirmed {
+            field_id,
+            spacing,
+            headland_offset,
+        }),
+        HostSessionAction::CancelFieldCourse => Some(AppIntent::FieldCourseCancelled),
+        HostSessionAction::OpenMoveSelectionDialog => {
+            Some(AppIntent::OpenMoveSelectionDialogRequested)
+        }
+        HostSessionAction::ConfirmMoveSelection { dx, dy, dz } => {
+            Some(AppIntent::MoveSelectionConfirmed { dx, dy, dz })
+        }
+        HostSessionAction::NudgeSelection { dx, dz } => Some(AppIntent::NudgeSelectionRequested {
+            delta: Vec2::new(dx, dz),
+        }),
+        HostSessionAction::CancelMoveSelection => Some(AppIntent::MoveSelectionCancelled),
+        HostSessionAction::OpenDeleteByFilterDialog => {
+            Some(AppIntent::OpenDeleteByFilterDialogRequested)
+        }
+        HostSessionAction::ConfirmDeleteByFilter {
+            only_subprio,
+            only_unconnected,
+            only_without_markers,
+        } => Some(AppIntent::DeleteByFilterConfirmed {
+            filter: NodeDeleteFilter {
+                only_subprio,
+                only_unconnected,
+                only_without_markers,
+            },
+        }),
+        HostSessionAction::CancelDeleteByFilter => Some(AppIntent::DeleteByFilterCancelled),
+        HostSessionAction::ToggleLiveCourseRecording => Some(AppIntent::LiveCourseRecordingToggled),
+        HostSessionAction::PushNetworkToGame => Some(AppIntent::LiveNetworkPushRequested),
+        HostSessionAction::OpenRoutesDialog => Some(AppIntent::RoutesDialogRequested),
+        HostSessionAction::ImportStoredRoute { file_name } => {
+            Some(AppIntent::StoredRouteImportRequested { file_name })
+        }
+        HostSessionAction::ExportStoredRoute { name } => {
+            Some(AppIntent::StoredRouteExportRequested { name })
+        }
+        HostSessionAction::CancelRoutesDialog => Some(AppIntent::RoutesDialogCancelled),
+        HostSessionAction::OpenSavegamePicker => Some(AppIntent::SavegamePickerRequested),
+        HostSessionAction::OpenSavegame { path } => Some(AppIntent::SavegameOpenRequested { path }),
+        HostSessionAction::CancelSavegamePicker => Some(AppIntent::SavegamePickerCancelled),
+        HostSessionAction::MapModZipExport => Some(AppIntent::MapModZipExportRequested),
+        HostSessionAction::CoursesModExport => Some(AppIntent::CoursesModExportRequested),
+        HostSessionAction::OpenPdfExportDialog => Some(AppIntent::OpenPdfExportDialogRequested),
+        HostSessionAction::ConfirmPdfExport {
+            paper,
+            landscape,
+            scale_denominator,
+            show_grid,
+            grid_spacing_m,
+        } => Some(AppIntent::PdfExportConfirmed {
+            options: PdfExportOptions {
+                paper: paper.into(),
+                landscape,
+                scale_denominator,
+                show_grid,
+                grid_spacing_m,
+            },
+        }),
+        HostSessionAction::CancelPdfExport => Some(AppIntent::PdfExportCancelled),
         HostSessionAction::SubmitViewportInput { .. } => None,
         HostSessionAction::SubmitDialogResult { result } => {
             dialog_result_to_intent(map_dialog_result(result))
@@ -166,6 +166,18 @@ pub fn build_host_chrome_snapshot(state: &AppState) -> HostChromeSnapshot {
         .unwrap_or((0, 0, 0, None));
     let selection_count = state.selection.selected_node_ids.len();
     let selection_example_id = state.selection.selected_node_ids.iter().next().copied();
+    let (selection_connected_length, selection_bounds_size) = state
+        .road_map
+        .as_deref()
+        .filter(|_| selection_count > 0)
+        .map(|rm| {
+            let selected = &state.selection.selected_node_ids;
+            let bounds = rm
+                .bounds_of_ids(selected)
+                .map(|(min, max)| (max - min).to_array());
+            (rm.connection_length_between_ids(selected), bounds)
+        })
+        .unwrap_or((0.0, None));
     let background_layer_entries = build_background_layer_entries(state);
     HostChromeSnapshot {
         status_message: state.ui.status_message.clone(),
@@ -192,13 +204,19 @@ pub fn build_host_chrome_snapshot(state: &AppState) -> HostChromeSnapshot {
         heightmap_path: state.ui.heightmap_path.clone(),
         selection_count,
         selection_example_id,
+        selection_connected_length,
+        selection_bounds_size,
         background_map_loaded: state.view.background_map.is_some(),
         render_quality: state.view.render_quality,
         has_farmland: state.has_farmland_polygons(),
         background_visible: state.view.background_visible,
         background_scale: state.view.background_scale,
+        background_source: state.view.background_source,
         background_layers_available: state.background_layers.is_some(),
         background_layer_entries,
+        live_link_connected: false,
+        live_recording: state.live_link.recording,
+        isolation_active: state.view.is_isolation_active(),
     }
 }
 
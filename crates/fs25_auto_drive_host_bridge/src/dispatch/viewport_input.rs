@@ -1,6 +1,7 @@
 //! Viewport-Input-Zustand und Gesture-Seam fuer Rust-Hosts.
 
 use anyhow::{bail, Result};
+use fs25_auto_drive_engine::app::tools::snap_to_grid;
 use fs25_auto_drive_engine::app::{AppController, AppIntent, AppState, EditorTool};
 use glam::Vec2;
 
@@ -12,7 +13,20 @@ use crate::dto::{
 #[derive(Debug, Clone, PartialEq)]
 pub(super) enum HostViewportDragKind {
     CameraPan,
-    SelectionMove,
+    /// Verschieben der Selektion; der gegriffene Node fuehrt beim Grid-Snap.
+    SelectionMove {
+        grab_node_id: u64,
+        grab_offset: Vec2,
+    },
+    /// Wischgeste entlang einer Verbindung (Richtung = Zugrichtung).
+    ConnectionSwipe {
+        start_world: Vec2,
+        toggle_dual: bool,
+    },
+    /// Messer-Linie fuer das Knife-Tool (Start in Weltkoordinaten).
+    KnifeCut {
+        start_world: Vec2,
+    },
     RectSelection {
         start_screen: [f32; 2],
         additive: bool,
@@ -144,6 +158,16 @@ fn apply_primary_tap(
             )?;
             Ok(true)
         }
+        // Das Messer reagiert nur auf Drags
+        EditorTool::Knife => Ok(false),
+        EditorTool::Measure => {
+            apply_intent(
+                controller,
+                state,
+                AppIntent::MeasurePointAdded { world_pos },
+            )?;
+            Ok(true)
+        }
         EditorTool::Route => {
             if state
                 .editor
@@ -166,14 +190,19 @@ fn apply_primary_double_tap(
     modifiers: HostInputModifiers,
 ) -> Result<bool> {
     let world_pos = screen_pos_to_world(&state.view.camera, viewport_size, screen_pos)?;
-    apply_intent(
-        controller,
-        state,
+    // Shift + Doppelklick selektiert alles Erreichbare statt nur das Segment
+    let intent = if modifiers.shift {
+        AppIntent::SelectConnectedRequested {
+            world_pos,
+            additive: modifiers.command,
+        }
+    } else {
         AppIntent::NodeSegmentBetweenIntersectionsRequested {
             world_pos,
             additive: modifiers.command,
-        },
-    )?;
+        }
+    };
+    apply_intent(controller, state, intent)?;
     Ok(true)
 }
 
@@ -211,6 +240,17 @@ fn start_primary_drag(
         return Ok(true);
     }
 
+    if state.editor.active_tool == EditorTool::Knife {
+        let viewport_size = input_state.effective_viewport_size(state);
+        let start_world = screen_pos_to_world(&state.view.camera, viewport_size, screen_pos)?;
+        input_state.active_drag = Some(HostViewportDragState {
+            button: HostPointerButton::Primary,
+            latest_screen: screen_pos,
+            kind: HostViewportDragKind::KnifeCut { start_world },
+        });
+        return Ok(true);
+    }
+
     if state.editor.active_tool == EditorTool::Select && modifiers.shift {
         input_state.active_drag = Some(HostViewportDragState {
             button: HostPointerButton::Primary,
@@ -236,6 +276,11 @@ fn start_primary_drag(
             .filter(|hit| hit.distance <= move_max_distance);
 
         if let Some(hit) = hit {
+            let grab_offset = state
+                .road_map
+                .as_deref()
+                .and_then(|road_map| road_map.node_position(hit.node_id))
+                .map_or(Vec2::ZERO, |node_pos| node_pos - world_pos);
             let already_selected = state.selection.selected_node_ids.contains(&hit.node_id);
             if !already_selected {
                 apply_intent(
@@ -257,7 +302,28 @@ fn start_primary_drag(
             input_state.active_drag = Some(HostViewportDragState {
                 button: HostPointerButton::Primary,
                 latest_screen: screen_pos,
-                kind: HostViewportDragKind::SelectionMove,
+                kind: HostViewportDragKind::SelectionMove {
+                    grab_node_id: hit.node_id,
+                    grab_offset,
+                },
+            });
+            return Ok(true);
+        }
+
+        // Drag auf einer Verbindung (abseits von Nodes) = Richtungs-Wischgeste
+        let connection_hit = state.road_map.as_ref().is_some_and(|road_map| {
+            road_map
+                .nearest_connection(world_pos, base_max_distance)
+                .is_some()
+        });
+        if connection_hit {
+            input_state.active_drag = Some(HostViewportDragState {
+                button: HostPointerButton::Primary,
+                latest_screen: screen_pos,
+                kind: HostViewportDragKind::ConnectionSwipe {
+                    start_world: world_pos,
+                    toggle_dual: modifiers.command,
+                },
             });
             return Ok(true);
         }
@@ -357,13 +423,31 @@ fn apply_viewport_input_event(
                     )?;
                     Ok(true)
                 }
-                HostViewportDragKind::SelectionMove => {
+                HostViewportDragKind::SelectionMove {
+                    grab_node_id,
+                    grab_offset,
+                } => {
                     if state.selection.selected_node_ids.is_empty() {
                         return Ok(false);
                     }
 
-                    let delta_world =
-                        delta_px_to_world(&state.view.camera, viewport_size, delta_px)?;
+                    let delta_world = if state.options.grid_snap_enabled {
+                        // Gegriffenen Node auf das Raster unter dem Cursor setzen
+                        let Some(current) = state
+                            .road_map
+                            .as_deref()
+                            .and_then(|road_map| road_map.node_position(*grab_node_id))
+                        else {
+                            return Ok(false);
+                        };
+                        let cursor_world =
+                            screen_pos_to_world(&state.view.camera, viewport_size, screen_pos)?;
+                        let target =
+                            snap_to_grid(cursor_world + *grab_offset, state.options.grid_snap_size);
+                        target - current
+                    } else {
+                        delta_px_to_world(&state.view.camera, viewport_size, delta_px)?
+                    };
                     apply_intent(
                         controller,
                         state,
@@ -371,7 +455,9 @@ fn apply_viewport_input_event(
                     )?;
                     Ok(true)
                 }
-                HostViewportDragKind::RectSelection { .. } => Ok(true),
+                HostViewportDragKind::ConnectionSwipe { .. }
+                | HostViewportDragKind::KnifeCut { .. }
+                | HostViewportDragKind::RectSelection { .. } => Ok(true),
                 HostViewportDragKind::LassoSelection { points_screen, .. } => {
                     push_lasso_point(points_screen, screen_pos);
                     Ok(true)
@@ -392,10 +478,38 @@ fn apply_viewport_input_event(
 
             match active_drag.kind {
                 HostViewportDragKind::CameraPan => Ok(true),
-                HostViewportDragKind::SelectionMove => {
+                HostViewportDragKind::SelectionMove { .. } => {
                     apply_intent(controller, state, AppIntent::EndMoveSelectedNodesRequested)?;
                     Ok(true)
                 }
+                HostViewportDragKind::ConnectionSwipe {
+                    start_world,
+                    toggle_dual,
+                } => {
+                    let to = screen_pos_to_world(&state.view.camera, viewport_size, final_screen)?;
+                    apply_intent(
+                        controller,
+                        state,
+                        AppIntent::ConnectionSwipeRequested {
+                            from: start_world,
+                            to,
+                            toggle_dual,
+                        },
+                    )?;
+                    Ok(true)
+                }
+                HostViewportDragKind::KnifeCut { start_world } => {
+                    let to = screen_pos_to_world(&state.view.camera, viewport_size, final_screen)?;
+                    apply_intent(
+                        controller,
+                        state,
+                        AppIntent::KnifeCutRequested {
+                            from: start_world,
+                            to,
+                        },
+                    )?;
+                    Ok(true)
+                }
                 HostViewportDragKind::RectSelection {
                     start_screen,
                     additive,
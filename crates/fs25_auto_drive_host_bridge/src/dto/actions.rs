@@ -1,11 +1,12 @@
 //! Stabiler Aktions-Satz fuer die kanonische Session-Surface der Host-Bridge.
 
 use fs25_auto_drive_engine::app::ui_contract::RouteToolPanelAction;
-use fs25_auto_drive_engine::shared::{EditorOptions, RenderQuality};
+use fs25_auto_drive_engine::shared::{BackgroundSource, EditorOptions, RenderQuality};
 use serde::{Deserialize, Serialize};
 
 use super::chrome::HostBackgroundLayerKind;
-use super::dialogs::HostDialogResult;
+use super::dialogs::{HostDialogResult, HostPdfPaperSize};
+use super::editing::{HostNodeAlignMode, HostNodeHeightEdit};
 use super::input::HostViewportInputBatch;
 use super::node_details::HostNodeFlag;
 use super::route_tool::{HostDefaultConnectionDirection, HostDefaultConnectionPriority};
@@ -20,6 +21,10 @@ pub enum HostActiveTool {
     Connect,
     /// Neue Nodes auf der Karte platzieren.
     AddNode,
+    /// Verbindungen entlang einer gezogenen Linie schneiden.
+    Knife,
+    /// Strecken und Kurse per Klick messen (Lineal, erzeugt keine Nodes).
+    Measure,
     /// Route-Tools (Linie, Parkplatz, Kurve, ...).
     Route,
 }
@@ -143,6 +148,11 @@ pub enum HostSessionAction {
         /// Pfad zur gewaehlten ZIP-Datei.
         path: String,
     },
+    /// Extrahiert das DEM eines Map-Mod-ZIPs als Heightmap neben die Config.
+    ExtractHeightmapFromZip {
+        /// Pfad zur gewaehlten ZIP-Datei.
+        path: String,
+    },
     /// Uebernimmt eine Bilddatei aus dem ZIP-Browser als Background-Map.
     SelectZipBackgroundFile {
         /// Pfad zur ZIP-Datei.
@@ -188,6 +198,8 @@ pub enum HostSessionAction {
         /// Ziel-Qualitaet fuer das Rendering.
         quality: RenderQuality,
     },
+    /// Schaltet den Isolier-Modus um (alle Nodes ausser der Selektion ausblenden).
+    ToggleIsolateSelection,
     /// Schaltet die Sichtbarkeit der Background-Map um.
     ToggleBackgroundVisibility,
     /// Setzt die Sichtbarkeit eines gespeicherten Hintergrund-Layers.
@@ -202,6 +214,11 @@ pub enum HostSessionAction {
         /// Relativer Skalierungsfaktor.
         factor: f32,
     },
+    /// Schaltet den Hintergrund zwischen Overview und Heightmap-Relief um.
+    SetBackgroundSource {
+        /// Neue Hintergrundquelle.
+        source: BackgroundSource,
+    },
     /// Passt den Viewport auf die aktuelle Selektion ein.
     ZoomToSelectionBounds,
     /// Beendet die Anwendung.
@@ -264,6 +281,13 @@ pub enum HostSessionAction {
         /// Neue Prioritaet der Verbindung.
         priority: HostDefaultConnectionPriority,
     },
+    /// Invertiert eine einzelne Verbindung (start ⇔ end).
+    InvertConnection {
+        /// Start-Node-ID der Verbindung.
+        start_id: u64,
+        /// End-Node-ID der Verbindung.
+        end_id: u64,
+    },
     /// Verbindet die aktuell selektierten Nodes mit den Standard-Defaults.
     ConnectSelectedNodes,
     /// Setzt die Richtung aller Verbindungen zwischen den selektierten Nodes.
@@ -273,6 +297,8 @@ pub enum HostSessionAction {
     },
     /// Invertiert alle Verbindungen zwischen den selektierten Nodes.
     InvertAllConnectionsBetweenSelected,
+    /// Kehrt den selektierten Pfad um (Einbahn-Verbindungen spiegeln, Reverse/Dual erhalten).
+    ReversePathBetweenSelected,
     /// Setzt die Prioritaet aller Verbindungen zwischen den selektierten Nodes.
     SetAllConnectionsPriorityBetweenSelected {
         /// Neue Prioritaet fuer alle betroffenen Verbindungen.
@@ -307,6 +333,16 @@ pub enum HostSessionAction {
         /// Neuer Flag-Wert.
         flag: HostNodeFlag,
     },
+    /// Setzt, verschiebt oder interpoliert die Y-Werte aller selektierten Nodes.
+    SetSelectedNodeHeights {
+        /// Art der Y-Wert-Aenderung.
+        edit: HostNodeHeightEdit,
+    },
+    /// Projiziert die selektierten Nodes auf das Gelaende der geladenen Heightmap.
+    ProjectSelectionOntoTerrain {
+        /// Versatz ueber dem Gelaende in Metern.
+        offset: f32,
+    },
     /// Oeffnet den Marker-Erstellen-Dialog fuer einen Node.
     OpenCreateMarkerDialog {
         /// Node-ID.
@@ -348,12 +384,73 @@ pub enum HostSessionAction {
     SelectAll,
     /// Invertiert die aktuelle Auswahl.
     InvertSelection,
+    /// Erweitert die Auswahl um alle direkten Nachbarn.
+    GrowSelection,
+    /// Verkleinert die Auswahl um ihre Randnodes.
+    ShrinkSelection,
+    /// Fuegt den kuerzesten Pfad zwischen den zwei selektierten Nodes hinzu.
+    SelectPathBetweenSelected,
+    /// Selektiert alle Nodes an Verbindungen der gegebenen Prioritaet.
+    SelectByConnectionPriority {
+        /// Haupt- oder Nebenstrasse.
+        priority: HostDefaultConnectionPriority,
+    },
+    /// Sperrt (`locked = true`) oder entsperrt die selektierten Nodes.
+    SetSelectionLocked {
+        /// Ziel-Zustand der Sperre.
+        locked: bool,
+    },
     /// Hebt die aktuelle Selektion auf.
     ClearSelection,
     /// Aktiviert das Streckenteilungs-/Resample-Panel.
     StartResampleSelection,
     /// Wendet die aktuelle Streckenteilungs-Konfiguration auf die Selektion an.
     ApplyCurrentResample,
+    /// Verteilt die Nodes der selektierten Kette auf gleiche Abstaende (Form bleibt erhalten).
+    EqualizeChainSpacing,
+    /// Baut die selektierte Kette mit neuem Node-Abstand neu auf (Endpunkte bleiben angeschlossen).
+    ResampleSelectedChain {
+        /// Gewuenschter Node-Abstand in Metern.
+        spacing: f32,
+    },
+    /// Glaettet die inneren Nodes der selektierten Kette (Endpunkte bleiben fest).
+    SmoothSelectedChain {
+        /// Glaettungsstaerke pro Durchgang (0..=1).
+        strength: f32,
+        /// Anzahl der Glaettungs-Durchgaenge.
+        iterations: u32,
+    },
+    /// Duennt die selektierte Kette per Douglas-Peucker aus (Form bleibt erhalten).
+    SimplifySelectedChain {
+        /// Maximal zulaessige Abweichung in Metern.
+        tolerance: f32,
+    },
+    /// Richtet die selektierten Nodes auf einer gemeinsamen Geraden aus.
+    AlignSelectedNodes {
+        /// Zielgerade der Ausrichtung.
+        mode: HostNodeAlignMode,
+    },
+    /// Verteilt die selektierten Nodes gleichmaessig zwischen den Endpunkten.
+    DistributeSelectedNodes,
+    /// Verbindet zwei selektierte Ketten per Spline-Uebergang zwischen den naechsten Enden.
+    JoinSelectedChains,
+    /// Fuehrt die selektierten Nodes im Schwerpunkt zu einem Node zusammen.
+    MergeSelectedNodes,
+    /// Kehrt alle Verbindungen der selektierten Kette um (Start wird Ende).
+    ReverseSelectedChain,
+    /// Startet die Vorschau fuer den seitlichen Versatz der selektierten Kette.
+    StartPathOffset,
+    /// Setzt den seitlichen Versatz der laufenden Vorschau.
+    SetPathOffsetDistance {
+        /// Versatz in Metern (positiv = links in Kettenrichtung).
+        distance: f32,
+    },
+    /// Verschiebt die erfasste Kette um den eingestellten Versatz.
+    ApplyPathOffset,
+    /// Verwirft die Versatz-Vorschau.
+    CancelPathOffset,
+    /// Wandelt den selektierten Kreuzungs-Node in einen Kreisverkehr um.
+    ConvertJunctionToRoundabout,
     /// Startet den nicht-destruktiven Gruppen-Edit-Modus fuer einen Record.
     StartGroupEdit {
         /// ID des zu bearbeitenden Gruppen-Records.
@@ -388,6 +485,13 @@ pub enum HostSessionAction {
         /// Erweitert eine bestehende Selektion statt sie zu ersetzen.
         additive: bool,
     },
+    /// Selektiert alle vom Node an einer Weltposition erreichbaren Nodes.
+    SelectConnected {
+        /// Weltposition der Anfrage.
+        world_pos: [f32; 2],
+        /// Erweitert eine bestehende Selektion statt sie zu ersetzen.
+        additive: bool,
+    },
     /// Schaltet den Lock-Status einer Gruppe um.
     ToggleGroupLock {
         /// ID des Segment-/Gruppen-Records.
@@ -407,10 +511,22 @@ pub enum HostSessionAction {
     CopySelection,
     /// Startet den Paste-Modus mit Vorschau.
     PasteStart,
+    /// Startet den Paste-Modus mit dem Text der System-Zwischenablage (Editor-JSON).
+    PasteFromText {
+        /// Inhalt der System-Zwischenablage.
+        text: String,
+    },
     /// Bestaetigt die Paste-Operation an der aktuellen Position.
     PasteConfirm,
     /// Bricht den Paste-Modus ab.
     PasteCancel,
+    /// Dreht die Paste-Vorschau um einen Schritt.
+    PasteRotate {
+        /// `true` dreht gegen den Uhrzeigersinn, sonst im Uhrzeigersinn.
+        counter_clockwise: bool,
+    },
+    /// Dupliziert die Selektion und startet die interaktive Platzierung der Kopie.
+    DuplicateSelection,
     /// Oeffnet den Dialog fuer das Nachzeichnen aller Felder.
     OpenTraceAllFieldsDialog,
     /// Bestaetigt das Nachzeichnen aller Felder mit den aktuellen Dialogwerten.
@@ -430,6 +546,100 @@ pub enum HostSessionAction {
     },
     /// Bricht den Dialog fuer das Nachzeichnen aller Felder ab.
     CancelTraceAllFields,
+    /// Oeffnet den Dialog fuer den Feld-Rundkurs.
+    OpenFieldCourseDialog,
+    /// Erzeugt den Feld-Rundkurs mit den aktuellen Dialogwerten.
+    ConfirmFieldCourse {
+        /// Farmland-ID des Feldes.
+        field_id: u32,
+        /// Abstand zwischen Pfadpunkten.
+        spacing: f32,
+        /// Abstand des Vorgewende-Rings vom Feldrand nach innen.
+        headland_offset: f32,
+    },
+    /// Bricht den Dialog fuer den Feld-Rundkurs ab.
+    CancelFieldCourse,
+    /// Oeffnet den Dialog "Selektion verschieben um...".
+    OpenMoveSelectionDialog,
+    /// Verschiebt die Selektion um einen exakten Versatz.
+    ConfirmMoveSelection {
+        /// Versatz entlang X in Metern.
+        dx: f32,
+        /// Versatz der Hoehe (Y-Wert) in Metern.
+        dy: f32,
+        /// Versatz entlang Z in Metern.
+        dz: f32,
+    },
+    /// Verschiebt die Selektion ohne Dialog um einen Versatz in der Kartenebene.
+    NudgeSelection {
+        /// Versatz entlang X in Metern.
+        dx: f32,
+        /// Versatz entlang Z in Metern.
+        dz: f32,
+    },
+    /// Bricht den Dialog "Selektion verschieben um..." ab.
+    CancelMoveSelection,
+    /// Oeffnet den Dialog "Loeschen nach Filter..." fuer die aktuelle Selektion.
+    OpenDeleteByFilterDialog,
+    /// Loescht die Nodes der Selektion, die alle aktiven Filter erfuellen.
+    ConfirmDeleteByFilter {
+        /// Nur Nodes mit Flag `SubPrio` loeschen.
+        only_subprio: bool,
+        /// Nur Nodes ohne Verbindungen loeschen.
+        only_unconnected: bool,
+        /// Nur Nodes ohne Map-Marker loeschen.
+        only_without_markers: bool,
+    },
+    /// Bricht den Dialog "Loeschen nach Filter..." ab.
+    CancelDeleteByFilter,
+    /// Startet bzw. beendet das Aufzeichnen eines Kurses waehrend der Fahrt im Spiel.
+    ToggleLiveCourseRecording,
+    /// Speichert das Netz und fordert das laufende Spiel zum Neuladen auf.
+    PushNetworkToGame,
+    /// Oeffnet den Dialog fuer den AutoDrive-Routes-Manager-Ordner.
+    OpenRoutesDialog,
+    /// Importiert eine gespeicherte Route als aktuelles Netz.
+    ImportStoredRoute {
+        /// Dateiname der Route im `routes/`-Unterordner.
+        file_name: String,
+    },
+    /// Exportiert das aktuelle Netz als gespeicherte Route.
+    ExportStoredRoute {
+        /// Anzeigename der Route im Routes-Manager.
+        name: String,
+    },
+    /// Schliesst den Routes-Manager-Dialog ohne Aktion.
+    CancelRoutesDialog,
+    /// Oeffnet die Savegame-Auswahl im FS25-Profil.
+    OpenSavegamePicker,
+    /// Oeffnet die AutoDrive-Config eines Savegames aus der Auswahl.
+    OpenSavegame {
+        /// Pfad der `AutoDrive_config.xml` des Savegames.
+        path: String,
+    },
+    /// Schliesst die Savegame-Auswahl ohne Aktion.
+    CancelSavegamePicker,
+    /// Fordert die Auswahl eines Map-Mod-ZIPs fuer den Standard-Config-Export an.
+    MapModZipExport,
+    /// Fordert den Speicherdialog fuer einen eigenstaendigen Kurs-Mod an.
+    CoursesModExport,
+    /// Oeffnet den Dialog "Als PDF exportieren...".
+    OpenPdfExportDialog,
+    /// Uebernimmt die PDF-Einstellungen und fordert den Speicherdialog an.
+    ConfirmPdfExport {
+        /// Papierformat.
+        paper: HostPdfPaperSize,
+        /// Querformat statt Hochformat.
+        landscape: bool,
+        /// Massstabszahl `n` fuer 1:n.
+        scale_denominator: u32,
+        /// Koordinatengitter zeichnen.
+        show_grid: bool,
+        /// Gitterabstand in Metern.
+        grid_spacing_m: f32,
+    },
+    /// Bricht den Dialog "Als PDF exportieren..." ab.
+    CancelPdfExport,
     /// Reicht einen Batch aus host-neutralen Viewport-Input-Events in die Session.
     SubmitViewportInput {
         /// Sequenzieller Batch von Resize-, Pointer- und Scroll-Events.
@@ -448,7 +658,10 @@ mod tests {
     use serde_json::json;
 
     use super::HostSessionAction;
-    use crate::dto::{HostDefaultConnectionDirection, HostDefaultConnectionPriority};
+    use crate::dto::{
+        HostDefaultConnectionDirection, HostDefaultConnectionPriority, HostNodeAlignMode,
+        HostNodeHeightEdit, HostPdfPaperSize,
+    };
 
     #[test]
     fn host_session_action_connection_family_roundtrips_json() {
@@ -505,6 +718,17 @@ mod tests {
                     "priority": "regular"
                 }),
             ),
+            (
+                HostSessionAction::InvertConnection {
+                    start_id: 9,
+                    end_id: 10,
+                },
+                json!({
+                    "kind": "invert_connection",
+                    "start_id": 9,
+                    "end_id": 10
+                }),
+            ),
             (
                 HostSessionAction::ConnectSelectedNodes,
                 json!({ "kind": "connect_selected_nodes" }),
@@ -522,6 +746,10 @@ mod tests {
                 HostSessionAction::InvertAllConnectionsBetweenSelected,
                 json!({ "kind": "invert_all_connections_between_selected" }),
             ),
+            (
+                HostSessionAction::ReversePathBetweenSelected,
+                json!({ "kind": "reverse_path_between_selected" }),
+            ),
             (
                 HostSessionAction::SetAllConnectionsPriorityBetweenSelected {
                     priority: HostDefaultConnectionPriority::SubPriority,
@@ -568,6 +796,15 @@ mod tests {
                     "path": "/tmp/source.zip"
                 }),
             ),
+            (
+                HostSessionAction::ExtractHeightmapFromZip {
+                    path: "/tmp/source.zip".to_string(),
+                },
+                json!({
+                    "kind": "extract_heightmap_from_zip",
+                    "path": "/tmp/source.zip"
+                }),
+            ),
             (
                 HostSessionAction::SelectZipBackgroundFile {
                     zip_path: "/tmp/background.zip".to_string(),
@@ -606,6 +843,132 @@ mod tests {
                 HostSessionAction::InvertSelection,
                 json!({ "kind": "invert_selection" }),
             ),
+            (
+                HostSessionAction::GrowSelection,
+                json!({ "kind": "grow_selection" }),
+            ),
+            (
+                HostSessionAction::ShrinkSelection,
+                json!({ "kind": "shrink_selection" }),
+            ),
+            (
+                HostSessionAction::SelectPathBetweenSelected,
+                json!({ "kind": "select_path_between_selected" }),
+            ),
+            (
+                HostSessionAction::SelectByConnectionPriority {
+                    priority: HostDefaultConnectionPriority::SubPriority,
+                },
+                json!({ "kind": "select_by_connection_priority", "priority": "sub_priority" }),
+            ),
+            (
+                HostSessionAction::SetSelectedNodeHeights {
+                    edit: HostNodeHeightEdit::Absolute { value: 12.5 },
+                },
+                json!({
+                    "kind": "set_selected_node_heights",
+                    "edit": { "mode": "absolute", "value": 12.5 }
+                }),
+            ),
+            (
+                HostSessionAction::SetSelectedNodeHeights {
+                    edit: HostNodeHeightEdit::InterpolateChain,
+                },
+                json!({
+                    "kind": "set_selected_node_heights",
+                    "edit": { "mode": "interpolate_chain" }
+                }),
+            ),
+            (
+                HostSessionAction::SetSelectedNodeHeights {
+                    edit: HostNodeHeightEdit::InterpolateChainSmooth,
+                },
+                json!({
+                    "kind": "set_selected_node_heights",
+                    "edit": { "mode": "interpolate_chain_smooth" }
+                }),
+            ),
+            (
+                HostSessionAction::ProjectSelectionOntoTerrain { offset: 0.5 },
+                json!({ "kind": "project_selection_onto_terrain", "offset": 0.5 }),
+            ),
+            (
+                HostSessionAction::SetSelectionLocked { locked: true },
+                json!({ "kind": "set_selection_locked", "locked": true }),
+            ),
+            (
+                HostSessionAction::ToggleIsolateSelection,
+                json!({ "kind": "toggle_isolate_selection" }),
+            ),
+            (
+                HostSessionAction::SetPathOffsetDistance { distance: -3.5 },
+                json!({ "kind": "set_path_offset_distance", "distance": -3.5 }),
+            ),
+            (
+                HostSessionAction::ApplyPathOffset,
+                json!({ "kind": "apply_path_offset" }),
+            ),
+            (
+                HostSessionAction::ConvertJunctionToRoundabout,
+                json!({ "kind": "convert_junction_to_roundabout" }),
+            ),
+            (
+                HostSessionAction::EqualizeChainSpacing,
+                json!({ "kind": "equalize_chain_spacing" }),
+            ),
+            (
+                HostSessionAction::AlignSelectedNodes {
+                    mode: HostNodeAlignMode::EndpointLine,
+                },
+                json!({ "kind": "align_selected_nodes", "mode": "endpoint_line" }),
+            ),
+            (
+                HostSessionAction::ResampleSelectedChain { spacing: 6.0 },
+                json!({ "kind": "resample_selected_chain", "spacing": 6.0 }),
+            ),
+            (
+                HostSessionAction::SmoothSelectedChain {
+                    strength: 0.5,
+                    iterations: 10,
+                },
+                json!({ "kind": "smooth_selected_chain", "strength": 0.5, "iterations": 10 }),
+            ),
+            (
+                HostSessionAction::SimplifySelectedChain { tolerance: 0.25 },
+                json!({ "kind": "simplify_selected_chain", "tolerance": 0.25 }),
+            ),
+            (
+                HostSessionAction::DistributeSelectedNodes,
+                json!({ "kind": "distribute_selected_nodes" }),
+            ),
+            (
+                HostSessionAction::JoinSelectedChains,
+                json!({ "kind": "join_selected_chains" }),
+            ),
+            (
+                HostSessionAction::MergeSelectedNodes,
+                json!({ "kind": "merge_selected_nodes" }),
+            ),
+            (
+                HostSessionAction::ReverseSelectedChain,
+                json!({ "kind": "reverse_selected_chain" }),
+            ),
+            (
+                HostSessionAction::PasteRotate {
+                    counter_clockwise: true,
+                },
+                json!({ "kind": "paste_rotate", "counter_clockwise": true }),
+            ),
+            (
+                HostSessionAction::PasteFromText {
+                    text: "{}".to_string(),
+                },
+                json!({ "kind": "paste_from_text", "text": "{}" }),
+            ),
+            (
+                HostSessionAction::DuplicateSelection,
+                json!({ "kind": "duplicate_selection" }),
+            ),
             (
                 HostSessionAction::StartGroupEdit { record_id: 5 },
                 json!({ "kind": "start_group_edit", "record_id": 5 }),
@@ -634,6 +997,17 @@ mod tests {
                     "additive": true
                 }),
             ),
+            (
+                HostSessionAction::SelectConnected {
+                    world_pos: [4.0, 2.0],
+                    additive: false,
+                },
+                json!({
+                    "kind": "select_connected",
+                    "world_pos": [4.0, 2.0],
+                    "additive": false
+                }),
+            ),
             (
                 HostSessionAction::ConfirmDissolveGroup { segment_id: 11 },
                 json!({ "kind": "confirm_dissolve_group", "segment_id": 11 }),
@@ -657,6 +1031,99 @@ mod tests {
                     "corner_rounding_max_angle_deg": 75.0
                 }),
             ),
+            (
+                HostSessionAction::ConfirmFieldCourse {
+                    field_id: 12,
+                    spacing: 8.0,
+                    headland_offset: 6.5,
+                },
+                json!({
+                    "kind": "confirm_field_course",
+                    "field_id": 12,
+                    "spacing": 8.0,
+                    "headland_offset": 6.5
+                }),
+            ),
+            (
+                HostSessionAction::ConfirmMoveSelection {
+                    dx: 250.0,
+                    dy: -1.5,
+                    dz: -120.0,
+                },
+                json!({
+                    "kind": "confirm_move_selection",
+                    "dx": 250.0,
+                    "dy": -1.5,
+                    "dz": -120.0
+                }),
+            ),
+            (
+                HostSessionAction::NudgeSelection { dx: 2.5, dz: -1.0 },
+                json!({
+                    "kind": "nudge_selection",
+                    "dx": 2.5,
+                    "dz": -1.0
+                }),
+            ),
+            (
+                HostSessionAction::ConfirmDeleteByFilter {
+                    only_subprio: true,
+                    only_unconnected: false,
+                    only_without_markers: true,
+                },
+                json!({
+                    "kind": "confirm_delete_by_filter",
+                    "only_subprio": true,
+                    "only_unconnected": false,
+                    "only_without_markers": true
+                }),
+            ),
+            (
+                HostSessionAction::PushNetworkToGame,
+                json!({ "kind": "push_network_to_game" }),
+            ),
+            (
+                HostSessionAction::ImportStoredRoute {
+                    file_name: "Hof.xml".to_string(),
+                },
+                json!({ "kind": "import_stored_route", "file_name": "Hof.xml" }),
+            ),
+            (
+                HostSessionAction::ExportStoredRoute {
+                    name: "Hof".to_string(),
+                },
+                json!({ "kind": "export_stored_route", "name": "Hof" }),
+            ),
+            (
+                HostSessionAction::OpenSavegame {
+                    path: "/fs25/savegame1/AutoDrive_config.xml".to_string(),
+                },
+                json!({
+                    "kind": "open_savegame",
+                    "path": "/fs25/savegame1/AutoDrive_config.xml"
+                }),
+            ),
+            (
+                HostSessionAction::CoursesModExport,
+                json!({ "kind": "courses_mod_export" }),
+            ),
+            (
+                HostSessionAction::ConfirmPdfExport {
+                    paper: HostPdfPaperSize::A3,
+                    landscape: true,
+                    scale_denominator: 5000,
+                    show_grid: false,
+                    grid_spacing_m: 100.0,
+                },
+                json!({
+                    "kind": "confirm_pdf_export",
+                    "paper": "a3",
+                    "landscape": true,
+                    "scale_denominator": 5000,
+                    "show_grid": false,
+                    "grid_spacing_m": 100.0
+                }),
+            ),
             (
                 HostSessionAction::ConfirmDeduplication,
                 json!({ "kind": "confirm_deduplication" }),
@@ -1,6 +1,6 @@
 //! Chrome-Snapshot-DTOs fuer host-neutrale Menues und Panels.
 
-use fs25_auto_drive_engine::shared::{EditorOptions, RenderQuality};
+use fs25_auto_drive_engine::shared::{BackgroundSource, EditorOptions, RenderQuality};
 use serde::{Deserialize, Serialize};
 
 use super::actions::HostActiveTool;
@@ -87,6 +87,10 @@ pub struct HostChromeSnapshot {
     pub selection_count: usize,
     /// Beispiel-ID eines selektierten Nodes (None wenn keine Selektion).
     pub selection_example_id: Option<u64>,
+    /// Summierte Laenge der Verbindungen zwischen selektierten Nodes in Metern.
+    pub selection_connected_length: f32,
+    /// Breite/Hoehe der Bounding Box der Selektion in Metern (None ohne Selektion).
+    pub selection_bounds_size: Option<[f32; 2]>,
     /// Ob eine Hintergrundkarte geladen ist.
     pub background_map_loaded: bool,
     /// Aktuelle Render-Qualitaetsstufe.
@@ -97,8 +101,16 @@ pub struct HostChromeSnapshot {
     pub background_visible: bool,
     /// Aktueller Skalierungsfaktor des Hintergrunds.
     pub background_scale: f32,
+    /// Quelle des angezeigten Hintergrunds (Overview oder Heightmap-Relief).
+    pub background_source: BackgroundSource,
     /// Ob gespeicherte Hintergrund-Layer fuer Einzel-Toggles verfuegbar sind.
     pub background_layers_available: bool,
     /// Verfuegbare Hintergrund-Layer in kanonischer Reihenfolge fuer Host-Menues.
     pub background_layer_entries: Vec<HostBackgroundLayerEntry>,
+    /// Ob ein Companion-Mod ueber die Live-Verbindung Nachrichten schickt.
+    pub live_link_connected: bool,
+    /// Ob gerade ein Kurs waehrend der Fahrt aufgezeichnet wird.
+    pub live_recording: bool,
+    /// Ob der Isolier-Modus aktiv ist (nur die damalige Selektion sichtbar).
+    pub isolation_active: bool,
 }
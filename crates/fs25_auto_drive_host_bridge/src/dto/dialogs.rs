@@ -1,7 +1,9 @@
 //! Dialog-DTOs fuer die Host-Bridge.
 
 use fs25_auto_drive_engine::app::OverviewSourceContext;
-use fs25_auto_drive_engine::shared::{OverviewFieldDetectionSource, OverviewLayerOptions};
+use fs25_auto_drive_engine::shared::{
+    OverviewFieldDetectionSource, OverviewLayerOptions, PdfPaperSize,
+};
 use serde::{Deserialize, Serialize};
 
 /// Stabile Art eines Host-Datei-/Pfad-Dialogs fuer die Bridge.
@@ -22,6 +24,12 @@ pub enum HostDialogRequestKind {
     CurseplayImport,
     /// Curseplay-Datei exportieren.
     CurseplayExport,
+    /// Map-Mod-ZIP fuer den Standard-Config-Export auswaehlen.
+    MapModZip,
+    /// Zielpfad des Kurs-Mod-ZIPs waehlen.
+    CoursesModExport,
+    /// Zielpfad des PDF-Kartenexports waehlen.
+    PdfExport,
 }
 
 /// Serialisierbare Dialog-Anforderung fuer Hosts ohne direkten Engine-State-Zugriff.
@@ -189,6 +197,66 @@ pub struct HostZipBrowserSnapshot {
     pub filter_overview: bool,
 }
 
+/// Eintrag des AutoDrive-Routen-Managers im Routen-Dialog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostStoredRouteSnapshot {
+    /// Anzeigename der Route.
+    pub name: String,
+    /// Dateiname der Routen-XML im `routes`-Unterordner.
+    pub file_name: String,
+    /// Map, fuer die die Route exportiert wurde.
+    pub map: String,
+    /// Revision der Route.
+    pub revision: u32,
+    /// Zeitpunkt des letzten Exports.
+    pub date: String,
+}
+
+/// Snapshot des Dialogs fuer den AutoDrive-Routen-Manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostRoutesDialogSnapshot {
+    /// Ob der Dialog aktuell sichtbar ist.
+    pub visible: bool,
+    /// Routen-Ordner im Spielprofil.
+    pub routes_dir: String,
+    /// Gespeicherte Routen laut `routes.xml`.
+    pub routes: Vec<HostStoredRouteSnapshot>,
+    /// Aktuell selektierte Route.
+    pub selected_route_index: Option<usize>,
+    /// Name fuer den Export des aktuellen Netzes.
+    pub export_name: String,
+}
+
+/// Eintrag der Savegame-Auswahl.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostSavegameSummarySnapshot {
+    /// Ordnername im Profil (z.B. `savegame3`).
+    pub dir_name: String,
+    /// Vom Spieler vergebener Name des Savegames.
+    pub savegame_name: String,
+    /// Anzeigename der Karte.
+    pub map_title: String,
+    /// Name des eigenen Hofs.
+    pub farm_name: String,
+    /// Datum des letzten Speicherns.
+    pub save_date: String,
+    /// Pfad der `AutoDrive_config.xml`, falls vorhanden.
+    pub config_path: Option<String>,
+}
+
+/// Snapshot der Savegame-Auswahl im FS25-Profil.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostSavegamePickerSnapshot {
+    /// Ob der Dialog aktuell sichtbar ist.
+    pub visible: bool,
+    /// Durchsuchtes FS25-Profilverzeichnis.
+    pub profile_dir: String,
+    /// Gefundene Savegames, neuestes zuerst.
+    pub savegames: Vec<HostSavegameSummarySnapshot>,
+    /// Aktuell selektiertes Savegame.
+    pub selected_savegame_index: Option<usize>,
+}
+
 /// Snapshot des Overview-Options-Dialogs.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HostOverviewOptionsDialogSnapshot {
@@ -259,6 +327,102 @@ pub struct HostTraceAllFieldsDialogSnapshot {
     pub corner_rounding_max_angle_deg: f32,
 }
 
+/// Snapshot des Dialogs fuer "Feld-Rundkurs erzeugen".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostFieldCourseDialogSnapshot {
+    /// Ob der Dialog aktuell sichtbar ist.
+    pub visible: bool,
+    /// Farmland-ID des Feldes.
+    pub field_id: u32,
+    /// Abstand zwischen generierten Wegpunkten.
+    pub spacing: f32,
+    /// Abstand des Vorgewende-Rings vom Feldrand nach innen.
+    pub headland_offset: f32,
+}
+
+/// Snapshot des Dialogs fuer "Selektion verschieben um...".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostMoveSelectionDialogSnapshot {
+    /// Ob der Dialog aktuell sichtbar ist.
+    pub visible: bool,
+    /// Versatz entlang X in Metern.
+    pub dx: f32,
+    /// Versatz der Hoehe (Y-Wert) in Metern.
+    pub dy: f32,
+    /// Versatz entlang Z in Metern.
+    pub dz: f32,
+}
+
+/// Snapshot des Dialogs "Loeschen nach Filter...".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostDeleteByFilterDialogSnapshot {
+    /// Ob der Dialog aktuell sichtbar ist.
+    pub visible: bool,
+    /// Nur Nodes mit Flag `SubPrio` loeschen.
+    pub only_subprio: bool,
+    /// Nur Nodes ohne Verbindungen loeschen.
+    pub only_unconnected: bool,
+    /// Nur Nodes ohne Map-Marker loeschen.
+    pub only_without_markers: bool,
+    /// Anzahl der Nodes in der Region (Selektion beim Oeffnen).
+    pub region_node_count: usize,
+    /// Anzahl gesperrter Nodes in der Region (werden nie geloescht).
+    pub locked_node_count: usize,
+    /// Anzahl der Nodes, die mit den aktuellen Filtern geloescht wuerden.
+    pub matching_node_count: usize,
+}
+
+/// Stabiles Papierformat des PDF-Kartenexports.
+///
+/// Gueltige JSON-Werte: `a4`, `a3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HostPdfPaperSize {
+    /// DIN A4.
+    A4,
+    /// DIN A3.
+    A3,
+}
+
+impl From<PdfPaperSize> for HostPdfPaperSize {
+    fn from(paper: PdfPaperSize) -> Self {
+        match paper {
+            PdfPaperSize::A4 => Self::A4,
+            PdfPaperSize::A3 => Self::A3,
+        }
+    }
+}
+
+impl From<HostPdfPaperSize> for PdfPaperSize {
+    fn from(paper: HostPdfPaperSize) -> Self {
+        match paper {
+            HostPdfPaperSize::A4 => Self::A4,
+            HostPdfPaperSize::A3 => Self::A3,
+        }
+    }
+}
+
+/// Snapshot des Dialogs "Als PDF exportieren...".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostPdfExportDialogSnapshot {
+    /// Ob der Dialog aktuell sichtbar ist.
+    pub visible: bool,
+    /// Papierformat.
+    pub paper: HostPdfPaperSize,
+    /// Querformat statt Hochformat.
+    pub landscape: bool,
+    /// Massstabszahl `n` fuer 1:n.
+    pub scale_denominator: u32,
+    /// Koordinatengitter zeichnen.
+    pub show_grid: bool,
+    /// Gitterabstand in Metern.
+    pub grid_spacing_m: f32,
+    /// Spalten des Seitenrasters fuer die aktuellen Einstellungen.
+    pub page_columns: usize,
+    /// Zeilen des Seitenrasters fuer die aktuellen Einstellungen.
+    pub page_rows: usize,
+}
+
 /// Snapshot des Gruppen-Einstellungs-Popups.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HostGroupSettingsDialogSnapshot {
@@ -292,6 +456,10 @@ pub struct HostDialogSnapshot {
     pub dedup_dialog: HostDedupDialogSnapshot,
     /// Snapshot des ZIP-Browsers.
     pub zip_browser: HostZipBrowserSnapshot,
+    /// Snapshot des Routen-Dialogs.
+    pub routes_dialog: HostRoutesDialogSnapshot,
+    /// Snapshot der Savegame-Auswahl.
+    pub savegame_picker: HostSavegamePickerSnapshot,
     /// Snapshot des Overview-Options-Dialogs.
     pub overview_options_dialog: HostOverviewOptionsDialogSnapshot,
     /// Snapshot des Post-Load-/Overview-Source-Dialogs.
@@ -300,6 +468,14 @@ pub struct HostDialogSnapshot {
     pub save_overview_dialog: HostSaveOverviewDialogSnapshot,
     /// Snapshot des Trace-All-Fields-Dialogs.
     pub trace_all_fields_dialog: HostTraceAllFieldsDialogSnapshot,
+    /// Snapshot des Feld-Rundkurs-Dialogs.
+    pub field_course_dialog: HostFieldCourseDialogSnapshot,
+    /// Snapshot des Dialogs "Selektion verschieben um...".
+    pub move_selection_dialog: HostMoveSelectionDialogSnapshot,
+    /// Snapshot des Dialogs "Loeschen nach Filter...".
+    pub delete_by_filter_dialog: HostDeleteByFilterDialogSnapshot,
+    /// Snapshot des Dialogs "Als PDF exportieren...".
+    pub pdf_export_dialog: HostPdfExportDialogSnapshot,
     /// Snapshot des Group-Settings-Popups.
     pub group_settings_popup: HostGroupSettingsDialogSnapshot,
     /// Snapshot des Confirm-Dissolve-Dialogs.
@@ -311,11 +487,15 @@ mod tests {
     use serde_json::json;
 
     use super::{
-        HostConfirmDissolveDialogSnapshot, HostDedupDialogSnapshot, HostDialogSnapshot,
+        HostConfirmDissolveDialogSnapshot, HostDedupDialogSnapshot,
+        HostDeleteByFilterDialogSnapshot, HostDialogSnapshot, HostFieldCourseDialogSnapshot,
         HostFieldDetectionSource, HostGroupSettingsDialogSnapshot,
-        HostHeightmapWarningDialogSnapshot, HostMarkerDialogSnapshot, HostOverviewLayersSnapshot,
-        HostOverviewOptionsDialogSnapshot, HostOverviewSourceContext, HostPostLoadDialogSnapshot,
-        HostSaveOverviewDialogSnapshot, HostTraceAllFieldsDialogSnapshot, HostZipBrowserSnapshot,
+        HostHeightmapWarningDialogSnapshot, HostMarkerDialogSnapshot,
+        HostMoveSelectionDialogSnapshot, HostOverviewLayersSnapshot,
+        HostOverviewOptionsDialogSnapshot, HostOverviewSourceContext, HostPdfExportDialogSnapshot,
+        HostPdfPaperSize, HostPostLoadDialogSnapshot, HostRoutesDialogSnapshot,
+        HostSaveOverviewDialogSnapshot, HostSavegamePickerSnapshot, HostSavegameSummarySnapshot,
+        HostStoredRouteSnapshot, HostTraceAllFieldsDialogSnapshot, HostZipBrowserSnapshot,
         HostZipImageEntrySnapshot,
     };
 
@@ -348,6 +528,32 @@ mod tests {
                 selected_entry_index: Some(0),
                 filter_overview: true,
             },
+            routes_dialog: HostRoutesDialogSnapshot {
+                visible: true,
+                routes_dir: "/profile/autoDrive/routesManager".to_string(),
+                routes: vec![HostStoredRouteSnapshot {
+                    name: "Hof".to_string(),
+                    file_name: "Hof.xml".to_string(),
+                    map: "Riverbend Springs".to_string(),
+                    revision: 2,
+                    date: "2025/01/31 18:04:12".to_string(),
+                }],
+                selected_route_index: Some(0),
+                export_name: "Hof".to_string(),
+            },
+            savegame_picker: HostSavegamePickerSnapshot {
+                visible: true,
+                profile_dir: "/profile".to_string(),
+                savegames: vec![HostSavegameSummarySnapshot {
+                    dir_name: "savegame1".to_string(),
+                    savegame_name: "Hof".to_string(),
+                    map_title: "Riverbend Springs".to_string(),
+                    farm_name: "Gut Sonnenhof".to_string(),
+                    save_date: "2025-02-02".to_string(),
+                    config_path: None,
+                }],
+                selected_savegame_index: Some(0),
+            },
             overview_options_dialog: HostOverviewOptionsDialogSnapshot {
                 visible: true,
                 zip_path: "/tmp/map.zip".to_string(),
@@ -392,6 +598,37 @@ mod tests {
                 corner_rounding_radius: 5.0,
                 corner_rounding_max_angle_deg: 15.0,
             },
+            field_course_dialog: HostFieldCourseDialogSnapshot {
+                visible: true,
+                field_id: 12,
+                spacing: 10.0,
+                headland_offset: 6.0,
+            },
+            move_selection_dialog: HostMoveSelectionDialogSnapshot {
+                visible: false,
+                dx: 250.0,
+                dy: 0.0,
+                dz: -120.0,
+            },
+            delete_by_filter_dialog: HostDeleteByFilterDialogSnapshot {
+                visible: true,
+                only_subprio: true,
+                only_unconnected: false,
+                only_without_markers: true,
+                region_node_count: 42,
+                locked_node_count: 2,
+                matching_node_count: 17,
+            },
+            pdf_export_dialog: HostPdfExportDialogSnapshot {
+                visible: true,
+                paper: HostPdfPaperSize::A3,
+                landscape: false,
+                scale_denominator: 2500,
+                show_grid: true,
+                grid_spacing_m: 250.0,
+                page_columns: 2,
+                page_rows: 3,
+            },
             group_settings_popup: HostGroupSettingsDialogSnapshot {
                 visible: true,
                 world_pos: [12.0, 18.0],
@@ -425,6 +662,12 @@ mod tests {
                 .and_then(|value| value.get("context")),
             Some(&json!("post_load_detected"))
         );
+        assert_eq!(
+            payload
+                .get("pdf_export_dialog")
+                .and_then(|value| value.get("paper")),
+            Some(&json!("a3"))
+        );
 
         let parsed: HostDialogSnapshot = serde_json::from_value(payload)
             .expect("HostDialogSnapshot muss aus JSON zuruecklesbar sein");
@@ -1,5 +1,6 @@
 //! Editing-DTOs fuer Properties-, Gruppen-Edit- und Streckenteilungs-Snapshots.
 
+use fs25_auto_drive_engine::app::use_cases::editing::{NodeAlignMode, NodeHeightEdit};
 use fs25_auto_drive_engine::shared::RenderQuality;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +16,84 @@ pub enum HostResampleMode {
     Count,
 }
 
+/// Host-neutrale Art der Y-Wert-Aenderung fuer selektierte Nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum HostNodeHeightEdit {
+    /// Alle Nodes erhalten denselben absoluten Y-Wert.
+    Absolute {
+        /// Neuer Y-Wert in Metern.
+        value: f32,
+    },
+    /// Der aktuelle Y-Wert jedes Nodes wird verschoben.
+    Offset {
+        /// Verschiebung in Metern.
+        delta: f32,
+    },
+    /// Innere Kettennodes linear zwischen den Endpunkten interpolieren.
+    InterpolateChain,
+    /// Innere Kettennodes als S-Kurve (flache Enden) zwischen den Endpunkten interpolieren.
+    InterpolateChainSmooth,
+}
+
+impl From<&NodeHeightEdit> for HostNodeHeightEdit {
+    fn from(edit: &NodeHeightEdit) -> Self {
+        match *edit {
+            NodeHeightEdit::Absolute(value) => Self::Absolute { value },
+            NodeHeightEdit::Offset(delta) => Self::Offset { delta },
+            NodeHeightEdit::InterpolateChain => Self::InterpolateChain,
+            NodeHeightEdit::InterpolateChainSmooth => Self::InterpolateChainSmooth,
+        }
+    }
+}
+
+impl From<&HostNodeHeightEdit> for NodeHeightEdit {
+    fn from(edit: &HostNodeHeightEdit) -> Self {
+        match *edit {
+            HostNodeHeightEdit::Absolute { value } => Self::Absolute(value),
+            HostNodeHeightEdit::Offset { delta } => Self::Offset(delta),
+            HostNodeHeightEdit::InterpolateChain => Self::InterpolateChain,
+            HostNodeHeightEdit::InterpolateChainSmooth => Self::InterpolateChainSmooth,
+        }
+    }
+}
+
+/// Host-neutrale Zielgerade fuer das Ausrichten selektierter Nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HostNodeAlignMode {
+    /// Gemeinsamer Z-Wert (Linie parallel zur X-Achse).
+    AxisX,
+    /// Gemeinsamer X-Wert (Linie parallel zur Z-Achse).
+    AxisZ,
+    /// Ausgleichsgerade durch den Schwerpunkt der Selektion.
+    BestFitLine,
+    /// Gerade zwischen den beiden Endpunkten der Selektion.
+    EndpointLine,
+}
+
+impl From<NodeAlignMode> for HostNodeAlignMode {
+    fn from(mode: NodeAlignMode) -> Self {
+        match mode {
+            NodeAlignMode::AxisX => Self::AxisX,
+            NodeAlignMode::AxisZ => Self::AxisZ,
+            NodeAlignMode::BestFitLine => Self::BestFitLine,
+            NodeAlignMode::EndpointLine => Self::EndpointLine,
+        }
+    }
+}
+
+impl From<HostNodeAlignMode> for NodeAlignMode {
+    fn from(mode: HostNodeAlignMode) -> Self {
+        match mode {
+            HostNodeAlignMode::AxisX => Self::AxisX,
+            HostNodeAlignMode::AxisZ => Self::AxisZ,
+            HostNodeAlignMode::BestFitLine => Self::BestFitLine,
+            HostNodeAlignMode::EndpointLine => Self::EndpointLine,
+        }
+    }
+}
+
 /// Host-neutrale Laufzeitoptionen fuer editing-nahe Panels.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HostEditingOptionsSnapshot {
@@ -112,6 +191,17 @@ pub struct HostResampleEditSnapshot {
     pub preview_count: usize,
 }
 
+/// Host-neutraler Snapshot des seitlichen Strecken-Versatzes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostPathOffsetEditSnapshot {
+    /// Ob die Versatz-Vorschau aktiv ist.
+    pub active: bool,
+    /// Vorzeichenbehafteter Versatz in Metern (positiv = links in Kettenrichtung).
+    pub distance: f32,
+    /// Anzahl der Nodes der erfassten Kette (0 ohne aktive Vorschau).
+    pub chain_node_count: usize,
+}
+
 /// Host-neutraler Sammelsnapshot fuer editing-nahe Panelzustandsdaten.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HostEditingSnapshot {
@@ -121,6 +211,8 @@ pub struct HostEditingSnapshot {
     pub group_edit: Option<HostGroupEditSnapshot>,
     /// Aktuelle Streckenteilungs-Konfiguration.
     pub resample: HostResampleEditSnapshot,
+    /// Aktueller Zustand des seitlichen Strecken-Versatzes.
+    pub path_offset: HostPathOffsetEditSnapshot,
     /// Editing-relevante host-neutrale Laufzeitoptionen.
     pub options: HostEditingOptionsSnapshot,
 }
@@ -129,8 +221,8 @@ pub struct HostEditingSnapshot {
 mod tests {
     use super::{
         HostEditableGroupSummary, HostEditingOptionsSnapshot, HostEditingSnapshot,
-        HostGroupBoundaryCandidateSnapshot, HostGroupEditSnapshot, HostResampleEditSnapshot,
-        HostResampleMode,
+        HostGroupBoundaryCandidateSnapshot, HostGroupEditSnapshot, HostPathOffsetEditSnapshot,
+        HostResampleEditSnapshot, HostResampleMode,
     };
     use crate::dto::HostRouteToolId;
     use fs25_auto_drive_engine::shared::RenderQuality;
@@ -174,6 +266,11 @@ mod tests {
                 hide_original: true,
                 preview_count: 9,
             },
+            path_offset: HostPathOffsetEditSnapshot {
+                active: true,
+                distance: -2.5,
+                chain_node_count: 4,
+            },
             options: HostEditingOptionsSnapshot {
                 render_quality: RenderQuality::Medium,
                 background_visible: true,
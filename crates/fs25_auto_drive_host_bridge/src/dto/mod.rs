@@ -23,17 +23,20 @@ pub use chrome::{HostBackgroundLayerEntry, HostBackgroundLayerKind, HostChromeSn
 pub use connection_pair::{HostConnectionPairEntry, HostConnectionPairSnapshot};
 pub use context_menu::{HostContextMenuAction, HostContextMenuSnapshot, HostContextMenuVariant};
 pub use dialogs::{
-    HostConfirmDissolveDialogSnapshot, HostDedupDialogSnapshot, HostDialogRequest,
-    HostDialogRequestKind, HostDialogResult, HostDialogSnapshot, HostFieldDetectionSource,
-    HostGroupSettingsDialogSnapshot, HostHeightmapWarningDialogSnapshot, HostMarkerDialogSnapshot,
+    HostConfirmDissolveDialogSnapshot, HostDedupDialogSnapshot, HostDeleteByFilterDialogSnapshot,
+    HostDialogRequest, HostDialogRequestKind, HostDialogResult, HostDialogSnapshot,
+    HostFieldCourseDialogSnapshot, HostFieldDetectionSource, HostGroupSettingsDialogSnapshot,
+    HostHeightmapWarningDialogSnapshot, HostMarkerDialogSnapshot, HostMoveSelectionDialogSnapshot,
     HostOverviewLayersSnapshot, HostOverviewOptionsDialogSnapshot, HostOverviewSourceContext,
-    HostPostLoadDialogSnapshot, HostSaveOverviewDialogSnapshot, HostTraceAllFieldsDialogSnapshot,
+    HostPdfExportDialogSnapshot, HostPdfPaperSize, HostPostLoadDialogSnapshot,
+    HostRoutesDialogSnapshot, HostSaveOverviewDialogSnapshot, HostSavegamePickerSnapshot,
+    HostSavegameSummarySnapshot, HostStoredRouteSnapshot, HostTraceAllFieldsDialogSnapshot,
     HostZipBrowserSnapshot, HostZipImageEntrySnapshot,
 };
 pub use editing::{
     HostEditableGroupSummary, HostEditingOptionsSnapshot, HostEditingSnapshot,
-    HostGroupBoundaryCandidateSnapshot, HostGroupEditSnapshot, HostResampleEditSnapshot,
-    HostResampleMode,
+    HostGroupBoundaryCandidateSnapshot, HostGroupEditSnapshot, HostNodeAlignMode,
+    HostNodeHeightEdit, HostPathOffsetEditSnapshot, HostResampleEditSnapshot, HostResampleMode,
 };
 pub use input::{
     HostInputModifiers, HostPointerButton, HostTapKind, HostViewportInputBatch,
@@ -117,6 +120,22 @@ pub type EngineSaveOverviewDialogSnapshot = HostSaveOverviewDialogSnapshot;
 /// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
 pub type EngineTraceAllFieldsDialogSnapshot = HostTraceAllFieldsDialogSnapshot;
 /// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineFieldCourseDialogSnapshot = HostFieldCourseDialogSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineMoveSelectionDialogSnapshot = HostMoveSelectionDialogSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineDeleteByFilterDialogSnapshot = HostDeleteByFilterDialogSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EnginePdfExportDialogSnapshot = HostPdfExportDialogSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineRoutesDialogSnapshot = HostRoutesDialogSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineStoredRouteSnapshot = HostStoredRouteSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineSavegamePickerSnapshot = HostSavegamePickerSnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
+pub type EngineSavegameSummarySnapshot = HostSavegameSummarySnapshot;
+/// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
 pub type EngineGroupSettingsDialogSnapshot = HostGroupSettingsDialogSnapshot;
 /// Kompatibilitaetsalias fuer bestehende Flutter-/FFI-Call-Sites.
 pub type EngineConfirmDissolveDialogSnapshot = HostConfirmDissolveDialogSnapshot;
@@ -190,7 +209,7 @@ pub type EngineChromeSnapshot = HostChromeSnapshot;
 #[cfg(test)]
 mod tests {
     use fs25_auto_drive_engine::app::ui_contract::{BypassPanelAction, RouteToolPanelAction};
-    use fs25_auto_drive_engine::shared::{EditorOptions, RenderQuality};
+    use fs25_auto_drive_engine::shared::{BackgroundSource, EditorOptions, RenderQuality};
     use serde_json::json;
 
     use super::{
@@ -403,6 +422,7 @@ mod tests {
             pending_dialog_request_count: 2,
             selection: HostSelectionSnapshot {
                 selected_node_ids: vec![11, 42],
+                selected_connection: Some([11, 42]),
             },
             viewport: HostViewportSnapshot {
                 camera_position: [12.5, -8.0],
@@ -472,11 +492,14 @@ mod tests {
             heightmap_path: None,
             selection_count: 0,
             selection_example_id: None,
+            selection_connected_length: 0.0,
+            selection_bounds_size: None,
             background_map_loaded: false,
             render_quality: RenderQuality::High,
             has_farmland: false,
             background_visible: true,
             background_scale: 1.0,
+            background_source: BackgroundSource::HeightmapGradient,
             background_layers_available: true,
             background_layer_entries: vec![
                 HostBackgroundLayerEntry {
@@ -488,6 +511,9 @@ mod tests {
                     visible: false,
                 },
             ],
+            live_link_connected: false,
+            live_recording: false,
+            isolation_active: false,
         };
 
         let payload = serde_json::to_value(&host_snapshot)
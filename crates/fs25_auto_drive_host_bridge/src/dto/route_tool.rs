@@ -93,6 +93,14 @@ pub enum HostRouteToolId {
     Rounding,
     /// Farb-Pfad-Analyse.
     ColorPath,
+    /// Feldkurs-Generator.
+    FieldCourse,
+    /// Klothoiden-Uebergangsbogen.
+    Clothoid,
+    /// Kreisbogen durch drei Punkte.
+    Arc,
+    /// Kreuzung aus 3–4 Zufahrts-Endpunkten.
+    Junction,
 }
 
 /// Stabile Route-Tool-Gruppe fuer host-neutrale Chrome-Snapshots.
@@ -149,6 +157,14 @@ pub enum HostRouteToolIconKey {
     Rounding,
     /// Icon fuer Farbpfad.
     ColorPath,
+    /// Icon fuer Feldkurs.
+    FieldCourse,
+    /// Icon fuer Klothoide.
+    Clothoid,
+    /// Icon fuer Kreisbogen.
+    Arc,
+    /// Icon fuer Kreuzung.
+    Junction,
 }
 
 /// Stabile Deaktivierungsgruende fuer Route-Tool-Eintraege.
@@ -2,14 +2,18 @@
 
 use fs25_auto_drive_engine::app::tools::ToolPreview;
 use fs25_auto_drive_engine::app::ui_contract::{
-    ClipboardOverlaySnapshot, ClipboardPreviewNode, ColorPathPanelPhase, ColorPathPanelState,
-    ColorPathPreviewStats, CurvePanelState, CurveTangentsPanelState, FieldBoundaryPanelState,
-    FieldPathPanelPhase, FieldPathPanelState, FieldPathPreviewStatus, FieldPathSelectionSummary,
-    GroupBoundaryOverlaySnapshot, GroupLockOverlaySnapshot, HostUiSnapshot, PanelState,
-    ParkingPanelState, PolylineOverlaySnapshot, RoundingPanelState, RouteOffsetPanelState,
-    RouteToolConfigState, RouteToolPanelState, SegmentConfigPanelState, SegmentLengthKind,
-    SegmentPanelMode, SmoothCurvePanelState, SmoothCurveSteererState, SplinePanelState,
-    TangentHelpHint, TangentNoneReason, TangentSelectionState, ViewportOverlaySnapshot,
+    ArcPanelState, ClipboardOverlaySnapshot, ClipboardPreviewNode, ClothoidPanelState,
+    ColorPathPanelPhase, ColorPathPanelState, ColorPathPreviewStats, CurvePanelState,
+    CurveTangentsPanelState, FieldBoundaryPanelState, FieldCoursePanelState, FieldPathPanelPhase,
+    FieldPathPanelState, FieldPathPreviewStatus, FieldPathSelectionSummary,
+    GroupBoundaryOverlaySnapshot, GroupLockOverlaySnapshot, HostUiSnapshot, JunctionPanelState,
+    LivePlayerOverlaySnapshot, LockedNodeOverlaySnapshot, MeasureOverlaySnapshot,
+    OwnedFarmlandOverlaySnapshot, PanelState, ParkingPanelState, PolylineOverlaySnapshot,
+    RoundingPanelState, RouteOffsetPanelState, RouteToolConfigState, RouteToolPanelState,
+    SegmentConfigPanelState, SegmentLengthKind, SegmentPanelMode,
+    SelectedConnectionOverlaySnapshot, SmoothCurvePanelState, SmoothCurveSteererState,
+    SplinePanelState, TangentHelpHint, TangentNoneReason, TangentSelectionState,
+    TerrainClippingOverlaySnapshot, VehicleOverlaySnapshot, ViewportOverlaySnapshot,
 };
 use fs25_auto_drive_engine::app::{BoundaryDirection, ConnectionDirection, ConnectionPriority};
 use fs25_auto_drive_engine::shared::I18nKey;
@@ -67,6 +71,12 @@ fn route_tool_panel_state_to_value(state: &RouteToolPanelState) -> Value {
         "has_pending_input": state.has_pending_input,
         "can_execute": state.can_execute,
         "config_state": state.config_state.as_ref().map(route_tool_config_state_to_value),
+        "elevation_profile": state.elevation_profile.as_ref().map(|profile| json!({
+            "points": profile.points,
+            "max_grade_percent": profile.max_grade_percent,
+            "grade_limit_percent": profile.grade_limit_percent,
+            "exceeds_limit": profile.exceeds_limit(),
+        })),
     })
 }
 
@@ -90,10 +100,14 @@ fn route_tool_config_state_to_value(state: &RouteToolConfigState) -> Value {
         }),
         RouteToolConfigState::Parking(state) => parking_panel_state_to_value(state),
         RouteToolConfigState::FieldBoundary(state) => field_boundary_panel_state_to_value(state),
+        RouteToolConfigState::FieldCourse(state) => field_course_panel_state_to_value(state),
+        RouteToolConfigState::Clothoid(state) => clothoid_panel_state_to_value(state),
+        RouteToolConfigState::Arc(state) => arc_panel_state_to_value(state),
         RouteToolConfigState::FieldPath(state) => field_path_panel_state_to_value(state),
         RouteToolConfigState::RouteOffset(state) => route_offset_panel_state_to_value(state),
         RouteToolConfigState::Rounding(state) => rounding_panel_state_to_value(state),
         RouteToolConfigState::ColorPath(state) => color_path_panel_state_to_value(state),
+        RouteToolConfigState::Junction(state) => junction_panel_state_to_value(state),
     }
 }
 
@@ -108,6 +122,16 @@ fn rounding_panel_state_to_value(state: &RoundingPanelState) -> Value {
     })
 }
 
+fn junction_panel_state_to_value(state: &JunctionPanelState) -> Value {
+    json!({
+        "kind": "junction",
+        "selected_node_count": state.selected_node_count,
+        "lane_count": state.lane_count,
+        "node_spacing": state.node_spacing,
+        "sub_priority_turns": state.sub_priority_turns,
+    })
+}
+
 fn curve_panel_state_to_value(state: &CurvePanelState) -> Value {
     json!({
         "kind": "curve",
@@ -135,6 +159,25 @@ fn spline_panel_state_to_value(state: &SplinePanelState) -> Value {
     })
 }
 
+fn clothoid_panel_state_to_value(state: &ClothoidPanelState) -> Value {
+    json!({
+        "kind": "clothoid",
+        "start_tangent": state.start_tangent.as_ref().map(tangent_selection_state_to_value),
+        "end_tangent": state.end_tangent.as_ref().map(tangent_selection_state_to_value),
+        "min_radius_m": state.min_radius_m,
+        "segment": segment_config_panel_state_to_value(&state.segment),
+    })
+}
+
+fn arc_panel_state_to_value(state: &ArcPanelState) -> Value {
+    json!({
+        "kind": "arc",
+        "radius_m": state.radius_m,
+        "sweep_deg": state.sweep_deg,
+        "segment": segment_config_panel_state_to_value(&state.segment),
+    })
+}
+
 fn smooth_curve_panel_state_to_value(state: &SmoothCurvePanelState) -> Value {
     json!({
         "kind": "smooth_curve",
@@ -203,6 +246,20 @@ fn field_boundary_panel_state_to_value(state: &FieldBoundaryPanelState) -> Value
     })
 }
 
+fn field_course_panel_state_to_value(state: &FieldCoursePanelState) -> Value {
+    json!({
+        "kind": "field_course",
+        "selected_field_id": state.selected_field_id,
+        "working_width": state.working_width,
+        "headland_passes": state.headland_passes,
+        "turn_style": state.turn_style,
+        "node_spacing": state.node_spacing,
+        "generated_headlands": state.generated_headlands,
+        "lane_count": state.lane_count,
+        "show_select_hint": state.show_select_hint,
+    })
+}
+
 fn field_path_panel_state_to_value(state: &FieldPathPanelState) -> Value {
     json!({
         "kind": "field_path",
@@ -238,6 +295,8 @@ fn route_offset_panel_state_to_value(state: &RouteOffsetPanelState) -> Value {
         "right_distance": state.right_distance,
         "base_spacing": state.base_spacing,
         "keep_original": state.keep_original,
+        "cross_links_enabled": state.cross_links_enabled,
+        "cross_link_spacing": state.cross_link_spacing,
         "chain_node_count": state.chain_node_count,
     })
 }
@@ -325,18 +384,49 @@ fn viewport_overlay_snapshot_to_value(snapshot: &ViewportOverlaySnapshot) -> Val
             .distance_preview
             .as_ref()
             .map(polyline_overlay_snapshot_to_value),
+        "path_offset_preview": snapshot
+            .path_offset_preview
+            .as_ref()
+            .map(polyline_overlay_snapshot_to_value),
+        "measure": snapshot.measure.as_ref().map(measure_overlay_snapshot_to_value),
         "group_locks": snapshot
             .group_locks
             .iter()
             .copied()
             .map(group_lock_overlay_snapshot_to_value)
             .collect::<Vec<_>>(),
+        "locked_nodes": snapshot
+            .locked_nodes
+            .iter()
+            .copied()
+            .map(locked_node_overlay_snapshot_to_value)
+            .collect::<Vec<_>>(),
+        "selected_connection": snapshot
+            .selected_connection
+            .map(selected_connection_overlay_snapshot_to_value),
         "group_boundaries": snapshot
             .group_boundaries
             .iter()
             .copied()
             .map(group_boundary_overlay_snapshot_to_value)
             .collect::<Vec<_>>(),
+        "terrain_clipping": snapshot
+            .terrain_clipping
+            .iter()
+            .copied()
+            .map(terrain_clipping_overlay_snapshot_to_value)
+            .collect::<Vec<_>>(),
+        "owned_farmlands": snapshot
+            .owned_farmlands
+            .iter()
+            .map(owned_farmland_overlay_snapshot_to_value)
+            .collect::<Vec<_>>(),
+        "vehicles": snapshot
+            .vehicles
+            .iter()
+            .map(vehicle_overlay_snapshot_to_value)
+            .collect::<Vec<_>>(),
+        "live_player": snapshot.live_player.map(live_player_overlay_snapshot_to_value),
         "show_no_file_hint": snapshot.show_no_file_hint,
     })
 }
@@ -420,6 +510,28 @@ fn polyline_overlay_snapshot_to_value(snapshot: &PolylineOverlaySnapshot) -> Val
     })
 }
 
+fn measure_overlay_snapshot_to_value(snapshot: &MeasureOverlaySnapshot) -> Value {
+    json!({
+        "points": snapshot
+            .points
+            .iter()
+            .copied()
+            .map(vec2_to_array)
+            .collect::<Vec<_>>(),
+        "segments": snapshot
+            .segments
+            .iter()
+            .map(|segment| {
+                json!({
+                    "length_m": segment.length_m,
+                    "cumulative_m": segment.cumulative_m,
+                    "heading_deg": segment.heading_deg,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
 fn group_lock_overlay_snapshot_to_value(snapshot: GroupLockOverlaySnapshot) -> Value {
     json!({
         "segment_id": snapshot.segment_id,
@@ -428,6 +540,24 @@ fn group_lock_overlay_snapshot_to_value(snapshot: GroupLockOverlaySnapshot) -> V
     })
 }
 
+fn locked_node_overlay_snapshot_to_value(snapshot: LockedNodeOverlaySnapshot) -> Value {
+    json!({
+        "node_id": snapshot.node_id,
+        "world_pos": vec2_to_array(snapshot.world_pos),
+    })
+}
+
+fn selected_connection_overlay_snapshot_to_value(
+    snapshot: SelectedConnectionOverlaySnapshot,
+) -> Value {
+    json!({
+        "start_id": snapshot.start_id,
+        "end_id": snapshot.end_id,
+        "start_pos": vec2_to_array(snapshot.start_pos),
+        "end_pos": vec2_to_array(snapshot.end_pos),
+    })
+}
+
 fn group_boundary_overlay_snapshot_to_value(snapshot: GroupBoundaryOverlaySnapshot) -> Value {
     json!({
         "segment_id": snapshot.segment_id,
@@ -437,6 +567,45 @@ fn group_boundary_overlay_snapshot_to_value(snapshot: GroupBoundaryOverlaySnapsh
     })
 }
 
+fn terrain_clipping_overlay_snapshot_to_value(snapshot: TerrainClippingOverlaySnapshot) -> Value {
+    json!({
+        "start_id": snapshot.start_id,
+        "end_id": snapshot.end_id,
+        "world_pos": vec2_to_array(snapshot.world_pos),
+        "deviation_m": snapshot.deviation_m,
+    })
+}
+
+fn owned_farmland_overlay_snapshot_to_value(snapshot: &OwnedFarmlandOverlaySnapshot) -> Value {
+    json!({
+        "farmland_id": snapshot.farmland_id,
+        "farm_id": snapshot.farm_id,
+        "outline": snapshot
+            .outline
+            .iter()
+            .copied()
+            .map(vec2_to_array)
+            .collect::<Vec<_>>(),
+        "triangles": snapshot.triangles,
+    })
+}
+
+fn vehicle_overlay_snapshot_to_value(snapshot: &VehicleOverlaySnapshot) -> Value {
+    json!({
+        "name": snapshot.name,
+        "world_pos": vec2_to_array(snapshot.world_pos),
+        "direction": vec2_to_array(snapshot.direction),
+    })
+}
+
+fn live_player_overlay_snapshot_to_value(snapshot: LivePlayerOverlaySnapshot) -> Value {
+    json!({
+        "world_pos": vec2_to_array(snapshot.world_pos),
+        "direction": vec2_to_array(snapshot.direction),
+        "recording": snapshot.recording,
+    })
+}
+
 fn vec2_to_array(value: Vec2) -> [f32; 2] {
     [value.x, value.y]
 }
@@ -586,10 +755,13 @@ mod tests {
     use fs25_auto_drive_engine::app::tools::ToolPreview;
     use fs25_auto_drive_engine::app::ui_contract::{
         ClipboardOverlaySnapshot, ClipboardPreviewNode, CommandPalettePanelState,
-        GroupBoundaryOverlaySnapshot, GroupLockOverlaySnapshot, HostUiSnapshot, OptionsPanelState,
-        PanelState, PolylineOverlaySnapshot, RoundingPanelState, RouteToolConfigState,
+        GroupBoundaryOverlaySnapshot, GroupLockOverlaySnapshot, HostUiSnapshot,
+        LivePlayerOverlaySnapshot, LockedNodeOverlaySnapshot, MeasureOverlaySnapshot,
+        MeasureSegmentSnapshot, OptionsPanelState, OwnedFarmlandOverlaySnapshot, PanelState,
+        PolylineOverlaySnapshot, RoundingPanelState, RouteElevationProfile, RouteToolConfigState,
         RouteToolPanelState, SegmentConfigPanelState, SegmentLengthKind, SegmentPanelMode,
-        StraightPanelState, ViewportOverlaySnapshot,
+        SelectedConnectionOverlaySnapshot, StraightPanelState, TerrainClippingOverlaySnapshot,
+        VehicleOverlaySnapshot, ViewportOverlaySnapshot,
     };
     use fs25_auto_drive_engine::app::BoundaryDirection;
     use fs25_auto_drive_engine::core::{ConnectionDirection, ConnectionPriority};
@@ -621,6 +793,11 @@ mod tests {
                             node_count_max: None,
                         },
                     })),
+                    elevation_profile: Some(RouteElevationProfile {
+                        points: vec![[0.0, 10.0], [5.0, 11.0]],
+                        max_grade_percent: 20.0,
+                        grade_limit_percent: 15.0,
+                    }),
                 })),
                 PanelState::Options(OptionsPanelState {
                     visible: true,
@@ -641,6 +818,14 @@ mod tests {
             value["panels"][0]["state"]["config_state"]["kind"],
             "straight"
         );
+        assert_eq!(
+            value["panels"][0]["state"]["elevation_profile"]["points"][1][0],
+            5.0
+        );
+        assert_eq!(
+            value["panels"][0]["state"]["elevation_profile"]["exceeds_limit"],
+            true
+        );
         assert_eq!(value["panels"][1]["kind"], "options");
         assert_eq!(value["panels"][2]["kind"], "command_palette");
     }
@@ -660,6 +845,7 @@ mod tests {
                     preview_node_count: Some(6),
                     is_adjusting: true,
                 })),
+                elevation_profile: None,
             }))],
         };
 
@@ -725,17 +911,60 @@ mod tests {
             distance_preview: Some(PolylineOverlaySnapshot {
                 points: vec![Vec2::new(7.0, 8.0), Vec2::new(9.0, 10.0)],
             }),
+            path_offset_preview: Some(PolylineOverlaySnapshot {
+                points: vec![Vec2::new(7.0, 11.0), Vec2::new(9.0, 13.0)],
+            }),
+            measure: Some(MeasureOverlaySnapshot {
+                points: vec![Vec2::ZERO, Vec2::new(4.0, 0.0)],
+                segments: vec![MeasureSegmentSnapshot {
+                    length_m: 4.0,
+                    cumulative_m: 4.0,
+                    heading_deg: 90.0,
+                }],
+            }),
             group_locks: vec![GroupLockOverlaySnapshot {
                 segment_id: 21,
                 world_pos: Vec2::new(11.0, 12.0),
                 locked: true,
             }],
+            locked_nodes: vec![LockedNodeOverlaySnapshot {
+                node_id: 30,
+                world_pos: Vec2::new(31.0, 32.0),
+            }],
+            selected_connection: Some(SelectedConnectionOverlaySnapshot {
+                start_id: 33,
+                end_id: 34,
+                start_pos: Vec2::new(35.0, 36.0),
+                end_pos: Vec2::new(37.0, 38.0),
+            }),
             group_boundaries: vec![GroupBoundaryOverlaySnapshot {
                 segment_id: 22,
                 node_id: 23,
                 world_pos: Vec2::new(13.0, 14.0),
                 direction: BoundaryDirection::Exit,
             }],
+            terrain_clipping: vec![TerrainClippingOverlaySnapshot {
+                start_id: 24,
+                end_id: 25,
+                world_pos: Vec2::new(15.0, 16.0),
+                deviation_m: 2.5,
+            }],
+            owned_farmlands: vec![OwnedFarmlandOverlaySnapshot {
+                farmland_id: 7,
+                farm_id: 1,
+                outline: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
+                triangles: vec![[0, 1, 2]],
+            }],
+            vehicles: vec![VehicleOverlaySnapshot {
+                name: "fendt942Vario".to_string(),
+                world_pos: Vec2::new(17.0, 18.0),
+                direction: Vec2::new(0.0, 1.0),
+            }],
+            live_player: Some(LivePlayerOverlaySnapshot {
+                world_pos: Vec2::new(19.0, 20.0),
+                direction: Vec2::new(1.0, 0.0),
+                recording: true,
+            }),
             show_no_file_hint: true,
         };
 
@@ -748,11 +977,25 @@ mod tests {
             value["route_tool_preview"]["connections"][0]["direction"],
             "dual"
         );
+        assert_eq!(value["path_offset_preview"]["points"][1][1], 13.0);
+        assert_eq!(value["measure"]["points"][1][0], 4.0);
+        assert_eq!(value["measure"]["segments"][0]["heading_deg"], 90.0);
         assert_eq!(
             value["route_tool_preview"]["connections"][0]["priority"],
             "sub_priority"
         );
+        assert_eq!(value["locked_nodes"][0]["node_id"], 30);
+        assert_eq!(value["locked_nodes"][0]["world_pos"][1], 32.0);
+        assert_eq!(value["selected_connection"]["end_id"], 34);
+        assert_eq!(value["selected_connection"]["end_pos"][0], 37.0);
         assert_eq!(value["group_boundaries"][0]["direction"], "exit");
+        assert_eq!(value["terrain_clipping"][0]["deviation_m"], 2.5);
+        assert_eq!(value["owned_farmlands"][0]["farmland_id"], 7);
+        assert_eq!(value["owned_farmlands"][0]["triangles"][0][2], 2);
+        assert_eq!(value["vehicles"][0]["name"], "fendt942Vario");
+        assert_eq!(value["vehicles"][0]["world_pos"][1], 18.0);
+        assert_eq!(value["live_player"]["world_pos"][0], 19.0);
+        assert_eq!(value["live_player"]["recording"], true);
         assert_eq!(value["show_no_file_hint"], true);
     }
 
@@ -9,6 +9,9 @@ use super::actions::HostActiveTool;
 pub struct HostSelectionSnapshot {
     /// Aktuell selektierte Node-IDs in stabiler Reihenfolge.
     pub selected_node_ids: Vec<u64>,
+    /// Per Kanten-Klick selektierte Verbindung als `[start_id, end_id]`.
+    #[serde(default)]
+    pub selected_connection: Option<[u64; 2]>,
 }
 
 /// Serialisierbarer Snapshot des aktuellen Viewports.
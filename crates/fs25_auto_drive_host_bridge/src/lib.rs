@@ -6,6 +6,8 @@ pub use fs25_auto_drive_engine::app::ui_contract::{HostUiSnapshot, ViewportOverl
 pub mod dispatch;
 /// Serialisierbare Session-, Action- und Dialog-DTOs der Host-Bridge.
 pub mod dto;
+/// UDP-Live-Verbindung zu einem Companion-Mod im laufenden Spiel.
+pub mod live_link;
 /// Kanonische Session-Fassade ueber `AppController` und `AppState`.
 pub mod session;
 
@@ -18,6 +20,7 @@ pub use dispatch::{
     take_host_dialog_requests, HostViewportInputState,
 };
 pub use dto::*;
+pub use live_link::{LiveLink, LiveLinkMessage};
 pub use session::{
     EngineRenderFrameSnapshot, FlutterBridgeSession, HostBridgeSession, HostDialogUiState,
     HostLocalDialogState, HostPanelPropertiesState, HostRenderFrameSnapshot,
@@ -0,0 +1,231 @@
+//! UDP-Live-Verbindung zu einem Companion-Mod im laufenden Spiel.
+//!
+//! Das Protokoll ist bewusst zeilenbasiert und textuell, damit der Mod es mit
+//! LUA-Bordmitteln sprechen kann. Jedes Datagramm enthaelt genau eine Nachricht:
+//!
+//! - Mod → Editor: `pos <x> <y> <z> <yaw>` — Spielerposition in Weltkoordinaten
+//!   (Y = Hoehe) und Gierwinkel in Radiant.
+//! - Editor → Mod: `reload <pfad>` — AutoDrive-Config unter `<pfad>` neu einlesen.
+//!
+//! Der Editor lauscht nur auf `127.0.0.1` und antwortet an die Adresse, von der
+//! zuletzt eine Nachricht kam.
+
+use glam::Vec2;
+use std::io::ErrorKind;
+use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
+
+/// Maximale Groesse eines empfangenen Datagramms in Bytes.
+const MAX_DATAGRAM_LEN: usize = 512;
+
+/// Vom Companion-Mod empfangene Nachricht.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LiveLinkMessage {
+    /// Aktuelle Spielerposition (X/Z) mit Gierwinkel in Radiant.
+    PlayerPose {
+        /// Position in Weltkoordinaten (X/Z)
+        position: Vec2,
+        /// Gierwinkel in Radiant (0 = Blick nach +Z)
+        yaw: f32,
+    },
+}
+
+/// Parst eine Textnachricht des Companion-Mods; unbekannte Nachrichten ergeben `None`.
+pub fn parse_live_link_message(text: &str) -> Option<LiveLinkMessage> {
+    let mut parts = text.split_whitespace();
+    match parts.next()? {
+        "pos" => {
+            let mut values = parts.map(|part| part.parse::<f32>().ok());
+            let [x, _y, z, yaw] = [
+                values.next()??,
+                values.next()??,
+                values.next()??,
+                values.next()??,
+            ];
+            [x, z, yaw]
+                .iter()
+                .all(|v| v.is_finite())
+                .then_some(LiveLinkMessage::PlayerPose {
+                    position: Vec2::new(x, z),
+                    yaw,
+                })
+        }
+        _ => None,
+    }
+}
+
+/// Formatiert die Aufforderung an den Mod, die Config unter `path` neu zu laden.
+pub fn format_reload_command(path: &str) -> String {
+    format!("reload {path}")
+}
+
+/// Host-seitiger UDP-Endpunkt der Live-Verbindung.
+///
+/// Der Socket ist nicht-blockierend und wird einmal pro Frame abgefragt;
+/// Threads oder eine Async-Runtime sind dafuer nicht noetig.
+#[derive(Debug, Default)]
+pub struct LiveLink {
+    socket: Option<UdpSocket>,
+    /// Port, auf den der Socket gebunden ist bzw. dessen Bindung zuletzt fehlschlug
+    port: Option<u16>,
+    /// Absender der zuletzt empfangenen Nachricht (Ziel fuer Antworten)
+    peer: Option<SocketAddr>,
+}
+
+impl LiveLink {
+    /// Oeffnet bzw. schliesst den Socket passend zu den aktuellen Optionen.
+    ///
+    /// Ein fehlgeschlagenes Binden wird pro Port nur einmal versucht und geloggt.
+    pub fn sync(&mut self, enabled: bool, port: u16) {
+        if !enabled {
+            if self.port.take().is_some() {
+                log::info!("Live-Verbindung geschlossen");
+            }
+            self.socket = None;
+            self.peer = None;
+            return;
+        }
+        if self.port == Some(port) {
+            return;
+        }
+
+        self.port = Some(port);
+        self.peer = None;
+        self.socket = match bind_local(port) {
+            Ok(socket) => {
+                log::info!("Live-Verbindung lauscht auf 127.0.0.1:{}", port);
+                Some(socket)
+            }
+            Err(error) => {
+                log::warn!("Live-Verbindung: Port {} nicht verfuegbar: {}", port, error);
+                None
+            }
+        };
+    }
+
+    /// Gibt an, ob der Socket gebunden ist.
+    pub fn is_listening(&self) -> bool {
+        self.socket.is_some()
+    }
+
+    /// Gibt an, ob bereits ein Companion-Mod Nachrichten geschickt hat.
+    pub fn is_connected(&self) -> bool {
+        self.peer.is_some()
+    }
+
+    /// Liest alle anstehenden Datagramme und merkt sich den Absender.
+    pub fn receive(&mut self) -> Vec<LiveLinkMessage> {
+        let Some(socket) = &self.socket else {
+            return Vec::new();
+        };
+
+        let mut messages = Vec::new();
+        let mut buf = [0u8; MAX_DATAGRAM_LEN];
+        loop {
+            match socket.recv_from(&mut buf) {
+                Ok((len, sender)) => {
+                    self.peer = Some(sender);
+                    let text = String::from_utf8_lossy(&buf[..len]);
+                    match parse_live_link_message(&text) {
+                        Some(message) => messages.push(message),
+                        None => log::debug!("Live-Verbindung: unbekannte Nachricht '{}'", text),
+                    }
+                }
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(error) => {
+                    log::warn!("Live-Verbindung: Empfangsfehler: {}", error);
+                    break;
+                }
+            }
+        }
+        messages
+    }
+
+    /// Fordert den Mod auf, die Config unter `path` neu zu laden.
+    ///
+    /// Gibt `false` zurueck, solange noch kein Mod verbunden ist.
+    pub fn send_reload(&self, path: &str) -> std::io::Result<bool> {
+        let (Some(socket), Some(peer)) = (&self.socket, self.peer) else {
+            return Ok(false);
+        };
+        socket.send_to(format_reload_command(path).as_bytes(), peer)?;
+        Ok(true)
+    }
+}
+
+fn bind_local(port: u16) -> std::io::Result<UdpSocket> {
+    let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, port))?;
+    socket.set_nonblocking(true)?;
+    Ok(socket)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_player_pose_and_ignores_height() {
+        assert_eq!(
+            parse_live_link_message("pos -120.5 95.2 340.25 1.5\n"),
+            Some(LiveLinkMessage::PlayerPose {
+                position: Vec2::new(-120.5, 340.25),
+                yaw: 1.5,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_or_incomplete_messages() {
+        assert_eq!(parse_live_link_message("hello"), None);
+        assert_eq!(parse_live_link_message("pos 1 2 3"), None);
+        assert_eq!(parse_live_link_message("pos 1 2 NaN 0"), None);
+        assert_eq!(parse_live_link_message(""), None);
+    }
+
+    #[test]
+    fn formats_reload_command() {
+        assert_eq!(
+            format_reload_command("C:/savegame1/AutoDrive_config.xml"),
+            "reload C:/savegame1/AutoDrive_config.xml"
+        );
+    }
+
+    #[test]
+    fn receives_from_peer_and_answers_with_reload() {
+        let mut link = LiveLink::default();
+        // Port 0 ist ungueltig fuer die Optionen, hier aber fuer einen freien Test-Port noetig
+        link.sync(true, 0);
+        assert!(link.is_listening());
+        assert!(!link.is_connected());
+        assert!(!link.send_reload("x.xml").expect("Senden ohne Peer"));
+
+        let local = link.socket.as_ref().unwrap().local_addr().unwrap();
+        let mod_socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        mod_socket.send_to(b"pos 1 2 3 0", local).unwrap();
+
+        let mut messages = Vec::new();
+        for _ in 0..100 {
+            messages = link.receive();
+            if !messages.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(
+            messages,
+            vec![LiveLinkMessage::PlayerPose {
+                position: Vec2::new(1.0, 3.0),
+                yaw: 0.0,
+            }]
+        );
+        assert!(link.is_connected());
+
+        assert!(link.send_reload("x.xml").expect("Senden an Peer"));
+        let mut buf = [0u8; MAX_DATAGRAM_LEN];
+        let (len, _) = mod_socket.recv_from(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"reload x.xml");
+
+        link.sync(false, 0);
+        assert!(!link.is_listening());
+        assert!(!link.is_connected());
+    }
+}
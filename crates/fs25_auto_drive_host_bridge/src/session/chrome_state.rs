@@ -5,9 +5,10 @@
 //! spiegelt eingehende "Request"-Flags aus dem Engine-`EngineUiState` per Drain.
 
 use fs25_auto_drive_engine::app::{
-    DedupDialogState, FloatingMenuState, GroupSettingsPopupState, MarkerDialogState,
-    OverviewOptionsDialogState, PostLoadDialogState, SaveOverviewDialogState,
-    TraceAllFieldsDialogState, ZipBrowserState,
+    DedupDialogState, DeleteByFilterDialogState, FieldCourseDialogState, FloatingMenuState,
+    GroupSettingsPopupState, MarkerDialogState, MoveSelectionDialogState,
+    OverviewOptionsDialogState, PdfExportDialogState, PostLoadDialogState, RoutesDialogState,
+    SaveOverviewDialogState, SavegamePickerState, TraceAllFieldsDialogState, ZipBrowserState,
 };
 
 /// Host-lokaler Chrome- und Dialog-Sichtbarkeitszustand.
@@ -36,6 +37,10 @@ pub struct HostLocalDialogState {
     pub dedup_dialog: DedupDialogState,
     /// ZIP-Browser-Dialog fuer Background-Map-Auswahl.
     pub zip_browser: Option<ZipBrowserState>,
+    /// Dialog fuer den AutoDrive-Routes-Manager-Ordner.
+    pub routes_dialog: Option<RoutesDialogState>,
+    /// Savegame-Auswahl im FS25-Profil.
+    pub savegame_picker: Option<SavegamePickerState>,
     /// Uebersichtskarten-Optionen-Dialog.
     pub overview_options_dialog: OverviewOptionsDialogState,
     /// Post-Load-Dialog (Heightmap/ZIP-Erkennung).
@@ -44,6 +49,14 @@ pub struct HostLocalDialogState {
     pub save_overview_dialog: SaveOverviewDialogState,
     /// Dialog fuer "Alle Felder nachzeichnen"-Einstellungen.
     pub trace_all_fields_dialog: TraceAllFieldsDialogState,
+    /// Dialog fuer "Feld-Rundkurs erzeugen"-Einstellungen.
+    pub field_course_dialog: FieldCourseDialogState,
+    /// Dialog fuer "Selektion verschieben um...".
+    pub move_selection_dialog: MoveSelectionDialogState,
+    /// Dialog fuer "Loeschen nach Filter...".
+    pub delete_by_filter_dialog: DeleteByFilterDialogState,
+    /// Dialog fuer "Als PDF exportieren...".
+    pub pdf_export_dialog: PdfExportDialogState,
     /// Segment-Einstellungs-Popup (erscheint nach Doppelklick).
     pub group_settings_popup: GroupSettingsPopupState,
     /// Bestaetigungsdialog zum Aufloesen einer Gruppe.
@@ -2,9 +2,13 @@
 
 use crate::dispatch::build_route_tool_viewport_snapshot;
 use crate::dto::{HostContextMenuAction, HostContextMenuSnapshot, HostContextMenuVariant};
+use fs25_auto_drive_engine::app::use_cases::editing::{
+    NodeAlignMode, DISTRIBUTE_MIN_NODE_COUNT, ROUNDABOUT_MIN_JUNCTION_DEGREE,
+};
 use fs25_auto_drive_engine::app::{AppState, GroupRegistry, RoadMap};
 use fs25_auto_drive_engine::shared::{t, I18nKey, Language};
 use indexmap::IndexSet;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ContextMenuActionId {
@@ -19,6 +23,8 @@ enum ContextMenuActionId {
     EditMarker,
     RemoveMarker,
     ConnectTwoNodes,
+    JoinChains,
+    MergeNodes,
     RouteStraight,
     RouteSmoothCurve,
     RouteQuadratic,
@@ -27,19 +33,35 @@ enum ContextMenuActionId {
     DirectionDual,
     DirectionReverse,
     DirectionInvert,
+    DirectionReversePath,
+    ReverseChain,
     PriorityRegular,
     PrioritySub,
     RemoveAllConnections,
     Streckenteilung,
+    EqualizeSpacing,
+    AlignAxisX,
+    AlignAxisZ,
+    AlignBestFit,
+    AlignEndpointLine,
+    DistributeEvenly,
     InvertSelection,
     SelectAll,
     ClearSelection,
+    SelectConnected,
+    ConvertToRoundabout,
+    SelectPathBetween,
+    SelectMainRoadNodes,
+    SelectSubPrioNodes,
+    LockSelection,
+    UnlockSelection,
     DeleteSelected,
     RouteExecute,
     RouteRecreate,
     RouteCancel,
     CopySelection,
     PasteHere,
+    DuplicateSelection,
     EditGroup,
     GroupSelectionAsGroup,
     RemoveFromGroup,
@@ -62,6 +84,8 @@ impl ContextMenuActionId {
             Self::EditMarker => "edit_marker",
             Self::RemoveMarker => "remove_marker",
             Self::ConnectTwoNodes => "connect_two_nodes",
+            Self::JoinChains => "join_chains",
+            Self::MergeNodes => "merge_nodes",
             Self::RouteStraight => "route_straight",
             Self::RouteSmoothCurve => "route_smooth_curve",
             Self::RouteQuadratic => "route_quadratic",
@@ -70,12 +94,27 @@ impl ContextMenuActionId {
             Self::DirectionDual => "direction_dual",
             Self::DirectionReverse => "direction_reverse",
             Self::DirectionInvert => "direction_invert",
+            Self::DirectionReversePath => "direction_reverse_path",
+            Self::ReverseChain => "reverse_chain",
             Self::PriorityRegular => "priority_regular",
             Self::PrioritySub => "priority_sub",
             Self::RemoveAllConnections => "remove_all_connections",
             Self::Streckenteilung => "streckenteilung",
+            Self::EqualizeSpacing => "equalize_spacing",
+            Self::AlignAxisX => "align_axis_x",
+            Self::AlignAxisZ => "align_axis_z",
+            Self::AlignBestFit => "align_best_fit",
+            Self::AlignEndpointLine => "align_endpoint_line",
+            Self::DistributeEvenly => "distribute_evenly",
             Self::InvertSelection => "invert_selection",
             Self::SelectAll => "select_all",
+            Self::SelectConnected => "select_connected",
+            Self::ConvertToRoundabout => "convert_to_roundabout",
+            Self::SelectPathBetween => "select_path_between",
+            Self::SelectMainRoadNodes => "select_main_road_nodes",
+            Self::SelectSubPrioNodes => "select_subprio_nodes",
+            Self::LockSelection => "lock_selection",
+            Self::UnlockSelection => "unlock_selection",
             Self::ClearSelection => "clear_selection",
             Self::DeleteSelected => "delete_selected",
             Self::RouteExecute => "route_execute",
@@ -83,6 +122,7 @@ impl ContextMenuActionId {
             Self::RouteCancel => "route_cancel",
             Self::CopySelection => "copy_selection",
             Self::PasteHere => "paste_here",
+            Self::DuplicateSelection => "duplicate_selection",
             Self::EditGroup => "edit_group",
             Self::GroupSelectionAsGroup => "group_selection_as_group",
             Self::RemoveFromGroup => "remove_from_group",
@@ -114,6 +154,8 @@ impl ContextMenuActionId {
                 Language::En => "Remove marker".to_string(),
             },
             Self::ConnectTwoNodes => t(lang, I18nKey::CtxConnectNodes).to_string(),
+            Self::JoinChains => t(lang, I18nKey::CtxJoinChains).to_string(),
+            Self::MergeNodes => t(lang, I18nKey::CtxMergeNodes).to_string(),
             Self::RouteStraight => t(lang, I18nKey::CtxRouteStraight).to_string(),
             Self::RouteSmoothCurve => t(lang, I18nKey::CtxRouteSmoothCurve).to_string(),
             Self::RouteQuadratic => t(lang, I18nKey::CtxRouteQuadratic).to_string(),
@@ -122,12 +164,27 @@ impl ContextMenuActionId {
             Self::DirectionDual => t(lang, I18nKey::CtxDirectionDual).to_string(),
             Self::DirectionReverse => t(lang, I18nKey::CtxDirectionReverse).to_string(),
             Self::DirectionInvert => t(lang, I18nKey::CtxDirectionInvert).to_string(),
+            Self::DirectionReversePath => t(lang, I18nKey::CtxDirectionReversePath).to_string(),
+            Self::ReverseChain => t(lang, I18nKey::CtxReverseChain).to_string(),
             Self::PriorityRegular => t(lang, I18nKey::CtxPriorityMain).to_string(),
             Self::PrioritySub => t(lang, I18nKey::CtxPrioritySub).to_string(),
             Self::RemoveAllConnections => t(lang, I18nKey::CtxRemoveAllConnections).to_string(),
             Self::Streckenteilung => t(lang, I18nKey::CtxStreckenteilung).to_string(),
+            Self::EqualizeSpacing => t(lang, I18nKey::CtxEqualizeSpacing).to_string(),
+            Self::AlignAxisX => t(lang, I18nKey::CtxAlignAxisX).to_string(),
+            Self::AlignAxisZ => t(lang, I18nKey::CtxAlignAxisZ).to_string(),
+            Self::AlignBestFit => t(lang, I18nKey::CtxAlignBestFit).to_string(),
+            Self::AlignEndpointLine => t(lang, I18nKey::CtxAlignEndpointLine).to_string(),
+            Self::DistributeEvenly => t(lang, I18nKey::CtxDistributeEvenly).to_string(),
             Self::InvertSelection => t(lang, I18nKey::CtxSelectionInvert).to_string(),
             Self::SelectAll => t(lang, I18nKey::CtxSelectAll).to_string(),
+            Self::SelectConnected => t(lang, I18nKey::CtxSelectConnected).to_string(),
+            Self::ConvertToRoundabout => t(lang, I18nKey::CtxConvertToRoundabout).to_string(),
+            Self::SelectPathBetween => t(lang, I18nKey::CtxSelectPathBetween).to_string(),
+            Self::SelectMainRoadNodes => t(lang, I18nKey::CtxSelectMainRoadNodes).to_string(),
+            Self::SelectSubPrioNodes => t(lang, I18nKey::CtxSelectSubPrioNodes).to_string(),
+            Self::LockSelection => t(lang, I18nKey::CtxLockSelection).to_string(),
+            Self::UnlockSelection => t(lang, I18nKey::CtxUnlockSelection).to_string(),
             Self::ClearSelection => t(lang, I18nKey::CtxClearSelection).to_string(),
             Self::DeleteSelected => t(lang, I18nKey::CtxDeleteSelected).to_string(),
             Self::RouteExecute => match lang {
@@ -144,6 +201,7 @@ impl ContextMenuActionId {
             },
             Self::CopySelection => t(lang, I18nKey::CtxCopy).to_string(),
             Self::PasteHere => t(lang, I18nKey::CtxPaste).to_string(),
+            Self::DuplicateSelection => t(lang, I18nKey::CtxDuplicate).to_string(),
             Self::EditGroup => t(lang, I18nKey::CtxEditGroup).to_string(),
             Self::GroupSelectionAsGroup => t(lang, I18nKey::CtxGroupAsSegment).to_string(),
             Self::RemoveFromGroup => t(lang, I18nKey::CtxRemoveFromGroup).to_string(),
@@ -173,25 +231,66 @@ impl ContextMenuActionId {
             Self::CreateMarker => ctx.focus_node_id.is_some_and(|node_id| {
                 ctx.road_map.contains_node(node_id) && !ctx.road_map.has_marker(node_id)
             }),
+            Self::SelectConnected => ctx
+                .focus_node_id
+                .is_some_and(|node_id| ctx.road_map.contains_node(node_id)),
+            Self::ConvertToRoundabout => ctx.focus_node_id.is_some_and(|node_id| {
+                ctx.selected_node_ids.len() == 1
+                    && ctx.selected_node_ids.contains(&node_id)
+                    && ctx.road_map.node_degree(node_id) >= ROUNDABOUT_MIN_JUNCTION_DEGREE
+            }),
             Self::EditMarker | Self::RemoveMarker => ctx.focus_node_id.is_some_and(|node_id| {
                 ctx.road_map.contains_node(node_id) && ctx.road_map.has_marker(node_id)
             }),
             Self::ConnectTwoNodes => is_two_selected_unconnected(ctx),
+            Self::JoinChains => ctx
+                .road_map
+                .two_disjoint_chains(ctx.selected_node_ids)
+                .is_some(),
+            Self::MergeNodes => can_reposition_selection(ctx, 2),
             Self::RouteStraight
             | Self::RouteSmoothCurve
             | Self::RouteQuadratic
-            | Self::RouteCubic => ctx.selected_node_ids.len() == 2,
+            | Self::RouteCubic
+            | Self::SelectPathBetween => ctx.selected_node_ids.len() == 2,
             Self::DirectionRegular
             | Self::DirectionDual
             | Self::DirectionReverse
             | Self::DirectionInvert
+            | Self::DirectionReversePath
             | Self::PriorityRegular
             | Self::PrioritySub
             | Self::RemoveAllConnections => has_connections_between_selected(ctx),
+            Self::ReverseChain => ctx.road_map.is_resampleable_chain(ctx.selected_node_ids),
             Self::Streckenteilung => {
                 !ctx.distanzen_active && ctx.road_map.is_resampleable_chain(ctx.selected_node_ids)
             }
-            Self::CopySelection => !ctx.selected_node_ids.is_empty(),
+            Self::EqualizeSpacing => ctx.road_map.is_resampleable_chain(ctx.selected_node_ids),
+            Self::AlignAxisX => {
+                can_reposition_selection(ctx, NodeAlignMode::AxisX.min_node_count())
+            }
+            Self::AlignAxisZ => {
+                can_reposition_selection(ctx, NodeAlignMode::AxisZ.min_node_count())
+            }
+            Self::AlignBestFit => {
+                can_reposition_selection(ctx, NodeAlignMode::BestFitLine.min_node_count())
+            }
+            Self::AlignEndpointLine => {
+                can_reposition_selection(ctx, NodeAlignMode::EndpointLine.min_node_count())
+            }
+            Self::DistributeEvenly => can_reposition_selection(ctx, DISTRIBUTE_MIN_NODE_COUNT),
+            Self::SelectMainRoadNodes | Self::SelectSubPrioNodes => {
+                ctx.road_map.connection_count() > 0
+            }
+            Self::LockSelection => ctx
+                .selected_node_ids
+                .iter()
+                .any(|id| !ctx.locked_node_ids.contains(id)),
+            Self::UnlockSelection => ctx
+                .selected_node_ids
+                .iter()
+                .any(|id| ctx.locked_node_ids.contains(id)),
+            Self::CopySelection | Self::DuplicateSelection => !ctx.selected_node_ids.is_empty(),
             Self::PasteHere => ctx.clipboard_has_data,
             Self::EditGroup => ctx.group_record_id.is_some(),
             Self::GroupSelectionAsGroup => {
@@ -226,6 +325,7 @@ impl From<ContextMenuVariant> for HostContextMenuVariant {
 struct ContextMenuContext<'a> {
     road_map: &'a RoadMap,
     selected_node_ids: &'a IndexSet<u64>,
+    locked_node_ids: &'a HashSet<u64>,
     focus_node_id: Option<u64>,
     distanzen_active: bool,
     clipboard_has_data: bool,
@@ -277,6 +377,7 @@ pub(super) fn build_context_menu_snapshot(
     let ctx = ContextMenuContext {
         road_map,
         selected_node_ids: &state.selection.selected_node_ids,
+        locked_node_ids: &state.locked_node_ids,
         focus_node_id,
         distanzen_active: state.ui.distanzen.active,
         clipboard_has_data: !state.clipboard.nodes.is_empty(),
@@ -368,18 +469,28 @@ fn has_connections_between_selected(ctx: &ContextMenuContext<'_>) -> bool {
         .is_some()
 }
 
+fn can_reposition_selection(ctx: &ContextMenuContext<'_>, min_count: usize) -> bool {
+    ctx.selected_node_ids.len() >= min_count
+        && !ctx
+            .selected_node_ids
+            .iter()
+            .any(|id| ctx.locked_node_ids.contains(id))
+}
+
 fn action_specs_for_variant(variant: ContextMenuVariant) -> Vec<ActionSpec> {
     match variant {
         ContextMenuVariant::EmptyArea => {
             let mut actions = tool_actions();
             actions.extend(zoom_actions());
             actions.extend(route_tool_selection_actions());
+            actions.extend(priority_selection_actions());
             actions
         }
         ContextMenuVariant::SelectionOnly => {
             let mut actions = tool_actions();
             actions.extend(zoom_actions());
             actions.extend(selection_actions());
+            actions.extend(priority_selection_actions());
             actions.push(ActionSpec::new(
                 ContextMenuActionId::DeleteSelected,
                 Some("selection"),
@@ -391,11 +502,20 @@ fn action_specs_for_variant(variant: ContextMenuVariant) -> Vec<ActionSpec> {
             let mut actions = tool_actions();
             actions.extend(zoom_actions());
             actions.extend(marker_actions());
+            actions.push(ActionSpec::new(
+                ContextMenuActionId::SelectConnected,
+                Some("selection"),
+            ));
+            actions.push(ActionSpec::new(
+                ContextMenuActionId::ConvertToRoundabout,
+                Some("editing"),
+            ));
             actions.push(ActionSpec::new(
                 ContextMenuActionId::DeleteSelected,
                 Some("selection"),
             ));
             actions.extend(selection_actions());
+            actions.extend(priority_selection_actions());
             actions.extend(clipboard_actions());
             actions
         }
@@ -451,6 +571,8 @@ fn selection_actions() -> Vec<ActionSpec> {
         ActionSpec::new(ContextMenuActionId::RemoveFromGroup, Some("group")),
         ActionSpec::new(ContextMenuActionId::DissolveGroup, Some("group")),
         ActionSpec::new(ContextMenuActionId::ConnectTwoNodes, Some("route")),
+        ActionSpec::new(ContextMenuActionId::JoinChains, Some("route")),
+        ActionSpec::new(ContextMenuActionId::MergeNodes, Some("route")),
         ActionSpec::new(ContextMenuActionId::RouteSmoothCurve, Some("route")),
         ActionSpec::new(ContextMenuActionId::RouteStraight, Some("route")),
         ActionSpec::new(ContextMenuActionId::RouteQuadratic, Some("route")),
@@ -459,13 +581,31 @@ fn selection_actions() -> Vec<ActionSpec> {
         ActionSpec::new(ContextMenuActionId::DirectionDual, Some("direction")),
         ActionSpec::new(ContextMenuActionId::DirectionReverse, Some("direction")),
         ActionSpec::new(ContextMenuActionId::DirectionInvert, Some("direction")),
+        ActionSpec::new(ContextMenuActionId::DirectionReversePath, Some("direction")),
+        ActionSpec::new(ContextMenuActionId::ReverseChain, Some("direction")),
         ActionSpec::new(ContextMenuActionId::PriorityRegular, Some("priority")),
         ActionSpec::new(ContextMenuActionId::PrioritySub, Some("priority")),
         ActionSpec::new(ContextMenuActionId::RemoveAllConnections, Some("priority")),
         ActionSpec::new(ContextMenuActionId::InvertSelection, Some("selection")),
         ActionSpec::new(ContextMenuActionId::SelectAll, Some("selection")),
+        ActionSpec::new(ContextMenuActionId::SelectPathBetween, Some("selection")),
         ActionSpec::new(ContextMenuActionId::ClearSelection, Some("selection")),
+        ActionSpec::new(ContextMenuActionId::LockSelection, Some("lock")),
+        ActionSpec::new(ContextMenuActionId::UnlockSelection, Some("lock")),
         ActionSpec::new(ContextMenuActionId::Streckenteilung, Some("resample")),
+        ActionSpec::new(ContextMenuActionId::EqualizeSpacing, Some("resample")),
+        ActionSpec::new(ContextMenuActionId::AlignAxisX, Some("align")),
+        ActionSpec::new(ContextMenuActionId::AlignAxisZ, Some("align")),
+        ActionSpec::new(ContextMenuActionId::AlignBestFit, Some("align")),
+        ActionSpec::new(ContextMenuActionId::AlignEndpointLine, Some("align")),
+        ActionSpec::new(ContextMenuActionId::DistributeEvenly, Some("align")),
+    ]
+}
+
+fn priority_selection_actions() -> Vec<ActionSpec> {
+    vec![
+        ActionSpec::new(ContextMenuActionId::SelectMainRoadNodes, Some("selection")),
+        ActionSpec::new(ContextMenuActionId::SelectSubPrioNodes, Some("selection")),
     ]
 }
 
@@ -473,6 +613,7 @@ fn clipboard_actions() -> Vec<ActionSpec> {
     vec![
         ActionSpec::new(ContextMenuActionId::CopySelection, Some("clipboard")),
         ActionSpec::new(ContextMenuActionId::PasteHere, Some("clipboard")),
+        ActionSpec::new(ContextMenuActionId::DuplicateSelection, Some("clipboard")),
     ]
 }
 
@@ -492,7 +633,9 @@ mod tests {
     use super::build_context_menu_snapshot;
     use crate::dto::HostContextMenuVariant;
     use crate::{HostBridgeSession, HostSessionAction};
-    use fs25_auto_drive_engine::app::{MapMarker, MapNode, NodeFlag, RoadMap};
+    use fs25_auto_drive_engine::app::{
+        Connection, ConnectionDirection, ConnectionPriority, MapMarker, MapNode, NodeFlag, RoadMap,
+    };
     use glam::Vec2;
     use std::sync::Arc;
 
@@ -525,9 +668,28 @@ mod tests {
 
         assert_eq!(snapshot.variant, HostContextMenuVariant::SelectionOnly);
         assert!(action_enabled(&snapshot, "connect_two_nodes"));
+        assert!(action_enabled(&snapshot, "select_path_between"));
         assert!(action_enabled(&snapshot, "copy_selection"));
+        assert!(action_enabled(&snapshot, "duplicate_selection"));
         assert!(!action_enabled(&snapshot, "paste_here"));
         assert!(!action_enabled(&snapshot, "remove_all_connections"));
+        assert!(!action_enabled(&snapshot, "direction_reverse_path"));
+        assert!(!action_enabled(&snapshot, "reverse_chain"));
+        assert!(!action_enabled(&snapshot, "join_chains"));
+        assert!(action_enabled(&snapshot, "merge_nodes"));
+        assert!(action_enabled(&snapshot, "align_axis_x"));
+        assert!(!action_enabled(&snapshot, "align_endpoint_line"));
+        assert!(!action_enabled(&snapshot, "distribute_evenly"));
+        assert!(action_enabled(&snapshot, "lock_selection"));
+        assert!(!action_enabled(&snapshot, "unlock_selection"));
+
+        session
+            .apply_action(HostSessionAction::SetSelectionLocked { locked: true })
+            .expect("Selektierte Nodes muessen gesperrt werden koennen");
+        let locked_snapshot = build_context_menu_snapshot(&session.state, None);
+        assert!(!action_enabled(&locked_snapshot, "lock_selection"));
+        assert!(action_enabled(&locked_snapshot, "unlock_selection"));
+        assert!(!action_enabled(&locked_snapshot, "align_axis_x"));
 
         session
             .apply_action(HostSessionAction::ConnectSelectedNodes)
@@ -539,6 +701,37 @@ mod tests {
             &connected_snapshot,
             "remove_all_connections"
         ));
+        assert!(action_enabled(
+            &connected_snapshot,
+            "direction_reverse_path"
+        ));
+        assert!(action_enabled(&connected_snapshot, "reverse_chain"));
+    }
+
+    #[test]
+    fn empty_area_snapshot_offers_priority_selection_once_connections_exist() {
+        let mut session = HostBridgeSession::new();
+        session.state.road_map = Some(Arc::new(selection_test_map()));
+
+        let without_connections = build_context_menu_snapshot(&session.state, None);
+        assert_eq!(
+            without_connections.variant,
+            HostContextMenuVariant::EmptyArea
+        );
+        assert!(!action_enabled(
+            &without_connections,
+            "select_subprio_nodes"
+        ));
+
+        session.state.selection.ids_mut().extend([1, 2]);
+        session
+            .apply_action(HostSessionAction::ConnectSelectedNodes)
+            .expect("Selektierte Nodes muessen verbunden werden koennen");
+        session.state.selection.ids_mut().clear();
+
+        let with_connections = build_context_menu_snapshot(&session.state, None);
+        assert!(action_enabled(&with_connections, "select_main_road_nodes"));
+        assert!(action_enabled(&with_connections, "select_subprio_nodes"));
     }
 
     #[test]
@@ -550,6 +743,7 @@ mod tests {
         assert_eq!(without_marker.variant, HostContextMenuVariant::NodeFocused);
         assert!(action_enabled(&without_marker, "create_marker"));
         assert!(!action_enabled(&without_marker, "edit_marker"));
+        assert!(action_enabled(&without_marker, "select_connected"));
 
         Arc::make_mut(
             session
@@ -571,4 +765,33 @@ mod tests {
         assert!(action_enabled(&with_marker, "edit_marker"));
         assert!(action_enabled(&with_marker, "remove_marker"));
     }
+
+    #[test]
+    fn node_focused_snapshot_offers_roundabout_only_for_selected_junction() {
+        let mut road_map = selection_test_map();
+        road_map.add_node(MapNode::new(4, Vec2::new(10.0, 10.0), NodeFlag::Regular));
+        for neighbor in [1, 3, 4] {
+            let start_pos = road_map.node_position(2).expect("Kreuzung vorhanden");
+            let end_pos = road_map.node_position(neighbor).expect("Nachbar vorhanden");
+            road_map.add_connection(Connection::new(
+                2,
+                neighbor,
+                ConnectionDirection::Regular,
+                ConnectionPriority::Regular,
+                start_pos,
+                end_pos,
+            ));
+        }
+        let mut session = HostBridgeSession::new();
+        session.state.road_map = Some(Arc::new(road_map));
+
+        session.state.selection.ids_mut().insert(2);
+        let junction = session.context_menu_snapshot(Some(2));
+        assert!(action_enabled(&junction, "convert_to_roundabout"));
+
+        session.state.selection.ids_mut().clear();
+        session.state.selection.ids_mut().insert(1);
+        let end_node = session.context_menu_snapshot(Some(1));
+        assert!(!action_enabled(&end_node, "convert_to_roundabout"));
+    }
 }
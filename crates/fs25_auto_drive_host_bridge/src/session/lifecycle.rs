@@ -151,6 +151,43 @@ impl HostBridgeSession {
             dirty = true;
         }
 
+        if ui.field_course_dialog.visible && !self.chrome_state.field_course_dialog.visible {
+            self.chrome_state.field_course_dialog = ui.field_course_dialog.clone();
+            dirty = true;
+        } else if !ui.field_course_dialog.visible && self.chrome_state.field_course_dialog.visible {
+            self.chrome_state.field_course_dialog.visible = false;
+            dirty = true;
+        }
+
+        if ui.move_selection_dialog.visible && !self.chrome_state.move_selection_dialog.visible {
+            self.chrome_state.move_selection_dialog = ui.move_selection_dialog.clone();
+            dirty = true;
+        } else if !ui.move_selection_dialog.visible
+            && self.chrome_state.move_selection_dialog.visible
+        {
+            self.chrome_state.move_selection_dialog.visible = false;
+            dirty = true;
+        }
+
+        if ui.delete_by_filter_dialog.visible && !self.chrome_state.delete_by_filter_dialog.visible
+        {
+            self.chrome_state.delete_by_filter_dialog = ui.delete_by_filter_dialog.clone();
+            dirty = true;
+        } else if !ui.delete_by_filter_dialog.visible
+            && self.chrome_state.delete_by_filter_dialog.visible
+        {
+            self.chrome_state.delete_by_filter_dialog.visible = false;
+            dirty = true;
+        }
+
+        if ui.pdf_export_dialog.visible && !self.chrome_state.pdf_export_dialog.visible {
+            self.chrome_state.pdf_export_dialog = ui.pdf_export_dialog.clone();
+            dirty = true;
+        } else if !ui.pdf_export_dialog.visible && self.chrome_state.pdf_export_dialog.visible {
+            self.chrome_state.pdf_export_dialog.visible = false;
+            dirty = true;
+        }
+
         if ui.overview_options_dialog.visible && !self.chrome_state.overview_options_dialog.visible
         {
             self.chrome_state.overview_options_dialog = ui.overview_options_dialog.clone();
@@ -181,6 +218,28 @@ impl HostBridgeSession {
             dirty = true;
         }
 
+        // Routen-Dialog: Option<RoutesDialogState> - beim Oeffnen kopieren
+        let engine_routes_open = ui.routes_dialog.is_some();
+        let chrome_routes_open = self.chrome_state.routes_dialog.is_some();
+        if engine_routes_open && !chrome_routes_open {
+            self.chrome_state.routes_dialog = ui.routes_dialog.clone();
+            dirty = true;
+        } else if !engine_routes_open && chrome_routes_open {
+            self.chrome_state.routes_dialog = None;
+            dirty = true;
+        }
+
+        // Savegame-Auswahl: Option<SavegamePickerState> - beim Oeffnen kopieren
+        let engine_picker_open = ui.savegame_picker.is_some();
+        let chrome_picker_open = self.chrome_state.savegame_picker.is_some();
+        if engine_picker_open && !chrome_picker_open {
+            self.chrome_state.savegame_picker = ui.savegame_picker.clone();
+            dirty = true;
+        } else if !engine_picker_open && chrome_picker_open {
+            self.chrome_state.savegame_picker = None;
+            dirty = true;
+        }
+
         if dirty {
             self.chrome_state.mark_dirty();
         }
@@ -1,4 +1,4 @@
-use fs25_auto_drive_engine::app::state::DistanzenState;
+use fs25_auto_drive_engine::app::state::{DistanzenState, PathOffsetState};
 use fs25_auto_drive_engine::app::{
     AppController, AppState, Camera2D, ConnectionDirection, ConnectionPriority, EditorTool,
     GroupEditState, GroupRegistry, RoadMap, ToolEditStore,
@@ -26,6 +26,7 @@ use crate::dto::{
     HostConnectionPairSnapshot, HostDefaultConnectionDirection, HostDefaultConnectionPriority,
     HostDialogRequest, HostMarkerListSnapshot, HostNodeDetails, HostSessionSnapshot,
 };
+use crate::live_link::LiveLink;
 use snapshots::build_snapshot;
 
 fn map_connection_direction(direction: ConnectionDirection) -> HostDefaultConnectionDirection {
@@ -78,6 +79,8 @@ pub struct HostPanelPropertiesState<'a> {
     pub group_editing: Option<&'a GroupEditState>,
     /// Lokaler Distanz-Dialogzustand fuer Properties/Edit-Panel.
     pub distanzen: &'a mut DistanzenState,
+    /// Zustand des seitlichen Strecken-Versatzes (read-only; Aenderungen laufen ueber Intents).
+    pub path_offset: &'a PathOffsetState,
     /// Laufzeit-Optionen (werden im Edit-Panel lokal editiert).
     pub options: &'a mut EditorOptions,
 }
@@ -151,6 +154,8 @@ pub struct HostBridgeSession {
     chrome_state: HostLocalDialogState,
     /// Puffer fuer PickPath-Requests, die aus dem Engine-Queue gefiltert wurden.
     pending_dialog_requests: Vec<HostDialogRequest>,
+    /// UDP-Endpunkt der Live-Verbindung zum laufenden Spiel.
+    live_link: LiveLink,
 }
 
 impl HostBridgeSession {
@@ -169,6 +174,7 @@ impl HostBridgeSession {
             inspected_node_id: None,
             chrome_state: chrome,
             pending_dialog_requests: Vec::new(),
+            live_link: LiveLink::default(),
         }
     }
 
@@ -49,6 +49,7 @@ impl HostBridgeSession {
             tool_edit_store: &state.tool_edit_store,
             group_editing: state.group_editing.as_ref(),
             distanzen: &mut state.ui.distanzen,
+            path_offset: &state.ui.path_offset,
             options: &mut state.options,
         }
     }
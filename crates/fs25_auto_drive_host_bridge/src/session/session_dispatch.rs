@@ -7,6 +7,7 @@ use fs25_auto_drive_engine::app::AppIntent;
 
 use super::HostBridgeSession;
 use crate::dto::{HostActiveTool, HostDialogRequest, HostDialogResult, HostSessionAction};
+use crate::live_link::LiveLinkMessage;
 
 impl HostBridgeSession {
     /// Wendet eine explizite Host-Aktion auf die Session an.
@@ -103,4 +104,45 @@ impl HostBridgeSession {
     pub fn submit_dialog_result(&mut self, result: HostDialogResult) -> Result<()> {
         self.apply_action(HostSessionAction::SubmitDialogResult { result })
     }
+
+    /// Fragt die Live-Verbindung zum laufenden Spiel ab (einmal pro Frame).
+    ///
+    /// Oeffnet bzw. schliesst den UDP-Socket passend zu den Optionen, reicht
+    /// empfangene Spielerpositionen als Intents an die Engine weiter und
+    /// schickt eine vorgemerkte Neulade-Aufforderung an den Companion-Mod.
+    /// Gibt `true` zurueck, wenn sich dabei sichtbarer Zustand geaendert hat.
+    pub fn poll_live_link(&mut self) -> Result<bool> {
+        let was_connected = self.live_link.is_connected();
+        self.live_link.sync(
+            self.state.options.live_link_enabled,
+            self.state.options.live_link_port,
+        );
+        let mut changed = was_connected != self.live_link.is_connected();
+
+        if !self.live_link.is_listening() && self.state.live_link.player.is_some() {
+            self.state.live_link.player = None;
+            self.state.live_link.stop_recording();
+            self.snapshot_dirty = true;
+            changed = true;
+        }
+
+        for message in self.live_link.receive() {
+            match message {
+                LiveLinkMessage::PlayerPose { position, yaw } => {
+                    self.apply_intent(AppIntent::LivePlayerPoseReceived { position, yaw })?;
+                }
+            }
+            changed = true;
+        }
+
+        if let Some(path) = self.state.live_link.pending_reload.take() {
+            if self.live_link.send_reload(&path)? {
+                log::info!("Live-Verbindung: Neuladen von {} angefordert", path);
+            } else {
+                log::warn!("Live-Verbindung: kein Spiel verbunden — Neuladen nicht angefordert");
+            }
+        }
+
+        Ok(changed)
+    }
 }
@@ -4,6 +4,7 @@
 
 use super::HostBridgeSession;
 use crate::dto::{HostConnectionPairSnapshot, HostMarkerListSnapshot, HostNodeDetails};
+use fs25_auto_drive_engine::app::use_cases::editing::clipboard_to_json;
 
 impl HostBridgeSession {
     /// Liefert die Details eines Nodes als getypten Rust-Struct.
@@ -38,6 +39,13 @@ impl HostBridgeSession {
             .unwrap_or_else(|_| "{\"markers\":[],\"groups\":[]}".to_string())
     }
 
+    /// Serialisiert die interne Zwischenablage als Editor-JSON fuer die System-Zwischenablage.
+    ///
+    /// Gibt `None` zurueck, solange nichts kopiert wurde.
+    pub fn clipboard_json(&self) -> Option<String> {
+        clipboard_to_json(&self.state.clipboard)
+    }
+
     /// Setzt die aktuell fuer das Properties-Panel inspizierte Node-ID.
     pub fn set_inspected_node_id(&mut self, id: Option<u64>) {
         self.inspected_node_id = id;
@@ -133,11 +133,12 @@ impl HostBridgeSession {
     ///
     /// Die Felder `show_command_palette` und `show_options_dialog` stammen aus
     /// `chrome_state`, das per `drain_engine_requests()` nach jedem Engine-Intent
-    /// aktualisiert wird.
+    /// aktualisiert wird; `live_link_connected` aus dem Live-Link-Socket der Session.
     pub fn build_host_chrome_snapshot(&self) -> HostChromeSnapshot {
         let mut snapshot = crate::dispatch::build_host_chrome_snapshot(&self.state);
         snapshot.show_command_palette = self.chrome_state.show_command_palette;
         snapshot.show_options_dialog = self.chrome_state.show_options_dialog;
+        snapshot.live_link_connected = self.live_link.is_connected();
         snapshot
     }
 
@@ -2,8 +2,8 @@ use crate::dto::{
     HostActiveTool, HostDialogSnapshot, HostEditableGroupSummary, HostEditingOptionsSnapshot,
     HostEditingSnapshot, HostFieldDetectionSource, HostGroupBoundaryCandidateSnapshot,
     HostGroupEditSnapshot, HostOverviewLayersSnapshot, HostOverviewSourceContext,
-    HostResampleEditSnapshot, HostResampleMode, HostRouteToolId, HostSelectionSnapshot,
-    HostSessionSnapshot, HostViewportSnapshot,
+    HostPathOffsetEditSnapshot, HostResampleEditSnapshot, HostResampleMode, HostRouteToolId,
+    HostSelectionSnapshot, HostSessionSnapshot, HostViewportSnapshot,
 };
 use crate::session::HostLocalDialogState;
 use fs25_auto_drive_engine::app::state::DistanzenState;
@@ -21,6 +21,8 @@ fn map_active_tool(tool: EditorTool) -> HostActiveTool {
         EditorTool::Select => HostActiveTool::Select,
         EditorTool::Connect => HostActiveTool::Connect,
         EditorTool::AddNode => HostActiveTool::AddNode,
+        EditorTool::Knife => HostActiveTool::Knife,
+        EditorTool::Measure => HostActiveTool::Measure,
         EditorTool::Route => HostActiveTool::Route,
     }
 }
@@ -39,6 +41,10 @@ fn map_route_tool_id(tool_id: RouteToolId) -> HostRouteToolId {
         RouteToolId::RouteOffset => HostRouteToolId::RouteOffset,
         RouteToolId::Rounding => HostRouteToolId::Rounding,
         RouteToolId::ColorPath => HostRouteToolId::ColorPath,
+        RouteToolId::FieldCourse => HostRouteToolId::FieldCourse,
+        RouteToolId::Clothoid => HostRouteToolId::Clothoid,
+        RouteToolId::Arc => HostRouteToolId::Arc,
+        RouteToolId::Junction => HostRouteToolId::Junction,
     }
 }
 
@@ -85,6 +91,10 @@ pub(super) fn build_snapshot(
         pending_dialog_request_count,
         selection: HostSelectionSnapshot {
             selected_node_ids: state.selection.selected_node_ids.iter().copied().collect(),
+            selected_connection: state
+                .selection
+                .selected_connection
+                .map(|(start_id, end_id)| [start_id, end_id]),
         },
         viewport: HostViewportSnapshot {
             camera_position: [state.view.camera.position.x, state.view.camera.position.y],
@@ -98,6 +108,8 @@ pub(super) fn build_dialog_snapshot(
     chrome: &HostLocalDialogState,
 ) -> HostDialogSnapshot {
     let zip_browser = chrome.zip_browser.as_ref();
+    let routes_dialog = chrome.routes_dialog.as_ref();
+    let savegame_picker = chrome.savegame_picker.as_ref();
 
     HostDialogSnapshot {
         heightmap_warning: crate::dto::HostHeightmapWarningDialogSnapshot {
@@ -136,6 +148,54 @@ pub(super) fn build_dialog_snapshot(
             selected_entry_index: zip_browser.and_then(|browser| browser.selected),
             filter_overview: zip_browser.is_some_and(|browser| browser.filter_overview),
         },
+        routes_dialog: crate::dto::HostRoutesDialogSnapshot {
+            visible: routes_dialog.is_some(),
+            routes_dir: routes_dialog
+                .map(|dialog| dialog.routes_dir.clone())
+                .unwrap_or_default(),
+            routes: routes_dialog
+                .map(|dialog| {
+                    dialog
+                        .routes
+                        .iter()
+                        .map(|route| crate::dto::HostStoredRouteSnapshot {
+                            name: route.name.clone(),
+                            file_name: route.file_name.clone(),
+                            map: route.map.clone(),
+                            revision: route.revision,
+                            date: route.date.clone(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            selected_route_index: routes_dialog.and_then(|dialog| dialog.selected),
+            export_name: routes_dialog
+                .map(|dialog| dialog.export_name.clone())
+                .unwrap_or_default(),
+        },
+        savegame_picker: crate::dto::HostSavegamePickerSnapshot {
+            visible: savegame_picker.is_some(),
+            profile_dir: savegame_picker
+                .map(|picker| picker.profile_dir.clone())
+                .unwrap_or_default(),
+            savegames: savegame_picker
+                .map(|picker| {
+                    picker
+                        .savegames
+                        .iter()
+                        .map(|savegame| crate::dto::HostSavegameSummarySnapshot {
+                            dir_name: savegame.dir_name.clone(),
+                            savegame_name: savegame.savegame_name.clone(),
+                            map_title: savegame.map_title.clone(),
+                            farm_name: savegame.farm_name.clone(),
+                            save_date: savegame.save_date.clone(),
+                            config_path: savegame.config_path.clone(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            selected_savegame_index: savegame_picker.and_then(|picker| picker.selected),
+        },
         overview_options_dialog: crate::dto::HostOverviewOptionsDialogSnapshot {
             visible: chrome.overview_options_dialog.visible,
             zip_path: chrome.overview_options_dialog.zip_path.clone(),
@@ -184,6 +244,42 @@ pub(super) fn build_dialog_snapshot(
                 .trace_all_fields_dialog
                 .corner_rounding_max_angle_deg,
         },
+        field_course_dialog: crate::dto::HostFieldCourseDialogSnapshot {
+            visible: chrome.field_course_dialog.visible,
+            field_id: chrome.field_course_dialog.field_id,
+            spacing: chrome.field_course_dialog.spacing,
+            headland_offset: chrome.field_course_dialog.headland_offset,
+        },
+        move_selection_dialog: crate::dto::HostMoveSelectionDialogSnapshot {
+            visible: chrome.move_selection_dialog.visible,
+            dx: chrome.move_selection_dialog.dx,
+            dy: chrome.move_selection_dialog.dy,
+            dz: chrome.move_selection_dialog.dz,
+        },
+        delete_by_filter_dialog: crate::dto::HostDeleteByFilterDialogSnapshot {
+            visible: chrome.delete_by_filter_dialog.visible,
+            only_subprio: chrome.delete_by_filter_dialog.filter.only_subprio,
+            only_unconnected: chrome.delete_by_filter_dialog.filter.only_unconnected,
+            only_without_markers: chrome.delete_by_filter_dialog.filter.only_without_markers,
+            region_node_count: chrome.delete_by_filter_dialog.candidates.len()
+                + chrome.delete_by_filter_dialog.locked_count,
+            locked_node_count: chrome.delete_by_filter_dialog.locked_count,
+            matching_node_count: chrome.delete_by_filter_dialog.matching_count(),
+        },
+        pdf_export_dialog: {
+            let (page_columns, page_rows) = chrome.pdf_export_dialog.page_grid();
+            let options = &chrome.pdf_export_dialog.options;
+            crate::dto::HostPdfExportDialogSnapshot {
+                visible: chrome.pdf_export_dialog.visible,
+                paper: options.paper.into(),
+                landscape: options.landscape,
+                scale_denominator: options.scale_denominator,
+                show_grid: options.show_grid,
+                grid_spacing_m: options.grid_spacing_m,
+                page_columns,
+                page_rows,
+            }
+        },
         group_settings_popup: crate::dto::HostGroupSettingsDialogSnapshot {
             visible: chrome.group_settings_popup.visible,
             world_pos: [
@@ -205,6 +301,11 @@ pub(super) fn build_editing_snapshot(state: &AppState) -> HostEditingSnapshot {
         editable_groups: build_editable_group_summaries(state),
         group_edit: build_group_edit_snapshot(state),
         resample: build_resample_snapshot(state),
+        path_offset: HostPathOffsetEditSnapshot {
+            active: state.ui.path_offset.active,
+            distance: state.ui.path_offset.distance,
+            chain_node_count: state.ui.path_offset.chain_ids.len(),
+        },
         options: HostEditingOptionsSnapshot {
             render_quality: state.view.render_quality,
             background_visible: state.view.background_visible,
@@ -10,9 +10,9 @@ use fs25_auto_drive_engine::app::ui_contract::{
 use fs25_auto_drive_engine::app::{
     AppIntent, Connection, ConnectionDirection, ConnectionPriority, FloatingMenuKind,
     GroupEditState, GroupRecord, MapMarker, MapNode, NodeFlag, OverviewSourceContext, RoadMap,
-    ZipBrowserState,
+    RoutesDialogState, SavegamePickerState, ZipBrowserState,
 };
-use fs25_auto_drive_engine::core::ZipImageEntry;
+use fs25_auto_drive_engine::core::{SavegameSummary, StoredRoute, ZipImageEntry};
 use fs25_auto_drive_engine::shared::{OverviewFieldDetectionSource, OverviewLayerOptions};
 use glam::Vec2;
 use std::sync::Arc;
@@ -682,6 +682,43 @@ fn marker_list_read_returns_empty_snapshot_for_empty_road_map() {
     assert!(snapshot.groups.is_empty());
 }
 
+#[test]
+fn clipboard_json_pastes_copied_selection_into_other_session_with_fresh_ids() {
+    let mut source = HostBridgeSession::new();
+    source.state.road_map = Some(Arc::new(node_details_marker_test_map()));
+    assert_eq!(source.clipboard_json(), None);
+    source.state.selection.ids_mut().extend([1, 2]);
+    source
+        .apply_action(HostSessionAction::CopySelection)
+        .expect("Kopieren muss gelingen");
+    let text = source
+        .clipboard_json()
+        .expect("Nach dem Kopieren muss Clipboard-JSON verfuegbar sein");
+
+    let mut target = HostBridgeSession::new();
+    target.state.road_map = Some(Arc::new(node_details_marker_test_map()));
+    target
+        .apply_action(HostSessionAction::PasteFromText { text })
+        .expect("Einfuegen aus Text muss gelingen");
+    assert!(target.state.paste_preview_pos.is_some());
+    target
+        .apply_action(HostSessionAction::PasteConfirm)
+        .expect("Einfuegen bestaetigen muss gelingen");
+
+    let road_map = target.state.road_map.as_deref().expect("Karte erwartet");
+    assert_eq!(road_map.node_count(), 5);
+    let new_ids: Vec<u64> = target
+        .state
+        .selection
+        .selected_node_ids
+        .iter()
+        .copied()
+        .collect();
+    assert_eq!(new_ids, vec![4, 5]);
+    assert!(road_map.has_connection(4, 5));
+    assert_eq!(target.marker_list().markers.len(), 2);
+}
+
 #[test]
 fn connection_pair_read_returns_bridge_snapshot_for_two_nodes() {
     let mut session = HostBridgeSession::new();
@@ -1078,6 +1115,30 @@ fn dialog_snapshot_reflects_host_local_dialog_state() {
             selected: Some(0),
             filter_overview: true,
         });
+        dialog_state.ui.routes_dialog = Some(RoutesDialogState {
+            routes_dir: "/fs25/autoDrive/routesManager".to_string(),
+            routes: vec![StoredRoute {
+                name: "Hof".to_string(),
+                file_name: "Hof.xml".to_string(),
+                map: "Elmcreek".to_string(),
+                revision: 2,
+                date: "2026/10/01 12:00:00".to_string(),
+            }],
+            selected: Some(0),
+            export_name: "Elmcreek".to_string(),
+        });
+        dialog_state.ui.savegame_picker = Some(SavegamePickerState {
+            profile_dir: "/fs25".to_string(),
+            savegames: vec![SavegameSummary {
+                dir_name: "savegame2".to_string(),
+                savegame_name: "Hof".to_string(),
+                map_title: "Elmcreek".to_string(),
+                farm_name: "Gut Sonnenhof".to_string(),
+                save_date: "2026-10-01".to_string(),
+                config_path: Some("/fs25/savegame2/AutoDrive_config.xml".to_string()),
+            }],
+            selected: Some(0),
+        });
         dialog_state.ui.overview_options_dialog.visible = true;
         dialog_state.ui.overview_options_dialog.zip_path = "/tmp/map.zip".to_string();
         dialog_state.ui.overview_options_dialog.layers = OverviewLayerOptions {
@@ -1131,6 +1192,21 @@ fn dialog_snapshot_reflects_host_local_dialog_state() {
             .ui
             .trace_all_fields_dialog
             .corner_rounding_max_angle_deg = 18.0;
+        dialog_state.ui.field_course_dialog.visible = true;
+        dialog_state.ui.field_course_dialog.field_id = 23;
+        dialog_state.ui.field_course_dialog.headland_offset = 9.0;
+        dialog_state.ui.move_selection_dialog.visible = true;
+        dialog_state.ui.move_selection_dialog.dz = -64.0;
+        dialog_state.ui.delete_by_filter_dialog.visible = true;
+        dialog_state.ui.delete_by_filter_dialog.locked_count = 3;
+        dialog_state
+            .ui
+            .delete_by_filter_dialog
+            .filter
+            .only_unconnected = true;
+        dialog_state.ui.pdf_export_dialog.visible = true;
+        dialog_state.ui.pdf_export_dialog.map_extent = Vec2::new(2048.0, 2048.0);
+        dialog_state.ui.pdf_export_dialog.options.scale_denominator = 2000;
         dialog_state.ui.group_settings_popup.visible = true;
         dialog_state.ui.group_settings_popup.world_pos = Vec2::new(8.0, -4.0);
         dialog_state.ui.confirm_dissolve_group_id = Some(99);
@@ -1148,6 +1224,17 @@ fn dialog_snapshot_reflects_host_local_dialog_state() {
     assert!(snapshot.zip_browser.visible);
     assert_eq!(snapshot.zip_browser.entries.len(), 1);
     assert_eq!(snapshot.zip_browser.entries[0].name, "overview.png");
+    assert!(snapshot.routes_dialog.visible);
+    assert_eq!(snapshot.routes_dialog.routes.len(), 1);
+    assert_eq!(snapshot.routes_dialog.routes[0].revision, 2);
+    assert_eq!(snapshot.routes_dialog.selected_route_index, Some(0));
+    assert_eq!(snapshot.routes_dialog.export_name, "Elmcreek");
+    assert!(snapshot.savegame_picker.visible);
+    assert_eq!(
+        snapshot.savegame_picker.savegames[0].farm_name,
+        "Gut Sonnenhof"
+    );
+    assert_eq!(snapshot.savegame_picker.selected_savegame_index, Some(0));
     assert!(!snapshot.overview_options_dialog.layers.terrain);
     assert_eq!(
         snapshot.overview_options_dialog.field_detection_source,
@@ -1161,6 +1248,24 @@ fn dialog_snapshot_reflects_host_local_dialog_state() {
         snapshot.post_load_dialog.matching_zip_paths,
         vec!["/mods/map.zip".to_string()]
     );
+    assert!(snapshot.field_course_dialog.visible);
+    assert_eq!(snapshot.field_course_dialog.field_id, 23);
+    assert_eq!(snapshot.field_course_dialog.headland_offset, 9.0);
+    assert!(snapshot.move_selection_dialog.visible);
+    assert_eq!(snapshot.move_selection_dialog.dz, -64.0);
+    assert!(snapshot.delete_by_filter_dialog.visible);
+    assert!(snapshot.delete_by_filter_dialog.only_unconnected);
+    assert_eq!(snapshot.delete_by_filter_dialog.region_node_count, 3);
+    assert_eq!(snapshot.delete_by_filter_dialog.matching_node_count, 0);
+    assert!(snapshot.pdf_export_dialog.visible);
+    assert_eq!(snapshot.pdf_export_dialog.scale_denominator, 2000);
+    assert_eq!(
+        (
+            snapshot.pdf_export_dialog.page_columns,
+            snapshot.pdf_export_dialog.page_rows
+        ),
+        (4, 7)
+    );
     assert_eq!(snapshot.group_settings_popup.world_pos, [8.0, -4.0]);
     assert!(snapshot.group_settings_popup.segment_stop_at_junction);
     assert_eq!(snapshot.group_settings_popup.segment_max_angle_deg, 42.5);
@@ -1527,6 +1632,214 @@ fn viewport_input_select_rect_and_move_drag_preserve_lifecycle() {
     assert!(session.state.can_undo());
 }
 
+#[test]
+fn viewport_input_grid_snap_places_and_drags_nodes_on_grid() {
+    let mut session = HostBridgeSession::new();
+    session.state.road_map = Some(Arc::new(viewport_test_map()));
+    session.state.view.viewport_size = [800.0, 600.0];
+    session.state.options.grid_snap_enabled = true;
+    session.state.options.grid_snap_size = 5.0;
+
+    let add_node_screen = screen_for_world(&session, Vec2::new(201.7, 2.2));
+    session
+        .set_editor_tool(HostActiveTool::AddNode)
+        .expect("AddNode-Tool muss gesetzt werden koennen");
+    session
+        .apply_action(HostSessionAction::SubmitViewportInput {
+            batch: HostViewportInputBatch {
+                events: vec![resize_event([800.0, 600.0]), tap_event(add_node_screen)],
+            },
+        })
+        .expect("AddNode-Tap muss verarbeitet werden");
+
+    let map = session.state.road_map.as_deref().expect("Karte erwartet");
+    assert!(map
+        .nearest_node(Vec2::new(200.0, 0.0))
+        .is_some_and(|hit| hit.distance < 1e-3));
+
+    session
+        .set_editor_tool(HostActiveTool::Select)
+        .expect("Select-Tool muss gesetzt werden koennen");
+    let node1_screen = screen_for_world(&session, Vec2::new(0.0, 0.0));
+    let drag_target = screen_for_world(&session, Vec2::new(7.2, -1.3));
+    session
+        .apply_action(HostSessionAction::SubmitViewportInput {
+            batch: HostViewportInputBatch {
+                events: vec![
+                    HostViewportInputEvent::DragStart {
+                        button: HostPointerButton::Primary,
+                        screen_pos: node1_screen,
+                        modifiers: HostInputModifiers::default(),
+                    },
+                    HostViewportInputEvent::DragUpdate {
+                        button: HostPointerButton::Primary,
+                        screen_pos: drag_target,
+                        delta_px: [
+                            drag_target[0] - node1_screen[0],
+                            drag_target[1] - node1_screen[1],
+                        ],
+                    },
+                    HostViewportInputEvent::DragEnd {
+                        button: HostPointerButton::Primary,
+                        screen_pos: Some(drag_target),
+                    },
+                ],
+            },
+        })
+        .expect("Move-Drag muss verarbeitet werden");
+
+    let node1 = session
+        .state
+        .road_map
+        .as_deref()
+        .and_then(|map| map.node_position(1))
+        .expect("Node 1 muss vorhanden sein");
+    assert!((node1 - Vec2::new(5.0, 0.0)).length() < 1e-3);
+}
+
+#[test]
+fn viewport_input_drag_along_connection_sets_swipe_direction() {
+    let mut session = HostBridgeSession::new();
+    session.state.road_map = Some(Arc::new(viewport_connected_path_map()));
+    session.state.view.viewport_size = [800.0, 600.0];
+
+    let start = screen_for_world(&session, Vec2::new(6.0, 0.0));
+    let end = screen_for_world(&session, Vec2::new(4.0, 0.0));
+    let camera_before = session.state.view.camera.position;
+
+    session
+        .apply_action(HostSessionAction::SubmitViewportInput {
+            batch: HostViewportInputBatch {
+                events: vec![
+                    resize_event([800.0, 600.0]),
+                    HostViewportInputEvent::DragStart {
+                        button: HostPointerButton::Primary,
+                        screen_pos: start,
+                        modifiers: HostInputModifiers::default(),
+                    },
+                    HostViewportInputEvent::DragUpdate {
+                        button: HostPointerButton::Primary,
+                        screen_pos: end,
+                        delta_px: [end[0] - start[0], end[1] - start[1]],
+                    },
+                    HostViewportInputEvent::DragEnd {
+                        button: HostPointerButton::Primary,
+                        screen_pos: Some(end),
+                    },
+                ],
+            },
+        })
+        .expect("Wischgeste muss ueber die Bridge verarbeitet werden");
+
+    let map = session.state.road_map.as_deref().expect("Karte erwartet");
+    assert!(!map.has_connection(1, 2));
+    assert!(map.has_connection(2, 1));
+    assert_eq!(session.state.view.camera.position, camera_before);
+}
+
+#[test]
+fn viewport_input_measure_taps_build_measure_line_without_nodes() {
+    let mut session = HostBridgeSession::new();
+    session.state.road_map = Some(Arc::new(viewport_connected_path_map()));
+    session.state.view.viewport_size = [800.0, 600.0];
+    session
+        .apply_action(HostSessionAction::SetEditorTool {
+            tool: HostActiveTool::Measure,
+        })
+        .expect("Mess-Tool muss aktivierbar sein");
+    let node_count_before = session
+        .state
+        .road_map
+        .as_deref()
+        .map(|map| map.node_count());
+
+    let first = screen_for_world(&session, Vec2::new(0.0, 0.0));
+    let second = screen_for_world(&session, Vec2::new(0.0, -10.0));
+    session
+        .apply_action(HostSessionAction::SubmitViewportInput {
+            batch: HostViewportInputBatch {
+                events: vec![
+                    resize_event([800.0, 600.0]),
+                    tap_event(first),
+                    tap_event(second),
+                ],
+            },
+        })
+        .expect("Messpunkte muessen ueber die Bridge gesetzt werden");
+
+    let overlay = session.build_viewport_overlay_snapshot(None);
+    let measure = overlay.measure.expect("Messlinie erwartet");
+    assert_eq!(measure.segments.len(), 1);
+    assert!((measure.segments[0].length_m - 10.0).abs() < 1e-3);
+    assert!(measure.segments[0].heading_deg.abs() < 1e-3);
+    assert_eq!(
+        session
+            .state
+            .road_map
+            .as_deref()
+            .map(|map| map.node_count()),
+        node_count_before
+    );
+    assert!(session.state.selection.selected_node_ids.is_empty());
+
+    session
+        .apply_action(HostSessionAction::SetEditorTool {
+            tool: HostActiveTool::Select,
+        })
+        .expect("Werkzeugwechsel muss funktionieren");
+    assert!(session
+        .build_viewport_overlay_snapshot(None)
+        .measure
+        .is_none());
+}
+
+#[test]
+fn viewport_input_knife_drag_cuts_crossed_connections() {
+    let mut session = HostBridgeSession::new();
+    session.state.road_map = Some(Arc::new(viewport_connected_path_map()));
+    session.state.view.viewport_size = [800.0, 600.0];
+    session.state.options.knife_split_connections = true;
+    session
+        .apply_action(HostSessionAction::SetEditorTool {
+            tool: HostActiveTool::Knife,
+        })
+        .expect("Messer-Tool muss aktivierbar sein");
+
+    let start = screen_for_world(&session, Vec2::new(15.0, -5.0));
+    let end = screen_for_world(&session, Vec2::new(15.0, 5.0));
+    let camera_before = session.state.view.camera.position;
+
+    session
+        .apply_action(HostSessionAction::SubmitViewportInput {
+            batch: HostViewportInputBatch {
+                events: vec![
+                    resize_event([800.0, 600.0]),
+                    HostViewportInputEvent::DragStart {
+                        button: HostPointerButton::Primary,
+                        screen_pos: start,
+                        modifiers: HostInputModifiers::default(),
+                    },
+                    HostViewportInputEvent::DragUpdate {
+                        button: HostPointerButton::Primary,
+                        screen_pos: end,
+                        delta_px: [end[0] - start[0], end[1] - start[1]],
+                    },
+                    HostViewportInputEvent::DragEnd {
+                        button: HostPointerButton::Primary,
+                        screen_pos: Some(end),
+                    },
+                ],
+            },
+        })
+        .expect("Messer-Schnitt muss ueber die Bridge verarbeitet werden");
+
+    let map = session.state.road_map.as_deref().expect("Karte erwartet");
+    assert!(map.has_connection(1, 2));
+    assert!(!map.has_connection(2, 3));
+    assert_eq!(map.node_count(), 4);
+    assert_eq!(session.state.view.camera.position, camera_before);
+}
+
 #[test]
 fn viewport_input_alt_drag_selects_lasso_polygon_via_bridge_contract() {
     let mut session = HostBridgeSession::new();
@@ -1604,6 +1917,41 @@ fn viewport_input_double_tap_selects_segment_via_bridge_contract() {
     assert!(session.state.selection.selected_node_ids.contains(&3));
 }
 
+#[test]
+fn viewport_input_shift_double_tap_selects_reachable_nodes() {
+    let mut session = HostBridgeSession::new();
+    session.state.road_map = Some(Arc::new(viewport_connected_path_map()));
+    session.state.view.viewport_size = [800.0, 600.0];
+    session.state.options.flood_select_follow_direction = true;
+
+    let node2_screen = screen_for_world(&session, Vec2::new(10.0, 0.0));
+
+    session
+        .apply_action(HostSessionAction::SubmitViewportInput {
+            batch: HostViewportInputBatch {
+                events: vec![
+                    resize_event([800.0, 600.0]),
+                    HostViewportInputEvent::Tap {
+                        button: HostPointerButton::Primary,
+                        tap_kind: HostTapKind::Double,
+                        screen_pos: node2_screen,
+                        modifiers: HostInputModifiers {
+                            shift: true,
+                            alt: false,
+                            command: false,
+                        },
+                    },
+                ],
+            },
+        })
+        .expect("Shift-Double-Tap muss ueber die Bridge verarbeitet werden");
+
+    // Nur in Fahrtrichtung: 2 → 3, Node 1 liegt davor
+    assert_eq!(session.state.selection.selected_node_ids.len(), 2);
+    assert!(session.state.selection.selected_node_ids.contains(&2));
+    assert!(session.state.selection.selected_node_ids.contains(&3));
+}
+
 #[test]
 fn viewport_input_requires_resize_before_position_dependent_events() {
     let mut session = HostBridgeSession::new();
@@ -1658,3 +2006,47 @@ fn viewport_geometry_snapshot_is_available_via_session_surface() {
     assert!(geometry.markers.is_empty());
     assert_eq!(geometry.viewport_size, [300.0, 200.0]);
 }
+
+#[test]
+fn poll_live_link_applies_player_pose_and_reports_connection() {
+    let mut session = HostBridgeSession::new();
+    assert!(!session
+        .poll_live_link()
+        .expect("Live-Link ohne Socket muss abfragbar sein"));
+
+    let port = std::net::UdpSocket::bind(("127.0.0.1", 0))
+        .and_then(|probe| probe.local_addr())
+        .expect("freier Test-Port")
+        .port();
+    session.state.options.live_link_enabled = true;
+    session.state.options.live_link_port = port;
+    session.poll_live_link().expect("Live-Link muss binden");
+
+    let mod_socket = std::net::UdpSocket::bind(("127.0.0.1", 0)).expect("Mod-Socket");
+    mod_socket
+        .send_to(b"pos 10 95 20 0", ("127.0.0.1", port))
+        .expect("Senden an den Editor");
+    let mut changed = false;
+    for _ in 0..100 {
+        changed = session.poll_live_link().expect("Live-Link-Abfrage");
+        if changed {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    assert!(changed);
+    assert!(session.build_host_chrome_snapshot().live_link_connected);
+    let player = session
+        .build_viewport_overlay_snapshot(None)
+        .live_player
+        .expect("Spieler-Overlay nach Positionsmeldung");
+    assert_eq!(player.world_pos, Vec2::new(10.0, 20.0));
+
+    session.state.options.live_link_enabled = false;
+    assert!(session.poll_live_link().expect("Live-Link schliessen"));
+    assert!(!session.build_host_chrome_snapshot().live_link_connected);
+    assert!(session
+        .build_viewport_overlay_snapshot(None)
+        .live_player
+        .is_none());
+}
@@ -1,6 +1,7 @@
 //! Background-Renderer fuer Map-Hintergrund.
 
 use crate::shared::RenderCamera;
+use crate::types::{compute_visible_rect, RenderContext};
 use crate::RendererTargetConfig;
 use image::{DynamicImage, GenericImageView};
 use wgpu::util::DeviceExt;
@@ -9,6 +10,12 @@ use wgpu::util::DeviceExt;
 /// 1.0 bedeutet: ein Hintergrund-Texel belegt mindestens einen Screen-Pixel.
 const NEAREST_SAMPLING_TEXEL_THRESHOLD_PX: f32 = 1.0;
 
+/// Maximale Kantenlaenge einer Hintergrund-Kachel in Pixeln.
+///
+/// Wird zusaetzlich durch `max_texture_dimension_2d` des Devices begrenzt, sodass
+/// auch 8192²- oder 16384²-Uebersichten auf Geraeten mit kleinem Limit laden.
+const MAX_BACKGROUND_TILE_SIZE: u32 = 4096;
+
 /// Weltkoordinaten-Bereich des Hintergrund-Quads im Render-Vertrag.
 #[derive(Debug, Clone, Copy)]
 pub struct BackgroundWorldBounds {
@@ -23,6 +30,25 @@ pub struct BackgroundWorldBounds {
 }
 
 impl BackgroundWorldBounds {
+    /// Liefert die Weltbounds eines Pixel-Ausschnitts des Hintergrundbilds.
+    fn sub_rect(self, rect: TileRect, image_size: [u32; 2]) -> Self {
+        let width = self.max_x - self.min_x;
+        let height = self.max_y - self.min_y;
+        let image_w = image_size[0].max(1) as f32;
+        let image_h = image_size[1].max(1) as f32;
+
+        Self {
+            min_x: self.min_x + width * rect.x as f32 / image_w,
+            max_x: self.min_x + width * (rect.x + rect.width) as f32 / image_w,
+            min_y: self.min_y + height * rect.y as f32 / image_h,
+            max_y: self.min_y + height * (rect.y + rect.height) as f32 / image_h,
+        }
+    }
+
+    fn intersects(&self, min: glam::Vec2, max: glam::Vec2) -> bool {
+        self.max_x >= min.x && self.min_x <= max.x && self.max_y >= min.y && self.min_y <= max.y
+    }
+
     fn scaled(self, scale: f32) -> Self {
         let half_w = (self.max_x - self.min_x) * 0.5;
         let half_h = (self.max_y - self.min_y) * 0.5;
@@ -71,23 +97,92 @@ impl BackgroundVertex {
     }
 }
 
+/// Pixel-Ausschnitt einer Hintergrund-Kachel im Quellbild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TileRect {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// Zerlegt ein Bild in Kacheln mit hoechstens `tile_size` Pixeln Kantenlaenge.
+///
+/// Die Kacheln sind zeilenweise geordnet; Randkacheln sind entsprechend kleiner.
+fn compute_tile_layout(width: u32, height: u32, tile_size: u32) -> Vec<TileRect> {
+    let tile_size = tile_size.max(1);
+    let mut tiles = Vec::new();
+    let mut y = 0;
+    while y < height {
+        let tile_h = tile_size.min(height - y);
+        let mut x = 0;
+        while x < width {
+            let tile_w = tile_size.min(width - x);
+            tiles.push(TileRect {
+                x,
+                y,
+                width: tile_w,
+                height: tile_h,
+            });
+            x += tile_w;
+        }
+        y += tile_h;
+    }
+    tiles
+}
+
+/// Eine hochgeladene Hintergrund-Kachel mit eigener Texture und Mip-Kette.
+///
+/// Jede Kachel besitzt einen eigenen Uniform-Buffer, weil mehrere
+/// `write_buffer`-Aufrufe auf denselben Buffer innerhalb eines Render-Passes
+/// nur den letzten Stand sichtbar machen wuerden.
+struct BackgroundTile {
+    _texture: wgpu::Texture,
+    vertex_buffer: wgpu::Buffer,
+    uniform_buffer: wgpu::Buffer,
+    linear_bind_group: wgpu::BindGroup,
+    nearest_bind_group: wgpu::BindGroup,
+    bounds: BackgroundWorldBounds,
+}
+
 /// Renderer fuer Background-Map
 pub struct BackgroundRenderer {
     pipeline: wgpu::RenderPipeline,
-    bind_group_layout: wgpu::BindGroupLayoutDescriptor<'static>,
-    vertex_buffer: wgpu::Buffer,
-    uniform_buffer: wgpu::Buffer,
+    bind_group_layout: wgpu::BindGroupLayout,
 
-    // Optional: aktuelle Background-Map
-    texture: Option<wgpu::Texture>,
+    // Optional: aktuelle Background-Map (in Kacheln)
+    tiles: Vec<BackgroundTile>,
     linear_sampler: Option<wgpu::Sampler>,
     nearest_sampler: Option<wgpu::Sampler>,
-    linear_bind_group: Option<wgpu::BindGroup>,
-    nearest_bind_group: Option<wgpu::BindGroup>,
     current_bounds: Option<BackgroundWorldBounds>,
     texture_dimensions: Option<[u32; 2]>,
 }
 
+fn quad_vertices(bounds: &BackgroundWorldBounds) -> [BackgroundVertex; 6] {
+    [
+        // Dreieck 1
+        BackgroundVertex {
+            position: [bounds.min_x, bounds.min_y],
+        },
+        BackgroundVertex {
+            position: [bounds.max_x, bounds.min_y],
+        },
+        BackgroundVertex {
+            position: [bounds.max_x, bounds.max_y],
+        },
+        // Dreieck 2
+        BackgroundVertex {
+            position: [bounds.min_x, bounds.min_y],
+        },
+        BackgroundVertex {
+            position: [bounds.max_x, bounds.max_y],
+        },
+        BackgroundVertex {
+            position: [bounds.min_x, bounds.max_y],
+        },
+    ]
+}
+
 fn screen_pixels_per_background_texel(
     bounds: &BackgroundWorldBounds,
     texture_dimensions: [u32; 2],
@@ -120,14 +215,6 @@ impl BackgroundRenderer {
         shader: &wgpu::ShaderModule,
         target_config: RendererTargetConfig,
     ) -> Self {
-        // Uniform-Buffer erstellen
-        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Background Uniform Buffer"),
-            size: std::mem::size_of::<BackgroundUniforms>() as u64,
-            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
         // Bind-Group-Layout fuer Background (group(1))
         let bind_group_layout_desc = wgpu::BindGroupLayoutDescriptor {
             label: Some("Background Bind Group Layout"),
@@ -212,50 +299,22 @@ impl BackgroundRenderer {
             cache: None,
         });
 
-        // Vertex-Buffer fuer ein Dummy-Quad (wird spaeter bei set_background() aktualisiert)
-        let vertices = [
-            BackgroundVertex {
-                position: [0.0, 0.0],
-            },
-            BackgroundVertex {
-                position: [0.0, 0.0],
-            },
-            BackgroundVertex {
-                position: [0.0, 0.0],
-            },
-            BackgroundVertex {
-                position: [0.0, 0.0],
-            },
-            BackgroundVertex {
-                position: [0.0, 0.0],
-            },
-            BackgroundVertex {
-                position: [0.0, 0.0],
-            },
-        ];
-
-        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("Background Vertex Buffer"),
-            contents: bytemuck::cast_slice(&vertices),
-            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-        });
-
         Self {
             pipeline,
-            bind_group_layout: bind_group_layout_desc,
-            vertex_buffer,
-            uniform_buffer,
-            texture: None,
+            bind_group_layout,
+            tiles: Vec::new(),
             linear_sampler: None,
             nearest_sampler: None,
-            linear_bind_group: None,
-            nearest_bind_group: None,
             current_bounds: None,
             texture_dimensions: None,
         }
     }
 
-    /// Setzt die Background-Map und laedt die Texture hoch
+    /// Setzt die Background-Map und laedt sie als Kacheln mit Mip-Kette hoch.
+    ///
+    /// Bilder oberhalb der Kachelgroesse (bzw. des Device-Limits) werden in
+    /// mehrere Texturen zerlegt, die jeweils ein eigenes Quad in Weltkoordinaten
+    /// erhalten.
     pub fn set_background(
         &mut self,
         device: &wgpu::Device,
@@ -266,168 +325,190 @@ impl BackgroundRenderer {
     ) {
         log::info!("BackgroundRenderer: Lade Background-Texture...");
 
-        // Erstelle Texture aus Image
-        let (texture, linear_sampler) =
-            super::texture::create_texture_from_image(device, queue, image, "Background Texture");
+        let (width, height) = image.dimensions();
+        let tile_size = MAX_BACKGROUND_TILE_SIZE.min(device.limits().max_texture_dimension_2d);
+        let layout = compute_tile_layout(width, height, tile_size);
+        let rgba_image = image.to_rgba8();
+
+        let linear_sampler =
+            super::texture::create_trilinear_sampler(device, "Background Texture linear_sampler");
         let nearest_sampler = super::texture::create_sampler(
             device,
             "Background Texture nearest_sampler",
             wgpu::FilterMode::Nearest,
         );
-        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
-
-        // Erstelle Bind-Groups fuer lineares und pixelgenaues Sampling.
-        let bind_group_layout = device.create_bind_group_layout(&self.bind_group_layout);
-        let linear_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Background Linear Bind Group"),
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: self.uniform_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::TextureView(&texture_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: wgpu::BindingResource::Sampler(&linear_sampler),
-                },
-            ],
-        });
-        let nearest_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Background Nearest Bind Group"),
-            layout: &bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: self.uniform_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::TextureView(&texture_view),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: wgpu::BindingResource::Sampler(&nearest_sampler),
-                },
-            ],
-        });
-
-        // Update Vertex-Buffer mit korrekten Quad-Koordinaten (Weltkoordinaten)
-        let bounds = world_bounds.scaled(scale);
-        let vertices = [
-            // Dreieck 1
-            BackgroundVertex {
-                position: [bounds.min_x, bounds.min_y],
-            },
-            BackgroundVertex {
-                position: [bounds.max_x, bounds.min_y],
-            },
-            BackgroundVertex {
-                position: [bounds.max_x, bounds.max_y],
-            },
-            // Dreieck 2
-            BackgroundVertex {
-                position: [bounds.min_x, bounds.min_y],
-            },
-            BackgroundVertex {
-                position: [bounds.max_x, bounds.max_y],
-            },
-            BackgroundVertex {
-                position: [bounds.min_x, bounds.max_y],
-            },
-        ];
-
-        queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&vertices));
 
         // Speichere skalierte Bounds (fuer konsistentes UV-Mapping im Shader)
-        self.texture = Some(texture);
+        let bounds = world_bounds.scaled(scale);
+        let tiles = layout
+            .iter()
+            .map(|&rect| {
+                let tile_image =
+                    image::imageops::crop_imm(&rgba_image, rect.x, rect.y, rect.width, rect.height)
+                        .to_image();
+                let texture = super::texture::create_full_mip_texture(
+                    device,
+                    queue,
+                    tile_image,
+                    "Background Tile Texture",
+                );
+                self.create_tile(
+                    device,
+                    texture,
+                    bounds.sub_rect(rect, [width, height]),
+                    &linear_sampler,
+                    &nearest_sampler,
+                )
+            })
+            .collect();
+
+        self.tiles = tiles;
         self.linear_sampler = Some(linear_sampler);
         self.nearest_sampler = Some(nearest_sampler);
-        self.linear_bind_group = Some(linear_bind_group);
-        self.nearest_bind_group = Some(nearest_bind_group);
         self.current_bounds = Some(bounds);
-        self.texture_dimensions = Some([image.dimensions().0, image.dimensions().1]);
+        self.texture_dimensions = Some([width, height]);
 
         log::info!(
-            "BackgroundRenderer: Texture geladen ({}x{})",
-            image.dimensions().0,
-            image.dimensions().1
+            "BackgroundRenderer: Texture geladen ({}x{}, {} Kachel(n) a max. {} px)",
+            width,
+            height,
+            self.tiles.len(),
+            tile_size
         );
     }
 
+    fn create_tile(
+        &self,
+        device: &wgpu::Device,
+        texture: wgpu::Texture,
+        bounds: BackgroundWorldBounds,
+        linear_sampler: &wgpu::Sampler,
+        nearest_sampler: &wgpu::Sampler,
+    ) -> BackgroundTile {
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Background Tile Uniform Buffer"),
+            size: std::mem::size_of::<BackgroundUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Background Tile Vertex Buffer"),
+            contents: bytemuck::cast_slice(&quad_vertices(&bounds)),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Erstelle Bind-Groups fuer lineares und pixelgenaues Sampling.
+        let create_bind_group = |label: &str, sampler: &wgpu::Sampler| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: uniform_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(&texture_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::Sampler(sampler),
+                    },
+                ],
+            })
+        };
+        let linear_bind_group = create_bind_group("Background Linear Bind Group", linear_sampler);
+        let nearest_bind_group =
+            create_bind_group("Background Nearest Bind Group", nearest_sampler);
+
+        BackgroundTile {
+            _texture: texture,
+            vertex_buffer,
+            uniform_buffer,
+            linear_bind_group,
+            nearest_bind_group,
+            bounds,
+        }
+    }
+
     /// Entfernt die aktuelle Background-Map
     pub fn clear_background(&mut self) {
-        self.texture = None;
+        self.tiles.clear();
         self.linear_sampler = None;
         self.nearest_sampler = None;
-        self.linear_bind_group = None;
-        self.nearest_bind_group = None;
         self.current_bounds = None;
         self.texture_dimensions = None;
         log::info!("BackgroundRenderer: Background entfernt");
     }
 
-    /// Rendert die Background-Map
-    pub fn render(
+    /// Rendert alle sichtbaren Kacheln der Background-Map.
+    ///
+    /// Gibt die Anzahl gezeichneter Kacheln zurueck.
+    pub(crate) fn render(
         &self,
-        queue: &wgpu::Queue,
+        ctx: &RenderContext,
         render_pass: &mut wgpu::RenderPass<'_>,
-        camera: &RenderCamera,
-        viewport_size: [f32; 2],
         visible: bool,
         opacity: f32,
-    ) {
+    ) -> u32 {
         // Nichts zu rendern, wenn kein Background oder nicht visible
-        if !visible || opacity <= 0.0 {
-            return;
+        if !visible || opacity <= 0.0 || self.tiles.is_empty() {
+            return 0;
         }
-        let Some(linear_bind_group) = self.linear_bind_group.as_ref() else {
-            return;
-        };
-        let Some(nearest_bind_group) = self.nearest_bind_group.as_ref() else {
-            return;
-        };
         let Some(bounds) = self.current_bounds.as_ref() else {
-            return;
+            return 0;
         };
         let Some(texture_dimensions) = self.texture_dimensions else {
-            return;
+            return 0;
         };
 
-        let bind_group = if should_use_nearest_background_sampling(
+        let use_nearest = should_use_nearest_background_sampling(
             bounds,
             texture_dimensions,
-            camera,
-            viewport_size,
-        ) {
-            nearest_bind_group
-        } else {
-            linear_bind_group
-        };
-
-        // Update Uniforms
-        let view_proj = super::types::build_view_projection(camera, viewport_size);
-        let uniforms = BackgroundUniforms {
-            view_proj: view_proj.to_cols_array_2d(),
-            opacity: opacity.clamp(0.0, 1.0),
-            _padding1: 0.0,
-            _padding2: 0.0,
-            _padding3: 0.0,
-            texture_bounds: [bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y],
-        };
-
-        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+            ctx.camera,
+            ctx.viewport_size,
+        );
+        let view_proj = super::types::build_view_projection(ctx.camera, ctx.viewport_size);
+        let (visible_min, visible_max) = compute_visible_rect(ctx);
 
-        // Render
         render_pass.set_pipeline(&self.pipeline);
-        render_pass.set_bind_group(0, bind_group, &[]);
-        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        render_pass.draw(0..6, 0..1);
+        let mut drawn = 0u32;
+        for tile in self
+            .tiles
+            .iter()
+            .filter(|tile| tile.bounds.intersects(visible_min, visible_max))
+        {
+            let uniforms = BackgroundUniforms {
+                view_proj: view_proj.to_cols_array_2d(),
+                opacity: opacity.clamp(0.0, 1.0),
+                _padding1: 0.0,
+                _padding2: 0.0,
+                _padding3: 0.0,
+                texture_bounds: [
+                    tile.bounds.min_x,
+                    tile.bounds.max_x,
+                    tile.bounds.min_y,
+                    tile.bounds.max_y,
+                ],
+            };
+            ctx.queue
+                .write_buffer(&tile.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+
+            let bind_group = if use_nearest {
+                &tile.nearest_bind_group
+            } else {
+                &tile.linear_bind_group
+            };
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_vertex_buffer(0, tile.vertex_buffer.slice(..));
+            render_pass.draw(0..6, 0..1);
+            drawn += 1;
+        }
 
-        log::trace!("BackgroundRenderer: Gerendert");
+        log::trace!("BackgroundRenderer: {} Kachel(n) gerendert", drawn);
+        drawn
     }
 }
 
@@ -435,6 +516,55 @@ impl BackgroundRenderer {
 mod tests {
     use super::*;
 
+    #[test]
+    fn tile_layout_keeps_small_images_in_one_tile() {
+        let tiles = compute_tile_layout(2048, 1024, 4096);
+        assert_eq!(
+            tiles,
+            vec![TileRect {
+                x: 0,
+                y: 0,
+                width: 2048,
+                height: 1024,
+            }]
+        );
+    }
+
+    #[test]
+    fn tile_layout_covers_large_image_without_gaps() {
+        let tiles = compute_tile_layout(10000, 8192, 4096);
+        assert_eq!(tiles.len(), 6);
+        let covered: u64 = tiles.iter().map(|t| t.width as u64 * t.height as u64).sum();
+        assert_eq!(covered, 10000 * 8192);
+        assert!(tiles.iter().all(|t| t.width <= 4096 && t.height <= 4096));
+        assert_eq!(tiles[2].x, 8192);
+        assert_eq!(tiles[2].width, 10000 - 8192);
+    }
+
+    #[test]
+    fn tile_bounds_split_world_rect_proportionally() {
+        let bounds = BackgroundWorldBounds {
+            min_x: -1024.0,
+            max_x: 1024.0,
+            min_y: -1024.0,
+            max_y: 1024.0,
+        };
+        let rect = TileRect {
+            x: 4096,
+            y: 0,
+            width: 4096,
+            height: 4096,
+        };
+        let tile = bounds.sub_rect(rect, [8192, 8192]);
+
+        assert_eq!(tile.min_x, 0.0);
+        assert_eq!(tile.max_x, 1024.0);
+        assert_eq!(tile.min_y, -1024.0);
+        assert_eq!(tile.max_y, 0.0);
+        assert!(tile.intersects(glam::Vec2::new(10.0, -10.0), glam::Vec2::new(20.0, -5.0)));
+        assert!(!tile.intersects(glam::Vec2::new(-50.0, 10.0), glam::Vec2::new(-5.0, 20.0)));
+    }
+
     #[test]
     fn nearest_sampling_is_used_when_texels_are_magnified() {
         let bounds = BackgroundWorldBounds {
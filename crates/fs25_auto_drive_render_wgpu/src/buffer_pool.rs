@@ -0,0 +1,161 @@
+//! Gemeinsamer GPU-Buffer-Pool fuer die Vertex-/Instanz-Uploads der Sub-Renderer.
+//!
+//! Jeder Sub-Renderer besitzt einen festen Slot. Die Kapazitaet eines Slots waechst
+//! nur (mit Reserve) und schrumpft nie; der Hoechststand bleibt ueber Kartenwechsel
+//! und schwankende Selektions- bzw. Sichtbarkeitsgroessen erhalten. Dadurch entfaellt
+//! das wiederholte Neuerzeugen von Buffern, wenn die Upload-Groesse pendelt.
+
+/// Kleinste Slot-Kapazitaet in Bytes.
+const MIN_CAPACITY_BYTES: u64 = 16 * 1024;
+
+/// Fester Slot eines Sub-Renderers im [`GpuBufferPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BufferSlot {
+    /// Instanz-Daten des Marker-Renderers.
+    MarkerInstances,
+    /// Vertex-Daten des Connection-Renderers.
+    ConnectionVertices,
+    /// Instanz-Daten des Node-Renderers.
+    NodeInstances,
+}
+
+impl BufferSlot {
+    const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        match self {
+            Self::MarkerInstances => 0,
+            Self::ConnectionVertices => 1,
+            Self::NodeInstances => 2,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::MarkerInstances => "Marker Instance Buffer",
+            Self::ConnectionVertices => "Connection Vertex Buffer",
+            Self::NodeInstances => "Node Instance Buffer",
+        }
+    }
+}
+
+/// Kennzahlen des Buffer-Pools ueber die gesamte Renderer-Lebensdauer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BufferPoolStats {
+    /// Anzahl bisher erzeugter GPU-Buffer (alle Slots).
+    pub allocations: u64,
+    /// Summe der aktuell reservierten Slot-Kapazitaeten in Bytes.
+    pub capacity_bytes: u64,
+}
+
+struct PooledBuffer {
+    buffer: wgpu::Buffer,
+    capacity_bytes: u64,
+}
+
+/// Persistenter Pool fuer die Vertex-/Instanz-Buffer der Sub-Renderer.
+#[derive(Default)]
+pub(crate) struct GpuBufferPool {
+    slots: [Option<PooledBuffer>; BufferSlot::COUNT],
+    allocations: u64,
+}
+
+impl GpuBufferPool {
+    /// Laedt `bytes` an den Anfang des Slot-Buffers hoch.
+    ///
+    /// Reicht die Kapazitaet nicht, wird der Buffer einmalig mit Reserve
+    /// vergroessert; kleinere Uploads verwenden den bestehenden Buffer weiter.
+    pub fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        slot: BufferSlot,
+        bytes: &[u8],
+    ) {
+        let required = bytes.len() as u64;
+        let entry = &mut self.slots[slot.index()];
+        let current = entry.as_ref().map_or(0, |pooled| pooled.capacity_bytes);
+        let capacity = grown_capacity(current, required);
+
+        if entry.is_none() || capacity != current {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(slot.label()),
+                size: capacity,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            *entry = Some(PooledBuffer {
+                buffer,
+                capacity_bytes: capacity,
+            });
+            self.allocations += 1;
+        }
+
+        if let Some(pooled) = entry.as_ref()
+            && !bytes.is_empty()
+        {
+            queue.write_buffer(&pooled.buffer, 0, bytes);
+        }
+    }
+
+    /// Liefert den Buffer eines Slots (`None`, solange noch nie hochgeladen wurde).
+    pub fn buffer(&self, slot: BufferSlot) -> Option<&wgpu::Buffer> {
+        self.slots[slot.index()]
+            .as_ref()
+            .map(|pooled| &pooled.buffer)
+    }
+
+    /// Aktuelle Pool-Kennzahlen.
+    pub fn stats(&self) -> BufferPoolStats {
+        BufferPoolStats {
+            allocations: self.allocations,
+            capacity_bytes: self
+                .slots
+                .iter()
+                .flatten()
+                .map(|pooled| pooled.capacity_bytes)
+                .sum(),
+        }
+    }
+}
+
+/// Berechnet die neue Slot-Kapazitaet fuer einen Upload von `required` Bytes.
+///
+/// Gibt `current` unveraendert zurueck, solange der Upload hineinpasst. Sonst wird
+/// auf `required` plus 25 % Reserve aufgerundet (naechste Zweierpotenz).
+fn grown_capacity(current: u64, required: u64) -> u64 {
+    if current >= required && current > 0 {
+        return current;
+    }
+    let with_headroom = required.saturating_add(required / 4);
+    with_headroom
+        .checked_next_power_of_two()
+        .unwrap_or(with_headroom)
+        .max(MIN_CAPACITY_BYTES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity_starts_at_minimum() {
+        assert_eq!(grown_capacity(0, 0), MIN_CAPACITY_BYTES);
+        assert_eq!(grown_capacity(0, 100), MIN_CAPACITY_BYTES);
+    }
+
+    #[test]
+    fn capacity_is_kept_for_smaller_or_equal_uploads() {
+        assert_eq!(grown_capacity(1 << 20, 1 << 20), 1 << 20);
+        assert_eq!(grown_capacity(1 << 20, 1024), 1 << 20);
+    }
+
+    #[test]
+    fn capacity_grows_with_headroom() {
+        // 1 MiB + 25 % Reserve → naechste Zweierpotenz 2 MiB.
+        assert_eq!(grown_capacity(1 << 19, 1 << 20), 1 << 21);
+        // Knapp ueber der alten Kapazitaet: Reserve verhindert sofortiges Nachwachsen.
+        let grown = grown_capacity(MIN_CAPACITY_BYTES, MIN_CAPACITY_BYTES + 1);
+        assert!(grown >= (MIN_CAPACITY_BYTES + 1) * 5 / 4);
+    }
+}
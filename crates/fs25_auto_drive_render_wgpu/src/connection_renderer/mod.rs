@@ -7,7 +7,9 @@
 mod culling;
 mod mesh;
 
+use super::buffer_pool::{BufferSlot, GpuBufferPool};
 use super::fingerprint::RenderFingerprint;
+use super::render_stats::SubRendererStats;
 use super::types::{compute_visible_rect, ConnectionVertex, RenderContext, Uniforms};
 use super::RendererTargetConfig;
 use crate::shared::{RenderConnectionDirection, RenderConnectionPriority, RenderMap};
@@ -20,18 +22,18 @@ pub struct ConnectionRenderer {
     pipeline: wgpu::RenderPipeline,
     uniform_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
-    vertex_buffer: Option<wgpu::Buffer>,
-    vertex_capacity: usize,
     /// Wiederverwendbarer Scratch-Buffer fuer Vertex-Daten (vermeidet per-Frame-Allokation)
     vertex_scratch: Vec<ConnectionVertex>,
     /// Fingerabdruck der letzten Render-Inputs fuer Buffer-Skip-Detection.
     last_fingerprint: Option<RenderFingerprint>,
     /// Vertex-Anzahl des letzten Render-Passes (fuer Draw-Call bei Skip).
     last_vertex_count: u32,
-    /// Spatial-Grid fuer schnelle Viewport-Abfragen (wird bei Render-Map-Wechsel neu aufgebaut).
+    /// Spatial-Grid fuer schnelle Viewport-Abfragen (wird bei geaenderten Verbindungen neu aufgebaut).
     spatial_grid: Option<culling::ConnectionSpatialGrid>,
-    /// Pointer der zuletzt verwendeten Render-Map (fuer Grid-Rebuild-Erkennung).
-    last_grid_map_ptr: usize,
+    /// Connection-Layer-Revision des zuletzt gebauten Grids (fuer Grid-Rebuild-Erkennung).
+    last_grid_revision: u64,
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    frame_stats: SubRendererStats,
 }
 
 impl ConnectionRenderer {
@@ -119,13 +121,12 @@ impl ConnectionRenderer {
             pipeline,
             uniform_buffer,
             bind_group,
-            vertex_buffer: None,
-            vertex_capacity: 0,
             vertex_scratch: Vec::with_capacity(1024),
             last_fingerprint: None,
             last_vertex_count: 0,
             spatial_grid: None,
-            last_grid_map_ptr: 0,
+            last_grid_revision: 0,
+            frame_stats: SubRendererStats::default(),
         }
     }
 
@@ -137,8 +138,10 @@ impl ConnectionRenderer {
         &mut self,
         ctx: &RenderContext,
         render_pass: &mut wgpu::RenderPass<'_>,
+        buffer_pool: &mut GpuBufferPool,
         render_map: &RenderMap,
     ) {
+        self.frame_stats = SubRendererStats::default();
         let viewport_width = ctx.viewport_size[0];
         let viewport_height = ctx.viewport_size[1];
         if !viewport_width.is_finite()
@@ -155,12 +158,15 @@ impl ConnectionRenderer {
 
         // Fingerabdruck berechnen und mit dem letzten Frame vergleichen.
         // Bei Uebereinstimmung koennen O(n)-Loop und GPU-Upload uebersprungen werden.
-        let new_fp = RenderFingerprint::from_context(ctx, render_map);
+        let connections_revision = render_map.revisions().connections;
+        let new_fp = RenderFingerprint::from_context(ctx, connections_revision);
 
         let skip_rebuild = self.last_fingerprint.as_ref() == Some(&new_fp);
         if skip_rebuild {
             // Inputs unveraendert — Draw-Call mit gespeichertem Ergebnis wiederholen.
-            if self.last_vertex_count == 0 || self.vertex_buffer.is_none() {
+            if self.last_vertex_count == 0
+                || buffer_pool.buffer(BufferSlot::ConnectionVertices).is_none()
+            {
                 return; // nichts zu zeichnen
             }
         } else {
@@ -176,10 +182,9 @@ impl ConnectionRenderer {
                 }]),
             );
 
-            // Spatial-Grid bei Render-Map-Wechsel neu aufbauen.
-            let current_map_ptr = render_map as *const RenderMap as usize;
-            if current_map_ptr != self.last_grid_map_ptr {
-                self.last_grid_map_ptr = current_map_ptr;
+            // Spatial-Grid nur bei geaenderten Verbindungen neu aufbauen.
+            if connections_revision != self.last_grid_revision {
+                self.last_grid_revision = connections_revision;
                 let conns = render_map.connections();
                 if conns.len() >= culling::MIN_CONNECTIONS_FOR_GRID {
                     let pairs: Vec<(glam::Vec2, glam::Vec2)> =
@@ -211,6 +216,7 @@ impl ConnectionRenderer {
             let connections = render_map.connections();
             let candidates: Vec<usize> = if let Some(ref grid) = self.spatial_grid {
                 let mut c = grid.query_viewport(visible_min, visible_max);
+                self.frame_stats.spatial_queries += 1;
                 c.sort_unstable();
                 c.dedup();
                 c
@@ -295,38 +301,21 @@ impl ConnectionRenderer {
                 return;
             }
 
-            if self.vertex_buffer.is_none() || self.vertex_scratch.len() > self.vertex_capacity {
-                let vertex_size = std::mem::size_of::<ConnectionVertex>() as u64;
-                // Use next_power_of_two capacity to reduce future reallocations.
-                let new_capacity = self
-                    .vertex_scratch
-                    .len()
-                    .checked_next_power_of_two()
-                    .unwrap_or(self.vertex_scratch.len());
-                let buffer_size = (new_capacity as u64) * vertex_size;
-                self.vertex_buffer = Some(ctx.device.create_buffer(&wgpu::BufferDescriptor {
-                    label: Some("Connection Vertex Buffer"),
-                    size: buffer_size,
-                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                    mapped_at_creation: false,
-                }));
-                self.vertex_capacity = new_capacity;
-            }
-
-            if let Some(vertex_buffer) = &self.vertex_buffer {
-                ctx.queue.write_buffer(
-                    vertex_buffer,
-                    0,
-                    bytemuck::cast_slice(&self.vertex_scratch),
-                );
-            }
+            // Vertex-Daten in den gepoolten Buffer hochladen (waechst nur bei Bedarf)
+            buffer_pool.upload(
+                ctx.device,
+                ctx.queue,
+                BufferSlot::ConnectionVertices,
+                bytemuck::cast_slice(&self.vertex_scratch),
+            );
 
             self.last_vertex_count = self.vertex_scratch.len() as u32;
+            self.frame_stats.uploaded = self.last_vertex_count;
             self.last_fingerprint = Some(new_fp);
         }
 
         // Draw-Call (laeuft immer — sowohl nach Rebuild als auch bei Skip)
-        let Some(vertex_buffer) = self.vertex_buffer.as_ref() else {
+        let Some(vertex_buffer) = buffer_pool.buffer(BufferSlot::ConnectionVertices) else {
             log::error!("ConnectionRenderer: missing vertex buffer before draw call");
             return;
         };
@@ -335,5 +324,11 @@ impl ConnectionRenderer {
         render_pass.set_bind_group(0, &self.bind_group, &[]);
         render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
         render_pass.draw(0..self.last_vertex_count, 0..1);
+        self.frame_stats.drawn = self.last_vertex_count;
+    }
+
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    pub fn frame_stats(&self) -> SubRendererStats {
+        self.frame_stats
     }
 }
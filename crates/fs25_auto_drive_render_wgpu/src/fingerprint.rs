@@ -7,17 +7,22 @@
 //!
 //! # Pointer + Revision
 //!
-//! Fuer Arc-Inhalte (RenderMap, EditorOptions, IndexSets) wird weiterhin die Adresse der
+//! Fuer Arc-Inhalte (EditorOptions, IndexSets) wird weiterhin die Adresse der
 //! Arc-internen Daten als schneller Vergleich genutzt. Fuer Selection/Hidden/Dimmed werden
 //! zusaetzlich monotone Revisionszaehler aus `RenderScene` verglichen. Dadurch werden auch
 //! in-place-Mutationen sicher invalidiert, selbst wenn Pointer stabil bleiben.
 //!
+//! Die Karte geht nicht als Snapshot-Pointer ein, sondern ueber die Revision des Layers,
+//! den der jeweilige Renderer zeichnet (`RenderMapRevisions`). Ein neuer Snapshot nach
+//! einer Node-Verschiebung invalidiert damit z. B. nicht den Marker-Renderer, solange
+//! sich die Marker-Positionen nicht geaendert haben.
+//!
 //! # Float-Vergleiche
 //!
 //! Kamera- und Viewport-Floats werden als IEEE-754-Bit-Muster (u32) verglichen,
 //! um NaN-Gleichheitsprobleme zu vermeiden und exakte Frame-zu-Frame-Aenderungen zu erkennen.
 
-use crate::shared::{EditorOptions, RenderMap};
+use crate::shared::EditorOptions;
 use indexmap::IndexSet;
 
 use super::types::RenderContext;
@@ -29,8 +34,8 @@ use super::types::RenderContext;
 /// Bei Uebereinstimmung koennen Buffer-Aufbau und GPU-Upload uebersprungen werden.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub(crate) struct RenderFingerprint {
-    /// Pointer-Adresse der RenderMap-Daten (0 = keine Map).
-    pub render_map_ptr: usize,
+    /// Inhalts-Revision des gezeichneten RenderMap-Layers (0 = keine Map).
+    pub layer_revision: u64,
     /// Pointer-Adresse der EditorOptions-Daten.
     pub options_ptr: usize,
     /// Pointer-Adresse der HiddenNodeIds-Daten.
@@ -60,13 +65,14 @@ pub(crate) struct RenderFingerprint {
 }
 
 impl RenderFingerprint {
-    /// Erstellt einen Basis-Fingerabdruck aus dem gemeinsamen Render-Kontext und der RenderMap.
+    /// Erstellt einen Basis-Fingerabdruck aus dem gemeinsamen Render-Kontext und der
+    /// Revision des Map-Layers, den der Renderer zeichnet.
     ///
     /// Renderer-spezifische Felder (`dimmed_ptr`, `selected_ptr`, `quality`) sind auf
     /// Null initialisiert und muessen vom Aufrufer bei Bedarf manuell gesetzt werden.
-    pub fn from_context(ctx: &RenderContext<'_>, render_map: &RenderMap) -> Self {
+    pub fn from_context(ctx: &RenderContext<'_>, layer_revision: u64) -> Self {
         Self {
-            render_map_ptr: render_map as *const RenderMap as usize,
+            layer_revision,
             options_ptr: ctx.options as *const EditorOptions as usize,
             hidden_ptr: ctx.hidden_node_ids as *const IndexSet<u64> as usize,
             hidden_revision: ctx.hidden_node_ids_revision,
@@ -89,9 +95,9 @@ mod tests {
     use super::RenderFingerprint;
 
     /// Hilfsfunktion: Erzeugt einen Fingerabdruck mit definierten Testwerten.
-    fn make_fp(ptr: usize, cam_x: f32, cam_y: f32, zoom: f32) -> RenderFingerprint {
+    fn make_fp(layer_revision: u64, cam_x: f32, cam_y: f32, zoom: f32) -> RenderFingerprint {
         RenderFingerprint {
-            render_map_ptr: ptr,
+            layer_revision,
             options_ptr: 0x2000,
             hidden_ptr: 0x3000,
             hidden_revision: 1,
@@ -135,8 +141,8 @@ mod tests {
     }
 
     #[test]
-    fn geaenderter_render_map_pointer_ergibt_anderen_fingerprint() {
-        // Neuer RenderMap-Snapshot (neuer Arc) muss als Aenderung erkannt werden.
+    fn geaenderte_layer_revision_ergibt_anderen_fingerprint() {
+        // Geaenderter Layer-Inhalt (neue Revision) muss als Aenderung erkannt werden.
         let fp1 = make_fp(0x1000, 0.0, 0.0, 1.0);
         let fp2 = make_fp(0x2000, 0.0, 0.0, 1.0);
         assert_ne!(fp1, fp2);
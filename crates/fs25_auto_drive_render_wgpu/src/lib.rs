@@ -7,20 +7,24 @@ compile_error!("Feature 'flutter-android' erfordert target_os = \"android\"");
 compile_error!("Feature 'flutter-linux' erfordert target_os = \"linux\"");
 
 mod background_renderer;
+mod buffer_pool;
 mod connection_renderer;
 mod export_core;
 pub mod external_texture;
 mod fingerprint;
 mod marker_renderer;
 mod node_renderer;
+mod render_stats;
 mod shared_texture;
 mod texture;
 mod texture_registration;
 mod types;
 
 pub use background_renderer::BackgroundWorldBounds;
+pub use buffer_pool::BufferPoolStats;
 pub use fs25_auto_drive_engine::shared;
 pub use fs25_auto_drive_engine::shared::{RenderQuality, RenderScene};
+pub use render_stats::{RenderStats, SubRendererStats};
 pub use shared_texture::{
     SharedTextureAlphaMode, SharedTextureError, SharedTextureFrame, SharedTextureNativeHandle,
     SharedTexturePixelFormat, SharedTextureRuntime,
@@ -38,6 +42,7 @@ pub use texture_registration::{
 };
 
 pub(crate) use background_renderer::BackgroundRenderer;
+use buffer_pool::GpuBufferPool;
 pub(crate) use connection_renderer::ConnectionRenderer;
 #[cfg(all(feature = "flutter-android", target_os = "android"))]
 pub use external_texture::vulkan_android::VulkanAhbTexture;
@@ -125,6 +130,10 @@ pub struct Renderer {
     connection_renderer: ConnectionRenderer,
     node_renderer: NodeRenderer,
     marker_renderer: MarkerRenderer,
+    /// Gemeinsamer, persistenter Pool fuer Vertex-/Instanz-Buffer der Sub-Renderer.
+    buffer_pool: GpuBufferPool,
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    last_frame_stats: RenderStats,
 }
 
 impl Renderer {
@@ -150,6 +159,8 @@ impl Renderer {
             connection_renderer,
             node_renderer,
             marker_renderer,
+            buffer_pool: GpuBufferPool::default(),
+            last_frame_stats: RenderStats::default(),
         }
     }
 
@@ -175,14 +186,14 @@ impl Renderer {
             selected_node_ids_revision: scene.selected_node_ids_revision(),
         };
 
+        let mut stats = RenderStats::default();
+
         // 1. Render Background zuerst (falls vorhanden)
         if scene.has_background() {
             let opacity = compute_background_opacity(scene.camera().zoom, scene.options());
-            self.background_renderer.render(
-                queue,
+            stats.background_tiles = self.background_renderer.render(
+                &ctx,
                 render_pass,
-                scene.camera(),
-                scene.viewport_size(),
                 scene.background_visible(),
                 opacity,
             );
@@ -190,22 +201,50 @@ impl Renderer {
 
         // 2. Render Markers (hinter Connections und Nodes)
         if let Some(render_map) = scene.map() {
-            self.marker_renderer
-                .render(&ctx, render_pass, render_map, scene.render_quality());
+            self.marker_renderer.render(
+                &ctx,
+                render_pass,
+                &mut self.buffer_pool,
+                render_map,
+                scene.render_quality(),
+            );
 
             // 3. Render Connections (darueber)
             self.connection_renderer
-                .render(&ctx, render_pass, render_map);
+                .render(&ctx, render_pass, &mut self.buffer_pool, render_map);
 
             // 4. Render Nodes (zuoberst)
             self.node_renderer.render(
                 &ctx,
                 render_pass,
+                &mut self.buffer_pool,
                 render_map,
                 scene.render_quality(),
                 scene.selected_node_ids(),
             );
+
+            stats.markers = self.marker_renderer.frame_stats();
+            stats.connections = self.connection_renderer.frame_stats();
+            stats.nodes = self.node_renderer.frame_stats();
         }
+
+        self.last_frame_stats = stats;
+    }
+
+    /// Liefert die Zaehlwerte des zuletzt gerenderten Frames.
+    ///
+    /// Gedacht fuer Profiling-Overlays: hochgeladene und gezeichnete
+    /// Instanzen/Vertices pro Sub-Renderer sowie deren Spatial-Abfragen.
+    pub fn last_frame_stats(&self) -> RenderStats {
+        self.last_frame_stats
+    }
+
+    /// Liefert die Kennzahlen des gemeinsamen Vertex-/Instanz-Buffer-Pools.
+    ///
+    /// `allocations` zaehlt alle bisher erzeugten Buffer; bleibt der Wert ueber
+    /// viele Frames konstant, laufen Uploads ohne Buffer-Neuerzeugung.
+    pub fn buffer_pool_stats(&self) -> BufferPoolStats {
+        self.buffer_pool.stats()
     }
 
     /// Setzt das Hintergrundbild fuer den Renderer.
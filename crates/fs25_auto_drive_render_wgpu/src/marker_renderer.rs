@@ -1,6 +1,8 @@
 //! Marker-Renderer mit GPU-Instancing fuer Map-Marker (Pin-Symbole).
 
+use super::buffer_pool::{BufferSlot, GpuBufferPool};
 use super::fingerprint::RenderFingerprint;
+use super::render_stats::SubRendererStats;
 use super::types::{MarkerInstance, RenderContext, RenderQuality, Uniforms, Vertex};
 use super::RendererTargetConfig;
 use crate::shared::options::MARKER_OUTLINE_WIDTH;
@@ -19,8 +21,6 @@ pub struct MarkerRenderer {
     uniform_buffer: wgpu::Buffer,
     bind_group_layout: wgpu::BindGroupLayout,
     bind_group: wgpu::BindGroup,
-    instance_buffer: Option<wgpu::Buffer>,
-    instance_capacity: usize,
     /// Wiederverwendbarer Scratch-Buffer fuer Instanz-Daten (verhindert per-Frame-Allokation)
     instance_scratch: Vec<MarkerInstance>,
     // Pin-Icon-Textur (muss gehalten werden, damit GPU-Ressourcen nicht freigegeben werden)
@@ -32,6 +32,8 @@ pub struct MarkerRenderer {
     last_fingerprint: Option<RenderFingerprint>,
     /// Instanzanzahl des letzten Render-Passes (fuer Draw-Call bei Skip).
     last_instance_count: u32,
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    frame_stats: SubRendererStats,
 }
 
 /// Patcht die stroke-width im SVG-String auf den angegebenen Wert.
@@ -246,14 +248,13 @@ impl MarkerRenderer {
             uniform_buffer,
             bind_group_layout,
             bind_group,
-            instance_buffer: None,
-            instance_capacity: 0,
             instance_scratch: Vec::with_capacity(256),
             _texture: texture,
             _sampler: sampler,
             last_outline_width: MARKER_OUTLINE_WIDTH,
             last_fingerprint: None,
             last_instance_count: 0,
+            frame_stats: SubRendererStats::default(),
         }
     }
 
@@ -306,47 +307,55 @@ impl MarkerRenderer {
         &mut self,
         ctx: &RenderContext,
         render_pass: &mut wgpu::RenderPass<'_>,
+        buffer_pool: &mut GpuBufferPool,
         render_map: &RenderMap,
         render_quality: RenderQuality,
     ) {
+        self.frame_stats = SubRendererStats::default();
         if render_map.marker_count() == 0 {
             return;
         }
 
-        // Fingerabdruck berechnen und mit dem letzten Frame vergleichen.
-        // Bei Uebereinstimmung koennen Instanz-Aufbau und GPU-Upload uebersprungen werden.
+        // Uniforms (View-Projection-Matrix + AA) folgen jedem Kamera-Schwenk und sind
+        // guenstig — sie werden daher unabhaengig vom Instanz-Rebuild jeden Frame geschrieben.
+        let view_proj = super::types::build_view_projection(ctx.camera, ctx.viewport_size);
+        let aa_params = match render_quality {
+            RenderQuality::Low => [0.0, 1.0, 0.0, 0.0],
+            RenderQuality::Medium => [1.0, 0.0, 0.0, 0.0],
+            RenderQuality::High => [1.8, 0.0, 0.0, 0.0],
+        };
+        let uniforms = Uniforms {
+            view_proj: view_proj.to_cols_array_2d(),
+            aa_params,
+        };
+        ctx.queue
+            .write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+
+        // Fingerabdruck der Instanz-Daten: Marker werden nicht gecullt, daher fliessen
+        // weder Kamera-Position noch Viewport-Breite oder Hidden-Menge ein. Zoom und
+        // Viewport-Hoehe bleiben relevant (Zoom-Kompensation, Mindestgroesse in Pixeln).
         let new_fp = {
-            let mut fp = RenderFingerprint::from_context(ctx, render_map);
-            fp.quality = render_quality as u8;
+            let mut fp = RenderFingerprint::from_context(ctx, render_map.revisions().markers);
+            fp.hidden_ptr = 0;
+            fp.hidden_revision = 0;
+            fp.camera_x = 0;
+            fp.camera_y = 0;
+            fp.viewport_w = 0;
             fp
         };
 
         let skip_rebuild = self.last_fingerprint.as_ref() == Some(&new_fp);
         if skip_rebuild {
-            // Inputs unveraendert — Draw-Call mit gespeichertem Ergebnis wiederholen.
-            if self.last_instance_count == 0 || self.instance_buffer.is_none() {
+            // Marker-Layer unveraendert — Draw-Call mit gespeicherten Instanzen wiederholen.
+            if self.last_instance_count == 0
+                || buffer_pool.buffer(BufferSlot::MarkerInstances).is_none()
+            {
                 return; // nichts zu zeichnen
             }
         } else {
             // Textur neu aufbauen wenn outline_width geaendert hat
             self.rebuild_texture_if_needed(ctx.device, ctx.queue, ctx.options.marker_outline_width);
 
-            // Uniforms erstellen (View-Projection-Matrix + AA aus View-Einstellungen)
-            let view_proj = super::types::build_view_projection(ctx.camera, ctx.viewport_size);
-            let aa_params = match render_quality {
-                RenderQuality::Low => [0.0, 1.0, 0.0, 0.0],
-                RenderQuality::Medium => [1.0, 0.0, 0.0, 0.0],
-                RenderQuality::High => [1.8, 0.0, 0.0, 0.0],
-            };
-            let uniforms = Uniforms {
-                view_proj: view_proj.to_cols_array_2d(),
-                aa_params,
-            };
-
-            // Uniforms hochladen
-            ctx.queue
-                .write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
-
             // Zoom-Kompensation und Mindestgroesse einmalig pro Frame berechnen.
             let compensation = ctx.options.zoom_compensation(ctx.camera.zoom);
             let wpp = ctx.camera.world_per_pixel(ctx.viewport_size[1]);
@@ -373,26 +382,16 @@ impl MarkerRenderer {
                 return;
             }
 
-            // Instanz-Buffer erstellen oder resizen
-            let needed_capacity = self.instance_scratch.len();
-            if self.instance_buffer.is_none() || self.instance_capacity < needed_capacity {
-                let new_capacity = needed_capacity.max(64).next_power_of_two();
-                self.instance_buffer = Some(ctx.device.create_buffer(&wgpu::BufferDescriptor {
-                    label: Some("Marker Instance Buffer"),
-                    size: (new_capacity * std::mem::size_of::<MarkerInstance>()) as u64,
-                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                    mapped_at_creation: false,
-                }));
-                self.instance_capacity = new_capacity;
-            }
-
-            // Daten hochladen
-            if let Some(buffer) = &self.instance_buffer {
-                ctx.queue
-                    .write_buffer(buffer, 0, bytemuck::cast_slice(&self.instance_scratch));
-            }
+            // Instanz-Daten in den gepoolten Buffer hochladen (waechst nur bei Bedarf)
+            buffer_pool.upload(
+                ctx.device,
+                ctx.queue,
+                BufferSlot::MarkerInstances,
+                bytemuck::cast_slice(&self.instance_scratch),
+            );
 
             self.last_instance_count = self.instance_scratch.len() as u32;
+            self.frame_stats.uploaded = self.last_instance_count;
             self.last_fingerprint = Some(new_fp);
         }
 
@@ -400,9 +399,15 @@ impl MarkerRenderer {
         render_pass.set_pipeline(&self.pipeline);
         render_pass.set_bind_group(0, &self.bind_group, &[]);
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        if let Some(buffer) = &self.instance_buffer {
+        if let Some(buffer) = buffer_pool.buffer(BufferSlot::MarkerInstances) {
             render_pass.set_vertex_buffer(1, buffer.slice(..));
         }
         render_pass.draw(0..6, 0..self.last_instance_count);
+        self.frame_stats.drawn = self.last_instance_count;
+    }
+
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    pub fn frame_stats(&self) -> SubRendererStats {
+        self.frame_stats
     }
 }
@@ -1,6 +1,8 @@
 //! Node-Renderer mit GPU-Instancing.
 
+use super::buffer_pool::{BufferSlot, GpuBufferPool};
 use super::fingerprint::RenderFingerprint;
+use super::render_stats::SubRendererStats;
 use super::types::{
     compute_visible_rect, NodeInstance, RenderContext, RenderQuality, Uniforms, Vertex,
 };
@@ -16,8 +18,6 @@ pub struct NodeRenderer {
     vertex_buffer: wgpu::Buffer,
     uniform_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
-    instance_buffer: Option<wgpu::Buffer>,
-    instance_capacity: usize,
     /// Wiederverwendbarer Scratch-Buffer fuer Instanzdaten (vermeidet per-Frame-Allokation)
     instance_scratch: Vec<NodeInstance>,
     /// Wiederverwendbarer Scratch-Buffer fuer sichtbare Node-IDs (KD-Query ohne pro-Frame-Vec)
@@ -28,6 +28,8 @@ pub struct NodeRenderer {
     last_fingerprint: Option<RenderFingerprint>,
     /// Instanzanzahl des letzten Render-Passes (fuer Draw-Call bei Skip).
     last_instance_count: u32,
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    frame_stats: SubRendererStats,
 }
 
 impl NodeRenderer {
@@ -149,29 +151,30 @@ impl NodeRenderer {
             vertex_buffer,
             uniform_buffer,
             bind_group,
-            instance_buffer: None,
-            instance_capacity: 0,
             // Reserve initial capacity to avoid tiny growths on first frames.
             instance_scratch: Vec::with_capacity(1024),
             node_id_scratch: Vec::with_capacity(1024),
             decimation_grid: HashMap::with_capacity(1024),
             last_fingerprint: None,
             last_instance_count: 0,
+            frame_stats: SubRendererStats::default(),
         }
     }
 
     /// Rendert alle sichtbaren Nodes der RoadMap per GPU-Instancing.
     ///
     /// Fuehrt Viewport-Culling durch und schreibt Instanzdaten in den
-    /// wiederverwendbaren Instance-Buffer.
+    /// Node-Slot des gemeinsamen Buffer-Pools.
     pub fn render(
         &mut self,
         ctx: &RenderContext,
         render_pass: &mut wgpu::RenderPass<'_>,
+        buffer_pool: &mut GpuBufferPool,
         render_map: &RenderMap,
         render_quality: RenderQuality,
         selected_node_ids: &IndexSet<u64>,
     ) {
+        self.frame_stats = SubRendererStats::default();
         let selected_set = selected_node_ids;
         let viewport_width = ctx.viewport_size[0];
         let viewport_height = ctx.viewport_size[1];
@@ -186,7 +189,7 @@ impl NodeRenderer {
         // Fingerabdruck berechnen und mit dem letzten Frame vergleichen.
         // Bei Uebereinstimmung kann der gesamte CPU/GPU-Rebuild uebersprungen werden.
         let new_fp = {
-            let mut fp = RenderFingerprint::from_context(ctx, render_map);
+            let mut fp = RenderFingerprint::from_context(ctx, render_map.revisions().nodes);
             fp.dimmed_ptr = ctx.dimmed_node_ids as *const IndexSet<u64> as usize;
             fp.dimmed_revision = ctx.dimmed_node_ids_revision;
             fp.selected_ptr = selected_node_ids as *const IndexSet<u64> as usize;
@@ -198,7 +201,9 @@ impl NodeRenderer {
         let skip_rebuild = self.last_fingerprint.as_ref() == Some(&new_fp);
         if skip_rebuild {
             // Inputs unveraendert — Draw-Call mit gespeichertem Ergebnis wiederholen.
-            if self.last_instance_count == 0 || self.instance_buffer.is_none() {
+            if self.last_instance_count == 0
+                || buffer_pool.buffer(BufferSlot::NodeInstances).is_none()
+            {
                 return; // nichts zu zeichnen
             }
         } else {
@@ -210,6 +215,7 @@ impl NodeRenderer {
             self.node_id_scratch.clear();
 
             render_map.nodes_within_rect_into(min, max, &mut self.node_id_scratch);
+            self.frame_stats.spatial_queries += 1;
 
             // Zoom-Kompensationsfaktor einmalig pro Frame berechnen (nicht pro Node).
             let compensation = ctx.options.zoom_compensation(ctx.camera.zoom);
@@ -337,40 +343,21 @@ impl NodeRenderer {
             ctx.queue
                 .write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
 
-            // Instance-Buffer erstellen/aktualisieren (Reuse)
-            if self.instance_buffer.is_none()
-                || self.instance_scratch.len() > self.instance_capacity
-            {
-                let instance_size = std::mem::size_of::<NodeInstance>() as u64;
-                let new_capacity = self
-                    .instance_scratch
-                    .len()
-                    .checked_next_power_of_two()
-                    .unwrap_or(self.instance_scratch.len());
-                let buffer_size = (new_capacity as u64) * instance_size;
-                self.instance_buffer = Some(ctx.device.create_buffer(&wgpu::BufferDescriptor {
-                    label: Some("Instance Buffer"),
-                    size: buffer_size,
-                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                    mapped_at_creation: false,
-                }));
-                self.instance_capacity = new_capacity;
-            }
-
-            if let Some(instance_buffer) = &self.instance_buffer {
-                ctx.queue.write_buffer(
-                    instance_buffer,
-                    0,
-                    bytemuck::cast_slice(&self.instance_scratch),
-                );
-            }
+            // Instanz-Daten in den gepoolten Buffer hochladen (waechst nur bei Bedarf)
+            buffer_pool.upload(
+                ctx.device,
+                ctx.queue,
+                BufferSlot::NodeInstances,
+                bytemuck::cast_slice(&self.instance_scratch),
+            );
 
             self.last_instance_count = self.instance_scratch.len() as u32;
+            self.frame_stats.uploaded = self.last_instance_count;
             self.last_fingerprint = Some(new_fp);
         }
 
         // Draw-Call (laeuft immer — sowohl nach Rebuild als auch bei Skip)
-        let Some(instance_buffer) = self.instance_buffer.as_ref() else {
+        let Some(instance_buffer) = buffer_pool.buffer(BufferSlot::NodeInstances) else {
             log::error!("NodeRenderer: missing instance buffer before draw call");
             return;
         };
@@ -380,5 +367,11 @@ impl NodeRenderer {
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
         render_pass.set_vertex_buffer(1, instance_buffer.slice(..));
         render_pass.draw(0..6, 0..self.last_instance_count);
+        self.frame_stats.drawn = self.last_instance_count;
+    }
+
+    /// Zaehlwerte des zuletzt gerenderten Frames.
+    pub fn frame_stats(&self) -> SubRendererStats {
+        self.frame_stats
     }
 }
@@ -0,0 +1,69 @@
+//! Per-Frame-Zaehlwerte der Sub-Renderer fuer Profiling-Anzeigen.
+
+/// Zaehlwerte eines Sub-Renderers fuer den zuletzt gerenderten Frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubRendererStats {
+    /// In diesem Frame in den GPU-Buffer hochgeladene Instanzen bzw. Vertices.
+    ///
+    /// Bleibt `0`, wenn der Rebuild per Fingerabdruck uebersprungen wurde.
+    pub uploaded: u32,
+    /// Gezeichnete Instanzen bzw. Vertices (auch bei uebersprungenem Rebuild).
+    pub drawn: u32,
+    /// Anzahl der Spatial-Abfragen (KD-Tree bzw. Culling-Grid) in diesem Frame.
+    pub spatial_queries: u32,
+}
+
+/// Zaehlwerte des gesamten Renderers fuer den zuletzt gerenderten Frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Anzahl gezeichneter Background-Kacheln.
+    pub background_tiles: u32,
+    /// Marker-Renderer (Instanzen).
+    pub markers: SubRendererStats,
+    /// Connection-Renderer (Vertices).
+    pub connections: SubRendererStats,
+    /// Node-Renderer (Instanzen).
+    pub nodes: SubRendererStats,
+}
+
+impl RenderStats {
+    /// Summe aller in diesem Frame hochgeladenen Instanzen und Vertices.
+    pub fn total_uploaded(&self) -> u32 {
+        self.markers.uploaded + self.connections.uploaded + self.nodes.uploaded
+    }
+
+    /// Summe aller Spatial-Abfragen der Sub-Renderer in diesem Frame.
+    pub fn spatial_queries(&self) -> u32 {
+        self.markers.spatial_queries + self.connections.spatial_queries + self.nodes.spatial_queries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn totals_sum_all_sub_renderers() {
+        let stats = RenderStats {
+            background_tiles: 4,
+            markers: SubRendererStats {
+                uploaded: 3,
+                drawn: 3,
+                spatial_queries: 0,
+            },
+            connections: SubRendererStats {
+                uploaded: 0,
+                drawn: 120,
+                spatial_queries: 1,
+            },
+            nodes: SubRendererStats {
+                uploaded: 40,
+                drawn: 40,
+                spatial_queries: 1,
+            },
+        };
+
+        assert_eq!(stats.total_uploaded(), 43);
+        assert_eq!(stats.spatial_queries(), 2);
+    }
+}
@@ -74,54 +74,7 @@ pub fn create_texture_from_image(
         view_formats: &[],
     });
 
-    // Schreibe Daten in die Texture (Mip-Level 0)
-    queue.write_texture(
-        texture.as_image_copy(),
-        &rgba_image,
-        wgpu::TexelCopyBufferLayout {
-            offset: 0,
-            bytes_per_row: Some(4 * width),
-            rows_per_image: Some(height),
-        },
-        wgpu::Extent3d {
-            width,
-            height,
-            depth_or_array_layers: 1,
-        },
-    );
-
-    // Erzeuge Mip-Levels 1..N per CPU-seitigem Downsampling (image::Triangle-Filter).
-    // Fuer Texturen <= 256 px entfaellt die Schleife (mip_level_count == 1).
-    if mip_level_count > 1 {
-        let mut current_image = rgba_image;
-        for level in 1..mip_level_count {
-            let mip_width = (width >> level).max(1);
-            let mip_height = (height >> level).max(1);
-            let mip_image = image::DynamicImage::ImageRgba8(current_image)
-                .resize_exact(mip_width, mip_height, image::imageops::FilterType::Triangle)
-                .to_rgba8();
-            queue.write_texture(
-                wgpu::TexelCopyTextureInfo {
-                    texture: &texture,
-                    mip_level: level,
-                    origin: wgpu::Origin3d::ZERO,
-                    aspect: wgpu::TextureAspect::All,
-                },
-                &mip_image,
-                wgpu::TexelCopyBufferLayout {
-                    offset: 0,
-                    bytes_per_row: Some(4 * mip_width),
-                    rows_per_image: Some(mip_height),
-                },
-                wgpu::Extent3d {
-                    width: mip_width,
-                    height: mip_height,
-                    depth_or_array_layers: 1,
-                },
-            );
-            current_image = mip_image;
-        }
-    }
+    write_mip_chain(queue, &texture, rgba_image, mip_level_count);
 
     // Erstelle Sampler — Mipmap-Filter Linear wenn mehrere Levels vorhanden
     let sampler_label = format!("{}_sampler", label);
@@ -143,6 +96,122 @@ pub fn create_texture_from_image(
     (texture, sampler)
 }
 
+/// Erstellt eine Texture mit vollstaendiger Mip-Kette (bis 1×1) aus RGBA8-Daten.
+///
+/// Gedacht fuer grosse Hintergrund-Kacheln, die bei niedrigem Zoom stark
+/// verkleinert dargestellt werden — die volle Kette verhindert Flimmern, weil
+/// der Sampler immer ein passend vorgefiltertes Level findet.
+pub(crate) fn create_full_mip_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    rgba_image: image::RgbaImage,
+    label: &str,
+) -> wgpu::Texture {
+    let (width, height) = rgba_image.dimensions();
+    let mip_level_count = calculate_full_mip_levels(width.max(height));
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+
+    write_mip_chain(queue, &texture, rgba_image, mip_level_count);
+    texture
+}
+
+/// Erstellt einen trilinearen Sampler mit anisotroper Filterung.
+///
+/// Fuer verkleinert dargestellte Texturen mit Mip-Kette (Hintergrund-Kacheln).
+pub(crate) fn create_trilinear_sampler(device: &wgpu::Device, label: &str) -> wgpu::Sampler {
+    device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some(label),
+        address_mode_u: wgpu::AddressMode::ClampToEdge,
+        address_mode_v: wgpu::AddressMode::ClampToEdge,
+        address_mode_w: wgpu::AddressMode::ClampToEdge,
+        mag_filter: wgpu::FilterMode::Linear,
+        min_filter: wgpu::FilterMode::Linear,
+        mipmap_filter: wgpu::MipmapFilterMode::Linear,
+        anisotropy_clamp: 16,
+        ..Default::default()
+    })
+}
+
+/// Laedt Mip-Level 0 hoch und erzeugt die Levels 1..N per CPU-Downsampling.
+///
+/// Jedes Level wird aus dem vorherigen mit Triangle-Filter halbiert.
+fn write_mip_chain(
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    rgba_image: image::RgbaImage,
+    mip_level_count: u32,
+) {
+    let (width, height) = rgba_image.dimensions();
+
+    // Schreibe Daten in die Texture (Mip-Level 0)
+    queue.write_texture(
+        texture.as_image_copy(),
+        &rgba_image,
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some(4 * width),
+            rows_per_image: Some(height),
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+    );
+
+    // Erzeuge Mip-Levels 1..N per CPU-seitigem Downsampling (image::Triangle-Filter).
+    let mut current_image = rgba_image;
+    for level in 1..mip_level_count {
+        let mip_width = (width >> level).max(1);
+        let mip_height = (height >> level).max(1);
+        let mip_image = image::imageops::resize(
+            &current_image,
+            mip_width,
+            mip_height,
+            image::imageops::FilterType::Triangle,
+        );
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: level,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &mip_image,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * mip_width),
+                rows_per_image: Some(mip_height),
+            },
+            wgpu::Extent3d {
+                width: mip_width,
+                height: mip_height,
+                depth_or_array_layers: 1,
+            },
+        );
+        current_image = mip_image;
+    }
+}
+
+/// Anzahl der Mip-Levels einer vollstaendigen Kette bis 1×1.
+fn calculate_full_mip_levels(size: u32) -> u32 {
+    u32::BITS - size.max(1).leading_zeros()
+}
+
 /// Berechnet die Anzahl der Mip-Levels fuer eine gegebene Texturgroesse.
 ///
 /// Texturen kleiner oder gleich 256 Pixel erhalten kein Mipmap (Level 1).
@@ -183,6 +252,15 @@ mod tests {
         assert_eq!(calculate_mip_levels(32768), 8); // limitiert
     }
 
+    #[test]
+    fn test_full_mip_levels_reach_one_pixel() {
+        assert_eq!(calculate_full_mip_levels(0), 1);
+        assert_eq!(calculate_full_mip_levels(1), 1);
+        assert_eq!(calculate_full_mip_levels(2), 2);
+        assert_eq!(calculate_full_mip_levels(4096), 13);
+        assert_eq!(calculate_full_mip_levels(4097), 13);
+    }
+
     #[test]
     fn test_mip_levels_edge_cases() {
         assert_eq!(calculate_mip_levels(1), 1);
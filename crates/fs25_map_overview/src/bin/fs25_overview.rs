@@ -0,0 +1,516 @@
+//! `fs25-overview` — Overview-Maps von der Kommandozeile erzeugen.
+//!
+//! Verarbeitet ein oder mehrere Map-Mod-ZIPs bzw. entpackte Mod-Ordner.
+//! Ein Ordner ohne eigene `modDesc.xml` gilt als Mod-Verzeichnis: alle darin
+//! liegenden ZIPs werden verarbeitet, Mods ohne Karte werden uebersprungen.
+//! So lassen sich z.B. auf einem Server alle Karten in einem Lauf erzeugen.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{bail, Context, Result};
+use fs25_map_overview::{
+    discover_map_from_zip, generate_overview_from_zip_with_progress,
+    generate_overview_svg_from_zip, save_with_world_file, ContourOptions, MapDataCache,
+    OverviewOptions, TerrainPalette,
+};
+use image::DynamicImage;
+
+const USAGE: &str = "\
+Verwendung: fs25-overview [OPTIONEN] <EINGABE>...
+
+EINGABE ist ein Map-Mod-ZIP, ein entpackter Mod-Ordner oder ein Ordner mit
+Mod-ZIPs (z.B. das mods-Verzeichnis eines Servers).
+
+Optionen:
+  -o, --output <PFAD>      Ausgabedatei (eine Karte) oder Ausgabeordner
+                           (Standard: aktueller Ordner, <name>_overview.<format>)
+      --format <FORMAT>    png, jpg oder svg (Standard: aus --output, sonst png)
+      --size <PX>          Kantenlaenge des Ausgabebilds (Standard: Kartengroesse)
+      --supersample <N>    Supersampling-Faktor 1-4 (Standard: 1)
+      --palette <DATEI>    Eigene Terrain-Palette (.toml oder .json)
+      --map-image          Mitgeliefertes Kartenbild (overview.dds) statt
+                           Weight-Maps als Terrain verwenden
+      --with <LAYER,...>   Layer zusaetzlich einschalten
+      --without <LAYER,...>
+                           Layer ausschalten
+      --no-poi-clusters    Nahe beieinander liegende POIs einzeln statt als
+                           Gruppe mit Anzahl zeichnen
+      --subtitle           Kartengroesse und Autor unter dem Titel anzeigen
+      --hillshade-azimuth <GRAD>
+                           Lichtrichtung des Hillshades (Standard: 315)
+      --hillshade-altitude <GRAD>
+                           Hoehenwinkel des Lichts (Standard: 45)
+      --hillshade-z <FAKTOR>
+                           Relief-Ueberhoehung (Standard: 1.0)
+      --hillshade-multi    Multidirektionales Hillshade (vier Lichtquellen)
+      --cache              Extrahierte Kartendaten im Standard-Cache-Ordner
+                           ablegen und wiederverwenden
+      --cache-dir <ORDNER> Wie --cache, aber mit eigenem Cache-Ordner
+      --world-file         World-File (.pgw/.jgw) neben das Bild schreiben
+  -q, --quiet              Keine Fortschrittsanzeige
+  -h, --help               Diese Hilfe anzeigen
+
+Layer: terrain, hillshade, farmlands, farmland-ids, farmland-prices, areas,
+       pois, legend, title, forest, water, fields, roads, contours, placement
+Standard: terrain, hillshade, farmlands, farmland-ids, pois, legend, title";
+
+/// Ausgabeformat einer Overview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Png,
+    Jpeg,
+    Svg,
+}
+
+impl OutputFormat {
+    fn parse(value: &str) -> Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "jpg" | "jpeg" => Ok(Self::Jpeg),
+            "svg" => Ok(Self::Svg),
+            other => bail!(
+                "Unbekanntes Format '{}' (erwartet png, jpg oder svg)",
+                other
+            ),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+/// Ausgewertete Kommandozeile.
+#[derive(Debug)]
+struct CliArgs {
+    inputs: Vec<PathBuf>,
+    output: Option<PathBuf>,
+    format: Option<OutputFormat>,
+    palette: Option<PathBuf>,
+    options: OverviewOptions,
+    world_file: bool,
+    quiet: bool,
+}
+
+#[derive(Debug)]
+enum Command {
+    Run(Box<CliArgs>),
+    Help,
+}
+
+/// Eine zu verarbeitende Eingabe.
+struct Job {
+    path: PathBuf,
+    /// Aus einem Mod-Verzeichnis aufgeloest (Nicht-Karten werden uebersprungen)
+    from_mods_dir: bool,
+}
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1)) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Fehler: {:#}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: impl IntoIterator<Item = String>) -> Result<ExitCode> {
+    let cli = match parse_args(args)? {
+        Command::Help => {
+            println!("{}", USAGE);
+            return Ok(ExitCode::SUCCESS);
+        }
+        Command::Run(cli) => *cli,
+    };
+
+    let mut options = cli.options.clone();
+    if let Some(palette) = &cli.palette {
+        options.terrain_palette = Some(TerrainPalette::from_path(palette)?);
+    }
+
+    let jobs = expand_inputs(&cli.inputs)?;
+    if jobs.is_empty() {
+        bail!("Keine Map-Mods gefunden");
+    }
+    let single = jobs.len() == 1 && !jobs[0].from_mods_dir;
+
+    let (mut generated, mut skipped, mut failed) = (0usize, 0usize, 0usize);
+    for job in &jobs {
+        match process(job, &cli, &options, single) {
+            Ok(Some(output)) => {
+                println!("{} → {}", job.path.display(), output.display());
+                generated += 1;
+            }
+            Ok(None) => skipped += 1,
+            Err(e) => {
+                eprintln!("Fehler bei {}: {:#}", job.path.display(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    if !single {
+        println!(
+            "{} erzeugt, {} uebersprungen, {} fehlgeschlagen",
+            generated, skipped, failed
+        );
+    }
+    Ok(if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Erzeugt die Overview fuer eine Eingabe; `None` = kein Map-Mod, uebersprungen.
+fn process(
+    job: &Job,
+    cli: &CliArgs,
+    options: &OverviewOptions,
+    single: bool,
+) -> Result<Option<PathBuf>> {
+    let input = job
+        .path
+        .to_str()
+        .with_context(|| format!("Pfad ist kein gueltiges UTF-8: {}", job.path.display()))?;
+
+    let map_info = match discover_map_from_zip(input) {
+        Ok(map_info) => map_info,
+        Err(e) if job.from_mods_dir => {
+            log::debug!("{} uebersprungen: {:#}", input, e);
+            if !cli.quiet {
+                eprintln!("{}: keine Karte, uebersprungen", job.path.display());
+            }
+            return Ok(None);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let format = output_format(cli);
+    let output = output_path(&job.path, cli.output.as_deref(), single, format);
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "Ausgabeordner konnte nicht erstellt werden: {}",
+                parent.display()
+            )
+        })?;
+    }
+
+    if format == OutputFormat::Svg {
+        if cli.world_file {
+            eprintln!("Hinweis: --world-file wird fuer SVG ignoriert");
+        }
+        let svg = generate_overview_svg_from_zip(input, options)?;
+        std::fs::write(&output, svg).with_context(|| {
+            format!("SVG konnte nicht geschrieben werden: {}", output.display())
+        })?;
+        return Ok(Some(output));
+    }
+
+    let quiet = cli.quiet;
+    let image = generate_overview_from_zip_with_progress(input, options, &mut |progress| {
+        if !quiet {
+            eprint!(
+                "\r{} [{:>3}%] {:<32}",
+                map_info.title,
+                progress.percent,
+                progress.stage.label()
+            );
+        }
+        true
+    });
+    if !quiet {
+        eprintln!();
+    }
+    let image = DynamicImage::ImageRgb8(image?);
+
+    if cli.world_file {
+        save_with_world_file(&image, &output, map_info.map_size)?;
+    } else {
+        image.save(&output).with_context(|| {
+            format!(
+                "Overview konnte nicht gespeichert werden: {}",
+                output.display()
+            )
+        })?;
+    }
+    Ok(Some(output))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command> {
+    let mut cli = CliArgs {
+        inputs: Vec::new(),
+        output: None,
+        format: None,
+        palette: None,
+        options: OverviewOptions::default(),
+        world_file: false,
+        quiet: false,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        // `--flag=wert` und `--flag wert` gleichermassen erlauben
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        let inline_value = inline_value.map(str::to_string);
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .with_context(|| format!("{} erwartet einen Wert", flag))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-o" | "--output" => cli.output = Some(PathBuf::from(value()?)),
+            "--format" => cli.format = Some(OutputFormat::parse(&value()?)?),
+            "--size" => cli.options.output_size = Some(parse_number(&flag, &value()?)?),
+            "--supersample" => cli.options.supersample = parse_number(&flag, &value()?)?,
+            "--palette" => cli.palette = Some(PathBuf::from(value()?)),
+            "--map-image" => cli.options.map_image = true,
+            "--no-poi-clusters" => cli.options.poi_clusters = false,
+            "--with" | "--without" => {
+                let enabled = flag == "--with";
+                for layer in value()?.split(',').filter(|layer| !layer.is_empty()) {
+                    set_layer(&mut cli.options, layer.trim(), enabled)?;
+                }
+            }
+            "--subtitle" => cli.options.title_bar_options.subtitle = true,
+            "--hillshade-azimuth" => {
+                cli.options.hillshade_params.azimuth_deg = parse_float(&flag, &value()?)?
+            }
+            "--hillshade-altitude" => {
+                cli.options.hillshade_params.altitude_deg = parse_float(&flag, &value()?)?
+            }
+            "--hillshade-z" => {
+                cli.options.hillshade_params.z_factor = parse_float(&flag, &value()?)?
+            }
+            "--hillshade-multi" => cli.options.hillshade_params.multidirectional = true,
+            "--cache" => {
+                cli.options.cache_dir = Some(
+                    MapDataCache::default_dir()
+                        .context("Kein Standard-Cache-Ordner ermittelbar, --cache-dir nutzen")?,
+                )
+            }
+            "--cache-dir" => cli.options.cache_dir = Some(PathBuf::from(value()?)),
+            "--world-file" => cli.world_file = true,
+            "-q" | "--quiet" => cli.quiet = true,
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                bail!("Unbekannte Option '{}' (siehe --help)", arg)
+            }
+            _ => cli.inputs.push(PathBuf::from(arg)),
+        }
+    }
+
+    if cli.inputs.is_empty() {
+        bail!("Keine Eingabe angegeben (siehe --help)");
+    }
+    Ok(Command::Run(Box::new(cli)))
+}
+
+fn parse_number(flag: &str, value: &str) -> Result<u32> {
+    value
+        .parse()
+        .with_context(|| format!("{} erwartet eine Zahl, erhalten: {}", flag, value))
+}
+
+fn parse_float(flag: &str, value: &str) -> Result<f32> {
+    value
+        .parse()
+        .ok()
+        .filter(|number: &f32| number.is_finite())
+        .with_context(|| format!("{} erwartet eine Kommazahl, erhalten: {}", flag, value))
+}
+
+/// Schaltet einen Layer per CLI-Namen ein oder aus.
+fn set_layer(options: &mut OverviewOptions, layer: &str, enabled: bool) -> Result<()> {
+    match layer {
+        "terrain" => options.terrain = enabled,
+        "hillshade" => options.hillshade = enabled,
+        "farmlands" => options.farmlands = enabled,
+        "farmland-ids" => options.farmland_ids = enabled,
+        "farmland-prices" => options.farmland_prices = enabled,
+        "areas" => options.area_labels = enabled,
+        "pois" => options.pois = enabled,
+        "legend" => options.legend = enabled,
+        "title" => options.title_bar = enabled,
+        "forest" => options.forest = enabled,
+        "water" => options.water = enabled,
+        "fields" => options.fields = enabled,
+        "roads" => options.roads = enabled,
+        "contours" => options.contours = enabled.then(ContourOptions::default),
+        "placement" => options.placement_collision = enabled,
+        other => bail!("Unbekannter Layer '{}' (siehe --help)", other),
+    }
+    Ok(())
+}
+
+/// Loest Mod-Verzeichnisse (Ordner ohne `modDesc.xml`) in ihre ZIPs auf.
+fn expand_inputs(inputs: &[PathBuf]) -> Result<Vec<Job>> {
+    let mut jobs = Vec::new();
+    for input in inputs {
+        if !input.is_dir() || input.join("modDesc.xml").is_file() {
+            jobs.push(Job {
+                path: input.clone(),
+                from_mods_dir: false,
+            });
+            continue;
+        }
+
+        let entries = std::fs::read_dir(input)
+            .with_context(|| format!("Ordner nicht lesbar: {}", input.display()))?;
+        let mut zips: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+            })
+            .collect();
+        zips.sort();
+        jobs.extend(zips.into_iter().map(|path| Job {
+            path,
+            from_mods_dir: true,
+        }));
+    }
+    Ok(jobs)
+}
+
+/// Format aus `--format`, sonst aus der Endung von `--output`, sonst PNG.
+fn output_format(cli: &CliArgs) -> OutputFormat {
+    cli.format
+        .or_else(|| {
+            let extension = cli.output.as_deref()?.extension()?.to_str()?;
+            OutputFormat::parse(extension).ok()
+        })
+        .unwrap_or(OutputFormat::Png)
+}
+
+/// Zielpfad einer Overview.
+///
+/// Bei genau einer Eingabe und einem `--output` mit Dateiendung (und keinem
+/// vorhandenen Ordner) ist das die Ausgabedatei selbst, sonst
+/// `<ordner>/<eingabename>_overview.<format>`.
+fn output_path(input: &Path, output: Option<&Path>, single: bool, format: OutputFormat) -> PathBuf {
+    if let Some(output) = output
+        && single
+        && !output.is_dir()
+        && output.extension().is_some()
+    {
+        return output.to_path_buf();
+    }
+
+    let name = input
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| String::from("map"));
+    output
+        .unwrap_or(Path::new("."))
+        .join(format!("{}_overview.{}", name, format.extension()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs> {
+        match parse_args(args.iter().map(|arg| arg.to_string()))? {
+            Command::Run(cli) => Ok(*cli),
+            Command::Help => bail!("unerwartet: Hilfe"),
+        }
+    }
+
+    #[test]
+    fn parses_layers_size_and_output() {
+        let cli = parse(&[
+            "map.zip",
+            "--size=2048",
+            "--supersample",
+            "2",
+            "--with",
+            "roads,contours,areas",
+            "--without=legend,title",
+            "--subtitle",
+            "--no-poi-clusters",
+            "--hillshade-azimuth=270",
+            "--hillshade-z",
+            "2.5",
+            "--hillshade-multi",
+            "--map-image",
+            "--cache-dir=cache",
+            "-o",
+            "out/map.svg",
+            "--world-file",
+        ])
+        .expect("gueltige Argumente");
+
+        assert_eq!(cli.inputs, vec![PathBuf::from("map.zip")]);
+        assert_eq!(cli.options.output_size, Some(2048));
+        assert_eq!(cli.options.supersample, 2);
+        assert!(cli.options.roads);
+        assert!(cli.options.contours.is_some());
+        assert!(cli.options.area_labels);
+        assert!(!cli.options.legend);
+        assert!(!cli.options.title_bar);
+        assert!(cli.options.title_bar_options.subtitle);
+        assert!(!cli.options.poi_clusters);
+        assert_eq!(cli.options.hillshade_params.azimuth_deg, 270.0);
+        assert_eq!(cli.options.hillshade_params.z_factor, 2.5);
+        assert!(cli.options.hillshade_params.multidirectional);
+        assert!(cli.options.map_image);
+        assert_eq!(cli.options.cache_dir, Some(PathBuf::from("cache")));
+        assert!(cli.world_file);
+        assert_eq!(output_format(&cli), OutputFormat::Svg);
+    }
+
+    #[test]
+    fn rejects_unknown_layer_option_and_missing_input() {
+        assert!(parse(&["map.zip", "--with", "clouds"]).is_err());
+        assert!(parse(&["map.zip", "--bogus"]).is_err());
+        assert!(parse(&["map.zip", "--size"]).is_err());
+        assert!(parse(&["map.zip", "--hillshade-z", "steil"]).is_err());
+        assert!(parse(&["--quiet"]).is_err());
+        assert!(matches!(
+            parse_args([String::from("--help")]),
+            Ok(Command::Help)
+        ));
+    }
+
+    #[test]
+    fn output_path_uses_file_for_single_input_and_names_batch_outputs() {
+        let input = Path::new("mods/FS25_Riverbend.zip");
+        assert_eq!(
+            output_path(
+                input,
+                Some(Path::new("riverbend.png")),
+                true,
+                OutputFormat::Png
+            ),
+            PathBuf::from("riverbend.png")
+        );
+        assert_eq!(
+            output_path(
+                input,
+                Some(Path::new("overviews")),
+                false,
+                OutputFormat::Jpeg
+            ),
+            PathBuf::from("overviews/FS25_Riverbend_overview.jpg")
+        );
+        assert_eq!(
+            output_path(input, None, true, OutputFormat::Png),
+            PathBuf::from("./FS25_Riverbend_overview.png")
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! On-Disk-Cache fuer extrahierte und dekodierte Kartendaten.
+//!
+//! Pro Map-ZIP entsteht eine Datei `<schluessel>.bin` im Cache-Ordner. Sie
+//! enthaelt alle fuer Discovery und saemtliche Layer benoetigten Eintraege
+//! unkomprimiert, sodass ein Cache-Treffer unabhaengig von den gewaehlten
+//! Optionen ist. Weight-Maps, DEM und Raster-Sperrlayer liegen bereits
+//! dekodiert als Rohraster vor ([`decode_raw_raster`]); Farmland-GRLEs und
+//! XML-Dateien bleiben unveraendert. Der Schluessel ist ein FNV-1a-Hash aus
+//! kanonischem Pfad, Dateigroesse und Aenderungszeit des ZIPs – ein
+//! geaendertes ZIP erzeugt also einen neuen Eintrag. Entpackte Mod-Ordner
+//! werden nicht gecacht, da sich ihre Dateien einzeln aendern koennen.
+
+use anyhow::{bail, Context, Result};
+use image::{DynamicImage, ImageBuffer, Luma};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::{dds, discovery};
+
+/// Magic-Bytes einer Cache-Datei.
+const BUNDLE_MAGIC: &[u8; 8] = b"FS25MAPC";
+
+/// Formatversion; aendert sich der Inhalt, werden alte Eintraege ignoriert.
+const BUNDLE_VERSION: u32 = 2;
+
+/// Magic-Bytes eines dekodierten Rohrasters.
+const RAW_MAGIC: &[u8; 8] = b"FS25RAW\x01";
+
+/// Laenge des Rohraster-Headers (Magic, Farbtyp, Breite, Hoehe).
+const RAW_HEADER_LEN: usize = 8 + 1 + 4 + 4;
+
+/// Cache-Ordner fuer extrahierte Kartendaten.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapDataCache {
+    dir: PathBuf,
+}
+
+impl MapDataCache {
+    /// Cache in einem beliebigen Ordner (wird bei Bedarf angelegt).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Plattformueblicher Cache-Ordner (`$XDG_CACHE_HOME`, `~/.cache` bzw.
+    /// `%LOCALAPPDATA%`) mit Unterordner `fs25_map_overview`.
+    pub fn default_dir() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+        Some(base.join("fs25_map_overview"))
+    }
+
+    /// Ordner des Caches.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Loescht alle Cache-Eintraege; ein fehlender Ordner ist kein Fehler.
+    pub fn clear(&self) -> Result<()> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Cache nicht lesbar: {}", self.dir.display()));
+            }
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "bin") {
+                std::fs::remove_file(&path).with_context(|| {
+                    format!("Cache-Eintrag nicht loeschbar: {}", path.display())
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Liefert die Dateien eines Map-ZIPs aus dem Cache oder ruft `extract` auf
+    /// und legt das Ergebnis ab.
+    ///
+    /// Gibt `Ok(None)` zurueck, wenn `zip_path` nicht cachebar ist (Ordner).
+    /// Ein unlesbarer Cache-Eintrag wird verworfen und neu erzeugt; ein
+    /// Schreibfehler wird nur protokolliert.
+    pub(crate) fn load_or_extract(
+        &self,
+        zip_path: &str,
+        extract: impl FnOnce() -> Result<HashMap<String, Vec<u8>>>,
+    ) -> Result<Option<HashMap<String, Vec<u8>>>> {
+        let Some(key) = cache_key(Path::new(zip_path))? else {
+            return Ok(None);
+        };
+        let path = self.dir.join(format!("{}.bin", key));
+
+        if let Ok(data) = std::fs::read(&path) {
+            match decode_bundle(&data) {
+                Ok(files) => {
+                    log::info!("Kartendaten aus Cache geladen: {}", path.display());
+                    return Ok(Some(files));
+                }
+                Err(e) => log::warn!("Cache-Eintrag {} verworfen: {}", path.display(), e),
+            }
+        }
+
+        let files = predecode_rasters(extract()?);
+        if let Err(e) = self.store(&path, &files) {
+            log::warn!(
+                "Cache-Eintrag {} nicht geschrieben: {:#}",
+                path.display(),
+                e
+            );
+        }
+        Ok(Some(files))
+    }
+
+    /// Schreibt einen Eintrag atomar (temporaere Datei + Umbenennen).
+    fn store(&self, path: &Path, files: &HashMap<String, Vec<u8>>) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Cache-Ordner nicht anlegbar: {}", self.dir.display()))?;
+        let tmp = path.with_extension("tmp");
+        {
+            let mut writer = BufWriter::new(std::fs::File::create(&tmp)?);
+            encode_bundle(&mut writer, files)?;
+            writer.flush()?;
+        }
+        std::fs::rename(&tmp, path)?;
+        log::info!("Kartendaten gecacht: {}", path.display());
+        Ok(())
+    }
+}
+
+/// Cache-Schluessel eines ZIPs; `None` fuer Ordner.
+fn cache_key(zip_path: &Path) -> Result<Option<String>> {
+    let metadata = std::fs::metadata(zip_path)
+        .with_context(|| format!("ZIP-Datei nicht gefunden: {}", zip_path.display()))?;
+    if metadata.is_dir() {
+        return Ok(None);
+    }
+    let canonical = zip_path
+        .canonicalize()
+        .unwrap_or_else(|_| zip_path.to_path_buf());
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_nanos());
+
+    let mut hash = Fnv1a::new();
+    hash.write(canonical.to_string_lossy().as_bytes());
+    hash.write(&metadata.len().to_le_bytes());
+    hash.write(&modified.to_le_bytes());
+    hash.write(&BUNDLE_VERSION.to_le_bytes());
+    Ok(Some(format!("{:016x}", hash.finish())))
+}
+
+/// FNV-1a (64 Bit); stabil ueber Rust-Versionen, anders als `DefaultHasher`.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Ersetzt Weight-Maps, DEM und Raster-Sperrlayer durch dekodierte Rohraster.
+///
+/// Nicht dekodierbare Eintraege bleiben unveraendert, damit der Fehler wie
+/// ohne Cache beim Rendern gemeldet wird.
+///
+/// Die Raster werden per Rayon parallel dekodiert.
+fn predecode_rasters(mut files: HashMap<String, Vec<u8>>) -> HashMap<String, Vec<u8>> {
+    files.par_iter_mut().for_each(|(path, data)| {
+        let lower = path.to_ascii_lowercase();
+        let is_raster = discovery::is_weight_map_path(&lower)
+            || discovery::is_dem_path(&lower)
+            || (discovery::is_placement_collision_path(&lower) && !lower.ends_with(".grle"));
+        if !is_raster || is_raw_raster(data) {
+            return;
+        }
+        match dds::decode_map_raster(data) {
+            Ok(image) => match encode_raw_raster(&image) {
+                Some(raw) => *data = raw,
+                None => log::debug!("Rasterformat von {} nicht cachebar", path),
+            },
+            Err(e) => log::debug!("{} nicht vorab dekodierbar: {}", path, e),
+        }
+    });
+    files
+}
+
+fn encode_bundle(writer: &mut impl Write, files: &HashMap<String, Vec<u8>>) -> Result<()> {
+    writer.write_all(BUNDLE_MAGIC)?;
+    writer.write_all(&BUNDLE_VERSION.to_le_bytes())?;
+    writer.write_all(&(files.len() as u32).to_le_bytes())?;
+    for (path, data) in files {
+        writer.write_all(&(path.len() as u32).to_le_bytes())?;
+        writer.write_all(path.as_bytes())?;
+        writer.write_all(&(data.len() as u64).to_le_bytes())?;
+        writer.write_all(data)?;
+    }
+    Ok(())
+}
+
+fn decode_bundle(data: &[u8]) -> Result<HashMap<String, Vec<u8>>> {
+    let mut reader = ByteReader { data, pos: 0 };
+    if reader.take(BUNDLE_MAGIC.len())? != BUNDLE_MAGIC {
+        bail!("Keine Cache-Datei");
+    }
+    let version = reader.u32()?;
+    if version != BUNDLE_VERSION {
+        bail!("Cache-Version {} statt {}", version, BUNDLE_VERSION);
+    }
+
+    let count = reader.u32()? as usize;
+    let mut files = HashMap::with_capacity(count);
+    for _ in 0..count {
+        let path_len = reader.u32()? as usize;
+        let path = std::str::from_utf8(reader.take(path_len)?)
+            .context("Cache-Pfad ist kein UTF-8")?
+            .to_string();
+        let data_len = usize::try_from(reader.u64()?)?;
+        files.insert(path, reader.take(data_len)?.to_vec());
+    }
+    if reader.pos != data.len() {
+        bail!("Ueberzaehlige Bytes am Ende der Cache-Datei");
+    }
+    Ok(files)
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .context("Cache-Datei ist abgeschnitten")?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+}
+
+// ── Rohraster ───────────────────────────────────────────────────────
+
+/// Prueft, ob `data` ein Rohraster aus dem Cache ist.
+pub(crate) fn is_raw_raster(data: &[u8]) -> bool {
+    data.starts_with(RAW_MAGIC)
+}
+
+/// Kodiert ein Bild verlustfrei und unkomprimiert (L8, La8, Rgb8, Rgba8, L16).
+///
+/// Gibt `None` fuer andere Farbtypen zurueck.
+fn encode_raw_raster(image: &DynamicImage) -> Option<Vec<u8>> {
+    let (color, pixels): (u8, Vec<u8>) = match image {
+        DynamicImage::ImageLuma8(img) => (1, img.as_raw().clone()),
+        DynamicImage::ImageLumaA8(img) => (2, img.as_raw().clone()),
+        DynamicImage::ImageRgb8(img) => (3, img.as_raw().clone()),
+        DynamicImage::ImageRgba8(img) => (4, img.as_raw().clone()),
+        DynamicImage::ImageLuma16(img) => (
+            5,
+            img.as_raw().iter().flat_map(|v| v.to_le_bytes()).collect(),
+        ),
+        _ => return None,
+    };
+    let mut raw = Vec::with_capacity(RAW_HEADER_LEN + pixels.len());
+    raw.extend_from_slice(RAW_MAGIC);
+    raw.push(color);
+    raw.extend_from_slice(&image.width().to_le_bytes());
+    raw.extend_from_slice(&image.height().to_le_bytes());
+    raw.extend_from_slice(&pixels);
+    Some(raw)
+}
+
+/// Breite und Hoehe eines Rohrasters aus dem Header.
+pub(crate) fn raw_raster_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    if data.len() < RAW_HEADER_LEN || !is_raw_raster(data) {
+        return None;
+    }
+    Some((
+        u32::from_le_bytes(data[9..13].try_into().ok()?),
+        u32::from_le_bytes(data[13..17].try_into().ok()?),
+    ))
+}
+
+/// Dekodiert ein Rohraster aus dem Cache.
+pub(crate) fn decode_raw_raster(data: &[u8]) -> Result<DynamicImage> {
+    if data.len() < RAW_HEADER_LEN || !is_raw_raster(data) {
+        bail!("Kein Rohraster");
+    }
+    let color = data[8];
+    let width = u32::from_le_bytes(data[9..13].try_into()?);
+    let height = u32::from_le_bytes(data[13..17].try_into()?);
+    let pixels = data[RAW_HEADER_LEN..].to_vec();
+    let mismatch = || anyhow::anyhow!("Rohraster passt nicht zu {}x{}", width, height);
+
+    Ok(match color {
+        1 => DynamicImage::ImageLuma8(
+            ImageBuffer::from_raw(width, height, pixels).ok_or_else(mismatch)?,
+        ),
+        2 => DynamicImage::ImageLumaA8(
+            ImageBuffer::from_raw(width, height, pixels).ok_or_else(mismatch)?,
+        ),
+        3 => DynamicImage::ImageRgb8(
+            ImageBuffer::from_raw(width, height, pixels).ok_or_else(mismatch)?,
+        ),
+        4 => DynamicImage::ImageRgba8(
+            ImageBuffer::from_raw(width, height, pixels).ok_or_else(mismatch)?,
+        ),
+        5 => {
+            let values: Vec<u16> = pixels
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect();
+            DynamicImage::ImageLuma16(
+                ImageBuffer::<Luma<u16>, _>::from_raw(width, height, values)
+                    .ok_or_else(mismatch)?,
+            )
+        }
+        other => bail!("Unbekannter Rohraster-Farbtyp {}", other),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, RgbImage};
+    use std::time::SystemTime;
+
+    struct TempDirGuard {
+        path: PathBuf,
+    }
+
+    impl TempDirGuard {
+        fn new(prefix: &str) -> Self {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Systemzeit muss nach Unix-Epoche liegen")
+                .as_nanos();
+            let path = std::env::temp_dir().join(format!(
+                "fs25_map_overview_{}_{}_{}",
+                prefix,
+                std::process::id(),
+                timestamp
+            ));
+            std::fs::create_dir_all(&path).expect("Temp-Verzeichnis muss erstellt werden");
+            Self { path }
+        }
+
+        fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for TempDirGuard {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn png(image: DynamicImage) -> Vec<u8> {
+        let mut data = Vec::new();
+        image
+            .write_to(
+                &mut std::io::Cursor::new(&mut data),
+                image::ImageFormat::Png,
+            )
+            .expect("PNG muss kodierbar sein");
+        data
+    }
+
+    #[test]
+    fn raw_raster_roundtrips_8_and_16_bit() {
+        let gray = DynamicImage::ImageLuma8(GrayImage::from_fn(3, 2, |x, y| {
+            image::Luma([(x * 10 + y) as u8])
+        }));
+        let dem = DynamicImage::ImageLuma16(ImageBuffer::from_fn(2, 2, |x, y| {
+            image::Luma([(x * 40_000 + y * 3) as u16])
+        }));
+        let rgb = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 1, image::Rgb([1, 2, 3])));
+
+        for image in [gray, dem, rgb] {
+            let raw = encode_raw_raster(&image).expect("Farbtyp unterstuetzt");
+            assert!(is_raw_raster(&raw));
+            assert_eq!(decode_raw_raster(&raw).expect("dekodierbar"), image);
+            // Die Format-Weiche erkennt Rohraster ebenfalls
+            assert_eq!(dds::decode_map_raster(&raw).expect("dekodierbar"), image);
+        }
+        assert!(decode_raw_raster(&RAW_MAGIC[..]).is_err());
+    }
+
+    #[test]
+    fn load_or_extract_stores_predecoded_rasters_and_reuses_them() {
+        let temp_dir = TempDirGuard::new("cache_roundtrip");
+        let zip_path = temp_dir.path().join("map.zip");
+        std::fs::write(&zip_path, b"zip").expect("ZIP-Platzhalter");
+        let zip_path = zip_path.to_str().expect("UTF-8");
+        let cache = MapDataCache::new(temp_dir.path().join("cache"));
+
+        let weight = png(DynamicImage::ImageLuma8(GrayImage::from_pixel(
+            4,
+            4,
+            image::Luma([200]),
+        )));
+        let mut extracted = HashMap::new();
+        extracted.insert(String::from("Map/data/grass_weight.png"), weight);
+        extracted.insert(String::from("Map/modDesc.xml"), b"<modDesc/>".to_vec());
+
+        let first = cache
+            .load_or_extract(zip_path, || Ok(extracted.clone()))
+            .expect("Extraktion")
+            .expect("ZIP ist cachebar");
+        assert!(is_raw_raster(&first["Map/data/grass_weight.png"]));
+        assert_eq!(first["Map/modDesc.xml"], b"<modDesc/>".to_vec());
+
+        let second = cache
+            .load_or_extract(zip_path, || bail!("darf nicht erneut extrahieren"))
+            .expect("Cache-Treffer")
+            .expect("ZIP ist cachebar");
+        assert_eq!(second, first);
+
+        cache.clear().expect("Cache leerbar");
+        assert!(cache
+            .load_or_extract(zip_path, || bail!("Cache ist leer"))
+            .is_err());
+    }
+
+    #[test]
+    fn directories_and_corrupt_entries_are_handled() {
+        let temp_dir = TempDirGuard::new("cache_dir_input");
+        let cache = MapDataCache::new(temp_dir.path().join("cache"));
+        let dir = temp_dir.path().to_str().expect("UTF-8");
+        assert!(cache
+            .load_or_extract(dir, || Ok(HashMap::new()))
+            .expect("Ordner")
+            .is_none());
+
+        assert!(decode_bundle(b"FS25MAPC").is_err());
+        let mut bundle = Vec::new();
+        let mut files = HashMap::new();
+        files.insert(String::from("a.xml"), vec![1, 2, 3]);
+        encode_bundle(&mut bundle, &files).expect("kodierbar");
+        assert_eq!(decode_bundle(&bundle).expect("dekodierbar"), files);
+        assert!(decode_bundle(&bundle[..bundle.len() - 1]).is_err());
+    }
+}
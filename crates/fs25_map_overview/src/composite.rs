@@ -4,13 +4,34 @@
 //! zu einer fertigen Overview-Map.
 
 use anyhow::Result;
-use image::{GrayImage, Rgb, RgbImage};
+use image::{DynamicImage, GrayImage, Luma, Rgb, RgbImage};
 use std::collections::HashMap;
-
-use crate::grle;
+use std::path::PathBuf;
+
+use crate::contours::ContourOptions;
+use crate::discovery::MapInfo;
+use crate::farmland_prices::FarmlandOwnership;
+use crate::hillshade::HillshadeParams;
+use crate::network::AutoDriveNetwork;
+use crate::palette::TerrainPalette;
 use crate::text;
+use crate::{dds, grle};
 
+mod labels;
 mod legend;
+mod poi_clusters;
+mod title_bar;
+mod water;
+
+pub(crate) use labels::{
+    detail_scale, label_scale, place_farmland_labels, place_poi_labels, Leader,
+};
+pub(crate) use legend::{LegendLayout, Swatch};
+pub use legend::{LegendOptions, LegendPosition};
+pub(crate) use poi_clusters::{group_pois, PoiGroups, PoiMarker, POI_GROUP_COLOR};
+pub(crate) use title_bar::TitleBarLayout;
+pub use title_bar::TitleBarOptions;
+pub use water::{draw_water, extract_water};
 
 /// Erkannter Point of Interest.
 #[derive(Debug, Clone)]
@@ -42,14 +63,93 @@ pub struct OverviewOptions {
     pub terrain: bool,
     /// Hillshade anwenden
     pub hillshade: bool,
+    /// Lichtrichtung, Ueberhoehung und Modus des Hillshades
+    pub hillshade_params: HillshadeParams,
     /// Farmland-Grenzen einzeichnen
     pub farmlands: bool,
     /// Farmland-ID-Nummern einzeichnen
     pub farmland_ids: bool,
+    /// Kaufpreise aus der `farmlands.xml` unter den Farmland-IDs einzeichnen
+    pub farmland_prices: bool,
+    /// Besitzverhaeltnisse aus einem Savegame unter den Farmland-IDs einzeichnen.
+    ///
+    /// `None` = keine Besitzerangabe.
+    pub farmland_ownership: Option<FarmlandOwnership>,
+    /// Flaeche in Hektar unter Farmland-IDs und Feldnummern einzeichnen
+    pub area_labels: bool,
     /// POI-Marker einzeichnen
     pub pois: bool,
+    /// Dicht beieinander liegende POIs zu einem Gruppen-Marker mit Anzahl
+    /// zusammenfassen; die Legende listet die Gruppen auf
+    pub poi_clusters: bool,
     /// Legende einzeichnen
     pub legend: bool,
+    /// Position, Spalten, Filter und Stil der Legende
+    pub legend_options: LegendOptions,
+    /// Titel-Bar am oberen Bildrand einzeichnen
+    pub title_bar: bool,
+    /// Hoehe, Farben und Untertitel der Titel-Bar
+    pub title_bar_options: TitleBarOptions,
+    /// Wald-Kronendach aus den Wald-Weight-Maps einzeichnen
+    pub forest: bool,
+    /// Gewaesser (Wasser-Weight-Maps + DEM-Tiefe) mit geglaetteter Uferlinie einzeichnen
+    pub water: bool,
+    /// Feldgrenzen und Feldnummern aus `densityMap_ground.gdm` einzeichnen
+    pub fields: bool,
+    /// Strassen-Splines aus der Map-i3d als Vektor-Linien einzeichnen
+    pub roads: bool,
+    /// Hoehenlinien aus dem DEM einzeichnen.
+    ///
+    /// `None` = kein Hoehenlinien-Layer.
+    pub contours: Option<ContourOptions>,
+    /// Bebauungsverbotszonen aus `infoLayer_placementCollision` schraffiert einzeichnen
+    pub placement_collision: bool,
+    /// AutoDrive-Netz (Verbindungen, Richtungsfarben, Marker) einzeichnen.
+    ///
+    /// `None` = kein Netzwerk-Layer.
+    pub network: Option<AutoDriveNetwork>,
+    /// Kantenlaenge des Ausgabebilds in Pixeln.
+    ///
+    /// `None` = Kartengroesse (1 Pixel pro Meter).
+    pub output_size: Option<u32>,
+    /// Supersampling-Faktor: Das Bild wird in `output_size * supersample`
+    /// gerendert und anschliessend herunterskaliert (Kantenglaettung fuer
+    /// Linien und Beschriftungen). `1` = kein Supersampling; Werte ueber
+    /// [`MAX_SUPERSAMPLE`] werden begrenzt.
+    pub supersample: u32,
+    /// Eigene Terrain-Palette mit Vorrang vor der eingebauten Farbzuordnung.
+    ///
+    /// `None` = nur eingebaute Palette.
+    pub terrain_palette: Option<TerrainPalette>,
+    /// Mitgeliefertes Kartenbild des Mods (`imageFilename` der Map-Config bzw.
+    /// `overview.dds`) statt der Weight-Maps als Terrain-Basis verwenden.
+    ///
+    /// Fehlt das Bild oder ist es nicht dekodierbar, wird wie bisher aus den
+    /// Weight-Maps gerendert. `terrain_palette` bleibt dann wirkungslos.
+    pub map_image: bool,
+    /// Ordner fuer den Kartendaten-Cache ([`crate::MapDataCache`]).
+    ///
+    /// Extrahierte und dekodierte Daten eines ZIPs werden dort abgelegt und
+    /// bei erneuter Generierung – auch mit anderen Optionen – wiederverwendet.
+    /// `None` = kein Cache.
+    pub cache_dir: Option<PathBuf>,
+}
+
+/// Obergrenze fuer [`OverviewOptions::supersample`].
+pub const MAX_SUPERSAMPLE: u32 = 4;
+
+impl OverviewOptions {
+    /// Kantenlaenge des fertigen Ausgabebilds fuer eine Karte der Groesse `map_size`.
+    pub fn output_size_for(&self, map_size: u32) -> u32 {
+        self.output_size
+            .filter(|&size| size > 0)
+            .unwrap_or(map_size)
+    }
+
+    /// Interne Render-Kantenlaenge inklusive Supersampling.
+    pub fn render_size_for(&self, map_size: u32) -> u32 {
+        self.output_size_for(map_size) * self.supersample.clamp(1, MAX_SUPERSAMPLE)
+    }
 }
 
 impl Default for OverviewOptions {
@@ -57,23 +157,100 @@ impl Default for OverviewOptions {
         Self {
             terrain: true,
             hillshade: true,
+            hillshade_params: HillshadeParams::default(),
             farmlands: true,
             farmland_ids: true,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
             pois: true,
+            poi_clusters: true,
             legend: true,
+            legend_options: LegendOptions::default(),
+            title_bar: true,
+            title_bar_options: TitleBarOptions::default(),
+            forest: false,
+            water: false,
+            fields: false,
+            roads: false,
+            contours: None,
+            placement_collision: false,
+            network: None,
+            output_size: None,
+            supersample: 1,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
         }
     }
 }
 
-/// Berechnet Farmland-Grenzen aus einer GRLE-InfoLayer-Datei.
+/// Skaliert ein gerendertes Bild auf die Ausgabegroesse herunter (Lanczos3).
 ///
-/// Grenzen entstehen dort, wo benachbarte Pixel unterschiedliche
-/// Farmland-IDs haben und mindestens eines davon > 0 ist.
-pub fn extract_farmland_boundaries(grle_data: &[u8], target_size: u32) -> Result<FarmlandData> {
-    let decoded = grle::decode_grle(grle_data)?;
-    let ids = GrayImage::from_raw(decoded.width as u32, decoded.height as u32, decoded.pixels)
-        .ok_or_else(|| anyhow::anyhow!("Fehler beim Erstellen des Farmland-Bildes"))?;
+/// Gibt das Bild unveraendert zurueck, wenn es bereits die Zielgroesse hat.
+pub(crate) fn downscale_to_output(image: RgbImage, output_size: u32) -> RgbImage {
+    if image.width() == output_size && image.height() == output_size {
+        return image;
+    }
+    image::imageops::resize(
+        &image,
+        output_size,
+        output_size,
+        image::imageops::FilterType::Lanczos3,
+    )
+}
 
+/// Berechnet Farmland-Grenzen aus einem Farmland-InfoLayer (GRLE oder PNG/DDS).
+///
+/// Das Format ergibt sich aus der Dateiendung von `path`. Grenzen entstehen
+/// dort, wo benachbarte Pixel unterschiedliche Farmland-IDs haben und
+/// mindestens eines davon > 0 ist.
+pub fn extract_farmland_boundaries(
+    path: &str,
+    data: &[u8],
+    target_size: u32,
+) -> Result<FarmlandData> {
+    Ok(farmland_boundaries_from_ids(
+        decode_farmland_ids(path, data)?,
+        target_size,
+    ))
+}
+
+/// Dekodiert einen Farmland-InfoLayer zu einem ID-Raster in Originalgroesse.
+///
+/// GRLE-Dateien liefern die IDs direkt. Bei Bildformaten gilt der Grauwert als
+/// ID; 16-Bit-Graustufen werden nicht gestreckt, sondern auf 255 begrenzt, und
+/// bei Farbbildern traegt der Rotkanal die ID.
+pub(crate) fn decode_farmland_ids(path: &str, data: &[u8]) -> Result<GrayImage> {
+    if path.to_ascii_lowercase().ends_with(".grle") {
+        let decoded = grle::decode_grle(data)?;
+        return GrayImage::from_raw(decoded.width as u32, decoded.height as u32, decoded.pixels)
+            .ok_or_else(|| anyhow::anyhow!("Fehler beim Erstellen des Farmland-Bildes"));
+    }
+
+    Ok(match dds::decode_map_raster(data)? {
+        DynamicImage::ImageLuma8(ids) => ids,
+        DynamicImage::ImageLumaA8(image) => {
+            GrayImage::from_fn(image.width(), image.height(), |x, y| {
+                Luma([image.get_pixel(x, y)[0]])
+            })
+        }
+        DynamicImage::ImageLuma16(image) => {
+            GrayImage::from_fn(image.width(), image.height(), |x, y| {
+                Luma([image.get_pixel(x, y)[0].min(255) as u8])
+            })
+        }
+        other => {
+            let rgb = other.to_rgb8();
+            GrayImage::from_fn(rgb.width(), rgb.height(), |x, y| {
+                Luma([rgb.get_pixel(x, y)[0]])
+            })
+        }
+    })
+}
+
+/// Skaliert ein Farmland-ID-Raster auf `target_size` und markiert die Grenzen.
+fn farmland_boundaries_from_ids(ids: GrayImage, target_size: u32) -> FarmlandData {
     // Auf Zielgroesse skalieren (Nearest-Neighbor fuer IDs)
     let ids = if ids.width() != target_size || ids.height() != target_size {
         image::imageops::resize(
@@ -114,12 +291,12 @@ pub fn extract_farmland_boundaries(grle_data: &[u8], target_size: u32) -> Result
         }
     }
 
-    Ok(FarmlandData {
+    FarmlandData {
         boundaries,
         ids,
         width: target_size,
         height: target_size,
-    })
+    }
 }
 
 /// Zeichnet Farmland-Grenzen auf ein RGB-Bild.
@@ -182,7 +359,10 @@ const POI_RULES: &[(&str, &str)] = &[
 ];
 
 /// Extrahiert POIs aus einer placeables.xml.
-pub fn extract_pois(xml_data: &[u8], map_size: u32) -> Vec<Poi> {
+///
+/// Weltkoordinaten (Kartengroesse `map_size` in Metern) werden auf ein
+/// Bild der Kantenlaenge `image_size` abgebildet.
+pub fn extract_pois(xml_data: &[u8], map_size: u32, image_size: u32) -> Vec<Poi> {
     let mut pois = Vec::new();
 
     let content = match std::str::from_utf8(xml_data) {
@@ -213,7 +393,7 @@ pub fn extract_pois(xml_data: &[u8], map_size: u32) -> Vec<Poi> {
                     }
 
                     if let Some(pos_str) = position
-                        && let Some(poi) = parse_poi(&pos_str, &filename, map_size)
+                        && let Some(poi) = parse_poi(&pos_str, &filename, map_size, image_size)
                     {
                         pois.push(poi);
                     }
@@ -230,7 +410,7 @@ pub fn extract_pois(xml_data: &[u8], map_size: u32) -> Vec<Poi> {
 }
 
 /// Parst eine einzelne Placeable-Position und prueft POI-Regeln.
-fn parse_poi(position: &str, filename: &str, map_size: u32) -> Option<Poi> {
+fn parse_poi(position: &str, filename: &str, map_size: u32, image_size: u32) -> Option<Poi> {
     let parts: Vec<&str> = position.split_whitespace().collect();
     if parts.len() < 3 {
         return None;
@@ -247,10 +427,9 @@ fn parse_poi(position: &str, filename: &str, map_size: u32) -> Option<Poi> {
         .map(|(_, name)| name.to_string())?;
 
     let half = map_size as f32 / 2.0;
-    let px =
-        ((x + half) / map_size as f32 * map_size as f32).clamp(0.0, (map_size - 1) as f32) as u32;
-    let py =
-        ((z + half) / map_size as f32 * map_size as f32).clamp(0.0, (map_size - 1) as f32) as u32;
+    let max = image_size.saturating_sub(1) as f32;
+    let px = ((x + half) / map_size as f32 * image_size as f32).clamp(0.0, max) as u32;
+    let py = ((z + half) / map_size as f32 * image_size as f32).clamp(0.0, max) as u32;
 
     Some(Poi {
         x: px,
@@ -295,7 +474,7 @@ fn draw_filled_circle(image: &mut RgbImage, cx: i32, cy: i32, radius: i32, color
 }
 
 /// Blendet zwei Farbkanaele zusammen.
-fn blend_channel(base: u8, overlay: u8, alpha: f32) -> u8 {
+pub(crate) fn blend_channel(base: u8, overlay: u8, alpha: f32) -> u8 {
     let result = base as f32 * (1.0 - alpha) + overlay as f32 * alpha;
     result.clamp(0.0, 255.0) as u8
 }
@@ -304,99 +483,216 @@ fn blend_channel(base: u8, overlay: u8, alpha: f32) -> u8 {
 
 /// Zeichnet Farmland-ID-Nummern in die Mitte jedes Farmlands.
 ///
-/// Berechnet den Schwerpunkt aller Pixel pro Farmland-ID
-/// und zeichnet die ID-Nummer dort hin.
-/// Kleine Farmlands (< 50 Pixel) werden uebersprungen.
-pub fn draw_farmland_ids(image: &mut RgbImage, farmlands: &FarmlandData) {
+/// Die ID steht bevorzugt auf dem Schwerpunkt aller Pixel des Farmlands und
+/// weicht bei Ueberlappung mit anderen Labels seitlich aus, bei grossem
+/// Abstand mit Fuehrungslinie. `detail_lines` reserviert Platz fuer die
+/// Detailzeilen aus [`crate::draw_farmland_details`] unter jeder ID. Zu kleine
+/// Farmlands und solche ohne freien Platz bleiben unbeschriftet.
+pub fn draw_farmland_ids(image: &mut RgbImage, farmlands: &FarmlandData, detail_lines: usize) {
+    // Font-Scale basierend auf Bildgroesse
+    let scale = labels::label_scale(image.width());
+
+    let label_color = Rgb([255, 255, 200]);
+
+    let (width, height) = image.dimensions();
+    for label in labels::place_farmland_labels(farmlands, width, height, detail_lines) {
+        if let Some(leader) = &label.leader {
+            leader.draw(image, label_color);
+        }
+        let id = label.centroid.id.to_string();
+        let tw = text::text_width(&id, scale) as i32;
+        text::draw_text_outlined(
+            image,
+            label.center_x() - tw / 2,
+            label.id_y(),
+            &id,
+            label_color,
+            scale,
+        );
+    }
+}
+
+/// Schwerpunkt und Pixelflaeche eines Farmlands.
+pub(crate) struct FarmlandCentroid {
+    /// Farmland-ID
+    pub id: u8,
+    /// Schwerpunkt X in Pixeln
+    pub x: i32,
+    /// Schwerpunkt Y in Pixeln
+    pub y: i32,
+    /// Anzahl Pixel des Farmlands
+    pub pixel_count: u64,
+}
+
+/// Berechnet die Schwerpunkte aller beschriftbaren Farmlands, sortiert nach ID.
+///
+/// Farmlands mit weniger als 50 Pixeln sind zu klein fuer ein Label und fehlen.
+pub(crate) fn farmland_centroids(farmlands: &FarmlandData) -> Vec<FarmlandCentroid> {
     let w = farmlands.width as usize;
     let raw = farmlands.ids.as_raw();
 
     // Schwerpunkt pro Farmland-ID berechnen
-    let mut centroids: HashMap<u8, (u64, u64, u64)> = HashMap::new(); // id → (sum_x, sum_y, count)
+    let mut sums: HashMap<u8, (u64, u64, u64)> = HashMap::new(); // id → (sum_x, sum_y, count)
     for (i, &id) in raw.iter().enumerate() {
         if id == 0 {
             continue;
         }
         let x = (i % w) as u64;
         let y = (i / w) as u64;
-        let entry = centroids.entry(id).or_insert((0, 0, 0));
+        let entry = sums.entry(id).or_insert((0, 0, 0));
         entry.0 += x;
         entry.1 += y;
         entry.2 += 1;
     }
 
-    // Font-Scale basierend auf Bildgroesse
-    let scale = (image.width() / 1200).clamp(1, 4);
-
-    let label_color = Rgb([255, 255, 200]);
-
-    for (id, (sum_x, sum_y, count)) in &centroids {
-        if *count < 50 {
-            continue; // Zu klein fuer ein Label
-        }
-        let cx = (*sum_x / *count) as i32;
-        let cy = (*sum_y / *count) as i32;
-
-        let label = id.to_string();
-        let tw = text::text_width(&label, scale) as i32;
-        let th = text::text_height(scale) as i32;
-
-        text::draw_text_outlined(image, cx - tw / 2, cy - th / 2, &label, label_color, scale);
-    }
+    let mut centroids: Vec<FarmlandCentroid> = sums
+        .into_iter()
+        .filter(|(_, (_, _, count))| *count >= 50)
+        .map(|(id, (sum_x, sum_y, count))| FarmlandCentroid {
+            id,
+            x: (sum_x / count) as i32,
+            y: (sum_y / count) as i32,
+            pixel_count: count,
+        })
+        .collect();
+    centroids.sort_by_key(|centroid| centroid.id);
+    centroids
 }
 
 // ── POI-Labels ──────────────────────────────────────────────────────
 
 /// Zeichnet POI-Marker MIT Text-Labels auf ein RGB-Bild.
 ///
-/// Labels werden rechts neben dem Marker platziert.
-/// Ueberlappende Labels werden uebersprungen (Mindestabstand).
-pub fn draw_pois_with_labels(image: &mut RgbImage, pois: &[Poi]) {
+/// Labels stehen bevorzugt rechts neben dem Marker und weichen bei
+/// Ueberlappung auf andere Seiten aus, notfalls mit Fuehrungslinie.
+/// Findet sich kein freier Platz, entfaellt das Label.
+///
+/// Mit `clustering` werden dicht beieinander liegende POIs zu einem groesseren,
+/// dunkleren Marker mit ihrer Anzahl und einer Kennung ("G1") zusammengefasst.
+/// Rueckgabe: eine Legendenzeile je Gruppe (fuer [`draw_legend`]).
+pub fn draw_pois_with_labels(image: &mut RgbImage, pois: &[Poi], clustering: bool) -> Vec<String> {
+    let groups = group_pois(pois, image.width(), clustering);
+    draw_poi_markers(image, &groups.markers);
+    groups.legend_lines
+}
+
+/// Zeichnet einzelne POIs und POI-Gruppen samt Beschriftung.
+fn draw_poi_markers(image: &mut RgbImage, markers: &[PoiMarker]) {
     let marker_color = Rgb([220, 50, 50]);
     let outline_color = Rgb([255, 255, 255]);
     let label_color = Rgb([255, 255, 255]);
-    let radius = (image.width() / 600).max(6) as i32;
-    let scale = (image.width() / 1200).clamp(1, 4);
-    let min_dist = (image.width() / 22) as i32;
+    let scale = labels::label_scale(image.width());
 
-    // Labels mit Ueberlappungspruefung platzieren
-    let mut placed_labels: Vec<(i32, i32)> = Vec::new();
+    let (width, height) = image.dimensions();
+    let placed = labels::place_poi_labels(markers, width, height);
 
-    for poi in pois {
-        let cx = poi.x as i32;
-        let cy = poi.y as i32;
+    // Fuehrungslinien zuerst, damit Marker und Texte darueber liegen
+    for label in placed.iter().flatten() {
+        if let Some(leader) = &label.leader {
+            leader.draw(image, label_color);
+        }
+    }
 
-        // Marker immer zeichnen
+    for marker in markers {
+        let cx = marker.x as i32;
+        let cy = marker.y as i32;
+        let radius = marker.radius(width);
         draw_filled_circle(image, cx, cy, radius + 1, outline_color);
-        draw_filled_circle(image, cx, cy, radius, marker_color);
-
-        // Label nur wenn genug Abstand zu bereits platzierten
-        let too_close = placed_labels
-            .iter()
-            .any(|&(lx, ly)| (cx - lx).abs() < min_dist && (cy - ly).abs() < 40);
+        if marker.count > 1 {
+            draw_filled_circle(image, cx, cy, radius, Rgb(POI_GROUP_COLOR));
+            let count = marker.count.to_string();
+            let count_scale = marker.count_scale(width);
+            text::draw_text(
+                image,
+                cx - text::text_width(&count, count_scale) as i32 / 2,
+                cy - text::text_height(count_scale) as i32 / 2,
+                &count,
+                label_color,
+                count_scale,
+            );
+        } else {
+            draw_filled_circle(image, cx, cy, radius, marker_color);
+        }
+    }
 
-        if !too_close {
-            let tx = cx + radius + 4;
-            let ty = cy - text::text_height(scale) as i32 / 2;
-            text::draw_text_outlined(image, tx, ty, &poi.label, label_color, scale);
-            placed_labels.push((cx, cy));
+    for (marker, label) in markers.iter().zip(&placed) {
+        if let Some(label) = label {
+            text::draw_text_outlined(image, label.x, label.y, &marker.label, label_color, scale);
         }
     }
 }
 
 /// Zeichnet die Farbschluessel-Legende auf das Bild.
 ///
-/// Die Legende wird unten links als halbtransparente Box dargestellt.
-/// Enthaelt Terrain-Farben, POI-Markierung und Farmland-Grenzfarbe.
-pub fn draw_legend(image: &mut RgbImage, options: &OverviewOptions) {
-    legend::draw_legend(image, options)
+/// Standardmaessig unten links als halbtransparente Box; Layout und Inhalt
+/// folgen `options.legend_options`. `poi_groups` sind die Legendenzeilen aus
+/// [`draw_pois_with_labels`] und erscheinen unter dem POI-Eintrag.
+pub fn draw_legend(image: &mut RgbImage, options: &OverviewOptions, poi_groups: &[String]) {
+    legend::draw_legend(image, options, poi_groups)
 }
 
 // ── Titel-Bar ───────────────────────────────────────────────────────
 
-/// Zeichnet eine Titel-Bar am oberen Bildrand.
+/// Zeichnet die Titel-Bar am oberen Bildrand, sofern `options.title_bar` gesetzt ist.
 ///
-/// Halbtransparenter Hintergrund mit dem Kartennamen.
-pub fn draw_title_bar(image: &mut RgbImage, title: &str) {
-    legend::draw_title_bar(image, title)
+/// Kartenname, optionaler Untertitel und Hintergrund folgen
+/// `options.title_bar_options`.
+pub fn draw_title_bar(image: &mut RgbImage, map_info: &MapInfo, options: &OverviewOptions) {
+    if options.title_bar {
+        TitleBarLayout::new(image.width(), map_info, &options.title_bar_options).draw(image);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, RgbImage};
+    use std::io::Cursor;
+
+    fn png_bytes(image: DynamicImage) -> Vec<u8> {
+        let mut cursor = Cursor::new(Vec::new());
+        image
+            .write_to(&mut cursor, ImageFormat::Png)
+            .expect("PNG muss erzeugt werden");
+        cursor.into_inner()
+    }
+
+    #[test]
+    fn decode_farmland_ids_reads_png_without_rescaling() {
+        let gray = GrayImage::from_raw(2, 1, vec![0, 7]).expect("2x1-Bild");
+        let ids = decode_farmland_ids("maps/data/infoLayer_farmlands.png", &png_bytes(gray.into()))
+            .expect("Luma-PNG erwartet");
+        assert_eq!(ids.as_raw(), &vec![0, 7]);
+
+        let wide =
+            image::ImageBuffer::<Luma<u16>, _>::from_raw(2, 1, vec![3u16, 300]).expect("2x1-Bild");
+        let ids = decode_farmland_ids("infoLayer_farmlands.png", &png_bytes(wide.into()))
+            .expect("16-Bit-PNG erwartet");
+        assert_eq!(ids.as_raw(), &vec![3, 255]);
+
+        let rgb = RgbImage::from_raw(2, 1, vec![5, 0, 0, 9, 200, 200]).expect("2x1-Bild");
+        let ids = decode_farmland_ids("infoLayer_farmlands.png", &png_bytes(rgb.into()))
+            .expect("RGB-PNG erwartet");
+        assert_eq!(ids.as_raw(), &vec![5, 9]);
+    }
+
+    #[test]
+    fn extract_farmland_boundaries_accepts_png_info_layer() {
+        // Links Farmland 1, rechts Farmland 2
+        let gray = GrayImage::from_fn(4, 4, |x, _| Luma([if x < 2 { 1 } else { 2 }]));
+        let farmlands = extract_farmland_boundaries(
+            "maps/data/infoLayer_farmlands.png",
+            &png_bytes(gray.into()),
+            8,
+        )
+        .expect("Grenzen aus PNG erwartet");
+
+        assert_eq!((farmlands.width, farmlands.ids.width()), (8, 8));
+        assert_eq!(farmlands.ids.get_pixel(0, 0)[0], 1);
+        assert_eq!(farmlands.ids.get_pixel(7, 0)[0], 2);
+        for y in 0..8 {
+            assert!(farmlands.boundaries[y * 8 + 3]);
+            assert!(!farmlands.boundaries[y * 8 + 1]);
+        }
+    }
 }
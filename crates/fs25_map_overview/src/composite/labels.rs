@@ -0,0 +1,496 @@
+//! Kollisionsfreie Platzierung von Farmland-IDs und POI-Namen.
+//!
+//! Greedy-Verfahren: Labels werden nach Prioritaet abgearbeitet, fuer jedes
+//! wird die erste Kandidatenposition gewaehlt, die kein bereits belegtes
+//! Rechteck schneidet und vollstaendig im Bild liegt. Nahe Kandidaten stehen
+//! direkt am Anker, weiter entfernte erhalten eine Fuehrungslinie zum Anker.
+//! Findet sich keine freie Position, wird das Label unterdrueckt. Raster- und
+//! SVG-Ausgabe nutzen dieselbe Platzierung.
+
+use image::{Rgb, RgbImage};
+
+use super::poi_clusters::PoiMarker;
+use super::{farmland_centroids, FarmlandCentroid, FarmlandData};
+use crate::{draw, text};
+
+/// Richtungen der Ausweichpositionen, in Pruefreihenfolge.
+const DIRECTIONS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (-1, -1),
+    (1, 1),
+    (-1, 1),
+];
+
+/// Anzahl Ausweichringe um den Farmland-Schwerpunkt; ab Ring 2 mit Fuehrungslinie.
+const FARMLAND_RINGS: i32 = 3;
+
+/// Farmlands, deren Flaeche kleiner als das Vielfache der ID-Label-Flaeche ist,
+/// bleiben unbeschriftet.
+const MIN_FARMLAND_AREA_FACTOR: u64 = 2;
+
+/// Breitester erwarteter Detailtext (Preis in Millionenhoehe), als Platzhalter
+/// fuer die Breite der Detailzeilen.
+const DETAIL_WIDTH_TEMPLATE: &str = "0.000.000 €";
+
+/// Achsenparalleles Rechteck in Pixeln (Oberkante links, Breite, Hoehe).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LabelBox {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl LabelBox {
+    /// Prueft, ob sich die Rechtecke inklusive Abstand `margin` ueberschneiden.
+    fn intersects(&self, other: &LabelBox, margin: i32) -> bool {
+        self.x - margin < other.x + other.width
+            && other.x - margin < self.x + self.width
+            && self.y - margin < other.y + other.height
+            && other.y - margin < self.y + self.height
+    }
+
+    /// Prueft, ob das Rechteck vollstaendig in einem Bild der Groesse liegt.
+    fn is_inside(&self, width: i32, height: i32) -> bool {
+        self.x >= 0 && self.y >= 0 && self.x + self.width <= width && self.y + self.height <= height
+    }
+
+    /// Naechster Punkt des Rechteckrands zu `(x, y)`.
+    fn closest_point(&self, x: i32, y: i32) -> (i32, i32) {
+        (
+            x.clamp(self.x, self.x + self.width),
+            y.clamp(self.y, self.y + self.height),
+        )
+    }
+}
+
+/// Fuehrungslinie vom Anker zum abgesetzten Label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Leader {
+    /// Anker (Schwerpunkt bzw. Marker)
+    pub from: (i32, i32),
+    /// Naechster Punkt am Label-Rechteck
+    pub to: (i32, i32),
+}
+
+impl Leader {
+    /// Zeichnet die Linie mit dunkler Umrandung, damit sie auf jedem Terrain lesbar bleibt.
+    pub(crate) fn draw(&self, image: &mut RgbImage, color: Rgb<u8>) {
+        let from = (self.from.0 as f32, self.from.1 as f32);
+        let to = (self.to.0 as f32, self.to.1 as f32);
+        draw::draw_thick_line(image, from, to, 3.0, Rgb([0, 0, 0]));
+        draw::draw_thick_line(image, from, to, 1.0, color);
+    }
+}
+
+/// Kandidatenposition eines Labels.
+struct Candidate {
+    bounds: LabelBox,
+    leader: bool,
+}
+
+/// Greedy-Platzierer: merkt sich belegte Rechtecke eines Bildes.
+pub(crate) struct LabelPlacer {
+    occupied: Vec<LabelBox>,
+    width: i32,
+    height: i32,
+    margin: i32,
+}
+
+impl LabelPlacer {
+    /// Neuer Platzierer fuer ein Bild; `margin` ist der Mindestabstand zwischen Labels.
+    pub(crate) fn new(width: u32, height: u32, margin: i32) -> Self {
+        Self {
+            occupied: Vec::new(),
+            width: width as i32,
+            height: height as i32,
+            margin,
+        }
+    }
+
+    /// Markiert ein Rechteck als belegt (z.B. einen POI-Marker).
+    pub(crate) fn reserve(&mut self, bounds: LabelBox) {
+        self.occupied.push(bounds);
+    }
+
+    /// Waehlt den ersten freien Kandidaten, belegt ihn und liefert Rechteck und
+    /// optionale Fuehrungslinie zu `anchor`.
+    fn place(
+        &mut self,
+        anchor: (i32, i32),
+        candidates: impl IntoIterator<Item = Candidate>,
+    ) -> Option<(LabelBox, Option<Leader>)> {
+        let candidate = candidates.into_iter().find(|candidate| {
+            candidate.bounds.is_inside(self.width, self.height)
+                && !self
+                    .occupied
+                    .iter()
+                    .any(|other| candidate.bounds.intersects(other, self.margin))
+        })?;
+        self.occupied.push(candidate.bounds);
+        let leader = candidate.leader.then(|| Leader {
+            from: anchor,
+            to: candidate.bounds.closest_point(anchor.0, anchor.1),
+        });
+        Some((candidate.bounds, leader))
+    }
+}
+
+/// Schrift-Skalierung von Farmland-IDs und POI-Namen fuer eine Bildbreite.
+pub(crate) fn label_scale(width: u32) -> u32 {
+    (width / 1200).clamp(1, 4)
+}
+
+/// Schrift-Skalierung der Farmland-Detailzeilen (Flaeche, Preis, Besitzer).
+pub(crate) fn detail_scale(width: u32) -> u32 {
+    label_scale(width).saturating_sub(1).max(1)
+}
+
+/// Radius der POI-Marker fuer eine Bildbreite.
+pub(crate) fn poi_marker_radius(width: u32) -> i32 {
+    (width / 600).max(6) as i32
+}
+
+// ── Farmland-Labels ─────────────────────────────────────────────────
+
+/// Platziertes Farmland-Label (ID plus reservierte Detailzeilen darunter).
+pub(crate) struct FarmlandLabel {
+    /// Beschriftetes Farmland
+    pub centroid: FarmlandCentroid,
+    /// Belegter Block aus ID und Detailzeilen
+    pub bounds: LabelBox,
+    /// Fuehrungslinie, falls der Block weit vom Schwerpunkt abgesetzt wurde
+    pub leader: Option<Leader>,
+}
+
+impl FarmlandLabel {
+    /// Horizontale Mitte von ID und Detailzeilen.
+    pub(crate) fn center_x(&self) -> i32 {
+        self.bounds.x + self.bounds.width / 2
+    }
+
+    /// Oberkante der ID.
+    pub(crate) fn id_y(&self) -> i32 {
+        self.bounds.y
+    }
+
+    /// Oberkante der ersten Detailzeile.
+    pub(crate) fn details_y(&self, id_scale: u32) -> i32 {
+        self.bounds.y + text::text_height(id_scale) as i32 + 2
+    }
+}
+
+/// Platziert die Labels aller Farmlands kollisionsfrei.
+///
+/// Jeder Block umfasst die ID und `detail_lines` Detailzeilen darunter, damit
+/// ID- und Detail-Layer dieselben Positionen nutzen. Grosse Farmlands werden
+/// zuerst platziert; bevorzugt steht die ID auf dem Schwerpunkt, sonst in
+/// Ausweichringen darum (ab dem zweiten Ring mit Fuehrungslinie). Farmlands
+/// unterhalb der Mindestflaeche oder ohne freie Position bleiben
+/// unbeschriftet. Ergebnis ist nach ID sortiert.
+pub(crate) fn place_farmland_labels(
+    farmlands: &FarmlandData,
+    width: u32,
+    height: u32,
+    detail_lines: usize,
+) -> Vec<FarmlandLabel> {
+    let id_scale = label_scale(width);
+    let detail_scale = detail_scale(width);
+    let id_height = text::text_height(id_scale) as i32;
+    let detail_height = text::text_height(detail_scale) as i32 + 1;
+    let detail_width = if detail_lines > 0 {
+        text::text_width(DETAIL_WIDTH_TEMPLATE, detail_scale) as i32
+    } else {
+        0
+    };
+    let block_height = if detail_lines > 0 {
+        id_height + 2 + detail_height * detail_lines as i32
+    } else {
+        id_height
+    };
+    let gap = (id_scale * 2) as i32;
+
+    let mut centroids = farmland_centroids(farmlands);
+    centroids.sort_by(|a, b| b.pixel_count.cmp(&a.pixel_count).then(a.id.cmp(&b.id)));
+
+    let mut placer = LabelPlacer::new(width, height, gap);
+    let mut labels = Vec::new();
+    for centroid in centroids {
+        let id_width = text::text_width(&centroid.id.to_string(), id_scale) as i32;
+        let min_area = (id_width * id_height) as u64 * MIN_FARMLAND_AREA_FACTOR;
+        if centroid.pixel_count < min_area {
+            continue;
+        }
+
+        let block_width = id_width.max(detail_width);
+        // Ausgangslage: ID mittig auf dem Schwerpunkt, Details darunter
+        let origin = LabelBox {
+            x: centroid.x - block_width / 2,
+            y: centroid.y - id_height / 2,
+            width: block_width,
+            height: block_height,
+        };
+        let candidates = std::iter::once(Candidate {
+            bounds: origin,
+            leader: false,
+        })
+        .chain((1..=FARMLAND_RINGS).flat_map(move |ring| {
+            DIRECTIONS.iter().map(move |&(dx, dy)| Candidate {
+                bounds: LabelBox {
+                    x: origin.x + dx * ring * (block_width + gap),
+                    y: origin.y + dy * ring * (block_height + gap),
+                    ..origin
+                },
+                leader: ring > 1,
+            })
+        }));
+
+        if let Some((bounds, leader)) = placer.place((centroid.x, centroid.y), candidates) {
+            labels.push(FarmlandLabel {
+                centroid,
+                bounds,
+                leader,
+            });
+        }
+    }
+    labels.sort_by_key(|label| label.centroid.id);
+    labels
+}
+
+// ── POI-Labels ──────────────────────────────────────────────────────
+
+/// Platzierter POI-Name.
+pub(crate) struct PoiLabel {
+    /// Linke Oberkante des Texts
+    pub x: i32,
+    /// Oberkante des Texts
+    pub y: i32,
+    /// Fuehrungslinie, falls der Name vom Marker abgesetzt wurde
+    pub leader: Option<Leader>,
+}
+
+/// Platziert die Namen aller POI-Marker kollisionsfrei; Ergebnis ist indexgleich zu `markers`.
+///
+/// Alle Marker gelten vorab als belegt, damit kein Name einen anderen Marker
+/// verdeckt. Bevorzugt steht der Name rechts, links, ueber oder unter dem
+/// Marker, danach diagonal und schliesslich weiter abgesetzt mit
+/// Fuehrungslinie. `None` heisst: kein freier Platz, der Name entfaellt.
+pub(crate) fn place_poi_labels(
+    markers: &[PoiMarker],
+    width: u32,
+    height: u32,
+) -> Vec<Option<PoiLabel>> {
+    let scale = label_scale(width);
+    let text_height = text::text_height(scale) as i32;
+
+    let mut placer = LabelPlacer::new(width, height, 2);
+    for marker in markers {
+        let radius = marker.radius(width);
+        placer.reserve(LabelBox {
+            x: marker.x as i32 - radius - 1,
+            y: marker.y as i32 - radius - 1,
+            width: radius * 2 + 3,
+            height: radius * 2 + 3,
+        });
+    }
+
+    markers
+        .iter()
+        .map(|marker| {
+            let (cx, cy) = (marker.x as i32, marker.y as i32);
+            let near = marker.radius(width) + 4;
+            let far = near + text_height * 2;
+            let text_width = text::text_width(&marker.label, scale) as i32;
+            let candidate = |(dx, dy): (i32, i32), distance: i32| Candidate {
+                bounds: LabelBox {
+                    x: match dx {
+                        1 => cx + distance,
+                        -1 => cx - distance - text_width,
+                        _ => cx - text_width / 2,
+                    },
+                    y: match dy {
+                        1 => cy + distance,
+                        -1 => cy - distance - text_height,
+                        _ => cy - text_height / 2,
+                    },
+                    width: text_width,
+                    height: text_height,
+                },
+                leader: distance > near,
+            };
+            let candidates = DIRECTIONS
+                .iter()
+                .map(|&dir| candidate(dir, near))
+                .chain(DIRECTIONS.iter().map(|&dir| candidate(dir, far)));
+
+            placer
+                .place((cx, cy), candidates)
+                .map(|(bounds, leader)| PoiLabel {
+                    x: bounds.x,
+                    y: bounds.y,
+                    leader,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::GrayImage;
+
+    fn farmlands(size: u32, fill: impl Fn(u32, u32) -> u8) -> FarmlandData {
+        FarmlandData {
+            boundaries: vec![false; (size * size) as usize],
+            ids: GrayImage::from_fn(size, size, |x, y| image::Luma([fill(x, y)])),
+            width: size,
+            height: size,
+        }
+    }
+
+    fn poi(x: u32, y: u32, label: &str) -> PoiMarker {
+        PoiMarker {
+            x,
+            y,
+            label: label.to_string(),
+            count: 1,
+        }
+    }
+
+    #[test]
+    fn placer_rejects_overlaps_and_out_of_bounds() {
+        let mut placer = LabelPlacer::new(100, 100, 2);
+        let bounds = LabelBox {
+            x: 10,
+            y: 10,
+            width: 20,
+            height: 10,
+        };
+        let candidate = |bounds| Candidate {
+            bounds,
+            leader: false,
+        };
+
+        assert!(placer.place((0, 0), [candidate(bounds)]).is_some());
+        // Ueberlappung innerhalb des Abstands
+        let near = LabelBox { x: 31, ..bounds };
+        assert!(placer.place((0, 0), [candidate(near)]).is_none());
+        // Teilweise ausserhalb des Bildes
+        let outside = LabelBox { x: 90, ..bounds };
+        assert!(placer.place((0, 0), [candidate(outside)]).is_none());
+
+        let (placed, leader) = placer
+            .place(
+                (0, 50),
+                [
+                    candidate(near),
+                    Candidate {
+                        bounds: LabelBox { x: 40, ..bounds },
+                        leader: true,
+                    },
+                ],
+            )
+            .expect("zweiter Kandidat ist frei");
+        assert_eq!(placed.x, 40);
+        assert_eq!(
+            leader,
+            Some(Leader {
+                from: (0, 50),
+                to: (40, 20),
+            })
+        );
+    }
+
+    #[test]
+    fn farmland_labels_skip_tiny_farmlands() {
+        // Farmland 1 fuellt die linke Haelfte, Farmland 2 hat nur 60 Pixel
+        let data = farmlands(200, |x, y| {
+            if x < 100 {
+                1
+            } else if x >= 190 && y < 6 {
+                2
+            } else {
+                0
+            }
+        });
+
+        let labels = place_farmland_labels(&data, 200, 200, 0);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].centroid.id, 1);
+        assert!(labels[0].leader.is_none());
+        assert_eq!(labels[0].center_x(), labels[0].centroid.x);
+    }
+
+    #[test]
+    fn farmland_labels_move_aside_instead_of_overlapping() {
+        // Zwei gleich grosse Farmlands mit fast identischem Schwerpunkt
+        let data = farmlands(300, |x, y| {
+            if (100..200).contains(&x) && (100..200).contains(&y) {
+                if y % 2 == 0 {
+                    1
+                } else {
+                    2
+                }
+            } else {
+                0
+            }
+        });
+
+        let labels = place_farmland_labels(&data, 300, 300, 2);
+        assert_eq!(labels.len(), 2);
+        assert!(!labels[0].bounds.intersects(&labels[1].bounds, 0));
+        assert!(labels[1].details_y(1) > labels[1].id_y());
+    }
+
+    #[test]
+    fn poi_labels_avoid_markers_and_each_other() {
+        let pois = vec![
+            poi(100, 100, "Silo"),
+            poi(100 + 12, 100, "Werkstatt"),
+            poi(100, 100 + 3, "Tankstelle"),
+        ];
+
+        let labels = place_poi_labels(&pois, 400, 400);
+        let boxes: Vec<LabelBox> = labels
+            .iter()
+            .zip(&pois)
+            .filter_map(|(label, poi)| {
+                label.as_ref().map(|label| LabelBox {
+                    x: label.x,
+                    y: label.y,
+                    width: text::text_width(&poi.label, 1) as i32,
+                    height: text::text_height(1) as i32,
+                })
+            })
+            .collect();
+
+        assert!(labels[0]
+            .as_ref()
+            .is_some_and(|label| label.leader.is_none()));
+        for (i, a) in boxes.iter().enumerate() {
+            for b in &boxes[i + 1..] {
+                assert!(!a.intersects(b, 0));
+            }
+            for poi in &pois {
+                let marker = LabelBox {
+                    x: poi.x as i32 - 6,
+                    y: poi.y as i32 - 6,
+                    width: 13,
+                    height: 13,
+                };
+                assert!(!a.intersects(&marker, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn poi_labels_are_suppressed_without_space() {
+        // Bild kaum groesser als der Marker: kein Name passt hinein
+        let labels = place_poi_labels(&[poi(8, 8, "Sehr langer Name")], 20, 20);
+        assert!(labels[0].is_none());
+    }
+}
@@ -1,7 +1,75 @@
 use image::{Rgb, RgbImage};
 
+use super::poi_clusters::POI_GROUP_COLOR;
+use super::title_bar::title_bar_height;
+use super::water::WATER_SHALLOW_COLOR;
 use super::OverviewOptions;
+use crate::contours::INDEX_CONTOUR_COLOR;
+use crate::fields::FIELD_BOUNDARY_COLOR;
+use crate::network::{NETWORK_COLOR_DUAL, NETWORK_COLOR_REGULAR, NETWORK_COLOR_REVERSE};
+use crate::placement::COLLISION_COLOR;
+use crate::roads::ROAD_FILL_COLOR;
 use crate::text;
+use crate::vegetation::CANOPY_COLOR;
+
+/// Bildecke, in der die Legende platziert wird.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegendPosition {
+    /// Oben links (unterhalb der Titel-Bar)
+    TopLeft,
+    /// Oben rechts
+    TopRight,
+    /// Unten links
+    #[default]
+    BottomLeft,
+    /// Unten rechts
+    BottomRight,
+}
+
+/// Darstellungsoptionen der Legende.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendOptions {
+    /// Bildecke der Legende
+    pub position: LegendPosition,
+    /// Anzahl Spalten, auf die die Eintraege verteilt werden (mindestens 1)
+    pub columns: u32,
+    /// Auszublendende Eintraege (Beschriftung, case-insensitive)
+    pub hidden_entries: Vec<String>,
+    /// Schrift-Skalierung (`None` = automatisch aus der Bildbreite)
+    pub font_scale: Option<u32>,
+    /// Deckkraft des Hintergrunds (0.0 = transparent, 1.0 = deckend)
+    pub background_opacity: f32,
+}
+
+impl Default for LegendOptions {
+    fn default() -> Self {
+        Self {
+            position: LegendPosition::default(),
+            columns: 1,
+            hidden_entries: Vec::new(),
+            font_scale: None,
+            background_opacity: 0.78,
+        }
+    }
+}
+
+/// Symbol eines Legenden-Eintrags.
+#[derive(Clone, Copy)]
+pub(crate) enum Swatch {
+    /// Gefuelltes Quadrat (Flaechen)
+    Area([u8; 3]),
+    /// Waagrechte Linie mit Staerke in Pixeln (Grenzen, Linien)
+    Line([u8; 3], u32),
+    /// Gefuellter Kreis (Marker)
+    Dot([u8; 3]),
+}
+
+/// Legende-Eintraege des AutoDrive-Netzes: (Farbe, Beschreibung).
+const NETWORK_LEGEND_ITEMS: &[([u8; 3], &str)] = &[
+    (NETWORK_COLOR_REGULAR, "AD: Einbahn"),
+    (NETWORK_COLOR_DUAL, "AD: Beidseitig"),
+    (NETWORK_COLOR_REVERSE, "AD: Rueckwaerts"),
+];
 
 /// Legende-Eintraege: (Farbe, Beschreibung).
 const LEGEND_ITEMS: &[([u8; 3], &str)] = &[
@@ -19,108 +87,220 @@ const LEGEND_ITEMS: &[([u8; 3], &str)] = &[
 
 /// Zeichnet die Farbschluessel-Legende auf das Bild.
 ///
-/// Die Legende wird unten links als halbtransparente Box dargestellt.
-/// Enthaelt Terrain-Farben, POI-Markierung und Farmland-Grenzfarbe.
-pub fn draw_legend(image: &mut RgbImage, options: &OverviewOptions) {
-    let scale = (image.width() / 1200).clamp(1, 4);
-    let padding = 15i32 * scale as i32 / 2;
-    let row_h = 20i32 * scale as i32 / 2 + padding;
-    let swatch_size = 14u32 * scale / 2;
-
-    let mut rows = LEGEND_ITEMS.len();
-    if options.pois {
-        rows += 1;
-    }
-    if options.farmlands {
-        rows += 1;
-    }
-    rows += 1;
-
-    let legend_w = (200 * scale / 2).max(180);
-    let legend_h = (rows as i32 * row_h + padding * 2) as u32;
-
-    let lx = 20i32;
-    let ly = image.height() as i32 - legend_h as i32 - 20;
+/// Position, Spaltenzahl, ausgeblendete Eintraege, Schriftgroesse und
+/// Hintergrund-Deckkraft folgen `options.legend_options`. `poi_groups` enthaelt
+/// je POI-Gruppe eine Zeile, die unter dem POI-Eintrag erscheint.
+pub fn draw_legend(image: &mut RgbImage, options: &OverviewOptions, poi_groups: &[String]) {
+    let Some(layout) = LegendLayout::new(image.width(), image.height(), options, poi_groups) else {
+        return;
+    };
 
-    text::draw_rect_blended(image, lx, ly, legend_w, legend_h, Rgb([30, 30, 30]), 0.78);
-
-    let mut yo = ly + padding;
-    text::draw_text(
+    text::draw_rect_blended(
         image,
-        lx + padding,
-        yo,
-        "Legende",
-        Rgb([255, 255, 255]),
-        scale,
+        layout.x,
+        layout.y,
+        layout.width as u32,
+        layout.height as u32,
+        Rgb([30, 30, 30]),
+        layout.background_opacity,
     );
-    yo += row_h + padding / 2;
 
-    for &(color, label) in LEGEND_ITEMS {
-        text::draw_rect_filled(
-            image,
-            lx + padding,
-            yo,
-            swatch_size,
-            swatch_size,
-            Rgb(color),
-        );
+    let (hx, hy) = layout.header_position();
+    text::draw_text(image, hx, hy, "Legende", Rgb([255, 255, 255]), layout.scale);
+
+    for (index, (swatch, label)) in layout.entries.iter().enumerate() {
+        let (x, y) = layout.entry_position(index);
+        draw_swatch(image, *swatch, x, y, layout.swatch_size);
         text::draw_text(
             image,
-            lx + padding + swatch_size as i32 + 8,
-            yo + 2,
+            x + layout.label_offset,
+            y + 2,
             label,
             Rgb([255, 255, 255]),
-            scale.max(1),
+            layout.scale,
         );
-        yo += row_h;
     }
+}
 
-    if options.pois {
-        let r = (swatch_size / 2) as i32;
-        let center_x = lx + padding + r;
-        let center_y = yo + r;
-        draw_filled_circle(image, center_x, center_y, r, Rgb([220, 50, 50]));
-        text::draw_text(
-            image,
-            lx + padding + swatch_size as i32 + 8,
-            yo + 2,
-            "Gebaeude / POI",
-            Rgb([255, 255, 255]),
+/// Berechnete Platzierung der Legende; gemeinsam fuer Raster- und SVG-Ausgabe.
+pub(crate) struct LegendLayout {
+    /// Sichtbare Eintraege in Anzeigereihenfolge
+    pub entries: Vec<(Swatch, String)>,
+    /// Schrift-Skalierung
+    pub scale: u32,
+    /// Kantenlaenge der Farbfelder in Pixeln
+    pub swatch_size: u32,
+    /// Abstand vom Farbfeld zum Beschriftungsanfang
+    pub label_offset: i32,
+    /// Linke obere Ecke der Box
+    pub x: i32,
+    /// Linke obere Ecke der Box
+    pub y: i32,
+    /// Breite der Box
+    pub width: i32,
+    /// Hoehe der Box
+    pub height: i32,
+    /// Deckkraft des Hintergrunds (0.0–1.0)
+    pub background_opacity: f32,
+    padding: i32,
+    row_h: i32,
+    column_w: i32,
+    rows_per_column: usize,
+}
+
+impl LegendLayout {
+    /// Berechnet die Legende fuer ein Bild der Groesse `width`×`height`.
+    ///
+    /// Gibt `None` zurueck, wenn alle Eintraege ausgeblendet sind.
+    pub(crate) fn new(
+        width: u32,
+        height: u32,
+        options: &OverviewOptions,
+        poi_groups: &[String],
+    ) -> Option<Self> {
+        let style = &options.legend_options;
+        let entries: Vec<(Swatch, String)> = legend_entries(options, poi_groups)
+            .into_iter()
+            .filter(|(_, label)| {
+                !style
+                    .hidden_entries
+                    .iter()
+                    .any(|hidden| hidden.eq_ignore_ascii_case(label))
+            })
+            .collect();
+        if entries.is_empty() {
+            return None;
+        }
+
+        let scale = style
+            .font_scale
+            .unwrap_or_else(|| (width / 1200).clamp(1, 4))
+            .max(1);
+        let padding = (15 * scale as i32 / 2).max(4);
+        let row_h = 20i32 * scale as i32 / 2 + padding;
+        let swatch_size = (14u32 * scale / 2).max(4);
+        let label_offset = swatch_size as i32 + 8;
+
+        let columns = style.columns.clamp(1, entries.len() as u32) as usize;
+        let rows_per_column = entries.len().div_ceil(columns);
+        let column_w = entries
+            .iter()
+            .map(|(_, label)| text::text_width(label, scale) as i32)
+            .max()
+            .unwrap_or(0)
+            + label_offset
+            + padding;
+
+        let header_w = text::text_width("Legende", scale) as i32;
+        let legend_w = (columns as i32 * column_w).max(header_w) + padding * 2;
+        let legend_h = (rows_per_column as i32 + 1) * row_h + padding * 2 + padding / 2;
+
+        let margin = 20i32;
+        let title_h = title_bar_height(width, options);
+        let (x, y) = match style.position {
+            LegendPosition::TopLeft => (margin, title_h + margin),
+            LegendPosition::TopRight => (width as i32 - legend_w - margin, title_h + margin),
+            LegendPosition::BottomLeft => (margin, height as i32 - legend_h - margin),
+            LegendPosition::BottomRight => (
+                width as i32 - legend_w - margin,
+                height as i32 - legend_h - margin,
+            ),
+        };
+
+        Some(Self {
+            entries,
             scale,
-        );
-        yo += row_h;
+            swatch_size,
+            label_offset,
+            x,
+            y,
+            width: legend_w,
+            height: legend_h,
+            background_opacity: style.background_opacity.clamp(0.0, 1.0),
+            padding,
+            row_h,
+            column_w,
+            rows_per_column,
+        })
     }
 
+    /// Obere linke Ecke der Ueberschrift "Legende".
+    pub(crate) fn header_position(&self) -> (i32, i32) {
+        (self.x + self.padding, self.y + self.padding)
+    }
+
+    /// Obere linke Ecke des Farbfelds von Eintrag `index`.
+    pub(crate) fn entry_position(&self, index: usize) -> (i32, i32) {
+        let first_row_y = self.y + self.padding + self.row_h + self.padding / 2;
+        (
+            self.x + self.padding + (index / self.rows_per_column) as i32 * self.column_w,
+            first_row_y + (index % self.rows_per_column) as i32 * self.row_h,
+        )
+    }
+}
+
+/// Sammelt alle Legenden-Eintraege in Anzeigereihenfolge; POI-Gruppen stehen
+/// direkt unter dem POI-Eintrag.
+fn legend_entries(options: &OverviewOptions, poi_groups: &[String]) -> Vec<(Swatch, String)> {
+    let mut entries: Vec<(Swatch, String)> = LEGEND_ITEMS
+        .iter()
+        .map(|&(color, label)| (Swatch::Area(color), label.to_string()))
+        .collect();
+    let mut push = |swatch: Swatch, label: &str| entries.push((swatch, label.to_string()));
+
+    if options.pois {
+        push(Swatch::Dot([220, 50, 50]), "Gebaeude / POI");
+        if options.poi_clusters {
+            for line in poi_groups {
+                push(Swatch::Dot(POI_GROUP_COLOR), line);
+            }
+        }
+    }
     if options.farmlands {
-        text::draw_rect_filled(
-            image,
-            lx + padding,
-            yo + swatch_size as i32 / 2 - 1,
-            swatch_size,
-            3,
-            Rgb([255, 220, 50]),
-        );
-        text::draw_text(
-            image,
-            lx + padding + swatch_size as i32 + 8,
-            yo + 2,
-            "Farmland-Grenze",
-            Rgb([255, 255, 255]),
-            scale,
-        );
+        push(Swatch::Line([255, 220, 50], 3), "Farmland-Grenze");
     }
+    if options.forest {
+        push(Swatch::Area(CANOPY_COLOR), "Wald (Kronendach)");
+    }
+    if options.water {
+        push(Swatch::Area(WATER_SHALLOW_COLOR), "Gewaesser");
+    }
+    if options.fields {
+        push(Swatch::Line(FIELD_BOUNDARY_COLOR, 3), "Feldgrenze");
+    }
+    if options.roads {
+        push(Swatch::Line(ROAD_FILL_COLOR, 5), "Strasse (Spline)");
+    }
+    if options.contours.is_some() {
+        push(Swatch::Line(INDEX_CONTOUR_COLOR, 3), "Hoehenlinie");
+    }
+    if options.placement_collision {
+        push(Swatch::Area(COLLISION_COLOR), "Bebauungsverbot");
+    }
+    if options.network.is_some() {
+        for &(color, label) in NETWORK_LEGEND_ITEMS {
+            push(Swatch::Line(color, 3), label);
+        }
+    }
+    entries
 }
 
-/// Zeichnet eine Titel-Bar am oberen Bildrand.
-///
-/// Halbtransparenter Hintergrund mit dem Kartennamen.
-pub fn draw_title_bar(image: &mut RgbImage, title: &str) {
-    let scale = (image.width() / 800).clamp(2, 6);
-
-    let label = format!("{} - Overview", title);
-    let tx = (scale * 4) as i32;
-    let ty = (scale * 3) as i32;
-    text::draw_text_outlined(image, tx, ty, &label, Rgb([255, 255, 255]), scale);
+fn draw_swatch(image: &mut RgbImage, swatch: Swatch, x: i32, y: i32, size: u32) {
+    match swatch {
+        Swatch::Area(color) => text::draw_rect_filled(image, x, y, size, size, Rgb(color)),
+        Swatch::Line(color, thickness) => text::draw_rect_filled(
+            image,
+            x,
+            y + size as i32 / 2 - thickness as i32 / 2,
+            size,
+            thickness,
+            Rgb(color),
+        ),
+        Swatch::Dot(color) => {
+            let r = (size / 2) as i32;
+            draw_filled_circle(image, x + r, y + r, r, Rgb(color));
+        }
+    }
 }
 
 fn draw_filled_circle(image: &mut RgbImage, cx: i32, cy: i32, radius: i32, color: Rgb<u8>) {
@@ -139,3 +319,84 @@ fn draw_filled_circle(image: &mut RgbImage, cx: i32, cy: i32, radius: i32, color
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options_with(legend_options: LegendOptions) -> OverviewOptions {
+        OverviewOptions {
+            legend_options,
+            ..OverviewOptions::default()
+        }
+    }
+
+    fn touched_columns(image: &RgbImage) -> (u32, u32) {
+        let xs = image
+            .enumerate_pixels()
+            .filter(|(_, _, pixel)| pixel.0 != [200, 200, 200])
+            .map(|(x, _, _)| x);
+        xs.fold((u32::MAX, 0), |(min, max), x| (min.min(x), max.max(x)))
+    }
+
+    #[test]
+    fn draw_legend_honors_position() {
+        let mut image = RgbImage::from_pixel(800, 800, Rgb([200, 200, 200]));
+        draw_legend(
+            &mut image,
+            &options_with(LegendOptions {
+                position: LegendPosition::TopRight,
+                ..LegendOptions::default()
+            }),
+            &[],
+        );
+
+        let (min_x, max_x) = touched_columns(&image);
+        assert!(min_x > 400, "Legende muss rechts liegen (min_x={min_x})");
+        assert_eq!(max_x, 800 - 20 - 1);
+    }
+
+    #[test]
+    fn draw_legend_filters_entries_and_skips_empty_legend() {
+        let hidden_entries = legend_entries(&OverviewOptions::default(), &[])
+            .into_iter()
+            .map(|(_, label)| label.to_ascii_uppercase())
+            .collect();
+        let mut image = RgbImage::from_pixel(400, 400, Rgb([200, 200, 200]));
+
+        draw_legend(
+            &mut image,
+            &options_with(LegendOptions {
+                hidden_entries,
+                ..LegendOptions::default()
+            }),
+            &[],
+        );
+
+        assert!(image.pixels().all(|pixel| pixel.0 == [200, 200, 200]));
+    }
+
+    #[test]
+    fn poi_groups_follow_the_poi_entry() {
+        let groups = vec!["G1: 2× Silo".to_string(), "G2: Werkstatt".to_string()];
+        let labels = |options: &OverviewOptions| -> Vec<String> {
+            legend_entries(options, &groups)
+                .into_iter()
+                .map(|(_, label)| label)
+                .collect()
+        };
+
+        let entries = labels(&OverviewOptions::default());
+        let poi = entries
+            .iter()
+            .position(|label| label == "Gebaeude / POI")
+            .expect("POI-Eintrag erwartet");
+        assert_eq!(entries[poi + 1..poi + 3], groups[..]);
+
+        let unclustered = labels(&OverviewOptions {
+            poi_clusters: false,
+            ..OverviewOptions::default()
+        });
+        assert!(!unclustered.iter().any(|label| label.starts_with("G1")));
+    }
+}
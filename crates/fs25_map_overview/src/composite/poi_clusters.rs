@@ -0,0 +1,228 @@
+//! Zusammenfassung dicht beieinander liegender POIs.
+//!
+//! Produktionsanlagen und Hoefe bestehen oft aus vielen Placeables auf engem
+//! Raum, deren Marker und Namen sich gegenseitig verdecken. POIs, deren Marker
+//! naeher als [`CLUSTER_DISTANCE_FACTOR`] Markerradien beieinander liegen,
+//! werden (transitiv) zu einer Gruppe verbunden. Eine Gruppe erscheint als ein
+//! groesserer Marker mit der Anzahl und einer Kennung ("G1"); die Legende
+//! listet die enthaltenen POIs je Kennung auf.
+
+use std::collections::BTreeMap;
+
+use super::labels::poi_marker_radius;
+use super::Poi;
+
+/// Markerabstand (in Markerradien), unterhalb dessen POIs gruppiert werden.
+const CLUSTER_DISTANCE_FACTOR: i64 = 4;
+
+/// Hoechstzahl unterschiedlicher Namen pro Legendenzeile; der Rest wird gezaehlt.
+const MAX_LEGEND_NAMES: usize = 4;
+
+/// Fuellfarbe der Gruppen-Marker (dunkler als einzelne POIs).
+pub(crate) const POI_GROUP_COLOR: [u8; 3] = [150, 25, 25];
+
+/// Darzustellender Marker: einzelner POI oder Gruppe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PoiMarker {
+    /// Pixel-X-Koordinate (Schwerpunkt bei Gruppen)
+    pub x: u32,
+    /// Pixel-Y-Koordinate (Schwerpunkt bei Gruppen)
+    pub y: u32,
+    /// Beschriftung neben dem Marker: POI-Name bzw. Gruppenkennung
+    pub label: String,
+    /// Anzahl zusammengefasster POIs (1 = einzelner POI)
+    pub count: usize,
+}
+
+impl PoiMarker {
+    /// Einzelner, nicht gruppierter POI.
+    pub(crate) fn single(poi: &Poi) -> Self {
+        Self {
+            x: poi.x,
+            y: poi.y,
+            label: poi.label.clone(),
+            count: 1,
+        }
+    }
+
+    /// Markerradius in einem Bild der Breite `width`; Gruppen sind groesser,
+    /// damit die Anzahl hineinpasst.
+    pub(crate) fn radius(&self, width: u32) -> i32 {
+        let radius = poi_marker_radius(width);
+        if self.count > 1 {
+            radius * 3 / 2 + 2
+        } else {
+            radius
+        }
+    }
+
+    /// Schrift-Skalierung der Anzahl im Gruppen-Marker.
+    pub(crate) fn count_scale(&self, width: u32) -> u32 {
+        (self.radius(width) / 7).max(1) as u32
+    }
+}
+
+/// Marker und Legendenzeilen der POIs eines Bildes.
+#[derive(Debug, Default)]
+pub(crate) struct PoiGroups {
+    /// Marker in Reihenfolge des jeweils ersten enthaltenen POIs
+    pub markers: Vec<PoiMarker>,
+    /// Eine Legendenzeile pro Gruppe, z.B. "G1: 3× Silo, Werkstatt"
+    pub legend_lines: Vec<String>,
+}
+
+/// Fasst nahe beieinander liegende POIs zu Gruppen zusammen.
+///
+/// Mit `clustering == false` entsteht fuer jeden POI ein eigener Marker und
+/// keine Legendenzeile.
+pub(crate) fn group_pois(pois: &[Poi], width: u32, clustering: bool) -> PoiGroups {
+    if !clustering {
+        return PoiGroups {
+            markers: pois.iter().map(PoiMarker::single).collect(),
+            legend_lines: Vec::new(),
+        };
+    }
+
+    let mut groups = PoiGroups::default();
+    for members in cluster_members(pois, width) {
+        if let [index] = members[..] {
+            groups.markers.push(PoiMarker::single(&pois[index]));
+            continue;
+        }
+
+        let id = format!("G{}", groups.legend_lines.len() + 1);
+        let count = members.len();
+        let (sum_x, sum_y) = members.iter().fold((0u64, 0u64), |(sx, sy), &index| {
+            (sx + pois[index].x as u64, sy + pois[index].y as u64)
+        });
+        groups.legend_lines.push(format!(
+            "{}: {}",
+            id,
+            summarize_labels(members.iter().map(|&index| pois[index].label.as_str()))
+        ));
+        groups.markers.push(PoiMarker {
+            x: (sum_x / count as u64) as u32,
+            y: (sum_y / count as u64) as u32,
+            label: id,
+            count,
+        });
+    }
+    groups
+}
+
+/// Indizes der POIs je Gruppe (Single-Linkage), sortiert nach erstem Index.
+fn cluster_members(pois: &[Poi], width: u32) -> Vec<Vec<usize>> {
+    let distance = poi_marker_radius(width) as i64 * CLUSTER_DISTANCE_FACTOR;
+    let mut parent: Vec<usize> = (0..pois.len()).collect();
+
+    fn root(parent: &mut [usize], mut index: usize) -> usize {
+        while parent[index] != index {
+            parent[index] = parent[parent[index]];
+            index = parent[index];
+        }
+        index
+    }
+
+    for (i, a) in pois.iter().enumerate() {
+        for (j, b) in pois.iter().enumerate().skip(i + 1) {
+            let dx = a.x as i64 - b.x as i64;
+            let dy = a.y as i64 - b.y as i64;
+            if dx * dx + dy * dy < distance * distance {
+                let (ra, rb) = (root(&mut parent, i), root(&mut parent, j));
+                // Kleinerer Index bleibt Wurzel, damit die Reihenfolge stabil ist
+                parent[ra.max(rb)] = ra.min(rb);
+            }
+        }
+    }
+
+    let mut clusters: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for index in 0..pois.len() {
+        let root = root(&mut parent, index);
+        clusters.entry(root).or_default().push(index);
+    }
+    clusters.into_values().collect()
+}
+
+/// Fasst gleichnamige POIs zusammen ("3× Silo") und kuerzt lange Listen.
+fn summarize_labels<'a>(labels: impl Iterator<Item = &'a str>) -> String {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for label in labels {
+        match counts.iter_mut().find(|(name, _)| *name == label) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((label, 1)),
+        }
+    }
+
+    let mut parts: Vec<String> = counts
+        .iter()
+        .take(MAX_LEGEND_NAMES)
+        .map(|&(name, count)| match count {
+            1 => name.to_string(),
+            _ => format!("{}× {}", count, name),
+        })
+        .collect();
+    if counts.len() > MAX_LEGEND_NAMES {
+        parts.push(format!("+{} weitere", counts.len() - MAX_LEGEND_NAMES));
+    }
+    parts.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poi(x: u32, y: u32, label: &str) -> Poi {
+        Poi {
+            x,
+            y,
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn nearby_pois_form_a_group_with_legend_line() {
+        // Markerradius 6 bei 1200 px → Gruppierung unter 24 px Abstand
+        let pois = [
+            poi(100, 100, "Silo"),
+            poi(500, 500, "Tankstelle"),
+            poi(110, 100, "Silo"),
+            poi(130, 100, "Werkstatt"),
+        ];
+
+        let groups = group_pois(&pois, 1200, true);
+
+        assert_eq!(
+            groups.markers,
+            vec![
+                PoiMarker {
+                    x: 113,
+                    y: 100,
+                    label: "G1".to_string(),
+                    count: 3,
+                },
+                PoiMarker::single(&pois[1]),
+            ]
+        );
+        assert_eq!(groups.legend_lines, vec!["G1: 2× Silo, Werkstatt"]);
+    }
+
+    #[test]
+    fn disabled_clustering_keeps_every_poi() {
+        let pois = [poi(100, 100, "Silo"), poi(101, 100, "Silo")];
+
+        let groups = group_pois(&pois, 1200, false);
+
+        assert_eq!(groups.markers.len(), 2);
+        assert!(groups.markers.iter().all(|marker| marker.count == 1));
+        assert!(groups.legend_lines.is_empty());
+    }
+
+    #[test]
+    fn long_member_lists_are_shortened() {
+        let labels = ["A", "B", "C", "D", "E", "F"];
+        assert_eq!(
+            summarize_labels(labels.into_iter()),
+            "A, B, C, D, +2 weitere"
+        );
+    }
+}
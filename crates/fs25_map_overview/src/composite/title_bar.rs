@@ -0,0 +1,233 @@
+use image::{Rgb, RgbImage};
+
+use super::OverviewOptions;
+use crate::discovery::MapInfo;
+use crate::text;
+
+/// Darstellungsoptionen der Titel-Bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleBarOptions {
+    /// Hoehe der Bar in Pixeln (`None` = automatisch aus der Schriftgroesse)
+    pub height: Option<u32>,
+    /// Hintergrundfarbe der Bar
+    pub background_color: [u8; 3],
+    /// Deckkraft des Hintergrunds (0.0 = transparent, 1.0 = deckend)
+    pub background_opacity: f32,
+    /// Schriftfarbe von Titel und Untertitel
+    pub text_color: [u8; 3],
+    /// Untertitel mit Kartengroesse und Autor (aus der modDesc.xml) anzeigen
+    pub subtitle: bool,
+}
+
+impl Default for TitleBarOptions {
+    fn default() -> Self {
+        Self {
+            height: None,
+            background_color: [30, 30, 30],
+            background_opacity: 0.0,
+            text_color: [255, 255, 255],
+            subtitle: false,
+        }
+    }
+}
+
+/// Berechnete Titel-Bar; gemeinsam fuer Raster- und SVG-Ausgabe.
+pub(crate) struct TitleBarLayout {
+    /// Titelzeile (`<Kartenname> - Overview`)
+    pub title: String,
+    /// Untertitel mit Kartengroesse und Autor
+    pub subtitle: Option<String>,
+    /// Schrift-Skalierung des Titels
+    pub scale: u32,
+    /// Schrift-Skalierung des Untertitels
+    pub subtitle_scale: u32,
+    /// Linker Textrand
+    pub x: i32,
+    /// Oberkante der Titelzeile
+    pub title_y: i32,
+    /// Oberkante der Untertitelzeile
+    pub subtitle_y: i32,
+    /// Hoehe der Bar
+    pub height: i32,
+    /// Hintergrundfarbe
+    pub background_color: [u8; 3],
+    /// Deckkraft des Hintergrunds (0.0–1.0)
+    pub background_opacity: f32,
+    /// Schriftfarbe
+    pub text_color: [u8; 3],
+}
+
+impl TitleBarLayout {
+    /// Berechnet die Titel-Bar fuer ein Bild der Breite `width`.
+    pub(crate) fn new(width: u32, map_info: &MapInfo, style: &TitleBarOptions) -> Self {
+        let scale = title_scale(width);
+        let subtitle_scale = subtitle_scale(scale);
+        let subtitle = style.subtitle.then(|| {
+            let size = format!("{0} × {0} m", map_info.map_size);
+            match &map_info.author {
+                Some(author) => format!("{} · Autor: {}", size, author),
+                None => size,
+            }
+        });
+
+        let height = bar_height(width, style);
+        let title_h = text::text_height(scale) as i32;
+        let content_h = content_height(scale, style.subtitle);
+        let title_y = ((height - content_h) / 2).max(0);
+
+        Self {
+            title: format!("{} - Overview", map_info.title),
+            subtitle,
+            scale,
+            subtitle_scale,
+            x: (scale * 4) as i32,
+            title_y,
+            subtitle_y: title_y + title_h + scale as i32,
+            height,
+            background_color: style.background_color,
+            background_opacity: style.background_opacity.clamp(0.0, 1.0),
+            text_color: style.text_color,
+        }
+    }
+
+    /// Zeichnet Hintergrund, Titel und Untertitel auf das Bild.
+    pub(crate) fn draw(&self, image: &mut RgbImage) {
+        if self.background_opacity > 0.0 {
+            text::draw_rect_blended(
+                image,
+                0,
+                0,
+                image.width(),
+                self.height.max(0) as u32,
+                Rgb(self.background_color),
+                self.background_opacity,
+            );
+        }
+        let color = Rgb(self.text_color);
+        text::draw_text_outlined(image, self.x, self.title_y, &self.title, color, self.scale);
+        if let Some(subtitle) = &self.subtitle {
+            text::draw_text_outlined(
+                image,
+                self.x,
+                self.subtitle_y,
+                subtitle,
+                color,
+                self.subtitle_scale,
+            );
+        }
+    }
+}
+
+/// Hoehe, die die Titel-Bar am oberen Rand belegt (0, wenn sie ausgeblendet ist).
+///
+/// Obere Legenden-Positionen beginnen unterhalb dieser Hoehe.
+pub(crate) fn title_bar_height(width: u32, options: &OverviewOptions) -> i32 {
+    if options.title_bar {
+        bar_height(width, &options.title_bar_options)
+    } else {
+        0
+    }
+}
+
+/// Schrift-Skalierung der Titel-Bar fuer eine Bildbreite.
+pub(crate) fn title_scale(width: u32) -> u32 {
+    (width / 800).clamp(2, 6)
+}
+
+fn subtitle_scale(scale: u32) -> u32 {
+    scale.saturating_sub(1).max(1)
+}
+
+fn bar_height(width: u32, style: &TitleBarOptions) -> i32 {
+    let scale = title_scale(width);
+    style.height.map_or_else(
+        || content_height(scale, style.subtitle) + (scale * 6) as i32,
+        |height| height as i32,
+    )
+}
+
+/// Hoehe von Titel und optionalem Untertitel inklusive Zeilenabstand.
+fn content_height(scale: u32, subtitle: bool) -> i32 {
+    let title_h = text::text_height(scale) as i32;
+    if subtitle {
+        title_h + scale as i32 + text::text_height(subtitle_scale(scale)) as i32
+    } else {
+        title_h
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map_info(author: Option<&str>) -> MapInfo {
+        MapInfo {
+            title: String::from("Testkarte"),
+            author: author.map(String::from),
+            map_size: 2048,
+            height_scale: crate::DEFAULT_HEIGHT_SCALE,
+            terrain_offset: (0.0, 0.0),
+            config_path: String::new(),
+            data_dir: String::new(),
+            config_dir: String::new(),
+            placeables_path: None,
+            i3d_path: None,
+            farmlands_path: None,
+            image_path: None,
+        }
+    }
+
+    #[test]
+    fn layout_builds_subtitle_from_map_size_and_author() {
+        let style = TitleBarOptions {
+            subtitle: true,
+            ..TitleBarOptions::default()
+        };
+
+        let layout = TitleBarLayout::new(1600, &map_info(Some("GIANTS")), &style);
+        assert_eq!(layout.title, "Testkarte - Overview");
+        assert_eq!(
+            layout.subtitle.as_deref(),
+            Some("2048 × 2048 m · Autor: GIANTS")
+        );
+        assert!(layout.subtitle_y > layout.title_y);
+
+        let layout = TitleBarLayout::new(1600, &map_info(None), &style);
+        assert_eq!(layout.subtitle.as_deref(), Some("2048 × 2048 m"));
+    }
+
+    #[test]
+    fn layout_centers_text_in_fixed_height() {
+        let style = TitleBarOptions {
+            height: Some(100),
+            ..TitleBarOptions::default()
+        };
+
+        let layout = TitleBarLayout::new(1600, &map_info(None), &style);
+        assert_eq!(layout.height, 100);
+        let title_h = text::text_height(layout.scale) as i32;
+        assert_eq!(layout.title_y, (100 - title_h) / 2);
+    }
+
+    #[test]
+    fn draw_fills_background_and_hidden_bar_takes_no_space() {
+        let style = TitleBarOptions {
+            height: Some(40),
+            background_color: [0, 0, 200],
+            background_opacity: 1.0,
+            ..TitleBarOptions::default()
+        };
+        let mut image = RgbImage::from_pixel(200, 100, Rgb([200, 200, 200]));
+
+        TitleBarLayout::new(200, &map_info(None), &style).draw(&mut image);
+
+        assert_eq!(*image.get_pixel(199, 39), Rgb([0, 0, 200]));
+        assert_eq!(*image.get_pixel(199, 40), Rgb([200, 200, 200]));
+
+        let hidden = OverviewOptions {
+            title_bar: false,
+            ..OverviewOptions::default()
+        };
+        assert_eq!(title_bar_height(200, &hidden), 0);
+    }
+}
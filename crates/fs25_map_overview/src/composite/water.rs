@@ -0,0 +1,325 @@
+//! Gewaesser-Layer: Seen und Fluesse aus Wasser-Weight-Maps und DEM-Tiefe.
+//!
+//! Die Wasser-Weight-Maps markieren nur den Gewaessergrund und sind an den
+//! Raendern ausgefranst. Ist ein DEM vorhanden, wird fuer jedes Gewaesser
+//! (zusammenhaengende Wasser-Pixel) die Hoehe der Wasserflaeche geschaetzt
+//! (90. Perzentil der DEM-Werte unter seinen Wasser-Pixeln) und die Flaeche
+//! per Flood-Fill bis zu dieser Hoehe aufgefuellt. Die Differenz zur
+//! Wasserhoehe, bezogen auf die tiefste Stelle des Gewaessers, ergibt die
+//! relative Tiefe: flache Uferzonen hell, tiefe Stellen dunkel. So erhalten
+//! auch hoch gelegene Bergseen eine eigene Tiefenschattierung. Abschliessend
+//! werden Tiefe und Uferlinie weichgezeichnet.
+
+use crate::hillshade::DemImage;
+use image::{DynamicImage, GrayImage, Luma, RgbImage};
+use std::collections::VecDeque;
+
+/// Wasserfarbe bei geringer Tiefe.
+pub(crate) const WATER_SHALLOW_COLOR: [u8; 3] = [88, 140, 190];
+/// Wasserfarbe bei maximaler Tiefe.
+const WATER_DEEP_COLOR: [u8; 3] = [28, 66, 135];
+
+/// Ab diesem Gewicht (0–255) gilt ein Pixel als Wasser-Saatpunkt.
+const WATER_SEED_THRESHOLD: u16 = 128;
+
+/// Deckkraft der Wasserflaeche im Inneren.
+const WATER_ALPHA: f32 = 0.9;
+
+/// Erkannte Gewaesser im Zielraster.
+pub struct WaterData {
+    /// Relative Tiefe pro Pixel (0 = flach/Ufer, 255 = tiefste Stelle)
+    pub depth: GrayImage,
+    /// Weichgezeichnete Abdeckung (0 = kein Wasser, 255 = vollstaendig Wasser)
+    pub coverage: GrayImage,
+}
+
+/// Erkennt Gewaesser aus Wasser-Weight-Maps und optionalem DEM.
+///
+/// `dem` muss bereits auf `target_size` skaliert sein. Gibt `None` zurueck,
+/// wenn die Karte keine Wasser-Weight-Maps mit nennenswerter Abdeckung besitzt.
+pub fn extract_water(
+    weight_images: &[(String, DynamicImage)],
+    dem: Option<&DemImage>,
+    target_size: u32,
+) -> Option<WaterData> {
+    let size = target_size as usize;
+    let seeds = water_seed_mask(weight_images, target_size)?;
+
+    let (mask, depth) = match dem.filter(|dem| dem.dimensions() == (target_size, target_size)) {
+        Some(dem) => fill_to_water_level(&seeds, dem, size),
+        None => (seeds, vec![0u8; size * size]),
+    };
+
+    let binary: Vec<u8> = mask.iter().map(|&w| if w { 255 } else { 0 }).collect();
+    let binary = GrayImage::from_raw(target_size, target_size, binary)?;
+    let sigma = (target_size as f32 / 1024.0).max(0.8);
+    let depth = smooth_depth(
+        GrayImage::from_raw(target_size, target_size, depth)?,
+        &mask,
+        sigma,
+    );
+    let mut coverage = image::imageops::fast_blur(&binary, sigma);
+    // Weiche Kante um die 50%-Linie: schmaler Uebergang statt breitem Schleier
+    for pixel in coverage.pixels_mut() {
+        let t = ((pixel[0] as f32 - 64.0) / 128.0).clamp(0.0, 1.0);
+        pixel[0] = (t * t * (3.0 - 2.0 * t) * 255.0).round() as u8;
+    }
+
+    Some(WaterData { depth, coverage })
+}
+
+/// Zeichnet die Gewaesser mit tiefenabhaengiger Blaufaerbung.
+pub fn draw_water(image: &mut RgbImage, water: &WaterData) {
+    debug_assert_eq!(image.dimensions(), water.coverage.dimensions());
+
+    for ((pixel, &Luma([cov])), &Luma([depth])) in image
+        .pixels_mut()
+        .zip(water.coverage.pixels())
+        .zip(water.depth.pixels())
+    {
+        if cov == 0 {
+            continue;
+        }
+        let alpha = cov as f32 / 255.0 * WATER_ALPHA;
+        let t = depth as f32 / 255.0;
+        for channel in 0..3 {
+            let water = WATER_SHALLOW_COLOR[channel] as f32 * (1.0 - t)
+                + WATER_DEEP_COLOR[channel] as f32 * t;
+            let base = pixel[channel] as f32;
+            pixel[channel] = (base * (1.0 - alpha) + water * alpha)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
+/// Summiert alle Wasser-Weight-Maps und liefert die Saatpunkt-Maske.
+fn water_seed_mask(
+    weight_images: &[(String, DynamicImage)],
+    target_size: u32,
+) -> Option<Vec<bool>> {
+    let size = target_size as usize;
+    let mut sum = vec![0u16; size * size];
+    let mut found = false;
+
+    for (name, img) in weight_images {
+        if !name.to_ascii_lowercase().contains("water") {
+            continue;
+        }
+        found = true;
+        let weights = img.to_luma8();
+        let weights = if weights.dimensions() != (target_size, target_size) {
+            image::imageops::resize(
+                &weights,
+                target_size,
+                target_size,
+                image::imageops::FilterType::Triangle,
+            )
+        } else {
+            weights
+        };
+        for (acc, &w) in sum.iter_mut().zip(weights.as_raw()) {
+            *acc = acc.saturating_add(w as u16);
+        }
+    }
+
+    if !found {
+        return None;
+    }
+    let seeds: Vec<bool> = sum.iter().map(|&w| w >= WATER_SEED_THRESHOLD).collect();
+    seeds.iter().any(|&s| s).then_some(seeds)
+}
+
+/// Fuellt jedes Gewaesser von seinen Saatpunkten aus bis zu seiner Wasserhoehe.
+///
+/// Rueckgabe: (Wasser-Maske, relative Tiefe 0–255 je Gewaesser).
+fn fill_to_water_level(seeds: &[bool], dem: &DemImage, size: usize) -> (Vec<bool>, Vec<u8>) {
+    let heights = dem.as_raw();
+    let mut mask = vec![false; seeds.len()];
+    let mut depth = vec![0u8; seeds.len()];
+
+    for component in seed_components(seeds, size) {
+        // Mit einem frueher gefuellten Gewaesser zusammengewachsen
+        if component.iter().any(|&i| mask[i]) {
+            continue;
+        }
+
+        let mut seed_heights: Vec<u16> = component.iter().map(|&i| heights[i]).collect();
+        seed_heights.sort_unstable();
+        let level = seed_heights[(seed_heights.len() - 1) * 9 / 10];
+
+        let lake = flood_fill(&component, size, &mut mask, |n| heights[n] <= level);
+        let floor = lake.iter().map(|&i| heights[i]).min().unwrap_or(level);
+        let range = level.saturating_sub(floor).max(1) as u32;
+        for &i in &lake {
+            depth[i] = (level.saturating_sub(heights[i]) as u32 * 255 / range).min(255) as u8;
+        }
+    }
+
+    (mask, depth)
+}
+
+/// Zerlegt die Saatpunkte in 4-zusammenhaengende Gruppen (Pixel-Indizes).
+fn seed_components(seeds: &[bool], size: usize) -> Vec<Vec<usize>> {
+    let mut visited = vec![false; seeds.len()];
+    let mut components = Vec::new();
+    for start in 0..seeds.len() {
+        if seeds[start] && !visited[start] {
+            components.push(flood_fill(&[start], size, &mut visited, |n| seeds[n]));
+        }
+    }
+    components
+}
+
+/// Flood-Fill (4er-Nachbarschaft) ab `starts`; markiert besuchte Pixel in `visited`.
+///
+/// `accept` entscheidet, ob ein Nachbar dazugehoert. Startpunkte werden immer
+/// uebernommen. Gibt alle neu markierten Pixel zurueck.
+fn flood_fill(
+    starts: &[usize],
+    size: usize,
+    visited: &mut [bool],
+    accept: impl Fn(usize) -> bool,
+) -> Vec<usize> {
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for &i in starts {
+        if !visited[i] {
+            visited[i] = true;
+            queue.push_back(i);
+        }
+    }
+
+    let mut filled = Vec::new();
+    while let Some(i) = queue.pop_front() {
+        filled.push(i);
+        let (x, y) = (i % size, i / size);
+        let neighbors = [
+            (x > 0).then(|| i - 1),
+            (x + 1 < size).then(|| i + 1),
+            (y > 0).then(|| i - size),
+            (y + 1 < size).then(|| i + size),
+        ];
+        for n in neighbors.into_iter().flatten() {
+            if !visited[n] && accept(n) {
+                visited[n] = true;
+                queue.push_back(n);
+            }
+        }
+    }
+    filled
+}
+
+/// Glaettet die Treppenstufen der 8-Bit-DEM-Tiefe; ausserhalb des Wassers bleibt 0.
+fn smooth_depth(depth: GrayImage, mask: &[bool], sigma: f32) -> GrayImage {
+    let mut smoothed = image::imageops::fast_blur(&depth, sigma);
+    for (pixel, &water) in smoothed.pixels_mut().zip(mask) {
+        if !water {
+            pixel[0] = 0;
+        }
+    }
+    smoothed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    fn weight_map(size: u32, f: impl Fn(u32, u32) -> u8) -> DynamicImage {
+        DynamicImage::ImageLuma8(GrayImage::from_fn(size, size, |x, y| Luma([f(x, y)])))
+    }
+
+    #[test]
+    fn extract_water_requires_water_weight_maps() {
+        let images = vec![(String::from("grass_weight.png"), weight_map(4, |_, _| 255))];
+        assert!(extract_water(&images, None, 4).is_none());
+    }
+
+    #[test]
+    fn extract_water_fills_basin_up_to_water_level() {
+        // Becken: Mitte tief (10), Rand hoch (200); Wasser-Saat nur im Zentrum
+        let size = 16;
+        let dem = DemImage::from_fn(size, size, |x, y| {
+            let inside = (4..12).contains(&x) && (4..12).contains(&y);
+            Luma([if inside { 2_570 } else { 51_400 }])
+        });
+        let images = vec![(
+            String::from("water_weight.png"),
+            weight_map(size, |x, y| if x == 8 && y == 8 { 255 } else { 0 }),
+        )];
+
+        let water = extract_water(&images, Some(&dem), size).expect("Wasser erwartet");
+
+        assert!(water.coverage.get_pixel(6, 6)[0] > 200);
+        assert_eq!(water.coverage.get_pixel(0, 0)[0], 0);
+    }
+
+    #[test]
+    fn extract_water_shades_each_lake_by_its_own_depth() {
+        // Zwei Becken auf unterschiedlicher Hoehe, jeweils flacher Rand und tiefe Mitte
+        let size = 32;
+        let basin = |x: u32, y: u32, x0: u32| {
+            let outer = (x0..x0 + 12).contains(&x) && (2..14).contains(&y);
+            let inner = (x0 + 4..x0 + 8).contains(&x) && (6..10).contains(&y);
+            (outer, inner)
+        };
+        let dem = DemImage::from_fn(size, size, |x, y| {
+            let height = match (basin(x, y, 2), basin(x, y, 18)) {
+                ((_, true), _) => 5_140,
+                ((true, _), _) => 7_710,
+                (_, (_, true)) => 35_980,
+                (_, (true, _)) => 38_550,
+                _ => 51_400,
+            };
+            Luma([height])
+        });
+        let images = vec![(
+            String::from("water_weight.png"),
+            weight_map(size, |x, y| {
+                if basin(x, y, 2).0 || basin(x, y, 18).0 {
+                    255
+                } else {
+                    0
+                }
+            }),
+        )];
+
+        let water = extract_water(&images, Some(&dem), size).expect("Wasser erwartet");
+
+        for x0 in [2, 18] {
+            let deep = water.depth.get_pixel(x0 + 5, 7)[0];
+            let shallow = water.depth.get_pixel(x0 + 1, 3)[0];
+            assert!(deep > 150, "tiefe Mitte bei x0={}: {}", x0, deep);
+            assert!(shallow < 40, "flacher Rand bei x0={}: {}", x0, shallow);
+        }
+        assert_eq!(water.depth.get_pixel(16, 20)[0], 0);
+    }
+
+    #[test]
+    fn draw_water_darkens_with_depth() {
+        let mut image = RgbImage::from_pixel(2, 1, Rgb([100, 100, 100]));
+        let water = WaterData {
+            depth: GrayImage::from_raw(2, 1, vec![0, 255]).expect("2x1"),
+            coverage: GrayImage::from_pixel(2, 1, Luma([255])),
+        };
+
+        draw_water(&mut image, &water);
+
+        let (shallow, deep) = (image.get_pixel(0, 0), image.get_pixel(1, 0));
+        assert!(shallow.0.iter().zip(deep.0).all(|(s, d)| *s > d));
+    }
+
+    #[test]
+    fn draw_water_tints_covered_pixels_blue() {
+        let mut image = RgbImage::from_pixel(2, 2, Rgb([100, 100, 100]));
+        let water = WaterData {
+            depth: GrayImage::from_pixel(2, 2, Luma([0])),
+            coverage: GrayImage::from_pixel(2, 2, Luma([255])),
+        };
+
+        draw_water(&mut image, &water);
+
+        let pixel = image.get_pixel(1, 1);
+        assert!(pixel[2] > pixel[0], "Wasser muss blaeulich sein");
+    }
+}
@@ -0,0 +1,214 @@
+//! Hoehenlinien-Layer: Isolinien aus dem DEM mit Hoehenbeschriftung.
+//!
+//! Das DEM wird mit voller 16-Bit-Aufloesung gelesen, leicht geglaettet und
+//! in Meter umgerechnet. Eine Hoehenlinie liegt ueberall dort, wo zwei
+//! benachbarte Pixel in unterschiedlichen Hoehenstufen liegen; jede
+//! `index_every`-te Stufe wird als kraeftigere Zaehllinie gezeichnet und
+//! beschriftet.
+
+use image::{DynamicImage, ImageBuffer, Luma, Rgb, RgbImage};
+
+use crate::draw::draw_disc;
+use crate::text;
+
+/// Farbe der normalen Hoehenlinien (helles Braun).
+pub(crate) const CONTOUR_COLOR: [u8; 3] = [160, 120, 80];
+/// Farbe der Zaehllinien (dunkles Braun).
+pub(crate) const INDEX_CONTOUR_COLOR: [u8; 3] = [105, 65, 35];
+
+/// Einstellungen fuer den Hoehenlinien-Layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContourOptions {
+    /// Hoehenabstand zweier Linien in Metern
+    pub interval: f32,
+    /// Jede n-te Linie wird als beschriftete Zaehllinie gezeichnet (0 = keine)
+    pub index_every: u32,
+    /// Hoehe in Metern, die dem maximalen DEM-Wert entspricht (`heightScale` der Map)
+    pub height_scale: f32,
+    /// Zaehllinien mit ihrer Hoehe beschriften
+    pub labels: bool,
+}
+
+impl Default for ContourOptions {
+    fn default() -> Self {
+        Self {
+            interval: 5.0,
+            index_every: 5,
+            height_scale: 255.0,
+            labels: true,
+        }
+    }
+}
+
+/// Hoehenraster in Metern (quadratisch, zeilenweise).
+pub struct HeightField {
+    /// Kantenlaenge in Pixeln
+    pub size: u32,
+    /// Hoehe pro Pixel in Metern
+    pub heights: Vec<f32>,
+}
+
+/// Dekodiert das DEM als 16-Bit-Hoehenraster der Kantenlaenge `size`.
+///
+/// Akzeptiert PNG, DDS und GDM. Gibt `None` zurueck, wenn das Bild nicht
+/// dekodiert werden kann.
+pub fn height_field_from_dem(dem_data: &[u8], size: u32, height_scale: f32) -> Option<HeightField> {
+    let dem = match crate::dds::decode_map_raster(dem_data) {
+        Ok(dem) => dem,
+        Err(error) => {
+            log::warn!("DEM konnte nicht geladen werden: {}", error);
+            return None;
+        }
+    };
+    Some(height_field_from_image(&dem, size, height_scale))
+}
+
+fn height_field_from_image(dem: &DynamicImage, size: u32, height_scale: f32) -> HeightField {
+    let raw: ImageBuffer<Luma<u16>, Vec<u16>> = dem.to_luma16();
+    let resized = if raw.dimensions() != (size, size) {
+        image::imageops::resize(&raw, size, size, image::imageops::FilterType::Triangle)
+    } else {
+        raw
+    };
+    // Treppenstufen der DEM-Quantisierung glaetten, sonst zerfasern flache Hoehenlinien
+    let smoothed = image::imageops::fast_blur(&resized, (size as f32 / 2048.0).max(1.0));
+
+    let heights = smoothed
+        .as_raw()
+        .iter()
+        .map(|&v| v as f32 / u16::MAX as f32 * height_scale)
+        .collect();
+    HeightField { size, heights }
+}
+
+/// Zeichnet Hoehenlinien und (optional) Hoehenbeschriftungen der Zaehllinien.
+pub fn draw_contours(image: &mut RgbImage, field: &HeightField, options: &ContourOptions) {
+    if options.interval <= 0.0 || field.size == 0 || image.width() != field.size {
+        return;
+    }
+    let size = field.size as usize;
+    let level_of = |h: f32| (h / options.interval).floor() as i64;
+    let is_index = |level: i64| options.index_every > 0 && level % options.index_every as i64 == 0;
+
+    let base_radius = (image.width() / 4096) as i32;
+    let mut index_pixels: Vec<(u32, u32, i64)> = Vec::new();
+
+    for y in 0..size {
+        for x in 0..size {
+            let level = level_of(field.heights[y * size + x]);
+            let mut crossed = None;
+            if x + 1 < size {
+                let right = level_of(field.heights[y * size + x + 1]);
+                if right != level {
+                    crossed = Some(level.max(right));
+                }
+            }
+            if y + 1 < size {
+                let below = level_of(field.heights[(y + 1) * size + x]);
+                if below != level {
+                    crossed = Some(crossed.unwrap_or(i64::MIN).max(level.max(below)));
+                }
+            }
+            let Some(crossed) = crossed else {
+                continue;
+            };
+
+            if is_index(crossed) {
+                draw_disc(
+                    image,
+                    x as i32,
+                    y as i32,
+                    base_radius + 1,
+                    Rgb(INDEX_CONTOUR_COLOR),
+                );
+                index_pixels.push((x as u32, y as u32, crossed));
+            } else {
+                draw_disc(image, x as i32, y as i32, base_radius, Rgb(CONTOUR_COLOR));
+            }
+        }
+    }
+
+    if options.labels {
+        draw_contour_labels(image, &index_pixels, options.interval);
+    }
+}
+
+/// Setzt hoechstens eine Beschriftung pro Rasterzelle und Hoehenstufe.
+fn draw_contour_labels(image: &mut RgbImage, index_pixels: &[(u32, u32, i64)], interval: f32) {
+    let scale = (image.width() / 1600).clamp(1, 3);
+    let cell = (image.width() / 8).max(1);
+    let cells_per_row = image.width().div_ceil(cell);
+    let label_color = Rgb([255, 235, 210]);
+
+    let mut labelled = std::collections::HashSet::new();
+    let mut placed: Vec<(i32, i32)> = Vec::new();
+    let min_distance = (cell / 2) as i32;
+
+    for &(x, y, level) in index_pixels {
+        let cell_index = (y / cell) * cells_per_row + x / cell;
+        if labelled.contains(&(cell_index, level)) {
+            continue;
+        }
+        let (px, py) = (x as i32, y as i32);
+        let too_close = placed
+            .iter()
+            .any(|&(ox, oy)| (ox - px).abs() < min_distance && (oy - py).abs() < min_distance);
+        if too_close {
+            continue;
+        }
+
+        let label = format!("{}", (level as f32 * interval).round() as i64);
+        let tw = text::text_width(&label, scale) as i32;
+        let th = text::text_height(scale) as i32;
+        text::draw_text_outlined(image, px - tw / 2, py - th / 2, &label, label_color, scale);
+        labelled.insert((cell_index, level));
+        placed.push((px, py));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rampe in X-Richtung: 1 m pro Pixel.
+    fn ramp(size: u32) -> HeightField {
+        let heights = (0..size * size).map(|i| (i % size) as f32).collect();
+        HeightField { size, heights }
+    }
+
+    #[test]
+    fn draw_contours_marks_level_crossings_only() {
+        let field = ramp(32);
+        let options = ContourOptions {
+            interval: 10.0,
+            index_every: 0,
+            labels: false,
+            ..ContourOptions::default()
+        };
+        let mut image = RgbImage::new(32, 32);
+
+        draw_contours(&mut image, &field, &options);
+
+        // Stufe wechselt zwischen x=9 (9 m) und x=10 (10 m)
+        assert_eq!(*image.get_pixel(9, 5), Rgb(CONTOUR_COLOR));
+        assert_eq!(*image.get_pixel(5, 5), Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn draw_contours_uses_index_color_every_nth_level() {
+        let field = ramp(32);
+        let options = ContourOptions {
+            interval: 10.0,
+            index_every: 2,
+            labels: false,
+            ..ContourOptions::default()
+        };
+        let mut image = RgbImage::new(32, 32);
+
+        draw_contours(&mut image, &field, &options);
+
+        // Stufe 1 (10 m) normal, Stufe 2 (20 m) Zaehllinie
+        assert_eq!(*image.get_pixel(9, 5), Rgb(CONTOUR_COLOR));
+        assert_eq!(*image.get_pixel(19, 5), Rgb(INDEX_CONTOUR_COLOR));
+    }
+}
@@ -0,0 +1,460 @@
+//! DDS-Decoder fuer Weight-Maps und DEM.
+//!
+//! FS25-Karten speichern Weight-Maps und DEM teils als `.dds`. Unterstuetzt
+//! werden unkomprimierte Formate (Luminanz, Alpha, RGB(A) mit Bitmasken),
+//! DX10-Header mit `R8`/`R16`/`RGBA8`/`BGRA8` sowie BC4 (ATI1) fuer
+//! einkanalige Daten. DXT1/3/5 uebernimmt der DDS-Decoder des `image`-Crates.
+//! Gelesen wird immer nur die oberste Mip-Stufe.
+
+use anyhow::{bail, ensure, Context, Result};
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, RgbaImage};
+
+use crate::{cache, gdm};
+
+/// DDS Magic Bytes
+const DDS_MAGIC: &[u8; 4] = b"DDS ";
+/// Groesse von Magic + DDS_HEADER
+const HEADER_LEN: usize = 128;
+/// Groesse des DX10-Zusatzheaders
+const DX10_HEADER_LEN: usize = 20;
+
+const DDPF_ALPHAPIXELS: u32 = 0x1;
+const DDPF_ALPHA: u32 = 0x2;
+const DDPF_FOURCC: u32 = 0x4;
+const DDPF_RGB: u32 = 0x40;
+const DDPF_LUMINANCE: u32 = 0x20000;
+
+const DXGI_R8G8B8A8_UNORM: u32 = 28;
+const DXGI_R8G8B8A8_UNORM_SRGB: u32 = 29;
+const DXGI_R16_UNORM: u32 = 56;
+const DXGI_R8_UNORM: u32 = 61;
+const DXGI_BC4_UNORM: u32 = 80;
+const DXGI_B8G8R8A8_UNORM: u32 = 87;
+const DXGI_B8G8R8A8_UNORM_SRGB: u32 = 91;
+
+/// Prueft anhand der Magic Bytes, ob `data` eine DDS-Datei ist.
+pub fn is_dds(data: &[u8]) -> bool {
+    data.starts_with(DDS_MAGIC)
+}
+
+/// Dekodiert die oberste Mip-Stufe einer DDS-Datei.
+///
+/// Einkanalige Formate liefern `Luma8`/`Luma16`, alle anderen `Rgba8`.
+pub fn decode_dds(data: &[u8]) -> Result<DynamicImage> {
+    ensure!(is_dds(data), "Keine DDS-Datei (Magic Bytes fehlen)");
+    ensure!(
+        data.len() >= HEADER_LEN,
+        "DDS-Datei zu klein: {} Bytes",
+        data.len()
+    );
+
+    let height = read_u32(data, 12);
+    let width = read_u32(data, 16);
+    let pf_flags = read_u32(data, 80);
+    let four_cc = &data[84..88];
+    let bit_count = read_u32(data, 88);
+    let masks = [
+        read_u32(data, 92),
+        read_u32(data, 96),
+        read_u32(data, 100),
+        read_u32(data, 104),
+    ];
+    ensure!(
+        width > 0 && height > 0,
+        "DDS mit Groesse {}x{} ist ungueltig",
+        width,
+        height
+    );
+
+    if pf_flags & DDPF_FOURCC != 0 {
+        return match four_cc {
+            b"DX10" => {
+                ensure!(
+                    data.len() >= HEADER_LEN + DX10_HEADER_LEN,
+                    "DDS-DX10-Header unvollstaendig"
+                );
+                let dxgi_format = read_u32(data, HEADER_LEN);
+                decode_dx10(
+                    &data[HEADER_LEN + DX10_HEADER_LEN..],
+                    width,
+                    height,
+                    dxgi_format,
+                )
+            }
+            b"ATI1" | b"BC4U" => decode_bc4(&data[HEADER_LEN..], width, height),
+            _ => image::load_from_memory_with_format(data, image::ImageFormat::Dds).with_context(
+                || {
+                    format!(
+                        "DDS-Format {} wird nicht unterstuetzt",
+                        String::from_utf8_lossy(four_cc)
+                    )
+                },
+            ),
+        };
+    }
+
+    if pf_flags & (DDPF_RGB | DDPF_LUMINANCE | DDPF_ALPHA) == 0 {
+        bail!("DDS-Pixelformat 0x{:x} wird nicht unterstuetzt", pf_flags);
+    }
+    let alpha_mask = if pf_flags & (DDPF_ALPHAPIXELS | DDPF_ALPHA) != 0 {
+        masks[3]
+    } else {
+        0
+    };
+    let single_channel = pf_flags & DDPF_ALPHA != 0 && pf_flags & DDPF_RGB == 0
+        || pf_flags & DDPF_LUMINANCE != 0 && alpha_mask == 0
+        || pf_flags & DDPF_RGB != 0 && masks[1] == 0 && masks[2] == 0 && alpha_mask == 0;
+    let channel_mask = if pf_flags & DDPF_ALPHA != 0 && pf_flags & DDPF_RGB == 0 {
+        masks[3]
+    } else {
+        masks[0]
+    };
+    decode_masked(
+        &data[HEADER_LEN..],
+        width,
+        height,
+        bit_count,
+        if single_channel {
+            MaskLayout::Single(channel_mask)
+        } else {
+            MaskLayout::Rgba([masks[0], masks[1], masks[2], alpha_mask])
+        },
+    )
+}
+
+/// Dekodiert eine Karten-Rasterdatei anhand ihrer Magic Bytes.
+///
+/// DDS und GDM werden selbst dekodiert, alle anderen Formate (PNG, JPEG)
+/// uebernimmt `image::load_from_memory`. GDM-Werte werden auf den vollen
+/// 8- bzw. 16-Bit-Bereich gestreckt. Vorab dekodierte Rohraster aus dem
+/// Kartendaten-Cache werden direkt uebernommen.
+pub fn decode_map_raster(data: &[u8]) -> Result<DynamicImage> {
+    if cache::is_raw_raster(data) {
+        return cache::decode_raw_raster(data);
+    }
+    if is_dds(data) {
+        return decode_dds(data);
+    }
+    if data.starts_with(b"\"MDF") || data.starts_with(b"!MDF") {
+        return gdm_to_image(&gdm::decode_gdm(data)?);
+    }
+    Ok(image::load_from_memory(data)?)
+}
+
+/// Liest die Bildgroesse einer Karten-Rasterdatei, ohne Pixel zu dekodieren.
+///
+/// Erkennt dieselben Formate wie [`decode_map_raster`]; `None` bei
+/// unbekanntem oder beschaedigtem Header.
+pub(crate) fn map_raster_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    if cache::is_raw_raster(data) {
+        return cache::raw_raster_dimensions(data);
+    }
+    if is_dds(data) {
+        return (data.len() >= HEADER_LEN).then(|| (read_u32(data, 16), read_u32(data, 12)));
+    }
+    if data.starts_with(b"\"MDF") || data.starts_with(b"!MDF") {
+        let dimension = gdm::read_gdm_info(data).ok()?.dimension as u32;
+        return Some((dimension, dimension));
+    }
+    image::ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()
+        .ok()?
+        .into_dimensions()
+        .ok()
+}
+
+fn gdm_to_image(gdm: &gdm::GdmImage) -> Result<DynamicImage> {
+    let size = gdm.dimension as u32;
+    let max = ((1u64 << gdm.num_channels.clamp(1, 24)) - 1) as f64;
+    if gdm.is_rgb {
+        let values: Vec<u16> = gdm
+            .pixels
+            .chunks_exact(3)
+            .map(|rgb| {
+                let value = rgb[0] as u32 | (rgb[1] as u32) << 8 | (rgb[2] as u32) << 16;
+                (value as f64 / max * 65535.0).round() as u16
+            })
+            .collect();
+        let image = ImageBuffer::<Luma<u16>, _>::from_raw(size, size, values)
+            .context("GDM-Pixeldaten passen nicht zur Dimension")?;
+        Ok(DynamicImage::ImageLuma16(image))
+    } else {
+        let values: Vec<u8> = gdm
+            .pixels
+            .iter()
+            .map(|&value| (value as f64 / max * 255.0).round().min(255.0) as u8)
+            .collect();
+        let image = GrayImage::from_raw(size, size, values)
+            .context("GDM-Pixeldaten passen nicht zur Dimension")?;
+        Ok(DynamicImage::ImageLuma8(image))
+    }
+}
+
+fn decode_dx10(payload: &[u8], width: u32, height: u32, dxgi_format: u32) -> Result<DynamicImage> {
+    match dxgi_format {
+        DXGI_R8_UNORM => decode_masked(payload, width, height, 8, MaskLayout::Single(0xFF)),
+        DXGI_R16_UNORM => decode_masked(payload, width, height, 16, MaskLayout::Single(0xFFFF)),
+        DXGI_R8G8B8A8_UNORM | DXGI_R8G8B8A8_UNORM_SRGB => decode_masked(
+            payload,
+            width,
+            height,
+            32,
+            MaskLayout::Rgba([0xFF, 0xFF00, 0xFF_0000, 0xFF00_0000]),
+        ),
+        DXGI_B8G8R8A8_UNORM | DXGI_B8G8R8A8_UNORM_SRGB => decode_masked(
+            payload,
+            width,
+            height,
+            32,
+            MaskLayout::Rgba([0xFF_0000, 0xFF00, 0xFF, 0xFF00_0000]),
+        ),
+        DXGI_BC4_UNORM => decode_bc4(payload, width, height),
+        other => bail!("DXGI-Format {} wird nicht unterstuetzt", other),
+    }
+}
+
+/// Kanal-Bitmasken eines unkomprimierten Formats.
+enum MaskLayout {
+    /// Ein Kanal (Luminanz, Alpha oder R)
+    Single(u32),
+    /// R, G, B, A (Maske 0 = Kanal fehlt; Alpha fehlt → opak)
+    Rgba([u32; 4]),
+}
+
+fn decode_masked(
+    payload: &[u8],
+    width: u32,
+    height: u32,
+    bit_count: u32,
+    layout: MaskLayout,
+) -> Result<DynamicImage> {
+    ensure!(
+        matches!(bit_count, 8 | 16 | 24 | 32),
+        "DDS-Bittiefe {} wird nicht unterstuetzt",
+        bit_count
+    );
+    let bytes_per_pixel = (bit_count / 8) as usize;
+    let pixel_count = width as usize * height as usize;
+    ensure!(
+        payload.len() >= pixel_count * bytes_per_pixel,
+        "DDS-Pixeldaten unvollstaendig: {} von {} Bytes",
+        payload.len(),
+        pixel_count * bytes_per_pixel
+    );
+    let raw = payload[..pixel_count * bytes_per_pixel]
+        .chunks_exact(bytes_per_pixel)
+        .map(|bytes| {
+            bytes
+                .iter()
+                .rev()
+                .fold(0u32, |value, &byte| value << 8 | byte as u32)
+        });
+
+    match layout {
+        MaskLayout::Single(mask) if mask.count_ones() > 8 => {
+            let values: Vec<u16> = raw
+                .map(|value| scale_masked(value, mask, 65535) as u16)
+                .collect();
+            let image = ImageBuffer::<Luma<u16>, _>::from_raw(width, height, values)
+                .context("DDS-Pixeldaten passen nicht zur Groesse")?;
+            Ok(DynamicImage::ImageLuma16(image))
+        }
+        MaskLayout::Single(mask) => {
+            let values: Vec<u8> = raw
+                .map(|value| scale_masked(value, mask, 255) as u8)
+                .collect();
+            let image = GrayImage::from_raw(width, height, values)
+                .context("DDS-Pixeldaten passen nicht zur Groesse")?;
+            Ok(DynamicImage::ImageLuma8(image))
+        }
+        MaskLayout::Rgba(masks) => {
+            let mut values = Vec::with_capacity(pixel_count * 4);
+            for value in raw {
+                for (channel, &mask) in masks.iter().enumerate() {
+                    let default = if channel == 3 { 255 } else { 0 };
+                    values.push(if mask == 0 {
+                        default
+                    } else {
+                        scale_masked(value, mask, 255) as u8
+                    });
+                }
+            }
+            let image = RgbaImage::from_raw(width, height, values)
+                .context("DDS-Pixeldaten passen nicht zur Groesse")?;
+            Ok(DynamicImage::ImageRgba8(image))
+        }
+    }
+}
+
+/// Extrahiert die Bits unter `mask` und streckt sie auf `0..=max`.
+fn scale_masked(value: u32, mask: u32, max: u32) -> u32 {
+    if mask == 0 {
+        return 0;
+    }
+    let bits = (value & mask) >> mask.trailing_zeros();
+    let channel_max = mask >> mask.trailing_zeros();
+    if channel_max == max {
+        bits
+    } else {
+        ((bits as u64 * max as u64 + channel_max as u64 / 2) / channel_max as u64) as u32
+    }
+}
+
+/// Dekodiert BC4 (ein Kanal, 8 Bytes je 4×4-Block) zu `Luma8`.
+fn decode_bc4(payload: &[u8], width: u32, height: u32) -> Result<DynamicImage> {
+    let blocks_x = width.div_ceil(4) as usize;
+    let blocks_y = height.div_ceil(4) as usize;
+    ensure!(
+        payload.len() >= blocks_x * blocks_y * 8,
+        "BC4-Daten unvollstaendig: {} von {} Bytes",
+        payload.len(),
+        blocks_x * blocks_y * 8
+    );
+
+    let (w, h) = (width as usize, height as usize);
+    let mut pixels = vec![0u8; w * h];
+    for (block_index, block) in payload
+        .chunks_exact(8)
+        .take(blocks_x * blocks_y)
+        .enumerate()
+    {
+        let palette = bc4_palette(block[0], block[1]);
+        let indices = block[2..8]
+            .iter()
+            .rev()
+            .fold(0u64, |bits, &byte| bits << 8 | byte as u64);
+        let (bx, by) = (block_index % blocks_x * 4, block_index / blocks_x * 4);
+        for texel in 0..16 {
+            let (x, y) = (bx + texel % 4, by + texel / 4);
+            if x < w && y < h {
+                pixels[y * w + x] = palette[(indices >> (3 * texel) & 0b111) as usize];
+            }
+        }
+    }
+
+    let image = GrayImage::from_raw(width, height, pixels)
+        .context("BC4-Pixeldaten passen nicht zur Groesse")?;
+    Ok(DynamicImage::ImageLuma8(image))
+}
+
+fn bc4_palette(r0: u8, r1: u8) -> [u8; 8] {
+    let (a, b) = (r0 as u32, r1 as u32);
+    let mut palette = [r0, r1, 0, 0, 0, 0, 0, 255];
+    if r0 > r1 {
+        for i in 1..7u32 {
+            palette[i as usize + 1] = (((7 - i) * a + i * b + 3) / 7) as u8;
+        }
+    } else {
+        for i in 1..5u32 {
+            palette[i as usize + 1] = (((5 - i) * a + i * b + 2) / 5) as u8;
+        }
+    }
+    palette
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Baut einen minimalen DDS-Header mit Pixelformat-Feldern.
+    fn dds_header(
+        width: u32,
+        height: u32,
+        pf_flags: u32,
+        four_cc: &[u8; 4],
+        bits: u32,
+        masks: [u32; 4],
+    ) -> Vec<u8> {
+        let mut data = vec![0u8; HEADER_LEN];
+        data[..4].copy_from_slice(DDS_MAGIC);
+        data[4..8].copy_from_slice(&124u32.to_le_bytes());
+        data[12..16].copy_from_slice(&height.to_le_bytes());
+        data[16..20].copy_from_slice(&width.to_le_bytes());
+        data[76..80].copy_from_slice(&32u32.to_le_bytes());
+        data[80..84].copy_from_slice(&pf_flags.to_le_bytes());
+        data[84..88].copy_from_slice(four_cc);
+        data[88..92].copy_from_slice(&bits.to_le_bytes());
+        for (index, mask) in masks.iter().enumerate() {
+            let offset = 92 + index * 4;
+            data[offset..offset + 4].copy_from_slice(&mask.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn decodes_uncompressed_luminance_8_and_16_bit() {
+        let mut l8 = dds_header(2, 1, DDPF_LUMINANCE, &[0; 4], 8, [0xFF, 0, 0, 0]);
+        l8.extend_from_slice(&[0, 200]);
+        let image = decode_dds(&l8).expect("L8 muss dekodieren");
+        assert_eq!(image.to_luma8().as_raw(), &vec![0, 200]);
+
+        let mut l16 = dds_header(2, 1, DDPF_LUMINANCE, &[0; 4], 16, [0xFFFF, 0, 0, 0]);
+        l16.extend_from_slice(&1000u16.to_le_bytes());
+        l16.extend_from_slice(&65535u16.to_le_bytes());
+        let DynamicImage::ImageLuma16(image) = decode_dds(&l16).expect("L16 muss dekodieren")
+        else {
+            panic!("16-Bit-Luminanz muss Luma16 liefern");
+        };
+        assert_eq!(image.as_raw(), &vec![1000, 65535]);
+    }
+
+    #[test]
+    fn decodes_dx10_r16_and_bgra() {
+        let mut r16 = dds_header(1, 1, DDPF_FOURCC, b"DX10", 0, [0; 4]);
+        r16.extend_from_slice(&DXGI_R16_UNORM.to_le_bytes());
+        r16.extend_from_slice(&[0; DX10_HEADER_LEN - 4]);
+        r16.extend_from_slice(&4242u16.to_le_bytes());
+        assert_eq!(
+            decode_dds(&r16).expect("R16").to_luma16().as_raw(),
+            &vec![4242]
+        );
+
+        let mut bgra = dds_header(
+            1,
+            1,
+            DDPF_RGB | DDPF_ALPHAPIXELS,
+            &[0; 4],
+            32,
+            [0xFF_0000, 0xFF00, 0xFF, 0xFF00_0000],
+        );
+        bgra.extend_from_slice(&[30, 20, 10, 255]);
+        assert_eq!(
+            decode_dds(&bgra)
+                .expect("BGRA")
+                .to_rgba8()
+                .get_pixel(0, 0)
+                .0,
+            [10, 20, 30, 255]
+        );
+    }
+
+    #[test]
+    fn decodes_bc4_block() {
+        let mut bc4 = dds_header(4, 4, DDPF_FOURCC, b"ATI1", 0, [0; 4]);
+        // r0=255, r1=0; alle Indizes 0 ausser Texel 15 → Index 1 (=0)
+        bc4.extend_from_slice(&[255, 0, 0, 0, 0, 0, 0, 0b0010_0000]);
+        let image = decode_dds(&bc4).expect("BC4").to_luma8();
+        assert_eq!(image.get_pixel(0, 0).0, [255]);
+        assert_eq!(image.get_pixel(3, 3).0, [0]);
+    }
+
+    #[test]
+    fn decode_map_raster_dispatches_by_magic_bytes() {
+        let mut l8 = dds_header(1, 1, DDPF_LUMINANCE, &[0; 4], 8, [0xFF, 0, 0, 0]);
+        l8.push(77);
+        assert_eq!(
+            decode_map_raster(&l8).expect("DDS").to_luma8().as_raw(),
+            &vec![77]
+        );
+        assert!(decode_map_raster(b"kein Bild").is_err());
+    }
+}
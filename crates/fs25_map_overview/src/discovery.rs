@@ -3,19 +3,31 @@
 //! Parst `modDesc.xml` und die Map-Config-XML um Kartengroesse,
 //! Datenverzeichnisse und relevante Dateipfade zu ermitteln.
 
-use anyhow::{bail, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::dds;
+use crate::heightmap::{self, DEFAULT_HEIGHT_SCALE};
+
 /// Erkannte Kartenstruktur aus einem Map-Mod.
 #[derive(Debug, Clone)]
 pub struct MapInfo {
     /// Kartentitel (aus modDesc.xml)
     pub title: String,
-    /// Kartengroesse in Pixeln (quadratisch)
+    /// Autor des Map-Mods (aus modDesc.xml, optional)
+    pub author: Option<String>,
+    /// Kartengroesse in Metern (quadratisch): aus dem Terrain der Map-i3d
+    /// (DEM-Groesse × `unitsPerPixel`), sonst aus width/height der Map-Config
     pub map_size: u32,
+    /// Meter fuer den maximalen DEM-Wert (`heightScale` der Map-i3d, sonst
+    /// [`DEFAULT_HEIGHT_SCALE`])
+    pub height_scale: f32,
+    /// Verschiebung der Terrain-Mitte gegenueber dem Welt-Ursprung in Metern
+    /// (X, Z aus `translation` des Terrains, sonst 0/0)
+    pub terrain_offset: (f32, f32),
     /// Pfad zur Map-Config-XML relativ zum Mod-Root
     pub config_path: String,
     /// Pfad zum data/-Verzeichnis relativ zum Mod-Root
@@ -24,6 +36,25 @@ pub struct MapInfo {
     pub config_dir: String,
     /// Pfad zur placeables.xml relativ zum Mod-Root (optional)
     pub placeables_path: Option<String>,
+    /// Pfad zur Map-i3d relativ zum Mod-Root (optional, aus `<filename>` der Map-Config)
+    pub i3d_path: Option<String>,
+    /// Pfad zur farmlands.xml relativ zum Mod-Root (optional)
+    pub farmlands_path: Option<String>,
+    /// Pfad zum mitgelieferten Kartenbild relativ zum Mod-Root (optional, aus
+    /// `imageFilename` der Map-Config, z.B. `maps/overview.dds`)
+    pub image_path: Option<String>,
+}
+
+/// Ausgewertete Eintraege der Map-Config-XML.
+struct MapConfig {
+    /// Kartengroesse (max. aus width/height)
+    size: u32,
+    /// Inhalt von `<filename>` (Map-i3d)
+    i3d_filename: Option<String>,
+    /// `filename`-Attribut von `<farmlands>`
+    farmlands_filename: Option<String>,
+    /// `imageFilename`-Attribut des Root-Elements (Kartenbild/PDA-Karte)
+    image_filename: Option<String>,
 }
 
 /// Erkennt die Kartenstruktur aus den Dateien eines Map-Mod-ZIPs.
@@ -36,23 +67,29 @@ pub struct MapInfo {
 /// 2. Map-Config-XML lokalisieren
 /// 3. Kartengroesse aus Config-XML lesen
 /// 4. `data/`- und `config/`-Verzeichnisse bestimmen
+/// 5. Liegt die Map-i3d in `files`, Kartengroesse, Hoehenskala und
+///    Terrain-Versatz aus deren `TerrainTransformGroup` uebernehmen
 pub fn discover_map(files: &HashMap<String, Vec<u8>>) -> Result<MapInfo> {
-    // modDesc.xml finden (kann in Root oder einem Unterverzeichnis liegen)
-    let (mod_desc_path, mod_desc_content) = find_file(files, "modDesc.xml")
-        .context("modDesc.xml nicht im ZIP gefunden – ist das ein FS25-Map-Mod?")?;
+    // modDesc.xml finden und parsen (Root, Unterverzeichnis oder verschachteltes ZIP)
+    let (
+        mod_desc_path,
+        ModDesc {
+            title,
+            author,
+            config_filename,
+            placeables_filename,
+        },
+    ) = find_map_mod_desc(files)?;
 
     let mod_root = parent_dir(&mod_desc_path);
 
-    // modDesc.xml parsen
-    let (title, config_filename, placeables_filename) = parse_mod_desc(mod_desc_content)?;
-
     // Map-Config-XML finden und parsen
     let config_path = join_paths(&mod_root, &config_filename);
     let config_content = files
         .get(&config_path)
         .with_context(|| format!("Map-Config-XML nicht gefunden: {}", config_path))?;
 
-    let map_size = parse_map_config(config_content)?;
+    let map_config = parse_map_config(config_content)?;
 
     // data/-Verzeichnis bestimmen
     let config_dir_path = parent_dir(&config_path);
@@ -65,6 +102,41 @@ pub fn discover_map(files: &HashMap<String, Vec<u8>>) -> Result<MapInfo> {
     // Placeables-Pfad
     let placeables_path = placeables_filename.map(|f| join_paths(&mod_root, &f));
 
+    // Map-i3d (Basisspiel-Pfade mit `$data/` liegen nicht im Mod)
+    let i3d_path = map_config
+        .i3d_filename
+        .filter(|f| !f.starts_with('$'))
+        .map(|f| join_paths(&mod_root, &f));
+
+    // farmlands.xml: explizit aus der Map-Config, sonst im config/-Verzeichnis
+    let farmlands_path = map_config
+        .farmlands_filename
+        .filter(|f| !f.starts_with('$'))
+        .map(|f| join_paths(&mod_root, &f))
+        .or_else(|| {
+            let candidate = format!("{}/farmlands.xml", config_dir.trim_end_matches('/'));
+            files.contains_key(&candidate).then_some(candidate)
+        });
+
+    // Kartenbild (Basisspiel-Pfade mit `$data/` liegen nicht im Mod)
+    let image_path = map_config
+        .image_filename
+        .filter(|f| !f.starts_with('$'))
+        .map(|f| join_paths(&mod_root, &f));
+
+    let terrain = terrain_from_i3d(files, i3d_path.as_deref(), &data_dir);
+    let map_size = match terrain.size {
+        Some(size) if size != map_config.size => {
+            log::info!(
+                "Terrain-Groesse aus Map-i3d ({} m) weicht von Map-Config ({} m) ab",
+                size,
+                map_config.size
+            );
+            size
+        }
+        _ => map_config.size,
+    };
+
     log::info!(
         "Map erkannt: '{}', {}x{}, data='{}'",
         title,
@@ -75,25 +147,88 @@ pub fn discover_map(files: &HashMap<String, Vec<u8>>) -> Result<MapInfo> {
 
     Ok(MapInfo {
         title,
+        author,
         map_size,
+        height_scale: terrain.height_scale,
+        terrain_offset: terrain.offset,
         config_path,
         data_dir,
         config_dir,
         placeables_path,
+        i3d_path,
+        farmlands_path,
+        image_path,
     })
 }
 
-/// Parst modDesc.xml: Titel, configFilename, placeablesFilename.
-fn parse_mod_desc(content: &[u8]) -> Result<(String, String, Option<String>)> {
+/// Aus der Map-i3d abgeleitete Terrain-Werte.
+struct TerrainValues {
+    /// Weltgroesse (`None` ohne i3d-Terrain oder lesbares DEM)
+    size: Option<u32>,
+    height_scale: f32,
+    offset: (f32, f32),
+}
+
+/// Liest das Terrain der Map-i3d und die Groesse der zugehoerigen Heightmap.
+///
+/// Die Heightmap kommt aus `heightMapId` der i3d, sonst aus dem data/-Verzeichnis.
+/// Fehlt die i3d in `files` (z.B. Basisspiel-Karte), gelten die Standardwerte.
+fn terrain_from_i3d(
+    files: &HashMap<String, Vec<u8>>,
+    i3d_path: Option<&str>,
+    data_dir: &str,
+) -> TerrainValues {
+    let Some((i3d_path, terrain)) = i3d_path.and_then(|path| {
+        let terrain = heightmap::parse_terrain_info(files.get(path)?)?;
+        Some((path, terrain))
+    }) else {
+        return TerrainValues {
+            size: None,
+            height_scale: DEFAULT_HEIGHT_SCALE,
+            offset: (0.0, 0.0),
+        };
+    };
+
+    let dem = terrain
+        .heightmap_filename
+        .as_ref()
+        .and_then(|filename| files.get(&join_paths(&parent_dir(i3d_path), filename)))
+        .map(|data| data.as_slice())
+        .or_else(|| find_dem(files, data_dir));
+    TerrainValues {
+        size: dem
+            .and_then(dds::map_raster_dimensions)
+            .map(|(width, height)| terrain.terrain_size(width.max(height))),
+        height_scale: terrain.height_scale,
+        offset: terrain.center_offset,
+    }
+}
+
+/// Ausgewertete Eintraege der modDesc.xml.
+struct ModDesc {
+    /// Englischer Titel (`<title><en>`)
+    title: String,
+    /// Inhalt von `<author>`
+    author: Option<String>,
+    /// `configFilename`-Attribut von `<map>`
+    config_filename: String,
+    /// `defaultPlaceablesXMLFilename`-Attribut von `<map>`
+    placeables_filename: Option<String>,
+}
+
+/// Parst modDesc.xml: Titel, Autor, configFilename, placeablesFilename.
+fn parse_mod_desc(content: &[u8]) -> Result<ModDesc> {
     let mut reader = Reader::from_reader(content);
     reader.config_mut().trim_text(true);
 
     let mut title = String::from("FS25 Map");
+    let mut author = None;
     let mut config_filename = String::new();
     let mut placeables_filename = None;
 
     let mut in_title = false;
     let mut in_title_en = false;
+    let mut in_author = false;
     let mut buf = Vec::new();
 
     loop {
@@ -102,6 +237,8 @@ fn parse_mod_desc(content: &[u8]) -> Result<(String, String, Option<String>)> {
                 let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if name == "title" {
                     in_title = true;
+                } else if name == "author" {
+                    in_author = true;
                 } else if in_title && name == "en" {
                     in_title_en = true;
                 } else if name == "map" {
@@ -134,10 +271,18 @@ fn parse_mod_desc(content: &[u8]) -> Result<(String, String, Option<String>)> {
             Ok(Event::Text(e)) if in_title_en => {
                 title = String::from_utf8_lossy(e.as_ref()).to_string();
             }
+            Ok(Event::Text(e)) if in_author => {
+                author = Some(String::from_utf8_lossy(e.as_ref()).trim().to_string());
+            }
+            Ok(Event::CData(e)) if in_author => {
+                author = Some(String::from_utf8_lossy(e.as_ref()).trim().to_string());
+            }
             Ok(Event::End(e)) => {
                 let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if name == "title" {
                     in_title = false;
+                } else if name == "author" {
+                    in_author = false;
                 } else if name == "en" {
                     in_title_en = false;
                 }
@@ -153,21 +298,45 @@ fn parse_mod_desc(content: &[u8]) -> Result<(String, String, Option<String>)> {
         bail!("Kein <map configFilename> in modDesc.xml gefunden");
     }
 
-    Ok((title, config_filename, placeables_filename))
+    Ok(ModDesc {
+        title,
+        author: author.filter(|author| !author.is_empty()),
+        config_filename,
+        placeables_filename,
+    })
 }
 
-/// Parst die Map-Config-XML fuer width/height.
-fn parse_map_config(content: &[u8]) -> Result<u32> {
+/// Parst die Map-Config-XML fuer width/height, Kartenbild sowie i3d- und farmlands-Dateinamen.
+fn parse_map_config(content: &[u8]) -> Result<MapConfig> {
     let mut reader = Reader::from_reader(content);
     reader.config_mut().trim_text(true);
     let mut buf = Vec::new();
 
+    let mut size = None;
+    let mut i3d_filename = None;
+    let mut farmlands_filename = None;
+    let mut image_filename = None;
+    let mut in_filename = false;
+
     loop {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(e) | Event::Empty(e)) => {
+                in_filename = e.name().as_ref() == b"filename";
+                if e.name().as_ref() == b"farmlands" {
+                    farmlands_filename = e
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"filename")
+                        .map(|attr| String::from_utf8_lossy(&attr.value).to_string());
+                }
+                if size.is_some() {
+                    buf.clear();
+                    continue;
+                }
                 // Das Root-Element hat width/height Attribute
                 let mut width = None;
                 let mut height = None;
+                let mut image = None;
                 for attr in e.attributes().flatten() {
                     let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
                     let val = String::from_utf8_lossy(&attr.value).to_string();
@@ -175,12 +344,22 @@ fn parse_map_config(content: &[u8]) -> Result<u32> {
                         width = val.parse::<u32>().ok();
                     } else if key == "height" {
                         height = val.parse::<u32>().ok();
+                    } else if key == "imageFilename" {
+                        image = Some(val);
                     }
                 }
                 if let (Some(w), Some(h)) = (width, height) {
-                    return Ok(w.max(h));
+                    size = Some(w.max(h));
+                    image_filename = image.filter(|f| !f.is_empty());
                 }
             }
+            Ok(Event::Text(e)) if in_filename && i3d_filename.is_none() => {
+                let value = String::from_utf8_lossy(e.as_ref()).trim().to_string();
+                if value.to_ascii_lowercase().ends_with(".i3d") {
+                    i3d_filename = Some(value);
+                }
+            }
+            Ok(Event::End(_)) => in_filename = false,
             Ok(Event::Eof) => break,
             Err(e) => bail!("Fehler beim Parsen der Map-Config-XML: {}", e),
             _ => {}
@@ -188,9 +367,17 @@ fn parse_map_config(content: &[u8]) -> Result<u32> {
         buf.clear();
     }
 
-    // Fallback: Standardgroesse
-    log::warn!("Keine width/height in Map-Config gefunden, verwende Standard 2048");
-    Ok(2048)
+    let size = size.unwrap_or_else(|| {
+        // Fallback: Standardgroesse
+        log::warn!("Keine width/height in Map-Config gefunden, verwende Standard 2048");
+        2048
+    });
+    Ok(MapConfig {
+        size,
+        i3d_filename,
+        farmlands_filename,
+        image_filename,
+    })
 }
 
 /// Sucht das data/-Verzeichnis anhand bekannter Dateien.
@@ -208,10 +395,10 @@ fn find_data_dir(files: &HashMap<String, Vec<u8>>, config_dir: &str, mod_root: &
         return candidate2;
     }
 
-    // Versuch 3: Suche nach Dateien wie dem.png oder *_weight.png
+    // Versuch 3: Suche nach Dateien wie dem.png oder *_weight.png (auch .dds/.gdm)
     for path in files.keys() {
         let lower = path.to_ascii_lowercase();
-        if (lower.ends_with("/dem.png") || lower.ends_with("_weight.png"))
+        if (is_dem_path(&lower) || is_weight_map_path(&lower))
             && let Some(dir) = Path::new(path).parent()
         {
             return dir.to_string_lossy().to_string();
@@ -222,29 +409,46 @@ fn find_data_dir(files: &HashMap<String, Vec<u8>>, config_dir: &str, mod_root: &
     format!("{}/data", mod_root.trim_end_matches('/'))
 }
 
-/// Findet eine Datei im ZIP (case-insensitive fuer den Basename).
+/// Findet alle Dateien im ZIP mit diesem Basename (case-insensitive).
 ///
-/// Bei mehreren Treffern wird der kuerzeste Pfad bevorzugt (naechste an Root-Ebene),
-/// da z.B. `placeables/productions/modDesc.xml` nicht die Haupt-modDesc.xml ist.
-fn find_file<'a>(
+/// Sortiert nach Pfadlaenge (naechste an Root-Ebene zuerst), da z.B.
+/// `placeables/productions/modDesc.xml` nicht die Haupt-modDesc.xml ist.
+fn find_files<'a>(
     files: &'a HashMap<String, Vec<u8>>,
     target_basename: &str,
-) -> Option<(String, &'a [u8])> {
+) -> Vec<(&'a str, &'a [u8])> {
     let target_lower = target_basename.to_ascii_lowercase();
-    let mut best: Option<(String, &'a [u8])> = None;
-    for (path, content) in files {
-        if let Some(name) = Path::new(path).file_name()
-            && name.to_string_lossy().to_ascii_lowercase() == target_lower
-        {
-            let dominated = best
-                .as_ref()
-                .is_some_and(|(prev, _)| path.len() < prev.len());
-            if best.is_none() || dominated {
-                best = Some((path.clone(), content.as_slice()));
+    let mut found: Vec<(&str, &[u8])> = files
+        .iter()
+        .filter(|(path, _)| {
+            Path::new(path.as_str())
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().to_ascii_lowercase() == target_lower)
+        })
+        .map(|(path, content)| (path.as_str(), content.as_slice()))
+        .collect();
+    found.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    found
+}
+
+/// Sucht die flachste `modDesc.xml`, die eine Karte deklariert.
+///
+/// Mod-Packs enthalten neben der Karte oft weitere Mods (oder eine eigene
+/// `modDesc.xml` ohne `<map>`); diese werden uebersprungen. Deklariert keine
+/// Kandidatin eine Karte, wird der Fehler der flachsten gemeldet.
+fn find_map_mod_desc(files: &HashMap<String, Vec<u8>>) -> Result<(String, ModDesc)> {
+    let mut first_error = None;
+    for (path, content) in find_files(files, "modDesc.xml") {
+        match parse_mod_desc(content) {
+            Ok(parsed) => return Ok((path.to_string(), parsed)),
+            Err(e) => {
+                first_error.get_or_insert(e);
             }
         }
     }
-    best
+    Err(first_error.unwrap_or_else(|| {
+        anyhow!("modDesc.xml nicht im ZIP gefunden – ist das ein FS25-Map-Mod?")
+    }))
 }
 
 /// Ueberprueft ob Dateien mit einem bestimmten Verzeichnis-Prefix existieren.
@@ -275,7 +479,40 @@ fn join_paths(base: &str, relative: &str) -> String {
     }
 }
 
+/// Dateiendungen, in denen FS25-Karten Weight-Maps und DEM ablegen.
+///
+/// Die Reihenfolge ist die Praeferenz, falls eine Datei in mehreren
+/// Formaten vorliegt.
+pub(crate) const RASTER_EXTENSIONS: [&str; 3] = ["png", "dds", "gdm"];
+
+/// Prueft, ob ein (kleingeschriebener) Pfad eine Weight-Map ist.
+pub(crate) fn is_weight_map_path(lower: &str) -> bool {
+    weight_map_stem(lower) != lower
+}
+
+/// Prueft, ob ein (kleingeschriebener) Pfad ein DEM (`dem.png`/`.dds`/`.gdm`) ist.
+pub(crate) fn is_dem_path(lower: &str) -> bool {
+    RASTER_EXTENSIONS
+        .iter()
+        .any(|ext| lower.ends_with(&format!("/dem.{}", ext)))
+}
+
+/// Entfernt das Suffix `_weight.<ext>` eines Weight-Map-Namens.
+///
+/// Gibt den Namen unveraendert zurueck, wenn er keine Weight-Map ist.
+pub(crate) fn weight_map_stem(name: &str) -> &str {
+    let lower = name.to_ascii_lowercase();
+    RASTER_EXTENSIONS
+        .iter()
+        .find_map(|ext| lower.strip_suffix(&format!("_weight.{}", ext)))
+        // to_ascii_lowercase erhaelt die Bytelaenge
+        .map_or(name, |stem| &name[..stem.len()])
+}
+
 /// Listet alle Weight-Map-Dateien im data/-Verzeichnis.
+///
+/// Neben PNG werden `.dds` und `.gdm` erkannt. Liegt dieselbe Weight-Map in
+/// mehreren Formaten vor, gewinnt die erste Endung aus [`RASTER_EXTENSIONS`].
 pub fn find_weight_maps<'a>(
     files: &'a HashMap<String, Vec<u8>>,
     data_dir: &str,
@@ -286,20 +523,50 @@ pub fn find_weight_maps<'a>(
         format!("{}/", data_dir)
     };
 
-    let mut weight_maps: Vec<(&str, &[u8])> = files
-        .iter()
-        .filter(|(path, _)| path.starts_with(&prefix) && path.ends_with("_weight.png"))
-        .map(|(path, content)| (path.as_str(), content.as_slice()))
-        .collect();
+    let mut by_stem: HashMap<String, (usize, &str, &[u8])> = HashMap::new();
+    for (path, content) in files {
+        if !path.starts_with(&prefix) || !is_weight_map_path(&path.to_ascii_lowercase()) {
+            continue;
+        }
+        let rank = extension_rank(path);
+        let stem = weight_map_stem(path).to_ascii_lowercase();
+        match by_stem.get(&stem) {
+            Some((existing_rank, _, _)) if *existing_rank <= rank => {}
+            _ => {
+                by_stem.insert(stem, (rank, path.as_str(), content.as_slice()));
+            }
+        }
+    }
 
+    let mut weight_maps: Vec<(&str, &[u8])> = by_stem
+        .into_values()
+        .map(|(_, path, content)| (path, content))
+        .collect();
     weight_maps.sort_by_key(|(path, _)| *path);
     weight_maps
 }
 
+/// Position der Dateiendung in [`RASTER_EXTENSIONS`] (unbekannt → hinten).
+fn extension_rank(path: &str) -> usize {
+    let extension = Path::new(path)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+    RASTER_EXTENSIONS
+        .iter()
+        .position(|ext| extension.as_deref() == Some(*ext))
+        .unwrap_or(RASTER_EXTENSIONS.len())
+}
+
 /// Findet das DEM (Digital Elevation Model) im data/-Verzeichnis.
+///
+/// Sucht `dem.png`, dann `dem.dds` und `dem.gdm`.
 pub fn find_dem<'a>(files: &'a HashMap<String, Vec<u8>>, data_dir: &str) -> Option<&'a [u8]> {
-    let path = format!("{}/dem.png", data_dir.trim_end_matches('/'));
-    files.get(&path).map(|v| v.as_slice())
+    let prefix = data_dir.trim_end_matches('/');
+    RASTER_EXTENSIONS.iter().find_map(|ext| {
+        files
+            .get(&format!("{}/dem.{}", prefix, ext))
+            .map(|v| v.as_slice())
+    })
 }
 
 /// Findet die Farmlands-InfoLayer-Datei.
@@ -323,6 +590,69 @@ pub fn find_farmlands<'a>(
     None
 }
 
+/// Moegliche Pfade des mitgelieferten Kartenbilds in Suchreihenfolge.
+///
+/// Zuerst `MapInfo::image_path`, danach `overview.dds`/`overview.png` im
+/// Kartenordner (Elternordner von `data/`) fuer Map-Configs ohne `imageFilename`.
+pub(crate) fn map_image_candidates(map_info: &MapInfo) -> Vec<String> {
+    let map_dir = parent_dir(&map_info.data_dir);
+    let mut candidates: Vec<String> = map_info.image_path.iter().cloned().collect();
+    for name in ["overview.dds", "overview.png"] {
+        let candidate = join_paths(&map_dir, name);
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// Findet das mitgelieferte Kartenbild (PDA-/Overview-Karte) des Map-Mods.
+pub fn find_map_image<'a>(
+    files: &'a HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+) -> Option<(&'a str, &'a [u8])> {
+    map_image_candidates(map_info).into_iter().find_map(|key| {
+        files
+            .get_key_value(key.as_str())
+            .map(|(k, v)| (k.as_str(), v.as_slice()))
+    })
+}
+
+/// Dateinamen (ohne Endung) der Placement-Collision-InfoLayer.
+const PLACEMENT_COLLISION_STEMS: [&str; 2] = [
+    "infolayer_placementcollision",
+    "infolayer_placementcollisiongenerated",
+];
+
+/// Prueft, ob ein (kleingeschriebener) Pfad ein Placement-Collision-InfoLayer
+/// (`.grle` oder `.png`) ist.
+pub(crate) fn is_placement_collision_path(lower: &str) -> bool {
+    PLACEMENT_COLLISION_STEMS.iter().any(|stem| {
+        lower.ends_with(&format!("/{}.grle", stem)) || lower.ends_with(&format!("/{}.png", stem))
+    })
+}
+
+/// Findet alle Placement-Collision-InfoLayer im data/-Verzeichnis.
+///
+/// Sucht `infoLayer_placementCollision` und `infoLayer_placementCollisionGenerated`
+/// jeweils als `.grle` oder `.png` (Gross-/Kleinschreibung egal).
+pub fn find_placement_collision<'a>(
+    files: &'a HashMap<String, Vec<u8>>,
+    data_dir: &str,
+) -> Vec<(&'a str, &'a [u8])> {
+    let prefix = format!("{}/", data_dir.trim_end_matches('/')).to_ascii_lowercase();
+    let mut layers: Vec<(&str, &[u8])> = files
+        .iter()
+        .filter(|(path, _)| {
+            let lower = path.to_ascii_lowercase();
+            lower.starts_with(&prefix) && is_placement_collision_path(&lower)
+        })
+        .map(|(path, data)| (path.as_str(), data.as_slice()))
+        .collect();
+    layers.sort_by_key(|(path, _)| *path);
+    layers
+}
+
 /// Findet `densityMap_ground.gdm` im data/-Verzeichnis.
 pub fn find_ground_gdm<'a>(
     files: &'a HashMap<String, Vec<u8>>,
@@ -350,6 +680,42 @@ mod tests {
         assert_eq!(join_paths("", "modDesc.xml"), "modDesc.xml");
     }
 
+    #[test]
+    fn test_parse_mod_desc_reads_title_author_and_map_attributes() {
+        let xml = br#"<modDesc><author>  Hof Bergmann Team </author>
+            <title><en>Hof Bergmann</en><de>Hof Bergmann</de></title>
+            <maps><map configFilename="maps/map.xml" defaultPlaceablesXMLFilename="maps/placeables.xml"/></maps>
+        </modDesc>"#;
+
+        let mod_desc = parse_mod_desc(xml).expect("modDesc.xml muss parsen");
+
+        assert_eq!(mod_desc.title, "Hof Bergmann");
+        assert_eq!(mod_desc.author.as_deref(), Some("Hof Bergmann Team"));
+        assert_eq!(mod_desc.config_filename, "maps/map.xml");
+        assert_eq!(
+            mod_desc.placeables_filename.as_deref(),
+            Some("maps/placeables.xml")
+        );
+
+        let without_author =
+            parse_mod_desc(br#"<modDesc><author></author><map configFilename="m.xml"/></modDesc>"#)
+                .expect("modDesc.xml muss parsen");
+        assert_eq!(without_author.author, None);
+    }
+
+    #[test]
+    fn test_parse_map_config_reads_size_and_filenames() {
+        let xml = br#"<map width="4096" height="4096" imageFilename="maps/overview.dds"><filename>maps/map.i3d</filename><farmlands filename="maps/config/farmlands.xml" /></map>"#;
+        let config = parse_map_config(xml).expect("Config muss parsen");
+        assert_eq!(config.size, 4096);
+        assert_eq!(config.image_filename.as_deref(), Some("maps/overview.dds"));
+        assert_eq!(config.i3d_filename.as_deref(), Some("maps/map.i3d"));
+        assert_eq!(
+            config.farmlands_filename.as_deref(),
+            Some("maps/config/farmlands.xml")
+        );
+    }
+
     #[test]
     fn test_find_ground_gdm_uses_data_dir() {
         let mut files = HashMap::new();
@@ -368,4 +734,139 @@ mod tests {
         assert_eq!(found.0, "MyMap/maps/data/densityMap_ground.gdm");
         assert_eq!(found.1, [1, 2, 3]);
     }
+
+    #[test]
+    fn test_find_weight_maps_accepts_dds_and_gdm_and_prefers_png() {
+        let mut files = HashMap::new();
+        files.insert(String::from("Map/data/grass_weight.png"), vec![1]);
+        files.insert(String::from("Map/data/grass_weight.dds"), vec![2]);
+        files.insert(String::from("Map/data/gravel_weight.dds"), vec![3]);
+        files.insert(String::from("Map/data/mud_weight.gdm"), vec![4]);
+        files.insert(String::from("Map/data/dem.dds"), vec![5]);
+
+        let weight_maps = find_weight_maps(&files, "Map/data");
+        assert_eq!(
+            weight_maps,
+            vec![
+                ("Map/data/grass_weight.png", &[1u8][..]),
+                ("Map/data/gravel_weight.dds", &[3u8][..]),
+                ("Map/data/mud_weight.gdm", &[4u8][..]),
+            ]
+        );
+        assert_eq!(find_dem(&files, "Map/data"), Some(&[5u8][..]));
+    }
+
+    #[test]
+    fn test_weight_map_stem_strips_known_extensions() {
+        assert_eq!(weight_map_stem("grass_weight.png"), "grass");
+        assert_eq!(weight_map_stem("Grass_Weight.DDS"), "Grass");
+        assert_eq!(weight_map_stem("mud_weight.gdm"), "mud");
+        assert_eq!(
+            weight_map_stem("infoLayer_farmlands.png"),
+            "infoLayer_farmlands.png"
+        );
+    }
+
+    #[test]
+    fn test_find_placement_collision_lists_base_and_generated_layers() {
+        let mut files = HashMap::new();
+        files.insert(
+            String::from("Map/data/infoLayer_placementCollisionGenerated.grle"),
+            vec![2],
+        );
+        files.insert(
+            String::from("Map/data/infoLayer_placementCollision.grle"),
+            vec![1],
+        );
+        files.insert(
+            String::from("Map/data/infoLayer_tipCollision.grle"),
+            vec![3],
+        );
+        files.insert(
+            String::from("Other/infoLayer_placementCollision.grle"),
+            vec![4],
+        );
+
+        let layers = find_placement_collision(&files, "Map/data");
+        assert_eq!(
+            layers,
+            vec![
+                ("Map/data/infoLayer_placementCollision.grle", &[1u8][..]),
+                (
+                    "Map/data/infoLayer_placementCollisionGenerated.grle",
+                    &[2u8][..]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_map_image_prefers_config_path_then_overview_in_map_dir() {
+        let mut map_info = discover_map(&HashMap::from([
+            (
+                String::from("modDesc.xml"),
+                br#"<modDesc><map configFilename="maps/map.xml"/></modDesc>"#.to_vec(),
+            ),
+            (
+                String::from("maps/map.xml"),
+                br#"<map width="2048" height="2048" imageFilename="maps/pda_map.dds"/>"#.to_vec(),
+            ),
+            (String::from("maps/data/dem.png"), vec![0]),
+        ]))
+        .expect("Discovery erwartet");
+        assert_eq!(map_info.image_path.as_deref(), Some("maps/pda_map.dds"));
+
+        let mut files = HashMap::new();
+        files.insert(String::from("maps/overview.dds"), vec![1]);
+        assert_eq!(
+            find_map_image(&files, &map_info),
+            Some(("maps/overview.dds", &[1u8][..]))
+        );
+        files.insert(String::from("maps/pda_map.dds"), vec![2]);
+        assert_eq!(
+            find_map_image(&files, &map_info),
+            Some(("maps/pda_map.dds", &[2u8][..]))
+        );
+
+        map_info.image_path = Some(String::from("$data/maps/overview.dds"));
+        map_info.data_dir = String::from("other/data");
+        assert_eq!(find_map_image(&files, &map_info), None);
+    }
+
+    #[test]
+    fn test_discover_map_takes_terrain_size_scale_and_offset_from_i3d() {
+        let mut dem = Vec::new();
+        image::DynamicImage::ImageLuma8(image::GrayImage::new(9, 9))
+            .write_to(&mut std::io::Cursor::new(&mut dem), image::ImageFormat::Png)
+            .expect("DEM-PNG muss kodiert werden");
+        let mut files = HashMap::from([
+            (
+                String::from("modDesc.xml"),
+                br#"<modDesc><map configFilename="maps/map.xml"/></modDesc>"#.to_vec(),
+            ),
+            (
+                String::from("maps/map.xml"),
+                br#"<map width="64" height="64"><filename>maps/map.i3d</filename></map>"#.to_vec(),
+            ),
+            (String::from("maps/data/map_dem.png"), dem),
+        ]);
+
+        // Ohne i3d: Groesse aus der Map-Config, Standard-Hoehenskala
+        let map_info = discover_map(&files).expect("Discovery erwartet");
+        assert_eq!(map_info.map_size, 64);
+        assert_eq!(map_info.height_scale, DEFAULT_HEIGHT_SCALE);
+        assert_eq!(map_info.terrain_offset, (0.0, 0.0));
+
+        files.insert(
+            String::from("maps/map.i3d"),
+            br#"<i3D><Files><File fileId="3" filename="data/map_dem.png"/></Files><Scene>
+                <TerrainTransformGroup heightScale="300" unitsPerPixel="4" heightMapId="3" translation="8 0 -8"/>
+            </Scene></i3D>"#
+                .to_vec(),
+        );
+        let map_info = discover_map(&files).expect("Discovery erwartet");
+        assert_eq!(map_info.map_size, 32);
+        assert_eq!(map_info.height_scale, 300.0);
+        assert_eq!(map_info.terrain_offset, (8.0, -8.0));
+    }
 }
@@ -0,0 +1,41 @@
+//! Einfache Raster-Primitive (Linien, Scheiben) fuer die Vektor-Overlays.
+
+use image::{Rgb, RgbImage};
+
+/// Zeichnet eine Linie mit gegebener Breite (in Pixeln) als Folge von Scheiben.
+///
+/// Endpunkte liegen in Pixel-Koordinaten (Subpixel erlaubt); Teile ausserhalb
+/// des Bildes werden abgeschnitten.
+pub fn draw_thick_line(
+    image: &mut RgbImage,
+    a: (f32, f32),
+    b: (f32, f32),
+    width: f32,
+    color: Rgb<u8>,
+) {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let steps = dx.abs().max(dy.abs()).ceil().max(1.0) as i32;
+    let radius = ((width - 1.0) / 2.0).round() as i32;
+    for step in 0..=steps {
+        let t = step as f32 / steps as f32;
+        let x = (a.0 + dx * t).round() as i32;
+        let y = (a.1 + dy * t).round() as i32;
+        draw_disc(image, x, y, radius, color);
+    }
+}
+
+/// Zeichnet eine gefuellte Scheibe; Radius 0 setzt genau ein Pixel.
+pub fn draw_disc(image: &mut RgbImage, cx: i32, cy: i32, radius: i32, color: Rgb<u8>) {
+    let w = image.width() as i32;
+    let h = image.height() as i32;
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy <= radius * radius {
+                let (x, y) = (cx + dx, cy + dy);
+                if x >= 0 && x < w && y >= 0 && y < h {
+                    image.put_pixel(x as u32, y as u32, color);
+                }
+            }
+        }
+    }
+}
@@ -140,9 +140,20 @@ pub fn extract_field_polygons_by_ccl(
     width: usize,
     height: usize,
 ) -> Vec<FarmlandPolygon> {
+    extract_field_polygons_with_pixel_counts(pixels, width, height).0
+}
+
+/// Wie [`extract_field_polygons_by_ccl`], liefert zusaetzlich die Pixelanzahl
+/// jeder Komponente (Schluessel = Polygon-ID) fuer Flaechenberechnungen.
+pub(crate) fn extract_field_polygons_with_pixel_counts(
+    pixels: &[u8],
+    width: usize,
+    height: usize,
+) -> (Vec<FarmlandPolygon>, HashMap<u32, u64>) {
     // Phase 1: Flood-Fill CCL – 4-Konnektivitaet
     let mut labels = vec![0u32; width * height];
     let mut label_starts: Vec<(i32, i32)> = Vec::new();
+    let mut label_pixels: Vec<u64> = Vec::new();
     let mut next_label = 1u32;
 
     for y in 0..height {
@@ -153,6 +164,7 @@ pub fn extract_field_polygons_by_ccl(
                 next_label += 1;
                 // Start-Pixel in Scan-Reihenfolge (top-left der Komponente)
                 label_starts.push((x as i32, y as i32));
+                label_pixels.push(0);
 
                 // Iterativer Flood-Fill mit Stack (4-Konnektivitaet)
                 let mut stack = vec![(x, y)];
@@ -162,6 +174,7 @@ pub fn extract_field_polygons_by_ccl(
                         continue;
                     }
                     labels[cidx] = label;
+                    label_pixels[(label - 1) as usize] += 1;
 
                     if cx > 0 {
                         let ni = cy * width + (cx - 1);
@@ -194,6 +207,7 @@ pub fn extract_field_polygons_by_ccl(
 
     // Phase 2: Pro Label -> Moore-Tracing auf Label-Array
     let mut polygons = Vec::with_capacity(label_starts.len());
+    let mut pixel_counts = HashMap::with_capacity(label_starts.len());
 
     for (i, &start) in label_starts.iter().enumerate() {
         let label = (i + 1) as u32;
@@ -205,6 +219,7 @@ pub fn extract_field_polygons_by_ccl(
                 id: label,
                 vertices,
             });
+            pixel_counts.insert(label, label_pixels[i]);
         }
     }
 
@@ -215,7 +230,7 @@ pub fn extract_field_polygons_by_ccl(
         height
     );
 
-    polygons
+    (polygons, pixel_counts)
 }
 
 // ---------------------------------------------------------------------------
@@ -0,0 +1,334 @@
+//! Farmland-Flaeche, -Preise und -Besitz: Zusatzbeschriftung unter den Farmland-IDs.
+//!
+//! Preise stammen aus der `farmlands.xml` der Map (`pricePerHa` ×
+//! `priceScale` × Flaeche), die Besitzverhaeltnisse optional aus der
+//! `farmland.xml` eines Savegames.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use image::{Rgb, RgbImage};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::composite::{self, FarmlandCentroid, FarmlandData};
+use crate::fields::{self, AREA_LABEL_COLOR};
+use crate::text;
+
+/// Textfarbe der Preisangabe (Hellgruen).
+const PRICE_LABEL_COLOR: [u8; 3] = [200, 255, 200];
+/// Textfarbe der Besitzerangabe (Hellorange).
+const OWNER_LABEL_COLOR: [u8; 3] = [255, 210, 160];
+/// Toenung verkaufter Farmlands (Hellblau).
+pub(crate) const OWNED_TINT_COLOR: [u8; 3] = [90, 170, 255];
+/// Deckkraft der Toenung verkaufter Farmlands.
+pub(crate) const OWNED_TINT_ALPHA: f32 = 0.3;
+
+/// Standardpreis pro Hektar, falls die farmlands.xml keinen angibt.
+const DEFAULT_PRICE_PER_HA: f32 = 60_000.0;
+
+/// Preisangaben aus der `farmlands.xml` einer Map.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FarmlandPrices {
+    /// Grundpreis pro Hektar
+    pub price_per_ha: f32,
+    /// Preisfaktor pro Farmland-ID (fehlend = 1.0)
+    pub price_scales: HashMap<u32, f32>,
+}
+
+impl FarmlandPrices {
+    /// Parst `pricePerHa` und die `priceScale`-Werte aus einer `farmlands.xml`.
+    pub fn from_farmlands_xml(data: &[u8]) -> Result<Self> {
+        let mut prices = Self {
+            price_per_ha: DEFAULT_PRICE_PER_HA,
+            price_scales: HashMap::new(),
+        };
+
+        for_each_element(data, |element| match element.name().as_ref() {
+            b"farmlands" => {
+                if let Some(value) = attr(element, "pricePerHa").and_then(|v| v.parse().ok()) {
+                    prices.price_per_ha = value;
+                }
+            }
+            b"farmland" => {
+                if let Some(id) = attr(element, "id").and_then(|v| v.parse().ok()) {
+                    let scale = attr(element, "priceScale")
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(1.0);
+                    prices.price_scales.insert(id, scale);
+                }
+            }
+            _ => {}
+        })
+        .context("Ungueltige farmlands.xml")?;
+
+        Ok(prices)
+    }
+
+    /// Kaufpreis eines Farmlands mit der gegebenen Flaeche in Quadratmetern.
+    pub fn price_for(&self, id: u32, area_m2: f64) -> f64 {
+        let scale = self.price_scales.get(&id).copied().unwrap_or(1.0);
+        area_m2 / 10_000.0 * self.price_per_ha as f64 * scale as f64
+    }
+}
+
+/// Besitzverhaeltnisse aus einem Savegame (`farmland.xml`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FarmlandOwnership {
+    /// Farm-ID pro Farmland-ID (0 = unverkauft, nicht enthalten)
+    pub owners: HashMap<u32, u32>,
+}
+
+impl FarmlandOwnership {
+    /// Parst die `<farmland id=".." farmId=".."/>`-Eintraege einer Savegame-`farmland.xml`.
+    pub fn from_savegame_xml(data: &[u8]) -> Result<Self> {
+        let mut owners = HashMap::new();
+        for_each_element(data, |element| {
+            if element.name().as_ref() != b"farmland" {
+                return;
+            }
+            let id = attr(element, "id").and_then(|v| v.parse::<u32>().ok());
+            let farm_id = attr(element, "farmId").and_then(|v| v.parse::<u32>().ok());
+            if let (Some(id), Some(farm_id)) = (id, farm_id)
+                && farm_id != 0
+            {
+                owners.insert(id, farm_id);
+            }
+        })
+        .context("Ungueltige Savegame-farmland.xml")?;
+        Ok(Self { owners })
+    }
+
+    /// Liest die Besitzverhaeltnisse aus einer Savegame-Datei.
+    pub fn from_savegame_xml_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .with_context(|| format!("Savegame-farmland.xml nicht lesbar: {}", path.display()))?;
+        Self::from_savegame_xml(&data)
+    }
+}
+
+/// Toent alle Pixel verkaufter Farmlands (Eintrag in `ownership`) halbtransparent.
+///
+/// Macht die eigenen Flaechen eines Savegames auf einen Blick erkennbar; wird
+/// vor den Farmland-Grenzen gezeichnet, damit diese sichtbar bleiben.
+pub fn draw_owned_farmlands(
+    image: &mut RgbImage,
+    farmlands: &FarmlandData,
+    ownership: &FarmlandOwnership,
+) {
+    if ownership.owners.is_empty() {
+        return;
+    }
+    let width = image.width().min(farmlands.ids.width());
+    let height = image.height().min(farmlands.ids.height());
+    for y in 0..height {
+        for x in 0..width {
+            let id = farmlands.ids.get_pixel(x, y)[0] as u32;
+            if id == 0 || !ownership.owners.contains_key(&id) {
+                continue;
+            }
+            let pixel = image.get_pixel_mut(x, y);
+            for (channel, tint) in pixel.0.iter_mut().zip(OWNED_TINT_COLOR) {
+                *channel = composite::blend_channel(*channel, tint, OWNED_TINT_ALPHA);
+            }
+        }
+    }
+}
+
+/// Zeichnet Flaeche, Preis und/oder Besitzer unterhalb der Farmland-ID-Labels.
+///
+/// Die Positionen folgen der kollisionsfreien Platzierung der IDs aus
+/// `draw_farmland_ids`; unbeschriftete Farmlands erhalten auch keine Details.
+///
+/// `map_size` ist die Weltgroesse in Metern und dient der Flaechenberechnung;
+/// `show_area` schreibt die Flaeche in Hektar als erste Zeile.
+pub fn draw_farmland_details(
+    image: &mut RgbImage,
+    farmlands: &FarmlandData,
+    map_size: u32,
+    show_area: bool,
+    prices: Option<&FarmlandPrices>,
+    ownership: Option<&FarmlandOwnership>,
+) {
+    if !show_area && prices.is_none() && ownership.is_none() || farmlands.width == 0 {
+        return;
+    }
+
+    let id_scale = composite::label_scale(image.width());
+    let scale = composite::detail_scale(image.width());
+    let line_height = text::text_height(scale) as i32;
+    let meters_per_pixel = map_size as f64 / farmlands.width as f64;
+    let line_count = detail_line_count(show_area, prices, ownership);
+
+    let (width, height) = image.dimensions();
+    for label in composite::place_farmland_labels(farmlands, width, height, line_count) {
+        let mut y = label.details_y(id_scale);
+        for (line, color) in detail_lines(
+            &label.centroid,
+            meters_per_pixel,
+            show_area,
+            prices,
+            ownership,
+        ) {
+            let tw = text::text_width(&line, scale) as i32;
+            text::draw_text_outlined(
+                image,
+                label.center_x() - tw / 2,
+                y,
+                &line,
+                Rgb(color),
+                scale,
+            );
+            y += line_height + 1;
+        }
+    }
+}
+
+/// Hoechstzahl der Detailzeilen pro Farmland; reserviert Platz bei der Label-Platzierung.
+pub(crate) fn detail_line_count(
+    show_area: bool,
+    prices: Option<&FarmlandPrices>,
+    ownership: Option<&FarmlandOwnership>,
+) -> usize {
+    show_area as usize + prices.is_some() as usize + ownership.is_some() as usize
+}
+
+/// Beschriftungszeilen (Text, Farbe) eines Farmlands: Flaeche, Preis, dann Besitzer.
+pub(crate) fn detail_lines(
+    centroid: &FarmlandCentroid,
+    meters_per_pixel: f64,
+    show_area: bool,
+    prices: Option<&FarmlandPrices>,
+    ownership: Option<&FarmlandOwnership>,
+) -> Vec<(String, [u8; 3])> {
+    let id = centroid.id as u32;
+    let mut lines = Vec::new();
+    if show_area {
+        let hectares = fields::pixels_to_hectares(centroid.pixel_count, meters_per_pixel);
+        lines.push((fields::format_hectares(hectares), AREA_LABEL_COLOR));
+    }
+    if let Some(prices) = prices {
+        let area_m2 = centroid.pixel_count as f64 * meters_per_pixel * meters_per_pixel;
+        lines.push((
+            format_price(prices.price_for(id, area_m2)),
+            PRICE_LABEL_COLOR,
+        ));
+    }
+    if let Some(farm_id) = ownership.and_then(|ownership| ownership.owners.get(&id)) {
+        lines.push((format!("Hof {}", farm_id), OWNER_LABEL_COLOR));
+    }
+    lines
+}
+
+/// Formatiert einen Preis mit Tausenderpunkten, z.B. `84.000 €`.
+fn format_price(price: f64) -> String {
+    let digits = (price.max(0.0).round() as u64).to_string();
+    let mut grouped = String::new();
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push('.');
+        }
+        grouped.push(ch);
+    }
+    format!("{} €", grouped)
+}
+
+/// Ruft `visit` fuer jedes Start-/Empty-Element der XML auf.
+fn for_each_element(data: &[u8], mut visit: impl FnMut(&BytesStart)) -> Result<()> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e) | Event::Empty(e)) => visit(&e),
+            Ok(Event::Eof) => return Ok(()),
+            Err(e) => bail!("XML-Fehler: {}", e),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+fn attr(element: &BytesStart, key: &str) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key.as_bytes())
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn farmland_prices_apply_price_scale_per_hectare() {
+        let xml = br#"<map><farmlands infoLayer="farmlands" pricePerHa="50000">
+            <farmland id="1" priceScale="1.5" npcName="A" />
+            <farmland id="2" />
+        </farmlands></map>"#;
+
+        let prices = FarmlandPrices::from_farmlands_xml(xml).expect("farmlands.xml muss parsen");
+
+        assert_eq!(prices.price_for(1, 20_000.0), 150_000.0);
+        assert_eq!(prices.price_for(2, 10_000.0), 50_000.0);
+    }
+
+    #[test]
+    fn farmland_ownership_skips_unowned_farmlands() {
+        let xml = br#"<farmlands><farmland id="1" farmId="1" /><farmland id="2" farmId="0" /></farmlands>"#;
+
+        let ownership = FarmlandOwnership::from_savegame_xml(xml).expect("Savegame muss parsen");
+
+        assert_eq!(ownership.owners.get(&1), Some(&1));
+        assert!(!ownership.owners.contains_key(&2));
+    }
+
+    #[test]
+    fn owned_farmlands_are_tinted_and_others_untouched() {
+        let ids = image::GrayImage::from_raw(2, 1, vec![1, 2]).unwrap();
+        let farmlands = FarmlandData {
+            boundaries: vec![false; 2],
+            ids,
+            width: 2,
+            height: 1,
+        };
+        let ownership = FarmlandOwnership {
+            owners: HashMap::from([(1, 1)]),
+        };
+        let mut image = RgbImage::from_pixel(2, 1, Rgb([0, 0, 0]));
+
+        draw_owned_farmlands(&mut image, &farmlands, &ownership);
+
+        assert_ne!(image.get_pixel(0, 0), &Rgb([0, 0, 0]));
+        assert_eq!(image.get_pixel(1, 0), &Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn format_price_groups_thousands() {
+        assert_eq!(format_price(84_000.4), "84.000 €");
+        assert_eq!(format_price(1_234_567.0), "1.234.567 €");
+        assert_eq!(format_price(950.0), "950 €");
+    }
+
+    #[test]
+    fn detail_lines_start_with_area_in_hectares() {
+        let centroid = FarmlandCentroid {
+            id: 3,
+            x: 0,
+            y: 0,
+            pixel_count: 5_000,
+        };
+        let ownership = FarmlandOwnership {
+            owners: HashMap::from([(3, 1)]),
+        };
+
+        let lines = detail_lines(&centroid, 2.0, true, None, Some(&ownership));
+
+        assert_eq!(lines[0], ("2,00 ha".to_string(), AREA_LABEL_COLOR));
+        assert_eq!(lines[1].0, "Hof 1");
+        assert!(detail_lines(&centroid, 2.0, false, None, None).is_empty());
+    }
+}
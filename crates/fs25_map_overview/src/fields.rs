@@ -0,0 +1,332 @@
+//! Feldgrenzen-Layer: Feldumrisse und Feldnummern aus `densityMap_ground.gdm`.
+//!
+//! Ergaenzt den Farmland-Layer (Besitzgrenzen) um die tatsaechlich
+//! bewirtschafteten Feldflaechen. Felder werden per Connected Component
+//! Labeling erkannt; die angezeigte Feldnummer ist die Farmland-ID aus
+//! `infoLayer_farmlands` unter dem Flaechenschwerpunkt (wie im Spiel). Die
+//! CCL-Labels selbst sind nur interne Indizes und werden nie beschriftet.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use image::{GrayImage, Rgb, RgbImage};
+
+use crate::draw::draw_thick_line;
+use crate::farmland::{self, FarmlandPolygon};
+use crate::{gdm, text};
+
+/// Linienfarbe der Feldgrenzen (Hellblau, hebt sich vom Farmland-Gelb ab).
+pub(crate) const FIELD_BOUNDARY_COLOR: [u8; 3] = [120, 220, 255];
+
+/// Felder mit weniger Rand-Vertices erhalten keine Nummer.
+pub(crate) const MIN_LABEL_VERTICES: usize = 24;
+
+/// Schriftfarbe der Flaechenangaben in Hektar.
+pub(crate) const AREA_LABEL_COLOR: [u8; 3] = [235, 235, 235];
+
+/// Erkannte Felder im Raster der Ground-Density-Map.
+pub struct FieldData {
+    /// Feld-Umrisse im Pixelraum des GDM-Rasters (ID = internes CCL-Label)
+    pub polygons: Vec<FarmlandPolygon>,
+    /// Kantenlaenge des quadratischen GDM-Rasters in Pixeln
+    pub raster_size: u32,
+    /// Pixelanzahl jedes Feldes im GDM-Raster (Schluessel = CCL-Label)
+    pub pixel_counts: HashMap<u32, u64>,
+    /// CCL-Label → Feldnummer (Farmland-ID unter dem Flaechenschwerpunkt)
+    pub field_numbers: HashMap<u32, u32>,
+}
+
+impl FieldData {
+    /// Flaeche eines Feldes in Hektar bei einer Kartengroesse von `map_size` Metern.
+    ///
+    /// Das GDM-Raster deckt die ganze Karte ab; ein Pixel entspricht daher
+    /// `(map_size / raster_size)²` Quadratmetern.
+    pub fn area_hectares(&self, id: u32, map_size: u32) -> Option<f64> {
+        if self.raster_size == 0 {
+            return None;
+        }
+        let meters_per_pixel = map_size as f64 / self.raster_size as f64;
+        self.pixel_counts
+            .get(&id)
+            .map(|&count| pixels_to_hectares(count, meters_per_pixel))
+    }
+
+    /// Leitet die Feldnummern aus dem Farmland-ID-Raster ab.
+    ///
+    /// Jedes Feld erhaelt die Farmland-ID des Pixels unter seinem
+    /// Flaechenschwerpunkt; das Raster darf eine andere Aufloesung als das
+    /// GDM-Raster haben. Felder ueber Farmland-ID 0 bleiben ohne Nummer.
+    pub fn assign_farmland_numbers(&mut self, farmland_ids: &GrayImage) {
+        self.field_numbers.clear();
+        if self.raster_size == 0 || farmland_ids.width() == 0 || farmland_ids.height() == 0 {
+            return;
+        }
+        let scale_x = farmland_ids.width() as f32 / self.raster_size as f32;
+        let scale_y = farmland_ids.height() as f32 / self.raster_size as f32;
+
+        for polygon in &self.polygons {
+            let (cx, cy) = polygon_centroid(&polygon.vertices);
+            let x = (((cx + 0.5) * scale_x) as u32).min(farmland_ids.width() - 1);
+            let y = (((cy + 0.5) * scale_y) as u32).min(farmland_ids.height() - 1);
+            let farmland_id = farmland_ids.get_pixel(x, y)[0];
+            if farmland_id > 0 {
+                self.field_numbers.insert(polygon.id, farmland_id as u32);
+            }
+        }
+    }
+
+    /// Feldnummer eines Feldes, sofern sie aus den Farmland-Daten bekannt ist.
+    pub fn field_number(&self, id: u32) -> Option<u32> {
+        self.field_numbers.get(&id).copied()
+    }
+}
+
+/// Dekodiert `densityMap_ground.gdm` und liefert die Feldmaske.
+///
+/// Rueckgabe: ein Byte pro Pixel (unteres Nibble des Bodentyps, 0 = kein Feld)
+/// sowie die Kantenlaenge des quadratischen Rasters.
+pub(crate) fn ground_gdm_field_mask(data: &[u8]) -> Result<(Vec<u8>, usize)> {
+    let img = gdm::decode_gdm(data)?;
+    // RGB-Encoding: R-Kanal enthaelt Bits 0–7; unteres Nibble = Feld-/Bodentyp
+    let mask = if img.is_rgb {
+        img.pixels.chunks(3).map(|rgb| rgb[0] & 0x0F).collect()
+    } else {
+        img.pixels.iter().map(|&b| b & 0x0F).collect()
+    };
+    Ok((mask, img.dimension))
+}
+
+/// Extrahiert Feld-Umrisse aus den Rohdaten einer `densityMap_ground.gdm`.
+pub fn extract_field_data(gdm_data: &[u8]) -> Result<FieldData> {
+    let (mask, dim) = ground_gdm_field_mask(gdm_data)?;
+    let (mut polygons, pixel_counts) =
+        farmland::extract_field_polygons_with_pixel_counts(&mask, dim, dim);
+    polygons.sort_by_key(|polygon| polygon.id);
+    Ok(FieldData {
+        polygons,
+        raster_size: dim as u32,
+        pixel_counts,
+        field_numbers: HashMap::new(),
+    })
+}
+
+/// Zeichnet die Feld-Umrisse als geschlossene Linienzuege.
+pub fn draw_field_boundaries(image: &mut RgbImage, fields: &FieldData) {
+    if fields.raster_size == 0 {
+        return;
+    }
+    let scale = image.width() as f32 / fields.raster_size as f32;
+    let width = (image.width() as f32 / 2048.0).max(1.0);
+    let color = Rgb(FIELD_BOUNDARY_COLOR);
+
+    for polygon in &fields.polygons {
+        let vertices = &polygon.vertices;
+        for (i, &(x0, y0)) in vertices.iter().enumerate() {
+            let (x1, y1) = vertices[(i + 1) % vertices.len()];
+            // Pixelmitte statt linker oberer Ecke
+            draw_thick_line(
+                image,
+                ((x0 + 0.5) * scale, (y0 + 0.5) * scale),
+                ((x1 + 0.5) * scale, (y1 + 0.5) * scale),
+                width,
+                color,
+            );
+        }
+    }
+}
+
+/// Zeichnet die Feldnummern in den Flaechenschwerpunkt jedes Feldes.
+///
+/// Beschriftet werden nur Felder mit bekannter Feldnummer (siehe
+/// [`FieldData::assign_farmland_numbers`]). Sehr kleine Felder (kurzer Umriss)
+/// werden uebersprungen, damit die Karte nicht mit Nummern ueberladen wird.
+pub fn draw_field_numbers(image: &mut RgbImage, fields: &FieldData) {
+    if fields.raster_size == 0 {
+        return;
+    }
+    let scale = image.width() as f32 / fields.raster_size as f32;
+    let text_scale = (image.width() / 1200).clamp(1, 4);
+    let label_color = Rgb([200, 240, 255]);
+
+    for polygon in &fields.polygons {
+        if polygon.vertices.len() < MIN_LABEL_VERTICES {
+            continue;
+        }
+        let Some(number) = fields.field_number(polygon.id) else {
+            continue;
+        };
+        let (cx, cy) = polygon_centroid(&polygon.vertices);
+        let label = number.to_string();
+        let tw = text::text_width(&label, text_scale) as i32;
+        let th = text::text_height(text_scale) as i32;
+        text::draw_text_outlined(
+            image,
+            ((cx + 0.5) * scale) as i32 - tw / 2,
+            ((cy + 0.5) * scale) as i32 - th / 2,
+            &label,
+            label_color,
+            text_scale,
+        );
+    }
+}
+
+/// Zeichnet die Feldflaeche in Hektar unter die Feldnummer jedes Feldes.
+///
+/// Es werden dieselben Felder beschriftet wie bei [`draw_field_numbers`].
+pub fn draw_field_areas(image: &mut RgbImage, fields: &FieldData, map_size: u32) {
+    if fields.raster_size == 0 {
+        return;
+    }
+    let scale = image.width() as f32 / fields.raster_size as f32;
+    let number_scale = (image.width() / 1200).clamp(1, 4);
+    let text_scale = number_scale.saturating_sub(1).max(1);
+    let color = Rgb(AREA_LABEL_COLOR);
+
+    for polygon in &fields.polygons {
+        if polygon.vertices.len() < MIN_LABEL_VERTICES {
+            continue;
+        }
+        let Some(hectares) = fields.area_hectares(polygon.id, map_size) else {
+            continue;
+        };
+        let (cx, cy) = polygon_centroid(&polygon.vertices);
+        let label = format_hectares(hectares);
+        let tw = text::text_width(&label, text_scale) as i32;
+        text::draw_text_outlined(
+            image,
+            ((cx + 0.5) * scale) as i32 - tw / 2,
+            ((cy + 0.5) * scale) as i32 + text::text_height(number_scale) as i32 / 2 + 2,
+            &label,
+            color,
+            text_scale,
+        );
+    }
+}
+
+/// Rechnet eine Pixelanzahl bei gegebener Pixelgroesse in Hektar um.
+pub(crate) fn pixels_to_hectares(pixel_count: u64, meters_per_pixel: f64) -> f64 {
+    pixel_count as f64 * meters_per_pixel * meters_per_pixel / 10_000.0
+}
+
+/// Formatiert eine Flaeche mit Dezimalkomma, z.B. `12,34 ha`.
+pub(crate) fn format_hectares(hectares: f64) -> String {
+    format!("{:.2} ha", hectares.max(0.0)).replace('.', ",")
+}
+
+/// Flaechenschwerpunkt eines Polygons (Shoelace); Fallback auf den Vertex-Mittelwert.
+pub(crate) fn polygon_centroid(vertices: &[(f32, f32)]) -> (f32, f32) {
+    let mut area2 = 0.0_f64;
+    let mut cx = 0.0_f64;
+    let mut cy = 0.0_f64;
+    for (i, &(x0, y0)) in vertices.iter().enumerate() {
+        let (x1, y1) = vertices[(i + 1) % vertices.len()];
+        let cross = x0 as f64 * y1 as f64 - x1 as f64 * y0 as f64;
+        area2 += cross;
+        cx += (x0 as f64 + x1 as f64) * cross;
+        cy += (y0 as f64 + y1 as f64) * cross;
+    }
+
+    if area2.abs() < f64::EPSILON {
+        let n = vertices.len().max(1) as f32;
+        let (sx, sy) = vertices
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+        return (sx / n, sy / n);
+    }
+
+    ((cx / (3.0 * area2)) as f32, (cy / (3.0 * area2)) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polygon_centroid_of_square_is_its_center() {
+        let square = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)];
+        assert_eq!(polygon_centroid(&square), (2.0, 2.0));
+    }
+
+    #[test]
+    fn draw_field_boundaries_scales_raster_to_image() {
+        let fields = FieldData {
+            polygons: vec![FarmlandPolygon {
+                id: 1,
+                vertices: vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)],
+            }],
+            raster_size: 4,
+            pixel_counts: HashMap::new(),
+            field_numbers: HashMap::new(),
+        };
+        let mut image = RgbImage::new(16, 16);
+
+        draw_field_boundaries(&mut image, &fields);
+
+        // Raster-Pixel (1,1) → Bildmitte des Pixels bei (6,6)
+        assert_eq!(*image.get_pixel(6, 6), Rgb(FIELD_BOUNDARY_COLOR));
+        assert_eq!(*image.get_pixel(0, 0), Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn area_hectares_scales_pixels_to_map_size() {
+        let fields = FieldData {
+            polygons: Vec::new(),
+            raster_size: 1024,
+            pixel_counts: HashMap::from([(1, 2500)]),
+            field_numbers: HashMap::new(),
+        };
+
+        // 2048 m / 1024 px = 2 m pro Pixel → 2500 px * 4 m² = 1 ha
+        assert_eq!(fields.area_hectares(1, 2048), Some(1.0));
+        assert_eq!(fields.area_hectares(2, 2048), None);
+    }
+
+    #[test]
+    fn field_numbers_come_from_farmland_under_centroid() {
+        let square = |id, x0: f32, y0: f32| FarmlandPolygon {
+            id,
+            vertices: vec![
+                (x0, y0),
+                (x0 + 2.0, y0),
+                (x0 + 2.0, y0 + 2.0),
+                (x0, y0 + 2.0),
+            ],
+        };
+        // CCL-Label 1 liegt links (Farmland 17), Label 2 rechts (Farmland 0)
+        let mut fields = FieldData {
+            polygons: vec![square(1, 0.0, 0.0), square(2, 4.0, 0.0)],
+            raster_size: 8,
+            pixel_counts: HashMap::new(),
+            field_numbers: HashMap::new(),
+        };
+        let farmland_ids =
+            GrayImage::from_fn(16, 16, |x, _| image::Luma([if x < 8 { 17 } else { 0 }]));
+
+        fields.assign_farmland_numbers(&farmland_ids);
+
+        assert_eq!(fields.field_number(1), Some(17));
+        assert_eq!(fields.field_number(2), None);
+    }
+
+    #[test]
+    fn format_hectares_uses_decimal_comma() {
+        assert_eq!(format_hectares(12.346), "12,35 ha");
+        assert_eq!(format_hectares(0.0), "0,00 ha");
+    }
+
+    #[test]
+    fn ccl_pixel_counts_match_component_sizes() {
+        #[rustfmt::skip]
+        let mask = [
+            1, 1, 0, 0,
+            1, 1, 0, 2,
+            0, 0, 0, 2,
+            0, 0, 0, 0,
+        ];
+        let (polygons, counts) = farmland::extract_field_polygons_with_pixel_counts(&mask, 4, 4);
+
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(counts.get(&1), Some(&4));
+        assert_eq!(counts.get(&2), Some(&2));
+    }
+}
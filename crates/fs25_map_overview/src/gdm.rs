@@ -2,11 +2,14 @@
 //!
 //! Dekodiert `.gdm`-Dateien (DetailLayer, FoliageMultiLayer) zu Pixeldaten.
 //! Unterstuetzt 1–8 Kanaele (Grayscale) und 9+ Kanaele (RGB-Encoding).
+//! Einzelne Layer (Kanalbereiche) lassen sich mit [`read_gdm_info`] auflisten
+//! und mit [`decode_gdm_layer`] als Rohwert-Graustufenbild dekodieren.
 //! Format-Dokumentation: <https://github.com/Paint-a-Farm/grleconvert>
 //!
 //! Basiert auf grleconvert von Kim Brandwijk (MIT-Lizenz).
 
-use anyhow::{bail, ensure, Result};
+use anyhow::{bail, ensure, Context, Result};
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma};
 
 /// GDM Magic Bytes (Version 2)
 const GDM_MAGIC_V2: &[u8; 4] = b"\"MDF";
@@ -20,7 +23,6 @@ pub struct GdmImage {
     /// Dimension (quadratisch: dimension × dimension)
     pub dimension: usize,
     /// Anzahl der Kanaele
-    #[allow(dead_code)]
     pub num_channels: usize,
     /// Pixeldaten als Grayscale-Bytes (1 Byte/Pixel bei ≤8 Kanaelen)
     /// oder RGB-Bytes (3 Bytes/Pixel bei >8 Kanaelen)
@@ -29,11 +31,136 @@ pub struct GdmImage {
     pub is_rgb: bool,
 }
 
+/// Ein Kanalbereich (Layer) innerhalb einer GDM-Datei.
+///
+/// GDM-Dateien speichern mehrere Bitfelder in einem kombinierten Pixelwert;
+/// jede Kompressions-Range ist ein solcher Layer. Eigene Bereiche (z.B.
+/// `firstChannel`/`numChannels` aus der Map-i3d) lassen sich direkt angeben.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GdmLayer {
+    /// Erster Kanal (Bit) im kombinierten Pixelwert
+    pub first_channel: u8,
+    /// Anzahl der Kanaele (Bits)
+    pub num_channels: u8,
+}
+
+impl GdmLayer {
+    /// Groesster Wert, den der Layer annehmen kann.
+    pub fn max_value(&self) -> u32 {
+        (1u32 << self.num_channels.min(31)) - 1
+    }
+}
+
+/// Header-Informationen einer GDM-Datei.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GdmInfo {
+    /// Dimension (quadratisch: dimension × dimension)
+    pub dimension: usize,
+    /// Gesamtzahl der Kanaele
+    pub num_channels: usize,
+    /// Layer (Kompressions-Ranges) in Kanalreihenfolge
+    pub layers: Vec<GdmLayer>,
+}
+
+/// Geparster GDM-Header inklusive Position der Chunk-Daten.
+struct GdmHeader {
+    info: GdmInfo,
+    chunk_size: usize,
+    data_start: usize,
+}
+
+/// Liest Dimension, Kanalzahl und Layer einer GDM-Datei, ohne Pixel zu dekodieren.
+///
+/// # Fehler
+/// - Datei zu klein oder ungueltige Magic Bytes
+pub fn read_gdm_info(data: &[u8]) -> Result<GdmInfo> {
+    Ok(parse_header(data)?.info)
+}
+
+/// Dekodiert einen einzelnen Layer als Graustufenbild mit Rohwerten.
+///
+/// Layer bis 8 Kanaele liefern `ImageLuma8`, breitere `ImageLuma16`; die
+/// Werte werden nicht skaliert (0..=[`GdmLayer::max_value`]). `layer` kann
+/// aus [`read_gdm_info`] stammen oder frei gewaehlt sein.
+///
+/// # Fehler
+/// - Layer liegt ausserhalb der Kanaele oder ist breiter als 16 Bit
+/// - Fehler aus [`decode_gdm`]
+pub fn decode_gdm_layer(data: &[u8], layer: GdmLayer) -> Result<DynamicImage> {
+    let header = parse_header(data)?;
+    let end = layer.first_channel as usize + layer.num_channels as usize;
+    ensure!(
+        layer.num_channels > 0 && end <= header.info.num_channels,
+        "GDM-Layer (Kanal {}, {} Kanaele) liegt ausserhalb von {} Kanaelen",
+        layer.first_channel,
+        layer.num_channels,
+        header.info.num_channels
+    );
+    ensure!(
+        layer.num_channels <= 16,
+        "GDM-Layer mit {} Kanaelen ist breiter als 16 Bit",
+        layer.num_channels
+    );
+
+    let size = header.info.dimension as u32;
+    let values = decode_values(data, &header)?;
+    let extract = |value: u32| (value >> layer.first_channel) & layer.max_value();
+
+    if layer.num_channels <= 8 {
+        let pixels = values
+            .into_iter()
+            .map(|value| extract(value) as u8)
+            .collect();
+        let image = GrayImage::from_raw(size, size, pixels)
+            .context("GDM-Pixeldaten passen nicht zur Dimension")?;
+        Ok(DynamicImage::ImageLuma8(image))
+    } else {
+        let pixels = values
+            .into_iter()
+            .map(|value| extract(value) as u16)
+            .collect();
+        let image = ImageBuffer::<Luma<u16>, _>::from_raw(size, size, pixels)
+            .context("GDM-Pixeldaten passen nicht zur Dimension")?;
+        Ok(DynamicImage::ImageLuma16(image))
+    }
+}
+
 /// Dekodiert GDM-Daten aus einem Byte-Buffer.
 ///
 /// # Fehler
 /// - Datei zu klein, ungueltige Magic Bytes oder unerwartetes Datenende
 pub fn decode_gdm(data: &[u8]) -> Result<GdmImage> {
+    let header = parse_header(data)?;
+    let values = decode_values(data, &header)?;
+
+    let use_rgb = header.info.num_channels > 8;
+    let pixels = if use_rgb {
+        values
+            .iter()
+            .flat_map(|&combined| {
+                [
+                    (combined & 0xFF) as u8,
+                    ((combined >> 8) & 0xFF) as u8,
+                    ((combined >> 16) & 0xFF) as u8,
+                ]
+            })
+            .collect()
+    } else {
+        values
+            .iter()
+            .map(|&combined| (combined & 0xFF) as u8)
+            .collect()
+    };
+
+    Ok(GdmImage {
+        dimension: header.info.dimension,
+        num_channels: header.info.num_channels,
+        pixels,
+        is_rgb: use_rgb,
+    })
+}
+
+fn parse_header(data: &[u8]) -> Result<GdmHeader> {
     ensure!(data.len() >= 16, "GDM-Datei zu klein: {} Bytes", data.len());
 
     let magic = &data[0..4];
@@ -82,32 +209,53 @@ pub fn decode_gdm(data: &[u8]) -> Result<GdmImage> {
     );
 
     // Kompressionsgrenzen lesen
+    let compression_boundaries_size = num_compression_ranges.saturating_sub(1);
+    ensure!(
+        data.len() >= header_size + compression_boundaries_size,
+        "GDM-Header unvollstaendig"
+    );
     let mut compression_boundaries = vec![0u8];
-    for i in 0..(num_compression_ranges.saturating_sub(1)) {
-        compression_boundaries.push(data[header_size + i]);
-    }
+    compression_boundaries
+        .extend_from_slice(&data[header_size..header_size + compression_boundaries_size]);
     compression_boundaries.push(num_channels as u8);
 
-    let mut bits_per_range = Vec::new();
-    for i in 0..num_compression_ranges {
-        let start_ch = compression_boundaries[i] as usize;
-        let end_ch = compression_boundaries[i + 1] as usize;
-        bits_per_range.push(end_ch - start_ch);
-    }
+    let layers = compression_boundaries
+        .windows(2)
+        .take(num_compression_ranges)
+        .map(|bounds| GdmLayer {
+            first_channel: bounds[0],
+            num_channels: bounds[1].saturating_sub(bounds[0]),
+        })
+        .collect();
+
+    Ok(GdmHeader {
+        info: GdmInfo {
+            dimension,
+            num_channels,
+            layers,
+        },
+        chunk_size,
+        data_start: header_size + compression_boundaries_size,
+    })
+}
+
+/// Dekodiert alle Chunks zu kombinierten Pixelwerten (ein `u32` pro Pixel).
+fn decode_values(data: &[u8], header: &GdmHeader) -> Result<Vec<u32>> {
+    let GdmHeader {
+        info,
+        chunk_size,
+        data_start,
+    } = header;
+    let (dimension, chunk_size) = (info.dimension, *chunk_size);
 
     let chunks_per_dim = dimension / chunk_size;
     let total_chunks = chunks_per_dim * chunks_per_dim;
-    let compression_boundaries_size = num_compression_ranges.saturating_sub(1);
-    let data_start = header_size + compression_boundaries_size;
-
-    let use_rgb = num_channels > 8;
-    let bytes_per_pixel = if use_rgb { 3 } else { 1 };
-    let mut image = vec![0u8; dimension * dimension * bytes_per_pixel];
+    let mut values = vec![0u32; dimension * dimension];
 
-    let mut pos = data_start;
+    let mut pos = *data_start;
     for chunk_idx in 0..total_chunks {
         let mut range_values: Vec<Vec<u16>> = Vec::new();
-        for _range_idx in 0..num_compression_ranges {
+        for _range_idx in 0..info.layers.len() {
             ensure!(
                 pos + 2 <= data.len(),
                 "Unerwartetes Datenende bei Chunk {}/{}",
@@ -128,39 +276,20 @@ pub fn decode_gdm(data: &[u8]) -> Result<GdmImage> {
         let total_pixels = chunk_size * chunk_size;
         for pixel_idx in 0..total_pixels {
             let mut combined: u32 = 0;
-            let mut shift = 0;
-            for (range_idx, range) in range_values.iter().enumerate() {
+            for (range, layer) in range_values.iter().zip(&info.layers) {
                 let val = *range.get(pixel_idx).unwrap_or(&0) as u32;
-                combined |= val << shift;
-                shift += bits_per_range[range_idx];
+                combined |= val << layer.first_channel;
             }
 
-            let py = pixel_idx / chunk_size;
-            let px = pixel_idx % chunk_size;
-            let img_y = base_y + py;
-            let img_x = base_x + px;
-
+            let img_y = base_y + pixel_idx / chunk_size;
+            let img_x = base_x + pixel_idx % chunk_size;
             if img_y < dimension && img_x < dimension {
-                let offset = (img_y * dimension + img_x) * bytes_per_pixel;
-                if use_rgb {
-                    if offset + 2 < image.len() {
-                        image[offset] = (combined & 0xFF) as u8;
-                        image[offset + 1] = ((combined >> 8) & 0xFF) as u8;
-                        image[offset + 2] = ((combined >> 16) & 0xFF) as u8;
-                    }
-                } else if offset < image.len() {
-                    image[offset] = (combined & 0xFF) as u8;
-                }
+                values[img_y * dimension + img_x] = combined;
             }
         }
     }
 
-    Ok(GdmImage {
-        dimension,
-        num_channels,
-        pixels: image,
-        is_rgb: use_rgb,
-    })
+    Ok(values)
 }
 
 /// Dekodiert einen einzelnen GDM-Block (Chunk).
@@ -244,4 +373,82 @@ mod tests {
         assert_eq!(pixels.len(), 16); // 4*4
         assert!(pixels.iter().all(|&p| p == 0x0042));
     }
+
+    /// Baut eine 32×32-GDM (v2, ein Chunk) mit 6 Kanaelen in zwei Ranges:
+    /// Kanaele 0–3 mit Bitmap (Wert = Pixelindex % 16), Kanaele 4–5 konstant 2.
+    fn two_layer_gdm() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(GDM_MAGIC_V2);
+        data.extend_from_slice(&0u32.to_le_bytes()); // Version
+        data.extend_from_slice(&[0, 5, 0, 6, 2, 0, 0, 0]); // dim 32, chunk 32, 6 Kanaele, 2 Ranges
+        data.push(4); // Grenze zwischen Range 0 und 1
+                      // Range 0: 4 Bit roh, zwei Pixel pro Byte (niedriges Nibble zuerst)
+        data.extend_from_slice(&[4, 0]);
+        data.extend((0..512).map(|i| {
+            let low = (2 * i % 16) as u8;
+            low | (low + 1) << 4
+        }));
+        // Range 1: konstant 2
+        data.extend_from_slice(&[0, 1, 2, 0]);
+        data
+    }
+
+    #[test]
+    fn test_read_gdm_info_lists_compression_ranges() {
+        let info = read_gdm_info(&two_layer_gdm()).expect("Header");
+        assert_eq!(info.dimension, 32);
+        assert_eq!(info.num_channels, 6);
+        assert_eq!(
+            info.layers,
+            vec![
+                GdmLayer {
+                    first_channel: 0,
+                    num_channels: 4
+                },
+                GdmLayer {
+                    first_channel: 4,
+                    num_channels: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_gdm_layer_extracts_bits() {
+        let data = two_layer_gdm();
+        let info = read_gdm_info(&data).expect("Header");
+
+        let first = decode_gdm_layer(&data, info.layers[0])
+            .expect("Layer 0")
+            .to_luma8();
+        assert_eq!(first.get_pixel(5, 0).0, [5]);
+        assert_eq!(first.get_pixel(0, 1).0, [0]); // Index 32 % 16
+
+        let second = decode_gdm_layer(&data, info.layers[1])
+            .expect("Layer 1")
+            .to_luma8();
+        assert!(second.pixels().all(|p| p.0 == [2]));
+
+        // Freier Bereich ueber die Range-Grenze: Bits 2–4 von (5 | 2 << 4)
+        let custom = GdmLayer {
+            first_channel: 2,
+            num_channels: 3,
+        };
+        let custom = decode_gdm_layer(&data, custom)
+            .expect("Freier Layer")
+            .to_luma8();
+        assert_eq!(custom.get_pixel(5, 0).0, [((5 | 2 << 4) >> 2) & 0b111]);
+
+        let combined = decode_gdm(&data).expect("Gesamt");
+        assert_eq!(combined.pixels[5], 5 | 2 << 4);
+    }
+
+    #[test]
+    fn test_decode_gdm_layer_rejects_out_of_range_layer() {
+        let layer = GdmLayer {
+            first_channel: 4,
+            num_channels: 3,
+        };
+        assert!(decode_gdm_layer(&two_layer_gdm(), layer).is_err());
+    }
 }
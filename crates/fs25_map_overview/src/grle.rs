@@ -1,6 +1,7 @@
 //! GRLE-Decoder (GIANTS Run-Length Encoded).
 //!
-//! Dekodiert `.grle`-Dateien (InfoLayer) zu Grayscale-Pixeldaten.
+//! Dekodiert `.grle`-Dateien (InfoLayer) zu Grayscale-Pixeldaten und
+//! kodiert veraenderte Pixel wieder zurueck (z.B. `infoLayer_farmlands.grle`).
 //! Format-Dokumentation: <https://github.com/Paint-a-Farm/grleconvert>
 //!
 //! Basiert auf grleconvert von Kim Brandwijk (MIT-Lizenz).
@@ -13,6 +14,12 @@ const GRLE_MAGIC: &[u8; 4] = b"GRLE";
 /// Header-Groesse in Bytes
 const GRLE_HEADER_SIZE: usize = 20;
 
+/// Header-Version, die [`encode_grle`] schreibt
+const GRLE_VERSION: u16 = 1;
+
+/// Breite und Hoehe sind im Header in Vielfachen dieser Groesse abgelegt
+const GRLE_DIMENSION_UNIT: usize = 256;
+
 /// Ergebnis einer GRLE-Dekodierung.
 #[derive(Debug)]
 pub struct GrleImage {
@@ -42,8 +49,8 @@ pub fn decode_grle(data: &[u8]) -> Result<GrleImage> {
     );
 
     let _version = u16::from_le_bytes([data[4], data[5]]);
-    let width = u16::from_le_bytes([data[6], data[7]]) as usize * 256;
-    let height = u16::from_le_bytes([data[10], data[11]]) as usize * 256;
+    let width = u16::from_le_bytes([data[6], data[7]]) as usize * GRLE_DIMENSION_UNIT;
+    let height = u16::from_le_bytes([data[10], data[11]]) as usize * GRLE_DIMENSION_UNIT;
 
     log::debug!("GRLE: {}x{} Pixel", width, height);
 
@@ -58,6 +65,102 @@ pub fn decode_grle(data: &[u8]) -> Result<GrleImage> {
     })
 }
 
+/// Kodiert ein Grayscale-Bild als GRLE-Datei.
+///
+/// Schreibt Version 1 und die Dimensionen; die uebrigen Header-Bytes sind 0.
+/// Um unbekannte Header-Felder einer Originaldatei zu erhalten, stattdessen
+/// [`encode_grle_with_header`] verwenden.
+///
+/// # Fehler
+/// - Breite/Hoehe kein Vielfaches von 256 oder Pixelanzahl passt nicht
+pub fn encode_grle(image: &GrleImage) -> Result<Vec<u8>> {
+    let mut header = [0u8; GRLE_HEADER_SIZE];
+    header[0..4].copy_from_slice(GRLE_MAGIC);
+    header[4..6].copy_from_slice(&GRLE_VERSION.to_le_bytes());
+    encode_with_header(header, image)
+}
+
+/// Kodiert ein Grayscale-Bild mit dem Header einer bestehenden GRLE-Datei.
+///
+/// Magic, Version und alle unbekannten Header-Felder werden aus `original`
+/// uebernommen, nur die Dimensionen werden aus `image` gesetzt. Typischer
+/// Ablauf: `decode_grle` → Pixel aendern → `encode_grle_with_header`.
+///
+/// # Fehler
+/// - `original` ist keine gueltige GRLE-Datei
+/// - Breite/Hoehe kein Vielfaches von 256 oder Pixelanzahl passt nicht
+pub fn encode_grle_with_header(original: &[u8], image: &GrleImage) -> Result<Vec<u8>> {
+    ensure!(
+        original.len() >= GRLE_HEADER_SIZE && &original[0..4] == GRLE_MAGIC,
+        "Vorlage ist keine gueltige GRLE-Datei"
+    );
+    let mut header = [0u8; GRLE_HEADER_SIZE];
+    header.copy_from_slice(&original[..GRLE_HEADER_SIZE]);
+    encode_with_header(header, image)
+}
+
+fn encode_with_header(mut header: [u8; GRLE_HEADER_SIZE], image: &GrleImage) -> Result<Vec<u8>> {
+    ensure!(
+        image.width.is_multiple_of(GRLE_DIMENSION_UNIT)
+            && image.height.is_multiple_of(GRLE_DIMENSION_UNIT),
+        "GRLE-Dimensionen muessen Vielfache von {} sein: {}x{}",
+        GRLE_DIMENSION_UNIT,
+        image.width,
+        image.height
+    );
+    let width_units = u16::try_from(image.width / GRLE_DIMENSION_UNIT)?;
+    let height_units = u16::try_from(image.height / GRLE_DIMENSION_UNIT)?;
+    ensure!(
+        image.pixels.len() == image.width * image.height,
+        "GRLE-Pixelanzahl {} passt nicht zu {}x{}",
+        image.pixels.len(),
+        image.width,
+        image.height
+    );
+
+    header[6..8].copy_from_slice(&width_units.to_le_bytes());
+    header[10..12].copy_from_slice(&height_units.to_le_bytes());
+
+    let mut output = header.to_vec();
+    encode_rle(&image.pixels, &mut output);
+    Ok(output)
+}
+
+/// RLE-Kompression, exakt invers zu [`decode_rle`].
+///
+/// - Einzelpixel werden direkt geschrieben (Transition)
+/// - Runs ab 2 Pixeln als Paar `(v, v)` plus Zaehler `count - 2`
+///   (0xFF-Bytes je 255, dann Rest)
+/// - Ein Einzelpixel am Ende braucht ein abweichendes Folgebyte, damit der
+///   Decoder es als Transition liest
+fn encode_rle(pixels: &[u8], output: &mut Vec<u8>) {
+    output.push(0x00); // Padding
+
+    let mut i = 0;
+    while i < pixels.len() {
+        let value = pixels[i];
+        let run = pixels[i..].iter().take_while(|&&p| p == value).count();
+        i += run;
+
+        if run == 1 {
+            output.push(value);
+            if i == pixels.len() {
+                output.push(value ^ 0xFF);
+            }
+            continue;
+        }
+
+        output.push(value);
+        output.push(value);
+        let mut count = run - 2;
+        while count >= 255 {
+            output.push(0xFF);
+            count -= 255;
+        }
+        output.push(count as u8);
+    }
+}
+
 /// RLE-Dekompression fuer GRLE-Daten.
 ///
 /// Algorithmus (aus grleconvert):
@@ -129,6 +232,84 @@ mod tests {
         assert_eq!(result, vec![0x10, 0x20, 0x20]);
     }
 
+    #[test]
+    fn test_encode_rle_matches_decoder_fixtures() {
+        let mut run = Vec::new();
+        encode_rle(&[0x42; 4], &mut run);
+        assert_eq!(run, vec![0x00, 0x42, 0x42, 0x02]);
+
+        let mut transition = Vec::new();
+        encode_rle(&[0x10, 0x20, 0x20], &mut transition);
+        assert_eq!(transition, vec![0x00, 0x10, 0x20, 0x20, 0x00]);
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let (width, height) = (512, 256);
+        // Mischung aus langen Runs (> 255, exakt 257 = 2 + 255), Einzelpixeln
+        // und einem Einzelpixel ganz am Ende
+        let mut pixels: Vec<u8> = (0..width * height)
+            .map(|i| match i {
+                0..1000 => 3,
+                1000..1257 => 7,
+                _ if i % 97 == 0 => (i % 251) as u8,
+                _ => (i / 300 % 5) as u8,
+            })
+            .collect();
+        *pixels.last_mut().expect("nicht leer") = 200;
+        let image = GrleImage {
+            width,
+            height,
+            pixels,
+        };
+
+        let encoded = encode_grle(&image).expect("Kodierung");
+        let decoded = decode_grle(&encoded).expect("Dekodierung");
+
+        assert_eq!(decoded.width, width);
+        assert_eq!(decoded.height, height);
+        assert_eq!(decoded.pixels, image.pixels);
+    }
+
+    #[test]
+    fn test_encode_with_header_preserves_unknown_fields() {
+        let mut original = vec![0u8; GRLE_HEADER_SIZE];
+        original[0..4].copy_from_slice(GRLE_MAGIC);
+        original[4..6].copy_from_slice(&3u16.to_le_bytes());
+        original[12..20].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let image = GrleImage {
+            width: 256,
+            height: 256,
+            pixels: vec![9; 256 * 256],
+        };
+
+        let encoded = encode_grle_with_header(&original, &image).expect("Kodierung");
+
+        assert_eq!(&encoded[4..6], &3u16.to_le_bytes());
+        assert_eq!(&encoded[12..20], &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            decode_grle(&encoded).expect("Dekodierung").pixels,
+            image.pixels
+        );
+    }
+
+    #[test]
+    fn test_encode_rejects_invalid_dimensions() {
+        let image = GrleImage {
+            width: 100,
+            height: 256,
+            pixels: vec![0; 100 * 256],
+        };
+        assert!(encode_grle(&image).is_err());
+
+        let image = GrleImage {
+            width: 256,
+            height: 256,
+            pixels: vec![0; 10],
+        };
+        assert!(encode_grle(&image).is_err());
+    }
+
     #[test]
     fn test_reject_invalid_magic() {
         let data = [
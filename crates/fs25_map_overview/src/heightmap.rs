@@ -0,0 +1,200 @@
+//! DEM mit Weltbezug fuer Hoehenabfragen (z.B. Y-Koordinaten im Editor).
+//!
+//! Das DEM (`dem.png`/`.dds`/`.gdm`) deckt die ganze Karte ab, unabhaengig
+//! von seiner Pixelgroesse (`unitsPerPixel`). Hoehenskala, Pixelgroesse,
+//! Heightmap-Datei und Verschiebung der Terrain-Mitte stehen am
+//! `TerrainTransformGroup` der Map-i3d ([`parse_terrain_info`]); daraus folgt
+//! die exakte Weltgroesse, ohne sie aus den Bilddimensionen zu raten.
+
+use std::collections::HashMap;
+
+use image::DynamicImage;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// Hoehenskala, wenn die Map-i3d keine angibt (FS25-Standard).
+pub const DEFAULT_HEIGHT_SCALE: f32 = 255.0;
+
+/// Aus einem Map-Mod extrahiertes DEM.
+#[derive(Debug, Clone)]
+pub struct MapHeightmap {
+    /// DEM-Rohbild (meist `Luma16`); Weiss entspricht `height_scale` Metern
+    pub dem: DynamicImage,
+    /// Weltgroesse in Metern, zentriert um 0/0
+    pub map_size: u32,
+    /// Meter fuer den maximalen DEM-Wert
+    pub height_scale: f32,
+    /// Verschiebung der Terrain-Mitte gegenueber dem Welt-Ursprung (X, Z)
+    pub center_offset: (f32, f32),
+}
+
+impl MapHeightmap {
+    /// Welt-Rechteck `(min_x, min_z, max_x, max_z)`, das das DEM abdeckt.
+    pub fn world_bounds(&self) -> (f32, f32, f32, f32) {
+        let half = self.map_size as f32 / 2.0;
+        let (x, z) = self.center_offset;
+        (x - half, z - half, x + half, z + half)
+    }
+}
+
+/// Terrain-Angaben aus dem `TerrainTransformGroup` einer Map-i3d.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerrainInfo {
+    /// Meter fuer den maximalen DEM-Wert (`heightScale`, sonst [`DEFAULT_HEIGHT_SCALE`])
+    pub height_scale: f32,
+    /// Meter pro DEM-Pixel (`unitsPerPixel`, Standard 1)
+    pub units_per_pixel: f32,
+    /// Verschiebung der Terrain-Mitte gegenueber dem Welt-Ursprung in Metern
+    /// (X, Z aus `translation`, Standard 0/0)
+    pub center_offset: (f32, f32),
+    /// Heightmap-Datei relativ zur i3d (`heightMapId` → `<File filename>`)
+    pub heightmap_filename: Option<String>,
+}
+
+impl TerrainInfo {
+    /// Weltgroesse in Metern fuer ein DEM mit `dem_width` Pixeln.
+    ///
+    /// Heightmaps im Format 2^n+1 (z.B. 2049 px) haben einen Randpixel mehr
+    /// als Intervalle; die Weltgroesse ist dann `(dem_width - 1) * unitsPerPixel`.
+    pub fn terrain_size(&self, dem_width: u32) -> u32 {
+        let intervals = match dem_width {
+            width if width > 1 && (width - 1).is_power_of_two() => width - 1,
+            width => width,
+        };
+        (intervals as f32 * self.units_per_pixel).round() as u32
+    }
+}
+
+/// Liest die Terrain-Angaben des ersten `TerrainTransformGroup` einer Map-i3d.
+///
+/// Gibt `None` zurueck, wenn die i3d keinen Terrain-Knoten enthaelt oder nicht
+/// lesbar ist. Ungueltige Einzelwerte fallen auf ihren Standard zurueck.
+pub fn parse_terrain_info(i3d: &[u8]) -> Option<TerrainInfo> {
+    let mut reader = Reader::from_reader(i3d);
+    let mut buf = Vec::new();
+    let mut files: HashMap<String, String> = HashMap::new();
+    let mut terrain = None;
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e) | Event::Empty(e)) => match e.name().as_ref() {
+                b"File" => {
+                    if let (Some(id), Some(filename)) =
+                        (attribute(&e, b"fileId"), attribute(&e, b"filename"))
+                    {
+                        files.insert(id, filename);
+                    }
+                }
+                b"TerrainTransformGroup" if terrain.is_none() => terrain = Some(e.into_owned()),
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(_) => return None,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let terrain = terrain?;
+    let positive = |value: Option<String>| {
+        value
+            .and_then(|value| value.trim().parse::<f32>().ok())
+            .filter(|value| value.is_finite() && *value > 0.0)
+    };
+    let center_offset = attribute(&terrain, b"translation")
+        .map(|value| {
+            value
+                .split_whitespace()
+                .map(|part| part.parse::<f32>().unwrap_or(0.0))
+                .collect::<Vec<_>>()
+        })
+        .and_then(|parts| match parts[..] {
+            [x, _, z] => Some((x, z)),
+            _ => None,
+        })
+        .unwrap_or((0.0, 0.0));
+
+    Some(TerrainInfo {
+        height_scale: positive(attribute(&terrain, b"heightScale")).unwrap_or(DEFAULT_HEIGHT_SCALE),
+        units_per_pixel: positive(attribute(&terrain, b"unitsPerPixel")).unwrap_or(1.0),
+        center_offset,
+        heightmap_filename: attribute(&terrain, b"heightMapId")
+            .and_then(|id| files.get(&id).cloned()),
+    })
+}
+
+/// Wert eines Attributs als String.
+fn attribute(element: &BytesStart<'_>, key: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+/// Liest `heightScale` des ersten `TerrainTransformGroup` aus einer Map-i3d.
+///
+/// Gibt `None` zurueck, wenn kein Terrain-Knoten oder kein gueltiger Wert
+/// gefunden wird.
+pub fn terrain_height_scale(i3d: &[u8]) -> Option<f32> {
+    let mut reader = Reader::from_reader(i3d);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e) | Event::Empty(e))
+                if e.name().as_ref() == b"TerrainTransformGroup" =>
+            {
+                return e
+                    .attributes()
+                    .flatten()
+                    .find(|attr| attr.key.as_ref() == b"heightScale")
+                    .and_then(|attr| String::from_utf8_lossy(&attr.value).trim().parse().ok())
+                    .filter(|scale: &f32| scale.is_finite() && *scale > 0.0);
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terrain_height_scale_reads_terrain_node() {
+        let i3d = br#"<i3D><Scene><TransformGroup name="x" heightScale="3"/>
+            <TerrainTransformGroup name="terrain" heightScale="310" unitsPerPixel="2">
+            </TerrainTransformGroup></Scene></i3D>"#;
+        assert_eq!(terrain_height_scale(i3d), Some(310.0));
+        assert_eq!(terrain_height_scale(b"<i3D><Scene/></i3D>"), None);
+        assert_eq!(
+            terrain_height_scale(br#"<i3D><TerrainTransformGroup heightScale="0"/></i3D>"#),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_terrain_info_reads_scale_offset_and_heightmap_file() {
+        let i3d = br#"<i3D><Files><File fileId="7" filename="data/map_dem.png"/></Files>
+            <Scene><TerrainTransformGroup name="terrain" translation="10 0 -20"
+                heightScale="310" unitsPerPixel="2" heightMapId="7"/></Scene></i3D>"#;
+
+        let terrain = parse_terrain_info(i3d).expect("Terrain erwartet");
+
+        assert_eq!(
+            terrain,
+            TerrainInfo {
+                height_scale: 310.0,
+                units_per_pixel: 2.0,
+                center_offset: (10.0, -20.0),
+                heightmap_filename: Some("data/map_dem.png".to_string()),
+            }
+        );
+        assert_eq!(terrain.terrain_size(1025), 2048);
+        assert_eq!(terrain.terrain_size(1024), 2048);
+        assert_eq!(parse_terrain_info(b"<i3D><Scene/></i3D>"), None);
+    }
+}
@@ -1,17 +1,38 @@
 //! Hillshade-Berechnung aus DEM-Daten.
 //!
 //! Erzeugt eine 3D-Reliefschattierung basierend auf dem
-//! Digital Elevation Model (DEM) der Karte.
+//! Digital Elevation Model (DEM) der Karte. Das DEM wird in voller
+//! 16-Bit-Aufloesung ausgewertet, damit flache Karten keine Treppenstufen
+//! der 8-Bit-Quantisierung zeigen.
 
 use anyhow::Result;
-use image::GrayImage;
+use image::{ImageBuffer, Luma};
+use rayon::prelude::*;
+
+/// 16-Bit-Graustufen-DEM, wie es Hillshade und Gewaesser erwarten.
+pub(crate) type DemImage = ImageBuffer<Luma<u16>, Vec<u16>>;
+
+/// Umrechnung von 16-Bit-Werten auf die 8-Bit-Skala, auf die `z_factor` abgestimmt ist.
+const U16_PER_U8_LEVEL: f32 = 257.0;
+
+/// Azimut-Versatz der vier Lichtquellen im multidirektionalen Modus (Grad).
+const MULTIDIRECTIONAL_OFFSETS_DEG: [f32; 4] = [-90.0, -45.0, 0.0, 45.0];
 
 /// Parameter fuer die Hillshade-Berechnung.
+#[derive(Debug, Clone, PartialEq)]
 pub struct HillshadeParams {
     /// Azimut der Lichtquelle in Grad (Standard: 315° = Nordwest)
     pub azimuth_deg: f32,
-    /// Hoehenwinkel der Lichtquelle in Grad (Standard: 45°)
+    /// Hoehenwinkel der Lichtquelle in Grad (Standard: 45°); kleinere Werte
+    /// ergeben laengere Schatten und staerkeres Relief
     pub altitude_deg: f32,
+    /// Ueberhoehung des DEM-Gradienten (Standard: 1.0); > 1 verstaerkt das
+    /// Relief flacher Karten, < 1 mildert bergige Karten ab
+    pub z_factor: f32,
+    /// Vier Lichtquellen (Azimut −90°, −45°, 0°, +45°) nach Hangrichtung
+    /// gewichtet mischen, statt nur aus einer Richtung zu beleuchten.
+    /// Hangkanten parallel zum Licht verschwinden so nicht.
+    pub multidirectional: bool,
     /// Mischfaktor: 0.0 = kein Hillshade, 1.0 = voller Effekt
     pub blend_factor: f32,
 }
@@ -21,6 +42,8 @@ impl Default for HillshadeParams {
         Self {
             azimuth_deg: 315.0,
             altitude_deg: 45.0,
+            z_factor: 1.0,
+            multidirectional: false,
             blend_factor: 0.45,
         }
     }
@@ -29,54 +52,82 @@ impl Default for HillshadeParams {
 /// Berechnet eine Hillshade-Map aus einem DEM-Grayscale-Bild.
 ///
 /// Gibt ein f32-Array zurueck mit Werten zwischen 0.0 und 1.0
-/// (0.0 = voller Schatten, 1.0 = voll beleuchtet).
+/// (0.0 = voller Schatten, 1.0 = voll beleuchtet). Die Zeilen werden
+/// parallel berechnet.
+///
+/// Gradienten werden in 8-Bit-Stufen gemessen (ein 8-Bit-Grauwert entspricht
+/// 257 16-Bit-Werten), sodass `z_factor` unabhaengig von der Bit-Tiefe der
+/// Quelle dasselbe Relief ergibt.
 ///
 /// # Parameter
-/// - `dem`: Grayscale-DEM-Bild
+/// - `dem`: 16-Bit-Grayscale-DEM-Bild
 /// - `params`: Beleuchtungsparameter
-pub fn compute_hillshade(dem: &GrayImage, params: &HillshadeParams) -> Result<Vec<f32>> {
+pub fn compute_hillshade(dem: &DemImage, params: &HillshadeParams) -> Result<Vec<f32>> {
     let width = dem.width() as usize;
     let height = dem.height() as usize;
 
     let azimuth = params.azimuth_deg.to_radians();
-    let altitude = params.altitude_deg.to_radians();
+    let altitude = params.altitude_deg.clamp(0.0, 90.0).to_radians();
+    let z_factor = params.z_factor.max(0.0);
 
     let sin_alt = altitude.sin();
     let cos_alt = altitude.cos();
 
-    let pixels = dem.as_raw();
+    let pixels: Vec<f32> = dem
+        .as_raw()
+        .iter()
+        .map(|&value| value as f32 / U16_PER_U8_LEVEL)
+        .collect();
     let mut hillshade = vec![0.5f32; width * height];
 
-    // Gradient (Sobel-artiger Ansatz mit numpy-aehnlichem gradient())
-    for y in 0..height {
-        for x in 0..width {
-            // dz/dx
-            let dx = if x == 0 {
-                pixels[y * width + 1] as f32 - pixels[y * width] as f32
-            } else if x == width - 1 {
-                pixels[y * width + x] as f32 - pixels[y * width + x - 1] as f32
-            } else {
-                (pixels[y * width + x + 1] as f32 - pixels[y * width + x - 1] as f32) / 2.0
-            };
+    // Gradient (Sobel-artiger Ansatz mit numpy-aehnlichem gradient()), zeilenweise parallel
+    hillshade
+        .par_chunks_mut(width.max(1))
+        .enumerate()
+        .for_each(|(y, row)| {
+            for (x, value) in row.iter_mut().enumerate() {
+                // dz/dx
+                let dx = if x == 0 {
+                    pixels[y * width + 1] - pixels[y * width]
+                } else if x == width - 1 {
+                    pixels[y * width + x] - pixels[y * width + x - 1]
+                } else {
+                    (pixels[y * width + x + 1] - pixels[y * width + x - 1]) / 2.0
+                };
 
-            // dz/dy
-            let dy = if y == 0 {
-                pixels[(y + 1) * width + x] as f32 - pixels[y * width + x] as f32
-            } else if y == height - 1 {
-                pixels[y * width + x] as f32 - pixels[(y - 1) * width + x] as f32
-            } else {
-                (pixels[(y + 1) * width + x] as f32 - pixels[(y - 1) * width + x] as f32) / 2.0
-            };
+                // dz/dy
+                let dy = if y == 0 {
+                    pixels[(y + 1) * width + x] - pixels[y * width + x]
+                } else if y == height - 1 {
+                    pixels[y * width + x] - pixels[(y - 1) * width + x]
+                } else {
+                    (pixels[(y + 1) * width + x] - pixels[(y - 1) * width + x]) / 2.0
+                };
 
-            let slope = (dx * dx + dy * dy).sqrt();
-            let aspect = (-dy).atan2(dx);
+                let (dx, dy) = (dx * z_factor, dy * z_factor);
+                let slope = (dx * dx + dy * dy).sqrt().atan();
+                let aspect = (-dy).atan2(dx);
+                let shade = |azimuth: f32| {
+                    (sin_alt * slope.cos() + cos_alt * slope.sin() * (azimuth - aspect).cos())
+                        .clamp(0.0, 1.0)
+                };
 
-            let hs = sin_alt * slope.atan().cos()
-                + cos_alt * slope.atan().sin() * (azimuth - aspect).cos();
-
-            hillshade[y * width + x] = hs.clamp(0.0, 1.0);
-        }
-    }
+                *value = if params.multidirectional {
+                    // Gewichte summieren sich ueber die vier Richtungen zu 2
+                    MULTIDIRECTIONAL_OFFSETS_DEG
+                        .iter()
+                        .map(|offset| {
+                            let light = azimuth + offset.to_radians();
+                            let weight = 0.5 * (1.0 + (2.0 * (light - aspect)).cos());
+                            weight * shade(light)
+                        })
+                        .sum::<f32>()
+                        / 2.0
+                } else {
+                    shade(azimuth)
+                };
+            }
+        });
 
     Ok(hillshade)
 }
@@ -91,13 +142,95 @@ pub fn compute_hillshade(dem: &GrayImage, params: &HillshadeParams) -> Result<Ve
 /// - `blend`: Mischfaktor (0.0 = kein Effekt, 1.0 = voller Effekt)
 pub fn apply_hillshade(rgb_data: &mut [u8], hillshade: &[f32], blend: f32) {
     let base = 1.0 - blend;
-    for (i, &hs) in hillshade.iter().enumerate() {
-        let offset = i * 3;
-        if offset + 2 < rgb_data.len() {
+    rgb_data
+        .par_chunks_exact_mut(3)
+        .zip(hillshade.par_iter())
+        .for_each(|(pixel, &hs)| {
             let factor = base + blend * hs;
-            rgb_data[offset] = (rgb_data[offset] as f32 * factor).clamp(0.0, 255.0) as u8;
-            rgb_data[offset + 1] = (rgb_data[offset + 1] as f32 * factor).clamp(0.0, 255.0) as u8;
-            rgb_data[offset + 2] = (rgb_data[offset + 2] as f32 * factor).clamp(0.0, 255.0) as u8;
+            for channel in pixel {
+                *channel = (*channel as f32 * factor).clamp(0.0, 255.0) as u8;
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// DEM mit konstanter Steigung in X-Richtung (`step` 8-Bit-Stufen pro Pixel,
+    /// negativ = fallend).
+    fn ramp(step: i32) -> DemImage {
+        DemImage::from_fn(8, 8, |x, _| {
+            Luma([((128 + step * x as i32) * U16_PER_U8_LEVEL as i32) as u16])
+        })
+    }
+
+    fn center(dem: &DemImage, params: &HillshadeParams) -> f32 {
+        compute_hillshade(dem, params).expect("Hillshade erwartet")[4 * 8 + 4]
+    }
+
+    #[test]
+    fn flat_terrain_is_lit_by_altitude_only() {
+        let flat = DemImage::from_pixel(8, 8, Luma([25_700]));
+        for multidirectional in [false, true] {
+            let params = HillshadeParams {
+                multidirectional,
+                ..HillshadeParams::default()
+            };
+            let expected = 45f32.to_radians().sin();
+            assert!((center(&flat, &params) - expected).abs() < 1e-5);
         }
     }
+
+    #[test]
+    fn z_factor_scales_relief() {
+        // Kleine Faktoren, damit keiner der Haenge in die Saettigung laeuft
+        let soft = HillshadeParams {
+            z_factor: 0.1,
+            ..HillshadeParams::default()
+        };
+        let strong = HillshadeParams {
+            z_factor: 0.3,
+            ..HillshadeParams::default()
+        };
+
+        let contrast =
+            |params: &HillshadeParams| (center(&ramp(1), params) - center(&ramp(-1), params)).abs();
+        assert!(contrast(&strong) > contrast(&soft) * 1.5);
+    }
+
+    #[test]
+    fn multidirectional_shades_slopes_parallel_to_the_light() {
+        // Licht von 90°: Hangrichtung 0° und 180° liegen quer dazu
+        let single = HillshadeParams {
+            azimuth_deg: 90.0,
+            ..HillshadeParams::default()
+        };
+        let multi = HillshadeParams {
+            multidirectional: true,
+            ..single.clone()
+        };
+
+        let (up, down) = (ramp(4), ramp(-4));
+        assert!((center(&up, &single) - center(&down, &single)).abs() < 1e-5);
+        assert!((center(&up, &multi) - center(&down, &multi)).abs() > 0.1);
+    }
+
+    #[test]
+    fn sub_8bit_slopes_are_shaded_smoothly() {
+        // Steigung unterhalb einer 8-Bit-Stufe pro Pixel: quantisiert waere das
+        // Gelaende abwechselnd flach und gestuft
+        let gentle = DemImage::from_fn(8, 8, |x, _| Luma([30_000 + x as u16 * 100]));
+        // Licht entlang der Steigung: der Hang ist heller als flaches Gelaende
+        let params = HillshadeParams {
+            azimuth_deg: 0.0,
+            ..HillshadeParams::default()
+        };
+        let shade = compute_hillshade(&gentle, &params).expect("Hillshade erwartet");
+        let flat = 45f32.to_radians().sin();
+
+        let row = &shade[4 * 8 + 1..4 * 8 + 7];
+        assert!(row.iter().all(|&value| value > flat));
+        assert!(row.windows(2).all(|pair| (pair[0] - pair[1]).abs() < 1e-5));
+    }
 }
@@ -1,14 +1,22 @@
 //! Layer-Bundle-API fuer separat speicherbare Overview-PNGs.
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
-use image::{GrayImage, Rgb, RgbImage, Rgba, RgbaImage};
+use anyhow::{Context, Result};
+use image::{Rgb, RgbImage, Rgba, RgbaImage};
 
-use crate::composite::{self, FarmlandData, OverviewOptions, Poi};
+use crate::composite::{self, FarmlandData, OverviewOptions, Poi, TitleBarLayout};
+use crate::contours::{self, HeightField};
 use crate::discovery::MapInfo;
-use crate::hillshade::{self, HillshadeParams};
-use crate::{terrain, FarmlandPolygon};
+use crate::farmland_prices::{self, FarmlandPrices};
+use crate::fields::{self, FieldData};
+use crate::hillshade::{self, DemImage, HillshadeParams};
+use crate::network;
+use crate::placement;
+use crate::progress::{OverviewStage, ProgressReporter};
+use crate::roads::{self, RoadSpline};
+use crate::{terrain, vegetation, FarmlandPolygon};
 
 /// Separat generierte Bild-Layer einer Uebersichtskarte.
 ///
@@ -18,14 +26,30 @@ use crate::{terrain, FarmlandPolygon};
 pub struct OverviewLayerBundle {
     /// Opakes Terrain-Basisbild inklusive Title-Bar.
     pub terrain: RgbaImage,
+    /// Transparentes Wald-Kronendach.
+    pub forest: RgbaImage,
+    /// Transparente Gewaesser (Seen, Fluesse) mit Tiefenfaerbung.
+    pub water: RgbaImage,
     /// Transparente Hillshade-Schattierung.
     pub hillshade: RgbaImage,
+    /// Transparente Hoehenlinien mit Beschriftung (leer ohne DEM).
+    pub contours: RgbaImage,
+    /// Transparente, schraffierte Bebauungsverbotszonen (leer ohne Collision-Layer).
+    pub placement_collision: RgbaImage,
     /// Transparente Farmland-Grenzen.
     pub farmland_borders: RgbaImage,
     /// Transparente Farmland-ID-Beschriftungen.
     pub farmland_ids: RgbaImage,
+    /// Transparente Flaechen-, Kaufpreis- und Besitzerangaben unter den Farmland-IDs.
+    pub farmland_prices: RgbaImage,
+    /// Transparente Feldgrenzen mit Feldnummern (und Flaechen, falls aktiviert).
+    pub field_borders: RgbaImage,
+    /// Transparente Strassen-Splines (leer, wenn die Map-i3d keine liefert).
+    pub roads: RgbaImage,
     /// Transparente POI-Marker und Labels.
     pub poi_markers: RgbaImage,
+    /// Transparentes AutoDrive-Netz (leer, wenn kein Netz uebergeben wurde).
+    pub network: RgbaImage,
     /// Transparente Legende.
     pub legend: RgbaImage,
     /// Aus den sichtbaren Layern zusammengesetztes Ergebnisbild.
@@ -38,10 +62,220 @@ pub struct OverviewLayerBundle {
     pub grle_height: u32,
     /// Weltgroesse der Karte in Metern.
     pub map_size: f32,
+    /// Hoehenskala der Map-i3d: Meter fuer den hellsten DEM-Wert.
+    pub height_scale: f32,
     /// Rohe Farmland-ID-Pixel fuer spaetere Editor-Analysen.
     pub farmland_ids_raw: Option<Vec<u8>>,
 }
 
+/// Einzeln exportierbarer Bild-Layer eines [`OverviewLayerBundle`].
+///
+/// Die Reihenfolge von [`OverviewLayer::ALL`] entspricht der Zeichenreihenfolge
+/// beim Zusammensetzen von `combined`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverviewLayer {
+    /// Opakes Terrain-Basisbild inklusive Title-Bar
+    Terrain,
+    /// Wald-Kronendach
+    Forest,
+    /// Gewaesser
+    Water,
+    /// Hillshade-Schattierung
+    Hillshade,
+    /// Hoehenlinien
+    Contours,
+    /// Schraffierte Bebauungsverbotszonen
+    PlacementCollision,
+    /// Farmland-Grenzen
+    FarmlandBorders,
+    /// Farmland-ID-Beschriftungen
+    FarmlandIds,
+    /// Kaufpreis- und Besitzerangaben der Farmlands
+    FarmlandPrices,
+    /// Feldgrenzen mit Feldnummern
+    FieldBorders,
+    /// Strassen-Splines
+    Roads,
+    /// POI-Marker und Labels
+    PoiMarkers,
+    /// AutoDrive-Netz
+    Network,
+    /// Legende
+    Legend,
+}
+
+impl OverviewLayer {
+    /// Alle Layer in Zeichenreihenfolge (Terrain zuerst).
+    pub const ALL: [OverviewLayer; 14] = [
+        Self::Terrain,
+        Self::Forest,
+        Self::Water,
+        Self::Hillshade,
+        Self::Contours,
+        Self::PlacementCollision,
+        Self::FarmlandBorders,
+        Self::FarmlandIds,
+        Self::FarmlandPrices,
+        Self::FieldBorders,
+        Self::Roads,
+        Self::PoiMarkers,
+        Self::Network,
+        Self::Legend,
+    ];
+
+    /// Stabiler Schluessel fuer Dateinamen und Konfigurationen (snake_case).
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Terrain => "terrain",
+            Self::Forest => "forest",
+            Self::Water => "water",
+            Self::Hillshade => "hillshade",
+            Self::Contours => "contours",
+            Self::PlacementCollision => "placement_collision",
+            Self::FarmlandBorders => "farmland_borders",
+            Self::FarmlandIds => "farmland_ids",
+            Self::FarmlandPrices => "farmland_prices",
+            Self::FieldBorders => "field_borders",
+            Self::Roads => "roads",
+            Self::PoiMarkers => "poi_markers",
+            Self::Network => "network",
+            Self::Legend => "legend",
+        }
+    }
+
+    /// Prueft, ob der Layer mit `options` in `combined` sichtbar ist.
+    pub fn is_visible(self, options: &OverviewOptions) -> bool {
+        match self {
+            Self::Terrain => options.terrain,
+            Self::Forest => options.forest,
+            Self::Water => options.water,
+            Self::Hillshade => options.hillshade,
+            Self::Contours => options.contours.is_some(),
+            Self::PlacementCollision => options.placement_collision,
+            Self::FarmlandBorders => options.farmlands,
+            Self::FarmlandIds => options.farmland_ids,
+            Self::FarmlandPrices => {
+                options.area_labels && options.farmland_ids
+                    || options.farmland_prices
+                    || options.farmland_ownership.is_some()
+            }
+            Self::FieldBorders => options.fields,
+            Self::Roads => options.roads,
+            Self::PoiMarkers => options.pois,
+            Self::Network => options.network.is_some(),
+            Self::Legend => options.legend,
+        }
+    }
+
+    /// Fortschrittsstufe, in der der Layer erzeugt wird.
+    pub(crate) const fn stage(self) -> OverviewStage {
+        match self {
+            Self::Terrain => OverviewStage::Terrain,
+            Self::Forest => OverviewStage::Forest,
+            Self::Water => OverviewStage::Water,
+            Self::Hillshade => OverviewStage::Hillshade,
+            Self::Contours => OverviewStage::Contours,
+            Self::PlacementCollision => OverviewStage::PlacementCollision,
+            Self::FarmlandBorders | Self::FarmlandIds | Self::FarmlandPrices => {
+                OverviewStage::Farmlands
+            }
+            Self::FieldBorders => OverviewStage::Fields,
+            Self::Roads => OverviewStage::Roads,
+            Self::PoiMarkers => OverviewStage::Pois,
+            Self::Network => OverviewStage::Network,
+            Self::Legend => OverviewStage::Legend,
+        }
+    }
+}
+
+impl OverviewLayerBundle {
+    /// Liefert das Bild eines einzelnen Layers.
+    pub fn layer(&self, layer: OverviewLayer) -> &RgbaImage {
+        match layer {
+            OverviewLayer::Terrain => &self.terrain,
+            OverviewLayer::Forest => &self.forest,
+            OverviewLayer::Water => &self.water,
+            OverviewLayer::Hillshade => &self.hillshade,
+            OverviewLayer::Contours => &self.contours,
+            OverviewLayer::PlacementCollision => &self.placement_collision,
+            OverviewLayer::FarmlandBorders => &self.farmland_borders,
+            OverviewLayer::FarmlandIds => &self.farmland_ids,
+            OverviewLayer::FarmlandPrices => &self.farmland_prices,
+            OverviewLayer::FieldBorders => &self.field_borders,
+            OverviewLayer::Roads => &self.roads,
+            OverviewLayer::PoiMarkers => &self.poi_markers,
+            OverviewLayer::Network => &self.network,
+            OverviewLayer::Legend => &self.legend,
+        }
+    }
+
+    /// Veraenderbarer Zugriff auf das Bild eines Layers.
+    pub(crate) fn layer_mut(&mut self, layer: OverviewLayer) -> &mut RgbaImage {
+        match layer {
+            OverviewLayer::Terrain => &mut self.terrain,
+            OverviewLayer::Forest => &mut self.forest,
+            OverviewLayer::Water => &mut self.water,
+            OverviewLayer::Hillshade => &mut self.hillshade,
+            OverviewLayer::Contours => &mut self.contours,
+            OverviewLayer::PlacementCollision => &mut self.placement_collision,
+            OverviewLayer::FarmlandBorders => &mut self.farmland_borders,
+            OverviewLayer::FarmlandIds => &mut self.farmland_ids,
+            OverviewLayer::FarmlandPrices => &mut self.farmland_prices,
+            OverviewLayer::FieldBorders => &mut self.field_borders,
+            OverviewLayer::Roads => &mut self.roads,
+            OverviewLayer::PoiMarkers => &mut self.poi_markers,
+            OverviewLayer::Network => &mut self.network,
+            OverviewLayer::Legend => &mut self.legend,
+        }
+    }
+
+    /// Iteriert ueber alle Layer in Zeichenreihenfolge.
+    pub fn layers(&self) -> impl Iterator<Item = (OverviewLayer, &RgbaImage)> {
+        OverviewLayer::ALL
+            .into_iter()
+            .map(|layer| (layer, self.layer(layer)))
+    }
+
+    /// Setzt die fuer `options` sichtbaren Layer neu zu einem Gesamtbild zusammen.
+    ///
+    /// Zeichnet keinen Layer neu; fuer reine Sichtbarkeitswechsel genuegt
+    /// `bundle.combined = bundle.compose(&options)`.
+    pub fn compose(&self, options: &OverviewOptions) -> RgbaImage {
+        let overlays: Vec<(bool, &RgbaImage)> = self
+            .layers()
+            .skip(1)
+            .map(|(layer, image)| (layer.is_visible(options), image))
+            .collect();
+        if OverviewLayer::Terrain.is_visible(options) {
+            compose_layers(&self.terrain, &overlays)
+        } else {
+            compose_layers(
+                &blank_layer(self.terrain.width(), self.terrain.height()),
+                &overlays,
+            )
+        }
+    }
+
+    /// Speichert jeden Layer als eigene PNG-Datei `<prefix>_<key>.png` in `dir`.
+    ///
+    /// Das Verzeichnis muss existieren. Gibt die geschriebenen Pfade in
+    /// Zeichenreihenfolge zurueck.
+    pub fn save_layer_pngs(&self, dir: &Path, prefix: &str) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::with_capacity(OverviewLayer::ALL.len());
+        for (layer, image) in self.layers() {
+            let path = dir.join(format!("{}_{}.png", prefix, layer.key()));
+            image.save(&path).with_context(|| {
+                format!(
+                    "Overview-Layer konnte nicht gespeichert werden: {}",
+                    path.display()
+                )
+            })?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
 /// Setzt Terrain-Basisbild und aktive transparente Layer zu einem Gesamtbild zusammen.
 pub fn compose_layers(terrain: &RgbaImage, layers: &[(bool, &RgbaImage)]) -> RgbaImage {
     let mut combined = terrain.clone();
@@ -70,94 +304,463 @@ pub fn generate_overview_layer_bundle(
     map_info: &MapInfo,
     options: &OverviewOptions,
 ) -> Result<OverviewLayerBundle> {
-    let terrain_base = render_terrain_base(files, map_info)?;
-    let terrain_rgb = render_terrain_with_title(&terrain_base, &map_info.title);
-    let terrain = rgb_to_opaque_rgba(&terrain_rgb);
+    build_layer_bundle(files, map_info, options, &mut ProgressReporter::none())
+}
 
-    let hillshade = if let Some(dem) = load_resized_dem(files, map_info) {
-        render_hillshade_layer(&terrain_base, &terrain_rgb, &map_info.title, &dem)
-    } else {
-        blank_layer(map_info.map_size, map_info.map_size)
+/// Baut das Layer-Bundle und meldet jeden Layer an `progress`.
+pub(crate) fn build_layer_bundle(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+    progress: &mut ProgressReporter<'_>,
+) -> Result<OverviewLayerBundle> {
+    build_layer_bundle_with_sources(files, map_info, options, progress).map(|(bundle, _)| bundle)
+}
+
+/// Baut das Layer-Bundle und behaelt die Zwischenergebnisse fuer spaetere
+/// Teil-Neuberechnungen (siehe [`LayerSources::rerender`]).
+pub(crate) fn build_layer_bundle_with_sources(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+    progress: &mut ProgressReporter<'_>,
+) -> Result<(OverviewLayerBundle, LayerSources)> {
+    let map_size = map_info.map_size;
+    let render_size = options.render_size_for(map_size);
+
+    progress.stage(OverviewStage::Terrain)?;
+    let weight_images = load_weight_images(files, map_info);
+    let terrain_base = render_terrain_base(files, map_info, &weight_images, render_size, options)?;
+    let canvas = LayerCanvas::new(
+        terrain_base,
+        map_info,
+        options,
+        options.output_size_for(map_size),
+    );
+    let terrain = rgb_to_opaque_rgba(&canvas.terrain_with_title);
+    let mut sources = LayerSources::new(canvas, map_size, options);
+
+    progress.stage(OverviewStage::Forest)?;
+    let forest = match vegetation::compute_forest_density(&weight_images, render_size) {
+        Some(density) => sources
+            .canvas
+            .render(|image| vegetation::apply_forest_canopy(image, &density)),
+        None => sources.canvas.blank(),
     };
+    sources.has_forest = forest.pixels().any(|pixel| pixel[3] > 0);
 
-    let farmland_data = load_farmland_data(files, map_info);
-    let farmland_borders = farmland_data
+    progress.stage(OverviewStage::Water)?;
+    sources.dem = load_resized_dem(files, map_info, render_size);
+
+    let water_data = composite::extract_water(&weight_images, sources.dem.as_ref(), render_size);
+    let water = water_data
         .as_ref()
-        .map(|farmlands| {
-            render_farmland_borders_layer(&terrain_base, &terrain_rgb, &map_info.title, farmlands)
+        .map(|water| {
+            sources
+                .canvas
+                .render(|image| composite::draw_water(image, water))
         })
-        .unwrap_or_else(|| blank_layer(map_info.map_size, map_info.map_size));
-    let farmland_ids = farmland_data
+        .unwrap_or_else(|| sources.canvas.blank());
+    sources.has_water = water_data.is_some();
+
+    progress.stage(OverviewStage::Hillshade)?;
+    let hillshade = sources.rerender(OverviewLayer::Hillshade, options);
+
+    progress.stage(OverviewStage::Contours)?;
+    sources.height_field = crate::discovery::find_dem(files, &map_info.data_dir)
+        .and_then(|dem| contours::height_field_from_dem(dem, render_size, sources.height_scale));
+    let contours_layer = sources.rerender(OverviewLayer::Contours, options);
+
+    progress.stage(OverviewStage::PlacementCollision)?;
+    let placement_mask = placement::extract_placement_collision(
+        &crate::discovery::find_placement_collision(files, &map_info.data_dir),
+        render_size,
+    );
+    let placement_layer = placement_mask
+        .as_ref()
+        .map(|mask| {
+            sources
+                .canvas
+                .render(|image| placement::draw_placement_collision(image, mask))
+        })
+        .unwrap_or_else(|| sources.canvas.blank());
+    sources.has_placement_collision = placement_mask.is_some();
+
+    progress.stage(OverviewStage::Farmlands)?;
+    sources.farmland_data = load_farmland_data(files, map_info, render_size);
+    let farmland_borders = sources.rerender(OverviewLayer::FarmlandBorders, options);
+    sources.prices = load_farmland_prices(files, map_info);
+    let farmland_ids = sources.rerender(OverviewLayer::FarmlandIds, options);
+    let farmland_prices_layer = sources.rerender(OverviewLayer::FarmlandPrices, options);
+
+    progress.stage(OverviewStage::Fields)?;
+    sources.field_data = load_field_data(files, map_info);
+    let field_borders = sources.rerender(OverviewLayer::FieldBorders, options);
+
+    progress.stage(OverviewStage::Roads)?;
+    let road_splines = load_road_splines(files, map_info);
+    let roads_layer = road_splines
         .as_ref()
-        .map(|farmlands| {
-            render_farmland_ids_layer(&terrain_base, &terrain_rgb, &map_info.title, farmlands)
+        .map(|splines| {
+            sources
+                .canvas
+                .render(|image| roads::draw_roads(image, splines, map_size))
         })
-        .unwrap_or_else(|| blank_layer(map_info.map_size, map_info.map_size));
+        .unwrap_or_else(|| sources.canvas.blank());
+    sources.has_roads = road_splines.is_some();
 
-    let pois = load_pois(files, map_info);
-    let poi_markers = if pois.is_empty() {
-        blank_layer(map_info.map_size, map_info.map_size)
-    } else {
-        render_poi_markers_layer(&terrain_base, &terrain_rgb, &map_info.title, &pois)
-    };
+    progress.stage(OverviewStage::Pois)?;
+    sources.pois = load_pois(files, map_info, render_size);
+    let poi_markers = sources.rerender(OverviewLayer::PoiMarkers, options);
 
-    let legend_options = OverviewOptions {
-        terrain: true,
-        hillshade: true,
-        farmlands: farmland_data.is_some(),
-        farmland_ids: true,
-        pois: !pois.is_empty(),
-        legend: true,
-    };
-    let legend = render_legend_layer(
-        &terrain_base,
-        &terrain_rgb,
-        &map_info.title,
-        &legend_options,
-    );
+    progress.stage(OverviewStage::Network)?;
+    let network_layer = sources.rerender(OverviewLayer::Network, options);
 
-    let combined_base = if options.terrain {
-        terrain.clone()
-    } else {
-        blank_layer(terrain.width(), terrain.height())
-    };
-    let combined = compose_layers(
-        &combined_base,
-        &[
-            (options.hillshade, &hillshade),
-            (options.farmlands, &farmland_borders),
-            (options.farmland_ids, &farmland_ids),
-            (options.pois, &poi_markers),
-            (options.legend, &legend),
-        ],
-    );
+    progress.stage(OverviewStage::Legend)?;
+    let legend = sources.rerender(OverviewLayer::Legend, options);
 
+    progress.stage(OverviewStage::Finishing)?;
     let (farmland_polygons, grle_width, grle_height, farmland_ids_raw) =
         crate::try_extract_polygons_from_files(files, map_info);
-
-    Ok(OverviewLayerBundle {
+    let mut bundle = OverviewLayerBundle {
         terrain,
+        forest,
+        water,
         hillshade,
+        contours: contours_layer,
+        placement_collision: placement_layer,
         farmland_borders,
         farmland_ids,
+        farmland_prices: farmland_prices_layer,
+        field_borders,
+        roads: roads_layer,
         poi_markers,
+        network: network_layer,
         legend,
-        combined,
+        combined: RgbaImage::new(0, 0),
         farmland_polygons,
         grle_width,
         grle_height,
-        map_size: map_info.map_size as f32,
+        map_size: map_size as f32,
+        height_scale: map_info.height_scale,
         farmland_ids_raw,
-    })
+    };
+    bundle.combined = bundle.compose(options);
+
+    progress.finish()?;
+    Ok((bundle, sources))
 }
 
-fn render_terrain_base(files: &HashMap<String, Vec<u8>>, map_info: &MapInfo) -> Result<RgbImage> {
-    let map_size = map_info.map_size;
+/// Layer, deren Inhalt neben der Terrain-Basis von weiteren Optionen abhaengt.
+///
+/// Nur diese Layer zeichnet [`LayerSources::rerender`] neu; alle anderen
+/// haengen ausschliesslich von den Kartendaten und der Terrain-Basis ab.
+pub(crate) const OPTION_LAYERS: [OverviewLayer; 9] = [
+    OverviewLayer::Hillshade,
+    OverviewLayer::Contours,
+    OverviewLayer::FarmlandBorders,
+    OverviewLayer::FarmlandIds,
+    OverviewLayer::FarmlandPrices,
+    OverviewLayer::FieldBorders,
+    OverviewLayer::PoiMarkers,
+    OverviewLayer::Network,
+    OverviewLayer::Legend,
+];
+
+/// Zwischenergebnisse eines Layer-Bundles, aus denen sich die
+/// [`OPTION_LAYERS`] ohne erneutes Terrain-Compositing neu zeichnen lassen.
+pub(crate) struct LayerSources {
+    canvas: LayerCanvas,
+    map_size: u32,
+    /// `height_scale`, mit dem `height_field` berechnet wurde
+    height_scale: f32,
+    dem: Option<DemImage>,
+    height_field: Option<HeightField>,
+    farmland_data: Option<FarmlandData>,
+    prices: Option<FarmlandPrices>,
+    field_data: Option<FieldData>,
+    has_forest: bool,
+    has_water: bool,
+    has_placement_collision: bool,
+    has_roads: bool,
+    /// POIs in Render-Pixelkoordinaten
+    pois: Vec<Poi>,
+}
+
+impl LayerSources {
+    /// Leere Quellen ueber der fertigen Terrain-Basis; die Daten fuellt
+    /// [`build_layer_bundle_with_sources`] Stufe fuer Stufe.
+    fn new(canvas: LayerCanvas, map_size: u32, options: &OverviewOptions) -> Self {
+        Self {
+            canvas,
+            map_size,
+            height_scale: options.contours.clone().unwrap_or_default().height_scale,
+            dem: None,
+            height_field: None,
+            farmland_data: None,
+            prices: None,
+            field_data: None,
+            has_forest: false,
+            has_water: false,
+            has_placement_collision: false,
+            has_roads: false,
+            pois: Vec::new(),
+        }
+    }
+
+    /// Prueft, ob die Terrain-Basis (Groesse, Palette, Kartenbild, Title-Bar) oder das
+    /// Hoehenraster fuer `options` neu berechnet werden muss.
+    pub(crate) fn needs_rebuild(
+        &self,
+        previous: &OverviewOptions,
+        options: &OverviewOptions,
+    ) -> bool {
+        let map_size = self.map_size;
+        let height_scale = options.contours.clone().unwrap_or_default().height_scale;
+        previous.render_size_for(map_size) != options.render_size_for(map_size)
+            || previous.output_size_for(map_size) != options.output_size_for(map_size)
+            || previous.terrain_palette != options.terrain_palette
+            || previous.map_image != options.map_image
+            || previous.title_bar != options.title_bar
+            || options.title_bar && previous.title_bar_options != options.title_bar_options
+            || height_scale != self.height_scale
+    }
+
+    /// Prueft, ob sich der Inhalt eines [`OPTION_LAYERS`]-Eintrags zwischen
+    /// `previous` und `options` aendert (Sichtbarkeit allein zaehlt nicht).
+    pub(crate) fn is_stale(
+        &self,
+        layer: OverviewLayer,
+        previous: &OverviewOptions,
+        options: &OverviewOptions,
+    ) -> bool {
+        match layer {
+            OverviewLayer::Hillshade => previous.hillshade_params != options.hillshade_params,
+            OverviewLayer::Contours => {
+                previous.contours.clone().unwrap_or_default()
+                    != options.contours.clone().unwrap_or_default()
+            }
+            OverviewLayer::FarmlandBorders => {
+                previous.farmland_ownership != options.farmland_ownership
+            }
+            OverviewLayer::FarmlandIds => self.detail_lines(previous) != self.detail_lines(options),
+            OverviewLayer::FarmlandPrices => {
+                previous.area_labels != options.area_labels
+                    || previous.farmland_ownership != options.farmland_ownership
+            }
+            OverviewLayer::FieldBorders => previous.area_labels != options.area_labels,
+            OverviewLayer::PoiMarkers => previous.poi_clusters != options.poi_clusters,
+            OverviewLayer::Network => previous.network != options.network,
+            OverviewLayer::Legend => self.legend_options(previous) != self.legend_options(options),
+            _ => false,
+        }
+    }
+
+    /// Zeichnet einen der [`OPTION_LAYERS`] fuer `options` neu.
+    pub(crate) fn rerender(&self, layer: OverviewLayer, options: &OverviewOptions) -> RgbaImage {
+        let canvas = &self.canvas;
+        let map_size = self.map_size;
+        match layer {
+            OverviewLayer::Hillshade => match &self.dem {
+                Some(dem) => render_hillshade_layer(canvas, dem, &options.hillshade_params),
+                None => canvas.blank(),
+            },
+            OverviewLayer::Contours => {
+                let contour_options = options.contours.clone().unwrap_or_default();
+                self.height_field
+                    .as_ref()
+                    .map(|field| {
+                        canvas
+                            .render(|image| contours::draw_contours(image, field, &contour_options))
+                    })
+                    .unwrap_or_else(|| canvas.blank())
+            }
+            OverviewLayer::FarmlandBorders => self
+                .farmland_data
+                .as_ref()
+                .map(|farmlands| {
+                    canvas.render(|image| {
+                        if let Some(ownership) = &options.farmland_ownership {
+                            farmland_prices::draw_owned_farmlands(image, farmlands, ownership);
+                        }
+                        composite::draw_farmland_boundaries(image, farmlands);
+                    })
+                })
+                .unwrap_or_else(|| canvas.blank()),
+            OverviewLayer::FarmlandIds => {
+                let detail_lines = self.detail_lines(options);
+                self.farmland_data
+                    .as_ref()
+                    .map(|farmlands| {
+                        canvas.render(|image| {
+                            composite::draw_farmland_ids(image, farmlands, detail_lines)
+                        })
+                    })
+                    .unwrap_or_else(|| canvas.blank())
+            }
+            OverviewLayer::FarmlandPrices => match &self.farmland_data {
+                Some(farmlands)
+                    if options.area_labels
+                        || self.prices.is_some()
+                        || options.farmland_ownership.is_some() =>
+                {
+                    canvas.render(|image| {
+                        farmland_prices::draw_farmland_details(
+                            image,
+                            farmlands,
+                            map_size,
+                            options.area_labels,
+                            self.prices.as_ref(),
+                            options.farmland_ownership.as_ref(),
+                        )
+                    })
+                }
+                _ => canvas.blank(),
+            },
+            OverviewLayer::FieldBorders => self
+                .field_data
+                .as_ref()
+                .map(|field_data| {
+                    canvas.render(|image| {
+                        fields::draw_field_boundaries(image, field_data);
+                        fields::draw_field_numbers(image, field_data);
+                        if options.area_labels {
+                            fields::draw_field_areas(image, field_data, map_size);
+                        }
+                    })
+                })
+                .unwrap_or_else(|| canvas.blank()),
+            OverviewLayer::PoiMarkers if self.pois.is_empty() => canvas.blank(),
+            OverviewLayer::PoiMarkers => canvas.render(|image| {
+                composite::draw_pois_with_labels(image, &self.pois, options.poi_clusters);
+            }),
+            OverviewLayer::Network => match &options.network {
+                Some(network) if !network.is_empty() => {
+                    canvas.render(|image| network::draw_network(image, network, map_size))
+                }
+                _ => canvas.blank(),
+            },
+            OverviewLayer::Legend => {
+                let legend_options = self.legend_options(options);
+                let poi_groups = self.poi_group_lines(options);
+                canvas.render(|image| composite::draw_legend(image, &legend_options, &poi_groups))
+            }
+            _ => unreachable!("{:?} haengt nur von der Terrain-Basis ab", layer),
+        }
+    }
+
+    /// Legendenzeilen der POI-Gruppen, gruppiert in Render-Groesse wie der POI-Layer.
+    fn poi_group_lines(&self, options: &OverviewOptions) -> Vec<String> {
+        let width = self.canvas.terrain_base.width();
+        composite::group_pois(&self.pois, width, options.poi_clusters).legend_lines
+    }
+
+    fn detail_lines(&self, options: &OverviewOptions) -> usize {
+        farmland_prices::detail_line_count(
+            options.area_labels,
+            self.prices.as_ref(),
+            options.farmland_ownership.as_ref(),
+        )
+    }
+
+    /// Optionen fuer die Bundle-Legende: listet alle Layer mit Daten,
+    /// unabhaengig von ihrer Sichtbarkeit in `combined`.
+    fn legend_options(&self, options: &OverviewOptions) -> OverviewOptions {
+        OverviewOptions {
+            terrain: true,
+            hillshade: true,
+            hillshade_params: HillshadeParams::default(),
+            farmlands: self.farmland_data.is_some(),
+            farmland_ids: true,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
+            pois: !self.pois.is_empty(),
+            poi_clusters: options.poi_clusters,
+            legend: true,
+            legend_options: options.legend_options.clone(),
+            title_bar: options.title_bar,
+            title_bar_options: options.title_bar_options.clone(),
+            forest: self.has_forest,
+            water: self.has_water,
+            fields: self.field_data.is_some(),
+            roads: self.has_roads,
+            contours: self
+                .height_field
+                .is_some()
+                .then(|| options.contours.clone().unwrap_or_default()),
+            placement_collision: self.has_placement_collision,
+            network: options.network.clone(),
+            output_size: options.output_size,
+            supersample: options.supersample,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
+        }
+    }
+}
+
+/// Gemeinsamer Zeichenkontext aller Overlay-Layer.
+///
+/// Jeder Layer wird auf eine Kopie des Terrains in Render-Groesse gezeichnet,
+/// auf Ausgabegroesse herunterskaliert und gegen das gleich skalierte Terrain
+/// zu einem transparenten Overlay differenziert.
+struct LayerCanvas {
+    /// Terrain ohne Title-Bar in Render-Groesse
+    terrain_base: RgbImage,
+    /// Terrain mit Title-Bar in Ausgabegroesse
+    terrain_with_title: RgbImage,
+    /// Title-Bar in Render-Groesse (`None` = ausgeblendet)
+    title_bar: Option<TitleBarLayout>,
+    output_size: u32,
+}
+
+impl LayerCanvas {
+    fn new(
+        terrain_base: RgbImage,
+        map_info: &MapInfo,
+        options: &OverviewOptions,
+        output_size: u32,
+    ) -> Self {
+        let title_bar = options.title_bar.then(|| {
+            TitleBarLayout::new(terrain_base.width(), map_info, &options.title_bar_options)
+        });
+        let mut terrain_with_title = terrain_base.clone();
+        if let Some(title_bar) = &title_bar {
+            title_bar.draw(&mut terrain_with_title);
+        }
+        Self {
+            terrain_base,
+            terrain_with_title: composite::downscale_to_output(terrain_with_title, output_size),
+            title_bar,
+            output_size,
+        }
+    }
+
+    fn render(&self, render: impl FnOnce(&mut RgbImage)) -> RgbaImage {
+        let mut rendered = self.terrain_base.clone();
+        render(&mut rendered);
+        if let Some(title_bar) = &self.title_bar {
+            title_bar.draw(&mut rendered);
+        }
+        let rendered = composite::downscale_to_output(rendered, self.output_size);
+        derive_overlay_from_base(&self.terrain_with_title, &rendered)
+    }
+
+    fn blank(&self) -> RgbaImage {
+        blank_layer(self.output_size, self.output_size)
+    }
+}
+
+fn load_weight_images(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+) -> Vec<(String, image::DynamicImage)> {
     let weight_maps = crate::discovery::find_weight_maps(files, &map_info.data_dir);
     let weight_images: Vec<(String, image::DynamicImage)> = weight_maps
         .iter()
         .filter_map(|(path, data)| {
-            let img = image::load_from_memory(data).ok()?;
+            let img = crate::dds::decode_map_raster(data).ok()?;
             let name = std::path::Path::new(path)
                 .file_name()?
                 .to_str()?
@@ -167,31 +770,88 @@ fn render_terrain_base(files: &HashMap<String, Vec<u8>>, map_info: &MapInfo) ->
         .collect();
 
     log::info!("{} Weight-Maps geladen", weight_images.len());
+    weight_images
+}
 
+/// Rendert die Terrain-Basis in Kantenlaenge `size`.
+///
+/// Mit `options.map_image` wird das mitgelieferte Kartenbild verwendet, sonst
+/// (oder wenn es fehlt) werden die Weight-Maps compositet; ohne Weight-Maps
+/// bleibt ein einheitliches Gruen.
+pub(crate) fn render_terrain_base(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    weight_images: &[(String, image::DynamicImage)],
+    size: u32,
+    options: &OverviewOptions,
+) -> Result<RgbImage> {
+    if options.map_image {
+        if let Some(image) = load_map_image(files, map_info, size) {
+            return Ok(image);
+        }
+        log::info!("Kein Kartenbild gefunden – Terrain aus Weight-Maps");
+    }
     if weight_images.is_empty() {
-        Ok(RgbImage::from_pixel(map_size, map_size, Rgb([80, 100, 60])))
+        Ok(RgbImage::from_pixel(size, size, Rgb([80, 100, 60])))
     } else {
-        terrain::composite_terrain_from_images(&weight_images, map_size)
+        terrain::composite_terrain_from_images(
+            weight_images,
+            size,
+            options.terrain_palette.as_ref(),
+        )
     }
 }
 
-fn render_terrain_with_title(terrain_base: &RgbImage, title: &str) -> RgbImage {
-    let mut terrain = terrain_base.clone();
-    composite::draw_title_bar(&mut terrain, title);
-    terrain
+/// Laedt das mitgelieferte Kartenbild als RGB-Bild der Kantenlaenge `size`
+/// (`None` wenn nicht vorhanden/defekt).
+pub(crate) fn load_map_image(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    size: u32,
+) -> Option<RgbImage> {
+    let (path, data) = crate::discovery::find_map_image(files, map_info)?;
+    match crate::dds::decode_map_raster(data) {
+        Ok(image) => {
+            log::info!(
+                "Kartenbild {} ({}x{}) als Terrain-Basis",
+                path,
+                image.width(),
+                image.height()
+            );
+            let image = image.to_rgb8();
+            if image.width() != size || image.height() != size {
+                Some(image::imageops::resize(
+                    &image,
+                    size,
+                    size,
+                    image::imageops::FilterType::Lanczos3,
+                ))
+            } else {
+                Some(image)
+            }
+        }
+        Err(error) => {
+            log::warn!("Kartenbild {} konnte nicht geladen werden: {}", path, error);
+            None
+        }
+    }
 }
 
-fn load_resized_dem(files: &HashMap<String, Vec<u8>>, map_info: &MapInfo) -> Option<GrayImage> {
+/// Laedt das DEM als 16-Bit-Graustufenbild der Kantenlaenge `size` (`None` wenn nicht vorhanden/defekt).
+pub(crate) fn load_resized_dem(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    size: u32,
+) -> Option<DemImage> {
     let dem_data = crate::discovery::find_dem(files, &map_info.data_dir)?;
-    match image::load_from_memory(dem_data) {
+    match crate::dds::decode_map_raster(dem_data) {
         Ok(dem_img) => {
-            let dem_gray = dem_img.to_luma8();
-            let map_size = map_info.map_size;
-            if dem_gray.width() != map_size || dem_gray.height() != map_size {
+            let dem_gray = dem_img.to_luma16();
+            if dem_gray.width() != size || dem_gray.height() != size {
                 Some(image::imageops::resize(
                     &dem_gray,
-                    map_size,
-                    map_size,
+                    size,
+                    size,
                     image::imageops::FilterType::Lanczos3,
                 ))
             } else {
@@ -205,26 +865,98 @@ fn load_resized_dem(files: &HashMap<String, Vec<u8>>, map_info: &MapInfo) -> Opt
     }
 }
 
-fn load_farmland_data(
+pub(crate) fn load_farmland_data(
     files: &HashMap<String, Vec<u8>>,
     map_info: &MapInfo,
+    size: u32,
 ) -> Option<FarmlandData> {
     let (path, data) = crate::discovery::find_farmlands(files, &map_info.data_dir)?;
-    if !path.ends_with(".grle") {
-        log::info!("Farmlands als PNG gefunden – grenzlose Variante nicht implementiert");
+    match composite::extract_farmland_boundaries(path, data, size) {
+        Ok(farmlands) => Some(farmlands),
+        Err(error) => {
+            log::warn!("Farmland-Verarbeitung fehlgeschlagen: {}", error);
+            None
+        }
+    }
+}
+
+/// Laedt die Kaufpreise aus der `farmlands.xml` der Map.
+///
+/// Gibt `None` zurueck, wenn die Map keine `farmlands.xml` referenziert oder sie ungueltig ist.
+pub(crate) fn load_farmland_prices(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+) -> Option<FarmlandPrices> {
+    let farmlands_path = map_info.farmlands_path.as_ref()?;
+    let Some(data) = files.get(farmlands_path.as_str()) else {
+        log::info!("farmlands.xml nicht gefunden: {}", farmlands_path);
         return None;
+    };
+
+    match FarmlandPrices::from_farmlands_xml(data) {
+        Ok(prices) => Some(prices),
+        Err(error) => {
+            log::warn!("Farmland-Preise nicht lesbar: {}", error);
+            None
+        }
     }
+}
 
-    match composite::extract_farmland_boundaries(data, map_info.map_size) {
-        Ok(farmlands) => Some(farmlands),
+/// Laedt die Felder aus der Ground-Density-Map und nummeriert sie nach Farmland.
+///
+/// Ohne `infoLayer_farmlands` bleiben die Felder unbeschriftet, da die
+/// CCL-Reihenfolge keine echte Feldnummer ist.
+pub(crate) fn load_field_data(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+) -> Option<FieldData> {
+    let (_, data) = crate::discovery::find_ground_gdm(files, &map_info.data_dir)?;
+    let mut field_data = match fields::extract_field_data(data) {
+        Ok(field_data) => field_data,
         Err(error) => {
-            log::warn!("Farmland-Verarbeitung fehlgeschlagen: {}", error);
+            log::warn!("Feld-Verarbeitung fehlgeschlagen: {}", error);
+            return None;
+        }
+    };
+
+    match crate::discovery::find_farmlands(files, &map_info.data_dir) {
+        Some((path, data)) => match composite::decode_farmland_ids(path, data) {
+            Ok(farmland_ids) => field_data.assign_farmland_numbers(&farmland_ids),
+            Err(error) => log::warn!("Feldnummern nicht ableitbar: {}", error),
+        },
+        None => log::info!("Keine Farmland-Daten – Felder bleiben ohne Nummer"),
+    }
+    Some(field_data)
+}
+
+/// Laedt die Strassen-Splines aus der Map-i3d.
+///
+/// Gibt `None` zurueck, wenn keine i3d vorliegt oder sie keine Strassen-Splines enthaelt.
+pub(crate) fn load_road_splines(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+) -> Option<Vec<RoadSpline>> {
+    let i3d_path = map_info.i3d_path.as_ref()?;
+    let Some(data) = files.get(i3d_path.as_str()) else {
+        log::info!("Map-i3d nicht gefunden: {}", i3d_path);
+        return None;
+    };
+
+    match roads::extract_road_splines(data) {
+        Ok(splines) if !splines.is_empty() => Some(splines),
+        Ok(_) => None,
+        Err(error) => {
+            log::warn!("Strassen-Extraktion fehlgeschlagen: {}", error);
             None
         }
     }
 }
 
-fn load_pois(files: &HashMap<String, Vec<u8>>, map_info: &MapInfo) -> Vec<Poi> {
+pub(crate) fn load_pois(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    size: u32,
+) -> Vec<Poi> {
     let Some(placeables_path) = &map_info.placeables_path else {
         return Vec::new();
     };
@@ -234,85 +966,25 @@ fn load_pois(files: &HashMap<String, Vec<u8>>, map_info: &MapInfo) -> Vec<Poi> {
         return Vec::new();
     };
 
-    composite::extract_pois(xml_data, map_info.map_size)
+    composite::extract_pois(xml_data, map_info.map_size, size)
 }
 
 fn render_hillshade_layer(
-    terrain_base: &RgbImage,
-    terrain_with_title: &RgbImage,
-    title: &str,
-    dem: &GrayImage,
+    canvas: &LayerCanvas,
+    dem: &DemImage,
+    params: &HillshadeParams,
 ) -> RgbaImage {
-    let params = HillshadeParams::default();
-    match hillshade::compute_hillshade(dem, &params) {
-        Ok(hillshade_values) => {
-            render_layer_from_renderer(terrain_base, terrain_with_title, title, |image| {
-                hillshade::apply_hillshade(image.as_mut(), &hillshade_values, params.blend_factor)
-            })
-        }
+    match hillshade::compute_hillshade(dem, params) {
+        Ok(hillshade_values) => canvas.render(|image| {
+            hillshade::apply_hillshade(image.as_mut(), &hillshade_values, params.blend_factor)
+        }),
         Err(error) => {
             log::warn!("Hillshade-Berechnung fehlgeschlagen: {}", error);
-            blank_layer(terrain_base.width(), terrain_base.height())
+            canvas.blank()
         }
     }
 }
 
-fn render_farmland_borders_layer(
-    terrain_base: &RgbImage,
-    terrain_with_title: &RgbImage,
-    title: &str,
-    farmlands: &FarmlandData,
-) -> RgbaImage {
-    render_layer_from_renderer(terrain_base, terrain_with_title, title, |image| {
-        composite::draw_farmland_boundaries(image, farmlands);
-    })
-}
-
-fn render_farmland_ids_layer(
-    terrain_base: &RgbImage,
-    terrain_with_title: &RgbImage,
-    title: &str,
-    farmlands: &FarmlandData,
-) -> RgbaImage {
-    render_layer_from_renderer(terrain_base, terrain_with_title, title, |image| {
-        composite::draw_farmland_ids(image, farmlands);
-    })
-}
-
-fn render_poi_markers_layer(
-    terrain_base: &RgbImage,
-    terrain_with_title: &RgbImage,
-    title: &str,
-    pois: &[Poi],
-) -> RgbaImage {
-    render_layer_from_renderer(terrain_base, terrain_with_title, title, |image| {
-        composite::draw_pois_with_labels(image, pois);
-    })
-}
-
-fn render_legend_layer(
-    terrain_base: &RgbImage,
-    terrain_with_title: &RgbImage,
-    title: &str,
-    options: &OverviewOptions,
-) -> RgbaImage {
-    render_layer_from_renderer(terrain_base, terrain_with_title, title, |image| {
-        composite::draw_legend(image, options);
-    })
-}
-
-fn render_layer_from_renderer(
-    terrain_base: &RgbImage,
-    terrain_with_title: &RgbImage,
-    title: &str,
-    render: impl FnOnce(&mut RgbImage),
-) -> RgbaImage {
-    let mut rendered = terrain_base.clone();
-    render(&mut rendered);
-    composite::draw_title_bar(&mut rendered, title);
-    derive_overlay_from_base(terrain_with_title, &rendered)
-}
-
 fn derive_overlay_from_base(base: &RgbImage, rendered: &RgbImage) -> RgbaImage {
     debug_assert_eq!(base.dimensions(), rendered.dimensions());
 
@@ -407,9 +1079,8 @@ fn blend_pixel(dst: Rgba<u8>, src: Rgba<u8>) -> Rgba<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use image::{DynamicImage, ImageFormat};
+    use image::{DynamicImage, GrayImage, ImageFormat};
     use std::io::{Cursor, Write};
-    use std::path::{Path, PathBuf};
     use std::time::{SystemTime, UNIX_EPOCH};
 
     struct TempDirGuard {
@@ -530,10 +1201,30 @@ mod tests {
         let options = OverviewOptions {
             terrain: true,
             hillshade: true,
+            hillshade_params: HillshadeParams::default(),
             farmlands: false,
             farmland_ids: false,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
             pois: false,
+            poi_clusters: true,
             legend: false,
+            legend_options: crate::LegendOptions::default(),
+            title_bar: true,
+            title_bar_options: crate::TitleBarOptions::default(),
+            forest: false,
+            water: false,
+            fields: false,
+            roads: false,
+            contours: None,
+            placement_collision: false,
+            network: None,
+            output_size: None,
+            supersample: 1,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
         };
 
         let bundle = crate::generate_overview_layer_bundle_from_zip(
@@ -543,15 +1234,42 @@ mod tests {
         .expect("Layer-Bundle aus Test-ZIP muss erzeugt werden");
 
         assert_eq!(bundle.terrain.dimensions(), (32, 32));
+        assert_eq!(bundle.forest.dimensions(), (32, 32));
+        assert!(bundle.forest.pixels().all(|pixel| pixel[3] == 0));
+        assert_eq!(bundle.water.dimensions(), (32, 32));
         assert_eq!(bundle.hillshade.dimensions(), (32, 32));
+        assert_eq!(bundle.contours.dimensions(), (32, 32));
+        assert_eq!(bundle.placement_collision.dimensions(), (32, 32));
+        assert!(bundle
+            .placement_collision
+            .pixels()
+            .all(|pixel| pixel[3] == 0));
         assert_eq!(bundle.farmland_borders.dimensions(), (32, 32));
         assert_eq!(bundle.farmland_ids.dimensions(), (32, 32));
+        assert_eq!(bundle.field_borders.dimensions(), (32, 32));
+        assert!(bundle.field_borders.pixels().all(|pixel| pixel[3] == 0));
+        assert_eq!(bundle.roads.dimensions(), (32, 32));
+        assert!(bundle.roads.pixels().all(|pixel| pixel[3] == 0));
         assert_eq!(bundle.poi_markers.dimensions(), (32, 32));
+        assert_eq!(bundle.network.dimensions(), (32, 32));
+        assert!(bundle.network.pixels().all(|pixel| pixel[3] == 0));
         assert_eq!(bundle.legend.dimensions(), (32, 32));
         assert_eq!(bundle.combined.dimensions(), (32, 32));
         assert!(bundle.terrain.pixels().all(|pixel| pixel[3] == 255));
         assert!(bundle.hillshade.pixels().any(|pixel| pixel[3] > 0));
         assert!(bundle.poi_markers.pixels().all(|pixel| pixel[3] == 0));
         assert_eq!(bundle.farmland_ids_raw, Some(vec![0, 1, 1, 0]));
+        // PNG-Farmlands liefern Grenzen wie GRLE-Farmlands
+        assert!(bundle.farmland_borders.pixels().any(|pixel| pixel[3] > 0));
+
+        let written = bundle
+            .save_layer_pngs(temp_dir.path(), "overview")
+            .expect("Layer-PNGs muessen geschrieben werden");
+        assert_eq!(written.len(), OverviewLayer::ALL.len());
+        assert!(written[0].ends_with("overview_terrain.png"));
+        let reloaded = image::open(temp_dir.path().join("overview_hillshade.png"))
+            .expect("Hillshade-PNG muss lesbar sein")
+            .to_rgba8();
+        assert_eq!(&reloaded, bundle.layer(OverviewLayer::Hillshade));
     }
 }
@@ -2,10 +2,20 @@
 //!
 //! Generiert aus einem FS25 Map-Mod-ZIP eine detaillierte Uebersichtskarte:
 //! - Terrain-Compositing aus Weight-Maps
+//! - Wald-Kronendach aus den Wald-Weight-Maps
 //! - Hillshade aus DEM (Digital Elevation Model)
-//! - Farmland-Grenzen und ID-Labels
+//! - Eigenstaendiges Reliefbild des DEM (Schattierung oder Hoehenfarben)
+//! - Hoehenlinien mit Hoehenbeschriftung aus dem DEM
+//! - Schraffierte Bebauungsverbotszonen (`infoLayer_placementCollision`)
+//! - Farmland-Grenzen und ID-Labels (optional mit Kaufpreis und Besitzer)
+//! - Feldgrenzen und Feldnummern (aus `densityMap_ground.gdm`)
+//! - Strassen als Vektor-Linien (Splines aus der Map-i3d)
 //! - POI-Marker mit Beschriftung
+//! - AutoDrive-Netz (Verbindungen, Richtungsfarben, Marker)
 //! - Legende und Titel-Bar
+//! - SVG-Export mit Vektor-Overlays ueber eingebettetem Raster-Terrain
+//! - World-File (`.pgw`) mit der Pixel→Welt-Transformation fuer GIS und Editor
+//! - Slippy-Map-Kachelpyramide (`z/x/y.png`) fuer interaktive Web-Karten
 //!
 //! # Beispiel
 //! ```no_run
@@ -25,30 +35,75 @@
 // ausschliesslich ueber die `pub use`-Reexporte unten sowie die freien Funktionen in
 // diesem lib.rs — analog zum Muster in `fs25_auto_drive_render_wgpu` (private Module,
 // selektive Reexporte).
+mod cache;
 mod composite;
+mod contours;
+mod dds;
 mod discovery;
+mod draw;
 mod farmland;
+mod farmland_prices;
+mod fields;
 mod gdm;
 mod grle;
+mod heightmap;
 mod hillshade;
 mod layer_bundle;
+mod network;
 mod palette;
+mod placement;
+mod progress;
+mod relief;
+mod roads;
+mod session;
+mod svg;
 mod terrain;
 mod text;
+mod tiles;
+mod vegetation;
+mod world_file;
 
 use anyhow::{Context, Result};
 use image::{DynamicImage, RgbImage};
 use std::collections::{HashMap, HashSet};
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Cursor, Read, Seek};
 use std::path::Path;
 
-pub use composite::{OverviewOptions, Poi};
+use progress::ProgressReporter;
+
+pub use cache::MapDataCache;
+pub use composite::{
+    LegendOptions, LegendPosition, OverviewOptions, Poi, TitleBarOptions, MAX_SUPERSAMPLE,
+};
+pub use contours::{draw_contours, height_field_from_dem, ContourOptions, HeightField};
 pub use discovery::MapInfo;
 pub use farmland::{
     extract_farmland_polygons, extract_farmland_polygons_from_ids, extract_field_polygons_by_ccl,
     extract_field_type_polygons_from_ids, FarmlandPolygon,
 };
-pub use layer_bundle::{compose_layers, generate_overview_layer_bundle, OverviewLayerBundle};
+pub use farmland_prices::{
+    draw_farmland_details, draw_owned_farmlands, FarmlandOwnership, FarmlandPrices,
+};
+pub use gdm::{decode_gdm, decode_gdm_layer, read_gdm_info, GdmImage, GdmInfo, GdmLayer};
+pub use grle::{decode_grle, encode_grle, encode_grle_with_header, GrleImage};
+pub use heightmap::{
+    parse_terrain_info, terrain_height_scale, MapHeightmap, TerrainInfo, DEFAULT_HEIGHT_SCALE,
+};
+pub use hillshade::HillshadeParams;
+pub use layer_bundle::{
+    compose_layers, generate_overview_layer_bundle, OverviewLayer, OverviewLayerBundle,
+};
+pub use network::{
+    draw_network, AutoDriveNetwork, NetworkConnection, NetworkDirection, NetworkMarker, NetworkNode,
+};
+pub use palette::{PaletteRule, TerrainPalette};
+pub use progress::{OverviewCancelled, OverviewProgress, OverviewStage};
+pub use relief::{render_relief, ReliefStyle};
+pub use roads::{draw_roads, extract_road_splines, RoadSpline};
+pub use session::OverviewSession;
+pub use svg::generate_overview_svg;
+pub use tiles::{max_zoom_for, write_tile_pyramid, TilePyramid, TILE_SIZE};
+pub use world_file::{save_with_world_file, WorldFile};
 
 /// Quelle fuer die Feldpolygon-Erkennung beim Generieren der Uebersichtskarte.
 ///
@@ -99,16 +154,39 @@ pub struct OverviewResult {
 /// (`./`-Praefix entfernt, `\\` nach `/`), damit verschachtelte Archive robust
 /// verarbeitet werden.
 ///
+/// Statt eines ZIPs kann `zip_path` auch auf einen entpackten Mod-Ordner
+/// bzw. ein Map-Projekt in Arbeit zeigen; die Discovery laeuft dann ueber
+/// den Verzeichnisbaum.
+///
 /// # Parameter
-/// - `zip_path`: Pfad zum ZIP-Archiv
+/// - `zip_path`: Pfad zum ZIP-Archiv oder zum entpackten Map-Mod-Ordner
 /// - `options`: Steuerung welche Layer gezeichnet werden
 ///
 /// # Rueckgabe
 /// RGB-Bild als `image::RgbImage`
 pub fn generate_overview_from_zip(zip_path: &str, options: &OverviewOptions) -> Result<RgbImage> {
+    generate_overview_from_zip_with_progress(zip_path, options, &mut |_| true)
+}
+
+/// Wie [`generate_overview_from_zip`], meldet aber jeden Arbeitsschritt an `progress`.
+///
+/// Der Callback erhaelt Stufe und geschaetzten Gesamtfortschritt; gibt er
+/// `false` zurueck, bricht die Generierung vor dem naechsten Schritt mit
+/// [`OverviewCancelled`] ab.
+pub fn generate_overview_from_zip_with_progress(
+    zip_path: &str,
+    options: &OverviewOptions,
+    progress: &mut dyn FnMut(OverviewProgress) -> bool,
+) -> Result<RgbImage> {
     log::info!("Lade Map-Mod-ZIP: {}", zip_path);
+    let mut progress = ProgressReporter::new(progress);
 
     // 1. Discovery-relevante XML-Dateien selektiv laden
+    progress.stage(OverviewStage::Discovery)?;
+    if let Some((map_info, files)) = load_cached_map_files(zip_path, options)? {
+        progress.stage(OverviewStage::ReadingArchive)?;
+        return render_overview(&files, &map_info, options, &mut progress);
+    }
     let discovery_files = extract_zip_for_map_discovery(zip_path).with_context(|| {
         format!(
             "Selektive Discovery-Extraktion fehlgeschlagen: {}",
@@ -127,10 +205,59 @@ pub fn generate_overview_from_zip(zip_path: &str, options: &OverviewOptions) ->
     );
 
     // 3. Nur fuer die Overview benoetigte Daten laden
+    progress.stage(OverviewStage::ReadingArchive)?;
     let files = extract_zip_for_overview(zip_path, &map_info, options)
         .with_context(|| format!("Selektive Overview-Extraktion fehlgeschlagen: {}", zip_path))?;
 
-    generate_overview(&files, &map_info, options)
+    render_overview(&files, &map_info, options, &mut progress)
+}
+
+/// Erkennt die Kartenstruktur eines Map-Mod-ZIPs (oder entpackten Ordners).
+///
+/// Liest nur die Discovery-relevanten Dateien; nuetzlich, um vor der
+/// Generierung Titel und Kartengroesse zu kennen oder Nicht-Karten-Mods
+/// auszusortieren.
+pub fn discover_map_from_zip(zip_path: &str) -> Result<MapInfo> {
+    let files = extract_zip_for_map_discovery(zip_path).with_context(|| {
+        format!(
+            "Selektive Discovery-Extraktion fehlgeschlagen: {}",
+            zip_path
+        )
+    })?;
+    discovery::discover_map(&files)
+        .with_context(|| format!("Map-Discovery fehlgeschlagen: {}", zip_path))
+}
+
+/// Extrahiert das DEM eines Map-Mods mit Weltgroesse und Hoehenskala.
+///
+/// Weltgroesse, Hoehenskala und Terrain-Versatz stammen aus dem
+/// `TerrainTransformGroup` der Map-i3d (siehe [`MapInfo`]); ohne i3d gelten
+/// die Kartengroesse der Map-Config und [`DEFAULT_HEIGHT_SCALE`]. Das DEM darf
+/// als PNG, DDS oder GDM vorliegen. Gelesen werden nur Discovery-Dateien,
+/// DEM und i3d.
+pub fn extract_heightmap_from_zip(zip_path: &str) -> Result<MapHeightmap> {
+    let map_info = discover_map_from_zip(zip_path)?;
+    let files = extract_zip_for_heightmap(zip_path, &map_info)
+        .with_context(|| format!("Selektive DEM-Extraktion fehlgeschlagen: {}", zip_path))?;
+
+    let dem_data = discovery::find_dem(&files, &map_info.data_dir)
+        .with_context(|| format!("Kein DEM in {} gefunden", map_info.data_dir))?;
+    let dem = dds::decode_map_raster(dem_data).context("DEM konnte nicht dekodiert werden")?;
+
+    log::info!(
+        "DEM extrahiert: {}x{} Pixel, Karte {} m, heightScale {}",
+        dem.width(),
+        dem.height(),
+        map_info.map_size,
+        map_info.height_scale
+    );
+
+    Ok(MapHeightmap {
+        dem,
+        map_size: map_info.map_size,
+        height_scale: map_info.height_scale,
+        center_offset: map_info.terrain_offset,
+    })
 }
 
 /// Generiert eine Overview-Map aus bereits extrahierten Dateien.
@@ -141,15 +268,186 @@ pub fn generate_overview(
     files: &HashMap<String, Vec<u8>>,
     map_info: &MapInfo,
     options: &OverviewOptions,
+) -> Result<RgbImage> {
+    render_overview(files, map_info, options, &mut ProgressReporter::none())
+}
+
+/// Wie [`generate_overview`], meldet aber jeden Arbeitsschritt an `progress`.
+///
+/// Gibt der Callback `false` zurueck, endet die Generierung mit [`OverviewCancelled`].
+pub fn generate_overview_with_progress(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+    progress: &mut dyn FnMut(OverviewProgress) -> bool,
+) -> Result<RgbImage> {
+    render_overview(
+        files,
+        map_info,
+        options,
+        &mut ProgressReporter::new(progress),
+    )
+}
+
+fn render_overview(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+    progress: &mut ProgressReporter<'_>,
 ) -> Result<RgbImage> {
     let map_size = map_info.map_size;
+    // Alle Raster-Layer entstehen in Render-Groesse; Weltkoordinaten bleiben `map_size`
+    let render_size = options.render_size_for(map_size);
+
+    // 3.–7. Terrain, Wald, Gewaesser, Hillshade, Hoehenlinien, Bebauungsverbotszonen
+    let mut image = render_raster_base(files, map_info, options, render_size, progress)?;
+
+    // 8. Farmland-Grenzen, IDs, Preise + Besitzer
+    progress.stage(OverviewStage::Farmlands)?;
+    let farmland_areas = options.area_labels && options.farmland_ids;
+    let farmland_details =
+        farmland_areas || options.farmland_prices || options.farmland_ownership.is_some();
+    if options.farmlands || options.farmland_ids || farmland_details {
+        if let Some((path, data)) = discovery::find_farmlands(files, &map_info.data_dir) {
+            match composite::extract_farmland_boundaries(path, data, render_size) {
+                Ok(farmlands) => {
+                    if options.farmlands {
+                        if let Some(ownership) = &options.farmland_ownership {
+                            farmland_prices::draw_owned_farmlands(
+                                &mut image, &farmlands, ownership,
+                            );
+                        }
+                        composite::draw_farmland_boundaries(&mut image, &farmlands);
+                        log::info!("Farmland-Grenzen gezeichnet");
+                    }
+                    let prices = options
+                        .farmland_prices
+                        .then(|| layer_bundle::load_farmland_prices(files, map_info))
+                        .flatten();
+                    if options.farmland_ids {
+                        let detail_lines = farmland_prices::detail_line_count(
+                            farmland_areas,
+                            prices.as_ref(),
+                            options.farmland_ownership.as_ref(),
+                        );
+                        composite::draw_farmland_ids(&mut image, &farmlands, detail_lines);
+                        log::info!("Farmland-IDs gezeichnet");
+                    }
+                    if farmland_details {
+                        farmland_prices::draw_farmland_details(
+                            &mut image,
+                            &farmlands,
+                            map_size,
+                            farmland_areas,
+                            prices.as_ref(),
+                            options.farmland_ownership.as_ref(),
+                        );
+                        log::info!("Farmland-Flaechen/-Preise/-Besitzer gezeichnet");
+                    }
+                }
+                Err(e) => log::warn!("Farmland-Verarbeitung fehlgeschlagen: {}", e),
+            }
+        } else {
+            log::info!("Keine Farmland-Daten gefunden");
+        }
+    }
+
+    // 9. Feldgrenzen + Feldnummern
+    progress.stage(OverviewStage::Fields)?;
+    if options.fields {
+        if let Some(field_data) = layer_bundle::load_field_data(files, map_info) {
+            fields::draw_field_boundaries(&mut image, &field_data);
+            fields::draw_field_numbers(&mut image, &field_data);
+            if options.area_labels {
+                fields::draw_field_areas(&mut image, &field_data, map_size);
+            }
+            log::info!("{} Feldgrenzen gezeichnet", field_data.polygons.len());
+        } else {
+            log::info!("Keine Felddaten gefunden – Feldgrenzen uebersprungen");
+        }
+    }
+
+    // 10. Strassen
+    progress.stage(OverviewStage::Roads)?;
+    if options.roads {
+        match layer_bundle::load_road_splines(files, map_info) {
+            Some(splines) => {
+                roads::draw_roads(&mut image, &splines, map_size);
+                log::info!("{} Strassen-Splines gezeichnet", splines.len());
+            }
+            None => log::info!("Keine Strassen-Splines gefunden – Strassen uebersprungen"),
+        }
+    }
+
+    // 11. POIs
+    progress.stage(OverviewStage::Pois)?;
+    let mut poi_groups = Vec::new();
+    if options.pois
+        && let Some(placeables_path) = &map_info.placeables_path
+    {
+        if let Some(xml_data) = files.get(placeables_path.as_str()) {
+            let pois = composite::extract_pois(xml_data, map_size, render_size);
+            if !pois.is_empty() {
+                poi_groups =
+                    composite::draw_pois_with_labels(&mut image, &pois, options.poi_clusters);
+                log::info!(
+                    "{} POIs gezeichnet ({} Gruppen)",
+                    pois.len(),
+                    poi_groups.len()
+                );
+            }
+        } else {
+            log::info!("placeables.xml nicht gefunden: {}", placeables_path);
+        }
+    }
 
+    // 12. AutoDrive-Netz
+    progress.stage(OverviewStage::Network)?;
+    if let Some(network) = &options.network {
+        network::draw_network(&mut image, network, map_size);
+        log::info!(
+            "AutoDrive-Netz gezeichnet: {} Nodes, {} Verbindungen",
+            network.nodes.len(),
+            network.connections.len()
+        );
+    }
+
+    // 13. Legende
+    progress.stage(OverviewStage::Legend)?;
+    if options.legend {
+        composite::draw_legend(&mut image, options, &poi_groups);
+        log::info!("Legende gezeichnet");
+    }
+
+    // 14. Titel-Bar
+    composite::draw_title_bar(&mut image, map_info, options);
+
+    // 15. Supersampling aufloesen
+    progress.stage(OverviewStage::Finishing)?;
+    let image = composite::downscale_to_output(image, options.output_size_for(map_size));
+    progress.finish()?;
+    Ok(image)
+}
+
+/// Rendert die flaechigen Raster-Layer (Terrain, Wald, Gewaesser, Hillshade,
+/// Hoehenlinien, Bebauungsverbotszonen) in Render-Groesse, ohne
+/// Vektor-Overlays und Titel.
+///
+/// Gemeinsame Basis von [`generate_overview`] und [`generate_overview_svg`].
+pub(crate) fn render_raster_base(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+    render_size: u32,
+    progress: &mut ProgressReporter<'_>,
+) -> Result<RgbImage> {
     // 3. Weight-Maps laden und compositen
+    progress.stage(OverviewStage::Terrain)?;
     let weight_maps = discovery::find_weight_maps(files, &map_info.data_dir);
     let weight_images: Vec<(String, DynamicImage)> = weight_maps
         .iter()
         .filter_map(|(path, data)| {
-            let img = image::load_from_memory(data).ok()?;
+            let img = dds::decode_map_raster(data).ok()?;
             let name = Path::new(path).file_name()?.to_str()?.to_string();
             Some((name, img))
         })
@@ -157,98 +455,109 @@ pub fn generate_overview(
 
     log::info!("{} Weight-Maps geladen", weight_images.len());
 
-    let mut image = if weight_images.is_empty() {
-        // Fallback: einheitliches Gruen
-        RgbImage::from_pixel(map_size, map_size, image::Rgb([80, 100, 60]))
+    let mut image =
+        layer_bundle::render_terrain_base(files, map_info, &weight_images, render_size, options)?;
+
+    // 4. Wald-Kronendach (vor dem Hillshade, damit Waelder mitschattiert werden)
+    progress.stage(OverviewStage::Forest)?;
+    if options.forest {
+        if vegetation::draw_forest_layer(&mut image, &weight_images) {
+            log::info!("Wald-Kronendach gezeichnet");
+        } else {
+            log::info!("Keine Wald-Weight-Maps gefunden – Wald-Layer uebersprungen");
+        }
+    }
+
+    // 5. Gewaesser + Hillshade (beide nutzen das DEM)
+    let dem = if options.hillshade || options.water {
+        layer_bundle::load_resized_dem(files, map_info, render_size)
     } else {
-        terrain::composite_terrain_from_images(&weight_images, map_size)?
+        None
     };
 
-    // 4. Hillshade
+    progress.stage(OverviewStage::Water)?;
+    if options.water {
+        match composite::extract_water(&weight_images, dem.as_ref(), render_size) {
+            Some(water) => {
+                composite::draw_water(&mut image, &water);
+                log::info!("Gewaesser gezeichnet");
+            }
+            None => log::info!("Keine Wasser-Weight-Maps gefunden – Gewaesser uebersprungen"),
+        }
+    }
+
+    progress.stage(OverviewStage::Hillshade)?;
     if options.hillshade {
-        if let Some(dem_data) = discovery::find_dem(files, &map_info.data_dir) {
-            match image::load_from_memory(dem_data) {
-                Ok(dem_img) => {
-                    let dem_gray = dem_img.to_luma8();
-                    let dem_resized =
-                        if dem_gray.width() != map_size || dem_gray.height() != map_size {
-                            image::imageops::resize(
-                                &dem_gray,
-                                map_size,
-                                map_size,
-                                image::imageops::FilterType::Lanczos3,
-                            )
-                        } else {
-                            dem_gray
-                        };
-                    let params = hillshade::HillshadeParams::default();
-                    match hillshade::compute_hillshade(&dem_resized, &params) {
-                        Ok(hs) => {
-                            hillshade::apply_hillshade(image.as_mut(), &hs, params.blend_factor);
-                            log::info!("Hillshade angewendet");
-                        }
-                        Err(e) => log::warn!("Hillshade-Berechnung fehlgeschlagen: {}", e),
-                    }
+        if let Some(dem) = &dem {
+            let params = &options.hillshade_params;
+            match hillshade::compute_hillshade(dem, params) {
+                Ok(hs) => {
+                    hillshade::apply_hillshade(image.as_mut(), &hs, params.blend_factor);
+                    log::info!("Hillshade angewendet");
                 }
-                Err(e) => log::warn!("DEM konnte nicht geladen werden: {}", e),
+                Err(e) => log::warn!("Hillshade-Berechnung fehlgeschlagen: {}", e),
             }
         } else {
             log::info!("Kein DEM gefunden – Hillshade uebersprungen");
         }
     }
 
-    // 5. Farmland-Grenzen + IDs
-    if options.farmlands || options.farmland_ids {
-        if let Some((path, data)) = discovery::find_farmlands(files, &map_info.data_dir) {
-            let is_grle = path.ends_with(".grle");
-            if is_grle {
-                match composite::extract_farmland_boundaries(data, map_size) {
-                    Ok(farmlands) => {
-                        if options.farmlands {
-                            composite::draw_farmland_boundaries(&mut image, &farmlands);
-                            log::info!("Farmland-Grenzen gezeichnet");
-                        }
-                        if options.farmland_ids {
-                            composite::draw_farmland_ids(&mut image, &farmlands);
-                            log::info!("Farmland-IDs gezeichnet");
-                        }
-                    }
-                    Err(e) => log::warn!("Farmland-Verarbeitung fehlgeschlagen: {}", e),
-                }
-            } else {
-                // PNG-basierte Farmlands (bereits dekodiert)
-                log::info!("Farmlands als PNG gefunden – grenzlose Variante nicht implementiert");
-            }
+    // 6. Hoehenlinien
+    progress.stage(OverviewStage::Contours)?;
+    if let Some(contour_options) = &options.contours {
+        let field = discovery::find_dem(files, &map_info.data_dir).and_then(|dem| {
+            contours::height_field_from_dem(dem, render_size, contour_options.height_scale)
+        });
+        if let Some(field) = field {
+            contours::draw_contours(&mut image, &field, contour_options);
+            log::info!("Hoehenlinien gezeichnet");
         } else {
-            log::info!("Keine Farmland-Daten gefunden");
+            log::info!("Kein DEM gefunden – Hoehenlinien uebersprungen");
         }
     }
 
-    // 6. POIs
-    if options.pois
-        && let Some(placeables_path) = &map_info.placeables_path
-    {
-        if let Some(xml_data) = files.get(placeables_path.as_str()) {
-            let pois = composite::extract_pois(xml_data, map_size);
-            if !pois.is_empty() {
-                composite::draw_pois_with_labels(&mut image, &pois);
-                log::info!("{} POIs gezeichnet", pois.len());
+    // 7. Bebauungsverbotszonen
+    progress.stage(OverviewStage::PlacementCollision)?;
+    if options.placement_collision {
+        let layers = discovery::find_placement_collision(files, &map_info.data_dir);
+        match placement::extract_placement_collision(&layers, render_size) {
+            Some(mask) => {
+                placement::draw_placement_collision(&mut image, &mask);
+                log::info!("Bebauungsverbotszonen gezeichnet");
             }
-        } else {
-            log::info!("placeables.xml nicht gefunden: {}", placeables_path);
+            None => log::info!(
+                "Kein Placement-Collision-Layer gefunden – Bebauungsverbotszonen uebersprungen"
+            ),
         }
     }
 
-    // 7. Legende
-    if options.legend {
-        composite::draw_legend(&mut image, options);
-        log::info!("Legende gezeichnet");
-    }
+    Ok(image)
+}
 
-    // 8. Titel-Bar
-    composite::draw_title_bar(&mut image, &map_info.title);
+/// Generiert eine SVG-Overview-Map aus einem FS25 Map-Mod-ZIP.
+///
+/// Terrain, Wald, Gewaesser, Hillshade und Hoehenlinien werden als PNG
+/// eingebettet; Grenzen, Strassen, POIs, Netz, Beschriftungen, Legende und
+/// Titel sind Vektor-Elemente. Das ZIP wird wie bei
+/// [`generate_overview_from_zip`] selektiv gelesen.
+pub fn generate_overview_svg_from_zip(zip_path: &str, options: &OverviewOptions) -> Result<String> {
+    log::info!("Generiere SVG-Overview aus ZIP: {}", zip_path);
+
+    if let Some((map_info, files)) = load_cached_map_files(zip_path, options)? {
+        return svg::generate_overview_svg(&files, &map_info, options);
+    }
+    let discovery_files = extract_zip_for_map_discovery(zip_path).with_context(|| {
+        format!(
+            "Selektive Discovery-Extraktion fehlgeschlagen: {}",
+            zip_path
+        )
+    })?;
+    let map_info = discovery::discover_map(&discovery_files)
+        .with_context(|| format!("Map-Discovery fehlgeschlagen: {}", zip_path))?;
+    let files = extract_zip_for_overview(zip_path, &map_info, options)
+        .with_context(|| format!("Selektive Overview-Extraktion fehlgeschlagen: {}", zip_path))?;
 
-    Ok(image)
+    svg::generate_overview_svg(&files, &map_info, options)
 }
 
 /// Generiert ein Layer-Bundle aus einem FS25 Map-Mod-ZIP.
@@ -263,9 +572,52 @@ pub fn generate_overview(
 pub fn generate_overview_layer_bundle_from_zip(
     zip_path: &str,
     options: &OverviewOptions,
+) -> Result<OverviewLayerBundle> {
+    generate_overview_layer_bundle_from_zip_with_progress(zip_path, options, &mut |_| true)
+}
+
+/// Wie [`generate_overview_layer_bundle_from_zip`], meldet aber jeden
+/// Arbeitsschritt an `progress`.
+///
+/// Gibt der Callback `false` zurueck, endet die Generierung mit [`OverviewCancelled`].
+pub fn generate_overview_layer_bundle_from_zip_with_progress(
+    zip_path: &str,
+    options: &OverviewOptions,
+    progress: &mut dyn FnMut(OverviewProgress) -> bool,
 ) -> Result<OverviewLayerBundle> {
     log::info!("Generiere Overview-Layer-Bundle aus ZIP: {}", zip_path);
+    let mut progress = ProgressReporter::new(progress);
+    let (map_info, files) = read_layer_bundle_files(zip_path, options, &mut progress)?;
+    layer_bundle::build_layer_bundle(&files, &map_info, options, &mut progress)
+}
+
+/// Liest ein Map-Mod-ZIP fuer eine [`OverviewSession`] ein.
+///
+/// Extrahiert wie [`generate_overview_layer_bundle_from_zip`] (inklusive
+/// `options.cache_dir`), erzeugt aber noch keine Layer; das erste
+/// [`OverviewSession::update`] baut das Bundle, weitere Aufrufe zeichnen nur
+/// geaenderte Layer neu.
+pub fn open_overview_session_from_zip(
+    zip_path: &str,
+    options: &OverviewOptions,
+) -> Result<OverviewSession> {
+    log::info!("Oeffne Overview-Session fuer ZIP: {}", zip_path);
+    let (map_info, files) =
+        read_layer_bundle_files(zip_path, options, &mut ProgressReporter::none())?;
+    Ok(OverviewSession::new(files, map_info))
+}
 
+/// Discovery und selektive Extraktion aller Layer-Bundle-Dateien.
+fn read_layer_bundle_files(
+    zip_path: &str,
+    options: &OverviewOptions,
+    progress: &mut ProgressReporter<'_>,
+) -> Result<(MapInfo, MapFiles)> {
+    progress.stage(OverviewStage::Discovery)?;
+    if let Some(cached) = load_cached_map_files(zip_path, options)? {
+        progress.stage(OverviewStage::ReadingArchive)?;
+        return Ok(cached);
+    }
     let discovery_files = extract_zip_for_map_discovery(zip_path).with_context(|| {
         format!(
             "Selektive Discovery-Extraktion fuer Layer-Bundle fehlgeschlagen: {}",
@@ -278,6 +630,7 @@ pub fn generate_overview_layer_bundle_from_zip(
             zip_path
         )
     })?;
+    progress.stage(OverviewStage::ReadingArchive)?;
     let files = extract_zip_for_layer_bundle(zip_path, &map_info).with_context(|| {
         format!(
             "Selektive Layer-Bundle-Extraktion fehlgeschlagen: {}",
@@ -285,7 +638,7 @@ pub fn generate_overview_layer_bundle_from_zip(
         )
     })?;
 
-    layer_bundle::generate_overview_layer_bundle(&files, &map_info, options)
+    Ok((map_info, files))
 }
 
 /// Versucht Feldpolygone aus einer `infoLayer_fieldType.grle`-Datei zu lesen.
@@ -419,16 +772,9 @@ fn find_ground_gdm_anywhere(files: &HashMap<String, Vec<u8>>) -> Option<(&str, &
 fn try_extract_polygons_from_ground_gdm_bytes(
     data: &[u8],
 ) -> Option<(Vec<FarmlandPolygon>, u32, u32)> {
-    let img = gdm::decode_gdm(data)
+    let (converted, dim) = fields::ground_gdm_field_mask(data)
         .map_err(|e| log::warn!("Ground-GDM Dekodierung fehlgeschlagen: {}", e))
         .ok()?;
-    let dim = img.dimension;
-    // RGB-Encoding: R-Kanal enthaelt Bits 0–7; unteres Nibble = Feld-/Bodentyp
-    let converted: Vec<u8> = if img.is_rgb {
-        img.pixels.chunks(3).map(|rgb| rgb[0] & 0x0F).collect()
-    } else {
-        img.pixels.iter().map(|&b| b & 0x0F).collect()
-    };
     let polygons = farmland::extract_field_polygons_by_ccl(&converted, dim, dim);
     if polygons.is_empty() {
         log::info!("Keine Feldpolygone in Ground-GDM gefunden");
@@ -443,17 +789,74 @@ fn try_extract_polygons_from_ground_gdm_bytes(
     Some((polygons, dim as u32, dim as u32))
 }
 
-/// Extrahiert nur XML-Dateien fuer die Karten-Discovery.
+/// Extrahiert XML-Dateien und Raster fuer die Karten-Discovery.
+///
+/// Die Map-i3d wird in einem zweiten Durchlauf nachgeladen, da ihr Pfad erst
+/// aus der Map-Config folgt; aus ihr liest die Discovery Terrain-Groesse,
+/// Hoehenskala und Versatz.
 fn extract_zip_for_map_discovery(zip_path: &str) -> Result<HashMap<String, Vec<u8>>> {
-    extract_zip_with_selector(zip_path, |entry_name| {
+    let mut files = extract_zip_with_selector(zip_path, |entry_name| {
         let lower = normalize_zip_path(entry_name).to_ascii_lowercase();
         lower.ends_with(".xml")
-            || lower.ends_with("_weight.png")
-            || lower.ends_with("/dem.png")
+            || discovery::is_weight_map_path(&lower)
+            || discovery::is_dem_path(&lower)
             || lower.ends_with("/infolayer_farmlands.grle")
             || lower.ends_with("/infolayer_farmlands.png")
             || lower.ends_with("/densitymap_ground.gdm")
-    })
+    })?;
+
+    // Fehler der Discovery meldet erst der Aufrufer
+    if let Some(i3d_path) = discovery::discover_map(&files)
+        .ok()
+        .and_then(|map_info| map_info.i3d_path)
+        .filter(|path| !files.contains_key(path))
+    {
+        files.extend(extract_zip_only_paths(
+            zip_path,
+            &HashSet::from([i3d_path]),
+        )?);
+    }
+    Ok(files)
+}
+
+/// Extrahierte Map-Dateien: normalisierter ZIP-Pfad → Rohdaten.
+type MapFiles = HashMap<String, Vec<u8>>;
+
+/// Liest Discovery- und Layer-Dateien ueber den Kartendaten-Cache.
+///
+/// Der Cache-Eintrag umfasst alle Discovery- und Layer-Bundle-Dateien, damit
+/// er fuer jede Optionskombination passt. Gibt `None` zurueck, wenn
+/// `options.cache_dir` fehlt oder `zip_path` ein Ordner ist.
+fn load_cached_map_files(
+    zip_path: &str,
+    options: &OverviewOptions,
+) -> Result<Option<(MapInfo, MapFiles)>> {
+    let Some(cache_dir) = &options.cache_dir else {
+        return Ok(None);
+    };
+    let files = MapDataCache::new(cache_dir).load_or_extract(zip_path, || {
+        let mut files = extract_zip_for_map_discovery(zip_path).with_context(|| {
+            format!(
+                "Selektive Discovery-Extraktion fehlgeschlagen: {}",
+                zip_path
+            )
+        })?;
+        let map_info = discovery::discover_map(&files)
+            .with_context(|| format!("Map-Discovery fehlgeschlagen: {}", zip_path))?;
+        files.extend(
+            extract_zip_for_layer_bundle(zip_path, &map_info).with_context(|| {
+                format!("Selektive Cache-Extraktion fehlgeschlagen: {}", zip_path)
+            })?,
+        );
+        Ok(files)
+    })?;
+    let Some(files) = files else {
+        return Ok(None);
+    };
+
+    let map_info = discovery::discover_map(&files)
+        .with_context(|| format!("Map-Discovery fehlgeschlagen: {}", zip_path))?;
+    Ok(Some((map_info, files)))
 }
 
 /// Extrahiert nur fuer die Legacy-Overview noetige Dateien.
@@ -469,35 +872,103 @@ fn extract_zip_for_overview(
         .then_some(map_info.placeables_path.as_ref())
         .flatten()
         .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+    let i3d_path = options
+        .roads
+        .then_some(map_info.i3d_path.as_ref())
+        .flatten()
+        .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+    let farmlands_xml_path = options
+        .farmland_prices
+        .then_some(map_info.farmlands_path.as_ref())
+        .flatten()
+        .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+    let map_images: Vec<String> = if options.map_image {
+        discovery::map_image_candidates(map_info)
+            .iter()
+            .map(|path| normalize_zip_path(path).to_ascii_lowercase())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    // Feldnummern stammen aus dem Farmland-Raster
+    let needs_farmland_raster = options.farmlands
+        || options.farmland_ids
+        || options.farmland_prices
+        || options.farmland_ownership.is_some()
+        || options.fields;
 
     extract_zip_with_selector(zip_path, |entry_name| {
         let normalized = normalize_zip_path(entry_name);
         let lower = normalized.to_ascii_lowercase();
         let in_data_dir = lower.starts_with(&data_prefix_lower);
 
-        if in_data_dir && lower.ends_with("_weight.png") {
+        if in_data_dir && discovery::is_weight_map_path(&lower) {
             return true;
         }
-        if options.hillshade && in_data_dir && lower.ends_with("/dem.png") {
+        if (options.hillshade || options.water || options.contours.is_some())
+            && in_data_dir
+            && discovery::is_dem_path(&lower)
+        {
             return true;
         }
-        if (options.farmlands || options.farmland_ids)
+        if needs_farmland_raster
             && in_data_dir
             && (lower.ends_with("/infolayer_farmlands.grle")
                 || lower.ends_with("/infolayer_farmlands.png"))
         {
             return true;
         }
+        if options.fields && in_data_dir && lower.ends_with("/densitymap_ground.gdm") {
+            return true;
+        }
+        if map_images.contains(&lower) {
+            return true;
+        }
+        if options.placement_collision
+            && in_data_dir
+            && discovery::is_placement_collision_path(&lower)
+        {
+            return true;
+        }
         if let Some(placeables) = &placeables_path
             && &lower == placeables
         {
             return true;
         }
+        if let Some(i3d) = &i3d_path
+            && &lower == i3d
+        {
+            return true;
+        }
+        if let Some(farmlands_xml) = &farmlands_xml_path
+            && &lower == farmlands_xml
+        {
+            return true;
+        }
 
         false
     })
 }
 
+/// Extrahiert DEM und Map-i3d fuer [`extract_heightmap_from_zip`].
+fn extract_zip_for_heightmap(
+    zip_path: &str,
+    map_info: &MapInfo,
+) -> Result<HashMap<String, Vec<u8>>> {
+    let data_prefix = format!("{}/", map_info.data_dir.trim_end_matches('/'));
+    let data_prefix_lower = normalize_zip_path(&data_prefix).to_ascii_lowercase();
+    let i3d_path = map_info
+        .i3d_path
+        .as_ref()
+        .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+
+    extract_zip_with_selector(zip_path, |entry_name| {
+        let lower = normalize_zip_path(entry_name).to_ascii_lowercase();
+        (lower.starts_with(&data_prefix_lower) && discovery::is_dem_path(&lower))
+            || i3d_path.as_ref() == Some(&lower)
+    })
+}
+
 /// Extrahiert Dateien, die fuer das Layer-Bundle benoetigt werden.
 fn extract_zip_for_layer_bundle(
     zip_path: &str,
@@ -509,17 +980,34 @@ fn extract_zip_for_layer_bundle(
         .placeables_path
         .as_ref()
         .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+    let i3d_path = map_info
+        .i3d_path
+        .as_ref()
+        .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+    let farmlands_xml_path = map_info
+        .farmlands_path
+        .as_ref()
+        .map(|path| normalize_zip_path(path).to_ascii_lowercase());
+    let map_images: Vec<String> = discovery::map_image_candidates(map_info)
+        .iter()
+        .map(|path| normalize_zip_path(path).to_ascii_lowercase())
+        .collect();
 
     extract_zip_with_selector(zip_path, |entry_name| {
         let normalized = normalize_zip_path(entry_name);
         let lower = normalized.to_ascii_lowercase();
         let in_data_dir = lower.starts_with(&data_prefix_lower);
 
+        if map_images.contains(&lower) {
+            return true;
+        }
         if in_data_dir
-            && (lower.ends_with("_weight.png")
-                || lower.ends_with("/dem.png")
+            && (discovery::is_weight_map_path(&lower)
+                || discovery::is_dem_path(&lower)
                 || lower.ends_with("/infolayer_farmlands.grle")
-                || lower.ends_with("/infolayer_farmlands.png"))
+                || lower.ends_with("/infolayer_farmlands.png")
+                || lower.ends_with("/densitymap_ground.gdm")
+                || discovery::is_placement_collision_path(&lower))
         {
             return true;
         }
@@ -528,6 +1016,16 @@ fn extract_zip_for_layer_bundle(
         {
             return true;
         }
+        if let Some(i3d) = &i3d_path
+            && &lower == i3d
+        {
+            return true;
+        }
+        if let Some(farmlands_xml) = &farmlands_xml_path
+            && &lower == farmlands_xml
+        {
+            return true;
+        }
 
         false
     })
@@ -547,7 +1045,6 @@ fn extract_zip_for_ground_gdm(zip_path: &str) -> Result<HashMap<String, Vec<u8>>
 ///
 /// Pfade werden robust normalisiert (`\\` → `/`, fuehrendes `./` wird entfernt),
 /// damit verschachtelte ZIP-Strukturen konsistent adressiert werden koennen.
-#[allow(dead_code)]
 fn extract_zip_only_paths(
     zip_path: &str,
     required_paths: &HashSet<String>,
@@ -562,6 +1059,10 @@ fn extract_zip_only_paths(
 }
 
 /// Extrahiert ZIP-Eintraege gemaess eines Selektors in eine HashMap.
+///
+/// Ist `zip_path` ein Verzeichnis (entpackter Mod oder Map-Projekt in Arbeit),
+/// werden stattdessen die Dateien des Ordners mit denselben relativen Pfaden
+/// gelesen.
 fn extract_zip_with_selector<F>(
     zip_path: &str,
     mut should_extract: F,
@@ -569,18 +1070,56 @@ fn extract_zip_with_selector<F>(
 where
     F: FnMut(&str) -> bool,
 {
+    if Path::new(zip_path).is_dir() {
+        return read_dir_with_selector(Path::new(zip_path), should_extract);
+    }
+
     let file = std::fs::File::open(zip_path)
         .with_context(|| format!("ZIP-Datei nicht gefunden: {}", zip_path))?;
     let mut archive = zip::ZipArchive::new(BufReader::new(file))
         .with_context(|| format!("Ungueltiges ZIP-Archiv: {}", zip_path))?;
 
     let mut files = HashMap::new();
-    let mut total_bytes: usize = 0;
+    extract_archive_entries(
+        &mut archive,
+        zip_path,
+        "",
+        0,
+        &mut should_extract,
+        &mut files,
+    )?;
+
+    log::info!(
+        "ZIP entpackt: {} Dateien, {} Bytes (selektiver Kern)",
+        files.len(),
+        files.values().map(Vec::len).sum::<usize>()
+    );
+    Ok(files)
+}
+
+/// Maximale Verschachtelungstiefe fuer ZIPs in ZIPs (Mod-Packs).
+const MAX_NESTED_ZIP_DEPTH: usize = 2;
+
+/// Liest die vom Selektor gewaehlten Eintraege eines (ggf. verschachtelten) Archivs.
+///
+/// Eintraege mit Endung `.zip` werden bis [`MAX_NESTED_ZIP_DEPTH`] transparent
+/// geoeffnet; ihre Eintraege erhalten den Pfad des inneren ZIPs als Praefix
+/// (`pack/map.zip/modDesc.xml`), damit Discovery und Selektoren unveraendert
+/// mit relativen Pfaden arbeiten. Defekte innere ZIPs werden mit Warnung
+/// uebersprungen.
+fn extract_archive_entries<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    archive_label: &str,
+    prefix: &str,
+    depth: usize,
+    should_extract: &mut dyn FnMut(&str) -> bool,
+    files: &mut HashMap<String, Vec<u8>>,
+) -> Result<()> {
     for i in 0..archive.len() {
         let mut entry = archive.by_index(i).with_context(|| {
             format!(
                 "ZIP-Eintrag konnte nicht geoeffnet werden (Index {} in {}): korruptes Archiv?",
-                i, zip_path
+                i, archive_label
             )
         })?;
         if !entry.is_file() {
@@ -588,8 +1127,10 @@ where
         }
 
         let raw_name = entry.name().to_string();
-        let normalized_name = normalize_zip_path(&raw_name);
-        if !should_extract(&normalized_name) {
+        let normalized_name = format!("{}{}", prefix, normalize_zip_path(&raw_name));
+        let is_nested_zip =
+            depth < MAX_NESTED_ZIP_DEPTH && normalized_name.to_ascii_lowercase().ends_with(".zip");
+        if !is_nested_zip && !should_extract(&normalized_name) {
             continue;
         }
 
@@ -597,18 +1138,106 @@ where
         entry.read_to_end(&mut buffer).with_context(|| {
             format!(
                 "ZIP-Eintrag konnte nicht gelesen werden: {} (Archiv: {})",
-                raw_name, zip_path
+                raw_name, archive_label
             )
         })?;
 
-        total_bytes += buffer.len();
-        files.insert(normalized_name, buffer);
+        if !is_nested_zip {
+            files.insert(normalized_name, buffer);
+            continue;
+        }
+
+        let nested_label = format!("{} > {}", archive_label, raw_name);
+        match zip::ZipArchive::new(Cursor::new(buffer)) {
+            Ok(mut nested) => {
+                if let Err(e) = extract_archive_entries(
+                    &mut nested,
+                    &nested_label,
+                    &format!("{}/", normalized_name),
+                    depth + 1,
+                    should_extract,
+                    files,
+                ) {
+                    log::warn!(
+                        "Verschachteltes ZIP unvollstaendig ({}): {}",
+                        nested_label,
+                        e
+                    );
+                }
+            }
+            Err(e) => log::warn!("Verschachteltes ZIP ignoriert ({}): {}", nested_label, e),
+        }
+    }
+    Ok(())
+}
+
+/// Liest Dateien eines Mod-Ordners rekursiv gemaess eines Selektors.
+///
+/// Schluessel sind die Pfade relativ zu `root` mit `/` als Trenner, wie bei
+/// ZIP-Eintraegen. Versteckte Eintraege (z.B. `.git`) werden uebersprungen;
+/// enthaltene ZIPs werden wie verschachtelte Archive gelesen.
+fn read_dir_with_selector<F>(root: &Path, mut should_extract: F) -> Result<HashMap<String, Vec<u8>>>
+where
+    F: FnMut(&str) -> bool,
+{
+    let mut files = HashMap::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .with_context(|| format!("Verzeichnis nicht lesbar: {}", dir.display()))?;
+        for entry in entries {
+            let entry = entry
+                .with_context(|| format!("Verzeichniseintrag nicht lesbar: {}", dir.display()))?;
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry
+                .file_type()
+                .with_context(|| format!("Dateityp nicht lesbar: {}", path.display()))?;
+            if file_type.is_dir() {
+                pending.push(path);
+                continue;
+            }
+
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let normalized_name = normalize_zip_path(&relative.to_string_lossy());
+            if normalized_name.to_ascii_lowercase().ends_with(".zip") {
+                // Map-ZIP im Mod-Ordner: wie ein verschachteltes ZIP behandeln
+                let label = path.display().to_string();
+                let archive = std::fs::File::open(&path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|file| Ok(zip::ZipArchive::new(BufReader::new(file))?));
+                match archive {
+                    Ok(mut archive) => extract_archive_entries(
+                        &mut archive,
+                        &label,
+                        &format!("{}/", normalized_name),
+                        1,
+                        &mut should_extract,
+                        &mut files,
+                    )?,
+                    Err(e) => log::warn!("ZIP im Mod-Ordner ignoriert ({}): {}", label, e),
+                }
+                continue;
+            }
+            if !should_extract(&normalized_name) {
+                continue;
+            }
+
+            let buffer = std::fs::read(&path).with_context(|| {
+                format!("Datei konnte nicht gelesen werden: {}", path.display())
+            })?;
+            files.insert(normalized_name, buffer);
+        }
     }
 
     log::info!(
-        "ZIP entpackt: {} Dateien, {} Bytes (selektiver Kern)",
+        "Mod-Ordner gelesen: {} Dateien, {} Bytes (selektiver Kern)",
         files.len(),
-        total_bytes
+        files.values().map(Vec::len).sum::<usize>()
     );
     Ok(files)
 }
@@ -660,53 +1289,25 @@ pub(crate) fn try_extract_polygons_from_files(
         return (Vec::new(), map_info.map_size, map_info.map_size, None);
     };
 
-    if path.ends_with(".grle") {
-        match crate::grle::decode_grle(data) {
-            Ok(decoded) => {
-                let w = decoded.width;
-                let h = decoded.height;
-                let polygons = farmland::extract_farmland_polygons_from_ids(&decoded.pixels, w, h);
-                log::info!(
-                    "Farmland-Polygone extrahiert: {} Felder aus {}x{} Raster",
-                    polygons.len(),
-                    w,
-                    h
-                );
-                (polygons, w as u32, h as u32, Some(decoded.pixels))
-            }
-            Err(e) => {
-                log::warn!("Farmland-GRLE-Dekodierung fehlgeschlagen: {}", e);
-                (Vec::new(), map_info.map_size, map_info.map_size, None)
-            }
+    match composite::decode_farmland_ids(path, data) {
+        Ok(ids) => {
+            let w = ids.width() as usize;
+            let h = ids.height() as usize;
+            let raw_ids = ids.into_raw();
+            let polygons = farmland::extract_farmland_polygons_from_ids(&raw_ids, w, h);
+            log::info!(
+                "Farmland-Polygone extrahiert: {} Felder aus {}x{} Raster ({})",
+                polygons.len(),
+                w,
+                h,
+                path
+            );
+            (polygons, w as u32, h as u32, Some(raw_ids))
         }
-    } else if path.ends_with(".png") {
-        // PNG-Farmland: Bild zu Graustufen decodieren, Pixelwerte als Farmland-IDs nutzen
-        match image::load_from_memory(data) {
-            Ok(img) => {
-                let luma = img.to_luma8();
-                let w = luma.width() as usize;
-                let h = luma.height() as usize;
-                let raw_ids = luma.as_raw().clone();
-                let polygons = farmland::extract_farmland_polygons_from_ids(&raw_ids, w, h);
-                log::info!(
-                    "Farmland-Polygone aus PNG extrahiert: {} Felder aus {}x{} Raster",
-                    polygons.len(),
-                    w,
-                    h
-                );
-                (polygons, w as u32, h as u32, Some(raw_ids))
-            }
-            Err(e) => {
-                log::warn!("PNG-Farmland konnte nicht dekodiert werden: {}", e);
-                (Vec::new(), map_info.map_size, map_info.map_size, None)
-            }
+        Err(e) => {
+            log::warn!("Farmland-InfoLayer {} nicht dekodierbar: {}", path, e);
+            (Vec::new(), map_info.map_size, map_info.map_size, None)
         }
-    } else {
-        log::info!(
-            "Farmland-Datei in unbekanntem Format ({}), Polygon-Extraktion uebersprungen",
-            path
-        );
-        (Vec::new(), map_info.map_size, map_info.map_size, None)
     }
 }
 
@@ -800,11 +1401,17 @@ mod tests {
     fn test_map_info_with_placeables() -> MapInfo {
         MapInfo {
             title: String::from("Test Map"),
+            author: None,
             map_size: 32,
+            height_scale: crate::DEFAULT_HEIGHT_SCALE,
+            terrain_offset: (0.0, 0.0),
             config_path: String::from("TestMap/maps/config/map.xml"),
             data_dir: String::from("TestMap/maps/data"),
             config_dir: String::from("TestMap/maps/config"),
             placeables_path: Some(String::from("TestMap/maps/placeables.xml")),
+            i3d_path: None,
+            farmlands_path: None,
+            image_path: None,
         }
     }
 
@@ -845,10 +1452,30 @@ mod tests {
         let options = OverviewOptions {
             terrain: false,
             hillshade: false,
+            hillshade_params: HillshadeParams::default(),
             farmlands: false,
             farmland_ids: false,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
             pois: false,
+            poi_clusters: true,
             legend: false,
+            legend_options: LegendOptions::default(),
+            title_bar: true,
+            title_bar_options: TitleBarOptions::default(),
+            forest: false,
+            water: false,
+            fields: false,
+            roads: false,
+            contours: None,
+            placement_collision: false,
+            network: None,
+            output_size: None,
+            supersample: 1,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
         };
 
         let result = generate_overview_result_from_zip(
@@ -867,6 +1494,282 @@ mod tests {
         assert_eq!(result.farmland_ids, Some(vec![0, 1, 1, 0]));
     }
 
+    #[test]
+    fn generate_overview_from_zip_accepts_unzipped_mod_folder() {
+        let temp_dir = TempDirGuard::new("mod_folder");
+        let mod_root = temp_dir.path().join("TestMap");
+        for (name, bytes) in [
+            (
+                "modDesc.xml",
+                br#"<modDesc><title><en>Folder Map</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                    .to_vec(),
+            ),
+            (
+                "maps/config/map.xml",
+                br#"<map width="32" height="32" />"#.to_vec(),
+            ),
+            (
+                "maps/data/infoLayer_farmlands.png",
+                luma_png_bytes(2, 2, vec![0, 1, 1, 0]),
+            ),
+            (".git/config.xml", b"<ignored />".to_vec()),
+        ] {
+            let path = mod_root.join(name);
+            std::fs::create_dir_all(path.parent().expect("Datei hat Elternordner"))
+                .expect("Ordner muss erstellt werden");
+            std::fs::write(&path, bytes).expect("Datei muss geschrieben werden");
+        }
+        let folder = mod_root.to_str().expect("Temp-Pfad muss UTF-8 sein");
+
+        let files = extract_zip_for_map_discovery(folder).expect("Ordner muss lesbar sein");
+        assert!(contains_normalized_path(&files, "modDesc.xml"));
+        assert!(contains_normalized_path(&files, "maps/config/map.xml"));
+        assert!(!contains_normalized_path(&files, ".git/config.xml"));
+
+        let options = OverviewOptions {
+            output_size: Some(16),
+            ..OverviewOptions::default()
+        };
+        let image = generate_overview_from_zip(folder, &options)
+            .expect("Overview aus Mod-Ordner muss erzeugt werden");
+        assert_eq!(image.dimensions(), (16, 16));
+
+        let result = generate_overview_result_from_zip(folder, &options)
+            .expect("Layer-Bundle aus Mod-Ordner muss erzeugt werden");
+        assert_eq!(result.farmland_ids, Some(vec![0, 1, 1, 0]));
+    }
+
+    #[test]
+    fn extract_heightmap_from_zip_reads_terrain_from_i3d() {
+        let temp_dir = TempDirGuard::new("heightmap_zip");
+        let zip_path = temp_dir.path().join("map.zip");
+        let mut dem_png = Vec::new();
+        DynamicImage::ImageLuma16(image::ImageBuffer::from_pixel(
+            17,
+            17,
+            image::Luma([40000u16]),
+        ))
+        .write_to(&mut Cursor::new(&mut dem_png), image::ImageFormat::Png)
+        .expect("DEM-PNG muss kodiert werden");
+        write_zip(
+            &zip_path,
+            vec![
+                (
+                    "modDesc.xml",
+                    br#"<modDesc><title><en>Hills</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                        .to_vec(),
+                ),
+                (
+                    "maps/config/map.xml",
+                    br#"<map width="32" height="32"><filename>maps/map.i3d</filename></map>"#
+                        .to_vec(),
+                ),
+                (
+                    "maps/map.i3d",
+                    br#"<i3D><Scene><TerrainTransformGroup heightScale="400" unitsPerPixel="2" translation="4 0 -6" /></Scene></i3D>"#
+                        .to_vec(),
+                ),
+                ("maps/data/dem.png", dem_png),
+            ],
+        );
+
+        let heightmap =
+            extract_heightmap_from_zip(zip_path.to_str().expect("Temp-Pfad muss UTF-8 sein"))
+                .expect("DEM muss extrahiert werden");
+
+        assert_eq!(heightmap.map_size, 32);
+        assert_eq!(heightmap.height_scale, 400.0);
+        assert_eq!(heightmap.world_bounds(), (-12.0, -22.0, 20.0, 10.0));
+        assert_eq!(heightmap.dem.to_luma16().get_pixel(3, 3).0, [40000]);
+    }
+
+    #[test]
+    fn map_image_option_uses_shipped_overview_as_terrain() {
+        let temp_dir = TempDirGuard::new("map_image");
+        let with_image = temp_dir.path().join("with_image.zip");
+        let without_image = temp_dir.path().join("without_image.zip");
+        let entries = |image: bool| {
+            let mut entries = vec![
+                (
+                    "modDesc.xml",
+                    br#"<modDesc><map configFilename="maps/config/map.xml" /></modDesc>"#.to_vec(),
+                ),
+                (
+                    "maps/config/map.xml",
+                    br#"<map width="16" height="16" imageFilename="maps/pda.png" />"#.to_vec(),
+                ),
+                (
+                    "maps/data/grass_weight.png",
+                    luma_png_bytes(4, 4, vec![255; 16]),
+                ),
+            ];
+            if image {
+                entries.push(("maps/pda.png", rgba_png_bytes(4, 4, [200, 20, 20, 255])));
+            }
+            entries
+        };
+        write_zip(&with_image, entries(true));
+        write_zip(&without_image, entries(false));
+
+        let plain = OverviewOptions {
+            hillshade: false,
+            farmlands: false,
+            farmland_ids: false,
+            pois: false,
+            poi_clusters: true,
+            legend: false,
+            title_bar: false,
+            ..OverviewOptions::default()
+        };
+        let map_image = OverviewOptions {
+            map_image: true,
+            ..plain.clone()
+        };
+        let path = |zip: &Path| zip.to_str().expect("Temp-Pfad muss UTF-8 sein").to_string();
+
+        let image = generate_overview_from_zip(&path(&with_image), &map_image)
+            .expect("Overview mit Kartenbild");
+        assert_eq!(image.get_pixel(8, 8).0, [200, 20, 20]);
+        let bundle = generate_overview_layer_bundle_from_zip(&path(&with_image), &map_image)
+            .expect("Layer-Bundle mit Kartenbild");
+        assert_eq!(bundle.terrain.get_pixel(8, 8).0, [200, 20, 20, 255]);
+
+        // Ohne Option bzw. ohne Bild bleibt das Weight-Map-Terrain
+        let weights = generate_overview_from_zip(&path(&with_image), &plain)
+            .expect("Overview aus Weight-Maps");
+        assert_ne!(weights.get_pixel(8, 8).0, [200, 20, 20]);
+        let fallback = generate_overview_from_zip(&path(&without_image), &map_image)
+            .expect("Fallback auf Weight-Maps");
+        assert_eq!(fallback, weights);
+    }
+
+    #[test]
+    fn generate_overview_from_zip_with_cache_matches_uncached_output() {
+        let temp_dir = TempDirGuard::new("map_cache");
+        let zip_path = temp_dir.path().join("map.zip");
+        let weight: Vec<u8> = (0..64).map(|i| if i % 3 == 0 { 255 } else { 0 }).collect();
+        write_zip(
+            &zip_path,
+            vec![
+                (
+                    "modDesc.xml",
+                    br#"<modDesc><title><en>Cached</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                        .to_vec(),
+                ),
+                (
+                    "maps/config/map.xml",
+                    br#"<map width="32" height="32" />"#.to_vec(),
+                ),
+                ("maps/data/grass_weight.png", luma_png_bytes(8, 8, weight)),
+                ("maps/data/dem.png", luma_png_bytes(2, 2, vec![0, 50, 100, 150])),
+                (
+                    "maps/data/infoLayer_farmlands.png",
+                    luma_png_bytes(2, 2, vec![0, 1, 1, 0]),
+                ),
+            ],
+        );
+        let zip_path = zip_path.to_str().expect("Temp-Pfad muss UTF-8 sein");
+        let uncached = OverviewOptions {
+            output_size: Some(16),
+            ..OverviewOptions::default()
+        };
+        let cached = OverviewOptions {
+            cache_dir: Some(temp_dir.path().join("cache")),
+            ..uncached.clone()
+        };
+
+        let expected = generate_overview_from_zip(zip_path, &uncached).expect("ohne Cache");
+        let first = generate_overview_from_zip(zip_path, &cached).expect("Cache fuellen");
+
+        // Zweiter Lauf mit anderen Optionen nutzt denselben Eintrag
+        let without_borders = OverviewOptions {
+            farmlands: false,
+            hillshade: false,
+            ..cached.clone()
+        };
+        let second = generate_overview_from_zip(zip_path, &without_borders).expect("aus Cache");
+        let expected_second = generate_overview_from_zip(
+            zip_path,
+            &OverviewOptions {
+                cache_dir: None,
+                ..without_borders.clone()
+            },
+        )
+        .expect("ohne Cache");
+        let bundle = generate_overview_layer_bundle_from_zip(zip_path, &cached)
+            .expect("Layer-Bundle aus Cache");
+
+        assert_eq!(first, expected);
+        assert_eq!(second, expected_second);
+        assert_eq!(bundle.map_size, 32.0);
+        assert_eq!(
+            std::fs::read_dir(temp_dir.path().join("cache"))
+                .expect("Cache-Ordner")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn generate_overview_result_from_zip_descends_into_nested_map_zip() {
+        let temp_dir = TempDirGuard::new("nested_map_zip");
+        let inner_path = temp_dir.path().join("inner.zip");
+        write_zip(
+            &inner_path,
+            vec![
+                (
+                    "modDesc.xml",
+                    br#"<modDesc><title><en>Inner Map</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                        .to_vec(),
+                ),
+                (
+                    "maps/config/map.xml",
+                    br#"<map width="32" height="32" />"#.to_vec(),
+                ),
+                (
+                    "maps/data/infoLayer_farmlands.png",
+                    luma_png_bytes(2, 2, vec![0, 2, 2, 0]),
+                ),
+            ],
+        );
+        let pack_path = temp_dir.path().join("pack.zip");
+        write_zip(
+            &pack_path,
+            vec![
+                // Eigene modDesc.xml des Packs ohne <map> darf die Discovery nicht stoeren
+                (
+                    "modDesc.xml",
+                    br#"<modDesc><title><en>Pack</en></title></modDesc>"#.to_vec(),
+                ),
+                (
+                    "mods/FS25_InnerMap.zip",
+                    std::fs::read(&inner_path).expect("Inneres ZIP muss lesbar sein"),
+                ),
+                ("mods/broken.zip", b"kein zip".to_vec()),
+            ],
+        );
+        let pack = pack_path.to_str().expect("Temp-ZIP-Pfad muss UTF-8 sein");
+
+        let files = extract_zip_for_map_discovery(pack).expect("Pack muss lesbar sein");
+        assert!(contains_normalized_path(
+            &files,
+            "mods/FS25_InnerMap.zip/maps/config/map.xml"
+        ));
+        let map_info = discovery::discover_map(&files).expect("Karte im inneren ZIP");
+        assert_eq!(map_info.title, "Inner Map");
+        assert_eq!(map_info.data_dir, "mods/FS25_InnerMap.zip/maps/data");
+        assert_eq!(
+            discover_map_from_zip(pack)
+                .expect("Discovery ueber ZIP-Pfad")
+                .title,
+            "Inner Map"
+        );
+
+        let result = generate_overview_result_from_zip(pack, &OverviewOptions::default())
+            .expect("Overview aus verschachteltem ZIP muss erzeugt werden");
+        assert_eq!(result.farmland_ids, Some(vec![0, 2, 2, 0]));
+    }
+
     #[test]
     fn generate_overview_result_from_zip_handles_dot_slash_entry_names() {
         let temp_dir = TempDirGuard::new("nested_zip_dot_slash");
@@ -904,10 +1807,30 @@ mod tests {
         let options = OverviewOptions {
             terrain: false,
             hillshade: false,
+            hillshade_params: HillshadeParams::default(),
             farmlands: false,
             farmland_ids: false,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
             pois: false,
+            poi_clusters: true,
             legend: false,
+            legend_options: LegendOptions::default(),
+            title_bar: true,
+            title_bar_options: TitleBarOptions::default(),
+            forest: false,
+            water: false,
+            fields: false,
+            roads: false,
+            contours: None,
+            placement_collision: false,
+            network: None,
+            output_size: None,
+            supersample: 1,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
         };
 
         let result = generate_overview_result_from_zip(
@@ -959,10 +1882,30 @@ mod tests {
         let options = OverviewOptions {
             terrain: false,
             hillshade: false,
+            hillshade_params: HillshadeParams::default(),
             farmlands: false,
             farmland_ids: false,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
             pois: false,
+            poi_clusters: true,
             legend: false,
+            legend_options: LegendOptions::default(),
+            title_bar: true,
+            title_bar_options: TitleBarOptions::default(),
+            forest: false,
+            water: false,
+            fields: false,
+            roads: false,
+            contours: None,
+            placement_collision: false,
+            network: None,
+            output_size: None,
+            supersample: 1,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
         };
 
         let result = generate_overview_result_from_zip(
@@ -1021,6 +1964,11 @@ mod tests {
                 ("TestMap/maps/data/dem.png", vec![1]),
                 ("TestMap/maps/data/fruit_weight.png", vec![2]),
                 ("TestMap/maps/data/infoLayer_farmlands.png", vec![3]),
+                (
+                    "TestMap/maps/data/infoLayer_placementCollision.grle",
+                    vec![4],
+                ),
+                ("TestMap/maps/data/infoLayer_tipCollision.grle", vec![5]),
                 ("TestMap/maps/placeables.xml", b"<placeables />".to_vec()),
                 ("TestMap/maps/data/not_needed.txt", b"x".to_vec()),
                 ("TestMap/README.txt", b"ignored".to_vec()),
@@ -1030,10 +1978,30 @@ mod tests {
         let options = OverviewOptions {
             terrain: false,
             hillshade: true,
+            hillshade_params: HillshadeParams::default(),
             farmlands: true,
             farmland_ids: false,
+            farmland_prices: false,
+            farmland_ownership: None,
+            area_labels: false,
             pois: true,
+            poi_clusters: true,
             legend: false,
+            legend_options: LegendOptions::default(),
+            title_bar: true,
+            title_bar_options: TitleBarOptions::default(),
+            forest: false,
+            water: false,
+            fields: false,
+            roads: false,
+            contours: None,
+            placement_collision: true,
+            network: None,
+            output_size: None,
+            supersample: 1,
+            terrain_palette: None,
+            map_image: false,
+            cache_dir: None,
         };
         let map_info = test_map_info_with_placeables();
 
@@ -1056,6 +2024,14 @@ mod tests {
             &files,
             "TestMap/maps/data/infoLayer_farmlands.png"
         ));
+        assert!(contains_normalized_path(
+            &files,
+            "TestMap/maps/data/infoLayer_placementCollision.grle"
+        ));
+        assert!(!contains_normalized_path(
+            &files,
+            "TestMap/maps/data/infoLayer_tipCollision.grle"
+        ));
         assert!(contains_normalized_path(
             &files,
             "TestMap/maps/placeables.xml"
@@ -1112,6 +2088,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn generate_overview_supersamples_and_downscales_to_output_size() {
+        let map_info = test_map_info_with_placeables();
+        let options = OverviewOptions {
+            output_size: Some(16),
+            supersample: 2,
+            ..OverviewOptions::default()
+        };
+
+        let image = generate_overview(&HashMap::new(), &map_info, &options)
+            .expect("Overview ohne Eingabedateien muss generiert werden");
+
+        assert_eq!(image.dimensions(), (16, 16));
+        assert_eq!(options.render_size_for(map_info.map_size), 32);
+    }
+
+    #[test]
+    fn generate_overview_with_progress_reports_stages_and_supports_cancellation() {
+        let map_info = test_map_info_with_placeables();
+        let options = OverviewOptions {
+            output_size: Some(16),
+            ..OverviewOptions::default()
+        };
+
+        let mut reported = Vec::new();
+        generate_overview_with_progress(&HashMap::new(), &map_info, &options, &mut |progress| {
+            reported.push(progress);
+            true
+        })
+        .expect("Overview muss ohne Abbruch generiert werden");
+        assert_eq!(
+            reported.first().map(|p| p.stage),
+            Some(OverviewStage::Terrain)
+        );
+        assert_eq!(reported.last().map(|p| p.percent), Some(100));
+        assert!(reported.windows(2).all(|w| w[0].percent <= w[1].percent));
+
+        let error =
+            generate_overview_with_progress(&HashMap::new(), &map_info, &options, &mut |p| {
+                p.stage != OverviewStage::Roads
+            })
+            .expect_err("Callback verlangt Abbruch");
+        assert!(error.is::<OverviewCancelled>());
+    }
+
     #[test]
     fn field_detection_source_defaults_to_zip_ground_gdm() {
         assert_eq!(
@@ -0,0 +1,453 @@
+//! AutoDrive-Netzwerk-Layer: Nodes, Verbindungen und Map-Marker auf der Overview.
+//!
+//! Das Overview-Crate kennt die Editor-Typen (`RoadMap`) nicht. Stattdessen
+//! beschreibt [`AutoDriveNetwork`] das Netz in einer schlanken, eigenen Form,
+//! die entweder direkt aus einer `AutoDrive_config.xml` geparst oder vom
+//! Aufrufer aus seinem eigenen Datenmodell befuellt wird.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use image::{Rgb, RgbImage};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::draw::{draw_disc, draw_thick_line};
+use crate::text;
+
+/// Farbe fuer regulaere Einrichtungs-Verbindungen (Blau, wie im Editor).
+pub(crate) const NETWORK_COLOR_REGULAR: [u8; 3] = [0, 177, 255];
+/// Farbe fuer bidirektionale Verbindungen (Hellgruen, wie im Editor).
+pub(crate) const NETWORK_COLOR_DUAL: [u8; 3] = [227, 255, 101];
+/// Farbe fuer Rueckwaerts-Verbindungen (Orange, wie im Editor).
+pub(crate) const NETWORK_COLOR_REVERSE: [u8; 3] = [255, 128, 26];
+/// Farbe der Map-Marker (Orange, wie im Editor).
+pub(crate) const NETWORK_COLOR_MARKER: [u8; 3] = [255, 126, 0];
+
+/// Fahrtrichtung einer Netzwerk-Verbindung.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetworkDirection {
+    /// Einrichtungs-Verbindung von `start` nach `end`
+    #[default]
+    Regular,
+    /// Bidirektionale Verbindung
+    Dual,
+    /// Rueckwaerts-Verbindung (Fahrzeug faehrt rueckwaerts von `start` nach `end`)
+    Reverse,
+}
+
+/// Ein Wegpunkt des AutoDrive-Netzes in Weltkoordinaten.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkNode {
+    /// AutoDrive-Node-ID
+    pub id: u64,
+    /// Welt-X-Koordinate (Kartenmitte = 0)
+    pub x: f32,
+    /// Welt-Z-Koordinate (Kartenmitte = 0)
+    pub z: f32,
+    /// Node gehoert zu einer Sub-Prioritaets-Strecke
+    pub sub_prio: bool,
+}
+
+/// Eine gerichtete Verbindung zwischen zwei Wegpunkten.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkConnection {
+    /// Start-Node-ID
+    pub start: u64,
+    /// End-Node-ID
+    pub end: u64,
+    /// Fahrtrichtung
+    pub direction: NetworkDirection,
+    /// Sub-Prioritaets-Verbindung (wird duenner gezeichnet)
+    pub sub_prio: bool,
+}
+
+/// Ein benannter Map-Marker (Ziel) im AutoDrive-Netz.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkMarker {
+    /// Node-ID, auf die der Marker zeigt
+    pub node_id: u64,
+    /// Anzeigename
+    pub name: String,
+}
+
+/// Schlanke Beschreibung eines AutoDrive-Netzes fuer die Overview-Ausgabe.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AutoDriveNetwork {
+    /// Alle Wegpunkte
+    pub nodes: Vec<NetworkNode>,
+    /// Alle Verbindungen (Dual-Verbindungen nur einmal pro Paar)
+    pub connections: Vec<NetworkConnection>,
+    /// Map-Marker
+    pub markers: Vec<NetworkMarker>,
+}
+
+impl AutoDriveNetwork {
+    /// Liest das Netz aus einer `AutoDrive_config.xml` auf der Festplatte.
+    pub fn from_autodrive_xml_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .with_context(|| format!("AutoDrive-Config nicht lesbar: {}", path.display()))?;
+        Self::from_autodrive_xml(&data)
+            .with_context(|| format!("AutoDrive-Config ungueltig: {}", path.display()))
+    }
+
+    /// Parst das Netz aus dem Inhalt einer `AutoDrive_config.xml`.
+    ///
+    /// Ausgewertet werden die Listen `id`, `x`, `z`, `out`, `incoming` und
+    /// `flags` im `<waypoints>`-Block sowie die `<mapmarker>`-Eintraege.
+    /// Die Richtungslogik entspricht dem Editor-Parser: Steht der Start in der
+    /// Outgoing-Liste des Ziels, ist die Verbindung bidirektional; fehlt er in
+    /// dessen Incoming-Liste, ist es eine Rueckwaerts-Verbindung.
+    pub fn from_autodrive_xml(data: &[u8]) -> Result<Self> {
+        let raw = read_raw_lists(data)?;
+
+        let ids: Vec<u64> = parse_number_list(&raw.ids).context("Ungueltige ID-Liste")?;
+        let xs: Vec<f32> = parse_number_list(&raw.x).context("Ungueltige X-Liste")?;
+        let zs: Vec<f32> = parse_number_list(&raw.z).context("Ungueltige Z-Liste")?;
+        let flags: Vec<u32> = if raw.flags.trim().is_empty() {
+            vec![0; ids.len()]
+        } else {
+            parse_number_list(&raw.flags).context("Ungueltige Flag-Liste")?
+        };
+        let outgoing = parse_nested_list(&raw.out).context("Ungueltige Outgoing-Liste")?;
+        let incoming = parse_nested_list(&raw.incoming).context("Ungueltige Incoming-Liste")?;
+
+        let len = ids.len();
+        if xs.len() != len || zs.len() != len || flags.len() != len || outgoing.len() != len {
+            bail!("Laengen der Waypoint-Listen stimmen nicht ueberein");
+        }
+
+        let nodes: Vec<NetworkNode> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| NetworkNode {
+                id,
+                x: xs[i],
+                z: zs[i],
+                // AutoDrive-Flag 1 = Sub-Prioritaet
+                sub_prio: flags[i] == 1,
+            })
+            .collect();
+        let index_of: HashMap<u64, usize> =
+            ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut connections = Vec::new();
+        let mut dual_pairs: HashSet<(u64, u64)> = HashSet::new();
+        for (i, &start) in ids.iter().enumerate() {
+            for &end in &outgoing[i] {
+                if end == start {
+                    continue;
+                }
+                let Some(&target) = index_of.get(&end) else {
+                    continue;
+                };
+                let target_incoming = incoming.get(target).map(Vec::as_slice).unwrap_or(&[]);
+                let direction = if outgoing[target].contains(&start) {
+                    NetworkDirection::Dual
+                } else if !target_incoming.contains(&start) {
+                    NetworkDirection::Reverse
+                } else {
+                    NetworkDirection::Regular
+                };
+                if direction == NetworkDirection::Dual
+                    && !dual_pairs.insert((start.min(end), start.max(end)))
+                {
+                    continue;
+                }
+                connections.push(NetworkConnection {
+                    start,
+                    end,
+                    direction,
+                    sub_prio: nodes[target].sub_prio,
+                });
+            }
+        }
+
+        let markers = raw
+            .markers
+            .into_iter()
+            .filter_map(|(id, name)| {
+                let node_id = id.trim().parse::<f64>().ok()? as u64;
+                Some(NetworkMarker { node_id, name })
+            })
+            .collect();
+
+        Ok(Self {
+            nodes,
+            connections,
+            markers,
+        })
+    }
+
+    /// Gibt `true` zurueck, wenn das Netz keine Nodes enthaelt.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Zeichnet Verbindungen, Richtungspfeile und Map-Marker auf ein RGB-Bild.
+///
+/// Weltkoordinaten (Kartenmitte = 0) werden ueber `map_size` auf die
+/// Bildgroesse abgebildet, sodass das Netz unabhaengig von der Ausgabe-
+/// Aufloesung deckungsgleich mit dem Terrain liegt.
+pub fn draw_network(image: &mut RgbImage, network: &AutoDriveNetwork, map_size: u32) {
+    if network.is_empty() || map_size == 0 {
+        return;
+    }
+
+    let scale = image.width() as f32 / map_size as f32;
+    let half = map_size as f32 / 2.0;
+    let to_pixel = |x: f32, z: f32| ((x + half) * scale, (z + half) * scale);
+    let positions: HashMap<u64, (f32, f32)> = network
+        .nodes
+        .iter()
+        .map(|node| (node.id, to_pixel(node.x, node.z)))
+        .collect();
+
+    let thickness = (image.width() as f32 / 2048.0).max(1.0);
+    let arrow_len = (thickness * 4.0).max(4.0);
+
+    for connection in &network.connections {
+        let (Some(&a), Some(&b)) = (
+            positions.get(&connection.start),
+            positions.get(&connection.end),
+        ) else {
+            continue;
+        };
+        let color = Rgb(match connection.direction {
+            NetworkDirection::Regular => NETWORK_COLOR_REGULAR,
+            NetworkDirection::Dual => NETWORK_COLOR_DUAL,
+            NetworkDirection::Reverse => NETWORK_COLOR_REVERSE,
+        });
+        let width = if connection.sub_prio {
+            (thickness * 0.5).max(1.0)
+        } else {
+            thickness
+        };
+        draw_thick_line(image, a, b, width, color);
+        if connection.direction != NetworkDirection::Dual {
+            draw_arrow_head(image, a, b, arrow_len, color);
+        }
+    }
+
+    let marker_radius = (image.width() / 700).max(4) as i32;
+    let label_scale = (image.width() / 1600).clamp(1, 3);
+    for marker in &network.markers {
+        let Some(&(px, py)) = positions.get(&marker.node_id) else {
+            continue;
+        };
+        let (cx, cy) = (px.round() as i32, py.round() as i32);
+        draw_disc(image, cx, cy, marker_radius + 1, Rgb([0, 0, 0]));
+        draw_disc(image, cx, cy, marker_radius, Rgb(NETWORK_COLOR_MARKER));
+        let ty = cy - text::text_height(label_scale) as i32 / 2;
+        text::draw_text_outlined(
+            image,
+            cx + marker_radius + 3,
+            ty,
+            &marker.name,
+            Rgb([255, 255, 255]),
+            label_scale,
+        );
+    }
+}
+
+/// Rohe Text-Listen aus dem `<waypoints>`-Block plus Marker-(ID, Name)-Paare.
+#[derive(Default)]
+struct RawNetworkLists {
+    ids: String,
+    x: String,
+    z: String,
+    out: String,
+    incoming: String,
+    flags: String,
+    markers: Vec<(String, String)>,
+}
+
+fn read_raw_lists(data: &[u8]) -> Result<RawNetworkLists> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    let mut raw = RawNetworkLists::default();
+    let mut path: Vec<String> = Vec::new();
+    let mut marker_id = String::new();
+    let mut marker_name = String::new();
+    let mut found_waypoints = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "waypoints" {
+                    found_waypoints = true;
+                }
+                path.push(name);
+            }
+            Ok(Event::End(_)) => {
+                // Marker-Eintrag (mapmarker/mmN) abgeschlossen
+                if path.len() >= 2 && path[path.len() - 2] == "mapmarker" {
+                    raw.markers.push((
+                        std::mem::take(&mut marker_id),
+                        std::mem::take(&mut marker_name),
+                    ));
+                }
+                path.pop();
+            }
+            Ok(Event::Text(e)) => {
+                let value = String::from_utf8_lossy(e.as_ref()).to_string();
+                let depth = path.len();
+                if depth >= 2 && path[depth - 2] == "waypoints" {
+                    let target = match path[depth - 1].as_str() {
+                        "id" => Some(&mut raw.ids),
+                        "x" => Some(&mut raw.x),
+                        "z" => Some(&mut raw.z),
+                        "out" => Some(&mut raw.out),
+                        "incoming" => Some(&mut raw.incoming),
+                        "flags" => Some(&mut raw.flags),
+                        _ => None,
+                    };
+                    if let Some(target) = target {
+                        target.push_str(&value);
+                    }
+                } else if depth >= 3 && path[depth - 3] == "mapmarker" {
+                    match path[depth - 1].as_str() {
+                        "id" => marker_id.push_str(&value),
+                        "name" => marker_name.push_str(&value),
+                        _ => {}
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => bail!("XML-Fehler an Position {}: {}", reader.buffer_position(), e),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    if !found_waypoints {
+        bail!("Kein <waypoints>-Block gefunden – ist das eine AutoDrive-Config?");
+    }
+    Ok(raw)
+}
+
+fn parse_number_list<T: std::str::FromStr>(text: &str) -> Result<Vec<T>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .parse::<T>()
+                .map_err(|_| anyhow::anyhow!("Wert '{}' konnte nicht geparst werden", value))
+        })
+        .collect()
+}
+
+fn parse_nested_list(text: &str) -> Result<Vec<Vec<u64>>> {
+    if text.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    text.split(';')
+        .map(|group| {
+            parse_number_list::<i64>(group)
+                // AutoDrive kodiert "keine Verbindung" als -1
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .filter(|&v| v > 0)
+                        .map(|v| v as u64)
+                        .collect()
+                })
+        })
+        .collect()
+}
+
+/// Zeichnet eine Pfeilspitze in der Mitte der Strecke `a → b`.
+fn draw_arrow_head(
+    image: &mut RgbImage,
+    a: (f32, f32),
+    b: (f32, f32),
+    length: f32,
+    color: Rgb<u8>,
+) {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let segment_len = (dx * dx + dy * dy).sqrt();
+    // Zu kurze Segmente bekommen keinen Pfeil, sonst verdeckt er die Linie
+    if segment_len < length * 2.0 {
+        return;
+    }
+    let (ux, uy) = (dx / segment_len, dy / segment_len);
+    let tip = (
+        (a.0 + b.0) / 2.0 + ux * length / 2.0,
+        (a.1 + b.1) / 2.0 + uy * length / 2.0,
+    );
+    let back = (tip.0 - ux * length, tip.1 - uy * length);
+    let wing = length * 0.5;
+    let left = (back.0 - uy * wing, back.1 + ux * wing);
+    let right = (back.0 + uy * wing, back.1 - ux * wing);
+    draw_thick_line(image, tip, left, 1.0, color);
+    draw_thick_line(image, tip, right, 1.0, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<AutoDrive>
+    <waypoints>
+        <id>1,2,3</id>
+        <x>-10.0,0.0,10.0</x>
+        <y>0,0,0</y>
+        <z>0.0,0.0,0.0</z>
+        <out>2;1,3;-1</out>
+        <incoming>2;1;2</incoming>
+        <flags>0,0,1</flags>
+    </waypoints>
+    <mapmarker>
+        <mm1>
+            <id>3.000000</id>
+            <name>Hof</name>
+            <group>All</group>
+        </mm1>
+    </mapmarker>
+</AutoDrive>"#;
+
+    #[test]
+    fn from_autodrive_xml_resolves_directions_and_markers() {
+        let network = AutoDriveNetwork::from_autodrive_xml(SAMPLE_XML.as_bytes())
+            .expect("Beispiel-XML muss geparst werden");
+
+        assert_eq!(network.nodes.len(), 3);
+        assert!(network.nodes[2].sub_prio);
+        assert_eq!(network.connections.len(), 2);
+        assert_eq!(network.connections[0].direction, NetworkDirection::Dual);
+        assert_eq!(network.connections[1].direction, NetworkDirection::Regular);
+        assert!(network.connections[1].sub_prio);
+        assert_eq!(
+            network.markers,
+            vec![NetworkMarker {
+                node_id: 3,
+                name: String::from("Hof"),
+            }]
+        );
+    }
+
+    #[test]
+    fn from_autodrive_xml_rejects_non_autodrive_documents() {
+        assert!(AutoDriveNetwork::from_autodrive_xml(b"<modDesc />").is_err());
+    }
+
+    #[test]
+    fn draw_network_paints_connection_colors() {
+        let network = AutoDriveNetwork::from_autodrive_xml(SAMPLE_XML.as_bytes())
+            .expect("Beispiel-XML muss geparst werden");
+        let mut image = RgbImage::new(32, 32);
+
+        draw_network(&mut image, &network, 32);
+
+        // Mitte der Dual-Verbindung zwischen Node 1 (x=6) und Node 2 (x=16)
+        assert_eq!(*image.get_pixel(10, 16), Rgb(NETWORK_COLOR_DUAL));
+    }
+}
@@ -2,18 +2,172 @@
 //!
 //! Ordnet Weight-Map-Dateinamen RGB-Farben zu.
 //! Basierend auf tatsaechlichen FS25-Terrain-Texturen.
+//!
+//! Eigene Karten mit exotischen Layer-Namen koennen ueber eine
+//! [`TerrainPalette`] (TOML oder JSON) eigene Farben vorgeben, die vor der
+//! eingebauten Zuordnung geprueft werden.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::discovery::weight_map_stem;
 
 /// RGB-Farbwert
 pub type Rgb = [u8; 3];
 
+/// Eine Regel einer eigenen Terrain-Palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteRule {
+    /// Namensmuster (case-insensitive, `*` = beliebig viele Zeichen),
+    /// verglichen mit dem Weight-Map-Namen ohne `_weight.png` (bzw. `.dds`/`.gdm`)
+    pub pattern: String,
+    /// Farbe fuer passende Weight-Maps
+    pub color: Rgb,
+    /// Vorrang bei mehreren passenden Regeln (hoeher gewinnt)
+    pub priority: i32,
+}
+
+/// Benutzerdefinierte Terrain-Palette.
+///
+/// Passende Regeln haben Vorrang vor der eingebauten Palette; bei mehreren
+/// Treffern gewinnt die hoechste `priority`, bei Gleichstand die erste Regel
+/// der Datei. TOML-Beispiel:
+///
+/// ```toml
+/// [[rules]]
+/// pattern = "myMossyStone*"
+/// color = "#7a8470"
+/// priority = 10
+///
+/// [[rules]]
+/// pattern = "*Swamp*"
+/// color = [70, 80, 55]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TerrainPalette {
+    /// Regeln, absteigend nach Prioritaet sortiert (stabil)
+    rules: Vec<PaletteRule>,
+}
+
+#[derive(Deserialize)]
+struct PaletteFile {
+    #[serde(default)]
+    rules: Vec<PaletteRuleFile>,
+}
+
+#[derive(Deserialize)]
+struct PaletteRuleFile {
+    pattern: String,
+    color: ColorValue,
+    #[serde(default)]
+    priority: i32,
+}
+
+/// Farbe als `[r, g, b]` oder Hex-String `"#rrggbb"`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ColorValue {
+    Array(Rgb),
+    Hex(String),
+}
+
+impl ColorValue {
+    fn to_rgb(&self) -> Result<Rgb> {
+        match self {
+            Self::Array(rgb) => Ok(*rgb),
+            Self::Hex(hex) => parse_hex_color(hex),
+        }
+    }
+}
+
+impl TerrainPalette {
+    /// Erstellt eine Palette aus Regeln (Reihenfolge = Vorrang bei gleicher Prioritaet).
+    pub fn new(mut rules: Vec<PaletteRule>) -> Self {
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.priority));
+        Self { rules }
+    }
+
+    /// Parst eine Palette im TOML-Format.
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let file: PaletteFile =
+            toml::from_str(content).context("Ungueltige TOML-Terrain-Palette")?;
+        Self::from_file(file)
+    }
+
+    /// Parst eine Palette im JSON-Format.
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        let file: PaletteFile =
+            serde_json::from_str(content).context("Ungueltige JSON-Terrain-Palette")?;
+        Self::from_file(file)
+    }
+
+    /// Liest eine Palette von der Festplatte; das Format folgt der Endung (`.toml`/`.json`).
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Terrain-Palette nicht lesbar: {}", path.display()))?;
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("json") => Self::from_json_str(&content),
+            _ => bail!(
+                "Unbekanntes Palettenformat (erwartet .toml oder .json): {}",
+                path.display()
+            ),
+        }
+    }
+
+    /// Alle Regeln in Auswertungsreihenfolge.
+    pub fn rules(&self) -> &[PaletteRule] {
+        &self.rules
+    }
+
+    /// Farbe der ersten passenden Regel fuer einen Weight-Map-Namen.
+    pub fn color_for(&self, weight_map_name: &str) -> Option<Rgb> {
+        let stem = weight_map_stem(weight_map_name).to_ascii_lowercase();
+        self.rules
+            .iter()
+            .find(|rule| glob_match(&rule.pattern.to_ascii_lowercase(), &stem))
+            .map(|rule| rule.color)
+    }
+
+    fn from_file(file: PaletteFile) -> Result<Self> {
+        let rules = file
+            .rules
+            .into_iter()
+            .map(|rule| {
+                let color = rule
+                    .color
+                    .to_rgb()
+                    .with_context(|| format!("Ungueltige Farbe fuer '{}'", rule.pattern))?;
+                Ok(PaletteRule {
+                    pattern: rule.pattern,
+                    color,
+                    priority: rule.priority,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(rules))
+    }
+}
+
+/// Gibt die Terrain-Farbe unter Beruecksichtigung einer optionalen eigenen Palette zurueck.
+pub fn terrain_color_with(weight_map_name: &str, custom: Option<&TerrainPalette>) -> Rgb {
+    custom
+        .and_then(|palette| palette.color_for(weight_map_name))
+        .unwrap_or_else(|| terrain_color(weight_map_name))
+}
+
 /// Gibt die Terrain-Farbe fuer einen Weight-Map-Dateinamen zurueck.
 ///
 /// Sucht zuerst exakt, dann Prefix-Match, dann Keyword-Fallback.
 /// Gibt bei keinem Match ein neutrales Grau zurueck.
 pub fn terrain_color(weight_map_name: &str) -> Rgb {
-    let stem = weight_map_name
-        .strip_suffix("_weight.png")
-        .unwrap_or(weight_map_name);
+    let stem = weight_map_stem(weight_map_name);
     // Trailing-Ziffern entfernen (z.B. "grass2" → "grass")
     let stem = stem.trim_end_matches(|c: char| c.is_ascii_digit());
 
@@ -185,6 +339,40 @@ fn keyword_match(stem: &str) -> Option<Rgb> {
     None
 }
 
+/// Parst `#rrggbb` bzw. `rrggbb`.
+fn parse_hex_color(hex: &str) -> Result<Rgb> {
+    let digits = hex.trim().trim_start_matches('#');
+    if digits.len() != 6 || !digits.is_ascii() {
+        bail!("Hex-Farbe muss 6 Stellen haben: '{}'", hex);
+    }
+    let channel = |i: usize| {
+        u8::from_str_radix(&digits[i..i + 2], 16)
+            .with_context(|| format!("Ungueltige Hex-Farbe: '{}'", hex))
+    };
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// Einfacher Glob-Vergleich mit `*` als Platzhalter (beide Seiten bereits kleingeschrieben).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // Kein `*`: exakter Vergleich
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +389,12 @@ mod tests {
         assert_eq!(terrain_color("grass2_weight.png"), [90, 150, 60]);
     }
 
+    #[test]
+    fn test_strips_dds_and_gdm_suffix() {
+        assert_eq!(terrain_color("grass_weight.dds"), [90, 150, 60]);
+        assert_eq!(terrain_color("grass_weight.gdm"), [90, 150, 60]);
+    }
+
     #[test]
     fn test_prefix_match() {
         // "asphaltNewType" → prefix "asphalt"
@@ -216,4 +410,52 @@ mod tests {
     fn test_unknown_returns_grey() {
         assert_eq!(terrain_color("xyzUnknown"), [130, 130, 128]);
     }
+
+    #[test]
+    fn test_custom_palette_overrides_builtin_by_priority() {
+        let palette = TerrainPalette::from_toml_str(
+            r##"
+[[rules]]
+pattern = "*moss*"
+color = [1, 2, 3]
+
+[[rules]]
+pattern = "exoticMoss*"
+color = "#0a0b0c"
+priority = 5
+"##,
+        )
+        .expect("Palette muss parsen");
+
+        assert_eq!(
+            terrain_color_with("exoticMossStone02_weight.png", Some(&palette)),
+            [10, 11, 12]
+        );
+        assert_eq!(
+            terrain_color_with("forestMossGround_weight.png", Some(&palette)),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            terrain_color_with("grass_weight.png", Some(&palette)),
+            [90, 150, 60]
+        );
+    }
+
+    #[test]
+    fn test_custom_palette_from_json() {
+        let palette = TerrainPalette::from_json_str(
+            r#"{"rules": [{"pattern": "swamp", "color": [70, 80, 55]}]}"#,
+        )
+        .expect("JSON-Palette muss parsen");
+        assert_eq!(palette.color_for("Swamp_weight.png"), Some([70, 80, 55]));
+        assert_eq!(palette.color_for("swampDeep_weight.png"), None);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("a*c", "abbc"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("a*c", "abcd"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+    }
 }
@@ -0,0 +1,184 @@
+//! Bebauungsverbots-Layer: schraffierte Sperrflaechen aus dem Placement-Collision-InfoLayer.
+//!
+//! `infoLayer_placementCollision.grle` markiert Flaechen, auf denen keine
+//! Platzierbaren (Produktionen, Silos, Gebaeude) gebaut werden duerfen;
+//! `infoLayer_placementCollisionGenerated.grle` ergaenzt die beim Map-Export
+//! erzeugten Sperren. Jeder Pixel ungleich 0 gilt als gesperrt. Die
+//! Sperrflaechen werden leicht getoent und diagonal schraffiert, damit das
+//! Terrain darunter erkennbar bleibt.
+
+use anyhow::Result;
+use image::imageops::{self, FilterType};
+use image::{GrayImage, RgbImage};
+use rayon::prelude::*;
+
+use crate::{dds, grle};
+
+/// Farbe von Toenung und Schraffur der Sperrflaechen (Signalrot).
+pub(crate) const COLLISION_COLOR: [u8; 3] = [220, 50, 50];
+
+/// Deckkraft der flaechigen Toenung gesperrter Pixel.
+const TINT_ALPHA: f32 = 0.18;
+
+/// Deckkraft der Schraffurlinien.
+const HATCH_ALPHA: f32 = 0.75;
+
+/// Fuehrt alle Placement-Collision-Layer zu einer Sperrmaske zusammen.
+///
+/// `layers` enthaelt Pfad und Rohdaten (`.grle` oder Bildformat); die Maske
+/// hat `target_size`² Pixel mit 255 = gesperrt. Nicht dekodierbare Layer
+/// werden uebersprungen. Gibt `None` zurueck, wenn kein Layer lesbar war.
+pub fn extract_placement_collision(
+    layers: &[(&str, &[u8])],
+    target_size: u32,
+) -> Option<GrayImage> {
+    if target_size == 0 {
+        return None;
+    }
+
+    let mut combined: Option<GrayImage> = None;
+    for &(path, data) in layers {
+        let mask = match decode_collision_mask(path, data) {
+            Ok(mask) => mask,
+            Err(e) => {
+                log::warn!("Placement-Collision '{}' nicht lesbar: {}", path, e);
+                continue;
+            }
+        };
+        // Nearest, damit Sperrkanten scharf bleiben und keine Zwischenwerte entstehen
+        let mask = if mask.dimensions() != (target_size, target_size) {
+            imageops::resize(&mask, target_size, target_size, FilterType::Nearest)
+        } else {
+            mask
+        };
+        match &mut combined {
+            Some(acc) => {
+                for (dst, &src) in acc.iter_mut().zip(mask.as_raw()) {
+                    *dst = (*dst).max(src);
+                }
+            }
+            None => combined = Some(mask),
+        }
+    }
+    combined
+}
+
+/// Dekodiert einen Collision-Layer zu einer 0/255-Maske in Originalgroesse.
+fn decode_collision_mask(path: &str, data: &[u8]) -> Result<GrayImage> {
+    let (width, height, pixels) = if path.to_ascii_lowercase().ends_with(".grle") {
+        let decoded = grle::decode_grle(data)?;
+        (decoded.width as u32, decoded.height as u32, decoded.pixels)
+    } else {
+        let decoded = dds::decode_map_raster(data)?.to_luma8();
+        let (width, height) = decoded.dimensions();
+        (width, height, decoded.into_raw())
+    };
+    let mask = pixels
+        .into_iter()
+        .map(|value| if value != 0 { 255 } else { 0 })
+        .collect();
+    GrayImage::from_raw(width, height, mask)
+        .ok_or_else(|| anyhow::anyhow!("Pixelanzahl passt nicht zu {}x{}", width, height))
+}
+
+/// Toent gesperrte Flaechen und legt eine diagonale Schraffur darueber.
+///
+/// Linienabstand und -breite wachsen mit der Bildgroesse, damit die
+/// Schraffur bei Supersampling und grossen Ausgaben gleich dicht wirkt.
+pub fn draw_placement_collision(image: &mut RgbImage, mask: &GrayImage) {
+    debug_assert_eq!(image.dimensions(), mask.dimensions());
+
+    let width = image.width() as usize;
+    let spacing = (image.width() / 256).max(6) as usize;
+    let line_width = (spacing / 3).max(1);
+    let mask = mask.as_raw();
+
+    image
+        .par_chunks_mut((width * 3).max(1))
+        .enumerate()
+        .for_each(|(y, row)| {
+            for (x, pixel) in row.chunks_exact_mut(3).enumerate() {
+                if mask[y * width + x] == 0 {
+                    continue;
+                }
+                let alpha = if (x + y) % spacing < line_width {
+                    HATCH_ALPHA
+                } else {
+                    TINT_ALPHA
+                };
+                for (channel, &color) in pixel.iter_mut().zip(&COLLISION_COLOR) {
+                    *channel = (*channel as f32 * (1.0 - alpha) + color as f32 * alpha)
+                        .round()
+                        .clamp(0.0, 255.0) as u8;
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Luma, Rgb};
+
+    fn grle_bytes(width: usize, height: usize, pixels: Vec<u8>) -> Vec<u8> {
+        grle::encode_grle(&grle::GrleImage {
+            width,
+            height,
+            pixels,
+        })
+        .expect("GRLE muss kodierbar sein")
+    }
+
+    #[test]
+    fn extract_placement_collision_merges_layers_and_scales() {
+        let mut base = vec![0u8; 256 * 256];
+        base[0] = 3;
+        let mut generated = vec![0u8; 256 * 256];
+        generated[256 * 256 - 1] = 1;
+        let base = grle_bytes(256, 256, base);
+        let generated = grle_bytes(256, 256, generated);
+
+        let mask = extract_placement_collision(
+            &[
+                ("data/infoLayer_placementCollision.grle", &base),
+                (
+                    "data/infoLayer_placementCollisionGenerated.grle",
+                    &generated,
+                ),
+            ],
+            512,
+        )
+        .expect("Sperrmaske erwartet");
+
+        assert_eq!(mask.dimensions(), (512, 512));
+        assert_eq!(mask.get_pixel(1, 1)[0], 255);
+        assert_eq!(mask.get_pixel(511, 511)[0], 255);
+        assert_eq!(mask.get_pixel(256, 256)[0], 0);
+    }
+
+    #[test]
+    fn extract_placement_collision_skips_unreadable_layers() {
+        let layers: [(&str, &[u8]); 1] = [("data/infoLayer_placementCollision.grle", b"nope")];
+        assert!(extract_placement_collision(&layers, 64).is_none());
+    }
+
+    #[test]
+    fn draw_placement_collision_hatches_only_blocked_pixels() {
+        let mut image = RgbImage::from_pixel(12, 12, Rgb([100, 100, 100]));
+        let mut mask = GrayImage::new(12, 12);
+        for y in 0..12 {
+            for x in 0..6 {
+                mask.put_pixel(x, y, Luma([255]));
+            }
+        }
+
+        draw_placement_collision(&mut image, &mask);
+
+        // (0,0) liegt auf einer Schraffurlinie, (3,1) nur in der Toenung
+        let hatch = image.get_pixel(0, 0);
+        let tint = image.get_pixel(3, 1);
+        assert!(hatch[0] > tint[0] && tint[0] > 100);
+        assert!(tint[1] < 100);
+        assert_eq!(*image.get_pixel(9, 9), Rgb([100, 100, 100]));
+    }
+}
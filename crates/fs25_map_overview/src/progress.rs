@@ -0,0 +1,224 @@
+//! Fortschrittsmeldungen und Abbruch waehrend der Overview-Generierung.
+//!
+//! Aufrufer uebergeben einen Callback, der vor jedem Arbeitsschritt mit Stufe
+//! und Prozentwert aufgerufen wird. Gibt der Callback `false` zurueck, bricht
+//! die Generierung mit [`OverviewCancelled`] ab. Ein Kanal laesst sich direkt
+//! anbinden: `|progress| sender.send(progress).is_ok()` bricht ab, sobald der
+//! Empfaenger verworfen wird.
+
+use std::fmt;
+
+use anyhow::Result;
+
+/// Arbeitsschritt der Overview-Generierung in Ausfuehrungsreihenfolge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverviewStage {
+    /// Kartenstruktur erkennen (modDesc.xml, Map-Config)
+    Discovery,
+    /// Benoetigte Layer-Dateien aus dem ZIP lesen
+    ReadingArchive,
+    /// Weight-Maps laden und zum Terrain compositen
+    Terrain,
+    /// Wald-Kronendach
+    Forest,
+    /// Gewaesser
+    Water,
+    /// Hillshade aus dem DEM
+    Hillshade,
+    /// Hoehenlinien
+    Contours,
+    /// Bebauungsverbotszonen
+    PlacementCollision,
+    /// Farmland-Grenzen, IDs, Preise und Besitzer
+    Farmlands,
+    /// Feldgrenzen und Feldnummern
+    Fields,
+    /// Strassen-Splines
+    Roads,
+    /// POI-Marker
+    Pois,
+    /// AutoDrive-Netz
+    Network,
+    /// Legende und Titel-Bar
+    Legend,
+    /// Herunterskalieren und Zusammensetzen des Ergebnisses
+    Finishing,
+}
+
+impl OverviewStage {
+    /// Geschaetzter Gesamtfortschritt (0–100) zu Beginn dieser Stufe.
+    ///
+    /// Die Gewichte spiegeln die typische Laufzeit: ZIP-Lesen und
+    /// Terrain-Compositing dominieren, Vektor-Overlays sind guenstig.
+    pub const fn percent(self) -> u8 {
+        match self {
+            Self::Discovery => 0,
+            Self::ReadingArchive => 5,
+            Self::Terrain => 25,
+            Self::Forest => 50,
+            Self::Water => 55,
+            Self::Hillshade => 62,
+            Self::Contours => 70,
+            Self::PlacementCollision => 73,
+            Self::Farmlands => 75,
+            Self::Fields => 80,
+            Self::Roads => 85,
+            Self::Pois => 88,
+            Self::Network => 90,
+            Self::Legend => 93,
+            Self::Finishing => 95,
+        }
+    }
+
+    /// Kurze deutsche Beschreibung fuer Statuszeilen.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Discovery => "Kartenstruktur wird erkannt",
+            Self::ReadingArchive => "ZIP wird gelesen",
+            Self::Terrain => "Terrain wird berechnet",
+            Self::Forest => "Wald wird gezeichnet",
+            Self::Water => "Gewaesser werden gezeichnet",
+            Self::Hillshade => "Hillshade wird berechnet",
+            Self::Contours => "Hoehenlinien werden gezeichnet",
+            Self::PlacementCollision => "Bebauungsverbotszonen werden gezeichnet",
+            Self::Farmlands => "Farmlands werden gezeichnet",
+            Self::Fields => "Feldgrenzen werden gezeichnet",
+            Self::Roads => "Strassen werden gezeichnet",
+            Self::Pois => "POIs werden gezeichnet",
+            Self::Network => "AutoDrive-Netz wird gezeichnet",
+            Self::Legend => "Legende wird gezeichnet",
+            Self::Finishing => "Bild wird fertiggestellt",
+        }
+    }
+}
+
+/// Einzelne Fortschrittsmeldung an den Callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverviewProgress {
+    /// Aktueller Arbeitsschritt
+    pub stage: OverviewStage,
+    /// Geschaetzter Gesamtfortschritt in Prozent (0–100)
+    pub percent: u8,
+}
+
+/// Fehler, wenn der Fortschritts-Callback die Generierung abgebrochen hat.
+///
+/// Wird in `anyhow::Error` verpackt zurueckgegeben; Aufrufer pruefen per
+/// `error.is::<OverviewCancelled>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverviewCancelled;
+
+impl fmt::Display for OverviewCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Overview-Generierung abgebrochen")
+    }
+}
+
+impl std::error::Error for OverviewCancelled {}
+
+/// Crate-interner Meldeweg; ohne Callback ist jede Meldung ein No-op.
+pub(crate) struct ProgressReporter<'a> {
+    callback: Option<&'a mut dyn FnMut(OverviewProgress) -> bool>,
+}
+
+impl<'a> ProgressReporter<'a> {
+    /// Reporter, der einen Aufrufer-Callback bedient.
+    pub(crate) fn new(callback: &'a mut dyn FnMut(OverviewProgress) -> bool) -> Self {
+        Self {
+            callback: Some(callback),
+        }
+    }
+
+    /// Reporter ohne Callback (bisherige API ohne Fortschritt).
+    pub(crate) fn none() -> Self {
+        Self { callback: None }
+    }
+
+    /// Meldet den Beginn von `stage`; `Err(OverviewCancelled)` bei Abbruch.
+    pub(crate) fn stage(&mut self, stage: OverviewStage) -> Result<()> {
+        self.report(OverviewProgress {
+            stage,
+            percent: stage.percent(),
+        })
+    }
+
+    /// Meldet den Abschluss der Generierung (100 %).
+    pub(crate) fn finish(&mut self) -> Result<()> {
+        self.report(OverviewProgress {
+            stage: OverviewStage::Finishing,
+            percent: 100,
+        })
+    }
+
+    fn report(&mut self, progress: OverviewProgress) -> Result<()> {
+        let keep_going = self
+            .callback
+            .as_mut()
+            .is_none_or(|callback| callback(progress));
+        if keep_going {
+            Ok(())
+        } else {
+            Err(OverviewCancelled.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reporter_forwards_stage_percent_and_cancels_on_false() {
+        let mut seen = Vec::new();
+        let mut callback = |progress: OverviewProgress| {
+            seen.push(progress);
+            progress.stage != OverviewStage::Roads
+        };
+        let mut reporter = ProgressReporter::new(&mut callback);
+
+        reporter
+            .stage(OverviewStage::Terrain)
+            .expect("kein Abbruch");
+        let error = reporter
+            .stage(OverviewStage::Roads)
+            .expect_err("Callback verlangt Abbruch");
+
+        assert!(error.is::<OverviewCancelled>());
+        assert_eq!(
+            seen,
+            vec![
+                OverviewProgress {
+                    stage: OverviewStage::Terrain,
+                    percent: 25
+                },
+                OverviewProgress {
+                    stage: OverviewStage::Roads,
+                    percent: 85
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stage_percentages_increase_monotonically() {
+        let stages = [
+            OverviewStage::Discovery,
+            OverviewStage::ReadingArchive,
+            OverviewStage::Terrain,
+            OverviewStage::Forest,
+            OverviewStage::Water,
+            OverviewStage::Hillshade,
+            OverviewStage::Contours,
+            OverviewStage::PlacementCollision,
+            OverviewStage::Farmlands,
+            OverviewStage::Fields,
+            OverviewStage::Roads,
+            OverviewStage::Pois,
+            OverviewStage::Network,
+            OverviewStage::Legend,
+            OverviewStage::Finishing,
+        ];
+        assert!(stages.windows(2).all(|w| w[0].percent() < w[1].percent()));
+        assert!(OverviewStage::Finishing.percent() < 100);
+    }
+}
@@ -0,0 +1,120 @@
+//! Eigenstaendige Reliefdarstellung eines DEM.
+//!
+//! Anders als der Hillshade-Layer der Overview, der die Terrain-Farben nur
+//! abdunkelt, entsteht hier ein Bild allein aus den Hoehendaten – etwa als
+//! alternativer Editor-Hintergrund, wenn keine Overview vorliegt oder das
+//! Gelaende selbst beurteilt werden soll.
+
+use anyhow::Result;
+use image::{DynamicImage, Rgb, RgbImage};
+
+use crate::hillshade::{self, HillshadeParams};
+
+/// Hypsometrische Farbstufen von tief nach hoch (Anteil am Hoehenbereich, Farbe).
+const GRADIENT_STOPS: [(f32, [u8; 3]); 5] = [
+    (0.0, [46, 110, 60]),
+    (0.3, [120, 165, 80]),
+    (0.55, [220, 205, 120]),
+    (0.8, [160, 110, 70]),
+    (1.0, [245, 245, 240]),
+];
+
+/// Darstellungsart des Reliefs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReliefStyle {
+    /// Reine Graustufen-Schattierung.
+    #[default]
+    Hillshade,
+    /// Hoehenfarben (gruen → braun → weiss) mit Hillshade ueberlagert.
+    Gradient,
+}
+
+/// Rendert das DEM als RGB-Reliefbild in DEM-Aufloesung.
+///
+/// Die Hoehenfarben von [`ReliefStyle::Gradient`] werden auf den tatsaechlich
+/// genutzten Hoehenbereich des DEM gestreckt, damit auch flache Karten Kontrast
+/// zeigen; die Schattierung wird mit `params.blend_factor` gemischt.
+pub fn render_relief(
+    dem: &DynamicImage,
+    style: ReliefStyle,
+    params: &HillshadeParams,
+) -> Result<RgbImage> {
+    // Volle 16-Bit-Aufloesung fuer Schattierung und Farbzuordnung
+    let heights = dem.to_luma16();
+    let shade = hillshade::compute_hillshade(&heights, params)?;
+
+    match style {
+        ReliefStyle::Hillshade => Ok(RgbImage::from_fn(
+            heights.width(),
+            heights.height(),
+            |x, y| {
+                let value = shade[(y * heights.width() + x) as usize];
+                let level = (value * 255.0).round() as u8;
+                Rgb([level, level, level])
+            },
+        )),
+        ReliefStyle::Gradient => {
+            let (min, max) = heights.pixels().fold((u16::MAX, u16::MIN), |(lo, hi), p| {
+                (lo.min(p[0]), hi.max(p[0]))
+            });
+            let range = (max.saturating_sub(min)).max(1) as f32;
+
+            let mut image = RgbImage::from_fn(heights.width(), heights.height(), |x, y| {
+                let t = (heights.get_pixel(x, y)[0].saturating_sub(min)) as f32 / range;
+                Rgb(gradient_color(t))
+            });
+            hillshade::apply_hillshade(image.as_mut(), &shade, params.blend_factor);
+            Ok(image)
+        }
+    }
+}
+
+/// Interpoliert die Hoehenfarbe fuer den relativen Hoehenwert `t` (0..=1).
+fn gradient_color(t: f32) -> [u8; 3] {
+    let t = t.clamp(0.0, 1.0);
+    for pair in GRADIENT_STOPS.windows(2) {
+        let ((t0, c0), (t1, c1)) = (pair[0], pair[1]);
+        if t <= t1 {
+            let f = (t - t0) / (t1 - t0);
+            return std::array::from_fn(|i| {
+                (c0[i] as f32 + (c1[i] as f32 - c0[i] as f32) * f).round() as u8
+            });
+        }
+    }
+    GRADIENT_STOPS[GRADIENT_STOPS.len() - 1].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Luma};
+
+    /// 16-Bit-DEM mit Anstieg in X-Richtung.
+    fn ramp() -> DynamicImage {
+        DynamicImage::ImageLuma16(ImageBuffer::from_fn(8, 8, |x, _| {
+            Luma([1000 + x as u16 * 2000])
+        }))
+    }
+
+    #[test]
+    fn hillshade_style_is_grayscale() {
+        let image = render_relief(&ramp(), ReliefStyle::Hillshade, &HillshadeParams::default())
+            .expect("Relief erwartet");
+
+        assert_eq!(image.dimensions(), (8, 8));
+        assert!(image.pixels().all(|p| p[0] == p[1] && p[1] == p[2]));
+    }
+
+    #[test]
+    fn gradient_style_spans_lowest_to_highest_color() {
+        let params = HillshadeParams {
+            blend_factor: 0.0,
+            ..HillshadeParams::default()
+        };
+        let image =
+            render_relief(&ramp(), ReliefStyle::Gradient, &params).expect("Relief erwartet");
+
+        assert_eq!(image.get_pixel(0, 4).0, GRADIENT_STOPS[0].1);
+        assert_eq!(image.get_pixel(7, 4).0, GRADIENT_STOPS[4].1);
+    }
+}
@@ -0,0 +1,320 @@
+//! Strassen-Layer: Strassen-Splines aus der Map-i3d als Vektor-Overlay.
+//!
+//! Die Terrain-Palette zeigt Strassen nur indirekt ueber die Asphalt-Weight-Maps.
+//! Dieser Layer liest die Spline-Kurven (`<NurbsCurve>`) aus der Map-i3d, die im
+//! Szenengraph unterhalb eines Strassen-/Verkehrs-Knotens liegen, transformiert
+//! sie in Weltkoordinaten und zeichnet sie als scharfe Linienzuege.
+//!
+//! Ausgelagerte Shapes (`externalShapesFile`, binaere `.i3d.shapes`) werden nicht
+//! dekodiert; in diesem Fall liefert die Extraktion schlicht keine Splines.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use image::{Rgb, RgbImage};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::draw::draw_thick_line;
+
+/// Fuellfarbe der Strassen (neutrales Asphaltgrau).
+pub(crate) const ROAD_FILL_COLOR: [u8; 3] = [120, 120, 126];
+/// Randfarbe der Strassen (dunkle Kontur fuer scharfe Kanten).
+pub(crate) const ROAD_CASING_COLOR: [u8; 3] = [38, 38, 42];
+
+/// Gezeichnete Strassenbreite in Metern.
+pub(crate) const ROAD_WIDTH_M: f32 = 7.0;
+
+/// Knotennamen (case-insensitive Teilstring), deren Unterbaum als Strasse gilt.
+const ROAD_NODE_KEYWORDS: &[&str] = &["road", "street", "strasse", "straße", "traffic"];
+
+/// Ein Strassen-Spline als Linienzug in Weltkoordinaten (X/Z, Kartenmitte = 0).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RoadSpline {
+    /// Stuetzpunkte des Splines (Welt-X, Welt-Z)
+    pub points: Vec<(f32, f32)>,
+}
+
+/// Affine 3x4-Transformation (Zeilen = Welt-Achsen, letzte Spalte = Translation).
+type Affine = [[f32; 4]; 3];
+
+const IDENTITY: Affine = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+];
+
+/// Extrahiert alle Strassen-Splines aus dem Inhalt einer Map-i3d.
+///
+/// Beruecksichtigt werden Shapes im Szenengraph, deren Knotenname oder
+/// ein Vorfahre einen der Begriffe `road`, `street`, `strasse` oder `traffic`
+/// enthaelt und die auf eine `<NurbsCurve>` verweisen. Die Kontrollpunkte
+/// werden mit der akkumulierten Knoten-Transformation (Translation,
+/// Rotation X→Y→Z in Grad, Skalierung) in Weltkoordinaten umgerechnet.
+pub fn extract_road_splines(i3d: &[u8]) -> Result<Vec<RoadSpline>> {
+    let mut reader = Reader::from_reader(i3d);
+    reader.config_mut().trim_text(true);
+
+    let mut curves: HashMap<u32, Vec<[f32; 3]>> = HashMap::new();
+    let mut instances: Vec<(u32, Affine)> = Vec::new();
+
+    let mut current_curve: Option<(u32, Vec<[f32; 3]>)> = None;
+    let mut in_scenegraph = false;
+    // Stack der Szenengraph-Knoten: (Welt-Transformation, Strassen-Unterbaum)
+    let mut stack: Vec<(Affine, bool)> = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let name = e.name();
+                let name = name.as_ref();
+                if name == b"Scenegraph" {
+                    in_scenegraph = true;
+                } else if in_scenegraph {
+                    let node = scene_node(&e, stack.last());
+                    if let Some(instance) = road_instance(&e, &node) {
+                        instances.push(instance);
+                    }
+                    stack.push(node);
+                } else if name == b"NurbsCurve" {
+                    current_curve = attr(&e, "shapeId")
+                        .and_then(|id| id.parse().ok())
+                        .map(|id| (id, Vec::new()));
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                if in_scenegraph {
+                    let node = scene_node(&e, stack.last());
+                    if let Some(instance) = road_instance(&e, &node) {
+                        instances.push(instance);
+                    }
+                } else if e.name().as_ref() == b"cv"
+                    && let Some((_, points)) = current_curve.as_mut()
+                    && let Some(point) = attr(&e, "c").and_then(|c| parse_vec3(&c))
+                {
+                    points.push(point);
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = e.name();
+                let name = name.as_ref();
+                if name == b"Scenegraph" {
+                    in_scenegraph = false;
+                    stack.clear();
+                } else if in_scenegraph {
+                    stack.pop();
+                } else if name == b"NurbsCurve"
+                    && let Some((id, points)) = current_curve.take()
+                {
+                    curves.insert(id, points);
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => bail!("Fehler beim Parsen der Map-i3d: {}", e),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let splines = instances
+        .into_iter()
+        .filter_map(|(shape_id, transform)| {
+            let points = curves.get(&shape_id)?;
+            (points.len() >= 2).then(|| RoadSpline {
+                points: points
+                    .iter()
+                    .map(|&p| {
+                        let world = transform_point(&transform, p);
+                        (world[0], world[2])
+                    })
+                    .collect(),
+            })
+        })
+        .collect();
+    Ok(splines)
+}
+
+/// Zeichnet die Strassen als dunkel umrandete Linienzuege.
+///
+/// Erst werden alle Konturen, danach alle Fuellungen gezeichnet, damit
+/// Kreuzungen und Einmuendungen nahtlos verschmelzen.
+pub fn draw_roads(image: &mut RgbImage, splines: &[RoadSpline], map_size: u32) {
+    if map_size == 0 {
+        return;
+    }
+    let scale = image.width() as f32 / map_size as f32;
+    let half = map_size as f32 / 2.0;
+    let fill_width = (ROAD_WIDTH_M * scale).max(2.0);
+    let casing_width = fill_width + 2.0;
+    let to_pixel = |(x, z): (f32, f32)| ((x + half) * scale, (z + half) * scale);
+
+    for (width, color) in [
+        (casing_width, Rgb(ROAD_CASING_COLOR)),
+        (fill_width, Rgb(ROAD_FILL_COLOR)),
+    ] {
+        for spline in splines {
+            for segment in spline.points.windows(2) {
+                draw_thick_line(
+                    image,
+                    to_pixel(segment[0]),
+                    to_pixel(segment[1]),
+                    width,
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// Berechnet Welt-Transformation und Strassen-Flag eines Szenengraph-Knotens.
+fn scene_node(element: &BytesStart, parent: Option<&(Affine, bool)>) -> (Affine, bool) {
+    let (parent_transform, parent_is_road) = parent.copied().unwrap_or((IDENTITY, false));
+
+    let is_road = parent_is_road
+        || attr(element, "name").is_some_and(|name| {
+            let lower = name.to_lowercase();
+            ROAD_NODE_KEYWORDS
+                .iter()
+                .any(|keyword| lower.contains(keyword))
+        });
+
+    let translation = attr(element, "translation")
+        .and_then(|v| parse_vec3(&v))
+        .unwrap_or([0.0; 3]);
+    let rotation = attr(element, "rotation")
+        .and_then(|v| parse_vec3(&v))
+        .unwrap_or([0.0; 3]);
+    let scale = attr(element, "scale")
+        .and_then(|v| parse_vec3(&v))
+        .unwrap_or([1.0; 3]);
+
+    let local = local_transform(translation, rotation, scale);
+    (multiply(&parent_transform, &local), is_road)
+}
+
+/// Liefert `(shapeId, Transformation)`, wenn der Knoten ein Strassen-Shape ist.
+fn road_instance(element: &BytesStart, node: &(Affine, bool)) -> Option<(u32, Affine)> {
+    if !node.1 {
+        return None;
+    }
+    let shape_id = attr(element, "shapeId")?.parse().ok()?;
+    Some((shape_id, node.0))
+}
+
+/// Lokale Transformation `T * Rz * Ry * Rx * S` (Rotation in Grad).
+fn local_transform(translation: [f32; 3], rotation: [f32; 3], scale: [f32; 3]) -> Affine {
+    let [rx, ry, rz] = rotation.map(f32::to_radians);
+    let (sx, cx) = rx.sin_cos();
+    let (sy, cy) = ry.sin_cos();
+    let (sz, cz) = rz.sin_cos();
+
+    let rotation = [
+        [cz * cy, cz * sy * sx - sz * cx, cz * sy * cx + sz * sx],
+        [sz * cy, sz * sy * sx + cz * cx, sz * sy * cx - cz * sx],
+        [-sy, cy * sx, cy * cx],
+    ];
+
+    let mut local = IDENTITY;
+    for row in 0..3 {
+        for col in 0..3 {
+            local[row][col] = rotation[row][col] * scale[col];
+        }
+        local[row][3] = translation[row];
+    }
+    local
+}
+
+fn multiply(a: &Affine, b: &Affine) -> Affine {
+    let mut out = IDENTITY;
+    for row in 0..3 {
+        for col in 0..4 {
+            let mut value = (0..3).map(|k| a[row][k] * b[k][col]).sum::<f32>();
+            if col == 3 {
+                value += a[row][3];
+            }
+            out[row][col] = value;
+        }
+    }
+    out
+}
+
+fn transform_point(m: &Affine, p: [f32; 3]) -> [f32; 3] {
+    [0, 1, 2].map(|row| m[row][0] * p[0] + m[row][1] * p[1] + m[row][2] * p[2] + m[row][3])
+}
+
+/// Parst einen Vektor wie `"1 2 3"` oder `"1, 2, 3"`.
+fn parse_vec3(value: &str) -> Option<[f32; 3]> {
+    let mut parts = value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<f32>());
+    let vec = [
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    ];
+    parts.next().is_none().then_some(vec)
+}
+
+fn attr(element: &BytesStart, key: &str) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key.as_bytes())
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const I3D: &str = r#"<?xml version="1.0" encoding="iso-8859-1"?>
+<i3D name="map">
+  <Shapes>
+    <NurbsCurve name="road01" shapeId="7" degree="3" form="open">
+      <cv c="0, 0, 0" />
+      <cv c="10, 0, 0" />
+    </NurbsCurve>
+    <NurbsCurve name="fence" shapeId="8" degree="1" form="open">
+      <cv c="0, 0, 0" />
+      <cv c="0, 0, 5" />
+    </NurbsCurve>
+  </Shapes>
+  <Scenegraph>
+    <TransformGroup name="roads" translation="100 0 -50">
+      <TransformGroup name="mainRoads" rotation="0 90 0">
+        <Shape name="road01" shapeId="7" />
+      </TransformGroup>
+    </TransformGroup>
+    <TransformGroup name="fences">
+      <Shape name="fence" shapeId="8" />
+    </TransformGroup>
+  </Scenegraph>
+</i3D>"#;
+
+    #[test]
+    fn extract_road_splines_applies_node_transforms_and_filters_by_name() {
+        let splines = extract_road_splines(I3D.as_bytes()).expect("i3d muss parsen");
+
+        assert_eq!(splines.len(), 1, "nur Splines unterhalb von 'roads'");
+        let points = &splines[0].points;
+        assert_eq!(points.len(), 2);
+        assert!((points[0].0 - 100.0).abs() < 1e-4 && (points[0].1 + 50.0).abs() < 1e-4);
+        // 90° um Y: lokales +X zeigt auf Welt -Z
+        assert!((points[1].0 - 100.0).abs() < 1e-4 && (points[1].1 + 60.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn draw_roads_maps_world_center_to_image_center() {
+        let splines = [RoadSpline {
+            points: vec![(-20.0, 0.0), (20.0, 0.0)],
+        }];
+        let mut image = RgbImage::new(64, 64);
+
+        draw_roads(&mut image, &splines, 64);
+
+        assert_eq!(*image.get_pixel(32, 32), Rgb(ROAD_FILL_COLOR));
+        assert_eq!(*image.get_pixel(32, 10), Rgb([0, 0, 0]));
+    }
+}
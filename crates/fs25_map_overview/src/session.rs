@@ -0,0 +1,288 @@
+//! Inkrementelle Layer-Neuberechnung fuer Live-Vorschauen.
+//!
+//! Eine [`OverviewSession`] haelt die extrahierten Kartendaten, das zuletzt
+//! erzeugte [`OverviewLayerBundle`] und dessen Zwischenergebnisse. Aendern sich
+//! zwischen zwei Aufrufen nur Sichtbarkeiten, wird `combined` lediglich neu
+//! zusammengesetzt; Optionen wie Hillshade-Parameter oder das AutoDrive-Netz
+//! zeichnen nur die betroffenen Layer neu. Terrain-Compositing und Hillshade
+//! laufen erst wieder, wenn sich Bildgroesse, Palette oder Title-Bar aendern.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::composite::OverviewOptions;
+use crate::discovery::MapInfo;
+use crate::layer_bundle::{self, LayerSources, OverviewLayerBundle, OPTION_LAYERS};
+use crate::progress::{OverviewProgress, OverviewStage, ProgressReporter};
+
+/// Kartendaten und zwischengespeicherte Layer einer Overview.
+///
+/// Erzeugung ueber [`OverviewSession::new`] oder
+/// [`open_overview_session_from_zip`](crate::open_overview_session_from_zip).
+pub struct OverviewSession {
+    files: HashMap<String, Vec<u8>>,
+    map_info: MapInfo,
+    state: Option<SessionState>,
+}
+
+/// Zuletzt erzeugtes Bundle samt Quellen und Optionen.
+struct SessionState {
+    bundle: OverviewLayerBundle,
+    sources: LayerSources,
+    options: OverviewOptions,
+}
+
+impl OverviewSession {
+    /// Session ueber bereits extrahierten Dateien; noch ohne Layer.
+    pub fn new(files: HashMap<String, Vec<u8>>, map_info: MapInfo) -> Self {
+        Self {
+            files,
+            map_info,
+            state: None,
+        }
+    }
+
+    /// Erkannte Kartenstruktur.
+    pub fn map_info(&self) -> &MapInfo {
+        &self.map_info
+    }
+
+    /// Zuletzt erzeugtes Layer-Bundle (`None` vor dem ersten [`update`](Self::update)).
+    pub fn bundle(&self) -> Option<&OverviewLayerBundle> {
+        self.state.as_ref().map(|state| &state.bundle)
+    }
+
+    /// Gibt das zuletzt erzeugte Layer-Bundle heraus und verwirft die Session.
+    pub fn into_bundle(self) -> Option<OverviewLayerBundle> {
+        self.state.map(|state| state.bundle)
+    }
+
+    /// Bringt das Layer-Bundle auf den Stand von `options`.
+    ///
+    /// Beim ersten Aufruf und nach Aenderungen an Ausgabe-/Render-Groesse,
+    /// Terrain-Palette, Title-Bar oder Hoehenlinien-`height_scale` entstehen alle
+    /// Layer neu. Sonst werden nur Layer mit geaenderten Inhaltsoptionen neu
+    /// gezeichnet und `combined` neu zusammengesetzt.
+    pub fn update(&mut self, options: &OverviewOptions) -> Result<&OverviewLayerBundle> {
+        self.update_with_progress(options, &mut |_| true)
+    }
+
+    /// Wie [`update`](Self::update), meldet aber jeden Arbeitsschritt an `progress`.
+    ///
+    /// Gibt der Callback `false` zurueck, endet die Aktualisierung mit
+    /// [`OverviewCancelled`](crate::OverviewCancelled); ein bereits vorhandenes
+    /// Bundle bleibt dann unveraendert.
+    pub fn update_with_progress(
+        &mut self,
+        options: &OverviewOptions,
+        progress: &mut dyn FnMut(OverviewProgress) -> bool,
+    ) -> Result<&OverviewLayerBundle> {
+        let mut progress = ProgressReporter::new(progress);
+
+        let rebuild = self
+            .state
+            .as_ref()
+            .is_none_or(|state| state.sources.needs_rebuild(&state.options, options));
+        if rebuild {
+            let (bundle, sources) = layer_bundle::build_layer_bundle_with_sources(
+                &self.files,
+                &self.map_info,
+                options,
+                &mut progress,
+            )?;
+            self.state = Some(SessionState {
+                bundle,
+                sources,
+                options: options.clone(),
+            });
+        } else if let Some(state) = self.state.as_mut() {
+            state.refresh(options, &mut progress)?;
+        }
+
+        Ok(&self
+            .state
+            .as_ref()
+            .expect("Session-Zustand nach update vorhanden")
+            .bundle)
+    }
+}
+
+impl SessionState {
+    /// Zeichnet veraltete Layer neu und setzt `combined` neu zusammen.
+    fn refresh(
+        &mut self,
+        options: &OverviewOptions,
+        progress: &mut ProgressReporter<'_>,
+    ) -> Result<()> {
+        if *options == self.options {
+            return progress.finish();
+        }
+
+        let stale: Vec<_> = OPTION_LAYERS
+            .into_iter()
+            .filter(|&layer| self.sources.is_stale(layer, &self.options, options))
+            .collect();
+        let mut rendered = Vec::with_capacity(stale.len());
+        for layer in stale {
+            progress.stage(layer.stage())?;
+            rendered.push((layer, self.sources.rerender(layer, options)));
+        }
+        log::info!("Overview-Session: {} Layer neu gezeichnet", rendered.len());
+
+        progress.stage(OverviewStage::Finishing)?;
+        progress.finish()?;
+        for (layer, image) in rendered {
+            *self.bundle.layer_mut(layer) = image;
+        }
+        self.bundle.combined = self.bundle.compose(options);
+        self.options = options.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer_bundle::generate_overview_layer_bundle;
+    use crate::{HillshadeParams, OverviewLayer};
+    use image::{DynamicImage, GrayImage, ImageFormat};
+    use std::io::Cursor;
+
+    fn test_files() -> HashMap<String, Vec<u8>> {
+        let dem = GrayImage::from_fn(16, 16, |x, y| image::Luma([(x * 12 + y * 3) as u8]));
+        let mut dem_png = Cursor::new(Vec::new());
+        DynamicImage::ImageLuma8(dem)
+            .write_to(&mut dem_png, ImageFormat::Png)
+            .expect("DEM-PNG muss erzeugt werden");
+
+        HashMap::from([
+            (
+                "TestMap/modDesc.xml".to_string(),
+                br#"<modDesc><title><en>Test Map</en></title><map configFilename="maps/config/map.xml" /></modDesc>"#
+                    .to_vec(),
+            ),
+            (
+                "TestMap/maps/config/map.xml".to_string(),
+                br#"<map width="32" height="32" />"#.to_vec(),
+            ),
+            (
+                "TestMap/maps/data/dem.png".to_string(),
+                dem_png.into_inner(),
+            ),
+        ])
+    }
+
+    fn session() -> OverviewSession {
+        let files = test_files();
+        let map_info = crate::discovery::discover_map(&files).expect("Discovery erwartet");
+        OverviewSession::new(files, map_info)
+    }
+
+    fn stages_of(session: &mut OverviewSession, options: &OverviewOptions) -> Vec<OverviewStage> {
+        let mut stages = Vec::new();
+        session
+            .update_with_progress(options, &mut |progress| {
+                stages.push(progress.stage);
+                true
+            })
+            .expect("Update erwartet");
+        stages.dedup();
+        stages
+    }
+
+    #[test]
+    fn visibility_change_only_recomposes() {
+        let mut session = session();
+        let options = OverviewOptions::default();
+        let first = session
+            .update(&options)
+            .expect("Bundle erwartet")
+            .hillshade
+            .clone();
+
+        let hidden = OverviewOptions {
+            hillshade: false,
+            legend: false,
+            ..options.clone()
+        };
+        assert_eq!(
+            stages_of(&mut session, &hidden),
+            vec![OverviewStage::Finishing]
+        );
+
+        let bundle = session.bundle().expect("Bundle erwartet");
+        assert_eq!(bundle.hillshade, first);
+        assert_eq!(bundle.combined, bundle.compose(&hidden));
+        assert_ne!(bundle.combined, bundle.compose(&options));
+    }
+
+    #[test]
+    fn changed_layer_options_rerender_only_affected_layers() {
+        let mut session = session();
+        session
+            .update(&OverviewOptions::default())
+            .expect("Bundle erwartet");
+
+        let options = OverviewOptions {
+            hillshade_params: HillshadeParams {
+                azimuth_deg: 135.0,
+                ..HillshadeParams::default()
+            },
+            ..OverviewOptions::default()
+        };
+        assert_eq!(
+            stages_of(&mut session, &options),
+            vec![OverviewStage::Hillshade, OverviewStage::Finishing]
+        );
+
+        // Ergebnis muss einer vollstaendigen Neuberechnung entsprechen
+        let fresh = generate_overview_layer_bundle(&session.files, &session.map_info, &options)
+            .expect("Bundle erwartet");
+        let bundle = session.bundle().expect("Bundle erwartet");
+        for layer in OverviewLayer::ALL {
+            assert_eq!(bundle.layer(layer), fresh.layer(layer), "{:?}", layer);
+        }
+        assert_eq!(bundle.combined, fresh.combined);
+    }
+
+    #[test]
+    fn poi_clustering_rerenders_markers_and_legend() {
+        let mut session = session();
+        session
+            .update(&OverviewOptions::default())
+            .expect("Bundle erwartet");
+
+        let options = OverviewOptions {
+            poi_clusters: false,
+            ..OverviewOptions::default()
+        };
+        assert_eq!(
+            stages_of(&mut session, &options),
+            vec![
+                OverviewStage::Pois,
+                OverviewStage::Legend,
+                OverviewStage::Finishing
+            ]
+        );
+    }
+
+    #[test]
+    fn output_size_change_rebuilds_all_layers() {
+        let mut session = session();
+        session
+            .update(&OverviewOptions::default())
+            .expect("Bundle erwartet");
+
+        let options = OverviewOptions {
+            output_size: Some(16),
+            ..OverviewOptions::default()
+        };
+        let stages = stages_of(&mut session, &options);
+        assert_eq!(stages.first(), Some(&OverviewStage::Terrain));
+
+        let bundle = session.into_bundle().expect("Bundle erwartet");
+        assert_eq!(bundle.terrain.dimensions(), (16, 16));
+        assert_eq!(bundle.combined.dimensions(), (16, 16));
+    }
+}
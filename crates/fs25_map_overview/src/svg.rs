@@ -0,0 +1,726 @@
+//! SVG-Export: Vektor-Overlays ueber eingebettetem Raster-Terrain.
+//!
+//! Flaechige Layer (Terrain, Wald, Gewaesser, Hillshade, Hoehenlinien) werden
+//! wie bei der PNG-Ausgabe gerendert und als PNG in das SVG eingebettet.
+//! Farmland- und Feldgrenzen, Strassen, POIs, das AutoDrive-Netz, alle
+//! Beschriftungen sowie Legende und Titel folgen als SVG-Elemente und bleiben
+//! damit bei jeder Vergroesserung und im Druck scharf.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::Cursor;
+
+use anyhow::{Context, Result};
+use base64::Engine as _;
+use image::{ImageFormat, RgbImage};
+
+use crate::composite::{self, LegendLayout, OverviewOptions, Swatch, TitleBarLayout};
+use crate::discovery::MapInfo;
+use crate::farmland::{self, FarmlandPolygon};
+use crate::network::{
+    NetworkDirection, NETWORK_COLOR_DUAL, NETWORK_COLOR_MARKER, NETWORK_COLOR_REGULAR,
+    NETWORK_COLOR_REVERSE,
+};
+use crate::progress::ProgressReporter;
+use crate::{farmland_prices, fields, layer_bundle, roads, text};
+
+/// Schriftfamilie der Beschriftungen (Metriken entsprechen der eingebetteten DejaVu Sans).
+const FONT_FAMILY: &str = "DejaVu Sans, Verdana, sans-serif";
+
+/// Generiert eine SVG-Uebersichtskarte aus bereits extrahierten Dateien.
+///
+/// Die Zeichenflaeche hat die Ausgabegroesse (`OverviewOptions::output_size_for`);
+/// Layer-Auswahl, Legende und Titel folgen denselben Optionen wie
+/// [`crate::generate_overview`].
+pub fn generate_overview_svg(
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+) -> Result<String> {
+    let map_size = map_info.map_size;
+    let size = options.output_size_for(map_size);
+    let raster = crate::render_raster_base(
+        files,
+        map_info,
+        options,
+        options.render_size_for(map_size),
+        &mut ProgressReporter::none(),
+    )?;
+    let raster = composite::downscale_to_output(raster, size);
+
+    let mut svg = SvgWriter::new(size);
+    svg.embed_raster(&raster)?;
+
+    let farmland_details = options.area_labels && options.farmland_ids
+        || options.farmland_prices
+        || options.farmland_ownership.is_some();
+    if options.farmlands || options.farmland_ids || farmland_details {
+        write_farmlands(&mut svg, files, map_info, options);
+    }
+    if options.fields {
+        write_fields(&mut svg, files, map_info, options.area_labels);
+    }
+    if options.roads
+        && let Some(splines) = layer_bundle::load_road_splines(files, map_info)
+    {
+        write_roads(&mut svg, &splines, map_size);
+    }
+    let poi_groups = if options.pois {
+        let pois = layer_bundle::load_pois(files, map_info, size);
+        composite::group_pois(&pois, size, options.poi_clusters)
+    } else {
+        composite::PoiGroups::default()
+    };
+    write_pois(&mut svg, &poi_groups.markers);
+    if let Some(network) = &options.network {
+        write_network(&mut svg, network, map_size);
+    }
+    if options.legend {
+        write_legend(&mut svg, options, &poi_groups.legend_lines);
+    }
+    if options.title_bar {
+        write_title(&mut svg, map_info, options);
+    }
+
+    Ok(svg.finish())
+}
+
+fn write_farmlands(
+    svg: &mut SvgWriter,
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    options: &OverviewOptions,
+) {
+    let Some(farmlands) = layer_bundle::load_farmland_data(files, map_info, svg.size) else {
+        log::info!("Keine Farmland-Daten gefunden");
+        return;
+    };
+
+    if options.farmlands {
+        let polygons = farmland::extract_farmland_polygons_from_ids(
+            farmlands.ids.as_raw(),
+            farmlands.width as usize,
+            farmlands.height as usize,
+        );
+        if let Some(ownership) = &options.farmland_ownership {
+            svg.group_start(&format!(
+                r#"fill="{}" fill-opacity="{}" stroke="none""#,
+                hex(farmland_prices::OWNED_TINT_COLOR),
+                farmland_prices::OWNED_TINT_ALPHA
+            ));
+            for polygon in polygons
+                .iter()
+                .filter(|polygon| ownership.owners.contains_key(&polygon.id))
+            {
+                svg.polygon_path(polygon, 1.0);
+            }
+            svg.group_end();
+        }
+        let width = svg.line_width();
+        svg.group_start(&format!(
+            r#"fill="none" stroke="{}" stroke-opacity="0.5" stroke-width="{:.2}" stroke-linejoin="round""#,
+            hex([255, 220, 50]),
+            width
+        ));
+        for polygon in &polygons {
+            svg.polygon_path(polygon, 1.0);
+        }
+        svg.group_end();
+    }
+
+    let id_scale = composite::label_scale(svg.size);
+    let detail_scale = composite::detail_scale(svg.size);
+    let prices = options
+        .farmland_prices
+        .then(|| layer_bundle::load_farmland_prices(files, map_info))
+        .flatten();
+    let meters_per_pixel = map_info.map_size as f64 / farmlands.width.max(1) as f64;
+    let show_area = options.area_labels && options.farmland_ids;
+    let line_count = farmland_prices::detail_line_count(
+        show_area,
+        prices.as_ref(),
+        options.farmland_ownership.as_ref(),
+    );
+
+    for label in composite::place_farmland_labels(&farmlands, svg.size, svg.size, line_count) {
+        let cx = label.center_x() as f32;
+        if options.farmland_ids {
+            if let Some(leader) = &label.leader {
+                svg.leader(leader, [255, 255, 200]);
+            }
+            svg.text_centered(
+                cx,
+                label.id_y() as f32,
+                &label.centroid.id.to_string(),
+                [255, 255, 200],
+                id_scale,
+            );
+        }
+        let mut y = label.details_y(id_scale) as f32;
+        for (line, color) in farmland_prices::detail_lines(
+            &label.centroid,
+            meters_per_pixel,
+            show_area,
+            prices.as_ref(),
+            options.farmland_ownership.as_ref(),
+        ) {
+            svg.text_centered(cx, y, &line, color, detail_scale);
+            y += (text::text_height(detail_scale) + 1) as f32;
+        }
+    }
+}
+
+fn write_fields(
+    svg: &mut SvgWriter,
+    files: &HashMap<String, Vec<u8>>,
+    map_info: &MapInfo,
+    area_labels: bool,
+) {
+    let Some(field_data) = layer_bundle::load_field_data(files, map_info) else {
+        log::info!("Keine Ground-Density-Map gefunden – Feldgrenzen uebersprungen");
+        return;
+    };
+    if field_data.raster_size == 0 {
+        return;
+    }
+    let scale = svg.size as f32 / field_data.raster_size as f32;
+
+    svg.group_start(&format!(
+        r#"fill="none" stroke="{}" stroke-width="{:.2}" stroke-linejoin="round""#,
+        hex(fields::FIELD_BOUNDARY_COLOR),
+        svg.line_width()
+    ));
+    for polygon in &field_data.polygons {
+        svg.polygon_path(polygon, scale);
+    }
+    svg.group_end();
+
+    let text_scale = (svg.size / 1200).clamp(1, 4);
+    let area_scale = text_scale.saturating_sub(1).max(1);
+    for polygon in &field_data.polygons {
+        if polygon.vertices.len() < fields::MIN_LABEL_VERTICES {
+            continue;
+        }
+        let (cx, cy) = fields::polygon_centroid(&polygon.vertices);
+        if let Some(number) = field_data.field_number(polygon.id) {
+            svg.text_centered(
+                (cx + 0.5) * scale,
+                (cy + 0.5) * scale - text::text_height(text_scale) as f32 / 2.0,
+                &number.to_string(),
+                [200, 240, 255],
+                text_scale,
+            );
+        }
+        if let Some(hectares) = area_labels
+            .then(|| field_data.area_hectares(polygon.id, map_info.map_size))
+            .flatten()
+        {
+            svg.text_centered(
+                (cx + 0.5) * scale,
+                (cy + 0.5) * scale + (text::text_height(text_scale) / 2 + 2) as f32,
+                &fields::format_hectares(hectares),
+                fields::AREA_LABEL_COLOR,
+                area_scale,
+            );
+        }
+    }
+}
+
+fn write_roads(svg: &mut SvgWriter, splines: &[roads::RoadSpline], map_size: u32) {
+    if map_size == 0 {
+        return;
+    }
+    let scale = svg.size as f32 / map_size as f32;
+    let half = map_size as f32 / 2.0;
+    let fill_width = (roads::ROAD_WIDTH_M * scale).max(2.0);
+
+    // Erst alle Konturen, dann alle Fuellungen (wie im Raster-Layer)
+    for (width, color) in [
+        (fill_width + 2.0, roads::ROAD_CASING_COLOR),
+        (fill_width, roads::ROAD_FILL_COLOR),
+    ] {
+        svg.group_start(&format!(
+            r#"fill="none" stroke="{}" stroke-width="{:.2}" stroke-linecap="round" stroke-linejoin="round""#,
+            hex(color),
+            width
+        ));
+        for spline in splines {
+            let points: Vec<(f32, f32)> = spline
+                .points
+                .iter()
+                .map(|&(x, z)| ((x + half) * scale, (z + half) * scale))
+                .collect();
+            svg.polyline(&points);
+        }
+        svg.group_end();
+    }
+}
+
+fn write_pois(svg: &mut SvgWriter, markers: &[composite::PoiMarker]) {
+    if markers.is_empty() {
+        return;
+    }
+    let scale = composite::label_scale(svg.size);
+    let placed = composite::place_poi_labels(markers, svg.size, svg.size);
+
+    for leader in placed
+        .iter()
+        .flatten()
+        .filter_map(|label| label.leader.as_ref())
+    {
+        svg.leader(leader, [255, 255, 255]);
+    }
+    for marker in markers {
+        let radius = marker.radius(svg.size);
+        let fill = if marker.count > 1 {
+            composite::POI_GROUP_COLOR
+        } else {
+            [220, 50, 50]
+        };
+        let _ = writeln!(
+            svg.body,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}" stroke="{}" stroke-width="1"/>"#,
+            marker.x,
+            marker.y,
+            radius,
+            hex(fill),
+            hex([255, 255, 255])
+        );
+        if marker.count > 1 {
+            let count = marker.count.to_string();
+            let count_scale = marker.count_scale(svg.size);
+            svg.text(
+                marker.x as f32 - text::text_width(&count, count_scale) as f32 / 2.0,
+                marker.y as f32 - text::text_height(count_scale) as f32 / 2.0,
+                &count,
+                [255, 255, 255],
+                count_scale,
+                false,
+            );
+        }
+    }
+    for (marker, label) in markers.iter().zip(&placed) {
+        if let Some(label) = label {
+            svg.text(
+                label.x as f32,
+                label.y as f32,
+                &marker.label,
+                [255, 255, 255],
+                scale,
+                true,
+            );
+        }
+    }
+}
+
+fn write_network(svg: &mut SvgWriter, network: &crate::AutoDriveNetwork, map_size: u32) {
+    if network.is_empty() || map_size == 0 {
+        return;
+    }
+    let scale = svg.size as f32 / map_size as f32;
+    let half = map_size as f32 / 2.0;
+    let positions: HashMap<u64, (f32, f32)> = network
+        .nodes
+        .iter()
+        .map(|node| (node.id, ((node.x + half) * scale, (node.z + half) * scale)))
+        .collect();
+    let thickness = svg.line_width();
+
+    // Pfeilspitzen als wiederverwendbare Marker, je Richtungsfarbe einer
+    let _ = writeln!(svg.body, "<defs>");
+    for (id, color) in [
+        ("ad-arrow-regular", NETWORK_COLOR_REGULAR),
+        ("ad-arrow-reverse", NETWORK_COLOR_REVERSE),
+    ] {
+        let _ = writeln!(
+            svg.body,
+            r#"<marker id="{}" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="4" markerHeight="4" orient="auto"><path d="M0,0 L10,5 L0,10 Z" fill="{}"/></marker>"#,
+            id,
+            hex(color)
+        );
+    }
+    let _ = writeln!(svg.body, "</defs>");
+
+    for connection in &network.connections {
+        let (Some(&(x1, y1)), Some(&(x2, y2))) = (
+            positions.get(&connection.start),
+            positions.get(&connection.end),
+        ) else {
+            continue;
+        };
+        let (color, marker) = match connection.direction {
+            NetworkDirection::Regular => (NETWORK_COLOR_REGULAR, Some("ad-arrow-regular")),
+            NetworkDirection::Dual => (NETWORK_COLOR_DUAL, None),
+            NetworkDirection::Reverse => (NETWORK_COLOR_REVERSE, Some("ad-arrow-reverse")),
+        };
+        let width = if connection.sub_prio {
+            (thickness * 0.5).max(1.0)
+        } else {
+            thickness
+        };
+        let marker_attr = marker
+            .map(|id| format!(r#" marker-end="url(#{})""#, id))
+            .unwrap_or_default();
+        let _ = writeln!(
+            svg.body,
+            r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="{:.2}"{}/>"#,
+            x1,
+            y1,
+            x2,
+            y2,
+            hex(color),
+            width,
+            marker_attr
+        );
+    }
+
+    let marker_radius = (svg.size / 700).max(4) as f32;
+    let label_scale = (svg.size / 1600).clamp(1, 3);
+    for marker in &network.markers {
+        let Some(&(px, py)) = positions.get(&marker.node_id) else {
+            continue;
+        };
+        let _ = writeln!(
+            svg.body,
+            r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}" stroke="{}" stroke-width="1"/>"#,
+            px,
+            py,
+            marker_radius,
+            hex(NETWORK_COLOR_MARKER),
+            hex([0, 0, 0])
+        );
+        svg.text(
+            px + marker_radius + 3.0,
+            py - text::text_height(label_scale) as f32 / 2.0,
+            &marker.name,
+            [255, 255, 255],
+            label_scale,
+            true,
+        );
+    }
+}
+
+fn write_legend(svg: &mut SvgWriter, options: &OverviewOptions, poi_groups: &[String]) {
+    let Some(layout) = LegendLayout::new(svg.size, svg.size, options, poi_groups) else {
+        return;
+    };
+
+    let _ = writeln!(
+        svg.body,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" fill-opacity="{:.2}"/>"#,
+        layout.x,
+        layout.y,
+        layout.width,
+        layout.height,
+        hex([30, 30, 30]),
+        layout.background_opacity
+    );
+    let (hx, hy) = layout.header_position();
+    svg.text(
+        hx as f32,
+        hy as f32,
+        "Legende",
+        [255, 255, 255],
+        layout.scale,
+        false,
+    );
+
+    let size = layout.swatch_size as f32;
+    for (index, (swatch, label)) in layout.entries.iter().enumerate() {
+        let (x, y) = layout.entry_position(index);
+        let (x, y) = (x as f32, y as f32);
+        let _ = match *swatch {
+            Swatch::Area(color) => writeln!(
+                svg.body,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                x,
+                y,
+                size,
+                size,
+                hex(color)
+            ),
+            Swatch::Line(color, thickness) => writeln!(
+                svg.body,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                x,
+                y + (size / 2.0).floor() - (thickness / 2) as f32,
+                size,
+                thickness,
+                hex(color)
+            ),
+            Swatch::Dot(color) => writeln!(
+                svg.body,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+                x + size / 2.0,
+                y + size / 2.0,
+                size / 2.0,
+                hex(color)
+            ),
+        };
+        svg.text(
+            x + layout.label_offset as f32,
+            y + 2.0,
+            label,
+            [255, 255, 255],
+            layout.scale,
+            false,
+        );
+    }
+}
+
+fn write_title(svg: &mut SvgWriter, map_info: &MapInfo, options: &OverviewOptions) {
+    let layout = TitleBarLayout::new(svg.size, map_info, &options.title_bar_options);
+    if layout.background_opacity > 0.0 {
+        let _ = writeln!(
+            svg.body,
+            r#"<rect x="0" y="0" width="{}" height="{}" fill="{}" fill-opacity="{:.2}"/>"#,
+            svg.size,
+            layout.height,
+            hex(layout.background_color),
+            layout.background_opacity
+        );
+    }
+    svg.text(
+        layout.x as f32,
+        layout.title_y as f32,
+        &layout.title,
+        layout.text_color,
+        layout.scale,
+        true,
+    );
+    if let Some(subtitle) = &layout.subtitle {
+        svg.text(
+            layout.x as f32,
+            layout.subtitle_y as f32,
+            subtitle,
+            layout.text_color,
+            layout.subtitle_scale,
+            true,
+        );
+    }
+}
+
+/// Minimaler SVG-Dokument-Builder mit quadratischer Zeichenflaeche.
+struct SvgWriter {
+    size: u32,
+    body: String,
+}
+
+impl SvgWriter {
+    fn new(size: u32) -> Self {
+        Self {
+            size,
+            body: String::new(),
+        }
+    }
+
+    /// Linienstaerke fuer Grenzen und Netz-Verbindungen (waechst mit der Bildgroesse).
+    fn line_width(&self) -> f32 {
+        (self.size as f32 / 2048.0).max(1.0)
+    }
+
+    fn embed_raster(&mut self, raster: &RgbImage) -> Result<()> {
+        let mut png = Cursor::new(Vec::new());
+        raster
+            .write_to(&mut png, ImageFormat::Png)
+            .context("Raster-Basis konnte nicht als PNG kodiert werden")?;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(png.into_inner());
+        let _ = writeln!(
+            self.body,
+            r#"<image x="0" y="0" width="{0}" height="{0}" preserveAspectRatio="none" xlink:href="data:image/png;base64,{1}"/>"#,
+            self.size, encoded
+        );
+        Ok(())
+    }
+
+    fn group_start(&mut self, attributes: &str) {
+        let _ = writeln!(self.body, "<g {}>", attributes);
+    }
+
+    fn group_end(&mut self) {
+        let _ = writeln!(self.body, "</g>");
+    }
+
+    /// Geschlossener Umriss; Vertices sind Rasterpixel, gezeichnet wird durch die Pixelmitte.
+    fn polygon_path(&mut self, polygon: &FarmlandPolygon, scale: f32) {
+        let points: Vec<(f32, f32)> = polygon
+            .vertices
+            .iter()
+            .map(|&(x, y)| ((x + 0.5) * scale, (y + 0.5) * scale))
+            .collect();
+        if points.len() < 2 {
+            return;
+        }
+        let _ = writeln!(self.body, r#"<path d="{}Z"/>"#, path_data(&points));
+    }
+
+    fn polyline(&mut self, points: &[(f32, f32)]) {
+        if points.len() < 2 {
+            return;
+        }
+        let _ = writeln!(self.body, r#"<path d="{}"/>"#, path_data(points));
+    }
+
+    /// Text mit linker oberer Ecke bei (`x`, `y`), analog zu `text::draw_text`.
+    fn text(&mut self, x: f32, y: f32, label: &str, color: [u8; 3], scale: u32, outlined: bool) {
+        let outline = if outlined {
+            format!(
+                r#" stroke="{}" stroke-width="{}" stroke-linejoin="round" paint-order="stroke""#,
+                hex([0, 0, 0]),
+                scale * 2
+            )
+        } else {
+            String::new()
+        };
+        let _ = writeln!(
+            self.body,
+            r#"<text x="{:.1}" y="{:.1}" font-family="{}" font-size="{}" fill="{}"{}>{}</text>"#,
+            x,
+            y + text::text_ascent(scale),
+            FONT_FAMILY,
+            text::font_size(scale),
+            hex(color),
+            outline,
+            escape_xml(label)
+        );
+    }
+
+    /// Fuehrungslinie eines abgesetzten Labels mit dunkler Umrandung.
+    fn leader(&mut self, leader: &composite::Leader, color: [u8; 3]) {
+        for (stroke, width) in [([0, 0, 0], 3), (color, 1)] {
+            let _ = writeln!(
+                self.body,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}" stroke-linecap="round"/>"#,
+                leader.from.0,
+                leader.from.1,
+                leader.to.0,
+                leader.to.1,
+                hex(stroke),
+                width
+            );
+        }
+    }
+
+    /// Umrandeter Text, horizontal um `cx` zentriert, Oberkante bei `y`.
+    fn text_centered(&mut self, cx: f32, y: f32, label: &str, color: [u8; 3], scale: u32) {
+        let x = cx - text::text_width(label, scale) as f32 / 2.0;
+        self.text(x, y, label, color, scale, true);
+    }
+
+    fn finish(self) -> String {
+        format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                "\n",
+                r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#,
+                "\n{1}</svg>\n"
+            ),
+            self.size, self.body
+        )
+    }
+}
+
+fn path_data(points: &[(f32, f32)]) -> String {
+    let mut data = String::with_capacity(points.len() * 14);
+    for (index, (x, y)) in points.iter().enumerate() {
+        let command = if index == 0 { 'M' } else { 'L' };
+        let _ = write!(data, "{}{:.1},{:.1} ", command, x, y);
+    }
+    data
+}
+
+fn hex(color: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_map_info() -> MapInfo {
+        MapInfo {
+            title: String::from("Test & Map"),
+            author: None,
+            map_size: 64,
+            height_scale: crate::DEFAULT_HEIGHT_SCALE,
+            terrain_offset: (0.0, 0.0),
+            config_path: String::from("TestMap/maps/config/map.xml"),
+            data_dir: String::from("TestMap/maps/data"),
+            config_dir: String::from("TestMap/maps/config"),
+            placeables_path: None,
+            i3d_path: None,
+            farmlands_path: None,
+            image_path: None,
+        }
+    }
+
+    #[test]
+    fn generate_overview_svg_embeds_raster_and_vector_labels() {
+        let options = OverviewOptions {
+            hillshade: false,
+            output_size: Some(32),
+            ..OverviewOptions::default()
+        };
+
+        let svg = generate_overview_svg(&HashMap::new(), &test_map_info(), &options)
+            .expect("SVG muss erzeugt werden");
+
+        assert!(svg.contains(r#"viewBox="0 0 32 32""#));
+        assert!(svg.contains("data:image/png;base64,"));
+        assert!(svg.contains(">Legende</text>"));
+        assert!(svg.contains(">Test &amp; Map - Overview</text>"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn generate_overview_svg_styles_or_hides_title_bar() {
+        let mut options = OverviewOptions {
+            hillshade: false,
+            legend: false,
+            output_size: Some(32),
+            ..OverviewOptions::default()
+        };
+        options.title_bar_options.subtitle = true;
+        options.title_bar_options.background_opacity = 0.5;
+        let map_info = MapInfo {
+            author: Some(String::from("Team <B>")),
+            ..test_map_info()
+        };
+
+        let svg = generate_overview_svg(&HashMap::new(), &map_info, &options)
+            .expect("SVG muss erzeugt werden");
+        assert!(svg.contains(r#"<rect x="0" y="0" width="32""#));
+        assert!(svg.contains(">64 × 64 m · Autor: Team &lt;B&gt;</text>"));
+
+        options.title_bar = false;
+        let svg = generate_overview_svg(&HashMap::new(), &map_info, &options)
+            .expect("SVG muss erzeugt werden");
+        assert!(!svg.contains("- Overview</text>"));
+    }
+
+    #[test]
+    fn write_roads_scales_world_coordinates_to_canvas() {
+        let mut svg = SvgWriter::new(100);
+        let splines = [roads::RoadSpline {
+            points: vec![(-50.0, -50.0), (50.0, 0.0)],
+        }];
+
+        write_roads(&mut svg, &splines, 200);
+
+        assert!(svg.body.contains(r#"<path d="M25.0,25.0 L75.0,50.0 "/>"#));
+    }
+}
@@ -3,10 +3,13 @@
 //! Mischt alle Weight-Maps eines FS25-Map-Mods farblich
 //! zu einem Terrain-Bild zusammen.
 
+use std::borrow::Cow;
+
 use anyhow::Result;
 use image::{DynamicImage, GrayImage, RgbImage};
+use rayon::prelude::*;
 
-use crate::palette;
+use crate::palette::{self, TerrainPalette};
 
 /// Hintergrundfarbe fuer Pixel ohne Weight-Map-Abdeckung.
 const BACKGROUND_COLOR: [f64; 3] = [80.0, 100.0, 60.0];
@@ -22,68 +25,74 @@ pub struct WeightLayer {
 /// Mischt Weight-Map-Layer zu einem RGB-Terrain-Bild.
 ///
 /// Jedes Pixel wird als gewichteter Durchschnitt der Layer-Farben berechnet.
-/// Pixel ohne Abdeckung erhalten die Hintergrundfarbe.
+/// Pixel ohne Abdeckung erhalten die Hintergrundfarbe. Skalierung und
+/// Mischung laufen parallel (Layer bzw. Bildzeilen ueber Rayon).
 ///
 /// # Parameter
 /// - `layers`: Weight-Map-Layer mit Name und Gewichtsbild
 /// - `target_size`: Zielgroesse (quadratisch), alle Layer werden darauf skaliert
-pub fn composite_terrain(layers: &[WeightLayer], target_size: u32) -> Result<RgbImage> {
+/// - `custom_palette`: optionale eigene Palette mit Vorrang vor der eingebauten
+pub fn composite_terrain(
+    layers: &[WeightLayer],
+    target_size: u32,
+    custom_palette: Option<&TerrainPalette>,
+) -> Result<RgbImage> {
     let size = target_size as usize;
-    let mut result_r = vec![0.0f64; size * size];
-    let mut result_g = vec![0.0f64; size * size];
-    let mut result_b = vec![0.0f64; size * size];
-    let mut total_weight = vec![0.0f64; size * size];
 
     log::info!("{} Weight-Maps werden gemischt...", layers.len());
 
-    for layer in layers {
-        let color = palette::terrain_color(&layer.name);
-        let color_r = color[0] as f64;
-        let color_g = color[1] as f64;
-        let color_b = color[2] as f64;
-
-        // Weight-Map auf Zielgroesse skalieren falls noetig
-        let weights =
-            if layer.weights.width() != target_size || layer.weights.height() != target_size {
-                image::imageops::resize(
-                    &layer.weights,
-                    target_size,
-                    target_size,
-                    image::imageops::FilterType::Lanczos3,
-                )
-            } else {
-                layer.weights.clone()
-            };
-
-        for (i, &w_byte) in weights.as_raw().iter().enumerate() {
-            let w = w_byte as f64 / 255.0;
-            result_r[i] += w * color_r;
-            result_g[i] += w * color_g;
-            result_b[i] += w * color_b;
-            total_weight[i] += w;
-        }
-    }
+    // Weight-Maps parallel auf Zielgroesse skalieren und ihre Farbe aufloesen
+    let prepared: Vec<([f64; 3], Cow<'_, GrayImage>)> = layers
+        .par_iter()
+        .map(|layer| {
+            let color = palette::terrain_color_with(&layer.name, custom_palette);
+            let weights =
+                if layer.weights.width() != target_size || layer.weights.height() != target_size {
+                    Cow::Owned(image::imageops::resize(
+                        &layer.weights,
+                        target_size,
+                        target_size,
+                        image::imageops::FilterType::Lanczos3,
+                    ))
+                } else {
+                    Cow::Borrowed(&layer.weights)
+                };
+            ([color[0] as f64, color[1] as f64, color[2] as f64], weights)
+        })
+        .collect();
 
-    // Normalisieren + Hintergrundfarbe fuer unbedeckte Pixel
+    // Zeilenweise mischen; die Layer-Reihenfolge pro Pixel bleibt erhalten,
+    // das Ergebnis ist damit identisch zur sequentiellen Berechnung
     let mut rgb_data = vec![0u8; size * size * 3];
-    for i in 0..(size * size) {
-        let (r, g, b) = if total_weight[i] > 0.0 {
-            (
-                result_r[i] / total_weight[i],
-                result_g[i] / total_weight[i],
-                result_b[i] / total_weight[i],
-            )
-        } else {
-            (
-                BACKGROUND_COLOR[0],
-                BACKGROUND_COLOR[1],
-                BACKGROUND_COLOR[2],
-            )
-        };
-        rgb_data[i * 3] = r.clamp(0.0, 255.0) as u8;
-        rgb_data[i * 3 + 1] = g.clamp(0.0, 255.0) as u8;
-        rgb_data[i * 3 + 2] = b.clamp(0.0, 255.0) as u8;
-    }
+    rgb_data
+        .par_chunks_mut((size * 3).max(1))
+        .enumerate()
+        .for_each(|(y, row)| {
+            let row_start = y * size;
+            let mut sums = vec![[0.0f64; 4]; size];
+            for (color, weights) in &prepared {
+                let row_weights = &weights.as_raw()[row_start..row_start + size];
+                for (sum, &w_byte) in sums.iter_mut().zip(row_weights) {
+                    let w = w_byte as f64 / 255.0;
+                    sum[0] += w * color[0];
+                    sum[1] += w * color[1];
+                    sum[2] += w * color[2];
+                    sum[3] += w;
+                }
+            }
+
+            // Normalisieren + Hintergrundfarbe fuer unbedeckte Pixel
+            for (pixel, sum) in row.chunks_exact_mut(3).zip(&sums) {
+                let rgb = if sum[3] > 0.0 {
+                    [sum[0] / sum[3], sum[1] / sum[3], sum[2] / sum[3]]
+                } else {
+                    BACKGROUND_COLOR
+                };
+                for (channel, value) in pixel.iter_mut().zip(rgb) {
+                    *channel = value.clamp(0.0, 255.0) as u8;
+                }
+            }
+        });
 
     RgbImage::from_raw(target_size, target_size, rgb_data)
         .ok_or_else(|| anyhow::anyhow!("Fehler beim Erstellen des Terrain-Bildes"))
@@ -95,14 +104,54 @@ pub fn composite_terrain(layers: &[WeightLayer], target_size: u32) -> Result<Rgb
 pub fn composite_terrain_from_images(
     images: &[(String, DynamicImage)],
     target_size: u32,
+    custom_palette: Option<&TerrainPalette>,
 ) -> Result<RgbImage> {
     let layers: Vec<WeightLayer> = images
-        .iter()
+        .par_iter()
         .map(|(name, img)| WeightLayer {
             name: name.clone(),
             weights: img.to_luma8(),
         })
         .collect();
 
-    composite_terrain(&layers, target_size)
+    composite_terrain(&layers, target_size, custom_palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composite_terrain_mixes_weights_per_pixel_and_keeps_background() {
+        let palette = TerrainPalette::from_toml_str(
+            r#"
+            [[rules]]
+            pattern = "red"
+            color = [200, 0, 0]
+
+            [[rules]]
+            pattern = "blue"
+            color = [0, 0, 200]
+            "#,
+        )
+        .expect("Palette muss parsen");
+        // Zeile 0: nur rot | rot+blau zu gleichen Teilen; Zeile 1: nur blau | leer
+        let layers = [
+            WeightLayer {
+                name: String::from("red_weight.png"),
+                weights: GrayImage::from_raw(2, 2, vec![255, 255, 0, 0]).expect("2x2"),
+            },
+            WeightLayer {
+                name: String::from("blue_weight.png"),
+                weights: GrayImage::from_raw(2, 2, vec![0, 255, 255, 0]).expect("2x2"),
+            },
+        ];
+
+        let image = composite_terrain(&layers, 2, Some(&palette)).expect("Compositing");
+
+        assert_eq!(image.get_pixel(0, 0).0, [200, 0, 0]);
+        assert_eq!(image.get_pixel(1, 0).0, [100, 0, 100]);
+        assert_eq!(image.get_pixel(0, 1).0, [0, 0, 200]);
+        assert_eq!(image.get_pixel(1, 1).0, [80, 100, 60]);
+    }
 }
@@ -84,6 +84,16 @@ pub fn text_width(text: &str, scale: u32) -> u32 {
     width.ceil() as u32
 }
 
+/// Abstand von der Oberkante zur Grundlinie (fuer Vektor-Ausgaben).
+pub(crate) fn text_ascent(scale: u32) -> f32 {
+    font().as_scaled(px_scale(scale)).ascent()
+}
+
+/// Schriftgroesse in Pixeln fuer einen `scale`-Faktor (fuer Vektor-Ausgaben).
+pub(crate) fn font_size(scale: u32) -> f32 {
+    px_scale(scale).y
+}
+
 /// Berechnet die Pixelhoehe eines Texts.
 pub fn text_height(scale: u32) -> u32 {
     let font = font();
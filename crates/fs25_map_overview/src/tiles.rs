@@ -0,0 +1,143 @@
+//! Slippy-Map-Kachelpyramide (`z/x/y.png`) fuer interaktive Web-Karten.
+//!
+//! Die Overview wird auf die naechste Zweierpotenz von [`TILE_SIZE`]
+//! skaliert und pro Zoomstufe in 256-px-Kacheln zerlegt. Zoom 0 ist eine
+//! einzelne Kachel mit der ganzen Karte; jede weitere Stufe verdoppelt die
+//! Aufloesung. Das Schema entspricht XYZ (Ursprung oben links) und laesst sich
+//! z.B. in Leaflet mit `CRS.Simple` direkt als Tile-Layer einbinden.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, RgbaImage};
+use rayon::prelude::*;
+
+/// Kantenlaenge einer Kachel in Pixeln.
+pub const TILE_SIZE: u32 = 256;
+
+/// Ergebnis von [`write_tile_pyramid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TilePyramid {
+    /// Hoechste erzeugte Zoomstufe (Zoom 0 ist immer vorhanden)
+    pub max_zoom: u8,
+    /// Anzahl geschriebener Kacheln ueber alle Stufen
+    pub tile_count: usize,
+}
+
+/// Hoechste Zoomstufe, bei der ein Bild mit `image_size` Pixeln noch
+/// nicht hochskaliert werden muss (aufgerundet auf die naechste Zweierpotenz).
+pub fn max_zoom_for(image_size: u32) -> u8 {
+    let tiles = image_size.div_ceil(TILE_SIZE).max(1);
+    tiles.next_power_of_two().trailing_zeros() as u8
+}
+
+/// Zerlegt `image` in eine Kachelpyramide unter `dir/<z>/<x>/<y>.png`.
+///
+/// Die hoechste Stufe ergibt sich aus [`max_zoom_for`] der laengeren Bildkante;
+/// nicht quadratische Bilder werden auf das Quadrat gestreckt. Kacheln sind
+/// RGBA, damit transparente Layer-Kombinationen erhalten bleiben. Die
+/// Kacheln einer Stufe werden parallel kodiert.
+pub fn write_tile_pyramid(image: &DynamicImage, dir: &Path) -> Result<TilePyramid> {
+    let max_zoom = max_zoom_for(image.width().max(image.height()));
+    let top_size = TILE_SIZE << max_zoom;
+
+    let mut level: RgbaImage = if image.width() == top_size && image.height() == top_size {
+        image.to_rgba8()
+    } else {
+        imageops::resize(&image.to_rgba8(), top_size, top_size, FilterType::Lanczos3)
+    };
+
+    let mut tile_count = 0;
+    for zoom in (0..=max_zoom).rev() {
+        tile_count += write_level(&level, zoom, dir)?;
+        if zoom > 0 {
+            let half = level.width() / 2;
+            level = imageops::resize(&level, half, half, FilterType::Triangle);
+        }
+    }
+
+    log::info!(
+        "Kachelpyramide geschrieben: Zoom 0–{}, {} Kacheln nach {}",
+        max_zoom,
+        tile_count,
+        dir.display()
+    );
+    Ok(TilePyramid {
+        max_zoom,
+        tile_count,
+    })
+}
+
+/// Schreibt alle Kacheln einer Zoomstufe; `level` hat `TILE_SIZE << zoom` Pixel.
+fn write_level(level: &RgbaImage, zoom: u8, dir: &Path) -> Result<usize> {
+    let tiles_per_side = 1u32 << zoom;
+    (0..tiles_per_side).into_par_iter().try_for_each(|x| {
+        let column_dir = dir.join(zoom.to_string()).join(x.to_string());
+        std::fs::create_dir_all(&column_dir).with_context(|| {
+            format!(
+                "Kachel-Verzeichnis konnte nicht erstellt werden: {}",
+                column_dir.display()
+            )
+        })?;
+        for y in 0..tiles_per_side {
+            let tile =
+                imageops::crop_imm(level, x * TILE_SIZE, y * TILE_SIZE, TILE_SIZE, TILE_SIZE)
+                    .to_image();
+            let path = column_dir.join(format!("{}.png", y));
+            tile.save(&path).with_context(|| {
+                format!("Kachel konnte nicht gespeichert werden: {}", path.display())
+            })?;
+        }
+        Ok::<(), anyhow::Error>(())
+    })?;
+    Ok((tiles_per_side * tiles_per_side) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_zoom_rounds_up_to_power_of_two_tiles() {
+        assert_eq!(max_zoom_for(100), 0);
+        assert_eq!(max_zoom_for(256), 0);
+        assert_eq!(max_zoom_for(257), 1);
+        assert_eq!(max_zoom_for(600), 2);
+        assert_eq!(max_zoom_for(4096), 4);
+    }
+
+    #[test]
+    fn write_tile_pyramid_writes_all_levels() {
+        let dir = std::env::temp_dir().join(format!(
+            "fs25_map_overview_tiles_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("Systemzeit nach Unix-Epoche")
+                .as_nanos()
+        ));
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+            300,
+            300,
+            image::Rgba([10, 20, 30, 255]),
+        ));
+
+        let pyramid = write_tile_pyramid(&image, &dir).expect("Kacheln schreiben");
+        let corner = image::open(dir.join("1/1/1.png")).map(|tile| tile.to_rgba8());
+        let root_exists = dir.join("0/0/0.png").is_file();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            pyramid,
+            TilePyramid {
+                max_zoom: 1,
+                tile_count: 5
+            }
+        );
+        assert!(root_exists);
+        let corner = corner.expect("Kachel 1/1/1 muss existieren");
+        assert_eq!(corner.dimensions(), (TILE_SIZE, TILE_SIZE));
+        assert_eq!(corner.get_pixel(128, 128).0, [10, 20, 30, 255]);
+    }
+}
@@ -0,0 +1,130 @@
+//! Wald-/Vegetations-Layer: Kronendach-Overlay aus den Wald-Weight-Maps.
+//!
+//! Der In-Game-PDA zeigt Waelder als geschlossene, dunkelgruene Flaechen.
+//! Die Terrain-Palette faerbt nur den Waldboden; dieser Layer summiert alle
+//! Wald-Weight-Maps (`forest*_weight.png`) zu einer Dichtemaske, glaettet sie
+//! zu zusammenhaengenden Kronen und legt sie halbtransparent ueber das Terrain.
+
+use image::{DynamicImage, GrayImage, RgbImage};
+
+/// Farbe des Kronendachs (dunkles Waldgruen).
+pub(crate) const CANOPY_COLOR: [u8; 3] = [34, 82, 32];
+
+/// Maximale Deckkraft des Kronendachs bei voller Walddichte.
+const CANOPY_MAX_ALPHA: f32 = 0.7;
+
+/// Unterhalb dieser Dichte (0–255) wird kein Kronendach gezeichnet.
+const CANOPY_MIN_DENSITY: u8 = 24;
+
+/// Berechnet die Walddichte-Maske aus den geladenen Weight-Maps.
+///
+/// Beruecksichtigt alle Weight-Maps, deren Name `forest` enthaelt
+/// (case-insensitive). Gibt `None` zurueck, wenn die Karte keine
+/// Wald-Weight-Maps besitzt.
+pub fn compute_forest_density(
+    weight_images: &[(String, DynamicImage)],
+    target_size: u32,
+) -> Option<GrayImage> {
+    let forest_layers: Vec<&DynamicImage> = weight_images
+        .iter()
+        .filter(|(name, _)| name.to_ascii_lowercase().contains("forest"))
+        .map(|(_, img)| img)
+        .collect();
+    if forest_layers.is_empty() || target_size == 0 {
+        return None;
+    }
+
+    let mut density = vec![0u16; target_size as usize * target_size as usize];
+    for layer in forest_layers {
+        let weights = layer.to_luma8();
+        let weights = if weights.dimensions() != (target_size, target_size) {
+            image::imageops::resize(
+                &weights,
+                target_size,
+                target_size,
+                image::imageops::FilterType::Triangle,
+            )
+        } else {
+            weights
+        };
+        for (acc, &w) in density.iter_mut().zip(weights.as_raw()) {
+            *acc = acc.saturating_add(w as u16);
+        }
+    }
+
+    let raw: Vec<u8> = density.into_iter().map(|d| d.min(255) as u8).collect();
+    let mask = GrayImage::from_raw(target_size, target_size, raw)?;
+
+    // Einzelne Wald-Pixel zu geschlossenen Kronen verschmelzen (Radius waechst mit der Bildgroesse)
+    let sigma = (target_size as f32 / 512.0).max(1.0);
+    Some(image::imageops::fast_blur(&mask, sigma))
+}
+
+/// Legt das Kronendach gemaess Dichtemaske halbtransparent ueber das Bild.
+pub fn apply_forest_canopy(image: &mut RgbImage, density: &GrayImage) {
+    debug_assert_eq!(image.dimensions(), density.dimensions());
+
+    for (pixel, &d) in image.pixels_mut().zip(density.as_raw()) {
+        if d < CANOPY_MIN_DENSITY {
+            continue;
+        }
+        let alpha = d as f32 / 255.0 * CANOPY_MAX_ALPHA;
+        for channel in 0..3 {
+            let base = pixel[channel] as f32;
+            pixel[channel] = (base * (1.0 - alpha) + CANOPY_COLOR[channel] as f32 * alpha)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
+/// Convenience: Berechnet die Dichte und wendet das Kronendach direkt an.
+///
+/// Gibt `true` zurueck, wenn Wald-Weight-Maps gefunden wurden.
+pub fn draw_forest_layer(image: &mut RgbImage, weight_images: &[(String, DynamicImage)]) -> bool {
+    match compute_forest_density(weight_images, image.width()) {
+        Some(density) => {
+            apply_forest_canopy(image, &density);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    fn weight(size: u32, value: u8) -> DynamicImage {
+        DynamicImage::ImageLuma8(GrayImage::from_pixel(size, size, image::Luma([value])))
+    }
+
+    #[test]
+    fn compute_forest_density_ignores_non_forest_maps() {
+        let images = vec![(String::from("grass_weight.png"), weight(8, 255))];
+        assert!(compute_forest_density(&images, 8).is_none());
+    }
+
+    #[test]
+    fn compute_forest_density_sums_forest_maps() {
+        let images = vec![
+            (String::from("forestGrass_weight.png"), weight(8, 100)),
+            (String::from("forestLeaves_weight.png"), weight(8, 100)),
+        ];
+        let density = compute_forest_density(&images, 8).expect("Wald-Maske erwartet");
+        assert_eq!(density.get_pixel(4, 4)[0], 200);
+    }
+
+    #[test]
+    fn apply_forest_canopy_darkens_towards_canopy_color() {
+        let mut image = RgbImage::from_pixel(2, 2, Rgb([200, 200, 200]));
+        let density = GrayImage::from_pixel(2, 2, image::Luma([255]));
+
+        apply_forest_canopy(&mut image, &density);
+
+        let pixel = image.get_pixel(0, 0);
+        assert!(pixel[0] < 100 && pixel[1] < 130);
+        assert!(pixel[1] > pixel[0], "Kronendach muss gruenlich sein");
+    }
+}
@@ -0,0 +1,219 @@
+//! World-Files (ESRI `.pgw`/`.jgw`/`.wld`) fuer georeferenzierte Overviews.
+//!
+//! Ein World-File beschreibt die affine Pixel→Welt-Transformation eines
+//! Bildes in sechs Zeilen. Die Overview nutzt das FS25-Weltsystem mit dem
+//! Kartenmittelpunkt bei 0/0 und Metern als Einheit. GIS-Werkzeuge erwarten
+//! Norden oben mit nach oben wachsendem Y; daher gilt `Y = -Z`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use image::DynamicImage;
+
+/// Affine Pixel→Welt-Transformation im World-File-Format.
+///
+/// Weltkoordinate des Pixelmittelpunkts `(col, row)`:
+/// `X = origin_x + col * pixel_size_x + row * rotation_x`,
+/// `Y = origin_y + col * rotation_y + row * pixel_size_y`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldFile {
+    /// Pixelbreite in Metern (Zeile 1, A)
+    pub pixel_size_x: f64,
+    /// Rotationsterm fuer Y (Zeile 2, D)
+    pub rotation_y: f64,
+    /// Rotationsterm fuer X (Zeile 3, B)
+    pub rotation_x: f64,
+    /// Pixelhoehe in Metern, negativ fuer Norden oben (Zeile 4, E)
+    pub pixel_size_y: f64,
+    /// X des Mittelpunkts des linken oberen Pixels (Zeile 5, C)
+    pub origin_x: f64,
+    /// Y des Mittelpunkts des linken oberen Pixels (Zeile 6, F)
+    pub origin_y: f64,
+}
+
+impl WorldFile {
+    /// Transformation einer quadratischen Overview mit `image_size` Pixeln
+    /// Kantenlaenge fuer eine Karte mit `map_size` Metern.
+    pub fn for_overview(map_size: u32, image_size: u32) -> Self {
+        let meters_per_pixel = map_size as f64 / image_size.max(1) as f64;
+        let half = map_size as f64 / 2.0;
+        Self {
+            pixel_size_x: meters_per_pixel,
+            rotation_y: 0.0,
+            rotation_x: 0.0,
+            pixel_size_y: -meters_per_pixel,
+            origin_x: -half + meters_per_pixel / 2.0,
+            origin_y: half - meters_per_pixel / 2.0,
+        }
+    }
+
+    /// Parst die sechs Zahlen eines World-Files.
+    pub fn parse(content: &str) -> Result<Self> {
+        let values: Vec<f64> = content
+            .split_whitespace()
+            .map(|token| {
+                token
+                    .parse::<f64>()
+                    .with_context(|| format!("Ungueltiger World-File-Wert: {}", token))
+            })
+            .collect::<Result<_>>()?;
+        let [a, d, b, e, c, f] = values[..] else {
+            bail!(
+                "World-File muss genau 6 Werte enthalten, gefunden: {}",
+                values.len()
+            );
+        };
+        if a == 0.0 || e == 0.0 {
+            bail!("World-File mit Pixelgroesse 0 ist ungueltig");
+        }
+        Ok(Self {
+            pixel_size_x: a,
+            rotation_y: d,
+            rotation_x: b,
+            pixel_size_y: e,
+            origin_x: c,
+            origin_y: f,
+        })
+    }
+
+    /// Serialisiert im Standardformat (eine Zahl pro Zeile).
+    pub fn to_file_content(&self) -> String {
+        format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n",
+            self.pixel_size_x,
+            self.rotation_y,
+            self.rotation_x,
+            self.pixel_size_y,
+            self.origin_x,
+            self.origin_y
+        )
+    }
+
+    /// Welt-Rechteck `(min_x, min_z, max_x, max_z)` eines Bildes dieser
+    /// Groesse, bezogen auf die Pixelraender (nicht -mittelpunkte).
+    ///
+    /// Rotationsterme werden ignoriert; Overviews sind achsparallel.
+    pub fn world_bounds(&self, width: u32, height: u32) -> (f64, f64, f64, f64) {
+        let left = self.origin_x - self.pixel_size_x / 2.0;
+        let right = left + self.pixel_size_x * width as f64;
+        let top = self.origin_y - self.pixel_size_y / 2.0;
+        let bottom = top + self.pixel_size_y * height as f64;
+        // Welt-Z = -Y
+        let (z_a, z_b) = (-top, -bottom);
+        (left.min(right), z_a.min(z_b), left.max(right), z_a.max(z_b))
+    }
+
+    /// Pfad des begleitenden World-Files nach ESRI-Konvention.
+    ///
+    /// `.png` → `.pgw`, `.jpg`/`.jpeg` → `.jgw`, `.tif`/`.tiff` → `.tfw`,
+    /// sonst `.wld`.
+    pub fn path_for_image(image_path: &Path) -> PathBuf {
+        let extension = image_path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+        let world_extension = match extension.as_deref() {
+            Some("png") => "pgw",
+            Some("jpg") | Some("jpeg") => "jgw",
+            Some("tif") | Some("tiff") => "tfw",
+            _ => "wld",
+        };
+        image_path.with_extension(world_extension)
+    }
+
+    /// Sucht und parst ein World-File neben `image_path` (`.pgw` usw., dann `.wld`).
+    ///
+    /// Gibt `Ok(None)` zurueck, wenn keines existiert.
+    pub fn find_for_image(image_path: &Path) -> Result<Option<Self>> {
+        let candidates = [
+            Self::path_for_image(image_path),
+            image_path.with_extension("wld"),
+        ];
+        for candidate in candidates {
+            if candidate.is_file() {
+                let content = std::fs::read_to_string(&candidate)
+                    .with_context(|| format!("World-File nicht lesbar: {}", candidate.display()))?;
+                return Self::parse(&content)
+                    .with_context(|| format!("Ungueltiges World-File: {}", candidate.display()))
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Schreibt das World-File neben `image_path` und gibt seinen Pfad zurueck.
+    pub fn write_for_image(&self, image_path: &Path) -> Result<PathBuf> {
+        let path = Self::path_for_image(image_path);
+        std::fs::write(&path, self.to_file_content()).with_context(|| {
+            format!(
+                "World-File konnte nicht geschrieben werden: {}",
+                path.display()
+            )
+        })?;
+        Ok(path)
+    }
+}
+
+/// Speichert eine Overview und schreibt das passende World-File daneben.
+///
+/// `map_size` ist die Weltgroesse in Metern (`MapInfo::map_size`); die
+/// Pixelgroesse ergibt sich aus der Bildbreite, sodass auch mit
+/// `OverviewOptions::output_size` verkleinerte Bilder korrekt ausgerichtet sind.
+/// Gibt den Pfad des World-Files zurueck.
+pub fn save_with_world_file(
+    image: &DynamicImage,
+    image_path: &Path,
+    map_size: u32,
+) -> Result<PathBuf> {
+    image.save(image_path).with_context(|| {
+        format!(
+            "Overview konnte nicht gespeichert werden: {}",
+            image_path.display()
+        )
+    })?;
+    WorldFile::for_overview(map_size, image.width()).write_for_image(image_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overview_world_file_centers_map_and_round_trips() {
+        let world = WorldFile::for_overview(2048, 1024);
+
+        assert_eq!(world.pixel_size_x, 2.0);
+        assert_eq!(world.pixel_size_y, -2.0);
+        assert_eq!(world.origin_x, -1023.0);
+        assert_eq!(world.origin_y, 1023.0);
+        assert_eq!(
+            world.world_bounds(1024, 1024),
+            (-1024.0, -1024.0, 1024.0, 1024.0)
+        );
+        assert_eq!(
+            WorldFile::parse(&world.to_file_content()).expect("Round-Trip"),
+            world
+        );
+    }
+
+    #[test]
+    fn parse_rejects_wrong_value_count() {
+        assert!(WorldFile::parse("1\n0\n0\n-1\n0\n").is_err());
+        assert!(WorldFile::parse("1 0 0 -1 0 x").is_err());
+    }
+
+    #[test]
+    fn path_for_image_follows_esri_convention() {
+        assert_eq!(
+            WorldFile::path_for_image(Path::new("out/overview.png")),
+            PathBuf::from("out/overview.pgw")
+        );
+        assert_eq!(
+            WorldFile::path_for_image(Path::new("overview.JPG")),
+            PathBuf::from("overview.jgw")
+        );
+        assert_eq!(
+            WorldFile::path_for_image(Path::new("overview.dds")),
+            PathBuf::from("overview.wld")
+        );
+    }
+}
@@ -133,6 +133,14 @@ if [ -n "$XML_UPPER_VIOLATIONS" ]; then
     VIOLATIONS=$((VIOLATIONS + 1))
 fi
 
+# Regel 3b: Engine-PDF darf nicht nach oben, in Frontends oder in den Render-Host greifen
+PDF_UPPER_VIOLATIONS=$(grep -rnE 'crate::app|crate::ui|crate::render|crate::editor_app|fs25_auto_drive_frontend_egui|fs25_auto_drive_host_bridge|fs25_auto_drive_render_wgpu' "$ENGINE_DIR/pdf" --include='*.rs' 2>/dev/null || true)
+if [ -n "$PDF_UPPER_VIOLATIONS" ]; then
+    echo "FEHLER: Engine-PDF importiert aus hoeheren Layern oder Frontend-Crates:"
+    echo "$PDF_UPPER_VIOLATIONS"
+    VIOLATIONS=$((VIOLATIONS + 1))
+fi
+
 # Regel 4: Engine-Shared darf keine Core-Typen direkt importieren
 SHARED_CORE_VIOLATIONS=$(grep -rn 'crate::core' "$ENGINE_DIR/shared" --include='*.rs' 2>/dev/null || true)
 if [ -n "$SHARED_CORE_VIOLATIONS" ]; then
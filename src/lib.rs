@@ -9,4 +9,4 @@ pub use fs25_auto_drive_frontend_egui::render;
 pub use fs25_auto_drive_frontend_egui::ui;
 
 pub use app::{AppCommand, AppController, AppIntent, AppState};
-pub use xml::{parse_autodrive_config, write_autodrive_config};
+pub use xml::{load_autodrive_config, parse_autodrive_config, write_autodrive_config};
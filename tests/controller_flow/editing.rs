@@ -5,9 +5,11 @@ use fs25_auto_drive_editor::app::ui_contract::{
     RouteToolConfigState, RouteToolPanelAction, SegmentConfigPanelAction, SmoothCurvePanelAction,
     StraightPanelAction,
 };
-use fs25_auto_drive_editor::app::{AppController, AppIntent, AppState, EditorTool, GroupRecord};
+use fs25_auto_drive_editor::app::{
+    AppController, AppIntent, AppState, EditorTool, GroupRecord, NodeDeleteFilter,
+};
 use fs25_auto_drive_editor::core::{
-    Connection, ConnectionDirection, ConnectionPriority, MapNode, NodeFlag, RoadMap,
+    Connection, ConnectionDirection, ConnectionPriority, FieldPolygon, MapNode, NodeFlag, RoadMap,
 };
 use std::sync::Arc;
 
@@ -555,6 +557,65 @@ fn route_tool_panel_action_requested_accepts_large_route_offset_distance_via_con
     );
 }
 
+#[test]
+fn route_offset_original_entfernen_refuses_locked_chain_via_controller_flow() {
+    let mut map = RoadMap::new(3);
+    let positions = [
+        glam::Vec2::new(0.0, 0.0),
+        glam::Vec2::new(10.0, 0.0),
+        glam::Vec2::new(20.0, 0.0),
+    ];
+    for (id, pos) in (1u64..).zip(positions) {
+        map.add_node(MapNode::new(id, pos, NodeFlag::Regular));
+    }
+    for (start, end) in [(1u64, 2u64), (2, 3)] {
+        map.add_connection(Connection::new(
+            start,
+            end,
+            ConnectionDirection::Regular,
+            ConnectionPriority::Regular,
+            positions[start as usize - 1],
+            positions[end as usize - 1],
+        ));
+    }
+    map.ensure_spatial_index();
+    let mut state = AppState::new();
+    state.road_map = Some(Arc::new(map));
+    state.view.viewport_size = [1280.0, 720.0];
+    state.selection.ids_mut().extend([1, 2, 3]);
+    state.locked_node_ids.insert(2);
+    let mut controller = AppController::new();
+
+    for intent in [
+        AppIntent::SelectRouteToolRequested {
+            tool_id: RouteToolId::RouteOffset,
+        },
+        AppIntent::RouteToolPanelActionRequested {
+            action: RouteToolPanelAction::RouteOffset(RouteOffsetPanelAction::SetKeepOriginal(
+                false,
+            )),
+        },
+        AppIntent::RouteToolExecuteRequested,
+    ] {
+        controller
+            .handle_intent(&mut state, intent)
+            .expect("RouteOffset-Flow sollte ueber den Controller laufen");
+    }
+
+    let road_map = state.road_map.as_ref().expect("RoadMap erwartet");
+    assert!(
+        road_map.contains_node(2),
+        "Gesperrter Node darf nicht entfernt werden"
+    );
+    assert_eq!(
+        road_map.node_count(),
+        3,
+        "Ergebnis darf nicht angewendet werden"
+    );
+    assert!(state.ui.status_message.is_some());
+    assert!(!state.can_undo());
+}
+
 #[test]
 fn route_tool_panel_action_requested_clamps_parking_values_via_controller_flow() {
     let mut controller = AppController::new();
@@ -681,6 +742,128 @@ fn trace_all_fields_dialog_requested_and_cancelled_toggle_visibility() {
     assert!(!state.ui.trace_all_fields_dialog.visible);
 }
 
+#[test]
+fn field_course_confirmed_closes_dialog_and_builds_ring_with_approach() {
+    let mut controller = AppController::new();
+    let mut state = AppState::new();
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(
+        1,
+        glam::Vec2::new(-40.0, 30.0),
+        NodeFlag::Regular,
+    ));
+    map.ensure_spatial_index();
+    state.road_map = Some(Arc::new(map));
+    state.farmland_polygons = Some(Arc::new(vec![FieldPolygon {
+        id: 5,
+        vertices: vec![
+            glam::Vec2::new(0.0, 0.0),
+            glam::Vec2::new(60.0, 0.0),
+            glam::Vec2::new(60.0, 60.0),
+            glam::Vec2::new(0.0, 60.0),
+        ],
+    }]));
+
+    controller
+        .handle_intent(&mut state, AppIntent::OpenFieldCourseDialogRequested)
+        .expect("OpenFieldCourseDialogRequested sollte den Dialog oeffnen");
+    assert!(state.ui.field_course_dialog.visible);
+
+    controller
+        .handle_intent(
+            &mut state,
+            AppIntent::FieldCourseConfirmed {
+                field_id: 5,
+                spacing: 10.0,
+                headland_offset: 4.0,
+            },
+        )
+        .expect("FieldCourseConfirmed sollte den Rundkurs erzeugen");
+
+    assert!(!state.ui.field_course_dialog.visible);
+    let rm = state.road_map.as_deref().expect("RoadMap vorhanden");
+    assert!(rm.node_count() > 5, "Ring und Zufahrt erwartet");
+    assert_eq!(rm.connected_neighbors(1).len(), 1);
+    assert_eq!(state.group_registry.len(), 1);
+    assert!(state.can_undo());
+}
+
+#[test]
+fn move_selection_confirmed_closes_dialog_and_moves_by_map_offset() {
+    let mut controller = AppController::new();
+    let mut state = AppState::new();
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(
+        1,
+        glam::Vec2::new(5.0, 5.0),
+        NodeFlag::Regular,
+    ));
+    map.ensure_spatial_index();
+    state.road_map = Some(Arc::new(map));
+    state.selection.ids_mut().insert(1);
+
+    controller
+        .handle_intent(&mut state, AppIntent::OpenMoveSelectionDialogRequested)
+        .expect("OpenMoveSelectionDialogRequested sollte den Dialog oeffnen");
+    assert!(state.ui.move_selection_dialog.visible);
+
+    controller
+        .handle_intent(
+            &mut state,
+            AppIntent::MoveSelectionConfirmed {
+                dx: 100.0,
+                dy: 0.0,
+                dz: -20.0,
+            },
+        )
+        .expect("MoveSelectionConfirmed sollte die Selektion verschieben");
+
+    assert!(!state.ui.move_selection_dialog.visible);
+    let rm = state.road_map.as_deref().expect("RoadMap vorhanden");
+    assert_eq!(rm.node_position(1), Some(glam::Vec2::new(105.0, -15.0)));
+    assert!(state.can_undo());
+}
+
+#[test]
+fn delete_by_filter_confirmed_closes_dialog_and_deletes_matching_nodes() {
+    let mut controller = AppController::new();
+    let mut state = AppState::new();
+    let mut map = RoadMap::new(3);
+    map.add_node(MapNode::new(1, glam::Vec2::ZERO, NodeFlag::SubPrio));
+    map.add_node(MapNode::new(
+        2,
+        glam::Vec2::new(10.0, 0.0),
+        NodeFlag::Regular,
+    ));
+    map.ensure_spatial_index();
+    state.road_map = Some(Arc::new(map));
+    state.selection.ids_mut().extend([1, 2]);
+
+    controller
+        .handle_intent(&mut state, AppIntent::OpenDeleteByFilterDialogRequested)
+        .expect("OpenDeleteByFilterDialogRequested sollte den Dialog oeffnen");
+    assert!(state.ui.delete_by_filter_dialog.visible);
+    assert_eq!(state.ui.delete_by_filter_dialog.matching_count(), 2);
+
+    controller
+        .handle_intent(
+            &mut state,
+            AppIntent::DeleteByFilterConfirmed {
+                filter: NodeDeleteFilter {
+                    only_subprio: true,
+                    ..NodeDeleteFilter::default()
+                },
+            },
+        )
+        .expect("DeleteByFilterConfirmed sollte passende Nodes loeschen");
+
+    assert!(!state.ui.delete_by_filter_dialog.visible);
+    let rm = state.road_map.as_deref().expect("RoadMap vorhanden");
+    assert!(!rm.contains_node(1));
+    assert!(rm.contains_node(2));
+    assert!(state.can_undo());
+}
+
 #[test]
 fn curseplay_dialog_requests_are_enqueued_for_host_dialogs() {
     let mut controller = AppController::new();